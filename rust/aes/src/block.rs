@@ -0,0 +1,86 @@
+// Helpers for the modes that treat a 128-bit block as a single integer.
+// Words are big-endian, as in `WordArray`, so word 0 holds the most significant bits.
+
+use crate::{decryptBlock, encryptBlock};
+
+pub fn wordsToU128(words: &[u32]) -> u128 {
+    ((words[0] as u128) << 96) | ((words[1] as u128) << 64) | ((words[2] as u128) << 32) | (words[3] as u128)
+}
+
+pub fn u128ToWords(block: u128) -> [u32; 4] {
+    [(block >> 96) as u32, (block >> 64) as u32, (block >> 32) as u32, block as u32]
+}
+
+// Read the `index`-th block of a `WordArray`-like buffer, zero-filling anything past `sigBytes`
+pub fn readBlock(words: &[u32], sigBytes: usize, index: usize) -> u128 {
+    let start = index * 16;
+    let mut block: u128 = 0;
+    for i in 0..16 {
+        let pos = start + i;
+        let byte = if pos < sigBytes && pos / 4 < words.len() {
+            (words[pos / 4] >> (24 - (pos % 4) * 8)) & 0xff
+        } else {
+            0
+        };
+        block = (block << 8) | byte as u128;
+    }
+
+    block
+}
+
+// Write the `index`-th block, leaving any bytes past `sigBytes` untouched
+pub fn writeBlock(words: &mut [u32], sigBytes: usize, index: usize, block: u128) {
+    let start = index * 16;
+    for i in 0..16 {
+        let pos = start + i;
+        if pos >= sigBytes {
+            break;
+        }
+        let byte = ((block >> (120 - i * 8)) & 0xff) as u32;
+        let shift = 24 - (pos % 4) * 8;
+        words[pos / 4] = (words[pos / 4] & !(0xff << shift)) | (byte << shift);
+    }
+}
+
+pub fn wordsToBytes(words: &[u32], sigBytes: usize) -> Vec<u8> {
+    (0..sigBytes).map(|i| (words[i / 4] >> (24 - (i % 4) * 8)) as u8).collect()
+}
+
+// Load up to 16 bytes as the leading bytes of a block, zero-filling the rest
+pub fn bytesToU128(bytes: &[u8]) -> u128 {
+    let mut block: u128 = 0;
+    for i in 0..16 {
+        block = (block << 8) | *bytes.get(i).unwrap_or(&0) as u128;
+    }
+
+    block
+}
+
+// Clear the bytes of the last word that lie past `sigBytes`
+pub fn clampWords(words: &mut [u32], sigBytes: usize) {
+    if sigBytes % 4 != 0 && sigBytes / 4 < words.len() {
+        words[sigBytes / 4] &= 0xffffffff << (32 - (sigBytes % 4) * 8);
+    }
+}
+
+pub fn encryptU128(nRounds: usize, keySchedule: &[u32], block: u128) -> u128 {
+    let mut words = u128ToWords(block);
+    encryptBlock(nRounds, &mut words, 0, keySchedule);
+    wordsToU128(&words)
+}
+
+pub fn decryptU128(nRounds: usize, invKeySchedule: &[u32], block: u128) -> u128 {
+    let mut words = u128ToWords(block);
+    decryptBlock(nRounds, &mut words, 0, invKeySchedule);
+    wordsToU128(&words)
+}
+
+// Compare the first `nBytes` bytes of two blocks without branching on their contents
+pub fn constantTimeEq(a: u128, b: u128, nBytes: usize) -> bool {
+    let mask = if nBytes >= 16 { u128::MAX } else { !(u128::MAX >> (nBytes * 8)) };
+    let diff = (a ^ b) & mask;
+    let folded = diff as u64 | (diff >> 64) as u64;
+    let folded = folded as u32 | (folded >> 32) as u32;
+
+    folded == 0
+}
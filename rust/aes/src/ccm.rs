@@ -0,0 +1,224 @@
+use wasm_bindgen::prelude::*;
+use crate::block::{bytesToU128, clampWords, constantTimeEq, encryptU128, readBlock, u128ToWords, wordsToBytes, writeBlock};
+
+/// AES-CCM encryption (RFC 3610, NIST SP 800-38C).
+///
+/// The nonce is 7 to 13 bytes long and the tag an even number of bytes from 4 to 16.
+/// Encrypts the first `dataSigBytes` bytes of `dataWords` in place and returns the tag.
+/// Returns an empty vector and leaves the data untouched when the parameters are not valid.
+#[wasm_bindgen]
+pub fn ccmEncrypt(
+    nRounds: usize,
+    keySchedule: &[u32],
+    nonce: &[u32],
+    nonceSigBytes: usize,
+    aad: &[u32],
+    aadSigBytes: usize,
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    tagSigBytes: usize,
+) -> Vec<u32> {
+    if !isValidParameters(nonceSigBytes, dataSigBytes, tagSigBytes) {
+        return Vec::new();
+    }
+
+    clampWords(dataWords, dataSigBytes);
+    let mac = cbcMac(nRounds, keySchedule, nonce, nonceSigBytes, aad, aadSigBytes, dataWords, dataSigBytes, tagSigBytes);
+    let a0 = getCounterBlock(nonce, nonceSigBytes);
+    ctr(nRounds, keySchedule, a0, dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+
+    let tag = mac ^ encryptU128(nRounds, keySchedule, a0);
+    let mut tagWords = u128ToWords(tag)[0..(tagSigBytes + 3) / 4].to_vec();
+    clampWords(&mut tagWords, tagSigBytes);
+
+    tagWords
+}
+
+/// AES-CCM decryption (RFC 3610, NIST SP 800-38C).
+///
+/// Returns `false` when the tag does not match, in which case `dataWords` is restored
+/// to the ciphertext so that no unauthenticated plaintext is handed back.
+#[wasm_bindgen]
+pub fn ccmDecrypt(
+    nRounds: usize,
+    keySchedule: &[u32],
+    nonce: &[u32],
+    nonceSigBytes: usize,
+    aad: &[u32],
+    aadSigBytes: usize,
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    tag: &[u32],
+    tagSigBytes: usize,
+) -> bool {
+    if !isValidParameters(nonceSigBytes, dataSigBytes, tagSigBytes) {
+        return false;
+    }
+
+    // The MAC is computed over the plaintext, so decrypt first and roll back on failure
+    clampWords(dataWords, dataSigBytes);
+    let ciphertext = dataWords.to_vec();
+    let a0 = getCounterBlock(nonce, nonceSigBytes);
+    ctr(nRounds, keySchedule, a0, dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+
+    let mac = cbcMac(nRounds, keySchedule, nonce, nonceSigBytes, aad, aadSigBytes, dataWords, dataSigBytes, tagSigBytes);
+    let expectedTag = mac ^ encryptU128(nRounds, keySchedule, a0);
+    if !constantTimeEq(expectedTag, readBlock(tag, tagSigBytes, 0), tagSigBytes) {
+        dataWords.copy_from_slice(&ciphertext);
+        return false;
+    }
+
+    true
+}
+
+fn isValidParameters(nonceSigBytes: usize, dataSigBytes: usize, tagSigBytes: usize) -> bool {
+    if !(7..=13).contains(&nonceSigBytes) || !matches!(tagSigBytes, 4 | 6 | 8 | 10 | 12 | 14 | 16) {
+        return false;
+    }
+
+    // The message length has to fit in the q = 15 - n length bytes of B0
+    let q = 15 - nonceSigBytes;
+    q >= 8 || (dataSigBytes as u64) < (1u64 << (8 * q))
+}
+
+// Flags || N || 0, counter 0 of the CTR part. Counter i is this block plus i.
+fn getCounterBlock(nonce: &[u32], nonceSigBytes: usize) -> u128 {
+    let q = 15 - nonceSigBytes;
+    let flags = (q - 1) as u128;
+
+    (flags << 120) | (readBlock(nonce, nonceSigBytes, 0) >> 8)
+}
+
+fn cbcMac(
+    nRounds: usize,
+    keySchedule: &[u32],
+    nonce: &[u32],
+    nonceSigBytes: usize,
+    aad: &[u32],
+    aadSigBytes: usize,
+    plaintext: &[u32],
+    plaintextSigBytes: usize,
+    tagSigBytes: usize,
+) -> u128 {
+    let q = 15 - nonceSigBytes;
+
+    // B0: flags || N || message length
+    let adata = if aadSigBytes > 0 { 0x40 } else { 0 };
+    let flags = (adata | (((tagSigBytes - 2) / 2) << 3) | (q - 1)) as u128;
+    let b0 = (flags << 120) | (readBlock(nonce, nonceSigBytes, 0) >> 8) | plaintextSigBytes as u128;
+    let mut x = encryptU128(nRounds, keySchedule, b0);
+
+    // Length-prefixed associated data, zero-padded to a whole number of blocks
+    if aadSigBytes > 0 {
+        let mut header: Vec<u8> = Vec::new();
+        if aadSigBytes < 0xff00 {
+            header.extend_from_slice(&(aadSigBytes as u16).to_be_bytes());
+        } else if (aadSigBytes as u64) <= 0xffffffff {
+            header.extend_from_slice(&[0xff, 0xfe]);
+            header.extend_from_slice(&(aadSigBytes as u32).to_be_bytes());
+        } else {
+            header.extend_from_slice(&[0xff, 0xff]);
+            header.extend_from_slice(&(aadSigBytes as u64).to_be_bytes());
+        }
+        header.extend(wordsToBytes(aad, aadSigBytes));
+        for chunk in header.chunks(16) {
+            x = encryptU128(nRounds, keySchedule, x ^ bytesToU128(chunk));
+        }
+    }
+
+    for i in 0..(plaintextSigBytes + 15) / 16 {
+        x = encryptU128(nRounds, keySchedule, x ^ readBlock(plaintext, plaintextSigBytes, i));
+    }
+
+    x
+}
+
+fn ctr(nRounds: usize, keySchedule: &[u32], a0: u128, dataWords: &mut [u32], dataSigBytes: usize) {
+    for i in 0..(dataSigBytes + 15) / 16 {
+        let keystream = encryptU128(nRounds, keySchedule, a0 + (i as u128 + 1));
+        let block = readBlock(dataWords, dataSigBytes, i) ^ keystream;
+        writeBlock(dataWords, dataSigBytes, i, block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::getKeySchedule;
+    use crate::test_utils::hexToWords;
+
+    fn check(key: &str, nonce: &str, aad: &str, plaintext: &str, ciphertext: &str, tag: &str) {
+        let (keyWords, keySigBytes) = hexToWords(key);
+        let nRounds = keySigBytes / 4 + 6;
+        let keySchedule = getKeySchedule(keySigBytes as u32 / 4, &keyWords);
+        let (nonce, nonceSigBytes) = hexToWords(nonce);
+        let (aad, aadSigBytes) = hexToWords(aad);
+        let (mut data, dataSigBytes) = hexToWords(plaintext);
+        let (expectedTag, tagSigBytes) = hexToWords(tag);
+
+        let computedTag = ccmEncrypt(nRounds, &keySchedule, &nonce, nonceSigBytes, &aad, aadSigBytes, &mut data, dataSigBytes, tagSigBytes);
+        assert_eq!(data, hexToWords(ciphertext).0);
+        assert_eq!(computedTag, expectedTag);
+
+        assert!(ccmDecrypt(nRounds, &keySchedule, &nonce, nonceSigBytes, &aad, aadSigBytes, &mut data, dataSigBytes, &computedTag, tagSigBytes));
+        assert_eq!(data, hexToWords(plaintext).0);
+    }
+
+    const RFC3610_KEY: &str = "c0c1c2c3c4c5c6c7c8c9cacbcccdcecf";
+
+    #[test]
+    fn rfc3610_packet_vector_1() {
+        check(RFC3610_KEY, "00000003020100a0a1a2a3a4a5", "0001020304050607",
+              "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e",
+              "588c979a61c663d2f066d0c2c0f989806d5f6b61dac384", "17e8d12cfdf926e0");
+    }
+
+    #[test]
+    fn rfc3610_packet_vector_2() {
+        check(RFC3610_KEY, "00000004030201a0a1a2a3a4a5", "0001020304050607",
+              "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+              "72c91a36e135f8cf291ca894085c87e3cc15c439c9e43a3b", "a091d56e10400916");
+    }
+
+    #[test]
+    fn rfc3610_packet_vector_3() {
+        check(RFC3610_KEY, "00000005040302a0a1a2a3a4a5", "0001020304050607",
+              "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+              "51b1e5f44a197d1da46b0f8e2d282ae871e838bb64da859657", "4adaa76fbd9fb0c5");
+    }
+
+    #[test]
+    fn sp800_38c_short_nonce_and_tag() {
+        check("404142434445464748494a4b4c4d4e4f", "10111213141516", "0001020304050607",
+              "20212223", "7162015b", "4dac255d");
+        check("404142434445464748494a4b4c4d4e4f", "1011121314151617", "000102030405060708090a0b0c0d0e0f",
+              "202122232425262728292a2b2c2d2e2f", "d2a1f0e051ea5f62081a7792073d593d", "1fc64fbfaccd");
+    }
+
+    #[test]
+    fn rejects_tampered_tag() {
+        let (keyWords, _) = hexToWords(RFC3610_KEY);
+        let keySchedule = getKeySchedule(4, &keyWords);
+        let (nonce, _) = hexToWords("00000003020100a0a1a2a3a4a5");
+        let (mut data, dataSigBytes) = hexToWords("08090a0b0c0d0e0f1011");
+
+        let mut tag = ccmEncrypt(10, &keySchedule, &nonce, 13, &[], 0, &mut data, dataSigBytes, 8);
+        let ciphertext = data.clone();
+        tag[1] ^= 0x80;
+        assert!(!ccmDecrypt(10, &keySchedule, &nonce, 13, &[], 0, &mut data, dataSigBytes, &tag, 8));
+        assert_eq!(data, ciphertext);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let keySchedule = getKeySchedule(4, &[0; 4]);
+        let mut data = vec![0; 4];
+        // Nonce too short, odd tag size, message too long for a 2-byte length field
+        assert!(ccmEncrypt(10, &keySchedule, &[0; 2], 6, &[], 0, &mut data, 16, 8).is_empty());
+        assert!(ccmEncrypt(10, &keySchedule, &[0; 4], 13, &[], 0, &mut data, 16, 7).is_empty());
+        assert!(!isValidParameters(13, 0x10000, 8));
+        assert!(isValidParameters(13, 0xffff, 8));
+    }
+}
@@ -0,0 +1,107 @@
+use wasm_bindgen::prelude::*;
+use crate::block::{bytesToU128, encryptU128, readBlock, u128ToWords, wordsToBytes, wordsToU128};
+
+// Multiplication by x in GF(2^128) with the x^128 + x^7 + x^2 + x + 1 polynomial
+pub fn dbl(block: u128) -> u128 {
+    let carry = block >> 127;
+    (block << 1) ^ (0x87 & 0u128.wrapping_sub(carry))
+}
+
+// K1 and K2 of RFC 4493, section 2.3
+pub fn getSubkeys(nRounds: usize, keySchedule: &[u32]) -> (u128, u128) {
+    let l = encryptU128(nRounds, keySchedule, 0);
+    let k1 = dbl(l);
+    let k2 = dbl(k1);
+
+    (k1, k2)
+}
+
+// Up to 15 bytes followed by the 10* padding
+pub fn padBlock(bytes: &[u8]) -> u128 {
+    let mut padded = bytes.to_vec();
+    padded.push(0x80);
+    bytesToU128(&padded)
+}
+
+/// Absorbs `nBlocks` complete blocks of `dataWords` into the CBC-MAC state `x` of a
+/// streaming AES-CMAC (RFC 4493).
+///
+/// The caller must hold back the last block of the message, even when it is complete,
+/// since it is combined with a subkey by `cmacFinalize`.
+#[wasm_bindgen]
+pub fn cmacUpdate(nRounds: usize, keySchedule: &[u32], x: &mut [u32], dataWords: &[u32], nBlocks: usize) {
+    let mut state = wordsToU128(x);
+    for i in 0..nBlocks {
+        state = encryptU128(nRounds, keySchedule, state ^ readBlock(dataWords, nBlocks * 16, i));
+    }
+    x.copy_from_slice(&u128ToWords(state));
+}
+
+/// Completes a streaming AES-CMAC (RFC 4493) with the held-back last block of the message,
+/// between 0 and 16 bytes, and returns the 128-bit MAC.
+#[wasm_bindgen]
+pub fn cmacFinalize(nRounds: usize, keySchedule: &[u32], x: &[u32], lastWords: &[u32], lastSigBytes: usize) -> Vec<u32> {
+    let last = wordsToBytes(lastWords, lastSigBytes.min(16));
+    let mac = finalizeBlock(nRounds, keySchedule, wordsToU128(x), &last);
+
+    u128ToWords(mac).to_vec()
+}
+
+// Last step of RFC 4493, section 2.4: a complete block is masked with K1, a padded one with K2
+fn finalizeBlock(nRounds: usize, keySchedule: &[u32], x: u128, last: &[u8]) -> u128 {
+    let (k1, k2) = getSubkeys(nRounds, keySchedule);
+    let lastBlock = if last.len() == 16 { bytesToU128(last) ^ k1 } else { padBlock(last) ^ k2 };
+
+    encryptU128(nRounds, keySchedule, x ^ lastBlock)
+}
+
+/// One-shot AES-CMAC (RFC 4493) of a byte string.
+pub fn cmac(nRounds: usize, keySchedule: &[u32], message: &[u8]) -> u128 {
+    let nBlocks = std::cmp::max((message.len() + 15) / 16, 1);
+
+    let mut x: u128 = 0;
+    for i in 0..nBlocks - 1 {
+        x = encryptU128(nRounds, keySchedule, x ^ bytesToU128(&message[i * 16..i * 16 + 16]));
+    }
+
+    finalizeBlock(nRounds, keySchedule, x, &message[(nBlocks - 1) * 16..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::getKeySchedule;
+    use crate::test_utils::{hexToBytes, hexToWords};
+
+    // RFC 4493, section 4
+    #[test]
+    fn rfc4493_examples() {
+        let keySchedule = getKeySchedule(4, &hexToWords("2b7e151628aed2a6abf7158809cf4f3c").0);
+        let message = hexToBytes("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                                  30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710");
+
+        assert_eq!(cmac(10, &keySchedule, &[]), 0xbb1d6929e95937287fa37d129b756746);
+        assert_eq!(cmac(10, &keySchedule, &message[..16]), 0x070a16b46b4d4144f79bdd9dd04a287c);
+        assert_eq!(cmac(10, &keySchedule, &message[..40]), 0xdfa66747de9ae63030ca32611497c827);
+        assert_eq!(cmac(10, &keySchedule, &message), 0x51f0bebf7e3b9d92fc49741779363cfe);
+    }
+
+    // The same message fed block by block must give the one-shot result
+    #[test]
+    fn streaming_matches_one_shot() {
+        let keySchedule = getKeySchedule(4, &hexToWords("2b7e151628aed2a6abf7158809cf4f3c").0);
+        let (message, _) = hexToWords("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                                       30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710");
+
+        let mut x = [0u32; 4];
+        cmacUpdate(10, &keySchedule, &mut x, &message[..4], 1);
+        cmacUpdate(10, &keySchedule, &mut x, &message[4..12], 2);
+        assert_eq!(cmacFinalize(10, &keySchedule, &x, &message[12..], 16), u128ToWords(0x51f0bebf7e3b9d92fc49741779363cfe));
+
+        let mut x = [0u32; 4];
+        cmacUpdate(10, &keySchedule, &mut x, &message[..8], 2);
+        assert_eq!(cmacFinalize(10, &keySchedule, &x, &message[8..], 8), u128ToWords(0xdfa66747de9ae63030ca32611497c827));
+
+        assert_eq!(cmacFinalize(10, &keySchedule, &[0; 4], &[], 0), u128ToWords(0xbb1d6929e95937287fa37d129b756746));
+    }
+}
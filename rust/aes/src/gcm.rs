@@ -0,0 +1,246 @@
+use wasm_bindgen::prelude::*;
+use crate::block::{clampWords, constantTimeEq, encryptU128, readBlock, u128ToWords, writeBlock};
+
+/// AES-GCM encryption (NIST SP 800-38D).
+///
+/// Encrypts the first `dataSigBytes` bytes of `dataWords` in place and returns the
+/// authentication tag truncated to `tagSigBytes` bytes. Returns an empty vector and
+/// leaves the data untouched when the IV or tag size is not valid.
+#[wasm_bindgen]
+pub fn gcmEncrypt(
+    nRounds: usize,
+    keySchedule: &[u32],
+    iv: &[u32],
+    ivSigBytes: usize,
+    aad: &[u32],
+    aadSigBytes: usize,
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    tagSigBytes: usize,
+) -> Vec<u32> {
+    if ivSigBytes == 0 || !isValidTagSize(tagSigBytes) {
+        return Vec::new();
+    }
+
+    let h = encryptU128(nRounds, keySchedule, 0);
+    let j0 = getJ0(h, iv, ivSigBytes);
+
+    gctr(nRounds, keySchedule, inc32(j0), dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+    let tag = computeTag(nRounds, keySchedule, h, j0, aad, aadSigBytes, dataWords, dataSigBytes);
+
+    let mut tagWords = u128ToWords(tag)[0..(tagSigBytes + 3) / 4].to_vec();
+    clampWords(&mut tagWords, tagSigBytes);
+
+    tagWords
+}
+
+/// AES-GCM decryption (NIST SP 800-38D).
+///
+/// The tag is checked before anything is decrypted: on a mismatch `false` is returned
+/// and `dataWords` still holds the ciphertext. On success the first `dataSigBytes`
+/// bytes of `dataWords` are replaced with the plaintext.
+#[wasm_bindgen]
+pub fn gcmDecrypt(
+    nRounds: usize,
+    keySchedule: &[u32],
+    iv: &[u32],
+    ivSigBytes: usize,
+    aad: &[u32],
+    aadSigBytes: usize,
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    tag: &[u32],
+    tagSigBytes: usize,
+) -> bool {
+    if ivSigBytes == 0 || !isValidTagSize(tagSigBytes) {
+        return false;
+    }
+
+    let h = encryptU128(nRounds, keySchedule, 0);
+    let j0 = getJ0(h, iv, ivSigBytes);
+
+    clampWords(dataWords, dataSigBytes);
+    let expectedTag = computeTag(nRounds, keySchedule, h, j0, aad, aadSigBytes, dataWords, dataSigBytes);
+    if !constantTimeEq(expectedTag, readBlock(tag, tagSigBytes, 0), tagSigBytes) {
+        return false;
+    }
+
+    gctr(nRounds, keySchedule, inc32(j0), dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+
+    true
+}
+
+// 128, 120, 112, 104 and 96 bit tags, plus the 64 and 32 bit tags of SP 800-38D appendix C
+fn isValidTagSize(tagSigBytes: usize) -> bool {
+    matches!(tagSigBytes, 4 | 8 | 12..=16)
+}
+
+// Multiplication in GF(2^128) using GCM's reflected bit order. Every step does the
+// same work regardless of the operands, so the timing does not depend on H.
+pub fn gfMul(x: u128, y: u128) -> u128 {
+    let mut z: u128 = 0;
+    let mut v = y;
+    for i in 0..128 {
+        let bit = (x >> (127 - i)) & 1;
+        z ^= v & 0u128.wrapping_sub(bit);
+        let lsb = v & 1;
+        v = (v >> 1) ^ ((0xe1 << 120) & 0u128.wrapping_sub(lsb));
+    }
+
+    z
+}
+
+// Absorb `sigBytes` bytes into the GHASH state `y`, zero-padding the last block
+pub fn ghash(h: u128, y: u128, words: &[u32], sigBytes: usize) -> u128 {
+    let mut y = y;
+    for i in 0..(sigBytes + 15) / 16 {
+        y = gfMul(y ^ readBlock(words, sigBytes, i), h);
+    }
+
+    y
+}
+
+// Increment the rightmost 32 bits, modulo 2^32
+fn inc32(block: u128) -> u128 {
+    let low = (block as u32).wrapping_add(1);
+    (block & !0xffffffff) | low as u128
+}
+
+// Pre-counter block: IV || 0^31 || 1 for 96-bit IVs, GHASH of the padded IV otherwise
+fn getJ0(h: u128, iv: &[u32], ivSigBytes: usize) -> u128 {
+    if ivSigBytes == 12 {
+        readBlock(iv, ivSigBytes, 0) | 1
+    } else {
+        let s = ghash(h, 0, iv, ivSigBytes);
+        gfMul(s ^ (ivSigBytes as u128 * 8), h)
+    }
+}
+
+fn gctr(nRounds: usize, keySchedule: &[u32], icb: u128, dataWords: &mut [u32], dataSigBytes: usize) {
+    let mut counter = icb;
+    for i in 0..(dataSigBytes + 15) / 16 {
+        let keystream = encryptU128(nRounds, keySchedule, counter);
+        let block = readBlock(dataWords, dataSigBytes, i) ^ keystream;
+        writeBlock(dataWords, dataSigBytes, i, block);
+        counter = inc32(counter);
+    }
+}
+
+fn computeTag(
+    nRounds: usize,
+    keySchedule: &[u32],
+    h: u128,
+    j0: u128,
+    aad: &[u32],
+    aadSigBytes: usize,
+    ciphertext: &[u32],
+    ciphertextSigBytes: usize,
+) -> u128 {
+    let mut s = ghash(h, 0, aad, aadSigBytes);
+    s = ghash(h, s, ciphertext, ciphertextSigBytes);
+    let lengths = ((aadSigBytes as u128 * 8) << 64) | (ciphertextSigBytes as u128 * 8);
+    s = gfMul(s ^ lengths, h);
+
+    encryptU128(nRounds, keySchedule, j0) ^ s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::getKeySchedule;
+    use crate::test_utils::hexToWords;
+
+    // Test cases from "The Galois/Counter Mode of Operation (GCM)", McGrew and Viega
+    fn check(key: &str, iv: &str, plaintext: &str, aad: &str, ciphertext: &str, tag: &str) {
+        let (keyWords, keySigBytes) = hexToWords(key);
+        let nRounds = keySigBytes / 4 + 6;
+        let keySchedule = getKeySchedule(keySigBytes as u32 / 4, &keyWords);
+        let (iv, ivSigBytes) = hexToWords(iv);
+        let (aad, aadSigBytes) = hexToWords(aad);
+        let (mut data, dataSigBytes) = hexToWords(plaintext);
+
+        let computedTag = gcmEncrypt(nRounds, &keySchedule, &iv, ivSigBytes, &aad, aadSigBytes, &mut data, dataSigBytes, 16);
+        assert_eq!(data, hexToWords(ciphertext).0);
+        assert_eq!(computedTag, hexToWords(tag).0);
+
+        assert!(gcmDecrypt(nRounds, &keySchedule, &iv, ivSigBytes, &aad, aadSigBytes, &mut data, dataSigBytes, &computedTag, 16));
+        assert_eq!(data, hexToWords(plaintext).0);
+    }
+
+    const KEY: &str = "feffe9928665731c6d6a8f9467308308";
+    const PLAINTEXT: &str = "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72\
+                             1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39";
+    const AAD: &str = "feedfacedeadbeeffeedfacedeadbeefabaddad2";
+
+    #[test]
+    fn empty_message() {
+        check("00000000000000000000000000000000", "000000000000000000000000", "", "", "",
+              "58e2fccefa7e3061367f1d57a4e7455a");
+    }
+
+    #[test]
+    fn single_zero_block() {
+        check("00000000000000000000000000000000", "000000000000000000000000",
+              "00000000000000000000000000000000", "",
+              "0388dace60b6a392f328c2b971b2fe78", "ab6e47d42cec13bdf53a67b21257bddf");
+    }
+
+    #[test]
+    fn partial_block_with_aad() {
+        check(KEY, "cafebabefacedbaddecaf888", PLAINTEXT, AAD,
+              "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e\
+               21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091",
+              "5bc94fbc3221a5db94fae95ae7121a47");
+    }
+
+    #[test]
+    fn short_iv() {
+        check(KEY, "cafebabefacedbad", PLAINTEXT, AAD,
+              "61353b4c2806934a777ff51fa22a4755699b2a714fcdc6f83766e5f97b6c7423\
+               73806900e49f24b22b097544d4896b424989b5e1ebac0f07c23f4598",
+              "3612d2e79e3b0785561be14aaca2fccb");
+    }
+
+    #[test]
+    fn long_iv() {
+        check(KEY, "9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728\
+                    c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b",
+              PLAINTEXT, AAD,
+              "8ce24998625615b603a033aca13fb894be9112a5c3a211a8ba262a3cca7e2ca7\
+               01e4a9a4fba43c90ccdcb281d48c7c6fd62875d2aca417034c34aee5",
+              "619cc5aefffe0bfa462af43c1699d050");
+    }
+
+    #[test]
+    fn rejects_tampered_message() {
+        let (keyWords, _) = hexToWords(KEY);
+        let keySchedule = getKeySchedule(4, &keyWords);
+        let (iv, _) = hexToWords("cafebabefacedbaddecaf888");
+        let (aad, aadSigBytes) = hexToWords(AAD);
+        let (mut data, dataSigBytes) = hexToWords(PLAINTEXT);
+
+        let tag = gcmEncrypt(10, &keySchedule, &iv, 12, &aad, aadSigBytes, &mut data, dataSigBytes, 12);
+        assert_eq!(tag.len(), 3);
+
+        let ciphertext = data.clone();
+        data[0] ^= 1;
+        assert!(!gcmDecrypt(10, &keySchedule, &iv, 12, &aad, aadSigBytes, &mut data, dataSigBytes, &tag, 12));
+        // Nothing is decrypted when the tag does not match
+        assert_eq!(data[1..], ciphertext[1..]);
+
+        data[0] ^= 1;
+        assert!(!gcmDecrypt(10, &keySchedule, &iv, 12, &aad, aadSigBytes - 1, &mut data, dataSigBytes, &tag, 12));
+        assert!(gcmDecrypt(10, &keySchedule, &iv, 12, &aad, aadSigBytes, &mut data, dataSigBytes, &tag, 12));
+        assert_eq!(data, hexToWords(PLAINTEXT).0);
+    }
+
+    #[test]
+    fn rejects_invalid_tag_size() {
+        let keySchedule = getKeySchedule(4, &[0; 4]);
+        let mut data = vec![0x01020304];
+        assert!(gcmEncrypt(10, &keySchedule, &[0; 3], 12, &[], 0, &mut data, 4, 2).is_empty());
+        assert_eq!(data, vec![0x01020304]);
+    }
+}
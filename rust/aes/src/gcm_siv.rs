@@ -0,0 +1,196 @@
+use wasm_bindgen::prelude::*;
+use crate::block::{clampWords, constantTimeEq, encryptU128, readBlock, u128ToWords, wordsToU128, writeBlock};
+use crate::gcm::gfMul;
+use crate::utils::getKeySchedules;
+
+/// AES-GCM-SIV encryption (RFC 8452).
+///
+/// Takes the raw 128 or 256 bit key-generating key (`keySize` in words) since per-nonce
+/// keys are derived from it, and a 96-bit nonce. Encrypts the first `dataSigBytes`
+/// bytes of `dataWords` in place and returns the 128-bit tag, which is appended to the
+/// ciphertext. Returns an empty vector when the key or nonce size is not valid.
+#[wasm_bindgen]
+pub fn gcmSivEncrypt(
+    keySize: u32,
+    keyWords: &[u32],
+    nonce: &[u32],
+    nonceSigBytes: usize,
+    aad: &[u32],
+    aadSigBytes: usize,
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+) -> Vec<u32> {
+    if !(keySize == 4 || keySize == 8) || nonceSigBytes != 12 {
+        return Vec::new();
+    }
+
+    let nonce = readBlock(nonce, nonceSigBytes, 0);
+    let (authKey, encNRounds, encKeySchedule) = deriveKeys(keySize, keyWords, nonce);
+
+    clampWords(dataWords, dataSigBytes);
+    let tag = computeTag(authKey, encNRounds, &encKeySchedule, nonce, aad, aadSigBytes, dataWords, dataSigBytes);
+    ctr(encNRounds, &encKeySchedule, tag, dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+
+    u128ToWords(tag).to_vec()
+}
+
+/// AES-GCM-SIV decryption (RFC 8452).
+///
+/// Returns `false` when the tag does not match the decrypted message, in which case
+/// `dataWords` is restored to the ciphertext.
+#[wasm_bindgen]
+pub fn gcmSivDecrypt(
+    keySize: u32,
+    keyWords: &[u32],
+    nonce: &[u32],
+    nonceSigBytes: usize,
+    aad: &[u32],
+    aadSigBytes: usize,
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    tag: &[u32],
+) -> bool {
+    if !(keySize == 4 || keySize == 8) || nonceSigBytes != 12 || tag.len() < 4 {
+        return false;
+    }
+
+    let nonce = readBlock(nonce, nonceSigBytes, 0);
+    let (authKey, encNRounds, encKeySchedule) = deriveKeys(keySize, keyWords, nonce);
+    let tag = wordsToU128(tag);
+
+    clampWords(dataWords, dataSigBytes);
+    let ciphertext = dataWords.to_vec();
+    ctr(encNRounds, &encKeySchedule, tag, dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+
+    let expected = computeTag(authKey, encNRounds, &encKeySchedule, nonce, aad, aadSigBytes, dataWords, dataSigBytes);
+    if !constantTimeEq(expected, tag, 16) {
+        dataWords.copy_from_slice(&ciphertext);
+        return false;
+    }
+
+    true
+}
+
+// Per-nonce message-authentication key and message-encryption key schedule (RFC 8452, section 4)
+fn deriveKeys(keySize: u32, keyWords: &[u32], nonce: u128) -> (u128, usize, Vec<u32>) {
+    let nRounds = keySize as usize + 6;
+    let keySchedule = getKeySchedules(keySize, keyWords);
+
+    // Each block is LE32(counter) || nonce, and only the first half of each output is kept
+    let nBlocks = 2 + keySize as usize / 2;
+    let halves: Vec<u64> = (0..nBlocks as u32)
+        .map(|i| {
+            let input = ((i.swap_bytes() as u128) << 96) | (nonce >> 32);
+            (encryptU128(nRounds, &keySchedule, input) >> 64) as u64
+        })
+        .collect();
+
+    let authKey = ((halves[0] as u128) << 64) | halves[1] as u128;
+    let encKeyWords: Vec<u32> = halves[2..]
+        .iter()
+        .flat_map(|half| vec![(half >> 32) as u32, *half as u32])
+        .collect();
+
+    (authKey, nRounds, getKeySchedules(keySize, &encKeyWords))
+}
+
+// POLYVAL works on little-endian blocks. RFC 8452, appendix A expresses it through GHASH:
+// POLYVAL(H, X) = ByteReverse(GHASH(mulX_GHASH(ByteReverse(H)), ByteReverse(X)))
+fn polyvalAbsorb(h: u128, s: u128, words: &[u32], sigBytes: usize) -> u128 {
+    let mut s = s;
+    for i in 0..(sigBytes + 15) / 16 {
+        s = gfMul(s ^ readBlock(words, sigBytes, i).swap_bytes(), h);
+    }
+
+    s
+}
+
+fn mulX(block: u128) -> u128 {
+    let lsb = block & 1;
+    (block >> 1) ^ ((0xe1 << 120) & 0u128.wrapping_sub(lsb))
+}
+
+fn computeTag(
+    authKey: u128,
+    encNRounds: usize,
+    encKeySchedule: &[u32],
+    nonce: u128,
+    aad: &[u32],
+    aadSigBytes: usize,
+    plaintext: &[u32],
+    plaintextSigBytes: usize,
+) -> u128 {
+    let h = mulX(authKey.swap_bytes());
+    let mut s = polyvalAbsorb(h, 0, aad, aadSigBytes);
+    s = polyvalAbsorb(h, s, plaintext, plaintextSigBytes);
+    // LE64(AAD bits) || LE64(plaintext bits), byte-reversed like the other blocks
+    let lengths = ((plaintextSigBytes as u128 * 8) << 64) | (aadSigBytes as u128 * 8);
+    s = gfMul(s ^ lengths, h).swap_bytes();
+
+    // XOR in the nonce, clear the top bit of the last byte and encrypt
+    let s = (s ^ nonce) & !0x80;
+    encryptU128(encNRounds, encKeySchedule, s)
+}
+
+fn ctr(nRounds: usize, keySchedule: &[u32], tag: u128, dataWords: &mut [u32], dataSigBytes: usize) {
+    let mut counter = tag | 0x80;
+    for i in 0..(dataSigBytes + 15) / 16 {
+        let keystream = encryptU128(nRounds, keySchedule, counter);
+        let block = readBlock(dataWords, dataSigBytes, i) ^ keystream;
+        writeBlock(dataWords, dataSigBytes, i, block);
+
+        // The first 32 bits are a little-endian counter, wrapping modulo 2^32
+        let low = ((counter >> 96) as u32).swap_bytes().wrapping_add(1).swap_bytes();
+        counter = (counter & !(0xffffffff << 96)) | ((low as u128) << 96);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::hexToWords;
+
+    // The RFC lists the ciphertext and tag as one string
+    fn check(key: &str, plaintext: &str, aad: &str, result: &str) {
+        let (keyWords, keySigBytes) = hexToWords(key);
+        let keySize = keySigBytes as u32 / 4;
+        let (nonce, nonceSigBytes) = hexToWords("030000000000000000000000");
+        let (aad, aadSigBytes) = hexToWords(aad);
+        let (mut data, dataSigBytes) = hexToWords(plaintext);
+
+        let tag = gcmSivEncrypt(keySize, &keyWords, &nonce, nonceSigBytes, &aad, aadSigBytes, &mut data, dataSigBytes);
+        assert_eq!(data, hexToWords(&result[..plaintext.len()]).0);
+        assert_eq!(tag, hexToWords(&result[plaintext.len()..]).0);
+
+        assert!(gcmSivDecrypt(keySize, &keyWords, &nonce, nonceSigBytes, &aad, aadSigBytes, &mut data, dataSigBytes, &tag));
+        assert_eq!(data, hexToWords(plaintext).0);
+
+        let mut badTag = tag.clone();
+        badTag[3] ^= 1;
+        let ciphertext = hexToWords(&result[..plaintext.len()]).0;
+        let mut data = ciphertext.clone();
+        assert!(!gcmSivDecrypt(keySize, &keyWords, &nonce, nonceSigBytes, &aad, aadSigBytes, &mut data, dataSigBytes, &badTag));
+        assert_eq!(data, ciphertext);
+    }
+
+    // RFC 8452, appendix C.1
+    #[test]
+    fn aes_128_gcm_siv() {
+        let key = "01000000000000000000000000000000";
+        check(key, "", "", "dc20e2d83f25705bb49e439eca56de25");
+        check(key, "0100000000000000", "", "b5d839330ac7b786578782fff6013b815b287c22493a364c");
+        check(key, "0100000000000000000000000000000002000000000000000000000000000000", "01",
+              "9b12b6f5e3014e4620b29f37daf67dfe2b358c8157d8cb9e3f35381a256fd4dbb5e7a1a1c0529a16215d3c7ef4e1361c");
+        check(key, "0200000000000000", "010000000000000000000000", "a6bc84ed02cc9fae8568a1005e8a523d0d06b5679a5a4a9e");
+    }
+
+    // RFC 8452, appendix C.2
+    #[test]
+    fn aes_256_gcm_siv() {
+        let key = "0100000000000000000000000000000000000000000000000000000000000000";
+        check(key, "0100000000000000", "", "c2ef328e5c71c83b843122130f7364b761e0b97427e3df28");
+        check(key, "010000000000000000000000", "01", "e9abc6aafde487f8750e05588b12e960e3c59a481f49954b1e34a84b");
+    }
+}
@@ -0,0 +1,245 @@
+use wasm_bindgen::prelude::*;
+use crate::block::{bytesToU128, constantTimeEq, decryptU128, encryptU128, wordsToBytes};
+
+// Default initial value of RFC 3394, section 2.2.3.1
+const DEFAULT_IV: u64 = 0xa6a6a6a6a6a6a6a6;
+// Alternative initial value prefix of RFC 5649, section 3, followed by the 32-bit message length
+const AIV_PREFIX: u32 = 0xa65959a6;
+
+/// AES Key Wrap (RFC 3394), or Key Wrap with Padding (RFC 5649) when `withPadding` is set.
+///
+/// Wraps the first `keySigBytes` bytes of `keyWords` under the key-encryption key and
+/// returns the wrapped key, which is always a multiple of 8 bytes. Without padding the key
+/// must be a multiple of 8 bytes and at least 16 bytes long; with padding any non-empty
+/// key is accepted. Returns an empty vector when the key length is not valid.
+#[wasm_bindgen]
+pub fn wrapKey(nRounds: usize, keySchedule: &[u32], keyWords: &[u32], keySigBytes: usize, withPadding: bool) -> Vec<u32> {
+    let key = wordsToBytes(keyWords, keySigBytes);
+    let wrapped = if withPadding {
+        wrapWithPadding(nRounds, keySchedule, &key)
+    } else {
+        wrap(nRounds, keySchedule, &key)
+    };
+
+    match wrapped {
+        Some(wrapped) => wrapped.iter().flat_map(|half| vec![(half >> 32) as u32, *half as u32]).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// AES Key Unwrap (RFC 3394), or Key Unwrap with Padding (RFC 5649) when `withPadding` is set.
+///
+/// Takes the inverse key schedule of the key-encryption key. On success, returns the byte
+/// length of the unwrapped key followed by its words. Returns an empty vector when the
+/// wrapped key has an invalid length or its integrity check fails, so a corrupted or
+/// forged input never yields key material.
+#[wasm_bindgen]
+pub fn unwrapKey(nRounds: usize, invKeySchedule: &[u32], wrappedWords: &[u32], wrappedSigBytes: usize, withPadding: bool) -> Vec<u32> {
+    if wrappedSigBytes % 8 != 0 || wrappedWords.len() < wrappedSigBytes / 4 {
+        return Vec::new();
+    }
+
+    let wrapped: Vec<u64> = wrappedWords[..wrappedSigBytes / 4]
+        .chunks(2)
+        .map(|pair| ((pair[0] as u64) << 32) | pair[1] as u64)
+        .collect();
+    let key = if withPadding {
+        unwrapWithPadding(nRounds, invKeySchedule, &wrapped)
+    } else {
+        unwrap(nRounds, invKeySchedule, &wrapped)
+    };
+
+    match key {
+        Some(key) => {
+            let mut result = vec![key.len() as u32];
+            result.extend(key.chunks(4).map(|chunk| {
+                chunk.iter().enumerate().fold(0u32, |word, (i, byte)| word | (*byte as u32) << (24 - 8 * i))
+            }));
+            result
+        }
+        None => Vec::new(),
+    }
+}
+
+fn bytesToSemiblocks(bytes: &[u8]) -> Vec<u64> {
+    bytes.chunks(8).map(|chunk| (bytesToU128(chunk) >> 64) as u64).collect()
+}
+
+// W of RFC 3394, section 2.2.1, in its index-based form. Returns A || R[1] || ... || R[n].
+fn wrapSemiblocks(nRounds: usize, keySchedule: &[u32], iv: u64, plaintext: &[u64]) -> Vec<u64> {
+    let n = plaintext.len();
+    let mut a = iv;
+    let mut r = plaintext.to_vec();
+    for j in 0..6 {
+        for i in 0..n {
+            let b = encryptU128(nRounds, keySchedule, ((a as u128) << 64) | r[i] as u128);
+            a = (b >> 64) as u64 ^ (n * j + i + 1) as u64;
+            r[i] = b as u64;
+        }
+    }
+
+    let mut result = vec![a];
+    result.extend(r);
+    result
+}
+
+// W^-1 of RFC 3394, section 2.2.2. Returns the recovered A and R[1] ... R[n].
+fn unwrapSemiblocks(nRounds: usize, invKeySchedule: &[u32], ciphertext: &[u64]) -> (u64, Vec<u64>) {
+    let n = ciphertext.len() - 1;
+    let mut a = ciphertext[0];
+    let mut r = ciphertext[1..].to_vec();
+    for j in (0..6).rev() {
+        for i in (0..n).rev() {
+            let t = (n * j + i + 1) as u64;
+            let b = decryptU128(nRounds, invKeySchedule, (((a ^ t) as u128) << 64) | r[i] as u128);
+            a = (b >> 64) as u64;
+            r[i] = b as u64;
+        }
+    }
+
+    (a, r)
+}
+
+fn wrap(nRounds: usize, keySchedule: &[u32], key: &[u8]) -> Option<Vec<u64>> {
+    if key.len() < 16 || key.len() % 8 != 0 {
+        return None;
+    }
+
+    Some(wrapSemiblocks(nRounds, keySchedule, DEFAULT_IV, &bytesToSemiblocks(key)))
+}
+
+fn unwrap(nRounds: usize, invKeySchedule: &[u32], wrapped: &[u64]) -> Option<Vec<u8>> {
+    if wrapped.len() < 3 {
+        return None;
+    }
+
+    let (a, r) = unwrapSemiblocks(nRounds, invKeySchedule, wrapped);
+    if !constantTimeEq((a as u128) << 64, (DEFAULT_IV as u128) << 64, 8) {
+        return None;
+    }
+
+    Some(r.iter().flat_map(|half| half.to_be_bytes().to_vec()).collect())
+}
+
+fn wrapWithPadding(nRounds: usize, keySchedule: &[u32], key: &[u8]) -> Option<Vec<u64>> {
+    if key.is_empty() || key.len() as u64 > u32::MAX as u64 {
+        return None;
+    }
+
+    let aiv = ((AIV_PREFIX as u64) << 32) | key.len() as u64;
+    let plaintext = bytesToSemiblocks(key);
+
+    // A single padded semiblock is encrypted directly, as in RFC 5649, section 4.1
+    if plaintext.len() == 1 {
+        let c = encryptU128(nRounds, keySchedule, ((aiv as u128) << 64) | plaintext[0] as u128);
+        return Some(vec![(c >> 64) as u64, c as u64]);
+    }
+
+    Some(wrapSemiblocks(nRounds, keySchedule, aiv, &plaintext))
+}
+
+fn unwrapWithPadding(nRounds: usize, invKeySchedule: &[u32], wrapped: &[u64]) -> Option<Vec<u8>> {
+    let (a, r) = match wrapped.len() {
+        0 | 1 => return None,
+        2 => {
+            let b = decryptU128(nRounds, invKeySchedule, ((wrapped[0] as u128) << 64) | wrapped[1] as u128);
+            ((b >> 64) as u64, vec![b as u64])
+        }
+        _ => unwrapSemiblocks(nRounds, invKeySchedule, wrapped),
+    };
+
+    // Integrity checks of RFC 5649, section 3: the prefix, the message length and the zero padding
+    if !constantTimeEq((a as u128) << 64, (AIV_PREFIX as u128) << 96, 4) {
+        return None;
+    }
+    let mli = (a as u32) as usize;
+    let n = r.len();
+    if mli <= 8 * (n - 1) || mli > 8 * n {
+        return None;
+    }
+
+    let mut bytes: Vec<u8> = r.iter().flat_map(|half| half.to_be_bytes().to_vec()).collect();
+    if bytes[mli..].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    bytes.truncate(mli);
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{getInvKeySchedule, getKeySchedule};
+    use crate::test_utils::hexToWords;
+
+    fn check(kek: &str, key: &str, wrapped: &str, withPadding: bool) {
+        let (kekWords, kekSigBytes) = hexToWords(kek);
+        let keySize = kekSigBytes as u32 / 4;
+        let nRounds = keySize as usize + 6;
+        let keySchedule = getKeySchedule(keySize, &kekWords);
+        let invKeySchedule = getInvKeySchedule(keySize, &kekWords);
+        let (keyWords, keySigBytes) = hexToWords(key);
+
+        let computed = wrapKey(nRounds, &keySchedule, &keyWords, keySigBytes, withPadding);
+        assert_eq!(computed, hexToWords(wrapped).0);
+
+        let unwrapped = unwrapKey(nRounds, &invKeySchedule, &computed, computed.len() * 4, withPadding);
+        assert_eq!(unwrapped[0] as usize, keySigBytes);
+        assert_eq!(unwrapped[1..], keyWords[..]);
+
+        // Any change to the wrapped key must be detected
+        for i in 0..computed.len() {
+            let mut tampered = computed.clone();
+            tampered[i] ^= 0x100;
+            assert!(unwrapKey(nRounds, &invKeySchedule, &tampered, tampered.len() * 4, withPadding).is_empty());
+        }
+    }
+
+    // RFC 3394, section 4.1
+    #[test]
+    fn wrap_128_bit_key_with_128_bit_kek() {
+        check("000102030405060708090a0b0c0d0e0f", "00112233445566778899aabbccddeeff",
+              "1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5", false);
+    }
+
+    // RFC 3394, section 4.6
+    #[test]
+    fn wrap_256_bit_key_with_256_bit_kek() {
+        check("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+              "00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f",
+              "28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21", false);
+    }
+
+    // RFC 5649, section 6
+    #[test]
+    fn wrap_with_padding() {
+        let kek = "5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8";
+        check(kek, "c37b7e6492584340bed12207808941155068f738",
+              "138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a", true);
+        check(kek, "466f7250617369", "afbeb0f07dfbf5419200f2ccb50bb24f", true);
+    }
+
+    #[test]
+    fn rejects_invalid_lengths() {
+        let keySchedule = getKeySchedule(4, &[0; 4]);
+        let invKeySchedule = getInvKeySchedule(4, &[0; 4]);
+        assert!(wrapKey(10, &keySchedule, &[0; 2], 8, false).is_empty());
+        assert!(wrapKey(10, &keySchedule, &[0; 5], 20, false).is_empty());
+        assert!(wrapKey(10, &keySchedule, &[], 0, true).is_empty());
+        assert!(unwrapKey(10, &invKeySchedule, &[0; 4], 16, false).is_empty());
+        assert!(unwrapKey(10, &invKeySchedule, &[0; 5], 20, true).is_empty());
+        assert!(unwrapKey(10, &invKeySchedule, &[0; 2], 8, true).is_empty());
+    }
+
+    // Unwrapping without padding must not accept a key wrapped with padding, and vice versa
+    #[test]
+    fn rejects_mismatched_variant() {
+        let keySchedule = getKeySchedule(4, &[1; 4]);
+        let invKeySchedule = getInvKeySchedule(4, &[1; 4]);
+        let wrapped = wrapKey(10, &keySchedule, &[7; 4], 16, false);
+        assert!(unwrapKey(10, &invKeySchedule, &wrapped, 24, true).is_empty());
+        let wrapped = wrapKey(10, &keySchedule, &[7; 4], 16, true);
+        assert!(unwrapKey(10, &invKeySchedule, &wrapped, 24, false).is_empty());
+    }
+}
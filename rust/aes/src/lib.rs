@@ -1,5 +1,16 @@
 mod utils;
-
+mod block;
+mod gcm;
+mod ccm;
+mod cmac;
+mod siv;
+mod gcm_siv;
+mod xts;
+mod keywrap;
+#[cfg(test)]
+mod test_utils;
+
+use blockmode::BlockCipher;
 use wasm_bindgen::prelude::*;
 use crate::utils::{getSbox, getInvSbox, getSubMix0, getSubMix1, getSubMix2, getSubMix3, getInvSubMix0, getInvSubMix1, getInvSubMix2, getInvSubMix3, getKeySchedules, getInvKeySchedules};
 
@@ -20,135 +31,59 @@ pub fn getInvKeySchedule(keySize: u32, keyWords: &[u32]) -> Vec<u32> {
 }
 
 #[wasm_bindgen]
-pub fn doEncrypt(mode: &str, nRounds: usize, nWordsReady: usize, blockSize: usize, iv: &[u32], dataWords: &mut [u32],  keySchedule: &[u32]) -> Vec<u32> {
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    encryptBlock(nRounds, dataWords, offset, keySchedule);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    encryptBlock(nRounds, dataWords, offset, keySchedule);
-                    offset += blockSize;
-                }
-            }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let mut keystream = prevBlock;
-                    encryptBlock(nRounds, &mut keystream, 0, keySchedule);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    encryptBlock(nRounds, &mut keystream, 0, keySchedule);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    encryptBlock(nRounds, &mut keystream, 0, keySchedule);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
-        }
-    }
+pub fn doEncrypt(mode: &str, nRounds: usize, nWordsReady: usize, iv: &[u32], dataWords: &mut [u32],  keySchedule: &[u32]) -> Option<Vec<u32>> {
+    // Only the forward cipher is used when encrypting
+    let cipher = Aes { nRounds, keySchedule, invKeySchedule: &[] };
+    blockmode::doEncrypt(&cipher, mode, nWordsReady, iv, dataWords)
+}
 
-    process
+#[wasm_bindgen]
+pub fn doDecrypt(mode: &str, nRounds: usize, nWordsReady: usize, iv: &[u32], dataWords: &mut [u32], keySchedule: &[u32], invKeySchedule: &[u32]) -> Option<Vec<u32>> {
+    let cipher = Aes { nRounds, keySchedule, invKeySchedule };
+    blockmode::doDecrypt(&cipher, mode, nWordsReady, iv, dataWords)
 }
 
 #[wasm_bindgen]
-pub fn doDecrypt(mode: &str, nRounds: usize, nWordsReady: usize, blockSize: usize, iv: &[u32], dataWords: &mut [u32], keySchedule: &[u32], invKeySchedule: &[u32]) -> Vec<u32> {
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    decryptBlock(nRounds, dataWords, offset, invKeySchedule);
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    decryptBlock(nRounds, dataWords, offset, invKeySchedule);
-                    offset += blockSize;
-                }
-            }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    let keystream = &mut prevBlock;
-                    encryptBlock(nRounds, keystream, 0, keySchedule);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    encryptBlock(nRounds, &mut keystream, 0, keySchedule);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    encryptBlock(nRounds, &mut keystream, 0, keySchedule);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
-        }
-    }
+pub fn doEncryptCts(mode: &str, nRounds: usize, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, keySchedule: &[u32]) -> bool {
+    let cipher = Aes { nRounds, keySchedule, invKeySchedule: &[] };
+    blockmode::doEncryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+#[wasm_bindgen]
+pub fn doDecryptCts(mode: &str, nRounds: usize, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, keySchedule: &[u32], invKeySchedule: &[u32]) -> bool {
+    let cipher = Aes { nRounds, keySchedule, invKeySchedule };
+    blockmode::doDecryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+/// Encrypt or decrypt a slice of an AES-CTR stream that starts `byteOffset` bytes in, for
+/// example to serve a range request. The offset is given as big-endian words, most
+/// significant first. `mode` is "ctr" or "ctr-N" for an N-bit counter.
+#[wasm_bindgen]
+pub fn doCryptCtrAt(mode: &str, nRounds: usize, iv: &[u32], byteOffset: &[u32], dataWords: &mut [u32], dataSigBytes: usize, keySchedule: &[u32]) -> bool {
+    let start = byteOffset.len().saturating_sub(4);
+    let byteOffset = byteOffset[start..].iter().fold(0u128, |n, word| (n << 32) | *word as u128);
+
+    let cipher = Aes { nRounds, keySchedule, invKeySchedule: &[] };
+    blockmode::doCryptCtrAt(&cipher, mode, iv, byteOffset, dataWords, dataSigBytes)
+}
 
-    process
+struct Aes<'a> {
+    nRounds: usize,
+    keySchedule: &'a [u32],
+    invKeySchedule: &'a [u32],
 }
 
-fn xorBlock(blockSize: usize, block: Vec<u32>, words: &mut [u32], offset: usize) {
-    // XOR blocks
-    for i in 0..blockSize {
-        words[offset + i] ^= &block[i];
+impl BlockCipher for Aes<'_> {
+    fn blockSize(&self) -> usize {
+        4
+    }
+
+    fn encryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        encryptBlock(self.nRounds, dataWords, offset, self.keySchedule);
+    }
+
+    fn decryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        decryptBlock(self.nRounds, dataWords, offset, self.invKeySchedule);
     }
 }
 
@@ -227,3 +162,79 @@ fn doCryptBlock(nRounds: usize, dataWords: &mut [u32], offset: usize, keySchedul
     dataWords[offset + 2] = t2;
     dataWords[offset + 3] = t3;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::hexToWords;
+
+    // NIST SP 800-38A, appendix F, AES-128. The mode vectors themselves are in the blockmode crate.
+    const KEY: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+    // The counter carries across words and wraps like OpenSSL's AES-128-CTR
+    #[test]
+    fn ctr_carries_like_openssl() {
+        let keySchedule = getKeySchedule(4, &hexToWords(KEY).0);
+        let check = |iv: &str, keystream: &str| {
+            let (expected, sigBytes) = hexToWords(keystream);
+            let mut data = vec![0; sigBytes / 4];
+            doEncrypt("ctr", 10, data.len(), &hexToWords(iv).0, &mut data, &keySchedule);
+            assert_eq!(data, expected);
+        };
+
+        check("f0f1f2f3f4f5f6f7ffffffffffffffff",
+              "712e91130a0ec6d8ac7db29700e12699cffb109cd4f3b372e9ec67e8fd60db99c70c08478dbdc6e724db9575ed1fa10a");
+        check("ffffffffffffffffffffffffffffffff",
+              "8af2860142f786f409307c1a3f7eaaac7df76b0c1ab899b33e42f047b91b546f");
+    }
+
+    // A counter wider than the block used to pass the data through and panic on the next call
+    #[test]
+    fn rejects_counter_wider_than_block() {
+        let keySchedule = getKeySchedule(4, &hexToWords(KEY).0);
+        let (plain, _) = hexToWords(PLAINTEXT);
+        let mut data = plain.clone();
+        assert_eq!(doEncrypt("ctr-256", 10, data.len(), &[0; 4], &mut data, &keySchedule), None);
+        assert_eq!(doDecrypt("ctr-256", 10, data.len(), &[0; 4], &mut data, &keySchedule, &keySchedule), None);
+        assert_eq!(data, plain);
+    }
+
+    #[test]
+    fn ctr_seeks_into_sp800_38a_stream() {
+        let keySchedule = getKeySchedule(4, &hexToWords(KEY).0);
+        let iv = hexToWords("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff").0;
+        let ciphertext = "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff\
+                          5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee";
+
+        // Bytes 21 to 57: starts partway through the second block and ends inside the fourth
+        let (mut data, sigBytes) = hexToWords(&PLAINTEXT[42..114]);
+        assert!(doCryptCtrAt("ctr", 10, &iv, &[0, 21], &mut data, sigBytes, &keySchedule));
+        assert_eq!(data, hexToWords(&ciphertext[42..114]).0);
+    }
+
+    // RFC 3962, appendix B: Kerberos AES-CTS is CBC-CS3 with a zero IV
+    #[test]
+    fn cbc_cs3_matches_rfc3962() {
+        let keyWords = hexToWords("636869636b656e207465726979616b69").0;
+        let keySchedule = getKeySchedule(4, &keyWords);
+        let invKeySchedule = getInvKeySchedule(4, &keyWords);
+        let iv = [0; 4];
+        let check = |plaintext: &str, ciphertext: &str| {
+            let (mut data, sigBytes) = hexToWords(plaintext);
+            assert!(doEncryptCts("cbc-cs3", 10, &iv, &mut data, sigBytes, &keySchedule));
+            assert_eq!(data, hexToWords(ciphertext).0);
+            assert!(doDecryptCts("cbc-cs3", 10, &iv, &mut data, sigBytes, &keySchedule, &invKeySchedule));
+            assert_eq!(data, hexToWords(plaintext).0);
+        };
+
+        check("4920776f756c64206c696b652074686520", "c6353568f2bf8cb4d8a580362da7ff7f97");
+        check("4920776f756c64206c696b65207468652047656e6572616c20476175277320",
+              "fc00783e0efdb2c1d445d4c8eff7ed2297687268d6ecccc0c07b25e25ecfe5");
+        check("4920776f756c64206c696b65207468652047656e6572616c2047617527732043",
+              "39312523a78662d5be7fcbcc98ebf5a897687268d6ecccc0c07b25e25ecfe584");
+        check("4920776f756c64206c696b65207468652047656e6572616c20476175277320436869636b656e2c20706c656173652c20",
+              "97687268d6ecccc0c07b25e25ecfe5849dad8bbb96c4cdc03bc103e1a194bbd839312523a78662d5be7fcbcc98ebf5a8");
+    }
+}
@@ -0,0 +1,179 @@
+use wasm_bindgen::prelude::*;
+use crate::block::{bytesToU128, clampWords, constantTimeEq, encryptU128, readBlock, u128ToWords, wordsToBytes, writeBlock, wordsToU128};
+use crate::cmac::{cmac, dbl, padBlock};
+
+// RFC 5297 allows at most 126 associated data components besides the plaintext
+const MAX_AAD_COMPONENTS: usize = 126;
+
+/// Deterministic AES-SIV encryption (RFC 5297).
+///
+/// The key is split in two: `macKeySchedule` is expanded from its first half and used
+/// by S2V, `ctrKeySchedule` from its second half and used for CTR. The associated data
+/// vector is passed as the concatenation of its components, each starting on a word
+/// boundary, with their byte lengths in `aadSigBytes`. A nonce, if used, is simply one
+/// of these components.
+///
+/// Encrypts the first `dataSigBytes` bytes of `dataWords` in place and returns the
+/// synthetic IV, which has to be prepended to the ciphertext.
+#[wasm_bindgen]
+pub fn sivEncrypt(
+    nRounds: usize,
+    macKeySchedule: &[u32],
+    ctrKeySchedule: &[u32],
+    aadWords: &[u32],
+    aadSigBytes: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+) -> Vec<u32> {
+    if aadSigBytes.len() > MAX_AAD_COMPONENTS {
+        return Vec::new();
+    }
+
+    let components = splitComponents(aadWords, aadSigBytes);
+    clampWords(dataWords, dataSigBytes);
+    let v = s2v(nRounds, macKeySchedule, &components, &wordsToBytes(dataWords, dataSigBytes));
+    ctr(nRounds, ctrKeySchedule, v, dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+
+    u128ToWords(v).to_vec()
+}
+
+/// AES-SIV decryption (RFC 5297).
+///
+/// `v` is the synthetic IV that was prepended to the ciphertext. Returns `false` when
+/// it does not match the decrypted message, in which case `dataWords` is restored to
+/// the ciphertext.
+#[wasm_bindgen]
+pub fn sivDecrypt(
+    nRounds: usize,
+    macKeySchedule: &[u32],
+    ctrKeySchedule: &[u32],
+    aadWords: &[u32],
+    aadSigBytes: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    v: &[u32],
+) -> bool {
+    if aadSigBytes.len() > MAX_AAD_COMPONENTS || v.len() < 4 {
+        return false;
+    }
+
+    let components = splitComponents(aadWords, aadSigBytes);
+    let v = wordsToU128(v);
+    clampWords(dataWords, dataSigBytes);
+    let ciphertext = dataWords.to_vec();
+    ctr(nRounds, ctrKeySchedule, v, dataWords, dataSigBytes);
+    clampWords(dataWords, dataSigBytes);
+
+    let expected = s2v(nRounds, macKeySchedule, &components, &wordsToBytes(dataWords, dataSigBytes));
+    if !constantTimeEq(expected, v, 16) {
+        dataWords.copy_from_slice(&ciphertext);
+        return false;
+    }
+
+    true
+}
+
+// Cut the concatenated associated data back into its components
+fn splitComponents(aadWords: &[u32], aadSigBytes: &[u32]) -> Vec<Vec<u8>> {
+    let mut components = Vec::new();
+    let mut offset = 0;
+    for sigBytes in aadSigBytes {
+        let sigBytes = *sigBytes as usize;
+        let nWords = (sigBytes + 3) / 4;
+        components.push(wordsToBytes(&aadWords[offset..offset + nWords], sigBytes));
+        offset += nWords;
+    }
+
+    components
+}
+
+// S2V of RFC 5297, section 2.4, with the plaintext as the last string
+fn s2v(nRounds: usize, keySchedule: &[u32], components: &[Vec<u8>], plaintext: &[u8]) -> u128 {
+    let mut d = cmac(nRounds, keySchedule, &[0; 16]);
+    for component in components {
+        d = dbl(d) ^ cmac(nRounds, keySchedule, component);
+    }
+
+    if plaintext.len() >= 16 {
+        // xorend: XOR D into the last 16 bytes
+        let mut t = plaintext.to_vec();
+        let start = t.len() - 16;
+        let tail = bytesToU128(&t[start..]) ^ d;
+        t[start..].copy_from_slice(&tail.to_be_bytes());
+        cmac(nRounds, keySchedule, &t)
+    } else {
+        let t = dbl(d) ^ padBlock(plaintext);
+        cmac(nRounds, keySchedule, &t.to_be_bytes())
+    }
+}
+
+fn ctr(nRounds: usize, keySchedule: &[u32], v: u128, dataWords: &mut [u32], dataSigBytes: usize) {
+    // Bits 31 and 63 are cleared so that implementations can use 32 or 64 bit counters
+    let mut counter = v & !((1 << 63) | (1 << 31));
+    for i in 0..(dataSigBytes + 15) / 16 {
+        let keystream = encryptU128(nRounds, keySchedule, counter);
+        let block = readBlock(dataWords, dataSigBytes, i) ^ keystream;
+        writeBlock(dataWords, dataSigBytes, i, block);
+        counter = counter.wrapping_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::getKeySchedule;
+    use crate::test_utils::hexToWords;
+
+    fn check(key: &str, aad: &[&str], plaintext: &str, expected: &str) {
+        let (keyWords, keySigBytes) = hexToWords(key);
+        let half = keySigBytes / 8;
+        let nRounds = half + 6;
+        let macKeySchedule = getKeySchedule(half as u32, &keyWords[..half]);
+        let ctrKeySchedule = getKeySchedule(half as u32, &keyWords[half..]);
+
+        let mut aadWords = Vec::new();
+        let mut aadSigBytes = Vec::new();
+        for component in aad {
+            let (words, sigBytes) = hexToWords(component);
+            aadWords.extend(words);
+            aadSigBytes.push(sigBytes as u32);
+        }
+
+        let (mut data, dataSigBytes) = hexToWords(plaintext);
+        let v = sivEncrypt(nRounds, &macKeySchedule, &ctrKeySchedule, &aadWords, &aadSigBytes, &mut data, dataSigBytes);
+        let (expected, _) = hexToWords(expected);
+        assert_eq!(v, expected[..4]);
+        assert_eq!(data, expected[4..]);
+
+        assert!(sivDecrypt(nRounds, &macKeySchedule, &ctrKeySchedule, &aadWords, &aadSigBytes, &mut data, dataSigBytes, &v));
+        assert_eq!(data, hexToWords(plaintext).0);
+
+        // A different associated data vector must not authenticate
+        let ciphertext = expected[4..].to_vec();
+        let mut data = ciphertext.clone();
+        assert!(!sivDecrypt(nRounds, &macKeySchedule, &ctrKeySchedule, &aadWords, &aadSigBytes[1..], &mut data, dataSigBytes, &v));
+        assert_eq!(data, ciphertext);
+    }
+
+    // RFC 5297, appendix A.1
+    #[test]
+    fn deterministic_authenticated_encryption() {
+        check("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+              &["101112131415161718191a1b1c1d1e1f2021222324252627"],
+              "112233445566778899aabbccddee",
+              "85632d07c6e8f37f950acd320a2ecc9340c02b9690c4dc04daef7f6afe5c");
+    }
+
+    // RFC 5297, appendix A.2
+    #[test]
+    fn nonce_based_authenticated_encryption() {
+        check("7f7e7d7c7b7a79787776757473727170404142434445464748494a4b4c4d4e4f",
+              &["00112233445566778899aabbccddeeffdeaddadadeaddadaffeeddccbbaa99887766554433221100",
+                "102030405060708090a0",
+                "09f911029d74e35bd84156c5635688c0"],
+              "7468697320697320736f6d6520706c61696e7465787420746f20656e6372797074207573696e67205349562d414553",
+              "7bdb6e3b432667eb06f4d14bff2fbd0fcb900f2fddbe404326601965c889bf17\
+               dba77ceb094fa663b7a3f748ba8af829ea64ad544a272e9c485b62a3fd5c0d");
+    }
+}
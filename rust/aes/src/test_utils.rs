@@ -0,0 +1,18 @@
+// Parse a hex string into big-endian words plus the number of significant bytes,
+// the same layout `WordArray` hands over to wasm
+pub fn hexToWords(hex: &str) -> (Vec<u32>, usize) {
+    let bytes = hexToBytes(hex);
+    let mut words = vec![0u32; (bytes.len() + 3) / 4];
+    for (i, byte) in bytes.iter().enumerate() {
+        words[i / 4] |= (*byte as u32) << (24 - (i % 4) * 8);
+    }
+
+    (words, bytes.len())
+}
+
+pub fn hexToBytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
@@ -0,0 +1,217 @@
+use wasm_bindgen::prelude::*;
+use crate::block::{clampWords, decryptU128, encryptU128, readBlock, writeBlock};
+use crate::cmac::dbl;
+
+// IEEE 1619 limits a data unit to 2^20 blocks
+const MAX_DATA_UNIT_BLOCKS: usize = 1 << 20;
+
+/// XTS-AES encryption of one data unit (IEEE 1619).
+///
+/// `keySchedule` is expanded from the first half of the key and encrypts the data,
+/// `tweakKeySchedule` from the second half and encrypts the sector number. The sector
+/// number is given as big-endian words, most significant first, up to 128 bits.
+///
+/// Encrypts the first `dataSigBytes` bytes of `dataWords` in place. A data unit that is
+/// not a multiple of the block size is handled with ciphertext stealing, so the
+/// ciphertext has the same length as the plaintext. Returns `false` and leaves the data
+/// untouched when the data unit is shorter than one block or longer than 2^20 blocks.
+#[wasm_bindgen]
+pub fn xtsEncrypt(
+    nRounds: usize,
+    keySchedule: &[u32],
+    tweakKeySchedule: &[u32],
+    sectorNumber: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+) -> bool {
+    if !isValidLength(dataSigBytes) {
+        return false;
+    }
+
+    let mut tweak = getTweak(nRounds, tweakKeySchedule, sectorNumber);
+    let nFullBlocks = dataSigBytes / 16;
+    let remainder = dataSigBytes % 16;
+
+    clampWords(dataWords, dataSigBytes);
+    for i in 0..nFullBlocks {
+        let block = encryptWithTweak(nRounds, keySchedule, readBlock(dataWords, dataSigBytes, i), tweak);
+        writeBlock(dataWords, dataSigBytes, i, block);
+        tweak = mulAlpha(tweak);
+    }
+
+    // Ciphertext stealing: the partial block borrows the tail of the previous ciphertext block,
+    // which in turn is replaced by the encryption of the completed partial block
+    if remainder > 0 {
+        let last = nFullBlocks - 1;
+        let cc = readBlock(dataWords, dataSigBytes, last);
+        let mask = headMask(remainder);
+        let pp = (readBlock(dataWords, dataSigBytes, nFullBlocks) & mask) | (cc & !mask);
+        writeBlock(dataWords, dataSigBytes, nFullBlocks, cc);
+        writeBlock(dataWords, dataSigBytes, last, encryptWithTweak(nRounds, keySchedule, pp, tweak));
+    }
+    clampWords(dataWords, dataSigBytes);
+
+    true
+}
+
+/// XTS-AES decryption of one data unit (IEEE 1619).
+///
+/// Takes the inverse key schedule of the first half of the key and the (forward) key
+/// schedule of its second half, since the tweak is always encrypted.
+#[wasm_bindgen]
+pub fn xtsDecrypt(
+    nRounds: usize,
+    invKeySchedule: &[u32],
+    tweakKeySchedule: &[u32],
+    sectorNumber: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+) -> bool {
+    if !isValidLength(dataSigBytes) {
+        return false;
+    }
+
+    let mut tweak = getTweak(nRounds, tweakKeySchedule, sectorNumber);
+    let nFullBlocks = dataSigBytes / 16;
+    let remainder = dataSigBytes % 16;
+
+    // With stealing, the last full ciphertext block was made with the tweak of the partial block
+    let nRegularBlocks = if remainder > 0 { nFullBlocks - 1 } else { nFullBlocks };
+
+    clampWords(dataWords, dataSigBytes);
+    for i in 0..nRegularBlocks {
+        let block = decryptWithTweak(nRounds, invKeySchedule, readBlock(dataWords, dataSigBytes, i), tweak);
+        writeBlock(dataWords, dataSigBytes, i, block);
+        tweak = mulAlpha(tweak);
+    }
+
+    if remainder > 0 {
+        let nextTweak = mulAlpha(tweak);
+        let pp = decryptWithTweak(nRounds, invKeySchedule, readBlock(dataWords, dataSigBytes, nRegularBlocks), nextTweak);
+        let mask = headMask(remainder);
+        let cc = (readBlock(dataWords, dataSigBytes, nFullBlocks) & mask) | (pp & !mask);
+        writeBlock(dataWords, dataSigBytes, nFullBlocks, pp);
+        writeBlock(dataWords, dataSigBytes, nRegularBlocks, decryptWithTweak(nRounds, invKeySchedule, cc, tweak));
+    }
+    clampWords(dataWords, dataSigBytes);
+
+    true
+}
+
+fn isValidLength(dataSigBytes: usize) -> bool {
+    dataSigBytes >= 16 && dataSigBytes <= MAX_DATA_UNIT_BLOCKS * 16
+}
+
+// The sector number is encoded as a 128-bit little-endian integer before encryption
+fn getTweak(nRounds: usize, tweakKeySchedule: &[u32], sectorNumber: &[u32]) -> u128 {
+    let start = sectorNumber.len().saturating_sub(4);
+    let sector = sectorNumber[start..].iter().fold(0u128, |n, word| (n << 32) | *word as u128);
+
+    encryptU128(nRounds, tweakKeySchedule, sector.swap_bytes())
+}
+
+// Multiplication by the primitive element α. The tweak is a little-endian integer, so
+// swap to the integer order used by `dbl` and back.
+fn mulAlpha(tweak: u128) -> u128 {
+    dbl(tweak.swap_bytes()).swap_bytes()
+}
+
+// Mask selecting the first `nBytes` bytes of a block
+fn headMask(nBytes: usize) -> u128 {
+    !(u128::MAX >> (nBytes * 8))
+}
+
+fn encryptWithTweak(nRounds: usize, keySchedule: &[u32], block: u128, tweak: u128) -> u128 {
+    encryptU128(nRounds, keySchedule, block ^ tweak) ^ tweak
+}
+
+fn decryptWithTweak(nRounds: usize, invKeySchedule: &[u32], block: u128, tweak: u128) -> u128 {
+    decryptU128(nRounds, invKeySchedule, block ^ tweak) ^ tweak
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{getInvKeySchedule, getKeySchedule};
+    use crate::test_utils::hexToWords;
+
+    fn crypt(key1: &str, key2: &str, sectorNumber: &[u32], plaintext: &[u32], plaintextSigBytes: usize) -> Vec<u32> {
+        let (key1, keySigBytes) = hexToWords(key1);
+        let (key2, _) = hexToWords(key2);
+        let keySize = keySigBytes as u32 / 4;
+        let nRounds = keySize as usize + 6;
+        let keySchedule = getKeySchedule(keySize, &key1);
+        let invKeySchedule = getInvKeySchedule(keySize, &key1);
+        let tweakKeySchedule = getKeySchedule(keySize, &key2);
+
+        let mut data = plaintext.to_vec();
+        assert!(xtsEncrypt(nRounds, &keySchedule, &tweakKeySchedule, sectorNumber, &mut data, plaintextSigBytes));
+        let ciphertext = data.clone();
+
+        assert!(xtsDecrypt(nRounds, &invKeySchedule, &tweakKeySchedule, sectorNumber, &mut data, plaintextSigBytes));
+        assert_eq!(data, plaintext);
+
+        ciphertext
+    }
+
+    // 0x00, 0x01, ..., 0xff repeated to fill a 512-byte sector
+    fn sector512() -> Vec<u32> {
+        let bytes: Vec<u8> = (0..512).map(|i| i as u8).collect();
+        bytes.chunks(4).map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])).collect()
+    }
+
+    // IEEE 1619, vectors 1 and 2
+    #[test]
+    fn xts_aes_128_single_blocks() {
+        let zero = "00000000000000000000000000000000";
+        let ciphertext = crypt(zero, zero, &[0], &[0; 8], 32);
+        assert_eq!(ciphertext, hexToWords("917cf69ebd68b2ec9b9fe9a3eadda692cd43d2f59598ed858c02c2652fbf922e").0);
+
+        let ciphertext = crypt("11111111111111111111111111111111", "22222222222222222222222222222222",
+                               &[0x33, 0x33333333], &[0x44444444; 8], 32);
+        assert_eq!(ciphertext, hexToWords("c454185e6a16936e39334038acef838bfb186fff7480adc4289382ecd6d394f0").0);
+    }
+
+    // IEEE 1619, vector 4, first and last 32 bytes of the sector
+    #[test]
+    fn xts_aes_128_sector() {
+        let ciphertext = crypt("27182818284590452353602874713526", "31415926535897932384626433832795",
+                               &[0], &sector512(), 512);
+        assert_eq!(ciphertext[..8], hexToWords("27a7479befa1d476489f308cd4cfa6e2a96e4bbe3208ff25287dd3819616e89c").0[..]);
+        assert_eq!(ciphertext[120..], hexToWords("eb4a427d1923ce3ff262735779a418f20a282df920147beabe421ee5319d0568").0[..]);
+    }
+
+    // IEEE 1619, vector 10, first and last 32 bytes of the sector
+    #[test]
+    fn xts_aes_256_sector() {
+        let ciphertext = crypt("2718281828459045235360287471352662497757247093699959574966967627",
+                               "3141592653589793238462643383279502884197169399375105820974944592",
+                               &[0xff], &sector512(), 512);
+        assert_eq!(ciphertext[..8], hexToWords("1c3b3a102f770386e4836c99e370cf9bea00803f5e482357a4ae12d414a3e63b").0[..]);
+        assert_eq!(ciphertext[120..], hexToWords("773dad38014bd2092fa755c824bb5e54c4f36ffda9fcea70b9c6e693e148c151").0[..]);
+    }
+
+    // Data units of 17 to 20 bytes, checked against OpenSSL's XTS-AES-128
+    #[test]
+    fn ciphertext_stealing() {
+        let key1 = "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0";
+        let key2 = "bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0";
+        let check = |plaintext: &str, expected: &str| {
+            let (plaintext, sigBytes) = hexToWords(plaintext);
+            assert_eq!(crypt(key1, key2, &[0x9a, 0x78563412], &plaintext, sigBytes), hexToWords(expected).0);
+        };
+
+        check("000102030405060708090a0b0c0d0e0f10", "641610679dcbf92e505c41333fb06c2a95");
+        check("000102030405060708090a0b0c0d0e0f1011", "223a725cbcd4dc647b9a9826d54c99c895c8");
+        check("000102030405060708090a0b0c0d0e0f101112", "0d39809a65c1d55501960b671d4b8b6b95c871");
+        check("000102030405060708090a0b0c0d0e0f10111213", "a8ba0048d75084603eb8423a09b7bf7595c871f6");
+    }
+
+    #[test]
+    fn rejects_short_data_unit() {
+        let keySchedule = getKeySchedule(4, &[0; 4]);
+        let mut data = vec![0x01020304; 4];
+        assert!(!xtsEncrypt(10, &keySchedule, &keySchedule, &[0], &mut data, 15));
+        assert_eq!(data, vec![0x01020304; 4]);
+    }
+}
@@ -0,0 +1,362 @@
+use wasm_bindgen::prelude::*;
+
+pub const ARGON2D: u32 = 0;
+pub const ARGON2I: u32 = 1;
+pub const ARGON2ID: u32 = 2;
+
+const VERSION: u32 = 0x13;
+const SYNC_POINTS: usize = 4;
+const BLOCK_WORDS: usize = 128;
+
+type Block = [u64; BLOCK_WORDS];
+
+// The shape of the memory and the parameters that go into the address blocks
+struct Instance {
+    argonType: u32,
+    passes: u32,
+    lanes: usize,
+    laneLength: usize,
+    segmentLength: usize,
+}
+
+/// Argon2 (RFC 9106) version 0x13: `argonType` is `ARGON2D`, `ARGON2I` or `ARGON2ID`, `memorySize` is in KiB
+/// and is rounded down to a multiple of `4 * parallelism`. The lanes are filled one after the other.
+/// Returns the `tagLength` bytes of tag, or an empty vector if a parameter is out of range.
+#[wasm_bindgen]
+pub fn argon2(
+    argonType: u32,
+    password: &[u8],
+    salt: &[u8],
+    secret: &[u8],
+    associatedData: &[u8],
+    iterations: u32,
+    memorySize: u32,
+    parallelism: u32,
+    tagLength: u32,
+) -> Vec<u32> {
+    if !checkParameters(argonType, salt.len(), iterations, memorySize, parallelism, tagLength) {
+        return Vec::new();
+    }
+    let lanes = parallelism as usize;
+    let segmentLength = memorySize as usize / (SYNC_POINTS * lanes);
+    let instance = Instance { argonType, passes: iterations, lanes, laneLength: segmentLength * SYNC_POINTS, segmentLength };
+    let laneLength = instance.laneLength;
+
+    // H0, section 3.2
+    let mut input: Vec<u8> = Vec::new();
+    for value in [parallelism, tagLength, memorySize, iterations, VERSION, argonType].iter() {
+        input.extend_from_slice(&value.to_le_bytes());
+    }
+    for field in [password, salt, secret, associatedData].iter() {
+        input.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        input.extend_from_slice(field);
+    }
+    let h0 = blake2bDigest(&input, 64);
+
+    // The first two blocks of every lane come from H0
+    let mut memory: Vec<Block> = vec![[0; BLOCK_WORDS]; laneLength * lanes];
+    for lane in 0..lanes {
+        for i in 0..2 {
+            let mut seed = h0.clone();
+            seed.extend_from_slice(&(i as u32).to_le_bytes());
+            seed.extend_from_slice(&(lane as u32).to_le_bytes());
+            memory[lane * laneLength + i] = bytesToBlock(&variableHash(&seed, 1024));
+        }
+    }
+
+    for pass in 0..iterations {
+        for slice in 0..SYNC_POINTS {
+            for lane in 0..lanes {
+                fillSegment(&mut memory, &instance, pass, slice, lane);
+            }
+        }
+    }
+
+    // XOR the last block of every lane
+    let mut last = memory[laneLength - 1];
+    for lane in 1..lanes {
+        xorInto(&mut last, &memory[lane * laneLength + laneLength - 1]);
+    }
+
+    bytesToWords(&variableHash(&blockToBytes(&last), tagLength as usize))
+}
+
+// Section 3.1: at least 8 bytes of salt, one pass, 8 KiB per lane, 2^24 - 1 lanes and 4 bytes of tag
+fn checkParameters(argonType: u32, saltLength: usize, iterations: u32, memorySize: u32, parallelism: u32, tagLength: u32) -> bool {
+    if argonType > ARGON2ID || saltLength < 8 || iterations < 1 || tagLength < 4 {
+        return false;
+    }
+    if parallelism < 1 || parallelism > 0xffffff || (memorySize as u64) < 8 * parallelism as u64 {
+        return false;
+    }
+
+    // The whole memory has to be addressable
+    memorySize as u64 * 1024 <= isize::MAX as u64
+}
+
+// Section 3.4: one segment of one lane, block by block
+fn fillSegment(memory: &mut [Block], instance: &Instance, pass: u32, slice: usize, lane: usize) {
+    let laneLength = instance.laneLength;
+    let segmentLength = instance.segmentLength;
+    let dataIndependent =
+        instance.argonType == ARGON2I || (instance.argonType == ARGON2ID && pass == 0 && slice < SYNC_POINTS / 2);
+
+    let mut input: Block = [0; BLOCK_WORDS];
+    let mut addresses: Block = [0; BLOCK_WORDS];
+    if dataIndependent {
+        input[0] = pass as u64;
+        input[1] = lane as u64;
+        input[2] = slice as u64;
+        input[3] = memory.len() as u64;
+        input[4] = instance.passes as u64;
+        input[5] = instance.argonType as u64;
+    }
+
+    // The first two blocks of a lane are already there
+    let mut startingIndex = 0;
+    if pass == 0 && slice == 0 {
+        startingIndex = 2;
+        if dataIndependent {
+            nextAddresses(&mut input, &mut addresses);
+        }
+    }
+
+    for index in startingIndex..segmentLength {
+        let curr = lane * laneLength + slice * segmentLength + index;
+        let prev = if curr % laneLength == 0 { curr + laneLength - 1 } else { curr - 1 };
+
+        let pseudoRandom = if dataIndependent {
+            if index % BLOCK_WORDS == 0 {
+                nextAddresses(&mut input, &mut addresses);
+            }
+            addresses[index % BLOCK_WORDS]
+        } else {
+            memory[prev][0]
+        };
+
+        // The first slice of the first pass can only reference its own lane
+        let refLane = if pass == 0 && slice == 0 { lane } else { (pseudoRandom >> 32) as usize % instance.lanes };
+        let refIndex = referenceIndex(instance, pass, slice, index, pseudoRandom as u32, refLane == lane);
+
+        let block = compress(&memory[prev], &memory[refLane * laneLength + refIndex]);
+        if pass == 0 {
+            memory[curr] = block;
+        } else {
+            xorInto(&mut memory[curr], &block);
+        }
+    }
+}
+
+// Section 3.4.1.3: map J1 onto the blocks that are already computed and not in a segment being filled
+fn referenceIndex(instance: &Instance, pass: u32, slice: usize, index: usize, J1: u32, sameLane: bool) -> usize {
+    let laneLength = instance.laneLength;
+    let segmentLength = instance.segmentLength;
+
+    let areaSize = if pass == 0 {
+        if slice == 0 || sameLane {
+            slice * segmentLength + index - 1
+        } else if index == 0 {
+            slice * segmentLength - 1
+        } else {
+            slice * segmentLength
+        }
+    } else if sameLane {
+        laneLength - segmentLength + index - 1
+    } else if index == 0 {
+        laneLength - segmentLength - 1
+    } else {
+        laneLength - segmentLength
+    };
+
+    let x = (J1 as u64 * J1 as u64) >> 32;
+    let y = (areaSize as u64 * x) >> 32;
+    let relativePosition = areaSize as u64 - 1 - y;
+
+    // In later passes the area starts right after the current segment
+    let startPosition = if pass == 0 || slice == SYNC_POINTS - 1 { 0 } else { (slice + 1) * segmentLength };
+    ((startPosition as u64 + relativePosition) % laneLength as u64) as usize
+}
+
+// The next block of pseudo-random addresses for data-independent addressing: G(0, G(0, input))
+fn nextAddresses(input: &mut Block, addresses: &mut Block) {
+    let zero: Block = [0; BLOCK_WORDS];
+    input[6] += 1;
+    *addresses = compress(&zero, &compress(&zero, input));
+}
+
+// Section 3.5: the compression function G
+fn compress(X: &Block, Y: &Block) -> Block {
+    let mut R: Block = [0; BLOCK_WORDS];
+    for i in 0..BLOCK_WORDS {
+        R[i] = X[i] ^ Y[i];
+    }
+
+    // P on the rows of 16 words, then on the columns of two words in each row
+    let mut Q = R;
+    for row in 0..8 {
+        let mut indexes = [0; 16];
+        for k in 0..16 {
+            indexes[k] = row * 16 + k;
+        }
+        permute(&mut Q, &indexes);
+    }
+    for column in 0..8 {
+        let mut indexes = [0; 16];
+        for k in 0..8 {
+            indexes[2 * k] = k * 16 + column * 2;
+            indexes[2 * k + 1] = k * 16 + column * 2 + 1;
+        }
+        permute(&mut Q, &indexes);
+    }
+
+    xorInto(&mut Q, &R);
+    Q
+}
+
+fn permute(block: &mut Block, indexes: &[usize; 16]) {
+    let mut v = [0_u64; 16];
+    for k in 0..16 {
+        v[k] = block[indexes[k]];
+    }
+
+    mix(&mut v, 0, 4, 8, 12);
+    mix(&mut v, 1, 5, 9, 13);
+    mix(&mut v, 2, 6, 10, 14);
+    mix(&mut v, 3, 7, 11, 15);
+    mix(&mut v, 0, 5, 10, 15);
+    mix(&mut v, 1, 6, 11, 12);
+    mix(&mut v, 2, 7, 8, 13);
+    mix(&mut v, 3, 4, 9, 14);
+
+    for k in 0..16 {
+        block[indexes[k]] = v[k];
+    }
+}
+
+// The BLAKE2b G function with the additions replaced by BlaMka
+fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize) {
+    v[a] = blaMka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = blaMka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = blaMka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = blaMka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+fn blaMka(x: u64, y: u64) -> u64 {
+    let product = (x & 0xffffffff) * (y & 0xffffffff);
+    x.wrapping_add(y).wrapping_add(product.wrapping_mul(2))
+}
+
+fn xorInto(block: &mut Block, other: &Block) {
+    for i in 0..BLOCK_WORDS {
+        block[i] ^= other[i];
+    }
+}
+
+// Section 3.3: H', chaining 64-byte BLAKE2b digests for outputs longer than 64 bytes
+fn variableHash(message: &[u8], outputSize: usize) -> Vec<u8> {
+    let mut input = (outputSize as u32).to_le_bytes().to_vec();
+    input.extend_from_slice(message);
+    if outputSize <= 64 {
+        return blake2bDigest(&input, outputSize);
+    }
+
+    let mut output: Vec<u8> = Vec::with_capacity(outputSize);
+    let mut v = blake2bDigest(&input, 64);
+    output.extend_from_slice(&v[..32]);
+    while outputSize - output.len() > 64 {
+        v = blake2bDigest(&v, 64);
+        output.extend_from_slice(&v[..32]);
+    }
+    let rest = outputSize - output.len();
+    output.extend_from_slice(&blake2bDigest(&v, rest));
+
+    output
+}
+
+fn blake2bDigest(message: &[u8], outputSize: usize) -> Vec<u8> {
+    let mut state = blake2b::getInitialState(outputSize as u32, 0, &[], 0, &[], 0);
+    let digest = blake2b::doFinalize(&bytesToWords(message), message.len() as u32, &mut state, outputSize as u32);
+
+    wordsToBytes(&digest, outputSize)
+}
+
+fn bytesToBlock(bytes: &[u8]) -> Block {
+    let mut block: Block = [0; BLOCK_WORDS];
+    for (i, chunk) in bytes.chunks(8).enumerate() {
+        let mut le: [u8; 8] = [0; 8];
+        le.copy_from_slice(chunk);
+        block[i] = u64::from_le_bytes(le);
+    }
+    block
+}
+
+fn blockToBytes(block: &Block) -> Vec<u8> {
+    block.iter().flat_map(|w| w.to_le_bytes().to_vec()).collect()
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: usize) -> Vec<u8> {
+    (0..sigBytes).map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8).collect()
+}
+
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(words: Vec<u32>, nBytes: usize) -> String {
+        wordsToBytes(&words, nBytes).iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    // Section 5: 32 bytes of 0x01 as password, 16 of 0x02 as salt, 8 of 0x03 as secret and 12 of 0x04
+    // as associated data, 3 passes over 32 KiB in 4 lanes
+    fn rfcTag(argonType: u32) -> String {
+        hex(argon2(argonType, &[1; 32], &[2; 16], &[3; 8], &[4; 12], 3, 32, 4, 32), 32)
+    }
+
+    fn tag(argonType: u32, iterations: u32, memorySize: u32, parallelism: u32, tagLength: u32) -> String {
+        let words = argon2(argonType, b"password", b"somesalt", &[], &[], iterations, memorySize, parallelism, tagLength);
+        hex(words, tagLength as usize)
+    }
+
+    #[test]
+    fn argon2_matches_rfc_9106() {
+        assert_eq!(rfcTag(ARGON2D), "512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb");
+        assert_eq!(rfcTag(ARGON2I), "c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8");
+        assert_eq!(rfcTag(ARGON2ID), "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659");
+    }
+
+    #[test]
+    fn argon2_matches_the_reference_implementation() {
+        // A tag longer than one BLAKE2b digest
+        assert_eq!(
+            tag(ARGON2ID, 2, 64, 1, 100),
+            "7712f6cfaea89a90b11559e10e234f92f892db147d4c3b6e628a51836a20dcd07537028d562157088d11c966eced97430f\
+             53e747196cd7d99ddfb21b159e05ae131bd627e4a4b3452d5800c3351986221ec89db7698fcf4f91a1f5f4b73ef5e692c2fbc1"
+        );
+        // 37 KiB in 2 lanes is rounded down to 32 KiB
+        assert_eq!(tag(ARGON2I, 1, 37, 2, 16), "6ae4265baa979a7e0bee6ed043222d18");
+        assert_eq!(tag(ARGON2D, 2, 256, 3, 32), "5b5e59351fb4071585856858c1a6d9570ce3ea621891d5270ae14632e2024246");
+        // More than one block of addresses per segment
+        assert_eq!(tag(ARGON2ID, 3, 4096, 1, 32), "a8b9a5e5c6ea1403ba63154786b4811cfd1459dc6b23190d70cf1a317ddb9735");
+    }
+
+    #[test]
+    fn rejects_out_of_range_parameters() {
+        assert!(argon2(3, b"password", b"somesalt", &[], &[], 1, 64, 1, 32).is_empty());
+        assert!(argon2(ARGON2ID, b"password", b"short", &[], &[], 1, 64, 1, 32).is_empty());
+        assert!(argon2(ARGON2ID, b"password", b"somesalt", &[], &[], 0, 64, 1, 32).is_empty());
+        assert!(argon2(ARGON2ID, b"password", b"somesalt", &[], &[], 1, 15, 2, 32).is_empty());
+        assert!(argon2(ARGON2ID, b"password", b"somesalt", &[], &[], 1, 64, 0, 32).is_empty());
+        assert!(argon2(ARGON2ID, b"password", b"somesalt", &[], &[], 1, 64, 1, 3).is_empty());
+    }
+}
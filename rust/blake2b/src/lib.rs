@@ -0,0 +1,326 @@
+mod utils;
+
+use std::cmp;
+use hashcontext::ContextFormat;
+use wasm_bindgen::prelude::*;
+use utils::*;
+
+// The state passed between calls: the chaining value as 8 pairs of (high, low) words,
+// followed by the byte counter as a (high, low) pair
+const STATE_WORDS: usize = 18;
+const BLOCK_BYTES: usize = 128;
+
+/// Initial state for a digest of `outputSize` bytes (1 to 64). A key of `keySize` bytes (up to 64)
+/// is not part of the state; the caller buffers it, zero-padded to a full block, ahead of the message.
+/// Salt and personalization are at most 16 bytes and are zero-padded.
+/// Returns an empty vector if any parameter is out of range.
+#[wasm_bindgen]
+pub fn getInitialState(
+    outputSize: u32,
+    keySize: u32,
+    saltWords: &[u32],
+    saltSigBytes: u32,
+    personalWords: &[u32],
+    personalSigBytes: u32,
+) -> Vec<u32> {
+    if outputSize < 1 || outputSize > 64 || keySize > 64 || saltSigBytes > 16 || personalSigBytes > 16 {
+        return Vec::new();
+    }
+
+    // Parameter block: digest length, key length, fanout 1, depth 1, then zeros up to salt and personalization
+    let mut param: [u8; 64] = [0; 64];
+    param[0] = outputSize as u8;
+    param[1] = keySize as u8;
+    param[2] = 1;
+    param[3] = 1;
+    param[32..32 + saltSigBytes as usize].copy_from_slice(&wordsToBytes(saltWords, saltSigBytes));
+    param[48..48 + personalSigBytes as usize].copy_from_slice(&wordsToBytes(personalWords, personalSigBytes));
+
+    let IV = getIV();
+    let mut h: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        h[i] = IV[i] ^ readU64(&param, i * 8);
+    }
+
+    let mut state: Vec<u32> = vec![0; STATE_WORDS];
+    storeState(&h, 0, &mut state);
+    state
+}
+
+/// Compresses the complete blocks that are ready, like `sha256::doCrypt`.
+/// The last buffered block is always held back, because `doFinalize` has to compress it with the final flag.
+#[wasm_bindgen]
+pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32, state: &mut [u32], minBufferSize: u32) -> u32 {
+    let blockSizeBytes = blockSize * 4;
+    let mut nBlocksReady = if doFlush > 0 {
+        (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes
+    } else {
+        (dataSigBytes / blockSizeBytes).saturating_sub(minBufferSize)
+    };
+    nBlocksReady = cmp::min(nBlocksReady, dataSigBytes.saturating_sub(1) / blockSizeBytes);
+
+    let (mut h, mut t) = loadState(state);
+    for i in 0..nBlocksReady as usize {
+        let block = readBlock(dataWords, i * BLOCK_BYTES, BLOCK_BYTES);
+        t = t.wrapping_add(BLOCK_BYTES as u64);
+        compress(&mut h, &block, t, false);
+    }
+    storeState(&h, t, state);
+
+    nBlocksReady * blockSize
+}
+
+/// Compresses the rest of the buffer, the last block with the final flag, and returns
+/// the first `outputSize` bytes of the chaining value.
+#[wasm_bindgen]
+pub fn doFinalize(dataWords: &[u32], dataSigBytes: u32, state: &mut [u32], outputSize: u32) -> Vec<u32> {
+    let sigBytes = dataSigBytes as usize;
+    let (mut h, mut t) = loadState(state);
+
+    // An empty message still compresses one all-zero block
+    let nBlocks = cmp::max((sigBytes + BLOCK_BYTES - 1) / BLOCK_BYTES, 1);
+    for i in 0..nBlocks {
+        let start = i * BLOCK_BYTES;
+        let length = cmp::min(BLOCK_BYTES, sigBytes - cmp::min(start, sigBytes));
+        let block = readBlock(dataWords, start, length);
+        t = t.wrapping_add(length as u64);
+        compress(&mut h, &block, t, i == nBlocks - 1);
+    }
+    storeState(&h, t, state);
+
+    let mut digest: Vec<u8> = Vec::with_capacity(64);
+    for word in h.iter() {
+        digest.extend_from_slice(&word.to_le_bytes());
+    }
+    digest.truncate(outputSize as usize);
+
+    bytesToWords(&digest)
+}
+
+// RFC 7693, section 3.2
+fn compress(h: &mut [u64; 8], block: &[u64; 16], t: u64, last: bool) {
+    let IV = getIV();
+    let SIGMA = getSigma();
+
+    let mut v: [u64; 16] = [0; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    // The counter is 128 bits wide, but a JS caller never gets past the low 64
+    v[12] ^= t;
+    if last {
+        v[14] = !v[14];
+    }
+
+    for round in 0..12 {
+        let s = &SIGMA[round % 10];
+        mix(&mut v, 0, 4, 8, 12, block[s[0]], block[s[1]]);
+        mix(&mut v, 1, 5, 9, 13, block[s[2]], block[s[3]]);
+        mix(&mut v, 2, 6, 10, 14, block[s[4]], block[s[5]]);
+        mix(&mut v, 3, 7, 11, 15, block[s[6]], block[s[7]]);
+        mix(&mut v, 0, 5, 10, 15, block[s[8]], block[s[9]]);
+        mix(&mut v, 1, 6, 11, 12, block[s[10]], block[s[11]]);
+        mix(&mut v, 2, 7, 8, 13, block[s[12]], block[s[13]]);
+        mix(&mut v, 3, 4, 9, 14, block[s[14]], block[s[15]]);
+    }
+
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+// Read `length` bytes starting at byte `start` as little-endian message words, zero-filling the rest
+fn readBlock(dataWords: &[u32], start: usize, length: usize) -> [u64; 16] {
+    let mut bytes: [u8; BLOCK_BYTES] = [0; BLOCK_BYTES];
+    for i in 0..length {
+        let pos = start + i;
+        bytes[i] = (*dataWords.get(pos / 4).unwrap_or(&0) >> (24 - (pos % 4) * 8)) as u8;
+    }
+
+    let mut block: [u64; 16] = [0; 16];
+    for i in 0..16 {
+        block[i] = readU64(&bytes, i * 8);
+    }
+    block
+}
+
+fn readU64(bytes: &[u8], offset: usize) -> u64 {
+    let mut le: [u8; 8] = [0; 8];
+    le.copy_from_slice(&bytes[offset..offset + 8]);
+    u64::from_le_bytes(le)
+}
+
+fn loadState(state: &[u32]) -> ([u64; 8], u64) {
+    let mut h: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        h[i] = (state[i * 2] as u64) << 32 | state[i * 2 + 1] as u64;
+    }
+    let t = (state[16] as u64) << 32 | state[17] as u64;
+
+    (h, t)
+}
+
+fn storeState(h: &[u64; 8], t: u64, state: &mut [u32]) {
+    for i in 0..8 {
+        state[i * 2] = (h[i] >> 32) as u32;
+        state[i * 2 + 1] = h[i] as u32;
+    }
+    state[16] = (t >> 32) as u32;
+    state[17] = t as u32;
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::BLAKE2B], stateWords: STATE_WORDS };
+
+/// Serializes a hash in progress: its configuration `parameters`, `state` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    state: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, state, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hash as the JS hasher does: the key block first, streamed in `chunk` byte updates
+    fn digest(message: &[u8], outputSize: u32, key: &[u8], salt: &[u8], personal: &[u8], chunk: usize) -> String {
+        let mut state = getInitialState(
+            outputSize,
+            key.len() as u32,
+            &bytesToWords(salt),
+            salt.len() as u32,
+            &bytesToWords(personal),
+            personal.len() as u32,
+        );
+        let mut buffer: Vec<u8> = Vec::new();
+        if !key.is_empty() {
+            buffer.extend_from_slice(key);
+            buffer.resize(BLOCK_BYTES, 0);
+        }
+        for part in message.chunks(chunk) {
+            buffer.extend_from_slice(part);
+            let nWordsReady = doCrypt(0, &bytesToWords(&buffer), buffer.len() as u32, 32, &mut state, 1);
+            buffer.drain(..cmp::min(nWordsReady as usize * 4, buffer.len()));
+        }
+        let words = doFinalize(&bytesToWords(&buffer), buffer.len() as u32, &mut state, outputSize);
+
+        (0..outputSize as usize).map(|i| format!("{:02x}", (words[i / 4] >> (24 - (i % 4) * 8)) as u8)).collect()
+    }
+
+    #[test]
+    fn matches_rfc_7693() {
+        assert_eq!(
+            digest(b"abc", 64, b"", b"", b"", 3),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+        assert_eq!(
+            digest(b"", 64, b"", b"", b"", 1),
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419\
+             d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"
+        );
+    }
+
+    #[test]
+    fn block_boundaries_match_one_shot() {
+        let message: Vec<u8> = (0..=255).collect();
+        let expected = "1ecc896f34d3f9cac484c73f75f6a5fb58ee6784be41b35f46067b9c65c63a67\
+                        94d3d744112c653f73dd7deb6666204c5a9bfa5b46081fc10fdbe7884fa5cbf8";
+        for chunk in [1, 64, 127, 128, 129, 256].iter() {
+            assert_eq!(digest(&message, 64, b"", b"", b"", *chunk), expected, "chunk {}", chunk);
+        }
+    }
+
+    #[test]
+    fn key_salt_personalization_and_length() {
+        let key: Vec<u8> = (0..64).collect();
+        let message: Vec<u8> = (0..=255).collect();
+        // The keyed vectors from the BLAKE2 reference package, blake2b-kat.txt
+        assert_eq!(
+            digest(b"", 64, &key, b"", b"", 1),
+            "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786\
+             b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"
+        );
+        assert_eq!(
+            digest(&message[..128], 64, &key, b"", b"", 50),
+            "72065ee4dd91c2d8509fa1fc28a37c7fc9fa7d5b3f8ad3d0d7a25626b57b1b44\
+             788d4caf806290425f9890a3a2a35a905ab4b37acfd0da6e4517b2525c9651e4"
+        );
+        assert_eq!(
+            digest(b"abc", 32, b"secret", b"saltsaltsaltsalt", b"personal", 3),
+            "ea29e45ff4577121132c5fd650b8e3e2bbd53a81fbfcc0e9c93b2940786f73bf"
+        );
+        assert!(getInitialState(0, 0, &[], 0, &[], 0).is_empty());
+        assert!(getInitialState(65, 0, &[], 0, &[], 0).is_empty());
+        assert!(getInitialState(64, 65, &[], 0, &[], 0).is_empty());
+        assert!(getInitialState(64, 0, &[0; 5], 17, &[], 0).is_empty());
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..300).map(|i| i as u8).collect();
+        let words = bytesToWords(&message);
+
+        // Hash the first 280 bytes: one block is compressed and 152 bytes stay buffered
+        let mut state = getInitialState(64, 0, &[], 0, &[], 0);
+        let nWordsReady = doCrypt(0, &words[..70], 280, 32, &mut state, 1);
+        let context = exportContext(hashcontext::BLAKE2B, &[64, 0, 0, 0], &state, &words[nWordsReady as usize..70], 152, 0, 280);
+        assert!(importContext(hashcontext::BLAKE2S, &[64, 0, 0, 0], &context).is_empty());
+        // The output length and key length are part of the configuration
+        assert!(importContext(hashcontext::BLAKE2B, &[32, 0, 0, 0], &context).is_empty());
+        assert!(importContext(hashcontext::BLAKE2B, &[64, 16, 0, 0], &context).is_empty());
+
+        let fields = importContext(hashcontext::BLAKE2B, &[64, 0, 0, 0], &context);
+        assert_eq!(fields[..4], [0, 280, STATE_WORDS as u32, 152]);
+        assert_eq!(fields[4 + STATE_WORDS..], words[32..70]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut state = fields[4..4 + STATE_WORDS].to_vec();
+        let nWordsReady = doCrypt(0, &words[32..], 172, 32, &mut state, 1);
+        let resumed = doFinalize(&words[32 + nWordsReady as usize..], 172 - nWordsReady * 4, &mut state, 64);
+        assert_eq!(
+            (0..64).map(|i| format!("{:02x}", (resumed[i / 4] >> (24 - (i % 4) * 8)) as u8)).collect::<String>(),
+            digest(&message, 64, b"", b"", b"", 300)
+        );
+    }
+}
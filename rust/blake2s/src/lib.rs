@@ -0,0 +1,300 @@
+mod utils;
+
+use std::cmp;
+use hashcontext::ContextFormat;
+use wasm_bindgen::prelude::*;
+use utils::*;
+
+// The state passed between calls: the 8 chaining value words, followed by the byte counter as a (high, low) pair
+const STATE_WORDS: usize = 10;
+const BLOCK_BYTES: usize = 64;
+
+/// Initial state for a digest of `outputSize` bytes (1 to 32). A key of `keySize` bytes (up to 32)
+/// is not part of the state; the caller buffers it, zero-padded to a full block, ahead of the message.
+/// Salt and personalization are at most 8 bytes and are zero-padded.
+/// Returns an empty vector if any parameter is out of range.
+#[wasm_bindgen]
+pub fn getInitialState(
+    outputSize: u32,
+    keySize: u32,
+    saltWords: &[u32],
+    saltSigBytes: u32,
+    personalWords: &[u32],
+    personalSigBytes: u32,
+) -> Vec<u32> {
+    if outputSize < 1 || outputSize > 32 || keySize > 32 || saltSigBytes > 8 || personalSigBytes > 8 {
+        return Vec::new();
+    }
+
+    // Parameter block: digest length, key length, fanout 1, depth 1, then zeros up to salt and personalization
+    let mut param: [u8; 32] = [0; 32];
+    param[0] = outputSize as u8;
+    param[1] = keySize as u8;
+    param[2] = 1;
+    param[3] = 1;
+    param[16..16 + saltSigBytes as usize].copy_from_slice(&wordsToBytes(saltWords, saltSigBytes));
+    param[24..24 + personalSigBytes as usize].copy_from_slice(&wordsToBytes(personalWords, personalSigBytes));
+
+    let IV = getIV();
+    let mut h: [u32; 8] = [0; 8];
+    for i in 0..8 {
+        h[i] = IV[i] ^ readU32(&param, i * 4);
+    }
+
+    let mut state: Vec<u32> = vec![0; STATE_WORDS];
+    storeState(&h, 0, &mut state);
+    state
+}
+
+/// Compresses the complete blocks that are ready, like `sha256::doCrypt`.
+/// The last buffered block is always held back, because `doFinalize` has to compress it with the final flag.
+#[wasm_bindgen]
+pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32, state: &mut [u32], minBufferSize: u32) -> u32 {
+    let blockSizeBytes = blockSize * 4;
+    let mut nBlocksReady = if doFlush > 0 {
+        (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes
+    } else {
+        (dataSigBytes / blockSizeBytes).saturating_sub(minBufferSize)
+    };
+    nBlocksReady = cmp::min(nBlocksReady, dataSigBytes.saturating_sub(1) / blockSizeBytes);
+
+    let (mut h, mut t) = loadState(state);
+    for i in 0..nBlocksReady as usize {
+        let block = readBlock(dataWords, i * BLOCK_BYTES, BLOCK_BYTES);
+        t = t.wrapping_add(BLOCK_BYTES as u64);
+        compress(&mut h, &block, t, false);
+    }
+    storeState(&h, t, state);
+
+    nBlocksReady * blockSize
+}
+
+/// Compresses the rest of the buffer, the last block with the final flag, and returns
+/// the first `outputSize` bytes of the chaining value.
+#[wasm_bindgen]
+pub fn doFinalize(dataWords: &[u32], dataSigBytes: u32, state: &mut [u32], outputSize: u32) -> Vec<u32> {
+    let sigBytes = dataSigBytes as usize;
+    let (mut h, mut t) = loadState(state);
+
+    // An empty message still compresses one all-zero block
+    let nBlocks = cmp::max((sigBytes + BLOCK_BYTES - 1) / BLOCK_BYTES, 1);
+    for i in 0..nBlocks {
+        let start = i * BLOCK_BYTES;
+        let length = cmp::min(BLOCK_BYTES, sigBytes - cmp::min(start, sigBytes));
+        let block = readBlock(dataWords, start, length);
+        t = t.wrapping_add(length as u64);
+        compress(&mut h, &block, t, i == nBlocks - 1);
+    }
+    storeState(&h, t, state);
+
+    let mut digest: Vec<u8> = Vec::with_capacity(32);
+    for word in h.iter() {
+        digest.extend_from_slice(&word.to_le_bytes());
+    }
+    digest.truncate(outputSize as usize);
+
+    bytesToWords(&digest)
+}
+
+// RFC 7693, section 3.2
+fn compress(h: &mut [u32; 8], block: &[u32; 16], t: u64, last: bool) {
+    let IV = getIV();
+    let SIGMA = getSigma();
+
+    let mut v: [u32; 16] = [0; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= t as u32;
+    v[13] ^= (t >> 32) as u32;
+    if last {
+        v[14] = !v[14];
+    }
+
+    for round in 0..10 {
+        let s = &SIGMA[round];
+        mix(&mut v, 0, 4, 8, 12, block[s[0]], block[s[1]]);
+        mix(&mut v, 1, 5, 9, 13, block[s[2]], block[s[3]]);
+        mix(&mut v, 2, 6, 10, 14, block[s[4]], block[s[5]]);
+        mix(&mut v, 3, 7, 11, 15, block[s[6]], block[s[7]]);
+        mix(&mut v, 0, 5, 10, 15, block[s[8]], block[s[9]]);
+        mix(&mut v, 1, 6, 11, 12, block[s[10]], block[s[11]]);
+        mix(&mut v, 2, 7, 8, 13, block[s[12]], block[s[13]]);
+        mix(&mut v, 3, 4, 9, 14, block[s[14]], block[s[15]]);
+    }
+
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+fn mix(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+// Read `length` bytes starting at byte `start` as little-endian message words, zero-filling the rest
+fn readBlock(dataWords: &[u32], start: usize, length: usize) -> [u32; 16] {
+    let mut bytes: [u8; BLOCK_BYTES] = [0; BLOCK_BYTES];
+    for i in 0..length {
+        let pos = start + i;
+        bytes[i] = (*dataWords.get(pos / 4).unwrap_or(&0) >> (24 - (pos % 4) * 8)) as u8;
+    }
+
+    let mut block: [u32; 16] = [0; 16];
+    for i in 0..16 {
+        block[i] = readU32(&bytes, i * 4);
+    }
+    block
+}
+
+fn readU32(bytes: &[u8], offset: usize) -> u32 {
+    let mut le: [u8; 4] = [0; 4];
+    le.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(le)
+}
+
+fn loadState(state: &[u32]) -> ([u32; 8], u64) {
+    let mut h: [u32; 8] = [0; 8];
+    h.copy_from_slice(&state[..8]);
+    let t = (state[8] as u64) << 32 | state[9] as u64;
+
+    (h, t)
+}
+
+fn storeState(h: &[u32; 8], t: u64, state: &mut [u32]) {
+    state[..8].copy_from_slice(h);
+    state[8] = (t >> 32) as u32;
+    state[9] = t as u32;
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::BLAKE2S], stateWords: STATE_WORDS };
+
+/// Serializes a hash in progress: its configuration `parameters`, `state` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    state: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, state, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hash as the JS hasher does: the key block first, streamed in `chunk` byte updates
+    fn digest(message: &[u8], outputSize: u32, key: &[u8], salt: &[u8], personal: &[u8], chunk: usize) -> String {
+        let mut state = getInitialState(
+            outputSize,
+            key.len() as u32,
+            &bytesToWords(salt),
+            salt.len() as u32,
+            &bytesToWords(personal),
+            personal.len() as u32,
+        );
+        let mut buffer: Vec<u8> = Vec::new();
+        if !key.is_empty() {
+            buffer.extend_from_slice(key);
+            buffer.resize(BLOCK_BYTES, 0);
+        }
+        for part in message.chunks(chunk) {
+            buffer.extend_from_slice(part);
+            let nWordsReady = doCrypt(0, &bytesToWords(&buffer), buffer.len() as u32, 16, &mut state, 1);
+            buffer.drain(..cmp::min(nWordsReady as usize * 4, buffer.len()));
+        }
+        let words = doFinalize(&bytesToWords(&buffer), buffer.len() as u32, &mut state, outputSize);
+
+        (0..outputSize as usize).map(|i| format!("{:02x}", (words[i / 4] >> (24 - (i % 4) * 8)) as u8)).collect()
+    }
+
+    #[test]
+    fn matches_rfc_7693() {
+        assert_eq!(digest(b"abc", 32, b"", b"", b"", 3), "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982");
+        assert_eq!(digest(b"", 32, b"", b"", b"", 1), "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9");
+    }
+
+    #[test]
+    fn block_boundaries_match_one_shot() {
+        let message: Vec<u8> = (0..=255).collect();
+        let expected = "5fdeb59f681d975f52c8e69c5502e02a12a3afcc5836ba58f42784c439228781";
+        for chunk in [1, 32, 63, 64, 65, 256].iter() {
+            assert_eq!(digest(&message, 32, b"", b"", b"", *chunk), expected, "chunk {}", chunk);
+        }
+    }
+
+    #[test]
+    fn key_salt_personalization_and_length() {
+        let key: Vec<u8> = (0..32).collect();
+        let message: Vec<u8> = (0..64).collect();
+        // The keyed vectors from the BLAKE2 reference package, blake2s-kat.txt
+        assert_eq!(digest(b"", 32, &key, b"", b"", 1), "48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49");
+        assert_eq!(digest(&message, 32, &key, b"", b"", 30), "8975b0577fd35566d750b362b0897a26c399136df07bababbde6203ff2954ed4");
+        assert_eq!(digest(b"abc", 16, b"secret", b"saltsalt", b"personal", 3), "b6922e914040f3d996b218d6e514b481");
+        assert!(getInitialState(0, 0, &[], 0, &[], 0).is_empty());
+        assert!(getInitialState(33, 0, &[], 0, &[], 0).is_empty());
+        assert!(getInitialState(32, 33, &[], 0, &[], 0).is_empty());
+        assert!(getInitialState(32, 0, &[0; 3], 9, &[], 0).is_empty());
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..150).map(|i| i as u8).collect();
+        let words = bytesToWords(&message);
+
+        // Hash the first 140 bytes: one block is compressed and 76 bytes stay buffered
+        let mut state = getInitialState(32, 0, &[], 0, &[], 0);
+        let nWordsReady = doCrypt(0, &words[..35], 140, 16, &mut state, 1);
+        let context = exportContext(hashcontext::BLAKE2S, &[32, 0, 0, 0], &state, &words[nWordsReady as usize..35], 76, 0, 140);
+        assert!(importContext(hashcontext::BLAKE2B, &[32, 0, 0, 0], &context).is_empty());
+        // The output length and key length are part of the configuration
+        assert!(importContext(hashcontext::BLAKE2S, &[16, 0, 0, 0], &context).is_empty());
+        assert!(importContext(hashcontext::BLAKE2S, &[32, 16, 0, 0], &context).is_empty());
+
+        let fields = importContext(hashcontext::BLAKE2S, &[32, 0, 0, 0], &context);
+        assert_eq!(fields[..4], [0, 140, STATE_WORDS as u32, 76]);
+        assert_eq!(fields[4 + STATE_WORDS..], words[16..35]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut state = fields[4..4 + STATE_WORDS].to_vec();
+        let nWordsReady = doCrypt(0, &words[16..], 86, 16, &mut state, 1);
+        let resumed = doFinalize(&words[16 + nWordsReady as usize..], 86 - nWordsReady * 4, &mut state, 32);
+        assert_eq!(
+            (0..32).map(|i| format!("{:02x}", (resumed[i / 4] >> (24 - (i % 4) * 8)) as u8)).collect::<String>(),
+            digest(&message, 32, b"", b"", b"", 150)
+        );
+    }
+}
@@ -0,0 +1,470 @@
+mod utils;
+
+use std::cmp;
+use hashcontext::ContextFormat;
+use wasm_bindgen::prelude::*;
+use utils::*;
+
+const BLOCK_BYTES: usize = 64;
+const CHUNK_BLOCKS: u32 = 16;
+
+// Domain separation flags
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+const KEYED_HASH: u32 = 1 << 4;
+const DERIVE_KEY_CONTEXT: u32 = 1 << 5;
+const DERIVE_KEY_MATERIAL: u32 = 1 << 6;
+
+// The state passed between calls: key words, flags, the current chunk's chaining value,
+// chunk counter as a (high, low) pair, blocks compressed in the chunk, then the chaining value stack.
+// 54 entries are enough for 2^64 bytes of input.
+const MAX_STACK: usize = 54;
+const STATE_WORDS: usize = 21 + MAX_STACK * 8;
+
+// A root node: chaining value, block words, block length and flags
+const ROOT_WORDS: usize = 26;
+
+/// Initial state for the default hash mode.
+#[wasm_bindgen]
+pub fn getInitialState() -> Vec<u32> {
+    HasherState::new(getIV(), 0).store()
+}
+
+/// Initial state for the keyed hash mode. Returns an empty vector unless the key is exactly 32 bytes.
+#[wasm_bindgen]
+pub fn getKeyedState(keyWords: &[u32], keySigBytes: u32) -> Vec<u32> {
+    if keySigBytes != 32 {
+        return Vec::new();
+    }
+
+    HasherState::new(readKey(&wordsToBytes(keyWords, keySigBytes)), KEYED_HASH).store()
+}
+
+/// Initial state for the key derivation mode: the context string is hashed into the key for the key material.
+#[wasm_bindgen]
+pub fn getDeriveKeyState(contextWords: &[u32], contextSigBytes: u32) -> Vec<u32> {
+    let context = HasherState::new(getIV(), DERIVE_KEY_CONTEXT);
+    let contextKey = context.finalize(&wordsToBytes(contextWords, contextSigBytes)).rootBytes(0, 32);
+
+    HasherState::new(readKey(&contextKey), DERIVE_KEY_MATERIAL).store()
+}
+
+/// Compresses the complete blocks that are ready, like `sha256::doCrypt`.
+/// The last buffered block is always held back, because it may end the root chunk.
+#[wasm_bindgen]
+pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32, state: &mut [u32], minBufferSize: u32) -> u32 {
+    let blockSizeBytes = blockSize * 4;
+    let mut nBlocksReady = if doFlush > 0 {
+        (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes
+    } else {
+        (dataSigBytes / blockSizeBytes).saturating_sub(minBufferSize)
+    };
+    nBlocksReady = cmp::min(nBlocksReady, dataSigBytes.saturating_sub(1) / blockSizeBytes);
+
+    let mut hasher = HasherState::load(state);
+    hasher.update(&wordsToBytes(dataWords, nBlocksReady * blockSizeBytes));
+    state.copy_from_slice(&hasher.store());
+
+    nBlocksReady * blockSize
+}
+
+/// Hashes the rest of the buffer and returns the root node, from which `squeeze` reads any amount of output.
+#[wasm_bindgen]
+pub fn doFinalize(dataWords: &[u32], dataSigBytes: u32, state: &[u32]) -> Vec<u32> {
+    let root = HasherState::load(state).finalize(&wordsToBytes(dataWords, dataSigBytes));
+
+    let mut rootWords: Vec<u32> = Vec::with_capacity(ROOT_WORDS);
+    rootWords.extend_from_slice(&root.cv);
+    rootWords.extend_from_slice(&root.block);
+    rootWords.push(root.blockLen);
+    rootWords.push(root.flags);
+    rootWords
+}
+
+/// Reads `nBytes` of output starting at byte `offset` (a (high, low) pair) of the root node's output stream.
+#[wasm_bindgen]
+pub fn squeeze(rootWords: &[u32], offsetHigh: u32, offsetLow: u32, nBytes: u32) -> Vec<u32> {
+    let mut root = Node {
+        cv: [0; 8],
+        block: [0; 16],
+        counter: 0,
+        blockLen: rootWords[24],
+        flags: rootWords[25],
+    };
+    root.cv.copy_from_slice(&rootWords[..8]);
+    root.block.copy_from_slice(&rootWords[8..24]);
+
+    let offset = (offsetHigh as u64) << 32 | offsetLow as u64;
+    bytesToWords(&root.rootBytes(offset, nBytes as usize))
+}
+
+struct HasherState {
+    key: [u32; 8],
+    flags: u32,
+    cv: [u32; 8],
+    counter: u64,
+    blocks: u32,
+    stack: Vec<[u32; 8]>,
+}
+
+impl HasherState {
+    fn new(key: [u32; 8], flags: u32) -> HasherState {
+        HasherState { key, flags, cv: key, counter: 0, blocks: 0, stack: Vec::new() }
+    }
+
+    fn load(state: &[u32]) -> HasherState {
+        let mut hasher = HasherState::new([0; 8], state[8]);
+        hasher.key.copy_from_slice(&state[..8]);
+        hasher.cv.copy_from_slice(&state[9..17]);
+        hasher.counter = (state[17] as u64) << 32 | state[18] as u64;
+        hasher.blocks = state[19];
+        for i in 0..state[20] as usize {
+            let mut cv: [u32; 8] = [0; 8];
+            cv.copy_from_slice(&state[21 + i * 8..29 + i * 8]);
+            hasher.stack.push(cv);
+        }
+        hasher
+    }
+
+    fn store(&self) -> Vec<u32> {
+        let mut state: Vec<u32> = vec![0; STATE_WORDS];
+        state[..8].copy_from_slice(&self.key);
+        state[8] = self.flags;
+        state[9..17].copy_from_slice(&self.cv);
+        state[17] = (self.counter >> 32) as u32;
+        state[18] = self.counter as u32;
+        state[19] = self.blocks;
+        state[20] = self.stack.len() as u32;
+        for (i, cv) in self.stack.iter().enumerate() {
+            state[21 + i * 8..29 + i * 8].copy_from_slice(cv);
+        }
+        state
+    }
+
+    // Compresses whole blocks that are known not to be the last of the input
+    fn update(&mut self, bytes: &[u8]) {
+        for block in bytes.chunks(BLOCK_BYTES) {
+            let node = Node {
+                cv: self.cv,
+                block: readBlock(block),
+                counter: self.counter,
+                blockLen: BLOCK_BYTES as u32,
+                flags: self.flags | self.chunkStartFlag(),
+            };
+
+            if self.blocks + 1 < CHUNK_BLOCKS {
+                self.cv = node.chainingValue();
+                self.blocks += 1;
+            } else {
+                let chunkNode = Node { flags: node.flags | CHUNK_END, ..node };
+                self.pushChunk(chunkNode.chainingValue());
+            }
+        }
+    }
+
+    // Merges the completed subtrees, as many as there are trailing zero bits in the new chunk count
+    fn pushChunk(&mut self, chunkCv: [u32; 8]) {
+        let mut cv = chunkCv;
+        let mut totalChunks = self.counter + 1;
+        while totalChunks & 1 == 0 {
+            let left = self.stack.pop().unwrap();
+            cv = self.parent(&left, &cv).chainingValue();
+            totalChunks >>= 1;
+        }
+        self.stack.push(cv);
+
+        self.counter += 1;
+        self.cv = self.key;
+        self.blocks = 0;
+    }
+
+    // Compresses all but the last block, then folds the final chunk into the stack up to the root
+    fn finalize(mut self, bytes: &[u8]) -> Node {
+        let nFullBlocks = bytes.len().saturating_sub(1) / BLOCK_BYTES;
+        self.update(&bytes[..nFullBlocks * BLOCK_BYTES]);
+        let last = &bytes[nFullBlocks * BLOCK_BYTES..];
+
+        let mut node = Node {
+            cv: self.cv,
+            block: readBlock(last),
+            counter: self.counter,
+            blockLen: last.len() as u32,
+            flags: self.flags | self.chunkStartFlag() | CHUNK_END,
+        };
+        for left in self.stack.iter().rev() {
+            node = self.parent(left, &node.chainingValue());
+        }
+        node
+    }
+
+    fn chunkStartFlag(&self) -> u32 {
+        if self.blocks == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn parent(&self, left: &[u32; 8], right: &[u32; 8]) -> Node {
+        let mut block: [u32; 16] = [0; 16];
+        block[..8].copy_from_slice(left);
+        block[8..].copy_from_slice(right);
+
+        Node { cv: self.key, block, counter: 0, blockLen: BLOCK_BYTES as u32, flags: self.flags | PARENT }
+    }
+}
+
+struct Node {
+    cv: [u32; 8],
+    block: [u32; 16],
+    counter: u64,
+    blockLen: u32,
+    flags: u32,
+}
+
+impl Node {
+    fn chainingValue(&self) -> [u32; 8] {
+        let out = compress(&self.cv, &self.block, self.counter, self.blockLen, self.flags);
+        let mut cv: [u32; 8] = [0; 8];
+        cv.copy_from_slice(&out[..8]);
+        cv
+    }
+
+    // Output block t of the root is the compression with counter t and the ROOT flag
+    fn rootBytes(&self, offset: u64, nBytes: usize) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(nBytes + BLOCK_BYTES);
+        let mut counter = offset / BLOCK_BYTES as u64;
+        let skip = (offset % BLOCK_BYTES as u64) as usize;
+        while bytes.len() < skip + nBytes {
+            let out = compress(&self.cv, &self.block, counter, self.blockLen, self.flags | ROOT);
+            for word in out.iter() {
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+            counter += 1;
+        }
+
+        bytes[skip..skip + nBytes].to_vec()
+    }
+}
+
+// The BLAKE3 specification, section 2.2
+fn compress(cv: &[u32; 8], block: &[u32; 16], counter: u64, blockLen: u32, flags: u32) -> [u32; 16] {
+    let IV = getIV();
+    let PERMUTATION = getMessagePermutation();
+
+    let mut v: [u32; 16] = [0; 16];
+    v[..8].copy_from_slice(cv);
+    v[8..12].copy_from_slice(&IV[..4]);
+    v[12] = counter as u32;
+    v[13] = (counter >> 32) as u32;
+    v[14] = blockLen;
+    v[15] = flags;
+
+    let mut m = *block;
+    for round in 0..7 {
+        mix(&mut v, 0, 4, 8, 12, m[0], m[1]);
+        mix(&mut v, 1, 5, 9, 13, m[2], m[3]);
+        mix(&mut v, 2, 6, 10, 14, m[4], m[5]);
+        mix(&mut v, 3, 7, 11, 15, m[6], m[7]);
+        mix(&mut v, 0, 5, 10, 15, m[8], m[9]);
+        mix(&mut v, 1, 6, 11, 12, m[10], m[11]);
+        mix(&mut v, 2, 7, 8, 13, m[12], m[13]);
+        mix(&mut v, 3, 4, 9, 14, m[14], m[15]);
+
+        if round < 6 {
+            let previous = m;
+            for i in 0..16 {
+                m[i] = previous[PERMUTATION[i]];
+            }
+        }
+    }
+
+    for i in 0..8 {
+        v[i] ^= v[i + 8];
+        v[i + 8] ^= cv[i];
+    }
+    v
+}
+
+fn mix(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+// Read up to one block of bytes as little-endian message words, zero-filling the rest
+fn readBlock(bytes: &[u8]) -> [u32; 16] {
+    let mut padded: [u8; BLOCK_BYTES] = [0; BLOCK_BYTES];
+    padded[..bytes.len()].copy_from_slice(bytes);
+
+    let mut block: [u32; 16] = [0; 16];
+    for i in 0..16 {
+        block[i] = readU32(&padded, i * 4);
+    }
+    block
+}
+
+fn readKey(bytes: &[u8]) -> [u32; 8] {
+    let mut key: [u32; 8] = [0; 8];
+    for i in 0..8 {
+        key[i] = readU32(bytes, i * 4);
+    }
+    key
+}
+
+fn readU32(bytes: &[u8], offset: usize) -> u32 {
+    let mut le: [u8; 4] = [0; 4];
+    le.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(le)
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::BLAKE3], stateWords: STATE_WORDS };
+
+/// Serializes a hash in progress: its configuration `parameters`, `state` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    state: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, state, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The input pattern of the official test vectors
+    fn input(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i % 251) as u8).collect()
+    }
+
+    // Hash as the JS hasher does, streamed in `chunk` byte updates, and read `nBytes` of output
+    fn digest(mut state: Vec<u32>, message: &[u8], chunk: usize, nBytes: u32) -> String {
+        let mut buffer: Vec<u8> = Vec::new();
+        for part in message.chunks(chunk) {
+            buffer.extend_from_slice(part);
+            let nWordsReady = doCrypt(0, &bytesToWords(&buffer), buffer.len() as u32, 16, &mut state, 0);
+            buffer.drain(..cmp::min(nWordsReady as usize * 4, buffer.len()));
+        }
+        let root = doFinalize(&bytesToWords(&buffer), buffer.len() as u32, &state);
+
+        toHex(&squeeze(&root, 0, 0, nBytes), nBytes)
+    }
+
+    fn toHex(words: &[u32], nBytes: u32) -> String {
+        (0..nBytes as usize).map(|i| format!("{:02x}", (words[i / 4] >> (24 - (i % 4) * 8)) as u8)).collect()
+    }
+
+    #[test]
+    fn hash_matches_reference() {
+        assert_eq!(
+            digest(getInitialState(), b"", 1, 32),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        assert_eq!(
+            digest(getInitialState(), b"abc", 3, 32),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+        assert_eq!(
+            digest(getInitialState(), &input(1), 1, 32),
+            "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213"
+        );
+        assert_eq!(
+            digest(getInitialState(), &input(1024), 1024, 32),
+            "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7"
+        );
+    }
+
+    #[test]
+    fn chunk_tree_is_independent_of_updates() {
+        let message = input(31 * 1024 + 7);
+        let expected = "c40b37349bf136062d9b600390005e60f06d397359930c0c32e44bf819a74bbc";
+        for chunk in [1, 63, 64, 65, 1023, 1024, 1025, 4096].iter() {
+            assert_eq!(digest(getInitialState(), &message, *chunk, 32), expected, "chunk {}", chunk);
+        }
+    }
+
+    #[test]
+    fn keyed_hash_and_derive_key() {
+        // Checked against the BLAKE3 reference implementation
+        let key = b"whats the Elephant's name, I mea";
+        let keyWords = bytesToWords(key);
+        assert_eq!(
+            digest(getKeyedState(&keyWords, 32), &input(2049), 100, 32),
+            "0771d6c2c219b1e81257b177f042d9c64453db9089c856f2635500f8bf487583"
+        );
+        let context = b"BLAKE3 2019-12-27 16:29:52 test vectors context";
+        assert_eq!(
+            digest(getDeriveKeyState(&bytesToWords(context), context.len() as u32), &input(2049), 100, 32),
+            "2ea477c5515cc3dd606512ee72bb3e0e758cfae7232826f35fb98ca1bcbdf273"
+        );
+        assert!(getKeyedState(&keyWords, 31).is_empty());
+    }
+
+    #[test]
+    fn squeeze_reads_any_offset() {
+        let state = getInitialState();
+        let root = doFinalize(&bytesToWords(&input(3000)), 3000, &state);
+        let whole = toHex(&squeeze(&root, 0, 0, 200), 200);
+        assert_eq!(toHex(&squeeze(&root, 0, 64, 70), 70), &whole[128..268]);
+        assert_eq!(toHex(&squeeze(&root, 0, 13, 1), 1), &whole[26..28]);
+        assert_eq!(&whole[..64], digest(getInitialState(), &input(3000), 500, 32));
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message = input(2000);
+        let words = bytesToWords(&message);
+
+        // Hash the first 1500 bytes: 23 blocks are hashed and 28 bytes stay buffered
+        let mut state = getInitialState();
+        let nWordsReady = doCrypt(0, &words[..375], 1500, 16, &mut state, 0);
+        let context = exportContext(hashcontext::BLAKE3, &[], &state, &words[nWordsReady as usize..375], 28, 0, 1500);
+        assert!(importContext(hashcontext::SHA256, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::BLAKE3, &[], &context);
+        assert_eq!(fields[..4], [0, 1500, STATE_WORDS as u32, 28]);
+        assert_eq!(fields[4 + STATE_WORDS..], words[368..375]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut state = fields[4..4 + STATE_WORDS].to_vec();
+        let nWordsReady = doCrypt(0, &words[368..], 528, 16, &mut state, 0);
+        let root = doFinalize(&words[368 + nWordsReady as usize..], 528 - nWordsReady * 4, &state);
+        assert_eq!(toHex(&squeeze(&root, 0, 0, 32), 32), digest(getInitialState(), &message, 2000, 32));
+    }
+}
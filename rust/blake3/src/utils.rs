@@ -0,0 +1,9 @@
+// The BLAKE3 specification, section 2.1
+pub fn getIV() -> [u32; 8] {
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19]
+}
+
+// The BLAKE3 specification, section 2.2
+pub fn getMessagePermutation() -> [usize; 16] {
+    [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8]
+}
@@ -0,0 +1,854 @@
+/// A block cipher working on big-endian 32-bit words, as produced by `WordArray`.
+///
+/// Implementors only provide the single block transforms, the chaining modes
+/// below are shared by every cipher.
+pub trait BlockCipher {
+    /// Block size in 32-bit words
+    fn blockSize(&self) -> usize;
+
+    fn encryptBlock(&self, dataWords: &mut [u32], offset: usize);
+
+    fn decryptBlock(&self, dataWords: &mut [u32], offset: usize);
+}
+
+/// Encrypt the first `nWordsReady` words of `dataWords` in place.
+///
+/// Returns the chaining value (last ciphertext block, keystream or counter)
+/// to be passed back as `iv` on the next call, or an empty vector for ECB.
+/// Returns `None` and leaves the data untouched when the mode is unknown or
+/// the IV is shorter than one block.
+pub fn doEncrypt<C: BlockCipher>(
+    cipher: &C,
+    mode: &str,
+    nWordsReady: usize,
+    iv: &[u32],
+    dataWords: &mut [u32],
+) -> Option<Vec<u32>> {
+    let blockSize = cipher.blockSize();
+    let mode = mode.to_lowercase();
+    if !isValidMode(&mode, blockSize, iv) {
+        return None;
+    }
+
+    let mut process: Vec<u32> = Vec::new();
+    if nWordsReady > 0 {
+        let mut offset: usize = 0;
+        match mode.as_str() {
+            // Ciphertext stealing modes are plain CBC until the last two blocks
+            "cbc" | "cbc-cs1" | "cbc-cs2" | "cbc-cs3" => {
+                let mut prevBlock = iv[0..blockSize].to_vec();
+                while offset < nWordsReady {
+                    xorBlock(blockSize, &prevBlock, dataWords, offset);
+                    cipher.encryptBlock(dataWords, offset);
+                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
+                    offset += blockSize;
+                }
+                process = prevBlock;
+            }
+            "ecb" => {
+                while offset < nWordsReady {
+                    cipher.encryptBlock(dataWords, offset);
+                    offset += blockSize;
+                }
+            }
+            "cfb" => {
+                let mut prevBlock = iv[0..blockSize].to_vec();
+                while offset < nWordsReady {
+                    let mut keystream = prevBlock;
+                    cipher.encryptBlock(&mut keystream, 0);
+                    xorBlock(blockSize, &keystream, dataWords, offset);
+                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
+                    offset += blockSize;
+                }
+                process = prevBlock;
+            }
+            "ofb" => {
+                let mut keystream = iv[0..blockSize].to_vec();
+                while offset < nWordsReady {
+                    cipher.encryptBlock(&mut keystream, 0);
+                    xorBlock(blockSize, &keystream, dataWords, offset);
+                    offset += blockSize;
+                }
+                process = keystream;
+            }
+            mode => {
+                if let Some(counterBits) = getCounterBits(mode, blockSize) {
+                    process = ctrProcess(cipher, counterBits, iv, dataWords, nWordsReady);
+                }
+            }
+        }
+    }
+
+    Some(process)
+}
+
+/// Decrypt the first `nWordsReady` words of `dataWords` in place.
+///
+/// The returned chaining value follows the same rules as `doEncrypt`.
+pub fn doDecrypt<C: BlockCipher>(
+    cipher: &C,
+    mode: &str,
+    nWordsReady: usize,
+    iv: &[u32],
+    dataWords: &mut [u32],
+) -> Option<Vec<u32>> {
+    let blockSize = cipher.blockSize();
+    let mode = mode.to_lowercase();
+    if !isValidMode(&mode, blockSize, iv) {
+        return None;
+    }
+
+    let mut process: Vec<u32> = Vec::new();
+    if nWordsReady > 0 {
+        let mut offset: usize = 0;
+        match mode.as_str() {
+            // Ciphertext stealing modes are plain CBC until the last two blocks
+            "cbc" | "cbc-cs1" | "cbc-cs2" | "cbc-cs3" => {
+                let mut prevBlock = iv[0..blockSize].to_vec();
+                while offset < nWordsReady {
+                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
+                    cipher.decryptBlock(dataWords, offset);
+                    xorBlock(blockSize, &prevBlock, dataWords, offset);
+                    prevBlock = thisBlock;
+                    offset += blockSize;
+                }
+                process = prevBlock;
+            }
+            "ecb" => {
+                while offset < nWordsReady {
+                    cipher.decryptBlock(dataWords, offset);
+                    offset += blockSize;
+                }
+            }
+            "cfb" => {
+                let mut prevBlock = iv[0..blockSize].to_vec();
+                while offset < nWordsReady {
+                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
+                    let keystream = &mut prevBlock;
+                    cipher.encryptBlock(keystream, 0);
+                    xorBlock(blockSize, keystream, dataWords, offset);
+                    prevBlock = thisBlock;
+                    offset += blockSize;
+                }
+                process = prevBlock;
+            }
+            "ofb" => {
+                let mut keystream = iv[0..blockSize].to_vec();
+                while offset < nWordsReady {
+                    cipher.encryptBlock(&mut keystream, 0);
+                    xorBlock(blockSize, &keystream, dataWords, offset);
+                    offset += blockSize;
+                }
+                process = keystream;
+            }
+            mode => {
+                if let Some(counterBits) = getCounterBits(mode, blockSize) {
+                    process = ctrProcess(cipher, counterBits, iv, dataWords, nWordsReady);
+                }
+            }
+        }
+    }
+
+    Some(process)
+}
+
+/// Encrypt the first `dataSigBytes` bytes of `dataWords` in place with CBC and ciphertext
+/// stealing (NIST SP 800-38A addendum), so that no padding is needed.
+///
+/// `mode` is one of "cbc-cs1", "cbc-cs2" or "cbc-cs3" and selects the order of the last
+/// two ciphertext blocks. The whole remaining message has to be passed at once since the
+/// last two blocks depend on each other. Returns `false` and leaves the data untouched
+/// when the message is shorter than one block, the mode is unknown or the IV is shorter
+/// than one block.
+pub fn doEncryptCts<C: BlockCipher>(
+    cipher: &C,
+    mode: &str,
+    iv: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+) -> bool {
+    let blockSize = cipher.blockSize();
+    let blockSizeBytes = blockSize * 4;
+    let variant = match getCtsVariant(mode) {
+        Some(variant) if dataSigBytes >= blockSizeBytes && iv.len() >= blockSize => variant,
+        _ => return false,
+    };
+
+    // CBC over the zero-padded message
+    let nBlocks = (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes;
+    let mut padded = wordsToBytes(dataWords, dataSigBytes);
+    padded.resize(nBlocks * blockSizeBytes, 0);
+    let mut words = bytesToWords(&padded);
+    doEncrypt(cipher, "cbc", words.len(), iv, &mut words);
+
+    // Truncate the next to last block to the length of the partial last block
+    let mut bytes = wordsToBytes(&words, words.len() * 4);
+    let lastSize = dataSigBytes - (nBlocks - 1) * blockSizeBytes;
+    if nBlocks > 1 {
+        let start = (nBlocks - 2) * blockSizeBytes;
+        bytes.drain(start + lastSize..start + blockSizeBytes);
+        if variant == 3 || (variant == 2 && lastSize < blockSizeBytes) {
+            swapLastBlocks(&mut bytes[start..], lastSize, blockSizeBytes);
+        }
+    }
+
+    writeBytes(dataWords, &bytes);
+    true
+}
+
+/// Decrypt the first `dataSigBytes` bytes of `dataWords` in place with CBC and ciphertext
+/// stealing, following the same rules as `doEncryptCts`.
+pub fn doDecryptCts<C: BlockCipher>(
+    cipher: &C,
+    mode: &str,
+    iv: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+) -> bool {
+    let blockSize = cipher.blockSize();
+    let blockSizeBytes = blockSize * 4;
+    let variant = match getCtsVariant(mode) {
+        Some(variant) if dataSigBytes >= blockSizeBytes && iv.len() >= blockSize => variant,
+        _ => return false,
+    };
+
+    let nBlocks = (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes;
+    let lastSize = dataSigBytes - (nBlocks - 1) * blockSizeBytes;
+    let mut bytes = wordsToBytes(dataWords, dataSigBytes);
+    if nBlocks == 1 {
+        let mut words = bytesToWords(&bytes);
+        doDecrypt(cipher, "cbc", blockSize, iv, &mut words);
+        writeBytes(dataWords, &wordsToBytes(&words, dataSigBytes));
+        return true;
+    }
+
+    // Bring the last two blocks back into CS1 order: truncated C(n-1), then C(n)
+    let start = (nBlocks - 2) * blockSizeBytes;
+    if variant == 3 || (variant == 2 && lastSize < blockSizeBytes) {
+        let tail = bytes[start..].to_vec();
+        bytes.truncate(start);
+        bytes.extend_from_slice(&tail[blockSizeBytes..]);
+        bytes.extend_from_slice(&tail[..blockSizeBytes]);
+    }
+
+    // Decrypting C(n) gives C(n-1) XOR P(n), where C(n-1) is complete since P(n) was
+    // zero-padded. Its tail is the part of C(n-1) that was stolen.
+    let mut lastBlock = bytesToWords(&bytes[start + lastSize..]);
+    cipher.decryptBlock(&mut lastBlock, 0);
+    let z = wordsToBytes(&lastBlock, blockSizeBytes);
+    let mut prevBlock = bytes[start..start + lastSize].to_vec();
+    prevBlock.extend_from_slice(&z[lastSize..]);
+    let lastPlaintext: Vec<u8> = (0..lastSize).map(|i| z[i] ^ prevBlock[i]).collect();
+
+    // Everything up to C(n-1) is regular CBC
+    let mut ciphertext = bytes[..start].to_vec();
+    ciphertext.extend_from_slice(&prevBlock);
+    let mut words = bytesToWords(&ciphertext);
+    doDecrypt(cipher, "cbc", words.len(), iv, &mut words);
+
+    let mut plaintext = wordsToBytes(&words, words.len() * 4);
+    plaintext.extend_from_slice(&lastPlaintext);
+    writeBytes(dataWords, &plaintext);
+    true
+}
+
+// Whether `mode` is a known lowercase mode name and `iv` holds the chaining value it needs
+fn isValidMode(mode: &str, blockSize: usize, iv: &[u32]) -> bool {
+    match mode {
+        "ecb" => true,
+        "cbc" | "cbc-cs1" | "cbc-cs2" | "cbc-cs3" | "cfb" | "ofb" => iv.len() >= blockSize,
+        mode => getCounterBits(mode, blockSize).is_some() && iv.len() >= blockSize,
+    }
+}
+
+// Variant number of a "cbc-csN" mode name
+fn getCtsVariant(mode: &str) -> Option<u8> {
+    match mode.to_lowercase().as_str() {
+        "cbc-cs1" => Some(1),
+        "cbc-cs2" => Some(2),
+        "cbc-cs3" => Some(3),
+        _ => None,
+    }
+}
+
+// Turn the truncated C(n-1) followed by C(n) into C(n) followed by the truncated C(n-1)
+fn swapLastBlocks(tail: &mut [u8], lastSize: usize, blockSizeBytes: usize) {
+    let truncated = tail[..lastSize].to_vec();
+    tail.copy_within(lastSize..lastSize + blockSizeBytes, 0);
+    tail[blockSizeBytes..].copy_from_slice(&truncated);
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: usize) -> Vec<u8> {
+    (0..sigBytes).map(|i| (words[i / 4] >> (24 - (i % 4) * 8)) as u8).collect()
+}
+
+// Big-endian words, zero-padding a partial last word
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|chunk| chunk.iter().enumerate().fold(0u32, |word, (i, byte)| word | (*byte as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// Overwrite the leading bytes of `words`, clearing the bytes that follow in the last word
+fn writeBytes(words: &mut [u32], bytes: &[u8]) {
+    let newWords = bytesToWords(bytes);
+    words[..newWords.len()].copy_from_slice(&newWords);
+}
+
+/// Encrypt or decrypt `dataSigBytes` bytes that start `byteOffset` bytes into a CTR
+/// stream, without processing anything before them.
+///
+/// The counter is advanced from `iv` by the number of whole blocks before the offset,
+/// and the keystream of the first block is used from the offset's position within it.
+/// Returns `false` and leaves the data untouched when `mode` is not a CTR mode or the
+/// IV is shorter than one block.
+pub fn doCryptCtrAt<C: BlockCipher>(
+    cipher: &C,
+    mode: &str,
+    iv: &[u32],
+    byteOffset: u128,
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+) -> bool {
+    let blockSize = cipher.blockSize();
+    let blockSizeBytes = blockSize * 4;
+    let counterBits = match getCounterBits(&mode.to_lowercase(), blockSize) {
+        Some(counterBits) if iv.len() >= blockSize => counterBits,
+        _ => return false,
+    };
+
+    let mut counter = iv[0..blockSize].to_vec();
+    advanceCounter(&mut counter, counterBits, byteOffset / blockSizeBytes as u128);
+
+    // Lay the data out at its position within the keystream blocks
+    let skip = (byteOffset % blockSizeBytes as u128) as usize;
+    let nBlocks = (skip + dataSigBytes + blockSizeBytes - 1) / blockSizeBytes;
+    let mut bytes = vec![0; skip];
+    bytes.extend(wordsToBytes(dataWords, dataSigBytes));
+    bytes.resize(nBlocks * blockSizeBytes, 0);
+    let mut words = bytesToWords(&bytes);
+    let nWords = words.len();
+    ctrProcess(cipher, counterBits, &counter, &mut words, nWords);
+
+    writeBytes(dataWords, &wordsToBytes(&words, nWords * 4)[skip..skip + dataSigBytes]);
+    true
+}
+
+// CTR is its own inverse, so encryption and decryption share this loop
+fn ctrProcess<C: BlockCipher>(cipher: &C, counterBits: usize, iv: &[u32], dataWords: &mut [u32], nWordsReady: usize) -> Vec<u32> {
+    let blockSize = cipher.blockSize();
+    let mut counter = iv[0..blockSize].to_vec();
+    let mut keystream;
+    let mut offset: usize = 0;
+    while offset < nWordsReady {
+        keystream = counter.clone();
+        cipher.encryptBlock(&mut keystream, 0);
+        incrementCounter(&mut counter, counterBits);
+        xorBlock(blockSize, &keystream, dataWords, offset);
+        offset += blockSize;
+    }
+
+    counter
+}
+
+/// Counter width in bits of a CTR mode name.
+///
+/// "ctr" counts over the whole block, as OpenSSL does. "ctr-N" only counts over the
+/// low N bits and keeps the rest of the IV as a fixed nonce, e.g. "ctr-32" for the
+/// GCM-style 96-bit nonce and 32-bit counter split.
+pub fn getCounterBits(mode: &str, blockSize: usize) -> Option<usize> {
+    let blockBits = blockSize * 32;
+    if mode == "ctr" {
+        return Some(blockBits);
+    }
+
+    match mode.strip_prefix("ctr-").map(str::parse::<usize>) {
+        Some(Ok(bits)) if bits > 0 && bits <= blockBits => Some(bits),
+        _ => None,
+    }
+}
+
+/// Increment the low `counterBits` bits of a big-endian counter block by one, carrying
+/// across words. The counter wraps modulo 2^counterBits and never touches the nonce bits.
+pub fn incrementCounter(counter: &mut [u32], counterBits: usize) {
+    let mut remaining = counterBits;
+    for word in counter.iter_mut().rev() {
+        if remaining == 0 {
+            return;
+        }
+
+        let bits = remaining.min(32);
+        let mask = if bits == 32 { u32::MAX } else { (1 << bits) - 1 };
+        let incremented = (*word & mask).wrapping_add(1) & mask;
+        *word = (*word & !mask) | incremented;
+        if incremented != 0 {
+            return;
+        }
+        remaining -= bits;
+    }
+}
+
+pub fn xorBlock(blockSize: usize, block: &[u32], words: &mut [u32], offset: usize) {
+    // XOR blocks
+    for i in 0..blockSize {
+        words[offset + i] ^= block[i];
+    }
+}
+
+/// Add `nBlocks` to the low `counterBits` bits of a big-endian counter block, modulo
+/// 2^counterBits, as if `incrementCounter` had been called `nBlocks` times.
+pub fn advanceCounter(counter: &mut [u32], counterBits: usize, nBlocks: u128) {
+    let value = counter.iter().fold(0u128, |value, word| (value << 32) | *word as u128);
+    let mask = if counterBits >= 128 { u128::MAX } else { (1 << counterBits) - 1 };
+    let value = (value & !mask) | ((value & mask).wrapping_add(nBlocks) & mask);
+
+    let nWords = counter.len();
+    for (i, word) in counter.iter_mut().enumerate() {
+        *word = (value >> (32 * (nWords - 1 - i))) as u32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A toy 64-bit "cipher": rotate each word and mix in the key. It is
+    // invertible, which is all the mode engine needs.
+    struct ToyCipher {
+        key: [u32; 2],
+    }
+
+    impl BlockCipher for ToyCipher {
+        fn blockSize(&self) -> usize {
+            2
+        }
+
+        fn encryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+            let l = dataWords[offset].rotate_left(7) ^ self.key[0];
+            let r = dataWords[offset + 1].rotate_left(13) ^ self.key[1] ^ l;
+            dataWords[offset] = r;
+            dataWords[offset + 1] = l;
+        }
+
+        fn decryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+            let l = dataWords[offset + 1];
+            let r = dataWords[offset] ^ self.key[1] ^ l;
+            dataWords[offset] = (l ^ self.key[0]).rotate_right(7);
+            dataWords[offset + 1] = r.rotate_right(13);
+        }
+    }
+
+    const CIPHER: ToyCipher = ToyCipher { key: [0x01234567, 0x89abcdef] };
+    const IV: [u32; 2] = [0xdeadbeef, 0x00c0ffee];
+
+    fn message() -> Vec<u32> {
+        (0..8).map(|i: u32| i.wrapping_mul(0x9e3779b9)).collect()
+    }
+
+    // A byte-oriented AES-128, slow but short, to run the NIST vectors through the engine
+    struct Aes128 {
+        roundKeys: [[u8; 16]; 11],
+        sbox: [u8; 256],
+        invSbox: [u8; 256],
+    }
+
+    fn xtime(b: u8) -> u8 {
+        (b << 1) ^ if b & 0x80 != 0 { 0x1b } else { 0 }
+    }
+
+    fn gmul(mut a: u8, mut b: u8) -> u8 {
+        let mut product = 0;
+        while b != 0 {
+            if b & 1 != 0 {
+                product ^= a;
+            }
+            a = xtime(a);
+            b >>= 1;
+        }
+        product
+    }
+
+    impl Aes128 {
+        fn new(key: &[u32]) -> Self {
+            // S-box: multiplicative inverse in GF(2^8), then the affine transform
+            let mut sbox = [0u8; 256];
+            let mut invSbox = [0u8; 256];
+            for x in 0..=255u8 {
+                let inv = if x == 0 { 0 } else { (1..=255u8).find(|&y| gmul(x, y) == 1).unwrap() };
+                let s = inv ^ inv.rotate_left(1) ^ inv.rotate_left(2) ^ inv.rotate_left(3) ^ inv.rotate_left(4) ^ 0x63;
+                sbox[x as usize] = s;
+                invSbox[s as usize] = x;
+            }
+
+            let mut roundKeys = [[0u8; 16]; 11];
+            roundKeys[0] = toBytes(key);
+            let mut rcon = 1;
+            for round in 1..11 {
+                let prev = roundKeys[round - 1];
+                let mut t = [sbox[prev[13] as usize] ^ rcon, sbox[prev[14] as usize], sbox[prev[15] as usize], sbox[prev[12] as usize]];
+                for i in 0..16 {
+                    roundKeys[round][i] = prev[i] ^ t[i % 4];
+                    t[i % 4] = roundKeys[round][i];
+                }
+                rcon = xtime(rcon);
+            }
+
+            Aes128 { roundKeys, sbox, invSbox }
+        }
+
+        fn addRoundKey(&self, state: &mut [u8; 16], round: usize) {
+            for i in 0..16 {
+                state[i] ^= self.roundKeys[round][i];
+            }
+        }
+    }
+
+    fn toBytes(words: &[u32]) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        for i in 0..16 {
+            bytes[i] = (words[i / 4] >> (24 - (i % 4) * 8)) as u8;
+        }
+        bytes
+    }
+
+    fn fromBytes(bytes: &[u8; 16], words: &mut [u32]) {
+        for i in 0..4 {
+            words[i] = u32::from_be_bytes([bytes[4 * i], bytes[4 * i + 1], bytes[4 * i + 2], bytes[4 * i + 3]]);
+        }
+    }
+
+    // Column-major state: byte `row + 4 * column`
+    fn mixColumns(state: &mut [u8; 16], coefficients: [u8; 4]) {
+        for column in state.chunks_mut(4) {
+            let a = [column[0], column[1], column[2], column[3]];
+            for row in 0..4 {
+                column[row] = (0..4).fold(0, |sum, i| sum ^ gmul(a[(row + i) % 4], coefficients[i]));
+            }
+        }
+    }
+
+    impl BlockCipher for Aes128 {
+        fn blockSize(&self) -> usize {
+            4
+        }
+
+        fn encryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+            let mut state = toBytes(&dataWords[offset..offset + 4]);
+            self.addRoundKey(&mut state, 0);
+            for round in 1..11 {
+                let shifted = state;
+                for i in 0..16 {
+                    state[i] = self.sbox[shifted[(i + 4 * (i % 4)) % 16] as usize];
+                }
+                if round != 10 {
+                    mixColumns(&mut state, [2, 3, 1, 1]);
+                }
+                self.addRoundKey(&mut state, round);
+            }
+            fromBytes(&state, &mut dataWords[offset..offset + 4]);
+        }
+
+        fn decryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+            let mut state = toBytes(&dataWords[offset..offset + 4]);
+            self.addRoundKey(&mut state, 10);
+            for round in (0..10).rev() {
+                let shifted = state;
+                for i in 0..16 {
+                    state[(i + 4 * (i % 4)) % 16] = self.invSbox[shifted[i] as usize];
+                }
+                self.addRoundKey(&mut state, round);
+                if round != 0 {
+                    mixColumns(&mut state, [14, 11, 13, 9]);
+                }
+            }
+            fromBytes(&state, &mut dataWords[offset..offset + 4]);
+        }
+    }
+
+    fn hexToWords(hex: &str) -> Vec<u32> {
+        (0..hex.len()).step_by(8).map(|i| u32::from_str_radix(&hex[i..i + 8], 16).unwrap()).collect()
+    }
+
+    // NIST SP 800-38A, appendix F, AES-128
+    const SP800_38A_KEY: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+    const SP800_38A_PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                                       30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+    fn checkSp800_38a(mode: &str, iv: &str, ciphertext: &str) {
+        let cipher = Aes128::new(&hexToWords(SP800_38A_KEY));
+        let iv = hexToWords(iv);
+
+        let mut data = hexToWords(SP800_38A_PLAINTEXT);
+        doEncrypt(&cipher, mode, data.len(), &iv, &mut data);
+        assert_eq!(data, hexToWords(ciphertext), "{} encrypt", mode);
+
+        doDecrypt(&cipher, mode, data.len(), &iv, &mut data);
+        assert_eq!(data, hexToWords(SP800_38A_PLAINTEXT), "{} decrypt", mode);
+    }
+
+    #[test]
+    fn ecb_matches_sp800_38a() {
+        checkSp800_38a("ecb", "00000000000000000000000000000000",
+                       "3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf\
+                        43b1cd7f598ece23881b00e3ed0306887b0c785e27e8ad3f8223207104725dd4");
+    }
+
+    #[test]
+    fn cbc_matches_sp800_38a() {
+        checkSp800_38a("cbc", "000102030405060708090a0b0c0d0e0f",
+                       "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2\
+                        73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7");
+    }
+
+    #[test]
+    fn cfb_matches_sp800_38a() {
+        checkSp800_38a("cfb", "000102030405060708090a0b0c0d0e0f",
+                       "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b\
+                        26751f67a3cbb140b1808cf187a4f4dfc04b05357c5d1c0eeac4c66f9ff7f2e6");
+    }
+
+    #[test]
+    fn ofb_matches_sp800_38a() {
+        checkSp800_38a("ofb", "000102030405060708090a0b0c0d0e0f",
+                       "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed825\
+                        9740051e9c5fecf64344f7a82260edcc304c6528f659c77866a510d9c1d6ae5e");
+    }
+
+    #[test]
+    fn ctr_matches_sp800_38a() {
+        checkSp800_38a("ctr", "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+                       "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff\
+                        5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee");
+    }
+
+    #[test]
+    fn every_mode_round_trips() {
+        for mode in ["cbc", "ecb", "cfb", "ofb", "ctr"] {
+            let plain = message();
+            let mut data = plain.clone();
+            doEncrypt(&CIPHER, mode, data.len(), &IV, &mut data);
+            assert_ne!(data, plain, "{}", mode);
+            doDecrypt(&CIPHER, mode, data.len(), &IV, &mut data);
+            assert_eq!(data, plain, "{}", mode);
+        }
+    }
+
+    #[test]
+    fn chaining_value_resumes_stream() {
+        for mode in ["cbc", "cfb", "ofb", "ctr"] {
+            let mut oneShot = message();
+            doEncrypt(&CIPHER, mode, oneShot.len(), &IV, &mut oneShot);
+
+            let mut split = message();
+            let (head, tail) = split.split_at_mut(4);
+            let chain = doEncrypt(&CIPHER, mode, 4, &IV, head).unwrap();
+            doEncrypt(&CIPHER, mode, 4, &chain, tail);
+            assert_eq!(split, oneShot, "{}", mode);
+        }
+    }
+
+    #[test]
+    fn ciphertext_stealing_round_trips() {
+        let plain = message();
+        for mode in ["cbc-cs1", "cbc-cs2", "cbc-cs3"] {
+            for sigBytes in 8..=32 {
+                let mut data = plain.clone();
+                assert!(doEncryptCts(&CIPHER, mode, &IV, &mut data, sigBytes));
+                assert!(doDecryptCts(&CIPHER, mode, &IV, &mut data, sigBytes));
+                assert_eq!(wordsToBytes(&data, sigBytes), wordsToBytes(&plain, sigBytes), "{} {}", mode, sigBytes);
+            }
+        }
+    }
+
+    #[test]
+    fn ciphertext_stealing_block_order() {
+        let plain = message();
+        let mut cbc = plain.clone();
+        doEncrypt(&CIPHER, "cbc", 8, &IV, &mut cbc);
+
+        // Whole blocks: CS1 and CS2 are plain CBC, CS3 swaps the last two blocks
+        for (mode, expected) in [("cbc-cs1", cbc.clone()), ("cbc-cs2", cbc.clone()),
+                                 ("cbc-cs3", [&cbc[..4], &cbc[6..], &cbc[4..6]].concat())] {
+            let mut data = plain.clone();
+            doEncryptCts(&CIPHER, mode, &IV, &mut data, 32);
+            assert_eq!(data, expected, "{}", mode);
+        }
+
+        // A partial block: CS1 keeps the truncated block first, CS2 and CS3 put it last
+        let mut cs1 = plain.clone();
+        doEncryptCts(&CIPHER, "cbc-cs1", &IV, &mut cs1, 27);
+        let cs1 = wordsToBytes(&cs1, 27);
+        assert_eq!(cs1[..19], wordsToBytes(&cbc, 19)[..]);
+        for mode in ["cbc-cs2", "cbc-cs3"] {
+            let mut data = plain.clone();
+            doEncryptCts(&CIPHER, mode, &IV, &mut data, 27);
+            let data = wordsToBytes(&data, 27);
+            assert_eq!([&data[..16], &data[24..], &data[16..24]].concat(), cs1, "{}", mode);
+        }
+    }
+
+    // Streaming whole blocks through `doEncrypt` and finishing with `doEncryptCts`
+    // must give the same result as a single call
+    #[test]
+    fn ciphertext_stealing_resumes_stream() {
+        for mode in ["cbc-cs1", "cbc-cs2", "cbc-cs3"] {
+            let mut oneShot = message();
+            doEncryptCts(&CIPHER, mode, &IV, &mut oneShot, 29);
+
+            let mut split = message();
+            let (head, tail) = split.split_at_mut(2);
+            let chain = doEncrypt(&CIPHER, mode, 2, &IV, head).unwrap();
+            doEncryptCts(&CIPHER, mode, &chain, tail, 21);
+            assert_eq!(split, oneShot, "{}", mode);
+
+            let (head, tail) = split.split_at_mut(2);
+            let chain = doDecrypt(&CIPHER, mode, 2, &IV, head).unwrap();
+            doDecryptCts(&CIPHER, mode, &chain, tail, 21);
+            assert_eq!(wordsToBytes(&split, 29), wordsToBytes(&message(), 29), "{}", mode);
+        }
+    }
+
+    #[test]
+    fn ciphertext_stealing_rejects_short_messages() {
+        let mut data = message();
+        assert!(!doEncryptCts(&CIPHER, "cbc-cs1", &IV, &mut data, 7));
+        assert!(!doEncryptCts(&CIPHER, "cbc", &IV, &mut data, 16));
+        assert_eq!(data, message());
+    }
+
+    #[test]
+    fn counter_carries_across_words() {
+        let mut counter = [0x00000001, 0xffffffff];
+        incrementCounter(&mut counter, 64);
+        assert_eq!(counter, [0x00000002, 0x00000000]);
+
+        // A full-width counter wraps around to zero
+        let mut counter = [0xffffffff; 4];
+        incrementCounter(&mut counter, 128);
+        assert_eq!(counter, [0; 4]);
+
+        let mut counter = [0xffffffff, 0xfffffffe, 0xffffffff, 0xffffffff];
+        incrementCounter(&mut counter, 128);
+        assert_eq!(counter, [0xffffffff, 0xffffffff, 0, 0]);
+    }
+
+    #[test]
+    fn counter_wraps_within_its_width() {
+        // 32-bit counter: the nonce words are never touched
+        let mut counter = [0x01234567, 0x89abcdef, 0xdeadbeef, 0xffffffff];
+        incrementCounter(&mut counter, 32);
+        assert_eq!(counter, [0x01234567, 0x89abcdef, 0xdeadbeef, 0]);
+
+        // 64-bit counter
+        let mut counter = [0x01234567, 0x89abcdef, 0xffffffff, 0xffffffff];
+        incrementCounter(&mut counter, 64);
+        assert_eq!(counter, [0x01234567, 0x89abcdef, 0, 0]);
+
+        // A width that is not a multiple of 32 bits
+        let mut counter = [0x01234567, 0x89abcdef, 0xdeadbeef, 0xfffff0ff];
+        incrementCounter(&mut counter, 8);
+        assert_eq!(counter, [0x01234567, 0x89abcdef, 0xdeadbeef, 0xfffff000]);
+        let mut counter = [0x01234567, 0x89abcdef, 0xdeadbeff, 0xffffffff];
+        incrementCounter(&mut counter, 36);
+        assert_eq!(counter, [0x01234567, 0x89abcdef, 0xdeadbef0, 0]);
+    }
+
+    #[test]
+    fn ctr_mode_names() {
+        assert_eq!(getCounterBits("ctr", 4), Some(128));
+        assert_eq!(getCounterBits("ctr-32", 4), Some(32));
+        assert_eq!(getCounterBits("ctr-64", 2), Some(64));
+        assert_eq!(getCounterBits("ctr-65", 2), None);
+        assert_eq!(getCounterBits("ctr-0", 4), None);
+        assert_eq!(getCounterBits("ctrgladman", 4), None);
+    }
+
+    // The keystream must continue across the wrap-around boundary instead of repeating
+    #[test]
+    fn ctr_stream_crosses_word_boundary() {
+        let iv = [0x00000000, 0xffffffff];
+        let mut data = vec![0; 4];
+        let chain = doEncrypt(&CIPHER, "ctr", 4, &iv, &mut data);
+        assert_eq!(chain, Some(vec![0x00000001, 0x00000001]));
+
+        let mut expected = vec![0x00000001, 0x00000000];
+        CIPHER.encryptBlock(&mut expected, 0);
+        assert_eq!(data[2..], expected[..]);
+
+        // With a 32-bit counter the same IV wraps back to a zero counter instead
+        let mut data = vec![0; 4];
+        let chain = doEncrypt(&CIPHER, "CTR-32", 4, &iv, &mut data);
+        assert_eq!(chain, Some(vec![0x00000000, 0x00000001]));
+        let mut expected = vec![0x00000000, 0x00000000];
+        CIPHER.encryptBlock(&mut expected, 0);
+        assert_eq!(data[2..], expected[..]);
+    }
+
+    #[test]
+    fn advancing_matches_repeated_increments() {
+        for (counterBits, start) in [(64, [0x00000001, 0xfffffff0]), (32, [0x00000001, 0xfffffff0]), (12, [0x00000001, 0xfffffff0])] {
+            let mut stepped = start;
+            for _ in 0..40 {
+                incrementCounter(&mut stepped, counterBits);
+            }
+            let mut advanced = start;
+            advanceCounter(&mut advanced, counterBits, 40);
+            assert_eq!(advanced, stepped, "{}", counterBits);
+        }
+
+        let mut counter = [0xffffffff; 4];
+        advanceCounter(&mut counter, 128, 2);
+        assert_eq!(counter, [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn ctr_seeks_to_any_offset() {
+        let plain = message();
+        let mut full = plain.clone();
+        doEncrypt(&CIPHER, "ctr", full.len(), &IV, &mut full);
+        let full = wordsToBytes(&full, 32);
+
+        for start in 0..32 {
+            for end in start..=32 {
+                let slice = bytesToWords(&wordsToBytes(&plain, 32)[start..end]);
+                let mut data = slice.clone();
+                assert!(doCryptCtrAt(&CIPHER, "ctr", &IV, start as u128, &mut data, end - start));
+                assert_eq!(wordsToBytes(&data, end - start), full[start..end], "{}..{}", start, end);
+            }
+        }
+
+        let mut data = plain.clone();
+        assert!(!doCryptCtrAt(&CIPHER, "cbc", &IV, 0, &mut data, 32));
+        assert_eq!(data, plain);
+    }
+
+    #[test]
+    fn ecb_returns_no_chaining_value() {
+        let mut data = message();
+        assert_eq!(doEncrypt(&CIPHER, "ECB", data.len(), &IV, &mut data), Some(Vec::new()));
+    }
+
+    // An unknown mode or a short IV must not pass the data through unencrypted
+    #[test]
+    fn rejects_invalid_modes_and_short_ivs() {
+        for mode in ["ctr-65", "ctr-0", "ctr-", "gcm", ""] {
+            let mut data = message();
+            assert_eq!(doEncrypt(&CIPHER, mode, data.len(), &IV, &mut data), None, "{}", mode);
+            assert_eq!(doDecrypt(&CIPHER, mode, data.len(), &IV, &mut data), None, "{}", mode);
+            assert_eq!(data, message(), "{}", mode);
+        }
+
+        for mode in ["cbc", "cfb", "ofb", "ctr", "ctr-32"] {
+            let mut data = message();
+            assert_eq!(doEncrypt(&CIPHER, mode, data.len(), &IV[..1], &mut data), None, "{}", mode);
+            assert_eq!(doDecrypt(&CIPHER, mode, data.len(), &[], &mut data), None, "{}", mode);
+            assert_eq!(data, message(), "{}", mode);
+        }
+
+        let mut data = message();
+        assert!(!doEncryptCts(&CIPHER, "cbc-cs1", &IV[..1], &mut data, 32));
+        assert!(!doCryptCtrAt(&CIPHER, "ctr", &[], 0, &mut data, 32));
+        assert_eq!(data, message());
+    }
+}
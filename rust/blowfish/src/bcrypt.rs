@@ -0,0 +1,143 @@
+use crate::blowfishEncrypt;
+use crate::utils::{getORIG_P, getORIG_S};
+use wasm_bindgen::prelude::*;
+
+// The 24 bytes encrypted 64 times by bcrypt, as big-endian words
+const CTEXT: [u32; 6] = [0x4f727068, 0x65616e42, 0x65686f6c, 0x64657253, 0x63727944, 0x6f756274];
+
+/// bcrypt on the Blowfish cipher: `salt` is 16 bytes and the work factor is `2^cost`, with `cost` in 4..=31.
+/// The password is NUL-terminated and only its first 72 bytes are used.
+///
+/// Returns the 23 bytes of hash in 6 big-endian words, the last byte being zero, or an empty vector if the
+/// salt or the cost is invalid.
+#[wasm_bindgen]
+pub fn bcrypt(password: &[u8], salt: &[u8], cost: u32) -> Vec<u32> {
+    if salt.len() != 16 || cost < 4 || cost > 31 {
+        return Vec::new();
+    }
+    let mut key = password.to_vec();
+    key.push(0);
+    key.truncate(72);
+
+    let (pbox, sbox) = eksBlowfishSetup(&key, salt, cost);
+    let mut ctext = CTEXT;
+    for _ in 0..64 {
+        for i in (0..6).step_by(2) {
+            let res = blowfishEncrypt(&pbox, &sbox, ctext[i], ctext[i + 1]);
+            ctext[i] = res[0];
+            ctext[i + 1] = res[1];
+        }
+    }
+
+    // Only 23 of the 24 bytes are part of the hash
+    ctext[5] &= 0xffffff00;
+    ctext.to_vec()
+}
+
+// The expensive key schedule: the salted expansion, then 2^cost rounds of expansion with the key and the salt
+fn eksBlowfishSetup(key: &[u8], salt: &[u8], cost: u32) -> ([u32; 18], Vec<[u32; 256]>) {
+    let mut pbox = getORIG_P();
+    let mut sbox = getORIG_S();
+
+    expandKey(&mut pbox, &mut sbox, key, salt);
+    for _ in 0..1_u64 << cost {
+        expandKey(&mut pbox, &mut sbox, key, &[]);
+        expandKey(&mut pbox, &mut sbox, salt, &[]);
+    }
+
+    (pbox, sbox)
+}
+
+// The Blowfish key schedule with the key taken as a cyclic stream of bytes, and the data encrypted at each
+// step XORed with the cyclic stream of salt bytes when there is a salt
+fn expandKey(pbox: &mut [u32; 18], sbox: &mut Vec<[u32; 256]>, key: &[u8], salt: &[u8]) {
+    let mut keyPosition = 0;
+    for i in 0..18 {
+        pbox[i] ^= streamToWord(key, &mut keyPosition);
+    }
+
+    let mut saltPosition = 0;
+    let mut block: [u32; 2] = [0, 0];
+    let mut nextBlock = |pbox: &[u32; 18], sbox: &Vec<[u32; 256]>| {
+        if !salt.is_empty() {
+            block[0] ^= streamToWord(salt, &mut saltPosition);
+            block[1] ^= streamToWord(salt, &mut saltPosition);
+        }
+        block = blowfishEncrypt(pbox, sbox, block[0], block[1]);
+        block
+    };
+
+    for i in (0..18).step_by(2) {
+        let res = nextBlock(pbox, sbox);
+        pbox[i] = res[0];
+        pbox[i + 1] = res[1];
+    }
+    for i in 0..4 {
+        for j in (0..256).step_by(2) {
+            let res = nextBlock(pbox, sbox);
+            sbox[i][j] = res[0];
+            sbox[i][j + 1] = res[1];
+        }
+    }
+}
+
+fn streamToWord(data: &[u8], position: &mut usize) -> u32 {
+    let mut word = 0;
+    for _ in 0..4 {
+        word = (word << 8) | data[*position] as u32;
+        *position = (*position + 1) % data.len();
+    }
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(words: Vec<u32>) -> String {
+        words.iter().map(|w| format!("{:08x}", w)).collect::<String>()[..46].to_string()
+    }
+
+    fn fromHex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    const LONG_PASSWORD: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+    #[test]
+    fn bcrypt_matches_known_hashes() {
+        // $2a$06$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.
+        assert_eq!(
+            hex(bcrypt(b"", &fromHex("144b3d691a7b4ecf39cf735c7fa7a79c"), 6)),
+            "557e94f34bf286e8719a26be94ac1e16d95ef9f819dee0"
+        );
+        // $2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW
+        assert_eq!(
+            hex(bcrypt(b"U*U", &fromHex("10410410410410410410410410410410"), 5)),
+            "1bb69143f9a8d304c8d23d99ab049a77a68e2ccc744206"
+        );
+        // $2y$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm
+        assert_eq!(
+            hex(bcrypt(b"password", &fromHex("71d79f8218a39259a7a29aabb2dbafc3"), 4)),
+            "8a31be1b1fb020771689ae1e3c6d14c73a62b5d04bd5fa"
+        );
+    }
+
+    #[test]
+    fn password_is_truncated_to_72_bytes() {
+        let salt = fromHex("71d79f8218a39259a7a29aabb2dbafc3");
+        // $2y$04$abcdefghijklmnopqrstuuRAip/W0RPQX4QKkqYqXE3GIXWH518Sm
+        assert_eq!(hex(bcrypt(LONG_PASSWORD, &salt, 4)), "4c292b058d9345267a48c9ac6ac646e48299609ef7f94a");
+
+        let mut longerPassword = LONG_PASSWORD.to_vec();
+        longerPassword.extend_from_slice(b"chars after 72 are ignored");
+        assert_eq!(bcrypt(&longerPassword, &salt, 4), bcrypt(LONG_PASSWORD, &salt, 4));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(bcrypt(b"password", &[0; 15], 4).is_empty());
+        assert!(bcrypt(b"password", &[0; 16], 3).is_empty());
+        assert!(bcrypt(b"password", &[0; 16], 32).is_empty());
+    }
+}
@@ -1,6 +1,8 @@
+mod bcrypt;
 mod utils;
 
 use crate::utils::{getORIG_P, getORIG_S};
+use blockmode::BlockCipher;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -68,172 +70,76 @@ pub fn blowfishInit(key: &[u32], keySize: usize) -> Vec<u32> {
 pub fn doEncrypt(
     mode: &str,
     nWordsReady: usize,
-    blockSize: usize,
     iv: &[u32],
     dataWords: &mut [u32],
     P: &[u32],
     S: &[u32],
-) -> Vec<u32> {
-    let mut pbox: [u32; 18] = [0; 18];
-    for i in 0..18 {
-        pbox[i] = P[i];
-    }
-    let mut sbox: Vec<[u32; 256]> = Vec::new();
-    for i in 0..4 {
-        let mut sboxi: [u32; 256] = [0; 256];
-        for j in 0..256 {
-            sboxi[j] = S[i * 256 + j];
-        }
-        sbox.push(sboxi);
-    }
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    encryptBlock(dataWords, offset, &pbox, &sbox);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    encryptBlock(dataWords, offset, &pbox, &sbox);
-                    offset += blockSize;
-                }
-            }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let mut keystream = prevBlock;
-                    encryptBlock(&mut keystream, 0, &pbox, &sbox);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    encryptBlock(&mut keystream, 0, &pbox, &sbox);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    encryptBlock(&mut keystream, 0, &pbox, &sbox);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
-        }
-    }
-
-    process
+) -> Option<Vec<u32>> {
+    let cipher = Blowfish::new(P, S);
+    blockmode::doEncrypt(&cipher, mode, nWordsReady, iv, dataWords)
 }
 
 #[wasm_bindgen]
 pub fn doDecrypt(
     mode: &str,
     nWordsReady: usize,
-    blockSize: usize,
     iv: &[u32],
     dataWords: &mut [u32],
     P: &[u32],
     S: &[u32],
-) -> Vec<u32> {
-    let mut pbox: [u32; 18] = [0; 18];
-    for i in 0..18 {
-        pbox[i] = P[i];
-    }
-    let mut sbox: Vec<[u32; 256]> = Vec::new();
-    for i in 0..4 {
-        let mut sboxi: [u32; 256] = [0; 256];
-        for j in 0..256 {
-            sboxi[j] = S[i * 256 + j];
+) -> Option<Vec<u32>> {
+    let cipher = Blowfish::new(P, S);
+    blockmode::doDecrypt(&cipher, mode, nWordsReady, iv, dataWords)
+}
+
+#[wasm_bindgen]
+pub fn doEncryptCts(mode: &str, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, P: &[u32], S: &[u32]) -> bool {
+    let cipher = Blowfish::new(P, S);
+    blockmode::doEncryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+#[wasm_bindgen]
+pub fn doDecryptCts(mode: &str, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, P: &[u32], S: &[u32]) -> bool {
+    let cipher = Blowfish::new(P, S);
+    blockmode::doDecryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+struct Blowfish {
+    pbox: [u32; 18],
+    sbox: Vec<[u32; 256]>,
+}
+
+impl Blowfish {
+    // Rebuild the boxes from the flattened context returned by `blowfishInit`
+    fn new(P: &[u32], S: &[u32]) -> Blowfish {
+        let mut pbox: [u32; 18] = [0; 18];
+        for i in 0..18 {
+            pbox[i] = P[i];
         }
-        sbox.push(sboxi);
-    }
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    decryptBlock(dataWords, offset, &pbox, &sbox);
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    decryptBlock(dataWords, offset, &pbox, &sbox);
-                    offset += blockSize;
-                }
+        let mut sbox: Vec<[u32; 256]> = Vec::new();
+        for i in 0..4 {
+            let mut sboxi: [u32; 256] = [0; 256];
+            for j in 0..256 {
+                sboxi[j] = S[i * 256 + j];
             }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    let keystream = &mut prevBlock;
-                    encryptBlock(keystream, 0, &pbox, &sbox);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    encryptBlock(&mut keystream, 0, &pbox, &sbox);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    encryptBlock(&mut keystream, 0, &pbox, &sbox);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
+            sbox.push(sboxi);
         }
-    }
 
-    process
+        Blowfish { pbox, sbox }
+    }
 }
 
-fn xorBlock(blockSize: usize, block: Vec<u32>, words: &mut [u32], offset: usize) {
-    // XOR blocks
-    for i in 0..blockSize {
-        words[offset + i] ^= &block[i];
+impl BlockCipher for Blowfish {
+    fn blockSize(&self) -> usize {
+        2
+    }
+
+    fn encryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        encryptBlock(dataWords, offset, &self.pbox, &self.sbox);
+    }
+
+    fn decryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        decryptBlock(dataWords, offset, &self.pbox, &self.sbox);
     }
 }
 
@@ -316,3 +222,43 @@ fn F(sbox: &Vec<[u32; 256]>, x: u32) -> u32 {
 
     y
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init(key: &[u32]) -> (Vec<u32>, Vec<u32>) {
+        let ctx = blowfishInit(key, key.len());
+        (ctx[0..18].to_vec(), ctx[18..].to_vec())
+    }
+
+    #[test]
+    fn ecb_matches_known_vectors() {
+        // Eric Young's Blowfish test vectors
+        let (P, S) = init(&[0x00000000, 0x00000000]);
+        let mut data = [0x00000000, 0x00000000];
+        doEncrypt("ecb", 2, &[], &mut data, &P, &S);
+        assert_eq!(data, [0x4ef99745, 0x6198dd78]);
+
+        let (P, S) = init(&[0xffffffff, 0xffffffff]);
+        let mut data = [0xffffffff, 0xffffffff];
+        doEncrypt("ecb", 2, &[], &mut data, &P, &S);
+        assert_eq!(data, [0x51866fd5, 0xb85ecb8a]);
+        doDecrypt("ecb", 2, &[], &mut data, &P, &S);
+        assert_eq!(data, [0xffffffff, 0xffffffff]);
+    }
+
+    #[test]
+    fn chained_modes_round_trip() {
+        let (P, S) = init(&[0x01234567, 0x89abcdef, 0xf0e1d2c3]);
+        let iv = [0xfedcba98, 0x76543210];
+        for mode in ["cbc", "cfb", "ofb", "ctr"] {
+            let plain = [0x37363534, 0x33323120, 0x4e6f7720, 0x69732074];
+            let mut data = plain;
+            doEncrypt(mode, 4, &iv, &mut data, &P, &S);
+            assert_ne!(data, plain, "{}", mode);
+            doDecrypt(mode, 4, &iv, &mut data, &P, &S);
+            assert_eq!(data, plain, "{}", mode);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod utils;
 
 use crate::utils::{getInvSubKeys, getSboxMask, getSboxP, getSubKeys};
+use blockmode::BlockCipher;
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
@@ -8,331 +9,177 @@ use wasm_bindgen::prelude::*;
 pub fn doEncrypt(
     mode: &str,
     nWordsReady: usize,
-    blockSize: usize,
     iv: &[u32],
     dataWords: &mut [u32],
     keyWords: &[u32],
-) -> Vec<u32> {
-    let subKeys = getSubKeys(keyWords);
+) -> Option<Vec<u32>> {
     let SBOX_P = getSboxP();
     let SBOX_MASK = getSboxMask();
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    doCryptBlock(dataWords, offset, &subKeys, &SBOX_P, &SBOX_MASK);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    doCryptBlock(dataWords, offset, &subKeys, &SBOX_P, &SBOX_MASK);
-                    offset += blockSize;
-                }
-            }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let mut keystream = prevBlock;
-                    doCryptBlock(&mut keystream, 0, &subKeys, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    doCryptBlock(&mut keystream, 0, &subKeys, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    doCryptBlock(&mut keystream, 0, &subKeys, &SBOX_P, &SBOX_MASK);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
-        }
-    }
-
-    process
+    let cipher = Des::new(keyWords, &SBOX_P, &SBOX_MASK);
+    blockmode::doEncrypt(&cipher, mode, nWordsReady, iv, dataWords)
 }
 
 #[wasm_bindgen]
 pub fn doDecrypt(
     mode: &str,
     nWordsReady: usize,
-    blockSize: usize,
     iv: &[u32],
     dataWords: &mut [u32],
     keyWords: &[u32],
-) -> Vec<u32> {
-    let subKeys = getSubKeys(keyWords);
-    let invSubKeys = getInvSubKeys(&subKeys);
+) -> Option<Vec<u32>> {
     let SBOX_P = getSboxP();
     let SBOX_MASK = getSboxMask();
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    doCryptBlock(dataWords, offset, &invSubKeys, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    doCryptBlock(dataWords, offset, &invSubKeys, &SBOX_P, &SBOX_MASK);
-                    offset += blockSize;
-                }
-            }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    let keystream = &mut prevBlock;
-                    doCryptBlock(keystream, 0, &subKeys, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    doCryptBlock(&mut keystream, 0, &subKeys, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    doCryptBlock(&mut keystream, 0, &subKeys, &SBOX_P, &SBOX_MASK);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
-        }
-    }
-
-    process
+    let cipher = Des::new(keyWords, &SBOX_P, &SBOX_MASK);
+    blockmode::doDecrypt(&cipher, mode, nWordsReady, iv, dataWords)
 }
 
 #[wasm_bindgen]
 pub fn tripleEncrypt(
     mode: &str,
     nWordsReady: usize,
-    blockSize: usize,
     iv: &[u32],
     dataWords: &mut [u32],
     keyWords1: &[u32],
     keyWords2: &[u32],
     keyWords3: &[u32],
-) -> Vec<u32> {
-    let subKeys1 = getSubKeys(keyWords1);
-    let subKeys2 = getSubKeys(keyWords2);
-    let subKeys3 = getSubKeys(keyWords3);
-    let invSubKeys2 = getInvSubKeys(&subKeys2);
+) -> Option<Vec<u32>> {
     let SBOX_P = getSboxP();
     let SBOX_MASK = getSboxMask();
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    doCryptBlock(dataWords, offset, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    doCryptBlock(dataWords, offset, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    offset += blockSize;
-                }
-            }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let mut keystream = prevBlock;
-                    doCryptBlock(&mut keystream, 0, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = dataWords[offset..offset + blockSize].to_vec();
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    doCryptBlock(&mut keystream, 0, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    doCryptBlock(&mut keystream, 0, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
-        }
-    }
-
-    process
+    let cipher = TripleDes {
+        des1: Des::new(keyWords1, &SBOX_P, &SBOX_MASK),
+        des2: Des::new(keyWords2, &SBOX_P, &SBOX_MASK),
+        des3: Des::new(keyWords3, &SBOX_P, &SBOX_MASK),
+    };
+    blockmode::doEncrypt(&cipher, mode, nWordsReady, iv, dataWords)
 }
 
 #[wasm_bindgen]
 pub fn tripleDecrypt(
     mode: &str,
     nWordsReady: usize,
-    blockSize: usize,
     iv: &[u32],
     dataWords: &mut [u32],
     keyWords1: &[u32],
     keyWords2: &[u32],
     keyWords3: &[u32],
-) -> Vec<u32> {
-    let subKeys1 = getSubKeys(keyWords1);
-    let subKeys2 = getSubKeys(keyWords2);
-    let subKeys3 = getSubKeys(keyWords3);
-    let invSubKeys1 = getInvSubKeys(&subKeys1);
-    let invSubKeys2 = getInvSubKeys(&subKeys2);
-    let invSubKeys3 = getInvSubKeys(&subKeys3);
+) -> Option<Vec<u32>> {
     let SBOX_P = getSboxP();
     let SBOX_MASK = getSboxMask();
-    let mut process: Vec<u32> = Vec::new();
-    if nWordsReady > 0 {
-        let mut offset: usize = 0;
-        match mode.to_lowercase().as_str() {
-            "cbc" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    doCryptBlock(dataWords, offset, &invSubKeys3, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &subKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &invSubKeys1, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, prevBlock, dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ecb" => {
-                while offset < nWordsReady {
-                    doCryptBlock(dataWords, offset, &invSubKeys3, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &subKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(dataWords, offset, &invSubKeys1, &SBOX_P, &SBOX_MASK);
-                    offset += blockSize;
-                }
-            }
-            "cfb" => {
-                let mut prevBlock = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    let thisBlock = dataWords[offset..offset + blockSize].to_vec();
-                    let keystream = &mut prevBlock;
-                    doCryptBlock(keystream, 0, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(keystream, 0, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(keystream, 0, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    prevBlock = thisBlock;
-                    offset += blockSize;
-                }
-                process = prevBlock;
-            }
-            "ofb" => {
-                let mut keystream = iv[0..blockSize].to_vec();
-                while offset < nWordsReady {
-                    doCryptBlock(&mut keystream, 0, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = keystream;
-            }
-            "ctr" => {
-                let mut counter = iv[0..blockSize].to_vec();
-                let mut keystream;
-                while offset < nWordsReady {
-                    keystream = counter.clone();
-                    doCryptBlock(&mut keystream, 0, &subKeys1, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &invSubKeys2, &SBOX_P, &SBOX_MASK);
-                    doCryptBlock(&mut keystream, 0, &subKeys3, &SBOX_P, &SBOX_MASK);
-                    // Increment counter
-                    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
-                    xorBlock(blockSize, keystream.to_owned(), dataWords, offset);
-                    offset += blockSize;
-                }
-                process = counter;
-            }
-            _ => {}
-        }
+    let cipher = TripleDes {
+        des1: Des::new(keyWords1, &SBOX_P, &SBOX_MASK),
+        des2: Des::new(keyWords2, &SBOX_P, &SBOX_MASK),
+        des3: Des::new(keyWords3, &SBOX_P, &SBOX_MASK),
+    };
+    blockmode::doDecrypt(&cipher, mode, nWordsReady, iv, dataWords)
+}
+
+#[wasm_bindgen]
+pub fn doEncryptCts(mode: &str, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, keyWords: &[u32]) -> bool {
+    let SBOX_P = getSboxP();
+    let SBOX_MASK = getSboxMask();
+    let cipher = Des::new(keyWords, &SBOX_P, &SBOX_MASK);
+    blockmode::doEncryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+#[wasm_bindgen]
+pub fn doDecryptCts(mode: &str, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, keyWords: &[u32]) -> bool {
+    let SBOX_P = getSboxP();
+    let SBOX_MASK = getSboxMask();
+    let cipher = Des::new(keyWords, &SBOX_P, &SBOX_MASK);
+    blockmode::doDecryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+#[wasm_bindgen]
+pub fn tripleEncryptCts(
+    mode: &str,
+    iv: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    keyWords1: &[u32],
+    keyWords2: &[u32],
+    keyWords3: &[u32],
+) -> bool {
+    let SBOX_P = getSboxP();
+    let SBOX_MASK = getSboxMask();
+    let cipher = TripleDes {
+        des1: Des::new(keyWords1, &SBOX_P, &SBOX_MASK),
+        des2: Des::new(keyWords2, &SBOX_P, &SBOX_MASK),
+        des3: Des::new(keyWords3, &SBOX_P, &SBOX_MASK),
+    };
+    blockmode::doEncryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+#[wasm_bindgen]
+pub fn tripleDecryptCts(
+    mode: &str,
+    iv: &[u32],
+    dataWords: &mut [u32],
+    dataSigBytes: usize,
+    keyWords1: &[u32],
+    keyWords2: &[u32],
+    keyWords3: &[u32],
+) -> bool {
+    let SBOX_P = getSboxP();
+    let SBOX_MASK = getSboxMask();
+    let cipher = TripleDes {
+        des1: Des::new(keyWords1, &SBOX_P, &SBOX_MASK),
+        des2: Des::new(keyWords2, &SBOX_P, &SBOX_MASK),
+        des3: Des::new(keyWords3, &SBOX_P, &SBOX_MASK),
+    };
+    blockmode::doDecryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+struct Des<'a> {
+    subKeys: Vec<[u32; 8]>,
+    invSubKeys: Vec<[u32; 8]>,
+    SBOX_P: &'a Vec<HashMap<u32, u32>>,
+    SBOX_MASK: &'a [u32; 8],
+}
+
+impl<'a> Des<'a> {
+    fn new(keyWords: &[u32], SBOX_P: &'a Vec<HashMap<u32, u32>>, SBOX_MASK: &'a [u32; 8]) -> Des<'a> {
+        let subKeys = getSubKeys(keyWords);
+        let invSubKeys = getInvSubKeys(&subKeys);
+        Des { subKeys, invSubKeys, SBOX_P, SBOX_MASK }
+    }
+}
+
+impl BlockCipher for Des<'_> {
+    fn blockSize(&self) -> usize {
+        2
+    }
+
+    fn encryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        doCryptBlock(dataWords, offset, &self.subKeys, self.SBOX_P, self.SBOX_MASK);
     }
 
-    process
+    fn decryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        doCryptBlock(dataWords, offset, &self.invSubKeys, self.SBOX_P, self.SBOX_MASK);
+    }
+}
+
+// EDE: encrypt with key 1, decrypt with key 2, encrypt with key 3
+struct TripleDes<'a> {
+    des1: Des<'a>,
+    des2: Des<'a>,
+    des3: Des<'a>,
 }
 
-fn xorBlock(blockSize: usize, block: Vec<u32>, words: &mut [u32], offset: usize) {
-    // XOR blocks
-    for i in 0..blockSize {
-        words[offset + i] ^= &block[i];
+impl BlockCipher for TripleDes<'_> {
+    fn blockSize(&self) -> usize {
+        2
+    }
+
+    fn encryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        self.des1.encryptBlock(dataWords, offset);
+        self.des2.decryptBlock(dataWords, offset);
+        self.des3.encryptBlock(dataWords, offset);
+    }
+
+    fn decryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        self.des3.decryptBlock(dataWords, offset);
+        self.des2.encryptBlock(dataWords, offset);
+        self.des1.decryptBlock(dataWords, offset);
     }
 }
 
@@ -397,4 +244,35 @@ fn exchangeRL(offset: u32, mask: u32, lBlock: &mut u32, rBlock: &mut u32) {
     let t = ((*rBlock >> offset) ^ *lBlock) & mask;
     *lBlock ^= t;
     *rBlock ^= t << offset;
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u32; 2] = [0x01234567, 0x89abcdef];
+    const IV: [u32; 2] = [0x12345678, 0x90abcdef];
+
+    #[test]
+    fn ecb_matches_known_vector() {
+        // "Now is t" under the classic 0123456789abcdef test key
+        let mut data = [0x4e6f7720, 0x69732074];
+        doEncrypt("ecb", 2, &IV, &mut data, &KEY);
+        assert_eq!(data, [0x3fa40e8a, 0x984d4815]);
+        doDecrypt("ecb", 2, &IV, &mut data, &KEY);
+        assert_eq!(data, [0x4e6f7720, 0x69732074]);
+    }
+
+    #[test]
+    fn triple_des_with_one_key_matches_des() {
+        for mode in ["cbc", "ecb", "cfb", "ofb", "ctr"] {
+            let mut single = [0x4e6f7720, 0x69732074, 0x68652074, 0x696d6520];
+            let mut triple = single;
+            doEncrypt(mode, 4, &IV, &mut single, &KEY);
+            tripleEncrypt(mode, 4, &IV, &mut triple, &KEY, &KEY, &KEY);
+            assert_eq!(single, triple, "{}", mode);
+
+            tripleDecrypt(mode, 4, &IV, &mut triple, &KEY, &KEY, &KEY);
+            assert_eq!(triple, [0x4e6f7720, 0x69732074, 0x68652074, 0x696d6520], "{}", mode);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! The serialized context of a hash in progress, so that hashing can be stopped part way
+//! through a message and resumed later, possibly in another session.
+//!
+//! A context is a sequence of 32-bit words:
+//!
+//! | word      | content                                                    |
+//! |-----------|------------------------------------------------------------|
+//! | 0         | `MAGIC`                                                    |
+//! | 1         | format `VERSION`                                           |
+//! | 2         | algorithm id, one of the constants below                   |
+//! | 3, 4      | total message length in bytes, as (high, low)              |
+//! | 5         | number of parameter words `p`                              |
+//! | 6         | number of state words `n`                                  |
+//! | 7         | number of buffered bytes `b`                               |
+//! | 8..8 + p  | the configuration the state was created with               |
+//! | then      | the `n` state words of the hash crate                      |
+//! | then      | `ceil(b / 4)` buffered words, big-endian, zero past byte `b` |
+//!
+//! The parameters cover configuration that changes the state but not the algorithm id,
+//! such as the rate of the legacy SHA3 hasher or the output length of BLAKE2.
+
+/// "CJWH"
+pub const MAGIC: u32 = 0x434a5748;
+pub const VERSION: u32 = 1;
+
+const HEADER_WORDS: usize = 8;
+
+pub const MD5: u32 = 1;
+pub const SHA1: u32 = 2;
+pub const SHA224: u32 = 3;
+pub const SHA256: u32 = 4;
+pub const SHA384: u32 = 5;
+pub const SHA512: u32 = 6;
+pub const SHA512_224: u32 = 7;
+pub const SHA512_256: u32 = 8;
+pub const SHA3: u32 = 9;
+pub const SHA3_224: u32 = 10;
+pub const SHA3_256: u32 = 11;
+pub const SHA3_384: u32 = 12;
+pub const SHA3_512: u32 = 13;
+pub const KECCAK256: u32 = 14;
+pub const SHAKE128: u32 = 15;
+pub const SHAKE256: u32 = 16;
+pub const CSHAKE128: u32 = 17;
+pub const CSHAKE256: u32 = 18;
+pub const KMAC128: u32 = 19;
+pub const KMAC256: u32 = 20;
+pub const RIPEMD160: u32 = 21;
+pub const BLAKE2B: u32 = 22;
+pub const BLAKE2S: u32 = 23;
+pub const BLAKE3: u32 = 24;
+pub const SM3: u32 = 25;
+pub const WHIRLPOOL: u32 = 26;
+pub const STREEBOG256: u32 = 27;
+pub const STREEBOG512: u32 = 28;
+
+/// The algorithms a hash crate implements and the size of its state.
+pub struct ContextFormat {
+    pub algorithms: &'static [u32],
+    pub stateWords: usize,
+}
+
+impl ContextFormat {
+    /// Serializes the context. Returns an empty vector if the algorithm is not one of this crate's,
+    /// or if the state does not have the expected size.
+    pub fn export(
+        &self,
+        algorithmId: u32,
+        parameters: &[u32],
+        state: &[u32],
+        bufferWords: &[u32],
+        bufferSigBytes: u32,
+        nBytesTotalHigh: u32,
+        nBytesTotalLow: u32,
+    ) -> Vec<u32> {
+        if !self.algorithms.contains(&algorithmId) || state.len() != self.stateWords {
+            return Vec::new();
+        }
+
+        let mut context = vec![
+            MAGIC,
+            VERSION,
+            algorithmId,
+            nBytesTotalHigh,
+            nBytesTotalLow,
+            parameters.len() as u32,
+            state.len() as u32,
+            bufferSigBytes,
+        ];
+        context.extend_from_slice(parameters);
+        context.extend_from_slice(state);
+
+        let nBufferWords = (bufferSigBytes as usize + 3) / 4;
+        context.extend((0..nBufferWords).map(|i| *bufferWords.get(i).unwrap_or(&0)));
+        if bufferSigBytes % 4 != 0 {
+            context[HEADER_WORDS + parameters.len() + state.len() + nBufferWords - 1] &= 0xffffffff << (32 - (bufferSigBytes % 4) * 8);
+        }
+
+        context
+    }
+
+    /// Checks a serialized context against the algorithm and parameters it is imported with and returns
+    /// it from the total length on, without the parameters: `[nBytesTotalHigh, nBytesTotalLow, n, b, state..., buffer...]`.
+    /// Returns an empty vector if the context is malformed, of another version, of another algorithm
+    /// or was created with other parameters.
+    pub fn import(&self, algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+        if !self.algorithms.contains(&algorithmId) || context.len() < HEADER_WORDS {
+            return Vec::new();
+        }
+        if context[0] != MAGIC || context[1] != VERSION || context[2] != algorithmId {
+            return Vec::new();
+        }
+
+        let nBytesTotal = (context[3] as u64) << 32 | context[4] as u64;
+        let nParameterWords = context[5] as usize;
+        let nStateWords = context[6] as usize;
+        let bufferSigBytes = context[7];
+        if nStateWords != self.stateWords || (bufferSigBytes as u64) > nBytesTotal {
+            return Vec::new();
+        }
+        if context.len() != HEADER_WORDS + nParameterWords + nStateWords + (bufferSigBytes as usize + 3) / 4 {
+            return Vec::new();
+        }
+        if context[HEADER_WORDS..HEADER_WORDS + nParameterWords] != *parameters {
+            return Vec::new();
+        }
+
+        let mut fields = vec![context[3], context[4], context[6], context[7]];
+        fields.extend_from_slice(&context[HEADER_WORDS + nParameterWords..]);
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMAT: ContextFormat = ContextFormat { algorithms: &[SHA224, SHA256], stateWords: 2 };
+
+    #[test]
+    fn export_clears_bytes_past_the_buffer() {
+        let context = FORMAT.export(SHA256, &[], &[1, 2], &[0x61626364, 0x65666768], 5, 0, 69);
+        assert_eq!(context, vec![MAGIC, VERSION, SHA256, 0, 69, 0, 2, 5, 1, 2, 0x61626364, 0x65000000]);
+    }
+
+    #[test]
+    fn import_returns_the_exported_fields() {
+        let context = FORMAT.export(SHA224, &[], &[1, 2], &[0x61626364], 3, 1, 3);
+        assert_eq!(FORMAT.import(SHA224, &[], &context), vec![1, 3, 2, 3, 1, 2, 0x61626300]);
+    }
+
+    #[test]
+    fn rejects_other_algorithms() {
+        assert!(FORMAT.export(SHA1, &[], &[1, 2], &[], 0, 0, 0).is_empty());
+
+        let context = FORMAT.export(SHA224, &[], &[1, 2], &[], 0, 0, 64);
+        assert!(FORMAT.import(SHA256, &[], &context).is_empty());
+        assert!(FORMAT.import(SHA1, &[], &context).is_empty());
+    }
+
+    #[test]
+    fn rejects_other_parameters() {
+        let context = FORMAT.export(SHA256, &[32, 0], &[1, 2], &[0x61626364], 4, 0, 4);
+        assert_eq!(context[8..10], [32, 0]);
+        assert_eq!(FORMAT.import(SHA256, &[32, 0], &context), vec![0, 4, 2, 4, 1, 2, 0x61626364]);
+
+        assert!(FORMAT.import(SHA256, &[32, 1], &context).is_empty());
+        assert!(FORMAT.import(SHA256, &[32], &context).is_empty());
+        assert!(FORMAT.import(SHA256, &[], &context).is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_contexts() {
+        let context = FORMAT.export(SHA256, &[], &[1, 2], &[0x61626364], 4, 0, 4);
+        assert!(!FORMAT.import(SHA256, &[], &context).is_empty());
+
+        assert!(FORMAT.import(SHA256, &[], &context[..context.len() - 1]).is_empty());
+        assert!(FORMAT.import(SHA256, &[], &[]).is_empty());
+        for (index, value) in [(0, 0), (1, 2), (5, 1), (6, 3), (7, 5)].iter() {
+            let mut modified = context.clone();
+            modified[*index] = *value;
+            assert!(FORMAT.import(SHA256, &[], &modified).is_empty());
+        }
+
+        // More buffered bytes than were ever hashed
+        let mut modified = context.clone();
+        modified[4] = 3;
+        assert!(FORMAT.import(SHA256, &[], &modified).is_empty());
+    }
+
+    #[test]
+    fn rejects_wrong_state_size() {
+        assert!(FORMAT.export(SHA256, &[], &[1, 2, 3], &[], 0, 0, 0).is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+/// HMAC (RFC 2104) over a hash taking a whole message and returning its digest bytes.
+/// `blockBytes` is the block size of the hash in bytes, the rate for SHA-3.
+pub fn hmac<F: Fn(&[u8]) -> Vec<u8>>(hash: F, blockBytes: usize, key: &[u8], message: &[u8]) -> Vec<u8> {
+    // Keys longer than a block are hashed first, then every key is zero-padded to a block
+    let mut paddedKey = if key.len() > blockBytes { hash(key) } else { key.to_vec() };
+    paddedKey.resize(blockBytes, 0);
+
+    let mut inner: Vec<u8> = paddedKey.iter().map(|b| b ^ 0x36).collect();
+    inner.extend_from_slice(message);
+
+    let mut outer: Vec<u8> = paddedKey.iter().map(|b| b ^ 0x5c).collect();
+    outer.extend_from_slice(&hash(&inner));
+
+    hash(&outer)
+}
+
+/// PBKDF2 (RFC 8018) with HMAC over `hash`: derives `dkLen` bytes from `password` and `salt`.
+/// Fewer than one iteration count as one, as in the JavaScript implementation.
+pub fn pbkdf2<F: Fn(&[u8]) -> Vec<u8>>(
+    hash: F,
+    blockBytes: usize,
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    dkLen: usize,
+) -> Vec<u8> {
+    // A long password would otherwise be hashed again in every iteration
+    let key = if password.len() > blockBytes { hash(password) } else { password.to_vec() };
+
+    let mut derivedKey: Vec<u8> = Vec::with_capacity(dkLen);
+    let mut blockIndex: u32 = 1;
+    while derivedKey.len() < dkLen {
+        let mut message = salt.to_vec();
+        message.extend_from_slice(&blockIndex.to_be_bytes());
+
+        // XOR the chain of HMACs into the block
+        let mut intermediate = hmac(&hash, blockBytes, &key, &message);
+        let mut block = intermediate.clone();
+        for _ in 1..iterations {
+            intermediate = hmac(&hash, blockBytes, &key, &intermediate);
+            block.iter_mut().zip(&intermediate).for_each(|(b, i)| *b ^= i);
+        }
+
+        derivedKey.extend_from_slice(&block);
+        blockIndex += 1;
+    }
+    derivedKey.truncate(dkLen);
+
+    derivedKey
+}
+
+/// HKDF-Extract (RFC 5869): the pseudorandom key is the HMAC of `ikm` keyed with `salt`.
+/// An empty salt needs no special case, since HMAC zero-pads it like the string of zeros it stands for.
+pub fn hkdfExtract<F: Fn(&[u8]) -> Vec<u8>>(hash: F, blockBytes: usize, salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+    hmac(hash, blockBytes, salt, ikm)
+}
+
+/// HKDF-Expand (RFC 5869): `length` bytes of chained HMACs keyed with `prk` over `info`.
+/// Returns an empty vector if `length` is 0 or more than 255 digests.
+pub fn hkdfExpand<F: Fn(&[u8]) -> Vec<u8>>(hash: F, blockBytes: usize, prk: &[u8], info: &[u8], length: usize) -> Vec<u8> {
+    let mut okm: Vec<u8> = Vec::with_capacity(length);
+    let mut block: Vec<u8> = Vec::new();
+    let mut blockIndex: usize = 1;
+    while okm.len() < length {
+        // T(i) = HMAC(PRK, T(i - 1) | info | i)
+        let mut message = block;
+        message.extend_from_slice(info);
+        message.push(blockIndex as u8);
+        block = hmac(&hash, blockBytes, prk, &message);
+
+        if length > 255 * block.len() {
+            return Vec::new();
+        }
+        okm.extend_from_slice(&block);
+        blockIndex += 1;
+    }
+    okm.truncate(length);
+
+    okm
+}
+
+/// HKDF (RFC 5869) with HMAC over `hash`: extracts a key from `ikm` and `salt`, and expands it to `length` bytes.
+pub fn hkdf<F: Fn(&[u8]) -> Vec<u8>>(
+    hash: F,
+    blockBytes: usize,
+    ikm: &[u8],
+    salt: &[u8],
+    info: &[u8],
+    length: usize,
+) -> Vec<u8> {
+    let prk = hkdfExtract(&hash, blockBytes, salt, ikm);
+    hkdfExpand(&hash, blockBytes, &prk, info, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Not a hash: keeps the first and last byte and the length, which is enough to see what was hashed
+    fn probe(message: &[u8]) -> Vec<u8> {
+        vec![message[0], *message.last().unwrap(), message.len() as u8]
+    }
+
+    #[test]
+    fn pads_short_keys() {
+        // The inner input is the 8-byte padded key and the 7-byte message, so the inner digest ends
+        // with 15; the outer input is the padded key and that digest
+        assert_eq!(hmac(probe, 8, &[1, 1, 1, 1], b"message"), [0x01 ^ 0x5c, 15, 8 + 3]);
+    }
+
+    #[test]
+    fn hashes_long_keys() {
+        let key: Vec<u8> = (1..=9).collect();
+        // The key is replaced by its 3-byte digest [1, 9, 9]
+        assert_eq!(hmac(probe, 8, &key, b"m"), [1 ^ 0x5c, 9, 8 + 3]);
+    }
+
+    #[test]
+    fn pbkdf2_concatenates_and_truncates_blocks() {
+        // Two 3-byte blocks for 5 bytes; the inner input is the padded key, the salt and the 4-byte block index
+        let derivedKey = pbkdf2(probe, 8, b"key", b"salt", 1, 5);
+        assert_eq!(derivedKey, [b'k' ^ 0x5c, 8 + 4 + 4, 8 + 3, b'k' ^ 0x5c, 8 + 4 + 4]);
+        assert_eq!(pbkdf2(probe, 8, b"key", b"salt", 0, 5), derivedKey);
+    }
+
+    #[test]
+    fn hkdf_chains_and_truncates_blocks() {
+        // The first inner input is the padded key, the info and the block index, the second one starts
+        // with the first 3-byte block
+        let okm = hkdfExpand(probe, 8, b"prk", b"info", 5);
+        assert_eq!(okm, [b'p' ^ 0x5c, 8 + 4 + 1, 8 + 3, b'p' ^ 0x5c, 8 + 3 + 4 + 1]);
+
+        // The salt is the HMAC key
+        let prk = hkdfExtract(probe, 8, b"salt", b"ikm");
+        assert_eq!(prk, [b's' ^ 0x5c, 8 + 3, 8 + 3]);
+        assert_eq!(hkdf(probe, 8, b"ikm", b"salt", b"info", 5), hkdfExpand(probe, 8, &prk, b"info", 5));
+    }
+
+    #[test]
+    fn hkdf_rejects_out_of_range_lengths() {
+        assert_eq!(hkdfExpand(probe, 8, b"prk", b"info", 255 * 3).len(), 255 * 3);
+        assert!(hkdfExpand(probe, 8, b"prk", b"info", 255 * 3 + 1).is_empty());
+        assert!(hkdfExpand(probe, 8, b"prk", b"info", 0).is_empty());
+    }
+}
@@ -1,7 +1,6 @@
 mod utils;
 
-use std::ptr::null;
-use std::cmp;
+use hashcontext::ContextFormat;
 use wasm_bindgen::prelude::*;
 use utils::*;
 
@@ -144,28 +143,182 @@ fn md5DoProcessBlock(originalM: &[u32], offsetU32: u32, hashWords: &mut [u32]) {
     c = II(c, d, a, b, M_offset_2, 15, T[62]);
     b = II(b, c, d, a, M_offset_9, 21, T[63]);
 
-    hashWords[0] = (hashWords[0] + a) | 0;
-    hashWords[1] = (hashWords[1] + b) | 0;
-    hashWords[2] = (hashWords[2] + c) | 0;
-    hashWords[3] = (hashWords[3] + d) | 0;
+    hashWords[0] = hashWords[0].wrapping_add(a);
+    hashWords[1] = hashWords[1].wrapping_add(b);
+    hashWords[2] = hashWords[2].wrapping_add(c);
+    hashWords[3] = hashWords[3].wrapping_add(d);
 }
 
 fn FF(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, t: u32) -> u32 {
-    let n: u32 = a + ((b & c) | (!b & d)) + x + t;
-    ((n << s) | (n >> (32 - s))) + b
+    let n: u32 = a.wrapping_add((b & c) | (!b & d)).wrapping_add(x).wrapping_add(t);
+    ((n << s) | (n >> (32 - s))).wrapping_add(b)
 }
 
 fn GG(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, t: u32) -> u32 {
-    let n: u32 = a + ((b & d) | (c & !d)) + x + t;
-    ((n << s) | (n >> (32 - s))) + b
+    let n: u32 = a.wrapping_add((b & d) | (c & !d)).wrapping_add(x).wrapping_add(t);
+    ((n << s) | (n >> (32 - s))).wrapping_add(b)
 }
 
 fn HH(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, t: u32) -> u32 {
-    let n: u32 = a + (b ^ c ^ d) + x + t;
-    ((n << s) | (n >> (32 - s))) + b
+    let n: u32 = a.wrapping_add(b ^ c ^ d).wrapping_add(x).wrapping_add(t);
+    ((n << s) | (n >> (32 - s))).wrapping_add(b)
 }
 
 fn II(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, t: u32) -> u32 {
-    let n: u32 = a + (c ^ (b | !d)) + x + t;
-    ((n << s) | (n >> (32 - s))) + b
+    let n: u32 = a.wrapping_add(c ^ (b | !d)).wrapping_add(x).wrapping_add(t);
+    ((n << s) | (n >> (32 - s))).wrapping_add(b)
+}
+
+/// Hashes a whole message in one call, padding included, and returns the digest.
+#[wasm_bindgen]
+pub fn hashBytes(message: &[u8]) -> Vec<u32> {
+    let padded = pad(message);
+    let mut hashWords: Vec<u32> = vec![0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+    md5Process(1, &mut hashWords, &bytesToWords(&padded), padded.len() as u32, 16, 0);
+
+    // Swap endian
+    hashWords.iter().map(|h| h.swap_bytes()).collect()
+}
+
+/// HMAC-MD5 of a whole message in one call.
+#[wasm_bindgen]
+pub fn hmacBytes(key: &[u8], message: &[u8]) -> Vec<u32> {
+    bytesToWords(&hmac::hmac(|m| wordsToBytes(&hashBytes(m), 16), 64, key, message))
+}
+
+/// PBKDF2-HMAC-MD5 in one call: runs all the iterations and returns `dkLen` bytes of derived key.
+#[wasm_bindgen]
+pub fn pbkdf2Bytes(password: &[u8], salt: &[u8], iterations: u32, dkLen: u32) -> Vec<u32> {
+    let derivedKey = hmac::pbkdf2(|m| wordsToBytes(&hashBytes(m), 16), 64, password, salt, iterations, dkLen as usize);
+
+    bytesToWords(&derivedKey)
+}
+
+/// HKDF-HMAC-MD5 in one call: extracts a key from `ikm` and `salt` and expands it with `info` to `length`
+/// bytes, or returns an empty vector if `length` is more than 255 digests.
+#[wasm_bindgen]
+pub fn hkdfBytes(ikm: &[u8], salt: &[u8], info: &[u8], length: u32) -> Vec<u32> {
+    let okm = hmac::hkdf(|m| wordsToBytes(&hashBytes(m), 16), 64, ikm, salt, info, length as usize);
+
+    bytesToWords(&okm)
+}
+
+// Append the 1 bit, then zeros up to 56 bytes mod 64, then the 64-bit little-endian length in bits
+fn pad(message: &[u8]) -> Vec<u8> {
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&(message.len() as u64 * 8).to_le_bytes());
+
+    padded
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+// Pack bytes into big-endian words, zero-filling the last one
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::MD5], stateWords: 4 };
+
+/// Serializes a hash in progress: its configuration `parameters`, `hashWords` as passed to
+/// `md5Process`, the unprocessed buffer and the total length in bytes. Returns an empty vector for
+/// an algorithm this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    hashWords: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, hashWords, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(words: Vec<u32>) -> String {
+        words.iter().map(|w| format!("{:08x}", w)).collect()
+    }
+
+    #[test]
+    fn hash_bytes_matches_rfc_1321() {
+        assert_eq!(hex(hashBytes(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex(hashBytes(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex(hashBytes(b"12345678901234567890123456789012345678901234567890123456789012345678901234567890")),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+
+    #[test]
+    fn hmac_bytes_matches_rfc_2202() {
+        assert_eq!(hex(hmacBytes(b"Jefe", b"what do ya want for nothing?")), "750c783e6ab0b503eaa86e310a5db738");
+        assert_eq!(
+            hex(hmacBytes(&[0xaa; 80], b"Test Using Larger Than Block-Size Key - Hash Key First")),
+            "6b1ab7fe4bd7bf8f0b62e6ce61b9d0cd"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_bytes_runs_every_iteration() {
+        assert_eq!(
+            hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 2, 24)),
+            "a472386aeab98c3c0539761e4cd8cb363e526a310efe30b4"
+        );
+    }
+
+    #[test]
+    fn hkdf_bytes_expands_over_several_blocks() {
+        // The inputs of RFC 5869 test case 1
+        assert_eq!(
+            &hex(hkdfBytes(&[0x0b; 22], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42))[..84],
+            "b222c9db38d17b2fea8b3bb511c0d6d86049ef481ba7065ca5c6422618ed9cc9144900e2c72b6a863a31"
+        );
+        assert!(hkdfBytes(&[0x0b; 22], &[], &[], 255 * 16 + 1).is_empty());
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let padded = bytesToWords(&pad(&message));
+
+        // Hash the first 100 bytes: one block is processed and 36 bytes stay buffered
+        let mut hashWords: Vec<u32> = vec![0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+        let nWordsReady = md5Process(0, &mut hashWords, &padded[..25], 100, 16, 0);
+        let context = exportContext(hashcontext::MD5, &[], &hashWords, &padded[nWordsReady as usize..25], 36, 0, 100);
+        assert!(importContext(hashcontext::SHA1, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::MD5, &[], &context);
+        assert_eq!(fields[..4], [0, 100, 4, 36]);
+        assert_eq!(fields[8..], padded[16..25]);
+
+        // Resume with the buffered bytes followed by the rest of the padded message
+        let mut hashWords = fields[4..8].to_vec();
+        md5Process(1, &mut hashWords, &padded[16..], (padded.len() as u32 - 16) * 4, 16, 0);
+        let resumed: Vec<u32> = hashWords.iter().map(|h| h.swap_bytes()).collect();
+        assert_eq!(resumed, hashBytes(&message));
+    }
 }
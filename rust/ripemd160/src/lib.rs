@@ -1,6 +1,7 @@
 mod utils;
 
 use crate::utils::{getHL, getHR, getSL, getSR, getZL, getZR};
+use hashcontext::ContextFormat;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -49,44 +50,42 @@ fn doProcessBlock(dataWords: &mut [u32], offset: usize, H: &mut [u32]) {
     // Computation
     let mut t: u32 = 0;
     for i in 0..80 {
-        t = (al + dataWords[offset + zl[i] as usize]) | 0;
+        t = al.wrapping_add(dataWords[offset + zl[i] as usize]);
         if i < 16 {
-            t += f1(bl, cl, dl) + hl[0];
+            t = t.wrapping_add(f1(bl, cl, dl)).wrapping_add(hl[0]);
         } else if i < 32 {
-            t += f2(bl, cl, dl) + hl[1];
+            t = t.wrapping_add(f2(bl, cl, dl)).wrapping_add(hl[1]);
         } else if i < 48 {
-            t += f3(bl, cl, dl) + hl[2];
+            t = t.wrapping_add(f3(bl, cl, dl)).wrapping_add(hl[2]);
         } else if i < 64 {
-            t += f4(bl, cl, dl) + hl[3];
+            t = t.wrapping_add(f4(bl, cl, dl)).wrapping_add(hl[3]);
         } else {
             // if (i<80) {
-            t += f5(bl, cl, dl) + hl[4];
+            t = t.wrapping_add(f5(bl, cl, dl)).wrapping_add(hl[4]);
         }
-        t = t | 0;
         t = rotl(t, sl[i]);
-        t = (t + el) | 0;
+        t = t.wrapping_add(el);
         al = el;
         el = dl;
         dl = rotl(cl, 10);
         cl = bl;
         bl = t;
 
-        t = (ar + dataWords[offset + zr[i] as usize]) | 0;
+        t = ar.wrapping_add(dataWords[offset + zr[i] as usize]);
         if i < 16 {
-            t += f5(br, cr, dr) + hr[0];
+            t = t.wrapping_add(f5(br, cr, dr)).wrapping_add(hr[0]);
         } else if i < 32 {
-            t += f4(br, cr, dr) + hr[1];
+            t = t.wrapping_add(f4(br, cr, dr)).wrapping_add(hr[1]);
         } else if i < 48 {
-            t += f3(br, cr, dr) + hr[2];
+            t = t.wrapping_add(f3(br, cr, dr)).wrapping_add(hr[2]);
         } else if i < 64 {
-            t += f2(br, cr, dr) + hr[3];
+            t = t.wrapping_add(f2(br, cr, dr)).wrapping_add(hr[3]);
         } else {
             // if (i<80) {
-            t += f1(br, cr, dr) + hr[4];
+            t = t.wrapping_add(f1(br, cr, dr)).wrapping_add(hr[4]);
         }
-        t = t | 0;
         t = rotl(t, sr[i]);
-        t = (t + er) | 0;
+        t = t.wrapping_add(er);
         ar = er;
         er = dr;
         dr = rotl(cr, 10);
@@ -94,11 +93,11 @@ fn doProcessBlock(dataWords: &mut [u32], offset: usize, H: &mut [u32]) {
         br = t;
     }
     // Intermediate hash value
-    t = (H[1] + cl + dr) | 0;
-    H[1] = (H[2] + dl + er) | 0;
-    H[2] = (H[3] + el + ar) | 0;
-    H[3] = (H[4] + al + br) | 0;
-    H[4] = (H[0] + bl + cr) | 0;
+    t = H[1].wrapping_add(cl).wrapping_add(dr);
+    H[1] = H[2].wrapping_add(dl).wrapping_add(er);
+    H[2] = H[3].wrapping_add(el).wrapping_add(ar);
+    H[3] = H[4].wrapping_add(al).wrapping_add(br);
+    H[4] = H[0].wrapping_add(bl).wrapping_add(cr);
     H[0] = t;
 }
 
@@ -125,3 +124,154 @@ fn f5(x: u32, y: u32, z: u32) -> u32 {
 fn rotl(x: u32, n: u32) -> u32 {
     (x << n) | (x >> (32 - n))
 }
+
+/// Hashes a whole message in one call, padding included, and returns the digest.
+#[wasm_bindgen]
+pub fn hashBytes(message: &[u8]) -> Vec<u32> {
+    let mut dataWords = bytesToWords(&pad(message));
+    let mut H: Vec<u32> = vec![0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    doProcess(dataWords.len(), 16, &mut dataWords, &mut H);
+
+    // Swap endian
+    H.iter().map(|h| h.swap_bytes()).collect()
+}
+
+/// HMAC-RIPEMD160 of a whole message in one call.
+#[wasm_bindgen]
+pub fn hmacBytes(key: &[u8], message: &[u8]) -> Vec<u32> {
+    bytesToWords(&hmac::hmac(|m| wordsToBytes(&hashBytes(m), 20), 64, key, message))
+}
+
+/// PBKDF2-HMAC-RIPEMD160 in one call: runs all the iterations and returns `dkLen` bytes of derived key.
+#[wasm_bindgen]
+pub fn pbkdf2Bytes(password: &[u8], salt: &[u8], iterations: u32, dkLen: u32) -> Vec<u32> {
+    let derivedKey = hmac::pbkdf2(|m| wordsToBytes(&hashBytes(m), 20), 64, password, salt, iterations, dkLen as usize);
+
+    bytesToWords(&derivedKey)
+}
+
+/// HKDF-HMAC-RIPEMD160 in one call: extracts a key from `ikm` and `salt` and expands it with `info` to `length`
+/// bytes, or returns an empty vector if `length` is more than 255 digests.
+#[wasm_bindgen]
+pub fn hkdfBytes(ikm: &[u8], salt: &[u8], info: &[u8], length: u32) -> Vec<u32> {
+    let okm = hmac::hkdf(|m| wordsToBytes(&hashBytes(m), 20), 64, ikm, salt, info, length as usize);
+
+    bytesToWords(&okm)
+}
+
+// Append the 1 bit, then zeros up to 56 bytes mod 64, then the 64-bit little-endian length in bits
+fn pad(message: &[u8]) -> Vec<u8> {
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&(message.len() as u64 * 8).to_le_bytes());
+
+    padded
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+// Pack bytes into big-endian words, zero-filling the last one
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::RIPEMD160], stateWords: 5 };
+
+/// Serializes a hash in progress: its configuration `parameters`, `H` as passed to `doProcess`, the
+/// unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm this
+/// crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    H: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, H, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(words: Vec<u32>) -> String {
+        words.iter().map(|w| format!("{:08x}", w)).collect()
+    }
+
+    #[test]
+    fn hash_bytes_matches_reference_vectors() {
+        assert_eq!(hex(hashBytes(b"")), "9c1185a5c5e9fc54612808977ee8f548b2258d31");
+        assert_eq!(hex(hashBytes(b"abc")), "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+        assert_eq!(
+            hex(hashBytes(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+            "12a053384a9c0c88e405a06c27dcf49ada62eb2b"
+        );
+    }
+
+    #[test]
+    fn hmac_bytes_matches_rfc_2286() {
+        assert_eq!(hex(hmacBytes(b"Jefe", b"what do ya want for nothing?")), "dda6c0213a485a9e24f4742064a7f033b43c4069");
+    }
+
+    #[test]
+    fn pbkdf2_bytes_runs_every_iteration() {
+        assert_eq!(
+            hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 5, 24)),
+            "55c92415eb6873692984793942eece55ebe9934a9b380cc6"
+        );
+    }
+
+    #[test]
+    fn hkdf_bytes_expands_over_several_blocks() {
+        // The inputs of RFC 5869 test case 1
+        assert_eq!(
+            &hex(hkdfBytes(&[0x0b; 22], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42))[..84],
+            "8e2a6e5c36796c02636a4246873f35edf59684f394da0ec847b3643aa1f0059ce97de9843cf9db968a88"
+        );
+        assert!(hkdfBytes(&[0x0b; 22], &[], &[], 255 * 20 + 1).is_empty());
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let padded = bytesToWords(&pad(&message));
+
+        // Hash the first 100 bytes: one block is processed and 36 bytes stay buffered
+        let mut H: Vec<u32> = vec![0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+        doProcess(16, 16, &mut padded[..16].to_vec(), &mut H);
+        let context = exportContext(hashcontext::RIPEMD160, &[], &H, &padded[16..25], 36, 0, 100);
+        assert!(importContext(hashcontext::SHA1, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::RIPEMD160, &[], &context);
+        assert_eq!(fields[..4], [0, 100, 5, 36]);
+        assert_eq!(fields[9..], padded[16..25]);
+
+        // Resume with the buffered bytes followed by the rest of the padded message
+        let mut H = fields[4..9].to_vec();
+        let mut rest = padded[16..].to_vec();
+        doProcess(rest.len(), 16, &mut rest, &mut H);
+        let resumed: Vec<u32> = H.iter().map(|h| h.swap_bytes()).collect();
+        assert_eq!(resumed, hashBytes(&message));
+    }
+}
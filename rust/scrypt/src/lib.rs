@@ -0,0 +1,186 @@
+use wasm_bindgen::prelude::*;
+
+/// scrypt (RFC 7914): derives `dkLen` bytes from `password` and `salt` with the CPU/memory cost `N`,
+/// the block size `r` and the parallelization `p`. PBKDF2-HMAC-SHA256 comes from the sha256 crate.
+///
+/// Returns an empty vector if the parameters or `dkLen` are invalid, or if the `128 * r * (N + p)` bytes
+/// of the ROMix table and the mixed blocks exceed `maxMemory`.
+#[wasm_bindgen]
+pub fn scrypt(password: &[u8], salt: &[u8], N: u32, r: u32, p: u32, dkLen: u32, maxMemory: u32) -> Vec<u32> {
+    if dkLen == 0 || !checkParameters(N, r, p, maxMemory) {
+        return Vec::new();
+    }
+    let blockWords = 32 * r as usize;
+
+    // Expand the password into p blocks of 128 * r bytes, mix each of them, and condense the result
+    let mut B = bytesToLeWords(&wordsToBytes(&sha256::pbkdf2Bytes(password, salt, 1, 128 * r * p, 32)));
+    let mut V = vec![0_u32; blockWords * N as usize];
+    for block in B.chunks_mut(blockWords) {
+        roMix(block, N, &mut V);
+    }
+
+    sha256::pbkdf2Bytes(password, &leWordsToBytes(&B), 1, dkLen, 32)
+}
+
+// N a power of 2 greater than 1 and below 2^(128 * r / 8), r * p below 2^30, and the memory within maxMemory
+fn checkParameters(N: u32, r: u32, p: u32, maxMemory: u32) -> bool {
+    if N < 2 || !N.is_power_of_two() || r == 0 || p == 0 {
+        return false;
+    }
+    if (r < 2 && N >= 1 << 16) || r as u64 * p as u64 >= 1 << 30 {
+        return false;
+    }
+
+    128 * r as u64 * (N as u64 + p as u64) <= maxMemory as u64
+}
+
+// Sequential memory-hard mixing of one block of 32 * r words, using V as the table of N blocks
+fn roMix(B: &mut [u32], N: u32, V: &mut [u32]) {
+    let blockWords = B.len();
+    let mut X = B.to_vec();
+    let mut Y = vec![0_u32; blockWords];
+
+    for i in 0..N as usize {
+        V[i * blockWords..(i + 1) * blockWords].copy_from_slice(&X);
+        blockMix(&X, &mut Y);
+        std::mem::swap(&mut X, &mut Y);
+    }
+    for _ in 0..N {
+        // Integerify: the first word of the last 64-byte block, modulo N
+        let j = (X[blockWords - 16] & (N - 1)) as usize;
+        for (x, v) in X.iter_mut().zip(&V[j * blockWords..(j + 1) * blockWords]) {
+            *x ^= v;
+        }
+        blockMix(&X, &mut Y);
+        std::mem::swap(&mut X, &mut Y);
+    }
+
+    B.copy_from_slice(&X);
+}
+
+// Salsa20/8 over the 2 * r 64-byte blocks of B, writing the even outputs first and then the odd ones
+fn blockMix(B: &[u32], Y: &mut [u32]) {
+    let nBlocks = B.len() / 16;
+    let mut X = [0_u32; 16];
+    X.copy_from_slice(&B[B.len() - 16..]);
+
+    for i in 0..nBlocks {
+        for k in 0..16 {
+            X[k] ^= B[i * 16 + k];
+        }
+        salsa208(&mut X);
+
+        let offset = (i / 2 + (i % 2) * nBlocks / 2) * 16;
+        Y[offset..offset + 16].copy_from_slice(&X);
+    }
+}
+
+fn salsa208(B: &mut [u32; 16]) {
+    let mut x = *B;
+    for _ in 0..4 {
+        // Columns, then rows
+        quarterRound(&mut x, 0, 4, 8, 12);
+        quarterRound(&mut x, 5, 9, 13, 1);
+        quarterRound(&mut x, 10, 14, 2, 6);
+        quarterRound(&mut x, 15, 3, 7, 11);
+        quarterRound(&mut x, 0, 1, 2, 3);
+        quarterRound(&mut x, 5, 6, 7, 4);
+        quarterRound(&mut x, 10, 11, 8, 9);
+        quarterRound(&mut x, 15, 12, 13, 14);
+    }
+
+    for i in 0..16 {
+        B[i] = B[i].wrapping_add(x[i]);
+    }
+}
+
+fn quarterRound(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+    x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+    x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+    x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+}
+
+// The big-endian words of the sha256 crate
+fn wordsToBytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|w| w.to_be_bytes().to_vec()).collect()
+}
+
+// Salsa20 works on little-endian words
+fn bytesToLeWords(bytes: &[u8]) -> Vec<u32> {
+    bytes.chunks(4).map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+fn leWordsToBytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|w| w.to_le_bytes().to_vec()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_MEMORY: u32 = 32 * 1024 * 1024;
+
+    fn hex(words: Vec<u32>, nBytes: usize) -> String {
+        wordsToBytes(&words)[..nBytes].iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn fromHex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn salsa208_matches_rfc_7914() {
+        let mut B = [0_u32; 16];
+        B.copy_from_slice(&bytesToLeWords(&fromHex(
+            "7e879a214f3ec9867ca940e641718f26baee555b8c61c1b50df846116dcd3b1d\
+             ee24f319df9b3d8514121e4b5ac5aa3276021d2909c74829edebc68db8b8c25e",
+        )));
+        salsa208(&mut B);
+        assert_eq!(
+            leWordsToBytes(&B),
+            fromHex(
+                "a41f859c6608cc993b81cacb020cef05044b2181a2fd337dfd7b1c6396682f29\
+                 b4393168e3c9e6bcfe6bc5b7a06d96bae424cc102c91745c24ad673dc7618f81"
+            )
+        );
+    }
+
+    #[test]
+    fn scrypt_matches_rfc_7914() {
+        assert_eq!(
+            hex(scrypt(b"", b"", 16, 1, 1, 64, MAX_MEMORY), 64),
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+             fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        );
+        assert_eq!(
+            hex(scrypt(b"password", b"NaCl", 1024, 8, 16, 64, MAX_MEMORY), 64),
+            "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162\
+             2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+        );
+    }
+
+    #[test]
+    fn output_length_is_not_tied_to_the_block_size() {
+        assert_eq!(hex(scrypt(b"password", b"NaCl", 2, 1, 1, 10, MAX_MEMORY), 10), "a2f63b8c062d32609194");
+        assert_eq!(
+            hex(scrypt(b"password", b"NaCl", 4, 2, 3, 33, MAX_MEMORY), 33),
+            "ff0cbffcefd84f40565b8adfef3f489b7aaa1a86f44e48106fa2ac117f5f5bdf58"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(scrypt(b"", b"", 1, 1, 1, 16, MAX_MEMORY).is_empty());
+        assert!(scrypt(b"", b"", 24, 1, 1, 16, MAX_MEMORY).is_empty());
+        assert!(scrypt(b"", b"", 16, 0, 1, 16, MAX_MEMORY).is_empty());
+        assert!(scrypt(b"", b"", 16, 1, 0, 16, MAX_MEMORY).is_empty());
+        assert!(scrypt(b"", b"", 1 << 16, 1, 1, 16, u32::MAX).is_empty());
+        assert!(scrypt(b"", b"", 16, 1 << 15, 1 << 15, 16, u32::MAX).is_empty());
+        assert!(scrypt(b"", b"", 16, 1, 1, 0, MAX_MEMORY).is_empty());
+
+        // The 16 MiB table and one 1 KiB block
+        assert!(checkParameters(16384, 8, 1, 16 * 1024 * 1024 + 1024));
+        assert!(!checkParameters(16384, 8, 1, 16 * 1024 * 1024 + 1023));
+    }
+}
@@ -1,5 +1,6 @@
 use std::ptr::null;
 use std::cmp;
+use hashcontext::ContextFormat;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -43,15 +44,15 @@ fn doCryptBlock(data: &[u32], hash: &mut [u32], offsetU32: u32) {
             w[i] = (n << 1) | (n >> 31);
         }
 
-        let mut t: u32 = ((a << 5) | (a >> 27)) + e + w[i];
+        let mut t: u32 = ((a << 5) | (a >> 27)).wrapping_add(e).wrapping_add(w[i]);
         if i < 20 {
-            t += ((b & c) | (!b & d)) + 0x5a827999;
+            t = t.wrapping_add((b & c) | (!b & d)).wrapping_add(0x5a827999);
         } else if i < 40 {
-            t += (b ^ c ^ d) + 0x6ed9eba1;
+            t = t.wrapping_add(b ^ c ^ d).wrapping_add(0x6ed9eba1);
         } else if i < 60 {
-            t += ((b & c) | (b & d) | (c & d)) - 0x70e44324;
+            t = t.wrapping_add((b & c) | (b & d) | (c & d)).wrapping_sub(0x70e44324);
         } else {
-            t += (b ^ c ^ d) - 0x359d3e2a;
+            t = t.wrapping_add(b ^ c ^ d).wrapping_sub(0x359d3e2a);
         }
 
         e = d;
@@ -64,9 +65,163 @@ fn doCryptBlock(data: &[u32], hash: &mut [u32], offsetU32: u32) {
     }
 
     // Intermediate hash value
-    hash[0] = hash[0] + a;
-    hash[1] = hash[1] + b;
-    hash[2] = hash[2] + c;
-    hash[3] = hash[3] + d;
-    hash[4] = hash[4] + e;
+    hash[0] = hash[0].wrapping_add(a);
+    hash[1] = hash[1].wrapping_add(b);
+    hash[2] = hash[2].wrapping_add(c);
+    hash[3] = hash[3].wrapping_add(d);
+    hash[4] = hash[4].wrapping_add(e);
+}
+
+/// Hashes a whole message in one call, padding included, and returns the digest.
+#[wasm_bindgen]
+pub fn hashBytes(message: &[u8]) -> Vec<u32> {
+    let padded = pad(message);
+    let mut hashWords: Vec<u32> = vec![0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    doCrypt(1, &mut hashWords, &bytesToWords(&padded), padded.len() as u32, 16, 0);
+
+    hashWords
+}
+
+/// HMAC-SHA1 of a whole message in one call.
+#[wasm_bindgen]
+pub fn hmacBytes(key: &[u8], message: &[u8]) -> Vec<u32> {
+    bytesToWords(&hmac::hmac(|m| wordsToBytes(&hashBytes(m), 20), 64, key, message))
+}
+
+/// PBKDF2-HMAC-SHA1 in one call: runs all the iterations and returns `dkLen` bytes of derived key.
+#[wasm_bindgen]
+pub fn pbkdf2Bytes(password: &[u8], salt: &[u8], iterations: u32, dkLen: u32) -> Vec<u32> {
+    let derivedKey = hmac::pbkdf2(|m| wordsToBytes(&hashBytes(m), 20), 64, password, salt, iterations, dkLen as usize);
+
+    bytesToWords(&derivedKey)
+}
+
+/// HKDF-HMAC-SHA1 in one call: extracts a key from `ikm` and `salt` and expands it with `info` to `length`
+/// bytes, or returns an empty vector if `length` is more than 255 digests.
+#[wasm_bindgen]
+pub fn hkdfBytes(ikm: &[u8], salt: &[u8], info: &[u8], length: u32) -> Vec<u32> {
+    let okm = hmac::hkdf(|m| wordsToBytes(&hashBytes(m), 20), 64, ikm, salt, info, length as usize);
+
+    bytesToWords(&okm)
+}
+
+// Append the 1 bit, then zeros up to 56 bytes mod 64, then the 64-bit big-endian length in bits
+fn pad(message: &[u8]) -> Vec<u8> {
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&(message.len() as u64 * 8).to_be_bytes());
+
+    padded
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+// Pack bytes into big-endian words, zero-filling the last one
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::SHA1], stateWords: 5 };
+
+/// Serializes a hash in progress: its configuration `parameters`, `hashWords` as passed to
+/// `doCrypt`, the unprocessed buffer and the total length in bytes. Returns an empty vector for an
+/// algorithm this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    hashWords: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, hashWords, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(words: Vec<u32>) -> String {
+        words.iter().map(|w| format!("{:08x}", w)).collect()
+    }
+
+    #[test]
+    fn hash_bytes_matches_fips_180_4() {
+        assert_eq!(hex(hashBytes(b"")), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(hex(hashBytes(b"abc")), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            hex(hashBytes(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        );
+    }
+
+    #[test]
+    fn hmac_bytes_matches_rfc_2202() {
+        assert_eq!(hex(hmacBytes(b"Jefe", b"what do ya want for nothing?")), "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79");
+        assert_eq!(
+            hex(hmacBytes(&[0xaa; 80], b"Test Using Larger Than Block-Size Key - Hash Key First")),
+            "aa4ae5e15272d00e95705637ce8a3b55ed402112"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_bytes_matches_rfc_3962() {
+        assert_eq!(hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 1, 20)), "cdedb5281bb2f801565a1122b25635150ad1f7a0");
+        assert_eq!(hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 4096, 20)), "b73983792c591753202b6618400c66c363431680");
+    }
+
+    #[test]
+    fn hkdf_bytes_matches_rfc_5869() {
+        assert_eq!(
+            &hex(hkdfBytes(&[0x0b; 11], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42))[..84],
+            "085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9cdd4f155fda2c22e422478d305f3f896"
+        );
+        // No salt stands for a string of zeros
+        assert_eq!(
+            &hex(hkdfBytes(&[0x0c; 22], &[], &[], 42))[..84],
+            "2c91117204d745f3500d636a62f64f0ab3bae548aa53d423b0d1f27ebba6f5e5673a081d70cce7acfc48"
+        );
+        assert!(hkdfBytes(&[0x0b; 22], &[], &[], 255 * 20 + 1).is_empty());
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let padded = bytesToWords(&pad(&message));
+
+        // Hash the first 100 bytes: one block is processed and 36 bytes stay buffered
+        let mut hashWords: Vec<u32> = vec![0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+        let nWordsReady = doCrypt(0, &mut hashWords, &padded[..25], 100, 16, 0);
+        let context = exportContext(hashcontext::SHA1, &[], &hashWords, &padded[nWordsReady as usize..25], 36, 0, 100);
+        assert!(importContext(hashcontext::MD5, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::SHA1, &[], &context);
+        assert_eq!(fields[..4], [0, 100, 5, 36]);
+        assert_eq!(fields[9..], padded[16..25]);
+
+        // Resume with the buffered bytes followed by the rest of the padded message
+        let mut hashWords = fields[4..9].to_vec();
+        doCrypt(1, &mut hashWords, &padded[16..], (padded.len() as u32 - 16) * 4, 16, 0);
+        assert_eq!(hashWords, hashBytes(&message));
+    }
 }
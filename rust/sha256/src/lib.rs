@@ -2,6 +2,7 @@ mod utils;
 
 use std::ptr::null;
 use std::cmp;
+use hashcontext::ContextFormat;
 use wasm_bindgen::prelude::*;
 use utils::*;
 
@@ -28,6 +29,55 @@ pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32
     nWordsReady
 }
 
+/// Initial hash value for a 28 byte (SHA-224) or 32 byte (SHA-256) digest.
+/// Any other output size yields an empty vector.
+#[wasm_bindgen]
+pub fn getInitialHash(outputSize: u32) -> Vec<u32> {
+    match outputSize {
+        28 => getH224().to_vec(),
+        32 => getH256().to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+/// Pads the buffered tail of the message, hashes it into `hash` and returns the digest
+/// truncated to `outputSize` bytes. The message length in bits is passed as two words.
+#[wasm_bindgen]
+pub fn doFinalize(
+    dataWords: &[u32],
+    dataSigBytes: u32,
+    nBitsTotalHigh: u32,
+    nBitsTotalLow: u32,
+    hash: &mut [u32],
+    outputSize: u32,
+) -> Vec<u32> {
+    let sigBytes = dataSigBytes as usize;
+    let mut block: Vec<u32> = (0..(sigBytes + 3) / 4).map(|i| *dataWords.get(i).unwrap_or(&0)).collect();
+    if sigBytes % 4 != 0 {
+        block[sigBytes / 4] &= 0xffffffff << (32 - (sigBytes % 4) * 8);
+    }
+
+    // Append the 1 bit, then zeros up to 56 bytes mod 64, then the 64-bit length
+    if sigBytes % 4 == 0 {
+        block.push(0);
+    }
+    block[sigBytes / 4] |= 0x80 << (24 - (sigBytes % 4) * 8);
+    while block.len() % 16 != 14 {
+        block.push(0);
+    }
+    block.push(nBitsTotalHigh);
+    block.push(nBitsTotalLow);
+
+    let mut offset = 0;
+    while offset < block.len() as u32 {
+        doCryptBlock(&block, offset, hash);
+        offset += 16;
+    }
+
+    let nWords = (outputSize as usize + 3) / 4;
+    hash[..nWords].to_vec()
+}
+
 fn doCryptBlock(data: &[u32], offsetU32: u32, hash: &mut [u32]) {
     let K = getK();
     let offset = offsetU32 as usize;
@@ -52,7 +102,7 @@ fn doCryptBlock(data: &[u32], offsetU32: u32, hash: &mut [u32]) {
             let gamma0  = ((gamma0x << 25) | (gamma0x >> 7)) ^ ((gamma0x << 14) | (gamma0x >> 18)) ^ (gamma0x >> 3);
             let gamma1x = W[i - 2];
             let gamma1  = ((gamma1x << 15) | (gamma1x >> 17)) ^ ((gamma1x << 13) | (gamma1x >> 19)) ^ (gamma1x >> 10);
-            W[i] = gamma0 + W[i - 7] + gamma1 + W[i - 16];
+            W[i] = gamma0.wrapping_add(W[i - 7]).wrapping_add(gamma1).wrapping_add(W[i - 16]);
         }
 
         let ch  = (e & f) ^ (!e & g);
@@ -61,27 +111,253 @@ fn doCryptBlock(data: &[u32], offsetU32: u32, hash: &mut [u32]) {
         let sigma0 = ((a << 30) | (a >> 2)) ^ ((a << 19) | (a >> 13)) ^ ((a << 10) | (a >> 22));
         let sigma1 = ((e << 26) | (e >> 6)) ^ ((e << 21) | (e >> 11)) ^ ((e << 7)  | (e >> 25));
 
-        let t1 = h + sigma1 + ch + K[i] + W[i];
-        let t2 = sigma0 + maj;
+        let t1 = h.wrapping_add(sigma1).wrapping_add(ch).wrapping_add(K[i]).wrapping_add(W[i]);
+        let t2 = sigma0.wrapping_add(maj);
 
         h = g;
         g = f;
         f = e;
-        e = d + t1;
+        e = d.wrapping_add(t1);
         d = c;
         c = b;
         b = a;
-        a = t1 + t2;
+        a = t1.wrapping_add(t2);
     }
 
     // Intermediate hash value
-    hash[0] = hash[0] + a;
-    hash[1] = hash[1] + b;
-    hash[2] = hash[2] + c;
-    hash[3] = hash[3] + d;
-    hash[4] = hash[4] + e;
-    hash[5] = hash[5] + f;
-    hash[6] = hash[6] + g;
-    hash[7] = hash[7] + h;
+    hash[0] = hash[0].wrapping_add(a);
+    hash[1] = hash[1].wrapping_add(b);
+    hash[2] = hash[2].wrapping_add(c);
+    hash[3] = hash[3].wrapping_add(d);
+    hash[4] = hash[4].wrapping_add(e);
+    hash[5] = hash[5].wrapping_add(f);
+    hash[6] = hash[6].wrapping_add(g);
+    hash[7] = hash[7].wrapping_add(h);
+}
+
+
+/// Hashes a whole message in one call, padding included, and returns the digest of
+/// `outputSize` bytes, 28 for SHA-224 or 32 for SHA-256. Any other output size yields an empty vector.
+#[wasm_bindgen]
+pub fn hashBytes(message: &[u8], outputSize: u32) -> Vec<u32> {
+    let mut hash = getInitialHash(outputSize);
+    if hash.is_empty() {
+        return hash;
+    }
+
+    let nBits = message.len() as u64 * 8;
+    doFinalize(&bytesToWords(message), message.len() as u32, (nBits >> 32) as u32, nBits as u32, &mut hash, outputSize)
+}
+
+/// HMAC-SHA224 or HMAC-SHA256 of a whole message in one call, chosen by `outputSize` as in `hashBytes`.
+#[wasm_bindgen]
+pub fn hmacBytes(key: &[u8], message: &[u8], outputSize: u32) -> Vec<u32> {
+    if getInitialHash(outputSize).is_empty() {
+        return Vec::new();
+    }
+
+    bytesToWords(&hmac::hmac(|m| wordsToBytes(&hashBytes(m, outputSize), outputSize), 64, key, message))
+}
+
+/// PBKDF2 over the HMAC of `hmacBytes` in one call: runs all the iterations and returns `dkLen` bytes
+/// of derived key, or an empty vector for an unsupported `outputSize`.
+#[wasm_bindgen]
+pub fn pbkdf2Bytes(password: &[u8], salt: &[u8], iterations: u32, dkLen: u32, outputSize: u32) -> Vec<u32> {
+    if getInitialHash(outputSize).is_empty() {
+        return Vec::new();
+    }
+
+    let hash = |m: &[u8]| wordsToBytes(&hashBytes(m, outputSize), outputSize);
+    bytesToWords(&hmac::pbkdf2(hash, 64, password, salt, iterations, dkLen as usize))
+}
+
+/// HKDF over the HMAC of `hmacBytes` in one call: extracts a key from `ikm` and `salt` and expands it with
+/// `info` to `length` bytes, or returns an empty vector for an unsupported `outputSize` or if `length` is more
+/// than 255 digests.
+#[wasm_bindgen]
+pub fn hkdfBytes(ikm: &[u8], salt: &[u8], info: &[u8], length: u32, outputSize: u32) -> Vec<u32> {
+    if getInitialHash(outputSize).is_empty() {
+        return Vec::new();
+    }
+
+    let hash = |m: &[u8]| wordsToBytes(&hashBytes(m, outputSize), outputSize);
+    bytesToWords(&hmac::hkdf(hash, 64, ikm, salt, info, length as usize))
 }
 
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+// Pack bytes into big-endian words, zero-filling the last one
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::SHA224, hashcontext::SHA256], stateWords: 8 };
+
+/// Serializes a hash in progress: its configuration `parameters`, `hash` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    hash: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, hash, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(message: &[u8], outputSize: u32) -> Vec<u32> {
+        let words = bytesToWords(message);
+        let mut hash = getInitialHash(outputSize);
+        let nWordsReady = doCrypt(0, &words, message.len() as u32, 16, &mut hash, 0);
+        let nBits = message.len() as u64 * 8;
+        doFinalize(
+            &words[nWordsReady as usize..],
+            message.len() as u32 - nWordsReady * 4,
+            (nBits >> 32) as u32,
+            nBits as u32,
+            &mut hash,
+            outputSize,
+        )
+    }
+
+    // 56 bytes, so the length no longer fits in the first padded block
+    const TWO_BLOCKS: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+    #[test]
+    fn sha256_matches_fips_180_4() {
+        assert_eq!(
+            digest(b"abc", 32),
+            [0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61, 0xf20015ad]
+        );
+        assert_eq!(
+            digest(TWO_BLOCKS, 32),
+            [0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 0xa33ce459, 0x64ff2167, 0xf6ecedd4, 0x19db06c1]
+        );
+        assert_eq!(
+            digest(b"", 32),
+            [0xe3b0c442, 0x98fc1c14, 0x9afbf4c8, 0x996fb924, 0x27ae41e4, 0x649b934c, 0xa495991b, 0x7852b855]
+        );
+    }
+
+    #[test]
+    fn sha224_matches_fips_180_4() {
+        assert_eq!(
+            digest(b"abc", 28),
+            [0x23097d22, 0x3405d822, 0x8642a477, 0xbda255b3, 0x2aadbce4, 0xbda0b3f7, 0xe36c9da7]
+        );
+        assert_eq!(
+            digest(TWO_BLOCKS, 28),
+            [0x75388b16, 0x512776cc, 0x5dba5da1, 0xfd890150, 0xb0c6455c, 0xb4f58b19, 0x52522525]
+        );
+        assert!(getInitialHash(20).is_empty());
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+
+        // Hash the first 100 bytes: one block is compressed and 36 bytes stay buffered
+        let first = bytesToWords(&message[..100]);
+        let mut hash = getInitialHash(32);
+        let nWordsReady = doCrypt(0, &first, 100, 16, &mut hash, 0);
+        let context = exportContext(hashcontext::SHA256, &[], &hash, &first[nWordsReady as usize..], 36, 0, 100);
+        assert!(importContext(hashcontext::SHA224, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::SHA256, &[], &context);
+        assert_eq!(fields[..4], [0, 100, 8, 36]);
+        assert_eq!(fields[12..], bytesToWords(&message[64..100])[..]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut hash = fields[4..12].to_vec();
+        let rest = bytesToWords(&message[64..]);
+        let nWordsReady = doCrypt(0, &rest, 136, 16, &mut hash, 0);
+        let resumed = doFinalize(&rest[nWordsReady as usize..], 136 - nWordsReady * 4, 0, 1600, &mut hash, 32);
+        assert_eq!(resumed, digest(&message, 32));
+    }
+
+    #[test]
+    fn hash_bytes_pads_in_rust() {
+        assert_eq!(hashBytes(b"abc", 32), digest(b"abc", 32));
+        assert_eq!(hashBytes(TWO_BLOCKS, 28), digest(TWO_BLOCKS, 28));
+        assert_eq!(hashBytes(b"", 32), digest(b"", 32));
+        assert!(hashBytes(b"abc", 20).is_empty());
+    }
+
+    #[test]
+    fn hmac_bytes_matches_rfc_4231() {
+        assert_eq!(
+            hmacBytes(b"Jefe", b"what do ya want for nothing?", 32),
+            [0x5bdcc146, 0xbf60754e, 0x6a042426, 0x089575c7, 0x5a003f08, 0x9d273983, 0x9dec58b9, 0x64ec3843]
+        );
+        assert_eq!(
+            hmacBytes(b"Jefe", b"what do ya want for nothing?", 28),
+            [0xa30e0109, 0x8bc6dbbf, 0x45690f3a, 0x7e9e6d0f, 0x8bbea2a3, 0x9e614800, 0x8fd05e44]
+        );
+
+        // A key longer than the block is hashed first
+        assert_eq!(
+            hmacBytes(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First", 32),
+            [0x60e43159, 0x1ee0b67f, 0x0d8a26aa, 0xcbf5b77f, 0x8e0bc621, 0x3728c514, 0x0546040f, 0x0ee37f54]
+        );
+        assert!(hmacBytes(b"Jefe", b"", 20).is_empty());
+    }
+
+    #[test]
+    fn pbkdf2_bytes_matches_the_javascript_loop() {
+        let hex = |words: Vec<u32>| words.iter().map(|w| format!("{:08x}", w)).collect::<String>();
+        assert_eq!(
+            hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 1200, 32, 32)),
+            "c76a982415f1acc71dc197273c5b6ada32f62915ed461718aad32843762433fa"
+        );
+
+        // Two SHA-224 blocks, the second one truncated
+        assert_eq!(
+            hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 3, 40, 28)),
+            "6dc1ced7f36b8c5bb7c53cc74d507a444956bef0981c1bc39d2ce23ea2a001c47a0c88e00539a0c4"
+        );
+        assert!(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 1, 32, 20).is_empty());
+    }
+
+
+    #[test]
+    fn hkdf_bytes_matches_rfc_5869() {
+        let hex = |words: Vec<u32>| words.iter().map(|w| format!("{:08x}", w)).collect::<String>()[..84].to_string();
+        assert_eq!(
+            hex(hkdfBytes(&[0x0b; 22], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42, 32)),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        );
+        assert_eq!(
+            hex(hkdfBytes(&[0x0b; 22], &[], &[], 42, 32)),
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8"
+        );
+        assert_eq!(
+            hex(hkdfBytes(&[0x0b; 22], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42, 28)),
+            "2f21cd7cbc818ca5c561b933728e2e08e154a87e1432399a820dee13aa222d0cee6152fa539ab70f8e80"
+        );
+        assert!(hkdfBytes(&[0x0b; 22], &[], &[], 255 * 32 + 1, 32).is_empty());
+        assert!(hkdfBytes(&[0x0b; 22], &[], &[], 42, 20).is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 mod utils;
+mod sp800_185;
 
 use std::ptr::null;
 use std::cmp;
+use hashcontext::ContextFormat;
 use wasm_bindgen::prelude::*;
 use utils::*;
 
@@ -18,16 +20,10 @@ pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32
 
     let nWordsReady = nBlocksReady as u32 * blockSize;
 
-    let mut state: [X64Word; 25] = [X64Word { high: 0, low: 0 }; 25];
-    for i in 0..25 {
-        state[i].high = stateData[i * 2];
-        state[i].low = stateData[i * 2 + 1];
-    }
+    let mut state = loadState(stateData);
 
     let mut T: [X64Word; 25] = [X64Word { high: 0, low: 0 }; 25];
 
-    let mut i: usize = 0;
-
     if nWordsReady > 0 {
         let mut offset = 0;
         while offset < nWordsReady {
@@ -36,20 +32,107 @@ pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32
         }
     }
 
-    i = 0;
-    while i < 25 {
+    storeState(&state, stateData);
+
+    nWordsReady
+}
+
+/// Pads the buffered tail of the message with `domainByte` and the final 0x80 bit,
+/// then absorbs it, leaving the state ready to squeeze.
+/// SHAKE uses 0x1f, cSHAKE 0x04, SHA-3 0x06 and the original Keccak 0x01.
+#[wasm_bindgen]
+pub fn absorbFinal(dataWords: &[u32], dataSigBytes: u32, blockSize: u32, domainByte: u8, stateData: &mut [u32]) {
+    let mut state = loadState(stateData);
+    absorbPadded(&mut state, blockSize, wordsToBytes(dataWords, dataSigBytes), domainByte);
+    storeState(&state, stateData);
+}
+
+/// Reads `nBytes` of output, starting `squeezeOffset` bytes into the current block.
+/// An offset equal to the block size permutes the state before reading, so the caller
+/// can track its position as `((squeezeOffset + nBytes - 1) % blockSizeBytes) + 1`.
+#[wasm_bindgen]
+pub fn squeeze(blockSize: u32, stateData: &mut [u32], squeezeOffset: u32, nBytes: u32) -> Vec<u32> {
+    let mut state = loadState(stateData);
+    let bytes = squeezeBytes(&mut state, (blockSize * 4) as usize, squeezeOffset as usize, nBytes as usize);
+    storeState(&state, stateData);
+
+    bytesToWords(&bytes)
+}
+
+fn squeezeBytes(state: &mut [X64Word; 25], blockSizeBytes: usize, squeezeOffset: usize, nBytes: usize) -> Vec<u8> {
+    let mut T: [X64Word; 25] = [X64Word { high: 0, low: 0 }; 25];
+    let mut position = squeezeOffset;
+    let mut bytes: Vec<u8> = Vec::with_capacity(nBytes);
+    while bytes.len() < nBytes {
+        if position == blockSizeBytes {
+            keccakF(state, &mut T);
+            position = 0;
+        }
+        // Lanes are little-endian, with bytes 0..4 in the low half
+        let lane = &state[position / 8];
+        let half = if position % 8 < 4 { lane.low } else { lane.high };
+        bytes.push((half >> ((position % 4) * 8)) as u8);
+        position += 1;
+    }
+
+    bytes
+}
+
+fn absorbPadded(state: &mut [X64Word; 25], blockSize: u32, mut bytes: Vec<u8>, domainByte: u8) {
+    let blockSizeBytes = (blockSize * 4) as usize;
+    bytes.push(domainByte);
+    while bytes.len() % blockSizeBytes != 0 {
+        bytes.push(0);
+    }
+    let last = bytes.len() - 1;
+    bytes[last] |= 0x80;
+
+    absorbBlocks(state, blockSize, &bytes);
+}
+
+// Absorb whole blocks, `bytes` must already be a multiple of the block size
+fn absorbBlocks(state: &mut [X64Word; 25], blockSize: u32, bytes: &[u8]) {
+    let words = bytesToWords(bytes);
+    let mut T: [X64Word; 25] = [X64Word { high: 0, low: 0 }; 25];
+    let mut offset = 0;
+    while offset < words.len() as u32 {
+        doCryptBlock(&words, offset, blockSize, state, &mut T);
+        offset += blockSize;
+    }
+}
+
+fn loadState(stateData: &[u32]) -> [X64Word; 25] {
+    let mut state: [X64Word; 25] = [X64Word { high: 0, low: 0 }; 25];
+    for i in 0..25 {
+        state[i].high = stateData[i * 2];
+        state[i].low = stateData[i * 2 + 1];
+    }
+
+    state
+}
+
+fn storeState(state: &[X64Word; 25], stateData: &mut [u32]) {
+    for i in 0..25 {
         stateData[i * 2] = state[i].high;
         stateData[i * 2 + 1] = state[i].low;
-        i += 1;
     }
+}
 
-    nWordsReady
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+// Pack bytes into big-endian words, zero-filling the last one
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
 }
 
 fn doCryptBlock(data: &[u32], offsetU32: u32, blockSize: u32, state: &mut [X64Word; 25], T: &mut [X64Word; 25]) {
-    let RHO_OFFSETS = getRhoOffsets();
-    let PI_INDEXES = getPiIndexes();
-    let ROUND_CONSTANTS = getRoundConstants();
     let offset = offsetU32 as usize;
 
     let nBlockSizeLanes = (blockSize / 2) as usize;
@@ -79,6 +162,15 @@ fn doCryptBlock(data: &[u32], offsetU32: u32, blockSize: u32, state: &mut [X64Wo
         i += 1;
     }
 
+    keccakF(state, T);
+}
+
+// Keccak-f[1600] over lanes split into 32-bit halves
+pub fn keccakF(state: &mut [X64Word; 25], T: &mut [X64Word; 25]) {
+    let RHO_OFFSETS = getRhoOffsets();
+    let PI_INDEXES = getPiIndexes();
+    let ROUND_CONSTANTS = getRoundConstants();
+
     let mut round: usize = 0;
     // Rounds
     while round < 24 {
@@ -185,3 +277,330 @@ fn doCryptBlock(data: &[u32], offsetU32: u32, blockSize: u32, state: &mut [X64Wo
         round += 1;
     }
 }
+
+/// Hashes a whole message in one call: pads it with `domainByte` as in `absorbFinal`,
+/// absorbs it at a rate of `blockSize` words and squeezes `outputSize` bytes.
+#[wasm_bindgen]
+pub fn hashBytes(message: &[u8], blockSize: u32, domainByte: u8, outputSize: u32) -> Vec<u32> {
+    bytesToWords(&digestBytes(message, blockSize, domainByte, outputSize))
+}
+
+/// HMAC over SHA-3 or Keccak of a whole message in one call, with the parameters of `hashBytes`.
+/// The HMAC block size is the rate.
+#[wasm_bindgen]
+pub fn hmacBytes(key: &[u8], message: &[u8], blockSize: u32, domainByte: u8, outputSize: u32) -> Vec<u32> {
+    let digest = hmac::hmac(
+        |m| digestBytes(m, blockSize, domainByte, outputSize),
+        (blockSize * 4) as usize,
+        key,
+        message,
+    );
+
+    bytesToWords(&digest)
+}
+
+/// PBKDF2 over the HMAC of `hmacBytes` in one call: runs all the iterations and returns `dkLen` bytes
+/// of derived key.
+#[wasm_bindgen]
+pub fn pbkdf2Bytes(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    dkLen: u32,
+    blockSize: u32,
+    domainByte: u8,
+    outputSize: u32,
+) -> Vec<u32> {
+    let derivedKey = hmac::pbkdf2(
+        |m| digestBytes(m, blockSize, domainByte, outputSize),
+        (blockSize * 4) as usize,
+        password,
+        salt,
+        iterations,
+        dkLen as usize,
+    );
+
+    bytesToWords(&derivedKey)
+}
+
+/// HKDF over the HMAC of `hmacBytes` in one call: extracts a key from `ikm` and `salt` and expands it with
+/// `info` to `length` bytes, or returns an empty vector if `length` is more than 255 digests.
+#[wasm_bindgen]
+pub fn hkdfBytes(
+    ikm: &[u8],
+    salt: &[u8],
+    info: &[u8],
+    length: u32,
+    blockSize: u32,
+    domainByte: u8,
+    outputSize: u32,
+) -> Vec<u32> {
+    let okm = hmac::hkdf(
+        |m| digestBytes(m, blockSize, domainByte, outputSize),
+        (blockSize * 4) as usize,
+        ikm,
+        salt,
+        info,
+        length as usize,
+    );
+
+    bytesToWords(&okm)
+}
+
+fn digestBytes(message: &[u8], blockSize: u32, domainByte: u8, outputSize: u32) -> Vec<u8> {
+    let mut state: [X64Word; 25] = [X64Word { high: 0, low: 0 }; 25];
+    absorbPadded(&mut state, blockSize, message.to_vec(), domainByte);
+
+    squeezeBytes(&mut state, (blockSize * 4) as usize, 0, outputSize as usize)
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat {
+    algorithms: &[
+        hashcontext::SHA3,
+        hashcontext::SHA3_224,
+        hashcontext::SHA3_256,
+        hashcontext::SHA3_384,
+        hashcontext::SHA3_512,
+        hashcontext::KECCAK256,
+        hashcontext::SHAKE128,
+        hashcontext::SHAKE256,
+        hashcontext::CSHAKE128,
+        hashcontext::CSHAKE256,
+        hashcontext::KMAC128,
+        hashcontext::KMAC256,
+    ],
+    stateWords: 50,
+};
+
+/// Serializes a hash in progress: its configuration `parameters`, `stateData` as passed to
+/// `doCrypt`, the unprocessed buffer and the total length in bytes. Returns an empty vector for an
+/// algorithm this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    stateData: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, stateData, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shake(message: &[u8], blockSize: u32, outputs: &[u32]) -> Vec<u8> {
+        let words = bytesToWords(message);
+        let mut stateData = [0u32; 50];
+        absorbFinal(&words, message.len() as u32, blockSize, 0x1f, &mut stateData);
+
+        let mut squeezeOffset = 0;
+        let mut output: Vec<u8> = Vec::new();
+        for &nBytes in outputs {
+            let outWords = squeeze(blockSize, &mut stateData, squeezeOffset, nBytes);
+            output.extend((0..nBytes as usize).map(|i| (outWords[i / 4] >> (24 - (i % 4) * 8)) as u8));
+            squeezeOffset = ((squeezeOffset + nBytes - 1) % (blockSize * 4)) + 1;
+        }
+
+        output
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn digest(message: &[u8], blockSize: u32, domainByte: u8, nBytes: u32) -> String {
+        let words = bytesToWords(message);
+        let mut stateData = [0u32; 50];
+        let nWordsReady = doCrypt(0, &words, message.len() as u32, blockSize, &mut stateData, 0);
+        let tail = &words[nWordsReady as usize..];
+        absorbFinal(tail, message.len() as u32 - nWordsReady * 4, blockSize, domainByte, &mut stateData);
+        let outWords = squeeze(blockSize, &mut stateData, 0, nBytes);
+        hex(&(0..nBytes as usize).map(|i| (outWords[i / 4] >> (24 - (i % 4) * 8)) as u8).collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn sha3_matches_fips_202() {
+        assert_eq!(digest(b"abc", 36, 0x06, 28), "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf");
+        assert_eq!(
+            digest(b"abc", 34, 0x06, 32),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        assert_eq!(
+            digest(b"abc", 26, 0x06, 48),
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+             98d88cea927ac7f539f1edf228376d25"
+        );
+        assert_eq!(
+            digest(b"abc", 18, 0x06, 64),
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+             10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        );
+
+        // Several full blocks pass through `doCrypt` before the padded tail
+        let message: Vec<u8> = (0..512).map(|i| i as u8).collect();
+        assert_eq!(
+            digest(&message, 34, 0x06, 32),
+            "d4728ea5e9f3819f2b4760151a8f802dbe9f941fd6fb59b3715892436555772a"
+        );
+    }
+
+    #[test]
+    fn keccak256_uses_the_original_padding() {
+        assert_eq!(digest(b"", 34, 0x01, 32), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        assert_eq!(
+            digest(b"abc", 34, 0x01, 32),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+
+    #[test]
+    fn shake128_matches_fips_202() {
+        assert_eq!(
+            hex(&shake(b"", 42, &[32])),
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+        );
+        assert_eq!(
+            hex(&shake(b"abc", 42, &[32])),
+            "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"
+        );
+    }
+
+    #[test]
+    fn shake256_matches_fips_202() {
+        assert_eq!(
+            hex(&shake(b"", 34, &[64])),
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+             d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        );
+    }
+
+    #[test]
+    fn squeezing_in_pieces_matches_one_squeeze() {
+        // Crosses two rate boundaries, one of them exactly at the end of a piece
+        let whole = shake(b"abc", 42, &[400]);
+        assert_eq!(shake(b"abc", 42, &[1, 167, 100, 132]), whole);
+
+        // A message spanning two blocks
+        let message = [0xa3u8; 200];
+        let whole = shake(&message, 34, &[300]);
+        assert_eq!(shake(&message, 34, &[136, 136, 28]), whole);
+        assert_eq!(
+            hex(&whole[..32]),
+            "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d"
+        );
+
+        // Exactly one block, so the padding fills a block of its own
+        assert_eq!(
+            hex(&shake(&message[..136], 34, &[32])),
+            "ed6a19aeeec3d80f588cc95d705e6c3244a0586d2b15fb0f27070f3002e864e0"
+        );
+    }
+
+    #[test]
+    fn hash_bytes_pads_in_rust() {
+        let hashHex = |words: Vec<u32>, nBytes: u32| hex(&wordsToBytes(&words, nBytes));
+        assert_eq!(hashHex(hashBytes(b"abc", 34, 0x06, 32), 32), digest(b"abc", 34, 0x06, 32));
+        assert_eq!(hashHex(hashBytes(b"abc", 36, 0x06, 28), 28), digest(b"abc", 36, 0x06, 28));
+        assert_eq!(hashHex(hashBytes(b"", 34, 0x01, 32), 32), digest(b"", 34, 0x01, 32));
+
+        let message: Vec<u8> = (0..512).map(|i| i as u8).collect();
+        assert_eq!(hashHex(hashBytes(&message, 34, 0x06, 32), 32), digest(&message, 34, 0x06, 32));
+    }
+
+    #[test]
+    fn hmac_bytes_uses_the_rate_as_block_size() {
+        let hmacHex = |key: &[u8], message: &[u8], blockSize: u32, nBytes: u32| {
+            hex(&wordsToBytes(&hmacBytes(key, message, blockSize, 0x06, nBytes), nBytes))
+        };
+        let message = b"what do ya want for nothing?";
+        assert_eq!(hmacHex(b"Jefe", message, 36, 28), "7fdb8dd88bd2f60d1b798634ad386811c2cfc85bfaf5d52bbace5e66");
+        assert_eq!(
+            hmacHex(b"Jefe", message, 34, 32),
+            "c7d4072e788877ae3596bbb0da73b887c9171f93095b294ae857fbe2645e1ba5"
+        );
+        assert_eq!(
+            hmacHex(b"Jefe", message, 18, 64),
+            "5a4bfeab6166427c7a3647b747292b8384537cdb89afb3bf5665e4c5e709350b\
+             287baec921fd7ca0ee7a0c31d022a95e1fc92ba9d77df883960275beb4e62024"
+        );
+
+        // A key longer than the rate is hashed first
+        assert_eq!(
+            hmacHex(&[0xaa; 200], b"abc", 34, 32),
+            "961fcf59ed455732e405e74f5dc78beb7aa41ad315af5e2b2a0dcf8cef9887e0"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_bytes_uses_the_rate_as_block_size() {
+        let derive = |blockSize: u32, dkLen: u32, outputSize: u32| {
+            hex(&wordsToBytes(&pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 2, dkLen, blockSize, 0x06, outputSize), dkLen))
+        };
+        assert_eq!(
+            derive(34, 40, 32),
+            "144535ca20111fbe6d77a0043b1adf1af1e3b8b8b87db92a44efcd6c6aa66dbb78d7a5b3dea9ae82"
+        );
+        assert_eq!(
+            derive(18, 64, 64),
+            "73d03cdcffd8fd2bf61952c36d778b3f262b786228013015274fe7160604a5f7\
+             124f34e4cf1e4d36fae9c884d371b5113fbbad7c24052b1984af3f9e86979b1e"
+        );
+    }
+
+
+    #[test]
+    fn hkdf_bytes_uses_the_rate_as_block_size() {
+        // The inputs of RFC 5869 test case 1
+        let derive = |blockSize: u32, outputSize: u32| {
+            hex(&wordsToBytes(&hkdfBytes(&[0x0b; 22], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42, blockSize, 0x06, outputSize), 42))
+        };
+        assert_eq!(
+            derive(34, 32),
+            "0c5160501d65021deaf2c14f5abce04c5bd2635abceeba61c2edb6e8ed72674900557728f2c9f2c4c179"
+        );
+        assert_eq!(
+            derive(18, 64),
+            "40e9f17e9bf2ef99425c2b23ccdf20a018ea5513f9ae68e1ea8c626deb57dfa4d56c27ccf2a2a24488a5"
+        );
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let words = bytesToWords(&message);
+
+        // Hash the first 160 bytes of SHA3-256: one block is absorbed and 24 bytes stay buffered
+        let mut stateData = [0u32; 50];
+        let nWordsReady = doCrypt(0, &words[..40], 160, 34, &mut stateData, 0);
+        let context = exportContext(hashcontext::SHA3, &[34], &stateData, &words[nWordsReady as usize..40], 24, 0, 160);
+        assert!(importContext(hashcontext::SHA3_256, &[34], &context).is_empty());
+        // A legacy SHA3 context only resumes with the rate it was started with
+        assert!(importContext(hashcontext::SHA3, &[18], &context).is_empty());
+
+        let fields = importContext(hashcontext::SHA3, &[34], &context);
+        assert_eq!(fields[..4], [0, 160, 50, 24]);
+        assert_eq!(fields[54..], words[34..40]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut stateData = fields[4..54].to_vec();
+        let nWordsReady = doCrypt(0, &words[34..], 64, 34, &mut stateData, 0);
+        absorbFinal(&words[34 + nWordsReady as usize..], 64 - nWordsReady * 4, 34, 0x06, &mut stateData);
+        let resumed = squeeze(34, &mut stateData, 0, 32);
+        assert_eq!(
+            hex(&(0..32).map(|i| (resumed[i / 4] >> (24 - (i % 4) * 8)) as u8).collect::<Vec<u8>>()),
+            digest(&message, 34, 0x06, 32)
+        );
+    }
+}
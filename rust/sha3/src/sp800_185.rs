@@ -0,0 +1,307 @@
+// SHA-3 derived functions from NIST SP 800-185: cSHAKE, KMAC, TupleHash and ParallelHash.
+// `blockSize` is the rate in 32-bit words, 42 for the 128-bit variants and 34 for the 256-bit ones.
+
+use crate::utils::X64Word;
+use crate::{absorbBlocks, absorbPadded, bytesToWords, loadState, squeezeBytes, storeState, wordsToBytes};
+use wasm_bindgen::prelude::*;
+
+const CSHAKE_DOMAIN: u8 = 0x04;
+const SHAKE_DOMAIN: u8 = 0x1f;
+
+pub fn leftEncode(x: u64) -> Vec<u8> {
+    let mut encoded = integerBytes(x);
+    encoded.insert(0, encoded.len() as u8);
+    encoded
+}
+
+pub fn rightEncode(x: u64) -> Vec<u8> {
+    let mut encoded = integerBytes(x);
+    encoded.push(encoded.len() as u8);
+    encoded
+}
+
+// Big-endian bytes of `x` without leading zeros, at least one byte
+fn integerBytes(x: u64) -> Vec<u8> {
+    let bytes = x.to_be_bytes();
+    let skip = bytes.iter().take(7).take_while(|b| **b == 0).count();
+    bytes[skip..].to_vec()
+}
+
+pub fn encodeString(s: &[u8]) -> Vec<u8> {
+    let mut encoded = leftEncode(s.len() as u64 * 8);
+    encoded.extend_from_slice(s);
+    encoded
+}
+
+pub fn bytepad(x: &[u8], w: usize) -> Vec<u8> {
+    let mut padded = leftEncode(w as u64);
+    padded.extend_from_slice(x);
+    while padded.len() % w != 0 {
+        padded.push(0);
+    }
+
+    padded
+}
+
+fn cshakePrefix(blockSize: u32, functionName: &[u8], customization: &[u8]) -> Vec<u8> {
+    let mut names = encodeString(functionName);
+    names.extend(encodeString(customization));
+    bytepad(&names, (blockSize * 4) as usize)
+}
+
+// One-shot cSHAKE, which is plain SHAKE when both strings are empty
+fn cshake(blockSize: u32, message: Vec<u8>, nBytes: usize, functionName: &[u8], customization: &[u8]) -> Vec<u8> {
+    let mut state: [X64Word; 25] = [X64Word { high: 0, low: 0 }; 25];
+    let domainByte = if functionName.is_empty() && customization.is_empty() {
+        SHAKE_DOMAIN
+    } else {
+        absorbBlocks(&mut state, blockSize, &cshakePrefix(blockSize, functionName, customization));
+        CSHAKE_DOMAIN
+    };
+    absorbPadded(&mut state, blockSize, message, domainByte);
+
+    squeezeBytes(&mut state, (blockSize * 4) as usize, 0, nBytes)
+}
+
+// The encoded output length, or 0 for the XOF variants
+fn outputLengthSuffix(outputBits: u32, xof: bool) -> Vec<u8> {
+    rightEncode(if xof { 0 } else { outputBits as u64 })
+}
+
+/// Absorbs the cSHAKE prefix for a function name and customization string into a fresh state.
+/// The message is then absorbed with `doCrypt` and finished with `absorbFinal` using domain byte 0x04.
+/// When both strings are empty cSHAKE is SHAKE, so the caller skips this and uses 0x1f instead.
+#[wasm_bindgen]
+pub fn cshakeInit(
+    blockSize: u32,
+    functionNameWords: &[u32],
+    functionNameSigBytes: u32,
+    customizationWords: &[u32],
+    customizationSigBytes: u32,
+    stateData: &mut [u32],
+) {
+    let functionName = wordsToBytes(functionNameWords, functionNameSigBytes);
+    let customization = wordsToBytes(customizationWords, customizationSigBytes);
+
+    let mut state = loadState(stateData);
+    absorbBlocks(&mut state, blockSize, &cshakePrefix(blockSize, &functionName, &customization));
+    storeState(&state, stateData);
+}
+
+/// Absorbs the KMAC prefix and the padded key into a fresh state.
+#[wasm_bindgen]
+pub fn kmacInit(
+    blockSize: u32,
+    keyWords: &[u32],
+    keySigBytes: u32,
+    customizationWords: &[u32],
+    customizationSigBytes: u32,
+    stateData: &mut [u32],
+) {
+    let key = wordsToBytes(keyWords, keySigBytes);
+    let customization = wordsToBytes(customizationWords, customizationSigBytes);
+
+    let mut state = loadState(stateData);
+    absorbBlocks(&mut state, blockSize, &cshakePrefix(blockSize, b"KMAC", &customization));
+    absorbBlocks(&mut state, blockSize, &bytepad(&encodeString(&key), (blockSize * 4) as usize));
+    storeState(&state, stateData);
+}
+
+/// Absorbs the buffered tail of the message followed by the encoded output length,
+/// or by a zero length in XOF mode, leaving the state ready to squeeze.
+#[wasm_bindgen]
+pub fn kmacFinalize(dataWords: &[u32], dataSigBytes: u32, blockSize: u32, outputBits: u32, xof: bool, stateData: &mut [u32]) {
+    let mut bytes = wordsToBytes(dataWords, dataSigBytes);
+    bytes.extend(outputLengthSuffix(outputBits, xof));
+
+    let mut state = loadState(stateData);
+    absorbPadded(&mut state, blockSize, bytes, CSHAKE_DOMAIN);
+    storeState(&state, stateData);
+}
+
+/// TupleHash over the elements packed back to back in `dataWords`, with their lengths in `elementSigBytes`.
+#[wasm_bindgen]
+pub fn tupleHash(
+    blockSize: u32,
+    dataWords: &[u32],
+    elementSigBytes: &[u32],
+    customizationWords: &[u32],
+    customizationSigBytes: u32,
+    outputBits: u32,
+    xof: bool,
+) -> Vec<u32> {
+    let data = wordsToBytes(dataWords, elementSigBytes.iter().sum());
+    let customization = wordsToBytes(customizationWords, customizationSigBytes);
+
+    let mut encoded: Vec<u8> = Vec::new();
+    let mut start = 0;
+    for &sigBytes in elementSigBytes {
+        let end = start + sigBytes as usize;
+        encoded.extend(encodeString(&data[start..end]));
+        start = end;
+    }
+    encoded.extend(outputLengthSuffix(outputBits, xof));
+
+    bytesToWords(&cshake(blockSize, encoded, (outputBits / 8) as usize, b"TupleHash", &customization))
+}
+
+/// ParallelHash with `chunkSize` byte chunks. Returns an empty vector if `chunkSize` is zero.
+#[wasm_bindgen]
+pub fn parallelHash(
+    blockSize: u32,
+    dataWords: &[u32],
+    dataSigBytes: u32,
+    chunkSize: u32,
+    customizationWords: &[u32],
+    customizationSigBytes: u32,
+    outputBits: u32,
+    xof: bool,
+) -> Vec<u32> {
+    if chunkSize == 0 {
+        return Vec::new();
+    }
+    let data = wordsToBytes(dataWords, dataSigBytes);
+    let customization = wordsToBytes(customizationWords, customizationSigBytes);
+
+    // Each chunk is hashed to twice the security strength, which is the capacity
+    let chunkDigestBytes = 200 - (blockSize * 4) as usize;
+    let chunks: Vec<&[u8]> = data.chunks(chunkSize as usize).collect();
+    let mut encoded = leftEncode(chunkSize as u64);
+    for chunk in chunks.iter() {
+        encoded.extend(cshake(blockSize, chunk.to_vec(), chunkDigestBytes, b"", b""));
+    }
+    encoded.extend(rightEncode(chunks.len() as u64));
+    encoded.extend(outputLengthSuffix(outputBits, xof));
+
+    bytesToWords(&cshake(blockSize, encoded, (outputBits / 8) as usize, b"ParallelHash", &customization))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{absorbFinal, doCrypt, squeeze};
+
+    fn hex(words: &[u32], nBytes: usize) -> String {
+        (0..nBytes).map(|i| format!("{:02x}", (words[i / 4] >> (24 - (i % 4) * 8)) as u8)).collect()
+    }
+
+    fn range(start: u8, end: u8) -> Vec<u8> {
+        (start..end).collect()
+    }
+
+    // Streams the message through `doCrypt` as the JS hasher does, then squeezes `nBytes`
+    fn stream(blockSize: u32, stateData: &mut [u32], message: &[u8], nBytes: u32, finish: impl Fn(&[u32], u32, &mut [u32])) -> String {
+        let words = bytesToWords(message);
+        let nWordsReady = doCrypt(0, &words, message.len() as u32, blockSize, stateData, 0);
+        finish(&words[nWordsReady as usize..], message.len() as u32 - nWordsReady * 4, stateData);
+        hex(&squeeze(blockSize, stateData, 0, nBytes), nBytes as usize)
+    }
+
+    #[test]
+    fn encodings_match_sp_800_185() {
+        assert_eq!(leftEncode(0), [1, 0]);
+        assert_eq!(leftEncode(168), [1, 168]);
+        assert_eq!(leftEncode(0x0100), [2, 1, 0]);
+        assert_eq!(rightEncode(0), [0, 1]);
+        assert_eq!(rightEncode(256), [1, 0, 2]);
+        assert_eq!(encodeString(b""), [1, 0]);
+        assert_eq!(encodeString(b"KMAC"), [1, 32, b'K', b'M', b'A', b'C']);
+        assert_eq!(bytepad(&[1, 2], 4), [1, 4, 1, 2]);
+        assert_eq!(bytepad(&[1, 2, 3], 4), [1, 4, 1, 2, 3, 0, 0, 0]);
+    }
+
+    #[test]
+    fn cshake_matches_nist_samples() {
+        let email = bytesToWords(b"Email Signature");
+        let samples: [(u32, Vec<u8>, u32, &str); 3] = [
+            (42, range(0, 4), 32, "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5"),
+            (42, range(0, 200), 32, "c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b"),
+            (34, range(0, 4), 64, "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+                                   64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"),
+        ];
+        for (blockSize, message, nBytes, expected) in samples.iter() {
+            let mut stateData = [0u32; 50];
+            cshakeInit(*blockSize, &[], 0, &email, 15, &mut stateData);
+            let output = stream(*blockSize, &mut stateData, message, *nBytes, |tail, sigBytes, stateData| {
+                absorbFinal(tail, sigBytes, *blockSize, CSHAKE_DOMAIN, stateData)
+            });
+            assert_eq!(&output, expected);
+        }
+    }
+
+    #[test]
+    fn kmac_matches_nist_samples() {
+        let key = bytesToWords(&range(0x40, 0x60));
+        let tagged = b"My Tagged Application";
+        let samples: [(u32, Vec<u8>, &[u8], u32, bool, &str); 6] = [
+            (42, range(0, 4), b"", 256, false, "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e"),
+            (42, range(0, 4), tagged, 256, false, "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5"),
+            (42, range(0, 200), tagged, 256, false, "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230"),
+            (34, range(0, 4), tagged, 512, false, "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+                                                   f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"),
+            (42, range(0, 4), b"", 256, true, "cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35"),
+            (34, range(0, 4), tagged, 512, true, "1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa9\
+                                                  6faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b"),
+        ];
+        for (blockSize, message, customization, outputBits, xof, expected) in samples.iter() {
+            let mut stateData = [0u32; 50];
+            let customizationWords = bytesToWords(customization);
+            kmacInit(*blockSize, &key, 32, &customizationWords, customization.len() as u32, &mut stateData);
+            let output = stream(*blockSize, &mut stateData, message, outputBits / 8, |tail, sigBytes, stateData| {
+                kmacFinalize(tail, sigBytes, *blockSize, *outputBits, *xof, stateData)
+            });
+            assert_eq!(&output, expected);
+        }
+    }
+
+    #[test]
+    fn tuple_hash_matches_nist_samples() {
+        let elements = [0x00010210, 0x11121314, 0x15000000];
+        let lengths = [3, 6];
+        let app = bytesToWords(b"My Tuple App");
+        assert_eq!(
+            hex(&tupleHash(42, &elements, &lengths, &[], 0, 256, false), 32),
+            "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1"
+        );
+        assert_eq!(
+            hex(&tupleHash(42, &elements, &lengths, &app, 12, 256, false), 32),
+            "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb"
+        );
+        assert_eq!(
+            hex(&tupleHash(34, &elements, &lengths, &[], 0, 512, false), 64),
+            "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec6073\
+             11ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194"
+        );
+        assert_eq!(
+            hex(&tupleHash(42, &elements, &lengths, &[], 0, 256, true), 32),
+            "2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488"
+        );
+    }
+
+    #[test]
+    fn parallel_hash_matches_nist_samples() {
+        let data = bytesToWords(&[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x10, 0x11, 0x12, 0x13,
+            0x14, 0x15, 0x16, 0x17, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27,
+        ]);
+        let app = bytesToWords(b"Parallel Data");
+        assert_eq!(
+            hex(&parallelHash(42, &data, 24, 8, &[], 0, 256, false), 32),
+            "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5"
+        );
+        assert_eq!(
+            hex(&parallelHash(42, &data, 24, 8, &app, 13, 256, false), 32),
+            "fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206"
+        );
+        assert_eq!(
+            hex(&parallelHash(34, &data, 24, 8, &[], 0, 512, false), 64),
+            "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c45110553\
+             1b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429"
+        );
+        assert_eq!(
+            hex(&parallelHash(42, &data, 24, 8, &[], 0, 256, true), 32),
+            "fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3"
+        );
+        assert!(parallelHash(42, &data, 24, 0, &[], 0, 256, false).is_empty());
+    }
+}
@@ -1,16 +1,12 @@
 mod utils;
 
+use hashcontext::ContextFormat;
 use wasm_bindgen::prelude::*;
 use utils::*;
 
 #[wasm_bindgen]
 pub fn doCrypt(nWordsReady: u32, blockSize: u32, dataWords: &[u32], hash: &mut [u32]) {
-    let mut hashU64: [u64; 8] = [0; 8];
-    for i in 0..8 {
-        let hashHigh: u64 = hash[i * 2] as u64;
-        let hashLow: u64 = hash[i * 2 + 1] as u64;
-        hashU64[i] = hashHigh << 32 | hashLow;
-    }
+    let mut hashU64 = wordsToU64(hash);
 
     if nWordsReady > 0 {
         let mut offset = 0;
@@ -19,9 +15,93 @@ pub fn doCrypt(nWordsReady: u32, blockSize: u32, dataWords: &[u32], hash: &mut [
             offset += blockSize;
         }
     }
+    u64ToWords(&hashU64, hash);
+}
+
+/// Initial hash value for a digest of `outputSize` bytes: SHA-384 (48), SHA-512 (64)
+/// or SHA-512/t for any other whole-byte t below 512, generated as in FIPS 180-4, section 5.3.6.
+/// Unsupported sizes yield an empty vector.
+#[wasm_bindgen]
+pub fn getInitialHash(outputSize: u32) -> Vec<u32> {
+    let mut hash: Vec<u32> = vec![0; 16];
+    match outputSize {
+        48 => u64ToWords(&getH384(), &mut hash),
+        64 => u64ToWords(&getH512(), &mut hash),
+        1..=63 => {
+            let mut hashU64 = getH512();
+            for h in hashU64.iter_mut() {
+                *h ^= 0xa5a5a5a5a5a5a5a5;
+            }
+            u64ToWords(&hashU64, &mut hash);
+
+            let name = format!("SHA-512/{}", outputSize * 8);
+            let words: Vec<u32> = name
+                .as_bytes()
+                .chunks(4)
+                .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+                .collect();
+            let nBits = name.len() as u32 * 8;
+            return doFinalize(&words, name.len() as u32, 0, nBits, &mut hash, 64);
+        }
+        _ => return Vec::new(),
+    }
+
+    hash
+}
+
+/// Pads the buffered tail of the message, hashes it into `hash` and returns the digest
+/// truncated to `outputSize` bytes. The message length in bits is passed as two words.
+#[wasm_bindgen]
+pub fn doFinalize(
+    dataWords: &[u32],
+    dataSigBytes: u32,
+    nBitsTotalHigh: u32,
+    nBitsTotalLow: u32,
+    hash: &mut [u32],
+    outputSize: u32,
+) -> Vec<u32> {
+    let sigBytes = dataSigBytes as usize;
+    let mut block: Vec<u32> = (0..(sigBytes + 3) / 4).map(|i| *dataWords.get(i).unwrap_or(&0)).collect();
+    if sigBytes % 4 != 0 {
+        block[sigBytes / 4] &= 0xffffffff << (32 - (sigBytes % 4) * 8);
+    }
+
+    // Append the 1 bit, then zeros up to 112 bytes mod 128, then the 128-bit length
+    if sigBytes % 4 == 0 {
+        block.push(0);
+    }
+    block[sigBytes / 4] |= 0x80 << (24 - (sigBytes % 4) * 8);
+    while block.len() % 32 != 28 {
+        block.push(0);
+    }
+    block.extend_from_slice(&[0, 0, nBitsTotalHigh, nBitsTotalLow]);
+
+    doCrypt(block.len() as u32, 32, &block, hash);
+
+    let nWords = (outputSize as usize + 3) / 4;
+    let mut digest = hash[..nWords].to_vec();
+    if outputSize % 4 != 0 {
+        digest[nWords - 1] &= 0xffffffff << (32 - (outputSize % 4) * 8);
+    }
+
+    digest
+}
+
+fn wordsToU64(words: &[u32]) -> [u64; 8] {
+    let mut hashU64: [u64; 8] = [0; 8];
     for i in 0..8 {
-        hash[i * 2] = (hashU64[i] >> 32) as u32;
-        hash[i * 2 + 1] = (hashU64[i] & 0xffffffff) as u32;
+        let hashHigh: u64 = words[i * 2] as u64;
+        let hashLow: u64 = words[i * 2 + 1] as u64;
+        hashU64[i] = hashHigh << 32 | hashLow;
+    }
+
+    hashU64
+}
+
+fn u64ToWords(hashU64: &[u64; 8], words: &mut [u32]) {
+    for i in 0..8 {
+        words[i * 2] = (hashU64[i] >> 32) as u32;
+        words[i * 2 + 1] = (hashU64[i] & 0xffffffff) as u32;
     }
 }
 
@@ -82,3 +162,275 @@ fn doCryptBlock(data: &[u32], offsetU32: u32, hash: &mut [u64]) {
     hash[6] = ((hash[6] as u128) + (g as u128)) as u64;
     hash[7] = ((hash[7] as u128) + (h as u128)) as u64;
 }
+
+/// Hashes a whole message in one call, padding included, and returns the digest of
+/// `outputSize` bytes, with the initial hash value of `getInitialHash`. Unsupported sizes yield an empty vector.
+#[wasm_bindgen]
+pub fn hashBytes(message: &[u8], outputSize: u32) -> Vec<u32> {
+    let mut hash = getInitialHash(outputSize);
+    if hash.is_empty() {
+        return hash;
+    }
+
+    let nBits = message.len() as u64 * 8;
+    doFinalize(&bytesToWords(message), message.len() as u32, (nBits >> 32) as u32, nBits as u32, &mut hash, outputSize)
+}
+
+/// HMAC over SHA-384, SHA-512 or SHA-512/t of a whole message in one call, chosen by `outputSize` as in `hashBytes`.
+#[wasm_bindgen]
+pub fn hmacBytes(key: &[u8], message: &[u8], outputSize: u32) -> Vec<u32> {
+    if getInitialHash(outputSize).is_empty() {
+        return Vec::new();
+    }
+
+    bytesToWords(&hmac::hmac(|m| wordsToBytes(&hashBytes(m, outputSize), outputSize), 128, key, message))
+}
+
+/// PBKDF2 over the HMAC of `hmacBytes` in one call: runs all the iterations and returns `dkLen` bytes
+/// of derived key, or an empty vector for an unsupported `outputSize`.
+#[wasm_bindgen]
+pub fn pbkdf2Bytes(password: &[u8], salt: &[u8], iterations: u32, dkLen: u32, outputSize: u32) -> Vec<u32> {
+    if getInitialHash(outputSize).is_empty() {
+        return Vec::new();
+    }
+
+    let hash = |m: &[u8]| wordsToBytes(&hashBytes(m, outputSize), outputSize);
+    bytesToWords(&hmac::pbkdf2(hash, 128, password, salt, iterations, dkLen as usize))
+}
+
+/// HKDF over the HMAC of `hmacBytes` in one call: extracts a key from `ikm` and `salt` and expands it with
+/// `info` to `length` bytes, or returns an empty vector for an unsupported `outputSize` or if `length` is more
+/// than 255 digests.
+#[wasm_bindgen]
+pub fn hkdfBytes(ikm: &[u8], salt: &[u8], info: &[u8], length: u32, outputSize: u32) -> Vec<u32> {
+    if getInitialHash(outputSize).is_empty() {
+        return Vec::new();
+    }
+
+    let hash = |m: &[u8]| wordsToBytes(&hashBytes(m, outputSize), outputSize);
+    bytesToWords(&hmac::hkdf(hash, 128, ikm, salt, info, length as usize))
+}
+
+fn wordsToBytes(words: &[u32], sigBytes: u32) -> Vec<u8> {
+    (0..sigBytes as usize)
+        .map(|i| (*words.get(i / 4).unwrap_or(&0) >> (24 - (i % 4) * 8)) as u8)
+        .collect()
+}
+
+// Pack bytes into big-endian words, zero-filling the last one
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat {
+    algorithms: &[
+        hashcontext::SHA384,
+        hashcontext::SHA512,
+        hashcontext::SHA512_224,
+        hashcontext::SHA512_256,
+    ],
+    stateWords: 16,
+};
+
+/// Serializes a hash in progress: its configuration `parameters`, `hash` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    hash: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, hash, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(message: &[u8], outputSize: u32) -> Vec<u32> {
+        let words: Vec<u32> = message
+            .chunks(4)
+            .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+            .collect();
+        let mut hash = getInitialHash(outputSize);
+        let nWordsReady = (message.len() / 128 * 32) as u32;
+        doCrypt(nWordsReady, 32, &words, &mut hash);
+        let nBits = message.len() as u64 * 8;
+        doFinalize(
+            &words[nWordsReady as usize..],
+            message.len() as u32 - nWordsReady * 4,
+            (nBits >> 32) as u32,
+            nBits as u32,
+            &mut hash,
+            outputSize,
+        )
+    }
+
+    // 112 bytes, so the length no longer fits in the first padded block
+    const TWO_BLOCKS: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+    #[test]
+    fn sha512_matches_fips_180_4() {
+        assert_eq!(
+            digest(b"abc", 64),
+            [0xddaf35a1, 0x93617aba, 0xcc417349, 0xae204131, 0x12e6fa4e, 0x89a97ea2, 0x0a9eeee6, 0x4b55d39a,
+                0x2192992a, 0x274fc1a8, 0x36ba3c23, 0xa3feebbd, 0x454d4423, 0x643ce80e, 0x2a9ac94f, 0xa54ca49f]
+        );
+        assert_eq!(
+            digest(TWO_BLOCKS, 64),
+            [0x8e959b75, 0xdae313da, 0x8cf4f728, 0x14fc143f, 0x8f7779c6, 0xeb9f7fa1, 0x7299aead, 0xb6889018,
+                0x501d289e, 0x4900f7e4, 0x331b99de, 0xc4b5433a, 0xc7d329ee, 0xb6dd2654, 0x5e96e55b, 0x874be909]
+        );
+    }
+
+    #[test]
+    fn sha384_matches_fips_180_4() {
+        assert_eq!(
+            digest(b"abc", 48),
+            [0xcb00753f, 0x45a35e8b, 0xb5a03d69, 0x9ac65007, 0x272c32ab, 0x0eded163,
+                0x1a8b605a, 0x43ff5bed, 0x8086072b, 0xa1e7cc23, 0x58baeca1, 0x34c825a7]
+        );
+        assert_eq!(
+            digest(TWO_BLOCKS, 48),
+            [0x09330c33, 0xf71147e8, 0x3d192fc7, 0x82cd1b47, 0x53111b17, 0x3b3b05d2,
+                0x2fa08086, 0xe3b0f712, 0xfcc7c71a, 0x557e2db9, 0x66c3e9fa, 0x91746039]
+        );
+    }
+
+    #[test]
+    fn sha512_t_generates_the_published_initial_values() {
+        assert_eq!(
+            getInitialHash(28),
+            [0x8c3d37c8, 0x19544da2, 0x73e19966, 0x89dcd4d6, 0x1dfab7ae, 0x32ff9c82, 0x679dd514, 0x582f9fcf,
+                0x0f6d2b69, 0x7bd44da8, 0x77e36f73, 0x04c48942, 0x3f9d85a8, 0x6a1d36c8, 0x1112e6ad, 0x91d692a1]
+        );
+        assert_eq!(
+            getInitialHash(32),
+            [0x22312194, 0xfc2bf72c, 0x9f555fa3, 0xc84c64c2, 0x2393b86b, 0x6f53b151, 0x96387719, 0x5940eabd,
+                0x96283ee2, 0xa88effe3, 0xbe5e1e25, 0x53863992, 0x2b0199fc, 0x2c85b8aa, 0x0eb72ddc, 0x81c52ca2]
+        );
+        assert!(getInitialHash(0).is_empty());
+        assert!(getInitialHash(72).is_empty());
+    }
+
+    #[test]
+    fn sha512_t_matches_fips_180_4() {
+        assert_eq!(
+            digest(b"abc", 28),
+            [0x4634270f, 0x707b6a54, 0xdaae7530, 0x460842e2, 0x0e37ed26, 0x5ceee9a4, 0x3e8924aa]
+        );
+        assert_eq!(
+            digest(TWO_BLOCKS, 28),
+            [0x23fec5bb, 0x94d60b23, 0x30819264, 0x0b0c4533, 0x35d66473, 0x4fe40e72, 0x68674af9]
+        );
+        assert_eq!(
+            digest(b"abc", 32),
+            [0x53048e26, 0x81941ef9, 0x9b2e29b7, 0x6b4c7dab, 0xe4c2d0c6, 0x34fc6d46, 0xe0e2f131, 0x07e7af23]
+        );
+        assert_eq!(
+            digest(TWO_BLOCKS, 32),
+            [0x3928e184, 0xfb8690f8, 0x40da3988, 0x121d31be, 0x65cb9d3e, 0xf83ee614, 0x6feac861, 0xe19b563a]
+        );
+    }
+
+    #[test]
+    fn hash_bytes_pads_in_rust() {
+        assert_eq!(hashBytes(b"abc", 64), digest(b"abc", 64));
+        assert_eq!(hashBytes(TWO_BLOCKS, 48), digest(TWO_BLOCKS, 48));
+        assert_eq!(hashBytes(b"abc", 28), digest(b"abc", 28));
+        assert!(hashBytes(b"abc", 72).is_empty());
+    }
+
+    #[test]
+    fn hmac_bytes_matches_rfc_4231() {
+        assert_eq!(
+            hmacBytes(b"Jefe", b"what do ya want for nothing?", 64),
+            [0x164b7a7b, 0xfcf819e2, 0xe395fbe7, 0x3b56e0a3, 0x87bd6422, 0x2e831fd6, 0x10270cd7, 0xea250554,
+                0x9758bf75, 0xc05a994a, 0x6d034f65, 0xf8f0e6fd, 0xcaeab1a3, 0x4d4a6b4b, 0x636e070a, 0x38bce737]
+        );
+        assert_eq!(
+            hmacBytes(b"Jefe", b"what do ya want for nothing?", 48),
+            [0xaf45d2e3, 0x76484031, 0x617f78d2, 0xb58a6b1b, 0x9c7ef464, 0xf5a01b47,
+                0xe42ec373, 0x6322445e, 0x8e2240ca, 0x5e69e2c7, 0x8b3239ec, 0xfab21649]
+        );
+
+        // A key longer than the block is hashed first
+        assert_eq!(
+            hmacBytes(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First", 64),
+            [0x80b24263, 0xc7c1a3eb, 0xb71493c1, 0xdd7be8b4, 0x9b46d1f4, 0x1b4aeec1, 0x121b0137, 0x83f8f352,
+                0x6b56d037, 0xe05f2598, 0xbd0fd221, 0x5d6a1e52, 0x95e64f73, 0xf63f0aec, 0x8b915a98, 0x5d786598]
+        );
+    }
+
+    #[test]
+    fn pbkdf2_bytes_runs_every_iteration() {
+        let hex = |words: Vec<u32>| words.iter().map(|w| format!("{:08x}", w)).collect::<String>();
+        assert_eq!(
+            hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 3, 80, 64)),
+            "a89ab2bb908c611eb9ab2d5d804073b394953429ce61c45fcf67b34c07b855a72362a1847b1a481df5c685e81868868a\
+             9c150aee9d26ac1067b1ec8efb43d12ff311a9cabb8897abbb77d0b9c9e0829a"
+        );
+        assert_eq!(
+            hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 2, 48, 48)),
+            "27147c2267a1acb00204fdb47607f6f3706b35c0023467a0b16421aa687e3b4af06dc6fdebc9fec716261419fb1f39db"
+        );
+        assert_eq!(
+            hex(pbkdf2Bytes(b"password", b"ATHENA.MIT.EDUraeburn", 2, 28, 28)),
+            "f8e40726a48535ac78f9d893b90bc50d9d25182b5d77d1a7c36c6bd2"
+        );
+    }
+
+
+    #[test]
+    fn hkdf_bytes_uses_the_128_byte_block() {
+        let hex = |words: Vec<u32>| words.iter().map(|w| format!("{:08x}", w)).collect::<String>()[..84].to_string();
+        // The inputs of RFC 5869 test case 1
+        assert_eq!(
+            hex(hkdfBytes(&[0x0b; 22], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42, 64)),
+            "832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c1481579338da362cb8d9f925d7cb"
+        );
+        assert_eq!(
+            hex(hkdfBytes(&[0x0b; 22], &(0..13).collect::<Vec<u8>>(), &(0xf0..0xfa).collect::<Vec<u8>>(), 42, 48)),
+            "9b5097a86038b805309076a44b3a9f38063e25b516dcbf369f394cfab43685f748b6457763e4f0204fc5"
+        );
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..300).map(|i| i as u8).collect();
+
+        // Hash the first 200 bytes: one block is compressed and 72 bytes stay buffered
+        let first = bytesToWords(&message[..200]);
+        let mut hash = getInitialHash(48);
+        doCrypt(32, 32, &first, &mut hash);
+        let context = exportContext(hashcontext::SHA384, &[], &hash, &first[32..], 72, 0, 200);
+        assert!(importContext(hashcontext::SHA512, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::SHA384, &[], &context);
+        assert_eq!(fields[..4], [0, 200, 16, 72]);
+        assert_eq!(fields[20..], first[32..]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut hash = fields[4..20].to_vec();
+        let rest = bytesToWords(&message[128..]);
+        doCrypt(32, 32, &rest, &mut hash);
+        let resumed = doFinalize(&rest[32..], 44, 0, 2400, &mut hash, 48);
+        assert_eq!(resumed, digest(&message, 48));
+    }
+}
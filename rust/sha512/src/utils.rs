@@ -15,4 +15,15 @@ pub fn getK() -> [u64; 80] {
         15097957966210449927, 16922976911328602910, 17689382322260857208, 500013540394364858, 748580250866718886,
         1242879168328830382, 1977374033974150939, 2944078676154940804, 3659926193048069267, 4368137639120453308,
         4836135668995329356, 5532061633213252278, 6448918945643986474, 6902733635092675308, 7801388544844847127]
-}
\ No newline at end of file
+}
+// FIPS 180-4, section 5.3.4
+pub fn getH384() -> [u64; 8] {
+    [0xcbbb9d5dc1059ed8, 0x629a292a367cd507, 0x9159015a3070dd17, 0x152fecd8f70e5939,
+        0x67332667ffc00b31, 0x8eb44a8768581511, 0xdb0c2e0d64f98fa7, 0x47b5481dbefa4fa4]
+}
+
+// FIPS 180-4, section 5.3.5
+pub fn getH512() -> [u64; 8] {
+    [0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+        0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179]
+}
@@ -0,0 +1,212 @@
+mod utils;
+
+use hashcontext::ContextFormat;
+use wasm_bindgen::prelude::*;
+use utils::*;
+
+#[wasm_bindgen]
+pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32, hash: &mut [u32], minBufferSize: u32) -> u32 {
+    let blockSizeBytes = blockSize * 4;
+    let nBlocksReady = if doFlush > 0 {
+        (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes
+    } else {
+        (dataSigBytes / blockSizeBytes).saturating_sub(minBufferSize)
+    };
+
+    let nWordsReady = nBlocksReady * blockSize;
+
+    if nWordsReady > 0 {
+        let mut offset = 0;
+        while offset < nWordsReady {
+            doCryptBlock(dataWords, offset, hash);
+            offset += blockSize;
+        }
+    }
+
+    nWordsReady
+}
+
+/// Initial hash value.
+#[wasm_bindgen]
+pub fn getInitialHash() -> Vec<u32> {
+    getIV().to_vec()
+}
+
+/// Pads the buffered tail of the message, hashes it into `hash` and returns the digest.
+/// The padding is the same as SHA-256's; the message length in bits is passed as two words.
+#[wasm_bindgen]
+pub fn doFinalize(dataWords: &[u32], dataSigBytes: u32, nBitsTotalHigh: u32, nBitsTotalLow: u32, hash: &mut [u32]) -> Vec<u32> {
+    let sigBytes = dataSigBytes as usize;
+    let mut block: Vec<u32> = (0..(sigBytes + 3) / 4).map(|i| *dataWords.get(i).unwrap_or(&0)).collect();
+    if sigBytes % 4 != 0 {
+        block[sigBytes / 4] &= 0xffffffff << (32 - (sigBytes % 4) * 8);
+    }
+
+    // Append the 1 bit, then zeros up to 56 bytes mod 64, then the 64-bit length
+    if sigBytes % 4 == 0 {
+        block.push(0);
+    }
+    block[sigBytes / 4] |= 0x80 << (24 - (sigBytes % 4) * 8);
+    while block.len() % 16 != 14 {
+        block.push(0);
+    }
+    block.push(nBitsTotalHigh);
+    block.push(nBitsTotalLow);
+
+    let mut offset = 0;
+    while offset < block.len() as u32 {
+        doCryptBlock(&block, offset, hash);
+        offset += 16;
+    }
+
+    hash.to_vec()
+}
+
+// GB/T 32905-2016, section 5.3
+fn doCryptBlock(data: &[u32], offsetU32: u32, hash: &mut [u32]) {
+    let T = getT();
+    let offset = offsetU32 as usize;
+
+    // Message expansion
+    let mut W: [u32; 68] = [0; 68];
+    W[..16].copy_from_slice(&data[offset..offset + 16]);
+    for j in 16..68 {
+        W[j] = p1(W[j - 16] ^ W[j - 9] ^ W[j - 3].rotate_left(15)) ^ W[j - 13].rotate_left(7) ^ W[j - 6];
+    }
+
+    // Working variables
+    let mut a = hash[0];
+    let mut b = hash[1];
+    let mut c = hash[2];
+    let mut d = hash[3];
+    let mut e = hash[4];
+    let mut f = hash[5];
+    let mut g = hash[6];
+    let mut h = hash[7];
+
+    for j in 0..64 {
+        let (t, ff, gg) = if j < 16 {
+            (T[0], a ^ b ^ c, e ^ f ^ g)
+        } else {
+            (T[1], (a & b) | (a & c) | (b & c), (e & f) | (!e & g))
+        };
+
+        let ss1 = a.rotate_left(12).wrapping_add(e).wrapping_add(t.rotate_left(j as u32 % 32)).rotate_left(7);
+        let ss2 = ss1 ^ a.rotate_left(12);
+        let tt1 = ff.wrapping_add(d).wrapping_add(ss2).wrapping_add(W[j] ^ W[j + 4]);
+        let tt2 = gg.wrapping_add(h).wrapping_add(ss1).wrapping_add(W[j]);
+
+        d = c;
+        c = b.rotate_left(9);
+        b = a;
+        a = tt1;
+        h = g;
+        g = f.rotate_left(19);
+        f = e;
+        e = p0(tt2);
+    }
+
+    // Intermediate hash value
+    hash[0] ^= a;
+    hash[1] ^= b;
+    hash[2] ^= c;
+    hash[3] ^= d;
+    hash[4] ^= e;
+    hash[5] ^= f;
+    hash[6] ^= g;
+    hash[7] ^= h;
+}
+
+fn p0(x: u32) -> u32 {
+    x ^ x.rotate_left(9) ^ x.rotate_left(17)
+}
+
+fn p1(x: u32) -> u32 {
+    x ^ x.rotate_left(15) ^ x.rotate_left(23)
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::SM3], stateWords: 8 };
+
+/// Serializes a hash in progress: its configuration `parameters`, `hash` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    hash: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, hash, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(message: &[u8]) -> Vec<u32> {
+        let words: Vec<u32> = message
+            .chunks(4)
+            .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+            .collect();
+        let mut hash = getInitialHash();
+        let nWordsReady = doCrypt(0, &words, message.len() as u32, 16, &mut hash, 0);
+        let nBits = message.len() as u64 * 8;
+        doFinalize(
+            &words[nWordsReady as usize..],
+            message.len() as u32 - nWordsReady * 4,
+            (nBits >> 32) as u32,
+            nBits as u32,
+            &mut hash,
+        )
+    }
+
+    #[test]
+    fn matches_gbt_32905_examples() {
+        assert_eq!(
+            digest(b"abc"),
+            [0x66c7f0f4, 0x62eeedd9, 0xd1f2d46b, 0xdc10e4e2, 0x4167c487, 0x5cf2f7a2, 0x297da02b, 0x8f4ba8e0]
+        );
+        // 64 bytes, so the padding takes a second block
+        assert_eq!(
+            digest(&b"abcd".repeat(16)),
+            [0xdebe9ff9, 0x2275b8a1, 0x38604889, 0xc18e5a4d, 0x6fdb70e5, 0x387e5765, 0x293dcba3, 0x9c0c5732]
+        );
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let words: Vec<u32> = message
+            .chunks(4)
+            .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+            .collect();
+
+        // Hash the first 100 bytes: one block is compressed and 36 bytes stay buffered
+        let mut hash: Vec<u32> = getInitialHash();
+        let nWordsReady = doCrypt(0, &words[..25], 100, 16, &mut hash, 0);
+        let context = exportContext(hashcontext::SM3, &[], &hash, &words[nWordsReady as usize..25], 36, 0, 100);
+        assert!(importContext(hashcontext::SHA256, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::SM3, &[], &context);
+        assert_eq!(fields[..4], [0, 100, 8, 36]);
+        assert_eq!(fields[12..], words[16..25]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut hash = fields[4..12].to_vec();
+        let nWordsReady = doCrypt(0, &words[16..], 136, 16, &mut hash, 0);
+        let resumed = doFinalize(&words[16 + nWordsReady as usize..], 136 - nWordsReady * 4, 0, 1600, &mut hash);
+        assert_eq!(resumed, digest(&message));
+    }
+}
@@ -0,0 +1,9 @@
+// GB/T 32905-2016, section 4.1
+pub fn getIV() -> [u32; 8] {
+    [0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d, 0xb0fb0e4e]
+}
+
+// GB/T 32905-2016, section 4.2: the constant of rounds 0 to 15, and of rounds 16 to 63
+pub fn getT() -> [u32; 2] {
+    [0x79cc4519, 0x7a879d8a]
+}
@@ -0,0 +1,146 @@
+mod utils;
+
+use crate::utils::{getCK, getFK, getSbox};
+use blockmode::BlockCipher;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn doEncrypt(
+    mode: &str,
+    nWordsReady: usize,
+    iv: &[u32],
+    dataWords: &mut [u32],
+    keyWords: &[u32],
+) -> Option<Vec<u32>> {
+    let cipher = Sm4::new(keyWords);
+    blockmode::doEncrypt(&cipher, mode, nWordsReady, iv, dataWords)
+}
+
+#[wasm_bindgen]
+pub fn doDecrypt(
+    mode: &str,
+    nWordsReady: usize,
+    iv: &[u32],
+    dataWords: &mut [u32],
+    keyWords: &[u32],
+) -> Option<Vec<u32>> {
+    let cipher = Sm4::new(keyWords);
+    blockmode::doDecrypt(&cipher, mode, nWordsReady, iv, dataWords)
+}
+
+#[wasm_bindgen]
+pub fn doEncryptCts(mode: &str, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, keyWords: &[u32]) -> bool {
+    let cipher = Sm4::new(keyWords);
+    blockmode::doEncryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+#[wasm_bindgen]
+pub fn doDecryptCts(mode: &str, iv: &[u32], dataWords: &mut [u32], dataSigBytes: usize, keyWords: &[u32]) -> bool {
+    let cipher = Sm4::new(keyWords);
+    blockmode::doDecryptCts(&cipher, mode, iv, dataWords, dataSigBytes)
+}
+
+struct Sm4 {
+    roundKeys: [u32; 32],
+    SBOX: [u8; 256],
+}
+
+impl Sm4 {
+    // Key expansion, GB/T 32907-2016 section 7.3
+    fn new(keyWords: &[u32]) -> Sm4 {
+        let SBOX = getSbox();
+        let FK = getFK();
+        let CK = getCK();
+
+        let mut k: [u32; 4] = [0; 4];
+        for i in 0..4 {
+            k[i] = keyWords[i] ^ FK[i];
+        }
+        let mut roundKeys: [u32; 32] = [0; 32];
+        for i in 0..32 {
+            let b = tau(&SBOX, k[1] ^ k[2] ^ k[3] ^ CK[i]);
+            roundKeys[i] = k[0] ^ b ^ b.rotate_left(13) ^ b.rotate_left(23);
+            k = [k[1], k[2], k[3], roundKeys[i]];
+        }
+
+        Sm4 { roundKeys, SBOX }
+    }
+
+    fn cryptBlock(&self, dataWords: &mut [u32], offset: usize, decrypt: bool) {
+        let mut x: [u32; 4] = [dataWords[offset], dataWords[offset + 1], dataWords[offset + 2], dataWords[offset + 3]];
+
+        // Decryption is the same 32 rounds with the round keys reversed
+        for round in 0..32 {
+            let rk = if decrypt { self.roundKeys[31 - round] } else { self.roundKeys[round] };
+            let b = tau(&self.SBOX, x[1] ^ x[2] ^ x[3] ^ rk);
+            let t = x[0] ^ b ^ b.rotate_left(2) ^ b.rotate_left(10) ^ b.rotate_left(18) ^ b.rotate_left(24);
+            x = [x[1], x[2], x[3], t];
+        }
+
+        // Reverse transformation R
+        dataWords[offset] = x[3];
+        dataWords[offset + 1] = x[2];
+        dataWords[offset + 2] = x[1];
+        dataWords[offset + 3] = x[0];
+    }
+}
+
+impl BlockCipher for Sm4 {
+    fn blockSize(&self) -> usize {
+        4
+    }
+
+    fn encryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        self.cryptBlock(dataWords, offset, false);
+    }
+
+    fn decryptBlock(&self, dataWords: &mut [u32], offset: usize) {
+        self.cryptBlock(dataWords, offset, true);
+    }
+}
+
+// The S-box applied to each byte of a word
+fn tau(SBOX: &[u8; 256], a: u32) -> u32 {
+    (SBOX[(a >> 24) as usize] as u32) << 24
+        | (SBOX[(a >> 16 & 0xff) as usize] as u32) << 16
+        | (SBOX[(a >> 8 & 0xff) as usize] as u32) << 8
+        | SBOX[(a & 0xff) as usize] as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // GB/T 32907-2016, appendix A: the key and the plaintext are the same
+    const KEY: [u32; 4] = [0x01234567, 0x89abcdef, 0xfedcba98, 0x76543210];
+    const IV: [u32; 4] = [0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f];
+
+    #[test]
+    fn ecb_matches_gbt_32907_examples() {
+        let mut data = KEY;
+        doEncrypt("ecb", 4, &IV, &mut data, &KEY);
+        assert_eq!(data, [0x681edf34, 0xd206965e, 0x86b3e94f, 0x536e4246]);
+        doDecrypt("ecb", 4, &IV, &mut data, &KEY);
+        assert_eq!(data, KEY);
+
+        // Example 2: the plaintext encrypted 1,000,000 times
+        let cipher = Sm4::new(&KEY);
+        let mut data = KEY;
+        for _ in 0..1000000 {
+            cipher.encryptBlock(&mut data, 0);
+        }
+        assert_eq!(data, [0x595298c7, 0xc6fd271f, 0x0402f804, 0xc33d3f66]);
+    }
+
+    #[test]
+    fn modes_round_trip() {
+        let plaintext = [0x4e6f7720, 0x69732074, 0x68652074, 0x696d6520, 0x666f7220, 0x616c6c20, 0x676f6f64, 0x206d656e];
+        for mode in ["cbc", "ecb", "cfb", "ofb", "ctr"] {
+            let mut data = plaintext;
+            doEncrypt(mode, 8, &IV, &mut data, &KEY);
+            assert_ne!(data, plaintext, "{}", mode);
+            doDecrypt(mode, 8, &IV, &mut data, &KEY);
+            assert_eq!(data, plaintext, "{}", mode);
+        }
+    }
+}
@@ -0,0 +1,316 @@
+mod utils;
+
+use hashcontext::ContextFormat;
+use wasm_bindgen::prelude::*;
+use utils::*;
+
+// The state passed between calls: the chaining value h, the bit counter N and the
+// checksum Σ, each a 512-bit little-endian number stored as 8 pairs of (high, low) words
+const STATE_WORDS: usize = 48;
+const BLOCK_BYTES: usize = 64;
+
+/// Initial state for a digest of `outputSize` bytes, 32 for Streebog-256 or 64 for Streebog-512.
+/// Returns an empty vector for any other size.
+#[wasm_bindgen]
+pub fn getInitialState(outputSize: u32) -> Vec<u32> {
+    let iv: u64 = match outputSize {
+        32 => 0x0101010101010101,
+        64 => 0,
+        _ => return Vec::new(),
+    };
+
+    let mut state: Vec<u32> = vec![0; STATE_WORDS];
+    storeState(&[iv; 8], &[0; 8], &[0; 8], &mut state);
+    state
+}
+
+/// Compresses the complete blocks that are ready, like `sha256::doCrypt`.
+/// The padding always adds a block of its own, so no full block needs to be held back.
+#[wasm_bindgen]
+pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32, state: &mut [u32], minBufferSize: u32) -> u32 {
+    let blockSizeBytes = blockSize * 4;
+    let nBlocksReady = if doFlush > 0 {
+        (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes
+    } else {
+        (dataSigBytes / blockSizeBytes).saturating_sub(minBufferSize)
+    };
+
+    let nWordsReady = nBlocksReady * blockSize;
+
+    if nWordsReady > 0 {
+        let tables = getTables();
+        let (mut h, mut N, mut sigma) = loadState(state);
+        let mut offset = 0;
+        while offset < nWordsReady as usize * 4 {
+            let block = readBlock(dataWords, offset, BLOCK_BYTES);
+            compress(&mut h, &N, &block, &tables);
+            add(&mut N, &[512, 0, 0, 0, 0, 0, 0, 0]);
+            add(&mut sigma, &block);
+            offset += BLOCK_BYTES;
+        }
+        storeState(&h, &N, &sigma, state);
+    }
+
+    nWordsReady
+}
+
+/// Compresses the rest of the buffer, then the padded last block, the bit counter and the checksum,
+/// and returns the digest: all of h for Streebog-512, its last 32 bytes for Streebog-256.
+#[wasm_bindgen]
+pub fn doFinalize(dataWords: &[u32], dataSigBytes: u32, state: &mut [u32], outputSize: u32) -> Vec<u32> {
+    let sigBytes = dataSigBytes as usize;
+    let tables = getTables();
+    let (mut h, mut N, mut sigma) = loadState(state);
+
+    let mut offset = 0;
+    while sigBytes - offset >= BLOCK_BYTES {
+        let block = readBlock(dataWords, offset, BLOCK_BYTES);
+        compress(&mut h, &N, &block, &tables);
+        add(&mut N, &[512, 0, 0, 0, 0, 0, 0, 0]);
+        add(&mut sigma, &block);
+        offset += BLOCK_BYTES;
+    }
+
+    // The remaining bytes, then a single 1 bit, then zeros
+    let remaining = sigBytes - offset;
+    let mut block = readBlock(dataWords, offset, remaining);
+    block[remaining / 8] |= 1 << (remaining % 8 * 8);
+    compress(&mut h, &N, &block, &tables);
+    add(&mut N, &[remaining as u64 * 8, 0, 0, 0, 0, 0, 0, 0]);
+    add(&mut sigma, &block);
+
+    compress(&mut h, &[0; 8], &N, &tables);
+    compress(&mut h, &[0; 8], &sigma, &tables);
+    storeState(&h, &N, &sigma, state);
+
+    let bytes: Vec<u8> = h.iter().flat_map(|w| w.to_le_bytes().to_vec()).collect();
+    bytesToWords(&bytes[BLOCK_BYTES - outputSize as usize..])
+}
+
+struct Tables {
+    // Entry x of table k is l applied to the byte π(x) in byte position k,
+    // so that LPS on one word is eight lookups
+    T: [[u64; 256]; 8],
+    C: [[u64; 8]; 12],
+}
+
+fn getTables() -> Tables {
+    let pi = getPi();
+    let A = getA();
+
+    let mut T: [[u64; 256]; 8] = [[0; 256]; 8];
+    for k in 0..8 {
+        for x in 0..256 {
+            let byte = pi[x];
+            let mut value: u64 = 0;
+            for bit in 0..8 {
+                if byte >> bit & 1 == 1 {
+                    value ^= A[63 - (k * 8 + bit)];
+                }
+            }
+            T[k][x] = value;
+        }
+    }
+
+    Tables { T, C: getC() }
+}
+
+// The composition L ∘ P ∘ S. P transposes the 8x8 byte matrix, so byte k of
+// output word i comes from byte i of input word k
+fn lps(state: &[u64; 8], T: &[[u64; 256]; 8]) -> [u64; 8] {
+    let mut result: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        for k in 0..8 {
+            result[i] ^= T[k][(state[k] >> (8 * i)) as u8 as usize];
+        }
+    }
+    result
+}
+
+fn xor(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let mut result: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        result[i] = a[i] ^ b[i];
+    }
+    result
+}
+
+// The compression function g_N(h, m) = E(LPS(h ⊕ N), m) ⊕ h ⊕ m
+fn compress(h: &mut [u64; 8], N: &[u64; 8], m: &[u64; 8], tables: &Tables) {
+    let mut key = lps(&xor(h, N), &tables.T);
+    let mut state = xor(&key, m);
+    for i in 0..12 {
+        state = lps(&state, &tables.T);
+        key = lps(&xor(&key, &tables.C[i]), &tables.T);
+        state = xor(&state, &key);
+    }
+    *h = xor(&xor(&state, h), m);
+}
+
+// Addition modulo 2^512
+fn add(a: &mut [u64; 8], b: &[u64; 8]) {
+    let mut carry = false;
+    for i in 0..8 {
+        let (sum, overflow1) = a[i].overflowing_add(b[i]);
+        let (sum, overflow2) = sum.overflowing_add(carry as u64);
+        a[i] = sum;
+        carry = overflow1 || overflow2;
+    }
+}
+
+// Reads `length` bytes from `start` of the big-endian words and zero-pads them to a little-endian block
+fn readBlock(dataWords: &[u32], start: usize, length: usize) -> [u64; 8] {
+    let mut bytes: [u8; BLOCK_BYTES] = [0; BLOCK_BYTES];
+    for i in 0..length {
+        let pos = start + i;
+        bytes[i] = (*dataWords.get(pos / 4).unwrap_or(&0) >> (24 - (pos % 4) * 8)) as u8;
+    }
+
+    let mut block: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        let mut le: [u8; 8] = [0; 8];
+        le.copy_from_slice(&bytes[i * 8..i * 8 + 8]);
+        block[i] = u64::from_le_bytes(le);
+    }
+    block
+}
+
+fn loadState(state: &[u32]) -> ([u64; 8], [u64; 8], [u64; 8]) {
+    let mut values: [[u64; 8]; 3] = [[0; 8]; 3];
+    for j in 0..3 {
+        for i in 0..8 {
+            values[j][i] = (state[j * 16 + i * 2] as u64) << 32 | state[j * 16 + i * 2 + 1] as u64;
+        }
+    }
+    (values[0], values[1], values[2])
+}
+
+fn storeState(h: &[u64; 8], N: &[u64; 8], sigma: &[u64; 8], state: &mut [u32]) {
+    for (j, values) in [h, N, sigma].iter().enumerate() {
+        for i in 0..8 {
+            state[j * 16 + i * 2] = (values[i] >> 32) as u32;
+            state[j * 16 + i * 2 + 1] = values[i] as u32;
+        }
+    }
+}
+
+fn bytesToWords(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+        .collect()
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::STREEBOG256, hashcontext::STREEBOG512], stateWords: STATE_WORDS };
+
+/// Serializes a hash in progress: its configuration `parameters`, `state` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    state: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, state, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(message: &[u8], outputSize: u32, chunk: usize) -> String {
+        let mut state = getInitialState(outputSize);
+        let mut buffer: Vec<u8> = Vec::new();
+        for part in message.chunks(chunk) {
+            buffer.extend_from_slice(part);
+            let nWordsReady = doCrypt(0, &bytesToWords(&buffer), buffer.len() as u32, 16, &mut state, 0);
+            buffer.drain(..nWordsReady as usize * 4);
+        }
+        let words = doFinalize(&bytesToWords(&buffer), buffer.len() as u32, &mut state, outputSize);
+
+        words.iter().map(|w| format!("{:08x}", w)).collect()
+    }
+
+    fn fromHex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn pi_is_a_permutation() {
+        let mut pi = getPi().to_vec();
+        pi.sort();
+        assert_eq!(pi, (0..=255).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn rejects_unsupported_output_size() {
+        assert!(getInitialState(48).is_empty());
+    }
+
+    #[test]
+    fn matches_rfc_6986_examples() {
+        // M1, 63 bytes
+        let m1 = b"012345678901234567890123456789012345678901234567890123456789012";
+        // M2, 72 bytes
+        let m2 = fromHex(
+            "d1e520e2e5f2f0e82c20d1f2f0e8e1eee6e820e2edf3f6e82c20e2e5fef2fa20f120eceef0ff20f1f2f0e5ebe0ece8\
+             20ede020f5f0e0e1f0fbff20efebfaeafb20c8e3eef0e5e2fb",
+        );
+
+        for chunk in [1, 7, 64, 100].iter() {
+            assert_eq!(
+                digest(m1, 64, *chunk),
+                "1b54d01a4af5b9d5cc3d86d68d285462b19abc2475222f35c085122be4ba1ffa\
+                 00ad30f8767b3a82384c6574f024c311e2a481332b08ef7f41797891c1646f48"
+            );
+            assert_eq!(
+                digest(m1, 32, *chunk),
+                "9d151eefd8590b89daa6ba6cb74af9275dd051026bb149a452fd84e5e57b5500"
+            );
+            assert_eq!(
+                digest(&m2, 64, *chunk),
+                "1e88e62226bfca6f9994f1f2d51569e0daf8475a3b0fe61a5300eee46d961376\
+                 035fe83549ada2b8620fcd7c496ce5b33f0cb9dddc2b6460143b03dabac9fb28"
+            );
+            assert_eq!(
+                digest(&m2, 32, *chunk),
+                "9dd2fe4e90409e5da87f53976d7405b0c0cac628fc669a741d50063c557e8f50"
+            );
+        }
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let words = bytesToWords(&message);
+
+        // Hash the first 100 bytes of Streebog-512: one block is compressed and 36 bytes stay buffered
+        let mut state = getInitialState(64);
+        let nWordsReady = doCrypt(0, &words[..25], 100, 16, &mut state, 0);
+        let context = exportContext(hashcontext::STREEBOG512, &[], &state, &words[nWordsReady as usize..25], 36, 0, 100);
+        assert!(importContext(hashcontext::STREEBOG256, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::STREEBOG512, &[], &context);
+        assert_eq!(fields[..4], [0, 100, STATE_WORDS as u32, 36]);
+        assert_eq!(fields[4 + STATE_WORDS..], words[16..25]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut state = fields[4..4 + STATE_WORDS].to_vec();
+        let nWordsReady = doCrypt(0, &words[16..], 136, 16, &mut state, 0);
+        let resumed = doFinalize(&words[16 + nWordsReady as usize..], 136 - nWordsReady * 4, &mut state, 64);
+        assert_eq!(resumed.iter().map(|w| format!("{:08x}", w)).collect::<String>(), digest(&message, 64, 200));
+    }
+}
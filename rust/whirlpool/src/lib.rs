@@ -0,0 +1,262 @@
+mod utils;
+
+use hashcontext::ContextFormat;
+use wasm_bindgen::prelude::*;
+use utils::*;
+
+const ROUNDS: usize = 10;
+
+#[wasm_bindgen]
+pub fn doCrypt(doFlush: u8, dataWords: &[u32], dataSigBytes: u32, blockSize: u32, hash: &mut [u32], minBufferSize: u32) -> u32 {
+    let blockSizeBytes = blockSize * 4;
+    let nBlocksReady = if doFlush > 0 {
+        (dataSigBytes + blockSizeBytes - 1) / blockSizeBytes
+    } else {
+        (dataSigBytes / blockSizeBytes).saturating_sub(minBufferSize)
+    };
+
+    let nWordsReady = nBlocksReady * blockSize;
+
+    if nWordsReady > 0 {
+        let tables = getTables();
+        let mut offset = 0;
+        while offset < nWordsReady {
+            doCryptBlock(dataWords, offset, hash, &tables);
+            offset += blockSize;
+        }
+    }
+
+    nWordsReady
+}
+
+/// Pads the buffered tail of the message, hashes it into `hash` and returns the digest.
+/// The message length in bits is passed as two words; Whirlpool's 256-bit length field
+/// is zero above them.
+#[wasm_bindgen]
+pub fn doFinalize(dataWords: &[u32], dataSigBytes: u32, nBitsTotalHigh: u32, nBitsTotalLow: u32, hash: &mut [u32]) -> Vec<u32> {
+    let sigBytes = dataSigBytes as usize;
+    let mut block: Vec<u32> = (0..(sigBytes + 3) / 4).map(|i| *dataWords.get(i).unwrap_or(&0)).collect();
+    if sigBytes % 4 != 0 {
+        block[sigBytes / 4] &= 0xffffffff << (32 - (sigBytes % 4) * 8);
+    }
+
+    // Append the 1 bit, then zeros up to 32 bytes mod 64, then the 256-bit length
+    if sigBytes % 4 == 0 {
+        block.push(0);
+    }
+    block[sigBytes / 4] |= 0x80 << (24 - (sigBytes % 4) * 8);
+    while block.len() % 16 != 8 {
+        block.push(0);
+    }
+    block.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    block.push(nBitsTotalHigh);
+    block.push(nBitsTotalLow);
+
+    let tables = getTables();
+    let mut offset = 0;
+    while offset < block.len() as u32 {
+        doCryptBlock(&block, offset, hash, &tables);
+        offset += 16;
+    }
+
+    hash.to_vec()
+}
+
+struct Tables {
+    // Entry x of table k is S[x] times the diffusion matrix row, rotated by k bytes,
+    // so that one round of γ, π and θ is eight lookups per row
+    T: [[u64; 256]; 8],
+    // c^r: the first row holds S[8r] to S[8r + 7], the other rows are zero
+    roundConstants: [u64; ROUNDS],
+}
+
+fn getTables() -> Tables {
+    let S = getSbox();
+    let C = getC();
+
+    let mut T: [[u64; 256]; 8] = [[0; 256]; 8];
+    for x in 0..256 {
+        let mut row: u64 = 0;
+        for j in 0..8 {
+            row = row << 8 | multiply(S[x], C[j]) as u64;
+        }
+        for k in 0..8 {
+            T[k][x] = row.rotate_right(8 * k as u32);
+        }
+    }
+
+    let mut roundConstants: [u64; ROUNDS] = [0; ROUNDS];
+    for r in 0..ROUNDS {
+        for j in 0..8 {
+            roundConstants[r] = roundConstants[r] << 8 | S[8 * r + j] as u64;
+        }
+    }
+
+    Tables { T, roundConstants }
+}
+
+// Multiplication in GF(2^8) with the reduction polynomial x^8 + x^4 + x^3 + x^2 + 1
+fn multiply(a: u8, b: u8) -> u8 {
+    let mut a = a;
+    let mut b = b;
+    let mut product: u8 = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        a = if a & 0x80 != 0 { (a << 1) ^ 0x1d } else { a << 1 };
+        b >>= 1;
+    }
+    product
+}
+
+// One round without the key addition: γ (S-box), π (cyclical column shifts) and θ (diffusion)
+fn round(state: &[u64; 8], T: &[[u64; 256]; 8]) -> [u64; 8] {
+    let mut result: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        for k in 0..8 {
+            let byte = (state[(i + 8 - k) % 8] >> (56 - 8 * k)) as u8;
+            result[i] ^= T[k][byte as usize];
+        }
+    }
+    result
+}
+
+// The Miyaguchi-Preneel compression of one block with the dedicated block cipher W
+fn doCryptBlock(data: &[u32], offsetU32: u32, hash: &mut [u32], tables: &Tables) {
+    let offset = offsetU32 as usize;
+
+    let mut key: [u64; 8] = [0; 8];
+    let mut block: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        key[i] = (hash[i * 2] as u64) << 32 | hash[i * 2 + 1] as u64;
+        block[i] = (data[offset + i * 2] as u64) << 32 | data[offset + i * 2 + 1] as u64;
+    }
+
+    let mut state: [u64; 8] = [0; 8];
+    for i in 0..8 {
+        state[i] = block[i] ^ key[i];
+    }
+
+    for r in 0..ROUNDS {
+        key = round(&key, &tables.T);
+        key[0] ^= tables.roundConstants[r];
+
+        state = round(&state, &tables.T);
+        for i in 0..8 {
+            state[i] ^= key[i];
+        }
+    }
+
+    for i in 0..8 {
+        let value = state[i] ^ block[i] ^ ((hash[i * 2] as u64) << 32 | hash[i * 2 + 1] as u64);
+        hash[i * 2] = (value >> 32) as u32;
+        hash[i * 2 + 1] = value as u32;
+    }
+}
+
+// The algorithms whose hashing context this crate can export and resume
+const CONTEXT: ContextFormat = ContextFormat { algorithms: &[hashcontext::WHIRLPOOL], stateWords: 16 };
+
+/// Serializes a hash in progress: its configuration `parameters`, `hash` as passed to `doCrypt`,
+/// the unprocessed buffer and the total length in bytes. Returns an empty vector for an algorithm
+/// this crate does not implement.
+#[wasm_bindgen]
+pub fn exportContext(
+    algorithmId: u32,
+    parameters: &[u32],
+    hash: &[u32],
+    bufferWords: &[u32],
+    bufferSigBytes: u32,
+    nBytesTotalHigh: u32,
+    nBytesTotalLow: u32,
+) -> Vec<u32> {
+    CONTEXT.export(algorithmId, parameters, hash, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow)
+}
+
+/// Checks a context from `exportContext` and returns its fields from the total length on,
+/// or an empty vector if it can not be resumed as `algorithmId` with `parameters`.
+#[wasm_bindgen]
+pub fn importContext(algorithmId: u32, parameters: &[u32], context: &[u32]) -> Vec<u32> {
+    CONTEXT.import(algorithmId, parameters, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(message: &[u8]) -> String {
+        let words: Vec<u32> = message
+            .chunks(4)
+            .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+            .collect();
+        let mut hash: Vec<u32> = vec![0; 16];
+        let nWordsReady = doCrypt(0, &words, message.len() as u32, 16, &mut hash, 0);
+        let nBits = message.len() as u64 * 8;
+        let words = doFinalize(
+            &words[nWordsReady as usize..],
+            message.len() as u32 - nWordsReady * 4,
+            (nBits >> 32) as u32,
+            nBits as u32,
+            &mut hash,
+        );
+
+        words.iter().map(|w| format!("{:08x}", w)).collect()
+    }
+
+    #[test]
+    fn sbox_matches_specification() {
+        let S = getSbox();
+        assert_eq!(S[..8], [0x18, 0x23, 0xc6, 0xe8, 0x87, 0xb8, 0x01, 0x4f]);
+    }
+
+    #[test]
+    fn matches_iso_10118_3_vectors() {
+        assert_eq!(
+            digest(b""),
+            "19fa61d75522a4669b44e39c1d2e1726c530232130d407f89afee0964997f7a7\
+             3e83be698b288febcf88e3e03c4f0757ea8964e59b63d93708b138cc42a66eb3"
+        );
+        assert_eq!(
+            digest(b"abc"),
+            "4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c\
+             7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5"
+        );
+        // 64 bytes, a whole block followed by a block of padding
+        assert_eq!(
+            digest(format!("{}{}", "x".repeat(31), "y".repeat(33)).as_bytes()),
+            "2e928b90e58e13296f437672b81cc627e8fc79cd83b788e1a5db4189fb1dfcce\
+             ce094aba281a1c3eafe7da6be852c79cea66244268e7c5b733167f6dbea4a6d8"
+        );
+        assert_eq!(
+            digest(&[b'a'; 1000]),
+            "fe24b173807796fdac15ebcaf5769f661695601ffeb64490ec0eecd30bd5b2c3\
+             773b36d4edaf3175378b8df114e9496c833ef13606e7ab3d455681e98ecc818f"
+        );
+    }
+
+    #[test]
+    fn resumed_hash_matches_one_shot() {
+        let message: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let words: Vec<u32> = message
+            .chunks(4)
+            .map(|c| c.iter().enumerate().fold(0, |w, (i, b)| w | (*b as u32) << (24 - i * 8)))
+            .collect();
+
+        // Hash the first 100 bytes: one block is compressed and 36 bytes stay buffered
+        let mut hash: Vec<u32> = vec![0; 16];
+        let nWordsReady = doCrypt(0, &words[..25], 100, 16, &mut hash, 0);
+        let context = exportContext(hashcontext::WHIRLPOOL, &[], &hash, &words[nWordsReady as usize..25], 36, 0, 100);
+        assert!(importContext(hashcontext::SM3, &[], &context).is_empty());
+
+        let fields = importContext(hashcontext::WHIRLPOOL, &[], &context);
+        assert_eq!(fields[..4], [0, 100, 16, 36]);
+        assert_eq!(fields[20..], words[16..25]);
+
+        // Resume with the buffered bytes followed by the rest of the message
+        let mut hash = fields[4..20].to_vec();
+        let nWordsReady = doCrypt(0, &words[16..], 136, 16, &mut hash, 0);
+        let resumed = doFinalize(&words[16 + nWordsReady as usize..], 136 - nWordsReady * 4, 0, 1600, &mut hash);
+        assert_eq!(resumed.iter().map(|w| format!("{:08x}", w)).collect::<String>(), digest(&message));
+    }
+}
@@ -0,0 +1,23 @@
+// The Whirlpool specification, section 3.1: the S-box is built from the mini-boxes E, E^-1 and R
+pub fn getSbox() -> [u8; 256] {
+    let E: [u8; 16] = [0x1, 0xb, 0x9, 0xc, 0xd, 0x6, 0xf, 0x3, 0xe, 0x8, 0x7, 0x4, 0xa, 0x2, 0x5, 0x0];
+    let R: [u8; 16] = [0x7, 0xc, 0xb, 0xd, 0xe, 0x4, 0x9, 0xf, 0x6, 0x3, 0x8, 0xa, 0x2, 0x5, 0x1, 0x0];
+    let mut EInv: [u8; 16] = [0; 16];
+    for i in 0..16 {
+        EInv[E[i] as usize] = i as u8;
+    }
+
+    let mut S: [u8; 256] = [0; 256];
+    for u in 0..256 {
+        let high = E[u >> 4];
+        let low = EInv[u & 0xf];
+        let r = R[(high ^ low) as usize];
+        S[u] = E[(high ^ r) as usize] << 4 | EInv[(low ^ r) as usize];
+    }
+    S
+}
+
+// The first row of the circulant diffusion matrix
+pub fn getC() -> [u8; 8] {
+    [0x01, 0x01, 0x04, 0x01, 0x08, 0x05, 0x02, 0x09]
+}
@@ -0,0 +1,249 @@
+import {
+  Base,
+  WordArray
+} from '../../core/core.js';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { Base64 } from '../../encoding/enc-base64';
+import { isString, wordArrayToBytes } from '../../utils';
+import { loadWasm } from '../../utils/wasm-utils';
+import { wasmBytes } from './argon2_wasm';
+import { argon2Wasm } from './argon2_bg';
+
+const ARGON2_TYPES = {
+  argon2d: 0,
+  argon2i: 1,
+  argon2id: 2
+};
+
+// Only version 0x13 of the algorithm is implemented
+const ARGON2_VERSION = 19;
+
+const PHC_PATTERN = /^\$(argon2id|argon2i|argon2d)\$v=(\d+)\$m=(\d+),t=(\d+),p=(\d+)\$([A-Za-z0-9+/]+)\$([A-Za-z0-9+/]+)$/;
+
+// The PHC string format uses Base64 without padding
+const toPhcBase64 = (wordArray) => Base64.stringify(wordArray).replace(/=+$/, '');
+
+/**
+ * Argon2 password hashing (RFC 9106), in its Argon2d, Argon2i and Argon2id variants.
+ */
+export class Argon2Algo extends Base {
+  static wasm = null;
+
+  static async loadWasm() {
+    if (Argon2Algo.wasm) {
+      return Argon2Algo.wasm;
+    }
+
+    Argon2Algo.wasm = await loadWasm(wasmBytes);
+    return Argon2Algo.wasm;
+  }
+
+  async loadWasm() {
+    return Argon2Algo.loadWasm();
+  }
+
+  /**
+   * Initializes a newly created password hashing function.
+   *
+   * @param {Object} cfg (Optional) The configuration options to use for the hashing.
+   *
+   * @example
+   *
+   *     const kdf = new CryptoJSW.algo.Argon2();
+   *     const kdf = new CryptoJSW.algo.Argon2({ keySize: 16 });
+   *     const kdf = new CryptoJSW.algo.Argon2({ type: 'argon2i', iterations: 4, memory: 19456, parallelism: 1 });
+   */
+  constructor(cfg) {
+    super();
+
+    /**
+     * Configuration options.
+     *
+     * @property {string} type The variant, 'argon2d', 'argon2i' or 'argon2id'. Default: 'argon2id'
+     * @property {number} keySize The key size in words to generate. Default: 8 (256 bits)
+     * @property {number} iterations The number of passes over the memory. Default: 3
+     * @property {number} memory The memory size in KiB, at least 8 * parallelism. Default: 65536 (64 MiB)
+     * @property {number} parallelism The number of lanes, computed one after the other. Default: 4
+     * @property {WordArray|string} secret (Optional) A secret key, kept apart from the stored hash.
+     * @property {WordArray|string} associatedData (Optional) Associated data.
+     */
+    this.cfg = Object.assign(
+      new Base(),
+      {
+        type: 'argon2id',
+        keySize: 256 / 32,
+        iterations: 3,
+        memory: 65536,
+        parallelism: 4,
+        secret: '',
+        associatedData: ''
+      },
+      cfg
+    );
+  }
+
+  /**
+   * Computes the Argon2 hash of a password.
+   *
+   * @param {WordArray|string} password The password.
+   * @param {WordArray|string} salt A salt of at least 8 bytes.
+   *
+   * @return {WordArray} The derived key.
+   *
+   * @example
+   *
+   *     const key = kdf.compute(password, salt);
+   */
+  compute(password, salt) {
+    if (!Argon2Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'Argon2Algo.loadWasm\' should be called first');
+    }
+
+    // Shortcuts
+    const { type, keySize, iterations, memory, parallelism, secret, associatedData } = this.cfg;
+    const argonType = ARGON2_TYPES[type];
+    if (argonType === undefined) {
+      throw new Error(`Unknown Argon2 type '${type}', expected 'argon2d', 'argon2i' or 'argon2id'`);
+    }
+    const toBytes = (data) => wordArrayToBytes(isString(data) ? Utf8.parse(data) : data);
+
+    const tagWords = argon2Wasm(Argon2Algo.wasm).argon2(
+      argonType,
+      toBytes(password),
+      toBytes(salt),
+      toBytes(secret),
+      toBytes(associatedData),
+      iterations,
+      memory,
+      parallelism,
+      keySize * 4
+    );
+    if (!tagWords.length) {
+      throw new Error('Invalid Argon2 parameters: the salt must be at least 8 bytes, keySize and iterations must be positive, '
+        + 'parallelism must be between 1 and 2^24 - 1, and memory must be at least 8 * parallelism KiB');
+    }
+
+    return new WordArray(Array.from(tagWords), keySize * 4);
+  }
+
+  /**
+   * Computes the Argon2 hash of a password and encodes it in the PHC string format.
+   *
+   * @param {WordArray|string} password The password.
+   * @param {WordArray|string} salt A salt of at least 8 bytes.
+   *
+   * @return {string} The encoded hash, such as '$argon2id$v=19$m=65536,t=3,p=4$<salt>$<hash>'.
+   *
+   * @example
+   *
+   *     const encoded = kdf.encode(password, salt);
+   */
+  encode(password, salt) {
+    // Shortcuts
+    const { type, iterations, memory, parallelism } = this.cfg;
+    const tag = this.compute(password, salt);
+    const saltWordArray = isString(salt) ? Utf8.parse(salt) : salt;
+
+    return `$${type}$v=${ARGON2_VERSION}$m=${memory},t=${iterations},p=${parallelism}`
+      + `$${toPhcBase64(saltWordArray)}$${toPhcBase64(tag)}`;
+  }
+
+  /**
+   * Checks a password against a hash in the PHC string format.
+   * The type, parameters, salt and hash length come from the encoded hash; the secret and the associated
+   * data come from this function's configuration.
+   *
+   * @param {string} encoded The encoded hash.
+   * @param {WordArray|string} password The password.
+   *
+   * @return {boolean} Whether the password matches.
+   *
+   * @example
+   *
+   *     const matches = kdf.verify(encoded, password);
+   */
+  verify(encoded, password) {
+    const match = PHC_PATTERN.exec(encoded);
+    if (!match) {
+      throw new Error('Invalid Argon2 encoded hash');
+    }
+    const [, type, version, memory, iterations, parallelism, encodedSalt, encodedTag] = match;
+    if (parseInt(version, 10) !== ARGON2_VERSION) {
+      throw new Error(`Unsupported Argon2 version ${version}, only ${ARGON2_VERSION} is supported`);
+    }
+
+    const expected = Base64.parse(encodedTag);
+    const kdf = new Argon2Algo(Object.assign({}, this.cfg, {
+      type,
+      // The hash length in bytes need not be a multiple of 4
+      keySize: expected.sigBytes / 4,
+      iterations: parseInt(iterations, 10),
+      memory: parseInt(memory, 10),
+      parallelism: parseInt(parallelism, 10)
+    }));
+    const actual = kdf.compute(password, Base64.parse(encodedSalt));
+
+    // Compare every word, so that the time taken does not depend on where the hashes differ
+    let difference = 0;
+    for (let i = 0; i < expected.words.length; i++) {
+      difference |= expected.words[i] ^ actual.words[i];
+    }
+
+    return difference === 0;
+  }
+}
+
+/**
+ * Computes the Argon2 hash of a password.
+ *
+ * @param {WordArray|string} password The password.
+ * @param {WordArray|string} salt A salt of at least 8 bytes.
+ * @param {Object} cfg (Optional) The configuration options to use for this computation.
+ *
+ * @return {WordArray} The derived key.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const key = CryptoJSW.Argon2(password, salt);
+ *     const key = CryptoJSW.Argon2(password, salt, { keySize: 16 });
+ *     const key = CryptoJSW.Argon2(password, salt, { type: 'argon2i', iterations: 4, memory: 19456, parallelism: 1 });
+ */
+export const Argon2 = (password, salt, cfg) => new Argon2Algo(cfg).compute(password, salt);
+
+/**
+ * Computes the Argon2 hash of a password and encodes it in the PHC string format.
+ *
+ * @param {WordArray|string} password The password.
+ * @param {WordArray|string} salt A salt of at least 8 bytes.
+ * @param {Object} cfg (Optional) The configuration options to use for this computation.
+ *
+ * @return {string} The encoded hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const encoded = CryptoJSW.Argon2.encode(password, CryptoJSW.lib.WordArray.random(16));
+ */
+Argon2.encode = (password, salt, cfg) => new Argon2Algo(cfg).encode(password, salt);
+
+/**
+ * Checks a password against a hash in the PHC string format.
+ *
+ * @param {string} encoded The encoded hash.
+ * @param {WordArray|string} password The password.
+ * @param {Object} cfg (Optional) The secret and the associated data used for the hash.
+ *
+ * @return {boolean} Whether the password matches.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const matches = CryptoJSW.Argon2.verify(encoded, password);
+ */
+Argon2.verify = (encoded, password, cfg) => new Argon2Algo(cfg).verify(encoded, password);
+
+Argon2.loadWasm = async () => Argon2Algo.loadWasm();
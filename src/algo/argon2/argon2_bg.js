@@ -0,0 +1,76 @@
+export function argon2Wasm(wasm) {
+  let cachegetUint8Memory0 = null;
+  function getUint8Memory0() {
+    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
+      cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
+    }
+    return cachegetUint8Memory0;
+  }
+
+  let cachegetUint32Memory0 = null;
+  function getUint32Memory0() {
+    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
+      cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
+    }
+    return cachegetUint32Memory0;
+  }
+
+  let cachegetInt32Memory0 = null;
+  function getInt32Memory0() {
+    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
+      cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
+    }
+    return cachegetInt32Memory0;
+  }
+
+  function getArrayU32FromWasm0(ptr, len) {
+    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
+  }
+
+  let WASM_VECTOR_LEN = 0;
+
+  function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1);
+    getUint8Memory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+  }
+
+  /**
+   * @param {number} argonType
+   * @param {Uint8Array} password
+   * @param {Uint8Array} salt
+   * @param {Uint8Array} secret
+   * @param {Uint8Array} associatedData
+   * @param {number} iterations
+   * @param {number} memorySize
+   * @param {number} parallelism
+   * @param {number} tagLength
+   * @returns {Uint32Array}
+   */
+  function argon2(argonType, password, salt, secret, associatedData, iterations, memorySize, parallelism, tagLength) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(password, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(salt, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray8ToWasm0(secret, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray8ToWasm0(associatedData, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      wasm.argon2(retptr, argonType, ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3, iterations, memorySize, parallelism, tagLength);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v4 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v4;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  return {
+    argon2: argon2
+  };
+}
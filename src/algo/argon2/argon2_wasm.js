@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJztvQuUXMd1GFhV73X3637d02+AATDAgMR9D0OyR8Snez7dM5RMTQ01AEcQQYqSRVn+NHpmeoDpGcynpzEEZIQ9kgEKtCkLFOU9PLY2i5NQJuIlI2ysXSORjj3x0dnFemkFTnS8WFubcLPchGsrCZMoCeXDFZb3Vr3XNR+ApEzHyglFYV7Ve1X33qq6devWvbeqWWXpJGeM8X/MO48xdozx5jHeZMd4kzePiWaT4R/ePGY1MY1/efOY3aQcPXjzWKyp8urJm8fiTf1GJ3jzWKIZvnOaG5O8eSzZbL1vpXnzmNtsGp/SzTXZtmZz7Qu72XyCHk80m8w6PrXN2m53xNqS7aLNydheMr7Dtm0nRv9i27Zm7fZ0KpVOp1OpuGOLeHxLPBGPJ2KWHY/F3KSwuRC2iNnxuG0nknYsbieYSFjxWMy2bVsI27ZijAk7xhf4li0xi7P2eJI3uVxZWWVu4u9zN36yenK+fkaweKV+fH6ul92RmJx/oH5mocHyqcn5Q9Nzldnpz1bZnkz19MJ8vfHA/FyjerrB7swerzbG5qYb05XZTzQqjSrzM9MnzRLBlnL58fHpucnj1blyvVqZnZ2fYJ/MGi+n6tUq+7l2481JVaqyx3hXmZwsN+bLS43KxEx5YX56rlGts59O7uBMcre9/JHe3R8/OvjQZ+QHYd+xj47tSv3qd8XUiw3R3vSe2MvkK4MzQVs3E8P4H3ApjmQYxKQzlmGQGs0wyEgb0668efMmw49p2azJH928mcCMC2lpNR7OcBeYZEXhABtZWcHeKwmW5i604VsP2uitVxIOtEmnJpm0JZfc2wttOZEOsvjwAq8G6SJj0AaetGuBVxSeGMZPTtAO3oy0ECXWx++t+o7fTmB8Bcb3XMiCV4PMRli3A7OhvnsLWrK3paUFhJrWjpBSGyG9AyBp1S6ChWDk1ncHZn194O9Zg6wNkIh/LKLnoQgQWLdpFLKHNZpRf5miUID1ZooxF2GDVQussJWWSaC1aSstbKVVg5iizTJaGVPttNYSFzOJs9a00hdYC8kSSJYNsRZZsVoQU2AtiJlkxdb2m6UhKcAxFwTEapBQ1MWQulir5xIELCIuBonbgMKeS1DPJRSJsRrEIRGSGIPEOyJxA23JzWlLEhFryUu2wGiCkvgRgSZo3lMKkkhfAmE7kGzRl1TwoU121PCzXGXeNhImojEXbJGiEcQhPRsImWr4Mcl8V5GiCRIojLiSVG/cVKKIPsQyTDq+J5m/Nc1db0I6vovSzHss8EYzHIS/1cX61GuyWfOT4PkxgvhETSZQ0CXl6UXfxdfWMMQkkyvOmy4iWiGxFeW8NbnONTlYk8utyeXX5AbX5FZZzY+BK0/XAjfDXBeSMrHouxsJoVJNVar1jXp9d5CV7MGMjelOv0Myv91SvZbFAQrl8CVQHcT9GGQl9x4L3NFMAtrALYrnAdogWxSXALKjJOQ7IBsOXrYoLgO0yUtQg6zJTVgrJy5DEKtJtpaRLoOSNJfA4EYEsIYdLwMyIkEKtiHLtBMU+UquBtsIllxx1Px8LefTlH4152/HEq1O1dMWWQa2+0nqOocWtC4/oXhEJh6iBc2rBba86BzCZiMIcGuBC8kexkoWrYGHMhbOpZkgBBE4Xb4FDnKS5Sdc6UBCvnp+lXn9zAUXJ0YSp56rQbhgIYfZyINgzQQxHFNahOKzwQ4QD2cYeLBDpho13YgVB7tZddYruYAE0HaUU97DbtiF6u226O0tRlaPamwUWQFiG0c19q5Hlf8XNarOf85RhR2S19+rMYV2yWtBO6QPZVCyurADhPz2k6vM60OKIOYdYq7kkKXnCsf//+C8+iwZbJnxOyAOW2RzKeis+TtBvOTvgfRLPkD8Jd8H/pIfQOolfy+0yeus5u8aYX63KV+tYege4Wf9u0aYf7c1PML8e0YYbIG7R/jZIPfC7Etw94j18S6/RwrJ4G7oPjdy8+bNm9lfCj7wSNDV5e/Gsi/MBneghL3TMiU3l81am+DMcqF7hD2aESif3mIbQ2K2yUstSTvC/Huhy98Bu30H7h4Rn8zwtOXCLsnk9cSG0sjJQcl6ENpgb8kahjbYU7IGoQ3uLll5aIN7SlYO2qC7ZAECFsPwgRH2ceKrHZL77ZL5Tlq4kiEfykuGUG8b4SXrEcW24UtEQGwcQw7DNmAq2WMxVRlziR6LnSeuIT6STg0/J9TzvOJIrxagUD+MslyxrEKjGAfaRn51ZWXldV4SNwDaRp5eWVm5zkviOmZ+eWVl5QoviWuYufDW/L/IS2IVM09iHVYSVzFzDuuwkriCmc+/VSdfouneEhkR4ktQ8yxoG/mbWN/SOL+K9S2N89cRp6VxPoc4LY3zv8E6QuP8CtYRGueXsY7QSC9iHbEp1r+LEGIa64sIIaax/hZCiGmslxFCTGP9Taxja6zPYx1bY/1bWMfWWC9hHXtTrL+LEBIa67cQQkJj/QcIIaGxXkUICY31f8I6cY31G1gnrrH+PawT11ivYJ34pli/gxCSGuvLCCGpsf4BQkhqrNcQQlJj/Z+xjqOxfhvrOBrr72MdR2NdxTrOplj/KUJwNdbvIQRXY/0ThOBqrDcQgqux/jHWSWms38U6KY31H2OdlMZ6HeukNsX6rxFCRmP9PkLIaKx/hhAyGutrCCGjsf4LrJPWWF/FOmmN9Z9jnbTG+grWSW+K9UcIIauxvokQshrrXyCErMb6BkLIaqz/Eeu0aaw/wDptGuu/wzptGuvrWKdtU6zhpxsmddfNDrpmjtGqySZXTU69Yk6Wt5muIXE3zP65bg7RNZNLVk1GvWrOlY3TVcuLTZCG3XPDHKHrJpNcM/l01ZwqG2drKDAumxJrE6zhCN0wmeS6yafXzKmycbaGAuOKKbMum0JzE6whk9ww+fS6OVU2ztZQYFw1ZdYVU2xeNuX2JlhDPr1hTpWNszUUGKumzLpqis0rpuS+bC4dm2ANp8rG2RoKjGumzFo1xeZVU3JfMRePy+bq9XazNRQYhDWUWYQ1FJuENZTchDVcPAhruH4R1nABXY9VrpymbZ56ics4bq6CJOhFXC/uSXzbWsgvOLUgAbiyv7WgXzC/XDS/XDS/POegjuCqL8+Zqz/USIPUqrOpMmo15DYax6W/epXDM1UOz1Q5PFPl8EyVwzNVDs9QOQw74CWvNRTeOpXDM1UOz1Q5PFPl8EyVwzNVDs+UYZ6pcmzEGqkcnqlyeKbK4Zkqh2eqHJ4pxDxT5fBMlWMj1kjl8EyVwzNVDs9UOTxTiHmmyuGZKodnqhwbsUYqh2eqHJ6pcnimEPNMlcMzVQ7PVDk8U+XYiDVSOTxT5fBMIeaZKodnqhyeqXJ4psrhmSrHRqyRyuGZQswzVQ7PVDk8U+XwTJXDM1UOz1Q5NmKNhJhnqhyeqXJ4psrhmSqHZ6ocnqlyeKbKsRFrpHKY1F03O+iaOUarJptcNTn1ijlZ3ma6RiqH2T/XzSG6ZnLJqsmoV825snG6hirHRqSRymGO0HWTSa6ZfLpqTpWNszVSOUyJtQnWSOUwmeS6yafXzKmycbZGKocpsy6bQnMTrJHKYfLpdXOqbJytkcphyqwrpti8bMrtTbBGKoc5VTbO1kjlMGXWVVNsXjEl92Vz6dgEa6RybJitkcphyqxVU2xeNSX3FXPxuGyuXm83WyOVwzNVDs9UOTxT5fBMlcMzVQ7PVDnWYf0JUzmcdSqH8J0R7t8ruTKIpLn7Lgw0aM5yYAuawOJwzwvB9lm4o+Zvs4ZhGzi1ICXxfQriC101tAxZZBFqzzCw0G6WIHdRqlEjdwKCkjfZYmBn2Gb2GLgXjVH3vm+Ued8o875R5n2jzPtGmfeNMu8bZd43yvzXYZTJmSpHzlQ5cqbKkTNVjpypcuQMlSNnyLBcayhy61SOnKly5EyVI2eqHDlT5ciZKkfOlGE5U+XYiDVSOXKmypEzVY6cqXLkTJUjZwqxnKly5EyVYyPWSOXImSpHzlQ5cqbKkTOFWM5UOXKmypEzVY6NWCOVI2eqHDlT5ciZQixnqhw5U+XImSpHzlQ5NmKNVI6cqXLkTCGWM1WOnKly5EyVI2eqHDlT5diINVI5cqYQy5kqR85UOXKmypEzVY6cqXLkTJVjI9ZIiOVMlSNnqhw5U+XImSpHzlQ5cqbKkTNVjo1YI5XDpO662UHXzDFaNdnkqsmpV8zJ8jbTNVI5zP65bg7RNZNLVk1GvWrOlY3TNVQ5NiKNVA5zhK6bTHLN5NNVc6psnK2RymFKrE2wRiqHySTXTT69Zk6VjbM1UjlMmXXZFJqbYI1UDpNPr5tTZeNsjVQOU2ZdMcXmZVNub4I1UjnMqbJxtkYqhymzrppi84opuS+bS8cmWCOVY8NsjVQOU2atmmLzqim5r5iLx2Vz9Xq72RqpHDlT5ciZKkfOVDlypsqRM1WOnKlyrMP6E6ZyrDPKhO9saaFxxIVYj8X8fbCdIhd3ZBjsG4ELL0B6gT6+5O8nw0r3IwHGhe0EpwYd0AlOF8RrcA/s/3iX70KPvx/DNO8ERRDcAc7ojPoKdz/S5bsjzN/vKstMHGIQn4X94L4UHDgL+8Jon32w74kRuAAH8O9I8/xZ8D//AoIja44jec13NupL4IFLZp6WwcfCwC5Tj7IhAa60GkGS9CQLklFP2ahAJVCFqm/y1ZWiRiGf79tv3rffvG+/ed9+87795n37zfv2m/+K7Tfe+jhsHS+fQi0lBR6kSAWxJfOTkvkJaxhsSKCqFcRI09JAkhgBHiFwaoEFVo826Kyx4UTvPeN9ZwTNlZ3R+4S0UQ1KIr1Jpa2kheuuo00RECmDDmw5TCHn96AH7B6AR7GlOf9uyI3YlL7L74a7YC+mxTDE8aBITEWmqzHBk0+phhGZnpZiLMMk9x1rmPS2wIH4bFTPCWus6SUFKxk1LOy1/xy9hL2QVr3QFg1vrSjO9agDh6/n1POVXPi9KFZ6Wua8XM3LaOOcOpmQVEcVIKNOIjzfqU4iXO70kYnsongtp87GvJpTpyleyYFmZm+LGIatoxT3v1WmGtKJAvs78FANnYrqBNcI+Hei9+k17y91qg8x/bZ1OCAJSXU4IJ3EYwdCPh8eB7BAyK+cNzLPn48OCgj5jTCTwiMEZua7YQahfePJ8PQAbSHUyQJs3mCtm7l/2C2STY6nRb2ZgIeHRcUwMPkf+BgevQImX775iw+Rq5albTwM6tYCgUepLMly4vlnhwN7NCPkHj+GtW7eTDyMxy3k3SCks3w8YDPLki8Ck7wxI+8PXcCt/xDIpWeHg7j0CLZ8w8LS7lhXYElrORDLAZPWInYek80lhCVqATJkYMnnnllltYCBJS9hKidYIHAQnEMZDg6wokgDA6coMPgfiODLzw4/lIkDwwMKinw2ivkTUjTkhYsaipMTtjy9CNaML8Dx43jUARw8ycoyDBMdlIjjSayY7rM4nqFkED+MH2SH9PBNR8C6FMQMpy4ULr52grXUcRer1CAuvRqwLpqGCT8JTsDwNaPXCM3p8hPACLoTAmZ4nIwVBQ75aCauiEEsu6M2YYPYIRrQWE54ED9EzMiKogO7QvEenedlGZ52XNV5DLBvXGA4LzpVE73AobFAuj1VrNNVLXWoI/FDh/6QxspRVwYOCHBA0LkcARgz4HT5ccCzGczFAy5x+QQkHl8sIkcgkzs1Hw9BWtKqF4WNKZIvCJHjm3RMHZNiIBpSgGgETDJgM/XFE0ESWURoFnFAtFiEYf8nDtH5RQcHwYGEGgRNQjIigUW4GeKOIwkzgaOwI9oaQmCKjS8/OxwIOsyUE1eeHVZhECF/JyQHIa3lRpBcxCK4060TFsDZpOkU6owlvglp9xMG7b5w8UApHscW+EiDhbTjQxSFg9MT+wzbUUQaJA3H5WeH07YrmeZ4+QQxBzby+WeH3ejopTdG8R1hi+PYYuw41VgHGyuKgoVNxXOqYVOZ2dSk5LhiLDeCmGpqEmK6qYlWUxN+kk67YlMTuqlJSLSamnAhCXTyPBHOlaR6JMIpoziCRQSTMGAt9qARdcKeENQTONOcGs56TieCaI5Hgi+2RgzESe45NHEpYMZBISJ3osRenoGY3HOoq+En8DtJiHgQV9weJMGiM6fILkm1RMd9B5K+wOO0kvkC4j69w1NPNH2S2EA87SvvWJb2Yg3nWvxwF77t8nGx5A0/AXF91JCBU9fUCYhFjA/2IjbNWifQfObiXOZoi9ETMogjbQkQkMApmezyYzjkYyhf8Exvl58UVNjDbiDp48fpODEkcPo6eHiuy3dUO2jFRsnQmgpMdQFOdGuGLhJwTHHpKHGJiz44SqAZ4jLeEpcOTtd4yAJxPfYOykVnnbiMrxeX8Za4jIeAW+JSHz4MCUJMhsiMrxWZzjqRaa8RmTiIkcjkhsikRSOuRGYcS8e1yKTWxvFsIn3o0B+Iz1oT1Vk3UcNp6kTT1KEpEE1TFk5TKruC6h++8zrTwl273obyKKEmaRwSkTx6g7cEUlwJpDd4SyLF10mkuJqmwhgjfIhwqNZOU2f9NMXBxBmZ2agZaD56OOSrF1UeT6RyPGD68rOrKKK/voKHTVfOLnoH8ChiTth66jJcyzieWI+pXr36LH13gmQtYEXM4rzBL99C6Y1UH+kqYqZFxDdCuX7hmVWa6dhgWnqpEUGidigjgFYVlmFpy406+ttKvcDOZTgDlFz+9rPDOMY39xTFNcIfK4qvPIOJt+Tb05R4i8svYII6vCiep/QlSr9ovL9E6cuU/oZR5jKlr1D6W0aZK5S+SulvG2WuUnqV0i8bZVYpfY3S3zXKXKP0dUp/zyhzndI3KP2qUeaG8f4VSr9C6e8b6dco/Rqlf2CkX6f065R+00i/Qek3KH3uy630CqVXvozpp430BUpfoPRXjPRFSl+k9FeN9HOUfo7Sz1P6EqVfNN5fovRlSn/DKHOZ0lco/S2jzBVKX6X0t40yVym9SumXjTKrlL5G6e8aZa5R+jqlv2eUuU7pG5R+1Shzg9KvUPr7RplXKP0apX9glHmN0q9T+k2jzOuqnyl97tlWmTdUPz9Lff5sq8wKpS/Q+6882ypzgdIXKf1Vo8xFmg5xma3hAsXwugRRxPkXlX5OlQA2A0n56VrAaig6nCJKBtQutRREVUDmisJGlCsrvChWnx1GWSAg/jCJT7oOh+Ea5Ei+SGsQX4bYoYyl5S/NXQQE8bEumrQQhyQ6PpQYiE5UkyhIAO05IiGAm/3NoHM3qk4ag5YjRA2uqShQaoEzlhFU3/FZpBobnZLQnbKhI2zVEQmwb9cR1FHHEBwCo/VBerWxriAhu6hoD0ofPwUJiZdh9IiLzwyXhIpqCRikSqgE3kZq4Tpw8RnU9nbXSF4ymaClEe8XYIBXEKEaK/CChgQkcCF4YlEp+AkQMwHTxJPepzahaiETwLzOtLNmIXMkB0YLWVwtZA7E9ULGjIUMNxl0lxEuZEwvZA6w1kJGtgdBNx3hI429pB64uqUTrlrZsLtwlCBZow5W44Id2VqTE62XQi/QMz5u4dVqghcVCK06H8rYuFLTkiWtRckPUzvjpKF5O0gLx6oQr+He1lY6WFx3GC5+PNqf4I4Au8uK1n3Lwz2Y2V1C4u7c6C4RdZdldJflC7rFh7pLb7R9YWy08VIe1TNgheu+UA/cnqRjyLSo+eEMfpFmsF5KaSflEAeDY+gt0U6LtjDpuBtuLoTe49HOK3sEqUb97YoCSko9khJtEm+1C7isdgG0kaPquAHV6LEWbeVa281wA5qO4cgn1HgbzdATUtDQhxOSgZjRigpa0ATNyrialQ7EDSVto3iyXDV1vhH2FlZSVyVhKhpr1aBWD5CapAteNgu2mEKpWOEuJgQeCLAe0kzwYw+UC1x6tW4GzP3ft4j25i40J63EZgK7m4E9kimJN2ywR34J7w7jJfE6Zp5aWVl5hZfEa5j5kjatv4KZz2lPxg3MfAGLsZK4jpkL+vKxa5hBczyUxGqYxipXw/pY6gpmvqgdFJcx87SqcgnT6Bla4SXxHGZ+RXlsLmL6V7Vz4QJmntR28BXMoF/nEi+JNyxNCxL2uqXpR5SvYSZWEq9YurkI6IalUXslcd0K24Q+aEsTiE1fDYFi5qqlewhK4oql6UAXtKUpJ58LZs5pmp6zNOnoK7ho6W5A0i+E6LGDVsIvSNgbQpOOxLwuNGVY7DWhQSO0V4SmLF8SN4QmAKm8LjRlSMA1oXsIR3hV6PZj/athMezuK0J3Nxa7LDTR6KITehyR5uciYKwkLmLmvG70BcwkS2IlpB2BvsHfItcuide5LokEvcY1DBylV7hGi029wXW/Iw3XuSbVK4lrXPcBBQVwPQZIw9Xwi1cSV8IaCPgy12Qj4EtcE4XNfi4kBkriIqbTJXEhJAOpXgkL5EvitH6PTVzQXQwlcSJk57eG4ZgmGrF+WpOGffWIHioE+aDuT2z/sE5jmcGQBd4agbwmGEcwp0cW2wshu7OS6AyH/63u93Q35vF+Pzv0qLKRr5z/4ddW/+Cp3z028ud/8eSf/N7/+B//4R6wuvxtIEb+6Pyv/5u/+Edf+cIfsfP+9pELX/73/+3Nl3947s/wIhbmWyP/3Y0f/h+//8yffP43mn7nyDe/81u/eeP6n//2N5v+zpH//vV/8oOv/+tv/c6H/F0jP/z9N37jmb/926//KfO7Rv7tv/rBG1974f974WtNfzewHmswuAPNhz1WZ3CnvwcT+QB8HxNeEPh7MZELuv27MOEEd/v3YAKCnN+DCRZ8AG+Iuf1/5CyywJLL6LmwZGqsS8YbtcAiGwKuQoLskEFCeg9nyCbpBA6mbUyngySmY5j2ghSm45juCFxMJzDdGaQx7WB6d5DBdBLTELRhOoXp7iCLaRfTucDDdBrT+4J2TGcwnQ+2YLoN0/3BVkxnMT0YdGDaw/SHAgvTHO6Bu84CBwcdOj0WQzcTh2SYg3ug8/wIPBV0ws6zwU646/xI7qngLizVeX4kb77nTwV3wb3Qg9DiEbR7gStnEUG7F7YTtO2w42ywA3oIWs/ZYB9WaltTKWtU2gMCC6SjAnuAQyYqsAe6CGoX7D4b7AZBUAWW6iIa9+uve8FHMKkIzF7gFHukwOyFbVRwG+w6G+wCn8D4WGobgaH3B/BT1Av3agxdqgr1gn8XHMDK/KnAh3sQobemb9uNvt2H/ZF/Ktine2U/tmA/NiLCfo8usl01jgD7PuzHZvKnAgF7EcWWNW3aarQpHL592OX7sNej/tkbjeEO/Z4/5QvYA/twZDiODHDoWNPrltHrYXcRB0RjuSfqrvA9f8rvgRi6iSxJrjxg0AV3nAdxHu9sYuADnIdt5/HmJgZ3Qfd56DyP9zfh7Vm589BzvmQBMNgNd56HPedLVicw2AXBedh7vmR5wOAeuPs87DxfslBVvxc+cB526AiylVitm2VRb/Xk95/SfrC49OSbX9CZhPTk0xd0xpGe/GqYSUpPvhhmUtKT3wozrvTky2EmLT35vTCTQTxhpg3xhJks4gkp8BBPmGlHPGFmC+IJM1sRT5jpQDw68xttItvseEJdtEVeLrnCySimjF2WvMnwkjSltQtt4rL1W9t4mw5i+m3MeOsFcf02brztCBL6bcJ42xk4+q1jvN0dJPXbpPEWgpR+mzLedgeufusab3NBWr9NG2/3BRn9NmO8zQdt+m2b8bY/yOq3WePtYODpt57a03AUh3i12liGA0f7Jfam/G7od8XdMLq70RjIKcXRcc2UmKTjH0E7GnhVKAFmt5yFdh07CVtG+JMjP7p58+aeX3ribLCFJs1WYEqABRks3nEWtobFO9YU74B2mljtcBuQsJVmHAIlsRlkFX3KS4/xFME2pabjgoXZ7WdhWwhs+xpgSkDvAKYkI/n4g86zsCMs3rmmeCfO9hzO9tuAhB1E3w54Gzj8KaKTFoKgTbWBlpIgjlTsBIGPXWdhZwhk1xogu7SgZ0rGB1R891noCovvXlN8N+wk2nfCbUAarVr75Q5CdicwJd+DlKKXFtLAQcx7gKlI3sDGLJyFPSEoWAMKCJQPTC1xAekkwVnww+LBmuIB7CG698BtQOJKkUegt4Fz1nwBd+pST+glj0b1DqP9d6yp3V2igFwihj8V3IEMcMetGGCXXijDEdoKt2b5rcaAbV03Ge4gsm6Haw/JfdiK84bjvAFj3qzto63Il7Tu7oLdZ2HX5lyy4yxsD7/sWMsb0dzcauBYS/JuCneBLbADmY0/ha0zZm/UBzSNfT33bjdNthLKrSgOtt5CHCDKOKKkLt+C4LZsDm4HguNPUegNdMOdWozsNWDv3WyaYgUHQv1qGw7mts0HcycOmaqQhN1al+nACdex+bTargYOKwjYrm7h3FGiqdWJjwzsxEcbbMNHFjrwkQJqNIetJYu5/z7B400VwpLuZmpBzMjsot8GGfk63ie8GGTlwpLvqbUzA1kpGjW/vaXri2FcomuBR5FUouFnrGHI4AWrGXm25megHS1eHrRjwFI7eCAOK0cPZHJoMkLTb0baNSPjmJm0mfHMTIeZ6TQzu80MmJluM5MzM/vMTN7M9JuZQTPzoZqfhYxE+14GsvRBxV/FSYs4dMsb4L0JsiuCtL3HArp4Noum4N2QRTNpJ2TBRrdmFoOrPMhCCq2DWWVhzY5c+7t/59c70JDs+ALSkiytabyt1lE5Wzp+Bvexo3QzvV2TDtjeByCNzrwMPhw/i9/xSmmhsGdwXDEUQsXTpdFcKlxIQwbsWpApinQYqgMCMnTpNUKGDMQVZBtpUReaKzwUZUAO3Tji8Uw8MfA0HgRQC3gLflJaNSmWgzYQwFt4OLQpPBECTjdqj5JrkJrbJvkhYkK+SAFqOFGsRb8dsniFf6Pm472qGUj4noVGT09e/+VVnCXOWFdOkP9ZICa8vl1/krxmfPXQZucpy6bAhtSCDLSTls4hU/M5uNRcjQ4SqrEb0XA8BMKx8dhubPFikBnNcBwSPI7SVpMJ1KFQTcVRRyiBQEM3k00JpA7NNBaLgnz8Arhc/WUdqmZL8H6WFDHsI2WQT/cIuySU+o2Ra38e505T6cNo+a3Js4uosM0EFHPA0Zxp4wjQ9bu2tBb9OEY3SuYjhQLQs69DO7gKIJoJkvLskdZbi0DjKRqxjzH5e82P1tAGWjMywsxYUcbH/TV6cWgaIQhfEDRhlEBQGAyCMZlJXRCvH0buHKXIEVu+iTegL7YIwPAPxR8JhMUVLHwpopdCv0QHCx7TsYYh6VsY5yDwNnZOf8jVg32ibqhH1rbl64nFgKvYFDL9Y3UVqWKp3lBi7C1Fv7kkE8uQlPHluvzc5885i8CVvLvFN/vW3271QdRqNewftC0HHJgSSXi7sx8DC2yMFcIAQ3nzc28mFvHeeHzW5OdW7FnpLWOfO9SR2JCEfJMvKgZEfFzj4y18xAS6b8kXsymxOG66rynMUOhCYl0hNIkTabux9ZtQpjcn2NlZLGwt0rzGAFyH7lKmX+bg8uwi3ZGP/IhXY+NESNYCHnEdN/mRm/zITX506Fp/uxYkaaILHaGSrNG4OsCNkmpe0x36gVBRPe53EqKt2Y52/VUxEyS6cauxyunHEzwPKIT7DY57e0q+znFnT8nXOO7rKfkKx109JW9w3NNT8jrHHT0lr3Hcz1NylZOyKW+ymkwsk4fPkfxIFwZEgiPZ4Rn0TfZYK8LfhgfMJPPTGAGERKFHhb/polPDgrRMNIIMBh46YB3pCtrkChqeVvhYl58dYf72EebvGGF+5wjzd44wujC7a4T5u0eYf8cI8+8cYf6eEebjpdL+CPODEebvxUu1R5h/F/0GQ0aFxjo0kZTwJsxuC6mDSB1CKn/EF30PL3BfpDup27BqSDmKmi1oosLEVnl2sSa/hVJ3qxTLIEjwBlvRyLAYdMjOpeX7GIMtKN23oq1A5dukV/PxpywclOwqoLpd/Z6ERoHGP8jUgrYWgrYWAnCQpa0GAnOxi68Kfzs+rwh/Bz4vC78Tn5eEvxOfzwl/Fz4vCr8LnxeEv1sPzR2KL/w7FVP4exRH+KDYwfcVL/iBYgR/r+ICv1uxgH8XirDtJesqhwTqhFfw2VmyLuNzZ8m6hM9dJes5fHaVrIv43F2yLuDzjpK1gs87S9ZpSOBt4QuQAChZJyABfsk6Bgm8UPzTkMALxR9BfRivF0/AXXi9eAK7i+E19tmXzgbbIA2pQ54AvJIdDyGmKZDex8BRNaMSsI0mQzvqRHKV0QX9Dgbe4+9fHCLPMu7LS4yplFNijkp5JeapVGeJdaoUlBioVK7EciqVL7G8Sg2W2CCGzhfRZYHCf5XRH4pS4CqmdZWDnqTeVnAURQ/jC4GL53+I83hzvcUbmDxbC2zkshg5n6VN1+hbi0GiC3hNSaR7aiShEiqoyTmSEWFYWlI56C1veyAweC/rqrUD48ePZByJR0LdGkUCY3S3j+v2aQ2NQKPPOoExhAlUPR9Sjxm5suIcySQABaUD8Zr+VYC4UiwSkUed00IW+tQZRRUEMSkWMxhfTxGZcTTl87FMEp253g769Rg+gxZ8JN0GDth6yRfrUtRRx3GA4+KTiALXYnSMIfIso7Hb68DgaRtiGyvHsDJv+aEdN/TVxvHgCx/LOPoXYviM8ikgFTEMcU+0oCTWQpGMNBlSHJGA2EZ641HEbgLxUMyCGWaB3mMeBsXGVXhwgvR9IgPD428JWcc3KGhhJ8S9Du2ufzFq3JGMTZEHqEfgP4znHw3DQZXeTLxmqfhf5DULec1SvCbwp4PiyMwPZeLy1adXmTwgv/LFVeYdZq58XuW/+7TOv833zZsTUx3Pw37FPnkxjMf7BgYKq0h3NCd7POBKubXkWXlakS6ktdhFsdqo2VooQlUbeauNZF9kXtIF4X4xIZJNB5dRmAmSrdMW+vcTfq9Zk8M0ieQqUz+B4x2h0KH2sVv/DFdyhOFqSg9PPRz1CH9bw5ugAyaPBalR+lUGVwc6k7KDveKnISbvXPQzekXCIOEU/rpGFhcyWifeeHKVjmAvg60WIk8tRO3hQpTFhQg3yGsXogyd2w5ckpJpWogQ8i3h4o/fROtP+ENNSUiFv9KUav28hqOa5lDT7NGMFTXNUU3DyCs/BQ42zTKaZqumxTeQkHhXTbPMpqWoaQj5lnDXN42YKynpN6j0ATCbWvMw/qiJ1elvgeSA6PC3QrLAtvod+Njib8NHu78dH56/A0s6fiedwPN3ehPyGpO292k8bGQBk1vU5slV0RpbkPXdEYbuYXfkH/zZ7/3Wr//TP/zVf0YuX3fk3/2Lb/7fv33+d//gCfT6uiPn/8+/+aff/Jd/5/8510TXryv/5csvXCdJ4sIWwz17Pthyv/gQuNBpeHbPB533i33gwk7TGxzsvF90U3W4cL8YpDqYylFBTAGdbHIawOvywpf/0681l5BwF7aPOE/CjnOwbcR78hx0jHQ+eQ62jsCT51reZ6KjP4Ked+lUGfqCJI9+VsWhpy2v0Zp8Kc7tJi1CuMGQZ9XxIYqxC6+wFCp4T0ixiIHayvUCrOaT5wX3jOEJCIzaonMp1qK0DuP6BzoySZ9XUfFaiEhJTQqaIu0fhLfD3bAak3MZESPgaJUTKn1FxZBZ+qCItwOhUNxTJJNZDfQ+PSSwRVNWnSdbT1JUydUBd/TK61QRQRiq3CQrxzWMoc7E8eXFZ4YDPEp1c4/PVfwSXxe/xCjOGlMRIpJ2UbdFMVxRVJerArJXMUDpoUwsCkcaVWkMXLJlz1jGVqGU3IrcYhaQ4EQFHFfM2hieXkIrls91GCVfFzWlyLtsktfqB1Jh1NaXNkVhdCdu2W7uoT9Hunyu4V7DjjVD7awoMlEs6m2B2DQy0doYmWiti0y0EAPOZbK2cHX2h1P8XQupGg2mRoO/W+LBBkG7GdmkeDTXfSUWWVYToWXVQctqEpzQsppCy6qrut+BFNmo0mssq/TLTS4JO9FQB1HRsuqgZdVBEw8jldqu+Wmc/6Fl1YlslI5pWXVMy6pjWlYd07LqmJZVx7SsOqZl1TEtq45pWXVMy6pjWlYd07LqmJZVx7SsOmhZTZHyje1MmZZVmThyyxU9PIiE2iQaSW0ULUrn1CmH4KgynYFDDK4/pYP0SyPwJJ1YCfhL5zCxOxAvfZLU9ZgKQMEbe3EnKJSVsnY4g1ubx49kLLzFdyyjZJ8LCjNetGuRmdUbGL0F1THXG8bbYZApXTrRCC5yFQrvNKmfuF1J490vdJ4LczYd/4WY4hnEPOMVgBR3bfNLKJufOnW6Aae16Vtxi16VDt5PLL+LNsadKgwAd0J/FuMx5PA8hSt6E9IOzydFp1BsUjDiasNDv45po9Jsg42tYOrhhL89aEsbVw36jTQbBQ/tC2w8MqMgxHAHrg3UMRAqqPYfsjHSXzDSiD2Y4frn+yxCjefs8Aw2WtFtNfFt2Yk9JtSvK9roaHfknocyGD9t04jjT5oxMgn2MNpsUoo2m5TCzaYEpE5CUXSQYvt/saO02BzD8F+OGj3QeX9bdtcoZByxoX0X2Y4rZZ4rhcXGl/swWBQw3SO6SxadfpJ7VLwuvDUjv/Kk7nwkkX6H08Zz0rEZKgS2TOMvzEGr9zyfmtOBR5cTajsVNoyHjVApahiPGmvrmwLQ/4C/kxngPvyhTIwWAS5t2pLHwCJ9QBF3LiSOqWOF3jbkJU0gBjuHvQBcdXlOGX1UK/YFnEJpdSOwWzY2goIPaApx48czeaj7xYDX1FDYGCNBqq8T/nAcIxhqXtg9Io3h+DQO+qdMI7VY7UwUrwk9ODKPvP69mHCa/Al5r1xZ+TZT60Gcwo9xh+/jtNy2LDnwLoiRIZpMoisrK/ZiZBVWqonaMGMnxei8Kr6jIgkVM24t+iltl0tQeTJnWjK96KfD92RlEaiKZyKjZcY0Z2ZMc2bGNGcmAA/X22t1bwRFbrsEZIySCNLPQApVlxQJXwzk9pMY+7MfeJdaq/Cg6UGeDlRMTavlziLt5pIzZILV3gP9+Y5laS222YJxhocU1Y7ckT+6iUH8yz65jPCXlXG1xGB0nw50MD9F9j706uAafNNafDgjJEefDpKagBQkwc0Jrz2GJ8fSMfIMsB7hBG0vIE03zyxKvAAHVQmHKtIZkpQ6i4YOGRyYD5E8SRDxhIVcEBqvWI9S5k2UNk4S7DRoQROQIhZ1cyLdnqDT834CuVdDZxF0VJDH/AzqoxgYuXmrrAiFCRT3zW0l4ZDxKGpZQrUsFdGCUwerpVQ1P+NCxv1XtshGVxmIbpJNXpfS4vczpn5j0hLDsqnCoGylzsRmgriKX0qokCdnRjIVEpXsClLqCrX4WBeklIoT70LbX0o2IdUVpHBVkc4RTLEHcUrsZ9xPk/S2IBX++Cr9mDbuHIXk6kCJq45LpkjbzIluP0PRUj6prZ1+m9JZ05Ir5TbaFMRH1boPNu3d9zPmp1BMpdV8T6v5bqGvx4IMbRdwY9iOv5cbLmMc4tBeYuh4TdNRaN8GCx8WtL/gpyBOF0XgqMXwfDSXtGvCOaKQO0BH5x1CjtYofR8c0tGmtuttocwS0VouorVc7ZxygrQLclZy2cTxnAlwclL/J7uCbC3w8EK5sS70J1Ovu7IJbpeWrOiA8V0cHV6DFPUuabrkhkU5DxakSM6v0QcwejncLZDopEi5uLqbI67optEPP+qVLhaudEIpDt+3RVyxG7pEHDzqgNptMrKl3sSdo+41Uv2TcwHDHyfFI0/sSBedbZbfw7DET2DwIf1IKSTx9xMgOUeHkchiH4O4hwZiJ/SxUFL5WCipfCyUVD4WSiofCyWVj4WSysdCSeVjcchS30ZmOgsdK0imOojOxrrQYsRaDhacQIqqlpNF+aWIUjxRS+6LJN7PEXkXEob7IoFrx2KQCU0rDE0rKKS1acVC04qFGwW6EIbrC2Ec9EOQI4DSVwQ5Ayh9WZBDgNKXBDkFKP2cIMcApS8Kcg5Q+oIgB4GjWk5OgqhvT7f6dqHVtydafXus1befbvXtI62+fbDVt3SenJwIbSMr/GzQJtl6D0IalW3ysNFPZ8e9dqyBFnqUi7NZV35fs8eqzR29EbNDuyFqh4tK5rAgRsflY6T+0gkmvkxiDsVjTAc9ppUL3BpWvmfHkCuxVdn8KCn7uKXDnscdTFKu8sMZC62npO8qg4JNN67FS3TwS1+nhHsbmzYpJN9xcYkp669SYRBqDOIo45Vh3Mfz81QqpkrhXgTvJDrIeCDCkko/RrsClrTdcDFRgaeS6PBTypi5qH75O4Z7LZFj3E8h1ybDSx0W9W8Mq6rkHsM7DA4y5ruhQdSm7lTnBNG5j5CwQDIsQOrAJhASZKqUHtGAdkFcB7FnDnKbwKM6qj8m13wk10Hyg7wNbYcf5GmwcVFw1nY5zc7Ne5pRz1hhz7ghN4QX/tjqaFbM/R1bWM22J3BhCi1bNcmX9Y+w8Na+U+BbdSsCqC2nFD5Xcjum8hYZLFTsDebt1nfKx1rfLczHW98pn2h9x0VAOq3vlE+2vscwn2p9p7zb+o6X7ch06zvlM63vCcy3tb5TPqsMLkJ6uGMSsh2ZnQ/QLo0PCFKbBkQaneEDwvGT+OjwU/jw0NQxIHb7aXx04ro9ILpxwR4Q4GfxsQ/jzAZEzm/HR7+/BR95H6N1+YAYxN05HxAfOqdi4bcoQ6aKhfegXWXoCirIqgzGwqchozIYC58CV2U8dVOHyji0c0moDFPYhjW2j5wrWaHV78UwzKZdtsunwwyGBL0Zhp6j+/+r+suvIeNwZBw7lDrEOLFWpIhQp2oSZHk1pNOyWlcFJO4no18sZCe6PEOo0zmqlqPCLxR32Qpo4n6MpwqZyjZqebqWR7U0z7VqdbRYLW7U6tS16CKjkBNbtXa3GNAxaoGuBVRL82erVneLLVNGrZyulaNammtbtfa1mDVt1MrrWnmqpXm5Vau/xcJtRq1BXWuQamkOb9X6EK6qxOjAqRyODbG8AH6/+EgWRZAlv/UFgy2++oWILSz5dHh0AteVF3XmFYunmy1lm/RV3HGgf3KNBUXfxkHCTymf1hz+WZDssLrzGPWmBC4SGOp2JIP+Vgp8OpLhyj1Ko0/3DVPUobR9sh2hi300Q+H7LDqujWp1jM5lowfEUh4vCqoCaw6doXh90uwMIg8SEHtI66CSTFTSVoplGoOLsEXq8hDas6GUVPtZEsdx/DfWFbjK1+QqN5orn1BuNC49itxJkCuN4y/X89p+Rj69xQbYKunUZOdiAxL1OnXAQs2PQYbieAK+xmNGdWUnHUeYwQoo6h0yqNaAjtZjHJmfJOca6vAYfxJXu0f56pNax7QhQQaP8AX2jNvSzdX9Sk6o4X7vnYzwX2p0o7gZNcLJzUY4uXaEk60RDgPlLBxoNUo6fIqGGmnh0VDzNUPNaahzaoyTasQd9ydxjNHQHo2x2metH2N37RA6rSHMuvLN9WMfvjhvc9HERaIWHq9X54i48jcpfxdTPhV0H4QH8Jnh7xJr/V08PIkvWv4uihPVB+y5jvXEWwlaDi8VbmcpR5cwHF3CcHSh5Dm9GFjeDuXbqYVee2oBQQa2xtHFQ0cXVl1PS1SJNqPRZQnc68xuuCuBkwPHCu9KsLQDhxsOHK7PFZEDh0d3JfCWA4dHdyXwdXclcHLgbHLuX+1k1bn/iG6zieu9ZtnNLgVQUC6vhRK2Puu6X7d4qmX/VtZLvOGM7jlQfz1Hmbj3oX+oiKeGsXK3skGjUdTbomyjZHimGBoAQZbPgIzfHWB58dBsLFR8BIYDS5uMk2hJjYdKAym0ZEVEo6rveBOGERz1DaVZHCIm5Whas+SPmDI30ndlVkSzDZo/cRP6A/TDC9yEOmNdAbqbMKjU2IS6uAml99E+FKNOfU63aYpaQF4b2qHTrSW1zUGTf8eMnlMXYajr6myVY6FxNzLQ6m7QtnD1PmZ2D9luyYtN1up/I3isqTuCyz+8+Yt42Rn+O9IVsJmH6ZZMD7iOtOLqHs2aTNdUBAs27XQ05dEoaoPaRdLdEBhyqjYZAV5AgkNdo3vl6CoTciCT2xvR0aVjMwGf8W2lUKk7OnHPQs4qHWyDLbcRjd2KGIphOM7aaClBAtQshPa7DtJi6JYxBKOvziA7Oc1dElpco+bkCycbwkOhE3gNGRY5EFCKtS51FDoovRXMZHkd+hpFXHjcpyyKEsAlonVLYCQz0FpnhYKMopT1bXN0+5xQV85xxbppfaUPEqFWM64vSGtdWie6fBs9Ln4MeCDwtaDXCI13+ehbQug8BIxuDH31J+o6+qIUtuYeO67usaOj6Bj3ztVlkR0gMhYqkUQOQ/+UTbekYtYDQdcjqmbRYdJDFJ2mKbe12NKSUj5BclJfm6mkrrr+ju4PDH1FCJewdbqqg1ofOvSHrJtdc/8dM+6/+18Et1u87yxTsJXcQ6IWjfUPExPcDdbxQKhbZ4Xkjbq8f4mCsEboPgOSvLvVDKP+IaOwxsfxClEyA1j6Mj9qB7BQaqMMj8gDu06EZcO9M10OYOv7Tslba4dLA14aKejSSKEujbTUDQP6rseAHLaCDLd4z6MFAgfbAOW6KuZcX7ajlxMS/p1rr97Jql/JCBtgb2iA+zUhuDJegmlQYuoaKo4PLtvRkYyX+mIsplCmCG2YSKt4R5muvURbUpVzKOdJdLqS4QEsb4hUD11TKNclAQ5uXf+fbajP31X95z532/q3oXxdzRcET7Q0YJnCoAwaYfp5Flu+lhjLqNc2+UmFjNfociF5tgYxvINIXvwOmwti+JjFQFMy/bzK5jCkvXGQvfbF4Q8yunIWraCOfJXNKneK+dXCOAkMB7n54j+3j6jbP2kjmKQYQSW3FWr5RC2w0GQca2Fim0JVMOhslbqvUgNoEgC8rHk/+3+/OEzrGPkyUflRzZMpsGa8NF1nRcYly/26IBvB2k2f8kJGJ9TJAkliCB0HQskWMr7rsFBLG9+1OKPT49qTgS4SVAk5OSrQx0xujVjk1qC9B5eO0rBwoUW3r/ITqyppiL3gozvDJxkvcQmx1/g3wpPwggwbtnEKngjYfYh0k1btjvUt6VzbEgSiWuMpFf0+hv4tG/v0a4LHm+p+NxEuKOh7RPOcRixXvIfJWIIvpCVtEnL2WBddvEVrrmJHEDNquSPEkns9CrCtrl7GozJ2hEMFcX94Ua4067jGyPgyKl8hOpwPSCZea7bK6/cxUti5TC/TKSNdT1Wg7ZyugeIhriom5CtRRfpmqW+CvikIqiSXW5blApVVscyh1gSiRmLWfYVzridgWzcGXtIZOuQInLcUHUzBoXQEzwu9LbQx1t5wHaeeCPPqOFjLW75mxNQZDrrUhW5wD2iG4a3uSiXFc1Lq9ngHFw26yNf2E8hqqKypQ1MxadO+PHC8jwW0sVVhh2p3bSt1UAWRdjP3RquFbjcjr7a8/oVVtlkzPdtsUEtxjNrUehU1K3qFAT3k7yRna5DAlrkUn4Jk0y7dUi/i0Yac7gMOW0ZJfVTM+xiZ/2ywdcu0hhuj+FDVsj9qtQy9Y87asUt4cU0y2mS8T7WakQjbEJfniF3jXtUNo2u4qTGry3XtwFZXCceOqJvZHWX7InecUC+wSUSwb5PSHTVJSE0E9z5GpnAbuG5STKuuauOtzhxyIdSymacVQcs51D1WTh9W7kIlFxlul0TocOwGIbtr8hu/ssqUioB/KMDTy+5S++V9eHcbbptEj+gObFyauNo+cFDnHpX9RQkUB3iPIPES+TSVxuWhalayHDqGkiYDCh4ys2WaDlSS39VTUAUeQqFt8ze+iBdr2mozimKeokW+1Rq/ZDej42PrmdJzNmdIW3k67DVDlVRDlYy4L7l2qOLqhRVxH4bIxlvzKh5ynxMNlbOW+2xlB6Oh+maL+ng3rrAb5pWXehdTJ66WsHjEZ3GiVUW7iAaylZblms/QZGGHxFOyxWfWZnxGa1ZcEf9ii/gYcZmtomS1H16osxh4p3j4FqVOTSknQkl9OmDawPO3eKFxIKSDgWxgy885Y13o2DnURZshjh+xR7y7VEChGWaIAYUKPI2VUjNZuBOLKWr/mHPRVKpkzDzTILlXQsdfm2Vzxul0yBtfwnunmx/D2BQ099eK+Aq/vPmlYVzC0VwGNI862m3cCNv3sTswUOs+thu3xajK0u+IOCXh6XpEB6ZXLg6rerj0xBSYTpVVUxRi3gN6c4FomyF6ye5jr39JDz2agR5hLnN/S812yf04SnmldL0E1ktPBA7+Kp+OKFHLtfNCYGkk9sxDxpdQH9JRWsBnUev3PmbETYHtJ9LCRVMH2N5jdHQ3E/YybQ+JwzHUwpWOT/eQJtS1jvpG0vdp/Sui9dfWz8Qfe+5tOuXu/jGn3F8xWff8mGR9oUWWdUuyrLclC40cNqa8e/U5CEUIXrFHd+spOBTV3yKE/OVWSIi6BVf7HMigjT8Xox0PzGQdToKeWIeT84FUnsf88IR2ZMZTMctMKstpy7rHtWVWv5asVcx9NtIY4O01ht0tjaED7Ty31xh2B3q9Fz2iAzUGZ73GsMnKjidi3AucW03jJ3/0aIT2/1Fl2UeTG211lpVZTKjDJC8jSTRu6jzBfuYA28+SXjftFKlhVFIZHLh87TYVjnHR1EEHHp6ip00qumvEMB4MbB5WPma9HcNSeE/BQo0MJx7+ugsDe+ZQl29EiEdWQuz/CEF4vIfcCPaG04YsXPhtOuJ6JKMO7WXf9qThY9oiGqlFjtdGx+3puhB0unwq9FPFlEJAuxKt2iY2V23BcX9OEx7a33S4rjUTWjaF9wG1zVOhDHoPjzhHM7q/LfIccbBVgHG0sXI/w/nbADf3kSaCFmwjit3csR01DEtWN1PHVtLKmoZdrmw3KuBQWWAsGsy0tsBcXllVUWAg0AKzKbjowmkTXHoNuNCg881za8B9lFtNFQUKyjaBV5NjwIwNnOJmDmfoFBD+6tCaexBGM4JCCm0dC265o1qIqGPPeExqu4oAZh7XskPZpM+SW08bx9UrBuxNF6eL+ygXTeW5QKXk3EVyOeGvnV1UswKDSjEIVLL9qKGQD0opK6rG9780TOXxKejeCfcw+vW4usqGvKIWbQYcYzPghJsBbfBW3rPn1wgL94NKlpsd77U6nn+Q71aW4k6KHEwrg4jsqHn3M7efBN20Etta2gGfOaJ+UEyd20Q967FAh8NnXeYOh6JIlT+kujUUHN6RrPLFU9vIHq9/yklxpJd1h/RPnqx8m8zdJNyjiGPJs1GYupLeYIcRcu4Bmv+aXi3EwJpRR8nIXI5qO+1qkNJ9IVcqCYrB2UpaKpEHooT9Ku2a9wvM3RutiToo2bDe4i083s8zdzfnTRolj0aJ0Sh56g4c906arUpIc8kfDKegikd172TRFLaRJX7wJbyanAPrarcZc/fo6/OxSfU1Pauq7zahj66DvYOtY/Es8bfbEY4jjeLPMtd7lLntTPkYG5LX1XTbwsJpQOINIe4iP22P+OrTwyVlvu4RX8E0iz49bXw6pz5t123kSl7ZetgykfD1Ell36yZM7Gke0jxyxHVDWg5m3ZQif3wXc7O0D/4VtT/0sm47k9/+4iqTe+UNFPRDzG1j8sqXVpnsA8qmNRzpeD/lumwvA1brZnvpA90bSaIxqTCMZsNUkVLYlR8OYfQIPKodvv4ZpsmSjvegm2DAvBGmnvuZiwG3kt/HXn52mCAqU6mDKce7z40xb5K5Fsu6Flp4mev+kyxnZI93/9fs9qXZ6YkqTM9NVk/DUqNSbyxBpQGrGRg/1YDq3KTKMl1i/lQD5qdgfP7U3OTSfdA4UYXZ6hxML8HqFqqBb1RRfMe21Ctzx6sKsH6/GoRQ1Lep+TooIuanENjxxglYZZ76WJ2bfFfVDtZPLTUmDg4MDeZLxUKxWpiq9A7050u9/eNT45WJQv9kaahUnCxV+yt9g8X8wdnp8XqlfubgUmPy4FJ94uDSmaWDjRP1amWyPDs/UZk9ODdfVvmlA/WlHxv8xHy9quAjxQenG9X6XwZcZXZ2foLg1SuPl5erEwdPzk9GAA9OVheWDk7OnqRi+/MHeg8UClQ6fEdFGycXDj4+fmp6dvLgicrSiYn5uUb1dIPKzU6Pry8yPluZqfaOm5/vnphfOFOeqs+fLFOz7oOl+VP1iaoeFj0mudV7e2ByvroEc/MNOFlpTJyAyepSY3qu0pien1tfmPcwlhceSzHGnmSMZVgr/0XG2J51+QNG/hnG2C4j/6V19fdyxtJGvpszljTyGY7B5628xxnrNvIfWwf/KMI08h9mjG018n+KMJBG7lHZfxtjLGCt/61/v1t47E7G2N9jjMWM/G+vy3+dKbrD/BWd3/4WjS9bHuOMsS3G9/vWlR9cl2+wtfgxf9DIn1r3/dS678u6H8x83sg/vu774+u+n173/fS672fWfcd8wch/dh19n11X/xfXff/Fdd8vM8ba1uVzRv433+Kt9nX5A3oMf8g9gn2MMdax7t1ZzQ+y0aieXGhUJ6ExD9Nz043pyuz0Z6ugBMt+EjTw+Inp2SpMN1Bujlen547DZH1+YaE6ydg/Yh77BcbYjOZnwRQf24wxSz9jGrej83HGWELncaxTmhfxm6vrZ3S7T/78//bH+x7584nT8jcfW+z6lb+/7W/93E/3/3Tiu584/dLnPv763/5PlaWlap0m61RlerY6eR8sLCH99/8U0PNeODk9V55frtZPVCuTjH2He+wDb43R/2Ar3Leq/6FW/crpTer/jq1oNNuVXdd+bNNEZaEyMd04AwhianYe+etvcI89gmOm+yafzxfyvfm+fH9+IF/Ml/KD+aFCvlAo9Bb6Cv2FgUKxUCoMFoZ6872F3t7evt7+3oHeYm+pd7B3qC/fV+jr7evr6+8b6Cv2lfoG+4b68/2F/t7+vv7+/oH+Yn+pf7B/aCA/UBjoHegb6B8YGCgOlAYGB4aK+WKh2FvsK/YXB4rFYqk4WBwq5UuFUm+pr9RfGigVS6XSYGloMD9YGOwd7BvsHxwYLA6WBgcHh4byQ4Wh3qG+of6hgaHiUGlocGioongJOWlyenl6sgrjZ+Cz1fo8e7Voz1VOVlnardSPz8/1Hni8snSSf6c4zu4qf+ZoUb3Uj0LpRG9vsTo+PjXQP9WbL/SV+kZ5tfzo0eWHlvLlo40HlmqLpwc/OXbmM+OPDJUHw3UjSowdbYwMlAc/orNHG0cbI6Vy39KZpeInziw1qidHi+rLA0vV8QcnKtX5T9RPnyn3LTUmRa78maMlvZwMTsyfXKhXl5YKpRN9+eJU/0R+cLCQz1emhvKj1t0tuksL1frJU41qoXSimJ/q65vsKw4O9lXGh/rzo/Z95c8cLRSMRazQ94BKHJqvn6w0itXTC/P1BtatTJQmp6qF8YGp4nipUBqNDWCjkf4Hlo5/+vDCwMOLp3uPnin344rdt9SoD0zMn5prFPon58uUKk+cqNSX4vcajSjkJ+cPTc/RnC6UTkz09Q4UCkMTk71Tg0N9pfHRxL2I44GlqY9NnSmOjhUGpj9WLpXLZVr8C32Ympwt16vUYU7ehDxwvNoYUwLjE40KNX+qmM/n86Xeqcmp3qmJ/qnR5MR7OW79U/VqdeOopd62j6dP6j4e7K1MDlYGStXxYu/kxEBx1N3XGsdC73KlPl0Zn60+WFk6USidKJSq/YXqZGWqb6hvYqp3ajR9v2pOpbz5mEydbFAjhhTeRrVe6F2oTJan5xrV4/XKbOZZTqQWyt0f+2S3UncOLFcnDhz4VHUCX32y+/Anu7tP9ea7K0vqYZRZWqhOKJUGdbTyXHWpUZ08cOATC9WJQ/X5k2ONav0ovVOQHugeI2iHP9k9FNUqlE70T0xN9fdO9lYmKuODEwOTo233GMNampx/oH5mgRhyKj80mR+fGK9MjfcNVHtHs3sVQ96i6QOP16cbVa/HgDY0O1+ZDLmjb6C/MjBVrfYVp4qV8crgaPtaTl1qzNerYeGhqYmBvoHeofHi4OD4eKkyuuUnoe/68hO91amp/GR+onewOlgc3fqTQFXv+MREIV8ZyOcnh8YnBiqjHSffy2lX6JucXlqYX6qWJ06cmpvZOAG37TcmUZ8ez49MH68uIRsVxov9feMT48XBgclSYbA0uv34e0nd4Mnqycrs9PG5jXTtmH1Pu6H31Nzs9NzMrXqhs/6eYhucnkPNpDxbqR+/Zc/vHLrNEjFA25cB2qIWipQpU6aMqs6uD2PVTy8NlrH6LSd139ypk33TJxdOHm2MDJVLH5leWpitnMEvXc8o1u8n1sfyBw7Q5vFAZbKy0KjWlw4cmJpFKTh34MCh2UrjocoClhzrfqD7p7sf6D60fmaYIBr1ynRjSeUqjfn6gQNjOoW1+udQyJdOFPorA5Pjg/nBgf5Kvr86Prr7F1SjPkNjMDDx8ES+9+GHPj07Xx6gji4uNerTc8ep94uf0OlbC7THUKAV8iTXaHG9I664/M6MWrb1MrMn1Vpl4RxXNJSVqFw/ZEMLlbnpiZnpueOFPkqWT1TmJmer9aONkf5yQS8dirZHKmdQgN5qfKl6ofcRfOiig43KTLU8Pn/az65bnoOMWgU1yXs/U/7MUdUpJb1R780/Wnn8U9WJsbm5ah1HSmJfl+rVpWp9udpbmJwv63S5MjepycYFZbxvfGpqqK+vt9Cf7++bHO3uC9eJ2zReUV9+fLpxonxifn7mrso7JKhQmJqem146UT5en3+8UDoxkB8aL1Wqk5PVqfHx8fG+0QOzs8snD5RKg4WBgX7Unwf6+wq9Q8Whu39cFL0TkwND44WhycnC4OBUsaRRDOWLg8V8cWCwMNBb6u/rGxrsu+cxJQT6b8GAIV7iwELBQL4G58aeyy29Z+M1MNTXX0GFvdA33jfY33vr1vQgL48dXcYW9b5Ni1CkDa5t0sLo2xBDcq8Szs3Ds/PjldnRjW3/wHvHq0Ol3smhodLUUDU/UBqq5EfvffDH5ImJ/vxQpW9oYqJYKU30TkyO7mv+Nc77vuPVxv5S+dGjD7xL7PmRgfKB0ZDyWy1cuDIRnXp5OtoYKZaHJH5Cmaz67+A72ktMqr1EPhEqmh+JBqBvuTpRKEaK0wO64wcqCwvVuclydbZ6sjrXwP1Yf7GQn5wYHxqoVAv5/MTkaO/PvfeCf0jJ/aVGva90S8XX6NhB1bHK/lyeOFGdmOn/KFXcvKpan3vz60yV5emTC7OF0mx1rnxyeomMkrRgD8RPVk9OnFwo5m/XzYO6m6nVZdx8VydL97W0hNswRkHRT1vJwem5iXq1slQd/Lxezgo/Bl8PlAtFXHymJz7RqP8lFrOh/e+g/1WqPHWycV97ufz4+PTc5HHsQ+qJD55Wrcj/eK14F7MT+cdUkz50VLHA2+0aS7fcNRZ6FSMu1KtT06d/aovROL0xv//A7ViihI/yePX49Fz51Nzj03OTH77nVgu0mi2DVGN+/uRwPiy4cUoNjM+frk4W+sfnT5fnqo+XT82h+VA+rlaMTVu60KgX+tB0WJ6eKy/MViZwBXh347HpUIyOPFQe2X9r6beuVSjzHvjsu6JzjJaq/nJxfgGNhcWH6TGmeGBj36Ak6/tUdeLE6Ogm69lHpt5tH91Opmlxtgme0Z7bsUXBlBSHbjPQ4bpY6FVjUK7W6/P1wz3vYE6qWf1g1mBZtOGM0Q7w05t3QGXuzDtr8dHGSG+5T86dKTXOLFTL05Mbe+CjD9we0aPVdwDkyH3v1urTWjk+tu92Q9CvU+E8fuhv/LXJWlQgjp5S6Hv/atGvl5APv7M+0orDI/fcrnSeihJxHz+BBX/mnfLSO1MGpk5u0i+PDryDyVMKPQHl0BPwiSO32bxvti7PLTV6+4xMeXJ6uTx+hlb5T43iZrwX7eplPdX68sa0IyWjMV9GLp8sV+r1yhlUh6v9hYmBwWqhUKn0VqvF0cc+cLve7Q3Hgpr26cLtyg6tKas1kZ8ZvE2V3uLaKiRmQhb7zMpfnx5SrCzhXP7Z/tsshvS3MKglpNGAn5/Uwn4zolGvHhqvTMw06pWJaqsDUN1dOjFfb5Sjj2ptGymW1y9mnx0dKZZ/Ye49xnLrDQPhKw/eXoPo7Z+sTlVOza4by/n5mWMkZH5m7B2s/AWls9CLkh6LR6uj737kRobK42+vNWOXUDCIwjghb1OjbyCsMTevm7h0YvpkeXqpfGpuqYFOhPJy7+Q7nE3j8/VGVW6YwDhS0QSeH69VJxpl0gzq1SncgZYmq/lCX76vlJ8c6uvrH2U/m1yoz0+emqjWl4QzW5k7fqpyvMrtR08tNVh6oT4/UV1aqk7uHz8jYkTCHYUDQwMH8pCLojygN99b3J/v31/o74k/Xpmtn1qK5w/05g/0/f8JDhgU');
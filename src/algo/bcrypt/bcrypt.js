@@ -0,0 +1,209 @@
+import {
+  Base,
+  WordArray
+} from '../../core/core.js';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { Base64 } from '../../encoding/enc-base64';
+import { isString, wordArrayToBytes } from '../../utils';
+import { BlowfishAlgo } from '../../encryption/blowfish';
+import { blowfishWasm } from '../../encryption/blowfish_bg';
+
+const BCRYPT_VERSIONS = ['2a', '2b', '2y'];
+
+const MODULAR_CRYPT_PATTERN = /^\$(2[aby])\$(\d\d)\$([./A-Za-z0-9]{22})([./A-Za-z0-9]{31})$/;
+
+/**
+ * bcrypt's radix-64 encoding: the Base64 bit layout with its own alphabet and no padding.
+ */
+const BcryptBase64 = Object.assign({}, Base64, {
+  stringify(wordArray) {
+    return Base64.stringify.call(this, wordArray).replace(/=+$/, '');
+  },
+
+  _map: './ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789',
+  _reverseMap: undefined
+});
+
+/**
+ * bcrypt password hashing, on the Blowfish key schedule.
+ */
+export class BcryptAlgo extends Base {
+  // bcrypt is part of the Blowfish wasm binary
+  static async loadWasm() {
+    return BlowfishAlgo.loadWasm();
+  }
+
+  async loadWasm() {
+    return BcryptAlgo.loadWasm();
+  }
+
+  /**
+   * Initializes a newly created password hashing function.
+   *
+   * @param {Object} cfg (Optional) The configuration options to use for the hashing.
+   *
+   * @example
+   *
+   *     const kdf = new CryptoJSW.algo.Bcrypt();
+   *     const kdf = new CryptoJSW.algo.Bcrypt({ cost: 12 });
+   *     const kdf = new CryptoJSW.algo.Bcrypt({ cost: 12, version: '2y' });
+   */
+  constructor(cfg) {
+    super();
+
+    /**
+     * Configuration options.
+     *
+     * @property {number} cost The base-2 logarithm of the work factor, from 4 to 31. Default: 10
+     * @property {string} version The version written in encoded hashes, '2a', '2b' or '2y'. Default: '2b'
+     */
+    this.cfg = Object.assign(
+      new Base(),
+      {
+        cost: 10,
+        version: '2b'
+      },
+      cfg
+    );
+  }
+
+  /**
+   * Computes the bcrypt hash of a password.
+   * Only the first 72 bytes of the password, NUL terminator included, are used.
+   *
+   * @param {WordArray|string} password The password.
+   * @param {WordArray} salt A 16-byte salt.
+   *
+   * @return {WordArray} The 23-byte hash.
+   *
+   * @example
+   *
+   *     const hash = kdf.compute(password, salt);
+   */
+  compute(password, salt) {
+    if (!BlowfishAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'BcryptAlgo.loadWasm\' should be called first');
+    }
+
+    // Shortcuts
+    const { cost } = this.cfg;
+    const passwordBytes = wordArrayToBytes(isString(password) ? Utf8.parse(password) : password);
+
+    const hashWords = blowfishWasm(BlowfishAlgo.wasm).bcrypt(passwordBytes, wordArrayToBytes(salt), cost);
+    if (!hashWords.length) {
+      throw new Error('Invalid bcrypt parameters: the salt must be 16 bytes and the cost between 4 and 31');
+    }
+
+    return new WordArray(Array.from(hashWords), 23);
+  }
+
+  /**
+   * Computes the bcrypt hash of a password and encodes it as a modular crypt string.
+   *
+   * @param {WordArray|string} password The password.
+   * @param {WordArray} salt A 16-byte salt.
+   *
+   * @return {string} The encoded hash, such as '$2b$10$<22 characters of salt><31 characters of hash>'.
+   *
+   * @example
+   *
+   *     const encoded = kdf.encode(password, CryptoJSW.lib.WordArray.random(16));
+   */
+  encode(password, salt) {
+    // Shortcuts
+    const { cost, version } = this.cfg;
+    if (!BCRYPT_VERSIONS.includes(version)) {
+      throw new Error(`Unknown bcrypt version '${version}', expected '2a', '2b' or '2y'`);
+    }
+    const hash = this.compute(password, salt);
+
+    return `$${version}$${String(cost).padStart(2, '0')}$${BcryptBase64.stringify(salt.clone())}`
+      + BcryptBase64.stringify(hash);
+  }
+
+  /**
+   * Checks a password against a bcrypt modular crypt string in the '$2a$', '$2b$' or '$2y$' versions.
+   * The cost and the salt come from the encoded hash.
+   *
+   * @param {string} encoded The encoded hash.
+   * @param {WordArray|string} password The password.
+   *
+   * @return {boolean} Whether the password matches.
+   *
+   * @example
+   *
+   *     const matches = kdf.verify('$2y$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm', 'password');
+   */
+  verify(encoded, password) {
+    const match = MODULAR_CRYPT_PATTERN.exec(encoded);
+    if (!match) {
+      throw new Error('Invalid bcrypt encoded hash');
+    }
+    const [, version, cost, encodedSalt, encodedHash] = match;
+
+    const expected = BcryptBase64.parse(encodedHash);
+    const kdf = new BcryptAlgo({ cost: parseInt(cost, 10), version });
+    const actual = kdf.compute(password, BcryptBase64.parse(encodedSalt));
+
+    // Compare every word, so that the time taken does not depend on where the hashes differ
+    let difference = 0;
+    for (let i = 0; i < actual.words.length; i++) {
+      difference |= expected.words[i] ^ actual.words[i];
+    }
+
+    return difference === 0;
+  }
+}
+
+/**
+ * Computes the bcrypt hash of a password.
+ *
+ * @param {WordArray|string} password The password.
+ * @param {WordArray} salt A 16-byte salt.
+ * @param {Object} cfg (Optional) The configuration options to use for this computation.
+ *
+ * @return {WordArray} The 23-byte hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.Bcrypt(password, salt);
+ *     const hash = CryptoJSW.Bcrypt(password, salt, { cost: 12 });
+ */
+export const Bcrypt = (password, salt, cfg) => new BcryptAlgo(cfg).compute(password, salt);
+
+/**
+ * Computes the bcrypt hash of a password and encodes it as a modular crypt string.
+ *
+ * @param {WordArray|string} password The password.
+ * @param {WordArray} salt A 16-byte salt.
+ * @param {Object} cfg (Optional) The configuration options to use for this computation.
+ *
+ * @return {string} The encoded hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const encoded = CryptoJSW.Bcrypt.encode(password, CryptoJSW.lib.WordArray.random(16), { cost: 12 });
+ */
+Bcrypt.encode = (password, salt, cfg) => new BcryptAlgo(cfg).encode(password, salt);
+
+/**
+ * Checks a password against a bcrypt modular crypt string.
+ *
+ * @param {string} encoded The encoded hash.
+ * @param {WordArray|string} password The password.
+ *
+ * @return {boolean} Whether the password matches.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const matches = CryptoJSW.Bcrypt.verify(encoded, password);
+ */
+Bcrypt.verify = (encoded, password) => new BcryptAlgo().verify(encoded, password);
+
+Bcrypt.loadWasm = async () => BcryptAlgo.loadWasm();
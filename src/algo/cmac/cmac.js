@@ -0,0 +1,115 @@
+import { Base, WordArray } from '../../core/core.js';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { AESAlgo } from '../../encryption/aes.js';
+import { aesWasm } from '../../encryption/aes_bg';
+import { isString } from '../../utils';
+
+/**
+ * AES-CMAC (OMAC1) algorithm, RFC 4493.
+ */
+export class CMAC extends Base {
+  /**
+   * Initializes a newly created CMAC.
+   *
+   * @param {WordArray|string} key The 128, 192 or 256 bit AES key.
+   *
+   * @example
+   *
+   *     const cmacHasher = new CMAC(key);
+   */
+  constructor(key) {
+    super();
+
+    // Convert string to WordArray, else assume WordArray already
+    let _key = key;
+    if (isString(_key)) {
+      _key = Utf8.parse(_key);
+    }
+
+    const keySize = _key.sigBytes / 4;
+    if (keySize !== 4 && keySize !== 6 && keySize !== 8) {
+      throw new Error('AES-CMAC requires a 128, 192 or 256 bit key');
+    }
+
+    this._nRounds = keySize + 6;
+    this._keySchedule = aesWasm(AESAlgo.wasm).getKeySchedule(keySize, _key.words);
+
+    // Set initial values
+    this.reset();
+  }
+
+  /**
+   * Loads the AES wasm binary, which CMAC runs on.
+   */
+  static async loadWasm() {
+    return AESAlgo.loadWasm();
+  }
+
+  /**
+   * Resets this CMAC to its initial state.
+   *
+   * @example
+   *
+   *     cmacHasher.reset();
+   */
+  reset() {
+    this._x = new Uint32Array(4);
+    this._data = new WordArray();
+  }
+
+  /**
+   * Updates this CMAC with a message.
+   *
+   * @param {WordArray|string} messageUpdate The message to append.
+   *
+   * @return {CMAC} This CMAC instance.
+   *
+   * @example
+   *
+   *     cmacHasher.update('message');
+   *     cmacHasher.update(wordArray);
+   */
+  update(messageUpdate) {
+    const data = this._data;
+    data.concat(isString(messageUpdate) ? Utf8.parse(messageUpdate) : messageUpdate);
+
+    // Process every complete block but the last one, which is needed by finalize
+    const nBlocks = Math.ceil(data.sigBytes / 16) - 1;
+    if (nBlocks > 0) {
+      const nWords = nBlocks * 4;
+      aesWasm(AESAlgo.wasm).cmacUpdate(this._nRounds, this._keySchedule, this._x, data.words.slice(0, nWords), nBlocks);
+      data.words.splice(0, nWords);
+      data.sigBytes -= nWords * 4;
+    }
+
+    // Chainable
+    return this;
+  }
+
+  /**
+   * Finalizes the CMAC computation.
+   * Note that the finalize operation is effectively a destructive, read-once operation.
+   *
+   * @param {WordArray|string} messageUpdate (Optional) A final message update.
+   *
+   * @return {WordArray} The CMAC.
+   *
+   * @example
+   *
+   *     let cmac = cmacHasher.finalize();
+   *     let cmac = cmacHasher.finalize('message');
+   *     let cmac = cmacHasher.finalize(wordArray);
+   */
+  finalize(messageUpdate) {
+    if (messageUpdate) {
+      this.update(messageUpdate);
+    }
+
+    const data = this._data;
+    data.clamp();
+    const mac = aesWasm(AESAlgo.wasm).cmacFinalize(this._nRounds, this._keySchedule, this._x, data.words, data.sigBytes);
+    this.reset();
+
+    return new WordArray(Array.from(mac), 16);
+  }
+}
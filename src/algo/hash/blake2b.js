@@ -0,0 +1,213 @@
+import { WordArray } from '../../core/core.js';
+import { Hasher } from '../../core/hasher';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { isString } from '../../utils';
+import { loadWasm } from '../../utils/wasm-utils';
+import { wasmBytes } from './blake2b_wasm';
+import { blake2bWasm } from './blake2b_bg';
+
+/**
+ * BLAKE2b hash algorithm (RFC 7693).
+ */
+export class BLAKE2bAlgo extends Hasher {
+  static wasm = null;
+  static outputSize = 512 / 8;
+  static algorithmId = 22;
+
+  static async loadWasm() {
+    if (BLAKE2bAlgo.wasm) {
+      return BLAKE2bAlgo.wasm;
+    }
+
+    BLAKE2bAlgo.wasm = await loadWasm(wasmBytes);
+    return BLAKE2bAlgo.wasm;
+  }
+
+  async loadWasm() {
+    return BLAKE2bAlgo.loadWasm();
+  }
+
+  constructor(cfg) {
+    /**
+     * Configuration options.
+     *
+     * @property {number} outputLength
+     *   The number of bits returned by finalize, a multiple of 8 up to 512. Default: 512
+     * @property {WordArray|string} key
+     *   The key for keyed hashing, up to 64 bytes. Default: none
+     * @property {WordArray|string} salt
+     *   The salt, up to 16 bytes. Default: none
+     * @property {WordArray|string} personalization
+     *   The personalization string, up to 16 bytes. Default: none
+     */
+    super(Object.assign(
+      { outputLength: new.target.outputSize * 8 },
+      cfg
+    ));
+  }
+
+  _doReset() {
+    this.blockSize = 1024 / 32;
+    // The initial state depends on the parameters and is fetched from wasm on first use
+    this._state = undefined;
+
+    // A key is hashed as a first block of its own, zero-padded
+    const key = toWordArray(this.cfg.key);
+    if (key.sigBytes) {
+      key.clamp();
+      const keyWords = key.words.slice(0, this.blockSize);
+      while (keyWords.length < this.blockSize) {
+        keyWords.push(0);
+      }
+      this._append(new WordArray(keyWords, this.blockSize * 4));
+    }
+  }
+
+  _getState() {
+    if (!this._state) {
+      const outputLength = this.cfg.outputLength;
+      const key = toWordArray(this.cfg.key);
+      const salt = toWordArray(this.cfg.salt);
+      const personalization = toWordArray(this.cfg.personalization);
+
+      const state = outputLength % 8 ? [] : blake2bWasm(BLAKE2bAlgo.wasm).getInitialState(
+        outputLength / 8,
+        key.sigBytes,
+        salt.words,
+        salt.sigBytes,
+        personalization.words,
+        personalization.sigBytes
+      );
+      if (!state.length) {
+        throw new Error('Invalid BLAKE2b parameters: the output length, key, salt or personalization is out of range');
+      }
+      this._state = state;
+    }
+
+    return this._state;
+  }
+
+  _process(doFlush) {
+    if (!BLAKE2bAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'BLAKE2bAlgo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const dataWords = data.words;
+    const dataSigBytes = data.sigBytes;
+    const blockSize = this.blockSize;
+
+    const state = this._getState();
+    const nWordsReady = blake2bWasm(BLAKE2bAlgo.wasm).doCrypt(doFlush ? 1 : 0, dataWords, dataSigBytes, blockSize, state, this._minBufferSize);
+    // Count bytes ready
+    const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
+
+    let processedWords;
+    if (nWordsReady) {
+      processedWords = dataWords.splice(0, nWordsReady);
+      data.sigBytes -= nBytesReady;
+    }
+
+    // Return processed words
+    return new WordArray(processedWords, nBytesReady);
+  }
+
+  _doFinalize() {
+    if (!BLAKE2bAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'BLAKE2bAlgo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const outputSize = this.cfg.outputLength / 8;
+
+    // Hash the held back blocks, the last one flagged as final
+    const state = this._getState();
+    const digest = blake2bWasm(BLAKE2bAlgo.wasm).doFinalize(data.words, data.sigBytes, state, outputSize);
+
+    return new WordArray(Array.from(digest), outputSize);
+  }
+
+  _contextWasm() {
+    if (!BLAKE2bAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'BLAKE2bAlgo.loadWasm\' should be called first');
+    }
+
+    return blake2bWasm(BLAKE2bAlgo.wasm);
+  }
+
+  _getContextState() {
+    return this._getState();
+  }
+
+  _setContextState(state) {
+    this._state = Uint32Array.from(state);
+  }
+
+  // The parameter block is folded into the state, so a context only resumes with the same one.
+  // The key itself is not stored: only its length, which the parameter block records.
+  _getContextParameters() {
+    const salt = toWordArray(this.cfg.salt);
+    const personalization = toWordArray(this.cfg.personalization);
+    salt.clamp();
+    personalization.clamp();
+
+    return [
+      this.cfg.outputLength,
+      toWordArray(this.cfg.key).sigBytes,
+      salt.sigBytes,
+      ...salt.words,
+      personalization.sigBytes,
+      ...personalization.words
+    ];
+  }
+
+  clone() {
+    const clone = super.clone.call(this);
+    if (this._state) {
+      clone._state = this._state.slice();
+    }
+
+    return clone;
+  }
+}
+
+function toWordArray(data) {
+  if (!data) {
+    return new WordArray();
+  }
+
+  return isString(data) ? Utf8.parse(data) : data.clone();
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options, e.g. { outputLength: 256, key: 'secret' }.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.BLAKE2b('message');
+ *     const hash = CryptoJSW.BLAKE2b(wordArray, { outputLength: 256 });
+ */
+export const BLAKE2b = Hasher._createHelper(BLAKE2bAlgo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacBLAKE2b(message, key);
+ */
+export const HmacBLAKE2b = Hasher._createHmacHelper(BLAKE2bAlgo);
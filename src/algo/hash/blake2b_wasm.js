@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJzVfQuQHMd1WHfP7M7sze7dHH488kDizeAoHkR8dvd2d/Yomro+6gCeIAA0SUkULXuxt7sH3N7hPnuLAyAj3KUCUKBEUrAtV7FsJUHFkkU7ZIxETJmOVNbZxaqwHNqGY5bD2ErCihWbZckxHMsx5WKM8L3u2Z07fEjKdLlCFG9eT/9ev379+r3Xr2dZefkYZ4zx3+A3HWbsMOOtw7zFDvMWbx0WrRbDP7x12GghjH9567DZohQ9eOtwrKXS6slbh+Mt/UYDvHXYaoXv7NbVIG8dTrS677swbx12Wq1IVrK1Jmm2Wo/S49FWixk/8ePG5k2x3kS/6E25ifgW04yZph2Lbewz+5M9yWRPT9w2RTy+IW7FrZhhxmMxx030CpMLYYqYGY+bppUwY3HTYsIy4rGYaZqmEKZpxBgTZowv8g0bYgZn/fEEb3HZbq8yx7rIe+LHascWGqcES9VOLi40mvcuzDdrJ5tsS9+RWnNyfqY5U557sFlu1tjNqZlj0RK3WNWFexunFpss21Nd2DszX56b+UyN5fpLpRNTM/PVI7X50rHy3NxChT28IfKuUVMv9/dFXk43ajX24LbIm3K1WmoulJab5cpsaXFhZr5Za7CPJW7iTHKn/xMfGt5838Suj/xoMT3gf1KObep57Nf4j//2kEi0+KPbmXRnfT7ExJj6B0z+NZ9MMYJeufKTB1JMMo8lTQeYdOq+kCeXPEOyYfHy+THfnEgJuc2LYa0rV6xDKQ6G/AAIaa8c8dnsiuRLwCRvzsp76l4syZ2wG/yHjbx0fsyPS5falm8aWNqZHPQNaaz4YsVn0liaSDFgsrWMbYm6b0mj6Rvym0+vsrrPwJAvITQsmC+Ghe3be1McbGAFkQQGdkHYSeEAIfzK+bEDqTiwlAjRZxOYPipFUz73lG7FHhamPLkExqwnwPbiBhLFHhauz1IMgU0ExIfFgBfTNIsPi6TPIL4PM+Qm6eKbTT4bVC2mOJFQOPja9tdixx2sUoe4dOvABj3LGAPLS4DtM3zN6DW2Zg96FjBq3Q4bZg4kJCsI5kBsIhVXyGAvWztjwgGxvTShsWHhQnxvikEMUdiEpEgmHJVygaV40nYU8RggbRxgECuIATVE17dpLhBvVxUbcNRIbSIkZmzSGUms3CGlb4MAG8QkjkIAgzjYg14cmGcnmeNIJNujYJ1YKiBHOMhAdY+BAEMajYIwEar7BhjYIsc3yZij2BVEUwoQTZ9JBmy2sXTUTyCLCM0iNoguizCkv4UUsBDZJNhgqUnQKCQ6KLBO3wz7jiMKs76tesdu69gCU2z8yvkxX0zgUhkWr54f84wof1uSg5DGStNPLGERsCDRoF4AV5PGU3gW8gK+CXH3rAjunnDAAqMgkJQG4m4g7vgQBWHj8kSa4TgKiIOk6Xjl/FjSdCTTHC8fJebAQb58fkyvRhDSnUTO7Yw4jiNGwqnB2jhYURAsHKo1gSythsqiQ01IDhYNNaaGmoCYHqrVHarlJXCo+Ma39FATYHWHajmQQC6ywQrXSkI9rHDJKI5gHYRJGLAue9CM2iElBFECV5pdx1XPkwLzcY13BF9sjRiIk9yzaeFiW56NQkTeDDHJV2YhJrftHWx6FuaThIj7ccXtfgIMpGYC2SUB4hBS1rMh4QlIUKMC4h69Sxp6+SRwgBAHS966Is2lOq61+L5BfDvoMbAkb3oWxFPMcYjl7YbGTkCsw/hgLuHQjHUCzWMOrmXuGGPhgvTjiJsFAixckolBL4ZTPonyBWzsMyGosItkIOnjxR0QEAMLl68NCQRsNQ7EClAydJcCUyTAhW7MHkpxB+VmV1zaSlzaKC5tJdAi4jLeFZc2Ltd4yAJxPfc2ykV7nbiMrxeX8a64jIcNd8UlzjehrBDCniIiM75WZNrrRKa5RmTiJHZEJo+ITNo04kpkxrF0XItMGm18IsVVxiadQXzWXaj2uoUaLlO7s0xtWgKdZcrCZUpl22KSehbuhqRw1u63oTyy1CKNg9WRR2/yrkCKK4H0Ju9KpPg6iRRXy1RE5ggfIpyqtcvUXr9McTJxRaau1gw0Hx0K+eqSSu9PMeDSrMvL51dRRP9K26zLdvv0kjskxoAPC1MvXYZ7GR8WSS+mqPrt85Rv+4m6zwqYxHWDOa+j9Eas9w8WMNFF4rVQrj/39CqtdBwwbb00CN+q700JoF2FpVjScDqEfkOpF0hchitAyeU3zo/hHF/ZVhDfo/5jBfHi0wgkCuIiAfGCeA4BInhBvEzwSwRfirx/ieBXCH4tUuYVgl8l+PVImVcJ/jbBb0TKfJvg7xB8OVLmOwR/j+A3I2W+R/D3CW5/sVvm+/T+LYLPfbFb5q3I+zP0/swXET4fgZ8k+EmCn4nAXyL4SwRfiMBfJvjLBD8bgb9C8FcIvhiBnyP4OYJfjMAvEPwCwasR+JsEf5Pglwl+ieBLkfcvEfwKwa9FyrxC8KsEvx4p8yrB3yb4jUiZbxP8HYIvR8p8R9GW4DcjZb6naEtw+3y3zPfp/VsEnzvfLfOWovN5ovn5bpkzBD9J75853y3zJMFfIvhCpMyXCP4ywc9GynyZ4K8QfDFS5isEP0fwi5EyzxH8AsGrkTIv0HKIy746blBMnqz7ooDrr1P6m6oEsFlIyIfrPquj6LALKBlQu9RSEFUBOVwQ5turvt1u84L4zvkxlAUC4odIfIr9alNLoja3RHsQX4HY3pSh5S+tXWwI4pODtGghDom6Z2sxoOSD2k+ZbwHZHB0hIBznmq1zp1OdNAYtRwgb3FNRoNR9ezIlqL7tsY5qHCGKpYlyFSFMRQgLzBsRggh1GJvDxmh/kG59ctC35CAV3YHSx+sBS7p1TL3w9FggUAVBMQ09ASqBN5BauA+88DRqe1vrJC+ZtGhrlCbWtydxD7ZA7E1ZYIGFG8GjS0rBt0DM+kwjT3qfMkLVRiaAuRuS9pqNzJYcGG1kcbWR2RDXGxmLbGRoZOC+TBsZ0xuZDay7kTHaqXAjo60riVRSD9zdkpajdjYkF84SJOpEYDUvSMjunmx1Xwq9Qc96Bgi9m+xNafhVhE3cqWnLksaS5PtonHHS0Nx+0sKxKsTraNuaSgeLa4Lh5sc79glaBEguo7PvG+6GZHwNuYRE6zxCLtEhlxEhl+EJJBe9Cg1tT0QMbc9wNGXACPd9oR5oniRjyLSo+eEKvkQrWG+lZEnZxMFgR/SWjqVFJkwy7oTGhdA2HlleffsRa9TfXlWNklKPqHSMxOtZAa8oK4AMOaqOBqjuHmuRKdc1N0MDNBnDmbfUfEeGoRekoKkPFyQDMasVFUjUfUGrMq5WpQ3xiJJ2tXgyHLV0XguphZXQ4lXVO3OtBtSlAKlJuuAr0YJdplAqVmjFhI37AowDmgl+6IlygEu3PsSAOf95g+hv3YLupHZs1jeHGJjjqUC8aYI5/k/b7fYqD8RlTDzRbrdf54F4AxNfbLfbF3kgXsfEY+12+xILxGuY+BwWY4G4hIlz2AALxMuY+Hy73YZArIYwVnkxrI+lLmLiqXa7fZkF4llMPKmqXEC4jf/xQDyDiS+83X86EOcRfrrdbp/ngTiHicexOg9EGxOffbv6BR6INw2NCyJ22dD4Y5dvYCIWiNcNPVxs6DVDd+0G4pIRjokH4mVDI4hDXw0bxcSLhqYQBOKiofFIB+JZQ2OOQ7qAiTMap2cMjfolHojzhiYDon4u7B4J1A5zELE3hUYdkbksNGZY7A2hm8bWXhcas3QgXhMaAcTyktCYIQIvC00hnOFVoceP9V8MiyG5LwpNbiz2rNBIu4G4IPQ8Is7PdBpjgTiPibN60OcwkQhEO8QdG32Tv42uGYjLXJdEhN7gug2cpde57haH+hrXdEccLnGNqhuIl7mmAVJnles5QBxeDHPcQFwMa2DDz3KNNjZ8gWukcNjPhMhAIM4jnAzEuRANxLodFkgH4qR+j0Nc1CSGQBwN2fntaTiskcZeH9aoIa3u11OFTd6n6YnjH9MwlimGLPD2DKQ1wjiDw3pmcbwQsjsLxEA4/W+T39VkTAfC1pi5gWDjXzr7g6+u/tYTv354/Lt/+/gffuvf/Z/f2AbGoLcZxPjvnf25v/jb3/3S536PnfW2jJ/76b/6Z1de+cGZP2PeTZJ5xvi/eO0H/+U3f+oPP/vzLW9g/Bu/88u/+Nql7379Gy3v5vF/dfn3v/8r/+ubv3q3d8v4D37zzZ//qV/4+uU/Yt7g+F/++fff/OrX/u/XvtrytgLbYRT9W9F9uMMY8G/ztiGQ9sHzEHB939uOwLA/5N2OgO1/wLsDAfCHvR0IMP+D3p1RQ/Na/4wxMMEAQ67U/RgYsmdyUMabdd8gHwLuQoL8kL4l3UMp8knavo2wiXDSTyAcQ9j1exCOI7zJdxC2EB7wkwjbCG/1UwgnEAa/F+EehIf8PoQdhId9F+Ekwjv9foRTCKf9DQj3IpzzNyLch3DR34Swi/DdvoEwhzvg9tPAwZZGs77DYKf9O4BDIkzBHTBwdhye8Afg5tP+zXD72fHhJ/zbsdTA2fF09D1/wr8d7oQd2Fq809qdQK4/3dqdsIVa2wI3nfZvgh3U2o7T/k6s1LumUl+k0jYQWCDZKbANOKQ6BbbBILU6CFtP+1tBUKsCSw0Sjrt07nbwsJmeTjPbgYPTaWY7bKaCm+GW0/4t4FEzHpbaTM3Q+92Y1aHCnbqHQVWFqODdDruxMn/C9+AO7NBdQ9v+CG13Ij3ST/g7NVV24Qh24SA6vd+hi2xRg6OGPQ924TD5E76A7djFhjVj2hgZUzh9O5HkO5HqHfps78zhTfo9f8ITsA124sxwnBngsGkN1Y0I1UNyEQd05nJbh1zhe/6EtwNieExkyOQ+VPUZDMKtZ0GcDYwiMPAAzsLms4GRBga3w9BZGDgbGMPAYAsMn4UdZwMDgMFWuO0sbDsbGAPA4Bbwz8L2s4HhAoM74ANn4eazgYGq+p3wwbNw09nAYGDKdqw+xPpQb3Xl+c+tMncHQ3+vKy+c1QlLuvJimLClK1fDREK68lKY6JGufD1MONKVl8NEUrqy/bhOpLCfMNGL/YSJPuwnTLjYT5jox37CxAbsJ0xsxH7CxCbsRw/hryweb6lTuuQQUw6ulOxb8nohJS9fuXKFL/l9cnHZczGP+Snok6JZ9/q74kyMIRZ1300x6JWi6aWMMUhNpExIydN1LwX9qNS70C/NutcPLoh9ypcFqWHUitG6TUmzHknY0UQymnCjiU3RxEA0sTWagGhiKJoYjiZ2RhPpaCIXTRSjibvrXh+kJJowKeijjH3ksI6TL3AvOWwl2TKM9jjc9VFXdh8h0wmk6R70+9CR14fW7lboQ0twAPrARM9tHzjo2e2DHjSA+pQR2Tf+8r/+pZ/bhLay7QlISjImk9CHmZQype2lcKvGhpPozrTBdAGS6K9M4cP2+jBfiiY5IAFHIZp1PO15q4chM6JFKBxIQgrMup8qiGR4GgkCUrMHdMuQgrhq2URcqGXdDx2kkM86jv240X5i4Op+sIG6z7vtJ6RRl2LF7wUBvNsPh17VT6cDjh30TpD3k4bbK/leYkK+5OGi+bsrV64YS14/9AEnrhVYECzPNdCuc+VL51YZpMCeHBwW5GIX2JOLBFNZktcjuS6aJa4y3gQOpO6noJ8EEYdU3ePg0HB1d2CpwV7dDZe87nEcPI4bR7zkpyZSHKcE4nXorUsLDXx+iEgmsBVfoC3PZEsCbVmzzaWCoGMMAVy+cE6tZ2lKcB8gyYg0Uj6H5A5hBkJJmPoQc74b53aLaE0Ojro8vYQO6lmfjlU4WmwmzgAeYYApjSUvLhkdRiGGAvDwQp9ecXVGOusn5On93bcGNQ123Rc7GZPfan20jmZePZIQ0YTRSXioQqCjipYRNuEJak1ESmBTeN4led1P6IJGnY6V6EAkAaZ868qVK9ZSFwE84VL8YWFbXLWFL0XnpdAv0YfE655tjEHCM/AoR0BMrnL6Q94spAlR0ELWNuVla8nn6viNvBtYXR3GGYoaSoz5CYw0sFYgIeMrDfnYZ8/YS8CVvLtOnnn9vOtliHq9jvRB89nnwJRIioE168XAABOPQzGGQl557C1rCYR61uVjbXNOuitIc5sIiQOx5Ft8STEg9sd1f7zbHzGBpi25m66JLM6bpjVFUghdSKwrhFY/obYVR38NzCjMA4jYfVjYWKJ1nUDewwxpEhfL00t4NEP8mDDQz8DQb8I7XMej/Mij/Mij/GhDDBLIkQla6EIfwiXqNK828EhJta7fJmir7gt1cOn8jiV6W/3oulgVs741hM7OVV4HE2JuEp2UO4w3OaovBF7mqLwQ+AZH1YXA1zkqLgS+xlFtIfASR6WFwJc5qiwErnJUWAx5hdWltUJOTFvy/YMY8wG2ZPtm0f26w2gLb7NkHsrOJB5yIlLoNOJvOei3MSApraafwtgKG4z9g36vbKNu3eaTg17fOPO2jDPvpnHmDYwz7+Zx5t0yjiYV87aOM+/WcebdNs68bePMg3HmeePM88eZt32ceUPjzLsdfYmQUtE/Ni0kJbypZ6fbqY2d2tSp/Du+5LnQh9K9XzKvF6uGmKOo2YBaOAIb5emlunz9c6sMNkqxAoIEr78R9aglf5McWF65izHYgNJ9I6pDKt0r3brXCy7YKNltmu5+mu6wC7RvIFX3e7sd9HY7ABtZ2mhiYw6S+EXhbcHnReHdhM9nhTeAzwvCuxmfzwjvFnyeF94gPs8Jb6uemlsVX3i3KabwtimO8ECxg+cpXvB8xQjedsUF3pBiAe92FGFbAuNFDhbcFBgX8TkQGM/i8+bAuIDPWwLjGXwOBsZ5fG4NjHP4vDUw2vi8LTBOggXbAmMRLIDAOAoWeIFxGCzwA+NhsGB7YNwPFgwFxn1gwe0BzgeSi9VhM/Q9f9rfDEno2euifdTmdQ/3IF73bQ9jY9SKsmAzLYYU6kRylUnuHkTxL7lcZe6PkPP8baKwgDEF2QGzFeQGzFXQQMAGFAQBAwUNB2xYQemApRVUDFgRLGkW0CuDwn+V0R88iHmJq7CdVdy6aMrdXrAVRnvxhcDN86/jPN5ab9QDk6frvolcFiP/ujSlDTHc161B4HUlke6ok4Sy1LmtvT8lwpP3hDqDMFzXFxif0OeovQND5PanbOmCgRFsGOyEAWwe7tsndWvUNLrlLQyTsFD1PKAes7LdtvenLEBBaUO87llagyPFwuocGnDayMJjA0YHJ35MiqVUAmIq6CSO3go+mUqgv9rtx9gF4LPopEDUTeCAo5d8qSFFA3UcGzhuPlbnbD6GLcQ6znO0590+jA8zIXZ15RhW5l1Xu+2E7uh43bcQEyU2LMSC3CaIRQyj+KxuK9baViQjTYYUR0QgdjW+8U5QkoX90LFM9CQJHeQ8jPuJqwgoi/R9QgMjAK/bsj7CUa2FRIi7ffpE4lJncPtTJh2uoB6B/2PI4kQY8aL0ZuI1Q4U4Ia8ZyGuG4jUBBgbMWWAfSMXluc+vMrlbvvj5Vebewxz58hOUfvMJnX6H/GsPJ6YIz0O6Ik0uhSEHr2EslArmQ4vZZT5Xyq0hT8uTCnUhjaVBCkdDzdZAEarGyLtj5GiAMNdyQDhPWSLRsnEbhVk/0Q0oVWqs/FarLsdoEclVhrwQk+5+Oh3tn7yuEQaJcYa7KT1c9bDVg+kIHvcRHLt70O/BUFTmOTqWi5QdpIqXhJi8bclL6R0J46B6wKn7fbiR0T6BHYKD+4SpNiJXbUT94UbUhxsRGshrN6IUVqr7DknJJG1E2PJ12wUnsv9o/CEBPRBT5OyhoezFLFsNzaahmRMpozM0Ww0ND5e9HrBxaEZkaKYaWvwqFKz3NDQjOrQeGhq2fN121w+NmCuBwVgm2HpB0Gj2OpDYYQx4GyCRF5u8jZDIsI3eJnxs8Dbjo9/bgg/XuwlL2t4APph3s/uIfJlJ0z3kOxTlJjco48lRB1IbkPWdcYYecGf83//Zt3755/7bbz/938mr7Yz/7z/5xh9//eyv/9aj6Nh2xs/+13/+R9/401/6n2da6N125J++8rVLJEkc2BDxQJ/1N9wj7gYHBiLO67P+wD1iJzhwc9Th7d98jxii6nDuHlGkOggNU0GEAOhYsQm8Ic/99N/8bGsZEXdgy7j9ONx0BjaPu4+fgU3jA4+fgY3j8PiZroOd8Mh1Wk+jxwrI3SU5xHALRiWSnqZ8mfbkC3FutmgTQgNDnlYR0hRGUFfhqWikLdGCFEsYi0bWCh6GYoQC2nMoPdSWgwfTFHprLEljH+5/oA9fdUiuOpLGjpTUpHNh0v5BuP3OVbsx+c+xY2y4s8sJBb+qjskNHQvr9mMrdLTbkcmsDtpODxHs4oQSDcRVKHUqOTqmgF65G9ShJ0ZjtcjL8T0ME0vF8eULT4/5GC1+ZZvH1REtX3dEyyiUDKFORyTtOmTrHFN3Dq4dFXP2HTyDPZCKdU5cJxSMZ7Om3DGZMlW0CCf/Hc2NASQ4UQHHHbM+iQHa6MXyuI4U4esOhhV6r0TR69KBVBhl+pJRFAawoMl2ZRv92T/ocd3u95Cw0WgCoxN8IZa0WSCuGXxhXB18YawLvjCwB1zL5G3hKryZU4hBt1M1G0zNBn+vyIMJgqwZ2aIjd8d5PdbxrFqhZ9VGz2oC7NCz2oOeVUeR34Ye8lEl13hWHTThHRJ2oknmjo2eVRs9qza6eBip1GbdS+L6Dz2rdsdHaUc9q3bUs2pHPat21LNqRz2rdtSzakc9q3bUs2pHPat21LNqRz2rdtSzakc9q3bUs2qjZ7WHlG8cZ0/Usyqt/dfd0cNYa9Qm0UlqomhROqeGbGpHlRnwbWJwnZX0k8+Pw+MUlOvz588gsNUXzz9E6npMnbHZdb8HLUGhvJT1fSkBjjyxP2VAHHomU0r2OaB6loDbNbpZ3Q9OXAfrmOMGvqNCXBy6tAEOchUK7ySpn2iuoKvAoZB1TGEgkIleVOIZ7HnWvQNIcdc+P0v5/NTFmqv6NK75VlyHqtKGHojLl9HHuFGddKAl9O2YsFv8UXmnbLdfYmqNxCnqBK0eD1HdvCI58EGIkXOO3ETtdttc6njKlLhWRgTaPwZdU8B3VMRSoULGktejfRUWlScXjyGTS14yfE+Wp0D1JNVx5KSiLp5U1MWTirp4LEiCgy6eqD6CTdFRhgWpSEls0ktBD4rzHmJIjN/xEnjkswv4oFq/eL9gD0/6/FAnCJxGbi+RhpuYJbeU9qjq7FtXpLHUawrGGcamKyvFln93BWO3Vjxyo1+5coWhBMEYJI/i+JjXQz4Q9HSjXLpiLB1KCcnRz42oWtADCXCGhdsfw4DhZIy8pWyHsP3eryFOV04tyYvtdhvFq00VKXSwR4Ugo5MaJyZDOqJFyFMv5JbV/Yr1Xcp0tEvTcSi3B7qtCeghw88ZFsl+iy5NeRbaFrp11mkdlYZJL4V7NJ6HX3tURqeLaKNoS/QGwiaDujMyS42sp4MLWnhYrUdV81IOpJzvmSKubrCh387GkCMUwYmOwX8F1Ruc6gm9PyXmfQYW3nywgO0fpDsGso2nex/FQ0BUggxI1GWrDol5Cgokt1IM4i56Q+zQEUigcgQSqByBBCpHIIHKEUigcgQSqByBBCpHoE3upF6yJQ30/iGa6kIImxxEs4Z1vYAYR6Gw6noClfOUMMXIdvKxJfCeXMcFZkV8bBYu5iU/Fer/DPV/XDVa/zdQ/zdwN6v7CdnmtOAI3RcFeasIvijIY0Xws4K8VgRfEOS5IvgZQd4rgs8L8mARfE6QF8tWIydPVoe2J7u0XezS9miXtoe7tH24S9v7u7S9r0tbutdBnq7e8TY/7fdKtt7NlcQdgdzA0EvznMIa6EZCRp3rc+R5zR6rJre1tmCGxi0G25EGvYsxP0bXVmIkoymSkK/Q9QPkV7z5qO4ixLUkpAMSO6IRx1Zl66O0I6HegZTHbTYhV/m+lIEmPu4pptJ6zfEL7XY7HlAAJsVc+Lih4hIZFiYtOFztMeWiIGcNtRqDOC465b3x8B4LlYqpUrhh+jEQexj3RVgyBgJLGqqk6YSr26bNQRIeXo+yuOmmppAxVAjEMONeD3JtIrxcpfYUR1clHy7eJdrDmOeEVrtJ5FTxungChS1hgURYgOTzNVqwyJ6WLuGAxisKJqTMHm5S87jV68zEmkzybyU+xHvRwP0QT4KJZ7n2WpLT6rw2pRlRxggp44TcwHDwMQdMFSIZc37VFEar91HJu+ZXXfIV3yS68K5yJPCtup0ESi+SwuNJU42C0gZp1eqAGNNmN5/SsW6+gel4N5/SVjcf9SBpd/MpnejmxzDd082ntNPNx0uvMtnNp3Sqm29hurebT+k+ZRUI6R5ICRCyH5md5zHGGR+0j+VFEk9s8sL2EvjY5PXgw0V9PC+2ekl8DHgpfAx5vfgArw8fOzEYIi+GvX585LwN+Eh7G9HAyIsiqpA8L+4+o2JSNihrW8WkuNCvEhiT0gt9KoExKUlIqQTGpPSAoxKuujGnEjZdbLVUgqnexnRvHzkTGKFpeknHdsh+2S8vhgk8t74QBq7gGdWqTvwsMg5HxjFDqUOME+seZwoV3WaReyAinVbU/TMB1j1kmcZCdqJLbEJFyalatjojVNxlqkate/DQP2QqM1LL1bVcqqV5rltrU5fV4pFaA7oWXSgOObFba2uXAe1ILdC1gGpp/uzWGuqyZU+k1rCuNUy1NNd2a+3sMmsyUiuta6Wplublbq1cl4V7I7WKulaRamkO79a6G3dVYnTgVA7nhlheAL9HfKQPRZAhXz8TYYvVMx22MORFnaB95ZJOfNvgSb0jCWW/2uQJRCd6rOt5pesFJPhQv4mDMY9/FiXbV0fxhLdePQs3CIzF2J/CAwE6md9P+5n24dPsJ6TaydxHpOmRgeMe9BMTeF9FSKbuSyTUbcCYcoSE/ZLDgpGJQGwGxjz629HSn5tFTHwOsQMpFd4h6YKfNN3b6Bwaj8Jx57QdUn+4MjFIIMfx/8lB31EuUUd5ex35qPL2cunSAbNFHl9e903J67sYuZ6XmmAq0K7LgaUmWI0GkWGx7sUgRcfNPl/j2KW6cgAPn5uzWEFdU7ZVJAk53mlTJx+w14Ou0lk/roMfhLL29KV/unRD7rsLZ7T2aYJF/rvwxVmTixaKkHp4CQInUischnbZMeUWQg9IeE2CRVx2Yq3Ljof3JUTXZUehLvoaBNfhKnh3pOuzUxEDhvLViYivTkR8dciXJ5d8w+1X7ql6ePBAI6CWga3x1fHQV4dV1+PSqUSBBJ0rLdzd0HfVjRZOPigjvNFiaB8Uj/iguBKS9MrnnRstvOuD4p0bLXzdjRZOPqhr3M7g5C1UtzM6eEeHuN7x13etqxuqlVfWthKOvs9x/kLwWAulPBHit6/8JN5wxv/3D/ps9hB9GsMFrs8eufp4Rl0m6+pMBxs92eEgNIlNUCorXQjBIAyl0fh46wh1njpdJqf7S+RSJUcwdkc3jWd9PuuZSnqrD3OggkTuG338hOib2I3ZPUOL4QHV2vNDQesxWkgAd/tIZNLVYmxG35cBXtesQGuA6645eYfJYDkQukXXoGGgWKHrLd0vOQgdptU93jPcPv3tBJRjzhMG+c1R4nQ/DdBhQbzFboTrguJ29BVzunIu1D1zTpJTJPU9PkRCCUeub0V3b6qLQc/EQxsvBtwX+FrQa2yND3omCGqdhw3jdz709z6MiVR4O4qtubzO1eV1ij/HSDCuvhCxCUTKwB2L0GEYJ2PSp1Ew6YKgbyKoYSHS5l46r9WYm3oV6IUnH6Vlp7+VoRaxuvNOHw3gSufg2C71NuAoAnUzNumMPqdvzaV3Frn0/h8EN7u8b6/Q8aPcRisXXTWHiAk+AMYRX6hPzQjJmw15zzIdS47TJQZayFspQk/RB+cv7I/jd0PI5jD0DX4aB7BQCKBI6KAHZoMQ6wsVdboRYOqPnJD/0gwlDX4pQtCXIoT6UoShrhXoDzz45MIUE3TkwZt4yw8nO9KU46goLH3DTksnkiUDa+/b9SFPuPVwAOZVA3C+KgRXnhKIWq9M3T3l+OCyH12r+CUfjE4Qyu7RVlBSRQDIZP150n9VyqaUK8/gUSBaOWC4u2kn0zUJR1M17F+//h9fVZ+/p/pffuyG9W+A+bqaXxPc6ipUsgePKWiGUZ0CU75hTabUa9OLkQ0fr9ONQnm6DjG8eCjP/w6b92P4mMPQC7Izv8PmMciruYc9+YWxDzH6zgy6XGz5HTannGnRXANPDvCA5Mpz/8Pcrz75QVpngk7NldxWXctH676B/qlYtyd2zVZVGxRtrD5SoRtoUQP4haZd7KkvjFFcFJf4j4XDkz1gzLoJusNKlqzhfFXweEvdURahfERHKpq2OvhPtt1DZGjgC2lIk9asOTlIl0dpC1HUBTGrpDetBMndbaphU30+CGMhzU4fKkrnw0uy3WqgyJTxFYzyDLvD6b2LcQzRlau8cRcjdYbL5AqFkep6qgIpu7oGcntcVbTk652KlGeoPEF5qgVVkssNK3KRyqpgFSIeHYPVSWo4r3HONT85Q4wc2PL7Z1ZpxeGhAzqckA8wBMAV3bgjjFiVpooDUBG+6ts0+pUONDG6r/A8gwKCyK/qWzgwB4lIfifS8A31It5R5umLL1hffU0HZ0hFyrr3kmFpgkmhXfTJHVN91ITRadcQc36vOzL0u9oq/DsclOWaGmWMz3EPdIdhhWOIyzM0mXH307TnqLgSsWZI+CEu31Qfi4ntV9/espVVRY5eoV7gkAhhD6+8i+6QhNRIcPdecrKYwPWQYlpPUUq7CrnmQigZmablrw9dcaNpn0TRTu47Q1k6O0GEF+KGQMihunwNI8hpP8A/dL7tOrcoXXsn3s4FhHeIId9EOcTVh5w4qLBvZbvhtkFvdwjmmcA7d+7U9uriPhwYNkXhJcn4whhbUyYpnpxjV65qVWAMHqncr30eP51gKkUW13QaB/vN7vwlhhhFz65nSjd+bYY0lQ/NXDNVCTVViQ73JdZOVVy9MDrchxEC8XCqCFTcZ3emyl7LfTRVCTVV3+hiHx9CcXrVunLt97B04mTfqG+4EZ/FCVd1sCWayFZa0mk+Q3PHDJEnsMtnxrX4DK9h0/fdhpjzB5yLltqJY9EgKcndneik7TVMzjiFm335KfxWT+tjeLCDrpl6AV9hzoWnxtD8Q+MViDM39ZvoqzXvYrdCDIy72Fb03KImEIMYMYOr65GugPBXnhpT9VDUxVQzAyqpmB5i7l1aN8NuW2H3kt3FvvSUJiYaZfuYw5xfVutHci8uTW2hx54H4/lHfXsczn1NHWPo7cH+mm/oTszZA5GcUN9W8xQDPodKk3tv5NARTM9KCsd9BKfaPUh3AVJa8SGOUzyDkYSOtD36doOlrsLrrzj8f4Xrz3aZnfhFWU6cviuGc0GcFsMFzHT8zjCZNagK4wUV/KiRL6SNAl3yvYNkEnF8RzhtVQft0eN3PGhXrdIiVspmiDSxz9A/OFq3/pBofa6LlnFdtIx3RAvtLhMh19PBSgoRvOpLd3xVOxR600WEzguMEBH1NQ7tVSOXDX62UrvWWJR1OIkjYh1O7jXamA964TUKrefjN1SUk0H5BjqvVcdkGtBrybrFnJ/p7Gvwzvva1u6+tglNzxvva1t9vSuJHWIT7mv2+n3tGvsPhq055zg3WpFPj+rZCD1cE8p3hV4ATl5sZakLFfF1GVGieVNBP7uYDWwXS7iDpLzSwKiksoG4fPKJ61c4zEVLH7q4eNWF9GZ0SIoxjN5t7VM+dnSr6gMLvEy0WCdbzsWvTDIwZ/cOepEwjo7jAunf6SCMwSNHmXlVSDALtyeT4tD3p1Rkbd87hgN/UjtpOpu37fbQnRi604duxQOhJzamti2HgsmVAmZdWwED2/m0Rjx0Cej4EWM2dLYIF5Sqro5ytFmBfU6kNL0N8o1yMFUEaEc5dh7h/B0aj9oC0Q66bZPKsq5hyZyDEVvXGGIqtiypDHwkuTInETdbG4UGTWZSG4XPtVfVKTgINAo/yg30QRiaSOqzT3jCZwKng759KYqtw8+VrrldNJESGJWHkzKLRo/hTOhVry4TYPChq2JImMv0Yld+rdPkadYONvWKAXvLQf52HuCipfzguDM/+xR5QesFhIiN2X1kiHHJduE2TW5RtWOrGuefGqPy+BR0m8vZh65mri6IkqPeIB3TjuiYdqhjaqeZcui+vGZ1Ox9SwjdKeDf8RJAh+Yf4VuVtGqBQh2Qd3UCG3FR388zJkWSaUXJWiyfgs/vVl4hVNDQqGwcpVN0B3ucwZyyUHar8XkXWcKW7430qdI/GRj49/Q1YxUKu44zqbyW2XyKXGUnjTsyK5H2dQCclbnFxqiN9J8d5C2J4DU+ac9J093gxiIFKIlOuX5RguR9wdtMq14PUogqMWRXVSX46VCFJw8bhZbEPk44ldB9oWKnkVX2gqXWTszPkfiVaMYxIiVElC0EEOH/SrLsfZ872zmaJB0hCLRTtacI7tO5DzNnKeYu4wSVuYMQNrrrB6txGy1hJby75feHa5BTo7dzGOmvbRNZ75in8dhIHNthvMuZs09/3Qio01sygqr412vrEurZvYuuWUh+tI2dTyC/ELQ8wx51kTj9T7vWm5A21rDewcLkRDbHFW+iIYodYfWIsUK62HeJFhFkn62Ik61mVtUWPkStBZmr2SHUmx431ORuvsVhczauaF8cdJ8Tl9j6nR6H/qVuY00dm3Dll3riO08/kG7gDbJdv4Q6wmzm9TL765CqTI0DJpG5H2u6I47DtDFh9iG2nDHSrc5KZCdXD3X0hdCdBSMpC2MYOgRctwtf3M42WtN0POxYD5o4y9dzOHIxEkvwudvn8GLWovNQ2Qra7x4kx98eYY7A+x0BvFHOcv0lypr6t/udJxhjbsjw3U6nBzHy1dhKWm+VGcxnKTVhNwdTxJtTmqyrJdImF401YmIaphePz1eW7oHm0BnO1eZhZhtUNVAPfqKL4jm1olOeP1FTD+v2qH7ai8qYXGqCQWJjGxo40j8Iqc1Vmbb76nqrtaRxfblb25EeL6aCQKdQy0+VsPpcOsrmp6alyJZOrBqNBoRrUcuWRYiG9Z25mqlFunNqz3KzuWW5U9iyfWt7TPNqolauluYVKeW7P/EJJpZd3N5Z/6OYrC42aah8x3jPTrDX+Ps3R5+mpvUb5RGmlVtlzbKHaaXBPtba4vKc6pz5tvyu9O7s7k6HS4Tsq2jy2uOfE1PGZueqeo+XloxX10XwqNzczhUWy3GUHGWN/GWPMZ4xtFi7rYYz9W8ZYLJL++rr0rzDGEpH0RZ3ewhhbFC7DH0XYEMm/a1354rp0k63tH9N7Iunj6/KPr8tfYYxtX5dOR9In1uWfWJd/cl3+yXX5p9blYzoTSX9mHX6fWVf/J9fl/+S6/GcZY73r0sOR9C8yxvrXpXcz9Z9sNmvHFpu1KjQXYEb9WsLMZ2qgmHoXMTmcODozV4OZJq7ZqdrM/BGoNhYWF2tVxn6fuewnGGOzjLEUY+z/cpdwPcwY26T7CN+dZoxtZIwJxhgKF5MxZuhnTJe1dTrOGLN0Guca8XZ1nqPrp/S4j/34f/yDnfd/t3JS/uInlwa/8Gub/+WnP577uPXqgyeff+xHL//C35SXl2uN5szCPEyXZ+Zq1btgcRnHeM+PAD3vhGMz86WFlVrjaK1cZew/cZd98O05+jem6vt69e/u1i+fvEb9XzUVjtFx9a0bP46pUl4sV2aapwCbmJ5bQP56jLvsfsbYVk2bdDqdSWfTI+lcOp8upIN0MT2aSWcymWxmJJPL5DOFTJApZkaz6Wwmm82OZHPZfLaQDbLF7OhIeiQzkh0ZGcmN5EcKI8FIcWQ0l85lctncSC6Xy+cKuSBXzI3m0/lMPpsfyefy+XwhH+SL+dFCupApZAsjhVwhXygUgkKxMBqkg0yQDUaCXJAPCkEQFIPRYrqYKWaLI8VcMV8sFINisTg6mh7NjGZHR0Zzo/nRwmgwWhwdLSt+Q26rzqzMVGswdQo+U2sssD/ZY86Xj9VYKjk1V56tZad2n8AfSPndPZ9ktdIDB1cOLKdLB5v3LteXThYfmjz1yNT9o6ViKLE6wOTB5ni+VPyITh5sHmyOB6WR5VPLhQdPLTdrxyYKKufe5drUfZVybeHBxslTpZHlZpUPlx45GOjOi5WFY4uN2vJyJjg6Wgzylel8MagG07lyNTsh7io9cjCTiYjFzIj+UZG9C41j5WZB/RRJJjhaKFeC6nQtM5WfLkwFmWDCyONgEK97l488vG8xf2jpZPbgqVIO94CR5WYjX1k4Pt/M5KoLJYJKlaPlxrJ5ZwS5TLr7cyWZ4Gg+O52ulqdr09nKdC2Xm5qI3Yl93Ls8/bHpU4WJyUx+5mOloFQq0XaSGUGoOhf+jEk8HW05v+5HU3D4mWylkitng3xQzVemqxNW5f2cjxz+csrVs2G/I43Vj7lkgqPFbLlaLOeD2lQhW63kCxOJexSC5dK1qTx9rElojaqWmrVGJrtYrpbwJ1qONMpzPXdESBLoH4nJBEenKkExOzKSmZrKTGVy1fSEs11N5nU6yZ9ozDRryR2R1kbnFsrVkLJBMB2UR6fy6XwVV1t1IrV2lpebC41aWLgwXZgayU6NTo3WcvnydGai92c4kShTGvrYQ0Nq4969Uqvs3v2JWgVfPTS076GhoePZ9FB5WT0iZZYXa5XSdGPhWAm1jdJ8bblZq+7e/eBirbK3sXBssllrHKR3qqV7hyaptX0PDY12amWCo+VqNp8tjozmM+V0eipbmOg79n4yR2akOrO8uLBcK1WOHp+fvZpN3CPvZ3fFY7Vj5bmZI/NXd9Q/976OK3t8fm5mfvZ6w9rQeF97K87M4xZWmis3jlyXlBtHbyCZ8qSZ5knXzhQoUaJECffETR/Gqg8vF0tY/brrYWT++LGRmWOLxw42x0dLwUdmlhfnyqcwZ/NPqAYeofHmK4cq6eyhAw/PLZTyNKjCcrMxM3+ERlp4UMPXX3efxHWXSdPyI/m5Ze2PQ90U+b2ngTNc9V1SK3k9WUYXy/MzldmZ+SOZEQJLR8vz1bla42BzPFfKaBmicLq/fArX9/VoSNUz2fvxoYsWm+XZWmlq4eTN63+u6pa1v1Y1OBKKmhsgqHoonZhpHi0dXViY3VouPXJQUTDQpkA2/UD5xCdqlcn5+VoD17bENZ3JTM/MzywfLR1pLJzIBEeruUwwPVJMVyq5WjaTnZrYPTe3cmx3IZ8p5EbSxdFcLlPMFLLZIHvrJxWn5q4zc2G/NHWZTKTzNX1ePazblt8l7kGjtlxrrNSymepCScOl8nxVzxMJqVq+UhnJVWrFfCFXq1x/NNuQGSYPruCIsu8wIlx3xbVDWpx4B2RocZZDpt43tzBVnpu4euzwyPs29tEgWx0dDaZHa+l8MFpOT3j3/ZA8UcmlR8sjo5VKoRxUspXqhN/6R1w4I0dqze1B6YGD977H3tPj+dLQRIj59aQrik/CU8vQg83xQmlUYla5udBQ9Lv9XelZVaVnfSD8tbk7PtKZgJGVWiVT6GzX92rC58uLi7X5aqk2VztWm2+iDporZNLVytRovlzLpNOV6sTwp99/iTmqBOZys7EjuK5iEyFsURFWeXtKlaO1yuwH8ef4KscW70zfiDRFTRrCtITqf626867u9nODycyoPkk1Ls7MVxq18nJt12e1DM/8ELyYL2UKKHFnKg82G38PCb5717ugmYJK08eae67+mcH0STWK9A83ivewonDOo/tv5iDh/o46c3BdnTmTVcyz2KhNz5wcucbvJeZ334glAnyUpmpHZuZLx+dPzMxXC3dcb8dTHF6kGgsLx4J0WPDqZZCfWjhZq2ZyUwsnS/O1E6Xj8+jkKJ5QUv6aI11sNjIj6OAozcyXFufKFZTa720+rjkVE+MHSqO7ri+x1o0K5dRdn3lPeE7S9pIrFRYW0V1ROESPScUDV9MGpc/IJ2qVoxMT19iDPjT9Xml0IzmkRdA1+rl7x43YIhOVFD9yg4kO97JMVs1BqdZoLDTu2fEu1qRa1R9e/2ueY2RaPHxtApTnT727ER9sjmdLI3L+VNA8tVgrzVSvpoC898YdPVB7F42M3/Vebd6utL93542mIKehcB1/5J/8o8la3PQnjqvus/+w3a+XkHvfHY30Zr/vjhuVTlNRQu6+o1jwU++Wl97dBj597Bp0mcy/i8UThL7IUuiL/Oj+G1iF19qX55eb2ZFIolSdWSlNnaJd/sAEeiyy6Ngr6aU2ko4su8rC4in8zVzk8mqp3GiUT6EKW8tlKvliLZMpl7O1WmHi4AdvRN1sOBc0tEOZG5UdXVNWayL3F29QJVtYW4XETMhiP9r+x9NDCuVlXMsP5G6wGdLfTFFLyMgAHqpqYX8tpFEXHp0qV2abjXKl1iUAqqjLRxcazVInU+1t44XS+s3sMxPjhdLH59/nXq6v5FN/nyjeWIPI5qq16fLxuXVzubAw+0kSMp+afBc7f0bpLPQi0HPxQG3ivc/c+GjpU++sNSNJ6LhU9fiIvEGNkXxYY35BD3H56Myx0sxy6fj8crM8NVcrrWR/7F2upqmFRvPT8qoFjDPVWcALU/VapVkizaBRm0arMajW0pmR9EiQro6OjOQm2I8lFhsL1eOVWmNZ2HPl+SPHy0dq3Hzg+HKTJRcbC5Xa8nKtumvqlIgRCrdmdo/md6dhuHMOCtl0trArnduVye2InyjPNY4vx9O7s+ndI/8PfuAfxQ==');
@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJzVfW2MXNd12L33fc6+edy3/BCXWn6c97S2lpZIzszuzOzSjr2X8pJar0gqkmwripvh7MyQu7PfM8MlKbPacULbtENbBKIAaqAWROPUaiK1KmogSqIi+8NABVQplNYIBFRFhEZohCZo1dZBVEANq3PufTNvl0tKchQElcB99953P84999zzdc99w8rNBc4Y47/P7zrD2BnG187wNXaGr/G1M2JtjeEfvnbGWMM0/uVrZ8w1ytGDr52x1lRePfnaGXtNl+gEXzvjrMVl7tqtSb52JrXWLe+m+doZb20t8Sq9tiFrrq09tcaMr/2isWvnNivVJ7b5Qco0LdN0LWtHr9mX7kmne3ps1xS2vd12bMcyTNuyvCC1TZhcCFNYpm2bppMyLdt0mHAM27JM0zSFME3DYkyYFl/m27dbBmd9doqvcdlurzPPeYn32Au1haXGJcH82sXlpUbrgaXFVu1ii+3sPVdrTS7OtmbL84+2yq0a2+3PLiRr9DvVpQcal5ZbLNNTXTo+u1ien32yxnLbS6UL07OL1XO1xVKjVp6fX6qwyd5E4dlGrcZ+vi9RsqBqfeVAoqxcrZZaS6Vmq1yZKy0vzS62ag32pdRuziT3+h4b+/SO4w/cd+x04fBd8OUvfH57z6+/xH/xjwZFao0/dQ+TwVzEB5kYV/8Dk3/FJ31Gqddufv2kzyQLWdr0gEmvHgl5cSU0JBsS7z09HpkTvpAHQgtb3bzpnPY5GPLTIKS7ei5ic6uSrwCTvDUnP18PrTT34mHwf+zkp0+PR7YMqG/5noG1vcmByJDGaiRWIyaNlQmfAZNrTexL1CNHGq3IkH/5vXVWjxgY8qeYGhIsEkPCjdzjPgcXWEGkgYFbEG5aeEAAv//0+EnfBuaLGHw2gfkZKVryzWu6F3dImPLiChhzoQA3tA1Eijskgoj5DBM7KWEPif7Q0jizh0Q6YmCfwBdypwywZGfEBlSPPicUCg+L3WgjdNzDJnWwZVAHNhA6xjg4YQrciGExo2LszR0IHWDUuxt3zDxISVYQzANrwrcVMDjK3s6ccELsOC2oNSQCsI/7DCwEYSeiIp3yVC4A5vO06ynkMUDceMDAKoh+NcUgcmktEO5AVev31ExdQiS+2KlfpLFxB5WRCwJcEJM4CwEMbHAHQhtY6KaZ50lE21PgXFgpIEV4SED1kIEAQxqNgjAxVY8MMLBHjiVpy1PkCqIlBYhWxCQDNtdYmYlSSCJCk4gLoksiDPHvIAYcBDYNLjhqETQIqQ4IrDM2w7FtBGEuctXoOGwde2CKjN9/ejwSE7hVhsSV6+OhkaRvR3IQ0lhtRakVrAIOpBo0CuBu0nCK0EFawJIY9tBJwB4KDxwwCgJRaSDsBsKOD1EQLm5PxBnOo4AwSFqO958eT5ueZJri5VNEHDjJ954e17sRhAwmkXI7M7Zxxog4NVkXJysKgsVTdSaQpNVUWXKqKcnBoalaaqopsPRUne5UnTCFU8WSyNFTTYHTnarjQQqpyAUn3isp9XDiLaMognUAJmbAuuRBK+rGmBCECdxpbh13PU8LfI97vMP4rA1swCa+59LGxb5CF5mI3AOW5KtzYMkDxwdaoYPviUPYka2oPUqBgdhMIbmkQJxGzIYupEIBKepUgB1SWdrQ2yeFEwQbHLlvVZorddxr9okBLB0IGTiSt0IHbJ95HpG829DQCbA6hA/mCk7N2MTQQubhXuaeMR5vyMhG2BwQ4OCWTA2EFi75JPIXcHHMlKDKAaKBuE9oeyDAAge3rwspTLhqHggVIGfobgWmUIAb3Zg77XMP+WaXXbqKXbrILl3F0BLs0u6ySxe3qx2TgK3X3kW+6G5il/Zmdml32aUdd9xll7jeBLICCEdKsEx7I8t0N7FMcwPLxEXssEyeYJkkNGzFMm2sbWuWSbO1J3yuXuzUL4jOuhvV3bRR423qdrapS1ugs01ZvE2pbltM0sgiCNLC2yhvY37kqE1qg9PhR+/xLkOyFUN6j3c5kr2JI9lqm4rEGuFDxEu1cZu6m7cpLibuSP9WzUDT0emYrtrXKT/lM+DSrMsb19eRRf/LtlmX7fbllSAS48CHhKm3LkNZxodEOrQUVq9dp/dulKpHH0B7jRgDvbl+fTxCWhVTAwXMdIG4el3z9Te/t047HSdMopcmETn1474AkirMZ2nD6yD62eukXiByGe4AxZefvT6Oa3zzQEE8R+NbBfHO9zCRKoi3KGEXxJuYIIQXxHuU/iml29/vlv+Uyt+n9NXvd+u8T+VXvo/p69/v1rlC6WtU/uz3u3WuUfoZSt9I1HmG0s9R+vlEneco/QNKv5So8wNKv0DplxN1XkiU/4jSP6L0eiL9CqVfofSrifSPKf1jSr+eSL9G6dco/UYi/RNK/4TSbyXSb1L6TUq/k0i/Tem3Kf1uIv2XCs+Ufk/hltLtp7vlP1W4pfTVp7t13le4fZrw/3S3zhVKX6PyZ5/u1rlG6WcofSNR5xlKP0fp5xN1nqP0Dyj9UqLODyj9AqVfTtR5gdI/ovR6os6PKP0KpV9N1HmF0j+m9OuJOj+m9GuUfiNR5zVK/4TSbyXq/ITSb1L6nUSdNyn9NqXfTdR5+2kiftlbRwHF5MV69AETuXq9W/svVQ1gc5CSj9cjVkfW8YEO1r4+jtql5oKoCsihgjA/2PXtdpsXxDPXx5EXCLBPE/sUU0qopVGbWyEZxFfBOu4bmv/S3sWOwJ4coE0LNqTqoavZgOIPSp6yyAGyOTpMQHjelr1zr9OcNAbNRwgalKnIUOqRO+kLau+GrKMaJ5DiaKTcgghTIcIB806IIESdwe6wM5IPMqhPDkSOHKCqB5H7hD3gyKCOube/N14UqIIgm4aeIiqBd+BaKAfe/h5qe3vrxC+ZdEg0ShPbu5Mogx0Qx30HHHBQEDy1ohR8B8RcxDTwpPcpI1QJMgEsCNLuBkHmSg6MBJmtBJkLthZkLCHI0MhAuUyCjGlB5gLrCjJGkgoFGYmuNGJJPVC6pR1PSTZEF64SpOqEYLUuiMiuTHa6hUIL6LnQAKGlyXFfp69g2kRJTSJLGiuSn6B52qShBb2khWNTsOto25pKB7M1wlD48Y59ghYBosvoyH0jCNL2BnQJidZ5Al2igy4jgS4jFIguKooN7VAkDO3Q8DRmwIjlvlAPNE/SFhItan64g9u0g7UoJUvKJQoGN6G3dCwtMmHSthcbF0LbeGR59U4h1Ki/XVGdklKPoHSMxNtZAe8T7yBdRFlHaIDq4bEVmXJdczM2QNMWrryj1jsxDb0hBS19vCEZiDmtqECqHgnalbbalS7YCSXtVvZkeGrrXI2xhY3Q4lXNO2utJtTFAKlJuiKmtiAKpWLFVkzceSTAOKmJ4GdeKA+4DOqDDJj3p9v5wbV7mGxbc5E5yMA85hfFeyaYx36l3W6v86J4FzPfabfbb/GieAczT7fb7Zd4UbyFmW+02+3XWVG8gZlvYzVWFK9j5ip2wIriVcx8t91uQ1Gsx2ls8nLcHmu9hJnvtdvtd1lRPI+Za6rJDUy38T9eFM9i5lc/GD9TFNcx/f12u32dF8VVzHwLm/OiaGPmlz9ofoMXxXuGhgUBe9fQ8OOQ72DGKoq3DD1d7OgNQw8dFMXrRjwnXhSvGhpAnPp63ClmXjY0hqAoXjI0HJmieN7QkOOUbmDmiobpWUOD/joviuuGRgOCfjUeHhHUjt8gYO8JDToC867QkGG1d4TuGnt7S2jIMkXxhtAAIJSvCw0ZAvCq0BjCFV4Xev7Y/uW4GqL7JaHRjdWeFxrooChuCL2OCPOznc5YUVzHzDf1pK9iJlUU7Rh27PQ9/gG4ZlG8y3VNBOgdrvvAVXqL62Fxqm9wjXeE4XWuQQ2K4lWucYDYWed6DRCGl+M3QVG8FLfAjp/nGmzs+AbXQOG0n42BgaK4jul0UVyNwUCo23GFTFFc1OU4xWWNYiiKmZicP1iGMxpoHPVxDRri6mG9VNjlgxqfOP9xncY6ozEJfLACGQ0wruCQXlmcL8TkzoqiP17+D9AfaDRmisLVkAVK9htz8nf+9dPv82ZogfihvPmD37tqNdEuPAZXfyiv/fGvv3GpGTryz1/74et26Mpv/ud/8p++Hqbk//qvf/BnRtgj/9Fv/MqfWqEnf/+//eFvPxmmZfvroY8ce2+0LexVelMQ9mGiP9oe7lBq1c5wlzLq7wp3K8OrP9yjHBR3hwNKk9ob7kuakFv9b4yDCX49MmTbIo/AfpQpyPWvqPwBGZz2DTDkVZUHzJtgyGsqH2LeAkNeV/kI8+g2e0bl78G8A4Z8VuUHMY+6wXMq/ynMp8CQN1T+05jvAUP+QOXvxTyK3udVfgjzaTDkCyp/EPM+GPIllf8M5reBIX+k8vdhvhcM+bLK34/5AAz5isobmOewB3bXgQNI0cLierQHOIRxDvaA05TBhQjlXpSC3U2580K0G2s5TdmfLN9zIdoN+2AAe9vf6W0fcDjQ6W0f2NSbDaitwwD1NlCP9mOjT29odG+iUR/0YoXBToU+4PCpToU+8KhXD9L1KA291Gsv1vIIxgP67S7Ygd1EnW52AYd7Ot3sAosqWtBTj3pgB3WzA2tZ1A2VA77qYGGfHsFTTQgL4W4AbLznQrQD9uCAQxtwezCB2/2Ij/4L0X6NlQM4gwM4ic7oe3QVW02OOg53wAGc5p4LUS/swiE+s2FO9yXmFC/ffkT5fsR6Bz+7Omvo6vI9F8Je6IP9uDJ7cGWAw/0bsG4ksK7QZSgK6KxlHxgaXXH5ngvhAPgS9UkfVX4GHmxrQm+zIPYCgx2wvQlWsyD6gcFuuKsJTpO8a2DD3U0YaJJzDdIQNKGvqXTLHtjZhF1NpaXvgf4mpJpKK9kHe5vgNlGFM3FrD7Je3ESBfP1b6yy4l3mwXwbyrSs6c0AG8t04AzKQ7W/qTCgDeT3ORDKQN+LMPTKQL8WZQRnI9TjzKRwnznwax4kz9+I4cWYIx4nBOYjjxJnP4Dhx5j4cJ87cj+PozP92uL2mTuvSg0w5unzZuxJuA1++e/PmTb4S9crlZhjgOxb50IvLFvZ1mZ8Yh0AG9SjwGWyTohX6xjj4E74JvrxcD33oQ+U+gD5p1sM+CECcUD4t8IdQO0Yr15dmPZFxk5l0MhMkMzuTmf5kZm8yA8nMYDIzlMzcn8xkkpmRZGY0mflcPexV9Bj60EsvTpDj2iaf4HFy3EqyaRhJO5T/qDMHj5MJBdIMHop60aHXi1bvXuhFi7AfesFEwu0FD0m2F3qQWHuVMdl77NV/8c9/YyfKTTcUkJZkVKahF19SzpRu6KPQxo7T6NZ0wQz2Qxr9lj4+3LAX30vRIkck4CxEq46nPu/3MOZhHRM9sWnwwaxHfkGk41NJEODPndQ9gw+26tlEWKhnPQ4dqJDv2sZxguQ4FgR6HOygHvFu/ylp1KVYjbaBAN4dh8M2NU5nAI4DbJsgLyhNd5vkx4kI+UrowTb5Nzdv3jRWwj7oBU5UK7AiOGFgoH0XyJ9+e52BD+7kwJAgV7vAkQJEmHoleT3xNkDzJFBGnMCJ1CMf+nDBgYNfDzl4NF09HDhqsrcOwyWvhxwnj/PGGa9E/oTPcUnArsO2unTQ0OenCWUCe4kE2vRMrkkAXxqtudZKQdBxhgAu3/622s/SlBA8TJwRcaS4WvqgMItosqXJrvLed8TuNYGH9Df4XOQkTukt6SDCLUm+J0umTvoCzLwYDPfjA8ID6Ak6KPqLRr9KBUUjUCm3aLgqxYoG2nRfqEt7NbIkdsSn8GDHAkuyE3NhCvbDgWPwrSvhfsnCHslCPEZyjrGi8SxXz+v6eVU/2/p5UT2W1WNGPc6gAgmetFtRei6ywAJjaiDCfUmbc3Ig3CYZOuzDXsnCQLKwT7Jwu2ThDsnCnZKFuyQL75Is3C1Z2C9ZuEey8G7JwgHJwr2SoRIIBqRV2AIdOCFxHCdT14Ce7qAWDmrRoPJv2Eq4XZEjjuNjU5ynfIvVwapHvcChBxOBvLxSl9eRTAIpVkEQpUQBMryVqE/2N1ePMga9SI7ISAOVR3YY+rAdzxjrkUWEuIMoMB4COFgo7f3uAH53ALAw/sFoYWcees2e46GFz2d52IvPZ3gY4PM6D/vweY2H2/F5lYc78HmFhzvx2ebhLnxeDu/Cx8VwNz5aYT8+lsM9+JgP78bHTDiAj2q4Fx9nwn3ot7MK4mvgIAN7HBwICuIxcKCvIB4GB7YXxEPgwI6CeBAc2FkQXwQHdhUE+iTvKojPgQO7C2IUHOgviBFwYE9BZMCBuwvifnBgoCCGwIG9BTEIDuwrCEAHoYQ67IdtL16O9oMHqeMBhx7i4j3gSV6PrBDP4lzCqAnOQaOf7BhMBWTFYMolIwZTyNTBhP3H4LtFAcHjEiQPHoosPDa1VAXGVMotMlelgiILVKq/yPrBkWZBzIADtgT8hy7iqsLNGfQW4YoGabCo7y9+MIUbnDQSyaQLlnwLBXof86QrXfmulu4yJVOyHbMGLiH4LPP+wubuGlEwuTnr8vIKHlPNRXS4ytFvYyL/xYNMMKWxEtqS0ZE08icB7HjnDJurSIm5KCUvT3VLDeoaVUFxP2PyD9e+VEdnTz2REcmM0cmEHFx06bokRLGLUFBvIlEDu8JTb1yolK5o1OlwmY5FU2DK92/evOmsdAHAc24lHRzsi6u+sFB0CoUuRIrk9dA1xiEVGnigK3BHc/pDPm3ECWHQQcFmynedlYirQ3jycWJzdSRvKGwoJSZK4X5zViEl7dWG/MYvX3FXgCtt5zbvzNu/u90LUa/XET/oRIs4MKWQWODMhRYYYGJQBEZSyZvfeN9ZAaGedfmNtjkvg1XEuUuIxIk48n2+osQPjsf1eLw7HhGBxi05nbcEFtdN45riqYSuJDZVQt8fgbYXZ78FZBTsBYTsXqxsrJBUTyHt4QtpEhXLyyvE75EeUwZ6GxkaEbxDdTxJjzxJjzxJj7hXU0iRKeIFQh/Fp+q0ri7wRE0l1T9A6Fo9Eip8wfsrm9trmx0GwOTlemQiQi3yykuT9rCxEjkDwOtqBvfWaUaOOu11p3wRn9en1MmFEWyLBEY1IBMgV/prN78+5bsSTXOvTiFSGPYW4j6/qHujrtGZ72BwhYOK6kn1mJPttjvlO4CIdcGuh47W90gNcTpHDZwIPz5sYHTcEllSrPgpsFSoil2PLOCTfgq93AGqmhagqqFCDUzggLOXfKUhRQM1Ihc4EqvTOdG3sAer43IHDk7gY1SZCdatjS1szLsOeteLndh2PXIQElftV4TCJpc/doSxf063F2djL5IR5yM1EwGwboXX7oQyOTgOHeYkz5/Qrc7jaCFbxU05ZB0QGBg3eNue9cGP6i1Ggh34+hwDHfhqclO+SUcyyHfwHwY6TsRxMkrLNpWbhgKjkNYMpDVD0ZoAA8PsHHBP+rZ8+TvrTB6W73xnnQWfY5587yrln4/zH/J+6+lYCvE8xivipB0HKly9Pq4CfYjwjIBFXKnChrwsLyrQhTRWBiiIDfVgA/UXNUfenSNHc4UFjgfCe8numLWprnqrhJ4s1eUZ2kESppSyO0XnqanJ25prkEI101UPpqN8gsdxpsFDUQ9KCPS3YQMwByLvEDOOMqqnGGMPeEe4OMoQN0IVJGojNburRxmKGUNXHxLsKDPAkmZcnQJSsIXkU5E3QEMgDVvdClFaVfEGaDRUt231Np1oaw7EA9qo8N/S/wCN7cTBTJBCJVXhuIdm/EV85SoMuISB9IRvdBRjV80Zz6lDH1y5bYXUb0Orv2ncrXiObJFSSmNaqJQ6SutFWIKVyIu1XhO1XtTLtNZroNZrwLak1usTX8aeb9vvBmVXww8p6dYhDW5si7o0O0iheWlA6hDzQhMfPWEPPlKhhw83dLCKGdr4YGE6eFwOSTM4qTQ+JnuU3WWpM60e3AfWMYZucUt5qNHqs46hE/t3/vsrv/u5okCuZigvNvmyLLC10xvdABaktUccvQEWiu8W8Ib889d+8w2bHFQWeFjmNKBHBq0GmLK/1dAedQoOS5Hp1+uhNYZaoOTg0tOUQ/i8YXNzjeQE6gzysgp9pviAuoo7Rb1rhbaNWMEgM1JA8JQTQw9QRcMNrqQCnjhTTK2xIo0TKKJAn6rqWFt11owDKcZGB74k0EEEvd4tAhMbRTgwdtwRREKlr6jzb0MHuQZoVqkz2w7bZHXQhncMYBcmZDogbgGp08jTwQJUFATqNBPDrNbIbfEcxn/5Nha+/b3xCMPAbx4IuTp75ZvOXhnFiGGqMxCxpA7aOufPnRNpTwWTPYOHqyd9q3OUOqHSeOhqyoOTvqnCQDg55GhtDGBT6syehFp9EiOv0S0Vch0Cwjed+CrwMLUFHkjLUNos6TlxZApqYTcP0J+pgZDrfp+7Pt67IUzA6ERViBVtNostoyqMW6MqjE1RFQaOgDuM3CdcxS1zih3oDqpWg6nV4B8XeDBBkLUv1+gs3fPesjoyxYldpS66SlPgxq7SHnSVegr9LvSQ0ym9wVXqoVbuEesRLbIxXHSVuugqddFnw0KPHF5hGryuq9TtOB3dpKvUTbpK3aSr1E26St2kq9RNukrdpKvUTbpK3aSr1E26St2kq9RNukrdpKvUTbpKXXSV9oArVZBMT9JVKp2p2wre2C+FEgu9niayFqUW6pRL/ag6/ZFLBK5fpaP0i8fgWxRtG/EXr2BibyRefIw0agtP/jyMpugBV5mhUqzWT/gCPHlhyjfAhp5JX/E+D9TI6DgwyG8aDE3cBmrLC/KRp2JXPLqNAR5SlQkepElDRFM/DQ46d12VwwgfE92iRDM48lzwKSDdWjvxHOXEUzdmbhnT2LJU3Aar0oUesOV731Y+A4rKGmTem5Zw1/hT8j7Zbv+YqT1iUzgJGiYhgrprVXLgA2CRvU2WX7vdNlc6xq9i10rPRxPFpPsHWEZVHBUDZKworx+WUH2y2gyZXgnTcTl6ykCAV4/8jm3mJ602P2m1+UmrzYE0eKgPeaQd9NBKYVd0NuGAn6iJXYY+9CA77yGCxIPIMIUBLoeAD6j9ixcHjvB0xE93ortp5u4KKaGpObI0tZNEv963Ko2VbaZgnGHQuTIkXPk3NzEoazUkv/jNmzcZchA8fQ4pQI+FPeQjRNc18qWbxsppX0iOjmsE1YEeSKF+GPRZGAmctsgBwg4KN9r2Q4Tp5qUV+VK73Ub26lJDpvQqOuJGNQcX5ghpbA4BT6OQp0WPKzYPKTPJIU3Po7c90O0NNWDUYrwhke5z6DZU6KD6r3tnnd5RaZgMfZTReCy+9ayMzhDJTlHd31YULtm8nZk5amY9HVjQCMNmPapZ6Hvge39tCqvr9XYxmghZcGqTZX4TlRxc8AktpVKLEQMHLzY4wKYG6AqBXMeDvgcx8B9VITy4lGt1SC0q6WSglxtzKeRuJ30051zUV2z0qBtgoUfdxweE29B81R51SpFHnVLkUacUedR92EY+c1+RI/6bHEDjg+ktk0Zpop3ornaiu9qJ7monuquc6K5yorvKie5qJzrp6OQuIf9xCu8ncXSQoi7V8SCzhIuaKWXdiZV1G5V15HAblfUUquopuU7OKLwXcdB4lmPoCSWvU+QJJa9S4Akl2xR3QsmLGHVCqWWMOaHUDEacUOoMKtYuSS/wj62zy5EvWcDBI2GD0pTjeXfPpHaEudqdSyly51KK3LmUIneuBb5y537QMblakRTnez35ul56crzaH8Hxum5yVysOZmyMYkAdKdOHGIssuppiEbumaEG+SlcMkHTxdqO6b2BrpkjuTzdBs9a6XPsSCSdUQVKyzVHipuQ6P+EbaJCjeDGVAmweu9Fut+0iBVnincd6hLIVd8uQMGnv4ca3lEOBXCvUqwU27j/lawnxrgrVslQtlJ2RBeII45GIa1ogsKahappevNFdkhOS4Ah7lI1MtzGFtFA3EEOMhz1Iz6n4ApUSL55uCq7EnsE9wliorkagnUzoVDG56F/GnrBCKq5ArHqLHpShKwOCAU+RkEchZo5wk7pHqa9fpja8JG9U6rN8G5jgfZanwQSy/DagnPwyW2OaEWaMGDNeTA1IW6HlganCIC3vTYOnNQUJpXq6tFXN2OYW451bKgQoMiUbjEX8syzZiTp2hzfRQgcXFM9Fp3x0t5GffIroT3vIfCZNcqdadEwtzZB0k+ChKDWBMeRCMhXDnFI3dCxlw8Tjkq2hzvRcch4ai+jNQiV9fg4hiThYJ3111Crp0o00g73kFUbHNFK66xnjKEmVdkAItPHf5EDkKd+Ch74FlPFPrdCxGpcBuXtxf/SgxwmdBofwzEUGKy0wVdKty/4VNJIbhIblemiBT87fiBNjSpOigB0cYkz2oyu4NYcN1NVBV53qkluLNiEKQFRrbfDmIlsfRQilqOmLuBQIT4b3jVhkmOCQ5R0XfNPkYg2NmXocmIwLqRmEoa1tpiw6NF7i0GWWsLbFRmubxzHMomtt07GzDk3m+ugY47m75rby3xvKzBYJM1skzGzkTxdXIiPoVZZlPXbr0QyoZ2AbzGwem9nYdDMsnUbk1u+EmfMg6L0lypyT+WjEUeaGNh95wnzkan9TUcQ7Uea8az7yTpQ53xRlzsl83CJimpOhryKmO3Anp7jZZu/dKpxa9aLCqW+Zfa/n/Q/BrTXUogkRf3Tz63jrEP9NDURs7jRdVw+Aa88+Vxfa6zJdVx5T7PRih4JQmzVBiRgK0sYjEcWBIrwJgDyqThc86U4BeUPIh4PD0e2/uYjPhbSz48vyyNDI8tLOXQTfxGHMrofaQvfvRu+8oP2YrCSABxQdIui6H3ajY9iB1zUp0B7gemhOjh1SJk7GHo0NYBjIVijkvHu7WuiQia7z3Ah8fZ8Z+Zj3HYNcXshxutd1OySIN0uNeF/QKZq+9kkRo0Ld/eTEOUVa361BIBRz5PqmYvf2qBgITYwUDS3gkcBiQcXYGx8ITRDUO487xrv3+g6+MeHHNxbYhgulXF0oRfdYgOeyXN3a3gnCN1DiEjgMT61M+lwBZgMQdE9ZTQuBJg+zGUNu6l2gN558iradvr+uNrG6h0pxslwdNHDsl0br9xSCui926he9Xu+Gi6gscRH13wpudmnfXSXnvjxAOxetrNNEBJ8G41wk1OcfhOSthvx8k5z+xyi0mDbyXoqWUfjB9YvH43iXn3QEQ9+qpXkAi5kAsoQOeGA2CLCYNyKGWWTqDw+Q68GMOQ3e3hZ0e1uo29sGBQQb+tJ1RN4HMUHeSt7Cmze42ImuPE+diepbL5o7ES/p33gHphdpIqjHEzBvmYD3W0Jw9f0NSGqbTN0H4/jgsg+9Ivh1DTz7E0pP0VpLWp2vyXT9xStokaicS7lAXlFnCEEdjOB+kmS6JcFoqo6j27f/s1va84/V/rlv3LH9HSDf1PKHgjtdhUr2oIeRVhjVKTDlO86kr4pNOrMQ0q7TLR95uQ4WXgaS1/89W4wsfMzjwSbphW+zRbw91jrCXvnu+GfJHHoKw8Bc+TabV3Zw8q2BTj/0bd584b+YU+oaPqmbKTqXUnxbDS2fqkdGnQKoOiOxLXtVfVDkn7o4rjtYow7wqymH2L/57jiF/HCJ/7N4erIHjLkgRffKSPM0vN8S3F5T9wZFzB/RByI5+WlVQYAFggqkIU3as+bkAF3oIhGisAtiTnFv2gmSB/tUx6b6pAdGJpidMdQZ+BdWZHutgSxT2qsYcxEPh8uLx2l4XW6dN44yUme4TK9SUIdupxqQsqtbILXbqqEj3+o0pHeGeifonepB1eRy+6pcprrqKJiQRx7sOnEN7w3OuaYnb5CR70n+6Mo67Tj0F+J5DtIBnqUFonuqj/Ej0lQHaireRn0vQhfpY1yjW4SuSDpuJ5dI5ODEPEQi2Ymk4RuqwO4o8/QVBmyvvnCBK6TiVgKJxoY0wUR9VH0Gw1TnVowc1YPM++PuzNBl4qpQzHhSTmBokPH0O5jqTsOJ52DLK7SYdvAEyRx1ais2TAk/jhOZ6gMO1pT6Ho4bmoRtnJJQBTglAjjE40PRnZKQGggeyMikKXE9JUvrKUpppyn9BRdC8cgMbX99XoKCpn0RWTtXx39k6dwPIr6mMghCDtbl1avrTMkD/ENHU4F3t9K178cbc4Dpg2IwMpEPcfVxFQ4qBFPZbig2qPSgYKEJvHMTRonXAOVw0XAxSlimyfjCiBdTpim2k+NQgepVkOMCGdLV7+J1ZlMpsrinMzjZV7rrlxpkFMuymSgDe2uCNJXNa25YqpRaqlSH+lIbl8pWBUaH+vBwz46XipKK+tzOUrkbqY+WSh1Xen/Qhd4eRHZ6y74K3I+xdWyyb9R3lYjObIJV+aRFC8lKczpNZ2jumDHwlOzSmbEVneHVSPrm0iDz/oRzsaYksZUMQpA8+Aw6VbYZJmecgjleu4bfz1h7CH2y5EMrYBG+ef3a+ASF4rroV+NDYmefib4V8yjbh2fGRxkeFqN+hcKBiCHQ7UhXwPRPro2rdsjqLNVNv8oqogcrGNW6GQ67Fg8v2VH242samWiUTTCPeb+t9o/koS1NbaFbL4Lx4lORi7e1lENSiwf3h3gvigYx504m3sT6tlonC/g8Kk2BTJwXAB4eCC94HJc6eIgi83yt+BDFKZrBOB1PuiHdp3bU9VR9s/r/K1h/vUvsRC/KcuL0rR9cC6I0Czcw00fvQ2TWoCqMweL4oZFISBcZuuTHB8gk4lhGMN2tzsiSJ2d4RqZ6pU2slM0YaCKfwb9zsAZ+RrC+3QXLuC1YxoeChXaXianggI4zUIAYCIgRA2LQqXkXEPLvGTEg6oa89qqRywY/JaddayxJOpzYEZEOJ/caCeaHwjioUev5+F0D5WRQvoFOsRqYTAMqlqxbzfu1jlyDD5dre7tybSeanneWa3sjLZXEQbET5Zq7Wa5tIX8AsXOVc2Mt8TlAvRqxh2tC+a7QC8DJ8a0sdaGCNW4gSLRu6rz+EHOBHWKpYA8przQxqqlsIC5fuUODM1ys6TiWAANPSW9Gh6QYx9i4tRPqDBPdqkrvDjC0d7lOtlyAX35jYM4dHwgTJ7AdxwXivzNAHD5DjjLzloA7Fosnk6I8p3wVt9b7ocF2X9VOmo7wdoMeilCl+zXoVpyKPbGWElsehWoqBczZWgED1/uaBjx2CeijX2MudraIYL9S1UlUCm1W4JgTvsY33eZDalChVB3l2HuC8w/pPGkLJAfo9k0qy6aOJfNOJWxdY5CpsJC0MvAR5cqcRNhcbRQatJhpbRS+0F5XJ1Qg0Cj8EjfQB2FoJKlPsaBH3gROjvkTPoXF4CcEN8T6TvgCA2pwUebQ6DG8Cb3rVaguxg1tU8e/LGB6syu/1mXyNGsHmypiwN73kL69R7hYU35wlMxvXCMvaL2AKSJj9iAZYlyyQyimyS2qJLZqsX5tnOrjU1BstXcCXc1cXdYiR71BOqab0DHdWMfUTjPl0H3vO8nd7X1WMd8k4oP4sx2G5J/le5W3qZ9OQ9N1dAMZcmc9GGbeCHGmWcVnNXsCPjelvg6qwgpR2XiIAkE94L0e88Zj3qHqH1dojXd6gJ4pnA/NjXx6+ruMioQCzxvT3y9r/5hcZsSNO8fNkvd2YhQUu8XNqY7gvBHO18DCoHhpzkszOBTiZR+VRaLcvCnBCQa9w7TL9SQ1qwJjTgVkkZ8OVUjSsHF6ORzDpGMJPQaeH6nsLWOgqbXLuz+mfsVaMQJAsVHFC0FgVCHeIAgeZd49HWGJB0hCbRTtacL7bMEjzNvL+RpRQ0DUwIgaAnWbzNtP21hxby75g/He5BQx6e1nnb1tIum9eg2/Z8KBDfSZjHkH9Dd3EAuNDSuomu9N9j6xqe/dbNNW6qV95O2M6YWo5WHmBSeY18eUe70leUNt6+0s3m6EQ+zxbjqiOCjevTpeVK62g+IdTLPOq7cSr95Qr+7Sc+SKkZmaPPzO4gRWr7dji80SaFrVtDjueTEs9/R6PQr8r97NvF4y464q8ybwvD4mn/3uOpP3yBe+u85wLtuYvHJtnclhoGxa9yPdIOt57B4GrD7I7qEX6FbnxDNTaoSjvXHqIKUQlSNxHyrEIC4+xTRY0g1+znMYsKDI1DNknof6FT/Kblwfpx6Vl9rFlBsc8iwW/ALzDNbrGeiNYp73wzRn6nvH/zjNGGN3NednKzWYXazWLkKzVW60mlBuwboP0+dbUFusqizTNZbOt2DpLEwvnV+sNo9Ca6YG87VFmG3C+nZqgSWqKpax7Y3y4rma6liXr0dxL+rd2aUGKCCWzmJn51ozsM4C9bK2WP1YzY40zjdblSP5sdFMsZAt1LJny7n8SKaYG5k+O12uZEeqxbFioVqsjZSHRwuZI/Oz041y49KRZqt6pNmoHGleah5pzTRq5WppfqlSnj+yuFRS+ebhRvNn7r6y1Kip/hHiI7OtWuNv0x19IJr6a5QvlFZrlSMLS9VOh0eqteXmkeq8+o70oczh3OFslmrHZVS1tbB85ML0+dn56pGZcnOmoj5kTfXmZ6exSo4H7BRj7H9ajEWMsd2MsYwIGH5fvIcxtksE9BxjjKUS+eKmfJOp9sn8kUS+tel9a9P784yxezblM4n86qb3q5veX9j0/sKm9xc3vcd8NpG/tAm+S5vaP7np/ZOb3v+AMbYvkf8txti2Tfl7mfpPtlq1heVWrQqtJZhVXxyffbIGiggPEVHChZnZ+RrMtnCPTddmF89BtbG0vFyrMvYfWcB+iTE2xxjzGWP/lwcE2xnG2E49Rlx2mTG2gzEmGGPIDEzGmKGflq7r6rzNGHN0PqXXP9DvPN3e1/Na+Af/7k/uf/gvKhflP/vqysCv/t6uf/q1L4982fnJoxdf/MbPv/ubf11uNmuN1uzSIpwtz87XqkdhuYlz/PzPAT3vg4XZxdLSaq0xUytXGfsPPGCf+WBN/pWpxr5d+89125cvbtH+d00FY3JevZvmj3OqlJfLldnWJcAuzs4vIb18gwfsYcbYXo2bTCaTzeQyw5mRTD5TyBQzo5mxbCabzeayw9mRbD5byBazo9mxXCaXzeVyw7mRXD5XyBVzo7mx4cxwdjg3PDw8MpwfLgwXh0eHx0YyI9mR3MjwyMhIfqQwUhwZHRnLZ/LZfC4/nB/J5/OFfDE/mh8rZArZQq4wXBgp5AuFQrEwWhgrZorZYq44XBwp5ouFYrE4WhwbzYxmR3Ojw6Mjo/nRwmhxdHR0bCwzlh3LjQ2PjYzlxwpjxbHRsbGyojekturs6my1BtOX4MlaY4ldP2IulhdqzE9Pz5fnarnm4Qv42wL/5/CXWa30yKnVk81M6VTrgWZ95eLoY5OXnph+eKw0GnOYTmLyVOtYvjT6RZ091TrVOlYsDTcvNQuPXmq2agsTBfXmgWZt+sFKubb0aOPipdJws1XlQ6UnThX14KOVpYXlRq3ZzBZnMmOF4bHh4dFsbng4X6tVJsTR0hOnstkEG8sO6w/zH19qLJRbBfU5/2xxplCuFKtna9np/NnCdDFbnDDuS4ySzXS/3Z8tzlSGse50tTI8ki/mCrkJM48zx0k80Dz3+Inl/OmVi7lTl0ojyOCHm61GvrJ0frGVHakulShVqsyUG03rPmz2QPPsQ2cvFSYms/nZh0rFUqlE7D87jKnqfPzzAHYmCVB+0w8P4BRq5drI9Egun69O5wu1kQmn8kmuxwj+IsGtq+F+KI7VDyJkizOjuXJ1tJwv1qYLuWolX5hIfV4BWC5tjbizCy0Ca0z11Ko1srnlcrWEP3NwrlGe77k3gZKi/qEFJIRc5Wy5OlqpVEbyY9mztQnvHrU+txkkf6Ex26qlf43TVLKlwYceG1QC8fBqrXL48FdqFSx6bPDEY4OD53OZwXJTPRJ1msu1SulsY2mhhFK8tFhrtmrVw4cfXa5VjjeWFiZbtcYpKlM9PTA4Sb2deGxwrNMqW5w5W6hWctPT+WL17NnR0Wp+wl/4JBcxO1ydbS4vNWulysz5xblbl3PbuU9yuNGF2kJ5fvbc4q0D9c5/ovPKnV+cn12cu920gsYnOtro7CKKmtJ8uXHutqjsG7sDU8iTxpcnHTZboEyJMiWUXdu/gE0fb46WsPlt6XZ48fzC8OzC8sKp1rGxUvGLs83l+fIlfLPjl1QHT9B885XTlUzu9MnH55dKeZpUodlqzC6eo5kWHtXp2++Pr+L+yGZomxDr2rnxh1B2JX7b5K4rXI1dUjtuM1rGlsuLs5W52cVz2WFKlmbKi9X5WuNU69hIKav3uoLp4fKl+aVy9XY4pObZ3MP40FVHW+W5Wml66eLuzT/N0r/xl1n2DMcs4Q4AqhFKF2ZbM6WZpaW5u6dLT5xSGCxqFTuXeaR84Su1yuTiYq2Be1vins5mz84uzjZnSucaSxeyxZmRYn4kX87UhiuV7Fihkpk4PD+/unA4O1LMjWYK+UJmNJMfKRSKw9mxga8qUh25zdLFA9PaZbOJ0TcMeuu89rY+IvDFRq1Za6zWctnqUkmnS+XFql4oEjX54Vq2PF08O1rOFfPFO0xnH5LD5KlVnFLuQ6aEO29045yWJz4EGtqe5ZisT8wvTZfnJ26d/P4nPrHJjxVz1bGx4tmxWiZfHCtnJg48+DNSRWUkM1YeHqtUCuViJVepTsDa3+PWGT5Xa4XF0iOnHviYo2eO5UvRRAz57fgrMlCCU3PRU61jhdKYxFfl1lJD4e+ej6QRVZVGNBj/ttKnvthZgOHVWiVb6AjsBzTi8+Xl5dpitVSbry3UFluoLY4UsplqZXosX65lM5lKdeLTX/vkeeaYYpnNVuPe4m1VkARiRxVilR+lVJmpVeaG8MenKgvLBzN3Qs2oRg1BWkJFvVb9zNGuALrDYmbVmKSXjs4uVhq1crN23y9rLp79GWgxX8oWkOfOVh5tNf4WPPz+Qx8BZypVOrvQOnTrr2UdvqhmkfnZZvExdhSueVICHzlFsH+odlu8rXabzSniWW7Uzs5ezG7xi2HDh+9EEkV8lKZr52YXS+cXL8wuVkfuvZ3MUxQ+Si2WlhbymbjirdsgP710sVbNjkwvXSwt1i6Uzi+iO6JwQXH5LWe63Gpkh9EVUZpdLC3PlyvItT/eemy5FBPHTpaKh27PsTbNCvnU6JMfC85JEi8jpcLSMjoWCqfpMalo4FbcIPcZ/kqtMjMxsYUMGjv7cXF0Jz6kWdAW4xw9eCeyyCY5xWfvsNCxLMvm1BqUao3GUuNzBz/CnlS7+uc2/57d58m4eHxrBJQXL320GZ9qHcuVhuXipWLr0nKtNFu9FQNfeODOAz1S+widjB/9uNZpl9vL+++0BCM6Fe/jY//w743XotB/4LwaPvd3O/xmDvnFj4YjLewn7r1T7QxVJeCOz2DFX/iotPTRBPjZhS3wciL/ETZPMfYalmKv4YNTd7ALt5LLi81WbjiRKVVnV0vTl0jKT02gzyKHLriS3mrDmcS2qywtX8JfiEQqr5bKjUb5EqqwtZFsJT9ay2bL5VytVph46DN3wm4uXgua2snsneqObairNZFTo3dokitsbEJsJiax0+2/Pz2kUG7iXn545A7CkP5mRzWHTEzgkapm9lsBjbrw2HS5MtdqlCu1LgJQRW3OLDVapc5LJduOFUqbhdmTE8cKpUcXP+FRbq/k03iPjd5Zg8iNVGtny+fnN63l0tLcl4nJ/MLkR5D8WaWzUEFRr8UjtYmPv3LHxkpf/XCtGVFCB5FqxMflHVoM5+MWi0t6is2Z2YXSbLN0frHZKk/P10qruV/4iLtpeqnRekLesoFxpTobeGm6Xqu0SqQZNGpn0WosVmuZ7HBmuJipjg0Pj0ywX0wtN5aq5yu1RlO48+XFc+fL52rcfOR8s8XSy42lSq3ZrFUPTV8SFoGwL3t4LH84A0OdE0bIZXKFQ5mRQ9mRg/aF8nzjfNPOHM5lDg//P/SmDxM=');
@@ -0,0 +1,206 @@
+import { WordArray } from '../../core/core.js';
+import { Hasher } from '../../core/hasher';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { isString } from '../../utils';
+import { loadWasm } from '../../utils/wasm-utils';
+import { wasmBytes } from './blake3_wasm';
+import { blake3Wasm } from './blake3_bg';
+
+/**
+ * BLAKE3 hash algorithm, with its keyed hash, key derivation and extendable output modes.
+ */
+export class BLAKE3Algo extends Hasher {
+  static wasm = null;
+  static outputSize = 256 / 8;
+  static algorithmId = 24;
+
+  static async loadWasm() {
+    if (BLAKE3Algo.wasm) {
+      return BLAKE3Algo.wasm;
+    }
+
+    BLAKE3Algo.wasm = await loadWasm(wasmBytes);
+    return BLAKE3Algo.wasm;
+  }
+
+  async loadWasm() {
+    return BLAKE3Algo.loadWasm();
+  }
+
+  constructor(cfg) {
+    /**
+     * Configuration options.
+     *
+     * @property {number} outputLength
+     *   The number of bits returned by finalize, a multiple of 8. Default: 256
+     * @property {WordArray|string} key
+     *   A 32 byte key, for the keyed hash mode. Default: none
+     * @property {WordArray|string} context
+     *   The context string, for the key derivation mode. Default: none
+     */
+    super(Object.assign(
+      { outputLength: 256 },
+      cfg
+    ));
+  }
+
+  _doReset() {
+    // The initial state depends on the mode and is fetched from wasm on first use
+    this._state = undefined;
+    // The root node, once the input is complete, and the bytes read from it so far
+    this._root = undefined;
+    this._outputOffset = 0;
+  }
+
+  _append(data) {
+    if (this._root) {
+      throw new Error('BLAKE3 cannot take more input once output has been squeezed');
+    }
+
+    super._append.call(this, data);
+  }
+
+  _getState() {
+    if (!this._state) {
+      const { key, context } = this.cfg;
+      if (key && context) {
+        throw new Error('BLAKE3 takes either a key or a context, not both');
+      }
+
+      const wasm = blake3Wasm(BLAKE3Algo.wasm);
+      if (key) {
+        const keyData = toWordArray(key);
+        this._state = wasm.getKeyedState(keyData.words, keyData.sigBytes);
+        if (!this._state.length) {
+          throw new Error('BLAKE3 key must be 32 bytes');
+        }
+      } else if (context) {
+        const contextData = toWordArray(context);
+        this._state = wasm.getDeriveKeyState(contextData.words, contextData.sigBytes);
+      } else {
+        this._state = wasm.getInitialState();
+      }
+    }
+
+    return this._state;
+  }
+
+  _process(doFlush) {
+    if (!BLAKE3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'BLAKE3Algo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const dataWords = data.words;
+    const dataSigBytes = data.sigBytes;
+    const blockSize = this.blockSize;
+
+    const state = this._getState();
+    const nWordsReady = blake3Wasm(BLAKE3Algo.wasm).doCrypt(doFlush ? 1 : 0, dataWords, dataSigBytes, blockSize, state, this._minBufferSize);
+    // Count bytes ready
+    const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
+
+    let processedWords;
+    if (nWordsReady) {
+      processedWords = dataWords.splice(0, nWordsReady);
+      data.sigBytes -= nBytesReady;
+    }
+
+    // Return processed words
+    return new WordArray(processedWords, nBytesReady);
+  }
+
+  _doFinalize() {
+    return this.squeeze(this.cfg.outputLength / 8);
+  }
+
+  /**
+   * Reads the next bytes of output.
+   * The first call completes the input; later calls continue the same output stream.
+   *
+   * @param {number} nBytes The number of bytes to read.
+   *
+   * @return {WordArray} The output bytes.
+   *
+   * @example
+   *
+   *     const blake3 = new CryptoJSW.algo.BLAKE3().update('message');
+   *     const first = blake3.squeeze(32);
+   *     const next = blake3.squeeze(32);
+   */
+  squeeze(nBytes) {
+    if (!BLAKE3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'BLAKE3Algo.loadWasm\' should be called first');
+    }
+    const wasm = blake3Wasm(BLAKE3Algo.wasm);
+
+    if (!this._root) {
+      const data = this._data;
+      this._root = wasm.doFinalize(data.words, data.sigBytes, this._getState());
+      this._data = new WordArray();
+    }
+
+    let outputWords = [];
+    if (nBytes > 0) {
+      const offset = this._outputOffset;
+      outputWords = Array.from(wasm.squeeze(this._root, Math.floor(offset / 0x100000000), offset >>> 0, nBytes));
+      this._outputOffset += nBytes;
+    }
+
+    return new WordArray(outputWords, nBytes);
+  }
+
+  _contextWasm() {
+    if (!BLAKE3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'BLAKE3Algo.loadWasm\' should be called first');
+    }
+
+    return blake3Wasm(BLAKE3Algo.wasm);
+  }
+
+  _getContextState() {
+    if (this._root) {
+      throw new Error('BLAKE3 state cannot be exported once output has been squeezed');
+    }
+
+    return this._getState();
+  }
+
+  _setContextState(state) {
+    this._state = Uint32Array.from(state);
+  }
+
+  clone() {
+    const clone = super.clone.call(this);
+    if (this._state) {
+      clone._state = this._state.slice();
+    }
+    if (this._root) {
+      clone._root = this._root.slice();
+    }
+
+    return clone;
+  }
+}
+
+function toWordArray(data) {
+  return isString(data) ? Utf8.parse(data) : data;
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options: outputLength, and key or context.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.BLAKE3('message');
+ *     const mac = CryptoJSW.BLAKE3('message', { key: key32Bytes });
+ *     const derivedKey = CryptoJSW.BLAKE3(keyMaterial, { context: 'example.com 2022-06-01 session tokens v1' });
+ */
+export const BLAKE3 = Hasher._createHelper(BLAKE3Algo);
@@ -0,0 +1,225 @@
+export function blake3Wasm(wasm) {
+  let cachegetUint32Memory0 = null;
+
+  function getUint32Memory0() {
+    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
+      cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
+    }
+    return cachegetUint32Memory0;
+  }
+
+  let cachegetInt32Memory0 = null;
+  function getInt32Memory0() {
+    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
+      cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
+    }
+    return cachegetInt32Memory0;
+  }
+
+  function getArrayU32FromWasm0(ptr, len) {
+    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
+  }
+
+  let WASM_VECTOR_LEN = 0;
+
+  function passArray32ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 4);
+    getUint32Memory0().set(arg, ptr / 4);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+  }
+
+  /**
+   * @returns {Uint32Array}
+   */
+  function getInitialState() {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      wasm.getInitialState(retptr);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v0 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v0;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint32Array} keyWords
+   * @param {number} keySigBytes
+   * @returns {Uint32Array}
+   */
+  function getKeyedState(keyWords, keySigBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      wasm.getKeyedState(retptr, ptr0, len0, keySigBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v1 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v1;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint32Array} contextWords
+   * @param {number} contextSigBytes
+   * @returns {Uint32Array}
+   */
+  function getDeriveKeyState(contextWords, contextSigBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(contextWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      wasm.getDeriveKeyState(retptr, ptr0, len0, contextSigBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v1 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v1;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {number} doFlush
+   * @param {Uint32Array} dataWords
+   * @param {number} dataSigBytes
+   * @param {number} blockSize
+   * @param {Uint32Array} state
+   * @param {number} minBufferSize
+   * @returns {number}
+   */
+  function doCrypt(doFlush, dataWords, dataSigBytes, blockSize, state, minBufferSize) {
+    try {
+      var ptr0 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(state, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ret = wasm.doCrypt(doFlush, ptr0, len0, dataSigBytes, blockSize, ptr1, len1, minBufferSize);
+      return ret >>> 0;
+    } finally {
+      state.set(getUint32Memory0().subarray(ptr1 / 4, ptr1 / 4 + len1));
+      wasm.__wbindgen_free(ptr1, len1 * 4);
+    }
+  }
+
+  /**
+   * @param {Uint32Array} dataWords
+   * @param {number} dataSigBytes
+   * @param {Uint32Array} state
+   * @returns {Uint32Array}
+   */
+  function doFinalize(dataWords, dataSigBytes, state) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(state, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.doFinalize(retptr, ptr0, len0, dataSigBytes, ptr1, len1);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint32Array} rootWords
+   * @param {number} offsetHigh
+   * @param {number} offsetLow
+   * @param {number} nBytes
+   * @returns {Uint32Array}
+   */
+  function squeeze(rootWords, offsetHigh, offsetLow, nBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(rootWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      wasm.squeeze(retptr, ptr0, len0, offsetHigh, offsetLow, nBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v1 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v1;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {number} algorithmId
+   * @param {Uint32Array} parameters
+   * @param {Uint32Array} state
+   * @param {Uint32Array} bufferWords
+   * @param {number} bufferSigBytes
+   * @param {number} nBytesTotalHigh
+   * @param {number} nBytesTotalLow
+   * @returns {Uint32Array}
+   */
+  function exportContext(algorithmId, parameters, state, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(parameters, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(state, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(bufferWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.exportContext(retptr, algorithmId, ptr0, len0, ptr1, len1, ptr2, len2, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v3 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v3;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {number} algorithmId
+   * @param {Uint32Array} parameters
+   * @param {Uint32Array} context
+   * @returns {Uint32Array}
+   */
+  function importContext(algorithmId, parameters, context) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(parameters, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(context, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.importContext(retptr, algorithmId, ptr0, len0, ptr1, len1);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  return {
+    getInitialState: getInitialState,
+    getKeyedState: getKeyedState,
+    getDeriveKeyState: getDeriveKeyState,
+    doCrypt: doCrypt,
+    doFinalize: doFinalize,
+    squeeze: squeeze,
+    exportContext: exportContext,
+    importContext: importContext
+  };
+}
@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJzVvQtwXFd2GHg/771+jddNPH4kgQJF3fcEScBIJLuBRneDI8/gQgI/Q/FjSfbQ8tjNRneDwAOIT3cTJGcYAWPTHtqWxkxFqVISVVa7lldMrEm05dm1HE9VkC1NrapWiZlalUubKAkrUWVV9uyudjPJKltac+ecc1/3A/iRNFbWG7GEd+5793vuved/b7Nq6wxnjPHX+V2nGDvF+OopvspO8VW+ekqsrjL4w1dPyVWA4S9fPWWtYgoffPWUvUppevLVU86qeWMAvnoqtRq/cw0Eb9OriYS32v2PncqsbkhCTc+ZRp9bZXLmtNzhbN/m2mlXbOnNpi0r49iWa1mubWe2WplMTyaTcd2eHse1hBCO4zspx0nZ0nJs2+tNC4sLYQnbchzLSqUt27FSTIiUdGzbsixLCMuSNmPCsvkS37bNlpxtddJ8leu1tXXmpf4lzzpnGmcWmxcE66kvHphdqM7PfrPBdmcb55cWm+3HFxfajfNt1r/1dKP9RKM5u9I40rjwdLvabrAHek832ocXZtuz1Xl682j2dKN9pHGhUaf0QHb2TLKW+1Kt5bONxjcbLEzVFx9vXlhqs8d6K5VzU7ML9dONhcp0s9Fgv7Qt8abZqM7PL9bYz21NvDxD76buT7yr1uuV9mKl1a7W5ipLi7ML7UaT/Xz6Hs4097aeeiK/82ePlY7+ot6/a6j6tcN9PX/nXX76Hw+I9Cp/7gGm/bmQDzAxTv8U0/+eH84yhN658a2jWaZZwDKWp5j2olDo88uB1GxQvHVlPLQms0LfH9hQ6saN1PEsV1I/pIR2V06HbG5F82XFNG/P6a9EgZ3hXtwM/INK1q+Mh472sW79sYTc3uH+UGq5EoqVkGm5PJlliunVFtQlojClZTuU+s3vrrMoZErqdYAGBQvFoHBD90CWK1exosgoptyicDPCU9jht6+MH806imVF3H02CekZLdr66gumFndQWPr8spJzgVBu4EhAijso/JBlGQA7EHAGRV9gG5w5gyITMuUchA96h/bhzY6Q9VONWY4oFB68dsONveMeFImUo/1Isf4gJcdVKkgrN2TwmuFrqM3tD1KKYe1uXDHzVFqzomCesiezDnUGWtnVGRMMiB3ACbUHha+cA1mmbOjCDkBFJu1RylcsyzOuR8hjCnDjKabsouijIfqhi3MB/fYpW59HI3URkfBhh/mQgcIdVIauEspV4jCMQiimHOX2B45igZthnqcBbc+p1LnlIqwIDxZQFDAllNSyWRQWQFEolYQaObzJ2B4tVyXaWijRDplmis01l2fCNCwRYZaIq0R3iTDAfwowkILOZpSrUjQJpgvpThdYp20GbTvQhbnQpdah2QhqYLSM374yHopJ2CqD4tqV8UAm13dKcyW0XGmH6WXIolIq3cRWFOwm008RpGAtwJu470Eq0fdAeCqlZFEAKiX0XULf4SGKwoXtCTiDcRShDxqn4+0r4xnL08yseP0cLg4Y5FtXxs1uVEL7h2HldkbswIgBcTRYFwYrioLFQ01NwpKmobLkUNOaqxQO1aahppVthprqDjUVpGGo8CZMmaGmVao71JSn0rCKXJWK90qaHql4y9CKYJ0OIzFg3eWBM+rGmBCICdhpbgS7nmcEfIc93iF89gYy4CDdc3HjQl2BC0RE71S25itzytb3H+hvByn4jhTCCR1a7WFaScBmGpZLWonjgNnAVelAqDRWKpQT4LuMNNsnDQNUjkrp+1a0tRzBXnMO9sPb/oCplObtIKWcLPM8XPJu0/ROKLuz8JW1DEOTmwhawDzYy9yT4/GGDB3oW0oJlYItme4PbJjyw0BflAttpgVm9gENSH0Cx1NC2SoF29dVaQBcGgf0SgFl6G4FRiiAjS7njme5B3SzSy5dIpcukEuXCFqCXDpdcunCdnXiJeCYuXeBLrqbyKWzmVw6XXLpxBV3ySXMN3aZOgQtJUims5FkuptIprWBZMIkdkgmT5BMZBoOkUwHcjuGZOJonckspw87zAdcZ92N6m7aqPE2dTvb1MUt0NmmLN6mmHdNHMaWhX93Rngb+W1Mj1K0SR2V6tCjj3mXIDlEkD7mXYrkbKJIDm1TkZgjeIh4qjZuU3fzNoXJhB2ZvVkyMOvoeLyu3qH0kSxTXFuR/tGVdSDRf3/NivTa2sVlf48YV3xQWGbrMuBlfFBkApuw+t4V/O6G6ShkRUjCvoEv7wP1hl4f6S9CotuJd2O6fvW767jTYcDIenEQYSo6kBUKuQrLsoz0Ooj+gMQLQC6DHUB0+YMr4zDHN+4vig+xfbsovv9dANJF8ToCTlFcBQARXhRvIbyO8DuJ9+sIv43wu4k8byN8DeH3E3muIfwewh8k8ryH8HWEf5TIcx3hDxH+cSLPhwh/hPAniTwfIfwxwpd+u5vn48T7NXy/9tsAP5+ALyN8GeEXE/AVhK8g/HICfgnhlxB+NQG/gvArCL+egK8ifBXh7yfgNxB+A+EfJOA3EX4T4bcQXkf4ncT7dYTfRvjdRJ63Eb6G8PuJPNcQfg/hDxJ53kP4OsI/SuS5jvCHCP84kedDwi3CnyTyfITwxwhfutLN8zHh+Qri/Eo3zxrCl/H9i1e6eS4jfAXhlxN5riD8EsKvJvK8hPArCL+eyPMKwlcR/n4iz1WE30D4B4k8b+B2cHRvBAyK6fNRKIqw/zq536Qcis2ptD4ZhSwC0uEWgTKAdGmoIIgCerAoLBjS2hoviutXxoEWCOUcR/IpjhBTy4A0t4w8iK8o+0BWGvqLexcqUs7hfty0ylHpKHANGSD6QPyUhSmFOkeHCAjPu2Xt3OsUR4nB0BHsDfBUIChR6B7OCizvBqwjGieQkjJIuQkRFiEipaw7IQIRdQqqg8qQP2g/OtwfpnQ/Zh0C6hP0qJT2I0i98d3xkgARBMi06imBEHgHqgV84I3vgrS3K0J6yXQKWaO2oLx7GHhwSokD2ZRKqRQwgueWScBPKTEXMtN5lPtICSVGJhTz7864GxiZq7liyMgcYmSucgwjYwlGBkoG8GVkZMwwMlexLiNjyKmAkSHrygCW6AHcLZPyiLMBumCWVDpCBNO8ACK7PDnVfSkMg54LpBKGmxzIGvgawBZwamRZWi5rfhDH6aCE5m9HKRyKKicC3dYiGcwxCAPmxzv6CWgEgC7Z4fvSvzvjbECX0KCdJ9AlOuiSCXTJQAC68FWsaAcioWgH0jOYUTLm+4IeoJ5kbFi0IPnBDn4Hd7BhpahJubiClZuQWzqaFqowGceLlQthdDzUvHqPQK9BfrtGlaJQD13pKIm30wLeJi0AFTksDgqoaR5KoSrXVTdjBTRjw8ynaL4TwzAbUuDUxxuSKTFnBBWVjkKBu9KhXekqJyGk3UyepEdb590YW1AINF4q3plrGlAXAygmmYxvJzN2FwWJWLEWE1ceCiWPmkXwU0+Up7j2owGmmPdfZnjP6gNMX2NzoTUA8pfdUaykRplas0MkcIPKoDmqu35dc1A+NPdPolYJfbSVpXFZWajuKQv2Hm6V1/RXl0PcVOlJlANhafYoMeFcDgQId/PabQYo9s0EGahKjisLVIIexZVQGeX5UlmDwg8cMQ6Apew5jUKlpa1I2drSXHP/QfjmBil4ZAIbSJYdKafImLIgUxTa2EHQjLKmpjB1h7qsRF0WaTV2pLK3qLAPK/wMdbmB06nytrXt+tTaPlM16ose5cAXOsrBL2aUj37Ro8x9oaMsfDGjLH/Ro3zsCx3l+Bczyie6o3Tu2DNn4yhTt6vwEFb4Geq6iWzcqrYnP7W2T69GTPCLARhl0ofRBpMG8YpTWRvoLhrOkU+zibW1tTW/JMCQ79eBSxDBFWRTs4h3owQxidZRW7mRkp/0MObFfF2C2cPxD6C0lFa2fvXX15l/D/NAroDXnWU1ifaSlOb+cU9Z+hqLBpj3xxnRv2qDx+GanAvlAJopyLh/jUUBMBUVgSsBsOMoNiTHg91K6ldEpMkYKvUVGUEmELc+YvQ6Tfwl6JEo6uh1EGnNn8P9oTenhP7qkf4wA01hbcqLNFOZTzzssdd9z5VHA5aD4hURZOD5qgiy8Lwqgi3wfF0EvfB8QwQ+PL8vgq3wfFME2+D5AxFsh+e6CHbA8y0R3AXPt0VwNzzfEcE98Lwmgj54viuCnfB8TwT3wvN9EfSrHmUPSVaSjCC3JF2C/JL0CeoryT4QvwbFoXCXciab4X1FMY+4KIoZJdXukjylpOoviseUVPcWRVlJtbMoCkqqvqLIKanuKYpHlVR3F8WgkuquohhQUu0oCqWk2l4Uu5RU24oCmtkKViqpfLBiSdVLZuctJAFmYflIlSEJygO3CVdeFPDYOaI5cH+fjKJx19Od4aQ7Q0x3hk3DeI/TOK5xGsjrnEZyldNQXuU0llc4DeZlTqN5idNwXuQ0niucBvQ8pxFd5jSkS5zGtMZpUBdpUOdpUG0a1JKS6j4tmqFXFO9zs1LStADVblhpIFNIJYfEmyK8vyQ/5pR6Q4SqJD8yqasiDEryQ5N6RYRhSV6H1P0l+ZJQUqmSvALPoCQvwzMsyTWhdk/wXz2R5WqXtppBBpIXg90w68/An5Oho+12pE9FgSdjyeoS7Mqncds6ehVF0JNKKm9I9EH3rkpK+NC7V0zChc69ZBIM+nZFgvYyJNcEqH8IXhYl4RJ4RZTQBSSH5EuihEskLMlXTP+vmvG8IWh8bwoctXxDluS6gd+UJfm2gddlSV4z8NuyJN8DZZCZhYIQLhSEcKEgRAsFpuh5aRbMZankxE/er0miFeAwgfmaQLKQiefpqjQ4h5mQBu9ySLwkDe7lkLgiEf8e4lbtnhC/GtwP6L8c7Fb3nwAtVprRd4Z3RZghE64IDYRC+YogNyQsnh5Y1dCh+zYunMsbFs7ahoVzPrlulpSt7ges20rBPNgqgJmxVVgSxtj8pBJZART7J4rUiQAfJwOH+vMx7/b5I97t84e82+frHKfTUR4opZb/iEeLDtdb6OEM4sQj+AYuBwSv4iJB8BVhuqN5VBQnNQOqDvzoSTCfmwm0O5Nqdyba7kw+QzYwwS+Gu0tyXAnkc1Jfk8BQ/iDFh1aVnFCXX4OhvQbuMpBGXHiUgzQ8CqAegCDmwePRIAOPwSALj4FgCzxU0AuPXYEPj75gKzx2BNvg4Qfb4ZEJdsDDDe5CdT24Gx4suEf/gz/9h3/3m0Gf/j//7R/9Gxns1L/2L/72P/9WcK/+X9557ZoT9OtUsAuUERbeF+wGwA/vJ0XFClUQALAjDIMHyC49EDwIQF/4UPAwWq7DwWAIgF3hlwIux1Vv8Ih6UG0PH43Uw1H4oNoW7omUpR5saf9caKmdUbhTPdzSO86FD0fhduVFakhtjRSHvH6kbJPTVn1R2Kc45uRROKTslu5Lvt+J7x9UPZF6UAXqrkg9EIWBAi+1CrAOR90bhfeqB7AO/OhgHXvN193qnnBbBP763erusBd24m78lFL9UdivBBbEzyksiO/vhk+dfj1o6nSoCPYr4CpQ2UhtVxZ+6456J446UJlIBVAntoUD6qAk6LQVv995LnhY7VaPRGpvZ0A7cUC71ZZwL+Bst8EZttMZ7+4OzuL3O88FD6i7YWA7z4VCpSM1BKh1YRIsM0PYRGfoQ50xxO93nguEuiu4R20N7lY7grtUNtihYA0+GmxVPYGvMsEW5QVZtSfIqL2Bp9JBj3KDtHokcNUuIPa7skj4bfWlFjB+EBgfagHvB4Nh2AL2D9Ld/S2QAJjqU4MtEAKY2qkGWiAHMHWvUi0QBZjqV/e1QBpgiiu7BQIBUw8rqwUyAdT3QAs9YmC7a6E3TPWpoRZZgh5UO1tkULtXBS2SMPvV7hZYJP5dijurFHmSGWC0HbK6dznYorL6oxs3bvDlsFcvtQKfNk9W9WrRjoKtsUkBeZ2v/Sj0s0xt0aIdZOW4yk5mLZXVF6Mgq7bCBvXVVm1FwVblK3GQ/DMqOwhyClhss9qKEgk3mcgkE34ysSOZ6EsmdiUTKpkYSCYGk4lHk4lcMlFIJsrJxGNR0KuyKPRmVS9+OIiE0UE6cgCdkEm5f52B4A/WFjQHKm35J8NekHt7wYK7S/UCy+hTvcqCCe1VHsxmr+qBqewlw2jvxNt/7+/8zR1g/3UDoTIaDaQZEJtcSlnaJZ0LKs6Ai85Vlv+wygD9zMLDDXrhuxZtdKopGIVoRxDBQHJ3Boir8FRGZZUVhdmiyMRCpBIqO3fU1KyyyqGaLegL1mzaweAA9MM60I6fbMdWvmkHKohC3q0/rWWkxUq4RQnFu+1wtYXa6TTAoYEtxlIFw92i+QFchHw58NQW/ec3btyQy8FW1as4rloBGVUq8IGBKV+/dHmdqaxyD/cPCnQbC2jJB4TRJ82jxFcfTG0+GSQFDCQKs2orTLjiKhsFHDQY7sXNqRQN9uZmuOZRwGHwMG4Y8XKYncxymBLlRGpLpFNgtObHEWUCagkFcjq9qpXKatmeay8XBbrmheL68uV15g8zT1ta+d9AwgM4om2fGRJWCcyPGbQReu+7IrXqgvr3hkAbIdkG4Z9mB+f0+o0bN1LLoQ1qAtjqbF8oS/EhWS7JkwTlSvIEQYMleYggBXKBBULfVU7PV8zzJfO8Yp6XzXPNPM/TA+R7ey6U+h+BnzZWgC0U7wXo3UYJtkC3XQJEWsoaEu2SsAi6WEJTpTUkLvGS2EHg87wkdhH4Ii+JAQJf5iXxKIGv8pIoUKWvcyDVFijZMzSo8dAD7c2C/XwotOHx5GQTlBABSh4fFM+gAZODCpAy/eWRsjRFPFkggCkBtk5o67KA+j7klFoTYQZ1D0x9zMNsSb5nUh/xcAvqW5bagnKxpbIoK1sqgwKhpTwUEi2MVrHbYUqfAlt3KEG96EX52QJJ1Q99FKAx4YZbUS/CBAu3oVpEVXFTNTdNxU3Ds5cmTfk0aWorTpraBpOGeOorybc5gX5JrhvQLck3DchK8g0cGpoLrjOYSrKhNMM0oBKXwClEHGBfdHAJZgZpygGlBSP1OFoZDK5dKAtSfPovgGapPNKeMiXUrLMlVKm3lHCZ9SjYkIBYzyBWAGIzBrECEJs1iBWA2C0GsRbK4YRcCwVxQrBFCstLBgaF5YqZiMtmItbMRJyneQBVgxth3DZ49gkyQjs3gvxNSCb8GsQaPONqSWlF/Iq2GWSntXwi5BQxwbXd1hYZj94Q0QDrBbeHraT+8a8ZkxPZCPSL36H0nzncXUWKgi7BSF9chpCOuVASPZFzoQX03aYJlsuBoxmGb8GWFwrCfUy8F6eowrkwrS8e6b6VWLVyo1A8ypj+h6tfi8AxEiUSIpmQnUTAlQtGO9cMGgg51iYSOaAqiBADY0naZJQRBmJhCFFaWfoTJJHdDkBMGHGfFNTFqS54KTovhXkJRkQeBa4cV+lAQvCTULZe5/gH/b+AE8RgChinpT9KLYecAtbQHwjFKXxNEjZISArTEJubWlFp7aw09bd/5ZK7/BPe4N7hm3X7b7f7IKIoAvyAwynkitECslVqLrCVVBbYASHqWN/49iepZSXoGelvr1nz2l8BnLuISBhISn/Cl4m9QXvctMe77eEiMLhFB+0tOwvzZnCNscfCZBKbMoGfDLu2C0Z/i55hYLRCZPdCZrmMUkMa1h580BauYn1xGYKZcD2mJXjmGHgaeWfV8eR65Mn1yJPrEbZtGlZkGsUIYcLW0hHOq6t4IidJDT9B6GoUkvrtev/e4c5qMl7LBI5fjEILEGqjB1tb2lU2SBmpfsUjGsHDEY4oRfvcPZIVcWxbmrz80t8WCogAhC2Pbud3bnzrSNbVvpIQIw7hxBAiHsA+P29qw6rB8Z2CQMQUUJCj9JjTa2vukWxKAWJd5URBysiTKOakOm55jgs/dswzDE0IbS2Ws2DpxrBOJwJWfDibBo+wvx2iAxWfC1Nk2rQUmt6Z5svAqYtoNeOwWFOd6DcbarA77mnFVcrvhQhsS9k3F7ahMO86s10vdvg6UZiCnri0X6EXDrrHkbfxZZXq1pLaWAtYYYCcwZaGDtg399fphP2moB0MfEjGagDx5XFkrUMxxinUPrAbEGN/25pNkATVFiPB8XuNz/+dzuCOZC0MXwC6A//DoYDJOKbUSGSw1iQFEcNak7DWJK01AWKcA1HeR7OOfv831pneq5//zXXmH2SefpnS7/yGSX/K91sPxybE8xivgJN34qC+dyHamMLlez0lfQasDaVLfVGfp64LLZf7MeAb5GypRDxG3h0jB3WI+WlPCe9/dXga1ObrHOVnZWlVFIeUNfF7v//bn/zV3/n9j/45AzZtTdx49Q8v//Cv/rNf+VurwKytCbBO/c5r/89rv7sKLNuaAOvUn1z7s9//o1Vk3BNWSTyDHFo8SYx6fFOl5U2V5jZVOripUtX1/4N0H/v9YXDG408OKAeOj5is8OoAumL4Mvp6/hy5HRE748oHb1/Yg5NxcTnSr35nnUHY9gqgDjAK4b3+cpjRfa2V/YypHlClUAOhdFr7wCiALIkotJH6pUhZBEn/dvUqGwi7bEMdnlENMEjeh1CFDyAF7yaYVj7HMHruH8cNRlnRP3aLrMZhNh+mUTTJMm0FsEHtjMQAZYwI0m5gaav7DnR9nQlA2+68k/DODyyd6b6z4N2OwNI+vlO2/lYU2loaF+FMaA8yFqSVPcisoAcebuDBIxNkjNiGUnQG/CIWmARAJOwBvwjKdku0cN4ibUr8wGhVb5gyL3MqhLJmD/pisBjKm/Yg2wXy9/Mm0Rf2oFcGEzvA03LJJPwwg/4ZLPu6qeuqqftV09Yr3IiSkX8XobROASLA5owP8jpHMRKESPRgQrR/aOs+cDzaekcEblYbmtEfGK/mKw63VpHJgMChL9IZIwzEi+iABwhtEOEntFiGaG6UXiCcKECBFmTI+JgEWNHx8IpchtniFMWDwTv0noK6oCGiihhZhdKAEv527yZuC4VCaBi3TMzFBMHXKNBMmtMk/nbUqCE4qkNzWaSMVSDuYLdPvag93tSlTiHPROXhK/9uChuCeOZVtKl8CIHWWQdevvHd8RDOW924P+AU5MQ3BTkxDMYGqNMQSjwdtHUCvTqhXx5FbV+HKKajWbsTszRJMEQ3WXrocNaieEuO1kKcG6nYEQqOQ44YHYYjTmAzC7iJteSbQquoe28nu9fFA4ooJAqjkBSHgIIId+N+/HOkP+Cm3g8Bscl4PNkJXxQUjyeVuGX4orw5fFFuCl+U0ALIg2jb4XRAiGOQXrdRmg1Gs8E/b+dBycSQMb2KQWued93u2HFTsR3XBTtuWrmxHbcH7Lgeod9VPWgRy2yw43og0nug4oAdFxQUF+y4LthxXTAoscBDa1yQUV7Xjut2LKJu0o7rJu24btKO6ybtuG7Sjusm7bhu0o7rJu24btKO6ybtuG7Sjusm7bhu0o7rJu24Lthxe5SrKRq1J2nH1akjtzXixqeVQFoEkyzQeSNTGsjFeihPX+jiAjefMmHmexPq1/FYS8i/dwmAXaH43jMojtvgjvIgbLEH/BaCbKLRwSz4Qc8dyUrlqJ7DWaJ9nqKWtQIDLhh1/cLkbXpte/546FGQqIfHHpUHqwrsVBg3kNIWfEsBVXcpBaG0EBNDawZanvPBZZHqWBhTZGGko6k3tSlv+VbcBqsaAiwc/fJlov0Y/jzAvH9pVvh1Nhe6KHKJNujkmxSfG8AGwIo8afZxeiFkKgVnrFKKHenH00z6+8BYnoEzSMAspEpHejVS6QUKP4bQmq1gk4gtolCJRFoFcSw9Kj0feiBLcUI/RZZr5os4jjKlPJSqMiRVSaMlZkBHBKEy3RVu0gmhCWQtfznMxkITA6EJrMhGaJIgNEkFspWIVUYXQykxoBIZrF/XL27Rlv8LoQeGBU+5YDNC97ALNiP0DrtgM8Kpd8FmJJjCXXwIXEz4ToGhFKFBsJMilAMzKULlkniMSjwJLmSEToIUcYK+j0+o3yyJJ8w0wNdnQqnS4LBG1PwYgoX0fSYq3Y/CrP4X8kAWZIRTeJJ4SGCXEcIuI4RdRgi7LLWCg6gp2MLpTpl0p0y6UybdKZMNAONxsBKVj3f6D+XBLGh/nn5xCxrOPMLkcTpAdiLsDAaHQlYxV1+HkCrVo6z5Xk+/FS+slPJAuNGWfnELSjkgvSimfyj1B8Zwhk3qd6HECPPet4W7yp/Tj+i1tbcY8QAH45JBaw9gK961orni/cqOaOEr/G4tdyxDRnj3Y/3dwcheij+D9UzB5HI56NEsAKaTwvxo0pA6s4yBuvgejvJBCFEUZjuGi2zSpJFNmjSySZNGCpeiFYUers8epERQFToGUyqbyAlVBlnVA+JKD04DRHgHacDrHsX7iT/BCdR9PBPy451jgjhydxk1tPQcaibGgmg+37ei5fIWSzDO4PQiadmu/vMbEN2/EqBT6saNGww4JESpU2AGQ6tySoPfCPjuDbl8PCs0B68RdDWlelRaeYPC32rDkbKMjdZBBubfLa9Bn25cWNZvrK2tgfjgYkE8XNJDh9TA5QMT8+WsRPoCncdW0Axp2hWbm9S5ZJOW5+HXHtWtTcD6g6U7KDJbU3isPkiBbmxqZ53aQSg+DEEXcBZO3mZUstNEslIQ7beUhIsGoc7IUjSynk5fwEIBxXqoWJD1VNZbt7hr5BMrvuEA9UtYqXsYC208amojV8Dof76CgRdQA9xWQOcHHbM20UTrJgi/va5Xv4Y8ECSdtF7jSBX0Oj+YBZ8AcjiL5Gxr4pW1tTWH7Ptwh0EUAgtHLUdYiALAv01GDzT/YK22cgANZA8K4Owp5rIpF7Do0FZiH+OhiHPaSkBOSTktL8a3i9tVYz+CHlIw8XYFoe0Io2YYD3pgJ6bjA9G0yz1TVLkaalbuPsYCOuoIiiaik87YgA0caoIM6TgD7phb1JAiRdXHPkjl4VIBzOzjFlYPwoX5mN7wES1m6S/zLaAHfplnSC10N6IcbUe3xjRDzMgYM168GhgeP/DwyMAAU7b3vuQZs4IESbgu8lVrwwEHOnWKHQXO7ii5AH+WNDtIeiVIDSmYUPANH8mCSRBt+Udw/RkrHqr/aOkghm4FKAL5J4EFgMeVDkUI0IThXK1NqlLcLqo0DImsiwZOuQAWN9AF5uegJyFX9tEsuZs1HqLVlv8gxTWlKcrJ9eQ4EDQi0ohAiBlzII6WrDMeWGeA1D63HGQxq48madgfPWAVszSP9jCG89pWFoFupPuW2yrVbCIalsCdlkUDdciRXmeQXkMFexjTfWCubs9BAboKwCXPNprecBMCHQLp2VHeXOgYd4kgedBcrIEH29BzdPU7hj1aKoXsMX7xaxYXq6AzRfFBI5hIQyCkUeoZKY48Mo4hCYfUukq92KjU8/hMkugq9eh6N0eNuHGfw/msrlZPPgZJ2rxIaPMioc0DfTq/HEp/OymwUWx6xBFgzYpt0OZ5rM1D0c196RRC10Pn2Bj37+696dQYRy1VxqfGpNFSeUJL5bS/8VXIO6fGeFdL5Z1TY3zTqTGOWuotTkBxtCfQCahOv5ND3Gwa6L3V8Siq5e2NtcSj7/W8/1nyFJ5dAvv1AMQrkoHPRnk8Nk52rZioK2EmlL/t7jmmTlg9v6VV0+1aNdMxEUHp5NOsmtYdrZpArQILI/I7Vk0XN1Tqs1s1zYggYta3MW7WyPUfMqRBNuwFWzlD8iRFnjpD8gQFnzpD8hDFnzpDEk+LIsRKQhHklsQgQX5J5AjqK4kyQaqEnhQHpf5DBOVK4gRB5ZI4qZi+l1Q+m1bAvcW4kZmSOKUcfW2jdU9b+kMGwJ9KYzJn5sTBBsXNOJ0kLJ6TSsYKoTxB9iG0rkvFQYrHUGMOkr10CUJHOELoHBfaP4paHR8Sj4HFHKEC2MoRehSs5AgNlKRSQm+l/b3tKBrq+Kh4AhRzPioOXcJQV739QNaijqC+I/SOo1kndpRbdGrhGkYmcP2uuZphLtJyBYVSV9+Hi4zjAuMHsik0ScpBcQLVdojolaBVoLbvwMMzykwPhofD3CKEygxCpAChwQh21EmVhqCkNFpJAuS+sV9fxmEAkjSVXlCwhf7xJRMwlNZbf8KHLsXO/K16q37x18y3bXqbfjVObNfb9fdNQqVBmwE/77txwR16h37LfP7fBbdXQSdBevaPb3wLtDz4/0h/yOaO40ETX3HjROR0z1SkMxE5Z4A2nO8wAtANLEWSIp6dBO8rCRIhHNCFwUZ47woe9UXbKVp8oTm8lGMu5HMBMuj4DiuQS9BOY/xIQIUsaMbqOsNs2K4bHYEC2Woyk1Dc70VxC2/hgGrM0VIFkdZI0ZGVcdM0RzMwavJHY/vnhm5IkA7wJGj30iNhor+6fjrp95prhkAc8X5DooEcBIfuLTodTgKh1DLeaOiwN7ex4O0sgq5k4UgpMTYedU8eH7/m5gKR7qUuoj+wwDEF6z0U8Frga6iN9wcWbBCY37hitPGTtVpOZuODxGzDPS+c7nmBjeBDCAiny5R2KJGVIDhjdxg4yC0M34ekrwReH0TDgk5bB9DxanpuGWZm+Kd+DrmnuVaKeDFdD4P363DyaXKoF1vr8whB3Q87zIder3fD/TAscT/MR4JY2HW2UdWBQBu/rhXyJZvsQqDOSAkkV6KnFVySMXuQCbYDZ5Z9UGsM20lhXCL40CgNF5ZB3D0wHWoJGRW5JG/lUiS3jyxiYBx6fTDq1Dh9UkWMnUOfD14whnAmTNNJYPBPhYKEfHBZhUbSBy8WeI8YBdA9RqeFy3R2GALsXIh0RvfRozRJg6QmDJDSANHNln8XqBqx1+5mAyE6kAwd41oBZ/l3gstVE1BrTF/mMNsPZeciAcskcSYN/RTERlCE54bOCmIjok/h4QC0iOE7tIghhBYxhNAihhBaxESXQ1A8IAOLmMAwwo5FDG+igbBBdGWBRQwcPGARE0mLmIUWMfiEFjHesYjxjkWMdyxi8YA4WLS4krB9NgwkObjugLGMFdABPRIRqTzyjt47Gq/+B8GtLol3V9BdrvFIBZpmjiOte0jJ06GgyweF5u2m/koL3eiwHH2amV0Y30pkAMhUvK043CSHGq00dzrhdlUsFllhaXR2obKauP96Y6u8hI1jmWvv0B5vxXIx3B0m8O4wQXeHSdwT0lz5FaJJXkyiC4+3YX6ApiWq8jyKMjJ3LhhZGhdr38YbGEAQwnuwaADWTQPwflcITrc/qiTBYHQbCYcH11vBVQAHMiGaRpBWbXTsDEWs6Ez0vUsYc4ApF1M+3q9Jx+6lX0a9y5TEPlpUcXj78v/qpvL8c5V/6dt3LH+Hnm8q+Zohq6T+655Akjpo4flaS3+YOpyl1xZK8kI7Ed4xoS9GyoarKPSVf8IWQhse8xAqhFaMD9gC3F3S3sd+67fGv4yk9DmQvF39AZsn41nyqwRPGDj8brz+r60jdAkcGkfSMFk2iSfUtH4uCiU4TexuS+yWtVIdKEbStWWmglWsAO7s3MOe/61x1A04nEoGzY+Gp3uUnPMzSELQTiK93xXcWaVba0QsBoDhVHN0XtILH14IfKGltnDPWof78ToRlJQIu0rMkZCCO0Fz/yGq2KILJSHWz+q0QVFlX13Wa6tNlISdFYqMpuZgevczCBNw9Dpv7meofHOdWcEwSVOOCqBpxpTAAGcqmNLXOwXxm6RvAr9RDZST620regnzUnDVfnN4WokIqYb3e4JzWE9XSNNEFWQnXbYq9E4QIxzglTNgthkSpyD81jH6xyGCXNBOHKN/nCSoDyJrHWIX0iVoELQTh9iF7COoDIoHQuOgjCB0CBQUhE6A0oLQSeDWjv6YRXhfopLoMrTAWwjhLYPiYoCPNQ5XGYKv7nkwNAkM4XBgr13Cm+30JR75W+ju0PPEHTHOFTwYjr5CKpveqXfqS98x4j7oUT/+dUq8xwlZ/lzoDTC07uuXfn2dTgdI3LgY4KXSqse3ukGFEL6KbhJzxx/GxHVfmSgy2X0F3jSM9kOjc5iCVeDhIXqYRzTeSXrhdOx0eGEilKfLKGE5U9is/yQF8iiLeFh8ut4GWuyRV/HPuBBEfXNIWEx4ArCwtfMHiVPT9mIgtYj48P6AEnog0q/DyQ7kNPAHI0H87L1kc3oU5CsQHsSQGAgtmHtOl4ZyRccxyIYJDAnfDgkWWIp37gcg+RS4tlWSLgg4OoMGAIhOtXQGz3lwPBtJtQrU74HUvf6bEDRkkUEHqEUOBvtH3Wkk48pN0+j3fI6ZctBSRjfu2kfoIl6XIiQgY2DRC5AQKKAKDGdWPFMIUuwP958MJc4UNzNld+9BQOF2gHn/3c2dT/Tbv3W/Oyv9pn6Lzf0Wxmje6Tfv9BtO0Xf6zbv9lv6TEIirLRPI1Om3SPT7TzgXq8Td7aTZQ3O/CG6FLdLijGPI5UsvwI2Qq0+Cc8gc1X3pBZSHXn5hHI0+QANwTe7YaoHZxdrP7gPtYT/bBb6GIpqD8Ay/8E05lD8AfuWFcSoH5NOmavooSctd2f7jRq2BZlfj5jXbz668YBYBmCVPMI95f5d2juaBoy1jo7a/p+T3ngtdOAeM/veY5bivhdI0Ys0dTXyJVVVaX7bi8yCI+U8mHJfKClIZgVdlKMs/ifHzWSNMWagk4MYGdcrTboA3hKXowiVzV9h/Vn39nTuu83vvuKg5LWrRWdScBF7RBiqJnFvg0W1tBZwO35joSACxRlzUfMOiFglKYhb1f8G57Lrz6MoU7KTv3q6DFnXQ6nTQ2thB2b0tJe6g3aXrCFIHnU4HnVt00Pq0Du74/0cH/3p3mm0SPyguGy4phi1nIiOtiMRcQQIXMu02nAyEG1JDoV1gb5of6EejEYd3uPQUxRwlI5Eg5ohqxYUQkyljsbL/P+lW8FN26zvdbsnbdkt+ardggiyA/EETt0kdkdARGXdE4rx3O4LmDRl3hK72M+5DNNPAHfjGh8iSFILj8kIKwdExgavlZBCfMDEqIlzISPYJcoJ0XlPDqFXia8262by/1hFc1KcLLru6gssOMM7dWXBBw5BPgssOEFzczYLLLQQMBdi53N11+DsGZjZiV94kOekYMlTw8JMtU1Dw61vQJZw3in/cw1zF9rC0fz/qPTgwzEnqM9fX71BgLtkRPOe0YWXQuuiuBzw2q4z/mNYECGeIRFwTRlTrrAmJXac18d/D4kTxzFifSD4zphMOpo4nFNd/42/+6r+yi9DGxPP/9K+/94f/7X/4R/eD54VPwNUV//cfv/idf8rAJ8Mn4OqK3/vffvAHj4GzhuTBj975j5f+FN04PHkcQPQpnjwOAKbL5HEAGELyOACgjyyBLPZSglHM8k5xsWocMT6cmkIVFTzVYhwOdqwepBgjcJWRnOTDubSlCM0mPuCSKWvuQH+QiADsmMJhvXYaiMO30YNq3XRapHPvlYVHlI5k6dBF76eeFPm6Mft39BHX9/B4FR4+B3/z12MXvU3SnIfnjC6h4pryp28lLyrX+4bpeGxkNqFZci423wv/YdKKUfIVRoOHNiezZn1KdJpzZXUu8SI91HuW80+pPKl2Jxvo1o2MbVPFmnnHEmYlOcAoLDlDtjRAOVluoG+usb9InMyMsb9cXVungEMlwP5yy+o6t44mq8tsqC4257x8aUN1X+MSrIfS4Jyu8IXID0txDAA5mMUob/jpiQ3n3iazAuLDYY7nwFwhvUlDdMmDCGHw24hrM58ZWkuOl4sY0WA8QPSKKQZ3jynuPcXFKsVbgPz76gvobY+KAOGuAJ+z5vB9DwjD6H4nuZhKPP/COOaHp8Bzht5BCGngdDECBoRIJBJuQodzYx3OeHVoS768gbh6Xybel0S830U8/zLfRe6QPgxXzUR4QZjeEflfYV4BGcMskTTDHRSfO0K/KoOHgFCkP4mHojzFez3mjcekm/IfILTGhNY/0ktB5Dg2dDqZ3/OgFelnvTFz7/3aW+jTYWQP9+OAn95OSCJxOzpdD6FeXoHzVWXDAVFtzWvLHwtsuP8Ok+jF3LTHVcrf5+1FomEGaTiFknN0vgAdSaBgopsWhvdovJQNd2BDhiURX1GihLTdivwK8x7oCB4QdSRoExmDL1wE4f8y83bF9N/HqWU4tT5dw+Dtxi1O3IdrfijetxytL95u1tn3FqyjF1+AS225Yv1bLca8+83FyzCk5obpoOK7krVPbqr7HrZpX/TipvB2xJOPU/8N5vlPMW8ro5iMtuZN2qPbWLx3EOlQ470Y1zIkXvyN8RJZvIfE8wCzzqdLiU+fXMZPd5sxciJylpnrbGc2/VSvt/0WK983C88srCOeF/dlb6/XQ92v3cu8XrR5XCZLhp/1tjL9g99cZ/oBfQ24Q5l5W5i+Dm8eUcgstjD9Pz2/zvSIwq8ZU612/Z/xPPYAU+BhegA/gE+XI3lNU4OTvTE0ihBg9qtxHRSgEL9+lpleatc/5KWYYv4Eo+eXmOeB6Mr3sx9dGccayUXqAuT6Y57N/AbzJOv1JNiImef9hwxn9ItZP8rc3ZqfrTXU7EK9cV612tVmu6WqbbWeVVNn26qxUKckMzkWz7bV4rSaWjy7UG/tV+2ZhppvLKjZllrfhiXgDWWFd2xbs7pwukEVm/frYVwLfZtebCrqxOI0VHa6PaPWmU8fGwv1z1VsX/Nsq13bNzpWzpWK+WIjP10dHi3kSsOFqempai1fqJfGSsV6qVGojpSLuX3zs1PNavPCvla7vq/VrO1rXWjta880G9V6ZX6xVp3ft7BYoXRrb7P1U1dfW2w2qH7o8b7ZdqP5F6kOfzAM62tWz1VWGrV9ZxbrnQr31RtLrX31efpdsT25vcN783nMHb/DrO0zS/vOTZ2dna/vm6m2Zmr0q2aYb352CrI8VFtculCZbi6eqWC/96vW4tlmrWHwbpA+uP7IkKovNlpqYbGtzlTbtRlVb7TaswvV9uziwubMfIhtFT7rYYytMMZGGOukzzPGhhLpC4yx/k3p0UT6m4yxnYn0txhjdyXSf4Ux9lAi/QJjbFsi/Q821f+6ab+PMbZD+gx+g++HvPv9Y8bYlkT+rzDG0on0Y5vSTzDGvGSaM7Y7kf4lxtj2RPqXGWN+Ip3nVB7+28t9dowx9n/YjIWMMd1uN84stRt11V5Us/SzdbPfbCharHtw8apzM7PzDTXbhr041ZhdOK3qzcWlpUadsT9mPrY39xO8ZH+C2//Ifaz3FIzdtBm/u2j6KRhjGcaYxRiT5mmbvK5JO4yxlEkDLnrMmCyDi4xpD/B45pf+xz959MSf1c7r//rry/2/9Yd3/Vff+LnCz6Xeffr89779sx/9zv9VbbUaTVxD09XZ+UZ9v1pqwRi/8jMKn4+oM7MLlcWVRnOmUa0z9k+4z770E7z9Nxa1fbvyj3XLV8/fovwfWNTH5Lh6N40fxlSrLlVrs+0LCqqYnl88B2uO++wEY2yXwU2tOj/fqKtTx5egI/v3n10416wuDQ6dUosLqqpOHVtcaJxSK9X5s41cLpfPDedGcoXcaK6YK+XKubF8Lp/PD+dH8oX8aL6YL+XL+bHh3HB+eHh4ZLgwPDpcHC4Nl4fHRnIj+ZHhkZGRwsjoSHGkNFIeGSvkCvnCcGGkUCiMFoqFUqFcGBvNjeZHh0dHRgujo6PF0dJoeXSsmCvmi8PFkWKhOFosFkvFcnGslCvlS8OlkVKhNFoqlkqlcmmsnCvny8PlkXKhPFoulkvlcnlsLDeWHxseGxkrjI2OFcdKY+WxsSotTViY9dmV2XpDTV1Q32w0F9kfj1gL1TMNlvGm5qtzjZG95+CXLv9wZIo1Kk8dWznaylWOtR9vRcvny88cvvDs1ImxSjmmWR3g8LH2xGil/IRJHmsfa0+UKiOtC63i0xda7caZySJ9ebzVmDpUqzYWn26ev1AZabXrfE/l2WNFartwbLHeGGsuLrYnLrQbrXxpZnqkMVqYLgwX6rXy6HBxZFIMd7Pn84eqrZlGE38Ksnh2qV5tN/KlmampUm6qlM81qtP1XKkxPSkf7pYp1xbPLDUbLah8ZGq4UKoVpsdG6/Xheq4wae2vPHssn0+Q3vyI+WXJA4vNM9V2kX6zMl+aKVZrpfp0Iz81Ol2cKuVLk3bhNh0rT5ufvMyXZhrl2vT0dL5YGh0ZLuRHapPOKCAZ8PV46/TJg0ujx5fPDx+7UCkAdxpptZujtcWzC+18ob5YQahSm6k2W6lHoNjjreknpy8UJw/nR2efrJQqlQryrvwIQPX5+Jct3XyiZ6WbfmUzX5rJTeVyhXKp3BgdqRdH8vnJdO2LnPsC/OjmzTPf86nYpt/2zJdmysPVerk6WmpMFYfrtdHipPdQd0wl80Of+dJMrT5WzY9UR6rT+UK9Uc1NZr5CA6lWbo3h6TNt7P4YtdhuNPPDS9V6BX7c83SzOp99gObnNmVHzzVn240tf43jSPKVgSefGSBuvnelUdu79+cbNXj1zMDBZwYGzg7nBqoteiTytJYaNWLoIIJUFhqtdqO+d+/TS43agebimcPtRvMYvqOaHh84jLUdfGZgrFMqX5qpFwvDuUa5UG1MF/PFan2y98wXOYf5kfpsa2mx1ajUZs4uzN08m/6XEqss1/2h13xpZqw8VRuu1/K1fGGkWJtqTG7N3WavFOYXq3WY77HSVKORL4+UpqrlXHFkctvpL3Iw5TONM9X52dMLNw9j+/wXirXhswvzswtzt0PajgTxy49s+GlbmNJCrT5SquXrjWqxkBubnrwrfxu8jbbai00oUs4Xc7VyeTo/nJvOF6fLk3c3v9DxlGcXgH9X5qvN07ddCveM3YGojaLsOYoKRL6IiQomKiAQ9H0Vip5slStQ/Lb7bmTh7JmR2TNLZ461J8YqpSdmW0vz1QvwZecvUwXP4nhHa8drueHjR0/OL1ZGcVDFVrs5u3AaR1p82sC3399fh/2dz+E2R9J7b5LwmN8aBsJeLIwWxsZKo2Pl/NTIVG2yf+PPG++6xKlfFaImm1E2tlRdmK3NzS6czo8gWJmpLtTnG81j7YlCJW/IE/X3RPUCbJPb4ReL54dPwMNkLberc43K1OL5+zb+XPLuxA8y3z8SU7o79I0qr5ybbc9UZhYX59RU5dljhNiSUXuGc09Vz/18o3Z4YaHRBJKlgVTl89OzC7Otmcrp5uI54DjlqXIxNzJWr5WnRxr1xuTe+fmVM3vzoyDKlMrFseGx4nC5kCuOjQRfpxVcuM2Mxg3jlObzidY3NHrzuML4t6IfuPmnpwc2/s70g+3POM5Ss9FqNFcaw/n6YsXAlepC3UxnvjQzWq8VqtVSvZCbKuana1N3GPlDsGgOH1uB0Q9/yuhh75Y3Dn9p8lN6gxu8Gm+Mg/OLU9X5yZvx9PCzX9jgx0rD9bGx0vRYIzdaGqvmJgcP/ZQLqFbIjVVHxmq1YrVUG67VJ4dW/xI3GJDuL5UqTx17/HO2npsYrTzy+E04yI8RDmKWjygow9AriwuAyNpovVAcK9SmRoDXF4YnH938U+l7JmN83I7uA2HH0Rvqfqw9UayMafhUbS82qUd7P5OkWSdJc1/8m+u5JzpDGoHhFDePJT9aXVpqLNQrjfnGmcZCG8TxQjGfq9emxkarjXwuV6tP5r/xxdPyMSLlrXZzuHRb0S4xXWWaLjKuVWozjdrcyNew4K2LEnsbzm0y01RmzyzN50vz8NPysy00yCC/KzhnGmdqZ5ZGc3dCc9mgGUddAbWtUS/u7zLZOyy3PPUfdYfy7EKt2ai2GqVfMdwo/1PsltFKvggrbLb2dLv5F+BF5T2fAf8EVabPtMe2VirnpmYX6qcBh4iJ/edpFLmfbhSfY8/D+klKGV8+Rkvg0xSL0m0Vi/wwLcSlZmN69vzPbEsMzqhtX9l7pyVRgkdlqnF6dqFyduHc7EL9qw/fjoHTbiljicXFM+O5OOPNW2p0avF8o54vTC2eryw0zlXOLoAdS58jPnTLkS61m/kRsGFVZhcqS/PVGvCVzzcft5yKyYmjlYk9t6epm0YFlPTxb36ufh5GBlioFBfREFQke9BhWgM34wYo2cjPN2ozk5O34JJPTH9eHN2Jphlydot2JofutCzySUpx4A4T3eE0wzQHlUazudg8OPQZ9iTt6kO9iSULCv5hVNFO3hoB1YULn23Ex9oTw5URvXCh1L6w1KjM1m/GwNcev3NDTzU+QyVH9n9ew0CXczz56J2moGCgeB8f/St/abQWxJJjZ6n54f+0zW+mkMc/G46M4HDi4TvlzmFW7NzPzkDGX/isa+mzCQPTZ26Bl6dGP8PmKcXm5kpsbn563213kCEz+RGyN1fIAP7MkTsoy7di5Aut9vBIIlGpz65Upi6gWPD1STBEDYMJt2L25kgusU9RKmkvVmBb1CvVZrN6AaTyRiFfGy038vlqdbjRKE6e/NKdpmM4njzExS/k75R3bENeI7o8W75DkeHixiJIl+I1+Ytrf3mCS7Hags3/jcIduCf+zZcNSU0M4JfrhjvcqtMgiI9NVWtz7Wa11ugiAOTj1sxis13pfCRmOFGsbOZ+35ycKFYqC19wK7fXW7C9U+U7ixzDhXpjunp2ftNcLi7OVZEq/cLhzyAq5EnIwRclMxdPNSY//8xNjFVqny5mA0rQNU4t1vUdSoyMxiUWFs0QWzOzZyqzrcrZhVa7OjXfqKwMNz7jbppabLan9U0bGGaqs4EXp6JGrV1BUaLZmAZNsFRv5PIjuZFSrj42MlKYZL+YXmou1s/WGs2WcOerC6fPVk83uPXU2VabZZaai7VGq9Wo75m6IGzswn35vWOje3NqsOPzVsO54eKeXGFPvjDknKvON8+2nNze4dzekf8X5WnrKA==');
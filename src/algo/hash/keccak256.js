@@ -0,0 +1,59 @@
+import { SHA3Algo } from './sha3.js';
+
+/**
+ * Keccak-256 hash algorithm, as used by Ethereum.
+ *
+ * This is SHA3-256 with the original Keccak 0x01 padding instead of the FIPS 202 domain byte.
+ */
+export class Keccak256Algo extends SHA3Algo {
+  static outputSize = 256 / 8;
+  static algorithmId = 14;
+
+  static async loadWasm() {
+    return SHA3Algo.loadWasm();
+  }
+
+  async loadWasm() {
+    return Keccak256Algo.loadWasm();
+  }
+
+  constructor(cfg) {
+    super(Object.assign({}, cfg, { outputLength: 256 }));
+  }
+
+  _doFinalize() {
+    return this._finalizeNative(0x01);
+  }
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.Keccak256('message');
+ *     const hash = CryptoJSW.Keccak256(wordArray);
+ */
+export const Keccak256 = SHA3Algo._createHelper(Keccak256Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacKeccak256(message, key);
+ */
+export const HmacKeccak256 = SHA3Algo._createHmacHelper(Keccak256Algo);
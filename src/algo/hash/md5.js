@@ -10,6 +10,7 @@ import { md5Wasm } from './md5_bg';
 export class MD5Algo extends Hasher {
   static wasm = null;
   static outputSize = 128 / 8;
+  static algorithmId = 1;
 
   static async loadWasm() {
     if (MD5Algo.wasm) {
@@ -115,6 +116,56 @@ export class MD5Algo extends Hasher {
     return hash;
   }
 
+  _hashBytes(bytes) {
+    if (!MD5Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'MD5Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(md5Wasm(MD5Algo.wasm).hashBytes(bytes), (word) => word | 0));
+  }
+
+  _hmacBytes(key, message) {
+    if (!MD5Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'MD5Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(md5Wasm(MD5Algo.wasm).hmacBytes(key, message), (word) => word | 0));
+  }
+
+  _pbkdf2Bytes(password, salt, iterations, dkLen) {
+    if (!MD5Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'MD5Algo.loadWasm\' should be called first');
+    }
+    const derivedKeyWords = md5Wasm(MD5Algo.wasm).pbkdf2Bytes(password, salt, iterations, dkLen);
+
+    return new WordArray(Array.from(derivedKeyWords), dkLen);
+  }
+
+  _hkdfBytes(ikm, salt, info, length) {
+    if (!MD5Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'MD5Algo.loadWasm\' should be called first');
+    }
+    const okmWords = md5Wasm(MD5Algo.wasm).hkdfBytes(ikm, salt, info, length);
+
+    return new WordArray(Array.from(okmWords), length);
+  }
+
+  _contextWasm() {
+    if (!MD5Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'MD5Algo.loadWasm\' should be called first');
+    }
+
+    return md5Wasm(MD5Algo.wasm);
+  }
+
+  _getContextState() {
+    return Uint32Array.from(this._hash.words);
+  }
+
+  _setContextState(state) {
+    this._hash = new WordArray(Array.from(state));
+  }
+
   clone() {
     const clone = super.clone.call(this);
     clone._hash = this._hash.clone();
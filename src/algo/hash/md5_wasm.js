@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJy1XH+cVcV1n5l736+977EXREUWZd4VFVRgf79dNA13FZASgp9+/PSTP5LKCqtyF4H94YIN8lYDggmJNmJqjWmIwWgT22gkiUbSrJEkpiXGpHxSm2A1CU1pP9hYiw02mu35npl7391lIfKpFXdnzszcc86cOXN+3Quie+AmKYSQV3orVbUq8EtWV0r8cqq2L7grMLplZb5q/6Mpt8rjgrpqCw04V3TllOtIKaSsE25B5F0nIzKZjHSoFa7K0S9XZJQSUij6nzpKSoUWTTabdYWbkRvklCkZR4rJ2QLRDIeHR4SXe4+bvannpvX9tyhRd9Pqtqv716/qGRgQ502+9tqN161Zt/qGnnXX3tS9du36VeLc+tTY9f09PWJO4WwpQulNDheenT8rKE2Zfmn5D97T0rzwvXVP3y677jlPXVCVt54vwhHVG+RnCZ15QmdDNfjEveW5qqpu1SIclX2L3IVXkLAE/9Jzde6Je4K5ei6DH2qggbkfbChKT8/9uBeY0ZVBhueHV3yoz12o5/4VTYfCK+dCURZq4Tv5E1Y1rdYZwn/F6Gh1xZ806Oza4DzClg37orJmPstl7YSXR+WMzodPqqgcUPsYtedT+wi1s6jdTe0F1N5H7YXU3k3tRdTuoHa2s1Dn/XoddImKI/T5ppllmgtMc6FpLjLNbNPk0Qwr7WqBn2UNQU6LxaWphHdYRSS2+j5iL9KZ8MpothKBE04bJFbzg3Sqb+X6+tEfCod/5/RRb9pQf3+7EoQqF1ajxbRFOrOoLIpnegk+GdVQXvXOUebCLREYOyNBpFKI3nc6iG5lRFMSRE4K0dWng2gzI5qcIHJTiK45HUQfZkR+giiTQvSB00H0p4yoPkGUTSH64OkguoURTUoQ5VKIVp4Ook2MqJQgyqcQrT4dRBsZUTFBVEghuvF0EA0xoppC1qUQrT0dRDczoroEkZdCtOF0EA0yokKCqJhCNHg6iAYYUT5BVEoh2nQ6iPoZUS5BNCmFaPPp2IFsFDg6p3W0uJStmZQaNqASQCVSqESCSiSo1KJSRqtQLi651KjlJYcaZ3FJ6Ywus92crd6S5Tloj8vyxWiPyfIlWqJ3twomoX1NRmh2q6CI9hUDPqaCerQvGHBYBT7aEQPuUcFkXmXAvSqYwjgMuFUFZzAFA+5SwVTGYUDGI7TENi9Fkw/OROMGoi9CpxjMpE1WBwjyoyjMbdQiIgs8KeLHmcmdKjiLRnkgj187VHC2PtMMuPoMPZO6dGiE4kzYXcIVFjfqXBQUeFDoghmcvFEXoqBOF2gwp+vM4FkbdR0o1tFgIX48x497IKB10VB6QAXT0M6gh3ngPhWcg3ZaRBjQmaqnEg7g9ghZnfYSXjzw4jHZMbzkmBcvJnsWk61jrgsxgzlmkHlp1PWGl0dVMB3tpTHpR1TQgHZ2zNwsPZl5qbNkx/ASM5hLeEmE5cUMnjWRsFK8LNRnGF6eVMEMtB1MjzqtegpzYUgXTk46KKWIUx8P71NBCe3lvLNYPuApODfFFbEaZmrMXE2S58ev0tOZDc9yjLEr9TmWn3MNP/RswTJDpx5OirefPPE+0kVD3vBuH5o6MfGVJGp+7AO6dALxa3SDJV5Iozo1/Q+S/r9z+htI3vzYjfqsE+iv1jNOn/5a0vl3Rp+Xbya1tHo4qM8+FTkvIbFJTxvL61he0uQKdPQ6NxCFbormTomNGaI7pD11mCMJ3nNAfC494sV3bwCbpQE/3gP1z7YyrBsga1gwBMCmud1SJxd7l8SB8M0uGqRGYIQ2hbIwwGZC4sQ91t0BJmnI2A3k4g08InFaBv8eCYVj/HUDhLTG94QkHpVWo7GvU5DYJ+1pwGpLHNJEJDzG+aSE8pozH2BhGJpWTmyWcKT9LLls6iQOSJg9s5HnpE7M436ZHHGOHwSjdbHe8QjYqI9PPEF+ZnxEPFIYR+6QJMNjyb3IsjYHf5DlmYutxbtF7qiENTPkjrDcDbnDLK93k5y+NIKfl3qmnq4vORluWlOkNWfqs/XF8XmOp0Zr8rRGYHqynsPsj2WBVrjaCYQ+b2lJeAgFR1RESeN59V7o0J8DlLD6ZwovpEgj3BcDkv48GgOC/jxgAQ5pKMspeRwqUa/ocfRFPc/jgI56dR7HiNQreBx2Ui/vcSRLvZzHwTH1sh7H29TLeBzCU8/1OCugnuNxokE95XHuQj3pcTpUBifaDXdatv5VK58yfkqK/d7Am8XpKi18Q65wF4bCvyyQWoZ5f3Y4Fb9J47npDY+PfjgKN/ZRnhQo2iUPU7TWi/VaLWvQYjmFXZzu0EzZDZ/bNiIowllUkpTnur1lhxPfKgCK+EjE08OZ1B0dHc0tI4C6WfrJD90QiN6hUCLMk4O94R9EXpAhQuHB7SOCg0xBGaSrM/6Fg5xwS4d34LcGlK8SWk1NL/WdFdSnaa0Cp0QHU3aMmKYyEqWztBBR7blDoUuG1Te4lzRgoqGcpRlJBLQgXdBZ5OVGurKkPFANpc4M+hfH2+wLaKcOsTHvxrHM0qhHaTh0GDxK7a6gLnGY1c7SvoBISaLogCYevyBggnIRyBzcwbhJJd0VJDcHB9m7oqFEIseY30kRrvA92r5jDmQFkmuaaKT41/EvouFhhTFAdeCe9HgokKEzGB4gvqNylg5kv9kBHSvhHQwkShpZiipR1MAczpej7azHvGbMTcrg0mV01jSynR/Q4NKN/POpR7rk0OkT2dA3imGUBnntsgajJbYcUuWSSMwIiQdsiiFiyUF5hmAriuWUfijSKCt1iDd3otCl38oapyXk6S50WD+sWtCxO0sDI25sDQcQy91LBJuFYGOxLqUsw4g1C7Em50Kn5NJtGSvSXHjITJczJ4qWjlXnIFsHp6bI+rCmUz5ECCjnObjNbiFL197oqTB4STVJEARC3qTk7ekDCrdoZ2MfnZEHDgmVf77O+peRyueIDhEX4UzSUNJXYBjsI4FBtmMoOpaiHEtRxhRlimK8MaKrLF2H5YPfnaTaQM7yTklLcOUHBzuhsFKaiMsliDloYi6ticSFyUW9Mv0ymihiTcyZhtjNe4wWuimYPMRFmskSyY7T6hy0OjdOq3M1rT4Bl0O9CVS9JL0JC32pS7yUTOyLNXBZyYVZIo/msuUVvbD2MLpjbC+qI8PDrj/bb9AeHs0vIp9CvXy5GB4GRx5yxzru03wUSPB6hCD+ZajB9cllDZh5ZUdimY9Styz4fjTp/GJYMKSjuAukosnhKLY8dC7mOtA2MBOKWCZYY3o8BiUjDVQsb7pUiq+UFR8zxyIVphogyVAp1h7zQJ0XDt8ZX29NB7y0v+SwK/TbYdL9ii4uQSeQ5qbY/QRquVsl/UcmHZFLyoTCI+PLyGoYCWEe9ge08xQElGMh4LAc1ndaB823koABG0N7MZ10TLNcSJ52+AmKL5a7RpyFZSV441ieqA1T7IKpIGdOOKjvDdfQceHsAWvSAEn3Tlv/SrHK0oagRFKZpEu6nmUIRcmw8XQMknLWLC4rZgr7gxfn5yWdGd1HHyKH4pFKU9gDI5il7EhGvUEGcvB1JsTFMmroWKxTLVaDXWKV34lrAxvTGyFcCt/CkQ8PD+NQmcVGlsccksxkPQmae8yAFZqfpCdTEx4HI0Ww9Bp6degdZc7Rw3o9iXqQZwi9gUHKITbLwTqTH1lWYkGWUIuiRhd6SWQQbKc/i9Yalwe4zoRSY02OOomhURMZGgVDo3TBGJoCrEIBxgQNDA2rJfQAziRv9FmgAFPH0SROImvECOtjTqWMMyIh9kZBhi8GGR9rPIlsL86fwpuot+zq5K4usVcOCiZQ7QIRco6hXFLK2PAna3dOjssEf8XEg1jLbuw5s69OZs+zJC5r0l3SEjBBrqSTA0+jYLlYm2JbRlYw4LucQ8wGPSJNMc7HaJ0xHrHpqd1XdhaxFbBPpi1B1lxeFiwHbynZxsTp/r6SGDmRukmBQHxkGJBQexpyYh406z2FRSm9twdl9d7aujKvIr0XRvMn0PvYUBrBsBE8eKJgyMgZodA+cnipQrrq2hMj2K+rTbvvgsbmjMayH8uZI8+xxloyFJRthV0cndn+e6/ki9AQsMLSA3DQAgfTM0cAHLLAofSy1wActsDh9LLjAI5a4Gh62fAdBByzwLH0sh2YecsCaJNld2Nm6x0G2Jpedh+AnXZmZ3rZrvTMbgC7LPBAGngEwAMW2JMGHgOwxwKPpoEnATxqgb1pYATAXgvsSwPPAdhngf1p4AUA+y1wIA28COCABV4BcNACB9MzRwAcssCh9LLXABy2wOH0suMAjlrgaHrZ8HYI3gLH0st2YOYtC7x1R2rZ3ZjZut2eT3rZfQB22pmd6WW7AeyywK70skcAPGCBB9LLHgOwxwJ70sueBPCoBR5NLxsBsNcCe9PLngOwzwL70steALDfAvvTyw6kZ07hVNMuO2242C8b2+XAdrHtQUU/MkbsXTJdCEqcdEi6vCT+Tyab4lLKpGcR197bWZmtkpm7nO0yZedw5SYmJritJKzjZHD+Ik5unIhDTcdfEIjY3y1Bumu9nbOkpFIBPQyd9GfVe/woETYpujOxI3QTR5hZwjmxC6vocuQ/gSN0EkdIHEp/LqfWTK8YR/qxWLTxrCmOawHuWIegTHBuot5661DFCQ41tczKOBHHYg5I6fHWgFJZOIeFyYal2bBMNizHen674ZN4/tqGsVHyV6kjSEkd7i6J9Gt5gMeKxcJYgZIPFNqkM6eoJIkTKkkUmSMvSaUoiU8nvR57e0j3VS2hovPE8dAFiiJKBUqJ0qYi/DhGl3GMLieK0W2sb6Q4vYhhv9iLU7W04q0upyG+TlUSALSQC1uSxVdn/OtWk3eEVRsJEBW/OL3e49Xk5p2am5fjajGUnjtw8zLt5knR2c1LD+UN49GdVIqOxkHW+lRG5qvhJXTT95ubRlmD7AsclLb4ornhsdtxdhT7u30NlIlLU6JjaYSsMRT56Zw/jTUuzmeT65SnBBbXKMz32Tw2D5vhUsxFfM0VOsArZ2dxA4epvZOUVBzCGZQuwknK+rVLwYhEzCOHCIWLL0Gol8EtlhHC6nAGyJXzHPuVOfKdVi7wWToQK2pMFI27/GZ1siSdQVWW7pZkfNj/Yj4TuxdGwBaROjNmq+JkR5jRGWUzWeZqoao6XDjTGXp8esIO7k+NELPg6cxSswtmkTKDsJHvLAmhXIeYeIHQCQNijKysIbESyyL8rklMSHV6QsqOEVJunJAQdmZPISQxoWiEEY0yOB3UPm2iDMHAQJHqohSCeLSGG+pJlEk0Ja6V1BkZFNhlkfY79V6KnOHfUPS+kpU5UxHE3XdRFT6lFXHHWBEHvCxroDtOovaJiXThUdrCo8OOiVCTGaWsFlY0/YfG2SNlUEUmBmNTgi8LVM0SkwH256IiieyLUrYgp52lBFPTS6eWZXNAciGzTGdFtJAG8iDfQeIMZV3q9Ya3DeeXNpTopI0pU+zNUMubaYs/ZPUEjhG1YqIEW5ehUCHcwK7ChVr6nSZPhrcYjAgSEDaqQnFpyKadJoBLl0VwgE5cHxpTOZKmdM0zSKdJJL2BiuuiIOZwHRrtTJpV9ne26HqJP8sg1XWWJ8uVXa4MujiUeTEurxm/mPY2NSxLSwgZxrBhXwjMJN1UuC98V0AIkuRibo27i+gaZhFObCFX59rMLTOmUAj/qhIrp+JKrKm/5mFj82PqrxNnz5laSf6ETdvfWcNozp9JTYm9usQZm1K/hMaEm8LN0MYGHLmLE/J90l1FVxe3iGam06/LvN+60queT4aHpELhlwpnReHh4RFTjQudBWK2Vl3D+M+tOHlTR9Y0Q8000/hW9fl7lLpFHNCYdyXuIhOCwW7B9pXxCofc1qaIdkvXP8iVM2xoXLPcpKZaO5wCoz/L2hLXVEtYpvxKgACyGCg78msufC8Ee5aBzcqgCJkKZ2BYaF1mMPDClVGYAelyzjKWoi/G0Bdp+o7HKlcHmzEjmisEy4V240ZzlOjSd0I2bjjNGlPebD5ElS/5sJOmWSrGQCviL4tadz4KitCqeoSrRbZlhAF2UIBmtl1NNUL3CQG/a0ohLI5FKCzCbIIwaxGyYS14BtMMU5iaBl+C551Butk16dLDEK2KZQqN1CTVJXjPgf1olp271DgHlhhkqXPAhNdNDVxg5UUpIS5C+dNsK2xEqC+8H7jSrXJMm1jSk4T4EpGHnCjEVyeE+HEETJcWIb78/wzxYcjdhTE1DneS4N5c/HcQ3Mt3FtzLkwf3QD5BbI1gkeN9uNpUvK/M7lWy+3G2yu7+JLZqTLwvx8b7snYECFVlLG5aV8ewwy/nJgpdyZhrNRioU4WuKg5d1bjQFax6ryvpVBNXLMMCXk2bgjjF65z+CF+WXX6zaDIDzyTZ2vk9oYIzJlRQ4AmhQi/eXuM8TvKiMnCSlzGbI7LRCqZI+ZfjDnOYA9nzm0qKjvhItIsrJHv7aFGSr/BbSBWafFz0IkyJ4GCJE672xnfEJf8g2JMK9qTSFpmFyeFczSlGxEcn/fmwzkiwrYycaDlG2OcIbhkPO1ruOtbx4CD9y4BhuvdTyNx8FAD/IayBos2NiuXxyxAnttn8ETeCT38yz5Q5skcwymVHaLlDwdYCIYx+4pBVFWrkc56KkIYVmLrv7QuHZf8CUc/QlKHwNUBcoQ6zQ8n0JB4o1gZKocuOOoUiWfPKhChKoWPOpvZEKV4zwk+EyuPXDbxJtiUBB2ROr5ZLzZ419uyyWfOSneIG0oQ/HS6aBoxJ4UpIKLyvK5Uxn1yQd3Zp6CLO2kXX3c+La1hncsSXA09QiJD7O/wRuVkwHOTQbLn1YUrgRkfJQh4W64JsKAfD5z5Cd2++EJcJyH5LhLfxNLu216wbt0IRUkTxXaPP/OP8P9a5snnhnXuYyP1S/CHbuC0RKjzERcTjhDWh6JwUs/n+oO794zE449YZVaimFzRGpCIw7Xbn4UVa9frISOBZvD1KZsivdIkKuU3B347I2rcjsvbtCB0hah1DN9BF4m9HVPLtiGJHmXwegMg/+exgYfIdQcYYrSyMlmMiHeVfyG9tWrWEf3LKCvYGD0DObMk48eY3E3jaBEHkZZMvSuDBBYyFwgs2WoBvUQxCiB71+8BWZdhY542xjms0mq3/tHq81BfWTjtw+G68GI96XzNSQnbmGtcLoGj+osVUNmlTI/Ou1Gkw7gFllbIxpOCBc7tiIM1nVNZdwMjQHigMMNEVc4+QMHDMrvgi+1EcFOIuljjuFbZoRnGjiNmZkZxAoOz7VYqDwqm4SsTh4obI+Htp7LuKIcESj8+JvBZwsdfikMCFOGjID1T8bss3D0+rfevDgSM4T944Y9pbL0tVft0Ybyf9RoTQm6pRlvauIL08vBaya8eEnG4cmJL6UayYQTSaYREwHhoYncm/7Ltm70Gl3MQMwMgiNcWRMVoi/8ht9lMIlr7VQig/qUc8GWSM24Sn55gynI2L5CN+xd/cURTjccQXw9rhHoL8qTBU0yI/g4+t8hEbKELZbqL0OU6+YsyWxuf5rgkl+BTwdw/MsjkmLsaLXTOF0Aq5aJZIhMXQ9S8JJESJbgdfbUI8rcJYiKRjUYpw1+1ITlxt4zwTS3oftW5IG0Mpy5lwBOc/Yu9pll0Myn47bGFNkp6HO2MgYuHxZGgK3DvvtPkdX41sKK6iQ1HL+kvYtAPjwHduWrg3Kb1V30dqt5dDFmQ3it8IgIt9cYjjVsMnt9lXfBxsm5qQAE4XgnRMmI/9TqXhGDnph0cWkAv4oSQ37Qmv5nSViYI4JsoLb9iFC3j6oZd/8snX9z7bW1HH6UZ23f72D77x8nf3feab1Yp6DQO77th23/17t259nhTlCAZ++Nbn3r7j0FOf2lJRrwB++8i3Rj/7jT//9jZ64kUMPPvEq1/8wiefuOMuGngBAw9/74VD928/8v3NFXysKrr279/zudHXv3Xvs4RyBAPH37zz7R8duPcnV1bwTazoemr3Pzz/0r5fvXp9RT0GeNvP9z380u7t+90KPuEVXd99+9X9D45+/4WoonYDfuVTr371rk/8+uW/JYr3MYWDv33o/jee+PXfE4W7MfDgt984+t3bnn/91gq+XBZd3379yOhnX3j7ye30xDAG/mnbvqe/8Gf33PtxGtgEkj++64cfHT3yG+J5A4HbP33wCy9/Zttff4cQ3kjwrz69/a5f/M8zn76iolYSeNsPtn/n42/+7t9uqKgPEPjS7n2Pf/Oj2x5UFXU1gT/a++ZXHnzj8Z+tqairCPzF3x1/7K4jj+5eWGEv/Ynt//LE/Vtv/+n3CXUHWH1w5NDXvvLAAWK1kcDHv/726K9/89W/uZMYm03wi4/ffuC/3/jmgx8jWBN8z85nnrl/90Ofp+Vkqrq+/NnXvvTl7/zns98zTq3rZz+/7XO/eOm//jmswOB3HX/ui0+/+u+v/vJG3MXHJeVVxIMJNyk7KrvsQUOHg6dwcx+bJ4QggeLAI8hwUms0l21SAivAqgY7gJ0YznL6HDjGM7lsMZPXMZHxAFqlkHMpi4uPLse//JzwnpKUC5gL7MziABIBDTG3lJN3k6FT3jPIFSwXX47SjeMqy7IGDoskXDCZKb6/iLdg5vi7JGTPMI0Olys88ykf3TL/DLYrbAp0/Bkb8lQMI6zuMK5zjnJhiBwycLNg319A/XtyeJga/xzhfcmynrbObOvdRSaV4Y9Jx9njCayxHGeNZdoay4mssTPOGjs1a5w2lE7KUG4Hs/GXk/xdrUR+SOYN8ZCyZRvFaTWygRIdVtH1TP0WXw6jXuiaD1pJcqbOIv1LPHaknIhxUd986IYKIxlj6cVcIDQ22eEuKzWfDzz2uRyJVO37Aj50VLMdLnOh9hC+NTzCAaFrSu8CcbaLwhL1PVJzDq3QuCyQcPj2ETayJG1/krA6YJdh/uhtY+bXSmmPEkyxEqUWz4hC80FgeGkUTuJel8KxOuGTHxkx71QwPgtVUTpCBBkONj6bB9wItTNzaHSFvWsMMR1bcaMXLh/vWG3wJ9QGaD++WKMZP8M6Qwf8fhKrtgm1SUecJB2h1FEhHXE4P7TpCITCWQu/LTa5iM0SKQdZBv/HGdjwfv6aKHkPktTeUbsvyekef2DLaT+vQL46tj6/JNkvC5dOPLSCPca66pM4ZYUFLuFgWe4sQRKI1W/I7UpskUMqZcOdMF+LIPK1CMKkvFbx9owJHLxOYgZCHlrInymGwgbOvNXNyASqiwPZYMosfAaDGqDwbkwyXmky3qKJAqGa4SXhgduMWbCpb2rmUDxjYmVTy0g4rNXMoI7+VOFdzFFwuHkZTCDwIPPGVm5dSlEx7ddUNus9NlOmOoD3KRl+Y4QQQw3itH3lES4TT7vIcmR/qPqtLCKYb5O4c1VJeK3mmCYKLkgMx6wY4x40aYbwzhO1K7vIFvAsfyCvCad5Reji62cR19vYX2BB2dS1nJOzdT54UUkFPl0Go+XmUM/BoXJYj8KizMRmxfOm4JXxOXinNoSvZWcubvDOEhPi8IWhX7WFGM8T7Ft4H/XgXOL7Z+r09nt5U7XLeWdMoGLY5lxBp0CZ75BXZ57RgjHyhNO3yCumNu5ZwbGcIm+SgaY7C4uCzhhPtUFH/AIhM4e5qS/pyrhbHNMd8s4xFB6LP33HFW6g4FJ4ObNV2/ZyS+di2k0RsdN17M2//NV/fP6Lb/5OEKddT/3FQ/fe9+zOX34YU/c9+KM3fvzqgaMfq3qOkPzj3Z+RwvwLDXdnBvpXzV+75rp5/QNES/iijn630M90UYM76WdyCl4wDr5sHHy5hV1h/lO2jx/Hthn6WdW9dm3Par1yxYbBNevXLVhw87qN/d0bZs9Zqdev09165fvXr+tZqYe6197cI6TFRbz2d/ffMn9gcPV88L6he92aVb1r1t1AO3iM6M8ADSI4k9oYdgk+j9osc2jo5+gnb3nL27EC/YB/j36Kdqw4bk0pxQP/yxXMRX/3xmuHelYRD6u6N3SvWjN4i14/1NN//dr1G2l9qzR8zFdm377di7RyWrNudc8mvf7mQb3+en3d+pvXrR5YoAdv7NFre9bpNQNaX0dTgM1CGhFiq8QVEGIPtVOobWxsbGpsbmxpbG1sa2xvrDR2NHY2NTY1NTU3tTS1NrU1tTdVmjqaOpsbm5uam5tbmlub25rbmyvNHc2dLY0tTS3NLS0trS1tLe0tlZaOls7Wxtam1ubWltbW1rbW9tZKa0drZ1tjW1Nbc1tLW2tbW1t7W6Wto62zvbG9qb25vaW9tb2tvb290t7R3llprDRVmistldZKW6W9Uql0VDo7GjuaOpo7WjpaO9o62jsqHR0dnZ3EYieR7yTUnfRYJw2JDxc29K9fffOqnv4BlV/bve6Gm7tv6JHuH908MCiKG8y/INKzeu51tziZfhpbdW7TvLb2eU16dltnT8/qju7mbo09zm1qmtvYNCe7sXstLcs2zmvqnNdY3Ng9cNNc+6+MTGmc1zyvo1HPbl3V3d3Z0dTeNud/AdZ852c=');
+export const wasmBytes = generateWasmBytes('eJztvQ14HMd1IFhV3T3dMz2DaVCQCBOUVd3ExmDMn5nB/AC0Y6MogxRMifRKiu0ojocDzIBE439mCJKKJED+ZEsrS4p27ezaie8iJ/ba8doX+5y9tWN/WeyeNvG3Z8feW58/3a1uo+wp+ZyLnWhv7QtzK4eX9151TwMEKMpRNnf3nfgJXV1dVa/q1av3V69qWKOzyBlj/Mv8lrOMnWV8/SxfZ2f5Ol8/K9bXGfzh62eNdUjDX75+1lzHN3zw9bPWOr3Tk6+fTa3rHJ3g62ft9SjPWb82ydfPptd7+b00Xz/rrq8nPmXXt7yKBx/kDxrTDcPrz+b7Bqy06Mul0qZpmaZjOY6V78vcZGYzmWw2k0o5jilEKrUnZadsw0xZluulhcmFMIV1s5lKmaadNq2UaTNhGynLNE1TCNM0LMaEafEVvmePZXDWn0rzda42NjaZa/8Oz6YWW4vL7cuC5VqXVpbb3duXl7qtS102lD7f6Jw/frnb6rAD6fPzzVlKD6bPLzZmKL0vN7eYrHOruzI935wt0dfXZRablXe0l2danQ6r7anXL07PLTXPtZbq7VZjYWF5hr0jn8icbbda7F39iZxFKvWe2xJ5jWaz3l2ud7qNmfn6yvLcUrfVZn83vZczxd3++956ZO9dby9N/fSbK/vf8LMnJgcyv/Vd3vjGsEiv84cOMOXNB3yYiQn6J5n6IZ/KMUx9/erP35Vjivksa7qSKTcMhLq06huKjYgfPDkRmJM5oW7zLah19ap9JseloX5CCuWsnQvY/Jriq5Ip3p1Xbwl9K8vdCAz8g0ZeenIiSCkP21ZXDCjtTg0FhjLWArEWMGWsTuaYZGq9A22JMLCV0Q0M9d0PbrIwYNJQL0FqRLBAjAgncE7kuHQkq4qsZNKpCicrXIkdvvLkxF25lGQ5EXWfTcL7eSW66rnHdSvOiDDVpVVpzPtCOn7KAKQ4I8ILWI5BYgATqREx6FsaZ6kRkQ2YTJ2ED2pAeZAzELAhajHHEYXChWwn2No77kKVUKaUF0o25NvGhLT9tHQCBtkMs6E1Z8i3JcPWnahh5sq0YlXBXGlN5lLUGYCyPx4TDIidwAm1RoQnUydyTFrQhQFARTbt0psnWY5nHZeQxyTgxpVMWlUxSEP0AgfnAvrtUbFBl0bqICLhw4D+kIXKMSoDRwrpSDEFoxCSyZR0hvyUZL6TZa6rAG0PSfviahUowgUCCn0mhTSU0a4KE1JhYEgDWuSQk7VcIlcpukpI0Q2YYpLNt1fPB2kgEaFJxJGiRyIM8G8DBmzobFY60qZJ0F1Ix11gMWwGsFPQhfnAIegANoQWGJHxlScnAjEJS2VEbDw14RtJ+rYVl0IZa90gvQpFpC3TbYQiYTXpfgrfBlqAnKjvvp3ouy9caUujKgCVBvTdgL7DQ1SFA8sTcAbjqEIfFE7HlScnsqarmKZ49RASBwzyB09O6NUohfKmgHLjEadgxIA4GqwDgxVVwaKh2pNA0jRUlhxqWnFp41AtGmpaWnqodm+otp+GoUJOYOuhpqXdG6rtyjRQkSPtaK2k6WFHS4YogsUdRmbAeuSBM+pEmBCICVhpTgirnmcFfIc1HjM+awsbSCHfc3DhQlu+A0xEvU5aiq/NS0vddmKo69vwHTlEKkgRtQdpaQA200AuaSnOAGZ9R6Z9IdPYqJApH/Oyhl4+aRigTElb3bqmzNUQ1lrq5BDkDvlM2op3fVumcsx1keSdtu6dkFZM+NJchaEZ2xiaz1xYy9w1JqIFGaSgb7YU0oYlmR7yLZjyKeAv0gGYaYGFPUADch8/5UohLWnD8nVkGhIOjQN6JYEz9JYCIxTAQjfmz+S4C3yzxy4dYpcOsEuHGFqCXaZ67NKB5ZqKSCCl594BvuhsY5ep7ewy1WOXqajhHruE+cYuU4cAUoJlprayTGcbyzS3sEyYxJhl8gTLRKGRIpaZgtIpzTJxtKnJHKcPA/oD0llvoTrbFmq0TJ14mTq4BOJlyqJlimU3xBRCFt6erHC3ytuIH9m0SFPSjvnRFd5jSCliSFd4jyOltnGkFC1TkZgjeIhoqrYuU2f7MoXJhBWZu1Yz0HR0JqKrl+n9VI5JrsxQfeypTWDRv7Fhhmpj44FV7yfFhOQjwtRLl4Es4yMi61uE1ceewu9OkA6Dv+r0Y8gY8MsTT00EQKvi1FAVXnqdeOQpzdef++AmrnQYMIpeHERghydyQqJUYTmWNdwY0R9+CtULQC6DFUB8+cNPTcAcX72tKj6C8K2qePGDkEhXxfOYSFXFc5BAhFfFDzD9EqZfTuS/hOkrmH7kiV6ZK5i/8QSkn3iiV2YD049h/oef6JV5DNNPY/pjiTJPY/ojmP5EosxHMP0Mpj+bKPMMpj+N6d9MlPl0Iv/zmP48pr+aSH8J01/C9LOJ9CamNzH99UT6a5j+Gqa/nUh/C9PfwvTzifRzmH4O0y8m0i9g+gVMfy+R/i6mv4vpHxBuMf1yIv8lwi2mH3myV+YK4fZJxP+TvTIbmH4M8z/8ZK/MY5h+GtMfS5R5GtMfwfQnEmU+gulnMP3ZRJlnMP1pTP9mosynMf15TH81UebzmP4Spp9NlPkSpjcx/fVEmU1Mfw3T306U+Rqmv4Xp5xNlvoXp5zD9YqLMc5h+AdPfS5R54UkkfpUPQUAxdSkMRBXWX1z6u1RCsnmZVu8OAxYC63CqwBlAu9RcEFQBNVIVJqB4Y4NXxdNPTQAvEDJ1BtmnOEVCLQva3CrKIL4mrRM5Q/NfXLvQkExNDeGilSmZDn1HswHiDyRPWWBLtDliJiBcd8fWuRtXR41B8xHsDchUYChh4EzlBNZ3fBarxgmk2Bop1yDCJETY0rweIhBRZ6E5aAzlg/LCqaHAVkNY9CBwHz8jbeWF8PbCBydqAlQQYNMyUwMl8DpcC+TACx8EbW9/iPySKRtFozKhvjMFMtiW4kTOlra0QRA8tEoKvi3FfMB051HvIyOUBJmQzNuTdbYIMkdxyVCQpUiQOTKlBRlLCDIwMkAuoyBjWpA5kvUEGUNJBYIMRVcWsEQPkG5Z2yXJBuiCWZLpEBFM8wKI7Mlku5cptICe9w0ptDQ5kdPpDUibIKlRZCljVfGTOM4UamheP2rhUFWmQrBtTdLBUhphIPx4bJ+ARQDoMmK5b3h7sqkt6BIKrPMEukSMLiOBLsMXgC7MigxtXyQMbd9wNWakEcl9QQ8wT7IWEC1ofrCCX8Y1rkUpWlIOUrB0EnpLbGmhCZNNuZFxIbSNh5ZX/hT0GvQ3VOohcQWXPouNxN2sgCvIO1AXIesIDFANHmqhKdczNyMDNGvBzNs034lh6AUpcOqjBcmkmNeKikyHgcBVmaJV6chUQkm7lj0ZLi0d4ne6EbB4qXo81zSgHgZQTdIFryQL9oiCVKzIiokaD4Q07tJE8GNPlCu58sJhJpn70X1iaJ0/eICpTTYfOMNMWl+UKSW6X/xF/4D2K11FdnhA2l/80O2MMeYp/wBQ2oEn/QOx+icPvMxlaiFIo+6kRNfPKOZbxoR0jrOaMUaPAj1G6CHpMUgPjx4OPZBZS1NadyHvt1R+VYluCJ4EFvxVnx5+2V5VziXJ1OAlemtrqx4qzSOLNE8NAWM6oVvgYdyIMtGysHvt2NvakUyJqKJIVHRuoKIRVTQSFbM3UNGMKpqJit4NVLSiilai4sANVExFFVOJioM3UNGOKtqJivtvoKITVXQSFeUNVExHFdOJisM3UDETVcwkKo7cQEU3qugmKh66gYrZqGI2UbFwAxVzUcVcomL5Bir2RRX7EhXHbqBiPqqYT1R8c3jdVSZFzgOeoz77yCbzjoITU+XBT9znYg98K5tzcRC+lc26iAffyrouotK3shkXZ8O30Iup0pByXKQJ3wKxDWTlW+CLBMr0LWDoQNy+hUa8MiFluLjEfAtcDrBK0VONC92H8tJU34s6KIANmFIocRdKcgPsP06mPhr2MsjCoxDk4MGCPngMB3l4lAP0dJhBP7kA9khP5uUe2S9zMitd2Qf2KQtuIjN1gKxZthqiGRvcjF7w1TBUV772ma88GCr7Isg7O5QD5Ci5JQS1qSNvlv2hZHKgI+1VOdAJ1Xf+9L/+nYdClb0o7TDYuypZJ1T/7g8e/rgIVf9FuTcMBuXejmTkl3ldKPdKuyMHV6XdCdWf/ld//uQDobrlohwMA3tV7u2E6jd+5aV/so7w7TDYF6JzJxiSg6HcBzX3yL3Qk8GO3LcqBzuh+swTv/JVE+HvQ/jQ7oee+Bf/4v4t8MHfEeyXNsDfh/D3dUL18Gd+KdwG/rkvvO/rqa3gR4JbY/D57eB/488+9L9c2gb+C//swe3ADwWv3w78t3/jP/3rSxH0fQT9V39183mCvi8MbkPobw6kpH6MBT70w4Mu7IMu3EZd+ND/9gcL2IPbwsCGzH3SVuudYHC1HaqnHv3DLxIqYHpg0m6Te+FrAF8//vS3vmFiH/aGwT74ulditf/wr698fj1UFnZkMJR90IfXA+QhGMeg3AtgsI3NP3rf+x4IVZqwmEDNv/nNv/hvRaj6ItDxtH/mF/7dNx4K1UAPQxr5/+szX/3Cgwh1EJHvwnglQL0VoOKg91Lj//bTT+9BoPsAaGJKv/Hov3pwC9B9BPRrf/7N394R6B/98qO/IBJAswD0dQDU3w70O7/w/Ecf2Anqo7/87X+8vhPU7//6dz9j7gT1/f/jL3zz/gTUHEDdD1Bv2Q71yu/+9rmdgP7P7//qV1I7AX38o7/+x5eSQIPbMP9f/h/nQ2VGEJGYcEqzBPE2QN/3f/crl0LlEjnLfR2c0VD96r/84fdSofJ0k9DaEy99eDlUe6k5OQhAYGDPfvs/f/J+BLMP+cd+AIPddgEW9eTXf+U//3sTwUDXO6F64R9+/5+u99rf25G3AsUR1XRC9dKVv/zhz0fQoIXf+dH3nxUIhtbp6+Il2tdbop1Q/V/ffOR/eigeECD+D776qQe3QBrqrUz4/lvPDCcBffmZf/t7DyYA4fTktsD4wy/+6X/3UBJpUsa9AZBX/uLv/YhHIKHNP/zsFz75AAKxsQ9qvdNGvDz77Cc+vh6qVDRHe6AdJMP9tOptLAr9/EdX/8lHzVBlNFkM4gfozad+91vP3x+qvCaLfXHjX978vWaobo6mEPOhe//9F7//mVQCaD8AvRXgvY4IY28M9B989ItXLu0E9Eff/efrO8H8N1/5+5+9tBPQb7788R8lgXoAdAiAyu1AX/jGF1d3gvnhD7z/IzsO9OO/9sxL5k5A3/ejb/xWD73yphD0BC5vlq/fDvOfvvgnf/zzMdAQDFAuB+Qtet7jPnzlk7//HRH3IQSzlcu9YPTlYUDJXv39T/3y8w9Br9C+N2QG7G8L7G9Lpqdoo0NtMjB50nlXGcpQz2vNQAkl1NejF664+qp++fzNPLN+gKkX2HyQhpoyrX6fhVWxv7f1JDl4gSzc+bBoj6Is08c3NjY2vJo4BIpJWh0KZVrtD8Eu8W6R6RFRRkXFey+oKt49QQZ2FNIyUxUF2HerikN6l0NmoM7LGQb7H/ChvMXbHneDS+sU7lxxBD8iCr4pue/ivj18nQ+ykH1IWvNgTmGXLJmF4Xqv1z1ysWJgSiv0M2JCZpWgcIKsWg+DLHZHMZl92WUudEtmwwCKgnWahU/HGG4bKh4GLvTDVewOvT8g3RxT3M/hqBX3s9LFkedg5K4yVv0+QCAUVCa4QVz1wKqf1wZkTlphgKgIg+xhxlS1A7AyiocwhjCZJSBLbMkyIMtIZOVRgQysk+DT6tNbbAAEQfTKkSIp+2D8fUhEaelWxYRMy1xVvJnexqSLHTcmEbFjIFmNHmIngCxGxJv9HJVAMEIa8aSCwS6qeiIJTXkiDkJRfjIndkeRoVGU346i59m1OIrzEkiK8xJY0nnGTmjKJ9GkC+6CpztlWuar4g56e5tMq28xvRJyUtBKuBdJLT0i3h1YhMBnGWDQ6mHwTh8p8w4/T2siL91QZnrLwoUxW1VxJzX0Dk2qMGrvlIurdj+wDMu7hUq8LdDtWFiCMscoM7cl8xBlmjqT1jg49Dx4OL7R4wlZ6QC5C9g+BDdexAJwpwpYF/KGwR5v8LIOrQULHFrePRiSE5eSJmwvpjFiQ3FypSjmm4r7/cYEMQtJxfYTaxjWJGSj+4NW+X5Y5XYPldLPwGOY7CQIBoCB2oQvK4T9RI3VEbHfN3VZIlMbkTyMTM88qZv3DmJZCZX7E+tfVMUwAdyDKMqGVTGiO2gQzzRugGeOgOwyvJu38EwDsWVN5mwaOPBMg3gmrjAL6kTEYUDbhq9jTv4fzCetV+KT1v9H+KRI8EmxI58UMZ/cI0WPT4ow2HN9PpnaHUXib5BPir8hPjkCfHIPUf9/AT7Zgwq88ua/Jq9EEMRCqO+O7r26yhdOxQEcOOnITQeDrF5zHlhNiYEN+ll062CQBo1+RAzI7JbRZXFGKX4DuFfQpwtK2Ucd6gfs98usdM7kYF/JjThzj1dHda24bjxCU6tj8eA8zYKgK1gK3FZZ6XrHQY2kp7Skg89t+bCfg/lMpg8KkhIYVEgcf3ArNOoVi3vVQ7F6AVTaPLRnQXv/Z46nQGMtzAepXmRoQlHcwnsNmSIEpGIEKO7bGO6ohlFL8bIQ53QIIp9GxIhveO9VHq42G4SVLQ31xPs3mRRDgXOYGUDjtnSOcnGMcUiNCKactWNM6JdjzMBKZqjrKX4qqmrGVa1k1VRc1caqTlxVxFWduGo6WTUTV8UAPPDF6qrGqaFAYNUs7F5i1Rykoqp9+uUYy0N8JyIJQthMemMUVzmMISYQxwcRSCZoCJ5vJKWUDUwpqhlv9U1iGGOsBOIHJ5JQGCiTAgml4+UMyJwPIl4J0WqBQwGpRujbGDOkJZAAzoJ9smGDCCUQ7PWJEIu6GMNmkwSCoEEeBhzNKlRdsGkoxTGMjE/mGAohITkOJg1zzoHDgjpiQBHisBw4LDRuoGoCCi0gPITpiWSEAAbrbBFCAvirs0UICWCvzhYh5CK9BAZy14zmrgAEQfTKwWh8Q2YABRlkrohEiNFKUwwXR0sTO27RJlU2BKHfQ+wArIkR4flpzWcATGyBwVRYuF8/oLjvxijS6HEhhG9X9FgaPe529ERCJImfOC+BoDgvgaFIruyEIjeJokij3xlHUqZA8uynt0Fa/WlpRqvfodUv4tVvwFCB7HE9OUPAnTK9zYJMYrOA0fqIVrwDK/56xU0s7sTFxfWLI3XHK9uBlY3RGb3iTrJ4Nlq4GBANIRV64cqUGgxhV7RHDPuDDDxkIDBM4CBjNYZBfAeZUwPAiIxThK0MhmNcD2HmdoS5vT66N4Cw3YrvgrDdikP4hEcREQZtCHsUD/DjYNEgLBoRFil/kPIzyAOjzCxlprdkskDzQsqUKVXQ8kx5KB+F5Fpe6ifl954kR7+Z5vn13hb2biKP+2lYhRigqgqRgAPvv5/BbZ7EjCGzS0czlnmFGUsnZizzyjOWTsxY5pVnLJ2YnEw0ObutiCwFsBbAqwSTk9lNNqVxEdOkRLIIBARYhCAPQKDYsDcYaQcQ9qwFgh35rYBaGJ0qyPTC8mH/f8rPgiVjaD0v2dAAdgMjt6EZFFgYxjMIG5HAs0HXkyJivRABoTUcKrCfQoktZerlihtZfbQTJUKZ6+Emt3WiHJwosE+hDQms9K0gBNLSkH1SeBZhIIp2HgnyiArcC/Q9zM6j7dEfmWhC5nEU/dDlPAylH3YHEZ1gFGLpPTLv3wShEEB2CZCevMmzCJQkPHggCWAtOLQtdVNVjED6oChgvITuh7xJ5uH/qSGqJkDuDCiGqrYguSPUy1evXrVX/Zsh35iQ/TIDskccZgAnEwbuYdaTPsEt8hb44pIQiiVQL18k8o24JZeEEebfLDOA3gxKogFU2AGoLxAedkLXyiRB+wINMD8jB0A0DaBo2gN92oNnczB6HndiHdqxFWgqQHyMRPxHROD5AlZ3Ho8Siarw4E3IPJAONDFJU5mFgOt+PUkY65xHmvAgM+JPOCcZmpNMInOAMvt0psxBz3MQB3cmZ0DkHySTXAp0+4PCIS0flkfsLYz4nvZN52OG9ssOtyKGZg8DhwTlJeZpJgUfmqjGQ+gaOo3snmsJjpyAHWWDimNI07sFQvYGfZMYgIkUy4FibVKSbPAkeZGdw6EOIQc/DPaifIQ0T5H6iiBBZ+LkPhHShONCNthzJs4PdsEEEYbzQz1wsVLApRmikuREyqsDU49y2YDYL1ReOVQPAygaH+UgK8PUbqZBiMqL3cwCV1s6WpsYAUWsjtMSAdXMTC4RrbmaqFSCEgUwt2iuxrWaq3Gt5mrspLmasBjMbZqrGSKIXjkYim8mtTLE7jDEr4Fyhm/7pcCOp0i73x9iRGGM2GEgA/C3pakEgrFkKp5E3AvRXkKNJpeIQUTaq9gdRSmNInc7ihKK5Xbt1dhBezV20F5TO6HJTaJJF9wFTwVpg/Z6iN5GpK3KmvDTuN9ig3wHSrNhw98k/I0A/swe/gog1EB1c2kFuMAhEotAwIjNqihQO2VNqOi6PYWxhgDQhZVGJUYC3U5sw9sgnEw9OYlML9BrTvsW7IgZAGPSWk70NOH5n2yy8r35IDvMKNgkp/Krfp/MqZeuXr3KV4O8Wun4HoWl5GQewnr8/mgRYxSzp7ww8ICRQaxeDjx7oKTm1APAzfohXNST/RBoA/JMnKRTEjI3AuE/EDedoxi66MVJvmSTL17yZSD5Mph82Z98kcmX4eTLSPLlUPKlkHwpJ1/Gki9vDv28zAGS/ZzM44eTkS00IiAkfJtnaJPVBNMqi5hQUpnkbgS3WBpWZh5ijAdlnpz2eaBGiA9ChShP4cn541/7b379lwbAxeL4QmYVhilnwe/n0JupHD8XMeAsaJOONL1hmYWTMDl4OH4evivRJZkPo8BwrchLmgX7XYC3KQfkmotFBp66yyFPhpZlTqaoZRP6gi1rOOhyQLkNIZyBl4RjSU/DgQbQdRC1n4ZIR7EW9IHG3oPDZR/BiQGArCAfqWI43D4Kz+xTfNV3ZZ/6y6tXrxqrfr/MS45UC57bnLR9D3UHTz0DWnBOOlOgBWMsGkDyAGH0CVlR/NWDkDKPwoIFDCQMcrIfeQ2XudDn0sXhanDSpsFeC4YD9+EwePQjpJWxituXKI5ToewLlQ06ET+DKAOXEKpI4FJaV1LmlNGd765WBSOdhKun3683nU0lvXeingA4omj27EFh1iAIOIuRuu6fpLizrl1EDLD9wCocfZoP8MAeeohMmAFywgIfB54Nxxw5ymY4FqfPRXI6fTsfpNUDp3q5BjYN4lYcYkz98/W3h6icJV5E8sWIX3wuHWDhDi4jaMIX2JpIlNB6XhrkdloXNEL0NEUSS+urvQ7A2UmiDxva4tQWZIo4U+hM4NI89B2QsbD5D/LMUpsc/+A5CcAJbZEBaZvqJXs14HSwE+PmoTod8zQIGzpkNw1n2O01mVaptbZ6+H2POKuS6zjgnb+Zu3/b7YMIwzBEV5sXBlwyYkmWtOd9CwQRHLSF0/lky0hBz1A9vGEuKG8NcO4gImEgtnqZrxIBAjyu4fEePCQCjVs8yLBjZ2HeNK7xjL7QhcS2QhBPjl3bD6PfoWd6gw2QnXfJSQbrGsxxZwdHGdBjGswlcJCHAY+pjifpkSfpkSfp0YF9BaDINC50oY93pkOcV0fyREla19p1SpEi7g9TPLV+jfOcqQfCwERPPJ70UKbCGO/VwB6SPKQRvCHEEdl0gtA5lRPRGdA0nYYxPC8QoLjnyR1rwGUNp3KO8qQBccZw7B6uUvBhnV/SrWHTcEDEhgO7Noiqu+gxrzY2nFM5W5LCkEKPMHJ8ZER2fHyFk12vD7AwPMITWEqs5mAvEo8/Y7gMn8qBFz3t9VN8y3xg0/FVE1QU4GZ8ta0EhgE7EjzIcHBcnxKFHSY4ehsdZeLS9vIQ4wv7oNdUtqAy7x36cNzoYEQKopH4VM7RW9p8PkjhMRLaX1qVdq8Ve2sriiHnQ0Fjknt3e39T8fF4G+DgAaHkmSY4qsGjE+gpOotvo36A3YC7KHZtWR8motYiJKS8vD4b83I8uFM5E4/5kH9fAovS7jQWbQwgrRl02B5ozQBaM4jWBJiQuDlyVy6lfvPRTaaOqBcf3WTe7cxVP/gAvn8ien+F7zsPxyLE8wivgBM8bAIumEeemqDD40h4hsfQnMSDTA+oS9R1MCeG8GIEkISGFNEYeW+MHBQW5qVdKdxnUtxcR6oHDqgeoMtB8ARdSDczgBRZRaNErIJNh+wUzgHB4TwQONBdonEIUcBbJ4xVZZyEBSf1uSN9GwWdxgJANE14JArZkxRe/zWBVlgpAMBkoeplJSi9QSfEDH0NhNcPreCpppgIWCi1IhF1sNcnQKEU13QpruTq43SY5e2h8z5wEHkd1bCPwAnpXAoyX/jgRAAXpVy9zed0OolvO53E8BQ1pGJAyIJjtMUntOIzWy4dt34aDlXflbPiw0aTlIZjSaY6OJUz6aAkRwMD58aQ7BSdasMlGk7B3SSgZvtcH5Lk285EUfeuJLvXwwPyTJLNyLWjs5sgU67ehn9ODflct/uRpybyWw7SGfG5Q0EH6Qwpdjx3aFx77tDYdu7QwC06yfS2Et3swfF0XQ8ozQaj2eCvtvPg58GzXmodT5u57gtWbPrZkenngOmXlk5k+mXA9HMJ/Q66f0M/u8X0c0HHcHGvXXRRY3LA9HPA9HNAB2W+iwo8xJ/0TD8nNqKcpOnnJE0/J2n6OUnTz0mafk7S9HOSpp+TNP2cpOnnJE0/J2n6OUnTz0mafk7S9HPA9MuQs813ZCZp+in71K52XxSqAOILrDiMfiIhp1MOtkNlBgMHCVx/ygbZzx2XH8D7KAL+uUcgsT8Qn7sX9QMLXGEunDfMSIeUaiXWwpMY8nfxVM6QKZmZyhHvcynuygK3tYF2oFeY3KXXluu9KXDpdKdLoQUuUJUpXZlFeadM+IauE4fewJGIXiOkGYA87x2WqCloo8Qmo4TulLoGprFjrtgFq8qRGZlSnwAj6CZG/sph5j5vCWedP6TeqDY2nmW0RlJ44BLULB+6evMa7AcNQfxVmnQd+G6uxqo8sWvSWkDhsvGGHsjDIjadktWuLliUNpZHHdRQ2VU/G+XDHSVSSDcMcrGmmUvqoLmkDppL6qA2BH2ADur2HFvYFPpabJlLlNQu5C2erXTop8EzfFjyIVq/cLXOUZ4N+Jk4fAZH7qyiSE3Po96sTT79+dY1Zaz2mYJxBrtepBY56i+vwrHlNR/t/KtXrzLgIHD81scj7LRdYCswxYEvXTVWz+QEbOfAzg/0OAMBRSPC67fgroysheYcOyicoO9T0Kerl1fV5zc2NoC9OlgRT81n6PYNsKJhYqo5iF+1sfMIBe1GDVdsB6kKSZCm6+LXjOy1JihgGopl+228L8y3QZnRrbO4dVAaptBrz87kjF1GZcQgko2C8tJXEw5q8PHIbBpZJu4Lo0hMYDNQzc+5Muc+bAmTLm+T84GR3KkEnyyoEuBPMrZulGkPrQkKj4HueLo6aiCKpgMBqV1A+GG/i4c/vYOgswzCueUNfkzvK3N0Y+5HR9JbV9XYiRxdg2RC2QFUmrCqS3XNkBzuBjncMdyUKhLjFJ87bnyA1g8EQpnzwEipDWmqyPNvYDhp3KS0YScdMwXWAxMXNgc4br+a0WDN43/55T/5V3/81C9+FHYfpXn84d/58G/9w//4v//RMuylGAp7Q7vftBVg0GYGRAnRMPrAZ6pwbFm4PAn2JoTyFPP4PuqzQ760VC+GJ+6DABcCAyj6egGT3oCpmK9wPhIKmLsWMKmAs2sBhwpkdy2QlSb28lQUzMKjoDA4S6u3kshrbCoPvEv4PM7cTZM7WoUwIwIE/z2q1YcZC/RhBmTcuLnP1/AcIxAxxg3j3TwpzR7RreMk6NjaVOtvRzEFykhabXCQvWm1yU/mDCBVGL5JqrB5/JmNjY1UDQMQ4H7AMAApC+tmRJi4CoEFWGQo0TFVaNWSKViJZEP6cK8TlrKoFG5OWlIcZTwQUUmIH/UFaMSczpDqJe+gxFDYD9w8BTOIdlQt0BLECOMUu0uyBi1mkCKuropbHHC31lHGfLpGCDamEZ10fwX4zaAlKJCOCiDT3qEFG2+4Ut4q7S+4yK0AM0e5ic2D/Ncf01s+opWdfhPvk6Z038Sz0gQPtLMV5Whv7oxphpgxIsy4ETVgVIAFGxl4ZYDlvmDwrKYgQUqog+cQTIq7TZ5LwSsysLOwnlPSWII/K4qdpBO6oH/aMKng9T0F25HkAzyV42T555gyUaxR8LcycbsTVqczCfetCMXiaz9g0VgYaacjGwTNGsIFOx8CTRbmAXhg070CGLWBypAyvdeDkysLfjYYEV1ChZIQJCppCYg+iANLTQ0FLrnPXHBdg6x/aNXPYVEPnViwOjJgR5u0L4az2pUmJZ1QDa52pd1uIwJWIDA4hy6tgKPCkEWFARqALbBBcHB156ECTI2Dqnso8YoWcKn6aRCEoN6mpDsPN2mBTFYvw1GmM2jg05Z0lAGYwfVNGh3d0+dgENMwc99vcrEOpk0YXeQBE6mZhKFtb0b2HZgy0VUfLGF7i622N4/u/BA92xud6podcu0Yh/tP+hPbzQRYkNHfM7pFwugGHnVpNTC8frIzw8hlgSPAliXbYnTzyOiGqtv7EldCl2V8LQv39uSvuZWFozFpRLeyGNqY5AljktMax6yAx7ey8J4xyeNbWfi2W1k4GpM73DBCgZt0w0jc7+QQt1vw+Z2uH6FWrmxtJRp93nX/THBrXYcEcfWNqz8P0WLwP1yrMX8Gr3f1JNdeS04XwIYqG5I3CBq9FFMQ6LamJDGDl5qAu5e4UAA35wCfCvFCRLyDB30j6NEBcHhb3nzA52kPPLpcFpga2mHacQXdh31alL56QBaIxq2eR4GrMlkI4szyyKvxejxoRt/5InmoSQHXANegObp58HKOuyL/xpZuGODDwitaereRCr0h1HMMGl5e3/8JvMz9ewY6wIAL9a63jEkQNCQjDsOAHQJ9TSJemyjorkSO0hujYNB24tG9SFFAce+2RTGEe/qmb0keCMgWmI3hGUM+XJYArfOoYbzmgbxRwFxpWgFK4gJGThcwkqbFTqDGCqqqFDkDpC52h4HaauL1vhikIwXe60nDgk7jNShm1HNTrwK98NRDuOz0fa+0iCnuHy++5ORE5dAuQht0CUG9DwP6Q97Nb7m4kSUubvxdwc0e7Ttr6LhUt+HKBZvrDBLBT0jjXCDoumSheLet3tJBh+ZxVgME6MiNGD8wfxE8Dnffop5g6FsocRySRUwAWELcPWm2sWP5yB2B9+CY+qJedESYEaeB204F3nYq6LZTCDw2paEvKQ3QF4HarQEXlBoQWkJ0qZtyXdrv0bdEae7EKMxoy51ReaAJL4wGYF4zAPeTQvDY5OlpnIxCmDg8uOoHHwncRg37GoJ0Fa25ZGnvQGXDzz0CVwXRm4Nvnnp6Y5OhZiINbxTlnK4pSEfGhoPd6//ZNfX5q6r/6YevW/86Pd9W81OC2z2lSmXA34gzjEeZTPVdeypH2SYeZBIqFeKtWOqBUFpw25F6+vfYUmDBYwE2bVA3fJEtwW1r3aPsS49NvInhXcmwye2oF9kCWcXJrwa4AMHTefWz/wHivGJ9No3HnIlvE2j1EBwqkBZqbxoS27FVagPjGuiiVd3AOjYAt4wfZl9+bOIYbgfgCWwWDU9lpDHvuXgPG2qfhvtpwY2e/YKxQzqQ18Q7Q9GwgkBeMdELvma94GtriCRObFeldg++tqJQ3t2Kbw2+tqJQ3t2K30DwtRXF91q9Nqzt8b1oXG4PvtYKei96+v/H1I1i6pOCp9bp7kYRyVzwsimOOwGU4UGGwAxlKBPlgDk1hJfqoVpCK1ZSbKo2nRX3AmrYpGvVYSffjGHQnvFbV9XGehvEsEqtQYxCBA5YBhylgmNHm7x9jKGKzFV2DYMgdD2qgAaUrgEcNEUVbfVCXBG/GfRN4DdqgUpytWdNrWBZ2jrFBYnTHKIkcj8ouNDHy+CiOHDiq03k4eAfgnBDaXsCo7B9PHolcV/ChOA8BwyMEYoPH8ZtAvTue30UVDtI98bZYMzo4wF0lje15QCe0YucE+T1EPo9up3a1Jc0n6JIXw9DXtFZ7gvKAH6aQrsSuiyAIvB4ISQp6snw7ghMKBGf0ktp3RL3RvGQgvsXnHONDIzQvRYZHoZk7vdTFJlpkZcd4hQtjFOEVocpcNCOUAExshTDk9p29vC6QwcXfSAospbicykWmrwPIOIxA4OVcOhw95URDR2TFFiW8u6AKAjAhB56pAjTpgEO/YecGEthPrDAI7B16J6Bexw+7m5ItG5x2BZM/QggBoYNV4JJKxo27pvomAn7xodt0bCteNjW9mFbr8WwLRr2c3rGvfnAHWbo3FePbGxfAHjJesZL9YJA7MTxmPiMbC/r2kM0sNeD0Rnocw7Qo+oCD9HXm+HIMCMV+0jwIvhoZJjUYU7eHeDDgYHqkdk0MgtG5urgMi4E6WgFVD/07i0ouhuXQLVEl59B3pZDUkT+6WEp1HCoHvnAJiN9FP7gRrmX20e2/iG44VJC+qAYDkzQgzj9GAKXFOBG/iNQWzH3oICQdt67PwHVew/sgJrhoLDKogMIoolMlcXIOQ6gPGpVHDQo1l498hhcP2ySIQ06BU7jV3rTmBoGheiaafQyr2Km8DymQUec8HaBFI6H9phE1zcpQ9AhGGXixbBmNFOYRCAB9+4IDJwprmfK0qYkdD5FM/XZXuctnCeT9uv1AQdBYSjw2wVRLh5OJ11SkATCJdSFUEW4OD0QyoFzNH+1zh52poZguZ0YQtsVzubSipa01pIbnrD0qHkUbtFS0QvHot5+h5PAkLq30T/FvSI4cvsMkzOOgTFfexzut1+/E3aEgOmHVciCL19/fALcTeAyk0iJA/0mSAbzGLsVjj4eY/uBeYPlYUkLJ9/T9bAfkP7W4xNUD28KpGYG6ZWIXFreW7QtCGDXI/Cw+7H5uJ56cALdyVzmfobWi+J+SpnaL2h9ThqfeyhwjsvHPoU/KBKpDs6nAkMDwWMR8ZfIvieqsiRfACPNuyOxWylNPI8NZ7ak6d2DUY65CMtozeNyhpgnVzk+3nds0/Wx+ubj/1f19Re3U/ePTc87krH/Y5LxL3Fu6m4Zw7jxFGKQUHZHoYRxQpy2nNDGNSjcBU1zZAiYYelzGKbP0S6M1RBgCCISSnyLUBIJbmZQ3x7toczYFWXGK6IMQMJtucL7CT0EQhJ0FYdC7eBIekjCjQXdkUc401tJ7DRqzhzk7S4ExPGg0x3R0SGDKmjqAVMEY0opFyzEmII4URCED/i0QRVTELpsH+V0mbbeUEBvNfzqlN5VYFs7AXw87geCNhE0xapqFwdt+pnUDYgPi7Jp+OgVwWzFesXcD8UiVb6ySN3fE6kD4HW7vkjdH2iBKA6C3gM3Jm8TqTuIPtgUdB/jsUWIvxymaSJy7k+S255pNYmvkZNSUNTax6BLSD0UuHQYjLTDLO3dhnY7DozufEP3D1dfuk6Fc8mOYMT0Fvok6lSwIeEgVf4dvZtEVAkqAyIQqVIrEDFVosFIF1y7Z7lY14GDHsQto2sCNoDEBIRWrp+koBHYvSKt0YPI8JUQ3WUe9IFJc/7EkJ8IeUnqhj0AUbwi7kWY18Rrxhc/mBgkfCpHYY/5V4zVfJf2g/f0S49jUPB2BQUXtegGljS8u3ucyYq1F/UI2puGdxZinN+jOx55XXWsjTEf+bOFN0yWK+oyQntuQKWczOl5NXATikfHpGJb0b2P81doPGkaJwH02g57UQ1JI/R0wp2I3JichIa+11577EwMAiC/m4GTmdV+ty9tbBLzlAL8bm/nxrq+rkHg7wrBTx/AxqcpOe5/nswhB4JfNdsSKj6ZE3jYE27bAh+A4d7NxTptFYIa8e3HcYsorEIKCRAOEiNfZIdBp8A9I1IvqMZXH5/A8vAUyADdk7APx+mcDu5GGqgAOwkF2IkUYL2jQLtdP3g0uf7dN5GQSKLMi34DwFD8TXw/ueIBWQbe1jVAARTjzC0j75qjlacZmOTzp+inBukGDNCM6ECpK3neZe5ExF2o/AkK+o14gXcyT7urODbc8NA/8kaT7+Xccf1jSBvP4n4C8us4MkfxfBzORQwZlhXFKLhlztclnf00F5TplX0uOR38XgBy0svJSfgsDrlHcH3qQWpmJo15il3FTQzQztEgheEdiohQMzB2UHNNYn1S1JAFmaH3M8w9EEto2C4XRK/apw4uMe/dzN3P+bp2xMHUMpxaj04Fua/H1UQMkit+R7REOCod7utZvMRMoKNnH4dfOuCSDfWbjLm36V/jgCG1t0wHVd+fbH1yW9t7cfLp7AKEHuN+lcfcAXgFTYBJ/nPwe2fsPUPuQEQRSA/vZK53mrn9jHYXu4q3acntYZEMxpkAMPtwh/ag+N4HJmq003BQvAhpFn96PvHp2/TpFj1wTkzG1AQQNSwNz8m7N+2wHDxNjZraTrpu1Jc35t0Mdf/n9jE3j1bkB8g29HJuP1MffmyTqQPq049tMhhLH1Mbj28yNSrxNavbUY435rrsAJNwlPMAfoBdRY78LE0QJvKQAqwei2oeFPDLB1H2vUx3Rjne21ybSeb9FKPnCHNd0O/4MfaxpyawRdqacyDleGXXYl6duQbLuwa44Jnr/lqGM/qp1I9m4HdmGfMYPE3G2C2dhbmZlpxbarYuyU630e52ZKMrN3Ny+kJXtpaa9Mp0ieULXbk8K6eXLyw1O8dk93xLLrSW5FxHbu7BGpBDRSGP7Wk3ls61qGGdvxlErdC32eW2pE4sz0Jj57rn5Sbz6GNrqfmqqh1tX+h0Z45WxscKtWqx2irONkqVcqFWKk/PTjdmiuVmbbxWbdZa5cboWLVwdGFuut1oXz7a6TaPdtozRzuXO0e759utRrO+sDzTWDi6tFyn986RdufHbn5mud2i9qHHR+e6rfZfpzn8RVlsr924WF9rzRxdXG7GDR5ttlY6R5sL9MOzhwtHSkeKRSwd5WHR7uLK0YvTF+YWmkfhN3Jn6NdvsdzC3DQU8YXHMoyxMcZYP2Px+/i292Pb3t+07b3EGNvHGHsL99hpxth/tBgLGP2XFh6mzzLGBrblPcAYu4kxprrd1uJKt9WU3WU5tzTXnWsszN3fkjQxh3Gi5MXzcwstOdcFuptuzS2dk8328spKq8nY7zOPvZcxNs8YyzHGBGMsq+nf0E9Lw3b0e4oxZuv3NGM4Dk9/c3V9aKuPMbb4c//Ddw69409mLql//K7VoQ9++eZffc9Pl3/a/vY9lz738N996df+vNHptNrdueUlOduYW2g1j8mVDvT/LT8l8flGuTi3VF9ea7XPtxpNxv4999hPMsa+YBLs3eq/uVe/cWmH+v/MpD4mx5XfNn4Y00xjpTEz170soYnZheWLfzW2J7nH3sEY269xUygUioVSYbRQLlQK1UKtMFYYLxaKxWKpOFosFyvFarFWHCuOlwqlYqlUGi2VS5VStVQrjZXGRwujxdHS6OhoebQyWh2tjY6NjpcL5WK5VB4tl8uVcrVcK4+VxyuFSrFSqoxWypVKpVqpVcYq49VCtVgtVUer5WqlWq3WqmPV8VqhVqyVaqO1cq1Sq9ZqtbHa+FhhrDhWGhsdK49VxqpjtbGxsfHxwnhxvDQ+Ol4er4xXx2vjY+PjDaIloKTm3NpcsyWnL8v7W+1l9mLRXGostlguu9is3H2h0z1yEX6X+xvF97BW/e7Ta3d1CvXT3ds74eqlsXunLt83/Y7x+li0ouLE1Onu8Up97G369XT3dPd4rT7audyp3nO5020tTlbpy+2d1vQdM43W8j3tS5fro51uk7+xft/pmgZeLPR+drpYO1+ttcYKs4VWozFTnKmUmpPCr993ugw/Yl2GX7Uu1s5XapXxQqvRbE23Zsenm61JI1FksTFTrJ0fn25Nl5oz06XabK3arLYmzQNRkSr95jWAKtemq+XG7GhzplksTTcmrWvaqTSazdrYeLlUKpVnZsZqk6lj9ftOF4sJPlIc1T+nfWK5vdjoVulnuaH5xkytOdsqTldmq9O1Ym3SrgB2AVG3d869++RK5czqpdLpy/UyMM3RTrddmVm+sNQtlpvLdUzVZ8432h3njVDt9s7snbOXq5NTxcrcnfVavV5HllochVRzIfqN7vTMazmDZfiR72vnL/OKSKDfGS/Wzo+VGs2xRqXWmq6WmjOV6qT7Fupgo74zGmYXu9itcWqp22oXSyuNZh1+Ofxcu7GQPZignfH4N8+LtfOt6fL0TLU2PT49XRkttcYncwcI37uAqVxsz3VbfR/iOJhiffjOe4dJaBxZa80cOfLO1gxk3Tt88t7h4QulwnCjQ49Emc5Ka6Y+215erIOkqy+1Ot1W88iRe1ZaMyfay4tT3Vb7NOZRS7cPT2FrJ+8dHo9rAakUphut0eLseKk6PTY225jML76W01gcbc51VpY7rfrM+QtL89dOqHfutQQ3tthabCzMnVu6FlD/wms6rtKFpYW5pfndhrWn/ZpCG5tbAvFUX2i0z+2KypvGr7PIK6gVVVDPK1bxpY4vdZB3A2+Fqu/ujNWh+q50O7p0YXF0bnFl8XT3+Hi99ra5zspC4zJ8ufl+4F9YbnmlMzZ7YWkGhGllbnFloVMdQwqHNJIxFDtyZHmlc+RIVPDIkRNLUEbFJD+73Mbn8N0nhk9AbnmmsbBQrJ0vTBdHW5WxYrVUbjRmZ2uTt/yXg10tjc2Ua5XCaGFserYyNj65972EuPtwniszZ2YKpTN3vXthuV7Byax2uu25pXM4w9V7dHp3vvAu4AvFArIHZMGDaZA8yGde55L4wJd9aRATmBzKEd/XbHD/I5y6VCcGtJ1KxlcaS3Mz83NL54qjmKyfbyw1F1rt093j5XpRMz/q6jsalxeWG83dSAqrF0vvgIcuOtZtzLfq08uXbs0RI9a9ev199ftOE0pqWp8uFe5uXHxna2ZqaanVjvFfa7c6rfZaq1RsLtd1ut5YaupeomAcb84WmmOFUqVRKdbGJm8bjZjtdcZKna1fnOuer59fXp6XjRvsULE4O7c01zlfP9devgjkNz4+NjvemClWqrOF2uzs5JGFhbXFI+XxWmWsVAadbHy8Vi6UK1X/XcQDyrvQRgQXiaNYTADfAvPaYQUwxVOn16Dx0is0DsxlbGvrK5OvgGTkQI2Igk8uLE83Fiav7caBdCwDh1+76R2vlZrj47XZ8VahUhtvFCb/zh2vCRqP1+o/ccePOecz5cJ4Y3R8ZqbaqM2UZpqTb1j/W1xjo+da3ZFa/e7Tt79K6IXjlfpBRdgcvQG6qdSrhJ3zk2OAifryUut4rf6Tk9HgdxNtILtwqFqAne4er9bHFXxqdJfbBOmNN6RcNkm5PJTpqeqH3xZP4+haa6ZYjdWl2/X0VRorK62lZr210FpsLXVBRStXi4XmzPR4pdEqFgozzckj73ntOfc4Me5Ot320tqsCmJieMZoe8vTUZ863ZuYLqcXW4sziSvFYT5hfZ4KL1ALq7GNzSzPtVqPTKr1Pi4Dij0GflXqxek+30Z2buafb/msIgNHDN4ABStVnF7vl/nr94vTcUvNca6lOVFS5RKMo/HijeBWrDGYwqc1UT2PfX9FWqO1qKxRLRAor7dbs3KWxPYnBaXNp/Mj1yL8Gj/p069zcUv3C0sW5peaxN+wm5Yhex7DG8vLimwpRwWuJujK9fKnVLJanly/Vl1oX6xeWwNXz5oskTnYc6Uq3XRwFN099bqm+stCYAfHw6uZjx6mYPH5X/acO787Fto0KeNdb7n9V/ZxCOVauV5dXQM2rnsHHFNHAtbgBXjKKzG5yB2H31tlXi6PrcRXNUHaAM3HwemRR1FwR21LW7GLj0uzx68x3xM2LJZqKeqvdXm7ffvAGliYt7rflE5QLJvkk2mvv3hkPjaXLNzbw093jpfqoWrpc615eadXnmtci4sTt1wd0d+sGGjl57NWa/D0Wfseh681EWaei5Tz14N8aywV94O0XCHzpbxb8dkZ56sZwpIX4nW+4XukCFsXO3XUeCv7MjdLSjUnl2cUd8HK6cgOLpxY5b+uR8/bMqeuY2juJ56VOtzSaeKk359bq05fr4BW9exLcQCXwhNb1UhstJJbdzPLK5Xp3uQ5U3qw32u3G5WLtfHO6PFspjVeLM+VquTJemLznJ6+H3VI0Fzi0e8euU7ZU3VKWeEZELz+98benW1QbHViY7yxfR8Dh3+KYZneJAby7qRn4Tp0GhXV8ujEz3203Zlo9BIAS2Tm/3O7W448kr45X69sF1P2Tx6v1n1l6jaHsrswjvPvGrq8VlMrN1mzjwsK2uVxenv9Z5Bg/M3UD0rxIeghm1PRc3N2afPUzd3y8/nOF61HpWIQS3MwkiO9V16kxWolqLC3rIXbOzy3W5zr1C0udbmN6oVVfK9VvcGlML7e7Z9U1qxFmKl6Ny9Nha6ZbR2nfbsGmQKM6UxkrztZmStOjhUqlOcl+Nr3SXm5emGm1O8JZaCydu9A41+Im+I1ZdoWsmFbz8PRlYWEXbi0eGa8cKciReJdSlgql6uFC+XCxfDB1sbHQvtBJFY6UCkdG/297s/F7');
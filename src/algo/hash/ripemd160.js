@@ -34,6 +34,7 @@ import { loadWasm } from '../../utils/wasm-utils';
 export class RIPEMD160Algo extends Hasher {
   static wasm = null;
   static outputSize = 160 / 8;
+  static algorithmId = 21;
 
   static async loadWasm() {
     if (RIPEMD160Algo.wasm) {
@@ -142,6 +143,56 @@ export class RIPEMD160Algo extends Hasher {
     return hash;
   }
 
+  _hashBytes(bytes) {
+    if (!RIPEMD160Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'RIPEMD160Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(ripemd160Wasm(RIPEMD160Algo.wasm).hashBytes(bytes), (word) => word | 0));
+  }
+
+  _hmacBytes(key, message) {
+    if (!RIPEMD160Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'RIPEMD160Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(ripemd160Wasm(RIPEMD160Algo.wasm).hmacBytes(key, message), (word) => word | 0));
+  }
+
+  _pbkdf2Bytes(password, salt, iterations, dkLen) {
+    if (!RIPEMD160Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'RIPEMD160Algo.loadWasm\' should be called first');
+    }
+    const derivedKeyWords = ripemd160Wasm(RIPEMD160Algo.wasm).pbkdf2Bytes(password, salt, iterations, dkLen);
+
+    return new WordArray(Array.from(derivedKeyWords), dkLen);
+  }
+
+  _hkdfBytes(ikm, salt, info, length) {
+    if (!RIPEMD160Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'RIPEMD160Algo.loadWasm\' should be called first');
+    }
+    const okmWords = ripemd160Wasm(RIPEMD160Algo.wasm).hkdfBytes(ikm, salt, info, length);
+
+    return new WordArray(Array.from(okmWords), length);
+  }
+
+  _contextWasm() {
+    if (!RIPEMD160Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'RIPEMD160Algo.loadWasm\' should be called first');
+    }
+
+    return ripemd160Wasm(RIPEMD160Algo.wasm);
+  }
+
+  _getContextState() {
+    return Uint32Array.from(this._hash.words);
+  }
+
+  _setContextState(state) {
+    this._hash = new WordArray(Array.from(state));
+  }
+
   clone() {
     const clone = super.clone.call(this);
     clone._hash = this._hash.clone();
@@ -1,4 +1,12 @@
 export function ripemd160Wasm(wasm) {
+  let cachegetUint8Memory0 = null;
+  function getUint8Memory0() {
+    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
+      cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
+    }
+    return cachegetUint8Memory0;
+  }
+
   let cachegetUint32Memory0 = null;
   function getUint32Memory0() {
     if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
@@ -6,6 +14,18 @@ export function ripemd160Wasm(wasm) {
     }
     return cachegetUint32Memory0;
   }
+
+  let cachegetInt32Memory0 = null;
+  function getInt32Memory0() {
+    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
+      cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
+    }
+    return cachegetInt32Memory0;
+  }
+
+  function getArrayU32FromWasm0(ptr, len) {
+    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
+  }
   
   let WASM_VECTOR_LEN = 0;
   
@@ -15,6 +35,14 @@ export function ripemd160Wasm(wasm) {
     WASM_VECTOR_LEN = arg.length;
     return ptr;
   }
+
+  function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1);
+    getUint8Memory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+  }
+
   /**
   * @param {number} nWordsReady
   * @param {number} blockSize
@@ -34,7 +62,162 @@ export function ripemd160Wasm(wasm) {
     }
   }
 
+  /**
+   * @param {number} algorithmId
+   * @param {Uint32Array} parameters
+   * @param {Uint32Array} H
+   * @param {Uint32Array} bufferWords
+   * @param {number} bufferSigBytes
+   * @param {number} nBytesTotalHigh
+   * @param {number} nBytesTotalLow
+   * @returns {Uint32Array}
+   */
+  function exportContext(algorithmId, parameters, H, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(parameters, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(H, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(bufferWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.exportContext(retptr, algorithmId, ptr0, len0, ptr1, len1, ptr2, len2, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v3 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v3;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {number} algorithmId
+   * @param {Uint32Array} parameters
+   * @param {Uint32Array} context
+   * @returns {Uint32Array}
+   */
+  function importContext(algorithmId, parameters, context) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(parameters, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(context, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.importContext(retptr, algorithmId, ptr0, len0, ptr1, len1);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} message
+   * @returns {Uint32Array}
+   */
+  function hashBytes(message) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(message, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      wasm.hashBytes(retptr, ptr0, len0);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v1 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v1;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} key
+   * @param {Uint8Array} message
+   * @returns {Uint32Array}
+   */
+  function hmacBytes(key, message) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(key, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(message, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.hmacBytes(retptr, ptr0, len0, ptr1, len1);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} password
+   * @param {Uint8Array} salt
+   * @param {number} iterations
+   * @param {number} dkLen
+   * @returns {Uint32Array}
+   */
+  function pbkdf2Bytes(password, salt, iterations, dkLen) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(password, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(salt, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.pbkdf2Bytes(retptr, ptr0, len0, ptr1, len1, iterations, dkLen);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} ikm
+   * @param {Uint8Array} salt
+   * @param {Uint8Array} info
+   * @param {number} length
+   * @returns {Uint32Array}
+   */
+  function hkdfBytes(ikm, salt, info, length) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(ikm, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(salt, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray8ToWasm0(info, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.hkdfBytes(retptr, ptr0, len0, ptr1, len1, ptr2, len2, length);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v3 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v3;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
   return {
-    doProcess: doProcess
+    doProcess: doProcess,
+    exportContext: exportContext,
+    importContext: importContext,
+    hashBytes: hashBytes,
+    hmacBytes: hmacBytes,
+    pbkdf2Bytes: pbkdf2Bytes,
+    hkdfBytes: hkdfBytes
   };
 }
@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJy1XG+MVcd1n5l773tv977HXjC2FxabedckWZyA3/59u8R1GJKFbCghqqLK38waNjb3YWD/eMG1411sbIODGz5QlUiooilVUMsHWtHGkfxhq/qDFbkpbS3FqvwBNSihqhOhxqlI6oSe35mZ9+4ui5OWlNV79547c8+cf3PmnDPzEGNTT0khhDTxLjU7K/AlZ3dJfAWz7l7wrcDT53cVZvmf2BXai6Au6nnqGGwdUcUwkFJI2S7CNhHSv1IQiSiKZEBXESr6hCJSSkihivShOyWlwhWXQqEQijCSB+WKFVEgxfJCG41p5ubmRVx8OCw8Nf7UgclnlGjbc+BLkwd2j09NiQ3LH3vs0ON79+95Ynz/Y0+N7dt3YLfQHblnX5kcHxefbLtXCiPj5Z/77OrSqnWVu+976AGzeXDgs1va354d+ZFWCbH2gDBJI43XCbVZbdbC/EzuDDcbkTySSi1NKXnQrMR3ZhK+NMyNm89m5tCE+WqWKiPsY6OmG+iv1fYuLXZUFCEKM7RUQ3PjpXnRrcRIRRqhw0Y1wEhqFoCZU6MVsdqspdubN28WtxNAtwX6lGaeSEVjxsgJwiWnG+aRLE4jGsgcPTYvMkKYinCzDnWUdE9Xi0ZUZcAcJPVU6ZDQaro06D7YSffUrFUaVAR1DMpBTEhXMhKlC9RR6KK5b8aEE8Snxb2tCw1d1QK1SBpAi4qIdYGGLFRFWcVaVlSMUY3U0XTyKc/mREqcBkRGz5MLiaWncUD0kZRAo9ThTrolCgs6GJ1IaShJIwYYE69/IuUB5QiGOXqccacksp0kN2qnF3d2VUjkeJY8XI3olZjYD6xCSIfok/TpSAfJeno8p/AMUDuoD0wwk0oTTJsPie6sWiCFfGA5ILUS3ulUToDZblUqy5jboN/JQSV0IWZao0FVomd0KRPigr3IQX5Bg8owSz5Gd2RLAWmfhjWJNQxrNM9mWmzvslbCNkFWge8mISQekClmiKRgYgTq9qLYUSlqRRblpA7xFm8VukzqbHFaQp7h5oDtw5kFqT0YTa24wRoU4OUeNwVbgGC9WEcrkRNrAWJt6oW0FNJsWSjSojlhm6vRraIlteoiZBtAa8rMTrGlZykpZjoV5ujLjoWCKTk7FRYvmSYJgkDIm4x8MK8g87wODk2QjmJQSKiSj+lC8giZfJHGocGFWUsWSvYKDNMTJDDIdsGIgRtRLhxR+hFlbkTPGI2r3LgBywffD5NpAznLOyctzFvBil1SWDlLxOQSRBwssZi3RKKCLbEYV+nLWqLwlli0FyK3FDNa2Kbg4SEuskyWSGGRVRdh1cVFVl1sWfUtuAK6W8LUKzJ21rzgLz+JR8nFHmuB2ysh3JKOyb6BQjRIPux0F/he0gutCmHyYHK/jvFqaaQS465ULZtToIjuw7Sd76k9SyVoPU0Qf9nRJIS3vQstJ483PfMZuq0Knh/9urQVHozw8lwgE20qR7HnIb3Y6UBsoMUILxP0sXf8DEZGFqhY3jSpFE8pJz4mjkXKXekBOSrF1mNfaI/NRU/2iCYFj05WYMiEcxguPdmky9twk0o7Uxw/qdoRzpL909MwoyUpMiIm58vIWhgJYQn+B2OXdHtW9UKAsgK2d+oHy3eSgANbMPZW0rQfs9rWfDvgN4Ru2xFacbZtJx/ekiepmqZJG5rSotVw2tEwe0ld0D1gTRYgad5pt77qtmy0K62QVJbpiu5gGcJQInaegUVSLdjOVcVEgT+s4vy+JJ3RfEwgchgemXR7BgeoC1lGQzXSCHJIdGQwsawZBg7rSofVYpfolTyMaQMf08gy9L8A2c7NzUGpTGIfy2M9SWa5XgbLPWfBOrUv08vpYs6DkDJIOou7dtydYcpxh/56Gd1BngZ2A4dE8zGkSUremdaR7RUWZGUr+X666LYGiQyCfTj5OPW1Sx7g9nIpvsXlqNs4GrWUo1FwNEq3WUfTBq/QBmeCCxwNmyXsAItJydozwSGJGRRDEwUrRngfq5UqdERCbGRpxBODnI9znjRsA/qn8CZrVEPdnKvb3JSDgYmttOpgEFocjdxWiVz4U3Cc08LF7smUmyuI8+zWnzP56nb+vEDici49JCsBEbSUkOWVI2v18I3WmrwvIy+Y8lwuImaDHZGl2MXHWp11Ht71tOYrLxbeC7g3856gYCcvC5aDt5xs/eA0f08257bIzaRUID6yBEiYPT0KPA2a7Z7CopzdO0U5u3e+rsq9yO6Ftfwl7N47SisYdoJHbxUMOTkrFOKjSKIVZKuh0xjBSXurOfwtWGzRWiyvY0Wr8iJbrBuGgrJLoPTm2sFfOyWPUYjApLD0ACBoYGbzLacBnHAtJ/LdzgI45YBT+W7nAZxxwJl8t4sAzjngXL7b6wAuOOBCvts8gEsOuJTv9haANxzwRr7bm/mWywDedMDbeeBdAG874J08cAXAOw54Lw9cA/CeA67mgesArjrg/TxwA8D7DvggD8y9Mm+fAPjw5RxwDC0fOuAkgKOvOP3kW04DOOFaTuS7nQVwygGn8t3OAzjjgDP5bhcBnHPAuXy31wFccMCFfLd5AJcccCnf7S0AbzjgjXy3ywDedMCb+W7vAnjbAW/nu10B8I4D3sl3uwbgPQe8l+92HcBVB1zNd7sB4H0HvJ/vNncMWnDAB/luH76Sa/mIRTW/ZOcdF6/L1ncF8F3se2jJFpl1Yr8l14WgJMiHpDsq4o5cNsWllEmvI6rjn3TIrtkHaKj2Rtpm6wz+T3NOxhltEYkwxRPbuxDWFuG5KJXL8NAGd53TtCSVponiD4sTk7ifMXO/CibornNm0kbwRlImq0tGbuU8vYhlnxj+PyBSFpG6Y0SBRRTcMaLQIgrvGFFkEUV3jKhgERXuGFHRIireMaKSRVS6Y0RtFlHbHSNqt4ja7xhRbBHFd4yobBGV7xhRxSKq3DGiZRbRsjtG1GERddwhorZkmW4z8ypLOug6F2VJAriYJct1NFIpIEnaWonoonZQBB7R5KZ8OTLkNBX50U7budphkVRXWSTV1bqt2qXDbpWka6or9ZpqCUA5vY8ypBDE3V+9V99PHITI4tdW79ZrqwmAUqqr7VpXY31f9R7KhjqrZX1PtaLj6grdXr1LJ9RyN/UoUPd7q/fo5ZQllgjXSsLVUV1pi2rkbLsQiRaztEMHlLnrsklG07srkm70aEXRpTZKfNylK2Z2alK3T5nv/eIfv/sHGTXMi52VcLVrmtDtujKRmfd+/pMbh7NyGGtCaN6fmxdJJyUL7fquKV2ZQpGRbicoLWvHS5n5xk+PvxNlyEgqaMEgU+ZPv/+j/ypkcbXUpHKVo3K5p5J8eifpsQN6RNs9mS5lejXdHiIZK73cNyQZifRWhmK9bEqvmGqxsYzWJrAR6xVE1r//+G/+pchs0IjmQ8/GCspaIYZ4yvzwj199iQgnhgjVhGuhN89989v/Zhmi7tx52ZT56S/n/wMMJcRh+yHSRoe+NyNddsGBd+nVuKzWq3BZpVfi0kEvU8/ltPonmV4JvpBvJXoZWUmFWOo0l8W2Cudhsb4/03fT8k2Goe/VazJ6BSE9ZWj6vkxXMgT2IXGoM5IT1mTKkvTaTC/PYNkSgqXwf5RLnGSW7bREd8QmpL/3HOcmoL+3PaDo7w0P0DpvLniAwhlzxkurSKI74YBfFmRhltKaz3AeVk8VUne/3idDFeESZQZ7R7iYSaskSktB8jup8PntNpS3XXYbbKOp1SrgIbGRycc7Yn6VAg1bkg+WTnzDZuIbbeMaeGhFFi2d+AbNxJcolEmNS+k8XtlX9nwYpG0mnaO4VdBamAAqW4yzVa4Ol0CLWxLoXDcXUzXFsZULUPR6PQ2oHyWDm5sMS8uwbDIsF2b6juHbZPothsEo5ac5FeSkjvS2Wdlr1f1iDiRZGDuxxYMA1pYvP2LnSNyycyS2d6EOmStJNnN4imMXRssU66pWAZX0CfVQwJxlqULg6mty8paanPQ1OblUTc7V9qwUV5fxOCk3oFU3lmd1Bz3i8HmWBAAr5I0syeJrt/n0JffGrMv8aZSkvLoj5t6U1gettF4u2nsJptMAab3Mp/Vk6Lw4yRjbGTaDD3IleVwCVKm/E8nSrPkkLXBv2pmW0nI1kQbYyuKJFppzL0J3WLEmuqoUNNstOZaGYYsR5E6KSRdbXCtWd9ZFDmY7ppEpTbi6dQk5QliNUJLYIDT1QMDbxWWpxjIlFZdsLMoQ5SNRJaVVA9hsaOQMoaBLhrsIs5g8PrFm1mA48n+o9VS50tVZbWNdBhAr9pR0Gym/REvqckk2AxdFc0syPvC/lXXieGEEnAHRzRpaeZcHwj5dU7WNVd4dVLMBb5TpiF5f3SQH86c1EJMQ62jUcsEkthHNNZ6zJARaj4WRm4RuEiAWyMo5EiexAsptLYkJqf53QiosEFJxkZBQZip8hJDEkqIRVjTK4gyw1+kK4xAMHBSZLrY+UH9q4YZ50sgkmgrvjbRbGbRxikrWH3TEueEs/XbE+K8Lsmh3ADH3Q+wCf6QXCRd4kQC0bO+iOU6iToiI/EajdBuNAS9MhJrcaEQLk1q4SUPPeUWKsGtMBHpXspXCCdXyxOSAkxqSVFRbdYQ1NRglmC4N0lqB3QHJhdwy6YrGQtmXH/IcJMqwjUt3DXNkrjTaRcFkaF2Z4tUMe3dr3WYPeT0BNWJvmEaCr4sa5BgP8lKBBV4mD9u6OFaL6YwgAWFjF8hvBbkysy3Y5LdBoMDA7wct2CmSdquaWxBJk0gaqfL7oBgs4H1nXKvUqtx3gUKp5noWobQd7Gh2V667suh86eKY306z62J+tWlhoVhObV5IhjsAUCXbROQd8VzBQJAkb962qFuvOQcIzfO01IWuUhv5hdqOqeCcvZdTfufV7reW4GNLC/Zbl66WR60t+FuYdt8FS2gRkXtY4VWddWy39iUsxhw2z8Eau6DyEBpK7iLbVTR1MYuoZTV9PRL/dyhjVFdqJJV1xIZZl5lTR+bt7psJNolurbbM4V9YD0p231hTC1067SVxpo/lJm0f4YDGno0IR2wIBr8F31fFkQ1atg5nxC1N/7RYjdjRhLa7LUVrHXDJG/frnC8J7e4Iy5SPABBAHgPbjEjaiW9ckESRz4qw6ZgLZ+BYqF80ncZmV2YiDF0tOsJy44sF44v8+EHMJtcOn7Em2yAEy4W4CbP1SmzRxyGb0HQ6Z8rMlgx29dyGIFyDlYp10IroK2Bvu5SlZVhVB8LVMvsywgA/KDj7HFQrrdApYrdnS3IIywsRCoew0ERYcAjZsbbFFtMauxHVqTmBxEs0s1vSpZchWuVlCovUJFWkEBzoaJZdOGoXB5aY7LbFqukMxbgu3lDlTjkhjmC707JlaijtifgfQhnOckzb9KS3CfElIg+5VIivbgnxfQRMkxYhvvz/DPHhyDng4dE43GkG93bi/wbBvfzNgnt5++AeyJeIrREscryPpTYX7yvLvWpyv8hXOe5v46sWxPtyYbwvWypAqCq9uKlfO8MBH8ZZKnQlZ67VdKo+KnRVPnRVi0JXkBr/p5LBbHMplqYNR9HsBjjF65z+iERUQz5JZDOD2BbVdfBrQoVgQaigQBNChQZOq0EftzmYlAbNwxfPZeSjFVyRSj6DOcxhDmTPJ5MoOmKV6BBTSDYmqFMzX+FTR8rY+rtoIEzJsMASJby76+dISOuD4JVU8Eoq3aaysDlcqDnFyFh1MumFd0aC7WQUZDvwhNccwVfGwwst3wZu4YEik0eAYXX8r5C5PQSI9UM4B0XM3RQ7/OGHwPvsrWwLpOlkJbdUObJHMMrbjLDygIKtTUJY+4SS1SzMKOE8FSENGzDdfmbCzMnJTaKDoRUz5jog3pE2hZlm8zJ+UG49qJiQF+ocimafK0uiqJjA6qb1RsX3mec3jMLhH8ck+5KUA7KgoeWo5VmD55DdWtzkFDOQGu5ajSWaHliXwjsfRsTfViqyRyxpdQ7p0Sc4axdbTn5PfJltpkh0BVgJ2jLk/gFZGTa30WEuLeLy/Fe/RQnczZvkIa+K/WnByGlzg/K37CEhPi0g++cznL6j1n0N229RD0VIEcVvufl333/o93Wxag+4Fb9Fw/1AfIF93PMZdnSIioyfE9bmiMFtMdvzhu1fXIwhWNTPmsJsvkMtIxOBa3ecm09o1UiQkWBlic8pGdG6skXUadkUfFZUts6KytZZUVIhah0zT9BE4rOiqnlWVPFC2TwOiMi/ecxwc/PcYGSdVgFOK7CRjkq6+ZRGXUusT0FVwd/gBciZPRkn3nwSAW/bIIhW2eYJ0pSLzQqH/CKcHo1w9tQihOixX5+6qgw765J11r5Go9n7d3bgEJ9wfjrAgh/6zng1/lsrJWRnoV16AXBhX5ArhEtbmdmzUUGXXR5QVqlaRwoaOLcrp+yDpV8u4GSIBwoDbHTF1CMkTAPLFU/kJPNBIeZiheNe4YpmFDcKT86apgZSZc+vIA4yPJWIwq1dmV3vpfXvykOCJe71RKsWcPGqxSFBCHEI1NKVP8uS2Jc7W2d7OXAE5c0TZmiOD8jKLB8v8uzkT0CgHMpVowLxriC9ElYtZNeBDTlDH5iS+VV5MyCDiJD8XuKcgjDxlztbFn9TqbDpBuBkkZpCZYyWhn/3iDv6yNJ3VgjjJ/PwjWlkl02s9BxTmm5MpATxaz3ALNcZR3we1gHfIchfCUfVmSURDleXMnZQhHLQRunrg1Ldui2KEgPu4RrBpz3HF663cTEOctkmhFbIRQs0hCmbMNmYSogSt5/mqU2IO+uMhYYMHEph3nwByUmoXZxnY8n4WgC393VKUy7Lunod7uprBFwhIVwE8CIyGGo5D+AP6T6pq7O4f4Huz1LDaQCvEDBPwEkALxFwkoBjAMK6msP1ZXqo6+qGdGgu0wDXpXv1OvW+BuA1Amp1dQX3J4CTer0LYM6RcVk6AjH0WwCOuNHmpRsadLzukV0k4KJHAKbOS0f7dQLOAjhmmTqN++OOmJOeTBBwTDo5YMw5TwCoOexwXaH7g+51MPYk3ZfrapeTLHH0KN2+6kj4kqONBv28lx093uyGxPhDjn/opOZeBSvdjl4wrJ1UwQhNyS1H3eCJI4pGLTnGE9jZVdb0cYeJNT1nu7GiX3N8nM9pixV9zI3Bij7iBmFFv+oFpBy5IH3OtwDZjaZQvbpfcFxdy6uL9d3hVP2io5BVfdRS/5ZXFOQ37xsue023OyWfsGSfz+uIdfyyk9lpb3FXvJKPWBGwjr/umJvzDdqq+Kh7+6AjAiQ/mdPWLscK7ONRJ1bQ9iXHDZj/vKMCnG12z+edpo+4d2uOhjmn6dccw9qxM+80fdySBkUXrZJfciyJ+Bes5Zfcm835DJS3qtnL/6xvmfcT+mX3TnNCX/QT2k+RuTyCG3n9XJc5Cq55C7ji9XzcoX5X5kR4OQ+85fU37+f1K04zr+et5qLMyei8R9DU+WsO22nfctLr3M+aY36cy35iexkcdg3oddBp5aTTuvd1u3LO61HX/6TT+jGH1M/vK25+e96HclTUcpx35wjS7l3g7HRs493E0QMaSt5d53T/tfwM9479olcqpHhe5Ug86x37FT/HT+Tn+Im5nDM/5ieIRz3nde/fuS5z71zLT70rHriS1/0Vr/tjjuq38hPxVt2/5vlp2ph0uvfu8mx+nNN5uzzpUbMt5z1TU/cXne5fdvo46B2baOke97tynvRR12fO6f7ruRl/3BG12fUBGUM51ms5zrtz+HVOCJ05W05y45Zya4GIX3XppLYJj6xG5jriuOsu3i5wqkjRoXndbZDhrJZ5wwMZB0HcaOzBtDd8nZZD3IIRn8fBi+2TFQQvAYJ8jp07zdXmFtrs71L4eJVLD6hSKj7JByre96WKcNZce8kdzeWimd3bEcAZIiAKbLkOcctKeuyRU5wXUybDB++MpHQ7FnEreVa2msG1jZKI/0rKcJZotkUDE0xUQ86DTMApsHlugoNMJJKp4vQxjbg0aenmyLIJK8CqBQeAAw8XuAiaBja/CDnubW6qZzaO1yqHnDckeAsp5CoGvyfi70ipnPqCdVwGQFpKxI1yCdbWWSWlNLwPEeL3fiRvrpVv7+LkViKRomCTtYesGcEq/5oENVAEuAEXnWP7AyyScXIPR4f20J7/8RGqjXiM4sinbQK0XoUIJwMKU/nQwhx2MZebU3RJ1oj4Lx3p+RibI/ZwxBak+CeAi6LqJWJquSimlvmYWi4VUweLYuqgFVPnw90gF+6+AmL9793415ASVT4ybmS1yhXfFRdHUdOpkLLKYWx34XBWBbs+of0ZIknOVstlsjHmdIjLabw1a3+ehH0imooy9lSgwGFrfKec1BJWuM+cOJ+cdbu+rHTsSQa8WYEKsrlA4uNSkt1AFaiWhNgeoPuYzJwTZFxCFoi5+MI8TzGSdrJCOBtw3dB+ZmH7PimdKkEUG1Gu85rM2J9xmU9lZhnfbVFQa2CuvThvd8bxfB32tkiFSBUDMN7ND8IMOyBWaUkq4i/bwbSfw9YuQlbvQmtIlrQGWD9+Z0QtScQ2Qwr+IolVu7KoLSoFzaKSaGiFolLAVT5XVIJQuPbEZ3xtRcnV+oyIt8P7cR1t7k3+DYj0e6jNHVTswFbk6ph/FsnFW+6BquPCXdZtTX5ZuKRx4wR7jqWXkDjJl4dW7mus3FmCJBBn35Db58AiJ8bKJa2m1MoDS6080BYuneG9syD9i4eJGAh5ZjP/uMwIV/5gVp9DPWd2ayq7bLGcdTCtAYr4yWbdUtq6Zdnm8jBN80nz4RHrFlwBM9dy4gXXYisekGGOwtbOB8wxuVfED3Itwzy3HS4QeFA/BStfHTVqmvi1+1MdMbraokeIUpScNGrSsZrBO9vqKpf+RdxvtbDUykFcWk2EzTsYyloR3y9aM3LE7bK44RP8OI1w2nMcIX6SKvymCC8H6FC1mw/B7cl6ALSo5jZpfq+CuludrYLOuPaC3R8Zea8RxytwrmcVDj7M4CeMa7d2xfeIJXEkwo4/66rlcSx46WA+OkC5xI9S6aYx6Trzz9Ww3Svjkt1rKcZ3LWFS4HuDCI2aIDLidotECx6CG4KJkbick0TsJMmCy+JlFlodbC4L0ineGoBNJG2EzGr38ETzVvrb8oLbmXiVHeGK/4EypmwXhRIiLlre3bXBV1KUvR7OiJwtH/z8T374kz/7i5//ShClW77zjT//o9N/f+IHz6Lp9Df/6Wf//OO33//abBwIyZ/4u5EU9j8MmI+mJnc/tG/v4xsnp2gskYh2+r6XPveJFvxx+qzIwd2L4AcXwZ9aBG9cBPfT564c/AUHh8L+U+4en8BdI/rsHtu3b3yP3rXz4PTeA/s3bXp6/6HJsYPd63fpA/v1mN71xQP7x3fpmbF9T48L6XARb5Njk888NDW95yHwenBs/97djb37nyCOr9D4azAGDbiWrh7G/8FwP10LTKEdv0ifkqOt5J610Qf0x/Qpu2flRX0qORr4f15gKibHDj02M76baNg9dnBs997pZ/SBmfHJr+w7cIj675GWjoeU5TtxvIAnmMTe/XvGD+sDT0/rA1/Rjx94ev+eqU16+slxvW98v947pfXj1ATYdqQnQlySmBZCvENX6KJWq/XUemt9tf7aQG2wVq8N1YZ7aj09Pb09fT39PQM9gz31nqGe4d5ab09vb29fb3/vQO9gb713qHe4r9bX09fb19fX3zfQN9hX7xvqG+6v9ff09/b39ff3D/QP9tf7h/qHB2oDPQO9A30D/QMDA4MD9YGhgeHB2mDPYO9g32D/4MDg4GB9cGhwuF6r99R76331/vpAfbBerw/Vh4dqQz1DvUN9Q/1DA0ODQ/WhoaHhYSJxmIYfJtTD9NowPRLPth2cPLDn6d3jk1OqtG9s/xNPjz0xLsPfe3pqWpQP2v8BY3zPhsefCaJJerb7vp6NA4Mbe3T3wPD4+J6hsd4xDR439PRsqPWsLxwa20fdCrWNPcMba+VDY1NPbXD/S8aK2sbejUM13d2/e2xseKhncGD9/wBxuaey');
+export const wasmBytes = generateWasmBytes('eJztvX9wXMeZGNjd7+fMm8E8kJAEC5DU7wm7Bm2RnBkAMwDttdGUQQqiSGolea3Venc4wAxIPPyeGYKkoxPgjWxzbS2PVXHutHtbKWbjZJmUnDBVTkW5c1VQFSfHXJxEVXEl+sOVZaVciTa7yTK1u4lypVuevu/r9+YBBCiJq83mrmKVOd39+tf3dX8/++sGq7eXOGOM/zrff4axM4xvnOEb7Azf4BtnxMYGg3/4xhljA9LwL984Y25gDn/4xhlrg/L0yzfO2Bu6RCf4xhlnIy5zN5JkZqOb9jY2UrncRjpr1M8YOT9X6NlnZURP3s6YpmW6rulaVqEn22vmstlcLmvbrmuKgrBt27FzjmHaluVlhMmFMIVl2rZpOhnTsk2HCcewLdM0TSFM07AYE6bFV/m+fZbBWa+d4RtcbW5uMc/5hzxnLzWXVlqXBMs3L66utDpPrix3mhc7bCBzrt4+d/RSp9lmj2fOLTTmKN2fObdUn6X0w/n5pXSbR7zVmYXGXJm+fiLTWHm2tTLbbLdZsVCrXZiZX26cbS7X5lrNJvu5famSVrO+uLgyy073pgqXqOwXHkuV1RuNWmel1u7UZxdqqyvzy51miz2beYgzxb3en//8oYeemR556oXPVgY/+dLUFx7M/uff52f+6ZDIbPBXHmfKXwj5EBOT9J9k6o/4dJ5h6od3/tzJPFMsYDnTk0x5USjUxbXAUGxYfOfKZGhO5YV6LLCg1Z07zuk8l4b6aSmUu342ZAvriq9JpnhnQX0uCqwc9+Jh4D/o5NqVydBWPvat3jWgtjc9EBrKWA/FesiUsTaVZ5KpjTb0JaLQUUYnNNTrr22xKGTSUNcgNSxYKIaFG7rH8ly6klVETjLpVoSbE57ECV+/Mnkyb0uWF/H02RTkzynRUZe/pXtxh4WpLq5JYyEQ0g1sA5DiDgs/ZHkGiT5M2MOiP7A0zuxhkQuZtI/DB9WnfCjpC9kA9ZjniELhQbEbbp8d96BJJG3lR5INBI4xKZ0gI92QQTHDYujNHQgcybB3N+6YeTKjWEUwT1pTeZsmA6MMJjABQOwYLqg1LHxpH8szacEU+gAVuYxHOV+yPM+5HiGPScCNJ5m0KqKfQPRDF9cC5u1TtX6PIHURkfChT3/IQeMElaErhXSlmAYohGTSlu5AYEsWuDnmeQrQ9op0LqxVYEd4sIGigEkhDWW0KsKEVBQa0oAeOZTkLI+2qxQdJaTohEwxyRZaa+fCDGwRobeIK0V3izDAvwMYcGCyOelKhxZBTyGTTIElYzMY24YpLIQujQ7DRtADo218/cpkKKaAVIbFjSuTgZHe347iUihjvRNm1qCKdGSmhaNIoCY9TxE4sBegJJ574KTmHghPOtKoCEClAXM3YO7wIyrCBfIEnAEcFZiDwuW4fmUyZ3qK6R2vXsHNAUB+58qkpkYplD8NOzeB2AaIAXEErAvAiopgMajOFGxpApWlQc0oLh0E1SJQM9LSoDpdUJ0gA6BCSehoUDPS6YLqeDIDu8iVTkwrGfpxYpKhHcGSCSMzYN3tgSvqxpgQiAmgNDcCquc5Ad+BxhPGZ21jAzbyPRcJF/oKXGAi6hPSUnx9QVrqsWMDncCB78gh7NCm3R5mpAHYzMB2yUhxGjAbuDITCJnBToW0AyzLGZp8MgCgtKWjHllX5loEtGYfH4DSgYBJR/FO4Eg7zzwPt7zb0rMT0ko2vjTXADRjB0MLmAe0zD1jMibI0Ia5OVJIB0gyMxBYsOTTwF+kC2NmBFb2AQ3IfQLbk0Ja0gHydWUGEi7BAbOSwBm6pMAIBUDoxsLpPPeAb3bZpUvs0gV26RJDS7FLu8suXSBXO94Ctl57F/iiu4Nd2jvZpd1ll3bccZddwnrjlGlCMFKKZdrbWaa7g2Wa21gmLGLCMnmKZaLQsIll2lDb1iwTobWn8pw+9OkPuM+6hOruINSYTN2ETF0kgYRMWUymWHdTTOPIwu/LCW+7vI35kUNEaksn4Ufv8i5Dsokhvcu7HMnewZFsIlORWiP4EfFSbSdTdyeZwmICRebv1gz0Pjod76s3KH8izyRXZqR+eGULWPTf2jQjtbn58pr/aTEp+bAwNekykGV8WOQCi7D65hX87oaZKGQVyALdwJfvA/eGWZ8YqECmO4nvxXz98mtbSOkAMIpeBCJ0omN5IVGqsDzLGV6C6B+QegHIZUABxJd/cGUS1vjOYxVxE8e3KuLbr0Hiff72Gibe3+WXIYEIr4jvYPoapt9IlV/D9HVMfy9V5zqmb2D6+6k6NzD9JqZ/kKrzJqa3MP3DVJ0tTN/E9I9SdW5i+i1M/zhV5y1Mv43pn6TqvJ0qv4XpW5j+vVT6HUy/g+k/TKVvY/o2pt9Lpd/F9LuYfvVXu+lNTG/+KqRfS6UvY/oypr+dSl/F9FVM/0Yq/TqmX8f0dzB9DdNvpMqvYfo6pr+XqnMd0zcw/f1UnRuYfhPTP0jVeRPTW5j+YarOFqZvYvpHqTo3Mf0Wpn+cqvMWpt/G9E9Sdd7G9C1M/16qzi1Mv4PpP0zVeQfTtzH9XqrObcIzpl+90q3zLuH5CuL8SrfOJqYvY/m3r3TrXMb0VUz/RqrOVSQHWxUiEFBMXYxCUQH6S2q/TjUkW5AZ9WIUsghYh1sBzgDapeaCoAqo4YowYcjNTV4RW1cmgRcIaZ9G9ilOkFDLgTa3hjKIr0vrWN7Q/BdpFzqS9vQAEq20ZSYKXM0GiD+QPGWhI9HmSJiA8Lxde+de0hw1Bs1HcDYgU4GhRKE7nRfY3g1YohqnkOJopNyFCJMQ4UjzXohARJ2B7qAzlA/Kj6YHQkcNYNUDwH2CrHSUH0Hu6muTVQEqCLBpma2CEngPrgVy4OproO0NRsgvmXJQNCoT2rvTIIMdKY7lHelIBwTBK2uk4DtSLIRMTx71PjJCSZAJyfy+nLtNkLmKS4aCzCZB5kpbCzKWEmRgZIBcRkHGtCBzJesKMoaSCgQZiq4cYIl+QLrlHI8kG6ALVklmIkQwrQsgsiuTnW6h0AJ6ITCk0NLkWF6nb0DaBEmNIksZa4ofRzht1ND8XtTCoam0I7BtTdLBbI0wEH48sU/AIgB0GYncN/y+nL0NXUKBdZ5Cl0jQZaTQZQQC0IVFsaEdiJShHRiexow0Yrkv6AfMk5wFmxY0P6DgN5CCtShFS8rFHSzdlN6SWFpowuRsLzYuhLbx0PIqnIBZg/52gzpFpR6mkhiJe1kB18kKQEMOm4MBqoeHVmjKdc3N2ADNWbDyDq13CgxNkAKXPiZIJsWCVlRkJgoFUqVNVOlKO6Wk3c2eDI9I53sxtqARWLzUPFlrAqiLAVSTdMXr6YrdTUEqVmzFxJ2HQhon9Sa474XyJFd+NMQk8/75o/zhjceZ2swuhHbXmxT7lNA0Rl3dkM7JPJoeSnSAcFxkg+rOV99z1pR7Ubqq/yLlWhVkPDwCcwSsNemAI8GVxokBoH0gl0zgEG/PYHcZmYHuMt3uMju7E9iddJW4v/ZG3N64v/Zm3N68v/ZW3N66v/Z23N6+v/ZO3N65v/Zu3N69v/aZuH3m/tpn4/bZ+2vvxe29+2ufi9vn7q99Pm6fv7/2PXH7nvtrX4jbF3Zp/4HkbB/9nzc3N9/iVfGmkPbRb21ubt5iVXEDMn9+ExhiVVyHzJXNzU2/Kq5B+pc3Nzev8ap4HTLf2Nzc3OJVcRUyX3u/1lVeFZchY1bFJvx+/f3qsire5bqbt1hV3Oa66W1eFe9A5lc3NzeLVXEL0q9Bn6wq3obMpp7GW1xPEIa+CZmv6tG2uB4a5vFm3NkNXhU34g4AqOtcz/02q4prkLlMQL0O6V/Rk7kaTxMmcJlrPMCYm/EEYDYXdV+3eFWs6uYA2Ln3p5+rijMas8WqeFHaR7+pp/CsnptfFU/FuGNVcFfhkDD+uIYf1qSomwIow3q+ALDUWAVA+t/H9Kt6cF9PqlgVrgbcr+Iq/4ru5V1Tlxer4rapZwMwvGN2V+qWqceC/t82NaZhgLdMPSOY9U1TTxWmvRV/gc7eNGOEwl4y9awAoutmaqmuQaZQFa+bGssww6umBsivisvxRAB3m/EH6Odd4/3hslVx29BzkFXxjpFan1uGhgfw9bahkQ2Te8vQABWr4qYRU8D7q7AVf5BV8aahB4PmNww9DZj0dSO1WNcMDQ6Sg6FxCxO8amiYAAWX48kAgJvxF+jtXaHHhA5uCz0bAOAdEa8Oq4pbMXEiVQiNXVkVb0HaqYqbMfUBhFtJhlXFm04KKzec1JJfd9JrEX+BzOuOni+0uRpXg8lfdlKkspnu4F07tVa37dQM3rF1Gxj0VpyBrt+2U7h8K525accAAzy2Xj5YpTft1KA37BSSrscdQNfX4i/Q2+vxF1jNq3aKei7H48CabdopHLxr6S9Q7balVwY6eMdKsb1bVoqRvR23gWpvWRoh0PXNuBpMdMtK4eBNKzWdG1YKB9et1NyuxR1A169bGge4PvHcYDqX4zaA6804g5SfSTH72xk9KGD0nUxqorcyegYw0bczMTMC2o8zMIObSRuAJ5Pat2+m29xIt7meSZHktTgDE309GRTgibvG/ZZJEehmJrUP3nX1YiE8bmr7v+OmWOgtNzXO225qj77l6q5hnJtuimNtuSkR8mbcASzJjbhrqHbdjZce5Imb4rKvx9VwfeJxcL/F1XC/xdVgOu86KRzcdlI4eMdJjXPLSSHkbSe1yd9yUjO46aTExZaDp5FwVHIsb8HBysm8KS1lgPVrKfMknMDC8YHacqIgC78iCuB0ZtOKgpy0g7w0wYPSExRkT+BDxg97g32yN8hAxg33B31yf/AAZMzwweAh+WDQDxkWfiJ4WH4iGDAm5UAwKB8OHpH9waPyoeAxmQkG5L7gYekHGVkI9sls8FDKRskPg3oThQX0OUshC6DRQOFgFBTgvGE69OFsWcnpPHhRitN5AxWhLQYW96PyAZlRG+1WWxYi9c/+73/+f30lCvrBgnxUPtCWmTX4txCpH//X//juRfhierIgDfXeq1vMP8wg84DMtOWjbfgoPJ1bg38Lkfq1P/iVH1nwhWOHMJD+8pf/1b/7z3YU9Kc86NLbDky/zA0LdiHoT4P1CIB1L4gKsk/ua8vH2lEwCHBQbg3+LUTqd/7D3/kXDnzRcLz2NQ3HY1AxQcS//V+/+TULqgn80pb71uDfQqS+85t/99/gF45fECLq+g/+n63ftaNg0JMPqOyFwJf9ciAK+mUejKe8zMFPTnrw48mH4Ccr+6BmQQ7KhwBS+XAUPCQfC/rko8EDYOlHoaveYsfB+2TKx+QnItkfwZGMKQdkbyQfisB/YcqM7InkwxG4Nky5T+6PpI+uHlMW5IORfDRCHVjyKHQk4MjDTZuNhljBU6Yy1e/p1VSGMtSP44xQQv0wznDF1ffjDDg03oh3gCMN9Rsajf/4Ae6BQX2LLYSZITgezKjfZlFFDHYPLCUH36GF52UWnWyNykyshj0BKM+oJyKZUYORNKXl7wP1fhQDMfxfBPL0nwuzcA6VkdmKKMJpbUU8oc/GZBbavJdlcGoGH0bTO4xL6wSecnIcdFgUA1PywMMYD/i6EOag+AlpLZyEijARS+YAfP9RPQ8PG4amtKIgKyZlTgkKPcmpjSjM4SQUk7n3PFw3S+aiEKqCJyMHn44wPGKG9fVgHp5iT+mzJOnlmeJBHmFVHDcMwJsHeD1lrAU9gDaoqEzY7p56eS0oQJkxKfPSikJEQBTmDjKmKm0YK6t4BDBE6SIBRWJbkQFFRqqoAHwvCi3cgT36OBYGwSG69QCUwJI9AH8P7q+M9CpiUmZkviI+S7lx6eHEjSlE7HgkwV2XIHYSNsOw+GyQpxo4jJBGspTg3BGVLrNARBVoUxCSCvdEkqGRVNiJpB+zu7GUlKXQlJSl8KTLjN0QVUgjSlfcA1PPyIwsVMRTlPuCzKi3mKaAvBREAS/gZssMixdDi1D4AwY4tLo4fCbAvflUUCBaKEgvktkuOXgAs1URz1BHz+rNClD7T3tIrYMRzNvfRzW+EOp+LKxBheNUmN9W+AQVmrqQaBvcvz78uIHR5QU55NbsKaQZF9y+XRsM/aMucYX+LlfwcxbRgwVs0X8OQrhYUkuacBydwQgfGFjxIKtYYCoe9OozD2QVkqoOEmMY0hvJgUlrah8Eane6CJVBFn6GkPlICCABcB3CmhXBGbTG7bAYDExdl7arg6geQpZHXjM1GPmfwroSGvem+ICoiCEacB8iKhdVxDBOz0B+SfsZXeUacQYV3s1AFQ/258wYXQaiaz+EuAX7IQJgv69wL+wn1mkQ60SS2y/9Lr0Z0KtBgGt+PBxBBX9/zAf/e2ep4oNYqvj/CUsVKZYqdmWpImGp+6ToslQRhfsq92Soxt4oEn+KDFX8KTHUYWCo+2gL/zdgqN1Rganu/xMyVRyCuAzN3dWzV3f44okkLggXHdluf5jTNOdHMpcGrD/IAWFbGPtD0A+LPpnbBl0OV5TCgoDBhT26opQ9NKFewH6vzEn3dB4CAb2YgXdZetzWStomEJpaX0uA80O0wHAqWAviMXPSA5alOP1KS7rb8vo7hJ0dECREMEaVBEL/9lFoNiyZTRe16hYjhZhLC/r7tQK3QZUt7nI0lGLM0kBF1nhfo0bAu/5ExQMHo2fVECoyfg7C5p6AQLphMRwY/i+qPqQyBxgRaNHf/9oWk2IgdA8yA/a2I93DXBxhHFLDgil3/QgTOnOEGdjIjHQ7xU/ETc2kqZVuaidNHWzqJk1F0tRNmmbSTbNJU1L4c0lTI2maS5rm0017kqYFbOonTc0TA6HApj6co2PTXkjFTffpzBG2H8KNEb8QTGlSjlGE7xAGO0FEKcTCmaB99AUG8TEjxrCdtEwOnacwoDZRMfGDGws1DNmyQajpyE0DChfCmL1C3GToUmi0EQUORq9poSWAGeGcHDiqRKEFp84iwqoeRlM6JLQgfJVHIYwjOQot7BpqcTyy4FN5hnJLSI7AZGC7cGDKoOSA6cuJKXNgytC5gQoPqMuA8AiWJxYrAniyu01uCWDJ7ja5JYAju9vklodbLTSQIWc1Q4ZBcIhuPYAmMGQWUJAlUxOQCNGCGYom5GC0cpw4UqGtchHoCV3E9gE5DQvweBCdwjCJVQdLYWHkSJ/igZegSKPHA5m1J3osjR5vJ3piuZPGT1KWQlBSlsJQLIp2Q5GXRlFsLeyOIyltEFaDlOsnxpGRZsw4XGIcImEcBoAK2x7pyR0AxpbtHmJlU4dYjOgjZhYuMIt7VTexuptUF/eujrs7YQouMIV7Vcfw74QRuMAI7nEAx/yYzjGSH3w+ms6lrfojyQEd8d4ZDLPwI0MX1tc4wFgV1QnjAHOrDCM7h5lfYb40EItPE5qzGJ97L0ybOzHtdWfrfQhM71V9D0zvVX0PTO9VPQfBxBQDZFAIRB9FwNwP+g1CvxGjn8r7qTyLvDYuzFFhZlshCzXPpUJpq6IWuaoPRbiQXIt2/Uvl3V9pwO8fZ3gBRPMWWwjd7bI5LZV5kAFqx5BsVYxlsDssxoMs/IymFhiZaiZe4OwHLHAmtcDZD17gTGqBsx+8wJnUAmc/eIEzqbXMxmu5F+X5FOFdBAcarGV2L5GZQd5CaxiLSJBbYP7GJqgDcYKxvgP3ArSccmIXHWwuRtdust17K9JWYjrIgU1maI013VEfTgOvNkA3KEcxzq1fumC396HWKkUsESBESOtsVGGQYu0tZWq2AGH8YQ+GpocikvkubvLb19XFdQVjHPqQwOE/D7IpIw3ZI4VvEQbi6wDDYQFRAemhwMfiAlpRvbGxCZ5rgKIXplwAUHqlLwuITjBvsfY+WQj2G5O0S1ND+nK/b9FQkvDgg4AC0sGQhfFwf0UMQ/qAKFYNmcxD7pcF+P/0ADUTIA77FEOjQZA4FOq9O3fuOGvBA1BuTMpemQWWKQ4yGCcbhd5B1hWK4YPyQfjikWxMBGO3XKTKjaQnj2Qklj8A+zMKsygg+9D0gEEDgePhJHSrbHroQKApie7yjSjsQ4m5D+a0Dy+v4fWSAl67oNsLAo0eCCDDk4NkE/iBAGZQwLt2oiJ8yAlZgK0DXUzRUubgRkKvXiS8DFDAPeFDYczOcE2ytCbZVGEfFfboQpmHmechcux03oDQWEimmRpYKweES3YLkEfiIo3ZJJxsxKYJ8r//zeVWzP+cIXTto1dN70yTonNNNEwgthO9ZE7XlwZ3ssAihLAeaUjT3wcxrf2BSQzAxB3LYcc6pLs54DbzY4uNQxtCDn7onuEAUZ4grRqHBFWOkyNISBPC4BywTE1cH5yCCQIS14dm4GGjkEszCuhUR+vULiw9yn8DgiNRp+bQPAqhanLXiewmU/vU+iFsNfGtC6S2TEybGCJIrI4TiaDXME0iWqE2UdcF3Q7G3KZQG3cr1MbdCrWxm0JtAjGYOxRqM8IhuvUAlMBMK4uI3SEI8ASdEXODUuDEbTI6BiMMuU0QOwTbAJyLGaqBw1jSThYRgnVjl6hGk0ebQcRKtdgbRbZGkbcTRSl9d6dSbeyiVBu7KNX2bmjy0mjSFffAU1E6oFQ/Qblh6ahRvfEzeLTkgDoAO80ZFp8lt7ID/hohzS7+iiDUQDH0iAI84BApIhAAsVkRRepnVG9U9FU/jcG4MKAHlEY1hkPdT+KVcEA4mXpxUoV+qGlOe0mcmBkAY9JKUfxrwu8fOOS38BfC3BCju0V5VVgLemRe3b5z5w5fCwtqtR348I2FeTpbJU85ecvFpPSVH4U+MDIlOiCnZR5U4Lx6GbhZL8RT+7IXDjFBnkFQKlwjouNoARcL4NQzlXHTmVw646czfelMfzozmM7IdGYonRlOZ55IZ4rpzGg6M57OfBbOl/OA5CAvC/jheGyiDQu4M7HDxwWxRkyrLGJSSWWS4xQcfBmgzAIE4ffLAp1SFGA3+rJAClGB4vcLR2/+zb/+633gNHIDIXMK4/hz4MF0KWcqN8jHDBiOkpUrTX8ITshzcLgMRywF+K5Eh2Q+QCE6sE21vzcHbgUB/rI8bNd8IjLwWmoeeTL0LPPSpp5NmAv2rMdBTwjKbRvG8dPjWNLX40AH6NGI+88oI1JiPewBBb87Dpc9NE4yAMgK8vYqhuD2QCwGbEK+FniyR/3xnTt3jLWgVxYkx10LPui8dAIfdQdfXfv6FjhR3WnQgjE6HkbyAWH0CVlR8tWHwF6f4uYFABKFedmLvIbLfBRw6SG4ejjpELB3D8OB+3AAHt0bGWWs4ZktimM7kj2RckAn4qcRZQLjTgV5ujaUlHlldBY6axXBSCfh6urX9WG7qaT/RdQTAEd03SN3QJhViJLPYSi797s2dze054oBtl9eg7uBCyHeaEXHlQkrQO5k4OPAs+EeMEfZDPdG9cVhTtfTF8KMevlEt9TArkHciicYU39/4+kIlbNURqQzRpIJIFYDJAKSEXQRCOxNpGpoPS8DcjujK+qY9VhiaX21OwG4XEz7w4G+OPUFhSIpFLrQw/iHwAUZGxhgQcAJwxbHf/AiEeCEzgNha5vqtrMWcrr5jBdLoDndgzYIG8TGQoieUc66zCh7vaW++suvumuSE7/b45u597e9PogoiiL0APpRyCUjlmRJZwGO+EBZQhtznWwZKeg3Ul/dNBeVvw44dxGRAIij3uNrtAFhPK7H493xcBNo3OJNn10nC+umcY2PWAhdSeyoBBcucGqDAP0uM9NHhYDsgke+O6BrsN7dXfx3sB8zYC6Byz8KebLreHo/8vR+5On96MIJCezIDBK60PefMxGuqyt5qibRtfbo0p1x749sbm/cdRzA1MtRiLFcFl6FUqZyIXpsLXQGJI8Igk9GCJFDV2zdE/ERpVAZui5m+H4oQHEvkJfYgNdMTuRdBf4qL8J3KeCtkQDo/KLuDbuGG1QO3Gh3QFSdpJ8Ftbnpnsg7khQGGx3VyPGRETnJ/S5Odr2+4cXwjltoKbGWh1NVfB8AVETJp/NwLpDxeymUZyF06H63CSoKcDO+1lICo+ZdiDjClxX0NWo4K4O76fFdPy4dvwBPecCJ7l2NLWjMu7eiXC++OWRDIBOfzrv6/J4vhDbes6KTsjXpdHtxtveiGHI+FDQmeZ13ztdO3o9wYBybgqe6l/7gLhOPn2iw6bEKB/UDnAY81rJnz/q2HfUWI8H2C/ry2BsJcCfyJt6Do2MHCSxKe99YfF6Be82g1yhgrxmw1wzaawJMSDzuOZm31U++scXUIfXty1vMP8o89R3K/+gbOv8B33cHxyLE8xivgJM34tvh34NnK+jdlYInDZ+hOYnhCy+rizR1MCcG8OUQ9NRKEcPIuzByUFiYn/Gk8K7Z3NzAXQ8cUL1Mr+fgFdOIni4BKbKGRolYA5sO2SlclIPbqyBwYLq0xyEeA59lMdbgShSn+2l4LY3K6boiDETLhHcGkT1J4fd6d5E/NAphYLJQNVkJSt+gK5SGfifF74Ve8NpfsglYJLUiEU+wOydAoRR3TSlp5On7pljk99GFOLipv4Fq2E14QiBvQ+HV1yZDeEnozmMBp+t7fMf1PYbPDEAqGQhZcIK25ApjcqnRo/cItuB+3sm8ldzGm6I03Nsz1YHpvEk3iTkaGLg2hmQn6Nonkmg0DY/3gJodcH2LmO+4NEjTu56eXhcPyDNJNiPXji83g0y58xj+c2Ig4Lrfm4DY9E1TI7mYK+imqSHFrhdzjbsv5ho7LuYaeHIomT7toqdvOF4/7Q5Kq8FoNfhHnTz4efAypNrA65ied8tKTD8nNv1cMP0y0o1NvyyYfh6h30X3bxTktpl+HugYHkYNiA5qTC6Yfi6Yfi6G5AZeEs3aNf3cxIhy06afmzb93LTp56ZNPzdt+rlp089Nm35u2vRz06afmzb93LTp56ZNPzdt+rlp088F0y9LzrbAldm06aecE3vafXHQBYgvsOIw1IuEnE652A/V6Q9d3OD6Uy7Mffeo/Do+2BLy774KicFQfPcF1A8scIV5cCE3K11SqpVYj45j3NKFE3lD2jKL0c8CfSLYKbitDbQD/dLUHrO2PP8zoUfXnz0KlvBgV5nSkzl94RO+oevEpZyJt0ClRXsGRl7wD0rUFLRR4pBRQo+u3TWmsWup2AOrypUQYf8dMIIeYOSvHGLejy3hbvBX1KfV5uYPGNGIjTeSQc0KYKoPrMPx0YCkG6eox25ubppriSqvw+j8WOFy8AmrJKLOoWvk2tUFROlgfdRBDZVbC3JxOTziI4X0ojCfaJr5tA6aT+ug+bQO6kgKBQ+9rmMLu0JfiyPzqZrahbzNswX3ScEzfFDyAaJfeHvqMM+F/HQSCISQu2soUjMLqDdrk09/fmRdGWs9pmCcwSEZqUWu+uM7cK9/PUA7/86dOww4CNxPD/CNBzoucBSY4sCX7hhrp/MCjnPo3rMjsxAaNSz8XgvuUOcsNOfYAeGGPb8Fc7pzaU3d2NzcBPbqYkN8ViJLz9OAFQ0LU81DyK6Dk8dR0G7U44qdQ6piekjT8/BrVnZ7ExQbDtVyvQ4+qBc4oMzo3lnSOygN0+i1Z6fzxh5QGckQ6U5BeempChc1+AQyhyDLJnNhFHYKbAaaBXlP5r3rlhD0uqFcCI30wSb4ZEGVAH+Ssf2gTHtoTVB4DHTH09tqfXFcIAhI7QLCD4Owvfsi/1Ogs/TDxf5NfkSfWnN0Yw6iI+nza2r8WJ7eCTOhbh8qTdjUo7ZmRA53gxzuGFtLDYlxiu8eNb5OQd8Q0mUuACOlPqSpYs+/gbGzSZfSghN7aBDgsP1gBkEVeAcChzDQwO5CiqFMNLOe2EcmtMMUT3jNGEGmuv0P/slfehnvUBz947/3u//wd678xV+Dw0tpHv3qP/r2//6//Kd//29X4CjGwHMksLqASeNpo7J8nvQmwIHAlJW8vmFSDgfGN/u6h4xs+2ViQdd/9qpgUgV3zwouVcjtWSFHFfw9K/jSRDCepoVxuy7mOK7CUDI+cSLnsqn6wAmFv4p5WyZ3taZhxvsU3PyofR9kLNTXO5C/Y8gAX8dnrWCvY0gxvnFlay6K3h83td2tLbXxNEoz0FkyapODiM6oLX48b8COpustqDGbR69tbm7aVQxrgHc2oxCEMZDXsDCRWIFTWGRPoWWJvVrSBoIlUzOA99GwlkW18AzTkuIw46GIa0LAbCBAcYaacFhFnMFFwaJwHnjGCtYSHbxaoEyIYcYpWJlEEhrWIGw83RRPQuCNusOMBfQcF5xfIzrpHRhwr0FPUCETV0DevksPDr4Up/w1OobwkKkBZg5zE7sHNUF/zGz7iMZ45jO8R5rS+wyH20VZuleUQjmapbtjmiFmjBgzXrwbMHjAgvMOfHrD8m4ZPKd3kCBd1cUbGmYc6d69s4NPzeBkgYRtaSzDP6uKHY+gS3gBMXBgUcE5fAJOLclVeCLPyUGQZ8pE6UdXiJSJp6JAxu4UvFskFEuezwHqsjBOUAdACFo1HBfcARC+srgAg4eOtE7mycWsUGdSpv8o+MJy4I4DiOgxNxSYIHhJmUD0QRSbPT0QeuRl88DDDSrBK2tBHqv66OsC6siCuW3S8RmuakealHQj1b/WkU6rhQhYhUjoPHq+Qo56RQ71CugATsr6wQ/WWYAGsDQuPX8g8amjDL5YAvIS7zZKbwFepAPRrd6Ay12n0A9AJ9dxAWAG6ZsUP3rv0sVwlCHmfc3kYgMsoCh+EAcWUjMJQ5vojMxAsHjiJ3NYykQX2010Hr+dI7omOvreNd/k2n8O7wj1pk6laWBBvoGubS5StjnwqItroeH3kjkaxZ4NhAB7lmybbc5j2xya7pxL0gg9m8nzRtzvK9z1uhFHm9OIXzcytM3JUzYnJxrHopAnrxvxrs3Jk9eN+I7XjTjanLu81ENhp/RSTzLvNIg7Df3Cbs/4UC/Xt/cSQ1/wvN8X3NrQkUNc/dM7fw5eu4T/nxgI2cJpfCbZl1w7Nzk9pBypXEROI+j0YrKDQAU2JYkZfBwIvMLEhUJ4gQr4VIQPi+JbVuhCQccPDIevTi6EfIGOyuNHmoGpobmm/VswfTjORTGtAbLAA7bdQSmQKtOVIHqtgLwan5mEbvTbSZJHeisgDXA9NEdvEIZEn4zdINumYUSoehgLYfdVX6HPjbr+Q8Mv6Hd0gZd5v2Kgnwy4UPeZ2GQLgj5lJNEacJCgnxvF50cFvTnKUXpjsAyaWDx+XywOh+6+WioG8OjfDCzJQwHFAosximMgMKXA3nncMQTw67efgbnSssIoqYdMOT1kikoYHE3xWM8TeQOkLk6HgXZr4jPZGMsjBb6PS2ChUnMMlV89c1NTgSY89QqSnX43mYiYLjrgA7KcVEgO/eJo/R4hqPuhT38oeIVtD6Cy1AOo/0pwo6smYSSDjkI08YlPVPQgClFMdgNNWTfQ1BqgjZ2ocfbegaZWHIe4V/XtgaZWHIe4V/XtgaZWHIe4V/UPEWhqxcGJVrcPa2dwIurGOwNNtdrQjRT9H4j9U0Ls/ym42eXW7jq2UI+hrAFnwmlkWz8tjbOhoIfyheKdlvpcGz31R/F5HaZDkhKKBo4TUwiHV89RszX0+8NIeZLFYguEWEJQ0mwhKRViP5sBcJr6iXb0sJmxbIR3rgW+cy3onWsI9DeloZ+nDtHJhoabAU9TGxAzRZxUd+V5dJCp3wfU8pRR/Ny21wILwMX8KAbAvAsA768KwRNbvmsjMYrN4/DDVS84/+DvEMCBnSDtWuvaOToUU7nou69WjX6dczHnq6ubWwzXThr+KGpmuiXO0aSOw73b//5d7flHan/9q/dsf4+Z72j5W4I7XTNAZcGRjiuMXgNTveNM56nYxLuGQtkRvoeoXo6kBc8mqqv/jC2HFvwswmkkWjM/Ycvw1l7nMHvn8uRnGL6SD9EbrvoJWyR3T/qrAb5tcOHfeePfQABjYoFl8KkC0jRoaPUKXOKRFtobeiS2a6/UB5rW9MS27mADO4C/L3GQ/c7lySN4zoXvK7AYPJWVxoLvBYbWpaXh/VXB7Q16YVXEEh1cfYrjcQQV+FAgsEAZykSaNacH8OlLVHoIu5ICZLVhrrgfUscm/fEDCCcwkzHo4Prza2pzowVCXtnrECgRDwfLCzfU4ErWFm8dYaiAc5Vbx0gM3Y4aoHmmW8But6mho24lDfGbQd8EfqMeqCZX+9bVKtal81tEHrLrCLmG9y3Bhb615w4xPElQW0hv4KSCmEfp+AJDwQO8libxcMSECEEXzJdhClIfwrMKPGJAnxKGkGfo9kImudIwhT4Be9t9RqMbvifIrSJ0Pn5D3tRPqZ+gcGMf427R+RIIKoC9b6PVClMWwKrxtiYkKfTK8I+HJtSgiGB66dUkVsTwyGOIef+Vc66RgWHCdyPDx7jQwcCm8FCLXP0QLGlhsCT0OkTRi06MCgjUpUAie8dVznuCDucEoaDwXgoSpoBs8m0AO8YCjJhC0IVEhx+BjkmKbrP94xCKAZjQoMdqNp1cIOh/xElBKC6EFvgbtoPuG3jQEuARi0TbGcG2YOmHATEAtgVgWzHYeHijAzecDw+2RWBbCdjWTrCtjwNsi8B+W6+4vxB6QwxPGNSrmzsJAP8UQta3u5EoTupKT3LluFt098Ufeq3HDMnxHaJb1wMegs49iyDDAjvxwOCfa4ghw6SOtfKPg4cIANWQOQQZ+lM9HeHGY994EUWFPkIGpWTzIqgB6FA0yJfzhBSxk3xICjUUqe9BwB7qDvAPntb7+YfJk/AEvEMrIX1ADIUmyCxOf7KES4qyI+8UqBhYekBAXD3vvlqBxoMPVkbVcFHpzKF7CUKaTJXD8D0OQ/nUqzhgUMC/+t5leCTcJDMd+D8u4//RXUZ7CITXXcvoZz/CSuFdVYOuZeFjDTbCQwddohOYVCDoJo4y8flmM14pTOIgIfePhwauFNcrZWlDFV/JpJV6ozt5C9fJpKABfctCUCwM/IWRuBTv+pPcFySBkIQ6EC8Jf94gFMqFyzzv09lX3ekBILdjA2gZw5VnomhJtJY+dQXSo+5RuMWkognHotn+S04CQ+rZxv8p7pfBTdxjmJxxjM5595vwVyg2noFjKWD6UQWK4Mt735wEZxY45CTuxL5eEySDeYQ9AtdCj7BBYN6gJVrSwsX3dTucB6Q3vzVJ7UAMWtRNP2Vpk0vL/5y2NGHYjXh4OIK5/U299OBiOsE85v0NohfFA1uZ2utofVca330ldI/Ky79Fbxtr1cH9rdDQg+DdjORL7D2gXWVJvggKtX88dWQqTbzmDhfHpOk/h6GW+RjL6CtAcobAK0+5Ab5K7tAjz/p98v9PzfUv7tzd972fd93GwX1u41/n3NTTMkBfpPhquOuzm1DCYCVOZ1tojxgUc4NmFDIELLD0ZRAz4KjDJ2oIMAQRCyW+TSiJFDczaG7f6KLM2BNlxgeizIxfDPB/WoNASIKpIijUD0LSRRIeW+iJvMqZPqhip1Bz5iBv99hAHG9bHY/vLxnUQO8ecClgYCuVgjaf7CBOOwhiGAI6/kp2EDqEv8HpyXt9XIG+cPjbcPrMgm2fBPDxZB44tIlDU8CsNkfhDxWQb4CcrkkxgY8WLBYr1q3m/QWeOm7+IJE62BWpfeDTu7dIHQy1QBQHQO+pGu5OkbqL6IMjR+8yTzw7+Pf99J6Ijw6m6FCAaTWJr5MLVFDo3A9hSrh7KHrqIHMlO8gy/mNoYyFgWJNMda7euUeDc9p3DM8NSNvnqOmDEJSO/7Nd7Z/HotdRr6Kx5Pg1T4vg0JYm1tVi2Y7rmrqu6dc872waYowP30YIRAYKzlVc3P4/pQ/FaPuDboIrhdtfayrJ9keXEb13753hYkOHSfoQpY32KpxjiUkIJN04TiEycAhH6qkPcfCrEfpQfJgDk+bCsYEgFeCTVkK7A8TRmXikYt4VnZq8vmFiSPSJPAV5Fj4wMvXLeoDYyasjgIyF2H0u/CEyZVG5EdrsBh1zKq8X2sAzLx5f3kqMR+8lzj+g87StnB6g23fUjbVIW6WnUr4gZM/k4TH0n6PQ7hYT4xjIaWIg0nPaafLm5hZxUynAaXIKDtZEgCr0ojL9sUDA5sIsDA5iDmQQfYNTW8rit3hP22gNOP4B72lubOinMgT+dTH4AyhwbGtKjqe3x/PI4eBvG26Lh5/KC7zRCu+ngY/B8J7jYoMOOkFNefVbeMAVVSCF+w5uSyPfZQdBZ8ETL1JfqMXvfXMS68OvQAbrHQdgOV1GwrNUAxVsN6Vgu7GCrc9D6KzuO9v4i/cZEkLpFfDjvwRiKP4ZPkgHCYB7A99f66OQkgnmjSJvnCeC0wxS8oUT9AdH6fUR0Lzo1qwnecFj3mTMvaj+MYpsjnmNf6xAZ8MIGx7X6D/1SHvJz3sT+k+ibf4AT0NQHiThR4oXkpg1YvhATRRh4R1CCtPz1XxPGgsUa4unKaDIo+0KM30i3p6aBbEDmsESl5SiikzEjPwXmfd4Iszh3F7QTtauUvCC+19i3iDnG7hKPq4Sw1Xy6RaT9yjSGbE4rvhTMfFw1E+8R1lCfCZsiT/8JvzpEi7ZQK/JmPeY/vM6AFJrG2ap+WC696kdfT+E60h3LSBUGg/OfOb1xeuIq/hF5vknmdfL6ESzo3iL6G4fiyUzEhH0+DCeCh8Qv/GNySr5ig+Ib0OaJZ9eS316lT49qGHkxGlMvWz5hDp9t+Dt32UT+3oP6T1yzPPiuTxR8LI0/S8/zLwC2pZfJ4vRz3u9TP3g8hZTj6u3gZWOMq+HqRvf3GJqRGI2p/tRrj/ueexxJuGW6eP4AU4yOTK1DI0wWYAUIPBI3PKAYFWDxcXPMz0Z5fpPeg6TzP8ZRr/DzPNA6+NH2A+vTGKPdBzoQsr1xzyL+b/EPIMVPAOcqMzz/kWWM/orx/84+wCD//kM/hS0xRh7sL04P9uU88uN5kXZ7tRbnbasd+RWXs6c78jmcoOyTNdYOd+RK3NyZuX8cqN9RHbONeVic1nOt+XWPmwBJVQVyti+Vn35bJM61uVbYdwLfZtbaUmaxMocdHa2c05uMZ8+NpcbH6nZ4db5dmf28NjEeLFaKVWapbl6eWy0WC2PzszN1GdLo43qRLXSqDZH6yPjleLhxfmZVr116XC70zjcbs0ebl9qH+6cazXrjdriymx98fDySo3y7UOt9n13P7vSalL/MOPD851m60/SHf41aOyvVb9QW2/OHl5aaSQdHm40V9uHG4v0R6MPFg+VD5VKWDsuw6qdpdXDF2bOzy82DsOft56lP1yN9RbnZ6BKIHyWZYx9kjG2j7Ekf2BH/tM78gd35A/vyI8xxh5J5Z/eke/X+c9xn51ijP0ni7GQ0f9Up9NcWu00G7KzIueX5zvz9cX5rzQlLdJBXDR54dz8YlPOd2APzjTnl8/KRmtldbXZYOy3mc9+iTG2wBjLM8Yywse+zzDG+vQYcdnLjLH9jDHBGMsxxkzGmKF/LV3X1XmbMebofIYxhMPX3zzdHsbrYYwt/eI/+ZdPPPu7sxfVX/vS2sC3/t4Dv/nlL45+0fnR8xe/+9Wfvf1X/ku93W62OvMry3KuPr/YbByRq22A8XM/I/H303Jpfrm2st5snWvWG4z9a+6zTzHG/rZJY+/V/rPd9vWLu7T/uybNMQ1XYQf8ANNsfbU+O9+5JKGLucWVC+/D9qvcZ88yxgY1borFYqlYLo4UR4tjxUqxWhwvTpSKpVKpXBopjZbGSpVStTRemigXy6VyuTxSHi2PlSvlanm8PDFSHCmNlEdGRkZHxkYqI9WR8ZGJ0eJoabQ8OjI6Ojo2Whmtjo6PTowVx0pj5bGRsdGxsbHKWHVsfGyiUqyUKuXKSGW0MlapVKqV8cpEtVgtVcvVkepodaxaqVar49WJ8eJ4abw8PjI+Oj42Xhmvjo+PT0wUJ0oT5YmRidGJsYnKRHVifGKiTvsNdltjfn2+0ZQzl+RXmq0V9tslc7m+1GSFntb8anOpUaoUD12ot5f4Pyr9AmvWnju1frJdrJ3qPNmO1i6OvzB96aWZZydq4zENJonpU52jY7XxL+jsqc6pztFqbaR9qV15/lK701yaqtCXJ9vNmadm682V51sXL9VG2p0G/3TtpVMTyfATyR+ZL1XPjdTHJ+rN2eZEsVJqjhbrUyKovXRqFP5i/Sj8CftS9dxYuTpXGpkrF2eKM42ZmZkpI1VlqT4LvYzWx8pzzZnxZh1WZW7KfDyuUqE/cF+qniuNzTRG5mZKs9XZyuhYszFl3dXPeHOmXKmMjs8Ux6tzjWZxyj5Se+lUqZTiPKWRJylxbKW1VO9UmhdXV1qdUvVcpT5bbcw1SzNjc5WZaqk65YwBdgFRT7bPvnh8dez02sXyqUu1UWCzI+1Oa2x25fxypzTaWKlhqjZ7rt5qu5+GZk+2556Zu1SZmi6NzT9Tq9ZqNWTCpRFINRZrrSYiOzP7ca7g6Fyr2bx7/bIfiIT5JY2E8XK9MV4fqzZnKuXG7FhlyvscTbBe2x0Nc0sdnNYE9dRptkrl1XqjNr/caZ5t1RdzO/YOTOLopU6zjQtamSg3Zsars9WxkWpzbCr/OGF8j4HGLrTmO82ev8ARnFJt6JkXhkjQHFpvzh469HPNWSh6Yej4C0ND58vFoXqbflJ12qvN2dpca2WpBtKxttxsd5qNQ4eeX23OHmutLE13mq1TWEY9PTk0jb0df2FoImlVqp4rz1Qb5fGJ4lx9tFSeG6lPFZY+zoUsjTTm26sr7WZt9tz55YW7l9Q/+3EON77UXKovzp9dvnug3sWPFa7y+eXF+eWFvcDa9xWgaFzvldX2+Nz55VkQMGPzS6uL7co4rjikcVmh2qFDK6vtQ4fiiocOHVuGOirZAnMrLfwdeu7Y0DEoHZ2tLy5qrgRSY7ZcrTZmG7NT+//bjV2pztWb5dFKozEy2pybGZvqa32sWB6fXwbRXFust87uuYUemLgHextDDXIMdeJSBTM1zNRA1j/4eWj6Ynu8Bs33pNeR5fNLI/NLq0unOkcnatUvzLdXF+uX4MtDv0QdvITwjs2eni2WT598cXGlNoZAVdqd1vzyWYS08rxO780XvgR8oVRE9oBMuD8Dsgf5zCc8EiCYeTgDggKTA3ni/JoRDr7KaUo1YkA7sTWxWl+en12YXz5bGsFk7Vx9ubHYbJ3qHB2tlTT7o6k+W7+0uFJv7IVabF4qPws/uup4p77QrM2sXHwkT6xYz+rRl2ovnSKUVLUOXi4+V7/wc83Z6eXlZivZb9VWs91srTfLpcZKTadr9eWGnmWpeq5eaTRnx6vF+vjExES1OTL12EjMbO8BK022dmG+c652bmVlQZ75kBMqlebml+fb52pnWysXStVzE8WZueJoudScG6uUKsXm1KHFxfWlQ6WxsYnKSLk4OlYuj0+Uxivl4EtECqN7bI14WNwbpVJq7G1D3g1VCCs8fWodOi9/QOdAY+Pbe1+d+gAcIyHW4w18fHFlpr44dfc0Hs8kInDo41vdiWq5MTFRnZtoFseqE/Xi1E899bGg8Wi19tNP3eeSz44WJ+ojE7OzlXp1tjzbmPrkxp8hiY2cbXaGq7XnTj35EUcvHh2rHcgkOu+nFCF25ENsobFahRB1bmockFJbWW4erdY+PRXjYS9mD9wcodYs/VTnaKU2oeBTvbPSopGe+FCKZoMUzYNfSFZxZL05W6okytKTevXG6qurzeVGrbnYXGoud0BBG62Uio3ZmYmxerNULM42pg59+ePn2xPEttud1uHqnupfanXGaXXIN1SbPdecXSjZS82l2aXV8pGuSLvH+paoB9TZx+eXZ1vNers58staAJTuY3uO1UqV5zv1zvzs853Wn4D9jx78EBigVA0Q2FurXZiZX26cbS7XaOdULhIUxfuD4iMQGaxgWqZXT+HcP9BWqO5pK5TKtBVWW825+Yvj+1LAaXNp4tC9tnwVfmozzbPzy7XzyxfmlxtHPrmXjKP9Oo4tVlaWPlOMK969qcdmVi42G6XRmZWLteXmhdr5ZXAIffYCSZNdIV3ttEoj4AyqzS/XVhfrsyAdPtp67LoUU0dP1n7m4N5MbAdUwLo+95WPNM9pFGOjtcrKKii1ldP4M0174G7cAC8ZQQY3tYus+/zcR8XRvbiKZii7jDN54F7boqQ5Ifal7rHQMesulWkNas1Wa6V19MCHoEmi6icLqS0LtvgX0Ex7cXcE1JcvfTiIT3WOlmsjavlStXNptVmbb9yNgakn7z3Qc80P0cmxIx/V1u/y7uNP3GsJRnUqpuOn/qc/M14LesD0eRq+/Kc7/E4O+fSHw5GW2Cc+ea/aRayKk3vmHFT8+Q+7lz6cOJ5b2gUvJ8c+BPFUY79tLfbbnjpxD0tzN7m83O6UR1KZWmN+vTZzqQYO0Z+dAu9PGVygNU1qI8UU2c2urF6qdVZqsMsbtXqrVb8E+nFztDQ7Nt4sler1crNZmXruU/fCbjleCwTt+fF71C1XttUlnhHvlxc2/+yUikq9DYT5xdF7SDb8tzSu2V0KgC81NOfebdKgnU7M1GcXOq36bLOLANAe2+dWWp1a8pEE1dFKbadk+srU0UrtxeWPeZS9lXgc7+fH760OlEcbzbn6+cUda7mysvAScoyfn/4QYrxECggWVPVaPNec+ugrd3Si9uXivXbpeIwSPPekEX9R3aPFyFjcYnlFg9g+N79Um2/Xzi+3O/WZxWZtvfxLH5I0ZmAN1F3UCCuVUOPKTNSc7dRQzLeacBowW200i6WR4ki12JgYGRmdYr+QWW2tNM7PNltt4S7Wl8+er59tcvO58+0Oy62S1dVsHJy5JCycwiOlQxNjh4pyODnQlOViuXKwOHqwNHrAvlBfbJ1v28VD5eKhkf8XconQNg==');
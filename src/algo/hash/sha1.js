@@ -10,6 +10,7 @@ import { sha1Wasm } from './sha1_bg';
 export class SHA1Algo extends Hasher {
   static wasm = null;
   static outputSize = 160 / 8;
+  static algorithmId = 2;
 
   static async loadWasm() {
     if (SHA1Algo.wasm) {
@@ -93,6 +94,56 @@ export class SHA1Algo extends Hasher {
     return this._hash;
   }
 
+  _hashBytes(bytes) {
+    if (!SHA1Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA1Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(sha1Wasm(SHA1Algo.wasm).hashBytes(bytes)));
+  }
+
+  _hmacBytes(key, message) {
+    if (!SHA1Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA1Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(sha1Wasm(SHA1Algo.wasm).hmacBytes(key, message)));
+  }
+
+  _pbkdf2Bytes(password, salt, iterations, dkLen) {
+    if (!SHA1Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA1Algo.loadWasm\' should be called first');
+    }
+    const derivedKeyWords = sha1Wasm(SHA1Algo.wasm).pbkdf2Bytes(password, salt, iterations, dkLen);
+
+    return new WordArray(Array.from(derivedKeyWords), dkLen);
+  }
+
+  _hkdfBytes(ikm, salt, info, length) {
+    if (!SHA1Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA1Algo.loadWasm\' should be called first');
+    }
+    const okmWords = sha1Wasm(SHA1Algo.wasm).hkdfBytes(ikm, salt, info, length);
+
+    return new WordArray(Array.from(okmWords), length);
+  }
+
+  _contextWasm() {
+    if (!SHA1Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA1Algo.loadWasm\' should be called first');
+    }
+
+    return sha1Wasm(SHA1Algo.wasm);
+  }
+
+  _getContextState() {
+    return Uint32Array.from(this._hash.words);
+  }
+
+  _setContextState(state) {
+    this._hash = new WordArray(Array.from(state));
+  }
+
   clone() {
     const clone = super.clone.call(this);
     clone._hash = this._hash.clone();
@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJy1W2+MXNV1v3/em5ndN+N9Ng4Yj4PvPNywDrE9s39mdk2a+DrxulvXsVRF/dQUL/ZC/MbY3j9egwDPEkiAxpWQ6khEsqo0pUqa8iFtUJtIfHBVf0CVW9EWNajiA1Kshg9uhFqnIiqte37n3vfmzXpNUjUFdt49795377nn/zn3IuaWHpVCCPnZ6Kjq9QR+ZO+oxI/u+bbgpsDb80crPf8PdQU9fi+oqc7TC/2Z/SrQsiyFlMMiGBIVHYQiDEOpdShEoMr0E4hQKSGFov+ooaRUeOJRKpUCEYTyjNy0KdRSbCwN0Zp2dfWyiMp7g9Kj84+eXnxcifLx059ZfPzMsrhn44MPnnvoxKnjj8yfevDRuZMnTx8THx0pvHt4cX5e7By6Sworo412312VO5Papq2faHzqV8fH9n16+IcP7/+xUTFt7F5h424S7RBqn9pnhP2pPBLssyJ+IJFG2ko8ajfjN7UxP7r2/ZtPpPbcgn0qTZQV7rVVy12MN+pQ3YjDNUUTBSl6GoF968uXxagSB2rSChN0GxorqR4Au6pma2Kr3U7Nmzdvlg8RQM0S/VVWHklEd8XKBZpLLnftp9IoCWkhe+25yyKlCRMR7DOBCeOPLTfKVjSk5h3EE4kyAU1r6NGltj5Cbeo2KtE1QQN1VUc06WaeRJkSDRSmbD+6YoMF2qeb+2AdHfVGiXokLWBETUSmREuWGqKqIiNrKsKqVppwOf54ts2FhHaqCY3dXxxElt5GmvAjKgFHaYIj1CQMS0bPLiS0lKQVNdbE57+S8ILyAJa59jzPnRDJjhDdqJ8+PFKvEcnxLp5uhPRJRNvXjiHEQ4yJmyY0Or6PXq8qvAM0DOy11SuJtHrZvk14p40SMeRNtwNiK827nMgFbHZUVaoy4j7wd7GthClFjGvYVhV6R48qTVxyD9nmDwywDNL4XmqRLGniPi1rYycYTmieSI04VHdSwjJBUoHfHBEiD9AUK4SSXjgAdmekOFwrG0US5akO8pZvJbqMJ1jijAQ9g32a5cOLBbFdzyaO3NgaGJDRPcoJWwJhM7LO1kJP1hLImvOFuBSQtgyStGyvu+5GeCtpia2mDNpqcE3Z3hJLepoQY5YTYa992W+hZCteToWbl0STCEEg6E1C3i4yyJ43+twC8SgChjRVfK8pxQ+QyJdpHVpc2O0koSSvmGF5gQgG2g6sqP2KcnBFma0oCytmG6N1lV9XM33wO02ijcmZ3gVqQW8FM3ZdYhUkEcolCDlIYrkoiYQFS2I5atCPk0SRSWLZPQjdSsTTQjYFLw9ykWQyRUprpLoMqS6vkepyX6pvmUtTax1Rr8nIS/PAv0UlniUT+24fPFQLYJZMRPKNKUSX6MNGd8D2El/IJwTxaFw3ET6tHKhFaFUaVXsDGFE7SIa5Tf1pIoHr+wTxj1tNgniH6uh57/ncMn9AzYZg/WiZygwsGM3LukAimjNHseUhvjh1oG2gx4qMJhjjWvwOQkYSqJjepFSKVcqTj5FjkvJQekGGSrH0uA+GI/viC5l6G2Lw7GINgkxztmHS446pHkQjkU5T/H4SdTjokfzT2yAllxRaEZHx5cn6M9KEFdgfrF0xw2kjIwKYpVneaRwk31MCBmxg7RnidLZmYyj/WvMXwgwdDhw5hw6RDe/Tk1hNajKErqTsOJyMdO0JYhd4D9iQBEjSO+P9qxlKZ+tJjaiywdTMCNMQghKy8dRukkbJDW4oRgr7gxfn7yXxjPQxBskheCTSwykMoCmlKS3VTULQITahhWI5MdR+1s1+Vje7xKh4GmoDG9NNU4y/ANqurq6CqYxik+mxkyiz0WyA5D77AoMd6t9gNtLDPk9vTBUoraI1jNYHjDlaGG82UAv0tJAbGCTSx4CUlKwz+ZFDNSZkbYZsPz3MUJdIBsJOxztorHN5gIerlegWk6NuY2jUeoZGwdAoM+QMzRCswhCMCR4wNCyWkAM4k4qTZ4IDIjMwBidKjoywPo4rDfCIiNhNk5AVg4yPN560bBf8p/Am7TYCk+vqQa9yEDAxQ14Hi5BztPJgLfThT8nvnBwXmydbzT2It+zOnjP66nb2vETk8iY9ICkBEuRKSPKqoZN62EYnTZktIyuYsC6XEbNBjkhSnPNxUueMR2Z6+vrKziKzAv7LoiUoOeVlwnLwVqBttjjp73u5kRMFTUoE4iOHgITY0yud4WBY7iksKsi9Z5SXe2/rGjyK5F44yV9H7jND6QjDRvDarYQhI+eIQvsoE2kFyWrgOUZwPNzvDn4JElt2Est+rOxYXmaJ9ctQUHYRe7i5vf1zVfJdSAhQYeoBuOaBa8We9wFc98D14rDVrxBwwwM3isOeR88HHsAzH/Yiep79igOeLQ57CcAF33OhOOwbAC564GJx2LcBXPLApeKwl4s93wXwsgdeKQLfB/CKB14tApcBvOqB14rA6wBe88CVIvAGgCseuFoE3gJw1QNvFoF3ALzpgbeLwLsA3vbAewCueeBased9ANc9cL04bPU5EN4DN4rDnkfPBx744CuFYS+i59nnPH+Kw14CcMH3XCgO+waAix64WBz2bQCXPHCpOOy7AF72wMvFYd8H8IoHXikOuwzgVQ+8Whz2OoDXPPBacdgbAK544Epx2FsArnrganHYOwDe9MCbxWFvF3s+xKkWXXbRcLFfdrZLw3ax7SGXLVJnxH5JpgtBiS6GpIdr4v9ksikupUx6B2Ed/VdJlnpk5j7Jdpmyc7hyFxMTPFkT3nEyuOcAJzc65VBTx3sTkfm7g0h3vbfTB2uqENDD0Ml4x0jEn9LCLkXX6zvCIHeE4UHOiQNYxYAj/3Ucoc4dIWEo412cWvN61SzSz8hinGctYNwPcAcdgnLBuYt6R7xDFbc41MIwT+OcHDMckNLnEwmlsnAO+/INS7dhmW9YDnp+v+HbeP7+hrFR8lcFFhSoDneXR/r9PCBiwWJiHEHJBwLt0pkPqSSJWypJFJkjLymkKLlPJ7ke1B6SfdVPqIifYA8pUJpSKlDLhbYQ4WcxusxidLlejO5jfUfFrVW8jqtdcNWvlW31ML1ideoRASCFXNiSTL5h518vupG25yMBWiWubh2JeDS5ed1383JNLYbScw03L4tungSd3byMUN5wHl0XUnQ8NLLWH4Sy0rP3k6ZfcZpGWYNcSDRKW6xogX32GfCOYv9goU6ZuHQlOqaGZYmhyM+U4y0scVk+m6tThRJYqJGtLPg8tgKbEVDMRXjtEoZGVKyeqXOY2t2gpOIQzk0ZIJykrN8EFIxIxDxyhaagR4pWCC2WKcJquw3LNSoc+zU48t3SGGJeapAVNSaKxinYHlXxRkkyQwyTpFuS58P+Z5gnfi88AVtEamwbVdWNWri32xqus8HVQtXTXDgzIX2+NUcH+tNfiFGITDjrdsEoUmZgm6yzRITGMGLivcLkCIgBWnlD4ilWQvjdp5iQ6n9HpNIAkcpriISws/QhRBLrkkY40ig3p0bt0yfKIAwMFIkuSiGIR/tzQzxpZSJNjWslw44GQ+yySPr1SFRYzuHvVoz+vCTLriII3Q9QFf5QKxIMWBENXA7VSceJ1DEhUSw8Sl941OyYaGoyo5TVwooW/6X37JFCVJEJwcyUzNQUAbklJgMc70JFEtkXpWxJ2ehZgunRJa6V2BwQXcgsE69oLaSB/JJ1kDBDWZdaXfv0amW2XiNOO1Om2JuhlrfdF3/I6gmwEbViWgm2LqRQwZ5hVxFALONplyfDWyynBAkQG1WhrDTk004XwBXLImCgzupDA5Uj6UrX3IN0mkjSTVRWF8VimuvQeG6nXuV/S9Ugyv1ZiFRXH86HKz9cuemyUObdrLzm/GLR2/Rnma0hZBhAwx8IbCfZVNAX1hUsBEpyMbeP3X2khiWEE+fJ1QU+cwsHCoXwryq3ciqrxLr6awU2tjJQf10/ew77JflbNu1/Sw7RcrydHjX26sxjV+qXkBj7mH0S0lgHywNwKB4h2YXqQouoZyv9PBD9Taju6Mmn7qXgV3WTGkVg4fcIG7X8va816oVqZE/11FMkHzclCuufEfQP/5i6KX/v95O6qTP4BWqY+m/XgWD996LEvT1KLh2t1SNfgGOq/wkoAPdoBD68ebN35HfqpnQyGcERAi3e2Ghqdl/a2ERUlDONO+DzZhqb6REcanyEjEgtjo2CUN9Bsq+sgohsJr36yIEac9LoxgaziSJZLvXdSV4AoWByVyNit5iQkUOjmtxNZhd2OdnaGKZ5hxtVM0S2t0yvIxpTaUTukIG1O7Qjh1m74xRuDCou7L6u2cAcd5XBKkWy4bk0bVRIkF23q744caCn8c8JPJeWlqw85z72n5oKfUxrbSaJpccoIoPQfnIWRwBmaMkML9l/euar//hkigUIDfv26mURf0REefcf/vDH/1FKIY5DZnjRlBfwXFi0b//sJ+8/loI3NHLB0DZtb2khtV//9xfeDNOI9r6BbAtRDtUyVMrtPedABFN1GElSJPsGhXU4aaLPt1JiQJaSZr87hXjDVWxJXUE8MndxKUvS53emXEY3G+FzSqBebEZm+dykRnKHuH+EBDOgf6/6vVgKcOxrGUActq9kAHkOeykDEAle8MB/BjLqkSQ3ScNpSmV3pPbG6mVXWbZ6rxg1av8q/gk6uuLORAz10GOLe8Se0Swjwwc4OHfnfsEBl07AB0NeGjiOpBDssZQ0F4QpN0J2moEb7sosxmgu56C9w/vFwFX+2D4woQkg74cSOr2heXGg0gDnyf+GKKgXQnM4SRoXLieRPZraEEs7USbECuuLgfVFcX0dsfkchv/blu4SgulCuwnSnUrsNy+ANoHd4gMD3mzFomLti91wc44qLthQhF8J5zaVNKnCQo4g9aqyX6YZ4NMF1iy11WZH9Jgm4HPTwoTVwQmFn7CUT1jyE3KQMBS5mba5IusW6AG+18vkpfrUpY9BWpXRFNbVEFUhwRy0G6ZdMOsCHaYYaEmSTzPh6LTOhwU8qEDEAyjlu23ZJsRXRH8byKDH+VkeFdwmXZWIouV66aq6JV3NsjlyQEhX5f9nuoqghIN3Xo1D9zxRdU7sF0hU5S+WqMrbJ6qYfJ08EYkP564IGwu5q3K7V/nu1/hdv/vb+N2B3FUO5q6yzwKkXTIjN40bZljzQfN6aRgFJkYtJ+rD0jCVpWFqTRoGVKN/U1L3HY+0Q7hm4Q53KPfkVF7EohHwKbnLciNXMDL654S9eiDsVcAJYW8XNzHAj9scuic6P1h8MqV4Q8EUqfiT0GEO2UF7PnWnSJ9ZYgKokOwu0KA89+YTdWVdbUl0EXKnCBYJEz65yHQkoFhHcFQoOCqU/sBEuHoEHAvqHcw6Ge+BdUaxyNNIp4fxhuMnwU+eh4NGbmofRIGR8QOYYWv0z6C5u+AC/yG8gaLN3RSHs4M9ndnsGZYF4nS8kXsanKUiseISOqRcU+KwVwgnn2Cy6kGMYq65IDxnAabmpxfsqlzcK0YY2rRi3wPEpy22tJJ3b+AX1f6Lmg046CxMkY95Z90palY73vS/qGVjLvMXVkV8dBakWdiScHKhu0bOuj0b7DlgsxblO4UGUsfIVoSb9MKZFK7qUaz3l0qF7voQeeeAXt3HFSix/8W/E59nmSkTXhqeYChFHUuTlOHgBgNWkzIe55/6FsUfN2+ShbwmTiUlK5ftW8+Q7u0R4gEB2p9PcbOEek923bg1IxRNiox0/82/+uGe36Ioz13eKH+LlvuR+HW2cedTVCsJi5Tf06z5ivq2M7u7NMOfWzuDXjPOiUKvOKCZkojAtPud2/uM6sboh2eJXlYyJL+yX3TIbQq+ByX796Bk/x4UsRB1u5VHSJH4HpTK70EpdpT5VRdksfkVmn35nZjQGa0SjJZ2kY6KP8YnkBNGwj/phoK9wQeg86KL6oQ/ZcPXLggiL5vfjoIHZ2OhEEDSANyrchOC9DiLSnyFkY11xRnrrN5o2PpvGcEFFeHttIbDD7LB+DT6C0clVBoC53oBVFlHyRTCpG1O3bm/rjv3gBJhwxlS4MB1imoiXRzr3QWMDO2BwgAXXTH2CAkT7XbFihynWVAIXaxxDid8AZjiRpGhsy3nQKL8XQGKg+xmqBJhOFNPnb+Xzr6rDBJM8YxP5LUwF3stDgkCkEMgh1HZOW3sPt7Sv7fGgSMwz29PoDs6LWs9PjrPtlM83aPpXQW0RHtXoF4FXguVIu1CziALTEn8KFZEhgASoZCDeejFze384+9NRN9UKsjNAIwsyixgGU9Ly3//aX+th6nvpRDCT+KRdSahc5vw9BxT2lEoUoz4taOh5SbliC+DjeYWgvzNMFRb0jjExcFKygaKpmy7KH2nrnSc2aIoUfMI34l9ujsqwU4XF+OSgutCaIW6SomWsFUbxPcnEqREc4pVmybe0uFZaEntpxT25S8hOQmMj/NcLBn9rndDxhlK2aCcC/x/w+tpiV0MStgv+SKxJDm3lzIgZeJxp3WHNZde8LUKVo2SFb9GTFGHFmvYtIZxYJ3bYq/kZeTeb5DYXeGQBdmN4tMtYHE1C3GCnn39y/64moNtV98UmDMAIbUL87HfzfQ6m5zkIyILyIdRVpKbjkTUd7rKRUEcE1VE9GeSomrC2QUbFBs3ArafVrPrtE8usHDCASWK3U4Sckrj8GaJzGEFWPVhDVhncImTp0Q7uxSwvuQHS6nTf6MKk3NRjsuoAUc//J2IHpZcAXaHMZZHS1tOs1Ypb4V5K8hbOm+pvCV9C+JOnLmsGMMfSFrHSYnewVEKFiQazHKG6NJA+niZS34BrtrSx1yWOlRn3yth50kXWEjg1KFLfJELKRr0T3NOHLm7j8TK+A4WXpY3k937QzKE14jdppx93qkCSLsmLdoBI/IOybndaG/QI75bRH/qUS+aADYowQEXL/Pt2zVKv47KyzUqL4sqL9dTeb1G5XVf5YvaqAva+ByQza6a8kVkiSSEdAhOV/nagOLcDSFnjWSiGkSu4I0KDQqs2t0AJsq5ZF7G90dsrTna51MQdzMQJVnSeBllWCD+cinIRU+1mBmeGXZ2dz1/wMJMR/lfc10QCa69QOTjSNedVQgEcwGqF9SOSJvYf+MRMEHsi1+6zJpM1I43CC8Dfhj6P3h6oP+klJ6VQIqFqDB4W2rdDUr7idRu4NZ+BbZq+/ozl90hFN7vQBmZWAhPprHxUX4RpCjQOKaRg4w+7xYzmalwchEwewelIV5XGiD9uOJHPXHIMkMM/hyR1fiszcW8Oo95KT9RiHk1JyE+5gVRODTm43UX8PpUhALdQzCyHOavXuHrV/nBUX5YgcOOmtwa8Y1kzi15BJKiwQONg/l+mbjEcesJ+yxTLyZyyg4TXMKKM92ZgnaLL7kx3T6LLbLfVt6n2krfTVX6bsrlVV7wXh3wTtE0IQMir+zje51W+OiMt/okws3eTCLrLpdnHiwbgCL6Yp5WSZdWVV2oAdG099u3n3ZmwedXhZ7rWY8LyFzCnGPYL8xAHOPNIvo4h1r2yUMwgZgH6R228tQshV60X1c+G4nYTLkUFAdQIR+x4cBILYPbsYxoLhe0BQil5aJVi54WKbyEyw65dCGiCcem9TwYkcGxKshbkKRtIrpH9FX2gK8SefywvKE53ZlqgOvief2Y3RIGNFzxRN8erXuBi8qPLIq1FhrumHo3mMqxI6pXMszMShRtQmX1bhxCruB68faZenSnWHeOWLj1ez7bjyLBvoX3MQLMJS6MU6O7GFVcaagc3bGOiGGbuwRxgdKrlWjYfWMEz8gdeuFAVC1sPPKEYzql0QYHbdX7qoJ4jK8mISPxEE3mmPnYQt6UWbM60FyJ7nYrXM7+XwGocJ0iGBGV3Vb9s8tP4ot7PpYSOvtv/OwP/uUnf/Sdn/23IEz3/+Drf/y1l/76wo+eQNdL3/z7n/7Dv169/tVepIXkv+g7oRTu/9z5Rri0eGzPyRMP7V5cwqFJLIbpdzv93Sn6sFkDN9bAyRr43jXwKP1tpr9AuH+Ub+NP+2dIf8fmTp6cP26OHjmzfOL0qb17z546tzh3ZnTnUXP6lJkzRz93+tT8UbMyd/LsvJB+LsJ9cW7x8T1Ly8f3YC9n5k6dONY9ceoR2hEpstiGNZTbUwYHBN9DzxJj6NYv01/F41bx74boD/hH9Ff176prxtQKOPD/5MRYLM6de3Bl/hjhcGzuzNyxE8uPm9Mr84sPnzx9jsZ/Vjo89ii379jvBXsCy0+cOj7/mDl9dtmcftg8dPrsqeNLe83yF+fNyflT5sSSMQ9RF2A3kN4IcVHGzKdX6bmJns1ms9Uca443J5qTzXaz05xqTrearVZrrDXemmhNttqtTmuqNT3WHGuNjY2Nj02MTY61xzpjU2PT483x1vjY+Pj4xPjkeHu8Mz41Pj3RnGhNjE2MT0xMTE60JzoTUxPTk83J1uTY5PjkxOTkZHuyMzk1Od1utlvtsfZ4e6I92W63O+2p9nSn2Wl1xjrjnYnOZKfd6XSmOtNTzanW1NjU+NTE1ORUe6ozNTU1PU0oTtPy0zT1NH02Ta/EE0NnFk8fP3tsfnFJVU7OnXrk7Nwj8zL4zbNLy6JKXcfml5bmj+966HEdLtK7Yx9t7Z5s726Z0cnp+fnjU3NjcwZ73NVq7Wq2dpbOzZ2kYaXm7tb07mb13NzSo7v8/5C2qbl7bPdU04xOHJubm55qtSd3/g9IpGIU');
+export const wasmBytes = generateWasmBytes('eJztfW2MXNd12L33fc68Gc5bcikxXtq672ljL21+zMzOzuxSir2X8pJaUyIdSbZlxfZwdmaW3LffM8Ml6cjadUzbjCQLAqwUTmoUauvUSiG1Lhy0ThugC8RtHcRJDdRN/MNAhMJo1cZoFcRpHUAw63POfW/eflGUIidtURHad9999/Pce77PvcMa3UXOGOO/w++4wNgFxtcv8HV2ga/z9QtifZ3BH75+wViHNPzl6xfMdXzDB1+/YK3TOz35+gV7XefoBF+/4KzHee76ziRfv5BZ7+f303z9gre+nvqUW9/yKj71Kf4pY6ZhDOQK+watjNiX9zO2aVqm65quZRX2ZQ+YuWw2l8vatuuaQtj2ftuxHcO0LcvzM8LkQpjCOmjatmk6GdOyTYcJx7At0zRNIUzTsBgTpsVX+P79lsHZgJ3h61xtbGwyz/k9nrMX24vLnWuC5dtXV5Y7vfuWl3rtqz02lLnU6F46da3X7rK7M5fmW7OUPpS5tNhoUvpt+bnFdJ23eysz863ZMn39Oae1fF/n2kqP1Qbq9Sszc0uti+2l+mJjYWG5yT5WSOXNdtpt9pH9qZxOm4p98K5UZqPVqveW691eozlfX1meW+q1O+wXM3dyprg38Nj7jt/54AfK0x+6d+zwu37p9NQd2X/+H3njD4dFZp0/cTdT/nzIh5mYpH+Sqb/k03mGqW/f/OUH80yxgOVMTzLlRaFQV1cDQ7ERcf2ZydCcygt1V2BBrZs3nfN5Lg31TimUu3YxZPNriq9KpnhvXr03Cqwc9+Ju4B80svHMZGgrH9tWPzagtDc9FBrKWAvFWsiUsTqVZ5Kp9S60JaLQUUYvNNSPn9pkUcikoTae3mTRiGChGBFu6J7Oc+lKVhU5yaRbFW5OeBIHfOOZyQfztmR5EQ+fTcH7JSV66pUndSvuiDDV1VVpzAdCuoFtAFDcEeGHLM8gMYgJe0QcCiwNM3tE5EIm7TPwQQ0qH3IGQzZELeY5glB4kO2GW0fHPagSSVv5kWRDgWNMSifISDdkkM0wG1pzhwJHMmzdjRtmnswoVhXMk9ZU3qbBQC+HkznBhNhpXFBrRPjSPp1n0oIhDAIochmP3nzJ8jznegQ8JgE2nmTSqopDNEU/dHEtYNw+FTvk0UxdBCR8GNQfclA5AWXoSiFdKaZhFkIyaUt3KLAlC9wc8zwFYHtCOldWq7AjPNhAUcCkkIYyOlVhQioKDWlAixxycpZH21WKnhJS9EKmmGTzndVLYQa2iNBbxJWiv0UYwN8BCDgw2Jx0pUOLoIeQSYbAkr4Z9G3DEOZDl3qHbiNogdE2vvHMZCimAFVGxLPPTAZGen87ikuhjLVemFmFItKRmQ72IgGb9DhF4MBegJx47IGTGnsgPOlIoyoAlAaM3YCxw0NUhQvoCTCDeVRhDAqX48YzkznTU0zvePUEbg6Y5PVnJjU2SqH8adi5yYxtmDEAjibrwmRFVbB4qs4UbGmaKktPNaO4dHCqFk01Iy09Vac/VSfIwFQhJ3T0VDPS6U/V8WQGdpErnRhXMvRwYpShHcGSASMxYP3tgSvqxpAQCAnANDcCrOc5Ad8BxxPCZ20hAzbSPRcRF9oKXCAi6uekpfjavLTUXaeHeoED35FC2KFNuz3MSAOgmYHtkpHiPEA2cGUmEDKDjQppB5iXMzT6ZGCC0paOevuaMlcjwDX7zBDkDgVMOor3AkfaeeZ5uOXdjh6dkFay8aW5ClMzthG0gHmAy9wzJmOEDG0YmyOFdAAlM0OBBUs+DfRFutBnRmBhH8CA1CewPSmkJR1AX1dmIOHSPGBUEihDHxUYgQAQ3Zg/n+ce0M0+uXSJXLpALl0iaClyaffJpQvoasdbwNZr7wJddLeRS3s7ubT75NKOG+6TS1hvHDINCHpKkUx7K8l0t5FMcwvJhEVMSCZPkUxkGjaRTBtK25pk4mztqTynD4P6A+6zPqK62xA1RlM3QVMXUSBBUxajKZbdENPYs/AHc8Lbym9jeuQQktrSSejRj3mfINlEkH7M+xTJ3kaRbEJTkVojeIh4qbaiqbsdTWExASPzOyUDvY/Ox/vqaXo/m2eSKzNSLz6zCST6n26YkdrYeHzVf7eYlHxEmBp1GfAyPiJygUVQ/dIz+N0NM1H4U3B/CQkDfvkyUG8Y9dmhKrz0B/FcTNdfeWoTMR0mjKwXJxE60em8kMhVWJ7lDC8B9FdIvADgMsAAostfeWYS1vjmXVXxAvZvVcWPnoJEpip+iAm7Kl6BBAK8Kq4/DWkAeVU8/XQ/fwPTNzD/uVSZG5h+FtNfTpV5FtNfwvRXUmW+hOnnMf1iqszzmH4B07+dKvMCpr+G6d9Nlfkapr+B6W+mynwjlb+J6U1MfzuV/hamv4Xp76bS38H0dzD9/VT6e5j+HqZ/kEq/jOmXMf3DVPoVTL+C6R+l0q9i+lVMv5ZK/5jgjOnrX0DYfgHh/4V+/gamb2D+c6kyNzD9LKa/nCrzLKa/hOmvpMp8CdPPY/rFVJnnMf0Cpn87VeYFTH8N07+bKvM1TH8D099MlfkGpjcx/e1UmU1MfwvT302V+Ramv4Pp76fKfAfT38P0D1JlvofplzH9w1SZlzH9CqZ/lCrzCqZfxfRrqTKvfgE3vypEwKCYuhqFogr4l5T+MZWQbF5m1KNRyCIgHT+V4p4GwiVjyQxEATVSFSYsycYGr4rnn5kEWiCkfR7JpzhLTC0H0twq8iC+Jq3TeUPTX8RdaEja00OItNKWmShwNRkg+kD8lIWORJ0jIQLC83ZtnXtJdZQYNB3B0QBPBYIShe50XmB9N2CJaJwCiqOBsgMQJgHCkeatAIGAugDNQWPIH5QfTQ+FjhrCokeA+gRZ6Sg/grdXn5qsCRBBgEzLbA2EwFtQLeADrz4F0t7hCOklUw6yRmVCfXcaeLAjxem8Ix3pACN4YpUEfEeK+ZDpwaPcR0ooMTIhmT+Yc7cwMldxyZCR2cTIXGlrRsZSjAyUDODLyMiYZmSuZH1GxpBTASND1pUDKNEDuFvO8YizAbhglWQmQgDTugAg+zzZ6WcKzaDnA0MKzU1O53X6WUibwKmRZSljVfEzOE8bJTR/AKVwqCrtCHRbk2QwWwMMmB9P9BPQCABcRsL3DX8wZ28Bl1CgnafAJRJwGSlwGYEAcGFWrGgHIqVoB4anISONmO8LeoB6krNg04LkBxj8NGKwZqWoSbm4g6WbklsSTQtVmJztxcqF0Doeal6FszBqkN+epUZRqIehJEriXlrADdICUJHD6qCA6u6hFqpyfXUzVkBzFqy8Q+udmoZGSIFLHyMkk2JeCyoyE4UCsdImrHSlnRLSdpInwyPUeS6GFlQCjZeqJ2tNE+pDAMUkXfBGumB/U5CIFWsxceOhkMaDehO86YXyJFd+NMwk837/IPfW72bqZTYfZoZBm8moP2VRVRzu61eSA6mzULy3SBCvyMypjY2NDb8mjoLAn1FHI5lRhyNpSsvfLzMjooJ2I/8ToHj5D4dZEJszMlsVRVAuq+KoFuVlFuq8lmUg5MOHSkqklFxaZ1Ep49jpiCgGpuSBhyYp+Dof5iD7qLTmH4SCMBBL5hRX3H+HHoeHFUNTWlGQFZMypwRZynJqPQpzOAjFZO41j3kwGJmLQigKgM/Bp5MMNWLFo9CDcXiK3a9FX+nlmeJBHueqeJCTHs43D/P1lLEa7AOwQUFlAoZ76vHVoAB5xqTMSysKEQBRmDvGmKp2oa+s4hHMIUpnCcgSW7IMyDJSWQVpAcG2zgC53qe1R+gEu+iXg6kEltwH89+HimBGelUxKTMyXxX30tu49HDgxhQCdjySQF0SwE7CZhgR9wZ5KoHdCGkkSwl7UVSTpSRAFWhTEJAKtwSSoYFU2A6k77OdUEryUmBK8lJw0nnGboAqpAGlC+4BqQdkRhaq4n56e7/MqO8wjQF5KQgDHsHNlhkRj4YWgfCbDGBo9WH4QIB78/6gQLhQkF4ks3108GDOVlU8QA19UG9WmLV/1kNsPRzBuP39VOL9oW7HwhKUOU6Z+S2ZRynT1JmE28CtfHi4gdGnBTlQimHLA864wKVi5EdFDBgKUoVDfarg5yzCBwvotf8wWJxZUkqaoD1n0CAJHSseZBULTMWDAS2iIamQVPQwEYZhvZEcGLTG9sOA7U4foDLIwmMYiY8EexdM1yGoWRGozBq2I+JwYOqytF0dBPUwkjzzjG7eP4JlJVQeSNEBURXD1OF+BFQuqooRHJ6B9JL2M3J2DTiDMncSUMWDAzkzBpeB4DoAFvngABgsDvincC8cINJpEOlElDsg/T6+GdCqQRPX9HgkggL+gZgO/p9OUsXrkVTx/whJFSmSKnYlqSIhqful6JNUEYX7q7ckqMbeIBI/Q4IqfkYEdQQI6n7awn8DBLXfKxDVA39NoopdEJWhsbt69OomXzibmDFx0ZHsHgpzGuf8SObSEzsU5ACxLTRV0uxHxKDMbZldDleUrJhA4MJ9uqCU+2hAAwD9AZmT7vk8+C28mID3SXpc10rqJjM0tbyWTM6HUjYNBUuB+ygnPSBZitNTWtLd8q6/g5X8iCAmgi41YgiHtvZCo2HJaPqgVS+zaJgVoF0L2vv1ArdBlC3Oh3bfL5qSJYkwSwMFWUPaNHE7mbjigYPOPjWMgoyfByv/UbD7j4iRwPA/oQYRyxwgRI401Jc/u8mkGArdY8yAve1I9wQXJxmH1Ihgyl07yYR+OckMrGRGup7iZ+OqZlLVSle1k6oOVnWTqiKp6iZVM+mq2aQqup9ULqlqJFVzSdV8uuq+pGoBq/pJVfPsUCiwqg9qP1YdgFRcdb9+OckOgHcU4Qu+H5PeGDkkh9E2Cw4wMN2bIH0MBgbRMSOGsJ3UTHTkKfT/JSImfnBjpoYWZhuYmnY0GZA5H8bkFdw8oUueXCMKHDS2a6YlgBjhmBzQrJBpgZIsIizqofPHIaYF3jYehdCP5Mi0sGkoxdH/wqfyDPmWkBwnk4HtwoEog5BjQBEiyhyIMjRuoMAD4jIAPILlidmKAJrsbuFbAkiyu4VvCaDI7ha+5eFWCw0kyFlNkKET7KJfDmYTGDILIMgiPUYggnMjQ84PDjYCjgNHLLRVLgI5oQ/YQUCnEeEHGU2aoJtEq4OlsNDQNah44CUg0uDxgGftCR5Lg8fbDp6Y76Thk+SlAJTkpSAUs6LdQOSlQRRrC7vDSEobmNVhejtEhCMjzZhwuEQ4REI4DJgqbHvEJ3cICFtW3fz0a86qcq/KrDp0ld46VUb4ERMLF4jFrYqbWNxNiotbF8fdnRAFF4jCrYqjtzohBC4QArSC9ou76eJ+jOcYeACmS43n0laHIskBHPHeORxm4SFDF9bXOMJYDcUJ4whzawwdUSPMrzJfGgjFswTmLLoTbwVpczukvf5ovduA9F7F94D0XsX3gPRexXPg+ySTpUEWm0Ey2L0Z8BsEfiMGP+Ufovws0to4M0eZmS2ZLNQ0lzKlrYqa5apBZOFCcs3a9ZPy+09pwPMnGV4A1rzJ5kN3K29Oc2UeZADb0YOsijEPdkfEeJCFRyW1wEhUM/ECZ19ngTOpBc6+/gJnUgucff0FzqQWOPv6C5xJrWU2Xsu9MM8nh3QRDGiwltm9WGYGaQutYcwigW+B+huroA64NWJ5B8IYNJ9yYhMdbC5GUULZfpiNtJWYDnKgkxlaYk03NIjDwEgMaAb5KJrlD0kX9PZBlFqliDkCWDS1zEYFDlNogKVMTRYg6iDch570UEQy34dNfuu6uriuoIxDGxIo/PuAN2WkIfdJ4ZsEgTh6YSQsICggPRz4mF1ALWogVjaFLOAsBmDIBZjKgPRlAcEJ6i2W3i8LwQFjknZpqktfHvBN6koSHHxgUIA6GKowHh6oihFIHxHFmiGTccgDsgD/Tw9RNQHscFAxVBoEsUOhXrt586azGhyEfGNSDsgskExxjEE/2Sj0jrE+UwzvkHfAF494Y8IY+/kilW8kLXnEIzH/IOzPKMwigxxE1QM6DQT2h4PQtbLprgOBqmSQlYPAMQeRY+6HMe3HWDuMhilglAgFWwhUesDeLRH+8SbwAwHEoIChgaIqfHgTsgBbB5qYoqXMQQDFgF4kjF0o4J7wITMmZ7gmWVqTbCpzkDL36UyZh5Hnwa91Pm+AJw+SaaIG2soR4ZLeAuiRmEhjMumqzUQ1Qfr3d11uxfTPGQaCytGqpnemSc5EExUTcEWhlczp29IghAw0QgckL0Oa/n5wwR0KTCIAJu5YDjvWIdnNAbOZH2tsHOoQcPDDocTCDkh5lqRq7BJEOU6GICFNCP9zQDM1cX1wCCYwSFwfGoGHlUIuzQhlNzeWqV1YeuT/BvhyUKbmUD0KoWgSmkV6k6ltaofAy5bY1gViWybGTfRoEKnjhCJoNUyjiBaoTZR1QbaDPrcI1MZOgdrYKVAbuwnUJiCDuU2gNiPsol8OphKYaWERoTsM/iiQGfHtsBQ4cJuUjsMReggTwA7DNgDjYoZKYDeWtJNFBN9ibBLVYPJoM4hYqBZ7g8jWIPK2gygl724Xqo1dhGpjF6Ha3g1MXhpMuuAecCpKB4Tqo/Q2Ih1V0Rs/g64lB8QB2GnOiLiXzMoO2GuENPvwKwJTA8HQIwzwgEKkkEDAjM2qKFI7Fb1R0VZ9Fn2H0KEHmEYlRkLdTmKVcIA5mXpxUpl+qHFOW0mcmBgAYdJCUfw04fkXDtkt/PkwN8woFCqvCqvBPplXr968eZOvhgW10g18+MbCvCwo0YvIUk7WcjEpfeVHoQ+ETIke8GmZBxE4rx4HajYA7l9fDigTrD++FGco6knmR8B/CnEQeWVGqRc3/ZJLv/jpl8H0y6H0y+H0i0y/DKdfRtIvR9MvxfRLJf0ynn65NwoKMg9ADvKygB/OxCraiIAQj202rk1WE0yLLGJSSWWS4RQMfBnAzALEDBySBfJSFGA3+rJAAlGBwg0Kp771T37rNwbBaOQGQuYUhh3kwILp0pup3CAfE+AcCJ+uNP1hmYPItjw83KAA35XoEc+HWYgebFNt782BWUGAvSwP2zWfsAyMos0jTYaWZV7a1LIJY8GWdT9oCUG+bUM/frofS/q6H2gALRpx+xllREqshftAwO/3w+U+6ifpAHgFWXsVw+nuU/w0bkK+Gnhyn/rJzZs3jdVgQBYkx10LNui8dAIfZQdfbXxuE4yo7jRIwejMh558ABh9QlKUfPXBge2Tm1/ARKIwLweQ1nCZjwIuPZyu7k46NNmd3XCgPhwmj+aNjDJW0WeL7NiO5L5IOSAT8fMIMrBUoYgElq51JWVeGb353mpVMJJJuHr1s5vMP8E8ZSrpfxjlBIARRafkjgizBk79HHrevT+zubuuLVcMoP34KoQyzocYgIuGKxNWgMzJQMeBZkPYMkfeDGGuOs6ZUzT9fJhRj5/t5xrYNLBbcZQx9a/XPxChcJZ6EekXI3kJOKiYER5TQGknCgS2JlIltJyXAb6d0QWNCA1gMcfS8mp/ABALTfvDgbY4tQWZIskUOhOoNI8CF3hsYIAGAR6GTY5/MO4JYEL+QNjapnrVWQ05BWpjHAxUp7Btg6BBZCzMwJkUZ01mlL3WUZ/+levuquRE7/b4Zu79ba8PIoqiCC2AfhRyyYgkWdKZBxcfCEuoY66RLiMFPSP16Q1zQflrAHMXAQkTcdRrfJU2IPTHdX+83x9uAg1bDEzadbCwbhrWeOZG6EJiWyGID8GhHYbZ7zIy7SoEYBc8st0BXoP27u5iv4P9mAF1CUz+UciTXcfT+5Gn9yNP70cXPCSwIzOI6EKHa2ciXFdX8lRJwmtt0aUQd+8vbW6v73AHMPV4FJroW8DILWUqV1pAB5whySOawbsinJFDEcHu2dhFKVSGotsM3w8FCO4FshIbcPjqbN5VYK/yIjxGA0ejAsDzq7o1bBoCvhwIwHeAVT1Ij3m1seGezTuSBAYbDdVI8ZEQOUk4Gie9XgekMQzJCy0lVvPgVcXjDCAiSj6dB79Axh+gUJ750KFwdBNEFKBmfLWjRAdooivBsA0HQXTUN/jKIJQ+Dk3k0vELcPIIPLo7KltQmfeDuFwvDnSyo9CBkbjaf8/nQxvDwshTtiqdfivO1lYUQ8qHjMYkq/P28drJcRcH+sGAv3SMIoRe8fhEiU1naxyUD3AYcLZsz5Z1cCC1FgPB9gs61u3pZHJn8yaG7ZHbQQKJ0tY3FvsrcK8ZdHgG9poBe82gvSZAhUR3z4N5W33z85tMHVc/+vwm8+/7KUe5Tu+/Hb+/zvfdp2MR4HkMV4DJ03Ew+3NwyoaOiRU8afgM1UkMX3hcXaWhgzoxhAed0FIrRTxH3p8jB4GF+RC26z1vc3Mddz1QQPU4HfbDiNiITloBF1lFpUSsgk6H5BTi+iDYFhgODJf2OMRj4CkyY1UZZwDhpI4j1KfLKLoSOqJlwhBHJE9S+APeDvSHSiF0TBqqRitB6Wcp4tPQx7r8AWgFoxSTTcAiqQWJeID9MQEIpdgxpKSSp8NjMcsfpPg9OFiwjmLYC3DiIW9D5qtPTYZw8PHmXQGnaEO+LdqQ4akISCUdIQlOwJZEXCYxmB4dn3gewgkfzFtJ8OAUpSHM0FRHpvMmBT5zVDBwbQzJzlKUKqJoNA1nDUHMDrgOeubbYhxpeDfSw+vDAWkm8Wak2nEsNvCUm3fhn7NDAdftvgCATQfGGkkcsaDAWEOKXeOIjZ1xxMa2OGIDPYeSaW8XndTjGC3b75RWg9Fq8Dc6eLDzYOymWsfoUc972UpUPydW/VxQ/TLSjVW/LKh+HoHfRfNvFOS2qH4eyBgeRg2IHkpMLqh+Lqh+LsigLPBQgIdImr7q5yZKlJtW/dy06uemVT83rfq5adXPTat+blr1c9Oqn5tW/dy06uemVT83rfq5adXPTat+Lqh+WTK2Ba7MplU/5ZzdU++Lgy6AfYEWh6FexOR0ysV2qMyh0MUNrj/lwtxLp+Tn8HxZyF+6DonDoXjpEZQPLDCFeRA/nJUuCdVKrEVnMG7pytm8IW2Znc4T7fMoyMwCs7WBeqBfnNpj1Jbn3xN6FK3tUbCEB7vKlJ7MIb9TJnxD04lLb2BIRKsR7hnoed4/JlFS0EqJQ0oJnRHf0aexa67YA6rKlVlpq+uf22T+QUb2ymHmfd8S7jp/Qr1HbWx8kxGO2BhADWJWAEM9uAbuoyGIJMuQrAPfzdVElNdhdH4scNl44jaJqHMo6l2bugApHSyPMqihcqtBLs6HM4dSSC8K84mkmU/LoPm0DJpPy6AOhK2ADOr1DVvYFNpaHJlPldQm5C2WrUwUZMAyfEzyIcJfOCp7gudCfj4JBMKZu6vIUjPzKDdrlU9/fvuaMlb3mYJxBk4yEotc9ZObcAxhLUA9/+bNmwwoCITTB3gkhdwFjgJVHOjSTWP1fF6AOwc8PzDiLIRGjQh/wIKzbzkL1Tl2RLjhvq/CmG5eW1Vf29jYAPLqYkU8BZOl03SgRcPCVPMQsuvg4LEX1Bt1v2J7l6qY7tL0PPyalf3WBMWGQ7HcgIPn/wMHhBndOktaB6FhGq327Hze2GNWRtJFulEQXvbVhIsSfDIzh2aWTcbCKOwUyAxUC/KezHsvWKKwzj8Fln2Bnk1pfR0tLV//teBOrWTcRAnnTul8/Yv3McaYr4I7YdXu/EJw5zapRN75Gpf2Ati3GWg5IEPw03kTCMk0mbxP5y0plDWdR+sWC7LINALNhHPYeZD/KVGcjIJ9kOkHBTpNCYG2A+izgtG+5jFpBPvlPvDVBAekFwzKXHBQ+oEjC6g2Q6AgC+6QTsDkwcCRg8FBeSAYTA5dEzoC8ZUiJvJEq7tonqEE/FX8Cvi9mLxDDirrSiQPRMEBaanBaZQwLTVCHgNL3TuNcD7Ylayr/vgzT/2Hx6PgDiRP0unIg6vwXO2o7//Vf//xVfhieEnpv/8n/+V/2pAnwEdlReEBaZ7P23qE+2FNcQwH4lFEEF3rSNbFdrvq1//iV79rRcEd4Kkyo2A/GqICIQ+qd1wJDso74lGDJK2+w7TJiYGrqQAGQvC9gAmW5IaDEPCKZJbLQSA4Hgk+B8BPl4WBGGCuMuQA6A8DErRJDz1HAg5mZAqeEkqo371ONiV5QJrqh/oFhCv15fiFK65ejF8MZahvX0/sUKb6vn7ZNLmrJQ0zdsCDmR+l72OMhfp4B9J3DBnga7hvYK9jSDFuIltTUbT+uKmda22q9Q8gNwOZJaM2OLDojNrkZ/IG6CMwfZMkZvPU8xsbG3YNwxrgWpAoBGYM6DUiTERWoBQW6VOoWWKrlrQBYUnVDOA4N5ayqBT6MC0pTjAeirgkBMwGAgRnKAnOKqIMLjIWheNAHytoS+R4tUCYECOMU7ByJr5jgPiRp6uiJwSO1J9gLKDTw+C/RnDSsTUwr0FLUCATF0DavksLjkc4tUpuCA+JGkDmBDexeRAT9MfMlo+ojGfu4fukKb17eE6aYKh2t4Ic1dLdIc0QMkYMGS/eDRg8YIG/A08KWd5vmsKgi2fkfGikLp4RpECB5dzYGhKgfVEmqHYGOh7p0ovBOAIaMEIbu/HDYWDkg5F/BLSzQ3DiaoOf1PE5HB02h9Fk/r5VNX46Txc4mFB2ENVDrOpRXTMi16JBrkU8RUAVSUQUL4X2KesGDHj8M9I+dc/npH3qPZ/DCWxsbN5kn7ku7VNDOuPl90EZj95evesz169fB+Mjdi3NeRA3qX9pqtg/auAJg2Q40oa4Jsy0sB4Yi8CFyjGmxYwBZapXf+8P/t7jQFLMUz/5nT/7N//1mV/7dQjXkOapT//b5/7l3/nz//afl+nkhUVinElxQhZSFYUzheMtLmpeh0izGcToUQLRPvA8KRxELsQH3OPjK+Z7b6M5ueTDslAqjSOHBKn6Op7BUDJ2cZM3y1SDYPV+2eA5TWgEqTQuHuQx4wMR/aNdeIAS9zTM25bGEvxZUexMBDsP7vUIHMB98CGcBec2WZTP5jnZkfJMmSgk0UkzZaLzHCDpTsFpXKFYcigUogIsDCfVcTKCkBv7BasRRDktzEPnoSOtB/PkiVAoWivTfwfwshxYbWFGdEUBylUgn5HMiVgGwY729FDokTHWA0cISI5PrAZ5LOqjSRSIaBasMiZ5WRH5e9KkpBupQ6s96XQ6CIAVCJjPo4E05Mh4cih+QgPgUD0E5tLePFQADHZREYwkHuAFA32QAbEKlCVbevNwzwJIeOppcFicR3MRBTjEGQAZZAOkH9AtLi5GLQ0z77MmF+ugKEfxMU9YSM1LDG3JYWQtAMU4PgjKUpYcsdWSw+MToaJvyUEXjT7oybWbBU7HDqSCF6hjQSakvglHpEw4wMquroaGP0BWiyg2gOEMsGXJtphweGzCgarbx5JUQgN4cmiX+4OFHWd2OZomjPjMrqFNEzxlmuDECjAr5MmZXd43TfDkzC7fdmaXo2lil/OnFJ1M50+TcaenuN0eVNjtcCq1cmNrK/HsC573PwS31nWAGVd/ePOXgYbA/2eHQjZ/Hi//8iXXNnBO14NFKheRbREavZrsINCUTEnSCB55BecBMasQzlUDp4jwuhw8oY2WNrQPQnd4l8p8yOcpoiK+egx4H2r12gwKwwevPxgzY1uuBYbSrXZsgViZLgRBjgVk6Xh5SkJxsajeCogDXHfN0WiIkfMPxtayLcMwwCKKB3j7d1UJ7V7sm5kNv6BvhwJa5v2qgeZUoEL9y4+SLQicxEiCekA81Zfo4KU6gm7S4SjkYUwVauI8PjUfR8337+IRQxghYgaW5KGAbIHZGOwzFIBOAq3zuGE456FvNAPiSssKvaSu5+F0PY/mOKdRKgBxQIq8AcIZDgc1HxMvf8OQLynw1ieaFrKm0yg56JGbGgs04qknEO30bWCExHQeBq9F4mSS5ySwC7zVBwHU/zCoPxS8wpZrfVjqWp8/EdzoS9MY8KKDVU28uAYeLgSrisl+PDLrxyNbQ7Sxk7BGe+94ZCsOV92r+NZ4ZCsOV92r+NZ4ZCsOV92r+G3EI1txDKvVb8PaHsNqIq3eFo+spct+QPH/B+zPCLD/TnCzT63dNayh7iJes7Hhnkey9U5pXAwFXf8oFO911Hu76NA5xWqAsjpyLcFooDgxhnC4yw8VIEPfqoWYJ1nMtoCJJQglzQ6iUiE2xxowT1NfPIiGWDPmjXB7m8Db2wTd3gbnQUxp6EvXQrTFiin03fAe3LwB5CnVlOeRv1vfeqH5KaMwyy13YBSAivlRPAFzxwS83xSCJ2pQX5VmFMLJ4cHVANiI4XZN8OsKUsK0SpYj36nKRS9drxmH9JuLbz5eY4prJw1/FCUzXRPHaFLD4d71/3RHff6G6n/p07esf4uRb6v5VcGdvhqgsuBvwRXGQ6mmesWZzlO2iZYmoewIb/lQj0fSgstA1LN/xJZCCx4L4LRGpfcHbAluj+mdYN+6MXkPw7sfIcjHVT9gC2QVTH81wAUCnp6bL/4niHNNFPUM3mhBkgZ1rZ6As17SQn1D98R2bZXaQAWJLo7TDaxjA3Br6jH2+zcmT6I7FM0zLJ6eykpj3s8GhpalpeH9puD2Ot0bJGKODhZhxdFrRRk+ZAjMUIYyEWfN6SG80AWFHoKupDhqbb9R3A+pYZOu9ISoEzPpg+Ib3reqNtY7wOSVvYYqre4OlhcOMsLJvU3eOclQAOcqt4YBO7oeVUD1TNeA3W5TRUe9nFTEbwZ9E/iNWqCSXO1fUytYltz8CDwk1xFSDe8pwYU+3AlmVnA4qU3EN9DwITRWOj7HEwMBnl6U6EMzIZDUBfVlhM4yDKNLCz1R/j4KAD9E9lYHVCV98oWUX3vLsVejH+W5RROOzxbkQlNfEHiWotJ9DM9GFRrMPJABe99GrRWGLIBU46FeSFKEnuHfH5pQggLH6f4ik0gRQ8/YMPP+inOugYHR5DuB4RsYnxrYFEVskUcIYmotjKmFVocpyNWJQQHx3BRvZm878XvLqYM7KRQUBU6x5BS3TyYwIMeYgYF1OHUBom08dUxSEKTt3w8ROwAJPfVYzCYHF079LzkJCMX50AKz1Nap+wL9cQF64iTqzjhtC5Z+BAAD07Zg2lY8bfTx6fge5/anbdG0rWTa1vZpW2/FtC2a9vf0ivvzoTfM0BGlNj67HQHwgs+sb/UDlpyU/SY5md7P2nk+DPySGEmE/pEQ7Voe0BC0AVs0M8ywEwsMXkIazwyTOiTPvx8MiTBRPTOHZoaWKk8HQnIhiJ8WkVXoSAMQSjaughiAdmeDbDlHpYgtjMNSqOFIPQdxnSg7wB8M6vBzbyNLwlG4XUlC+ogYDk3gWZwu4uWSgjHJOgUiBuYeEXD8gvcvN0HlwQcto2a4KHTm0LwEkW+mymGUJ4eufGpVHDHoXIh67gZcfWeSmg70H5fxX/WX0R4G5rVjGf3MG1gpPNJs0Ok9vNPDxvmQP1T0ApMyBB3YUiZeSmbGK4VJ7CTk/v2hgSvF9UpZWlGFwdu0Ui/2B2/hOpkUW6IP4wgKmYJ7c+NcvBKC+L4gDoQo1IOwWri0MxTKhTNfP8WzT7vTQ4Bup4dQM4aT8YTRknAt7ZwH1KPmkbnFqKIRx6LR/jEnhiH1aON/ivsl8CbsM0zOOAZxfe9JuFt1/QHwXgLRj6qQBV++/+QkGLPAICdxJw4OmGgWPcneDqeHT7LDQLxBSrSkhYvv63o4Dki//OQk1QM2aFEzh+iVNrm0/PdqTRO6XY+7B/v1d57USw8mpgeYx7x/TPiieGArU1sdrZek8dIToXtK3vgqXmYdiw7uV0NDd4JHeJIvsfWAdpUl+QII1P79Kc+6NPE2BDhfKE3/YYzIzcdQRlsBojPE53nKDfCuPYeuLtO37v1fNdZf27673/R+3nUbB29yG/8G56YeljGM5v0IA9ryuzIljGnjZNhHfcSg0CxUo5AgYIalzwyZAUcZPhFDgCCImCnxLUxJpKiZQWP7fB9kxp4gM14XZGZ8sYT/Tj0FAhIMFadC7eBM+kBC75YeyHXOtD+TnUPJmQO/3WMDcTyUd398zM2gCnr3gEkB458pF6T5ZAdx2kEQ6hKQlzTZQWgQ/jynixy1uwJt4fCLB9pnwbYOAuh4Mg7s2sSuKa5aq6Nw/SbZBsjommTT9FGDxWzF+sW8LyYsVb4+Sz3cZ6mDYNO7NUs9HGqGKI6A3FMz3O0sdRfWB44i7wZPLDv4qxV6T8SugylyCjAtJvE1MoEKirB8EYaEu4eC7I4xV7JjLOPfhToWTgxLkqrO1bduUeFieiAY3b9lf9LuVODucHFX/rz2VdGuBJEBAYi7UgsQya5ESw5druhd4GJdB7n6EGOPaiS4l8QkhAGvn6EAJ/CNkdTowymGlQhNGz6MgUlz/vRQkArPSsuG/Q7i2Fr0dJg7YouTu1NMDGg/m6cQ3cLrxhV/RFvZ+/Kl72EA+3YBBZFa9MCZ6D/Up0xWIr2o66hvGv4FiMf/mB54bNPVcWHGfGwtF/4waa4oywitZYNIOZXX62qgi4vHR/oSXdF7jPPXaTytGqc76Lcd9f3SaSX0XMr0g9SYDDqGvlNVW1dMdLWSjcTAxcxpG8kLG5tEPKUAG8kHuLGubzwReKc9RNyA992UHJ3wZ/JIgeAXNbYca5jKCzyYDNfggQ3A8B7iYp0ckSBG/OBJdEBFVUjhBoRD70gX2TGQKdAjReIF1fj2k5NYHp4CCaB3Brx8nM6Uoa/TQAHYTQnAbiwAa38F+dKub8F/7x5iEmmQ+fH9s4bi9/DDZOgHYBl4jd4guaknmFdB2jVHmKcJmOTzZ+lnbugSGZCM6PCzJ3nBY95kTF2o/GkKUI9pgX+mQL5bnBu6U/QPjNDi+zlvQl/Ev/FN9FYgvU6iyBQvJKGHRJABrShQxqtwvi7pnLK5oEy/EnDJ6ZKCBdhOGp3clM3iqHcc8VNPUhMzacxTnDW6SEA6R4UUpnc03oSagLEjmmoS6ZOihiTIjPyPMu/uhEODM17QftX2TzBt+48y7zDn67i0Pi4tw6X16QSb9w7EJiKQXPH7YxThKHR472AJipmwj777JNyyyyUbGjAZ8+7SN0HDlDpbloOqH063PrWt7Ttx8emcDYTJozfMZ94gvIIkwCT/OPzWBvvYkDcY7wjcDx9mnn+OeQOMfJc9xTuEcvtZzINxJaCbt6H/94h47XOTNbIKHxE/gjRLPv0w9ekH9OkOPXFORMbUGyBuWBq+U/AO7IIOvt6Nered8bx4LO8peFka/sffxrwCapGfI93Qz3kDTH3lxiZTd6tv3NhkMJd9TD375CZToxJfc7od5frjnsfuZhKOHd+NH8BnyZGeZaiHyQKkAKon45pHBKsZLM5+hOnBKNd/v+cwyfxfYPQcYZ4H8h0/yV58ZhJbJMefCynXr3gW8+vMM1jBM8Bcyjzv61nO6De6fit7R3dhrtmWc0ut9lXZ7TU6va5s9ORmXs5c7sn2UotemS6xfLknl2flzPLlpVb3pOxdasuF9pKc68rN/VgDcqgo5LH9ncbSxTY1rPM3w7gV+ja73JE0iOVZaOxi75LcZD59bC+13lC1E53L3V7zxNjEeLFWLVXbpdlGeaxSrJUrM7MzjWap0qpN1KqtWrvSGB2vFk8szM10Gp1rJ7q91olup3mie617onep02606gvLzcbCiaXlOr13j3e6b7r55nKnTe3DiE/M9dqdv05z+Otl2F6ncaW+1m6eWFxuJQ2eaLVXuidaC/RbaMeKx8vHSyUsHedh0d7iyokrM5fnFlon4MfYmvQza1huYW4GigwIn2UZEErG7mAseQ+2vYfb3u/e9j687f0IY2yQMSYYY48Ln8GP7FmMsePcZ+cYY39uUZvwn+r12osrvXZL9pbl3NJcb66xMPfJtqRFOYaLJK9cmltoy7ke7LmZ9tzSRdnqLK+stFuM/Xvms08wxuYZY3nG2F9xH9u+oMfAUnmPM8YO6HHlGGMmY8zQT0uXdfW7zRhz9HuGMZyXr795uj70t48xtvjxP/jjox/8s+ZV9Y8+sjr01O8c/Acf+1DlQ853H7760qd/8dV/+L8a3W6705tbXpKzjbmFduukXOnCHN/7CxKf75GLc0v15bV251K70WLsj7jP3s0Y+2cm9b1X/Xv79RtXd6n/L0waY3pehW3zhzk1GyuN5lzvmoQmZheWrzDGPsV99kHG2GENm2KxWCqWi6PFSnGsWC3WiuPFiVKxVCqVS6OlSmmsVC3VSuOliXKxXCqXy6PlSnmsXC3XyuPlidHiaGm0PDo6WhkdG62O1kbHRycqxUqpUq6MViqVsUq1UquMVybGimOlsfLY6FhlbGysOlYbGx+bqBarpWq5OlqtVMeq1WqtOl6dqBVrpVq5Nlqr1MZq1VqtNl6bGC+Ol8bL46PjlfGx8ep4bXx8fGKiOFGaKE+MTlQmxiaqE7WJ8YmJBu032G2tubW5VlvOXJOfbHeW2XdL5lJjsc2yme6lRun4Ffh1yM3Sx1i7/tC5tQe7xfq53n3daPXq+CPT1x6b+eBEfTxGtyQxfa53aqw+/n79eq53rneqVh/tXutWH77W7bUXp6r05b5ue+b+ZqO9/HDn6rX6aLfX4kH9sXMV+HXECvxcYql2qTo23q7OVEq1Yml8tFqbmBKpIouNZql2aaxRLM/OtKvjxWJjtlauThl3x0Wq9GOKpdqlSrVdmZkZbZWa7Wa12q5OmTvbmWmVm43WbGOi2pyozbanrJP1x86VSim6URrVv9N4ermz2OhV6fceYZiNZq012y7NjM1WZ2ql2pQ9BgCDud/XvfjomZWx86tXy+eu1StAJEe7vc5Yc/nyUq9UaS3XMVVvXmp0us57oNp93dkHZq9Vp6ZLY3MP1Gv1eh1JaGkUUq2F+Jcd3eZbuSgV+AXJnUuSeV0g0A9YlmqXxsuN1nhjrNaeqZZbzbHqVPa9NMBGfXcwzC72cFgT1FKv3SmVVxqtOvwq5cVOY8EbhjWCjVjTv4MJoC6XShNjteJstdEYLZbaU7m7CdR79DB2pTPXa+ffGTc1kfwoZ6l2qTFTrU2MN4ozrfFGsVGrTe37Isf5lurDDzwyTHzk+Fq7efz4h9tNyHpk+Mwjw8OXy8XhRpceqTLdlXazPttZXqwD86svtbu9duv48YdX2s3TneXF6V67cw7zqKX7hqextTOPDE8ktUq1S+VKsT07XmzVStVaaaZUmSosvpUrXRptzXVXlrvtevPS5aX5nWvuX3wruxtfbC82FuYuLu3saGDhLZ1X+fLSwtzS/F7T2v9J2AO4L5ZXuuOzl5eawE/G5hZXFrrVcVxxSOOyQrHjx5dXusePxwWPHz+9BGVUsgVmlzv4HH7o9PBpyK00GwsLSJMqE+3yRKk8Nl5pFiuVqQN/c323K6WJ0UqlXJ4oN4u14ujUYOcthfL43BJw4vpCo3Nxzy10cOIW9G8MBcQxFHlLVXyp40sdWPsd74Oqj3bH61B9T7weXbq8ODq3uLJ4rndqol57/1x3ZaFxDb7c+Qlq4DGc71jzfLNYPv/gowvL9TGcVLXb68wtXcSZVh/W6b3px0eAfpSKSEaQSh/q/5bvz6V/rvdt/d/1Hdr6U8CHr3MaUp0I1XZoTaw0luaa83NLF0ujmKxfaiy1Ftqdc71TlXpJ00ca6gcb1xaWG629QIvVS+UPwkMXHe815tv1meWrb9/6Y8PveKz+2DkCSU2L2OXiQ40rH243p5eW2p1kv9U67W67s9Yul1rLdZ2uN5ZaepSw3ydGZ9sTzUq1Va422pXW1F2jMVG+xVxpsPUrc71L9UvLy/OycZsDKpVm55bmupfqFzvLV0q1S7Ozs+XSTLlYbFbGQIKaOr6wsLZ4fKJarowXx2pjo+UKCEKliXLwEcKFyh57I+4XN0eplOp8S587pxXCEk+fW4PGy6/TOCDZ+NbWV6ZeB8iIiY14B59ZWJ5pLEztHMbd/d+cHn7rlneiVm5NTNRmJ9rFsdpEozj18/e/JWA8Vau/8/43uebNSnGiMTrRbFYbtWa52Zp61/rfIo6NXmz3Rmr1h87d9wZ7L54aqx9RBM3R29g3Y/UqQefS1DhAor681D5Vq797Kp78XiQeaDhOVRPyc71T1fqEgk+N3nKHenrPbcmfLZI/j8a/Tn7s/ckajq61m6VqIivdp9durLGy0l5q1dsL7cX2Uq+LEnmp2GrOTIw12qVisdmaOv6xt55sTxDV7vY6J2p7SomptRmntSHLT715qd2cL8LPuzcXV0on+xztFqtbohZQph+fW2p22o1uu/wrmv6X3sTmHKuXqg/3Gr255sO9zl+D+o8euw0IUKo+u9ir7PzF+bGrNIvim5vFG0AxWME0S6+ew7G/ri5R21OXKJVpK6x02rNzV8f3pyan1amJ47fa+zV41GfaF+eW6peXrswttU6+ay8WR/t1HGssLy/eU4wL7tzUYzPLV9utUmVm+Wp9qX2lfnkJzD/3XiFesutMV3qd0iiYfupzS/WVhUYTeMMbW49dl2Lq1IP1Xzi2NwnbNisgXO/95Bsa5zQysUq9urwCMm31PD6maQ/shA3QklGkdFO7cLr3zb5RGN2KqmiCsks/k0dutS1KmiRiW8qaXWxcnT11i/WOSXmpTEtRb3c6y537jtwGahJyv7+Q2rmgsk+hsvbo7nBoLF27vYmf650q10fV0rVa79pKuz7X2gmI0/fduqOH2rfRyJmTb9Qk0Cfh9x+91UpUdCpG5+lP/a2RXBAGPnCZui//bLvfTijP3h6MNAd/4F23Kl3Eoji4By9BwY/e7l66Pa48u7gLXM6N3Qby1GJjbT021p4/ewt9czf2vNTtlUdTL/XW3Fp95lodrKAPTYENqAzGz7pGtdFiCu2ayyvX6r3lOuzyVr3R6TSugZDcrpSaY+PtUqnRKLfb1amH330r6JbjtcCpPTJ+i7Ll6payRDPi/fKhjb892aLa6AJifrhyCwaHf0vjmtylJvBoSxPw3QYN0urETKM53+s0mu0+AECI7F5a7vTqyUfiV6eq9e0M6pNTp6r1jy69xb3sLcljf4+N31oqKFda7dnG5YVta7m8PP9LSDE+On0b3LxEcghm1PRaPNSeeuMrd2qi/vHirXbpeAwSdG5Sj59Qt6gxOhbXWFrWU+xemlusz3Xrl5e6vcbMQru+Vq7fJmrMLHd6F9QObISVSrBxeSZqN3t15PadNjgNmrVWu1gaLY7Wiq2J0dHKFPulzEpnuXW52e50hbvQWLp4uXGxzc2HLnd7LLfSWW62u91269jMNWHhEN5eOj4xdrwoRxKvpSwXy9VjxcqxUuWIfaWx0LnctYvHy8Xjo/8bo9IslA==');
@@ -1,4 +1,3 @@
-import { WordArray } from '../../core/core.js';
 import { SHA256Algo } from './sha256.js';
 
 /**
@@ -6,6 +5,7 @@ import { SHA256Algo } from './sha256.js';
  */
 export class SHA224Algo extends SHA256Algo {
   static outputSize = 224 / 8;
+  static algorithmId = 3;
 
   static async loadWasm() {
     return SHA256Algo.loadWasm();
@@ -14,27 +14,6 @@ export class SHA224Algo extends SHA256Algo {
   async loadWasm() {
     return SHA224Algo.loadWasm();
   }
-
-  _doReset() {
-    this._hash = new WordArray([
-      0xc1059ed8,
-      0x367cd507,
-      0x3070dd17,
-      0xf70e5939,
-      0xffc00b31,
-      0x68581511,
-      0x64f98fa7,
-      0xbefa4fa4
-    ]);
-  }
-
-  _doFinalize() {
-    const hash = super._doFinalize.call(this);
-
-    hash.sigBytes -= 4;
-
-    return hash;
-  }
 }
 
 /**
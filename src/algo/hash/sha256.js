@@ -4,15 +4,13 @@ import { loadWasm } from '../../utils/wasm-utils';
 import { wasmBytes } from './sha256_wasm';
 import { sha256Wasm } from './sha256_bg';
 
-// Initialization and round constants tables
-const H = [1779033703, -1150833019, 1013904242, -1521486534, 1359893119, -1694144372, 528734635, 1541459225];
-
 /**
  * SHA-256 hash algorithm.
  */
 export class SHA256Algo extends Hasher {
   static wasm = null;
   static outputSize = 256 / 8;
+  static algorithmId = 4;
 
   static async loadWasm() {
     if (SHA256Algo.wasm) {
@@ -28,7 +26,16 @@ export class SHA256Algo extends Hasher {
   }
 
   _doReset() {
-    this._hash = new WordArray(H.slice(0));
+    // The initial hash value depends on the output size and is fetched from wasm on first use
+    this._hash = undefined;
+  }
+
+  _getHashArray() {
+    if (!this._hash) {
+      return sha256Wasm(SHA256Algo.wasm).getInitialHash(this.constructor.outputSize);
+    }
+
+    return Uint32Array.from(this._hash.words);
   }
 
   _process(doFlush) {
@@ -41,19 +48,12 @@ export class SHA256Algo extends Hasher {
     const dataSigBytes = data.sigBytes;
     const blockSize = this.blockSize;
 
-    const H = this._hash.words;
-    const H_array = new Uint32Array(8);
-    for (let i = 0; i < 8; i++) {
-      H_array[i] = H[i];
-    }
-
+    const H_array = this._getHashArray();
     const nWordsReady = sha256Wasm(SHA256Algo.wasm).doCrypt(doFlush ? 1 : 0, dataWords, dataSigBytes, blockSize, H_array, this._minBufferSize);
     // Count bytes ready
     const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
 
-    for (let i = 0; i < 8; i++) {
-      H[i] = H_array[i];
-    }
+    this._hash = new WordArray(Array.from(H_array));
 
     let processedWords;
     if (nWordsReady) {
@@ -66,29 +66,85 @@ export class SHA256Algo extends Hasher {
   }
 
   _doFinalize() {
+    if (!SHA256Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA256Algo.loadWasm\' should be called first');
+    }
     // Shortcuts
     const data = this._data;
-    const dataWords = data.words;
+    const outputSize = this.constructor.outputSize;
 
     const nBitsTotal = this._nDataBytes * 8;
-    const nBitsLeft = data.sigBytes * 8;
 
-    // Add padding
-    dataWords[nBitsLeft >>> 5] |= 0x80 << (24 - (nBitsLeft % 32));
-    dataWords[(((nBitsLeft + 64) >>> 9) << 4) + 14] = Math.floor(nBitsTotal / 0x100000000);
-    dataWords[(((nBitsLeft + 64) >>> 9) << 4) + 15] = nBitsTotal;
-    data.sigBytes = dataWords.length * 4;
+    // Pad and hash the final blocks, truncating to the output size
+    const H_array = this._getHashArray();
+    const digest = sha256Wasm(SHA256Algo.wasm).doFinalize(
+      data.words,
+      data.sigBytes,
+      Math.floor(nBitsTotal / 0x100000000),
+      nBitsTotal >>> 0,
+      H_array,
+      outputSize
+    );
+    this._hash = new WordArray(Array.from(H_array));
+
+    return new WordArray(Array.from(digest), outputSize);
+  }
 
-    // Hash final blocks
-    this._process();
+  _hashBytes(bytes) {
+    if (!SHA256Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA256Algo.loadWasm\' should be called first');
+    }
 
-    // Return final computed hash
-    return this._hash;
+    return new WordArray(Array.from(sha256Wasm(SHA256Algo.wasm).hashBytes(bytes, this.constructor.outputSize)));
+  }
+
+  _hmacBytes(key, message) {
+    if (!SHA256Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA256Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(sha256Wasm(SHA256Algo.wasm).hmacBytes(key, message, this.constructor.outputSize)));
+  }
+
+  _pbkdf2Bytes(password, salt, iterations, dkLen) {
+    if (!SHA256Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA256Algo.loadWasm\' should be called first');
+    }
+    const derivedKeyWords = sha256Wasm(SHA256Algo.wasm).pbkdf2Bytes(password, salt, iterations, dkLen, this.constructor.outputSize);
+
+    return new WordArray(Array.from(derivedKeyWords), dkLen);
+  }
+
+  _hkdfBytes(ikm, salt, info, length) {
+    if (!SHA256Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA256Algo.loadWasm\' should be called first');
+    }
+    const okmWords = sha256Wasm(SHA256Algo.wasm).hkdfBytes(ikm, salt, info, length, this.constructor.outputSize);
+
+    return new WordArray(Array.from(okmWords), length);
+  }
+
+  _contextWasm() {
+    if (!SHA256Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA256Algo.loadWasm\' should be called first');
+    }
+
+    return sha256Wasm(SHA256Algo.wasm);
+  }
+
+  _getContextState() {
+    return this._getHashArray();
+  }
+
+  _setContextState(state) {
+    this._hash = new WordArray(Array.from(state));
   }
 
   clone() {
     const clone = super.clone.call(this);
-    clone._hash = this._hash.clone();
+    if (this._hash) {
+      clone._hash = this._hash.clone();
+    }
 
     return clone;
   }
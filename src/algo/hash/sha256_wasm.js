@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJy1W3+UVcV9n5l773tv977HXhAVWIR5T0SIAd/+fLvENMwalmwpoacnzekfTWWFVbmLwP5w0eOPtxo1pGJqDCZ4JKkaUknCsSYSo5Yma8tJTYqJv9IY5SSeShN7agxpTMSGSr+f78x97+6ymPQ0Zdl75zszd+Y73+93vr9mVvSPXCmFEPL94XpVrQo8ZHW9xMOrurLgokDtDetzVfePmvwq1wsqqhuownv/xcr3slIKKRuF3yByvucHIggC6XmBEL7K0sMXgVJCCkX/qaCkVHjjlclkfOEHcpucNSvwpJiZaaBJzfj4hAizK/zMlQNXbh2+Ronsxq0XD1+zbVQsnHnJJdsv3bRl4+UDWy65sn/z5q0bxIKmVN1lwwMD4l0NZ0thZDizx8zJnX1u4YzmZaX3/UF7m1nZ+OvBi3+qVUQrO1eYaLAULhJqpVqphfmVXOevNCK6qCS1NLloqZmNZ2wifg2a4yevjc32IXN9XFJG2GqjRgfRX6s1zVqsLSgayI/RUvTNjlsnxBIlVhWkEdofLHqYSVUBmHHVVxDzzEIqnjx5MruGACpm6Dc3dnlJDI4ZOURjydFB8wdxWApoIrNrx4SIacCS8FdqXwfR+aPFrBFF6fEKoo6S0j4Nq+k1SGVvHZWpWauSVxDU0ct7IQ06mwdROkMdhc6ac8aMP0TrtGOvbkZDczFDLZIm0KIgQp2hKTNFkVehlgUVYlYjdTAaXZAsc6hEK/UIjQuvmIws1YYe4UdUAo5S++uoSBhmtNc3VKKpJM3oYU58vrjEE8pVmGbXx3nsEpFsHdGN2unDdc0FIjnqohXFgD4JafmeZQjxEH2iFh1oL1pC1eMKdYAagb1nvLGSNN6o2Ul4x8UMMeRmuwJiK407WpJDWOwSlcvLkNvA3+FOJXQmZFyDTpWjOnrlaeCMfclO/kADSz+OFlGJZMkj7tO0JrKCYYXm2liLNc1WSlgmSCrwrCFC5AGaYoxQ8oZWgd0JKdYWslqRRDmqg7zZU4kuow6WOC1BT3+lx/LhxILY7vWVLLmxNDAgoXtYI2wGhE3I2lcIHFkzIGuNL8Qln3bLZJJmzR7bXAxOJS2xVWdBWw9cU6Y6wpIel4gxoyVhdt3qlpAxOSenwo5LokmEIBD0JiHvTDPI3KC97UPEoxAY0lDRIp2JLiKRz9I8NLkwC0lCSV4xwugQEQy0nTSj52aUk2eUyYwyNWOyMJpXuXk9pg+eK0i0MTjTO0Ut7FvBjJ2WWClJxOYShBwkMZuWRMKCJTEbFulhJVEkkpi1L0I3F/KwkE3B04NcJJlMkcwUqc5CqrNTpDpbl+pTxvKoNI2oF2TopHnST3oT95GK3V0H1xR8qCUdknxjCDFI9GGlO0n3El/IJvjR0mi+DvFpblUhRClXzJu9wIjKfqmRy9QelyRw3UcQP+xsEsRb04yWez9e08z7qVgUvD9ada4XGozG5b1AIlpjjmLNQ3yx24GWgRYjEpqgjy1xHYSMJFAxvWlTKd5SjnyMHJOUu1IFKSrF0mM/aAzNRIL2Kk0M7hsuQJBpzApUetSl86tRKEm7U9x6SmqtXyX5p1o/JpMUGBGS8uXB6iPSgDnoH8yd041xMSECmOWxvFM/SL6jBBTYpLl7idPJnMWG2tcefyF0w1rfkrNhDenwOj2J1bRNGtBUyloOl5oGzSZiF3gPWJMESNp32tlX3RD3NZcKRJUZuqCbmIYQlICVp2cHKWZs56JipLA+WHH+XhLPaD9GIDkEj0S6MYYC1Jk4pqkGSwHoEOnAYGNZMfTcqLPdqHZ0iV7RCmwb6JjBOEb/g6Dt+Pg4mMootjA9lhJlZuoZkNwDFqxQ+ww9k17mUSCSB0oPodSI0n7GHCX01zOoBHoayA0UEu1HnzYpaWeyI2sKTMhCL+l+eumGQSIZCLsiOo/6WpMHuDGfC09ROeo0ikZNp2gUFI3SDVbRNEArNECZ4AVFw2IJOYAxyVl5JtgnMgNjcCJjyQjtY7lSBI+IiINxKeCNQcrHKU+adhD8J/cmHiz6urZXV7stBwETvWR1MAkZRyNXFwLn/mTcyslwsXoy+ZoFcZrd6nNGX51On2eIXE6l+yQlQIJMCUlePrBSD91opSnRZaQFS7yXs/DZIEckKdb4WKmzyiNRPfX9ysYi0QLuy7QmyNjNy4Rl5y1F22Ry2r/31va2SO2kkoB/ZBGQEHuq8hIcNMs9uUUpuXeMcnLvdF2Re5HcCyv508h9oigtYVgJ7jqVMKTkLFFoHVkirSBZ9R3HCI4a683+70Fis1Zi2Y5lLcuzLLFuGnLKDgHTkws7f+uW3A0JASpMPQC7HLAr3bIPwB4H7El3ewjAXgfsTXd7FMB+B+xPd5sAcMABB9LdngRw0AEH092eBnDIAYfS3V4AcNgBh9Pdnk+3vAzgeQccSQOvAjjigKNp4BiAow54LQ0cB/CaA95IA+Mfm7A1AE7cmgJ2oOWEA25OA3cAQA2AnWlgN4CdDrgXwC4H7Eq37AOwxwF70t0eArDXAXvT3R4FsN8B+9PdJgAccMCBdLcnARx0wMF0t6cBHHLAoXS3FwAcdsDhdLeXATzvgOfT3V4FcMQBR9LdjgE46oCj6W7HAbzmgNfS3cZ3gAsOeCPdbQdaTjjgxMdS3e5Ay807HLPS3XamW97BqKZNdlpxsV22usuD7mLdQyZbxFaJ/Z5UF5wSL+2Sri2I/5PKJr+UIulFhHX43xmZqZKaey/rZYrOYcqtT0xwZ0E4w8lgeRUHN17MrqYXvackEnu3GuGus3be6oJKOfRQdDI6rynkT2liG6J70xtCv2YIg9UcE/vQij57/tMYQq9mCAlDGS3n0JrnyyeefkIWbS1rCuO6gzvZICjrnFuvt8kZVHGKQU11czSukaOXHVL6vKNEoSyMw8ragqVdsKwtWE62/G7Bp7H89QVjoWSvUixIUR3mrubp1+OAkAWLibEOKR8ItA1n3iGTJE7JJJFnjrgkFaLUbDrJ9eTdQ7Kv6gEV8RPsoQ0UxxQKFGpCm/LwEx9dJj66nM5Hd76+peK8PKqj/CC46uZKlrqWqng7VYkAkEJObEkmX6O1r4fcF1XnCdAsUX5eU8i9ycx7dTMvp+RiKDz3YOZl2syToLOZlyHSG9aie6kQHS8PUetjgcxVzQW00w/ZnUZRgxwqeUht8UbzzYGPgnfk+/tDzRSJS5uiY2oYlhjy/HQ2mssSl8Szte2UowAW28jkhlwcm4PO8MnnIryWCU09csbrbWY3dXCGkopdODukD3eSon7tkzMi4fPIMRqCXjFKAXaxjOFWm/mYrphj36/Inu+cYgPz0gNZkWMib5yc7SUqmilJZohhkvaW5PGw/l7miVsLD8AakQrzl6j8TE/Y2vlF21jkbKGqepw40wF9Pq+GDvZPfSJGIdRBn10Fo0iRgSnzniUiFBvhE68QuoaAmEQrp0gcxTJwv+sUE1L974iUmUSk7BQiwe3MvAORxLSkEZY0yo7pIffpAmUQBgqKRBepEPij9bEhnjQzkabAuZJGS4MGNlkk/V5TmJrO4m9nDF/JqAVVef25ZKaISA2wJg8Tkmr04buKi6fJsFRVVV1PY5yUSBZeLOgfP/RinX34U6XFejGDH2mmisV/3gxKLr49LNna9aSmUBpf9xFstsVfxFbDlteC+l988mR13V80a39zaRbnIdVocYFuMBO06IXELNlb1MyzYpFe/ppiiV5Bb/FcemXWFBfRK9tbPI/I1RA16QzSf7pA0bchBeXrYiHQpVWFjD6XItlFqwo5fV6hQQfg5Bl4+cXZeOWKZ+KVL56FV1Q8G6/ZxTl4zS/OxWtOcR4xhqJS0kBxNFM3FJu0LEZ6IQWXZ9DemU3sOJNk8axisz67GGqScT2vWNBzi/MJtwIF64IghA6NxRnao545tsx50wRVmdfnxLSd1bpCQWfNykEdsQZwiTCuyUKvZs2i2L59qyPM3O2kBGZsH4EqGBshDyZrulxTE5oK3NRITTxcqJvw/XySlMJQrAktUx2hgjDzttPjTOotTHb7SKxzpOVD3ahnjGhvCO8hKpgF2+lxxnYUG6lXTEsPdXNMK44IJSIIMCOaNOHVpGcQOWYW59MyZVzKmwmydIRCQPPOJV9pPpUKeh6V5iCvqOdQiRhCI55NpYhKM/RZsc0UNuozY46uaCfM5tg/oD1+Rsy6Wi/AFvFBxHP0rD5ONBOnfDhKs2gTwgzuHJ8Q0VnYOfSzJwEU/exPALIV5mAC+PRzOAEC+jmSACR55rUEyNLPCQcg7WB23miBr2Zk1qbdYWB9HL28o6n2J5lqDxt+TTMZUtJnEe30dHZfuuy+x94fDU2+SkDen5q8b6me3b4ARzWkBRJ73UubRNXdHfJyouVI+yPFoYO4lNVeH8H0GiTVmGGbS8qHfB9SiDQXci1cyYaOMMPZCZUGzY3jub5m2nO+9RcUu4xImC90GVZyLQR0JQ5kaCY4FAH542Yb+2M+dH+0wiaj4JKNxgQJaDSkXpP8q8vt2CgpnXuElvSSJOyk9Ky050PcgpwVkWSwpJLDB0zm8WEP3ppalXtm8n5YcxoD5JO8tbXuynVXdrgkXtid5LCt85l26eqj9BWw+yeh4U7dNOkZBaPEBgkTgZJ8YlLHbgnZugx89hvIn/RdeiSYlI2HE6tqroRKjjvsIUcOmyo36ZBj+hRVUD/3OmXR7pmxiGYj5NkL7Dozj+15moTEmKvNdZDGZrDcB4eiiGQX9hGmilrm0eOi8De+DKtklspEFdq6irSd2XvjhE15G2+FWKJVzzj++RUvZw9rCAmIyBz7ipzow6crNa7iqMEeSPqrbJwD5wAORhHnpOQbXh3TaqGessWArbnVrL7N/2jtcZ4J5UXOYPs2Jck0RQoV/cksI7dPNTQuTnpolgCOQYBMfypmgPWmfsFoKTTrYxNgatKXFrHU/GLS/CI9vxeyyDVCZ8yPlwnBdKHV+PFSJXr0x0Eb38xxHgsvNmeQSndZeKgGSxXrBSnCL4MDpRwpaUhVE2LCPDsMNAKcDYE5M1DPTPSIBuAD3dSA+ckDCjdgpjZgxg3I3ktDaEeab7O/c+Cw4XtvlHZ2nbr0MUirEppCIjVRFXaEownNtPP7rAfGFJPWQtJIONNt5lMM7pQi4iqcMdhlmTLMhAif8qVf5cCxpklPE0dLuPdyujhanRJHJ2EmbVrE0fL/M46GIueogmfjmKIWQduN/ztE0PJ3i6Dl6SNoDD5NAIuIjINq+LOpoFrZ1ava6qfoKrf60+iqSUG1nBxUyzoLEA/KhNzUr5Fhj0/Ap4sPSZlrNVpS7xQfqiQ+VFPiQ6Aa/qeSXrVmiqVpwP0Pe+pEQTHnGEQkij4f39vwO7SZLO39FlfBm+QqKOAEV2EQV0TAj9PcBih5tRPP62LS0QqqSEXvxR7mWAK05+sAFIIwS7SPLSQHh6hTLSnAR/3K2KSXGISbEsPAEiZ8pJLsET/SHKjbZ8aFb9iynCjxNcfxMbNORmVoZ2SxHI28eC1q2OYIfvM4bGi56DnDA0ZGF2GEeeGLoLm9eQP7IZyCosWdFGuTE0cv0dm9LAvE6WgWtxQ5fEbEx7l9SLlHztYKIax8gsmqCjGKOBkEl4YFmIrvGzLjcniFaGJo1pg5BoiPgUxmrNY8gyvy9YqC8dlQp4ao9Xl52iEKxrO8qX9RSPpM8BdG4cTdLZJ1SYkdMm9Qyz67Zo01+6zWwtpKsQOpIZoHE00VVqVwupECtq8rFdh7TWSdfao6n1NjoueO74oPscxkCS8PlqAhRoLNIynDiRI6jJeyeN1w/QOlwJw8SRryqNhSovBu1Oy4mfbehUK8R4D2N8S48kKtmwdtvyk9FA2KULnn5BM/uPDDOlu0t0qyD9B0r4g/ZB13Q4w0KmERcz2NWpvRO+3I9pJP4wenjuBN6WdFoZruUI5JRKDa3crN+VoNRmiHZQn3KhmQXekRFdoLgi9oyfoFLVm/oEUsREJx7HLaSHxBS9UuaCk2lLU7OPD8a3d7VtYu6wRWaWWgtDzr6ajofD4a7dAS9skrKugbfAA6sybj7BYf/+Fr6wSRla1d24IFZ2WhcIpNHXDhyw4I0uOQrORSn6ysc1ZZJ4lQzdp/ThNuzginpz0YfD/pjE/DRyyVEGn71vQCyPMeJVUIlTY7thcSvGZrHpC7LFpFChw4gZIvsQ6WibmAkqE1kBtgvSvGHi5hybOr4o0cxYlTiL3IIarNucKxIS2ZoDO/xoGScpcYyA8ys7GVCMPe5tjae2n1u0ogwRRP+ERWC2Ox1WKXwAc5qCoqqeQAObIfz6lfqGPHEZjXrnWgOdwqC1U+00+Wkz52pOFtajZDa1egXg5WC4kPz7qcfuKYkviRrxjAGw2YBDwOVZxcyA93oSO8Xym/pgagZBGagmU8LE3/6o3uvhFT30khhJ/EI2ksBdZswtKzT2mWYCNF8F8rHna5jtnjS2DtcQlO/mwoqjlxFOBGYy5mBUVDdlovfamXq1i1RV6ixz1cI9ZpL8/4S61fjNsTtgmuFWLRDE1h8saP3l2SICWK3by1aeA5FR6FpvTckMI8fxOCE187P8/6kuFfOjOkraKUxcCMg//jbp9m2MQgt/6ky15LknNzOAFiJh43GnuKdDiJ73hrZIz4ADFFrRkuYNEelAPvuTnmjVuS/Hb1j0jsAEKNxNAgFjQnXBfPr5rjt7hzdHa2beJVYEwfhPSsm4/1zqbqZHCSj5A0IJ+SGUlmOhRh3egq6wWxT5QT4c0+TMCb//STN7/8uR892ltRx2lH9vzXyd/c8thn79m3t1pRx1Cx69h9f7PjiYlXdlLFq6h4/ZZv7H3zZy88e5wk52VUfG/XL4987e17PvMqVbyAiofvPvbk57/9pde+SxVPo+Lw6/c9/uyn//aF9op6EvCnb/rUUzfe+NI9CypqAvAt//HovX/1iX1fiyrqUcB3P/3PO5869MThD1fUQ4CPffrBOw8e2PXI6oraB/i+Z07e9fi37759H+F0LyoevPO2T7xwx22P3UkVu1Hx/E++uO+RH9z0tV8TCneg4sRLX/n3A/d/dM9zVLGD57zryN/f9MCTty+vqHHAz3zri48c2/+dXbMr6moCf/mZ8a+f2Hf0uT+tqG0EPvm9neO/Of7j/d+kCa4g+Nn733rih0/9YjcwWE/wkef+4d++cM+/nsB8f0bwz1/Y+/P777rv8e8Q/McEf+vvbn174rF9ty6qqA8Q+MRzP9v7+rFP/nhrhQ31A7cf+9G3HzzxlKmoLgLfePytu376zf0/H6fBywR/47Zv3ffVR//61u/TYORG9+z+/ucmPvvyvzwxq0IxlejZe8vbn/zhi5/5xJepO2mrnpdufuk7n/rGoeM/tHat53tvfvTBl9749YubKtD5Pbt/vG/XrjufuecXuJkUfkVSbEVIWJeTIqSiz1bUeOxAmeuGWEXBDSkpdj5KAQe2VnpZL9VgBVjVYQ+wl8AZDqFLnrVOPmvN2rlnbK2AVqnBOZ3FWX6ffWD+ToSXST6gsGeFhntLk42TUqZWCmolv1byaiVVK0lXgtIr4SSLMXxM0jxWV3iL2FfFhESDPs4T2GQAfTzKyTIfN8HpY07orGlmD0zC2pNGZFUB1w4ale8ZIlCHFvY4MxLaq7m0oaPZrMJY6+jkWipCYlTDg++2Vnqp8qHzPNKli2BK7sB51kyzl17RPBF+2aGeNgRsVvxVNmriy+FTVP80il9OUfwyrfjldIrfm6L4vbriT+tkL6WTPwZkk5vQfE9eIhQlTQrXS7kMkeIIHoFHgWQi74f2PIYKnJr07QV1opxN6cjo3SHbbI75+JDOXlxFMpP0vgwTLOCF20B0l6NaxAxPzDs7PVV3/sdMx+mUxxk1pDnMQSIfxzv2KE3ApfeRw6JySLuJvTi8fCaImbhpgvU5UTuaIZwMuG5o3z+5fbOUjpVAioUo1Xl+bOwFX/Pu2MzgUo8CWz1z/KMT9owU9YuQgCUWwp/xsPAlXOHHSNNZpkVFEX7ITqYTg2Hlwmf2TpaGaFppgPTjBiq1RAHLDDH4g0RW7WJ3G/l4tciHolSFyMfjUNRFPiAKB0h8+8OGPS4gpXBnDUwtB3vjh/h2oExO02pnaTiLK8h5IV+Y5wwD90BoPPm8bXVtvUxc4rhxhD3A1IuInLLCBJew5Ux3pqCZEzv5Bt3ejyWy96acZ2VydWclV3dWbHTtBO/oJB8l7CZkQOSxlXzt2Ajno/NSr0PQUe0tyWab0WEejGqAIryiFlxLG1znrcMJ0TQXmJ03WbXgouxUy56kxbrlNm1Sw7CenoM4RmeK8F3scJvr1kAFYhwE+VjK9X3kgNN6bRK1KWQ1ZRMROB8N+ATY40CJb2vIkMayrruPgEoOGzXsaBHDStgcASewRNhu2TSdH0NDWlb5tRIk6RwRLhD1LbvK5Qodfphe05j2yN/HXzOIJLXHZgkdijaF5p0erXOBi6ol+9MZN+pumToXTOUIAjlMGSRqJQxn4exrLs7Ix3D7fWFvc3iWmHaMSNj5qy7nE4aCbQuvowmYS/w9AxUGh8OcTRBmwzOmETEsc5nwjaIgeyxstN9owSNygze0KsynFh46wjGd4nCGheZ5K/OCeIyvOiAjUQMNZpl59VCtKJNiflJxLJxrZzjm/F2BLdxMfqwIs3ap7j3Ib+KLfV8dEzo9b7z1uZ+8/vkvvfW2IEx7Hrv7C3ft/sedr1yLpt33P/OrZ392+LXbqqEnJP+GLwZS2D8sezoYGd5w4eZNly4fHqG5RCQa6bmIfjF1Ap83BV48BT5/CrxkCrx0CvyuKfAFU+AW+p1Nv76w/5Qr49dz74B+N/Rv3jywUa9ft21009YtK1ZctWX7cP+2JUvX661bdL9e/8GtWwbW67H+zVcNCOnGorUO9w9fc+HI6MYLsfZt/Vs2bRjctOVyosAxmn8+5qAJF9I7gX2CF9A7wxja+bP0m3O45VxdA/0C/5B+864uP6VPIYUD/80eYzHcv/2SsYENhMOG/m39GzaNXqO3jg0MX7Z563bqPyotHhcqu+7IrUU6um3asnHgar31qlG99TJ96dartmwcWaFHrxjQmwe26E0jWl9KTYBtR6oR4pDEFhLiKL1n0btcLreUW8tt5fZyR7mzXCl3lbtbyi0tLa0tbS3tLR0tnS2Vlq6W7tZya0tra2tba3trR2tna6W1q7W7rdzW0tba1tbW3tbR1tlWaetq624vt7e0t7a3tbe3d7R3tlfau9q7O8odLR2tHW0d7R0dHZ0dlY6uju7OcmdLZ2tnW2d7Z0dnZ2els6uzu1KutFRaK22V9kpHpbNSqXRVurvKXS1drV1tXe1dHV2dXZWurq7ubkKxm6bvpqG76bNuqhLXNmwb3rrxqg0DwyMqt7l/y+VX9V8+IP0/uWpkVOSpacPAyMjAxmWXXuMFw1S34ZyW5R2dy1v0ko7ugYGNXf2t/RprXNbSsqzcsjSzvX8zdcuUl7d0Ly/nt/ePXLnM/X3lrPLy1uVdZb2kfUN/f3dXS2fH0v8B+PlA9w==');
+export const wasmBytes = generateWasmBytes('eJztvQuYXFd5IHjOuc+qW9V9W2rZbUvgc6+b0AJLqqqurqo2DunTpiULYckvwHFMStVd1VJXt/pRVWpJjHG3iQ1m/YJgHlnIxIAzdiaGIQtJgDCkmfUyTmIyTnCyTvA38U68ifeLJ3E2nuDZcdDy//+5t24/JNvEmezutx9Yde65557Hf/73+f/TrNY+wRlj/Dv8wqOMHWV85ShfYUf5Cl85KlZWGPzDV44aK1CGf/nKUXMFn/CHrxy1VuiZfvnKUXtF1+gCXznqrER17srmIl85mlrp1nfLfOVoeiXxxltJPmVW1j2KD3yAf8CYbRrbe/p6dvRZ6ZToyaZM0zJd13Qtq397n5npS7u9velMxtyWybhpG14JIWzbdmzHMG3L8vr8lDC5EKawLjBt2zSdlGnZpsOEY9iWaZqmEKZpWIwJ0+KLfNs2y+Csz07xFa5WV9eY5/xvvMc+0Tix0DojWLZxenGh1blyYb7TON1hAz3HGp2D8zOdmdrcVbX2cXZZ6nitfXz8TKfRZrtTx2fr01S+OHX8RG2KyoPZmRPJTnZ5i5Oz9ekCvQ2c+sKVrTOLHTaWri/sn5mvzc28v8F+qq9aPTU5M18/1pivnqjNzS1MsUZvom661Wiwo9sSNa0GNfvpSxKVtXq92lmotju1qdnq4sLMfKfRYjelLuRMca9v6qrShe+5/vLrfnbiJ3fuqx85fEH6Y3/Jm98dFKkVfuulTPmzIR9kYoz+J5n6L/xglmHp8bP/4uosUyxgGdOTTHnNUKjTS4Gh2JB47t6x0JzICnVJYMFXZ886R7JcGuonpFDu8rGQzS4rviSZ4p1Z9fZmYGW4Fw0D/4NOnr13LLSVj32rlwxo7R3cGRrKWA7FcsiUsTSRZZKplTb0JZqho4xOaKin715jzZBJQz0LpSHBQjEk3NDdn+XSlawkMpJJtyTcjPAkTvj5e8euztqSZUU0fTYBz8eV6KjH79K9uEPCVKeXpDEbCOkGtgFAcYeEH7Isg0I/FuwhMRBYGmb2kMiETNoH4IXqVz7U9IdsJ/WY5QhC4UG1G66fHffgk6a0ld+UbGfgGGPSCVLSDRlUM6yG3tydgSMZ9u5GHTNPphQrCeZJayJr02RglF3xmmBBbD9uqDUkfGnvzzJpwRT6ARSZlEdPvmRZnnE9Ah6TABtPMmmVxAAt0Q9d3AuYt0/NBjxaqYuAhBf9+kUGPo5BGbpSSFeKg7AKIZm0pbszsCUL3AzzPAVgu1U6p5ZKgBEeIFAzYFJIQxmtkjCh1AwNaUCPHGoylkfoKkVHCSk6IVNMstnW0vEwBSgiNIq4UnRRhAH8HYCAA5PNSFc6tAl6Cql4Ciwem8HYNkxhNnRpdBi2CT0wQuPn7x0LxQSQypB48d6xwEjit6O4FMpY7oSpJWgiHZlq4SgSqEnPUwQO4ALURHMPnMTcA+FJRxolAaA0YO4GzB1+REm4QJ4AM1hHCeagcDuev3csY3qKaYxXtyJywCKfu3dMU6MUyj8ImBuv2IYVA+BosS4sVpQEi5bqTABK01JZcqkpxaWDS7VoqSlp6aU63aU6QQqWCjWho5eakk53qY4nU4BFrnQiWknRjxORDGEEiyeMzIB10QN31I0gIRASQGluE6ieZwS8BxqPGZ+1jg3YyPdcJFzoK3CBiaiLpKX48qy01CX7d3YCB94jh7BDm7A9TEkDoJkCdElJcQQgG7gyFQiZwk6FtAOsyxiafFKwQGlLR71hWZlLTaA1+8BOqN0ZMOko3gkcaWeZ5yHKuy09OyGtGPGluQRLMzYwtIB5QMvcM8YiggxtmJsjhXSAJFM7Awu2/CDwF+nCmCmBjX0AA3KfwPakkJZ0gHxdmYKCS+uAWUngDF1SYAQCIHRj9kiWe8A3u+zSJXbpArt0iaEl2KXdZZcukKsdoYCt994FvuhuYJf2RnZpd9mlHXXcZZew3zhlmhCMlGCZ9nqW6W5gmeY6lgmbGLNMnmCZKDRsYpk2tLY1y8TV2hNZTi/69QvEsy6huhsINSJTNyZTF0kgJlMWkSm2XRUHcWTh92eEt17eRvzIISK1pRPzo5d4lyHZxJBe4l2OZG/gSDaRqUjsEfyIaKvWk6m7kUxhM4Eis5s1A41HRyK8eoGeD2WZ5Mpsqo/etwYs+t+smk21unrLkj8sxiQfEqYmXQayjA+JTGARVF++F9+7YaoZshI8At3Am9X7xkLAVXFoZwkeupN4KeLrj9+9hpQOC0bRi4sIneb+rJAoVViWZQwvBvSd96F6AcBlQAHEl++8bwz2+OwlJXH3fTC+VRJP3Q2FVEk8gQW7JB6HAgK8JJ7D8rNYfiFR/yyWn8fyS4k2z2P5RSyv3tNt8yLWv4zlO+/ptnkZ62+/B8ofvafb5nYs3431n76n2+ZuLN+P5QcSbe7H8mex/HCizWcT9Q9i+UEsfzlRfgTLj2D5a4nyV7H8VSyvJcrfxPI3sfxYovwolh/F8hOJ8uNYfhzLTyXKT2L5SSw/kyg/jeWnsfwclp/F8guJ+mcJtlh+KdHmeYItllfv7bZ5kWCL5Tvv7bZ5mWB7L8L/3m6b27F8N9Z/+t5um7uxfD+WH0i0uR/Ln8Xyw4k2n8Xyg1j+cqLNg1h+BMtfS7R5BMtfxfJaos1XsfxNLD+WaPNNLD+K5ScSbR7F8uNYfirR5nEsP4nlZxJtnkRytFVvEwQUU6eboSgB/cWtn6YWks3KlLqxGbImsA63BJwBtEvNBUEVUEMlYf6I6ldXV3lJ3H7fGPACIe0jyD7FIRJqGdDmllAG8WVp7c8amv8i7UJH0j64E4lW2jLVDFzNBog/kDxloSPR5oiZgPC8LXvnXvw5agyaj+BsQKYCQ2mG7sGswO/dgMWqcQIojgbKJkCYBAhHmucDBALqKHQHnaF8UH7z4M7QUTux6W7gPkFaOspvwtOTd4+VBaggwKZlugxK4Hm4FsiBJ+8GbW9XE/klUw6KRmXC9+5BkMGOFPuzjnSkA4Lg1iVS8B0pZkOmJ496HxmhJMiEZH5/xl0nyFzFJUNBZpMgc6WtBRlLCDIwMkAuoyBjWpC5knUFGUNJBYIMRVcGoEQ/IN0yjkeSDcAFuyRTTQQw7QsAsiuTnW6l0AJ6NjCk0NJkf1aXX4SyCZIaRZYylhQ/gOu0UUPz+1ALh0+l3QTb1iQdzNYAA+HHY/sELAIAlxHLfcPvz9jrwCUUWOcJcIkYXEYCXEYgAFxYFRnagUgY2oHhachII5L7gn7APMlYgLSg+QEFv4AUrEUpWlIuYrB0E3pLbGmhCZOxvci4ENrGQ8ur9xDMGvS3F6lTVOphKrGReC4r4HmyAtCQw8/BANXDw1doynXNzcgAzViw8w7td2IZmiAFbn1EkEyKWa2oyFQzFEiVNlGlK+2EkraZPRkekc5LEbTgI7B46fN4r2lBXQigmqQbPp9s2EUKUrEiKybqPBTSuFojwY+9UZ7kym8OMsm839vB0yuXMvUMmw3Tg0ymJQeIpmW6JGRsYtnE7UjDt2UatrAi0+Orq6urflnkQOdPq1xTppVsSkva/naZHhIV1ET9YwBE/+aQg+aMAxRlGugyB12DrsXhm5fTjHn0opLQKgHbYfA04GoFui0GXIrAQ68UvJ0NM1Cdk/bs1dAQJmLLjOKK+2/U8/Dww5BLuwnmjMwoQc6yjFpphhmYhKWYzLzsAXTg82YITQH2GXh1OYMp2Io3Qw/m4Sl2ldZ+pZdligdZXKviQUZ6uN4srNdTxlLQA2CDhsoEIvfULUtBL9QZYzILbMKCMZthZg9jqtSGsSzFm7CGZrJKQJVYV2VAlZGo6pU28GzwaXmyRxuQMAgO0W0HSwls2QPr70FbMC29kniHTMtsSYzR0xXSw4mbEwjYK5rSAzkVAfYdgAxDYizIUgscxpBmvJXSRBR/h95KAlQvIQUBqfe8QDI1kHo3AulpthlKcV0CTHFdAk66ztwKUL1JQOmG54DUNTIte0viXfR0lUyrJ5mmgKw0iAJuRGRLD4mbyaBMq8cYwNDuwvCaAHHzXUEv0UKv9JpJcvBgzXZJXEMd3aCRFVbt3wBkZ+pBe4HsqNFVoe7KxkZUeQVVZtdV5kJNgFRJ5A1KjA8/mcAkVuCLMbDAr0KSSYGcimgfTTEQKcgUdnWZQn/GInKwZSoifxa3Ai4wINOgiPTDoIqDuRdwxYM+raSlQV0ZpKaS+MJQbPfzmNhlk2RCBM/BwIKfIbKC3QkkUvRzAFSb4EDUoB0SMuC6LWErMf4h5Hgo1aF7v4BtB+HjvgQbMEpiiAbcBkBSfrMkLsPpmcguCZ3hMQKcSZWb+afiwfaMGYHLRHBtz3JpBtvBVN/uH0JU2E6c0yTOiRS3XfpdcjOhV5MWrtnxZU1o4G+L2OD/0zmqeCWOKv4/wlGNBEc1tuSoRsxRt0mjy1GNZrhNc9Rz8FPj3CAy/gn5qfFPxE8vA366jVD4vwc/jUcFhrrtH8lQcQjiMjT3lJ69OsvnDsWOTNz0fvhnV5jRNNfflJnkwnYFGfSgo7OSVj8kBmRm3eoyuKMlsYtmI8Me3XBQ9tCE+gD6fTIjU0eycHLhRQy8y9Kjb+3423iFnIDF48WhQ9SmqWArOEDKSA9YluL0C1b4umf9XjKZ3i36UYiAJHBJIOxaPwrNxoxn48agVc+w5iDrhX5t6O+hHlJmc7Oh0z0ZTaiSxJiliXqsKx1auBMvXPEgBbqsowZRjwG/Iwtd/xLFAlO6yKDA+B0K0iSHsvQJHIoMicsC4lbQEkgxNZEFegNj0VV8P7kUloKMdNUPz5496ywFWcWQJE0DEMNshj3AxdUtS0087pamEssSzlV7hwQo8f5S6KuB9jJQUQ+QKtb7yqcqQ/lgeGbhu2ZoIiFmEF2g8627hg5MOKU1OtCHRwdscICTInseLSxnSAzCoTHHTkRHmf4NxHXMaKl29xOTbFoUUKmuPogvXGkGbiSG3EN4ohd5nR30O7uzYaRigp8h7IEf8P7C6RyMTmLGBPaBQxiKSRPFTA9BEZp6uGUGiRkHVBB0TIFRFO2iGaT0RHAb/UjamGA4+TeHPkgbAay0V2+jIFYqgJW6et981HH19nWFgQHb07NO2hjASHvWSRsD+GjPOmnjShPYKO1er2ajMAgO0W1nAh2bshfA0ItcFAHZLx3pw6GFoz0iOHGkHUdlmuiIiIHbH5jw4wc+tcBhYm7igIrcDLMl0Z8Ak0v7TiByJ9Avcg4QpTWI3I0giiRGEkZxXQJIcV0CSrouvRWY3CSYIjV/azhJQu1d9DRAJO/LLJG8p0kedRKDSD6bELl2TPJcowxJX3sia2pAeETyHpG8F5G8lSB5m0g+u4kuOdJl2Eskn15P8qBqrqN3azO9Q8/n7HcdvYuxmLgZcQ4kbqj3CGkGAGm8LtJIQppdgRu1QbDb0iO0sREWN0hTek06/CZwA4CBvj3/ks0ATccAtWNVJj2RtbYAptUFJk8AM30uYNrr+KfYzD/FBnjyJDwthGd6a3jaW/BPBtoLOCTT5HrxiBmeC8jOkBigehd5aVSZoUp/XSULNU+lSumoHApAaUoXBaophRa0Qj+vE8SKSxN+/z7Fe0BQrrHZMDUIHfKSyJxLVHIkZA8EY0oVE4JxHZUImQK7ILNxU1ND4oogu45K0l3BmNzYnu7G9qItmNhYQ2Y2QT97cGfoA/w5bGxftLHIVbC+L9pYcHcFXPYmN7anu7Fbdr1pY1Ng7qAMzGwpA2FVsaukK/XMwPNiLUAbgi5sYqR1+Bljvd6A2g+edw/QzvTLFMXuOEocDLaBtWhovTHZ0S6cDdRiNxGqAPGlwHrehbqjNCMOD55FrTlRg0GZUrsgoimSvynQAvvhZzA0m3K7Onvby86Sck/L7WrgND21Soww0sSzDOhjqClTJGtA3e6Xpq+ZIs45BUryDgQFlC/TKLNjvclnyh1dJ9oOopuM3IEwFWNyG7a+QO4IekB3ArRMDJmRPb5JQw0RHDIRyaQQIcOekshBebcolo2heB6yR+6A/w7upM9MEG0+ICNqHCjaTPVyAknRFOTN0JTmHgbjgGdgD+vKnrBP9u3RaNmtF4l6kag34p4MknfaAoWj85Aj4vqIuDBoYOJ4OAn9FU8OHZigQgPm+yD9fJR+F8CcLpDOAR2VsgMW78M/A6GJpkcKYjEQ/hESDAQmUP+OLCeEGoAnU+4A1IEuJmgr+4lL0iZBS7kDcWIAKiM2hnvCaU94onIXVfZHtsV2mPl24GxHsgacqCWYXGQ9pHYLckGhzhqJozQJIOBWa7GBgHzvMy63Ir7nDIJemOR7680Dk8yDga554JNp4DdJkzKk6W9H/h2YST6Abm6H6BZVXj/iCnyDLjwQu7mlkGasCw+QasYjd4wJYXigpknaH4f2x+2K5IHAROnZH3Jpkp7sRnqyC1vvRnqyq90xJvi6TK0nu1092dR68gDpyTypIKci2nS19peClaL2l95C+/OShg1K1WboblCQ3c0KsrtZQXbXKcheUvNLa80PDRy+lYKchvWnu4rfINkpkp52rVeQd21QkAdJ15EQqKTZ6WYFGdyUCTB5SQXZi22IrUBkaxB5G0GUVJDdLRRkdwsF2V2vINtbgclLgmm9grwRTjnpgO5yGT0NSQd4LCJ+SlqE+BXENAf4qUnwGwL4Jay3XCC0ZUwU4EGoU4IIhCSpkaN+ihpRURDegGd4MKAHlEYtNDv31qlFu9arRVTph5rmYl1JMwNQQpNKUsQc/s7h9grFVWcGGYUkZVUv6CVZ9cLZs2f5UtirFtuBT9pPVvYq0WmSv5p81mIMjHHktGDDdIIsiIeJrCmz6pZmkJV9cAzryz5lgg/Gl+IARR/JLCqiEI+QVWYz8eAmHzLJBz/50J98GEg+7Eo+yOTDYPJhKPlwWfIhl3woJh8qyYcrmkGvzAKQg6zWq1HEgCt9SECoxQZP0xorC6ZVFjGmpDLJfQniDr1AvaCTDIDWBqy0F7DRB/MHzNpeOvbvHX/sS7/yP4IHSbmBkBmFx/8Z8CO69GQqFwwNQr8MBKC50vTfKjNwupGFHzfoJb9CB0PGJKxCdABNBaFpBlwFArSKLKBrNhYZGM2aRZ4MPYMnkHo2YS7Ysx4n4biDcfzkOJb09Tjkue6KJJlSRlOJ5bBHCsm743DZQ+PEA4CsII1WMVxuD+nVPaBXe7IH9WpjKehDVQKwVkBD6QS+ASLGV89+aA1ULBcVXzxUh5F8ABi9QlYUv/XhINmn43YBC2mGWdmHvIbLLKgcHi5XDycdWuzmYUhBgcXDumHFS6jzoTi2m7KnqRwIyeBHEGQCegkFRF8wtaKkzCqjM9tZKgkMPBWSqyc/tMb8MvOUqaRfRT0BYERGWWa3MMtwuJ7BE3DveYdchj4aQoppxx4w1BA85mGGtL1DaKSIZW20ZrJMmehhJ+e7MoM07I1/MzhcucwGvcCGsqi3RjpkBu0djAXNYq+wITAkvDNA90w3Q4BfuhluI2MkI0UMKQ82ZJsytZ0CXvp1b11468Zvxfq3GXibid8aybdwdkXzb4YZvYukZXLoqBN68LoH7HkSIunNs4NzyCCNkww8nFyQSaqdKTgDyciU7IGNSAGFmgToJSQ7LIvlMCOzsNM9OHAzTIPHl3ZaxDsN6mImSEMEb+ZHDOrLt9OGA8Bl7wHUYM2mn4dmbtADbkFCPpxoClE5RC4CI5JXPS0zpMyuM4IzMn2EVFtXzycDuMTUKuo9HTXQ7rTIvocphRnVu6R69iOdik4gDOokNSTM7rQ86k/E8wFggc8+gzSHbAy6iRT0c3yP80FXK3XRArUtgwrj1uMRs4naC1TzMhr1qAWcHkjHd5E4wgytSWDoFygXYQY2SEjnEJrvgDBJAjFlJsuViXpfOoP8Mq3W9N6sIxFSioJ0VyAAbzfJZ8KkyAoKrBHSUU9ABztYYodkhjQAW2aIb1LYsjYjTDrPiUGEfhY0aQaZ91c2d1dwZzEArQnzF5LNhhj2ziGiBsz/0IptP9DQHG3/CQnB5Tq7gFMOy2yYUrcc6tYa2DUo1+IyxtRvr7yzCZpgM/Egkg9G/BBw6QIBukgu0AXgj+RSJFpAV5CPABiT0g2NJob9R/qptk67E4AMBJIGDvTFqS+oFHGl0JWgk/Fm4AJKgGcRNspSaxz/wWhDgAlC0AHGYKoXnKWQU3oERp/B55QsYRA0SGkJU+BKcZZlStnLLXXbB293lyQn7eYc78xzvzvXC9FsNgE+4O8JuWSkgFjSmQ0sUDshXQVy3MhzIQX9NtVtq+ac8pcB5i4CEhbiqJf5EokbGI/r8Xh3PEQCDVsMB9xysrBvGtaY6SZ0I7GhEURl4dR2weq3mBnZgwjsXmhsLKEUTwHuwQsyLDgYFpbGxxQ6xRnEtfEY63gSH3kSH3kSH11pIQcJUygQhE6SSJG/wZU80VK7GQTwe0GJJd6/S/ELQbSumrOhNcikNf6Df/8XP/jVf/kfv7a/LF7i0hr/v87+tzu+/oufefjBlbJ4ASruf+Fz/+rOb6/9+d0rZfEcVPz1Hd968Af/+ak/fImVxTNQ8R/u/7unf/2Hn/nUc6wsnoKKr/zCC4994Xf+9fO/z8riCah4/K8/940//OS/eapYFo/B8yc/+PHv3nbb9z/zxrIAPB6/46++9sB99z78635ZfA2ef+GJ37v7u49++/H3lMWX4fmFT37p57/51ft/40BZPAzPn/uDs5/4xu/8wj0Pr5TFA1DxpZ+/696nPnrX139+pSw+DRVP/sWvPPwb/+sHf/3vWVl8FCpe/v6v/R9f/fzPffZ7rCzuxDE/8fS//eBDj92ztyxW4fkPvvMrv/HCI797f39ZnJbW+N99avU3X3742e+9uywWpTX+2H+4e/W/vfRnj/z2j6BwXFrjf/j5//rtP/nu334aZnBUWuNPf+/f/e+//Jn/9DKMd6O0xv/mqQf/5vOf+Nw3fvdHILhGWuPf+a0P/XDt6w9/aLAsrpLW+Le/958f/OsXPvZnC2XIixl/6J4X/uPvfOnl76qyqEhr/MVv/NdP/OVvP/I3qytlkZPW+Lfu+s7n/qev/dKH/oiVxZC0xj/9R/9y7Ref+eNvbysLKa3xB+/44cf+5E8/de+v/mjxA9Ia//7t3//dj3/r0Zf+5Edr92F/fvBzX/r+i3//pzNl4cLXf/bw/ff//B985m9ZOT4SRGWb79cFcbUuGFGNGdVYUY0d1WAGoAE2lI0phYEDPy44MEAjSMGPH6Thpz/w4GdXkIGfATxq7ZGWWgW5q1bFyx4FUYMu3IvsDfRZK+iTTrBNusF2mQr6pRfskNngApkJLpR2MCDTwUXGmLwoMOVAwOQFwcVyR3CB3B7slNuC7Vpp6MGAVylkTzMcAL0V3G9rjA4bwgGtqVKVr7ncgLrolBxQPafackAZy23pk3VGjmxoV9HteqFdFtull9tN6o97ckBy9cwdJGwlU288JZnafqotmUrDv3Kn3N5egn+X2k15gby4Lc0l+PfCpjTVxaekqXacagOIT7WbcqCJlmozvKgZDMiLZH8zuEj2gnLSG9uu8ONLFmyTO4N+aQY75MXBhXiqEvaoNWS90pAXy0wTbDlDXiCzTTDnDDwYAovOkBfJdBOMOkPuxFhdSIrcLt2mDlGWDsZrG3JA2k3QDCy1apJDz1GOekIvV9nKVmvRg6Us9eU7YhPAVA9ED4Yy1EejB6GEWo0euOLqBa2pQDCtekY//Beb2yubwgiYuqUZmrAfFsZ8K1O5EBKzFDo7JW8SF35zE7myQ7lE7qEotEmoFMXFG74fCnA19nokLyFt+1DWVb40IKsaEnAhqToAXeW07g27hlBxB0LaHDCur6afWbW66h7KOpJcHHYzcLSNiqaTEweycxTeUSg7o0N2S4mlbEpalAhJwUEHsynwlfl9wDcknw0dMl5McKqAFsyXWkqA/gvSAASuE+eLWdCDFQd0Sy4dvxdyluH0YdPHFnzMu+HfrheFSNvN0IGZuKRzwCxsoi8TgpWWpNPtxVnfC4YVMjKNYQLW5vnacaKsA+NgqkAyuwGUUB7lotqUleugRwOnAVnp5+xZpxVQbxEQbL9XR8m/EC/uUNbEgH/QneA/SKOfiLIw9RkS4JpBabeAa2g1GYRrAgLFIPjCvTprq4fvXGNqr3rqzjXmv4t56rkP4/Ono+dXeL/1ciwCPI/gCjDBsHMwJ16C/FxKMO/1pOEzdICjOXeLOk1TBwfoTkyRBtvdALOR1si7a+TgYmF+2pPCe8Dm5gpiPWhx6ha6JgBzaZqUow2a8BK6UcUSGNyoEkJGAKTpgNIM0yUcP0AyA9R5ZRwAgpM6A0HnpVNeBgxE24TJEahiSeH3eZvIHz4KYWDyqWuyElR+kXJFDJ0Q7vdBL5jfECMBa0rt+ogm2J0TgFCKTVOKP/J0Yg1W+f0U+Q8piSvoOLr7PlAQbah88u6xEK5MOHtJwMmc4hvyFBjmU0IpHgjVyBhsca5GnL3hUeLl7ZB4eTWczuu0gwkqQ4KCqXYfzJqUMsXRAMa9MSQ7RPktSKLNg3BLATgGA67TpfiG7Aia3vPJ6XXhgDyT7AvUPKMsLtCLz16C/xzaGXDd7933jfWuS6kx4gwkQSk1hhRbZiAZmzOQjA0ZSAaMAEo2OrA45fhzzLPpDkq7wWg3+GudPJxMYdaHWsG8E897xoqd1U7krHbBWZ2SbuSsToOz2tMxbDKNqk582IU744Gd5KGnQHTQ6nPBWe2Cs9pFTwf6ckzw5XhdZ7Ubu33dpLPaTTqr3aSz2k06q92ks9pNOqvdpLPaTTqr3aSz2k06q92ks9pNOqvdpLPaTTqrXXBWp6WrKKEsnXRWK+fQOT3V0YUDIL7A74xOFhJyuuRiP9RmIHQRwfWrTJj54rj8EGamh/yLt0NhVyi+eAPqBxYc3oEPL0xLlxwDSiw3D2C886lDWQNCRA9mifd5kkaG2HcDPdd+ZeIcs7Y8/x2hR7FgHoX2eYBVJhyTo7xTJrxzKGwFn+DoE8+5EGdg5Fl/RKKmoN2oDrlR6XaZTWMaW9aKc0BVuRgG/tyHtKPHIV/N05ZwV/it6q1qdfVRRjRiY+oVqFkYvbljWXHJd0oLPSBoi6+urppLsTtC+3L9SOFy8K4OqMMmDuXL6cM5IEoH26MdbajMUpCJ6uG2AohdaobZ2FrOJu3obNKOzibtaAfCXcGO9rpHcdgVng45MptoCV0G2fVncalmkAIH3B7Jd+pYiZRk+3gm5Ee6AcSwcncJRWpqFm1/7bbSr9+wrIylHlMwzuCCBlKLIBAVEhiXAzyZOHv2LAMOAol4ASazsiANc1Tgqwa+dNZYOpIVEKcBl1vAjNMyJb0h4fdZkDWfsdAlxXYLN+x5COZ09syS+vLq6iqwVxc/xPzZNOXhg5MQNubt6FF0cPI4Cvq+9Lhi45AqlxzS9Dx8S4Ef1BuEgYCm6Q2JTJ+DNwcFEF3Kde8s7h2UhoNBFmT0EYj023JVRjxEslNQXnrKwkUNPl6ZQytLx3PBY3WJSSnwWZD1ZNZbM7mr+bcZhSrDcS/qNHsYCy0MQrCQajDBkS/jrQjQA1zIRFck2Bo30S/oJnQUa02tvBN5BEiClFrlwPhSao0fyGL8FlC5SXqIOf7A6uqqXUajC65paoYWRj8hb0MQAPwt0lJRX8deLWkDGEiBD+B6DWxlUStgYaElxT7GQxG1tKSAlga1hKAFgreL5KpwHnAEgzooKsHKAhYthhgP0kCJROhorgAJe/pTPBGHK072MRbQbQ7SUiaCk9KIwfEKPUGDVNQAKWaLHhy8aET5S3Qc7SGqAGT2cRO7B+arX6bWvUQTJ/U23gNm7tt4Rprg1HbXgxyV/a0hzRAyRgQZL8IGMEwDC869MXPT8p4xeEZjkCANwKVYSGi3Tl/lcfS8AaqyLY15+GdRsQOQj4MX6AQObCqcSR2C6JXIp8/J7MLTLlcR5qErH0UEBQDDsZticfY1nCNYmGYNscHGBNIeOqilMQ9GFtyGNDcLg4eOtK7O0lGjQkmkTP+N4CUFX45Azw7pL8CGdEggWbUgDw0M7dwYZ3srhdVz5aMX1MEIQgjQojAK3NUOBUtZcFw2sNSRTquFAFhsBhZEVsHpCE+E00IHEDEBThY4hJJOC7bGRb2pKTFTHnzyQQq4EOgWtvRm4UITYIjqAfAm3IjWlYMRB1EFQAbpm8RplAUhSALeYXKxAnplM8qnho3UTMLQhg8j5Rr0yCjjmiUMH7He8OFR6rXoGj54Bqszqrn2TkEael8iOokGFmRxdS0ekbB4gEedXgoNv4+U/GZkL+IKKK2YrbN4eGTxwKcb5xJ/hD7vODue+/29m5LjOWryRpQcb2hNnic0eU40jlUhj5PjeVeT53FyPN+QHM9Rk98i0ZujzUWJ3vG8k0vcaD71bpUFTr08v76XaPW9nvc3glsroNAgIL579l/AZUnw36GdIZs9grfs+ZJrlxGne/iaKtMkUxw6PR1jECZNSBIzmFsO5wXEhUK4wAD4VBPvpcKrENAwRXMahsNLi2ZDPkshU9Edf8DUUAnWXgOYvolxjF3XB+SaWevdPkKHTXYbCcn9XuTVeEsRdKNT7yVvalRAGuB6aI42NsZtXx0Zl+umYYADATPlu5fCCR0/0PXKGH6vvoYNeJn3EQO9D8CFureMxSgIzmQjjtoDP6e+rQpvrxJ0ZRVH6S0y+koQmAQxSK5TXbqXXomdGAJmBpbkoYBqgdUYzbcTk1Ggdx51DDHp+upAYK60rTBK4h4sTvdgoTscDi05XTbXL0XWAKmL02FwpINxnfjSlwKvV6NlwaTN/ehm0zM3NRVowlO3Itnpa/eIiOn6LLx/jJMHi0O/ONqARwDqvujXL3q93nX3Z7HE/Vl/IHiqqyZhRBsMhDIh/n9oYRw6R6mMYcUmiA46prRQoqKmb8KOuhjBiE4u/2Z9IZ7+FOUIKB4BOEJ1JHpapxhw1NUhOBLcf91wcQ7h4i7IoTjFIBNFooNWJrE+syHFIJ2UMSmUMdD51l1TiPu6FANLm2GE0ZbOt0LSIH8zj4++SWFAb5YFwuf3XxGk/z88Xws8/73gZpc9u8vo2FSXoHABm+wI8qmfkMaxUNDFqkLxTku9vY0Oz3E8OGM6FjUmYWAxEUlwuCUTVVlD31eHpCZZJKdAasUUJM0W0k5v5K4wMG9EX+mJjgozEoZwL6LAexEF3YtoYCS5oa8zDNFXISbQt8k7cKcN8KNEV55HZ9r6PhktQBkFTq+7XaYX2JbfjBZgblqA98tCcLrZViaNIkZB2Rx+uOoDHwrcWwvnHoLUaa1cZ+hsQWWaX7y9bAzoJxeffHU75Xb4TWn4b0NVTH+JczSp4/Dc3//5pu/5a/r+s7ed9/vzzHzDlw8J7nT1fpUGfyTuMKYXm+o552CWqs3AQtMQ7uRohra6pSktuGZHffT32Xxowc8cHOqg+fIsm4d7mTr72CMfGXsbZmnfipclqGfZHFnNybcGuAjBE3r2kf8EkeuxyZWCzbJItaCh1a3N0ABvktUdiW3ZK/WBkZp0JaPuYAU7gPuI97AvfmQMaZbjoR2LlqfS0pj1M3hjExpIhvfLgtsrdCOXiEQ4eEwUR68uVfhQIbBCGcpEmjUP7sSrklDLIehKMUsKhtDJ+m+hjk26LBciS8x4DDr/+6kltbrSAqmu7GVMxNfDwfZezjjeP7LGW5cz1Li5yixjUI7+jj5Ae0x/Adhu04eOeib+EN8Z9E7gO+qBWnK1bVktYls6BtMMz5CiiVzDe4pzrvHJG6QoQ/XSHWtIcXhYa6HJBjF7ad/qnmg6iSQ2ipeim1h1lT7CMrpV4LjEo0Z0oIQUUglARHcGGqEGVdixzYn3m+pEKyrquCP/WrCJlUmh2+tZN7q1B5n3R92V4dVGFDqbXFTKt/VKwpR0/Z/pri4VrcNVt+OGuv4MqkZ0aiXWLYvS09HBQ8vCG1TsjA73DQRVuPGybLycSS8LizQJ7l+Lt2Cakq9flh2FaQ4y7zvdZWHu3uZluf4booAwq5u6T7nS3Rj5eCk8WgeGz5kU9oc3hGAApYmYQ+vACosSz9ALYHXXgUWKbzb8a0MT12HoddjaLCBLmYLxuBDE73PIyvRJEQjN1dMgptDDZZBz4TIpojScQSnUYFO9BJHEKNsM1BSGhOlnLybT9jK4nEVCeTdkrgFP5XQFM5cU/kvuEhCBWLtbQMIP715qg9qsD2pv2XAhQl1R6CJEX5kqg3HFHIbyqVex23DpMpyX7oRLD02yG4E/QYqm91vdTbMHGd6UsYHK/NdCSDYa5HR/uXWIrjV36bAKGgYmVQitsJl4HZ0Z7RQWcRDEOGMdxlnacoLJ27RTa5yLFZLLlC0KcfUyhdf5+m/C5C1EbkgBgzRIRJwBPD3oTtnNsq39/zIFocuAxmoj1/E5hfq6mKMWQBq/CXpSCuhigCbSD14T1d/0exA76fJW0EF0CpiEFTzSBb81GF/Fo5iOKBV0aA93Pke14D1skmQVxOORVDoQig4XzoZCuZAn+SN6us09uBNDmnaiscnhJWaHvZkOgpLHQ3AQRN0jaZGOxCJL1yJ4/ybnhr7HglR0yDbz30TSEWBskdk9JPpJbzV3i4wGpgkQuoz0iiHSMgalCUncphoEIOF3u0IM4UASUW9YxmBmqPBDW1rS8G0aKUMZEXaX30HtQLiRC0qTsPxbXURxBxld0m7Svc+O/ya8GRmPWPAi5dQE3cs/gF60LqI450QUOGDEI8cNiOIbeDU1+JEBTeA6KEQTlxJIcBqAJi6hiRujiROhiUto8tUumuD8N7FWX74qRupihADdl415fS5So3ZDg5zDCq4REdJFeFfOYZESUhz/WoinVNpxmPCIYHAGocsfd6FuJa89UdwfBUd9j2FyxjHq5Jt3wTXSK++C4xa8h6cEVfBm7a4xcCeCS1Qi6+3vM0F8mJezNwBKXM52AYcHeFrSQm7n6+8QbaH86F1j9B3oJRZ1M0CPxNWl5R/Qtj4MuxIND9mHX71L8zpw8r2becz7RnddyDO1WxmwyfLfhBfikywCbCK5PYQ3OSSxyTonNsGd8sSIu9jkC+SpoHcAJuEVcZg4TQYLTgEwySZMsmNMsiJMsgmTvryZ3ydY/aWvCotswiI7xiJ7IxbZrw6LrBiLrC2wSDP52wVLOLvqzR4L/noNRZxsBF8vHAJQwhan69C5vl0S41rGH/qlv3joOy9+/B++hVGifPzXPrn6w1/6/B2PPIfGLh//1Df+55/7+EOf/PApOHPi40/920/e+fIDv/6tEklQF81FFLrxGGzTGL/6lfte/tgXvvLC9xmNcfbBr9/5v3zsTz/4mRUa4//8i9/68y889A8P/fIKDfKXjz/0xB8/8Vdf+a2V5CiYJHSIxb9f4Jx/QBpfQcB95RP6uIkBGWF4vfuVj1/JGGP+O+nan9Q9eJgkUy9zac4hLoCDgwL3pYHcycXQc7CRD+p4dFKSpIMap9bTjPhGCK1xOltrnJJ5/5o0J7iHRJn6QMT6ojS+eGvojss7H9KTICPHfSg0NPVh+nD8Jtpr0i8syefA9PevTZyRSzNwMgKyWYE2bsb8gGwkrdCNiRweIu085QZ4365D15dqpfX/VXP9rJa7SdcYbqN/yZYESg4iLSgPRX4yTr4VJFAz0peR/MBN1iVQLOK+hrZ/LWmW+jYGpMxYhSSPk/eJjTrMj621bKmsDP2YysqHu9Myzjkt4xWnBZAwoeTv1SF2NBGI78bobuoHAdydCLIEgyZyJ6fkfn0givmLXGtNdICFJ4shP6QzMdbpyyLaKa71ZaurL2MRtxR3yli3UzyhL+uDu9s50+f57DD6GyAtSpwDmTnc7uxfG6X7G/SBxmRwqmJmCNWCkImxmRM2QwBNQFECMTbjudmHOSXV6VNdPDKEv8AU5TOunwRwl3geOLSJQxNEtBMPrgMnJkxnU3F1xJt14CQWombex2NDT76yobera+j1w9HH+Q29XaE208Ru0Q+GnrvR0NvCIJMaWyJax7+ipbEzOmGdIBxhWqbyZTopEhS3+dEPr+kbxSl0bw9zJdvDUn6InilcGLYkBydXj5zng9nkRDDvaR2lEJ106QMPyd+sj/WJRsCYRSAiSmrTNqYRA6dOmHnsVY0FHgkXaXHPjznOUS5WdJiuD9YFOvrgxF+MQSDzygEK0YJwBVJnfMglW2yi89mHOTBpzu7fGSQCzJJKS3eAKDoYD5/NTdHRLLKgTQzJP5SlIOPeV4yMvlkPEB2H6Qg0YzY6aBT+W8kHiFxEaH8l+KYmsnqvDYwO4NF1B7HXzbuJ81foPOlkTA7Q7RtF04aOFfMOJ5zoxiCj6NQMnRwAaMhPDXNztbfZQKBntLf5kdU1OlWRArzN7+QGHG4YWv7RX0+AiBQTr5oJrQNZ5ErwV7/WJYFNZIW+tMWaBW+q4V3HxQrFcID+/9hdeHbfLEEJEQVOmpBXsj1gDOBhPtkF9MWX7xrD9vArkCl6ByBAglO+PYaJGKg1uglXjRu5avRRL4UhPLeOJ3hvIxGWBJkf3ZFvKP42vovOSAFYACrMXgETYJx5ReRnM0QhmqlJPnuI/hTfRJb5x9CkIVXZk7zXY95YxHGo/X4KhY/4g39NL4W94NrwJFr/ETTafD/rjeo/FrT6KB70Ig+P49UU742DHIlJ041dEDzmlThfkTb+3S5zTpn+FQH4QOkR0Elu9PyCZ/QirwifWZAeGX1mSUvS4xafudLxf8Lbi+SnYaP5ojRmKRAcD6VBnKIhBFC5LMJdzZ/Ybs2AiYtKUUYOYzb9SeZdGqsdKO0JzfUBFFxL4NeYt4vzFcQIHzGCIUb4dCmA90YkQuJ/XPGrIsqi+4e8N7KYMk1Av6/dBX9AgEu2s89kzLtE/5ELWFJr3S7S57uSvU9s6PtCxBlKBII4fow/8JnXD4+g3jDJ3wd/RozdvNPrjxAJ0ajKPP+9zOtjFC3SUbxFlLqNReIcdwKGuRhN393imQ+PlelYbrd4CsosfvVE4tVj9OoCvXBOvMnUeJONt9hP9Xrbt6AiXyOxRtJrPC+aS7HXS9P0py9mXi+6ST9EVref9fqYuvMja0xdqj77kTUGa+lh6sX/YY2pYYmPGd2Pcn3leexSJuEml0vxBUSJcGSDKRrhYC+UAKpXRl/uFqxssKj6fUxPRrn+1Z7DJPP3M/rNMc8DpZVfzj563xj2SKEWLlqM/hWexfzjzDNYr2fAeRXzvE95nNEfJ73HA8vwgvbczFRDzszXG6dlu1Nrddqy1pFrWTl5siMb83V6ZLrFwsmOXJiWkwsn5+vty2XneEPONeblTFuubcMvoIaaQh3b1qrNH2tQx7p+LYx6oXfTCy1Jk1iYhs6OdY7LNebTy8Z8/TV9tq91st2Z2jcyWsmVS/lSIz9dK4wUc+VCcXJ6sjaVL9bLo+VSvdwo1oYrpdy+uZnJVq11Zl+7U9/Xbk3ta59p7+scbzVq9ercwlRtbt/8QpWe23tb7R+7+6mFVoP6hxnvm+k0Wv+Y7vCPs2J/rdqp6nJjat+JhXrc4b56Y7G9rz5Hf+p1T25vYW8+j62jOmzaObG479TkyZm5+j74w7NT9Cdlsd3czCQ0KXCfHWaM/a3FWMgY2yF8lmaMjTLGehLPY4zhb/QM36QSz+9hjPUlnt+74fnGDc8/veH5pg3PP7Ph+eYNz+/b8DzFGOtnjBmMMZMx9oLwmWCMuYwx1ek0Tix2GnXZWZAz9Od4Z97fkLTpexAJ5KnjM3MNOdMBnJ5szMwfk/XWwuJio87Y95jPfpYxNssYyzLG/oH7CKujekyWqLuFMbYdvVSMZfRcojlZuq2rn23GmKOfUxq+vn7n6e+zeh9OvO/3/viya/5q6rT6V+9d2nnX13d8/uZ3F9/tPHn96S/edu0LX/hBrd1utDozC/NyujYz16hfLhfbsMa3/6TE37fKEzPz1YXlRut4o1Zn7A+5z97CGPs1k8Y+1/dXdL+vnd7i+980aY7JdfVuWD+saaq2WJua6ZyR0MX03MIpxtht3GfXMMZ2adjkcrl8rpAbzhVzI7lSrpyr5EbzuXw+X8gP54v5kXwpX85X8qOFXCFfKBSGC8XCSKFUKBcqhdHh3HB+uDA8PFwcHhkuDZeHK8OjxVwxXywUh4vF4kixVCwXK8XRkdxIfqQwMjxSHBkZKY2URyojo6VcKV8qlIZLxdJIqVQqlyql0XKunC8XysPlYnmkXCqXy5XyaCVXyVcKleFKsTJSKVXKlUpldDQ3mh8tjA6PFkdHRkuj5dHK6GiN8A2wrT6zPFNvyMkz8v2N1gL7xaI5XzvRYBmvfbxWGCntPQV/kvvDxTprVK87vHx1O1c93Lmy3Vw6Xbnh4JmbJq8ZrVYigo4LBw93xkeqlXfox8Odw53xcnW4faZduv5Mu9M4MVGiN1e2G5NXTdUaC9e3Tp+pDrc7dR5UbzpchL81XYQ/Pp0vHy+W6vnpSmV6ul6qVeqN0oRINDlRm8qXjw8PF0ampsulynBjdLhcKEwYl0ZNSvSnqfPl4/ny5GSpXpyarg/narniyIS5qZ+p0VKxPF3LVYaHJ+sjU/UJ6/LqTYfz+QRnyg/rv3q9f6F1otYp0Z/TzpePl2pT5fp0Iz85Ml2aLOfLE/ZbqjcdLhEU87nuX8KGto1aqdiYLJXqw7lcLZ+bcEYAuACnK9vHbjywOHJk6XTh8JlqEVj2cLvTGplaODnfyRfrC1UsVaeO11pt97LEEAX9Z7fH5xamZgFuU9PT+eHJ4alisVasTxYmUm+FQa5sT79r+kxp4mB+ZOZd1XK1WkX2nx+GUn0u+qPb6anXc7uL8Me9N2+294rgpT80ni8frxRq9UptpNyYLBXqUyOliczbaYK16tZAmz7RwWmNUk+dRitfWKzVq/AHw4+1anPZSwnk5/h25FRrptPo+TjHGearg++6YZCk1t7lxtTeve9pTEHVDYMHbhgcPFnIDdba9JNo015sTFWnWwsnqiBqq/ONdqdR37v3+sXG1P7WwomDnUbrMNZRT1cOHsTeDtwwOBp/BYibqzQma/X6ZG2qWCtP5iZ6T7yee5Mfrs+0FxfajerU8ZPzs5t3yT/2eg5XOdE4UZubOTa/eaC+udd1XYWT83Mz87PnWta29wP5434vLLYr0yfnp0C6jMycWJxrlyq441DGbYVme/cuLLb37o0a7t27fx7aqBgFphda+Dt43f7B/VBbnKrNzeXLx3ONfKOSKxTLtXolV54cmdj+32/sfKHemGxMlisjpdHJRq0+0d96XaFcmZkHuVydq7WOnROFdoyeh7+NoDo6ggp2voQPVXyogqC/4Kfg0xvblSp8fk56HZ4/eWJ45sTiicOd8dFq+R0z7cW52hl4c+HPUgc34XpHpo5M5QpHrr5xbqE6gosqtTutmfljuNLS9bp8br7wXuAL+RyyB+TCA1mSAZpxXZTusvqLUyDDxs90Gu2dt3OaRpWYzkYIjS7W5memZmfmj+WHsVg9XpuvzzVahzvjxWpeczGa3jW1M3MLtfq5wImf5wvXwI9uWunUZhvVyYXTu7LEUfVk3zDUFSCj8VxBQlVGCpON4fxUYapWyzUqE2+8qXrTYQJYWav7hdx1tVPvaUwdnJ9vtGJsLLca7UZruVHI1xequlytzdf1eoCV13K1erGRb9SLo1OT5eLEJetmUWsfj2ZRqeVGa+WRRiE/XanncvUJ+daEwMuTaI8bF3KjuVJlujTcqJRKU8WJwEs0CIcjdn8eyBPoqqdmOserxxcWZi9dN7ETtalorOHiZK4GSl1xJJer5XITg6n49Zv2JuZYPNboHCR9/qpa+3i+fLxRGqkXi5XySKNWLzVKlYmfcLTgfvPRVwnifH56Zn6mfbx6rLVwCgXE9FQpn8+NTE3WylO53MTeubnlE3sLucJooZIvlCqFkZHRykiuMPReIv3iOUghGhZpIZ9PjL1uyM0w3J2Kt+0tgOgHDy/DOIVXGAfYS2X9QIsTr4BAyINqEe0emFuYrM1NbJ7RW18/fB0tF+qjo+Xp0UZupDxay01c1rN+V/dc9bqAdbxc3XvVj4kCU8XcaG14dGqqVCtPFabqE/tW/hnZzfCxRidXrl53+MrXOHoOAHTlJhjkRwkGkZ6EIKjA0qsL87BFudJooTGZm87Xhyv56UJ5oqBoS4ZfBQaOVEvU/fGJuE+QchMRBM8lJkEOIry0MDzcGS9VRxW8qnUWWjRS8VVp3XXSukfeEa99GNZd2rjo/EhtcbExX6825honGvOdNhpI+Vx9anJ0pNbI53JT9YnSza+/xBslgdfutMrlcyrOiX2t0L6Si646dbwxNVuxTzROTJ1YHL28qwycBzPy1AOaO5WZ+alWo9ZuXP5BLUbzPwZij1Tzpes7tc7M1PWd1j9CiL5tz6uAAJWq0yc6V/RVq6cmZ+brxxrzVcKcnzxNq8j9eKt4DeQJO5jUht5+GOf+ioZT+ZyGU75AqLDYakzPnFbbEovTtuP43vOhfBl+qpONYzPz1ZPzp2bm61e++VyymfC1gl8sLJx4Ry5quBmpRyYXTjfq+eLkwunqfONU9eQ8+NEmTpEw2nKli51Wfhh8aNWZ+eriXG0KhMtr248tt2Ji/Orq/j3nZn8bVgVM78D7X9M8D6IULFZLC4tgDpSO4M9BwoHNsAFeMowMbmILUXnV9GuF0fm4imYoW4xzcPf50CKvOSH29U5r+kTt9PSh8+x3LBsKtBXVRqu10HrX7ldBmkTcV/cmMBf8E4fRzr1xazjU5s+8uoUf7owXqsNq/ky5c2axUZ2pbwbEkSvPP9B1jVfRyTWXv1b/R5eFX3vZ+XaiqEsROV/3gX82lguKxPUnafjCP+3wGxnlDa8ORlpwv/vN52udw6Y4ufcch4Y//Wpx6dVJ5ekTW8DlvSOvgnjKkde7Gnm9bzx0HlN9K/E83+4UhhMP1frMcnXyTBXcyT8zAe6zAviQq5rUhnMJsptaWDxT7SxUAcvr1VqrVTsDCnejmJ8aqTTy+Vqt0GiUJm5+y/mgW4j2Apf2vsp52hZK69oSz4jw5WdX//l0i1KtDYRZLZ5HwOG/+Ypmd4kF1OqagW81aVBSRydrU7OdVm2q0QUAKJHt4wutTjV+SfJqvFTdKKDePzFeqk7Ov86jnNsKwPGmKufXCgrFemO6dnJuw14uLMzWkWP89MFXIc3zpIdgRVnvxXWNide+c+Oj1enc+bC0EoEET6FpxGPqPF8Mj0RfzC/oJbaPz5yozrSrJ+fbndrkXKO6XDj+KkljcqHVmVGbqBF2KqbGhclmY6pTRWnfasDZy1S53sjlh3PD5Vx9dHi4OMF+JrXYWqifnGq02sKdq80fO1k71uDmdSfbHZZZbC1MNdrtRn3P5Blh4RTekN87OrI3J4fi42VZyBVKe3LFPfnibvtUba51sm3n9hZye4f/b9BTSLY=');
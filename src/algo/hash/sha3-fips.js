@@ -0,0 +1,209 @@
+import { SHA3Algo } from './sha3.js';
+
+/**
+ * FIPS 202 SHA3-256 hash algorithm.
+ *
+ * `SHA3` keeps the original Keccak padding for compatibility with crypto-js,
+ * so its output differs from standard SHA-3. These hashers use the 0x06 domain byte.
+ */
+export class SHA3_256Algo extends SHA3Algo {
+  static outputSize = 256 / 8;
+  static algorithmId = 11;
+
+  static async loadWasm() {
+    return SHA3Algo.loadWasm();
+  }
+
+  async loadWasm() {
+    return SHA3_256Algo.loadWasm();
+  }
+
+  constructor(cfg) {
+    // The output length is fixed by the algorithm
+    super(Object.assign({}, cfg, { outputLength: new.target.outputSize * 8 }));
+  }
+
+  _doFinalize() {
+    return this._finalizeNative(0x06);
+  }
+
+  _hashBytes(bytes) {
+    return this._hashNative(bytes, 0x06);
+  }
+
+  _hmacBytes(key, message) {
+    return this._hmacNative(key, message, 0x06);
+  }
+
+  _pbkdf2Bytes(password, salt, iterations, dkLen) {
+    return this._pbkdf2Native(password, salt, iterations, dkLen, 0x06);
+  }
+
+  _hkdfBytes(ikm, salt, info, length) {
+    return this._hkdfNative(ikm, salt, info, length, 0x06);
+  }
+}
+
+/**
+ * FIPS 202 SHA3-224 hash algorithm.
+ */
+export class SHA3_224Algo extends SHA3_256Algo {
+  static outputSize = 224 / 8;
+  static algorithmId = 10;
+
+  async loadWasm() {
+    return SHA3_224Algo.loadWasm();
+  }
+}
+
+/**
+ * FIPS 202 SHA3-384 hash algorithm.
+ */
+export class SHA3_384Algo extends SHA3_256Algo {
+  static outputSize = 384 / 8;
+  static algorithmId = 12;
+
+  async loadWasm() {
+    return SHA3_384Algo.loadWasm();
+  }
+}
+
+/**
+ * FIPS 202 SHA3-512 hash algorithm.
+ */
+export class SHA3_512Algo extends SHA3_256Algo {
+  static outputSize = 512 / 8;
+  static algorithmId = 13;
+
+  async loadWasm() {
+    return SHA3_512Algo.loadWasm();
+  }
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHA3_224('message');
+ *     const hash = CryptoJSW.SHA3_224(wordArray);
+ */
+export const SHA3_224 = SHA3Algo._createHelper(SHA3_224Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacSHA3_224(message, key);
+ */
+export const HmacSHA3_224 = SHA3Algo._createHmacHelper(SHA3_224Algo);
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHA3_256('message');
+ *     const hash = CryptoJSW.SHA3_256(wordArray);
+ */
+export const SHA3_256 = SHA3Algo._createHelper(SHA3_256Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacSHA3_256(message, key);
+ */
+export const HmacSHA3_256 = SHA3Algo._createHmacHelper(SHA3_256Algo);
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHA3_384('message');
+ *     const hash = CryptoJSW.SHA3_384(wordArray);
+ */
+export const SHA3_384 = SHA3Algo._createHelper(SHA3_384Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacSHA3_384(message, key);
+ */
+export const HmacSHA3_384 = SHA3Algo._createHmacHelper(SHA3_384Algo);
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHA3_512('message');
+ *     const hash = CryptoJSW.SHA3_512(wordArray);
+ */
+export const SHA3_512 = SHA3Algo._createHelper(SHA3_512Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacSHA3_512(message, key);
+ */
+export const HmacSHA3_512 = SHA3Algo._createHmacHelper(SHA3_512Algo);
@@ -10,6 +10,7 @@ import { loadWasm } from '../../utils/wasm-utils';
  */
 export class SHA3Algo extends Hasher {
   static wasm = null;
+  static algorithmId = 9;
 
   static async loadWasm() {
     if (SHA3Algo.wasm) {
@@ -59,12 +60,7 @@ export class SHA3Algo extends Hasher {
     const dataSigBytes = data.sigBytes;
     const blockSize = this.blockSize;
 
-    const stateData = new Uint32Array(50);
-
-    for (let i = 0; i < 25; i++) {
-      stateData[i * 2] = this._state[i].high;
-      stateData[i * 2 + 1] = this._state[i].low;
-    }
+    const stateData = this._getStateData();
 
     for (let i = 0; i < dataWords.length;i++) {
       if (!dataWords[i]) {
@@ -75,10 +71,7 @@ export class SHA3Algo extends Hasher {
     // Count bytes ready
     const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
 
-    for (let i = 0; i < 25; i++) {
-      this._state[i].high = stateData[i * 2];
-      this._state[i].low = stateData[i * 2 + 1];
-    }
+    this._setStateData(stateData);
 
     let processedWords;
     if (nWordsReady) {
@@ -90,6 +83,23 @@ export class SHA3Algo extends Hasher {
     return new WordArray(processedWords, nBytesReady);
   }
 
+  _getStateData() {
+    const stateData = new Uint32Array(50);
+    for (let i = 0; i < 25; i++) {
+      stateData[i * 2] = this._state[i].high;
+      stateData[i * 2 + 1] = this._state[i].low;
+    }
+
+    return stateData;
+  }
+
+  _setStateData(stateData) {
+    for (let i = 0; i < 25; i++) {
+      this._state[i].high = stateData[i * 2];
+      this._state[i].low = stateData[i * 2 + 1];
+    }
+  }
+
   _doFinalize() {
     // Shortcuts
     const data = this._data;
@@ -133,6 +143,104 @@ export class SHA3Algo extends Hasher {
     return new WordArray(hashWords, outputLengthBytes);
   }
 
+  // Pads with `domainByte` and squeezes the digest natively, in place of the padding above
+  _finalizeNative(domainByte) {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const wasm = sha3Wasm(SHA3Algo.wasm);
+    const data = this._data;
+    const outputLengthBytes = this.cfg.outputLength / 8;
+
+    const stateData = this._getStateData();
+    wasm.absorbFinal(data.words, data.sigBytes, this.blockSize, domainByte, stateData);
+    const hashWords = wasm.squeeze(this.blockSize, stateData, 0, outputLengthBytes);
+    this._setStateData(stateData);
+
+    return new WordArray(Array.from(hashWords), outputLengthBytes);
+  }
+
+  // One-shot hashing, HMAC, PBKDF2 and HKDF in wasm, padding with `domainByte`
+  _hashNative(bytes, domainByte) {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const outputLengthBytes = this.cfg.outputLength / 8;
+    const hashWords = sha3Wasm(SHA3Algo.wasm).hashBytes(bytes, this.blockSize, domainByte, outputLengthBytes);
+
+    return new WordArray(Array.from(hashWords), outputLengthBytes);
+  }
+
+  _hmacNative(key, message, domainByte) {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const outputLengthBytes = this.cfg.outputLength / 8;
+    const hmacWords = sha3Wasm(SHA3Algo.wasm).hmacBytes(key, message, this.blockSize, domainByte, outputLengthBytes);
+
+    return new WordArray(Array.from(hmacWords), outputLengthBytes);
+  }
+
+  _pbkdf2Native(password, salt, iterations, dkLen, domainByte) {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const outputLengthBytes = this.cfg.outputLength / 8;
+    const derivedKeyWords = sha3Wasm(SHA3Algo.wasm).pbkdf2Bytes(
+      password, salt, iterations, dkLen, this.blockSize, domainByte, outputLengthBytes
+    );
+
+    return new WordArray(Array.from(derivedKeyWords), dkLen);
+  }
+
+  _hkdfNative(ikm, salt, info, length, domainByte) {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const outputLengthBytes = this.cfg.outputLength / 8;
+    const okmWords = sha3Wasm(SHA3Algo.wasm).hkdfBytes(ikm, salt, info, length, this.blockSize, domainByte, outputLengthBytes);
+
+    return new WordArray(Array.from(okmWords), length);
+  }
+
+  // Legacy SHA3 keeps the original Keccak 0x01 padding of _doFinalize
+  _hashBytes(bytes) {
+    return this._hashNative(bytes, 0x01);
+  }
+
+  _hmacBytes(key, message) {
+    return this._hmacNative(key, message, 0x01);
+  }
+
+  _pbkdf2Bytes(password, salt, iterations, dkLen) {
+    return this._pbkdf2Native(password, salt, iterations, dkLen, 0x01);
+  }
+
+  _hkdfBytes(ikm, salt, info, length) {
+    return this._hkdfNative(ikm, salt, info, length, 0x01);
+  }
+
+  _contextWasm() {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+
+    return sha3Wasm(SHA3Algo.wasm);
+  }
+
+  _getContextState() {
+    return this._getStateData();
+  }
+
+  _setContextState(state) {
+    this._setStateData(state);
+  }
+
+  // The rate follows from the output length, so a context only resumes with the same one
+  _getContextParameters() {
+    return [this.blockSize];
+  }
+
   clone() {
     const clone = super.clone.call(this);
 
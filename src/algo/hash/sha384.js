@@ -1,4 +1,3 @@
-import { X64Word, X64WordArray } from '../../core/x64-core.js';
 import { SHA512Algo } from './sha512.js';
 
 /**
@@ -6,6 +5,7 @@ import { SHA512Algo } from './sha512.js';
  */
 export class SHA384Algo extends SHA512Algo {
   static outputSize = 384 / 8;
+  static algorithmId = 5;
 
   static async loadWasm() {
     return SHA512Algo.loadWasm();
@@ -14,27 +14,6 @@ export class SHA384Algo extends SHA512Algo {
   async loadWasm() {
     return SHA384Algo.loadWasm();
   }
-
-  _doReset() {
-    this._hash = new X64WordArray([
-      new X64Word(0xcbbb9d5d, 0xc1059ed8),
-      new X64Word(0x629a292a, 0x367cd507),
-      new X64Word(0x9159015a, 0x3070dd17),
-      new X64Word(0x152fecd8, 0xf70e5939),
-      new X64Word(0x67332667, 0xffc00b31),
-      new X64Word(0x8eb44a87, 0x68581511),
-      new X64Word(0xdb0c2e0d, 0x64f98fa7),
-      new X64Word(0x47b5481d, 0xbefa4fa4)
-    ]);
-  }
-
-  _doFinalize() {
-    const hash = super._doFinalize.call(this);
-
-    hash.sigBytes -= 16;
-
-    return hash;
-  }
 }
 
 /**
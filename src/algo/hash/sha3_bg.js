@@ -1,4 +1,12 @@
 export function sha3Wasm(wasm) {
+  let cachegetUint8Memory0 = null;
+  function getUint8Memory0() {
+    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
+      cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
+    }
+    return cachegetUint8Memory0;
+  }
+
   let cachegetUint32Memory0 = null;
   function getUint32Memory0() {
     if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
@@ -7,6 +15,18 @@ export function sha3Wasm(wasm) {
     return cachegetUint32Memory0;
   }
 
+  let cachegetInt32Memory0 = null;
+  function getInt32Memory0() {
+    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
+      cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
+    }
+    return cachegetInt32Memory0;
+  }
+
+  function getArrayU32FromWasm0(ptr, len) {
+    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
+  }
+
   let WASM_VECTOR_LEN = 0;
 
   function passArray32ToWasm0(arg, malloc) {
@@ -15,6 +35,14 @@ export function sha3Wasm(wasm) {
     WASM_VECTOR_LEN = arg.length;
     return ptr;
   }
+
+  function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1);
+    getUint8Memory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+  }
+
   /**
    * @param {number} doFlush
    * @param {Uint32Array} dataWords
@@ -38,7 +66,352 @@ export function sha3Wasm(wasm) {
     }
   }
 
+  /**
+   * @param {Uint32Array} dataWords
+   * @param {number} dataSigBytes
+   * @param {number} blockSize
+   * @param {number} domainByte
+   * @param {Uint32Array} stateData
+   */
+  function absorbFinal(dataWords, dataSigBytes, blockSize, domainByte, stateData) {
+    try {
+      var ptr0 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(stateData, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.absorbFinal(ptr0, len0, dataSigBytes, blockSize, domainByte, ptr1, len1);
+    } finally {
+      stateData.set(getUint32Memory0().subarray(ptr1 / 4, ptr1 / 4 + len1));
+      wasm.__wbindgen_free(ptr1, len1 * 4);
+    }
+  }
+
+  /**
+   * @param {number} blockSize
+   * @param {Uint32Array} stateData
+   * @param {number} squeezeOffset
+   * @param {number} nBytes
+   * @returns {Uint32Array}
+   */
+  function squeeze(blockSize, stateData, squeezeOffset, nBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(stateData, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      wasm.squeeze(retptr, blockSize, ptr0, len0, squeezeOffset, nBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v1 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v1;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+      stateData.set(getUint32Memory0().subarray(ptr0 / 4, ptr0 / 4 + len0));
+      wasm.__wbindgen_free(ptr0, len0 * 4);
+    }
+  }
+
+  /**
+   * @param {number} algorithmId
+   * @param {Uint32Array} parameters
+   * @param {Uint32Array} stateData
+   * @param {Uint32Array} bufferWords
+   * @param {number} bufferSigBytes
+   * @param {number} nBytesTotalHigh
+   * @param {number} nBytesTotalLow
+   * @returns {Uint32Array}
+   */
+  function exportContext(algorithmId, parameters, stateData, bufferWords, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(parameters, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(stateData, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(bufferWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.exportContext(retptr, algorithmId, ptr0, len0, ptr1, len1, ptr2, len2, bufferSigBytes, nBytesTotalHigh, nBytesTotalLow);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v3 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v3;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {number} algorithmId
+   * @param {Uint32Array} parameters
+   * @param {Uint32Array} context
+   * @returns {Uint32Array}
+   */
+  function importContext(algorithmId, parameters, context) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(parameters, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(context, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.importContext(retptr, algorithmId, ptr0, len0, ptr1, len1);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} message
+   * @param {number} blockSize
+   * @param {number} domainByte
+   * @param {number} outputSize
+   * @returns {Uint32Array}
+   */
+  function hashBytes(message, blockSize, domainByte, outputSize) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(message, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      wasm.hashBytes(retptr, ptr0, len0, blockSize, domainByte, outputSize);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v1 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v1;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} key
+   * @param {Uint8Array} message
+   * @param {number} blockSize
+   * @param {number} domainByte
+   * @param {number} outputSize
+   * @returns {Uint32Array}
+   */
+  function hmacBytes(key, message, blockSize, domainByte, outputSize) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(key, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(message, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.hmacBytes(retptr, ptr0, len0, ptr1, len1, blockSize, domainByte, outputSize);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} password
+   * @param {Uint8Array} salt
+   * @param {number} iterations
+   * @param {number} dkLen
+   * @param {number} blockSize
+   * @param {number} domainByte
+   * @param {number} outputSize
+   * @returns {Uint32Array}
+   */
+  function pbkdf2Bytes(password, salt, iterations, dkLen, blockSize, domainByte, outputSize) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(password, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(salt, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.pbkdf2Bytes(retptr, ptr0, len0, ptr1, len1, iterations, dkLen, blockSize, domainByte, outputSize);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {Uint8Array} ikm
+   * @param {Uint8Array} salt
+   * @param {Uint8Array} info
+   * @param {number} length
+   * @param {number} blockSize
+   * @param {number} domainByte
+   * @param {number} outputSize
+   * @returns {Uint32Array}
+   */
+  function hkdfBytes(ikm, salt, info, length, blockSize, domainByte, outputSize) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(ikm, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(salt, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray8ToWasm0(info, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.hkdfBytes(retptr, ptr0, len0, ptr1, len1, ptr2, len2, length, blockSize, domainByte, outputSize);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v3 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v3;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {number} blockSize
+   * @param {Uint32Array} functionNameWords
+   * @param {number} functionNameSigBytes
+   * @param {Uint32Array} customizationWords
+   * @param {number} customizationSigBytes
+   * @param {Uint32Array} stateData
+   */
+  function cshakeInit(blockSize, functionNameWords, functionNameSigBytes, customizationWords, customizationSigBytes, stateData) {
+    try {
+      var ptr0 = passArray32ToWasm0(functionNameWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(customizationWords, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(stateData, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.cshakeInit(blockSize, ptr0, len0, functionNameSigBytes, ptr1, len1, customizationSigBytes, ptr2, len2);
+    } finally {
+      stateData.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+   * @param {number} blockSize
+   * @param {Uint32Array} keyWords
+   * @param {number} keySigBytes
+   * @param {Uint32Array} customizationWords
+   * @param {number} customizationSigBytes
+   * @param {Uint32Array} stateData
+   */
+  function kmacInit(blockSize, keyWords, keySigBytes, customizationWords, customizationSigBytes, stateData) {
+    try {
+      var ptr0 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(customizationWords, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(stateData, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.kmacInit(blockSize, ptr0, len0, keySigBytes, ptr1, len1, customizationSigBytes, ptr2, len2);
+    } finally {
+      stateData.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+   * @param {Uint32Array} dataWords
+   * @param {number} dataSigBytes
+   * @param {number} blockSize
+   * @param {number} outputBits
+   * @param {boolean} xof
+   * @param {Uint32Array} stateData
+   */
+  function kmacFinalize(dataWords, dataSigBytes, blockSize, outputBits, xof, stateData) {
+    try {
+      var ptr0 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(stateData, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.kmacFinalize(ptr0, len0, dataSigBytes, blockSize, outputBits, xof, ptr1, len1);
+    } finally {
+      stateData.set(getUint32Memory0().subarray(ptr1 / 4, ptr1 / 4 + len1));
+      wasm.__wbindgen_free(ptr1, len1 * 4);
+    }
+  }
+
+  /**
+   * @param {number} blockSize
+   * @param {Uint32Array} dataWords
+   * @param {Uint32Array} elementSigBytes
+   * @param {Uint32Array} customizationWords
+   * @param {number} customizationSigBytes
+   * @param {number} outputBits
+   * @param {boolean} xof
+   * @returns {Uint32Array}
+   */
+  function tupleHash(blockSize, dataWords, elementSigBytes, customizationWords, customizationSigBytes, outputBits, xof) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(elementSigBytes, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(customizationWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.tupleHash(retptr, blockSize, ptr0, len0, ptr1, len1, ptr2, len2, customizationSigBytes, outputBits, xof);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v3 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v3;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+   * @param {number} blockSize
+   * @param {Uint32Array} dataWords
+   * @param {number} dataSigBytes
+   * @param {number} chunkSize
+   * @param {Uint32Array} customizationWords
+   * @param {number} customizationSigBytes
+   * @param {number} outputBits
+   * @param {boolean} xof
+   * @returns {Uint32Array}
+   */
+  function parallelHash(blockSize, dataWords, dataSigBytes, chunkSize, customizationWords, customizationSigBytes, outputBits, xof) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(customizationWords, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.parallelHash(retptr, blockSize, ptr0, len0, dataSigBytes, chunkSize, ptr1, len1, customizationSigBytes, outputBits, xof);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
   return {
-    doCrypt: doCrypt
+    doCrypt: doCrypt,
+    absorbFinal: absorbFinal,
+    squeeze: squeeze,
+    exportContext: exportContext,
+    importContext: importContext,
+    hashBytes: hashBytes,
+    hmacBytes: hmacBytes,
+    pbkdf2Bytes: pbkdf2Bytes,
+    hkdfBytes: hkdfBytes,
+    cshakeInit: cshakeInit,
+    kmacInit: kmacInit,
+    kmacFinalize: kmacFinalize,
+    tupleHash: tupleHash,
+    parallelHash: parallelHash
   };
 }
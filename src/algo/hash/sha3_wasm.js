@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJy1fH2MVUeWX33c+97rvu/RFwbbDY1N3WvGbmw++vO9buzxUNjANAzDKhpF88dshjb02NzGQH+4bcsf3Z7xrpkNSdhdJiIKitAuq2GzRGJ3UNYjkaij8IcVkYhESLEi/4G0KPEfToQir8QqZMn5nap73+2m8WyUjWfpqlN1btWpc06dc+pUvRXjM29IIYR8JTqk5ucF/sj5QxJ/9LyvC64KtH5wqDbv/6OuYJ7bBVXVB9SgX96lqoGWUkjZKYIOUdNBKMIwlFqHQgSqSn8CESolpFD0f1RRUiqUKCqVSiCCUJ6Ua9aEWorVlQ6a0y4sLIqouiOovDHxxonpd5SoHjnx8vQ7J2fFU6t/9KO3Xj16/MhrE8d/9Mb4sWMnDosnu0ptP56emBCbO56Qwspotd35RO3xtLFm/ZbkpW8NDuz8ducfvbvrvxkV08KeFjaeTKNNQu1UO42wfykPBjutiF9IpZG2Fvfatfib2ZiLSXvvwbuZfWvKvp+lygrXbNXsJPCN2t9jxIGGooGCDD1JYO/91qLoVWJ3Q1phgslEYyY1D8AuqLGGWG83UvXBgwfV/QRQtUL/anOvpWJyzsopGkvOTtqXsigNaSL70alFkdGAqQh2msCE8TOzSdWKRGpeQTyUKhPQsIaKSarrg1SnbqNS3RCEqOs6okHX8iDKVAhRmKp9cs4GU7RON/beHnT0JBXqkTSBEQ0RmQpNWUlEXUVGNlSEWa004Wz8XL7MqZRWqomMba8vJZZaI030EZdAozTBQaoShRWjx6ZSmkrSjBpz4vNvpjyh3I1pPvoZj50Syw4S36ifPjzY0yCWoy0eTUL6JKLlaycQkiFw4j4TGh0/S80LCm2AOkG9tnoulVbP2vtEd5ZUSCBfuRWQWGnc2VROYbG9qlaXEfdBvtNNJUwlYlrDpqpRGxV1GrjiCtnkDwyoDLL4aaqRLmmSPk1rY6cYTmnezYzY3+O0hHWCtAJ/C0KIPSBTzBFJemo3xJ2z4kCjahRplOc62Ft9mOkyHmKNMxL8DHZq1g+vFiR2PZY6dmNpEEDO96hgbAWMzdk61gg9WytgayEXklJAu2UpS6v2tOtOwodZS2I1VfBWQ2rKzs+wpmcpCWY2Ffaj3/ZLqNia11PhxiXVJEYQCH6TkjfLArIfGP3WFMkoAoU0VPy0qcQvkMpXaR6aXNiNpKGkrxhhdooYBt4umVH7GeXSGWU+oyzNmC+M5lV+Xs38wd9RUm0MzvwucQv7VrBgV2RWSROxuQQRB02sljWRqGBNrEYJ/XGaKHJNrLqCyK1FPCx0U/D0YBdpJnOkskyrq9Dq6jKtrra1+qGxNNVWUPWGjLw2L/lfeROPkYk91Qb3NwKYJRORfmMIMUn8YaO7xPaSXMgnBHFv3GMifFrb3YhQqyV1exYUUT1IO7lO/VkqQes5gviPm02Ceft70HPmZ4VlPk/VRPD+6De1PbBgNC7vBVLRQjiKLQ/JxW0HWgZ6rMh5AhxX4zYoGWmgYn7TplK8pTz7mDhmKaNSAxkqxdrjPuiM7JWc7N2GBDw23YAi05hNmPS4Zep7UUml2yl+Pak6EMyT/lNrkJFLCq2IyPjyYO0RacAa7A/mrpnOLMmZAGFp1nfCg+Z7TsCALZl7D0k6nzPpKL7W/IUwHQcCx86O/WTD2/wkUdM26UBXWnUSTrsm7VESF2QP2JAGSNp3xvtX05GN9aQN4soq0zBdzEMoSsjGU7tBkopDThQThfXBi/P3kmRG+zEGy6F4pNKdGQygqWQZTTWZhuBDbEKLjeXUUPtR1/pR3egSWPEotg1szGSWAf8yeLuwsAChMol9zI/NxJnVZhU096IDW9S/yqymwl4CIXWQdAG1TtTOM+WoAd+sohr4aaE3MEi0HwPapGSdyY/sbzAjG3vI9lNhOiaJZWDsaLyJcJ3LA9xZr0UPmRz1CEOjVjI0CoZGmQ5naDpgFTpgTFDA0LBaQg/gTGpOnwkOiM2gGJKoODbC+jipJJARMXEyS0PeGGR8vPGkaSchfwpvsskkMMVe3eu3HBRM7CGvg0nIOVq5txH68KfiV06Oi82TrRcexFt2Z8+ZfPUoe14hdnmTHpCWgAhyJaR59dBpPWyj06bclpEVTHkvVxGzQY9IU5zzcVrnjEduetr7lZ1FbgX8l2VLUHGblxnLwVuJt/nktH/PFHtblHZSKhAfOQIk1J6adE6DYb2nsKik915QXu+9rUsYi/ReOM1fQe9zQ+kYw0bwo4cZQ0bOMYXWUSXWCtLVwEuM4Liz3R38LWhs1Wks+7GqE3mVNdZPQ0HZVVD6YGPz127JUxQiMCnMPQAIGnix5Z5zAE77ntNltAsAznrgbBntEoDzHjhfRrsC4KIHLpbRPgFw2QOXy2iLAK564GoZ7VMA1zxwrYx2vdxzE8B1D9woA58BuOGBW2XgNoBbHvi8DHwB4HMP3CkDdwHc8cCXZeAegC898FUZWPh40bUAuP/bJeAUeu574AyAjz728in3nANw2vecLqNdAHDWA2fLaJcAnPfA+TLaFQAXPXCxjPYJgMseuFxGWwRw1QNXy2ifArjmgWtltJsArnvgehntMwA3PHCjjHYbwC0P3CqjfQHgcw98Xka7C+COB+6U0e4B+NIDX5bRFk5BCh74qox2/+NSz9c41bLLLhsu9svOdmnYLrY95LJF5ozY35LpQlCiyyHpgYb4fzLZFJfSSXoTUR2dW692zcv3nyb1qkymNbT9kkybmv3lz5N96n1yFQ8kDncvC/qP/5h9pvrL30/3mX0M/mYPNez7YQ+c2b5/ECX7EEGZGjmzOLbzat6jHUo11xYO/iaM4r4/hknEyoymAV5+8GD+4N/rMcGx5DH+ljx1mFTLETqfzisH6VStcdpwi7MyQxu5bQo4TJX++J4qxqga7YYSVpGJtwN7EZLU7E1JtJnHdtN5EStNBqiQc8kgdXVnyRDNVLMU29zTprbrX5EMFqotdRfA71HdtNQXqP8cHbKlbgP4F1S/S8BnAH5BwJmwpW4C+BMAuqU+BfBTAi4Q2iKAPydgUbXUJwAuE3Cbwq8rAP4peoKWugTgXwOgAS4AwJxxS52jug2a6nOKXXb9jNqu0Di3APxDYBD2jcBPd5Omuw7gFOYmtGsA/jHoJeAqgJ9gBkK7DOBj0Es9FwO/RlB1HsCHmIfQzgL4R5iH0E4DOIt5CO2jwJOINd7P6T314YWFhfmWPlfxDWfwhxrO5A2nzpiWPpVD5844/IW84dKHDv9emA//IeHfzaFTcUt/kQOXSD76dg59AuizHDr3uwTdLPpWt/SnOXDld92ci+0p3Jyf5A1n/Cqu5A0L9P2lAp2AC8U8IO9cuGw5Z8Jlyz/VnpzwF4rlLDj8ezkzL6H7bsFa8OqLHKKl3w6gxJwWpH2XkrYPJp2kwtqogw22PxlqFdoEn0rE4theyGXUabekVd5LsBJUh+nosN2zpsPWZsn43K9OTaM+Zxf+Wk9RrXtuenoG26tu6r28y3jD0UGJPqqWPqoWH1XbH2nahbwZaU9S9GLns7QT2Rwi/HFT61V12A2clzV2IKqXZDqMckGmTZQ/SFso+tIRo2fov/SJpropHe5lmY6i/EimO1D+MO1CMZSuMYJxu5vqlkTTFZk8jfKUTDahPJR8E0WcPINiJHnWDXhVpi+gPC3TF1EeSVeheDGNUaxNO3nUdbQLQcHTZpP5pnnGPMut65vqM0/XNZl+C+VZmb6E8lj6bRSvpDtRbEgtf0AH3Tv+g09kugvlGZm+jPL19BUUO9PdKLrT1fzBhqa6zatZlEkvynMy2YzyZPIcCpM8j+I7yRY36nWZ7kF5XqZ7Uc6m30Hx3XQMxaY04lGfbKovQUav2WyeM8+bLdz6VFN9wXPdkMlWlBdlsg3le8l2FN9P+lBsSfrdXJ/KtIHygkw7UL6d1lH8BmkbFb2pkx2FtHcx11azzWw3faafW01TfYXWjWa9lbNmnd04Nz2TfoMVYyZNEBrnqmJGjSEEwlrvsNaSml3Gx98ww1S9hOpas4OqH7nWJlUXUO0ywP0hN7ao9gOqreG2IW4boVofMhDUlq5BWF4zL5gezLUBc5numbSLcK460W9ARw93PDGTCuq44mZ5kaqnJQ+0iaqnUF1l8OURbvwm1Q4xZky1F7ntWaqNIOlBeCm5obXc+sxMSvE+qZ75lnkS0z3F062bSfHlNQy8i9qo40nuWO/o+AQdsXmJqmcdHS9T9Yxr/TZVj3HjK1R7ndt2Uu0VbttNtZ3cZqm2gdtWU62banuMwVQbeaqemRTt153ibESH4Y4NjoZFdKw2e6l63tGwmarnXOt3qDrLjc9R7SS3jVHtu9y2hWqkpybimTdx2/NUM6w3T5puTPaEk35E7TcwaMM8RW3U0e06QMOnkkfZRtWLjoYOql5wrdup+h431qn2Nrf1Ue373Fal2m9wWz/VtnCbplqvDUgLhxCl6J0U59TsOQ3D6qyowYmx03TMmlUU43RQPDg3ndRJ+NTSaQhAvOSgcr/7Av1R0klD3i+G1LYbKSuOXjh5nFFEVUdgp/kkSHb1ttjjL3eSwjYnq9jC02AuJKJpkTBfZ68tLIr4McRDZo2zjCaBNSVr3GlVUrfS2WF4i84s9XGTqdvwuP3BMW5ruOsY18M3H53W7ElFD1PXgUzF/MyUSdj6R4jQGu5ex3QgL4K+Nb6vN/Pj07BVgoDUsCMO2cHA99hbEOtt8UgveqQty5BuCmDdFB7ttvB4rqWEeIsRb+WId3LEW8sR7zHivRzxlPSI95Yj3mfE+zni6Rzx/nLECxKIF6RHvJIjupYS4kVGvJgjXs0RLy5FFLiGgcsnP1tHQRoFsUP6nGdK1rAeoJawKrA5pT/2NonncRYcjhAzOL/UXG+d8zkzKd8wPE6jpo/bbg6lpRnIEmkCk2Zpah4bQz46RAKoYm/k6uVidPt5DlNcYk8XAEcn9ryHSS3X2cs5IP05wt1JcqJx3of7FNfkRwB3JgihXbod/4f4LlwW/w/6+P9CBWefx7ocpffblGJ4+6WH/3dFVuaNiF/kjNVQqpDkdGcRgocbwqcUGdy+m699dMZJeB3vSEWeCdyLi0CfB9R7G6p01YEUkIw3dUX8Ke0md3mpV04RBkWKMNzLt4UBBBLwncgKKUJdpAiJQhlv5UtHnq+e34HkB0bjco4litup/6WpMuWuLdx9QJdPNYqHUo0lNH/6LNixh1P19PkQ2S+FtNnOYsHSLVgWC5ZLc6J+wY/IibYXjIXGz5qSCEpcRyKwuANp35BEfORmZhzEZTiO+u6i52vu2MVDd+xifw9ubEqXN0W2k078S/MKgRWqfdVE8oR4jJjMslThiJ/fXsiHbi9kfnshV7q98Lcgjovr62iO65OQqp8rX+oBauJEwzwxAFrIV/6S2dfpMo9X/RfzPkdKs8T19V0RY+s5vhH1CVC57JZaz6YaZ31ZToCSonMCVEa4+HW5Tl26vEQB0xP9KpS1efs8nSiuu52WhlZOpRqX/rzRAnvxp5Ad9v5UT1KFw3Kbk+azrDG0vU017maNy/MIxXaqmWA/tpGtTfkbvhqyKUESInm7lc5SMIt6Tw8n8CdXKak4ue2GDJBoF2RigkRDZzmDoFFkqIVRboaF3cDmtsZWNOE7ge6kg2WpwVbcvpsOEj7OIKsl6QxsXxfsH8bD+vewTPxaeADOFVFlA5nn1Vq41g2J60z4HYWa1/ykwIT0+fqCHOyf9kRMQmTCMbcKJrGDaO7jPUtMINdBFnyHMAUBYgmvvCHxHKvgYqLNMSHV/x2TKkuYVF3GJCTkK1/DJLEia4RjjXJjarwK8VeIYAwMFHyYxu4rjw31hEMIaUNA7J2OBx2czCPt111RaTpHv5sx+rOKrLq3Etj7OJ9/vRUJllgRDVr299AeJ1bHRET5SYb0TzI0OyYamsxoSI5JLb3Opnb2SCHe1xCBuSlBfKjalpgMcLwVbzVwL2VCpAP0GMFUTJLUKmwOiC8xu3WaCxdk3Mh7kCjDgxeqTdoPF2pjPUioOVOm2JvhlcNGfy1OVk9AjHhFQzPB1oWTZBhPsqsIoJbxqLtBhLeYzQgSYDbuy/NLc38h51Lb5QtjCFDnN+dL7tQlJ1bdVTIuGoklk6nKX4xgMs0vdFBupF7l/1bqQVT4sxCXgPpAga48unLD5UneU/nDA+cXy96mPcpYAyHDEjL8U6mNpJsK+4X3CiYCJ/mZS5u6Z2kbVhBOfECuLvB3WmHuqN2cCsY5t3Iqf6PiXqbU3GlW/dp7xbD9WOmhRfu/FUdoNd5IRYO9OsvYPYKS0Bj7tn0P2tgDkQeQUNxFuqto62IXUc96+vNC9L8CGc0/TYaHuELBmbKbMnv2w0X3TsHqHaLXKJd3C1q65l7YGOoROJByEXvVh7tJO3dzQONekQW7XQgGuwXbl+BxG7mttzNaLeLZahKyoXHZrMBd2hmOMZ1Z2ORtSeDukZmnfNAigCxGo8hq+YAzhM0KEQ6XwhkYFsILZ9PIHspsiKmTqiesNL9YMr8oz68jVrlO2IwN2VYhmC+0miDbrMQu8zPwJrDd3pjyYmsW7x/80wmYBscVZ6AV0VfBK6BaltahVV0IV+tsy2gEDsIxZwV5CGZ6TAPwsa80YH3pgMIPWCkGrPgB2bB2RG6kDe7KHvkEWI3i7OG5Sx+DtSrnKTTSEFf3+pwh7msRV48558Acky4rSSPxxQI/PWGkEhN342GIW5btw0FARP8+kME8x7SFJX1EiC8ReciVQnz1UIifR8C0aRHiy/+fIT4MOQc8PBuHO0Vw7zb+3yC4l3+z4F4+OrjH4CvE1ggWOd6Hqy3F+8qtXhWrX2ar/OofYauWxPtyabwv2yJAqCpzdhNeJ8Oany2uFLqSMTdqNlVfF7qqPHRVy0JXkBr9TyX1fOGKpe1Adt49FaJ4nY8/IhZJwG8u3ckgctePRv+aUEEvCRUUaEKoMIl3vZDHI55wprp4pvZeRjZawRSp+EXsYQ5zwHt+w0nREYvEBNhCcnKKkIrzCr/PVNbdVIpJhCkZHCxRwu9g8j0SkH8Q7EkFe1Lpn98Id4YLDB8xMhadjLfDOuOA7XmkswNoYZ8juORx2NFyVXvHA0HGL2CE9dF/Ac/dc2n4D+ENFC3ugTiQPxPTuc3ew7pAko5Xc0/CkT2CUX6QAS3XFGztEMLpJ4Ss5qFGMZ9TEdKwAlP121N2QU7vEF0MrZmzdwHx2x1bmSu6V3FDvd3QsAE76tIQBc7tFYdoWO1k0/6ikeMs8hdW4ZmkXyTbkpQDMj1p5Jhbs8GaAzZrUbFS7EDq6FoPF00NzqTwHbEV0Z8rFbrH6OSdA2p6lk/tYteZ/yC+zzqDtKKGJ+jIcPbXpGU0FyMspFUUH7z/CzrAPXhAFvKOOJ5WrJy19+j8lm0X4gUB3n+Q4Z0y9R6bdHjLMBQNiih+14N/85+3/11TTdxT4OovaLq/EPvYxn2Q4e6bqMi4nUYtZtSPHNm9zO783vIR9DI8pwrzZYS+jFQEpt2v3D5r1GSMEwk8S3RRyZD8yi7RIrcp+FW9bL+ql+1X9SRC5DrmXqONxK/qVfGqXrGjLB5OI/IvHmTvLF5Yh85oVWC0tIt0VPwMv2cbMhL+SScK9gYfgM9syfjgzW+28LULgsjLFm/t4cHZWCg8PSQEvNJ3A4L1eNmU+qwMG+uaM9Z5jsaw9e/uwnNn4e20hsMPcmR8Gv1LxyWczgLnegHUeY+SKYRJW5u5V6S6x7kHpFUSZ0hBA5/t6inbYJm7CxgZWgOFAS66YuoREqbarYo3cpzlQSH2YoPjXuGTZhQ3ipycDYUEUtXrXp5SHGTXYisRhXt6MufvpbPvKocEczyXE3ktjMVei0OCAOygpjhV+au/2H3c3f4VBAeOoLx4i4vu6IRszPNDzHw55bdiNLzLGlVo7Qrcq8Fr4XStXcgZ5IEpqR/FiiGi0ZBZwONQw4ON/Me/wo3+QKmgMAMwsjiaQmQ8LE3/2Yf+kThz32shlJ/UI+9MQ+c24ek5pkTqX5MY8PpUY5ebjCO+HDaaawjy18JQdWdxiJ+h1DI2UD7fS3q3WddazmwZXI0ELpRgKdA63YvnYLOLi/Hk1XUhtMJZtEJT2LoN4udTCVaiOsJbmwbubvEoNGWRdrbXf4LDSWB8nOdiyeh3vBsyzlDKJLR3If+7fp9W2MUg7feJT6xJ0nN7LQcyZh53Wvf051p+vuOtUbHiOyQUtX+6gUVrGAfec932TpF6m/8uqd0dDllwulH8VgpUfJmHOMG8/eK3/ONHDrZdTkhgzACM1C7Mx3rXUnM+OOlHRBbQZdgluelIRG2nq1wUxDFRTUR/KimqJppdsEGxcRKw/bSaXad9b4qVEw4oVex20pCPNI5u1sgCVoBVG9aAdQ5X+PCUameXAt4vRTI+c/vfqNLgnMjg1FPA0Q9/J6IfS86auQS2ZWxpq1leqxS1sKgFRU0XNVXUpK9B3UkynzrL+StJ8zgt0Zs4SsGExIMxPiG6YyB9PMtpkgA/3KKP+Si/v4d9r+R7nu6MlQROHXuJfxaAIxr2n+YzceR+SUOijL/Bysv6ZvJfkeAwhGbEbiPOPm9WQYsfaxjSZzIiC0iyrrZnqYjXiehPPOllE8AGJdjt4mX+LdeyTb/ClpfLtrwsb3m50pbXy7a8bm/58m7Upd34MYjNf7jEP2uTOITQHoLTVT43oPjshpCzQTpRDyKXJKQKJ6UC93sy4pw7zMv4+YitNUf7nDl2vzNBGot2vIxyKhB/uSPIWc+1mAWeG3Z2d/M+Kc1CR8pUcy4FB1x7mdjHka7L7woEc7h0RvgWJYHz3ygC977myk8WeScTt+NVwuuAR0P/+aX9x6T0ogRRrEQl5A2Zdb/HsVsyPIej2i4FsWr7xU8XXeIe7ZuQeiMRwpNpLLyXG4IMCRonNHKQ0ffdZCY3FU4vAhbvUm2IV9QGaD9+MIKHhSHrDAn4e8RW409tLubVRcxL5xOFmFfzIcTHvGAKh8b8WNMFvP4oQoHufhhZDvMXrvNj/iLZXiR4kSBuyPUR/76Nz5aMgUPR0iTw3mK9zFySuPWMvcjci4mdssUMl7DizHfmIDHE6zf49gqWyH5beZ9qa203VWu7KXeu8op3a4l3ikaJGDB5bif/SsgKH53xUt9DuDm/J5U97izPMpg1AEX0enGsku5YVXehBlTTPm/vf+jMgj9flXpO/8T3uIDMHZgLCtuJGahjvFZEz3GoZd/b33DX2Dbg+1lh3x+j0IvW69JnXRGbKXcERdI+5GsJySEyXyGqiMZyQVuAUFpOWzXteZHBS7jTIacuRDTkxLSSB6MhnaiCogZN2iCip0R7y+72WSJPH6Y3NKa7hwrw40ORJ3XYLQEhcckT/WiyngYtqkjzlnMthO6Eug5C5dgR2SsZ5mYlitbgXnIdLm7m8GO1jXt6osfFimPE7u0fvDKf9qNIsG/hdXTxC1v8/JAqk9NRzaWGqtE3VlAxLHOrCKyi49Vc1Om+MYJH5A49tTuqlxYeecYxn7JolYPW6511QTLGV8PQkbiDBnPCfHuqqMq8Wl9SnYvWuRlu5788xRbuoQhGRFW3VF9OcklyceXbGZGz66u/+mf/9X/84T//q78WROmuX/2TP/r5uX97+i/eRde5P/iPf/mf/vuNL//+fKSF5H/RvwulcL8DXwxnpg9vP3b01W3TMzSXiEUn/f0W/esRbfilZfB++reuBJ+lf6YEP7Gsv9v/y+HN9K+jBD/n4UC4/5Sv45/2ZUj/Do8fOzZxxBw6eHL26InjO3a8efyt6fGTvZsPmRPHzbg59L0TxycOmbnxY29OCOnHorVNj0+/s31m9sh2rPXk+PGjhyePHn+NVnyb5t+AOWjCjVTmcEDwU1RWmEI3f5X+1TxtNd8GmkF/RP/qvq2+DKdRooF/Us9UTI+/9aO5icNEw+Hxk+OHj86+Y07MTUz/+NiJtwj/iHR0bFdu3bFfC9YElTh6/MjE2+bEm7PmxI/NqyfePH5kZoeZfX3CHJs4bo7OGPMqdQF2iNQixFUZs4xuUbmGyr6+vv6+gb7BvqG+4b5mX6tvpG+0v6+/v3+gf7B/qH+4v9nf6h/pHx3oG+gfGBgYHBgaGB5oDrQGRgZGB/sG+wcHBgcHhwaHB5uDrcGRwdGhvqH+oYGhwaGhoeGh5lBraGRodLhvuH94YHhweGh4eLg53BoeGR5t9jX7mwPNweZQc7jZbLaaI83RVl+rvzXQGmwNtYZbzVarNdIaHekb6R8ZGBkcGRoZHmmOtEZGRkZHicRRmn6Uhh6lz0apSbzbcXL6xJE3D09Mz6jasfHjr705/tqEDP7OmzOzok5dhydmZiaObH31HR1OU9vhJ/u3DTe39Zve4dGJiSMj4wPjBmvc2t+/ta9/c+Wt8WOEVunb1j+6ra/+1vjMG1v9//uDNX3bBraN9JneocPj46Mj/c3hzf8H8EyJNg==');
+export const wasmBytes = generateWasmBytes('eJztvXuQHMeZJ5aZVd1d3dU9XfMAMMCAYlZxRA0kPLrn0T3D59SAAxACXytxV1yttI2emR5iegbz6G6AgI7iDFdcidqjtFiv1iH7aAfD1t3KNmnTsXKcfCfbCJsRy4jl2gyb3mCE+QfD1h+MO9qGY3VhOkwfzN/3ZVXXPACSkvb27uxAYCo7KysfX+b3zC+/FPX2BSmEkH8hB88JcU7IzXNyU5yTm3LznNrcFPgjN89Zm0jjr9w8Z2/SL3rIzXOpTf7NT7l5Lr1pckxCbp7LbEZ5zubupNw8l93s5nfTcvNcbjPxxt1M/spvbvupNp8R59Q3vym/aV1+yrL7Bwb6e/p7e3v2pbI9qqfg5fNZ207ZtrPfHnBy/cVUqpjPD9g9eSeXy+fzubTj2Eql033pTDGdsex0KuX29nrFbE9e2VIpW6UO2Om0bWeydiptZ4RSGSudsm3bVsq2rZQQyk7JddnXl7Kk6E1n5aYMt7auCTfz76j+9IXGhbXWFSXy6/VWfWWlsfJgvX1eWNnOxfWVBqXtQuPy+lqrc3JttdO43BEj2fP19vmZK51GW5Sz55cXFjn9mez5C/V5TgeFpQvJb77grs8tLyyO8ls/s7B2snVlvSPOuPW59lpr7tTSan1FPJRpb1xsNL7REA/n5tvn68uNM6tLHXHaWb5Qn6fkg3kkqfTSNxrii8Va7am5pdWFJxurtcVWoyEu9CVyWo36ysravFjsTWRe4LzW7Ym8+sJCrbNWa3fq88u19bWl1U6jJZ7MHpAilG7v+uP3Ds/VZn53+Tdmj1Q2fuerd+T+7p+rp/5yWGU35TN3iNBbDuSwUNP8T4vwn8kzBUGpN278nYcLIhS+yNuuFqHbDFR4ecO3QjGiXr86HdizBRXe7qfw1Y0bmUcLUlvhnVqFzqUnA7F8KZQbWoSysxze1/RTeelGzeAfKnnt6nSQDj2qO/zAQmn3zFBghdalQF0KRGhtzBaEFuFmG3WpZpAJrU5ghT/7/jXRDIS2wteQGlEiUCPKCZxTBakdLSoqr4V2KsrJK1dTh9+4Ov1wIa1FQUXdF7P4fT5UnfDl75lanBFlh5c3tLXsK+34aQtAcUaUF4iCQGKAEukRNeinDMzSIyofCJ0+jRfhQOghZyAQQ1xjQRIIlYtsJ9jeO+nik6ZOh15TiyE/Y03rjJ/VTiCQLSgbtTlDfkYLqt2JKhauzoaiooSrU7OFNHcGrRyOx4QBiVM0oakR5en0qYLQKXRhAKDIZ13+5WlRkHnHZeAJDdi4WuhURQ3yEL3AoblAvz0uNujySB0CJF4MmBd5fByDMnC00o5WZzAKpYVOa2fIT2vhO3nhuiHA9ozOPLVRwYpwsYCavtBKW6HVqigbqWZgaQs1SuTkUy4vV606odKqE4hQaLHc2jgfZLFElFkijlbdJSIA/wwgkEFn89rRGZ4E04Vs3AURty3QdhpdWA4cbh3NNlGD4GX8xtXpQM0CVUbUW1enfSu5vjOh1Cq0LnWC7AaK6IzOtqgVDWwy/VR+BmsBOVHf/Uyi775ydUZbFQVQWui7hb7joSrKAXoCZhhHBX0IaTreuDqdt91QmBUfPkOLA4N8/eq0wUatQu8MVm484jRGDMDxYB0MVlWUiIaamcWS5qGK5FCzodQZGmqKh5rVKTPUTHeoGT+LoSInyJihZnWmO9SMq7NYRY7ORLiS5UcmQhleESLuMBED0V0eNKNOBAlFkACmOU1gvcwrvAeOx4QvtY0MpInuOYS4qMt3QETCgzoVykvLOhXefmqo42fwnihEOkjzag+y2gI0s1guWa0eBWR9R2d9pbNUqdJpn/LylkGfLAao0zoT3nYptDeawLX06SHkDvlCZ0LZ8TM6XRCuS0veaZneKZ2KF762NzA0awdB84ULXJauNR0hZJBG3zJa6QxQMjvkpzDlZ0BftIM2s4oKewADUR8/7WqlUzoD9HV0FgmHx4FeaVCGLioIBgEQ3Vp+tCBd0M0uuXSYXDoglw4TtAS5THfJpQN0TUdLIG3m3gFddHaQy/ROcpnukst0VHGXXGK+qcvcIbSUIJnp7STT2UEy7W0kE5MYk0yZIJnENNJMMtMonTYkk0abni1IfjFgXtA66yKqswNRIzR1YjR1CAViNBURmlLZLXWGWlbebXnlbue3ET3KMJKmdSamRx/ILkFKM0H6QHYpUnoHRUozmqrEHOGhoqnajqbOTjTFZAIjC7slA7OOHo3W1Zv8+2xBaBnazfD61Wsg0f/Jlt0Mt7ae3vCm1LSWI8o2qCvAy+SIyvsphuo7V+m9E2SbgajgJ/AGb94F9Uavzw5V8KPbibcjuv7y968RpmPAxHppEEGmeaqgNHEVURB5y40B/R6LFwCuAAYwXX7v6jTm+MbtFfU+tZ+qqJ9+H4lsRb1KiXRFvYwEAbyiXqf0a5R+M5H/GqXfoPTbiTJvUPotSr+bKPMWpd+h9HuJMu9Q+ueUvp4o83NKv0/pDxJl3qf0Lyi99YfdMr+g/A8p/fwfdst8mMh/jvKf+0OkrybSL1D6BUr/MJH+AaV/QOmXEukXKf0ipX+cSP+I0j+i9KuJ9MuUfpnSP02kf0Lpn1D6WiL9M0r/jNKvU/o1Sr+ZyH+N0m9Q+u1EmTco/Ral302UeYvS71D6vUSZdyj9c0pfT5T5OcOW0h8kyrzPsKX01tVumV9Q/oeUfv5qt8yHDOerBPOr3TLPUfoFyv/h1W6ZFyj9A0q/lCjzA0q/SOkfJ8q8SOkfUfrVRJkfUfplSv80UeZlSv+E0tcSZX5C6JAOi00wKBFebgaqAvyLS/+MS2ixrLPhE81ANEE6nAooA6RLQwUhCoQjFWV/hPVbW1uyon5+dRq0QOn0o0Q+1VlmanlIcxvEg+QlnTpVsAz9JdxFRTp9ZoiQVqd1tuk7hgwwfWB+KoKMJp0jJgLKdfesXbrx5yQxGDpCvQFPBUFpBs6ZgqLvHV/EonECKBkDlF2AsBkQGW3fChAEqHOoDpURfwi95pmhIBMOUdEjoD5+TmdCr4lfP/n+dFVBBAGZ1rkqhMBbUC3wgZ98H9Le4SbRSxFmiDWGNr53zoAHZ7Q6VcjojM6AETyzwQJ+RqvlQJjOk9zHSigzMqWFd1ve2cbInFBqQYwszYzM0WnDyESCkUHJAF8mRiYMI3O06DIyQZwKjIxYVx5Q4ge4Wz7jMmcDuDBLOtskAPO8AJBdnpzpZirDoJd9SyvDTU4VTPotpG1wamJZobURytM0zjRJaN5BksLxqU43odvaLIOlDcDA/GSsn0AjALismO9b3m359DZwqRDaeQJcKgaXlQCX5SuAi7IiRdtXCUXbt1wDGW1FfF/xA+pJPoVFC8kPGPwmYbBhpaRJObSCtZOQW2JNi1SYfNqNlAtldDzSvIpn0WvIb29xpSTUoyuxkngzLeAN1gJIkaPPoYCa5vEVqXJddTNSQPMpzHyG5zsxDIOQiqY+Qkih1bIRVHS2GSjCyjRjpaPTCSFtN3myXEadtyNo4SNovPx5PNc8oC4ESEwyBd9IFuwuChaxIi0mqjxQ2nrYLIJfeqJcLUOvOSy0cP/ykHxo8w4RXkVlw6AqPRV1XquZ/2Jra2srU1XntJr5N7a2tnRVPaHVzJ8gW1bVY1rNvLK1tXVdVtWDWs386dbW1tVUFXgx8x8hbVXVpFYz3/ro05dkVZW0mvmHW1tb11RVjWg18/LW1ta7oqq0VjN/D/l2VQ1qNfNfIm1VlafVDJryqpBSZ767tbX1qqqq16RWM9/HC6uqfiZNA2/KqvoJfjyP1lRVvYwf/ya6p6rqR/jxe6hXVtWL+PEddFBV1Q+kGRF68gJ+PIt2ZFU9hx9/iHZUVT2t1cwP0Iyoqo7pF8a0olVoV9Qb0uQ9/+xLW1ubVfW8ZTKu4s9mVW1FGc9f1VX1gTK/fniVy1+PMn78LJd/L8p46VldVe9Gv573qurtuCxm5M3o10/x6/W44j/SVXUtftdbVT+Nfrz6R9zmq90muM0fRxlXzSheijK2eqvqh3Hx3qq6GreD7j2/czhbasfwP5Bx47qqrsfg2uLy70UZP8brd6NfLwFWb8vuOnhTEnaHNhHX8H1BSZvIBiyZoHAk5b8moUGOqGvSJ+78ovSzeP5Q+jk8Oz4x6nU/j8dDfgGPB/0ePIb9Ih7a9/D4mfR78fyp9Pvw/IH0+/G8Kv0BPFf8fXic9/fj8YB/AI9pfxCPw/5BPAb9Q3j8RPpDeL4q/cN4viD92/B8XvqfwXPBvx2Pc77G4x7fx2PSD1iJv4OUen8Yj5el/1k8fyz9O/F8Tvqfw3NL+iN4fs0/gscT/ufxGPe/gEfJP0qyjX+MRZTjePxI+ifwfEn6JTyf9st4XPZH8XjcH8PjMX8cj6P+BB4jfiUUftWa1lIXdLGt3bbOtnWmHUxW1LCWukd7bZ1v61xbO+1gqqK0lvqAPtjW+9q6v61728FdFXVYSz2oD7X1/rYeaOu+dnB3RQ1qqX19R1vf3ta3tfVQO7gHOrbUgR5ua93Wn2nrw+3gXmjkUn9BH2vrI239ubb+bDu4D2xM6qP6eFt/vq1H2vrOdnA/OJ3UEzrV1mNtXW7rE+1gGmRQ6opOt/V4W4+2dakdhCwjTOr7QtnR94e3X2q1gxl9ol0B9RB6St+PF/fxi5O61K6ol/BiRpfbFfW0FvqkHm1X1GXKG2tX1OOUN96uqMcob6JdUUcpr9KuqBEt9D2o8l5UqafbwQP6sx+VeBmV3osX99CLsB3M6js/+uDHePGA/ly7Atok9KweaVfUFuceaVfU1yjz8+2KeoLyvtCuqHHKO9quqBLlHWszt5/Vx9ssNN2Fpu6mpu5DH4Y++uonqPRuvLiLXtyPPhz+qJJXubXbPqrlBe7DZz6q5nnOvb1dUQuUqdsVdY7y/HZF3UN5QbuiJinvjjZZY/SsHm6TJUZPoalJaupe9OFQG2tB6EnkT1H+PejCwTYWDuoYbFdIupjVB9oV9QDl7W+DbyFvX7uiVihv4KMWrnJP+z9q9gfc076P+vJTzu39qIM/kyQq3KWn0V7IU/zAR/W+JsmqdrcO8WKaX8x+1PQ1SWY/lHkRyZxG7g+RdCm3o4XOU+a6FrpAeQ9poXso70EtdJHyhrXQHuVpndIzbf8endYn2/69ofBPast/QNv+LCss+mQzmAnzJMZN6pnQodQUiVkPQCGZCTXkxyk9A2AKPbMc3H2p5d+lJ5EzpfFjEvLhVPSS3nCJuy/Rhy1/Ega2Wa5vkEw2eia0Os1gRt8FxJnRk0CVB0B5H9CzeMzqkxCYTobX5KkCJFCIXvew2HEvCs+EB8MPv31NePdD8OBXkl8JGHYmg0k8SsFUuNneQHIkuKsdVBhL5Ii6J7gbj/HgvqjA0eD+djDBCCVH1LmALERPBGFU4LEg1Q7GgXtkSZrBY8A/SXYk/wE8PH+WP14I0nh8LXCjjx8P8u1gjFFYgkQHGTy3ZJCLilwOCu1glPFdgpoHDp7PySAbFXk66GkHZSYOEoQ/KOL5Yxl4UZGXZHCqHZQMLZHgEkEvni/L4HRU6EcyeLAdnDCUiPo+GBzSs3jfDo4zHtMADwcH9UnOPsaIPoXs6WBQT3L2UaYE9yH7geCAvpuzv8CkIkT2+WC/nubszzMtIWvcSrBPpzn7CBObHLKvymBAZzh/xNCjLF78QAb92uEXnzM0y8OLn8qgTxf5xZ2Grp3Gi5/J4Izu5RefNZRwFi908EV9iPOHmWLQPA4HZ/VBzr6DSQotoweDh/QgZwdMc2jxPBRM6gOc7TNRoiWzHtyt93O2ZqpFi6ETTOt9nH07kzVaAT+UQVoPcP5nDOWjeX9RBg/rfn5xm6GONNvXsDD6+MVhQ0Fphl/DojrDL4YMzT2jH4xg2WvoUp8+FUGxz1Ctft2jH+asfkPTBnQhmpwBQ/H26Xw0jfuYHO7XqWi+9zOpPKDvjxbGASajg/ou/RDnDDKJPajv0Wc55yCT30P6Xv1FzjnEhDqjT+sHOScTk01Pn+IsxxDMh3VW93BW1hDOtM7pAmflDAGd1q7Oc5bLhPRuHeoU5+SZoE7q+/T9nFNgwvqQntJ3cU4PE9izekbfwzlFJrRf1A/oeznHA8E9qWcIxVT4pmzqKlO5EWW39T1tP6Vn9QP0emZEiba+t+2ndTWUzaAaDp6GLYSMKXZkX1DhVas5LNx/tE/moE69K5aD3DAhSUUNx1tVabYa8U5JWuegCk/qXCThlrB3kgtLTZ0Lh5s6pdPeUZ2DHAiLvteBMuotBS52IHLaBdbm0H4JVcNmjf2N9Ic5IVx+MZmwzsNqgMZz0PknUe24n9LKz9PuPt4uBwVkl3R6+WEUREfSuhDKUHrHTD9c+jBI6XTTx2eFULHTQSHcbAYF6kQodOFDV1BndKEZoCh02AJe3SXQhTRAmUc/8qF40Owi6HxBhNLvobGG0i/oPI23B+PNh9aGXwTYUDC0wezy4dMbvoc8a1r3wNxCbTaDwjEhwkobbbmhbGIMzWSWQpbalmUhy0pkeToN21eaZrtoNuLQCDXRLYeh+GmQtGZQpD21nM4DnXK6BzhEv+7Reeq4PUuAvaep87D3RYB9AIsBmkMPl6BmLG3HU6ltMhU8YKaSAeXxomAgebcEkm2A5O0E0jtiN5TivASY4rwEnEyevRegvCSgTMGbQOoxndMe8Jh+Pahz4VvCYECPthgDnqDFlgObTjMIXxeAYboLw8f8PB4P+R7jgqfzTe120SGPMacr6jGu6HGzWDFq7xzQzjGNekA7LvRgYKpKUyHOvIcze7ZlljgzZTKRlfctPDzfZjIwYOYlixUPlMnC3hfhPm1pgZkTUdBdojCYTzE6pHWWZjrFAOBSoEKHdQ4G3UHUH0ofqJYKpd9rjN1EKUa46DDThaPx/mkqRvbhJtvWIniO+DSKo7yb6MwSktJ+MaDahCOGAS205pQpy6uVDWhHieKlTpvqvUkqO4KPexNkQEKOowb7qFM2EUkGFn5G4LI5czfVDKXfn7cjINkEpP6C1Lbfj43Ofu8rtAD6mV7aTC8Jz/qTSGajVpuHa4jwAGFhTITdf9npqPo4Oqr+NaGjMkFH5Z50VMZ0tE/LLh2VTQhSD9yCilo3B5H8G6Si8m+Iig6Aivb9i6Oicau/LjL6ONMW7nvW9D68IVfOxm4gNOmD+KODgsG5wSYJxfHAtF8g/yNy9eDRj6jDurBtdAWa0YrS3JvhoGgKjugid6gX0O/VBZ19tJBhZZfJdpeQR9+m42/jEaaMkBYPbhCl0twVKgX3u4LOg2SFkp/Yw9z227yHDnZEDRLrAP13mA3o7a1wb5y4N/kYtOG7ojksiqg3jfpeKKoCe5a+pJYDl11LdYopETQsl4Z4TVRVnjcl8tsItCHRkgBNhCdviHGB3M5QHBlwyrFDuQFhJ/znN27cyDC2gQDlrWkMrxn0akU41ySfYZ0P1SVtnRkK+kaU0F7obQT94WD7EhACU6Ipvz/0OMsLvabv6SK+awZ5wqkemnlUvnfVqACaR2h1UIcZn3a6o3NodMXu6BwaXbE7OodHhw1kf0A70eh6EqMr8ujSu7qQoS4EfXuObtfQepJDG6Choeab1rttaNoFsxsB2kIkcCEZHNUucMYFoz6kXfCs1CveYe3Cku2paSR00Ee+kn6/mtYe9j/zoXikACfj8HWpZag6wcCyv097ug/6lL9fe36vFcusZgV5VfVgKMmtWPfCjNXvdYjSknQDIJsFTh17CBohKFqeKUO/zjO6ukz23PBt0QxF+Lr80BX8S0vtgrSFt3tD2gWX4OyBUOh9nsZQ7vH78Zj2Dxga4sIiksejhD+TQQ8REgIEgM2EBG+IsaI3/Fcgc1z3NPWBbs96dL5ZUZNqWvejxAHdH5EPB7O//xQtmX7dG9rk4kbc24bfgHZ13yvB4Mzkt/XgzNY/t741M/Lt55Dc+jDzrZlByt3a+qD4rRnn2889FwxWrQep/5S9dV1+a0b8RkGEtt/L8gjnb717v3mRohdhJkxHr7au3RDfemzI73UxDWo6dHTvcsCz0cekpA8mH4L3E5goZPYxiHVva9t8nGNQe5OAytewIvPE8bAvQRB/AlJemKUmEtDu7UK7dzu0e10CsN8Ttdyje5vJVdBLGMXQ7mOpro+h7ZIja27oV4Dn7TE8+24Gz76bwLMPZMLRfREs+6GhuRBDd8Ky38Cy75PDMsWw7CdY9sWwvBkYx/2eCJrbwJjaDsbEkk1FS5bAP0m90e4RVapaT5juSoNW2dC6FP7iuWsizOuidrzP4ouHfOrSY36vdrFLis97u0PXbpg3aOntZ/QbDHpZSugNrU5oM+8ifaNIlJZ+k9heIL5yjnr2IGS2lM5rj9hnKmaTMRXJa8c8bXr26xSealpn2M8vAwdS0541W2B/gjgrog75wMNjIMifZZz3SHTUXqg6GCQy+oxUafue7gNye+dQhJKgxp2g3/s6qerg3nk2IHkwILnhS4q5sc0Ky3/Xo4rMja8J4sbk58wsN3J8pnmCsFoIBTGZonGIJpE1FX5448YNyZJ9Udt+L0pZ07qX9xGIGPHT5if+FJvNZtMv6l7wmV7t6R6IpT3EZwrUcVv3hKrTBGGPPqHyNglGpL4UyAEKGqgudvlnkfhnX8Q/e0mmIhoj48LEP4vgn/26GPFPaM4YIlrs073NoEcr3dvlc71dFh54ugD+uS/inz3gn57eF/HPAsZV0AP4qBn00rj6aVyo+ab1kmU6IRr0xqJBKP39ZqGaYbFYsL87rKRY0N8VC5LD2s/DSu9qPvOrDgs137TencMqsKx3uMupB3TePxBKH9aFAW1jAdFk9qBXvXqgGXi69wzhbfFhtDeAVPiD378mvNtBWIgWvTJjfds7bNYtIUsp2MeUv79LssAPY5JV8rmA309yguGz/kH84pQmenFQ90NAGmjqXiJlppF+AJRaGtAF08QAdIJEE4P+IMs3pBr0EriozkHoBQdNhRCTUFmhog6rab2PG93HFAjY4fdozx/QB8Knm8EBkoZATZno9OoiESNgIK0XSbDcsWaK3TWjGI1vJkruw3pBEVozxWZQ0NAF47ktJtZMD5B4I/CiNQPNFBv00ZoxKL4PHzWDYkKURM03rZd2b269ZtBBoHqSBR8CCz7UZcGHuiz40DYWfKhqaZrCQztZcA+z4EO7WHBPxIIPbWfBPcyCe5aD3q6R1AXFHWd2BEuQ4TpuqJu6p9WddIgUtC6ZAY8Dj3oNAx7nxVmCZBxmqYFPutTcqElaZPu2LzJeYh5e72OOZhjwYeoMGPBA1SpxZyVjDbHfH/3+NRFm9X5mv0fUsH+I+S6+OgT/KxcGThpOtPRQOuKrRZbpitFvkvaLRrod7ubvwTKJDYG93YKFohaHYeQFRcNA8ySKghx1sIiQ0WPYhO336h5diBgoJbEGO4HnfR1yggUGWmQG2ssM9BqrsySCMP/vpaetPTz/74LMY2vmVYu2ZkLhbzu6gFNCEaOyiKrnI0YFn0qsnjxTdBojY6cF7CxoK6noQZWF7SavXdBGTFKMRS6wSDF2gv15G0FfhJ0esLNX90XYWQR2FnUPWZACN8GAUfNN69XuduzE2SlDdvrNNpEZVDqSSpUZVJoHleZBpfcaVD8Pyt7VeOpXHRRqvmm9OwdVJHN9rPTBU9v3QosIpWyG8lJQoG67bAIHuyoIPRB+s+nncHCSjP1F8IcMHjAesBuuqz2AAMKM7mOxiBfXIByA3VC2gn26cKrgkPCk+1iCQpF9+FKRbKX7WMDiL1XTB5t4BmxiANxiwECBrEcDeDEArumEP3/OOEnkaEtShFeB1Lbu12lvALabt+CGlhtRb2P6csDu62Yj8H08+yrwpcuF70iY4N6TTW8/LQBKR8Bie9DPZWzaeleyJKeLmkxKvA+Cr+9EBgydxTPmBFtuRL0jsf9ovsTwYjqQG1FvmpdQDo2R6T3Z1FbE/jXtiLwHF4DciLouA9dY61CK0Cwy171nhnpd+i5OalkFhZ/vS38ffrraMhaCNFv3+RXTnn1YSnltGaseVS5AO4qvxDKIC0dZl1syfelN9AVKS9yX65IJlWv2QKLxvy/BEvZpN94HQUnYEXuBJi6mJyYvRTq1pl1dXJ8t8NFD0/zpqFWzQfK+1G4zMvDDpMw10ads7yXw9JhBD+yxEgb+dldCD7IHIKPy7z6eFdbKiZpYvtAGRSN5cpYO9xJSRujZFyGR9k7jpAZwRdABZcZk292JqYAqcFUwrg4AV61duDoAXE19DK7i5IMvErgabbL3d+25tNzyxB7O4XiEg1Xm6qx3PmKxGXBhLpwNn5N0wNS7lGCbqQTccGJNw8vVZOlc+KrFWuEW5OkGjL7hZtPPYZE54U8iggES+Fr0YyBR4i2T+T+5MgPmt6WWg0w3aECC/fGum7aJxeE4BJ2TzMRO2HBNgPcU7HXk82IfE8InXx/yvMXJk6Px2vI6xvTrYs8pg4X5gcQZl4r6hYzUeLe7+0ZvPpRUh0ZVv5BNnfWG6PyKyQVUhHY8jeMnnm/zvg03k4MVPaNzcHuiupx4s31HI160iWefpelRNEzIHrloA89eDlxkOdomkkB9sGOKwK1TiXyQ03BHpmVnNvBcaMCEGFYotEsbeDl83gxQlHmyxfhNh37owJSH0yXxBp4iASTbFUAUjTILYCoo/DjfTIdQWNVX2J3KI8/CHgmcBdBmM3C7+2sWWLG7bQPPwt6Uu20Dz8LWlLttAw9WfLsZ2MSsHbMzhUaoiW45DMW34a7UpAgHLkP3MM5hwFGIfg1oRR0nTMpATFbxLk0G8rGNxyAOBs/SYWy72fVpycDXoBmkSEqOwcRymgFRfpYO6twERGkDovxOEJm9tW0wivMSQIrzElCK9uX2AlM+CabIX2JvOI3oDCxCw/xL85FXC6jl8vFYBwhH7mkiwB/C0qMJcm52awh1gUsR7v5CMoRS0WYMjmkVDEamDEam2LCW1akI1inU4jLy2QYl04SSSHE246TLOPmBJDEyM6I+lIHNE6wxwXZ3gkd8hccw9oaYeAK6XQxVmBJsTnBFv5B0jFczBpxztQEIwYLIkPCt3WCwbwUGphgKVkMC9W7CpDBv2wiTAYNlBu94OOPPWTEIuMc6MOOyY5UJRkPbrOZEphMYAmWMmZlwS0UbcraRbpTRZKJnyjzt5PN/zd5Ss/n/dZv/r+g2bj73a9Btsv8idBtITf8KKzZGiB349EIsAwVHU82UpT5OiKVJsfeelPSnnZS/TSF2l9ZGkipteSZk2YFdsqwpEcmyL2SjCFjX5XKQJYKX20HwVGSaj3pEdCFnhASXiV2OChJdUKALea0iuhBtqOQsUtOaQZFWUIy/OeCvPDMUeMDgHtCF3oguFMk0z8Z1L7LWe5iNAr5rBjkiDbyBjMr3rhoV5JKb9lmWn7JAC49/5eFMCPgPvRL0wb7a17Wv9nXtq33b7Kt9Vetd0ks5fw8Da9/NDax9NzOw5mJfCJ2FYe4wzL4cTyXHe3jZ8F1JFtYcs1Z6r3U2HISFNQsXmFxT5wCvLETAIuX5ns5iiSs2s+aMKJHFeY6eqPIsor30YEMkrrtH55oVNRCZNj1jwsS8UpMxgc2AwAKZFZKGwLrRmmJFnVYIqZw5l77WPWAVaTx6Y6TN6SLWkgds7u0S2BwT2BxwOcUE1gMu93ZxOYUvlfYw0N4uLmO1+LABPNMkHcDGeW5eS0RgFV4oHOrOdAlslmBDFDOrs0dUHtPNnbZ4DkLbG6L1jTGbsRoC5rJ5l0YXjbM36o0ugoBxoyBgBiQpd+eQAQsmYN1B23sPOv2pB83G5yCnHSJQxiCdizeMDYFymEBJDuMjY2qUDa9Lpka5WKrKETVSsd6c6VIjgDamRskSETX6t7KyGGnW2e2adUKn5vBFeZ2NhFE7qU/nYn3ajfVpoPbIbn26ABUwC+n9F5hfm21M0bZ4rOrSmw8k1aHzPGjtekMf0W+7aXJZcM15OikWk/6UhSLicCV2VHt+R+151yhYkYcU/KO6A/TyFkuYttnbIqcDAsMgNwBSRhHpsmT5x8Pxi5jdM74XythxKJ2s9jB1lgU6bykWq4mQwLP5MHn4gSSy0gi6ZfzbuMCwzoaHEbXP7lISHfThMRzQKr7x7IeZjdC5rHvDwcv8q1URLL2zcjJsACq10hD4+rTt5Rga1OMsdKGgn+CCH7+QLK3pfuJJA5HwbZOPxVKATTf2uhiAg4jZxtQeld6n+/390LgxaVGLrt7v5bil60bXciM9gghACYd8PjAkYKRqXZfdjuj9uh//zwzxl3bkQsAOeqQu2+Q8kNnwDxi/tQF42dgaqxQuHM3AOia6+myQ07ljRoHu5qtEvkrkW3FNFuvQlH8AW0iRSxsLzmjUt6k96oT5Kp9s2sfWk2z6+YTvAXZnm8E+nTlt5Lt+ZhHgKwEbdbIIOEYTEK2CQZ+Qsr9gEGsQv4w3RuQVA9IKlX7AzBJK6n5aFIPIjGQqnhf2DiPHtij3cGC8p/Lb/Elt7TxasMj/y463r2zj5Zk9oviAAAWe5Hpss/TJSmXmfaf++Pccmdo0DiSZYTrDVlH5PahT1+I32FWXPQwf0WR0BhSa/eAzDKYEwZBYu1TzgDGwRQRD7rC8Dbpd8SxhfRskBdmXCeubQza22PpGE+V0bQiDxvo2EMjI+uZE1je2qkTWN4esbyAHTtf65uy2vg2ywCj3tL45XeubZNNSbg/Tkrvd+oaekcUmaX1zdlvfnN3WN2eb9c1NmpVySeub3Mv6lsP4c12r0jBb33Rki9tmfTu8w/o2zNY3/XHWt+EEmNyk9c2NDZR7gSiyvrk7QZS0vjl7WN+cPaxvzi2sbxGY3CSYtlvfdsKpxEbqo7EtLhw3Cz+rU7zwJ2mlZeC5boxbIzuMWyU2bh313cjArZpJJIiMWyWuZ9wsVLMLTxYl8ra3yXEe1rLtpJ0zD+9lTPICg3OxMSnaDnd2GZGIOPx1RqY3OYpwfliwhbEQFuGVXQivk+NYUAzX277HFscCbb83+VRRLBnDGznw4E1Im/jw3oZ5sRA+DW8kcu71ItcwT6vTHGtPF6DcKETfKhjfM/PDSf7IJ394yR8DyR+DyR+Hkz908sdw8sdI8sfR5I9S8sd48sdk8sc9MPUUAGS/oIv0gngNnx9GYLEdJwPgNi+M8KKmQx3arC6RZgTMLEI6GdRFkMoB2CwgGhV5S6PIQa6KM6//x//Bvz2AWF6ODyd2CnaVh7rl8C87dPxCLLEh3KKjbW9M5yFiFfBwyLsIhlu2rGIUqoNlqniZ5hFORcESV8ByLcQsg2K3sn8LasZhCa7ZRl9YeON2EgctyGUj2U5Ke6YdPm3RZUlwZGmG6hJ598luO1L3cDtxA5J8QmYpOiMNt4e1+B5o8bB9QIu3NuBhSJJ/01fkp5TxPQssxgt/9p1rkJcdUrP5AK/dJDXGvCJSFL+F6uJ7HFxK0WmeoKDZyU7qQtOXvDccNQfzOp67m5GgPmTmlKy9Wxt0OoLYcRrqVphBADL5KIEMRhT8VCNKhJuhxqGhznJno6IozKrSMnz5O6yHhHaovRWSFwAjDguQP6LsKkJJ5Snek/tP09LZJFhTALZm+PQGAmguBxT2VSKilI0ZSMViIWg2guVK4s0Irmqi60qO4bwcZMOnz3ZzKTKMALtVR4UI/6vNL0J1l83ED5X8YcU/fPmR7mZHWyKowldUm0qUQFWIxwu+nTUFrSaFvY04lhFcux1ABF5eHxnUJbkuZKo4U5lMUGnZ9B3wWN+CeUvpVHhN0h+KtgeYEARJC7XD65mNQHJ4YIq+hs85WLDF0GAyRnEhwswlnQ3Tl1rhs7/3nLOhJdO7m7yzb/7uZi8UOe8qCu8VSC2YJKV0ZtlPgREhXDNivLNW85E+S89m+OyWvRJ6lwBzhwDp0lbHh3KDFyBtOZn2ZLc9WgQGthQOb8/OYt4MrCnSuzKF1I5CiEpGXTuM0e/RM5YQCdhFFLY2CK+zWHt4waKGhKiRMusxa+GQq0BcNxmvOplcjzK5HmVyPeJwLNTlIEuIrozXdrZJ8+pomSjJeE0GgkBxYGX3n6VlenPXPruAl6gNgKYoXmBohw45cQeZIS2bPILPNWlExrbhnI33hcMsx1S0vMFAQXAvurzWEPL/bMEJPW0hIj+CtyMgvw88v2xqo6oRZjADs24GrOphfiyHW1vO2UJGs8CQbvoZQ/GJEGXiIIiSFn4UBlFQIMggFaqNQlanOIg2REQtzxSykDy9gzASa7kcZDgIMkK/YPSh3GiFqgWa6OBQJoUfN7GGU6ghFQcD1FJnvANw24JWv+vjFD6W3dCBjhuF10s3EbjlTMFhfEUv0hSMkCzWckNnurVkttdCR6kFMxp0ILW7v+k4yHoG7VAYiGRkTHi+yiiOeZojumdIPqBu4EaDm9ZsQlJybREQ0t4BE2HxzXhwZws2BYsE3cF/XMEwG0XwZj5La83ikO1YaxbWmsVrTcH4D8cJ5+FCOnzzu9dEeDzc+oNrwntCuOFV/n3tu+b3x7zfezgpBryM4AqYvBmFUH4bsd35coIiNgcEqZO0B/l0eJm7DnViiMLrgxNa2ArkMcruGCUEFuEVXa3c/yslXQrBIUnrJYUPcnVs8E3D4Jvlo9O58JkN2kiSl8h/opDcS4hsohxHnGTzApz0U3gUY6toFtZd+IHr4g6jb5rtn57Ow3AKLcanexrS4QuGa+se2iXqGkU9VAd7Zx7ZO42iYJC+Y5z9yVoAdYk8V0ghYuNkOnwxEgri0XR3RLy9dkS8vXdEers7Im4EHTqWnAMcbrZtERT5sIKz7bACfNy3H1Zwk9sh7H3vffLtEFLU3oHtwasgTiD9fktG1opM+JZsatsbgqWlQ913khNsmYlN8XT2xPNbTNjzsxzpnkCs3L0nWcWTDCP3jvmEioQZFZ9sRqW7fR7f/H2zTDKw2yet1ttW0zVT7KW0tDeJ4IP5h0/z7SoUgrjJV1vQtNLsqg2YM0iSQCBVRDeGrAVMZfKOodO1HdZGaJ3G8tAmcKu5zoPD2aIhplAUU5Y4s1beQXcX58NHARpm44zhKIrTb3GIXcvco+EdJFMnwsLG9E80tZGhow52+wTqgZ2JHV2KP3JNPGLK8m7jgKmI5L5JGsj7CDFfSCPzJ9+fDnDTzI3bfcnhXeWO8K6CwtAjFTdE0kcMtjjEbRz01uV49T9H/NaHC6k4WusspxHX1Q6PnCnYHGlakm5Nc2NpcZbDAhN3ap7B5S4UxUyaKNNyR1BZ7t4bye514UDiAoulJLBEwa8hTt24nf6cHfKlqfd9AFYlIhFbceBmxZGILa32DNxs7Q7cbO0I3GyhBchmkmM3WvyAytxtlGdD8GzIT9t5mDgpWG64SeF6XffdVGz1yERWDwdWj6x2IqtHDlYP3ogJHHI7bHaP3fF+IMRrl4zIqkPKggOrhwOrhwP1Cxs50F1xBrJr9XBi+4GTtHo4SauHk7R6OEmrh5O0ejhJq4eTtHo4SauHk7R6OEmrh5O0ejhJq4eTtHo4SauHA6sHSBbH4c4lrR5h5uxNTR7RcUVIbjBgwEhn5DuTcqgeLjPIofQc8yof5F+Z0d+mCz0C+cpzSBwO1CuPk2icghUYJ1uwH8j6ZKguNU8XlHbDp84WLGwNnilYhpFzy3BKs8gE4k3P3qTXKdd7LHB5j90luzvi4AGX6PwqxAGbnU1duu4Gv2BDJ4MprRm0vOwh0lwm1sczrI/zpVy72rT2zFU3gWroEIu49h1z0C7DKv47KeVsymfCL4RbW68JxpE0RayGhkGy0L5LodRySKdIcSYVbmtry96ItVjjM+ZFukaerjgyzmOC7zuyIisvkDJD5Un9ssI8u6JSPi550QqOVYVYySok1a9CUv0qJNWvDHzBoH65XZsuVUVmxowuJEqiSr+w3aibbfpZsMljWg6ZSBVZLU7IfCAf7UYOwcidDZIms8skIRhrh3l926XQ2uixlZAC99qwRoDTeIj7fsknE9eNGzfEBocYtjm0MItJmRBWKNClG9bGowVFoXjgY+BnIFvQYajeFC4byafIkiGOKCfo+VP06caVjfBVhJ9uVZRDH9K1Azm+vgQGJEzMqYKFHlPnqRUymZh21c4mw1KySdt16W1Od2tT5KlMZ8vzvRm6cM3PQI43tYu4dggNZ/wCePSjBesmo7LiJpKVQm7vqSqHlNd4ZBkeWS7uC+3PaIr9gs/8gqsL7rs2Vvc3TcByZ1ho688Ik//sTyDYkn59gyQcT2f+7I9PCiGE91vmoPb3fA+LEqR+my6QYV3Agi6Qwy7EJd/V3odS2yu0krHmrR1KQY6lOroYp4BbhZpBj5HsLJ2BKAPLNkmMUTZrBIjzHhS1e5qcAhQt0mxSI4ARnMRI810R1Skyh5MQGVenwFNhoMDSg/xI906QbYz5L+ypiPlvbdcIsN/w+ja/uTydSCGJy8JeGRfw7iSTUWDpPLnJwRutQ5HksruBom0AxGZIFBguPbEMbNFObNQ15e4NHBUDh+G3DQ5w5NF0A9wngoR0t48/lqQdjtSo80V3G/wjGfq/sU0ER6jppD5ieUTrhRaHQztVQZbXS5bWC3bJAZldy4S6nMaGOZAl310mWWgfu5dJlpdJNrFMMugmmSatWy0TZ69lwkMkxgSKCuDkaZkQxprr3CwDBSteJjqNFQBjRag6QENETcH05wyF3DFYHqjNIyzweLvTn+Urw0yTe0x/lqc/+zHT7+w1/XuOULrbxxVPf3pEvYFw8Gn4dPoIhmSTFpkGV38bzyxpkSANb0k+K5seUa+bzfxstG+fjhWy5OxEi+gf21JhEWlaQ3R7XfcQHHNXulKSb2fM0F4HFcrEZ+DwxnBa4nxk8sB9lTqDXUdj+IDtxmrSHWU4S5Ha1kaKL6nL0NWBgWVaoZh2qW4rAz4XYJqRiq7IJGs2p9jLE5dwYCUkD9lbsL4lh5LqDiW1fSipvYaSSgwlRbemRFZPcsYjQGPDR3G/KcBQ6pXtQBzg4WW6w9sGxMN7AXFwDyAivubh+Ho5DC0+qMftmEtC6Zge1WGZU3pppgf4nj4z+xJpOrUNsS+NU9u0huke0fiAtFlJevsRr2u2dIyOYkcOZeSNj6+OCRGkyFaTIsmQ67lEYa7BJQnMFPM6beQv2jJxEnp46lq4+UWSg6HtZMMteYp40TV5ugDyT1Kuzbq2PfPS1tZWukpXxmQoRi7EeKyDEWUTm1cUyiltLjg0taZ0Gqye7bM+bt6jUikuBTE9SGl1QshARSVTWqGkxSVtN5IpHFqKIfWDDFQwMRIEwxTUEDUipJ9zI3qkyRpNhyDMp2wqUdo5AQc71xSyCZx8w5AiWwr6I/xsVICkwj1qyJDfc+ht8N69S+IQIHNC2lQ9FAzzMrvtJVmws3fLHpwFuFvmtY3dXWc7yJlb7glpQZCxIsi40Wogrp6CkwBd6pJy37X4QIjHd7qQuQCCInh3aptNhu/do87CHJTW1ir+rIfidBNVUsyZDCYVO6pniRnR/trZArsQZ8hv2Al55Xmd0CZXImxuO7O4EUux46NiHUpx1OQ0vAwtPp1Be3faWgWdAiKuLKPxIKNTDxd4XzYkbSu0vWPYQMpjDwsj4msCSdSGyM5qCGMr/p8ZClw2Zbrs3O2CXxeoqEcbRBniW7IZ2OxzQrPaYRezFM5gDG50dKbVIgCsN/2ULtB2USATDtyoAO4lg9g86izjA0yNQ7aBps4wiwLjg6QN/Tmt3WXcdQihP3wVSL9AxvMMuWdEGYAM4TerjFGIP8Va3u+DwcB20oyuWsJEGiJhGeOeYAMSbCXRZUwiYdxT2417MrqVSXWNe7RhbS5bkmbTGTdUHUy4cnHDiq2KXaueSlj1QKMubwSWd5ANWc1oO4BGQDVrsc2qJyOrHj7d2Zf4I9oOjC/Okt5txV33ZkmyVlnRvVmWsVbJhLVKMo5TViDje7Nk11ol43uz5I57syRZq/a4A4rj2vIdUHG/k0PcaSIs7nVBFNfyxvZaotEXXfd/VzK1CaWdAPGXN/4OxF/8PzsUiOVH6QJuT0uzIyj5iu5mmG/yTgsqvRyvICjPtmY2QxI0tlKZCgW42wx0qklX1tItaWR8JZMxmqP7TJcDucz+ZdH139o2t3qZTSF03ybvz+7OVgrbRtt39RRhZbKQ0tI7QLSaLjBFNeZWLi2bZikQDkjTtCQ7MqwYzYcjA+q2blgwktMlWt37opVxtuhuulneAXNDM2iZ+12LLOwkk3SJaLQEIS1ZsYsjdt/NRbZ0sa3i22wlcW+VN7cFohNMIKW5e7V7H64aIn853G4kA0WHZSmbXB+HfFsrql1GFUOKMreKg7jytNJdRN0rciVfkQtrvAd/Dsn3UA9oVbDAdak7AjtE5A1r8QFpRTcv87Do5CIF8Yx6bhssMIgXPkNoZ27kZiTmkyB0NbFk8UfyPT6KbtYlAHVfDJgXRbe47Wpdkbha95+oWEy6OZP7BAzO6jI4uZ3BsV9BzOSyezG57HYml+0yucglxQKvI0ZlKvxk3M5hbucwt8uD28FwgaiaKYv21OkMBRSpLsPLcJ4DXkXbVqBYGXLA0BliOu52nuJ0eUrxFszonyhl8ZErqMX2MB+oAJjZpSNNaElMBdIxMIgi2EM6Vsa6wq7Le0rXuz+ORGsVi9ZOJFprC6cw0nyoczNyPcF+uKXhkx5uyRZ9ekTZPlx86XCArTO4YM6G8mjjxNF+soTYiZNA8MHCMmTnJ+gsabo3OEWb64I/u5P5l6VT0dFBGzZqK/omGfaIxmWkfRoUC/x2pDqGm9oKrxuVMXzXiP9/pdhrW5sL+bTEwS7ZPdgluwe7ZPJgl5YzzvMmQ8uZwee5HPJHnqfPUdXzzz33XCCr1iCjB8oPmmNeFqmAUW7yXNi2/OS5MCs6Fya3nwuzyM8R3gbwKIuj1hNmeEwx6LDBLF1UntKKjie1IldZxU6QCpxqEsDzaK1RWewlp6KCKW1jrhH6ylo294DiyBN59Olt3vB/rqTd5ZXOJZqY8Hbi9DACP0pM405tPRmo5UtwD1Gh7LTC+9rkXDAjqnT3DHtRx/QU9D6iT1LDI4wkMHOvONE9LSKhASJETM603SJCVoyVaawWm29617QzYkeSCe6vV3R/veL76y2yuFnm2vmANkfULFvQOrgeD8whUZXrMqKaez+NNCPY5X/bLaBF0ESvGQ3A3jUA979WUmKBljgspQslBudWsneJft4rwZYJQqBRVGHthv1NjjaMmJ8UcDiKNExnTWO7I8LcZzzFcdIcCkFGB8FsrifHlTgcspgCFceRQ+kUIDbrjEf0tijikT+s1fWYjg+pykSAMhHYHKcsbQKU2XTUgezLvuIMh5chcQBQThOgjJLs7Zryvh4gDrbNh/X5hhibQclxDoeF+/eVkkxQdVLLF3wkQ+Ihw15sfOFmQ/hpKdYPjbaYZ1+oMN985bmqNWh+OfTLC5+D4wS0QW15J4mcmy9pnm2uOLj59//Lru/lp/r+xWdv+f0ter7jy2tKWpExNjXM1z+kwnGQ7Je6wR62xR9nCegdqVPRQZa3ZHSSI7ocIjNrLuCJy0JPextPG9Y/KzIvYVeSnJryTY8UTAiq70iyrVhmFwz7B1b453DFgvREqjt4VBuYOUL2aatzibbIaL/yLd70JOvipIscmB2bOgNClmJmmaZeWWQux9GGM4ZxU+tM5lJH1FuySuw+skSmzYmhTHgwfNVcuPZqF1n5PqIskDWnHSBrDsPO6xwF7KdbOHTOICtSjKwUSp+uB8nsQFaPovEfxr4PHxrJMapSIyNc+zDVZFCVAt5n+UR59pOjZo6dVCiMPZ0bypHPubHcdLh9m2yHhHjwhyTx7Bx75GZCmx3epfd1eGeGtpYGNSMlgj1yCDVf7kIM+0sORjEANxtAzAEDy9MOCUMMKYaYQ3v3GDE252nrncwnEcS8NBwADkMyA7Rwzt7mGjL8OV3P4tCHHKIR0MowIct8cmg5DC0nhpazHVopztgOrVQXWqlPBi2HofWnigNLsRQe5uDmQZSUGLYdvpc5U+Bsm+RWFUIubAbp8OmmTuHS5/DqfytWgxQeK3ATJYvZz8UqbgnvnBBv/cH03XTrxTN05Uz4c7HCm5HJtxY8L+BgcuPl/xljjq18WQAgxdosNx0+0wwsSBCpbktiz1q5DgK44M1nrmCTKhCh7BwT/+MfTJMzGt1BAgsDDy/MaWvZ66V7askmZ7l/X8n0Jl++qiKtERvRoYylq3DLQ4ZiqcoC4MOtLfvMEF3cTYo1Q1erZdZplbF0j3LFtsvXnONa3LgN9ii+fyPc2mxBkQzTl/gKFW4OqHyXkHSL0zWSnOlkcpjnfVHzHX9AJkDzBfhRmj+Ec1r0Ib2z+J2id1wDl5Rh36VwncqyYy0Bj9yImiQbud9VvHPyKm/vmu3abfFQ3qSTt5G4MIDl/JbkZf22jPDqumTEel8yZr0H95V36HwZxQ/hZ0L0dyh2CKGbQ7FDWPjP4DpD6oH58k5kNIOszkQKgEOxQ7Lxl2T5i4+jU+yQLGMx0zyuTZLwMESh2xze2oA/lac/wiyKl+H+tZR27D9OVn5WZAUfC/Ez4U+fIUGQjotYZOhHyiblH+IhvOdel7jlnlyPaJfDCd+Q5mBBfNA52z3o3GZb3BvSXMgueC8YH4juB2LHB4rt4xY0T+y2bjaNrgkhSBbd8GB4MHzp2/GZehluxT+o1+FV8/u65Pk32686nfDcjSwrtA/DjDu9g8kbd9YM6/oZs6VHjqERsYS3hIo2fsiF1KVWaJs48hnlFGfzzGAHNE0rTOH5jqSBkpqShuDscW15zX2ONg7fkru2eyLtzxzIe1syv/GMOA0TwPVvXxPbxWIYlHXOu3UI3tTHBLInETZgT4wgY4Rch/eMyNJvcUY6tnmkaHPL8AZKmnMv3tfppg47PqScYd6QYiGXTxtJpVjILRF3MD6N0La2LpNkxZekkfXkaPei72GtwuFm+NLz1wQrRRaJUSPK9vYdYgP1UZgENNJHcGwfgiR5EMLewSee2B4E3Ylyj1C8NNm9bU3xpCltVy2HlNU8GXRwvMQO83SUSpLqybWqI5bDt7S99AfXhDHE8tonRviz7jTSWWs+uUa8Vae9fjMVAWS0893pSUfTZZuwFTaFreAznXagOMggUd0MsY6YjduckTZeWWDjdAY2mio7tLnFVMzGI33ESrBx4zX2j7u9Z6zbtQg971OsszRtCtDebZA6y3uuTiQxU+fThhHqFBsY6CCt6TwlqRGSQaxtMoi5ddNC59Pc+b8AoUzapkyEPIqtkYpja6Tj2BqS9I8dZMWiI29MVuydZMXqkhXbkBUbfSCyYjFZifeT6c07pDjmiVUxMSFiz1ldkmInyAEFR3q5OxOp4fjau1CYKASKj4ioR7u5bAQUxpN7hMzWMF3gGKFWZ4cCFTqIdvGR0Pisc2aIrmAaItu3xEuSTU+w72XSIxO+l1w9iRbRgjFSYIpB/1cydnRIJYOfhNILsRHcY9lSSDq08uL3poN0uPkQXNZIy6kgC29e+h7cMMluydcgD/Tagk07t8H6dpc4DBMRLBEpnSI89Mx31A+kf/S9af4OQkiKqxnkn0xvdMr7srElo9nNqHnYGX/wPTOJ2ESqC1e4/yGTLuiMoW32FVOvaOuVZwJnRj//p3wNkhHcnD8NLNMIhSyI30Q2aGP60nIFa8P7esKdUtt+Jq9oiWnbWyJHkkIEZdoNIMoKbdUNHbwW8F2weCpw6Ohfqb6+tJvOJEjM5/ZUaIiYSCYmKiYmki1jpNBQhm1CEtg+CYcRMaEkkczA8r7O5N2EIaMVHdNxQ0z+ZCf+/dIYtyeilX5JRPtOt1vWTbtlfWy3AAkbKW/CnMjgjljoiBV1xCIAdztC7gkWd+Q5aeJI0s1fofQleM9NlpEkqvb1KLgHx56UZg2BpNKpT86FRhWvI8nrCF7OPru5xOuINn6/I2kXIXJLEMzqrGjrRmzvBFhT3A9q2qam+TSpMXwi4DPv0vLmapzNwydbKWWHolvM/eNYxtEfL+Mc7so4MG4P3FrGORwYCUUdQQDRquXslHH2kEVg83afl2w285YDSV3iNRG5CJgNCWyjkpHpEm91Kj5c81N0iVYPn684Jhwtjomsd5z0XBoYlWSjsAzfusUHTyY7Qmeat61PXp0hrKcOrcpxs2XHqxI8nABIq9JIdPGqJDFG8qo8J9WmOd/k4WQxqfLYWFPTOPy4eZp927E9yBKUh7Pb600yonvog9D28qkhP+GZnzR3dBuIjlWRR4O960SliOQBm47x4uQuDiYWP/Y05VfMbnos8jteHx1BJqMyNuTOd+3adiR9ZYzYmOFoZ7tiLzru10zHo71bcyTAWo52xZU3xtYDEs+UsXSgzdmCmVeLXFlktOkS6+vuV6X8mMqT5olkA926m119LGkI+I0d8PB6PiUsdkZ+cx9JmNytYcEHkPK8V4NJZKu2TVos26YtWh55Y5t+GVZxizbgYZv+orQ22QeaRkNesAIOWbaW5JfFIesRDmD78fDZgjKBnlLL5J7sfkmqTXZhgnjy4++R60qzglTy5KYMxTHIKuTLwmILf3H1e9NUHk9FJNU9Df8gybE5OEoyb8gldBwn0nGMpwNr/le3URT3bmY7SZB52uwiWaG8Wx5mFwEAyyL754C24CT5iHDHiRouMS4bkqjlMpFnmNQQ1Q4SFweRcrUsusKdjugVlz/FB30j6uJ9rcheXzQ2csQg04RZera3z50i7kinX04ZI5mMjySEshifY2ESD0Rl30m3IuWmzpDByV4Jbe8BP0P2H/zEAtV76ORe5pf7LOWO47MU4i1En+EoKv/c4zNHZ7y7+aM0neIxH6X53U3b8hz3OBElMw+Ggmtrmc8Vkv8HtCwyLGMGRrljme0dy9ysYxnvPreET5RPBgL+REG5pZ/bPkmTnPd592iEi4YziCOGHTFP0apKtN1uemvCvSMWfcyGeGILEyFZvFXhHpZyk1a4Rytc0Ar3OCCK+xkiU8x5ZCgfjGiPJCHT/YyIaZcNdPrh96Zx7FSLoV5bCPd2WpEMtta2VcmfH07WPruj7gOEAxy2AUdPB7HqPOEO4CdELKHl16EYiq8NuQMRYhBarAjXmxduL1Mx2QlliylPn0iQN63QzCFyoDuinv/udJU3do+oLaRF/OqD57uvrj9Pr/abgUum3rbBg0I8X16h6PbvQRU8g5QG6b7mulFf7iq6Oe5++5Bwi2QveZ7tCN4+t1eEr/7BNRHeEb4GtndSuD0ifPGFayIc0/SzKML/DD//WvDvvKk3dLyzrivuEBpRre6gF3AC45u9s9zibxaRApQfjb48okTVElF2U5jOhY73224GbXxJ8LMqXBeCtLxLXL86TTWyJ5WDlOM94KaEd1G4lii6FvYGhOt+kJeCDqm7/1seB432t1eW5ht6aXWhcVm3O/VWp63rHX2toOcudnRjdYF/ClNi7WJHry3qubWLqwvtu3TnfEOvNFb1Ultf66MvkMNFkSf6WvXVJxtcscm/FkS18LvFtZbmTqwtorInO+f1NeHxy8bqwqf67ETrYrszf2JiarJUrZQrjfJifXRivFQdHZ9bnKvPl8cXqlPVykK1MV4fm6yUTqwszbXqrSsn2p2FE+3W/In2lfaJzvlWo75QW1mbr6+cWF2r8e/28Vb7l65+fq3V4PrR4xNLnUbrV6muvrKyNk/1tepP1S415k9cWFuIKzyx0Fhvn1hYuUDFjpWOjx4vl6l0lEdFOxfWTzw1d3FpZeHE+Xr7/PzaaqdxuUPlVpbmUIQ6vD5ZKtXKkxPIEELsU57ICSE6Qohs4vfFHb+XhRAHE79XhBCDQojH6q36ykpj5cF6+7xWnvCEEJ8TQrhCiLMPhyejvBeEEJ8XQjx+cX2lgcJCiFHpiUeEEP9HSojgU/TlcVN/9PsJIegZ/b4qhBhK/P6jHb//3R1jeRBp00bO1A1kKggheoQQRRzg+6ieXiFEnxCiXwgxIISYtjwqN5qo67oAp/l4uP0/0qMxnzN1iUTe06aNsNNpXFjvNBZ0Z00vrS51luorS99oaF7Ax2hB66fOL6009FIH+DnXWFp9Ui+01tbXGwtC/A/CE79r+oCxKDMuWwhhmWfKtO2Y32khRMb8juDhmXc74XLh63/xV0cf+6fzl8N/8JWNob/7n+/79772m+O/mXnry5dfefY3rv/7/2e93W60Oktrq3qxvrTSWLhLr7fR//vu1fT8gr6wtFpbu9RonW/UF4T476VHa+Q/tbntm31/T/f7+uU9vv+HNvcxOa7ijvFjTPP19fr8UueKRhWLK2tPCSGelZ54TAhx2MCmVCqVS6OlsdJ4aaJUKVVLk6WpcqlcLo+Wx8rj5YlypVwtT5anRkuj5dHR0bHR8dGJ0cpodXRydGqsNFYeGx0bGxsfmxirjFXHJsemxkvj5fHR8bHx8fGJ8cp4dXxyfGqiNFGeGJ0YmxifmJioTFQnJiemKqVKuTJaGauMVyYqlUq1MlmZqpaq5epodaw6Xp2oVqrV6mR1arI0WZ4cnRybHJ+cmKxMVicnJ6emSlPlqdGpsanxqYmpylR1anJqqs5rCStpYenS0kJDz13R32i01hIv5usr8xdX6p0GUf1W40IdpLqln1rqnNd1+q691gJ9xofiH1Xs1fqFhshl2+frY8efqrcvyH9QuSAatS89cunhdqn2SOdku7lxefLxM1e+OvfYVG0yoldx4swjnZmJ2uQD5ucjnUc6M9XaWPtKu/LlK+1O48Jshd+cbDfmHpyvN9a+3Lp8pTbW7izI4dpXHxlHy9Xlxvx8fflUuXp+vDE2UZqYX2yUF+cqpbG5WeWj1PkL9fnx88sLi+Xq+cbY+ML85MTo/Gh5oVSdGp+18usJGmZnOxGFSjnLF+rzZ1aXOulELRfq86ilXl6ca5TH5qpTi+X6XHU2k5tvn68vN1DcyePDU0urhK7ZO6KPK+tzywuLo+Xq+Wqj3KgulCarY6XJqcXy5GxuVwtz4wvlynxjfqoyOTZRnluYde+qffWRcjlB28tjJzlxaq11od6pNC6vr7U65er5Sn2+urDYKM9NLFbmquXqbH4CcwLwnmw/+cTp9YlHNy6PPnKlNg5GNtbutCbm1y6udsrjC2s1StXmz9db7cIX8NnJ9uJDi1cqs2fKE0sP1aq1Wo3YXHkMqYWVWqtBU9RzJJqQcrk+115rzREEytXzU+MT5epYo1JqzJXmG5Xx2eL8r3OJjC+2Go3dC8T7WHgtXTDwmhytL0zWJ6qNucrowvxEZbb3Pu5gvbY3xBYvdKhbU1xTp9Eqj67XF2pLq53Gk636Sl93cS6snWxdWUcrE6PV8cbYWKU0P9eYGF2ozvZ3S7U3LjYa32iUq+dLY1PV8tRoo75YnaqU5+uzA+NRqamYeZdHebU91mosLl3GjE/NzS1Olcar5bmpqcmxxuy+O3jGb9L7iadaS53G/j+WBKNybfihx4dZlDh+qTF//PhvNeaR9fjw6ceHhy+OlobrbX4kyrTXG/O1xdbahRrkn9pqo91pLBw//uX1xvyp1tqFM51G6xHK45pODp+h2k4/PjwVfwXglxrj1bmJaqPemCuVJ+ZmD1z4da6O8tjCUnt9rd2ozZ+/uLq8e50MPvnrbG7yQuNCfWXpydXdDR1c+bWOa/Ti6srS6vLNhnXoX4a5XRgrV+bnG6VSvVEuzVUnZoc+HxOJUSYSj9UXFhoLoBKTo+VqeaK8WFksLWIJHx7dY+GXVhqLndnV+bUFIMtiZb5anirVq/P1hanK+MLsba1fK4wnl1YhgtRW6q0nb7qAPpMFa5m50mm0b5+6BZ2dIGVhgtSfcoV+1OhHDeKNTkDGUAOqElxtsjzXKFWnFqcWFsYrjalZ32VOQgWCLDgGJe+4H80/0Z6soQs3Rf6x1YsXxpYurF94pDMzVas+sNReX6lfwZvh3+UKvkrgm5h/dL40+ujDT6ys1SYIRpV2p7W0+iQBrvJlk745kfkKiEy5RLSGOMpnj+2e0gqTMhCxxsLURGV0Yq5eWZgaLU3O3vlAFyaGjs6srM0vl6vnyxP1+ugkIDI2Vy9Pzs0eX1m5dOF4eXxiamq0WimVKmOlqanyZKlc/dydcaNgBhFU5yaqE2PzlcXJ+dGF0sTcxOxIgRmoYRJHnpMMjBrT0Z3TPrVeX12aX15afbI8Rsna+frqwkqj9UhnZrxWNqyBgfRY/crKWn3hZguDPi+PPoaHKTrZqS83anNrlz+fMavhCwXmV6Z7R7+BURGo19bbk4sXV+chKk8sXVhfaVcmCeuRJtRGsePH19bbx49HBY8fP7WKMmFMBhbXWvQc/tKp4VPIHZ+vr4B/j5XKE/PVRr0+UZ+YWKwvzB77au2rj/CKqBqNdbT0pfpTv9WYP7O62mjF9VZbjXajdakxWl5Yq5l0rb66YEAFQWp0fGphdLE8uVCZn5gvL8weH4sY1y0AzhCrQTCtnV9bWz5R+4QdKpcXl1aX2udrT7bWngK3LdfnGov1sdHGeHW0NFWPllFptDo6Xq2UIT+PVkdLpa8wXRm/CWJErRJmlMuJpre1uHtQ5Wy8Jkex4M48cgntjH5MO6Bdk9sbWp/9GGgTgatHmHx6ZW2uvjK7u0djv77JnaqOLkxNVRenGqWJ6lS9NDv+4K8FjDPV2sSDv+SMz4+XpupjU/PzlXp1fnR+Ybay+beI5mNPNjrVau1Lj5z8lK2XwLpChubYJ1gsE7UKQ+f87CQgUVtbbcxUa1Oz0eBvxi7BD2mohik+0pmp1KZCvKp31lrc0l2fSFFYYEXh7oyh5fc8EM/h2KXGfLkSSyYnzdxN1NfXG6sLtcZK40JjtUPMsFIuLczPTU1ApCjNL8ze+7VfP8+aYpbV7rTucxO6zP3VmwrViYma5Ilim2pt/nxjfnk6faFxYf7CenhXVz64xVSXuQbSxCaXVudbjXq7MfN7hh+Vf4mVOlErV77cqXeW5r/caf0K3OjksU8AAU7VFi90Huit1Z6aW1pdeLKxWuP1NHuZR1H65UbxKfAN05kUbk49Qn3/WLWuelO1rjzK62Kd1K6zfYnBGSX4keO3QoQqHrW5xpNLq7WLq08trS48+rmbcTtevJP0xdrahcdKUcHdK3xibu1yY6E8Prd2ubbaeKp2cRXGwt94irnJniNd77TKYzAU1pZWa+sr9Xlwh083H3tOxezMw7UvHbs5PdsxKlCxL3/jU/XzDLGx8VplbR1iTOVRepzhNbAbNiAsY0T2ZvfgdY8vfloY3YrEGOqyRzu/eeRWy6Js6CPV9VupxQv1y4tfucV8R3S9PMpTUWu0WmutJ458AtRk5P7tYmLlwnryVdKBn9gbDvXVK59s4I90ZkZrY+HqlWrnynqjtrSwGxC/c/LWDX2p8Qkq+dpdn9Y606XnXz96q5kYN6kInX/3m39rJBeSQe0iNz/6N9v8TkJ57pPByLDz+uduVbpERalzc+dR8Lc/6Vr6ZCx68cIecJmf+ATIU43M/7XI/L9w9hba+17sebXdGR1L/KgtLF2qzV2pwTze+JUrazUuRJWdn4UtZxQm9prB27FSAofn19av1DprNaDMQq3eatWvQPxujJfnJyYb5XK9PtpoVGaXPn+rqRqNJpbg1Jy8RdnRyrayTICixbe89bcnqFTqbWD5yvgtuCX9LU8a2pkYwOqC4QZ7dRpy8NRcfX6506rPN7oAgHjaPr/W6tTil8z8Ziq1ndzuG7Mzldra6q+5lZvrCNTe+uStRYzR8YXGYv3iyo65XFtb3iDy89tnPoFoUGahhjKqZi6+1Jj99DM3M1Vrl261SicjkJAPArfYCW/xxdhE9MXqmhli+/zShdpSu3Zxtd2pz600apdGL35C1Jhba3UuhbuwETMVY+PaXLMx36mR6NBqYNNpvrrQKJXHSmPV0sLU2Nj4rPid7HprbeHifKPVVs5KffXJi/UnG9L+0sV2R+TXW2vzjXa7sXBs7opKURduKx+fmjhe0iOxc4EeLY1WjpXGj5XHj6Sfqq+0LrbTpeOjpeNj/y99naWC');
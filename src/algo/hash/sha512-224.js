@@ -0,0 +1,49 @@
+import { SHA512Algo } from './sha512.js';
+
+/**
+ * SHA-512/224 hash algorithm.
+ */
+export class SHA512_224Algo extends SHA512Algo {
+  static outputSize = 224 / 8;
+  static algorithmId = 7;
+
+  static async loadWasm() {
+    return SHA512Algo.loadWasm();
+  }
+
+  async loadWasm() {
+    return SHA512_224Algo.loadWasm();
+  }
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHA512_224('message');
+ *     const hash = CryptoJSW.SHA512_224(wordArray);
+ */
+export const SHA512_224 = SHA512Algo._createHelper(SHA512_224Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacSHA512_224(message, key);
+ */
+export const HmacSHA512_224 = SHA512Algo._createHmacHelper(SHA512_224Algo);
@@ -0,0 +1,49 @@
+import { SHA512Algo } from './sha512.js';
+
+/**
+ * SHA-512/256 hash algorithm.
+ */
+export class SHA512_256Algo extends SHA512Algo {
+  static outputSize = 256 / 8;
+  static algorithmId = 8;
+
+  static async loadWasm() {
+    return SHA512Algo.loadWasm();
+  }
+
+  async loadWasm() {
+    return SHA512_256Algo.loadWasm();
+  }
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHA512_256('message');
+ *     const hash = CryptoJSW.SHA512_256(wordArray);
+ */
+export const SHA512_256 = SHA512Algo._createHelper(SHA512_256Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacSHA512_256(message, key);
+ */
+export const HmacSHA512_256 = SHA512Algo._createHmacHelper(SHA512_256Algo);
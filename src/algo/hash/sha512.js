@@ -1,6 +1,5 @@
 import { Hasher } from '../../core/hasher';
 import { WordArray } from '../../core/core.js';
-import { X64Word, X64WordArray } from '../../core/x64-core.js';
 import { loadWasm } from '../../utils/wasm-utils';
 import { wasmBytes } from './sha512_wasm';
 import { sha512Wasm } from './sha512_bg';
@@ -11,6 +10,7 @@ import { sha512Wasm } from './sha512_bg';
 export class SHA512Algo extends Hasher {
   static wasm = null;
   static outputSize = 512 / 8;
+  static algorithmId = 6;
 
   static async loadWasm() {
     if (SHA512Algo.wasm) {
@@ -32,16 +32,16 @@ export class SHA512Algo extends Hasher {
   }
 
   _doReset() {
-    this._hash = new X64WordArray([
-      new X64Word(0x6a09e667, 0xf3bcc908),
-      new X64Word(0xbb67ae85, 0x84caa73b),
-      new X64Word(0x3c6ef372, 0xfe94f82b),
-      new X64Word(0xa54ff53a, 0x5f1d36f1),
-      new X64Word(0x510e527f, 0xade682d1),
-      new X64Word(0x9b05688c, 0x2b3e6c1f),
-      new X64Word(0x1f83d9ab, 0xfb41bd6b),
-      new X64Word(0x5be0cd19, 0x137e2179)
-    ]);
+    // The initial hash value depends on the output size and is fetched from wasm on first use
+    this._hash = undefined;
+  }
+
+  _getHashArray() {
+    if (!this._hash) {
+      return sha512Wasm(SHA512Algo.wasm).getInitialHash(this.constructor.outputSize);
+    }
+
+    return Uint32Array.from(this._hash.words);
   }
 
   _process(doFlush) {
@@ -76,18 +76,10 @@ export class SHA512Algo extends Hasher {
 
     // Process blocks
     if (nWordsReady) {
-      const H = this._hash.words;
-      const H_array = new Uint32Array(16);
-      for (let i = 0; i < 8; i++) {
-        H_array[i * 2] = H[i].high;
-        H_array[i * 2 + 1] = H[i].low;
-      }
+      const H_array = this._getHashArray();
       // Perform concrete-algorithm logic
       sha512Wasm(SHA512Algo.wasm).doCrypt(nWordsReady, blockSize, dataWords, H_array);
-      for (let i = 0; i < 8; i++) {
-        H[i].high = H_array[i * 2];
-        H[i].low = H_array[i * 2 + 1];
-      }
+      this._hash = new WordArray(Array.from(H_array));
       // Remove processed words
       processedWords = dataWords.splice(0, nWordsReady);
       data.sigBytes -= nBytesReady;
@@ -98,32 +90,85 @@ export class SHA512Algo extends Hasher {
   }
 
   _doFinalize() {
+    if (!SHA512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA512Algo.loadWasm\' should be called first');
+    }
     // Shortcuts
     const data = this._data;
-    const dataWords = data.words;
+    const outputSize = this.constructor.outputSize;
 
     const nBitsTotal = this._nDataBytes * 8;
-    const nBitsLeft = data.sigBytes * 8;
 
-    // Add padding
-    dataWords[nBitsLeft >>> 5] |= 0x80 << (24 - (nBitsLeft % 32));
-    dataWords[(((nBitsLeft + 128) >>> 10) << 5) + 30] = Math.floor(nBitsTotal / 0x100000000);
-    dataWords[(((nBitsLeft + 128) >>> 10) << 5) + 31] = nBitsTotal;
-    data.sigBytes = dataWords.length * 4;
+    // Pad and hash the final blocks, truncating to the output size
+    const H_array = this._getHashArray();
+    const digest = sha512Wasm(SHA512Algo.wasm).doFinalize(
+      data.words,
+      data.sigBytes,
+      Math.floor(nBitsTotal / 0x100000000),
+      nBitsTotal >>> 0,
+      H_array,
+      outputSize
+    );
+    this._hash = new WordArray(Array.from(H_array));
+
+    return new WordArray(Array.from(digest), outputSize);
+  }
+
+  _hashBytes(bytes) {
+    if (!SHA512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA512Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(sha512Wasm(SHA512Algo.wasm).hashBytes(bytes, this.constructor.outputSize)));
+  }
+
+  _hmacBytes(key, message) {
+    if (!SHA512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA512Algo.loadWasm\' should be called first');
+    }
+
+    return new WordArray(Array.from(sha512Wasm(SHA512Algo.wasm).hmacBytes(key, message, this.constructor.outputSize)));
+  }
+
+  _pbkdf2Bytes(password, salt, iterations, dkLen) {
+    if (!SHA512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA512Algo.loadWasm\' should be called first');
+    }
+    const derivedKeyWords = sha512Wasm(SHA512Algo.wasm).pbkdf2Bytes(password, salt, iterations, dkLen, this.constructor.outputSize);
+
+    return new WordArray(Array.from(derivedKeyWords), dkLen);
+  }
+
+  _hkdfBytes(ikm, salt, info, length) {
+    if (!SHA512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA512Algo.loadWasm\' should be called first');
+    }
+    const okmWords = sha512Wasm(SHA512Algo.wasm).hkdfBytes(ikm, salt, info, length, this.constructor.outputSize);
+
+    return new WordArray(Array.from(okmWords), length);
+  }
+
+  _contextWasm() {
+    if (!SHA512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA512Algo.loadWasm\' should be called first');
+    }
 
-    // Hash final blocks
-    this._process();
+    return sha512Wasm(SHA512Algo.wasm);
+  }
 
-    // Convert hash to 32-bit word array before returning
-    const hash = this._hash.toX32();
+  _getContextState() {
+    return this._getHashArray();
+  }
 
-    // Return final computed hash
-    return hash;
+  _setContextState(state) {
+    this._hash = new WordArray(Array.from(state));
   }
 
   clone() {
     const clone = super.clone.call(this);
-    clone._hash = this._hash.clone();
+    if (this._hash) {
+      clone._hash = this._hash.clone();
+    }
 
     return clone;
   }
@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJy1W32YVcV5nzlz7sfuuZc9IMrCosy9oRWagHc/7y61hLMpUEqJbZ+2T/8LK2wMdxHYZV31ycZd5EM0aIlBXQN+hKDyKEVUNBhRVoMNKCaKxBBFIbJEY1GJQsRKpe/vnTl3z8Ji2qctuufMOzNnPn7zzvs1c0XT4iulEEIG3mynq0vgIbtmSzxUl00LTgrkXjc73sX/xGzXvARVca6jiuovv+a4CSWlcKUsFW6JSCo3JmKxmFQqJoTrCJFwXRFzHCGFQ/9TwpHSwRuveDzuCjcmF8lhw2JKiqHxEuox6O7uFV5ikhu/svnKhW3XOiIxd+HX2q5d1C4yQ7/xjasvn7dg7hXNC75xZdP8+QvniIvKInnfbGtuFn9WMkKKQHpDG4PykhFfGnJexYTsVyfXVAdTSlct/to72vFpWl8Sgd+S9cYKZ4ozRYvghLzMnRII/9Ks1DJI+uOD4XgWAp9fLcHJ098uBFe3Bt8pZJ1AmOzAaW9Bfe3MrNBiVtqhhtwCSjJusG15rxjniKlpGQjttmQUenK6QATdzoy0GBWMoeTp06cTM4mgZJz+kh1XZEVLRyBbqS3Z3hJMLnjZGHUU7LyhVxSowaxwp2hXx/yL2zOJQGSk4hn4tVlHu9SsplcLpdVllKZi7WRVWlBFlVIeNTqcG3F0nCoKnQgu7AjcVpqnaXt6BQoqMnEqkdSBFmnh6Th1Gc+IlONpmXY89BpIHWv3vxxOszVLM1U0jEu+NXCwlOspGh+hhDFK7V5GSRphXKsZrVnqSlKPCn3i8z/NcodyKrrZuZLbzhJklxFuVE4fXlaRJsiR50/KxOiTFE1fmQWhNUQdv1LHtPLHUXa3gzxQHkavAtWRlYFqD7bTuAuZOC3IVjMDWlZqtz0rWzHZcU4yJT0uw/q21TlCxz0ea6zOSVIevVLUcNy8ZB1/oDFKt+CPpRTxkqLVp24D3zCGYZpvF7SYWWG4hHmCuALP4kAIHgxTdNCQVOtULHcIxax0QjvEURZ1wJs4G3Tp1zLHaQk83SmK+cOyBS27mpE1cGNqWIAQd68IbBzAhrDOSMcsrHHAWlwXWiWXdstASBPBHlOciZ0NLS2rTgBb5dGqOUHXYub0QpYWpj0rgp3L7RTiQdLyqTDtEmsSEEQCb2LyuugCBddpdXUrrZGHEVJT/lgd9y8llk9QP9S5CMYQhxK/ooX2VgIM2A7oUdke5cAeZdijjPQYToz6dWy/ivHBcxKxNhpnvCNoYd8KXthBwYpwIjaXoMGBExNRTqRRMCcmvAw9DCeKkBMT5kXDTXrcLHhTcPeAiziTEYmfwdUJcHXiDK5O9HP1WW0pSg3C6mnpWW4e8F90E88gEburn5yZdiGWtEf8jSZEC+HDQneA7KV1IZ3g+uP90drDp8mpaQ+pZCYV7MOIKO1mSzlN5YWsxFj3E8UP05sEeDMrUPLyyqJkPkDJjOD9UaWT0yDBqF3eC8SixcVxWPLQupjtQNNASSBCTFDHpDgPTEYc6DDetKkc3lIWPh4cQ8pVKYMElcPcYz4o9YJj4bCnalrgGW1pMDK1mYdI9+t1ajoSWWl2ip1P1pnldhH/U65bIJUUC4RHwpcb62+RGkxC/qDvpC4tZEIQsFiK+Z3qgfMtEhBgA/qeRisd9pkpKX6t+AuhS2a5Bs6SmSTD+/GkpaZtUoKibMKscLasJZhHy4W1B62JAyTtO231qy4pzKjIpgmVITqtyxhDMEqMhacyjWTipnLG4UFhftDi/L2kNaP96ANyMB6xdGkBAlDHCwXqqiUbAw6+jgXYWIYNlW11uG3VtC5Ry5+EbQMZ01IooP5RYNvd3Y1F5SFWMh7jCZmhegg4t8+QeSofoofSK3gXA0lhSIeQKkXqAI8cKdTXQygFPAPwDQQS7UeXNilJZ9IjM9MMZHoayX566ZIWggzATvL/hOoalQfaSyW9s0SOcw5B4wwmaBwIGkeXGEFTAqlQAmGCFwQNsyX4AMokafiZaJdgxoixEnEDI6SPWZUM1ohAbClkY7wxSPhY4UndtmD9ybwptGRcXdyr0+2WA4OJaaR10Akpx0BOT8es+RO3MyfFxeIpSBU1iJXsRp7z8J1zyfM4wWVFuktcgkGQKiHOS8UM10M2Gm4KZRlJwSzv5QRsNvARcYpRPobrjPAIRU//fmVlEUoB+2VUEsTN5mVg2XiLYBt2Tvv35eLeFpGdlBWwj8wAJNieslQ4Bs18T2ZRhO/tQlm+t7Iuw7WI74Xh/EH4PhSUBhgWgjvPBoaEnAGF5pEgaAXxqmtXjGjf6y92/w84NmE4lvVYwix5gjnWdkNG2XGM9PSYuj+6JXeBQzAURg/ETkvsjJbsB7HHEnui1Q6B2GeJfdFq74I4YIkD0WrHQPRZoi9a7SSIo5Y4Gq3WvYKI45Y4Hq22EiWnLIF3sdqyaMlqEMgBsSpK9IBYZYk1UeJeEGsssS5KbASxzhIbosQWEBsssSlKbAOxyRJbo0QviK2W2B4ldoHYbomXQey0xM5oyX4QeyyxJ1rtEIh9ltgXrfYuiAOWOBCtdgxEnyX6otVOgjhqiaPRat03AHhLHI9WW4mSU5Y4tSJSbTVKlt1gFytarQfEKluyKlrtXhBrLLEmWm0jiHWWWBettgXEBktsiFbbBmKTJTZFq/WC2GqJrdFq26MlX6BUoyo7KrhYLxvZpSC7WPaQyhYFI8T+j0QXjBIVNUlnpcX/SmSTXUqe9FgatfefcRnvIjH3FyyXyTuHKjc2MdF1aWEVJ5O5qezcqAKbmsr/86wI9d10uLtW26npaSdi0EPQSf9Pyjz+lDo2LroaXBG6RUUYm84+sQup6LLlP4giVEVFSCOU/kR2rbm/VGjph7Boo1kjI+43cAcqBMcY58bqLbMKVZylUCPVLMZFOKaxQUqf12bJlYVymFKcsDQTlsUJy4Ga3074HJq/f8KYKOmryBJEUIe6K1r6/X6Ax4zFYFyGkA8Y2rgzXxBJEmdFksgyh18ScVGKOp34euDuId53+h0qWk8sD22gQoFcgXSRaSMWfmijy9BGl4PZ6NbWNyiOSiHbT7dgVW1f4VRnURZvpy4CAFzIgS3J8Fn9etx+0WUtAerFT48q87g2qXnVr+blGbEYcs8V1LyMqnlidFbz0kN4w2h0FXHR8VLwWt9JOImuYYjzdZe2ZEtoS8ZgaGbidgvGhjgSq+YgN2C8VCZOSUUcFsQ4GYOn52YSYCqiUJDgggQK4mFBAgUlXFCCgmRYUIICjws8FJSGBR4K0lyQRkEqLEijoIwLylAwJCwoowLt1tJOoIdo1CuWZXwkU3gkmR6K5HA8fKaHITkaj3Kmz0NyLB6aaa76FTzGMX0+kjV45Ji+AMlL8ahneoQRXbzTnfYMuQBBt1vIILpX4idAxQr+UF1KUl5kYtrPpPXQTJkelinX52Uu1MMzI/X55MhdkBmlR2QuojoJ+nJUZrQeQjkjqaQ8U6HLqHY6U0YdJYIycGkcgcwYmeWygBQho71C0C3hxsUK2aRBQyfJL6Lksmw6rzg6gWrZJFdMjlekY4JdomASSbyz6cav3qjTjfU3LqdnYuXyzk4qOiZs2QSUKS6LUxk3i0ZLuI2LyJcbtbRT09gbu5ZTYkhj1Y30mEgfDGkccePyTp3u7MyO1OW6YrkuW4r3Uko0jr2RHhfdiORIqtVJGI3UF3YSNAmaILFiPCCZFtcVBNjozEW08EnykgjZ6fD5HJ2CghihR3dmRugL9KhOWqQh+vxOWrvhemQnLel5uqKTVnqYLu8kBijTQzuJL9La7yR2gReQLdViBkf6XD1iMpZXXzAZq67Pnwxm0MMng0f0eZPBOnrYZHCUHjqZBac/GfynRzTqlZOdenzKqRy+5tQ4NMApjTY4VY5mOOWjJU4l0RinBPimlDRmmQndreruFf4FiOPxggfrQhq7dFOxkIjtlngyJpNdwZdJpe80KpXwkq1ZhRg2a1Q36LseQpq+cVsrMgS0icWz2AtYNZCLpxP+SFYtYeCqqDdpsDOhL4Nkqw1YJWEcuORckQCaIDTVSJLYqGB/tAVyhX0106QLvxH7wSWvQ8K5kR3UBL0KSMWgrmnlSYYFo9FdJslOXoZd3PJMCQttBfmJYDK53eRVj3P8oZKUA4kvSUpUcnuY/zQWvnYu3ACbPpQYPc5JDVXC5I7OmMIMHws4XYoj5BoCblRxOFCU/R3xEGgDzDCz4CGW0JhzrJwJhEwpnN9JQhcHIAZgZS0Gi1gcfnY/YkI6/zOQ4gNASpwBEvzL+BeAJAaFRhhoHNOmwiGHjYgBGFgipKMQ84Tj2d829BD1TNCkOShaajAoYduUuFqVeZHuzPhNj96jcZkwoX8oeRfHP19oLrgDzAWFscysIGVOUNPuHnDCIO0Jg2ILlJomeylGFqgzMDpL+Wx6xiBbaYChzTAt7RBRNLnI0vIn4ugBYRaI3YRWM4imV0sGwhl6n3Ah+4vWivpCvIczeQ/SyHB+Q6mWYEl3ckYFCXTX2CwOm63Y+WNslJfMG4FlxKEQ9QSjJkY+QbCIbULIH+lPMgExmIXtBaIEwEb4N4wB2/iS8dSi8U8soAoDwQNCxNKcUXEJ4mYESUvWCQ9A0JniAye8x1CpY5/xlOsVDdcYYlpqVrG6Y6s7prnQZ9kVxtGNARw1K/tbmZGGbzBgGPbkbwzxpoP9wnsFHQFJPrXpH9042oZx+A3XkU3r2hBNbMCJAAxppyjlnPDIxRy0JGFMJQcctAweJov1n72dNWn7jJuBJvwx9Eqz+c5rbM70JDgmuCboBDdWYMldrJDvE+9i62IXUckoelzqfeZKr4usuhyhQkadE4wtBPu6e03YPVCTxDjtNHbjn5tXSXNgpKmEXuXm5VvWh12ZLZ3K9ow5FHWnGl8LcguyL4OzWrJPrynQbGn7ZxOZGAsa11Q3MSitFce6kB5rZYlrwqKMKZ/9EUESA+cLlEPt4rSJeolBZsVw2hDxWyBYqF6sPesFswtBrMOYBmZgkf7FgP5FtH/lMcuVQmaMLkwQgnGh2biF8TCYbgQ2blBuhSlPNhkgnG9PAiAaDCpGQDsSKlm1a7JHUuCqMvilKZZl1ALkIBso8ToyGBh00vbmUDnSYGpgg8I2GC82GLcNsmAt8UxLo00Euhy6BN+rdtrZ/ejSx4DWCTEFR2pCFSYTezSasXNnGOXAiAFLMrmoJZwrV/BJCleKgDgV5xxmWkEOPr3wXnKl28XOa1GSnsOXl7A85GC+vHOWLx+6urRp4cvL/09fHoLcnRL2xuZO0Ys3G/+/4cXL/54XL8/txaPxQZxoeIXs2EPVRhx7x8zeKc7+DFllZ38OWTXAsZcDHXvZvwTwSWUIN9UztOJT+MF8VBLm2mnPOl/kozqhj+qc4aNiqN4PSgmcxju23LV35yMr1h8VeXWSMGo8euKN1x478caRg5RxDBkPnrrt8C8ee2HHrynjXWQ8sfzTXx+948TbeyjjEDL2/KD30O6Tv77lp5SxHxnb//P+zT989uFfXJpXL4P+/u51K7Z++OCRqrzaBXrZZ9/r2fv5m++Py6te0Gtv+X3PK799bltFXm0DvXlt3ycvdv/2nqF5tQX0/Y+sv/31bQcfKc2rjaB/cvDN1Q+cuPNkPK/uBX1y/VMff/7hq89clVc9oO9+bemHR39yz/uFvFoN+oZlS2/5qGfzT/8ur1aCXvfTO1/54Ne/+P5f51U36Ifu3712891vbZtGGJCQbLztoztv/+zzd08900UYION3n910/Om71m7cQBnvImPj9lVr15/ecXA1ZRxCxtGPV7+65Nnew6soYz8yPnrtuX954fq7+5ZRxsvIOPTWgZ7j7+/fe5JQ2oWMU0ee+fDIm4d+dpwyepHxo9+/8PjTn6+9413K2IaMj//tX576ZPejP3+DMrYg48XbVvxg8+4Hj/6cMjYi4/DW+zZvv33pLQ2EBI/re++veuv2h/fXEBKg7/zVU6de+t1dGy4mJEC/+tqKm5YveWPtRYQE6F0/fPHf/n39lr0jCQnQ23704W1Lb9n4uE9IOFj83p1P/XLLzTe0ExCgV++9/aHndz67558IB9A9G57+8V1Lf7KbkD0EesupvY9u2brmiemEAujvPfz2DTd/8PZ9jQQC6CWHVr791O47b95IqOxCRt+Kne+sWfMfB9dTRi8ynnpv5YZXVn/3yVspYxsyfvvmEzv67nv0N0soYwsyNh556/Bjv7r+8T8ABGS89bOn39z13rrlRyjjXmQcfOe1o0+uX7ruVcroQcb7v1z92vM71t7+EmWsRsb9t679zfIHdt08kXAAfergrif2vfzcG4RTN+hjB558+dimF9YMJxxoEzV+uOyW7rvf6zlCHHcM9H0/f7Dn0419r/4j4QD6uXvWv//Y59cfIw46BLrvru2ffHby4KYdYAdk7Hju6M9u/HT3ki1gB2Ss7nv5/b0v/b6HkUDGQzvWfrz5wTX39AAJZDz+wPM9G9e+fQrz3IaMfZ/c8N6LTz24/wDYARlLd689cf9tP/zJC0ACGSeWbdp94uYXj08gIEAf2fbo0z97cuOKsYQDN/nQYwfe3fbOoTKCAfThF7c/d/jY9w4uJBhA773t+U/u3Pb6S3MJBtBrXtrw2Z7Np14K8uoafL5h3eaP9i35YC0NcRHRj/zr6/sP79j0YTfR3wJKt37w/V9svuPgRzSe2US//bvPH3562z0rfkn0PxO96sitn/T9+Dc9NJy/JfKZg88+tObQa88Oy6u/AobLVvXc9Op7S/85j0hhY8/1T7z65ut33PIQNV4PIXHPkZMv7Dt0DxgjB9a+944ta5/ZeRJyaRxY9731P9jx4paHaCtoyKAfP7Bq//E/vD4vr8qJfPgPW9/+/NNnDj1OX5Np1/jSi0/fc+utr6z9vYAxIhrXH1j/5M0ff/f55wRiLR85UnUV3RQZlOB+nrkVIGeaGLDwRcbl61UmPOqZkwat/ogbpQa4UQ7kNdyoFlzhg646x22trCreSOkskP3qwExz/L+AfcMuIPQSX9ciz5HVlXZhXsiWVqpUDNryVSwnMIcSogUuXAHOB42Ej7xD+8El21mwlyHYy5D2pF2YQLarOc5aYLUm/RwsV5wyWIxUYRZy2B4X/OZ22AnhpLJGOZScfylaGOW9DszNzUjY1sIabzS502JWeCNEhfbsNNaTpAX9YVyS4agHHHU+e4UFoMgRnSSE0d1QgE4XVKzPwXq4e6zcKfnV1qBbtk0SZUwN6wiOgeJj+iDeUSwewhmp/ox04LITE2miWOfQoE2kA2XWpv+LdFinl78IHI/vXPAk2c7KsrOqWrScYeasMWeXTT6vOFNYJ1Tgj4L7QhnG3OLjoEB4P3acmLl3Sp6LS1kX89EFSaGfi39gnkGkV8FKLingAEQRl+HEHxW6swm8rvvOA9lYcPo0WY99YkE2Hsj2YNtSsksuEeLPBbC/roAriVQ6v8XUO6OGQ40iwtF4+tlfXfJPOpExt/4SD1B3h8Vfs/13XQHHXDSKAudTq8Ue1Tlb9thaK/36mS2oM+oZVuiKVsgViEVg9tqZBxdrp8VHtAZWt7fBkTGyuRtFnlwKwRdoZf8FWtl/gZaWEAc+HVfQRuILtE7xAq3DTkTxjiSiIsW7l1OKlyljxqCLw6BTxgt0/Iv56kqtlrDdVcaBvMEHwJmtPD594OsZ+No4iOSBFK/VwrthYeHglhFVwIVc0yCgxyWGrD2aYkM2aQzZ8KBKs2VczuFRYW1YVcdRVVsZn3pPGJQQuXKNWwIixXuURCFE2vCCuTCmKozpjLOljBGkGAPHvVJZtk9laEpDyNAcyEUyniePHu5yVplZ8Ub2C6HDjL2Y5piAsCeH5FOLcDijiyuQdewlM/IRg+HYSjTCaRUF4wtJI9+dkBKMeLhOZNGjLbbo2V1yAQdl+VknvODjm4/L+y88s1ONkRev3aHYWyjTXXznKpxO9FoINW+OzuI0dwfoJWHRI/KojDvuhk47sR+f2pDjEmMIuB3KOD2GH/bCnbfecdyiGICQRdgOS8bNUvc9S+x9UEbfciGYn9gjLMzGjEsBL4j97WAcNpIP3550J9G6wN5wSGvFKQRAhkNQlRf8GG6cJwssoKjJOhPBGK+SeSO2yINWXMMWYp7mcqM73sQMcLvNFMHtRJyOTLPyIBW4/leyElAi2cBbmxouz3Mr1KWyTYpg2fUI3Lja+sDGz/ZusmpIG0EpM7FgC9Z/i92ncVYxOPs8aU8XJfF5cCokCgweFwbmlP9UGPvirREPxF/hzG9mWxqTVnV81EFcUx5sKJ4/dv0Nsd0GducQ+XH4WgRGsSl0/9yuYONye8+JAxEmXi7QpgsglQmBYL7DKTtsnPjDIwnItxgCSWraE16/0nWMh8j+YlJ4j0jpkhmkjbERqNaMy/IzUKw6g85WZk4ooKzDaicb43CPGTdzZJF2QDv9tAKtQjrOgaWsMnLJ5f1SvJFQMPtfO5HGOcjLYXmXrR/+TnjflHyiYE7xA64tg0QhTMWLqVgx5RZTqphyiilpU2B3WhlzGOY9KakfwyVqLFsp6JAwmMHRMxMio4/bOYTs4jca9DGHOWdWsO6VkPO0F5hJoNSxl/gGMMJX2H+K44WeuTRPS+kPZ+ZlftPhhXEEipAN263ByOfxjgtuV7SLcLIV9OIAamiwj17+KOE9ZIceFQEsUNypJpbAP9s4Y9MPsuXlGVteRre8HGzLqzO2vOrf8tHdqCK78QYMNvyNAv+CRSJAQ3sIStexcVOH41owOdPEEynXMwcolOCAvWt+OkLImUCn9L/isbTmSAifqpkr5Qjx046XXjgK2F8mPLPGoubzgoeCndVdlz2w40XHcZLiODOCf8HR7l62Olxz9iVgzLmI7FLao93E+hsvlwEJji3p5Z1MaPtlwvKArYbyAwPL50tplxKDYiaKVB5dCMzV++ArhWAIpxodLKsKNi7tNbcXkD8WxxK0hNBkChMfxxluAcFrs2ikIL1/MJ3pUFQYvnB5eQdygz8oN4D7cTecSvwY8wwt8NcJVm0jWsbmVUWbl/wTBzavYifE2rwAhU1jvpdlDF7ripChOxNCls387p18b7d4EFk8/MLhWVqO8vinLBx34xpwigYekE0vzpfBpRUPLLB9zKs+wSnzDLiEFGfcGUECxPI3cPtLTJH1tmN1apDsV1PJfjVl/CrLeGsGaCevgQYDkDum8A8CAmGtM55qJ8zNrmlZWWHinLwG7Rqk8L5VdKukcatSxtQAawZfDrYvMWLB+leRkj1hiTHITDCxOML+oDXY0T9feH/GplbQORMiEO3AvcNUvjODTC+arzlaKPNQ1dhkLixl2RY4bXaqBSgB4/xx1FZ4NWYVBlNQNMs+i1KYAqNcKLzRNqTNB6YxH/LTaedQrvS8i0T/dp1qo+d2bCjW1KE5n3fxGyMRBrtZJaFCxgSV1bnH/CUM1Ckef0Vj0FTdLOhILCjbjYjqy1goUjxvGC5mjcSBdgd+kzJmWoV3gRi0DV+Y/rusp+95gvUKz6MMI5f4lRElWtq8pAmZJ73zBmEvTHOCcAOHXKsOr9R8owW3yAWqdaqXikzcs8AxTgVviKFGqSkpQeuLr2rBH36pV2pX+prWYlKGydSAZIc30vRwb/gDM2zfCrJehJcwU7XvFn7Tupj3NQUaTuPxT+/+7Qc/evDTzwWNtPHJO++7reenqw5/G0U96185sff9PUe/2+UpIfnPuykmhfm5Z3dscducS+bPu3xi22LqS/iilJ4T6W+46KfJhSTp00/j78II7dHfaPpzhfnn2DT+lH3H6G9O0/z5zXP17MsWtc9buGDSpKsWXN3WtGjc+Nl64QLdpGd/feGC5tm6o2n+Vc1C2rZobG1Nbddesrh97iUY66KmBfPmtMxbcAWN+F7qH/0q6nAMvUMav4C9iN5xHqHpP0F/STu2pM0rsXPB+FM2L3VGnXRkDPzLVx5FW9PV3+honkNjmNO0qGnOvPZr9cKO5rZvzl94NdUfK804LnHMvH07F8wJSzpvwdzma/TCq9r1wm/qyxdetWDu4km6/VvNen7zAj1vsdaXUxFoU5FyhGiXYHkh1tB7GL1zuVxlripXnavJ1ebqcvlcfa6hMldZWVlVWV1ZU1lbWVeZr6yvbKjKVVVWVVVVV9VU1VbVVeWr6qsaqnPVldVV1dXVNdW11XXV+er66oaaXE1lTVVNdU1NTW1NXU2+pr6moTZXW1lbVVtdW1NbW1tXm6+tr22oy9VV1lXVVdfV1NXW1dXl6+rrGvK5fGW+Kl+dr8nX5uvy+Xx9vqE+V19ZX1VfXV9TX1tfV5+vr69vaKAhNlD3DdR0A33WQFni2yWL2hbOvWpOc9tiJzm/acEVVzVd0Szdv79qcbtIUdGc5sWLm+dOuPxaFWujvDkXVk6srZtYqcfVNjQ3z61vqmrSmOOEysoJucrx8aub5lO1eG5iZcPEXOrqpsVXTrC/Uh6Wm1g1sT6nx9XMaWpqqK+sqx3/Xzhg5zg=');
+export const wasmBytes = generateWasmBytes('eJztvQt8XFW1ML7PPo85M2cmOS0pBFJgn0M+TZW2M3nMSYCr3YEUaqFFQEWu12GSmTSZvCfTtPX2I0FaKApYFbQKCiIKIiAqKghI5OIVFBQUERWlKigqCipovZfP/l1r7TNzkqYFvHjv///9/j6avffZz7XXXq+91h6WnxzRGGPatVrD2YydzbTps7VpdrY2rU2fzaenGfyjTZ+tT0Ma/tWmzzamMYd/tOmzzWnK019t+mxrWpWohDZ9dmw6LLOnq8n4dC2dmI5knOloLjkdzeqbN+mL6pJunXuQGed1qZgRNwzLtAzbNmzTNBcvco2km7Dr6xPJZDJpJyz8xDm3rIQVq4tZMVM3LNN03HiSGxrnBje5YVmGETPjhmlaRozFeEy3TNMwDNPg3DB0kzFumNq4duihpq6xRVZcm9bkzMwsc2I/1eqskeLIWHkLZ6ni5vGxcuX4sdFKcXOFHV63oVhZMzpYGcwPn5SfHGDp+EB+cqB7S6U4yY6ODwwV+il9ZHxgJN9H6WWpwZFoJ54z3jtU6G+lr6+KFcaOL28Zr7A1icLY6sHR/PDgO4rsxEW53KbewdHChuJobiQ/PDzWx8qLI2XlIhUW6iOF/eVikY0cGSnJFwq5ylhuspLvG8qNjw2OVoplVowfoTGpOY2HjZ96bNPZb+v+59IpPaJt4i1vPu2flvYOv/VfEp/azjc90Mzj09o5RzHpDvlaM+Or6L+Cyee1NSmGqfv3/uspKSaZx5KGI5h0Sj6Xmyc8XbIWPrtzlW/0pLg80jOh1d69sfUpTejyVYJLe2qDz4ampDYhmNQqQ/J1Jc9Mak44DPwXOrlj5yrfki72LffoUNtZ0+TrUp/y+ZTPpD7Rk2KCyelJ6IuX/JjUK74ub7lklpV8JnR5B6RaOPN5C7d9e3VKE7ZgWZ4UTNhZbie5I3DC9+xcdUrKEizFw+mzHsgPSF6R11yserFbuCE3Twh9yOPC9iwdgGK3cNdnKQaJBkxYLbzRMxXMrBae9JmwToQPskG6UNLgsybqMaUhCLkDxbY/d3aaA01KwpJuSbAmL6avEjEvLmyfQTHDYujNbvJigmHvdtgxc0RcsixnjjB7UhZNBkZZWl0TLIitxg01W7grrNUpJkyYQgOAIhl3KOcKltKStkPAYwJg4wgmzCxvpCW6vo17AfN2qVqjQyu1EZDwoUF9SELjKih9W3BhC74GVsEFE5awmzxLMM9OMseRALZzRGzTRBYwwgEEKnlMcKFLvZzlBqRKvi506FGDkqTpELoKXpFc8IrPJBNsqDwx4McBRbhCEVvwGoowgH8MIBCDySaFLWK0CWoK8eoUWHVsBmNbMIUh36bRYdgS9MAIje/ZucrnPXBUWvj9O1d5ehS/Y1ITXOpTFT8+AVVETMTLOIqA06Tmyb0Y4AKUhHP3YpG5e9wRMaFnOYBSh7nrMHf4w7PchuMJMIN1ZGEOErfjnp2rkoYjmcJ4eQ4iByxyducqdRoFl+4awNzqii1YMQCOFmvDYnmWs3CpsR5AaVoqiy41LjURw6WatNS4MNVSY7Wlxrw4LBVK/JhaalzEakuNOSIOWGSLWHhW4vQnFh4ZwghWnTASA1ZDD9xRO4QER0jASbNLcOq1JIfvcMarhM+cQwYspHs2Hlzoy7OBiMhDhSm1qSFhyiNXN1W8GHxHCmH5FmG7Hxc6QDMO6BIXfD1A1rNF3OMijp1yYXlYltTV8YnDAoUlYvLwKWlMlOCsWSc2QWmTx0RMahUvJqwUcxxEebusZseFWUV8YUzA0vR5BM1jDpxlzdFXhQfSt2BuMcFFDI5kvMkzYcvXAH0RNowZ51jZBTAg9fEsR3BhihgcX1vEIWHTOmBWAihD7SgwAgEcdH1ofUpzgG7WyKVN5NIGcmkTQYuQS6tGLm04rlaIApbaexvooj2PXFrzyaVVI5dW2HGNXMJ+45RpQjBShGRac0mmPY9kGnNIJmxilWRqEZKJTMMikmlBbUuRTFyt1ZPS6EOD+oB4Vjuo9ryDGh5Tu3pMbTwC1WPKwmOKdWf4GhyZu41J7szltyE9itEhtUSsSo/2aDWCZBFB2qPVKJI1jyJZdEx5ZI/gDw+3au4xtecfU9hMOJGpfSUDhUfrQ7y6l/JrU0xo0ijJp3bOAon+zIxRkjMzWyfcgK8SWgs31NFlwMu0Fp70TILqwzvxu+3HSz7LQhbODXx5FKg3zHptUxYytUk8GNL1ay6ZxZMOC0bWi4vwY6XVKS6Qq7AUS+pOFdC7SbwA4DI4AUSXd+9cBXu898gsfwLHN7P85ksgEc/y6zBhZfk1kECAZ/kspu/A9L2R8jswfQ+mH4zUuQfT92P60Uid+zH9MKZ3R+o8jOnHMP1UpM5jmH4C089G6jyB6acxvSdS52lMP4fpmffU6jwXKX8B0y9gesd7aultmN72HkjvjKQvwvRFmN4VSV+K6UsxfVUkfQWmr8D0dZH0NZi+BtM3R9I3YPoGTN8aSd+C6VswPYvpOzB9b6T8Dkzfg+kHI3XuwfT9mH40Uud+TD+M6d2ROg9j+jFMPxWp8ximn8D0s5E6TxBsMb0nUudpTD+H6ZmdtTrPEZwxvWNnrc4LBOedCPOdtTrbMH0Rlu/aWatzEaYvxfRVkTqXYvoKTF8XqXMFpq/B9M2ROtdg+gZM3xqpcwMeB0vWl4BBMbm55PMsnL9q7VuohmBDIi7PLPmsBKTDzgJlAOlSUUEQBWRLlht/O/UzMzNalj+2cxXQAi6s9Ug++VpiakmQ5iaQB2lTwlyd0hX9xbMLHQlrTRMeWmGJeMmzFRkg+kD8lPkxgTpHlQhwx1mwd82pNkeJQdERnA3wVCAoJd9ek+LY3vZYVTSOACWmgLIPIAwCREwYBwIEAups6A46Q/4g3dKaJj8mm7DqMqA+XkLEpFuC3A2XrAo4iCBApkUiACHwAFQL+MANl4C0t7SE9JLJGLJGaUB7ew3w4Jjgq1MxERMxYATnTJCAHxN8yGdq8ij3kRJKjIwL5jYm7TmMzJaaYMjILGJktrAUI2MRRgZKBvBlZGRMMTJbsBojY8ipgJEh60oClOgPcLdkzCHOBuCCXRLxEgKY9gUAWePJsVohVwx6yNMFV9xkdUql74e0AZwaWZbUJ6R2Iq7TQgnNXYJSODQVVgl0W4NkMEsBDJifVtVPQCMAcOlVvq+7oINFwcUlaOcRcPEquPQIuHSPA7iwKFS0PR5RtD3dUZAResj3Of0B9SRpAtKC5Acn+F48wYqVoiZlIwYLOyK3VDUtVGGSlhMqF1zpeKh51a+FWYP8dj91ikI9TKWqJO5PC7iHtABU5LA5KKBqeGiFqlxN3QwV0KQJOx+j/Y4sQx1IjlsfHkgm+JASVES85HM8lRadSltYESFtX/KkO3R0HgyhBY1A46Xm1b2mBdUggGKSqnhPtGINKUjECrWYsHOfC/0UhQR/90Y5QpNuqZkJ5nxuiRafPorJ3WzITzQzkRAaQDQhElkuqiqWRdSOJHxLJGALO0Wie2ZmZsYNeBpk/oRMl0RCipIwheUeIhItvBMlUbcCQHQ3+BpIzjhAu0jAuUxD1yBradDmhQRjDn3onCNVVqfBhQXTSADWdsIA7Z4muOegfQq+DvlJKE4La+gUqAhTskRSalJzj1IzcrChrwmrBIqNSEpOZrOknC75SZiOKZlIvuAAnKB5yYeqsAtJ+HQMgylYUiv5DszDkewkJQcLJ8Wk5qVw1VLzksLBladg5Y7UJ7w6ACBUlAYcd0dunfDqoUxfJVJAMEwYs+QnlzMms5Mwlim1EqyhFC3iUMTnFOlQpEeK6oUF1BusW46oU6okDIJD1OrBUjxL1MH661ArTAgny08QCZHK8lWUO044OHGjBwF7XEk4wLFCwJ4AaNHCV3kpqoHD6MKobqowENlPiICpnpCDQFTfk+L7B5GhQFQ/H0SPsX1hVC2LAKlaFoGSKjMWAlN9FEyq4n7gdKpIiPosP5lyJ4mEfJipk5ASOp2EMxHVEi38baRYJuS9DCBo1SB4qoeYebJXT2eiXjil6LFwYM1Wlp9KHZ2hUBVW7b4djp+hBq2H40eVTvJVVxZWosLjqDA1pzDtq4NIhXTMQZhx4U/SM4gkuKCHn5TifBXYRAQLKQAqZMBYkDQsrZGGhqRNR8ES8ZAI8GotoAWNIgHiSIPUQOHzLMk8TWreIh0Qz8zyZqomiDK0VDV/rXrIRYm4QgjJZs+EPy2kB9s9eDjR0gHwLIEJUQG1hQtPU3UJS4n0tyDNQ74O3btZrNsMjRdFjr+e5S004GIAj3RLWX60mqBBJNN4CSTz6JJwheEePIdkGggtqycVo4UDyTSIZBqknrq102VA3yBn/L+dTJovRibN/0vIpB4hk/qCZFKvksnFQq+RSb3kLz4wmbT2DyL9H0gm9X8QmTwayORiwv7/DjJZHRXo5MH/RTqJQxAJobnH1ezlXm14bdVOiZveAP8s9ZPqzDWURDK6sKVeEg3kaIuk1bfwRpGcs7ok7miWL6XZCL9OVWwWdTShRQD9RSIp4utToD45IWWu0eqwrVVtW12hRsDSqotrUCQIpoK1QKlKCsc9nTlSo7+gZOPfeeVSEwaWM5FYxhuQSwCpt4niL507Gs3KqM7KroJY7malZlYP/VnQ37V1WgJk1vSQH6tdgEbkxDm01xYxAkCsCgCpeXGgvzHZjEIKmBeZb7vNknmGsJHDgY7b4iWI2aSoCdx9tPCjPaJaUBOOZLwnBecOdEJbaqvJcjDhJYUt/7p3797YhJeSDI+moQOCGCW/Dmi23DpRkvecP8uEIfmUgOvT+hYOsro74buycXIKTlMdHFksd6VLRbp0Qb9MQbuSb+CBTCLaQOcLdw0dGHAZq1egD4fu0eCeJk5qOypSsRbeDHfDGnbCK9Jw3x5lR3GgPtUmBqmuyI7iNWEPP9jC8OyQHdlr8eIuNC7H0LxsD/mh/AjmBL8O/oCRFy7hYHRiNwaQERxCl0wYyG7qCIpQ1cEt04ndxEDGQPsT6D7hLhpeXE0Et9ENuY4B+pG7wXdBIuFAUuvVNnIiqRxIqq32zUUBVm1fjSnosD11c7iODgS1bg7X0YGe1s3hOrYwgJzS7tUrcgqD4BC1egacZ0PUAxjqkZoiIBtETLggj8WU4QMnjmcnJpMltDdUgdvgGfDH9VyqgcNUqUoMJOCSn8ryhgiYbNp3ApHdg+aP/YAooUBkzwdRyDmiMKqWRYBULYtASZUlFgKTHQVTKMUvDCdBqL2Uco105F2RoiPvqCOPsolORz4VYb1W9chrCmWcUCwzFCAcOvIOHXknPPJm5MhbdORT+5xLDc+lX09HPjH3yIM8Oee8m/ued+h5v/3OOe98VfVwM6IceLih3CGkaQSkcWpIIwhplnp2WAfBbgmH0MZCWLxdGMIp0R03gRsADOfbcZv3BWiiClCrKtIkelLmAsA0a8DUIsBM7A+Y1hz6yfeln3wePLUoPE2EZ2JheFoL0E8GUgzYHRNkYXGIGO4PyLEW3kjlNtLSsDBJhe6cQuYrmkqFIibTyACFIWxkqIbgisFylV+Q8d65mNvTi8FXaDYx5FvNsAMxdWeulaQ2BZ4oksysVDgFJB6UC6ODs25xPvw1tvl1gc6IvZ2YYpJ7Nt5MArWFvE4eENjIVo2S2/z6QLexEYdKRq0R5s1II1c1atjmu4HuYiMdKlm1RpiPRRo1qkZLt/mLAh3vbSXspbRrjTAfjzQSqlHzNn9xoAtsZEKlRK0R5p1IoxbV6Oht/kGB3oKNQAyWyVojzKcijdKqUfs2vyHQ09goBpXqao0wXx9p1KkaHbfNXxLonWBCRWlT8ooHutSsUfJQwcJbDKv7gzd/5Dv3fPb8q59mgX6rKazup5//0SOff/5HTz7OAv1mKLj+hct+/u3Pf+OuH7BAvw4Kvrj9Lz94+oPP/+x+FuhXQcH9H57dfd+eH1zybyzQd0HBHf/nkzd97Kuf+fZxgb4T8u+/74rzb3nm+idbA30H5Lf953t3feevP/5tS6DPQP7yS36/66Ff3H1rU6DvMYTVfdPlT/z5mzO/uHJRoD8L+U9+9uoP/PDWxz+bCPSnIP/lx3+889rnP7THCvTdkN9z9e1//Osz3/3KxkB/FPIffeS8Z57+8pW/LQX6g5C/YNt5l/xh103/9sZAvxfyV/zbhx763Q++/f43BPos5D/9yfsuv+mjP7l1daDfCvnL/vChD/znX5964SvTgX4zFPzqP9/13J0fufy6a6YD/ToouO6Oiy6/eu9dj++cDvSroODpP+787rlfnf35RdOBvgsK/vDI3e/5xjs/+sS26UDfCQW7f/LYrud+++h39rBA3wEFLzz5lWee/PHurz/HAn0GCj7++2984c6/Xv7Bp1ig79GF1f3Hf3/P7X++73Pf+hEL9Geh4JuXnf/hm+67/ulvsUB/Cgp+fssnbrrjA+dd0hXouyF/3Xt/e9FPPvCZR9sD/VHIf+j7t7/wwK8+cs2rA/1ByH/3kfPftf3cH11+RKDfC/l7P/bNf//N1Td/59BAn4X8rR9/5rLzLrnuC26g3wr562fvuf17N198QSXQb4b8zu984NNfu+er97850K+D/K5r7vzSR8778n1vDPSrIH/zC9/53M23XPrFEwN9F+Tf+5mfXXDx7372ie5A3wn5c3fv+Nnt933o4uumA30HFDxx/j2/vPTS/3j86ulAn4GC23+945qHdr77tvdNB/oeLqzuX/z4i3c98YnP/fTc6UB/Fgque/InP//899/5hT8BEKDgJ1+/88f3/vqK7U+yQN8NBY//8pGnb7v6vCu+ywL9USj47fd2PvK1uy7/wAMs0B+Egk++7/Kfbr/23otXBPq9kH/h8Xu/+PCDd//o1YE+C/lnH7vtwWdv+MalDYF+K+Sf2XbJzEd/vetJK9BvhvwnvnX9rr9c98R33xTo10H+7iuv/u3n//rOZ1cH+lWQf+Ijd/z5P/c8fsNdgA5QcNfdT3/9wr/cd+7NgA5QsPOJB3/7nQd+vwshAQWfvuvyP950/aVX7gJIQMEXrv3arusu/9kLsM49mrC6H/7zBb/+5u3XP/oYoAMUnHff5c9/8rKPffkbAAkoeH7bDfc9f/E3n1se6Lsh/+Stn7vz67ddd35zoD8K+S98+vOPPXXrL3fXB/qDkP/5N++4++fPvvfxsUC/F/Lfuexrf/7QrT98oBDos5C/9IFr/vP+m154QAb6rdj+mitu+sPD5/7ucjgYUPDZG3/46M/vuuGZGTgYUPDM+373/m/f9MHH/wDEAQp+9qu/fubOW688/3tAHKDgoiff9+cnvvTTXc2BvhPyX3n8q5++dPcjX10c6Dsgf/e2i3a967u/Pu/MQJ+B/K53fvG7P/7hBy/59N8QYjMQiyuf3PONh3dfCQgyDih+1Qdvvvwr9+wB+jQAKPzrqz981zdv/vTfjsTZQIu+dO1Fjz73px8OBvqZwur+zJ9u+dlf//KV3V+YDvRThdX9wDfvvPJ973vo8t+zQD9JWN1XP3b1bRf/8d1fu5sFQB/lrFmC6xrzBYeJpBdHbbDOO1jUe4cI12sUi7xDxWLvMHGQ1yQavKViiXe4ZMAi9VViqXeEaPKWisO8JtHoHSkO8RrFwd4hyD5jeKcFSs/6lE6MsIQuL8IRdknOaCBL2SU/pbhoShqlDs62+QcHOjpIUQO54/xZ5r6OOdjMT2HDlLRLy3TmH9z9+gvFwd2dF24XB3fHdmwXKTCGLNPZVpGif+WzTNVcDjV1rGnt2L4Vh3HEkaJxuzjkPPh73nZxSHfzheKQ7iMuhOShF27fKpq6Wy8UTd0rLtwumroPuXC7OHyrWCqO2C6azhNHbN8qDt4KIIRLNhzWP2yrd7A4TBy61TsMXNvAzTEuObgAHukdKo7wDienIFvOmChTcZGAC8gjxJKt3hKxVDRs9RpEkzhoq3eQOEws3uotFkeKRVu9RaJRuFs9Vxwi6rd69eJgUbfVqxNJoZX8JHjhcLRjxOFqc7sCmCWAQQpLAHMVlgDGLCwBTF1YAgQCsJAGOjhvgSAiLFGHQLGEIUx3MawsQUJVTJjyqbBfW5jyUZX5U1yrAzvDLBvy482w3VqWJ/dnadBQD3Jod9sjdoU5SgYXcTCvJufLxPEWfpyXmqNkJGp2hahcXFeTi+vRVh6Ri3WR3Ed4Ta1p8l0QXzWQixeFcjEqZVi+KJSL4VLQ00R9VC6uq8nFC3a9j1wcB6sxmhCSC5oQYFXVa6Sa0cDwHKdqRGEnoepvwxELjTZuUp9rdkHjEXoFNtLONIg4eTjHJF/jLZaad5CuzG/RjpbibKAUuwklbdBd4mBoX4omOMASUpDh/lUZnqhCs4jLpeD3HZov4mBMa4A/zb5REgfJvee+EJuQ9mZxkGzcTLlylpFAb6DHB/TRUhJxUtXBatkgDFepQTjnONgalyAoIH20Qpklcy3nhlhSu2BcQmpHUixBmPJVYjHWPlgs8erA9ARoGRkyKepck4ZqITgkQ40jjgjp12V5GtLLeDucr3Aeok4sgf+vaaJmBlgGXEBGNNigZcCQL0SQFC3qWsk3hLGcMTra+nJWU939RWLRcoWWtXIeKeeRcr3ak07mAmXIBwdDX0PEdRFxYVDPwPFwEqqVFh3aM8ACCZjvgvHARePBwTCngwVI6Oi7uwQW78I/jb6BFtw4eKwi/EMkaPQMOP1LUhohFPhvQg1AHeiih7aygZRM2iSoKZYgTjRCYagF4p5otCdapHApFTaEJtqDYOYHgWK4PqWD31FER3SU8TW+jNMVHZr8Qm0+Qfo7UKvZqn0V1cbLbc0M6V6sGcxqUbo317pqkHW1sWZddcmy6pbIEKULwz0E1V/PiNIBdAaI0blFi6EbUgVtnimxseoMILgwqqbERrJsaeGtlgHBCmDlErQ/Mdofu2bRaPQMND40+JowyMxoh2ZGG7beDs2MtrrVMoAtG8rMaNfMjIYyMzaSmVGL2hfj4dm0lfEsDitF41liAeOZE7ULo1Gi5Nvz7Iv2vvZFe1/7oj3HvuhEDWcJZThD+7C2kH0xAetP1OxmzWTmFZRbOte+uHSefbGZTEUC3LkVOd3XvghXuREwOVH7olM1wS4EIkuByJkPoqh90V7AvmgvYF+059oXrYXA5ETBNNe+OB9OaRED08/RlGsRMaCxiPhxYRLidyKmxYCeGgS/FoBfxPid9ri6WKAT4IBDeOQQcEFcI039tCtERUb4dvR0ggEdOGlUQ5FzZ45VaelcqxIVur46c1VTkyIGYMOL2phC4rDDpssXF2UiydQVCcDWhztIP0mEfy3KK3xKmf+SaAKCO0u6zpSGlxBJabgb4ApLEymvHmaUQhYWspMkij7oPJ/CXvUJbxEMCd90YEOJkg/nNFHyF5NckhQchRK4zXDAJWIx+JzTJ0CT6FcbvtrVr3zu1yR8TVa/6tGvcNVP8wcJdVGE4WjQUcV34HMdWEYJnxL7zg4cNrwETtJzcHJeMsqB4nCrnBRxUQd2xjjcnRoE6Al0bcc0n/KTIgWKRx0OXPITcHfG5LQUcKAqQ5UJGCwOPhwJCHlIipTcs40EXAC4qD8RmZlRcjugmu3VwQVLCjrDkAuYCJC7BMYswY7iPWVCJImvzZGHkyKxnricreaTFEmYzwySwIpsnKyUyVIKU/KT8sgJuRRkW6jtcZ06ibdwozYth/rj1fkAsOAWNAlkAlQM6ibk1dBaJIckxhxAF7BR8F9i00kv6cydoQldJenaKtnNAhwEauL+4kcpVBMIunB1JCN+EuDPRWwtCuqAD1H8N0QypUkDKXwCmGJSJOQOpVvMOQFE/rxE7a4WaIpBxmUmeAqcfyGgUpfTksldqg90AozJ6yB7KItsiUjS6bdEkkgIBXZgJ776xnGDt4K3Kw8RJllFmBCMVLsKLLRmo+TTzJw/xjRLUYJkM6MQjpSsBw0lJZ/du3evNuHXy/FJzyU9KCXqYUxvUYgtuNmudBHj4TKo4qV0pAHG38771pKXEovAbdUVi2CbFwlXgOkYojVECi364L8NunUkY0czyWjGjWYaopnGaGZpNCOimeZopiWaOTqaSUcz7dFMZzRzXMmrFykgt15KXVAgAoPjUQsH1/R5V/ezgJhKeeGrpEDUqSe/BbxOrwftpBH0NxCq6gGHXLhHAgSuJwyo7773pk99GK7ipY3nxibUr0fUh5whbSDZxIjgAEhbGO4KOMVJLwV/bK+eLmgrSIdEPRELiGckbEvCnSviewoYV6oqPGL0XwqlM+gZXCuoZwPmgj2rcSKeEDCOGx3HFK4ah1yBasIpXBOU4EzWCS602jiaqKNxqgOA1Ei6rWS43DrSsOtAw3ZEHWrYwG1QqQCs5VBRxDxXB2HTldddMAvKll2l50CKhAsAo08olFS/uuB465J7MoeFlPyUYhyaSIHy4eBy1XAipijwPsOQqgKL15A9wJEG7Q8Fc6sk6koyBodaW48gw2MePeOp6hkHksSFJnddoMiJIYU7hNQCYER0KLmMGwE4Iyfp0P/G0uxphDW68JeA4nHBhnwMHNTAJxlMA75Z1QtBeosp3ZALCM9T8ZkaRQEP+XG5dW2tVMeuQfDmRzMm75p+QwmkxFIkw6MZvZrxNLgCKmF4tYNdAEMRmuCRGtAVRHQCC4mrinoJAydD2VVprrUJQAwn4UcM+tKoLyjk1UKuCkFe00qeDdI2XNoCaTflrIb/YLwGwERZEXnFN+SzsQlfowBT9N+H5hRuqhM0iIz5cTCzxKbgsmmqLM995zZ7QmhE7/bzzdj/t/194KVSCeADtiBfE4xIkiliQ54JIilY++CVALJqCE5/S/LcGWNYulMAcxsBCQuJyRe0CUJAGE9T42m18RAJFGwxoGLBycK+KVjjWwFcVeLzKoFfO05tKax+gZmRrojArofK+gSeazQMwwdSOjRQOkyFj3H0N2AQGaBVsU6L4qMWxUctio+2MFH+8ONkD1VhpnGyRdhCi9RUJggOAiCn0FzneUuzpvfxbmLAtQ0AqIkRJ9KQYLXUJ/xYk9BKtIJXl3BFMbrNtdemeBiLGqeoHN09yOegwtc7hGvwaMTalC1docObDhD+D086eHDON6vesGsIVImBO20MWNUp9GdIzszYa1MxQaqDVVIXzRYRolg1jEZDxA8DaRj5/piST6TiwqQwbPJdXJOKgw7qLoGbaaEN+THSBAxQVoCaaRNlyUGYBEgCssaq0aom9GBWw0mEJmLuIngxAax6+zQ2obFWCz6xnTBAwyr5MZiJTecVZmGR6d+A2/IJEav1EpvbS+jKDEcaJmDuO1+rGqYfg3EwUCkaWwUCnhZGwlv0JkAM5QOcBryJsd+eVVAT9RYCwXIXqRide6uLW5syMNwI6A78Hx7x6AljwJVtFnBNp6B/wDVUQXTCNQ5+rOATZp+SsuTTO2aZXCGvuHCWuWcwR95A+cd2qPyLfF94OSYBXgvhCjC5NwzCfRBeB6DnLeodobsMDUuoG22Vm2nqYFhowgcagBPqoIPRGrXaGjUQWJibcAR3rrI0YxqxHiig3EqPlGAkX4leiAAuMoHmCT4B2iuSU4hHgiBBYDgwXcJxUGPw9Qt9AnwYNAoDwugfKqeoMBiItglDs5A8Ce4u2ScQBhv5MDDZqtSx4pS+nyLVdPUchbsEesHoqioSsJJQgkQ4wdqcAISC7zOlaiNHhfVhkdtIcUcQED2NYtgTEKmdsqDwhktW+fBgy94jPY2ipLR5UVIMo7khVR0ISXAVbNVIsWrsmENh349BGNQp4DSkgp56KA3hUYZctiZlUMCmhgoG7o0u2FqKrsMjWloDb6SAmO1pKlhTmxebRdO7Jzq9GhyQZhJvRqodxpACT9l7JP6ztsnTVL9PAGCjAX16Nf6RU0CfLviC8Y/6vvGP+rz4Rx1GAAaF4qBGL4xoGOVXG5R2g9FuaC938mDxxZgzOY1Rb46z26yqfrFQ9bNB9YsLO1T9EqD6Ocq1ViRQiE7OUf0ckDEc1NF5BSUmG1Q/G1Q/G80GaBgxwDDi1FQ/u6pE2VHVz46qfnZU9bOjqp8dVf3sqOpnR1U/O6r62VHVz46qfnZU9bOjqp8dVf3sqOpng+qXELakcNZEVPWTsbX71fvC506AfYEWhxYSYnIqZWM/VKfRtxHB1aekn7wRbqLhXQxfu3EbJJb6/MYzUD4wwSgOBjE/IWwSqiWfKp2IIVmb1qZ08GBfkyLa5wgaGeJudNQD3df37GfWpuOe7DvkouqQx7EDWGXA9RPyO2nAtxh502EOrhTQfow4AyMPuccIlBSUUhIjpYTettpnTH3BUr4fqEobo1RuuEAZUWKk5zxmcntaO0e+Vs7M3MPojFgY+AliFjqVL5mSmtCahInaA8qxMzMzxkRVlFeGUTcUuGz09IIyrBKjaF1l9HbI8UDoJIPqMjnhJcNyeCsFXCpLfqoqaaaiMmgqKoOmojJoDLzyQQZ1aiZu7AptLTGRitSELr3UXBt3vOTFIRR1udCa1B1kXLCVWtLX1tfiG2Dl9gSy1PgQys1K5VOfD5+S+kSdwZnG4HkYEovAPx7Cp6c81PP37t3LgIJAGLCHofTMS8AcJajiQJf26hPrUxzuP0P/g4SIC6eFu4tMcJRLmqjOsWXc9uuuhTnt3TIhb56ZmQHyamNDjN5P0CsgoEXDxqxG+10MJ4+joN6oxuXzh5Tp6JCG4+BXulCl3uB6FSRNp4UnF8Xw3TIPnN411Tur9g5CwxovBTx6PTggL7gqvTpEtFMQXuoCbqMEX11ZjFaWqM4Fr6sEBsRBMy/liJTzH4YWIy0eEVsHUM1qE9WHdRTmziC+o3C1Uqvz6caPk/eietQGhBTbM5OGo252GGboMidU/c3ohU6SojlNhdcQpRQr+XYVe+0oXttRvLajeG0KohM+XtGCymk42BUyElPYkZrQJailgNcW7RlMvIST4wKu6gyMm7JB2Fydouh182jGfFtOn5yiwKlQfbTl2etTFDdFDxSY0pBgER5b21Ty8CE2cwh659UR0bfBAvnWGvJMfIUITpBN71qYQ6iBRm//9ciJ4eBRgpenIl49MQaQDjoxtOUePXBDr1QIk3DXUGdGw+tIXISJgfEWYRddGeNH3Ep8EA+RC+YDtpDIeaA+fUPwNQAwPBgL9cnxGpqhy4nAR3YMhXsAG+cTlrZYRe/wZqaMmbNMGu4ASuXymit/9ed/9Qz5kZ3fu9PwTHntlb+41vIsefFjNz/6v72YfO/1u++2PFtecNlDz2/24vKzH5g5x0vIX95//22W58jbPnfbrdNeUj76g7s+pXkp+cEv/9t5ulcnf/jOJ961xauXF1z/x8/rniuv/85Tl1reIvnonR/YsdVbLH/85d/9eKt3kNoATZ5aqluk8bn/YdyRf7l7z+WaZ8gPffi8x03PlL/5j/PP8iz56c+/5wXNi8krH/3LD7hny4seuuzRLV5cPrT9w8+8w0vIvdfctsP0HPn73z63x/SS8su/vuv6d3gp+elnv1vx6uQffnH7z3WvXt7+res/udlz5fYff/RH/+otkjve98ePTHuL5S/vv/ZByzsI5D0Dogx1UH46ISgPQkPxRbk03AjCVaQO5uAWoYPlt1nowFqF0IHfLhU63Co1Ch1ulRqEDoZfV+gQSgIP0i2ityEWkx5yED0A4hLT1en9AJcshwpI0yX5eny2YiHWKvTuOx667A/fvvynv/3idACz7b5kz4ce+s2Hzv/DT1gA8+3+1IfPe/7jX/rDA5dMBzDj7h8+85lPfPt3933MDmDK3Vd+7FMXzP76msfeEcCcu79/443f+tVnv7/rRyyAWXdf9YEdf/rpd2e/5AUw7e67L/js/3nssh9d9Aje4IANS6/AKrnUUPbhsqF04zZwDQMzLL4rKrhsLLlQxQDIoZ3UBVWmhdJJctbhGB0hm0uuK9AGtdQ34Y8AfQXHAT4vZ5lr0csgjfSGFLyvGd6aoOUKSwGMeNmqE96Df5Q0oDX4a3B09ndmDc1WIrYRBrnBTTeqncsZ8006pCjY4AsY2hQ+mwUHDV7spDe0LEVm0expR6i7OSun34BkDIT1uJzRQDaNy1ntxBS6rsF2G6QqGt1XzczMWAhlsLJUSr6Jjl8ofiKXAhZpkiEBSRr2agoLiAnZWDx4fw1rmVQLpEwA40qm+TysaSLhBI1RI0aiWKKNEpXEecCVM5oJ0E4hTaDtvIVpXgLIbFwFOJBq5TmqKbISeANvJWMePfcFhBvBSWScI0GH+bAwYsyUKNQs0EOMAibcCbqJd5A2AmRWagZ2D/Kx+hif8xGtUPFjtTrw0DlWSwoDzqk9F+Roj1kY0gwho4eQcUJsgPtA4C0GPe1hOrt1LakwiJOSZlMUDXHkCC5o1bhLHawZltBH4Z9xyU4sIbsC/SyGDGDvXn0tOO6El5waWcYowEMS5uHdJnJnCh2DewbJqs/zQHCQie/wQFSZ3oPiEdrfhT4KdjB4LnN4CAb3Y8I8JUV3KxKVBbzEhYMJdmhYET0Wh5Ki8oYkwyOoLDoGBc2P0DqHAjI16VaFkAT6ppEHCe5qhfzETKATjRMVESuXEQDjwOxSaPJVfmZJ5SBJ3ieNSAyGoAFsjY2qbUngU0pw5eDFQVAE9c8SzhAKI6bjyFvAD7gPDWAxdLYICwAyeL5J4wnjZzkpKdsNjU8D5y6FD+7ARioioSvbFCP7B6j64ZM8LGKb4nNtU1r4Ng+v2abw0kk9uaOpiyN4p2hJxDGLBuZkFKsZpXjEKAU0avOEr7tLyA5TCk16uAJ6d4bNMUppoVEKms6fS7URmvSrzydpbmP9Pq8naWhs0cPXk3RlbNEixhaNzjgW+Vr19SStZmzRqq8nafNeT9LQ2LLAS0AamsXoJaDqvKNLnG/hql/omSDq5Z65vYSrr3ecR3Wug66q1YyTGNxlIAFEVUsTDDzFrW6x43XqnUlNXdJpwnodmt6MMLoLHlmlFrZqYRPBpWAvnTq0XgfXrqD/U3hXrZWrWrlEhynaq9aqAVup+K5aq0bVqhFbqXCvWqul5FpKAV61VkK1EkTOKd6r1qoZW6kIr1qrFtWqBVupgK9aq6OxlQrxqrVKq1ZpbKUivmqt2rGVivGqtepUrTqxlQr5qrU6Ttlc7w090OEO6FaVeYZr5jToKIjiD+z9VxDA4P9rm3w2tB4FL1do6r5Goye4SzJZIjs4oMvmKm3AQGpBAgQ+KwUXXcRffHi7DDhQCZ+kxVfQ0CqMtmwYDt8rHfK1IfIDDJ/3BnaFFihlsgfENNA5t3bvABqeOffOhStf4FolLjR3EXJhfKAUulGvbgmtpA45UjdNDa2hgRtjOU8JLbtzpqGD9R4fyaq9B83VVXjtSkR3F6kXmIFLOefqVcOmPv9pATT6k8gBChgCEFiv0aPUJa0qbdETGOQtS3IW4sZebULOkLFOWCuhaQlf8DVKIFlY+NA4XppLe7NvS7OCGrgxAaGDlbI8fKoEh3RK8okSOuBDwrOE2YPsRkURouRlYDlc15qOsHCWJCAYGO7griHhCNIa3EGzk0B9NsAfglZhhF6ssdpLNGiuUs/HWaQiwBMAQpfPXjhLpgCI0Ja73jXL5CNQXl+SOyDz7LtmmdtFvqDKKVlv4bZSLJZxFNh1YmoX6nj7Aiy+9sZzlb6DF69e9QaG62n1VjC+HczpwWCtaqgg+4YWPg6oqRcIak8O8yZ0LTVg+3xQEJC3Ao40+FoTvhEAvWthxxAqrB5uB8mFThaMEnmFWKNXiGGJLjg8aPTUd4PgKT2JVgqOh6Anhf7i+NEVHB+3pmXBpI3VqCqomRuKxSiuJs9BnqYePScOSY8X4+vPGt3gadAvjtboEIBqHxrUh3qnfs7rxSzyevFDnB6HIx0EPWVhIBS4qv/zTYxv0ZC8YbiCAXIZYb+J4iqiJhgBPBtxCi/53A3qOXLVFIU0kOo9uAhWES4JFfmtoa0SnK7h+rMWhqJBGIoNQl418jsZRrgAGxRYnpwX+Z2ICnBxRGzofOGuKXRmTuS3qczQRFRM9QwGUie6b9eqWh9J43ibZwLmf+tFQfr/w/PlwPPrXDNqHNKeIhPSkSi5gU16PbKKVwl9g8/pZy241Cpl+bpJvPDtRksCUz7u1SMMJCY8Ehr8RgHqibp6LRyPmmChEAgUsXqChFHGs1MfXtfoGM6vflABab8RSprwKj3HV+k5vUqvY4SKrh6T9/Guhvfg3a5WgRdFgR5FunKUVUG95qmkU0YBGXPe9qwHsuWWwgUY+yzA+QTnGv2uiIhaHBgFe2jwRyM7Cpr7tFNSnHRVpbkmybdCJpWJhXI25lz57hmMGXNLQnePRz1HtcQ5GtSxv//2v96nvfay2n/83AO2P8DM57W8lmuxmlItE3AfizuMRm5DPhVbk6JiwzPR7gLvIJZ8S24tCRMeOZU7v8VGfRP+DINTC9oGnmCj8CpuZSXb/u5Vx+IjWufgQ3XyCTZMNtjoVx2uSOEmeO8NP4OImKo9Iw6bZZJ0R0PLc0q+DrdpZm0ktmCv1Af6fdKD+KqDaewAfg1mOTv/3avwzGoQvAC6FC1PJoQ+5CbxvVy0PujOJ7hmTdN7yDxk4XBjBFqKcsOSM+56VDigQOrSwDNrrGnCh2pR0CToCj5EMh5Xb6ktp44N+qkSEHOM6hjk//T6CTkzXQauLq0pfCdNDQfbewzT8NXHWa18DEN1VpPJKXToU+2oARo7VAvAdosaxuTuakP8ptM3jt+oB6qpycVTchzrkhuQIni64CWkGs7H/kFgclf+XwOiRzVNU0fOaaaoDnnd+SRwYpStiSYjiJFIuLGa01ss8vwKuaPST4WoIuXlpNeK4G4bvdHwjs2nEBYAokm3PBxosKOYsbJ54Q9wKBsuJZVbp3sW2OSkURV4I9wNPR+amfO92srw7V3yVY4uKu5aaiV+XNhuf2118XAdttyGG2q7Uyg9kk7P5yyLHlbDO0BaFj7taSWVf7XHqcCuLsvC14PVsjBJk9Dcs/BnGgyhzV2WFYbFNDPn32vLwrDpfZdlu37ob2vWHp+jV75q4UnVpWjhOjAkwaDQEXzjEgNWUBUzaB1YYFLML1ohzdo6MEkO5bp7FppHDKGrdVhKeSVLHfk6a5wTS6S7KuVMhErZZuDkaGHXybh5tOBhBGQzXhXIO8B1G9m/jsJUCzfc+sPItHY0vB0qIL0MgoaB7Wj0G0GaIH9rMteClIClyzjoblrtvVUU+F3QDALdhls0SbEk4NxqyCQ6cmt4nUG98mW6Te+03nEhvMpvkN2KhfcNt9c2zWpm+NbjvFPmvpyDZKFBkH5gy1xLv7tlk9kDKnoGFXAl0xr4XroR7hQmcRDEOH0OxplKv4fJW7RTs5rGp0l0oUB9CGQQcfy9GbcO42YRuSH6FiLQEXEa0cGkNmV7v/dYcQgVAzSGMKY5mOxqFFFkY3gwPohggCgZh3PRSBNpAKutbIC7o3j1DsgO74DiUsAKbqiB30RMM8gBTgX0cPLrhB8lCkvB3FUi4YMuWemoVMD3H34RxefShhD1v52nc+01TXCsVjehSUSDjxiY+xryFYp6EIGvEHWPR4vESBbaY0yC95c0TVd3uKTFQKCvW0cCBMDYJONQC28g0d5YxpMKmAZdtRkUL4mCWLMw4ILNCC/YDIxzhj94ROThUxg8BgWuD3YNHe/ZQAOiEBSrRu+gtNGfTwWFQVj+lRqi2M2MfkXMoB8mirl1+NM96IWDv/SDT2HBDyChFb+GKLH9Igr4oKFX2jxEcQ387SS4xwI0gdeKEU1sitjBaQCa2IQmdhVNYiGa2IQmt9TQBOe/D2l1/9dLIqQ2OpHSDzphSLWNp1FdgwGfwwJNISLE52g1PodJigCKuWeBu7pUFxcRux367xK6PFKDuhm1qknNlWCuqtMNjWnomHzpxfA7R9Mng0cOmtGyUARfdl28Cq4z4EpGIOltWGQA+zCOYYcDShzDlgKFB3iawkRq56p2iLaQvuLiVdQO5BKTummkLFF1YbrrlTkEhp0Oh4fA74suVrQOLhlyzGHOl2vrQpqprrUAm0y3Dn+xjXgRYBPx7RZ8gzCKTeZ+sQl+9IwIcQ2bXPLRALkDMAnfLsc3K0inwykAJlmESVYVk8wQkyzCpJv3pfcRUt/ykrDIIiyyqlhkzcci66VhkVnFInMBLFJE/npix2igNMJ37G4U+o3n+Ha32HEt/oxfKDXb15JX08xmeg6g+iU0KBLTMoU2DCq3e1bEN08YXizJITodAL4BY3pSIQlE8yGSDXTpkbaHvzISox9tUJLQ/6fmeoUi5lGTFGKC27zgrpNhRlHftaF9iq6ZaNeNUAjjdDMEx7MmhMGuA3r4lnsWiSvqdRXc7qpcQpYe57L5jPHvZoULcsDX/p0c8ILatPT9Tkt/0WkBJAxIua3KtZ8mAtZx+IcmoiOAaxNBRwH9FZ0IyPg2TiTzd05kh0avhih3AwyH1JRMQNfD5FCjrVVhXHOkQR6ijKakQbMmDWIScQtRRp+DMlpEGlTX4ts0prxl2DrUpCEKm+/nVGnw4zruWeE7Ijo1UEcKrKoYVkalQEKrx0qjYwUexB754FSPFd5KX6BRiL7ymcALefgB3PB1hLmTAI5QnQcObeDQBBFlxYNfY6IrZLr5rRYrKEgVOYKJsJrz/qoaI15cjVlaU2Ma0GHqgGrMUl8pIXwZbwA1xp6vxiygbgiFLSHRwR8xVtgZ+i/0EI4wxTG0Kbqt4xS48jBMCfGYYheWM1uw5SzuvhpNU7gwrEkWTk0+e4AGQ9GJYNDknJNC56R2UNEF5TXKaYbOCKhqCERESaW4Vc8I+s3R7+04G17SWHPP4t8zztkan1ZXli7IzmjpA38avgoiuaZPJB91dc8HteAScLyE1mcX5sCEMbS6yYt42EdZcm2AMDwKXTuMfcLDWKgfGhiTuDZFUVb1Lxoa9laiaiQxAuujm2S0X5sU5GJSSLspD6HIBmWybRSmbKQSsuD+ECy4aP02wIL7NjX18KZNOffrQ+E1MndXkO0M6RNXplCw6fSkFBbp6NWjhS+0VK1Vzlma9iKdR+2X0QFqfSP3ndexZM56dR2vJDRdhAqysNAuZIZgVmq5pexCFtmF5mvpzptfdKqhDfFlTXNd5BpBb2YUn5SkuxPADbLUAwjD7dIR65LK3v75mVm6VxIcdusNmg7XO7qSROjX+/A+my7fzRNTSJbhV6fnhND2KD9kA9yrdUfoTo8iuRQBCwFsB5HUwlymKC05FmxFVy7l4UBFTDB6Eso5TePT5GgGSsJVF6ODUSkLKTxvcGOHLIctB40BPY5IeaAWOy5ehfXhL0fe4pwIXlwavYKAvmw62nPsiD3HDu05ymuBfKVumENanWOrZ6YKeDf8pTddasdqS+muGUAOAEcPYNAT3sCcdmQLg0RoFG8Q2tBa+kH5nhRzK6j30MNdjtDqHeasCgk31V9NYA3JrHtmPfnm4drwRl/9lDehkFvvdKmfvJ25By/MkRVW4x6kVl8NliFeRy8qgoerk9W0aWHhr08bw9Jw4YdVLEFZQEox3zwM5tMjnBVIkNQyFacQ+hDFBuI9PQgYqPjAAtMwClhDzXAUcNCk7L6juMc5R4fYr0g8W6Z4GDEiwQMk0kbJHWPOUVXJDQUmOijqEg88lN1R5izVtGnEBhexgSE2uPRMg3MEHmFiIZrUTgrPJr0N5xzBqufaANTbeTH8BB4XrGmRwZhzpPqZRoBBec4OUvOl0d575vV9CJt3lOrxHB24UUOITIhKQ8xx88xZxMitrSK1Mp35xSw8i+RU8nbHOQx15GX8mh2rArriXMavgDSrfro08uki+nQwWygMwWmoFoeFJnNS1c104/XOQQscL1e1UNh75vyClOOEM+6sdxK0yMnDmFOPVtcLQhcZZxGT9184y+RRcjdwu+NVjZoTjVPH5AMXzTLZJvBzUvUrbfckx2FHMQFvdB2FH8BVSkMyHKcR31gfplZhCnZnbdgHuNroLCwexGVrcubds0ya7plq0tJ23+TEmGDuOkZ/25njgDahHcOe2rkK+ycnGBtStnuCYzJ3I3N0Vu/ocJPIHOfZpMbQx975RdI+/SS5vCPTunKWHTw5PNhXFIOjheJmMVnJlyuTIl8RsynRu7EiiqMFyjJVY2xjRYz1i96xjaOFyWNEZaAohoujYnBSzC7GFlBCVaGMLS7nRzcUqWNVPuuHvdC3/rGyoEmM9UNnGyoDYpa59LE4WnhZzWb5MWKWrSxvnKz0rezo6kwH2Uy2mOnPt3a0p4PW9t7+3nxfpr0QdAXZQlBsz7d1ZtMrhwd7y/nylpX54eGxvpWT5b6V/SOVFeXJv7ujyUoBu5ncMrmyMlAu5gu54bG+/PDK0bEc5Sf/K933jZWL1D8AYOVgpVj+r3RXW3Y5vyk3VexbOTJWqHa4slAcn1xZGB7BasvTK1pXZDJYOyzDqpWR8ZWbejcODhdWDuQnB/rGRivFzRWsNzzYC1UYG+IuSzDGuhljdZH8Gsbwb5g/gzFWH8mfxhg7LJLfwBiLR/ID8/L/whg7PJJ/O2NsaSR/DmOsIZKfZox5jDGTMWYxxmKMMZsx1qi7zGCMuX/L79Bcto4x9nuTMZ/Rf2SlUhwZrxQLojImBkcHK4P54cF3FAVt8XLccrFpYHC4KAYrcCB6i4OjG0ShPDY+XiwwtkxzcW5DjLEUY+x07mLfZ6v5sUjZVsbYQYwxnTGWZAznZUTmzNScjcj84wqujlqDodqmFPwBxiP/8s1Hjj71N32b5SffMtH07tuWXP22N7W/Kfbw6ZtvPPeNz378z/nJyWK5Mjg2Kvrzg8PFwjFifBLW+Lp/Evj3tWJkcDQ3NlUsDxTzBQb+buw1jLHPGjT+/tofV2uf37xA+y8ZNM/outx561/EGOvLj+f7BitbBHTRPzy2iTH2fc1lp6o9B/gsjrQ5SMF2Cav9R2OMHQxzBaoykq9UYJcq5Txs2sj4cHGkOFrJ4xrKxcrG8mixIPKjolguj5XFpoHiKFK9jaOFYnl4CzSdrJSL+RFRGCyI0bEKY7uZy076G7zfwwnuPdAynU5n0q3ptnR7uiOdTQfpznRXJp3JZFozbZn2TEcmmwkynZmu1nRrprW1ta21vbWjNdsatHa2drWl2zJtrW1tbe1tHW3ZtqCts62rPd2eaW9tb2tvb+9oz7YH7Z3tXR3pjkxHa0dbR3tHR0e2I+jo7OjKprOZbGu2Ldue7chms0G2M9sVpINM0Bq0Be1BR5ANgqAz6OpMd2Y6WzvbOts7OzqznUFnZ2dXV7or09Xa1dbV3tXRle0Kujq7uvJ0COAIFAanBgtF0btFvKNYHmM/OdYYzY8UWdKZHMh3ZFpXbMpPjmj3HjvBirnT1k2dMpnOrascP1ma2Nx5xpotZ/We2pXrDGlKNbFmXaW7I9d5gsquq6yrdAe5tsktk9nTt0xWiiM9Wfpy/GSx96S+fHHs9PLmLbm2yUpB83JnrWsfGMn3tQ8MFfozwUBfR3t/V0druthbaG/r7yj08EiVkXxfJhgodrW39qYL6XxHOtMWtHf26K/KnbUuSysICmPHl7eMVzLBQGtXb19Hpthb7A/a+/KZ/h7jqLCr7HjvUKG/NRMM5PvyvfmgWOxoa+vs7Au6esx9xuvrauvqaO/tb+0vtHX0Ftt7rNfUxsukC2OrB0eRqmSCgf5if9DV1VZId/Rm2zqCvp7YMbmz1mUyEYKbaTueEqsRk7PFzeNjZZhvNt8XFPqLmd6O/mxvkAl67A7YBIDn8ZMbzjxxvGP9xObWdVty7cBd2iYr5Y6+sY2jlUx7YSyHqVzfQL48GX8tNDt+sv/k/i3ZnjWZjsGTc0Eul0Pek2mDVGE4Vy7iniT6XsmNbu8vF4v7brPzokAYHFFA6GzNFzrzHUGxN9ta6OvI9iRfRxPM5xYGQ/9IBafVRT1ViuVM63i+kBscrRQ3lPPDqfaX275tPF+oWxHZ4vYNxcoa4h0n5ScHACU605mgv6Mrn+lM93a2d/XUH0VbtZ8ROjaVBytF9/0awiGTaz75jGZizCumin0rVry52AdFZzSfeEZz88bWdHN+kv5E6kyOF/ty/eWxkRxIE7nR4mSlWFix4vTxYt/q8tjImkqxvA7LqKfjm9dgbyee0dxVbZUJBgqFtmxXpqO1L+htz2TaO3sWjbySGJBpKwxOjo9NFnN9AxtHh/bFhcVzDs/GbPsZY28ZKxcm4WB3dPa1t7enu1q7gv7e3o6egza8klPrHCmO5IcHN4zuO6kGOmm4gR196/vSretPOXN4LNeBfcEOZonvZFrpb25wdLRYXjL8ioKudePo8ODo0P4gd/A7gDIhSo2NT3b2bxztA47XAQxwMtuJSAVpxByotmLF2PjkihVhxRUrVo9CHVnFsv6xMv5tPm1182oobe/LDw9ngoFMpjef7+rsT/cWs639mfaeQ/77xk539rdlOzKZvv7+QltbPt/TWH5Fodw5OAryTm44X96wXyw9tOsApLcDhfoO1HoyWczkMJMDAeqw10PTMyc7c9B8vyShbXTjSNvgyPjIukp3Vy44YXByfDi/Bb40vZ06OCu3MDpmJyvlwdENuNLs6Sq9f9LzFiA9mTRSIGQQS//B/R+eIpamKPwRiRqHPDIObL57S6U4KbZpNI0cHbv5O9A1nh8d7BsaHN2QacNkbiA/WhgultdVuttzGUWuaXqn5rcMj+UL+9subJ5pPRX+qKqdlfxQMdc7ttlLEetRk/VbatSpqzpXoE1BR0ehv60929YZpFvTQc9RZ+XOWkcAC5RS1po+Lb/pzcW+NUAbqtgelIuTxfJUsTVTGMupdC4/WlDrAaGhL5PtQ7JXyIAI09M8Zxb5yYFwFl3Zvnymqz2b7c+n88X+3p7/9doINc2QVBNW7ki3t2Xb+1sL+XxXW7G/o+dVTqTCq9tCjnUAyBPocpsGKwO5gbGxoZY5ExvJ94Vj9fa35ot9fa3ZdFt/pqNQ7FkWr35+Tf4lQiqT6R8cHZwcyG0oj20CVhUEbcVisau3I91b6G9v61kxPDw1siJobetszbSD7N3VgXJ29rVvIRLRvh+UDsdFnM5kIoPPGXNfWBwdr4J/OSDsGuASp0y2vsg4QIY65w403vMiiIC0Kh+ewROHx3rzwz37zmjFK4d3XUFroasr6O8qpjuCrny6Z+XG//4Vdudz6bp5AtZJr8hudge51pP+TtSD45hv6+rry+aDvta+Qk/b9P8gtWrbUKy0B7nT1h3/MkdPd3fkOo7fBwaZLoJBKCkiCDph6bmxUcCMQr6tkC7m24ED9/f1Znqykrak7SWgRUcuS90P9FT77A5yQU8Iwf1xcWDTCC/Fq9dVurO5Lgmf8pWxMo3U+ZK0mwJpN10r9yuWZ8vFyY3DlUzbxtFN5fx4Ttk+TqgCqw0AlZ0PpUxHfny8OFrIFcnoAMSvPZtJF/p6uzryxUw63VfoOfZtrzyH7SIGO1kpHxdTOu4//UOHeV2wX9hF8K2T8I3Mzbm+gWLf0OutkeJI38j4qvSBdqpT7RTONAfWiGJBHlOTug6A4xkaE1XezsHRvnIxP1nsfqeSJzJ/xxHtyGWyp4MBqe/0Svm/IE0cv/wlwIxSuf6RygmLcrlNvYOjhQ3F0RydgZ7NtIr037eKl0FoYM+jYufqdaQDvZiqHOxX1c60EvKMl4v9g5tPWhxZnLI2vGHFgVAigD+53uKGwdHcxtFNg6OFta/en5CiiAG2GBsbOTkdVtz3GHT0jm0uFjLtvWObc6PFTbmNo2AIPmUT8boFVzpeKWfawAicGxzNjQ/n+4B3vbz9WHArerpPya1bvn9CPm9VQL7Xv+NlzXMNMtn2XHZsHPSu7Hr8s4ZwYAHVdqrY14akumcBWePU/pcLowPRIUWCFhjnjcsOhBaZKKU47S3/iCl1r82dfgAMqvLNVtrcHBqWz1j2Eg47kYs31UfOAtjI3oyWjTMXXkZ+dMtLm/e6Sndrrk2ObgkqW8aLucHCvqB9y/EHHui04kvo5MxjXq4NrcZG3nqcIssb9t/4tBOQ1XecUOzduKHn5Fz35hwUd3fmzjr6QJjRrlIhefnn//0/xgJARHvbRhq+9R87/HzC/S+datWDB4BwESEcbdbdmXv7S4OuEqZyrz5Q7TRWxWWdPQAV3/pScfilSST9IwtANN/xEg5tEF5A5cILqN63/mMm2J3N9a09gOFoIRlmdLLS2hbJ5AqDU7neLSgR9feAvbgVLmVyinq0pSOUpG9sfEuuMpaDg1vI5cvl/BZQ64rtmb6OzmImk8+3FovZng2vOdDGtYbbjGsfyByobtecukpqG+w8QJPW7NwmSDlDvC/N/M/JbNn8JJCnofYDCA74b6ZTEf3IAoaPpXmvfRGC2IH3iJRE4gZfRguKhS20YtCBunrzfUOVcr6vWIMeqByTA2PlSq76kYQIoJvzpIZ39HRnc2Ojr/Ao+1cycbzxzgOLaq3thWJ/fuPwPEQYGxuaQLL51jUvQcTKkHCIBYHayNOKPS9/27u7cpMvrp4ASNAxhkasyAO0aOsIW4yOqSVODgyO5AYncxtHJyv53uFibqp140s8ir1j5cqU3Of0w05VT/9Yb6nYV8mhvFMu4uVpUCimM23ptiBd6Gpra+9h/xwfL48VNvYVy5PcHs6PbtiY31DUjNM2TlZYcrw81lecnCwWlvdu4SZO4fDMiq6OFWnRUnVREa3p1uzydPvyTPsya1N+uLxx0kqvaE2vaPt/ABDPQuE=');
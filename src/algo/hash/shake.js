@@ -0,0 +1,158 @@
+import { WordArray } from '../../core/core.js';
+import { Hasher } from '../../core/hasher';
+import { SHA3Algo } from './sha3.js';
+import { sha3Wasm } from './sha3_bg';
+
+/**
+ * SHAKE128 extendable-output function.
+ */
+export class SHAKE128Algo extends SHA3Algo {
+  static securityStrength = 128;
+  static algorithmId = 15;
+
+  static async loadWasm() {
+    return SHA3Algo.loadWasm();
+  }
+
+  async loadWasm() {
+    return SHAKE128Algo.loadWasm();
+  }
+
+  constructor(cfg) {
+    /**
+     * Configuration options.
+     *
+     * @property {number} outputLength
+     *   The number of bits returned by finalize, a multiple of 8.
+     *   Default: twice the security strength
+     */
+    super(Object.assign(
+      { outputLength: 2 * new.target.securityStrength },
+      cfg
+    ));
+  }
+
+  _doReset() {
+    super._doReset.call(this);
+
+    this.blockSize = (1600 - 2 * this.constructor.securityStrength) / 32;
+    // Bytes already read from the current output block, or -1 while still absorbing
+    this._squeezeOffset = -1;
+  }
+
+  _append(data) {
+    if (this._squeezeOffset >= 0) {
+      throw new Error('SHAKE cannot absorb more data once output has been squeezed');
+    }
+
+    super._append.call(this, data);
+  }
+
+  _getContextState() {
+    if (this._squeezeOffset >= 0) {
+      throw new Error('SHAKE state cannot be exported once output has been squeezed');
+    }
+
+    return super._getContextState.call(this);
+  }
+
+  // Pads and absorbs the buffered tail of the message
+  _absorbFinal(stateData) {
+    const data = this._data;
+    sha3Wasm(SHA3Algo.wasm).absorbFinal(data.words, data.sigBytes, this.blockSize, 0x1f, stateData);
+  }
+
+  _doFinalize() {
+    return this.squeeze(this.cfg.outputLength / 8);
+  }
+
+  /**
+   * Reads the next bytes of output.
+   * The first call completes absorption; later calls continue the same output stream.
+   *
+   * @param {number} nBytes The number of bytes to read.
+   *
+   * @return {WordArray} The output bytes.
+   *
+   * @example
+   *
+   *     const shake = new CryptoJSW.algo.SHAKE256().update('message');
+   *     const first = shake.squeeze(32);
+   *     const next = shake.squeeze(32);
+   */
+  squeeze(nBytes) {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const wasm = sha3Wasm(SHA3Algo.wasm);
+    const stateData = this._getStateData();
+
+    if (this._squeezeOffset < 0) {
+      this._absorbFinal(stateData);
+      this._data = new WordArray();
+      this._squeezeOffset = 0;
+    }
+
+    let outputWords = [];
+    if (nBytes > 0) {
+      outputWords = Array.from(wasm.squeeze(this.blockSize, stateData, this._squeezeOffset, nBytes));
+      this._squeezeOffset = ((this._squeezeOffset + nBytes - 1) % (this.blockSize * 4)) + 1;
+    }
+    this._setStateData(stateData);
+
+    return new WordArray(outputWords, nBytes);
+  }
+}
+
+// SHAKE keeps squeezing after finalize and cSHAKE absorbs a prefix first,
+// so neither takes the one-shot paths of legacy SHA3
+SHAKE128Algo.prototype._hashBytes = undefined;
+SHAKE128Algo.prototype._hmacBytes = undefined;
+SHAKE128Algo.prototype._pbkdf2Bytes = undefined;
+SHAKE128Algo.prototype._hkdfBytes = undefined;
+
+/**
+ * SHAKE256 extendable-output function.
+ */
+export class SHAKE256Algo extends SHAKE128Algo {
+  static securityStrength = 256;
+  static algorithmId = 16;
+
+  async loadWasm() {
+    return SHAKE256Algo.loadWasm();
+  }
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options, e.g. { outputLength: 512 }.
+ *
+ * @return {WordArray} The output.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHAKE128('message');
+ *     const hash = CryptoJSW.SHAKE128(wordArray, { outputLength: 512 });
+ */
+export const SHAKE128 = Hasher._createHelper(SHAKE128Algo);
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options, e.g. { outputLength: 912 }.
+ *
+ * @return {WordArray} The output.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SHAKE256('message');
+ *     const hash = CryptoJSW.SHAKE256(wordArray, { outputLength: 912 });
+ */
+export const SHAKE256 = Hasher._createHelper(SHAKE256Algo);
@@ -0,0 +1,148 @@
+import { WordArray } from '../../core/core.js';
+import { Hasher } from '../../core/hasher';
+import { loadWasm } from '../../utils/wasm-utils';
+import { wasmBytes } from './sm3_wasm';
+import { sm3Wasm } from './sm3_bg';
+
+/**
+ * SM3 hash algorithm (GB/T 32905).
+ */
+export class SM3Algo extends Hasher {
+  static wasm = null;
+  static outputSize = 256 / 8;
+  static algorithmId = 25;
+
+  static async loadWasm() {
+    if (SM3Algo.wasm) {
+      return SM3Algo.wasm;
+    }
+
+    SM3Algo.wasm = await loadWasm(wasmBytes);
+    return SM3Algo.wasm;
+  }
+
+  async loadWasm() {
+    return SM3Algo.loadWasm();
+  }
+
+  _doReset() {
+    // The initial hash value is fetched from wasm on first use
+    this._hash = undefined;
+  }
+
+  _getHashArray() {
+    if (!this._hash) {
+      return sm3Wasm(SM3Algo.wasm).getInitialHash();
+    }
+
+    return Uint32Array.from(this._hash.words);
+  }
+
+  _process(doFlush) {
+    if (!SM3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SM3Algo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const dataWords = data.words;
+    const dataSigBytes = data.sigBytes;
+    const blockSize = this.blockSize;
+
+    const H_array = this._getHashArray();
+    const nWordsReady = sm3Wasm(SM3Algo.wasm).doCrypt(doFlush ? 1 : 0, dataWords, dataSigBytes, blockSize, H_array, this._minBufferSize);
+    // Count bytes ready
+    const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
+
+    this._hash = new WordArray(Array.from(H_array));
+
+    let processedWords;
+    if (nWordsReady) {
+      processedWords = dataWords.splice(0, nWordsReady);
+      data.sigBytes -= nBytesReady;
+    }
+
+    // Return processed words
+    return new WordArray(processedWords, nBytesReady);
+  }
+
+  _doFinalize() {
+    if (!SM3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SM3Algo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const outputSize = this.constructor.outputSize;
+
+    const nBitsTotal = this._nDataBytes * 8;
+
+    // Pad and hash the final blocks
+    const H_array = this._getHashArray();
+    const digest = sm3Wasm(SM3Algo.wasm).doFinalize(
+      data.words,
+      data.sigBytes,
+      Math.floor(nBitsTotal / 0x100000000),
+      nBitsTotal >>> 0,
+      H_array
+    );
+    this._hash = new WordArray(Array.from(H_array));
+
+    return new WordArray(Array.from(digest), outputSize);
+  }
+
+  _contextWasm() {
+    if (!SM3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SM3Algo.loadWasm\' should be called first');
+    }
+
+    return sm3Wasm(SM3Algo.wasm);
+  }
+
+  _getContextState() {
+    return this._getHashArray();
+  }
+
+  _setContextState(state) {
+    this._hash = new WordArray(Array.from(state));
+  }
+
+  clone() {
+    const clone = super.clone.call(this);
+    if (this._hash) {
+      clone._hash = this._hash.clone();
+    }
+
+    return clone;
+  }
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.SM3('message');
+ *     const hash = CryptoJSW.SM3(wordArray);
+ */
+export const SM3 = Hasher._createHelper(SM3Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacSM3(message, key);
+ */
+export const HmacSM3 = Hasher._createHmacHelper(SM3Algo);
@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJzNfWuMXNd52DnnPmfu3J1LaiXRXto692pjLy2RnPfsUH7sobyk1muSiiQ/oqYZzu4MuXN3d2bnwV3SUTWrhLYZWSFcREHVgmiFVqnVQGocVECcRkUXrYo4iJ0aqJD4h4q6hYAaiNEIqIPqh5qtv++cO3P3RUmuihQCd8655/Wd73zne98rUuutUkII/Vf0zouEXCR0cJEOyEU6oIOLbDAg8IcOLmoDKMNfOrioD7CGP3Rw0RjIuvylg4vmQD1RBTq4aA2iZ/Zgb5EOLiYGo+ejMh1cdAaDWFNqEK9qX/qiNp42Ekk25iZ0Q9dtw7jj8CE9lUwlTdvWGTNN0zItjemmYTheOsF0ypjODN00dd1K6IapW4RZmmnouq4zpuuaQQjTDbpGDx82NEoOmQk6oGJzc4s41u/RpLnaWG13rzHiNq6utbv9B9utfuNqnxwau9zoz7Wa/WZt5aFab4l8zG2uxjvcadXbD3avrfXJ/cl6+0yzVVtpfqVBjh+uVjcWmq365Uar2m3UVlbai2Q2HXt4qdtokM8fij1Zlb1+8Z7Ys1q9Xu23q71+bXG5utZutvqNLjmTuJsSQZ1DF4qBd/ozH//053KfGD/68AOn0slnfot+8fuTLDGgT95LhLcc0EnCZuR/nIi/pnMuwdL3tn/1nEsE8UlKdzgRThgwcbXja4JMseduzgT6rMvEPb4Bo7a3rQsu5Zr4GGfCXr8ckOV1QTucCNpfFp8OfSNFnWgZ+A8mefbmTGAKD+cWb2vQ25mbCDShrQdsPSBC68y6hBMx6MFcLAwsofUDTTzzzBYJA8I18SyUphgJ2BSzA/uMS7nNSYmlOOF2idkp5nAE+NbNmXOuyYnLIvDJLNSXBOuLd55Ws9hTTBdXO1xb9hm3fVMDpNhTzAuIS6AwjgVzih3xDYUzc4qlAsLNs9AgxoUHT8YDMiFndCmikDnw2A52QkcdGBJyU3ghJxO+pc1wy09wOyDwmOBjmM2e8C1OcHY7mpg4PCFIiRGHG7OuKYGBVY4O9wQbImfwQI0p5nHzjEu4ASCMAypSCUfWPE5cmrIdiTzCATcOJ9wosSNyi15g41kA3J7sdsSRO7URkdAwrhpSMHiIysDmjNuczcEuGCfc5PaEb3Li2yniOALQ9iS3NjoloAgHCCj0CWdcE1q3xHQohYHGNZiRwpOU4Uhy5awvGGf9gAjCyXK3sxQkgESYIhGbsxGJEMC/BRiwANgUt7klD0GBkBiCQIZrE1jbBBCWA1uuDsuGMAORZHzr5kzAZuGqTLEXbs74Wpy+LUE5E9p6P0h0oAu3eKKLq3C4TQpO5ltAC/Akgt23YrD7zOEW10oMUKkB7BrADj+sxGy4noAz2EcJYBB4HLduzqR0RxBF8eJJJA7Y5HM3Z9Rt5Ex4c0C5wx2bsGNAnNysDZtlJUairVqzQNJyqyS+1YSg3MKtGnKrCW6orVqjrVp+ArYKTwJLbTXBrdFWLYcngIpsbkV3JSF/rOjKSIogQ4CRGZAReeCJ2hEmGGICbpodwq2nKQbtcMeHjM/YwQZM5Hs2XlyYy7eBiYgPcUPQ9WVuiHvOTPR9C9qRQ5iBKak9SHANsJkAcklwdgEw69s84TOewEkZN318ltLU9UnABrnJLfGRdaF3Qrhr5tkJeDrhE24J2vctbrrEcZDk7a6CjnFjSPhc78DWtF0MzScO3GXqaDPRhQxMgM3ijFtwJRMTvgFHPgf8hduwZoJhZw/QgNzHNx3OuMEtuL42T0DBlvsAqDhwhtFVIBIFcNG15QsudYBvjtilLdmlDezSlgwtxi7NEbu04bqaEQmY6uxt4Iv2LnZp7maX5ohdmtHEI3YJ540gS4BgpRjLNHeyTHsXy9R3sEw4xCHLpDGWiULDlCzThN6mYpm4W3PWpbJhXDUgnY0uqr3rokbX1B5eUxuvwPCakuiaYt9NNocrM89NMWenvI34kSUvqcmtIT96m44YkikZ0tt0xJHMXRzJlNeUxc4Iflh0VDuvqb37msJhwo1092oGio4uRHT1vKzPu4RToYdi6+YWsOjf29RDsbn5RMe7h81wOsV0dXUJyDI6xVK+IbH60k1st4NEGJASVOHeQMu3gXsD1PMTJaiMgHgx4uvvfGMLbzpsGEUvbiKwwjMu4yhViEtSmjNE9HekegHIJXADJF/+zs0ZOOPte0rsVVzfKLEbz0AhUWKbWDBL7J1vzAiCCC+x5+AhorzEnn9m9PxZLN/C8ouxPrew/AKWvx3r8wKWX8Lyd2J9XsLyK1jeivV5BcuvYvm7sT6vYvk1LP8g1uc1LH8Pyz+M9fle7PnrWH4dyz+Kld/A8htY/nGs/CaW38TyW7HyT7D8Eyy/HSv/FMs/xfLmb47K7+Dzd7B84zdH5etYvv6bUP5mrPwMlp/B8nNYfhbLz8eeP4vlW1h+MdbnFpZfwPK3Y31ewPJLWP5OrM9LWH4Fy1uxPq9g+VUsfzfW51Usv4blH8T6vIbl72H5h7E+38Py61j+UazP61h+A8s/jvV5A8tvYvmtWJ83sfwTLL8d6/MTiWcsb94c9fmpxDOWb9wc9XlH4vkm4vzmqM91vA6mSIcgoIi4GgasBPdv2PsZ2YOTZZ4QXw4DEgLrsEvAGUC7VFwQVAExVWL6z2795uYmLbFXbs4AL2DcvIDsk81LoZYCba6DMoiuc+OMqyn+i3cXJuLm3AReWm7yROjbig1I/iDlKQksjjbHkAkwx9l3duoMh6PGoPgIQgMyFRhKGNhzLsPxtk+GqnEMKZZCyh5E6BIRFtdvhwhE1EWYDiZD+SC8cG4isMQEdj0G3MdPckt4IdSuPzNTZqCCAJvmyTIogbfhWiAHrj8D2t7REPklERaKRqHDeHsOZLDF2RnX4ha3QBA82ZEKvsXZckAU8Kj3SSNUCjLGieem7B2CzBaUExRkphRkNjeVICMxQQZGBshlFGRECTKbk5EgIyipQJCh6EoBluQPSLeU5UjJBuiCU+KJEBEszwUQOZLJ1ughUwJ62dc4U9LkjKvKL0BZB0mNIktoHUHP4j5N1NC8FGrhMJSbIdi2utTBTIUwEH50aJ+ARQDo0oZyX/PclLkDXUyAdR5DFxuiS4uhS/MZoAsfRYa2z2KGtq85CjNci+Q+kz9gnqQMIFrQ/OAGP483WIlStKRspGBux/SWoaWFJkzKdCLjgikbDy2v9DxADfrbC3JSVOoBlKGReJAVcEtaAWjI4XAwQNXyMApNuZG5GRmgKQNO3pLnHduGupAMjz66kISzZaWo8EQYMLyVpryVNjdjStpe9qQ58uq8GGELBoHFK4cPz1puaIQBVJNUx1vxjiOikCpWZMVEkweMa+cUEfzcB+VwKrxwknDi/E+LmgPpTEpNEqmHuSLd8ce4K97a3t6mnSAt1nq+B20kcHlasH7oH4pUPmSJnvDCwHMJHxOs77vaDHdnXZ274onQd/khoD2PHxJ66B/iHmdnpcrF3Sk4PGDCrtDDWMWOV1LxihevjMcrR+KVo/EKj1cm45WpeOX+eCUTrxTilel45ZOhn+augJvm8jQ2nEW7ykSV9QzaFQKvHDm9uQkeyTKDI/UexRvOhe49FKRB30wDUz7K08CwjvA018HASHMHDJA0T8I9TUtelz793X/5L/7ROLB022c8JZDnpXgaGrGmC9t32QzXYeIUaN02170P8RSo1S782H4a2gXro57MYResH4JT4p0kIQ700cFQSHGX62Hgllgqcppxxt3lc2pm7nJTzqwDLDizWgftfTStTFjHi69jcE+tAxOEAR3NnxBaKNh6MMYZp6N1KB+T6wwXoLDA2Cwq6bjdMUHPIBHSju/wMfE329vbWsc/xNOcItUy6Mgt39OA/Xji2a9tEe5ye25iiqElyGAlDxAmmwQNY60e3B5P8hgGGwkDlx+CA+eUu6FPuYPbVctxS2527zJU0NCnsHnYN+y4E7izLoUj4WbIx0JhgRyiFxBlDGYJGIgcIgaCc1do/eV+p8TQ2mY/M6yuf22LePcSR+iCe/PgOkAcSdGYOsb0MnCUFF57559YNKkuvT0JNlcCEa8JLQyS4olO4Ih3tre3LdBwkoKt+3g03HGJ0H1XEH8MKVjofoI7SMEAPE/5Y2CfpyTRaR0/LYgPT5LSAE7hrFrH92BJaANGATw3ySn8HBJv/foW4Q5nQ0wl4UCAc6gmOJB4qw2t9rCV7WxNQWtq2KrFWxPCCyX8YeBwD08xjcdHYaJ+kIRmlydDP6nN8CRP7IUuASwtgUD6SQTOd3haDMIgLb0rPCWdlS4chM3HSkyXiO7gtcMyWw8cnoKTdnHhMEjwRHTSbHTSmsPhBH/w6/KkAdN8TLpi9ND7KDpdfNfhLk8h1SGEwBBoGCAYsJTvwKVJcMcrk8iNuGt1hzuw+iY6G/riSK/flToKun8cke6IMbhm0NtnmpwBvO8jWKQHCCSmAgJQEziomgGuGJfTnFWeMTk+SO7cje4nlSspIUEDnqhm64LoS4EaxpO7cZCADTLJZmAAkIWfBAeeNsMTPAn6E7c8A1XogHHnHCq3YAvsZdVMYv1HCuvAt7kF5CEJn0k3EZNi1pKIcmalQsgdyeOG7k+hR4iXG8aOcnNC92Yc9I5NEucvTWoP8GxQUQ7hTjJOlgN0z1GQ/DrQDbjCuA5XzRQEnZoUUQJOMOUFpdLXvhwkxBPzo6caTs3tMGD3EyL+7eBzIagLYazC4hVtWPEpIhuDGA5OgXilnMV6wFTgN4VjT6iOWojuSVPuWZcspjMCADylkoFbMBeVc8FDNnzI1EOwRWjo23CgvgYuQcYNsUXxD1pFgBPEIB6WLt6yOgGVblzUkmG4dOpqEhtSzwgSELGy1nlCmOtd8dSvXbc7nEqF5IA2/eC2gxpYGIaAH1DDAsqJ1BkMbi37Btc4MhWIxYntp96xOpzJ31A8tamvCG8dcG4jImEjlniHdqSEgPWoWo+O1kMiULhFs2VfYOHcFK4xIsdUJ7arE2iPCNpR2P0+kGG4kCOy09BZ66DgBY5vQ4PQkYrFEx1w8SE9JjTQVwkIATqkOhqnRxqnRxqnR5sbyIiDBPJwppy5iRDP1eY01lMK3p8hdBAGTLJo569Nag7iXkwVTn0iDHRAqIF2ndCFzQ1g3NYEp6HcwcdD3JEl/YX2vMsij29C2r6a5wQM/OJpR9IahGbnXVt4XIPIKQTZIHDqwz2/qmbDqcEctMA9b4EueU7+LIvNTXvetTgg1uZm6FtKJUNNwRoaqxQJPzJXCRrsgSFYx01wQwY7zDAwOJ1zE2AnecBKDU6XA0vKap1TDrsXtNMVrCv5LgVitYY+YQNmMIZGG6fc8pIQl9S5sXewAYPpyMSzncgMMsPAAkhseV8BChONRpgIosfWaBZr5yyCIOdDTRAAMPbCaw6DYRasg+6AuAcDDDMaxZtMGXmzUIFHMCDyfODMynUgZ4uQYHpJZQk/P9zcvKujUQ98B/5BqHw2irRIIYG0psnQGtAaKgmapDXGNQjUWtw+55rih1/fIuKEuHFji3jTxBHPyfp3v67q79K+/3YMiXga4RVw8nzk6n4RYnAyiJx2uOaRgEptVRNPiKsSdCa0zgSGQUGmaqAlyT3S0R4pWBTEA3+I869NOg5K6DfZcmBMEuDcJBRE/IC+40jvlmLgTLTn8X7ReRnOifThY2y6rE1zg7NjLFPWMrI0VdamZImXNS5LR8raEVnyyponS3ZZs2WJlDUivv0ksgsDcMHEN0GRFG9TaeuJN7AgrI0eZ+In0dMfYAEeXWfYnt7oBUzcjb3S8LcnlX09BNHj7OIwuhTeFCxJXbBzLgNRDo4mXejnXJ3rwjjjGlwXJhKhsM64NnIRw6cQUfcCC6KbKGT8JMbYA8dPQSEVuP4YFMaDtO9B4WhwyD8MhSPBHf44FOzgTv8ubYbf5RM+7t/Nk74OeRPxkBXhKQhPwm+n69vc43f3uNnhd/f8cfH0H/3j1y0/CVfP42YPH/IU13uc9Hxb/MOnf//PLNTg+FiIkg6whiLR7oVcF6mN4C4wdpKcbYTC2gjG+F090KPH+eGQ22AFBbZIbHBbHNrocbuH/FsH/u2JOzZgMZHY8O9CZSM44jOu+ylO/DFu+h6/2z/Mj4gtFK1c43fzQz0wrzU+zu/ogYWtcZOne2Bka8AbemBna5xwtyeD83fxO3vSVaZzpwdXWwMjAI/UAAKZRKYODnKlGwoqqLh+XVWYYOLZqKIJTbxwfWii6eKVqGIIQ7wWVUxhitdVBRVIKn4K1THiCEtY4k3V9rxJ9QFKDNAexBMyjQZ9zaHMYQCkgBObCdYB2wzxDh4zcGODsgZXXcoHUJ8xP0PrCO0sCCuuPHQqb0P6LWEhyeLQeYiinTMv5ewRnUiIsDBMPBRJTJZfkL5UTSVMeCmYBf1/QwZKQq6s5AjAEUzAfjjbA9JwkKMcz/jIc6VnDEJ2A/QxvAqxRNeEh9efmQkgpWj7Hp9KPx7d5ccjGG+E0nAhVF+GaBv6MofeTUcGJl8BR9051xi65WZlGRx4ujg25+oypEDResKz0TiZl/5fFG/hHGTxgA/JpyqcQHd5DyV4t+LgjfCA+obUa1HjiaIcoI9t34N/5id8quZ9FRAbdzlrQw89ky5njbN9PfTaXg+9tstDr8EKoNyhr4PKHBiKfujRovI0iDwN+n6B5zpn6BUVA/TLOs6PjKFf04r8mjb4NRPcjvyaSfBrOoolgaOjH/qpHX5NB/RzB+wVsHXB2rDBr2mDX9MGBwvxHfRO+SnwC0R+TXvoIbTjfk077te0435NO+7XtON+TTvu17Tjfk077te0435NO+7XtON+TTvu17Tjfk077te0wa+Z5LaQAZdk3K8prPkDnZpRQg7IQnBRokdAKoiqZOM8ss+RwEYCV02pIPXyaf41zNwI6MvXoXA0YC8/hrLfAFHmgGc+CaKBSR9heNYFV8LGvAvcPDnnasqhIVcWHBya4OT0JmcPgNpwvGzgyDiIg5l93AGq0sHsR11R6NBmgSfWljWIFungw0SagZWXPZ+jlq08bpb0uMnsyz1ravs+ZQdgVdg8yU3x3NekIMAI3yRx3jCYPaBPivvE5uZrRN4RE0MTYKL4AOqd64JyOsGNUHn4sF3vDM1g5fbzImNFw1w2eIZdLBlP0jroPYFLaWF/tN80ker4qeg5ZPNwxp0wcIdWmhu339y4/ebG7TeLp5QXDmV1Ek8KpsJAgsXdWE+Y0nd5Eth5EgkSgjzg8bHFcU4nlKcywclJmgrohWGmEO7c7qA6mlhGm1O5S1TzR9aF1hnTGaEEEpikSWGLv9mGAN+6j07s7e1tAhwEAlU+BnsJ+gYtAW5N4EvbWueCywQFLzOAaoHKAP4075ABWSUpA10h5Bizg7FvAUzb1zri25ubm8BebRyI8eWkzFMBFzEczH2uBhAj8LgK+lzUumz3kiITX1J3HGxN8tFsjCfRSnOmWOqQhZm1vgWGgJqdDGcHpWHOd0FGX3C1A3alDZeITwqK/1iZ2Wj9DndmyZ0lh7CAOQbDknIYujCdLZ3ain/rUZIzxMhQpzlOSGBgtpmBtwYDgHQds4ZgBkhYlilEpqJN9EfZMR3F2BKDzyGPAEmQEJuohifEFj0LCrjkALrUQ/TTz29ubpplVAYhjTkMgMUB0FNMRxQA/g1p4aGti7Ma3AQ0SOPXh/Qz7GXIXsDCAoOzk4QGLOppcAY9NdlTdyJ823hdBcLhJzHpkWKCNRMGsGg2RaifdIaufDT14Qo7aii3wQnKuH2SEF9mO3FD6IhOGWYHhx/MBB0SUQe8MfvMYGEinvAQBo07SCqAmZNUx+mB+arGxI5GdA8kHqBjoFY/QFNch9iWvRPlaCjvj2mCmNEizDgRNRDYvOFwXUY2DeerOmUD0DrCKBoNZKdISFNqMZGqF2gZUbyaxNRitlMtplHgmo3UYgzmqHg0VQEZCOKP9GLpctOkPsxi+jCL6cNAwVc7gealpAoYRpY47gBn5mSHPkwjfRiG7oZlOAg9ccPcAuq56T2pBRT1PC1KLdCUnkdjeh6VFICPAjpMLaAjPY8OUwvortQCinrePmFyihq5DJMP4Y5vcbdynd4vhi5nubVzlmj3acf5K0aNAYg7RMT3t38VUk3h3/xEQJYv4DsKHqfKGUflWwyhSIXSyQGTXh1SEIgdnUsmhJF58GJKGg0g/QOoOMSsXkwkQbMFjS1YDlM+lwO67OsocdQbEkDyqCIpfwyAr8My+sipZIDHZqdDjcHOd3RinHpojTPM8YRpVOICp6EiBbwDVC1N0QID+R+ei0yPHWBoYF5insEopZ6pQOTI36V5SZXEDrFI5zc0tE2B+45ytIckCOnEWnQv0PGtcn0x95fJhF+KvJ2lVEIVACGzeqhKTx2lDLMJX9dmuO4bnAYMHjN8DLPRCV/nDGen0cTwwoV68UKbdaM0FbIji5jKLGJ0sUAohcpU/XHOXA14MoJDwNGs4zsqUPU4w+R0uS0AWj+DDkwFua5ugbp44km8duqlBXmJZfIxZm9T6RukMC+udsSRCBo1jKuGtJPekX1MYtnHf8yoPqJ9ex39ceIevLmgDl1AIvgY1y4HTL7zwwTtd8Wne+inO03KgABY5ij63CR+4Pyi9Si8wIFSRFOp1LgPTiImACxhCB7XuwhYOrIU0Helq7dN0EbQI04DKfsMU/aZTNnX4Ji5pjLtAzQT2Cy6FWgf0q3gsGNTOY4MY6hUJ8WdkJcc2Zn4lAaa8MJoA/qeDTi/wxiVL13xuD5CZBIghR8qDoH5Aq9UgbueSUmm5FpKusRFKnz5OrgiZc3GmofvqaHc4po3hf5ZNRJh1OXEwcHj/8ue8fR9jX/uqduOvw3ku0Z+i1FLKW1skogkuALwhOENAq6LH1tzrnys+wZqZWaIqV3iiZAbkAEmvvlnpBUY8LMCsQjUHN4kLUgZ7J8kb9yYeYDgCz+QXGGLN8mKVFjjrRpY5+CE2H7pv+nzMsKMCkkC/ceSb8ulxZNhoIEhZ4xWIvvO6oxctvJtATXBACeAV+WOk/98Y+YUernREUii7Ykk15Y9G5MJUTfRnN9h1BzIZFEW8UcwVgRFh4p84MEDhg+EJnS8s/rcBGbxoQiR2OVsWXJvvAmCekfkxLp8jwuCifpwDRm2+kxHbA66wDKFuQ4e32g5ON5ThEISitii3VME1RkqUusYh1Xj5IDtbW0+GgHUbsqBlvjRcCC2abKNYZucQfak4vC6WMO+MnpzSsbKNc5C5BrODymlip6cSYJGorjx1S28ceiXhddfLHytJenRUSDOiuLp6m0RjCONHqnIizZ6BD4DjJCh7RJg4osDSERLAsb6mnxgygCd0OWrNzBevtYEJyRDzd6nQB0VOtch1C+D/7p8u4SgR2mSON+HnaFnQirsqFxso2KRwHe0QG9uBQluL2Oyb2J+wk9I8ZJQWnUoBiFPtPCxeOGrW8T7LHHEs6qARrC+gq8yqPgqIC0BKPMMaIM0EzKnAs+YoJXglnc2itBjTFXiyxLXkXAs7wso32RQhw2TFojzn0YHlZiUaTE7z8hj6lgCg9u4iDoqI1rEVovY+y8izXpMuYUfdUYJPJKUyjCDhAN4YA/PyMQkSHVGWJRAUO9T+LaJzunOMzKjzCBIwqCMSaafQX6mPLUgOTevgqxCCxPTgkvsfs7QaeOV2SRnYjIUL0LSFwo4+INOcS/xYWk83A95nxzKx9hkoANjpfIVQcplphZ6fkEfkzr1MUZ8ndNoCSL1BQ8Ui7JmQ1KKSGE+CkTddZHCFDAKS3lyVnZMs8toQ7x4A5LydamZA5PKwGb/aHSC5iRw2D1XzbPex20y0eSR79ca8/K1W1s6i6Gjr8sHoJjgXcF0aT06KSziInhS2o6TMpRuCsCb8qT+nFI2kMLZGL0RzWYE9X4BbtWYplNCMST7k6fhParB58GfAqHhsASPoOWtp2fAIjQgpQzPdvyQDtdAP0U+wg2unSJHwTwH5cDgBqLTU+NQfYDyT5+ekeOA+xlymiOyKsmGG15BqWuw7CBaXpBT5M2nFTLBThPEIc7vSgoU1DeFrkL9xstce/nJwD7Nb3xL3m0lMexvBZpaRF8+F2uJVHB5TganK6BHeZ+K+fq47lsp5niPwlF7D2F+jat0IR0VbrwgEG13hO1jXr0l05TVpXF+e0RAeAbSQKH4HiXsD0/PAMZAVChqCq0H0Dgh0xFe4gqYsIFvCnpmAi0PCs8QprulzzjuSQafsZwVxZ3U6SKg8UgmifP1EVjagWBp7woW2BE6lLwJFeCSgEAYFeOtch4M14wAQY+GFgEis/22t5XghHsQaMNEy/gZUbxLeEYUMy1R0DzkR3k1Sm+FlzOk0Sxt3eFjuTCquvhYkFG3/38A+a0hf+Xvzl+PjvjrONh0t+evRwPFHdkxNg781d7NX/fhgxyO6Qal2iD2cQVFFpHraFY6hcC8puhzlCYwk+HKLQAJCUhGrI4Tm5PjJOHdhVohbgx7SuOCijduM+ByHBAMfO8gUUmgMcL8sHqNUhImyA9EIBKmkiZDwtQQbEmYFykbRDF/SPZCxZecDQM2A/kog7MyWiCIr0vF2YN0urUQjTEPYCBcXz4z4cdiHUPPA5zzcIEoUI2eLn1PkguJhImOmVXzrswVSb9rgssvqwUi21sFQ7TlyKvBvA9JnRgFEFP6O+hqs67Cv4aRHMr1KGNTaaHO45S+y+RxpTu+wGhuVIZ2TSyIcz5mVGqTRAZKU9KSBtRIuw1gs5X1pSHSU8r6enET36QFdwdYX5+jGhj7mtKe5Itu4BzVOUUf6VnMcIW8x515cLMugxAzyPVlsC405xHKYCoqJdXbT6OjMCxBCQmFPIS2ChXkOIgt9BxKCSZH/OjpGewPvwwzBp1LlA5QT+ExPYVL6jz9/Tf+4Nb/+JN//7+/PSizI5yefvW/vvqnv/vbf/bcXeCAoKf/4qt//w+f2nrqPzwOLnh6+q9e2rz1D/7ipaf/hEiNxh5dY+csOH2pfBmB4jtPuKgdW9SOlCPlvpKu1ed2sAPnASk24ifjRW9NaYI+QI9Kvw+cCZwI5pGI8dA7SZwCsrKmvIiKn3G6PC8/zjLrEu9RlPEPYRaVw2naIc5MxGxk/zMycSpiDd4n0zJQjXtD75r6LIakMS/hVNTr45uvofMK2fcwQiNoehjWk/wZbpkMlzgFsEUMyCgV+orQvWO+wQ0uq0C1fKfBZXPLOywHmRhmVINM2bb/IIvb3h3OCbzjCjOKIXJtWSY+oJsN1D00cAAn90cXRDFBckxxXsk+OSsjG9ND7zxx7h0KejapfGRDrw+8seGdI87RiAg9pAeC9ODJ9yWcj+JNl0yWCvpQdH3hes84zkfJ8PrrQOM/fhpeKKOcTBzSCXHuUS89wpa6O85QDj8an31219x3I8XIpFHIW3HgjDzijEcUg/QyTxzvQeIcItLV3Re0K2/+YRKJbEQ6zPhhDBccY9/8+kxZur2OsRtQJsOmzVjT21/DprvUHqnkdboiEHd4mp6edu7Y57p4iloVNX7ScSJYeNpJSvAf+TBx0miBfE3aFV7COUTEd25sEXGv+B4w8ynijBHxwtNbROQ5VlNqHmF7JxyH3Es4CSfJvdgALm6KbDUhVyiloQQIzEQjZXZf9HiOKGCE7VUci3Di5Yn8/QhxHNAI6SmydXMGZ5R+YhtZjHfMMYj3GHE0knY08AcRx/njJCXyK1P/JnlXb6W52ODNVr1xlff6tW6/x2t9vuXyhSt93mjVZZWoHu0rfd6+xBfaV1r13ineX2rwlUaLN3t86zCOgCeyKzwjh7u11uWGnFg93wqiWWTbpXaXSyDal2Cyy/0lvkU82dho1d/XsJPdK73+4sliZTpTLmVLjeylWq5YyJRzhYVLC7XFbKFerpRL9XKjUMtPlzInV5oL3Vr32slev36y11082bvWO9lf6jZq9epKe7G2crLVrsp670S393NPjx/RwgW6tY3qemPx5Gq7PpzwZL2x1jtZX5Hf2jqeOZE7kc1i7+gZdu2vrp3cWLjSXKmfXKr1lhblx76w30pzAboQQv4d9UiSEPIJQshYrJ4lBH/jfc4TQg7F6hd21R/eVf/FXfVHdtUf3VV/bFf9C7vqDxFCAkLIhwgh15lH4OtwdqzdJYQkYnVH1UW/31hd6zfqvN/mTfkdtOZXGlwe1HE8OL6x1Fxp8GYf6HCh0Wxd5vVue22tUSfkPxKP/AohZFmt8bvUQzguEkLGFY6iZ08QQu4ghDBCSOpncOqEEE39GqqvreomIcRS9YTCt6faHDXeVeey+nf/9M/vf/gvF6+Kf/6lzsQ3/vDOf/rLXyh8wXr90asvP/WLb/2z/1Xr9RrdfrPd4pdqzZVG/RRf68EeP/0pjr/38dVmq9peb3SXGrU6IQPq4Zn/vi7XPmj8J0fja1f3Gf8HuoQxvq/0rv3DnhZra7XFZv8ahykurbQ3CCEnqIc0c1ThJpPJZDO5TD5TyBQzpUw5M52pZDPZbDaXzWcL2WK2lC1np7OVXCaXzeVy+VwhV8yVcuXcdK6Sz+Sz+Vw+ny/ki/lSvpyfzlcKmUK2kCvkC4VCsVAqlAvThUoxU8wWc8V8sVAsFkvFcnG6WCllStlSrpQvFUrFUqlULk2XKuVMOVvOlfPlQrlYLpXL5elyZToznZ3OTeenC9PF6dJ0eXp6ulLJVLKVXCVfKVSKlVKlXJmuVGqS3oDa6s31Zr3BF67xrzS6bfLf79NbtdUGSdi91fyJDfjC4ev3PUwa1UfOr5/rZarn+w/2ws7V6cfmrj2+8HClOh1d6GFh7nz/dLE6/VlVPd8/3z9druZ713qlR6/1+o3V2ZJsebDXWHhosdZoP9q9eq2a7/Xr9FT18fPZbIwXZPPqC4Bn2t3VWr8kPxuYLS+Vaovl+qVGdqF4qbRQzpZn2cerj5/P91bz2czoA4HZ8lKlVspWGoVMLZMvLJQz9VmtCHsBsB7sXf7y2bXihc7V3Plr1cJiu9vI9/rd4mL7SqufLdTbVSxVF5dq3Z5+Hwx7sHfp85eulWbnssXm56vlarWK/DObh1J9JfoGoXEsAianvlt4eqW9uJwtL00XarWFbLlYm17MFQq1yqy5+EGitgDfO9yLWOtdESs/twgA5mr16Vqx3Fgo5eqLxdKs/WkJYK26P8YurfYRrIqcqd/oZnNrtXoVPqJ4uVtbSdwr8X3A2OJGt9lvJFc/SCxk8/Vmb63da1QXl660lvfiw7n8QS43vdpYra00L7f2LpRa+UD3lbvSWmm2lg/altv9QFebbraA5VZXat3LB6JyrHKb61REfaaI+k62hJUqVqrAw9OfgaFf7k1XYfiBFJJvXVnNN1fXVs/3T1eq5c82e2srtWvQ4v2KnOBx3G9x8cJiJnfh3JdX2tUibqrU63ebrcu409KjqnwwJX4JKDGbQYLES39o52dKD0cfIb0j9g3S8etUAlGVRL4bP5W1Wqu5uNxsXc7msVhdqrXqK43u+f7pQjWrbo0E7uHatZV2rX4QMnF4Nvcw/Kiu0/3acqO60L56584Ppt6Vjy7dbeCRE1Y3mv2l6lK7vXz3lyT5FA5AZ1mpeojPbPaR2sYXG4tzrVajm81earaavaXq5W57Y++aRwBHc+fXYfLcu0wOdDm9c/a12XK30Wt01xu5bL1dVeVqrVVXuETirUWHfnalvVBbmd0Lxocerz5+fudquUxsncnPPzYpJs8+Nvkuy4FUKefqlUr5UqWRKZYrtczshx96j3PvQFW2vFSoTFdKxXKmnlmoFC81crMTP+9Mi4VMpZavLC6WauXF3GJ99ujgb5Ey85cb/Y+Uq4+cf/B9rp45Xax+9ME9OMhWJA5g+49NPihRMA1br7ZbcCSFYq5cuFQA1WdxOlsuz94zG23/IGYI3A43q1je+f7pUrUioKnWb3clAPw9Cf66FPz+Z4eA5wHo0m6Is8Xa2lqjVa82VhqrjVa/B5CXspn64kKlWGtkM5nF+mzwyx88X6tIttbrd+8tHyiQY4cyLQ9F2sXVxaXG4vIkfL55cXXtF06NWP5tjjUrZ0AdarrZWuw2ar3Gx3Z96fnjv6bYZ/bnoNJiNVt6tF/rNxcf7Xf/L5jn1PH3gBFZql5a7R/b+znpT1yVu8j8fLt4H3cNTjQuA+87j7C/q4JWPlBBy+Ykaax1G5eaV0/s80ntkydudwXK8FNdaFxutqpXWhvNVj3z8YOkj6TfaRzRbq9mM1HHvUReXGhfbdSzhYX21WqrsVG90gLDOLchJcm+O13rd7N5MIqrzVZ1baW2CJLh/Z3Hvkcxe/pcNX/8YF62a1fAwQpfeV9wzqEIK1RL7TUwcUsX8GdO0sBe3ABvyX+xsbg0O7uPnCteer84uh2XUQxmn3VKx25HFlnFGSUXv81BDzl8Tp5BtdHttrvTx97DnZS3urL7g++nUL3/8v4IqLWuvbcdn++fzlXzonWt3L+21qg263sx8MCDt1/okcZ7mOSTp96vgTXi5Z+6/3ZHUFCl6B5/+u/9rfFaUAc+c0Uun/t/u/xuDjnz3nCkJLj4+O16Z7ArAnd6CTr+0nulpfcmni+t7oOXB4vv4fKUI/9VNfJffXb+NpbZfnK61evn8rFKtd5cry5cq4Jj6OwseBBy4BCqqquWz8Su3WJ77Rr8LxSAyuvVWrdbuwZqcqOQXSxON7LZWi3XaJRmH/rE7bCbi85CWqjTt+mbK+3oK3lGRC+f2/zbUypKtR5czPnCbSQb/s1OK3YX28C5uuLc+wEN2mploba43O/WFhsjBIA22Vtqd/vVYaMUVKdL1d2S6Suzp0vV860PeJWDdXlc78L07dWBXKHeuFS7srLrLNvt5YeRY/zS3HsQ41mpgOCDsjqLRxqz7//kTleqj2RuR6XTEUowBiRXfFTcZkS+GI1otdUWe0vN1WqzV73S6vVrCyuN6nrusfd4NRba3f4XxJ7bCCc1vI3thbCx2K+imO82LoFxWK43Mtl8Jl/O1Cv5fGGW/J3EWrddv7LY6PaYvVJrXb5Su9yg+iNXen2SWuu2Fxu9XqN+fOEaMxCEj2RPVIonMnxqGCviuUyudDxTOJ4tHDM3aivdKz0zcyKXOZH/P4nHZ0o=');
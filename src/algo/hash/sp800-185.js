@@ -0,0 +1,420 @@
+import { WordArray } from '../../core/core.js';
+import { Hasher } from '../../core/hasher';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { SHA3Algo } from './sha3.js';
+import { SHAKE128Algo } from './shake.js';
+import { sha3Wasm } from './sha3_bg';
+
+/**
+ * cSHAKE128 customizable extendable-output function (NIST SP 800-185).
+ */
+export class CSHAKE128Algo extends SHAKE128Algo {
+  static algorithmId = 17;
+
+  async loadWasm() {
+    return CSHAKE128Algo.loadWasm();
+  }
+
+  constructor(cfg) {
+    /**
+     * Configuration options.
+     *
+     * @property {WordArray|string} functionName
+     *   The NIST function name. Default: ''
+     * @property {WordArray|string} customization
+     *   The customization string. Default: ''
+     */
+    super(Object.assign(
+      { functionName: '', customization: '' },
+      cfg
+    ));
+  }
+
+  _doReset() {
+    super._doReset.call(this);
+
+    // The prefix is absorbed on first use, once the wasm is known to be loaded
+    this._prefixPending = true;
+  }
+
+  _process(doFlush) {
+    this._absorbPrefix();
+
+    return super._process.call(this, doFlush);
+  }
+
+  squeeze(nBytes) {
+    this._absorbPrefix();
+
+    return super.squeeze.call(this, nBytes);
+  }
+
+  _getContextState() {
+    this._absorbPrefix();
+
+    return super._getContextState.call(this);
+  }
+
+  _setContextState(state) {
+    // The imported state already has the prefix absorbed
+    this._absorbPrefix();
+    super._setContextState.call(this, state);
+  }
+
+  _absorbPrefix() {
+    if (!this._prefixPending) {
+      return;
+    }
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    this._prefixPending = false;
+
+    const stateData = this._getStateData();
+    this._initState(stateData);
+    this._setStateData(stateData);
+  }
+
+  _initState(stateData) {
+    const functionName = toWordArray(this.cfg.functionName);
+    const customization = toWordArray(this.cfg.customization);
+
+    // With both strings empty cSHAKE is plain SHAKE
+    this._isShake = !functionName.sigBytes && !customization.sigBytes;
+    if (!this._isShake) {
+      sha3Wasm(SHA3Algo.wasm).cshakeInit(
+        this.blockSize,
+        functionName.words,
+        functionName.sigBytes,
+        customization.words,
+        customization.sigBytes,
+        stateData
+      );
+    }
+  }
+
+  _absorbFinal(stateData) {
+    const data = this._data;
+    const domainByte = this._isShake ? 0x1f : 0x04;
+    sha3Wasm(SHA3Algo.wasm).absorbFinal(data.words, data.sigBytes, this.blockSize, domainByte, stateData);
+  }
+}
+
+/**
+ * cSHAKE256 customizable extendable-output function (NIST SP 800-185).
+ */
+export class CSHAKE256Algo extends CSHAKE128Algo {
+  static securityStrength = 256;
+  static algorithmId = 18;
+
+  async loadWasm() {
+    return CSHAKE256Algo.loadWasm();
+  }
+}
+
+/**
+ * KMAC128 keyed hash (NIST SP 800-185).
+ */
+export class KMAC128Algo extends CSHAKE128Algo {
+  static algorithmId = 19;
+
+  async loadWasm() {
+    return KMAC128Algo.loadWasm();
+  }
+
+  /**
+   * Initializes a newly created KMAC.
+   *
+   * @param {WordArray|string} key The secret key.
+   * @param {Object} cfg (Optional) The configuration options.
+   *
+   * @example
+   *
+   *     const kmac = new CryptoJSW.algo.KMAC128(key, { customization: 'My Tagged Application' });
+   */
+  constructor(key, cfg) {
+    /**
+     * Configuration options.
+     *
+     * @property {number} outputLength
+     *   The number of bits returned by finalize. Default: twice the security strength
+     * @property {WordArray|string} customization
+     *   The customization string. Default: ''
+     * @property {boolean} xof
+     *   Whether to run as KMACXOF, whose output does not depend on its length. Default: false
+     */
+    super(Object.assign(
+      { xof: false },
+      cfg
+    ));
+
+    this._key = toWordArray(key);
+  }
+
+  _initState(stateData) {
+    const customization = toWordArray(this.cfg.customization);
+
+    sha3Wasm(SHA3Algo.wasm).kmacInit(
+      this.blockSize,
+      this._key.words,
+      this._key.sigBytes,
+      customization.words,
+      customization.sigBytes,
+      stateData
+    );
+  }
+
+  _absorbFinal(stateData) {
+    const data = this._data;
+    sha3Wasm(SHA3Algo.wasm).kmacFinalize(
+      data.words,
+      data.sigBytes,
+      this.blockSize,
+      this.cfg.outputLength,
+      this.cfg.xof,
+      stateData
+    );
+  }
+}
+
+/**
+ * KMAC256 keyed hash (NIST SP 800-185).
+ */
+export class KMAC256Algo extends KMAC128Algo {
+  static securityStrength = 256;
+  static algorithmId = 20;
+
+  async loadWasm() {
+    return KMAC256Algo.loadWasm();
+  }
+}
+
+function toWordArray(data) {
+  return typeof data === 'string' ? Utf8.parse(data) : data;
+}
+
+// The sponge rate in 32-bit words for a security strength of 128 or 256 bits
+function getBlockSize(securityStrength) {
+  return (1600 - 2 * securityStrength) / 32;
+}
+
+function createKmacHelper(SubKmac) {
+  const result = (message, key, cfg) => new SubKmac(key, cfg).finalize(message);
+  result.loadWasm = async () => {
+    if (!SHA3Algo.wasm) {
+      await SHA3Algo.loadWasm();
+    }
+  };
+
+  return result;
+}
+
+function createTupleHashHelper(securityStrength) {
+  const result = (tuple, cfg) => {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const { outputLength, customization, xof } = Object.assign(
+      { outputLength: 2 * securityStrength, customization: '', xof: false },
+      cfg
+    );
+
+    // Pack the elements back to back and pass their lengths alongside
+    const data = new WordArray();
+    const elementSigBytes = new Uint32Array(tuple.length);
+    tuple.forEach((element, i) => {
+      const elementData = toWordArray(element);
+      data.concat(elementData);
+      elementSigBytes[i] = elementData.sigBytes;
+    });
+    data.clamp();
+    const customizationData = toWordArray(customization);
+
+    const hash = sha3Wasm(SHA3Algo.wasm).tupleHash(
+      getBlockSize(securityStrength),
+      data.words,
+      elementSigBytes,
+      customizationData.words,
+      customizationData.sigBytes,
+      outputLength,
+      xof
+    );
+
+    return new WordArray(Array.from(hash), outputLength / 8);
+  };
+  result.loadWasm = async () => {
+    if (!SHA3Algo.wasm) {
+      await SHA3Algo.loadWasm();
+    }
+  };
+
+  return result;
+}
+
+function createParallelHashHelper(securityStrength) {
+  const result = (message, cfg) => {
+    if (!SHA3Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SHA3Algo.loadWasm\' should be called first');
+    }
+    const { blockLength, outputLength, customization, xof } = Object.assign(
+      { blockLength: 8192, outputLength: 2 * securityStrength, customization: '', xof: false },
+      cfg
+    );
+    if (!Number.isInteger(blockLength) || blockLength <= 0) {
+      throw new Error('ParallelHash block length must be a positive number of bytes');
+    }
+
+    const data = toWordArray(message);
+    const customizationData = toWordArray(customization);
+
+    const hash = sha3Wasm(SHA3Algo.wasm).parallelHash(
+      getBlockSize(securityStrength),
+      data.words,
+      data.sigBytes,
+      blockLength,
+      customizationData.words,
+      customizationData.sigBytes,
+      outputLength,
+      xof
+    );
+
+    return new WordArray(Array.from(hash), outputLength / 8);
+  };
+  result.loadWasm = async () => {
+    if (!SHA3Algo.wasm) {
+      await SHA3Algo.loadWasm();
+    }
+  };
+
+  return result;
+}
+
+/**
+ * Shortcut function to the cSHAKE128 object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options, e.g. { customization: 'Email Signature' }.
+ *
+ * @return {WordArray} The output.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.CSHAKE128('message', { customization: 'Email Signature' });
+ */
+export const CSHAKE128 = Hasher._createHelper(CSHAKE128Algo);
+
+/**
+ * Shortcut function to the cSHAKE256 object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options, e.g. { customization: 'Email Signature' }.
+ *
+ * @return {WordArray} The output.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.CSHAKE256('message', { customization: 'Email Signature' });
+ */
+export const CSHAKE256 = Hasher._createHelper(CSHAKE256Algo);
+
+/**
+ * Shortcut function to the KMAC128 object interface.
+ *
+ * @param {WordArray|string} message The message to authenticate.
+ * @param {WordArray|string} key The secret key.
+ * @param {Object} cfg (Optional) The configuration options, e.g. { outputLength: 256, xof: true }.
+ *
+ * @return {WordArray} The MAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const mac = CryptoJSW.KMAC128(message, key, { customization: 'My Tagged Application' });
+ */
+export const KMAC128 = createKmacHelper(KMAC128Algo);
+
+/**
+ * Shortcut function to the KMAC256 object interface.
+ *
+ * @param {WordArray|string} message The message to authenticate.
+ * @param {WordArray|string} key The secret key.
+ * @param {Object} cfg (Optional) The configuration options, e.g. { outputLength: 512, xof: true }.
+ *
+ * @return {WordArray} The MAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const mac = CryptoJSW.KMAC256(message, key, { customization: 'My Tagged Application' });
+ */
+export const KMAC256 = createKmacHelper(KMAC256Algo);
+
+/**
+ * TupleHash128 over a list of strings, so that ['ab', 'c'] and ['a', 'bc'] hash differently.
+ *
+ * @param {Array<WordArray|string>} tuple The elements to hash.
+ * @param {Object} cfg (Optional) The configuration options: outputLength, customization and xof.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.TupleHash128(['alice', 'bob'], { customization: 'My Tuple App' });
+ */
+export const TupleHash128 = createTupleHashHelper(128);
+
+/**
+ * TupleHash256 over a list of strings, so that ['ab', 'c'] and ['a', 'bc'] hash differently.
+ *
+ * @param {Array<WordArray|string>} tuple The elements to hash.
+ * @param {Object} cfg (Optional) The configuration options: outputLength, customization and xof.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.TupleHash256(['alice', 'bob'], { customization: 'My Tuple App' });
+ */
+export const TupleHash256 = createTupleHashHelper(256);
+
+/**
+ * ParallelHash128, which hashes the message in independent chunks of blockLength bytes.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options: blockLength, outputLength, customization and xof.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.ParallelHash128(wordArray, { blockLength: 8192 });
+ */
+export const ParallelHash128 = createParallelHashHelper(128);
+
+/**
+ * ParallelHash256, which hashes the message in independent chunks of blockLength bytes.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {Object} cfg (Optional) The configuration options: blockLength, outputLength, customization and xof.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.ParallelHash256(wordArray, { blockLength: 8192 });
+ */
+export const ParallelHash256 = createParallelHashHelper(256);
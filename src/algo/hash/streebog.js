@@ -0,0 +1,177 @@
+import { WordArray } from '../../core/core.js';
+import { Hasher } from '../../core/hasher';
+import { loadWasm } from '../../utils/wasm-utils';
+import { wasmBytes } from './streebog_wasm';
+import { streebogWasm } from './streebog_bg';
+
+/**
+ * Streebog-512 hash algorithm (GOST R 34.11-2012, RFC 6986).
+ */
+export class Streebog512Algo extends Hasher {
+  static wasm = null;
+  static outputSize = 512 / 8;
+  static algorithmId = 28;
+
+  static async loadWasm() {
+    if (Streebog512Algo.wasm) {
+      return Streebog512Algo.wasm;
+    }
+
+    Streebog512Algo.wasm = await loadWasm(wasmBytes);
+    return Streebog512Algo.wasm;
+  }
+
+  async loadWasm() {
+    return Streebog512Algo.loadWasm();
+  }
+
+  _doReset() {
+    // The initial state depends on the output size and is fetched from wasm on first use
+    this._state = undefined;
+  }
+
+  _getState() {
+    if (!this._state) {
+      this._state = streebogWasm(Streebog512Algo.wasm).getInitialState(this.constructor.outputSize);
+    }
+
+    return this._state;
+  }
+
+  _process(doFlush) {
+    if (!Streebog512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'Streebog512Algo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const dataWords = data.words;
+    const dataSigBytes = data.sigBytes;
+    const blockSize = this.blockSize;
+
+    const state = this._getState();
+    const nWordsReady = streebogWasm(Streebog512Algo.wasm).doCrypt(doFlush ? 1 : 0, dataWords, dataSigBytes, blockSize, state, this._minBufferSize);
+    // Count bytes ready
+    const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
+
+    let processedWords;
+    if (nWordsReady) {
+      processedWords = dataWords.splice(0, nWordsReady);
+      data.sigBytes -= nBytesReady;
+    }
+
+    // Return processed words
+    return new WordArray(processedWords, nBytesReady);
+  }
+
+  _doFinalize() {
+    if (!Streebog512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'Streebog512Algo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const outputSize = this.constructor.outputSize;
+
+    // Pad and hash the final block, then the bit counter and the checksum
+    const state = this._getState();
+    const digest = streebogWasm(Streebog512Algo.wasm).doFinalize(data.words, data.sigBytes, state, outputSize);
+
+    return new WordArray(Array.from(digest), outputSize);
+  }
+
+  _contextWasm() {
+    if (!Streebog512Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'Streebog512Algo.loadWasm\' should be called first');
+    }
+
+    return streebogWasm(Streebog512Algo.wasm);
+  }
+
+  _getContextState() {
+    return this._getState();
+  }
+
+  _setContextState(state) {
+    this._state = Uint32Array.from(state);
+  }
+
+  clone() {
+    const clone = super.clone.call(this);
+    if (this._state) {
+      clone._state = this._state.slice();
+    }
+
+    return clone;
+  }
+}
+
+/**
+ * Streebog-256 hash algorithm (GOST R 34.11-2012, RFC 6986).
+ */
+export class Streebog256Algo extends Streebog512Algo {
+  static outputSize = 256 / 8;
+  static algorithmId = 27;
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.Streebog256('message');
+ *     const hash = CryptoJSW.Streebog256(wordArray);
+ */
+export const Streebog256 = Hasher._createHelper(Streebog256Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacStreebog256(message, key);
+ */
+export const HmacStreebog256 = Hasher._createHmacHelper(Streebog256Algo);
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.Streebog512('message');
+ *     const hash = CryptoJSW.Streebog512(wordArray);
+ */
+export const Streebog512 = Hasher._createHelper(Streebog512Algo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacStreebog512(message, key);
+ */
+export const HmacStreebog512 = Hasher._createHmacHelper(Streebog512Algo);
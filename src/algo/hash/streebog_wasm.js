@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJzFfQt8FNXV+L0zs7uzmd1kwjOSAHdGqkF57Oaxk6C1mdiAFAGrtLX9+n1rSBbIJuSxWRH68ZnlkZDwEqtYtFqjYANWFKxVtFXjoxrfoFbx1cZHKyparFqx0uafc+7M7uwmoPbz//v0t8w9Z+7j3HPPPefcO+fekOrWpZQQQnvoqIsIuYjQtotoG7mIttG2i4S2NgL/0LaLxDZIw7+07SKpDSF80LaLXG0c5k/adpG7zcJYCdp2kafNxsltQ5O07SJvmwNQ2lL/kYt8bU5Q/M8fy2K24M5SR7q8QrbfK7kkSXa5RuRIvizJl+V2y5IsuN25bo/b4xIlt8ulqN5sQaKCIAkuye2WJI9XcrklDxE8otvlkiRJEgRJEl2ECJKLNtMRI1wiJbluL22jZiLRSxTPHprlXhpZ2hRbIRB/ZHlzUyx+dlNjPLI8TkbnLI7EZzfWxeuqGy6IV8cjZJy/bqkzR56ntuns2IrmOAlm1TbNrGusbqj7SYQU54bDlyysa6xdHGkML61uaGiqIT/IceAWxSIRcv4IByYW4dm+M9GBrK6tDcebwq3x6pr6cHNTXWM8EiNzvGMpMamS+70Zp46a9e0pZ59nTB/Lvl/xrZFZO/5Jf3znRGFM2zcvZcJksUwfx0QzoUZ1idHJoszIZFHW89v1gsqyzh5TjEcni4SJ8I7AO6KPb9cnON61Q3EW1V2QRYUsqj6xXWeZWQJR3Q1Z8iBLnq6163pmll4S1T2Qh0Eepp/crk/KzLOfRHUZ8hRCnkL9G+36KZl5+klU90KeAOQJ6Ke264WZeY6QqJ4FecogT5k+uV0/zZlHP71dmwIMytOnMokV9OjUHL3MPJrTYjFkQo+uODDtzMVYj+5LQ7mZ3qP701AeNqlHz05DyeyUHj0nDeVlhT26mobKYqf16LlOlD6tXZsOJKp6gEmMmn4nfYoDBOJ8abCb+dNgD8tOg2WWkwZ7mZoGZ7FcJ6wH27UiIEXWi5EUVzoprgxSXBmkuDJIcWWQ4sogxZVBigPWS9q1UiCF6CEkZYC2pMRYcYCcFCcMpDhhIMUJAylOGEhxwkCKA9aNdq0MSAno5SBDlYHOnnRyJmSgUIqG4NxMH4LzsElDcDI7ZQjOywqH4LLYaZk4fUa7dgaQWqifiaQWDiW1cBhSM3FAaiYOSM3EAamZOCA1EwekZuD0b7ZrZwGpTP8WksqGksqGITUTB6Rm4oDUTByQmokDUjNxQGoGTq9o1wpMoo0QK1hZZVmnZrJSeFSyIniczabD49sMdK1Wxc6Cx0x2BjxmsYIeTWBllYFO7RxWCo/ZrAge32HT4TEH5Uk7l50Fj7msrLKwU5vHSuExnxXB4zw2HR7fxeHUzmcSKx3KrLJhmFU0DLOmD8OsTN4Ds84ahllnDMOsKUNwEhPZiKhOzc4EReJKwNIMIRiMMn9vgJkZSj01t1gZgmBkhnaFmtusDNPAwgztFzW7rQwVYF6GdpKaO60M3wTbMrTH1NxjZZgBhmVo96m5z8pwert++rCCA+MFZid9uMDsZIwWmJ2MwQKzk94nIXOcwAxlDBOYoYxRAjOUPkgTwAyl0cR69JEZNOk9+qgMmib16KMzaDqlRx+TQVZhjz42g6zTevS8DLJO79FPytC0+gVa2f/e9AgZpic7w/TkZJiedFMkpZkiJrKRGc2Pymh+dEbzYzKaH5vRfF5G8ydlWD59gVb6v3cFhAxXIDvDFcjJcAXSXQMpzTVAJvgzmODPYII/gwn+DCb4M5jgz2BCuieif08rQiaMTmfC6AwmjM5gwugMJozOYMLoDCaMzmDC6AwmjE5nwugMJozOYMLoDCaMzmDC6AwmjM5gQrpnqH9fm84kNj9Dec7LVJbnZSrH72Yqw/OHavihlvuMYSz3lCE4aYgXAdpjGMdiqBPhHsaJ8AzjRMjDOBHeoU4E6JBhHAt9nnYWk9jsDBLPyaTvO5nEzcmk7NxMsuYOZdlQv2zKEJw0xEdElg11G4e6iO5hXETPMC6iPIyL6B3qIiLLhrqN+jnaGUxilY61otnjoOvsHgdB3+5xUFLV4yBhZo+j7Vk9aTxxLt2AH2kL0/SFJvDBCQMPnDD03wlD350w9NsJQ5/TV4X6BG0KG2HCWnWEmXDP8hOFETaOTW5nE9oNWE6ycnZqOzun3YD1JzuTfaOdzWs3YMHKvsVObmcF7QascNlUprWz77cbsCRmATaxnX2v3YAlNCtm+e1sQbsBy3EWYuPb2QXthkiUpyYJ3jZ66cnEVOt1OokIFfx/RsxP6Gw/wdSTA/89109MohGfpDBiKlFdMJe3aKJJCoVjmyp0qcovmBM1F5QaGPDM91MmmqcwwZSXLdZJ/TKTtjBi0ni9eVZUc/moYjcD/0MlRzdV6G5TxbrNoyLkVmbn66IpLtOFZToxxZYqP2HEbGuFuoSo7jHFuC6aRzYA2wgTzaOQKhSILhQKsi7P9FMmMxISfIwwOSTIPkFhSHBic8Vcv5sRv2CTT6oAXmIKcbN/vVWLXChI5vIWJtZrApM1twhMkQsFVSd+AolRmHAXCnmay+KZu1Dw6YTBCDK3OcpUATNKJ/m8Rj9FFgoKoGU9nTqqQJEoc5tqlJF8zSNWMI/mZbJOAE0QDbXJ+ZqHEaxdtismCvOaJCQQhbmq/G5ODLRSkOwTdIjMxAF1FQoqc8/0E+YCEkYBK3xehUMqI37qkxXOPMKANyCOrpCQx7uo6jKOBdCt8mx5Cu+pjIyEF6OsFz4onGSlLjOByUyYDb0QGGFuJudrbkY02UcUxQS2Xco8l7SEQCIUEKCoRpjARFOMhQQJUlFdZCLUSAHjcylcXJkQNwUmxHViEkbqYy1LdC+IiGCJiMyElIgQ4L8HOOABYn1MZh4+CBYJ3iQJJNk2gbbdQEK9LvPWodko1EC4GCc2V+hCFUyVQqFzc4UmOuXbY1ImmOKyuO5tgSzMw7wxbIXBbLLoFDQPyAJgbNo1j4N2TVCYh4khAVgpAu0i0A4PISTIMD2BZ9CPENBg4nAkNlf4JMUklsSbl6JwQCePbaqwZiMTTHU2SG6yx27oMTCOd1aGzgohgdhd9VSBSPOuEmdXvSZlHuyqi3fVy1xWVz2prno0L3QVMLrH6qp3EEx21aMwL0iRzDz2XPHyh8eeMlwiSJJgVAYkJR44orLNCQE5ATNNjsKspz4B3sMcTyo+V5oacKPek3HiQl2aDErEPIm5TLqsnrnMiTPz45oH3qOGcOtuLu26l4nATS+Ii5cJ84Gzmsy8msC8WKnA3BrifKI1fbzQQeZmHnP8MlNqicJcc8/KB2y+RpjHpHHNw9x+oigo8nLMok5grqTgM6kFuiZmKDQNjEqVnypihT0hdTfQ5mEC+KazdW++5oIhnw36hcnQplfAzCqwAbWP5laYwFzMA9NXZl5IyLwfQBUDzZCaCoSzACa6WD/fTxXQmyl1KXN1KYO6lLlCc6hLd0pdyjBd3bYIuK2xl0Evyhnq0p2pLt0pdem2K06pSxhvJJkTBC05VKY7XWXKGSpTSlOZMIhJlUkdKhONhpurTDfkdlsqE3vrrvJT/mKU9QLlLDVR5YyJak9TOTlNZZwCyWlK7GmKeRPCbGxZUHN9gpJub2195OGT1M08SX10lKYUkpsrpKM0pZHcGRrJzaep4BgjeAj2UKVPUzlzmsJgwoz0D/UMLDmab8vVWg7P8RNGTSlq7tjcCyr6toQUNROJlS3qyUIFo4WCZE1dAraMFgo+zcW5umUzvpd1b1QfHLwtqBjwzZWgvYHqOfkhAFJEbLT1ev+GXpzp0GE0vdgJ3ROd6RcYWhXiJz5RSTL62s3oXgBzCcwArpev3VwBYzwwMSR0Y/uukHB4AyS8IeEtTLhDQj8kkOEh4Rimj2J67cYU/ijiExshvXFjKk8C052Iv3JjKk8nprdg+lpHni2Y3obpHY482zDdjelbHHm6Mb0T03c48uzE9B5M/86RZ48Dvw/T+zD9sCPdi+leTD/pSPdhug/TzzvS+zG9H9OvOtIHMX0Q02850v2Y7sf0YUf6EKYPYfpjR/oI5zOmj3HeYnrtphT+KOftJuT/plSeBKY7EX/lplSeTkxvwfS1jjxbML0N0zscebZhuhvTtzjydGN6J6bvcOTZiek9mP6dI88eTO/D9MOOPPsw3YvpJx15ejHdh+nnHXn6ML0f06868uzH9EFMv+XIcxDT/Zg+7MjTj+lDmP7YkefQJhR+MycKBoqYy6O6EIL5l8x9hOdgpJ55zQujOomC6pBDoBnAu7S0ILgCZmFIkID1iQQNCds2V4AuEJh7PqpPYQ43aj7w5lrQBtFlzDXTL1r6F+cuVMTcs/Nx0jI380Y12VIDXD9we0p0D8M1R1IJCIoybO1USRZHj8HSI0gN2FRQKFFdnu0XsLyskaRr7GCKx2LKEEZInBEeJp2IEcioi6A6qAztg6lGZ+frHjMfs04G7aNlMY+pRgE6tKHCEMAFATXNsgxwAk+gtcAOHNoA3l5BFPUlMT1oGk0JysuzwQZ7mDDT72Ee5gFDcGkLd/A9TKjXiUU8+n18EcoNmcCImuuT0wyZbFJG0JC5uSGTmdsyZMRhyGCRAXYZDRmxDJnMSMqQEbRUYMjQdPmAS/wB1s3nUbhlA3bBKDFvFBnMxwUYmbLJnhRSsAx0vSYywbImM/1WuhPSElhqNFmm2GLSWdhPN3poqopeOBRl7iisbSXug7kthoHxo8n1CawIgF1i0u6Laq7PncYuwYTVuYNdQpJdooNdoiYAuxBlL7Q1wbHQ1kTF4gwTbbsv8AcsT3wuEFrw/GAGr8UZbJlSXEnJKMFMdvgtyZUWLmF8bsVeXAjWGg9XXjlzgGrw3zp5pejUAynJReLxVgEJvgrAhRwWhwWo1TyUwqVcarlpL0B9Lhh5Dx9vRzesCSng0NsTkjCh3nJUmDeqCzgr3XxWysztcNKGqidR4VNno80tKAQrXl48Oda8QykOoJtkZUw4M6aEgrtY9irGrlwXmDjXEoJ/e6AURk01OokwovxsrJDVlgvbSYn1tF73TCLMo4rMY/auo1EmMZcKc9ozWTy6jhrivg7KoSMA7bGhQwDttKF+gLpt6CBA22xoP0BbbKgPoE4b6gUoYUP7OqkhHrWhPQAdsaGdAB2yoW6A+m1oG0AHbWgLQPttqBOgPhtKANRrQ0cB2rfO7h9Ae2zoEEA7bagfoG4bOgjQNhvaD9AWG+oDqNOGejupnmuIiXWwTJJxgohmLwHbJuLCGher0PV1VBuBvV5HtZHY4XVUG4V9XUe10djNdVQbgz1cR7Wx2Ll1VMvDnnRQ7STsRAfVxiH9HVTLR9I7qFaAVHdQbTwS3EG1CUhrB9UmIpkdVGOwOKskBow6TxyxE4fsRL+dOGgn9tuJPjvRi33NMokGq1hLqJSo7mOw16dEda+5siVqXtneS5jXFJYxYXY+WFcvyzLVFt1v5rUum0EI85k0yrzMb6oczjLVqJbFFFOI6orZS2Zx65QU0640Me1KE9OuNDHtShPTrjQx7UoT0640MQXITHTQKPSKPxJd8FBB70xkrHLgv7+/e6Wu2QLHKhPSSp3ZEjeBaWzigt0r9Ym2fI5nE9kEwEyw5beATWDjATPelu98Np4VAKbAlv9xrIDlAybfnh8nsXw2DjAnWfNnMvJjxEqUqC6qj2AjV+rj2IgFDIVjZxfVR7JRKwG/gKHcdXdRfRQbvRLwCxhK4LYuqo9mY1YCfgFDWdzSRfUxbOxKwC9gKJWdXVQfy/JWAn4BQ/lMICp3pZ7LxgJVeWzMgtjulfpYNnpBbHclXQOZR9nJ0WyknRzFRtjJkWycldRGsHxtHCvQ8tl4rYBN0MazidoEpmkTmchk3JGX63Wv+a05XCJG2HN6pD2dR9kzebQ9icfY83esPXXzcNYq1hTosqeAnThkJ/rtxEE7sd9O9NmJ3i4KEx33ImAeCBXMa1LYmPCatEXLZl7zrBYtJ32adPFp4reniS81TXypacIniWpPEj9MEopz5XgF1IwJlGNNIZw/2bgLkmydUaYwOapnparKclSlwM6+GIfakoW85vKWqK4wZbJIKtFnie/uWGuIZMhc6U3NFa5y9HEMdn+5ICeVcl8H1QtseR7n0N37O6ieD8JvvUiq+INYYhyfE+MclqA/o0TSYBzKKJG0K0cySiTND8wqnj85w0D5oobt60Kdi73qgopzcfom1vHJmr9ST87h8Q7bsL+L6hMsdb7S6jIbz/JxquQ7TMrBLqqPtwwAzOICNmEBy2cF9lQpcBijfrvSbsgLXRm/gBUk5xLkTZqxQ3a9O3m9+VBvARJg500awCN2vXuGr3ecw3Ti7EGTtpLXOS5ZpzU1UTLM/Wt6SVI8VJKBTqxLQzt0ru2luFDXgMFTf5jcjZJNqs7VvVW4qzvOIET9oYUTYBNPYOPOJCCCQkh4GEmRQ0LvOsq84CXJsH0bEvoQplEtyxQ0xZQ1P18GChUsi28wZk0lRPOB6+3F2quY3+wlsL9kUeuH74ZAWIogl0WQ3ZQfHn42rkfzMa+WpbAsbFKogHkW1SmsuHrX4ULDsqKgGbLqYa4LZhsT8tXxkOfhdZR/b1A45QJTomZblPlg0itQp+LDzWizlzB3ve7n9WfNwQ0vNMiVYFJXtqA68jHFJOf4JbtqyaKD7wiD8liGu3aSpvqoov7QlDQfyzIlda6uVvlhPQFfVJjXfAC2ZZnE/NBLr3l/W1QT4KUIW5cS/Gbna7av4LN2TVFLUtCSOYya7hbc1c8yVQ3q9YkVzM+ygGIanYq9U1vizMuTctTMa4kzXyyGfWiOarBSzAJll4XKLgeVHVQwlRAzj2WZYrweCmg+halMNIV4lPlgcSEC10TmZxRoFxit1wXcOuerJkaYyn1sN1/JwfY0iCRIAmjGxHoanURysLFBvbtlbS9RRxDFpKasnjn0yXwsC55/LxBy+HfeRAfFL72MVh546t6t17797N472wxylDJa+dKTz17x/rX3vlxhkCMAP3HXVavev+vuO58lBjkEiO43rtv+8p8fX/sKMUg/IDb17GrfdfXfH3ydGOQgIF6/a+OTD79z2bFLDbIf4D0f3LJ9896/f9TVZpA+QKzb81T7W1ve23yJQXoB3vjoVe//bPcnj59hkH0A795w9U8/e/jFm6cbZA/A1xx54/K+wz1XPEAMshMQPbet/vnbaxJ7HyYG6QbEqwe2r/rzOxvbHycG2QaI/uv3fLr7spsO/q7NIFsA8eJHL935zDN9r4oG6QT4T+9d8di76969YbZBEgC/v/Pqjn37/trxIjHIckYrb3/3z4c+un/T7/9FDNLMaOVH29/4/IXnel7a22aQJYxWbn3w5SdXv/GLp2YZ5CJGK+//81P7Hv7o/VvXtRnkQkYrf/P63XvaV6357QiDnMdo5d/+0H3Fy3/a/Pa5BjmH0cr33tnTddN1t+/4VZtBKhit7Ljqjkc/u+yTdyYapIzRyvbO9UfuefXlR7vbDBJgtHLV3Ucuv2Nv4tFnBvtfyGjlZ3uvfO6ma1/8VDMIY7TyZ4cf/tMTN350m2mQPEYr3/nrA3+699knnrxnsOsqo5Wfd7334r079q5+ihhEZrTy2Gufbv7wpVe2jQFtQSvXPPDbV2/ftXHzW+CZuBit/Pk96/b8+dlt155jiEcAvvPp9f3XdOzdt6nNEA8B4vAvL9+545abjngMsR/gg0/duL/v6FMfZBviQYB3b3+gb/+zrzxXYIj7AX704dWXf9jx6a4yQ+wDePOvV934xu5dzx0BZwYQ2/a9+Oln7xz4/Y42Q9wHiD2P//GyN557s/0PxBD3AKL/1qd+duWOxIab2wxxJyB6X/ugZ2df4qrHiCF2A+K6Nbtve2DLqlt+2maI2wDRs/bWF55dvfpguSFuAfjp22/ZcVP7w/f9jRhiJyCu/fMtvY+v7/xnrSEmAN75wWMHtr3y+n0rDPGoxGjl/leveOLIb9b+s8kQjwDcvWb7VXv7+j4+2xAPAfzafXfueGPLgbd/3maI/YC459Fre69c/cYDpxviQYDf2Xh3YteOhza9AG4cIJ69+m+HHurr+PktbYbYB4i7N/QfWHf5pjt/D5wAxC8OPNh7YOP2Na8QQ9wHiBuuWbVq+92rOu5sM8Q9gHjwjtWrrzqweuOHxBB3AmLtc4m1x1a1b1toiN0Ab93xyaF31qzf80ND3AbwzX3v3v3guiv7b2szxC2AeP7mAx+tvezQ0w8BIwCx8w83vt1134tXdLQZYgIQ+1Y/8/wTe1/aIhriUZHRyiP3ffDYC7233+A2xCMA9z968IrdN9z4oM8QDwH82qa9zzzxxkPr8wyxH+Ceq6/6V8fv120NGOJBgJ9b/+rHm9ZfdnM/MAIQv9z6ZNf6K3/xfIUh9gF8YOe2z/+w4dgvr2szxF5A7P/pzVdesbXnL8CpfYDovn5H9993PfYgcGoPtvm7vt7Dzz27YT1IBCDu+WzLO9vW3vQzryF2A/zOkRvu6d656dYRhrgN4Gdff+jj3v3XvDrJELcAfPfnn75+YPUnd/YBGwDx16ffe2j7Tbvf3wJsAMTmvZufv7x/zSuPwMwQYB6+fLh7e++j+za2GeIRQKy+46UHHklc9qHLEA8BvPbQ7Ws2rfrj4SxD7Ae4893XP9iw+tiLowzxIMBbnr/r3o4dAzcUGuJ+gLt/ev3fNh66+6H94N8DovcX92zsPPJxZzcwAhBvbb7mp5/3v7F9DTACEH09e558+/XVj+Yb4h6ANz115ZZnHvp9ImSIOwFet21X32XrNqw6DBMDEJv3PHt08+arVl9oiNsAvvbl7qs3XnvL2t0gD4DYd8cDL7/02+s7HwVGAOLIodWv/e35+7ZsBkYI+Jmamgl3FPep6DGFcBBtLTUTQtQsg6+IcV2qh5AmzWVhy5mFcFuIGTbCYyHOsBGyhTjTRngtxDdtRJaFOMtGKBbiWzbCx0TNbxItG8hi2WCG9ZxeTaokmipUsByTYgCRD1wnFVZNkikgBhYXTG23kRIis9KRMiK96UgVkXI6kiHSk47sJYh1p2PbvgNfk5M45mcqLG0s1y0bXLFsMyGge4HHqXD7GB002QoR4wMDw5JFCCOV97x5sHPg8N2PGoPKvJ0yUvmPN355Y+Kq/q7zB7U5Ij6959hfftVx2bNzB7U5In5+f8eqh15eu2X5oDpHRMfVjz584KPf9r4G61DEPLt/7aM7t76zefGgRkfE1Xf1PfXaP9r/8ZNBlY6I7lv/2nnbYzv+CLOpFzGfX3Hjvza//PZfPgdVhpgr//neNS+8tHdVYFCpI+L1zbe8dt+n1/zzwkGljojPnrx8x+Vr+xNsUKkj4vGPbnz2X/e+uhlm+TbEbHvtundf3P7r15oG1ToiXttz/5pbdl114L8G1Trvz8tPXnbw6l1/fo8MKnZO/h+3Jg49f9efftU2qNrXAuajt6/42YZdd28tG9TtiHjlw13Hnnnzs+4uMHKIWfPgUy/dsPfowMRB7Y6IJ/6y4/Ob+1/66Pq2Qf2OmI9veumVNR0/v+wfoNcQ89D7H+y6/OX7ngwNKnhEvH/3VQ/ePbD35Y+BL4j5+e8f3vvA4y/ACO1DxB/vf6l/8yu//OWVoOIRs2PtTbfc/tDT980YVPGIWHvFHYl/3fmHw2cO6nhE7L3uidfuXXV5ewL4gpjn9/1iz83PvbEV2L0FMf3PHzt4y0/vujZ7UM3zPv5m09Xb1h/YAxYwgZieK545vOPQC5eBCTu6BjAPrL77ze7edz8SB1U9In7xu1dev3rzv155GrYpEHNN118/uGPd5S8Dq/oRs2XT4Svv/uzxh+OD+h4Rt3X+bNO9N7156B1gDGJeOvDI4394ZM8LwBhe79Mbjj5xz0P3g83vRczhDb/65IMHH3ozPqjyEfH8T7d89t6fbnjhAOh8xDx22b9+86+De58pH9T5HNHz7hN7H3lm+wWDSh8Rdx3sPPzs0Zsf8w5qfUSsXb/zwdv3vLzdPaj2EfHR1g8vf3zXgQ2lg2ofEVfsem7/c49tfagT1B0ntue9d17/w3s9twJbVgOm96PeA3e9vefZv8KuDWIO961fv/X6vb+eOqj6EfHIdQ+++NgLf7kfjCAi7vjru299+MKuf84fVP6IuHPT51sPH173Wdag9kfEb37xwB3bb/1w3/3gDyDmg39c/usdl2254yeD6h8RV1923buPf3LNJ0/ALELMm4/s+PMjN+y//hoQF8Q83r3tjg/XPLp+AFwCxNw7cH/i6D2bnikdtAGI2P7ee7v+duj3f4GWtiHmqjuuvfpvV/UOhAetACI+eXTXM+8+s/NXIMydiHnx2K/ffPH6FzaA2U4g5tmn/tB7398/vazJEI+uwsn32KPvtd+xb8cKQzyCiH8ePXLNm7fd8CjogUOIuf/dow++sHXd3WDb+xHzyS2XHbp1yxPv7YKJhJj71vVdc/dfHn7lQkPcj4gP/9p9/dXP/2NXiSH2IeLWGy/r6Xvxtg7dEHsR8eif9v3xwa133XOxIe5DxG86/36go33g2ixD3IOIDfcfufKTJ1486jbEnYj4/O83dT1x43WfxAyxGxF7731h1WPH1nx4gSFuQ0TXnRvW/P2Rp98yDXELz3Hne7/e13nvK5WG2ImI27ce+sP+P9502dUgLIi55o8fP3nz3vcPgGgcTSDf3nvw8/6db3/4DAgLYra9uPFA99P3DLQa4iFE7F39/G8+fODFd1+HPT/EHLz95av2vv/7l2FYDyLm8WduPrh738GXX4VJhJgXrrntvnsfOvQBuMV9iLnrrkc2rd78wO8WGWIvIt75za/+fsPnV63dC/4CYq4/unXNtUee+HO9Ie5BxFXdP/vb7X3X/Wa6Ie5ExLGun93y4uW39oBL2Y2YVc9ed8XVP913H0jdNsRs/eWa7esfWH/DjeA1IGb7NQfePNxx3W9dhtiJiHXrr956/a+u7v1PQ0zAkgq3KiYR5XmfoLZNudRagudETWmZLuLWJYVPFFSTrFfZ+IoHYzEBQjlNYU6+6Ue05CemoEGMFb70IRJ2CkRNwmhie9k/Jx8CiKVluge3EZIlXPCbk29m4UvZT0xXqiQEM7nn5JtefAnBee5USRmRWX5ielIl4FuYPCff9OBLxU9MOVXCC785+aYbX/r8xPSmSmbBb06+6cKXfj8xs1Il4XujMicfwpqkZXq2n5hKqqQPfnPyTRFf5viJ6UuV9MNvTr4p4EvVT0x/qmQ2/ObkmxRf5vqJmZ0qKS3TR/iJmWNnF/H7s6naOVwczrXfSxweYb93c3ik/d7D4VH2e5nDo+33Xg6Psd9ncXis/V7hcJ793sfhk+z3fg6Ps99nczjffp/D4QL7vcrh8fb7XA5PsN+P4PDEVP/B5TJZqv8Ia6n+I6yn+o/wyan+Izwp1X+Ev5HqP8KnpPqP8Kmp/iNcmOo/wpNT/Uf4tFT/ET491X+Ep6T6j/DUVP8Rnobv2QiAqDldkxQGE2htey9RC+GLbqkgaSPhQbRR8PBpo+Eha2PgMUobCw9Vy4NHgXYSPPK0cfCYpOXDg2kF8JiijYdHoTYBHiXaRHgENAaPMzUNHmWaDo9vayfDo0KbBI9ztW/A4xztFHgs0E6Fx3laITx+rE2Gx4XaafCo1U6Hx0XaFHg0aFPhsUSbBo+4Nh0ezVoAHiu1IDyWa0XwWEu1YngmqFYCz41UK4VnJ9VC8LySagY8t1CtDJ7XUq0cntuoNgOeO6h2Bjy7qXYmPG+h2jfhuZNqZ4FzXirsoZWsAxJ30LWDhgniPL8Jp1nhI8ZOgM5gZ3Kom+Ihjxkc2gaQAedoAdoCUCkLcaiT4mmOEg6B7mVBOF0L0HJG2HQW4EAzngeZxoEljLDT4YAsABcxwibD0WIALmSEnQpnjwE4jxH2DTicDMA5jLCT4fQyABBToMHxZgDgRAp8k0QATqSMhwPSAMCJlHw4xAsAnEg5iY3jAJxIGcvyOAAnUkazMRyAPc+RbBQHCOfcPptzvwPO7aM5CoNg1Y/XcvF8S8HdTDQdkmVVXGhVRG5V3BCSAgf2R1WyzrMEYuXkRsYDqzGRjTpLkPiEhl/S1rjR1ogwO0R+dQWvQ8a8vqQBEXlcLNbj42tH+CUtkBctUKoe1apHxbwu+CUNUhYapFR9ozCPG35Ju6SgXUrVl2fVl8eXqElj43HUU8DfwS9prfxorVL1MKsehnm98Esar2w0Xqn6JmGeLPglbVgO2rBUfYVWfYWYV4Ff0qSpaNJS9U3hPIVf0rLlomVL1Rew6gtgXj/8koZuBBq6VH0lfJThl7R3I9Hepeors+or42O2DIL1zBxHJWemrJ5ql6MpWapIGblcR6lvp2zhCEcpW3rOSZnCkY5S56Ys4ihHKVtWzksZyNGOUgtSdnKMo5QtERemzOZYR6kfp6xnnqOUPf4XpYzpSY5StSmbOs5Ryh7lJSkTm+8o1ZCytAWOUvZYNqcM73hHqbhQwUba9jlVyh6x5RgHaJnrVKmVKSvN7FJCarwSNGWVNUextTRlvXVHOXvEOmnKep/sKLeRpqz4JEc5e8y20JRV/4aj3JU0Zd1PcZSzR20bTVn7Ux3lrqUpq1/oKGePWzdNeQGTHeV20JQ3cJqjnD1yO2nKOzjdUe4WmvISpjjK2WO3h+LgWV5FqtwdFEfP8i5S5ezR2wdBmpa34YYTA5K5x1LnvILfgZYXmWR2W+jtiuDiwWS9XbRelycRUNRxOJViHVEcwMhaLx5DYiLzNupe5qnHeFbvnHzNiycemJfrey9+uvQ2Itp8CxqZTRTzeSsBGZnUoGfhtp6K8R/wZdTF3CocWZAdQWsIJYPWEEoGrSGUDFpDKBm0hlAyaA2hZNAaQsmgNYSSQWsIJYPWEEoGrSGUDFpDKBm0hlAyaA2hZNAaQsmgNd4/O2iN988OWuP9s4PWeP/soDXePztojffPDlrj/bOD1nj/Oqnux/gFPkniuoLH5TQf9ncd1bKxq+uoloO9XEc1FTu4jmq52DeMZpN5pNpI7BFGs8k8Um00Ut4B0Wwyj1Qbi/R2QDSbzCPVTkIqMZpN5pFq+UgbRLOJFUy2I9VkO1JNtiPVZDtSTbYj1WQ7Uk22I9XkVKSaZBLNCzVySYIDJxBRAwkxFQYjOiLVRNxY1j12EA6BIByIn7QCbSQItJFA/cCXaitSzSGbXWmy2ZUmm11pstmVJptdabLZlSabXWmyCRCPQ5B51ITMI9VkFeKuwffjkWrjbSkrwEi1AlvMxmEcCg9D4UJ5kiMihwttHhvHTrJizrhQj2UnsTyM97KFfgzL4xFgY+1JMZqNZWMAM9qaNDxSLXslSlQX1bNZDkSHZUMomcwj1XKYuhLwCxjKHUSqqRBjk8PUBQwlECLVctmIlYBfwFAWt9hRb7kQ9YZS2ZkR9SbzSLWRzL9S97ORQBUPQIPos1w7smUEU+1kLsuxkyrLtpM5GN2GkWrZbKw2huVpY9lJWh4bp52EkWvjtXzmwzg1H1Nmc2HItudwjj19VXvm5tqTdoQ9X0faU3WUFaQmMz4/h46yrQxdEEoMEVeTCMtSPvJQdxs/Pu6DoAIIY/ebORCK5jePDAwM0BY9x2xu1VR4R3Q/2KR4VMu1w2rwEIRqqlHwDsGQxTU/BGNU+SXmN1fCx5BciDZXWa4pRbVcpjKwMXDIivkhdEyAYxd+U4o6ANkJ+JyA6gRGOYE8J1DgBJgTmOQECp3AFCcQcAIlTqDMCZwZ1XIwwAf6mYMvZmE4iBsPqc3Ek4QmBtmTykQCLg80BILRMRiTxDA6JgdsVg4cwyhgORA5ksdymARHCnMgdEdlOSwLIvNz+OmGnMq+W3ddMwoOcciawHwmnnLwsRx4iZDEI5OYhB/NwHuXmaROYD44SOeHh6zl8MVTHE/GMegFBLmITICvUQrkkSB4yMf8TIrq/pDgs4/JM4H56+daNTM/c/OaJaAFa7bawRO+GFnjhnZUZzsuplrtQAVRnabq98JKQlimZ2N4TbIdyrJ5O8kGYAuSxymaBLubzYOEsiFISGHZ5r8GBgbEFi2XQcQQSC18EPUzj6ZCpBFTzaOgwP1MRu2NZwegJRUYxl+B/k69VeHjnspPFQjQEQjKzEUlTiGYiTIFu2s1xzy8s0OboRBFRKHz0G/ocYvur/KDVZWYO8qyo6YHTp7Q+cgyAWrRBThkQsw2kzE/xie1hAQ8XwufeA9ZOz+mZDL1u/h5EXjEQ5J8kwXJgDMEPgz0V95zU7kNeY0nb6LmyhY4OVmv43lfCkcJJBgBHrxkii2a2yR4ShooFBiZmTxWTfnhfYiyXTknhcXPuAQCRoUphJj3t30nil8/HYDgBMQkoIHqghNUVlQVsBprExw5oCo4iA3fU712+FUUzzu7eXyfZB4bGBjwtKQIgKPXXD48UBfldQFSSCIFCwkxczSqyWIF82oiBHEJzGX2UvwHj1kBT6y1pBDXJfOIp0Wn/Fw4d6kpnuKHEzecG1yN6V4IlPUsY17TvSxmrlq9Vm5hlOu747yTjv/ueC+EaDQaxS//KkQLEq6SXMxTr7nARYdP9HC5hzmw6pinBS4Cg2fUXJWQGkx1GcMgRGCkgs7LMdrCBRDao1Z7NNWexpdiyFs8BzUssTBuFq/xig/ByiRkZILYACStAHo/DGV8IxSZDftKMBQwr738HAVwDKUYohZdljyiFweriqhOk1JHnfJInfJInfIoMxfzgkR6caIL1ulwbxTHVWbUkZPP60GGtkWtsEBZ+cRN3W3OY9HW4mdlVJeAoS48KGbCPo8L9IAnn9Eo78GpUeyRhx9Aluf4BfsIuZcfphPVHF2A1VOOwmUN7nqZ45dNiFlUonhrB9zEosE8X27VhlXD+TIPnPf3gKmayx/1ZiIhz/F7GDBWZu6o5rE0PioiT/L0G1+T2+ffCJ4A1F2m0OL3Mhe/PcGNF8DO9nvh4JUKu2YuCJb08NPvEqMMem/SlpgpxEAnyoxiaGvykLkLanAlT4ExyjxqNlx0IjHX0MIuKExTZ8ZkxT5X5cY7ZGf7+SEbD1DhxlNoPC6zhXlStXjSazEJaj40NECAayi97uTtGh5oB88XOo9Ewkkval9g4eZXeXjQP0Ay4Cqb49ZsnUXktdlMcKvZ1tG6tcnOzfFLeEoQ9A784O6dKvvqBm5nUdZEflcHyJoIsiZyWROYCDe/eJg81+82f7eul5jTzMPreon6TaKYxzoQvsWGv+D98N1xccZTm6/Ak7X22fmNcKkHv5UG9hBUqlNuDEVzpbmcky6YYks+3qsCllCEFRfvI031EaOdiZqlMEHpdlOpDaUeNKC5kt8thAdwo/xiF7AisP8gYCgR5eoUjhHC2V4wOEAul3GItMZLa8QWU5yF2xXWsUXrMht+mBMa4sOEJyoJ3/ZWVWXI9IdCOjQMFSenlcDTnfyAqWjdIqOqUAseikwKAYkyy5GwCUzRhNswwhCSkoUU6zQuotRcflwQ7jFoQzesGy5Y8LsBeWhDhQ73LA1MxM9NfNTSDjcSvIQBUsmGUAUn2ZY84Jk88qnw2xq2wenFuX5X8qxiFU/DqUbJnDzbL/Fz1hQXGJSvrskcfigWp2h0NlxtBG62Rq0z1jTjSCUnL+EkL8UH1JncNqPWto9+g00ZmIj/zMnXqFVvNzDWeQ5XTB5bFvg5XJEJwx5bFoceWxYzji2L0AIYKHQHKb8YiOLh3FSjfDQIHw36VYmH74N4VNRsw8OqitLvSi79PPbST4aln5fJ9tIvC5Z+Cmc/RsxDrF7a0k8BH0PBA0xCHD0mGZZ+Miz9ZPBBiaagAw+h96mln5xcRMnOpZ/sXPrJzqWf7Fz6yc6ln+xc+snOpZ/sXPrJzqWf7Fz6yc6ln+xc+snOpZ/sXPrJsPTL4ofMNJllOZd+pmfOcdd99i1FYL5gFSeBauFGzkrJWA/Pkwc3mxcKsvXKp/t2w4c1uM5Gp7vXQqJAF3YvQP/ABffRwGEHPYvJ3Kk2hWXRWX6BKeYlc/wic7Os2X6u+xTGWzZZFDdthbg6ueo4VLsUNaQr/HC4gtedMQWkSmIKPxThMSV4h+dMZA7BEXoJlnkoM9ByvXoKQ0/BWpR4+KKEX0k3pE1xWKxwHK6aMstibvNYOz9NgdceTCLKqy5BbqOXmqebicTDhM8RN57XBjcLz/SMXmZSRvOZK8r3pRm+l1qSrrx1Mle1HS4ZL/gCHGbx8EP2Ij8hA5PSg/nRBxVNX4vms/FwmA+P4Oj+pKfpd/qgfqcP6nf6oB4G51Yk+2xeFo4UVIV7LR7md+SEKjU/y8KTPCiQcPJd88K2z1RG860DNV5GplOfTucnr0/CnsstaFK99eg3W0s+6/X4ZabYki0JhBK41Ym7RbL5rwG49WCZhuv8gYEBAhoETu9reAMG0bLwGCMsxUEvDYgt8/2CSWEhDqR6WBbzMqVQUHNdcNWOz4XLOTJZkPXsHqBpYEWLuSeRSIB6lbEgXrqRxS/vgVU0DEzALwLFSDy2gutGq10hs0kz4GxSUhR8m8VStQksCz1NpVDw5XrwukHNA86MVTtJ1g5Ow2w8DUTm+8Xj9EpMNuGsFJyXbEOQ0YNP9szDe5aVpAVcSiiWxYtpfoX5lV6Jypb+luybHyH+B30aCKB24RVcLpw1eCsCXYZXKUENcIsjv1fJbckmrqllh4/i6rWCnOE7je41ExQUn9fspbP8Inh5MMsl7odIld2JRMJt4GUScLdjVAcVB0QXChKyAPjv4l4q+utYq4u5gQ3cgdfgTi7M5eK5QIXpLiZMJ1QX7JwuJkBOkeeUFJvfMk5XE+nAs3IuK2hcMF2gooVCQrUsmIle+6I4PssVqyh8LYRVszydEI1fAcVcpoTs5HePwKYF1AQZvHYGnDHD1ODh4Uc8zhz/zgDMEzEenU4lrB6Ur/XSm/YSlzjeM2g2k5hyBvUxCbb/5HSWo7M/PKcJcka0OaPY0kCg8y4Fv0JMIsyltEtUaAOvI2pf0QFiZ4mQaLnFhLte4GXYl3gQh1sspLvF1L7NQ0i5xbjfZV3SQa09K7jZJOUX820DkfvDgsMfFhz+MEjw8hZdVFXuAkbt1QT2AGtmJM0fprY/DEUzaUkWwt2E5IUrVM3NGXLfCkU/T7TvWxEtP486/DzKJQBROk3et0JTfh5N3rdCM+5boejnDXN3CEWPnN8dkqTb2cVM5zpnuItFeC2J9Frs3ucoyl8F6moDc4eMeGrgv+H+PfjNyddJ/Xy8uFVl1NpQoPxq16jpi/KFGlS6PClBYHYkxpUQXlcCOzFcRnW4EwekOIpXHeLtOrhswcUWNIf34NXrtB7iSmEBzK+NBZFHF8laUwL5EjQjpRbGLlh1pm8KCNDztEwCo2o2zmS8+A6qsW5zYTRqiQLOAWo1TXEFBvY/OtdeeqSRIcLyEi9fSd0zKlh7tak1u6hmWzd7wnat0iXi2hS0b+riyqQIwh2Loj0vcPPOugARL0QU+C2IFHW74LNumQIi+FVH1LqzL3WPopCvSWIFk+Bsjy4AWkA01EbzNYgbhNqpXTHcQmvdRitW+e27e0ja1YqUX60I61gVtoMpv790FBP8IuhkJIfAZpmEF/cCqDIBb+zk3QKiJX4M2KJcsmaBNfHMS3HaWTe58knMb2TEKy0p39+gUC+2lqdwBqVejLJe5Cg5aVcyEseVjI8KVErJvrwM9xTMifzYVCIhz0chOIWJi3WBX4QsmDQeM89qxb2GSmIAA6CZAtym5/yB8bPbo3CrLVoR0bpfEvvBiK0EQCUkyWNSDAnLsVcKIgyKZF3Bi2sEydY0cI+pgPeYCvweUzjUJTHRun5Ux2WCUIXbCjQO549gsB1VKQrfirXuf7K0E+qSvPTboHJAJtSo3QFpSAeUmwSB8hPKzOmPEH4zGoUHNXNh+QL3TMOWo8AtmWXXfHxbz/RFd2O4IYdkhFT8q1tot5ioTsU9Jqsk0ijxivXjl//TkPL0K5XftuqE5U9AeUbJHoF6LKdNmISRd9zASnCInknmIc9sHpAH8xW9MncU77syV0aZC0JjzC1Pk0bdBY8G2E9Fz+Et0gj3qMWnk97OijMI3oIM359k8y3SwB1W51sRVuewCTFwyxvSHH4hLTokXhgsF9fbvGnz0qguwkLOlWqJDFsrrwM/OfIrVK0K2rACuD98KnmgswKvCKEm/E/s7plZTKxXfXhEDn0TUblJoO42foOeYOtHWKyYFDdUOEKdb0V7UsUUTQnnLNwdAFeboQnh3GVCPdfeOBNMqhbwiiV+uTV8EJGSbfCt92+1mIm2GB6HdC+DTz12czC8MwiF73RmL43NIOjOUNO3DL8lWeV4gYEBcY5dAqTdzQt6zP5kQXwn8ncCvuM18JzUHLHMbMa8fAcamYdbTVHUGspBSqklT8okgotEs29tL844WNjDJ3+QA+ZlWaon9TEBPluZklqlOO8oSKGs3WMxhYI9A9zlx7WL7oGOKcBEXElAWU3kCDf/yGBK/D5iKM/veoYR4p/L1EqM95WYhJcc4IXQEr9yF69ugMX7gVTPIHRM5t+A7U55VMEiGTbd1XNT3fDYfXCba3Ew3ep/oM3hm8VCWpfgmnhd4lcZu+bwm+FljLXnsWoCR0CXkGANLmQUUl2CcytIBFUr8ZiLxKjVJZflp+AWuPXdlQoC15EBnP7WxiYYmsRyUO24IMOrBUPCFCbgHodqCJOYYE6Kmhs7egm3B/AP7iGr/nHc154Cd8cxSE8WJukS6CHKb6SgjH/7xY1ScF+4CzpZIBqcF7eaINy8qmCHDVGG8ATTh8EJ8KFNMn34UZlCUyqvFWItDXS5N3bCxZ4Sd2RhTgegs79NjZ97EiikIZKpfhXhc+MKgd/RjyPlxv7w7RchDgNj6QprpGDBINkjhcnUSInDjRRcs4f3908iyguUCm3clrlSf1VBqDCpejosXLNFiRKKX2H2r4e7mNvOhe0H+BoUDQEK3jy/vgIWUC5TjjIc21G5EqxfpRlkPHMxcQYpgNUs2FIXHCWWDUG1yqG1hfTB9RW8HCgLF68mj4NcbJhLLbe8G2i2zW7eJDNI33qLmbCsmUkUovw+KYG9xBLBpPvJZvlJ5arVazu3dPcmqH3ghOLtNXgaC+PzBaWSaBLc7tJLTUmdh59quNSU8UeAPwr5g/FHHn/Y8sUfxAygtMO9PvxyH2oGVJd1mYpDoHoJXppiSmYvDtrNvBtwUsCUrI+Urt1M3H2pLsNfzqpKHiaAOd6jixavpPq5jjd2z7m4uRhtAO9JrXTs8DFJ8/gERf0hSKw6FyMD/JYHhFqCqy74Tgi3AeEVox5+Y6N1B4yyNTUPUJT4soTilfIwTCiELtBuxPoAVYhrBvAzIQQE7rOGQ+N4PoHOzMf1BgUc0pTPd4qd+8ewU8xrRWXGPTmbaJSsSURZlyJLPC5Z4heTxY/S6YKqWZ+1OCEiECLahIj4kSZFCO5jiF8rIdb5QV1QJ/7bhPCrVwcGLLsNekUXeSDJHL5NagsLRd2EwkIxmgvt3FzNDk1IXgfE98KJyZfaqVuCqLWUt9AmSWVTrkhOUvbFZqIgZSZGwUruxGaiQLeUvDBZGKXjTMwwE8Oocwbc+R9KYZ0kWbuMZB66YtSUUrvkSd5QZIxkM4aqczXJ8g55Kd5TawJJfALBLj6Gy0gKvwNfSg4RUTopFdscf+XGkgV7u6qKb0ThbRe4z8mX3QL/RLoDGIJSw7+STSUyI1OJVz0JPVFkK+bkCxpq9p6gwEVUaLPv8YfgFXSCyayoLlTA9/W2WfzLAbCKO9EqhAc1R3FhpsIfNCFMqp+Zrzm+eyR3IaCryQbsj9a46yUN+WhPbEspYaTIHD//9p3zhR/sf2DtuCTdQ1l1Y5QLRulJzIPeFMZRubgF5QcjuTflGd6bYrLyY4twe31vfXAR6+2dE0GdwP1utNqCtUaANqv8Fr/5TVuUSfyDftLTVX5E6RdU7nTsnQ2k6kb/MaNikyjzHAtXcRLhH2N9fLUOLOdrQ6BNtlZ4Ig6mz1rh7UxgFDhsqcAK7ztUbLNuOhPwODQcvoMNWIlR3Ied5Uf5h7+MkxYvVOUX4DM2DEo9rGBEpcrSOTzcB77W5/CPLkSllqrhm1Qr8d4ya7eMowgjxxSQb+V8KgBBlDsJr67HLc1oCFIoxuQcayqTqeAx4B4ndx54iYfXV2B+eAoYn6XMgn1jykM+Kd4ljQ6j7HAYZdthtHbA+O7ssXVO3aKcwVW/k/GqfRu1aNIzaAHfOgKWA8NhT0c0R0XVEqKUoF6s41reUo6M1s/hf/Sqyk/UH6LfMxc9FIXRHIUoFbbu4PlncrbaM101c/i3buwbbtBZf26Ii5DqV8qtP8uReBj3v1DfJT/ymDQn+WWQK3uYnPyLi1JCaRtzQWCdKTWYkjpNczEX4yC/ACZ9UjKP+g1lGs5yq5OWqmJiPQ+DwE038GbxIwJ0rwjakDQP+BO8DfhcwMEhbcC6aYwyxZZ+rlrhuxtXo1wXMsGA8TOlqLqAKCcnTbUwydpkS24bQVSsegFRCihtQ2lQURoISoPKY1KVCTiNufamJj3HnpsU78dTJpDk3JZA9J5cD9d0U0bycyVClInWVfLAhVjaCPLiBc7aqzLqHksyplIOziNllC0vKC3fJYp6DlFyCd8rj5s0xqf1CGJPN+Qh1DgOvzdMFj7uqDD4vtlk4TCkSfLVW45Xr/JXY6w+Uq7IJEs8/MnBUb05yshhJotqyaoli6ai2LRMylGyOPkXjiNKDq7JOvhKS/UrucS8trOXmCebezp7CfQlm5id63uJWcwQ9Fn1mLJapCjkZMLA3T4ZX8AeOd5PpHh5C2fk2KnTMAWsLLXrmCwQ+Kt0Fno+scgyZfUsxUMYUcsIf+pEUcC7ozPIjs0VWCPfcpYhJavTFBdRf0QUkeQoImwtEUX5VRYluBGo3JA1prWhribC6hprI8tZa7w6Fm9l1XHW62cLL46zSGMtB4mVo+niOGtaxBY2XdxY2zqDxZdEWEOkkdW1st4RWAIwPCvgyIhYdePiCK/Ywvfqdi383aKmGONENC2CyhbHl7BeovKXkcbar1Rseuzi1njN9NLysoARCoYiwUXVRaUlAaOoZOGihdU1wZJao9wI1RqRkurislBgekPdwlh1bMX01njt9NZYzfTWFa3T40tikeracENTTXXD9MamMIdbp8Va/+3qqxsammqwgVj1JeFlkZrpS5tqkxVOr400t06vbViK2aYGphVNCwYxt43DrPGlzdMvWXhxXUPt9CXVrUtqmhrjkeVxzNdQtxCyfA3/PUhVkkUI+QEhJM8B308I8TvgXgvOJ4QUDMIVgkoEQkjAkedkQojXAWsZ8J2EkPEZ8NkWHWY8HlnaHI/UsngTq2usi9dVN9T9JML4YEzFwWGXLKlriLC6OMjawkhd42JWG2tqbo7UEvIMUcl/EULqLTpvpirRCSEXEUJGWW3YuJWEkJGEIP0+QohECBGtp8vKK1uwmxDisWDoC9CtWu8Uqzy0l00IWfqfT7ww5bz3apabv/xBS/6Gu0ff+OPvlXzP8/wFy3ev+u6R7Z9Wt7ZGYvG6pka2qLquIVI7gzW3Qh/P+ibD5+lsaV1juGlZJLYkUl1LSBtVyWmEkL0Sb/t45c9Mla9ePkz5uyROo7NfORn9hz7VVDdX19TFVzCoYlFD0yWEkGlUJedZYw68CQQCwUBRoDhQEigNhAJGoCxQHgwEg8GiYHGwJFgaDAWNYFmwvChQFCwqKiouKikqLQoVGUVlReXFgeJgcVFxcXFJcWlxqNgoLisuLwmUBEuKSopLSkpKS0IlRklZSXlpoDRYWlRaXFpSWloaKjVKy0rLQ4FQMFQUKg6VhEpDoZARKguVGwEjaBQZxUaJUWqEDMMoM8rLAmXBsqKy4rKSstKyUJlRVlZWXh4oD5YXlReXl5SXlofKjfKy8vJqLm8gbbV1y+pqI2zhCvaTSKyJ/GKa1Fi9NEKy/a3xWCSysGnxtEuqW5fSjmnfJ5PDP5pXZqPLapqWNscira1BY8nCRUZxqLy03FhYUlxcVFJTRSPh8+ctm9saCM+Ln90abVletmD2ih8tPK88XGZP8mRi9rx4ZWm47NsWOC8+L15phItbV7SGLljRGo8srQrxN2e3RhaeU1MdabogtnxFuLg1XitMcVIUDNQ2zaxrxJkTNJYUldUGakoCC0OlixYuDNQuqhJPc+YuXxyJL6he2BCBDkRqgqGaokVlJcGamtLa2tIqKT1vQ1N17QXx6jjUGwlWl5ZFysoW1ZYWFReVGlWuDCpa402xiJ27qCZSVFtaXLaotKSmdlFZTZW70JnbqG06O7aiOQ5MLC+rjiwqqi4PLgoWldREqjwzwj+aFww6tF+w+GyemNkUW1odD0WWNzfFoGyousaoXRQJLixdFFpoBI0quRQGAHh5duviC2c1l85vWV40b0W4pKYpFilujcdKa5oubowHS2qbwpgK1yypjrV6T4diZ7cuOnfRilDV7GBp3blhIxwOoyEIFkOqtiEci+B4ZNV8nYNcsigWiQwdYuULmVC31GJCWVF1bVl1qRFZGCqqrSkNVfnO4gRWh4dnw6KlcSSrnNcUj8SCRc3VteG6xnhkcay6wX8yZ+JxypZeEquLR7KXfp1cCBbX1rU2N7VGwjVLLm6sH8qPnMVfZ3NlSyNLqxvqFjcObUht+Fr7VXRxY0NdY/3xupUb+1pbK6trBGMRbqiOLT4uK0eUn2COlKK3VYreWDCEQBiBMFifkd+Cohe2loWh+HElpLjx4qXFdUubl86LV5aHjW/XtTY3VK+AN6P+i1fwI+xvac38mkDR/LkXNjSFS7FTodZ4rK5xMfY0dIGVPr4k/gAkMRhAgcSZPNrPdYM1VcZkpVTj2LWUtx3msp3JlvLm6sa6mvq6xsXBYkyGl1Q31jZEYvPilSXhoDVZOE3nVa8A1Xg8HmLxYNF58LCylsWr6yPhhU3L8/x84loUnlRsz7UT0MMrDF9SF18SXtLUVD8uZ3EkPpu7S6hw83/AxajkOGw1LIcU+RoMnl99yfcjNbMbGyOxYHBRXWNd65Lw4ljTJUOJKACmzZ63DCov+oLKQT7L0mtvrjJikdZIbFmkKFjbFLbS4erGWou5KMTV9uDPamhaWN1QNZSM8T8K/2heemtFAUc7k85dMMmcNGvBpC9oLmgsWVRSXVtdHgyVh6qN0pqIUTXh66u73CiqLS83FpVHAqVGeXWgauI5X7LutGEIGktqSgLl1cXlNTWhaqOmqKa2irX9Hwpv8eJIXPt3u1IbCRQXFwUWFS9aWFteVlxepRvh8+ed/RX7EagsDZ9cZfPgeLoSlCH22NKI8+KVoXC5Ca+q400xTv6kL2Xsa7mx/4bH8lRO+Xay/8XLwG/6fqQG+rxg0tlWv0urm5sjjbXhSENkaaQxDv5VSSgYqK1ZWF5aHQkGAjW1Vaf++OvXf+Vc/bXGY4XGcQ23g7FlnLF8dR+uWRKpqZ/sXhpZWrO0+bTAiVhTZrEGKQ2D2xypPX1GypicYDCDvE10ucrqGmtikerWyJTVlkYO/htSXRoOhkD31dVcEI/9L/Tx1Klfgmc8FV60ND4tNxy+ZGFdY+3iSGOYC9305bwXgX+vF19hbsKYO61pYB7S/oWunnFcVy9YxIWnORZZVLe8aISjc5a3WzLtRCJhwCO8MLK4rjF8ceMldY21pacez6BxCS/DEk1NS0MBO+PQaVC6sGl5pDZYsrBpebgxckn44kbYHDAu4bZo2J42x2PBYtgYCNc1hpsbqmvAtny18Rh2KKoq54bLph5fY2X0CvRU+U++Ep2z0QiWhENNzbDMD83Hx2wuA0N5A9qn+PuRmiVVVcNYyhmLviqPTqSHLBU0TDtnTD6RWASdmuLMEwy0bUqCRXwMwpFYrCn2zclfYk7yWX1WjkNkYSn1LVwoXDg8A6obV3y5Hs+LVxaFi83GFUZ8RXMkXFc7lAMVZ5+4ofMjX6ISc8ZXXaqltH3llBMNQYmVsufx2f/zf6ZrwX349sW8+aL/v81nasiqL8cjy9jPPPVEuQOYFYmbtQQy/vDLytKXM+CLlg7Dl3NKv8TkMew9vLC9hzd7zgnWeMPZ5cbWeFGxAwjX1i0LL1yBVv7cKtiLKIL9sLA11YoDjmlX09S8IhxvCoOU14arY7HqFeAMR0qCNaVlkWCwurooEglVzT3tRNwtsseCL26DJ8pbnpbX8kTml52gSFEovQiqGVvEzkv83/khoepWmMvfLTmBMcR/g2WWhnR04IJaS9kPRzT4wuULq2vq47HqmkiKAeCiti5pisXDyZfctlWGwpnG7CdVlaHwgsavuZXjO/nY3vfKTuxBFJXURhZVX9yQMZZNTfXfRyXzw9lfwvIHuc+CCMMai/MjVV995CrLwxd+sdcMLMHPY7zFH5onKFFcapdobLK62Lqkbmm4rjV8cWNrHPZuw8uKfvQlZ9PCplj8P8whExhGKjmBmxZGIzXxMHoGscgiWH8atZFAsDhQbARqy4uLS6rIf3ibY021F9dEYq2C3FDduPji6sURKp1/cWuc+JpjTTWR1tZI7dSFKwQXkjA+OK28dFqAFSY/o7GiQFFoaqBkarBksvuS6obYxa3uwLSiwLTi/wfbMh/+');
@@ -0,0 +1,142 @@
+import { WordArray } from '../../core/core.js';
+import { Hasher } from '../../core/hasher';
+import { loadWasm } from '../../utils/wasm-utils';
+import { wasmBytes } from './whirlpool_wasm';
+import { whirlpoolWasm } from './whirlpool_bg';
+
+/**
+ * Whirlpool hash algorithm (ISO/IEC 10118-3).
+ */
+export class WhirlpoolAlgo extends Hasher {
+  static wasm = null;
+  static outputSize = 512 / 8;
+  static algorithmId = 26;
+
+  static async loadWasm() {
+    if (WhirlpoolAlgo.wasm) {
+      return WhirlpoolAlgo.wasm;
+    }
+
+    WhirlpoolAlgo.wasm = await loadWasm(wasmBytes);
+    return WhirlpoolAlgo.wasm;
+  }
+
+  async loadWasm() {
+    return WhirlpoolAlgo.loadWasm();
+  }
+
+  _doReset() {
+    // The initial hash value is all zeros
+    this._hash = new WordArray(new Array(16).fill(0));
+  }
+
+  _getHashArray() {
+    return Uint32Array.from(this._hash.words);
+  }
+
+  _process(doFlush) {
+    if (!WhirlpoolAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'WhirlpoolAlgo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const dataWords = data.words;
+    const dataSigBytes = data.sigBytes;
+    const blockSize = this.blockSize;
+
+    const H_array = this._getHashArray();
+    const nWordsReady = whirlpoolWasm(WhirlpoolAlgo.wasm).doCrypt(doFlush ? 1 : 0, dataWords, dataSigBytes, blockSize, H_array, this._minBufferSize);
+    // Count bytes ready
+    const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
+
+    this._hash = new WordArray(Array.from(H_array));
+
+    let processedWords;
+    if (nWordsReady) {
+      processedWords = dataWords.splice(0, nWordsReady);
+      data.sigBytes -= nBytesReady;
+    }
+
+    // Return processed words
+    return new WordArray(processedWords, nBytesReady);
+  }
+
+  _doFinalize() {
+    if (!WhirlpoolAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'WhirlpoolAlgo.loadWasm\' should be called first');
+    }
+    // Shortcuts
+    const data = this._data;
+    const outputSize = this.constructor.outputSize;
+
+    const nBitsTotal = this._nDataBytes * 8;
+
+    // Pad and hash the final blocks
+    const H_array = this._getHashArray();
+    const digest = whirlpoolWasm(WhirlpoolAlgo.wasm).doFinalize(
+      data.words,
+      data.sigBytes,
+      Math.floor(nBitsTotal / 0x100000000),
+      nBitsTotal >>> 0,
+      H_array
+    );
+    this._hash = new WordArray(Array.from(H_array));
+
+    return new WordArray(Array.from(digest), outputSize);
+  }
+
+  _contextWasm() {
+    if (!WhirlpoolAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'WhirlpoolAlgo.loadWasm\' should be called first');
+    }
+
+    return whirlpoolWasm(WhirlpoolAlgo.wasm);
+  }
+
+  _getContextState() {
+    return this._getHashArray();
+  }
+
+  _setContextState(state) {
+    this._hash = new WordArray(Array.from(state));
+  }
+
+  clone() {
+    const clone = super.clone.call(this);
+    clone._hash = this._hash.clone();
+
+    return clone;
+  }
+}
+
+/**
+ * Shortcut function to the hasher's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ *
+ * @return {WordArray} The hash.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hash = CryptoJSW.Whirlpool('message');
+ *     const hash = CryptoJSW.Whirlpool(wordArray);
+ */
+export const Whirlpool = Hasher._createHelper(WhirlpoolAlgo);
+
+/**
+ * Shortcut function to the HMAC's object interface.
+ *
+ * @param {WordArray|string} message The message to hash.
+ * @param {WordArray|string} key The secret key.
+ *
+ * @return {WordArray} The HMAC.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const hmac = CryptoJSW.HmacWhirlpool(message, key);
+ */
+export const HmacWhirlpool = Hasher._createHmacHelper(WhirlpoolAlgo);
@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJzNfW1sXNeV2L33fc68eZxHibIZU47ve+YmVGxZM0POB+WswkuHkmlFkuOPxOtudzTkDEU+UhxyZkRJWa9n7FAO13ZcoXULow0KofWuva3dut0A67YLlCkC1EWzrYEGi/wIUKEIUAMboAKaRf3DWNXn3Ptm3pCUbGddbCGI7369c88999xzzzn33Dek0jxPCSH0j+mBs4ScJbR9lrbJWdqm7bOs3Sbwh7bPam1Iw1/aPqu3MYcP2j5rtGVePmn7rNlWJSpB22etdlRmt3cnaftsot0r76Vp+6zTbseqUu14Vvutp7QUM9NDRoINuAnd0HXbMPbtH9RTyVTS1G1bZ8w0Tcu0NN00DMdLJ5hOGdOZoZumrlsJ3TB1izBLMw1d13XGdF0zCGG6Qdfovn2GRsmgmaBtKjqdbeJY/5gmzPO18/XGZUbc2qW1eqP1UH21VbvUIkPu0vl4/k6rWn+ocXmtRR5IVuvHl1YrK0vfqZEj+8rli3NLq9VztdVyo1ZZWanPkxPpWOFCo1YjpwdjJedlq8fviZVVqtVyq15utirzy+W1+tJqq9YgDyfupERQZ/Cbxd/Y93VxaOobE/ffcc9jv/nVweS//df0qT8bZYk2ffZeIrzlgI4SNiX/cSL+ks66BFM/ufm7p1wiiE9SusOJcMKAiUvrvibIGNt8ZSrQZ1wm7vENeOvmTeuMS7kmvsSZsDfOBWR5Q9B1TgRtLYtjoW+kqBN1A/8ASOeVqcAUHsIWH2rQ2pkdCTShbQRsIyBCW59xCSei3QRYLAwsobUCTXz40jYJA8I10Xl5m4RjjARsjNmBfdyl3OakwFKccLvA7BRzOCK89crUKdfkxGUR+mQG8ouCtcQHLyoo9hjTxaV1ri37jNu+qQFR7DHmBcQlkBjChDnGhn1D0cwcY6mAcPMEVIgh4UHJUEBGJESXIgmZA8V20I8ddeCVkJvCCzkZ8S1tilt+gtsBgWKCxQDNHvEtThC6HQEmDk8IUmDE4caMa0pkoJeD3THBgMhxnFBjjHncPO4SbgAKQ0CKVMKROY8Tl6ZsRxKPcKCNwwk3CmxYDtELbJwLwNuTzYYdOVIbCQkVQ6oiBS93SRnYnHGbs1kYBeOEm9we8U1OfDtFHEcA2Z7l1sX1AnCEAwwU+oQzrgmtUWA6pMJA4xpApFCSMhzJrpy1BOOsFRBBOFlurC8GCWARpljE5qzHIgTobwEFLEA2xW1uyUlQKCS6KJBu3wT6NgGF5cCWvUO3IUAgko23XpkK2AwslTF29ZUpX4vztyUoZ0LbaAWJdWjCLZ5oYC8cVpPCk/kW8AKURLj7Vgx3nznc4lqBASk1wF0D3OHBCsyG5Qk0g3EUAAeB07H1ylRKdwRRHC+eReaAQW6+MqVWI2fCmwXO7Y7YhBED4eRgbRgsKzASDdWaAZaWQyXxoSYE5RYO1ZBDTXBDDdXqDdXyEzBUKAksNdQEt3pDtRyeAC6yuRWtlYR8WNGSkRxBugijMCA99sAZtSNKMKQErDQ7hFVPUwzqYY13BZ/RJwZMlHs2LlyA5dsgRMQXuCHoxjI3xD3HR1q+BfUoIczAlNweJLgG1EwAuyQ4OwOU9W2e8BlPIFDGTR/LUppaPgkYIDe5Je7eEPp6CGvNPDECpSM+4ZagLd/ipkscB1nebijsGDe6jM/1dRiatkOg+cSBtUwdbSpakIEJuFmccQuWZGLEN2DKZ0G+cBv6TDBs7AEZUPr4psMZN7gFy9fmCUjYchyAFQfJ0FsKRJIAFrq2fMalDsjNnri0pbi0QVzaUqDFxKXZE5c2LFczYgFTzb0NctHeIS7NneLS7IlLMwLcE5cw34iyRAh6iolMs19k2jtEpt4nMmESuyKTxkQmbhqmFJkmtDaVyMTRmjMulRVDqgL5rLdQ7R0LNVqmdneZ2rgEusuURMsU23bYLPbMvHSKOf37bSSPLLlITW515dGHtCeQTCmQPqQ9iWTukEimXKYsNkfwYNFU9S9Te+cyhcmEFenu1gwUH52J+OplmT/pEk6FHoq3XtkGEf0vO3ooOp1n1r2ATXE6xnS1dAnsZXSMpXxDUvW1V7DeDhJh8DG5X0PBgDU/AOkNWJ8cKUCmh8SrkVz/4KVtXOkwYNx6cRCBFR53GcddhbgkpTldQr8u1QsgLoEVIOXy669MwRzfvKfA3sT+jQL71UuQSBTYLzFhFtgHkECCF9jmy5AGkhfYyy/3yjuY3sLyV2NttjB9FdM/iLW5iunXMP16rM1rmL6G6bdiba5h+k1M/zDW5k1Mv4PpP421eQfT72L6x7E278bKtzG9jemfxNLvYfo9TP80ln4f0+9j+uex9M8w/TNM/yKWvo7p65j+ZSz9AaY/wPSvYukbmL6B6Y9i6Q8lnTG9+X2k7feR/t/vlXcwvYXlr8babGH6KqZ/EGtzFdOvYfr1WJvXMH0N02/F2lzD9JuY/mGszZuYfgfTfxpr8w6m38X0j2Nt3sX0NqZ/Emuzjen3MP3TWJv3MP0+pn8ea/M+pn+G6V/E2vwM09cx/ctYm+uY/gDTv4q1+QDTNzD9UazNje8j84t0CBsUEZfCgBVg/XVbfyhbcLLME+KpMCAhiI6PtbiXQXDxSDMDVUCMFZgOU9Lp0AK79soUyALGzTMoPtlJuamlQJtbxz2IbnDjuKsp+YtrFwBxc3YEFy03eSL0bSUGpHyQ+ykJLI42R1cIMMfZEzp1uq+jxqDkCGIDeyoIlDCwZ12G79s+6arGMaJYiii7CKFLQlhcvx0hkFBnARwAw/1BeOHsSGCJEWx6CKSPn+SW8ELI3XhpqshABQExzZNFUAJvI7VgH7jxEmh7B0OUl0RYuDUKHd63Z2EPtjg77lrc4hZsBM+uSwXf4mw5IAp51PukESo3MsaJl07ZfRuZLSgnuJGZciOzuak2MhLbyMDIgH0ZNzKiNjKbk95GRnCngo0Mt64UUEk+YHdLWY7c2YBcMEs8ESKB5bwAIXt7stUrZGqDXvY1ztRuctxV6auQ1mGnxi1LaOuCnsBxmqiheQOohcOr3AzBttWlDmYqgsHmR7v2CVgEQC6tu+9rXjpl9pGLCbDOY+RiXXJpMXJpPgNyYVFkaPssZmj7mqMow7Vo32fyAeZJygCmBc0PVvDLuILVVoqWlI0czO2Y3tK1tNCESZlOZFwwZeOh5ZU+CViD/nZVAkWlHlDpGom3sgK2pBWAhhy+Dgao6h7eQlOuZ25GBmjKgJm35HzHhqEWJMOpjxYk4WxZKSo8EQYMV6UpV6XNzZiStls8aY5cOq9G1IKXwOKVr3fnWg6oRwFUk1TDrXjDHlNIFSuyYiLgAePaKcUEv/ZEOZwKLxwlnDgvu8xoD4M7aZsuB+YoEdS3cPJ0QUNBN8BZIQgaflHxRpCUCpKWZ2SavwBPfTNwihpqsJyi7ge9h4GFOcHAMSKoXDlJXDFC8y3QwUGSsVaYZ8RHQzHKuLIDW3WQ2gwGipqNcFkYJDhFY81nYG8woUVdgf4uGMDX/QQ6G3hS5o2ov0TURTrenyf781R/Q5vBYFHzsD99d39GX38awDd7/WHe2tXfvnh/+2V/w6q/g5vBUFEbxv7M3f1Zff3pAN/u9Yf5xK7+DsT7u0P2x1V/o5vBnUWNY3/27v4Sff0ZAD/Z6w/zzq7+huP9fUH2N6b6u38zuKuojWF/yd39OX39gUNOpHr9Yd7d1d9IvL+Dsr+M6m9iM7i7qGWwv9Tu/ty+/iyAP9DrD/PpXf19Md7fPbK/kurvq5sBL2ol7G8ANhbsz476S0f9xUH4YLZHmYCb/IsA7J5NfvcVQH6Nm3wESg5u8ruuAPkWucmRYb6wye+8AhN4lpv8AJTcscmHrgR3FLWnuMn3Qcn+TT54JThQ1B7lJk9DibfJB64Amz3MTZ6CEneTO1eC/UU0kHweTPMXNjm/EgwXtUui06E+AxJYnIp3NreJ9yUCOwgV16IMaC1XVQZgiA4NuckNTwN35iHtXVrUSjL5Di1qGZl8kxa1MZm8Rosal8nXaFEblsmrtKh5MrlFi5otkx2KMoabHOx4Fh7SyBVVIvsV2yTs9d2hQUoOTCFyqYfIWg+RxR4iZ3uIPNVD5NEIkcBVlAOaORK0C1X2FejpYdnuUfjjXYHG6s2n4M/wFZBfCuxZ+MOvBOlun4vwZ+xK4HURWoM/mSsghRS2l+BP6UqwD4aCq5aJ9zsgSx2u8WG+70qw7xj7Ktf43TDzg8fYBNf4Xdy7EnjH2P1c43fy9JUgfYyNco3fAbwwcIwd5Bo/wN0rgXuMDXGND/HUlSB1DB2U+3Gkx5jONeCprWOsxDU+iKkM17iHqTGuAX9tHWOca3wAU8Nc4y6mPK4Br20dQ7XDwRTM2DYNR0kaHIa66CgW+ijF7HYSdqN3nqd4vMHp9CtXf3Dt2o+2WZFsPU85nX7uH7x+/f0f/eh7WpF0sOCFF669f+3aj0iRvIb577321gfXn/vRZrpIrkKBoEfJP8fE3+n8sFQg1zBtHCV/iInvbl3LFMhbmH7x725dJwXyBmZeOvggef15ip4/Cts++8jp2RBUXHuehrC018PDhATax206skTfwBK9KW7S9cAABQjgXYVaA6sMrip14Z1xwUGnRY29M67GKbiEFDwd3hB6K+oRITSOEiJ3WMNn3BAdhmzw8SssRA2FfuQQTuHEB45CZAUqpFy7HxAQtBXo4u4m1z9+mzwMhkSvxNrGIp0bzbenb9LvTtsvcANTQWLae4HrmN7kiWn7hSCxiUXwug2v21xXr0etoMEmt2QWYHUBbwYJWL9MdG6QEMD9vspex6wXZd/H7HCU3cYsj7IZyI1FOQ65TJTzIFfCnCYo6OXoSzZFx5MUO0SIn4CH7Sfh4flYOOyn4MF9Fx5j/gA8Mn4aHiXfg8eUPwiPh3Ffl5QHuicJCPl906UX+L7pzl9p350ee2ETkp2PrO9OD2Npp/NhGqi6CRX2lirg+6aHt2Q7KB/bwtcB1Nbm5mZRe68DnsdBgDzYgzzYgzzYB3kwBnkwBnmwC3kwgryNkD2A7PUgez3IXh9kLwbZi0H2upC9CPK7CDkNkNM9yOke5HQf5HQMcjoGOd2FnI4gv4OQBwDyQA/yQA/yQB/kgRjkgRjkgS7kgQjymwjZBchuD7Lbg+z2QXZjkN0YZLcL2Y0gX0PIKYCc6kFO9SCn+iCnYpBTMcipLuRUBPk1hOwAZKcH2elBdvogOzHITgyy04XsRJCvIuQkQE72ICd7kJN9kJMxyMkY5GQXcjKCvIWQEwA50YOc6EFO9EFOxCAnYpATXciJCHKnAwL3nefVHmMIT7wf6Sy68MT1KAM1N1TmPySZ27af5WSagNaCj4x8jMkHl49h+fC4kjAg+w9ppcCbzmy9AWJcaC3QTkBXOqSRAJbZG6rsihRRgQ5VmQBWefyVK1KeBQZUjwX7p/nuajzpgHoeDL0RWOLAhvgwvd6tfh8PT+khbTg48EaQEG5/9XUSBiDoNC+4443AEUZ/9Q0SBiD5NDu4842AxvsGGUpw/981zDv7RqijIOjH20AFor/M5PvfCNw+/G1u9SGc5Ik+DFPc6UcJXT/8jlj/nID+BdrODjwBscE9EPP2QGzfG8HADsTcHYhZOxBL9COG3MEP7EBsGBC7Yw/E7twDscE9EPPeCNI7EBvYgZi7AzGrH7FhRGxoB2IcEDuwB2J37IHYnXsgNriTFW2e3oHYwA7E3H7EOCK2fwdiY4DY0B6IHdgDsTv2QIx+AoOld2A10I/VGGK1bwdWGcBq/x5YDe2B1YE9sHJ2YEU/gbvS/VhlECtvB1alK6io78Zq/x5YDe2BVWIHVs4OrOjtWavkbNk02b6XiOsduhzYo+g6Qr+RBi6apHhmPXDERzdv3rTAfZ4UbMNPYb2DHhtXEH8gRR3vSXDfcEfo3iOBCyeuKX8AbPMUeJ5SQlv304L40lrH09UUQtXWfQ+6hDptirvg0EtyCo9B8dF3twl3OJsdGWN47psUesgH4VBSVgnQ3mO1NtTa3VrWX5uC2lS3VovXJoQXSvzDwOFS00zjKTMFQK0gCdUuT4Z+UptCKbcLu4TQoREg6ScROd/5mA3aYZCWR/c8JSNhXPDo2nygwHRJ6HU8+8Q02wgcngK/g4sdgyckMcaIaAsObrPWcmu9wOAElDs8JX7+XbkVAqX5gDzn10PPxxN933W4y1MABQ/jAQOwQBAN6Mp3wAGd4I43SaIYlR29O9yB3jt4kt0Sw81WQzrAMbbAEel1gaf20NpnmoQAoV09XGR4AbhjFRJAmsBBvz/QinEJ5oQKu4C3A8adZWGcVEC44x3EcgiKgKNmR8UsJBSayWkCpyf4sOXDw57MMEgWWAr4jrN++ihgtp8AGiQBDtcjDK1bv9f3ghG9YOM5WgrjJTym+DnBGYeze0sWAy95QFMmj2fgNEugf5+AntTZhkGA/wmm9pdqar0n2RS6oAK5upgMdGDSUWzJ2XBm5JEGd8CccXoBPEKPZlfSFRtKqgndmwan9fUOaF3O/7ao2ZZhiimw46G1K9Lr/gB3xY2bN8HaTYu1JthScKLm8jRQwB+Mgglw8j0YoOcSPgAM4cKannF17opnQt/lg2PouIUl7A9yDykLh/ncHYNjATjec4UexjJ2PJOKZ7x4ZiieGY5nDsYzPJ4ZjWfG4pn745lMPDMRz5Tima+Gfpq74NHyXZ7GCmRo2NDGGJxcOntNNsX5ZVOC4+SmYXrScNx3kKfhKGyYp4Eph3iaOxDakubAljwtT9HS0+/9iz/6h0NwWGj7jKcEnqaleBoqMacL23fhGEDKYT0UNte9EZ6CVeTCw/bTeEzAWpK1YBTA2RpnkpdSuFQc2P+5HgYuLgsVAcm4u3xKQQbfmoQMK0tCVv1gJBmKUxP68eL9GNxT/QCAMKA9+AmhhSARB0Ax7/VD+YDsp9sBhQ4GZjD8A4c7AGcXwIR03Xf4gPgrudcM8jSnyLWwQl1u+Z4GJyCe6LywDULP7kpzEE3cA4LJKthrerUenMt48vSKwUDCwOWD0kHB3dCnsCKpE3XHLTnY3d1Q2CYoDB7GDSNex/0TpoSbIR8IQRTJMxqYdZR2DA4z5Zbg9rYEJn29N65IuSF0wb1TKAuARvLQNXWI6UU4q0rhgZLzFya120hrPOENYb9nnCwHGFdG4chKhxmAGC6uwzZuCoLReBSPjiB6S4XvURkkuhwkxDMne6UaguYgHu8nRPz79iMh+sFiGRbPaN2MT1HoYvStgyBggwFXW6wFgIKAP9hSEqqhFmJcnSlFnS7Vl/UeAhDiJ/nDAlhUwoJC1i1kqhCd6aFvgzD3NYhlA+/dNsU/eJwPNEEKoozWxQ1rPaAy/hCPd+F1GY2oSWpIMRYkINTa2uAJYW40xHPPb9rrnEp5d4s6/dZ1t6pgYRiG6A71woByIkWSwa1l3+AaR4UFgsjFzec+stY5k89QPNfRV4S3ATS3kZAwEEt8RNclA0J/VPVHe/0hEyja4nn7nsjCvClaYyg5U43YjkbgHkXUDsLo98AMzzg5EjsNjbV1XNegTcLBIxU6crF4Zh1i05AfExoctBJQMGmX62icH2mcH2mcH21uoJIXJHChMxWFmAhxXm1OYy3luv6YoO0wYFL9c/7SpGY7Hn6n7gE8EwY6ENTAgAShC5sbIAesEU5DOYIvhzgiS57j2iddFoUqJmTQhua5AYOAzrQjeQ3uFJx0beFxDUL+ITocIv59WOeXFDRXukFnXQviSi3Yqk7Jx7LodOyTrsWBsDY3Q3XIbEpBZHWjLCgyfhRnQTDSJDAEW3cT3JBRuiZ6R2bdBBzwewMQ7MnpcmBJO0DnlMPoBV1vCNaQ6hQFZrW6wYwGQDC60QaccstLQUC9zo3dLxvwMu3FJthOdH5v4qHerGvL9QpYmBjtgE51us6tHhSrHwq45UGcwZIGBIzd+JrdKG48/MU4lnjoDUQU0ChQ2pQh4xbqB4gGXJm4JWQV8yKhRUQwvZQK4Xi5O7iTro7RKCB34D/c8ZiJQoTlPou8psmYcOA1NEA0yWuMa3DDwOL2KdcUP/7eNhEPiF99b5t4Rz/eUTZl/odR/hPq9x6OIQlPI7oCTV6OYjRfheBxefsh7XDNIwGVm6EmnhGXJOpMaOsjGL+PpyNggckx0t4YKSgsxINoNOeaSfU2cj1IQPGMvMOCgV6hvEAAuwhEkDHB1sF2RXEK4SoQQwYbDqAreRxMAbwcoa1D1ACVUSIYHCLLZdAQdCSnCSN3UDxx5g04u5a/htYOLBgib8/gsmIyfVUGMmnqtoI3AFAw+KbLBCTkSpGIEOzhBCTkbBdK3ZccFfWFRV5ahqVAvGwb1bA3IZDXNaHwxktTAdznuXmPT2UQDd0RREMw2BdS3Y5QBHfJ1g0k6oYWOTIq+BpEyZxyjW5MzIxMQ/SMLg7NurqM56NoYODcaJyclMFXuETDWbhCA2q2T1UsH90RuiPR24qj16MDyky5N6PUjkIMYU+5eQ/+OTniUwX3TSBsPN5L64bHMRnvpXG2Z3ictjs8TtsRHqdBD7BBoTpI5QUUikFgvU7lbBA5G/SzIs91zjAkSbQxKMpxrhtd08+KTD8bTD8IAlKmXxJMP0eS35bGr5/qM/2kbevK+AvUmGww/Www/WzQQYnvoALvp8BvEpl+dteIsuOmnx03/ey46WfHTT87bvrZcdPPjpt+dtz0s+Omnx03/ey46WfHTT87bvrZcdPPBtMvyW0hox2TcdNPWCdvafdFt2Fg+wIrDj0mcpNTKRvhyDbDcEQwxmxVlQpSb0M8CFybCOjbm5A4GLC3n0D9wIB7D+iSSHJbKtWCbYQnXHC1XDzpQsBFctbVlMNH9iw42HxgB3pfnrkF1objjYP7ApjSwWt13AGu0sFjgfud0KHOAmPVljkI1dTBzJMOE5Mnl71RjpqCMkosaZTIq4+7+tT2LGW3oKqweZKbYvOFbeJ54GORds7PDWa36bPiPtHp/JjINWJiXCCoWT6gemBDUE5HuBEqDyjW6+tdVV65Rb1I4TLxIhmUYRNLBnNq634SvD9Qgu1RB9VEat1PReVwlQY8Q2HgdjVNN66DunEd1I3roBaeqICXEnXQJM4UgEJfi8XdWEsA6bs8CeI8iQwJEZbgvbLFYU5HlCc3wckRmgpkcFVv5PY6bqmJZdSblcmnqu/eENr6gM4IJXB7SKpFtvirmxBdu+GjnX/z5k0CEgSiRH2MtCboO7UEmOIgl25q62dcJigY4oCqBX5V8Dd6gwZc6UgZaM6RQ8wOBt4AnG5eXhfvdDodEK82vojB3Ul5SQSsaJiYwy5EUViIPPaCdqPql+3sUmTiXeqOg7VJ3oPGeBI1TWeMpQYtvNbqW6DMKOikCx2UhlnfhT0aokL2HpXW7SIOFM/Gi8xGDb47MkuOLNnFBVRKeC0pX0MXr7OtU1vJbz26YQzRE6jTYPgKXvUycNVg9C3dwCs7AAFuC8v7O6biTbSp7ZiOYmyL9iMoI2AnSIgOBcGXENv0hKuBlgerXJd6iD59rdPpmEWMHoI7xGEAIg6QHmM6kgDob0gtFfV1GUjDTSCDVOB9uPuFrQzZCgNGDc6OEBqwqCXElGHAG7bUnYjeNi5XgXj4SbxxSPF2M4SB+jZnY4T6Sad71IHmCixhR70KQX5gNdtHCPHlVSNuCB3JKWPcwWkBkKBBImqAK2YPCBbeghMe4qDBgSisE60VHqE6ggfhqyoTfZVo4iQepANc586DNMV1cP/Z/SRHZX9vShOkjBZRxom4gcDgDTxah7Biw7miU9YGrSOMQsGB7RQLaUotJlL1Ai0jChYnMbWY9avFNIoaZz21GP1dKhicKp8VRND39GLpNtCkPsxi+jCL6cPAwZfWA80bkCpgGFkTOAKEzEmfPkwjfRhe3YlL9yX0JnQD+6mXTu+K66eo52lRXL+m9Dwa0/Oo5AAsCmg3rp/29DzajeunO+L6Kep5e8SoU9TIZYx6F+/4EHcq1+m9AtgllK1+KNHo047zvxg12rDdISH+7Obvwj1P+H9yJCDLGA0rPE6VQ4HKTwiEIhVKQw2AXupyEGw7OpdCCMPiwRMjeTSAuxfAxSFeqcVbHGi2oLEF3eF9y+WALvs67jjq8wTA8qgiKZsS0NehG71nGBtgdfY7BRiMvK8R49RL4UrGC5YARt0a4DRUrIBrgKquKVpgsP+HpyLTow8NDcxLDPLv3Wdnylfbs9k1L6VukIO71vl9DW1TkL69C9JdFoS7vFq0LtB5py7a4sVbJm/bUpTtGLaMag2NbtZQdTe0d1+Xjfi6NsV13+A0YFDMsBig0RFf5wyh0wgwxttIQ1GbcaM7IqTvCi+VV3jBjvXAHUzlPfkhzlwNZDKiQ8BZpuMHIiDrcYY3w+WwAGn9ODphFOa6WgVq4YlncdmpLwbIRSxv/uLVaSr9GxTgYm/DjiRQr2JIVaSddN/VXxK7+vsfGdV7vG9voE9B3CNXbqdjn0Em+BLXzgVMfnCDCdpqiGNN9DXII0dcyAfRTS/pA/MX9Ufh6wm4i2jqHjOOg5NICIBI6KLH9QYilo4sBQ0mRVefekAbQY8kDdyXZ3hfnsn78hpMM9fUNfcAzQQ2g24F2oK7TjDZMVCOI12x6p6Rkk4oS4b7bx3J6KwwGoC+awDOHzBG5RdPeFwfIfIGHoUHFYNgvsD3TMDlyOROpva1lHTriVT49iZEv8icjTkPvwmD+xbXvK+gj0m9iTjqEnBw6/f/+6736Wd6/7Xnbvv+bTDf8eYbjFpKaWOjcFtDkxusDtf3uS4+sGZdWaz7hiavmeC9KvFMyA24fiWu/heyGhjwWAF/KmoOvyCrcF+vdYS8tzX1IMGvbYR4AP0LsiIV1nitBtY5OCFuvvU/9JPyBB4VkgRMliHltuxaPBsGGhhyRq8nsidUdfMIJbY0UCSANgKA79QcJv9pa+ooeuoE/CPR8ESSa8teEm/yoW6iOX/AqNmWNzVZJB/xkhJFh4os8OStJSgQmtBxzeqzI3iFDrcQSV3OlqX0xpUgqHeXBKzLj6jAgYje7UO63r+2LjrtBohMYW7AUU/UHUzvUULhnE5sUwjwBgFHRWoDz5LUe/KFmze1k9EbwO2mfNES17svYp0m6xjWSQiyJRX7NsQatpUe6KPymF/jLESp4fxPShnw0/sQyZMYJWjKg/qrduObuBMn8YsiXOPJVXAOLOPV1OTJET8p5XFSqqHJULRDnlzFYnEVjhSPf6w0dlQCwyL0FXnxPoHhDkl5NMeTHL5xkfAotAAHBJlVZ0549JvkljcbHc6BTwCCEPAMYRPpbXnfxm1B+nNZN0whAeOSV+B+RilVC8cZJWgNix9vbmN78GAgAhZ+PCPpGb1TEysCpr5JgU7/XpFyk2u9InCO4HEGGmkBRkA5wC1oMsG7viYLTHmaInT5gQ94X348BVhRngt6XwO9W+hch3AMGaChy29YEHSdjRLnv/VGBhOYkIfdsUF5uhpHYHAb6ajGZkSjsBUd7b3pKA1+vAkLDzWoBI4hpY7n4TgVCuzuoEy8m6gGhUmJBPW+hh+B0DntH5QZxVTBETNlTG4HGZR0yocLe2rnEuxiaHvibd0Cu58zdOd4RTbKmRgNxatwYo5bH/xBd7nn3CXNivvhOiaH9CE2Guggcqn8cg/l8pgbfcKgqUlt+xAjvs5p1AWRmoQHKkdRsyESQ6QwDgPOFHWRwvNzCl15Eio7pNlFtC5e3YK78rrU2UF8ZWCw/643g+YoyN5dvOklPgP7mWgMyc9eYTCUieORnibW8nVZACoLMhfeYtajmcIkdoIzpfXNlKG01gJe7sGZ+nMlQfhyYPQ+VMamBPXGQHwMaDolFA+cfvYifN6k/Q3wtMDBV1iAIqj5+YtTYCsaEIyHczs0qMNK14+Su+HazFFyEAx3UBsMbiA5PfUeKhaQvv7ilHwP5KIhwQzLrGQbbngFpchBt+2oe0GOkvdfVMQEC+4h4hDnn0kOFNQ3ha4OMo23ufb2s4ENMZX4PaloL7HfCDTVib58KlYTKedyngxOV0DD8r4W8wJy3bdSzPGehKn2HsHoAVdpSTqq4rhA4CzREbaP190teXtYLRrn7/cYCOdAmi4UP28E48PZM0AwEHVINYZ2BeiiECYC31YJmLBB0Ah6fARtEgpliNMXpDc57mMGb7KEihuh1PYipHFKRonzvR5a2i3R0j4RLbAwdEh5X1RHXxIRDRDRIkQ0PMjpIYK+Dk0i8nuSRb2uKBGXT8VQYF0UNIUCRABSVHsDPBI9ORJQ727laFCfAkBXe9Q7kyJDrWtcIbpSRpzvURmlefOm2tBhFQZaN0A2ziEUVzJyCMUwL9wXHvGjmAWlT8MXG6QxL23wbrEcNqrgWCxIr9n/P4j8va50558s3Q/2pPsQ2Jq3l+4HAyWb2SE2BNLd3ind95DCHKZpi1KtHfviomLKyKU1I+ccrwmiL1Sa5kweo74FKCHvyJO0w8Tm5DBJeMPyvh4MDFtKo4eK927zwrk4IhjC07dA5PKILYu71beVJGPC7oUERMakEWOyGGPKDwM4ZylrR9+ugkAaVMjJiTBgU3DW3z4hTzEE8XWp0HsQqrQWopHoAQ6E68vHR/zYGUzXIwLz3O0gOkBHD5y+K4CARFuZjlErJ115Dp/+xOCB31YdRD4BdUijLUfeFuaNSF0dtz+m7ApQrWZcRX8NT5go16MgWKUdO09T+gnA48ZAvIMebFTFdgAWxDkdM3a1USIPcFPSwgfSSHsScLOVVagh0VPKKnyzg5/XAjcMWIWPUA2cEJrS3eTXb/ByKqfouz2BQcMQU9YfYzTjMjj6Bq1iGawezXmMMgBF5T75ixfRgRkWIIWMQh5GG4oKchg2TfRoyv1TvvGTF6ewPTwZRmM5J8BLTGWAJ8UvlKDOZMd0JjvSmZS/S/piN/vWqfOg3E3iJPOib5xogj5ID0pHERALSAUeHE0MhV6WOBMoY5bkClGChtPlk/JTqjMu8Z7Erf8RDB1xOE07xJmKpIBsf1xGi0Rr1juWlifbODZ0x6mPWMrJ9xxnUn3srfNj9HahXO0e6Qia7p4DSsEJ7C/PV5wJStvcgDA6oa8I3bvPN7jBZVbdPY3r+Da3vP3yJRPPJdVLpqzb+yWL294B5wFcfIoySlJxbVlGSqBfDrRANPCAJvdHnKukEzmkRKKUa5wVUb7oofcoce7t7v9sVDnVum4iiIL1zhDnIKVt5AcP+YEgP3gyBtX5Ii5BKf2ooA9H6wrW3bTjfJF016UOzPfTF+HzL5STkUGdEOce9YkiGFKjbw7l6wfj0Gd2wL5TKgoJGdJKPBfmyCPOUMQxyC+niOPNEGeQSN94S9CGXJL7SLSXItEB4l14vnCIffTCVFH6yQ6xX0GadKt+Gav6hay6Q42RSiGkKwZxu7PpWWln/x7LxVPcqrjxmONEuNybdpIS/SfuIk4aDZMXpLnhOc4gEa9vbRNxr3h3a5vAWAaIuPriNhHjHLMpBUfYXsZxyL2Ek3CU3IsV4BPHy9FOQvZQSkMKCJiL3jzESFEjUfFJopARtvegYxFOvDyRT04cB7Q0epS89coUQpSOZRtStnefYxDvW8TRSNrRwIFEHOePk5TIT0D/UfKO5srSfI0vrVZrl3izVWm0mrzS4tsun7vQ4rXVqswS1aJ+ocXrC3yufmG12jzKW4s1vlJb5UtNvr0P34AS2RTKyL5GZfVcTQJW5dtBBEXWLdQbXCJRXwBg51qLfJt4srK2Wv1MrzUb80cutJZWmg80muRI40KzNX8kP1nKFAvZQi27UMnlJzLF3MTcwlxlPjtRLU4WC9VibaIyXipkjqwszTUqjctHmq3qEQDUvNw80lps1CrV8kp9vrJyZLVelvm/Fnj8/jV20KhcLG/U5o+cr1e7AI9Ua2vNI9UV+Znsw5kHcg9ks9g6KsOmrfNrRy7OXVhaqR5ZrDQX5+V3urHdytIcNCHkv1KPJAkhY4SQgVj+CCH4JLGyTULI/h35I7H8FULIgR35o4SQuwghU8wj8OF1D+qJR1Kqv8FYPr2jHvIPxOBBPhHLuyovWq3a+bVWrcpbdb60utRawi+PczkPh3Fe+MXFpZUaX2oBz83VllbP8WqjvrZWqxLyc+KR3yGELCuYf0I9EhD4OjwhQ4oGUdkzigaMEMRRJ4Ro6mmotrbKm4QQS+UTip6eqnPU+66iw/m//Z///P5H/2L+kvjDb6+PvPRvDvyT335y4knrp49fevu5b974p/+n0mzWGq2l+ipfqCyt1KpH+VoTxnjsNzk+7+Pnl1bL9Y1aY7FWqRLyMvXIVwgh/0qXfd/q/a/23q9c2uP9P9EljvFxpXeMH8Y0X1mrzC+1LnMAsbBSv0gIeZB65FFCyEFFm0wmk83kMuOZiUw+U8gUM6XMZDaTzWZz2fHsRDafLWSL2VJ2MpfJZXO53HhuIpfPFXLFXCk3OZ4Zz47nxsfHJ8bz44Xx4nhpfHIiM5GdyE2MT0xM5CcKE8WJ0sRkPpPP5nP58fxEPp8v5Iv5Un6ykClkC7nCeGGikC8UCsVCqTBZzBSzxVxxvDhRzBcLxWKxVJwsZUrZUq40Xpoo5UuFUrFUKk1OZiazk7nJ8cmJyfxkYbI4WZqcrEh+A26rLm0sVWt87jL/Tq1RJ//osL5aOV8j6YGLi0uNlbV6feWBi/CTA88ffozUyo+d3jjVzJRPtx5qhuuXSk/MXn567tHJcilatd3E7OnWdL5c+rrKnm6dbk0Xy+PNy83C45ebrdr5mYKseahZm3t4vlKrP964dLk83mxV6ZHy06cnu91nc+qj/NMr9fnlbHGxVqoWqvn5TGahslCsVaoz7L6+FybP1VpPVOZWak1oXK3mKoXMfKZUm8hX5ydmtC/3Nc43QMhni4sLC9VSbS5Tq5YmcpPFfGZGP9yPRqb3iwAAd646kall5vLV+fF8Yb40YxwtP306m42Jqey4+l2B4/XG+UqrIH97IFtcLFTmi9WFWnYuv1CYK2aLM2YeCAs0eqh57qkTa/kz65dypy+XJ+brjdp4s9XIz9cvrLayE9V6GVPl+cVKo2ndB6891Fz4xsLlwsxsNr/0jXKxXC6jxM6OQ6q6Ev1ggT3/eU7eBPziwe6pS3wiEeQPLmSLi6VcpVqq5Iu1uUKuOp8vzCSPSQQr5b3JsHC+hWhNSkitWiObW6tUy/AzCucalRXnXknEW7ybv9hYatVS5z9PKmTHq0vNtXqzVp5fvLC6vJse7rnPs7vS+dr5ysrSudXdHQ2sfK7jyl1YXVlaXb7VsNKNz7W30tIqiPbySqVx7pak9CZvs0byqCPlUYfKFjBTxkwZ9orBr8GrTzVLZXj9lhwyvnrh/PjS+bXzp1vTk+Xi15eaayuVy1Cz73ckgKdxvPn5M/OZ3JlTT63Uy3kcVKHZaiytnsORFh5X6Vtz4reBE7MZZEhcyfuj3x0Z6v+BkgOxXyG5Y5NKJMqSyXfSZ3Ktsro0v7y0ei47jsnyYmW1ulJrnG5NT5SzatVI5B6tXF6pV6q3Iia+ns09Cg/VtNSqLNfKc/VLd/b/ZMrweLToboOPBFi+uNRaLC/W68tf+LZkn4lbkLOoNEakZzb7WOXit2rzs6urtUY2u7C0utRcLJ9r1C/u7vMuoNHs6Q0AnvsE4MCXpX7oazPFRq1Za2zUctlqvazS5cpqVdESmbcSTfqJlfpcZWVmNxojT5efPt3fWy4T62f0G0+MitETT4x+QnfZ4uJkMVednCwuTNYy+eJkJTNz8PODXckvLOQqk6DAl4qThcmZux/+lLD7piFbXJzIjBeKc9nS3FxtfjyXz8x88deFND+RmayMT87PFyrF+dx8deae9t8g14+fq7V4sfzY6Yc+Y++Z6XzZf2gXDbKTkgYw/CdGH5IkKMHQy/VVmJKFbH5iYbKWm6zWCgvFzPxMMBMN/1aCFiQpDlaJ09Ot6UJ5UkBVpVVvSATu/VSaQlVqCqNf7yI+DkgXdmKczVfW1mqr1XJtpXa+ttoCPWuikM1U5+cm85VaNpOZr878xm9//jJzUorMZqvxpeItN/vYpJTkpEg7vjy/WJtf/jL8FtT8+bWxo73t5DbTmpUQUOkqLa3ON2qVZu3Q80oUZ38NrsyXs4XHW5XW0vzjrcZfQxB/5fCnoIBMlRfOt+7b/eNU91+So8j8eqP4DGsLZjC+nx4+jbh/orJXvKWyl81JVlhr1BaWLmX2+IGu7AO3Y/kiPMpztXNLq+ULqxeXVqu5L99qJ5P8WsI36vXz45mo4W6mzs/VL9Wq2Ym5+qXyau1i+cIqGPMTF+WutOdI11qN7DgY8uWl1fLaSmUedpnPNh97TsXM9Kly/vCtZdeOUYHEKnznM+E5i9vhRLlQXwOzvHAGH7OSB3bTBmTJ+Ldq84szM3vsmcWFz0qj20kVJVD26Kd06HZskVWSEGFN3maiuxI9J+egXGs06o2jhz7FmpSr+sGdPx/3VTQVntqbAJXVy59uxKdb07nyuFi9XGxdXquVl6q7KfCbD92+o8dqnwLIsaOf1Vjrye6v3X+7KZhQqWgdT/3e35ishe1fXJDd5/7fdr9TQk5/OhqpHfuhL9+udQabInJfX4SGv/VpeenTbccL5/egy0z+UyyeYuRzK0c+t+Mnb2Pl7bUvrzZbufFYplxd2ijPXS6DM2t2BrwROXBfldVSG8/Elt18fe0y/CAjcHm1XGk0KpdB5a5NZOfzpVo2W6nkarXCzCNfuR11c9Fc4NBOlm7TNlfoaytlRsQv3+j8zSkVhUoTFuapidvsbHJTLSlxFxvAmaqS3HshDdrp5FxlfrnVqMzXegQA7bG5WG+0yt1KuVFNF8o7d6bvzEwXyo+ufs693Fp3x/6+Wbq9OpCbqNYWKhdWdsxlvb78GEqM35r9FNt4ViogWFBUc/FYbeazz9z0ZPmJzO24tBSRBM+oZI9Pitu8MZ6P3litqyE2F5fOl5ea5QurzRa4V8sbuW99yqUxV2+0vi12rUaYqe5qrM+FtflWGbf5Rm0BjMFitZbJjmfGi5nq5Pj4xAz5W4m1Rr16Yb7WaDJ7pbJ67kLlXI3qj11otkhqrVGfrzWbterhucvMQBTuzj4wmX8gw8e6x1c8l8kVDmcmDmcnDpkXKyuNC00z80Au88D4/wXo0ioO');
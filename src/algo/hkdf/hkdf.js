@@ -0,0 +1,177 @@
+import {
+  Base,
+  WordArray
+} from '../../core/core.js';
+import { SHA256Algo } from '../hash/sha256.js';
+import { HMAC } from '../hmac/hmac.js';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { isString, wordArrayToBytes } from '../../utils';
+
+
+/**
+ * HMAC-based Extract-and-Expand Key Derivation Function (RFC 5869).
+ */
+export class HKDFAlgo extends Base {
+  /**
+   * Initializes a newly created key derivation function.
+   *
+   * @param {Object} cfg (Optional) The configuration options to use for the derivation.
+   *
+   * @example
+   *
+   *     const kdf = new CryptoJSW.algo.HKDF();
+   *     const kdf = new CryptoJSW.algo.HKDF({ keySize: 4 });
+   *     const kdf = new CryptoJSW.algo.HKDF({ length: 42 });
+   *     const kdf = new CryptoJSW.algo.HKDF({ keySize: 16, hasher: CryptoJSW.algo.SHA512 });
+   */
+  constructor(cfg) {
+    super();
+
+    /**
+     * Configuration options.
+     *
+     * @property {number} keySize The key size in words to generate, at most 255 digests. Default: 8 (256 bits)
+     * @property {number} length (Optional) The key size in bytes, in place of keySize, for keys that are not whole words
+     * @property {Hasher} hasher The hasher to use. Default: SHA256
+     */
+    this.cfg = Object.assign(
+      new Base(),
+      {
+        keySize: 256 / 32,
+        hasher: SHA256Algo
+      },
+      cfg
+    );
+  }
+
+  /**
+   * SHA256 is the default hasher of hkdf.
+   * With another hasher configured, user should call the corresponding loadWasm of the configured hasher.
+   *
+   * @returns {Promise<null>}
+   */
+  static async loadWasm() {
+    return SHA256Algo.loadWasm();
+  }
+
+  async loadWasm() {
+    return HKDFAlgo.loadWasm();
+  }
+
+  /**
+   * Derives a key from input keying material: HKDF-Extract, then HKDF-Expand.
+   *
+   * @param {WordArray|string} key The input keying material.
+   * @param {WordArray|string} salt (Optional) A salt. Default: a string of zeros as long as a digest
+   * @param {WordArray|string} info (Optional) Context and application specific information. Default: empty
+   *
+   * @return {WordArray} The derived key.
+   *
+   * @example
+   *
+   *     const key = kdf.compute(key, salt, info);
+   */
+  compute(key, salt = new WordArray(), info = new WordArray()) {
+    // Shortcut
+    const { cfg } = this;
+
+    // Run both steps in wasm when the hasher supports it
+    const hasher = new cfg.hasher();
+    if (hasher._hkdfBytes) {
+      const toBytes = (data) => wordArrayToBytes(isString(data) ? Utf8.parse(data) : data);
+      const derivedKey = hasher._hkdfBytes(toBytes(key), toBytes(salt), toBytes(info), this._getLength());
+      if (!derivedKey.words.length) {
+        throw new Error('HKDF cannot derive more than 255 digests of key');
+      }
+
+      return derivedKey;
+    }
+
+    return this.expand(this.extract(key, salt), info);
+  }
+
+  /**
+   * HKDF-Extract: concentrates the input keying material into a pseudorandom key.
+   *
+   * @param {WordArray|string} key The input keying material.
+   * @param {WordArray|string} salt (Optional) A salt. Default: a string of zeros as long as a digest
+   *
+   * @return {WordArray} The pseudorandom key, one digest long.
+   *
+   * @example
+   *
+   *     const prk = kdf.extract(key, salt);
+   */
+  extract(key, salt = new WordArray()) {
+    // HMAC zero-pads an empty key, the same as the string of zeros it stands for
+    return new HMAC(this.cfg.hasher, salt).finalize(key);
+  }
+
+  /**
+   * HKDF-Expand: expands a pseudorandom key to the configured key length.
+   *
+   * @param {WordArray|string} prk A pseudorandom key, at least one digest long.
+   * @param {WordArray|string} info (Optional) Context and application specific information. Default: empty
+   *
+   * @return {WordArray} The derived key.
+   *
+   * @example
+   *
+   *     const key = kdf.expand(prk, info);
+   */
+  expand(prk, info = new WordArray()) {
+    // Shortcuts
+    const { hasher } = this.cfg;
+    const length = this._getLength();
+
+    // Init HMAC
+    const hmac = new HMAC(hasher, prk);
+
+    // Initial values
+    const derivedKey = new WordArray();
+    let block = new WordArray();
+
+    // Generate key: T(i) = HMAC(PRK, T(i - 1) | info | i)
+    for (let blockIndex = 1; derivedKey.sigBytes < length; blockIndex++) {
+      block = hmac.update(block).update(info).finalize(new WordArray([blockIndex << 24], 1));
+      hmac.reset();
+
+      if (length > 255 * block.sigBytes) {
+        throw new Error('HKDF cannot derive more than 255 digests of key');
+      }
+      derivedKey.concat(block);
+    }
+    derivedKey.sigBytes = length;
+    derivedKey.clamp();
+
+    return derivedKey;
+  }
+
+  // The output length in bytes, given either as length or as keySize words
+  _getLength() {
+    const { length, keySize } = this.cfg;
+
+    return length !== undefined ? length : keySize * 4;
+  }
+}
+
+/**
+ * Computes the HMAC-based Extract-and-Expand Key Derivation Function.
+ *
+ * @param {WordArray|string} key The input keying material.
+ * @param {WordArray|string} salt (Optional) A salt.
+ * @param {WordArray|string} info (Optional) Context and application specific information.
+ * @param {Object} cfg (Optional) The configuration options to use for this computation.
+ *
+ * @return {WordArray} The derived key.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const key = CryptoJSW.HKDF(key, salt, info);
+ *     const key = CryptoJSW.HKDF(key, salt, info, { keySize: 4 });
+ *     const key = CryptoJSW.HKDF(key, salt, info, { length: 42 });
+ *     const key = CryptoJSW.HKDF(key, salt, info, { keySize: 16, hasher: CryptoJSW.algo.SHA512 });
+ */
+export const HKDF = (key, salt, info, cfg) => new HKDFAlgo(cfg).compute(key, salt, info);
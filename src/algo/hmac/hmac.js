@@ -1,6 +1,6 @@
 import { Base } from '../../core/core.js';
 import { Utf8 } from '../../encoding/enc-utf8';
-import { isString } from '../../utils';
+import { isString, wordArrayToBytes } from '../../utils';
 
 
 /**
@@ -40,6 +40,7 @@ export class HMAC extends Base {
 
     // Clamp excess bits
     _key.clamp();
+    this._key = _key.clone();
 
     // Clone key for inner and outer pads
     const oKey = _key.clone();
@@ -77,6 +78,7 @@ export class HMAC extends Base {
     // Reset
     hasher.reset();
     hasher.update(this._iKey);
+    this._updated = false;
   }
 
   /**
@@ -93,6 +95,7 @@ export class HMAC extends Base {
    */
   update(messageUpdate) {
     this._hasher.update(messageUpdate);
+    this._updated = true;
 
     // Chainable
     return this;
@@ -102,6 +105,9 @@ export class HMAC extends Base {
    * Finalizes the HMAC computation.
    * Note that the finalize operation is effectively a destructive, read-once operation.
    *
+   * If the whole message is given here and the hasher implements _hmacBytes(key, message),
+   * the HMAC is computed in a single wasm call.
+   *
    * @param {WordArray|string} messageUpdate (Optional) A final message update.
    *
    * @return {WordArray} The HMAC.
@@ -116,6 +122,16 @@ export class HMAC extends Base {
     // Shortcut
     const hasher = this._hasher;
 
+    // Compute HMAC natively
+    if (hasher._hmacBytes && !this._updated) {
+      let message = messageUpdate || '';
+      if (isString(message)) {
+        message = Utf8.parse(message);
+      }
+
+      return hasher._hmacBytes(wordArrayToBytes(this._key), wordArrayToBytes(message));
+    }
+
     // Compute HMAC
     const innerHash = hasher.finalize(messageUpdate);
     hasher.reset();
@@ -4,6 +4,8 @@ import {
 } from '../../core/core.js';
 import { SHA256Algo } from '../hash/sha256.js';
 import { HMAC } from '../hmac/hmac.js';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { isString, wordArrayToBytes } from '../../utils';
 
 
 /**
@@ -72,6 +74,14 @@ export class PBKDF2Algo extends Base {
     // Shortcut
     const { cfg } = this;
 
+    // Run the whole iteration loop in wasm when the hasher supports it
+    const hasher = new cfg.hasher();
+    if (hasher._pbkdf2Bytes) {
+      const toBytes = (data) => wordArrayToBytes(isString(data) ? Utf8.parse(data) : data);
+
+      return hasher._pbkdf2Bytes(toBytes(password), toBytes(salt), cfg.iterations, cfg.keySize * 4);
+    }
+
     // Init HMAC
     const hmac = new HMAC(cfg.hasher, password);
 
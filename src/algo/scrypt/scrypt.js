@@ -0,0 +1,117 @@
+import {
+  Base,
+  WordArray
+} from '../../core/core.js';
+import { Utf8 } from '../../encoding/enc-utf8';
+import { isString, wordArrayToBytes } from '../../utils';
+import { loadWasm } from '../../utils/wasm-utils';
+import { wasmBytes } from './scrypt_wasm';
+import { scryptWasm } from './scrypt_bg';
+
+
+/**
+ * scrypt key derivation function (RFC 7914).
+ */
+export class ScryptAlgo extends Base {
+  static wasm = null;
+
+  static async loadWasm() {
+    if (ScryptAlgo.wasm) {
+      return ScryptAlgo.wasm;
+    }
+
+    ScryptAlgo.wasm = await loadWasm(wasmBytes);
+    return ScryptAlgo.wasm;
+  }
+
+  async loadWasm() {
+    return ScryptAlgo.loadWasm();
+  }
+
+  /**
+   * Initializes a newly created key derivation function.
+   *
+   * @param {Object} cfg (Optional) The configuration options to use for the derivation.
+   *
+   * @example
+   *
+   *     const kdf = new CryptoJSW.algo.Scrypt();
+   *     const kdf = new CryptoJSW.algo.Scrypt({ keySize: 16 });
+   *     const kdf = new CryptoJSW.algo.Scrypt({ keySize: 16, N: 1024, r: 8, p: 16 });
+   */
+  constructor(cfg) {
+    super();
+
+    /**
+     * Configuration options.
+     *
+     * @property {number} keySize The key size in words to generate. Default: 8 (256 bits)
+     * @property {number} N The CPU/memory cost, a power of 2 greater than 1. Default: 16384
+     * @property {number} r The block size. Default: 8
+     * @property {number} p The parallelization. Default: 1
+     * @property {number} maxMemory The maximum number of bytes used, 128 * r * (N + p). Default: 32 MiB
+     */
+    this.cfg = Object.assign(
+      new Base(),
+      {
+        keySize: 256 / 32,
+        N: 16384,
+        r: 8,
+        p: 1,
+        maxMemory: 32 * 1024 * 1024
+      },
+      cfg
+    );
+  }
+
+  /**
+   * Computes the scrypt key derivation function.
+   *
+   * @param {WordArray|string} password The password.
+   * @param {WordArray|string} salt A salt.
+   *
+   * @return {WordArray} The derived key.
+   *
+   * @example
+   *
+   *     const key = kdf.compute(password, salt);
+   */
+  compute(password, salt) {
+    if (!ScryptAlgo.wasm) {
+      throw new Error('WASM is not loaded yet. \'ScryptAlgo.loadWasm\' should be called first');
+    }
+
+    // Shortcuts
+    const { keySize, N, r, p, maxMemory } = this.cfg;
+    const toBytes = (data) => wordArrayToBytes(isString(data) ? Utf8.parse(data) : data);
+
+    const derivedKeyWords = scryptWasm(ScryptAlgo.wasm).scrypt(toBytes(password), toBytes(salt), N, r, p, keySize * 4, maxMemory);
+    if (!derivedKeyWords.length) {
+      throw new Error('Invalid scrypt parameters: N must be a power of 2 greater than 1, keySize, r and p must be positive, '
+        + 'and 128 * r * (N + p) bytes must not exceed maxMemory');
+    }
+
+    return new WordArray(Array.from(derivedKeyWords), keySize * 4);
+  }
+}
+
+/**
+ * Computes the scrypt key derivation function.
+ *
+ * @param {WordArray|string} password The password.
+ * @param {WordArray|string} salt A salt.
+ * @param {Object} cfg (Optional) The configuration options to use for this computation.
+ *
+ * @return {WordArray} The derived key.
+ *
+ * @static
+ *
+ * @example
+ *
+ *     const key = CryptoJSW.Scrypt(password, salt);
+ *     const key = CryptoJSW.Scrypt(password, salt, { keySize: 16 });
+ *     const key = CryptoJSW.Scrypt(password, salt, { keySize: 16, N: 1024, r: 8, p: 16 });
+ */
+export const Scrypt = (password, salt, cfg) => new ScryptAlgo(cfg).compute(password, salt);
+
+Scrypt.loadWasm = async () => ScryptAlgo.loadWasm();
@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJztvQuUJEd1IBoRmVmVVVnVnTPTM+qZ7pEiU43UI8301K+rqoXAHS16pGbQjCz+4lOqrqqe7up/VU3PDCvUDRYgDALJfIwf7JqfV/JaYvGCbcAsHnZ5rGwLW7axn2x01jq7PFbvmbXlZ62R38rM494bmZX9mZEEeOG8Y8R0RURGxufGvTfuvXHjJqu2FzljjH+J77udsdsZX7+dr7Pb+Tpfv12srzP4w9dvN9YhDX/5+u3mOubwh6/fbq1Tnn75+u2xdV2iE3z99vh6UGavh8nE+k5pvn57cj3yxFmP5lLrm7LirW/lbzXWThu7e+zdbk+fayUToidtmgnTtEzbNk3btCzT3LPbNVO73WTS7u1NplLmrlQqZSdjUEMIEYvF4rG4ZZgxy3ISwuRCmMLaa8ZiphlPmFbMjDMRN2LQkmkKYZqGxZgwLb7Cd++2DM52xRJ8nauNjfPMif/fvDe22Fhcbp0TLNautc6tdNiL4vXlGzCVSdaXj80tVRfm3tJgXrpxdmW51blheanTONthsudUozO1NNeZqy7cVG3PslJittqenTjXabRZITE7X5+htJ+YXazWKD2SnluMNjLkrEzP12dy9PTa3ZXKmem5pfqpxlKl1aguLCzXWK03UjjTajTY/K5IySLVWrkiUlat1yud5Uq7U63NV1aW55Y6jRarJy7jTHFn19Kt6uDtbzr2xrmTU1det/z61w0m3/4hcfobQyKxzu+8kil33udDTIzTf5Kp/8Gn0gxTj1z4FzenmWIeS5mOZMpp+kKdXfUMxYbFt+8d983JtFBXeBa8deFC/GSaS0NdJYWy1075bH5N8VXJFO/Mq5c2PSvFnaAb+A8aeeLecT+mXGxbPWNAbWdqwDeUseaLNZ8pY3UyzSRT621oSzT9uDI6vqEee9951vSZNNQTkBoWzBfDwvbtY2kubcmKIiWZtIvCTglH4oCfvHf85nRMsrQIhs8mIT+rREc9fI9uxR4Wpjq7Ko15T0jbixkAFHtYuD5LM0j0YSI2LPo9S8MsNixSPpOxG+GB6lMulPT5bIBaTHMEoXCg2PY3j4478EpTxpTblGzAixvjMu4lpO0zKGZYDK3ZA15cMmzdDhpmjkwoVhTMkdZkOkaDgV4GwznBhNgxXFBrWLgydizNpAVD6ANQpBIO5VzJ0jxlOwQ8JgE2jmTSKop+mqLr27gWMG6XqvU7NFMbAQkP+vSDFLwcgtK3pZC2FFMwCyGZjEl7wItJ5tkp5jgKwHanjJ9ZLQJGOIBATY9JIQ1ltIrChFTTN6QBLXIoSVkOoasUHSWk6PhMMcnmW6uzfgJQRGgUsaXooggD+McBAnEYbEraMk6LoIeQCIfAwr4Z9B2DIcz7NvUO3TahBUZo/OS9476YBFIZFk/dO+4ZUfyOKy6FMtY6fmIVqsi4TLSwFwnUpMcpvDjgApQEY/fikbF7wpFxaRQFgNKAsRswdvgRRWEDeQLMYB5FGIPC5Xjy3vGU6SimMV7dicgBk/z2veOaGqVQ7hRgbjjjGMwYAEeTtWGyoihYMNX4JKA0TZVFp5pQXMZxqhZNNSEtPdV4d6pxLwFThRI/rqeakPHuVOOOTAAW2TIe0EqCfuIByRBGsHDAyAxYFz1wRe0AEgIhAZRmN4HqeUrAc6DxkPFZm9hADPmejYQLbXk2MBG1X1qKr81LS11xbKDjxeE5coiYHyNs9xPSAGgmAF0SUpwEyHq2THhCJrBRIWMelqUMTT4JmKCMybg6uKbM1SbQWuzGASgd8JiMK97x4jKWZo6DKG+39OiEtELEl+YqTM3YwtA85gAtc8cYDwjSj8HY4lLIOJBkYsCzYMmngL9IG/pMCKzsAhiQ+3gxRwppyTiQry0TkLBpHjAqCZyhSwqMQACEbsyfTHMH+GaXXdrELm1glzYxtAi7jHXZpQ3kGgtQIKbX3ga+aG9hl7Gt7DLWZZexoOEuu4T1xiHTgKCnCMuMbWaZ9haWaW5imbCIIcvkEZaJm0aMWGYMasc0y8TZxibTnB706QeIZ11CtbcQakCmdkimNpJASKYsIFOsuyGmsGfh9qeEs3m/DfhRnIg0JuMhP3qGdxlSjBjSM7zLkWJbOFKMyFRE1gh+RLBUm8nU3kqmsJhAkentkoHGo5MBXn2X8sfTTHJlNtV77zsPLPrfbphNtbFxx6r7UjEu+bAwNeky2Mv4sEh5FkH1mXvxue0nmj4rQhboBp48C9wbRn18oAiZ7iCeDvj6w+87j5QOE8atFyfhx5vH0kLirsLSLGU4IaDvug/FCwAuAwogvnzXfeOwxheuKIq774P+raL45vsgkSiKRzARK4qHIYEAL4pvY/oJTH83Uv4Epp/E9NOROk9i+ilMPxup8xSmn8H0Xe/v1nkGyzfeD+n3vr9bZwPTd2P5B9/frXM3pu/F9Mcide7F9Ecw/elInY9Eyj+O6Y9j+sFI+gFMP4Dpz0fSn8X0ZzH95Uj6C5j+Aqa/Fkmfx/R5TD8SST+M6Ycx/c1I+lFMP4rpxyPpxzD9GKa/jeknMP3dSPkTmH4S009H6jxJsMX0s5E6TxFsMX3Xvd06zxBs70X439uts4Hpu7H8g/d269yN6Xsx/bFInXsx/RFMfzpS5yOY/jimH4zU+TimH8D05yN1HsD0ZzH95Uidz2L6C5j+WqTOFzB9HtOPROqcx/TDmP5mpM7DmH4U049H6jyK5BhTvU3YoJg62/RFEegvrP0Y1ZBsXibU65o+awLrsIvAGUC61FwQRAE1XBQmgGxjgxfFxn3jwAuEjJ1E9imO06aWAmluFfcgviatY2lD81+kXWhIxqYGkGhlTCaanq3ZAPEH2k+ZH5eoc4RMQDjOjq1zJ3wdJQbNR3A0sKcCQ2n69lRa4Pu2x0LROAKUuAbKNkCYBIi4NC8FCATU7dAcNIb7g3KbUwN+XA1g1UPAfbykjCu3CblH3zdeEiCCAJuWyRIIgZfgWrAPPPo+kPYGm8gvmYrj1qhMeN+egj04LsWxdFzGZRw2gjtXScCPSzHvMz14lPtICaWNTEjm9qfsTRuZrbhkuJHFaCOzZUxvZCyykYGSAfsybmRMb2S2ZN2NjOFOBRsZbl0pgBL9wO6Wiju0swG4YJVkookApnUBQHb35Hi3UOgNet4zpNC7ybG0Tj8FaRN2atyylLGq+I04zxhKaO5elMLhVRlrgm5rkgwW0wCDzY+H+gloBAAuI9z3DRd0sCi4hALtPAIuEYLLiIDL8ASAC4sCRdsTEUXbMxwNGWkE+76gH1BPUhYgLUh+QMHfRQrWWylqUjZisLQjckuoaaEKk4o5gXIhtI6HmlfvcRg1yG9PUaMo1MNQQiXxYlrAk6QFoCKHr4MCqruHt1CV66qbgQKasmDl47TekWloghS49AFBMinmtaAiE01fIFXGiCptGYsIadvZk+EQ6TwdQAteAo2XXg/XmibUhQCKSbrik9GKXaQgESvQYoLGfSGNmzUS/NAL5Uiu3OYQk8z5/b08uX4lU0+weT85xGRScoBoUiaLQoYqVoy4HUn4MZmEJSzL5MTGxsaGWxIZkPmTKtOUSSWb0pIx9zKZHBZllETdFgDRnfE5SM7YQUEmgS4z0DTIWhzeeTbJmEMPyhGpErAdOk8Crpah2YLHpfActErB03k/BcUZGZu/GSrCQGIypbji7rAeh4Mv+lzGmqDOyJQSZCxLqfWmn4JBWIrJ1LMOQAdeb/pQFWCfgkfXMRhCTPGm78A4HMVu0tKvdNJMcS+Nc1XcS0kH55uG+TrKWPV6AGxQUZlA5I66Y9XrhTJjXKaBTVjQZ9NPHWFMFdvQl6V4E+bQjBYJKBKbigwoMiJFvTIGPBtsWo7s0QokdIJddOvBVLyY7IH596AumJROUbxMJmW6KMYpd710cODmJAL2+qZ0YJ8KAPsyQIZhMe6lqQZ2Y0gzXEppIoq/TC8lAaqXkIKA1HtJIJkaSL1bgfQ42w6lsCwCprAsAiddZu4EqN4ooHTFi0DqFpmUvUXxCsrdJJPqm0xTQFoaRAGvQ2RLDos3kkKZVA8zgGGsC8NbPMTNV3i9RAu90mlGycGBOceK4hZq6FUaWWHW7puB7EzdaS+QHVW6yddNxbASFV5PhelNhRlfEyAVEnmDEOPCT8oziRW4Yhw08JuQZBKwTwW0j6oYbCnIFAa7TKEvZRE5xGQiIH8W1gIu0C+TIIj0QaeKg7rnccW9XVpIS4K4MkRVJfGF4VDv5yGxyybtCQE8hzwLfoZJC7YnkUjRzgFQbYIBUYN2WEiP67qErcT4h5Hj4a4OzbsvwbpD8PKuCBswikJ3uBuApNxmURzG4ZnILgmdIRsAzqTC7fxTcW9PygzAZSK49qS5NL09oKrvcV+NqLCHOKdJnBMpbo90u+RmQqsmTVyz48NNqODuC9jgTztHFc/FUcX/TziqEeGoxo4c1Qg56m5pdDmq0fR3a456EX5qXBxExj8hPzX+ifjpYeCnuwmF/1fw07BXYKj7fkSGil0Ql6GxJ/To1QW+cDw0ZOKi98GfQT+laa6vKVPRiQ16KbSgo7GSZj8s+mVq0+xSuKJFMUijkX6Prjgke2hAuwD6u2RKJk6m4eTCCRh4l6UH78bCd8MZcgIWDyeHBtEYDQVrwQFSSjrAshSnX9DCN+X1c8lk8pDow00EdgKbNoTBzb3QaMxwNHYIWvUEaw6xXmg3Bu3d30PCbGbej3dPRiOiJDFmaaIca8s4TTweTlxxLwGybFwNoRwDdkfm2+4hxTxT2sigQPkd9pK0D6XpFTgUGRaHPeJWUBNIMTGZBnoDZdFW/BiZFFa9lLTV9y9cuBBf9dKKIUmaBiCG2fR7gIurO1ab6iPvPM+kqcSahHPV3mEBQry76ruqv70GVNQDpIrlrnKpyFAuKJ5peK/pm0iIKUQXaHznpqEBE05pjQ604dABGxzgJEifRw0rPiyG4NCYYyOio0z3zcR1zGCqse4rJum0uEEluvIgPrCl6dnBNmQfxxO9wOocR7uzPe8HIibYGfwe+AHrL5zOQe+0zZjAPrALQzFp4jbTQ1CEqg4umUHbTBxEEDRMgVIUrKLpJfRAcBndYLcxQXFyZ3wXdhsBrLRXL6MgViqAldp63VyUcfXydTcDA5anZ9NuYwAj7dm02xjAR3s27Ta2NIGN0ur1ajYKnWAX3Xom0LEpewEMvchFEZB9Mi5dOLSIa4sIDhxpJ65STTREhMDt80z4cT2XamA3ITeJg4jc9NNF0RcBk03rTiCyJ9EuchEQJTWI7K0gCnaMKIzCsgiQwrIIlHRZcicw2VEwBWL+znCShNqDlOsnkndlmkje0SSPMolBJJ+ObLmxkOS5RhnafWOTaVMDwiGSd4jknYDkrQjJx4jk09vokiNd+r1E8snNJA+i5iZ6t7bTO7R80XY30bsYD4mbEedA4oZyh5CmH5DG6SKNJKQZ9OygDoI9Jh1CmxjC4s3SlE6TDr8J3ABgoG/HPbQdoMkQoLFQlElOpq0dgGl1gckjwExeDJixTfxTbOefYgs8eRSeFsIzuTM8YzvwTwbSCxgkk2R6cYgZXgzI8WHRT+U28tKgMEWF7qZC5mueSoUyrjK4AUpT2rihmlLojVbo/KaNWHFpwu/vpfh165KrlSZAF7Z1PKLUSXPNt1CGUdaaF4NduKnMNS8uuRKYsiVXBqYSdKZnrnlJqI4psC7FMJWSXMUxlZZc2ZjqkVwlMNUruUpiypUc3IjMNW+X5CqFqd2SqzSm9kiuejDVJzlY8Mw1b6/EFeg0fRMMm/ukCez8MvixvX74SXn74cf1DsBPnzcAP/3eIPwMegfhR3qXw8+QdwX8DHsSfg57HvxkPB9+Ct6V8FP2huDneu9FinlXGePyKmV2PFNeJfceS5toufeuFuPyKtkHWuPVHri5wABb3jAW74Hi4aDYaHmHsHg3FB8Kis2Wdw0W74Lia4Jiq+Vdi8UuFF8bFMda3mEs7oXiw0FxvOUdweIeKD4SFNstbwSL01A8EhQnWt5RLE5B8dGgONnyMljsQHEmKHZaXhaLk1CcDYpTLS+HxQkozgXF6ZaXx2L0F8gHxT0tr4DFcSguUPFVwKRHlekVw4UtAWXta/tlD6goB2VQ4rf9nLdPMnlNUHKg7Y9510gmR4KSy9v+dd6IZPLaoGSg7b/YOyCZvDoouaztX+9dJpk8GpRc0faPetdKJvNByZVtP+9dIZnMBCWy7Wc88O84HJQMtv3D3qBkshCUDLX9gudLJoeDkv62/xLvalkaFtfLF7X9F3n9ksls8NRr+1nvcsnkoaBkf9s/5O2XTB4JSg62/SPeQWNcXi77myp+Bqrsh2TiDDwblvubKn0GJn6lHG6q3Wegz345KAewtt/2L8fEPmni7zVt/xpIJs7IkbYv5TX4+r62P9T29+l2L5MHsO61bf8gJBNnAERe279C7gt68+UVujevX14uL4f+Emfk1W3/aqwy2PYH5dVYZaDtD8iD2OKVbT+oKdv+iLwc6x5s+9fKEV3XG5Ce9KB++gys1WVYfqDtH4CxxnXLNKphGOAg1sR5HdR1vQNyvxySErNm26epQ2N66jBMeRm+iPC8Wtf0TFkEcaEIG0BMMUDP1QH4u9ZUZscfVm7Tl+DTIKSgZfaHZb98UdN/EWj4w9KXhaY/BFaNYXmFzDf9K8F0Myz3yVzT94siI4fl5TLb9L2iOCyH5YDMNH0JBqVhea082vSvANPXsByR1zX9y2H7HJYH5ZGmfxBElmE5KA83/UGQW4blAfnipj8Akt+wvEaONf0DIP4Ny/3yUNPfDzLgsLxavqTp98O+NCwvk9c3/ctgcxqWpiw3/X0g4Y96V8lRacGe5/Q6clhKaajH3nGeuQdwH+GSq2ciWfUkZCaY8+tJ0b8uwCH1UTpDCJyy0OcFFbk5OiIjIXSS/HPUxgYKijGoCHv/Q74vYw/5V945ceHChQvGa9JMXil9aT10x50T8XfKxEOvQa14EMwJApQK0PpkfEHFO0q615AlLgGWOFBd42RBIDsb/AzLJKoBotNEGwP4GyXVcNPtRS3WQ728H+ydCuxrZE07TArpcGBlHW66+0kddbfsxDFldTytqg6SqhqHudE+Lx1pLfgpJTpeTDEvjlYynCYeq6hnQZzR1gBwzFImqoY9pJGY4EOmTPcUnSikvJ5AOgtMjNRvCow/TKZBinGlpdZBz0dj7m5j3G0FNniwiMjeqQF/jxIdv0+ZpL/BqvRN+ntxVrtlHwpzbkv2Yc/7JtMcX98jU+RabIDE1iv3zHuXQfWm16+4F5d7wOdtP5o5LTTfH5AJbwAPzvei8HMAlkAmdAcyIffIfXKPa4QKHZyA7yFlbz/oH4OYn0KI7lnwDsh93kFpeOB1x8HVcp+XkAPePnnQG5CDMDqwafTSPKcG4Cx2jyMNGLj6mEZgZco+lIf0r9wDotDlcg+IQULuQWui3AN6zRWKeVLu8w4Y43Kf52FPB+TlAWPetQpuWU25Z8GPwzgX/MRUGnTg1PG0KSWMUMoDsCKmdGWfjC2Qho6IiHACV8RBY1wekHHwVo3hBgDpxLBgbSBNNKsdlAfhSUKZzW65HSm3I+WpsKWESoXlcFyNAzH8BEm64CzgxWWvHIQuBoH4xbi8ArDoCvTQC1qJDgUN+iZ5fq43/TjqVAfkHjmAy+h5AwAuKYmZ6HXfLfcFK+4hSr0ZCvC339stL/N65WVpMsqnyBqfRJffnck41iXjQU2XrmTIGMhwhdxAcWkDaxDjsocE756I4I02pQQRaiIkVCTf+GTaQq9OMGdZjgTEePYuYnfgQilT6r0BJj34jvNMldTDkL8RleYYmbaiRrXzrAQHukn1KBisnL9P8B6wU51n835iCOiNF0XqYpYqjnq0A3aphCpE7FKblFQBi1T2Ult1qgSIrelNSmqya5eK6lU9Xb2qF49iInqVIVPblJ/01IDvAnZw0Kt2BXoVKvVYvivQq+C02eOyN6pX9XT1qh2b3qZXJeC0AU1QqR1NUDCr8KSya3QyPccJjXD6HMYGHhcY/dyUsdlsh8ZHdDftp5XpkwlynY8rMeXthsMaYzxg1d2GBnE0UIrNBPsDbN4JOLwaRNOtNAMDCxzsa8MlVRiSCUDnmArMXwnA5T74GfLNptyjLrzt2fiqss/KPar/LOVaRUbbkImuRNDGcBPJHohBOrJPmm6MIIBjToCNei+CAtKHNcrs3XziYsq93TPsvUQ9KbkXYSrG5W6svU/u9XrAdAloGekyJXvcGHU1THBIBftkAhHS74EtNiETh0ShZAyH45A9ci/8mxqg10zgwC4gIxr8UGAwabfUSIonMbzpm9I8wqAfOJg7wrqmH3+X3HVEo2W3XETKRaTcCFsyyNykD4BAy/U5Iq6LiAudeib2h4PQb/Fo156JurPHpQuM0kVGuQ/GtA+VH3QK3wuTd+FPv2+i5R94s0T4B0jQ75lA/XvTnBAKHIOhBqAONDFJS9lHRoq9wcZqyr2IE/1QGFgRcE04rQmPFA5SYV9g2t8DI98DhoWTaQMc2iI2hsB4nzgk6AQYTcaBNShJ9h/gVudD+zyaHT5qcyvge/EhYLxRvrfZOm+Sdb6/a513yTLvNsmQaUjTvQzNJ54Z5QPoZRInukWLsxtwBb7FFN0feplIIc3QFN1PllEenIaacAsGrKSS1idO62N3LWL9nonGqz6fS5MkFzswU9uw9HZgprb1aagJR82mNlPbXTO1qc3U/WSm5lH7dCKgTVsbXxMwUzS+JncwvjrRcwU0ajV9e4t92t5un7a326ftTfZpJ2p4TWrDK54v8J3s00mYf7Jrdx2iYwJJucHN9unBLfbpITI1SrgnoNnpdvs0eAlEwORE7dNOaMLfCUQxDSJnK4ii9ml7B/u0vYN92t5sn47tBCYnCqbN9umtcMrIOJgOD1NuWMaBxyLiJ6RFiF9GTIsDPzUJfsMAv8jhScYT+mCKKMCBmwYRIhCSdo0MtVPQiIob4ZvRhQ46dIDSqIZm584mq+TgZl2ICrWCxLumSs0MwAYctVEGzOHv4jy2TtcaU0OMbgSkVS/IJWn11IULF/iq36tW2mAtBOknLXtRht4VEDHqGC6o5m6aoajnpWF7AI0ire5oemm5C4yFrtwF4usu6UpxIzn/yzTagcEdOE0CdpCxo5lUNONGM33RTH80MxjNyGhmKJoZjmYORzOZaKYQzZSjmeubXq9MA5C9tDZr4xYDnizDAjydd5JJtcgixpVEPbCXTrnxELYXZJJ+kNqAlfYCNrpw+gAWhV7yuu2dePgzv/q/wQGusj0hUwq9b1NwjG9TzlQ22PkJ/VJgK7al6RZlCuT8NPzYXi8d63XwxoaEWYCGaEhBaJoC064AqSIN6JoOtwy8TJZGngwtw0E8tWzCWLBl3U/k3Bz6caP9WNLV/ZDjSHdLkgmwcos1v0cKybv9cNlD/YQdwF5BEq1iON0ekqt7QK52ZA/K1caqtwtFCcBaARVl3HMN2GJcde/d50HEslHwRZ9W6MkFgNEjZEXhUxf8OF3ydhUwkaafRrMxbC5pEDkcnK7uTsZpstu7IQEFJg/zhhmvosyH23GsKXuaKg4e0fwkgkygrUuA8zNT60rKtDI6853VosB7X0JytXE3KUrKVNJtopwAMKIzkdQhYaIqlEIHVOe7cTqxd1ERUkyfqwND9cFhxU+FthHpKLGmz4xSaCIBB5fewESShLVxZ8Dfgcu01wtsKE3mEC1DplDfwatYaWwVFgS6hGcGyJ7Jpg/wSzb93aSMpKQIIeXAguyGBaFHsCDRpzY8tcOnYvPTFDxNhU+N6FOwRdH4m35KryJJmRwa6vgOPO6B4zTaRJLbRwcGKi+Jg/QcHJyXioqdCXBBSsmE7IGFSACFmgToVSQ7TIs1PyXTsNI92HHTRzWcVlqEKw3iYspLwgW61A+41YPvpAUHgNNhBJgH3Ouhmu31wKk8IR8ONIGo7CMXgR7JqSUpUyTMblKCUzJ5kkRbW48nBbjE1AbKPR3V3+606HgNhuSnVO+q6jmGdCo6njCokcSwMLvDcqg9EY4HgAUuMymkOWRj0EwgoF/kfRwPejpQEy0Q21IoMO7cHzGboL5AMS+lUY9qgPOOjLtJJA4/RXMSePMChAuwH675QsaPo/oO1aIEYspUmisT5b5kCvllUn1Zr80mEiGhyEt2NwTg7SYdWTIp0oL82oWMq0feqU2+4QrJFEkAMZkivkm3BrUaYZI7VQgisqokiNr/KsbtdTpkBN//JoxfSDbv461TDg7toP7T6SPqfjFtIOMIWLjbqS/3crpCPu8n1B3Hu6UGNg3CtTjMmPqd9Zc3QRJsRjIimjHCjAfGIpD/kFygCcAfMClFakBTcB0YMCahKxpNvHUbyKdaO+0OAI23nK6ui8OMU1towwoLhS4EmYw3PRtQAg72YaEsdZ7jH7zsAzBBCMaBMZjqqfiqz+l2Ml7+gNfprrJB0CChxU+AKSW+JhMqttZSb3v7XfYqHsle/Jl58WcXeyCazSbAB+w9PpeMBBBLxuc9C8ROsBlCiAmyXEhBv031tg1zQblrAHMbAQkTiatn+SptN9Af1/3xbn+IBBq2eBtnx8HCumlYY6AJoSuJLZXg2BqHNgiz32FkpA8isHuhsrGKu3gCcI/OzRGLQbGwND4m0CeFwbUSHmIdj+Ijj+Ijj+KjLS3kIH4CNwSh7ygnyN5gSx6pqc0MAvi9oHvdzn9I8Mtga90w531riElr4nv/6Tvf+7V/9Z+/cKwknuHSmvh/L/zPd3zxX370gU+vl8RTUPDBpz7xr+/+6vn/+t71kngSCv76HV/59Pf++2N//AwriSeg4A8/+HeP/8b3P/qLT7KSeAwKPvdLTz38qd/9N9/9A1YSj0LBI3/9iS/98Yf/7WOFkngY8h9++we+8ba3feujl5cE4PHEO/7qCx9///se+A23JL4A+V969Pff+42vffWR15TEZyH/1Ic/8wtf/vwHf/PGkngA8p/4owsf+tLv/tI9D6yXxMeh4DO/8J73PXbve774C+sl8REo+OZ3fvWB3/w/3v4bf89K4l4oePZbv/5/ff6TP/exP2ElcTf2+aHH//3b73/4npGS2ID8H339V3/zqQd/74N9JXFWWhN/94sbv/XsA9/+k1eXxIq0Jh7+w/du/M9n/vLB3/kBFGalNfHHn/yHr/75N/72IzCC26U18fif/If/81c++l+ehf5eJ62Jv3ns03/zyQ994ku/9wMQ3CKtia//9ju/f/6LD7xzqCRuktbEV//kv3/6r5+67y+XS3DsNXH/PU/959/9zLPfUCVRltbE01/6hw/9t9958G821ksiI62Jr7zn65/4d1/45Xf+KSuJYWlNfORP/9X5f/nEn311d0lIaU18+h3fv+/P/+IX3/drP5h8v7QmvnXXt37vA1/52jN//oO5u7A+3/u5z3zr6b//i7mSsOHtv3zggx/8hT/66N+yUuiRh8I2P6YT4madMIISMyixgpJYUIIBOAzQoWIY0cOLw48NBgyQCBLw43pJ+OnzHPgZ9FLw00/HWXDqB/uu2hDPOnSHEWThXmRveGbl7ZJxb7e0vT0y4fVJx9sr094+mfIukzGvXya9/ca43O+Zst9jeMqw19sn93gDcre3RwsNPXjfTArZ0/T7QW4F89t5Rr4+cAaKkioVuZrL9av9Z2S/6jnTlv3KWGtLl7QzMmRDvbKu1wv10lgvudZuUnvckf2Sq8ffpY8ImLr8jGRqz5m2ZCoJf+FspL0Kf1fbTblPHmhLcxX+XtaUpjpwRppq75k2gPhMuyn7m6ipNv39Ta9f7ofDtf2yF4ST3lB3hR9XMm+3HPD6pOntlQe8y9Cpye9R55H1SkMekKkm6HKG3CfTTVDnDPTLAo3OkPtlsglKnSEH8KpcCo/J7Ka+ISjjeF3SkP0y1gTJwFIbJhn04iquHtHTVTEVU18OMpay1IPvClUAU30syBjKUO8NMkIJ9awWTsBeob4bZOCq4OM68z9iPLa+zYuXqTvI78e38MqlMpUNHumrfnxA8iZx4aubyJXjdJXfPh7cLBAqQddSDbfPF2Bq7HVov4SoScfTtnKlAd5IEP8GYhp5IKuc1a1h03BTMw5H23Dix2+mn3m1sWEfT8clmThiTTjlpYskJ9E2E9wj5bh5BzdJGfm4WkqsphPSojgk5Js/lYajpoS7F/iG5PN+nJQXOIuH2Su+2lIC5F/YDWDDjYfhGixowQrvU0ou4+4eCBkEpw/bXrbgZd69fWk7wQ3FWNOPw0hskjlgFDGiLxPuCqzKeLeV+OZW8FYPI9UYBmBtH28sjFMTh37wpm70cjEIoTwIBROjoDhxtGjgMCAo1EVb1rd6qbUACDF3j76k+t1wcsfTJt63BdkJ/kEUq8kgCIo+QwJcMyjqDeAaak0G4ZqAexrg+2zfnI6pT//8eaZG1Dd//jxzX8Mc9e13Y/6DQf45nu88HYsAzwO4Akzw1ieoE09DeByK79TrSMNlaABHde4OdZaGDgbQAYxQBLq7AWojzZF358jBxMLclCOF8/EYN9cR60GKU3dQlC68yt6kEEkgCa+iGVWsgsKNIiFcyIVb8iA0w3AJx2+kPQPEeWXcCAQn9QVgHRaKrkVDR7RMeDcZRSwp3L3ONvKHl3zomGzqmqwEpZ+iq9qGjsfk7sVDYbheHCIBa0pt+ggG2B0TgFCKbUMKX3L0vXYscvvp4i1EBFlHw9Hd94GAGIPCR9837kPEsgtXeJzUKb7lmjDDcCaQCjtCMTIEW3hVOrw87VDck437xn1xMzjH6lu/k5SG+8GmOjSVNiliAUcFGNfGkOw4XS9HEm1OQZAwMAx6XEcr4FsuJ9PwnowOrwsH5JmkX6DkGQRRALn4whX45/iAx3W7d9833rvpRrsRBgAQdKPdkGLHAADG9gAAxpYAAAb0AEI2GrA4hdjieM292ymtBqPV4C908HAyhU4vah2vfTvOE1ZorI4HxmobjNUJaQfG6iQYqx19hUQmUdQJD7twZRzQkxy0FIgOan02GKttMFbbaOlAW44Jthyna6y2Q7OvHTVW21FjtR01VttRY7UdNVbbUWO1HTVW21FjtR01VttRY7UdNVbbUWO1HTVW21FjtQ3G6qS0FcVzSEaN1Sp+/KKW6iDeF2xfYHdGIwttcjplYztUp9+3EcH1o5SfemhCvhMDQ/n8obsgMeiLh16F8oEFh3dgw/OT0ibDgBJrzRvxuuGZ42kDbmhNpQ3tQUU9w9VTAy3X7g2TFxm15bgnfIeuYjh0s8YBrDLhmBz3O3A3S9Fhj005OPrEcy7EGeh53gVrRzw0o8bJjErBHbf1aexYKi4CVWXjLcwP3q0NPXGy1TxuCXud36muVRsbX2NEIzGMfABiFl6e2rumuOQD0kILCOriGxsb5mpojtC2XDcQuBIYKg/KsEqcwlXowzlHO8YZpEcbKrXqpYJyCBYGVweafjrUltNRPTod1aPTUT06DrfNQI92ukdx2BSeDsVlOlITmvTSm8/iEk0vAQa4I5IPaF+JhGRHecrnJ7v392Dm9ipuqYl51P212Uo/PrimjNUeUzDOID4aiUVwDwzih6x5eDJx4cIFBhwE4mB4GEuGeUkYowJbNfClC8bqybQAP43A+S0pE9IZFu4uC4JWpSw0SbFDwvZ77ocxXTi3qj67sbEB7NXGFzF8TZLCYIGREBbm5WhRjOPgsRe0fel+xdYuVSbapek4+JQcP6g1cAMBSdMZFqldcQzc6cHlLq5bZ2HrIDRMeWnYo0/CRZsdZ2WEXUQbBeGlpyRslODDmcVpZslwLHisLvFOOLzmpR2Zdv7R5L2af4shQE7uDpBcc4Qxcqw0xLhax0BkvkkM3pr38TBtEMRGjJ45r5D3u35iwMfDAweuhMCVdYxbN4CWbrUukwN+EuhM2cchhb434gjjXgoP9A2ZDE6rwbKagmNhoThFsnEoThveJuFwJI5XlKSHG3k/3KeAXTylOG333Zgd2tFWmhgF7wgLzMKkEenrrgaYGA2ZRgEKEM4CU3Fwlwk8bK2u15HV9Tryk0UGZzApcgmEm3bgziBjnuXgLWLPglCNXJnaaqw9TG2JRgQbh4NeonC+7mGw1x4yofYEh88i5Hci5HckXWoDO56RcbUO6zvv4/1BWJHEgN/b9F3pov9nr6R1cNS6dAbwbPxqtAR6DqwXb8okwhulATz9Q28XQya3+/Hpy7G4unRFBtmiji1A40Z8CB72+5svzApirt/Zjn6X/zP6Ib/bin7/jGc/Ap6dN7mt8cwMLkSDV8sqIQDzLfS1slA4wDBKfA1xDhglhH2mQIwxvQXj8YcdWWTrvFp/OYpCIPAm1AYH+S6hzvMb0+imClhjkrplTnx8Y2MjVkLbEgSDbvoWOnmiCIecHrYZi5RxNEtgq5aMAbcnO4UHQTyxlkW1QFLzLSmOMu6LoKYlBdQ0qCb4ZtG2YqNUonAccNKMqjbq+soCSVQMM+4lQeBIBJcYSJhx9Kvo+AOBVI8y5lHMSGkpE8FJwcrgfAlaggqJoAIKBju0EMdwpspdJa8bB3dEgMxRbmLzIGPqh4lND9GSk3gx7wFr3ot5SppAofZmkKNNY2dIM4SMEUDGCbAB7G8eXtPC+FCW84TBUxFOhcSOzsNQb5NazsM7+gZRrrEEf1YUu7FJxCogGK4V3HsAc5U+uqSbBXE81LcVYR6eWKIkTNeMwbtAsTDGG/AkC4O5wQ1kYCO0atgvuuLHpbEwD537cWndrKlYocCtTHcYqR7Oo9CATWoaSFva85mMdyD2G3iBdOtt3jvp8j5XLh72xNFRGvxQyVsMV7VDPqEWeAX0r3ZkvNVCAKw0PQscSOEQmEcu7UID4BgGtmQ4a5fxFiyNTZcFJMbjg6NHvOWCPgEx6cxD2FSQ+9THwGg6jUakODpWBQUAGafL3YJYC5pHvMPkYh3U52YQtQ0WUjMJQ9t3GNkQQF0O4rqxiH1HbLbv8CDAm+jad9DVRMdt49oID8Hu9kacMKljQYalrmFHRAw7wKPOrvqGu5dsGc3ALIYzoOBlbJNhhweGHXh161jCl5DhhzH4uNvfuy0EH0eDhRGE4DO0wYJHDBacaByLfB6G4ONdgwUPQ/DxLSH4OBosdggnR7sFhZMLxx2d4lYrUe9OseaolSc3txLMvtdx/kZwax30NgTENy78CwjJDP+OD/hs/iTG8tf3dM+CLoLR/psq1SSLIzR6NsQgvMkjaZvBCHZwLEpcyIcwicCnmhj9GgMuov0NrYbQHYZGnvf5PHmGBl8SAKaGur42jsLwTXTX7lp4IaKNtdm6LbR3eLcSSFt7kFdjLGRoRgf4k7ypUQFpgOuuOZoS8Xb4zYENbdMwDLCTYjy+buh5od2kusZnw92jg70DL3PebaCRFbhQN5Z5iIIgWxmhczIc5+iY2BgjW1BgbI67t0jpwKMwCGKQXAfU6IbWFgPo6Wp6luS+gGKBxei0PIAhL6B1HjQMN9/1BwqAudKyQi+RaNucom3jqR/4ZnAKad8nRdqAXReHw+DkGt3X8aErBQZxp2nBoFE+NIORm5oKNOGpO5HsdHB/ImIK0o1RzjkZ6jm0i731OwSg7oM+/aDX6d0UpZtFonT/keCJrpiEjrt4xRAmGP7ft/C6DcddGUVovGxO3hgW7qho0MC75zYK1mjLd2d02H39Ku4jIHh4cN6jL9wkdSADjiYJ8AGHU47urRgOt2Js2IfCQAap4MINSGUSy1NbAhkko3tMAvcYaHznpukmz6ZABpa2NhFGWzqqC5IGSfE89PAhgQGN9hZsPn/wnCD9Z3i+EHj+J8HNLnu21/D8Rl2BmwuYnk4in7pKGqd8QZ9vEYp3WuqlbTzXmUD/AKZd7kMSRi1TkwSHb3GgKGvoqPhIapIF+xTsWiEFSbOFtNMbWGUNjE6hPxyC9lgz2Azh6wsCv74g6OsLBl6YMfRHE3w0yQrUBOHyqAF3Aoh16qYch1x3dNRavYEyuh+yKYZtL7AttxlMwNw2AecrglQieSmB9kcSZskfKBRoEzsJtImuQOuCxyBNy6JbNEG/Bt4N2UGgNQKB1oZN1ECBFnVNF5UYkGsdEpP7QQjDyIR91M0gsEPV13SvIMXXDRVfu4Rex5IO+O/eKkwGBb8iBKfvD8moUsno7g6HH652gakdvi4Ex+OC1BGtnKToCFqlmg/dVTL6dc7GnItfe0LclwbepgzfxDU2qWH/4u//5bb3+Qt6/yNvu+T7lxj5ljd/R3BjfbN2xAJHW0QuZIUp3D7BRiFoT0Q9Xx8/G1rP19swWF18bS8BQwxIxhzNIXCbBo0nIjSeINZyZZKgaeBxp66LH57p2vRExKYnigy8MTyUVhQIQ+Ym4woN0AUXM6AY7RAH+y0OafAYcu3u231b59a/eW54zQvn56LdWlzHwKhrAru8X/B4V/FUSTj3QxaDUfRM9WR8Kk3FpmehbQJCzzb9mLqjKS2IJq3u/QO25FvwswDOE6g/f5stQfjxzlH2wHvGX4zBCO/EmKDq22yBrNPRpwYcxcGJ44UH/wvcEAt1/gRgu0WyLXWt7mz6BpzaWN2e2I6tUht4I4K+PKIbWMcG4LNbR9ivvmccNw2OzjEsmJ5KSmPe7cHA5KihG86vCB5bp8DzIpAh4WQCDHt6hdSGCwUCC5ShTNw0zKkBjAiOYjZBV4p5knCFjkk5Sg2b9E0o8OA0wz7Iz+ZnVtXGegvEShVbw3iTujugD1hPCLN7nreuY6jycZVaQ+dX/R69oAMh4BvALmL0Ylw9Eb6Izwx6JvAZtUA1udq9plawLrmb6B3XkKKJ25bzwE8dQYI8zCJ0+dNCed/h3ELLM1yjQbOIEXW+AlgR7gJoLO39oR3cPfrMCspdFnHIqQHwvQDdnYG3IeiuxjG8X8+PMK7sDl6abcFfodwOJgzVj479QbwB7XcN7gceLDeb9800WU+F9kZQTH35rog/2SN3RTzNHr8r4oP2XZ15jHOuOYwzRPc71NPvOs+C+CISYwDgR5OSbrzrSxaPBBEgT3X6BJUuCuEcFsGRMRrw8ejKp8ssQFZoYUVRwqCCWCg14Ied9BV3SmqPb/cNYKZTJl2a2yxNokPBEHP+tDszjOlOl5aik0q4tp6Jn5C22+jOLhHMw1Z3IYnbbge1NfIXEpumRXE5KfYITgujjcRS+qKVJ6jADqcVw6j0elqYpEFw9w34+R9T8s3TigUXZIaY8/XutDBqwvZp2e5VgfxkdWOWUpDI7u3EcCo8mAdeXDDpwgWGRsarKybyEpoHFlhhdBboMJwHJulmmeG+ATBTmdLQ84hpSwUZ7+gaBBeCRKgMbm6aG4Ecv3H2RjLop+jbE3ABVARHAkNSqKGmehrucKG4baDyMixMd9cBsrYdhqjUEtKHIGYAiCmcvj3HJV28IoGXaN+W/JBATtCN5o0KNsikZsmw4W6goksj4PduqhTe6OKh5MilOGTYFAX86Z+Hr72YZMqCHQti0zm/3V202BAR9BYqc9MvgJBixKrxw43Wcfqeo01uQlDRM6lAaB3SxO9wmMFKYRI7QYwzNmGcpY05xHdwpb7wnIRky4RrPi8cQ1oRRCtmSCtCHxoAC8ACruOEI/PtsgBM0i3JhPsG3AdMCqobsV9FaOU850KrORRhBO5iygR+gc09ihf+kSwhbACEzkCU70ePky6w7TTb2WcEw80gAaqt/NLldD3MxrgGHsRWMUHtSABF99NA+sAEDUrIbqQr+t4WKHQ6bADoIM6DXdhbQ2H0dMX0OZ8gR0/4TF9QCkcxTZISBckruAAduL4I3wjzhbJhO/7BKr3NnhpAN/gBtNxxeIgRBbLkPBR1KQLnIWoemUIAaA12i+D9kxlt7occ7W9xbuhAyWSdgXgK7lGSSwEjLLK4Dos+0g/NQyKll95UGBjLpGvpqCANSROihJpqCJYU3xv00UkZWZE6uIbX9TAeox+TljRcm3pK0Z3fWHdfwTCN/tbdRprETb7SRWt7iNFXQE36sGDcPYqf3kMnIvxSHwb3gQ8YduP7BHr5RdAaXOjQqW4LWrsWfvsQjhABqeEwGZHapivSOAxAapuQ2g6ROh4gtU1I/fkumuD4tzEUjFb03MzEJmZih8zE3spM7OfHTOIhM4nvwExsQpc/60LdisbVVtydhDPaHsPkjKNf9Rfuge8Urr8CjBcY6L0IRfDky/eMg6wIp2ESt7i+XSZs0+Z17CCgxHVsEHZSgKclLdxVXP0eoi2kz98zTu+BRmBRM/2Upd1TWu7PajMvdLsedA/xNT57j95T4HynwhzmfKk7L9yb9IkiYJPlHsUvrtKeD9hE8tEwhgqOYpN1UWyC+Fy04XWxyRW4d4F8B5iE3yDB0EBkq8IhACbFCJNiISZZASbFCJM+u31fjWypR54XFsUIi2IhFsW2YlHs+WGRFWKRtQMW6c30LtH9WDVX9WaPBZ9JFzvGyuoFcxmFJOD0vU2uP1+EpoyJ+3/5O/d//ekP/ONX8B4Un/j1D298/5c/+Y4Hn0Q7J5/4xS/9x5/7wP0fftcZcDfgE4/9+w/f/ezHf+MrRZJUbLQUonAT9sG29fFrn3v/s/d96nNPfYtRHxc+/cW7//f7/uLtH12nPv6f7/z2f/3U/f94/6+sUyf/7ZH7H/2zR//qc7+9Hu0Fr8G/moW/n+Kcv1Uan0PAfe5D2tOAARmhV4r9uQ/cwBhj7qsornziHvQjkIlnuTQX/MA7iK6mSgO5E4a7ArMgm9I3LkkYlXGU7LU8bIQhh7VkH99ZspfM+TckoUKga4g+SHj+kDQeutO3J+Td90+GEQCBF93vG5r6MEBO+CRYa5LjLMkXwOrrviHiBSpNL54SEK8FaGMGb8Cmg90KT7CQw4MDj6NsDz/oFqfvY2nl4J/H+k801o9pGSF6goMo5x7akZnQOYbe1I8HxzmcjgCQmZiBDoWsAk5zuswEk4iDfsx9A2kbQSQ94CKhWkEHI86HtspbP7SEtaNglf8hBat3dYdlXHRYxnMOCyBhQsod0xdeaCBw2xLvWlI7CODuQJB9GTSQuzmF2go8DHH1tIRHfhZ4UODz4/pe9CYdSgQrxbUOZXV1KEzikuJKGZtWikd0KO1fchdn2u2MnUArFQQpEBdBZg6fOnTfEATfMugFjclgSMN72lQKG2KIzZywGdzZPXJmC7EZ3TvexSnERRh01UAOuTnyajgI4IThOLBrE7smiOizJvg2Jm0Y5EIRFgf7iL7GhImgmvOBUPmXz638D3aV/z48trmk8j/oa9VdHBJ9oPzbW5X/HZR0qbEloHWOQyLsDByBJglHmN7/+Ro5NAi6RfXed5/Xn9ekizRHmC3ZEZZwD6P9GidGBxB4DsfVA5d4YT46EIxCsIlSiE669IFHXzltwiUaQWMkD2hEmztCGjFw6ISZp55XX2ClspEWyz9kP7dzsa4vzbmgCeFxAJzjiXG4Vrh+I12Y0IZdqAWRHVaaeEbqwhiYNOePDXiR6x5RAavbQXBXD32kzG13FVlgVTHxguzxNF35633Oe4pv1B0EXhva+GvMB/4wwi3SSQFyEaFPNcBeqS3FJhIcBj8LooZo27xzG+fP0Xj0KCLaQbdt3Jq2NKyYcyJyVmkMMborlqIDbgANHQfC2Gx9qGcg0FP6UO+BjfN0+C8FHOq9nBvr5Jot6eQAvpMLjpMm2rB968Y0cqWmxzaHZJhMCx1C0ZqHMxfDmQw+tpCg0CfMpYiDkrlMMxpyPboDXQK1DxQVMckojKBzKxfr5LAIGs/X7kFHtWYRUohu4FaBHJcdAfUHPddIE6I3HrxnHOvDr0DW6twI3oCbjP8oJ9sRI6AdGAG1XxP53H17E2dxXkwbYRTwbvDZWUPxF/NBMt4DyAHgeGYBSs8rmFNArqijhmvWKPk8smkEgttCJY6UA0fyXoc54wHfovrHCKwBl3Fv6yUfT5wbul0BoQToZrq7nDHcr/G60zF9CMbDOyiK94YXl4jVUxRe8JR2RpCw9Hg1x5PGPF24RK8o2ChRHYORHg6wUnMedkizVuKPUpSQd5hNd5E5V4YChb4pEPGAAAcAd4E5g5yv4yq5uEoMV8ml4FvO5UhexNm44jcFNEOe4s7lLKQ5E1Di8/fAd3K5ZAO7TMacK/S3nGFKrU2QpdcHo61Pbmn7MrYFxXsRv50+yILgwiR/E3xNgb1xwOkLFheXtskct8qcXYzcFTuKt4gGd7OANlD+hG4OoAJ+SDz+7vES+YUcEt+ENAsfPRJ59DV6tE9PnBPXMfVaBg1Lw3V6nT07YLarEUsjzm2OE4zlZ3qdJA1/9QBzetEofjfp/u4uZxdTd73nPFNXqo+8h4JF9zD11HvPM5WXmE3pdpTtHnccdiWTEDHxSnwAboocGVyCenhlb5B6GaYAvjcHbRwSrGSwoHiW6WEp232tE2eSubcw+n0xcxwQTPl17L33jWOLdLppowbr3uRYzG0zx2C9jgEn18xxPpDmDB01nHen97UX5moNObdUb5yV7U611WnLakeeT8vp0x3ZWKpTlukay6c7cnlGTi+fXqq3r5Od2YZcaCzJubY8vxvfgBKqCmVsd6u6dKpBDevy837QCj2bWW5JGsTyDDR2qjMrzzOXHjaW6i/otaOt0+1O7ejoWDlTKmaLjexMNTdayJRyhemZ6WotW6iXxkrFeqlRqObLxczRhbnpVrV17mi7Uz/abtWOts+1j3ZmW41qvbKwXKsuHF1arlC+PdJq/9DN15ZbDWofRnx0rtNo/SjNVRcWlmvYXqt6prLWqB1dXK6HDR6tN1baR+sLi1jtSGYkN5LNYu2gDKt2FleOnpk+PbdQPzpbbc/Wlpc6jbMdrLcwN721Snu2mhstRp8ydlS4LMkYW2aMZbfkS1vyL9mSvyGSzzHG9kTyecbYNZH8YcbwtzZ7emletufe0pCLp9sdOd2QS8tLR97SaC2HdXczxvoi775cvxvkJxljeyN5xRjbzxgb4S47wRj7W4sxn9H/tpYNCpcdZIyNMcZ6Ivlx3UeQh3cSkfxrGGO7IvnXbsm/bkv+9Vvyt23Jv2FL/o1b8m/akq9pmBiMMZMx9l3DZYIxZjPG/oG7OLfbdR0WKbtDr4vqdBqLK51GXXaW5dzSXGeuugCLQIRxBAlFnpmdW2jIuQ7Q/XRjbumUrLeWV1Yadcb+kLnszYyxecZYGi2GjKX0WIIxWbpvW+djjLG4zic0fF39zNHvp/U6LL7p9//s8C1/VTur/vVrVwfe88W9n3zjqwuvjn/zlWcfetvPPvWp71Xb7UarM7e8JGeqcwuN+nVyBZHopS8hZLpWLs4tVZbXGq3ZRrXO2B9wF/Hv103q+2LvX999v3p2h/d/y6QxRufVu2X+MKdadaVam+uck9DEzMLyGcbYW7nLboE11LDJZDLZTC6TzxQyo5lippQpZ8aymWw2m8vms4XsaLaYLWXL2bFcJpfN5XL5XCE3mivmSrlybiyfyWfzuXw+X8iP5ov5Ur6cHytkCtlCrpAvFAqjhWKhVCgXxkYzo9nR3Gh+tDA6OlocLY2WR8eKmWK2mCvmi4XiaLFYLBXLxbFSppQt5Ur5UqE0WiqWSqVyaaycKWfLuXK+XCiPlovlUrlcHhvLjGXHcmP5scLY6FhxrDRWHhurEi4BJtXn1ubqDTl9TiIF3/NSc6m62GApp11rnVvpjJypthf5916yyhqVW0+s3dzOVE50bmg3V8+WXzV17rbpW8Yq5YChhYmpE52J0Ur5ZTp7onOiM1Gq5Nvn2sVXnmt3GouTRXpyQ7sxfVOt2lh+ZevsuUq+3alzr3LbicLsYrVWmJ2vz2RLs4VSdaw8mi3ni/XRTGmsNikiVRartWxptj42k2vkszOFmUYu3xhrTBpXV247UaQZlKcXlmvzN8+dzZZmp3P50UY1M5Ofbow2cjOjk+aLuhX1T7Y0m58ey401GvncTHGsMZqpTlpXBl0WV6bn6zM5GFe2PpOdGcsXx2amZ6rlmcnYtnHVCvXcTBaXp1EbK0xPxq+r3HYim41w+mz+BkocW24tVjvFxtmV5RaMoVitleozjez06ExxupQtTdrX4FBxB8hm6svH5paQAWRLs2Mz9VIjX83kcvXCTLWRn0yMwmIB3G9on3rdjSujJ1fP5k6cqxRgC8y3O63R2vLppU62UF+uYKpSm6222snDkS5y9eUbAB4TAL5sabY0WmuUZjLVXLVeLTRy5UnnWujkhvbMK2bOFSensqNzr6iUKpUKbqfZPKTqC5VWA1c6Vftxok9hptVobEee9HOCd25Rg7ecq9bL1dFSY7qYq9dGi5M9L6UBVis7A21msYPDGqOWOo1WNrdSrVfmljqNU63qQu8HOHaerQy94lVDtMGPrDVqIyOvadSg6FVDN75qaOh0LjNUbdNPpE57pVGrzLSWFysglVSWGu1Ooz4y8sqVRu1Ya3lxqtNoncAyaumGoSls7cZXDY2Fb2VLs1Wgk3KhVp3Oz5Sz2cak+9MwqkyhMFYvlwqlUma0VCxmJnddSeh5ETiPnmnNdRq7fxqGXp4ujRZymfxMNTtdzFULk3sWf5x4nM3X59ory+1GBaWq7Rjdd+rH2V15sbFYXZg7tbS9o70LP9Z55U4vLcwtzV9sWvveAqwS13t5pV2eOb1Ug519dG5xZaFdLOOKQxqXFaqNjCyvtEdGgoojI8eWoI4KUWBmuYW/Q7ceGzoGpYVadWEhW5rNFadz1WpxpjZdaBTz1ZnJy/7X9Z0pjxVy06X6zEx1NJ+pFSf7Wz9WKJfnlkAmqixUW6cuikL7fxroqJorF0dHs9P1Wj6fLeZnJg+MXWKHGkUFbRRVzmwRMxXMVED0G7iPJlTACdEKoS43Uq1XVzqNFizWArBoWKuFaufm6grUnBq6YejVQzfQEkXnG22i06rOddqUq3aWWyMjUzqFC7sEe0ppNjvWKOWKtfxYfnQmX8tnJgd/Bqbzuna5AlO6KGfLL51ezM8triye6EyMVUovm2uvLFTPwZODb6YGbkPMGK2drGVyJ29+3cJyZRSXo9jutOaWTiFOFF+p0xfnoK8FDprNICPFvf3ynzzQqmPZejVfGyvO1Eu5anFm8op34JgyuUuMabG6MjISGcuPMo6Z5YU6SHeNXH50rFSuZ0Dyrs1MyjRJXVpU8JJd4cpPgBQ6ca7TaF95F6clqtDWtZXOxlaqS3O1+bmlU9k8Jiuz1aX6QqN1ojNRqGS13EBLd0v13MJytX4x9MfXs7lb4EdXLXeq843K9PLZoTTJMHqwL4qR1HrVcFd0GwvHnC3NjubGSvV8KVceLWSmZ7LZyatvq9x2gpCqpA0Xucyt1TOvadSmlpYarZC3lVqNdqO11shl68sVna5Ul+p6XsBbc41crjBTHctla9lcPjc5/ONre3S0nGuURuuF6VqpWh0tTx7aNMNqezaYYaNRmC5k641SuTpdbdSqk9dcGxFjsySwB5ULhbFarTzTyJXy9eJ0ozB5rROpcDgfCCaXWF1ansqZuc5sZXZ5ef7IpoEtVmtBX9VMpjBTy2Yz06Oj1Vo+NzmSCB8fHYmMsXCq0Zkijf6mansWhpnPlWcyxenRwnQ9O1NoTGbiWhzPTj9PEGezM3NLc+3ZyqnW8hlQkkr5fLlampnJZcqjmUxucmRhYW1xJFvKF0qZTD47NprLj2UyhWwpk6v+kH1kGvV6uVTK1XO56ZlaMR/0USyWstmxTD5XLoyW8oVseTT/WtoJCxfhd0G/yPCy2Ujnm/rcvlCFRIgbo0CxUyfWoJ/cc/QDu215c0crk8+BpbglVwMGfePC8nR1YXL7iIo/PqIYK+XqY2OlmbFGZrQ0Vs1Mlno2o075ph8LWCdKlbGbfkgcqBUyY9X8WK1WrJZquVp98rr1nyDfzJ9qdF5cqtx64oYX2HtmYrRy/Q3bYJAdIxgE4g6CoAxTrywvNZAbTRcy5cZYeXq6lm80GpMvUbQk+eeBgaOVIjU/Oxm2OVGqvHQygODFpEYQCxFeWjY80ZkoVsYUPILtj3r6meelsNdJYR9/WTj3PMy7uHXS2dHqykpjqV5pLDQWG0sd5K5gmahNj41WG9lMplafVG/88Ys1YyTVtDutidJF9cjIupZpXem0pFKbbdTmb4gtNhZriysvy1wKImUNERxpBcxjjfrkdV259RK4lKU+0bZSnluqtRrVduPY27UEkf0hSGG0ki2+slPtzNVe2Wn9CPLDjUeeB8woVZlZ7Ny0q1I5Mz23VD/VWKpoxeQszSLzw83iBRA0rHlUSH75CRz7c1ppShe10mRzhDwrrcbM3NnjuyOT04aqV4xcCiVK8FOZbpyaW6qcXjozt1S/+eqLiQyE4WV8Y3l58UQmqLidDEanl8826tnC9PLZylLjTOX0Ehj4T56h7WvHma50Wtk8GPcrc0uVlYVqDbajF7YeOy7F5MTNlVuOXJxhbpkVsMmffcsLGucU7puFSnF5BfTp4kn8mSIc2A4b4D55ZImTO2yut868UBhdig9pFrRDP688dCm0yEY5xausmcXq2ZlXX2K9w90kR0tRabRay63XHHoepEnE/dreCOaCMfR1aCh63c5wqC6de34TP9GZyFXyaulcqXNupVGZq28HxOtvuHRHtzaeRyO3XfdCja1dpv+Gw5daiYJOBeT8xrf+xFguiB5vOk3d5/5pu9/KKN/8/GCkt/rK1ZeqncGqOLjbZ6Hi658vLj2/fXxmcQe4VEefB/GUgiO7SnBkN338ElalnbbnpXYnl49kKvW5tcr0OdrsJ8E6kYMDsIomtXwmQna15ZVzlc5yBbC8Xqm2WtVzIKI3CtnaaLmRzVaruUajODlzzaWgmwvWAqd2KnupumOb6mqBZLZ8iVdyxc2vIJsJUGxu4ycnjhSrbaDlZuESeyL+zZY1h4xMYKGuef5OgwZJeGy6WpvvtKq1RhcAIKm2Z5dbnUr4kLa4iWJl6572lsmJYmVx6cfcy8VVDexvqXxpQSJXqDdmqqcXtqzl8vL8MjKZ1089DwEgS6ILFpT0WtzamHzhKzcxVll9buEZQIJeR9RjS13ijfxo8MbSsp5ie3ZusTLXrpxeaneq0wuNylqu/TypaXq51emobQQMKxUS8PJ0s1HrVFBAaDXgrLlWqjcy2XwmX8rUx/L5wiR7Q2KltVw/XWu02sJeqC6dOl091eDmrafbHZZaaS3XGu12o35k+pywcAgHsyNjoyMZORy6E8lcJlc8kikcyRYOxc5UF1qn27HMSC4zkv//APSlKno=');
@@ -455,6 +455,11 @@ export class BlockCipher extends Cipher {
       padding
     } = this.cfg;
 
+    // Ciphertext stealing keeps the message length, so there is nothing to pad
+    if (this.cfg.mode._ciphertextStealing) {
+      return this._processCts();
+    }
+
     // Finalize
     if (this._xformMode === this.constructor._ENC_XFORM_MODE) {
       // Pad data
@@ -472,6 +477,42 @@ export class BlockCipher extends Cipher {
 
     return finalProcessedBlocks;
   }
+
+  /**
+   * Processes everything left in the buffer with a ciphertext stealing mode.
+   *
+   * Ciphers that support these modes implement _doEncryptCts and _doDecryptCts, which run
+   * their wasm implementation in place. Both receive the chaining value, the data words and
+   * the number of significant bytes, and return false when the data is shorter than one block.
+   *
+   * @return {WordArray} The processed data.
+   */
+  _processCts() {
+    // Shortcuts
+    const data = this._data;
+    const mode = this.cfg.mode;
+    const iv = this.modeProcessBlock != undefined ? this.modeProcessBlock : this.cfg.iv && this.cfg.iv.words;
+    if (!iv) {
+      throw new Error(`${mode._name} requires an IV`);
+    }
+    const crypt = this._xformMode === this.constructor._ENC_XFORM_MODE ? this._doEncryptCts : this._doDecryptCts;
+    if (!crypt) {
+      throw new Error(`${mode._name} is not supported by this cipher`);
+    }
+
+    const dataArray = new Uint32Array(Math.ceil(data.sigBytes / 4));
+    dataArray.set(data.words.slice(0, dataArray.length));
+    if (!crypt.call(this, iv, dataArray, data.sigBytes)) {
+      throw new Error(`${mode._name} requires at least one block of data`);
+    }
+    const processed = new WordArray(Array.from(dataArray), data.sigBytes);
+
+    // Everything buffered has been consumed
+    data.words = [];
+    data.sigBytes = 0;
+
+    return processed;
+  }
 }
 
 /**
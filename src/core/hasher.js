@@ -1,5 +1,6 @@
-import { BufferedBlockAlgorithm, Base } from './core';
+import { BufferedBlockAlgorithm, Base, WordArray } from './core';
 import { HMAC } from '../algo/hmac/hmac';
+import { wordArrayToBytes } from '../utils';
 
 
 /**
@@ -116,6 +117,9 @@ export class Hasher extends BufferedBlockAlgorithm {
      * Finalizes the hash computation.
      * Note that the finalize operation is effectively a destructive, read-once operation.
      *
+     * A hasher implementing _hashBytes(bytes) hashes a message that is still entirely buffered
+     * in a single wasm call, padding included.
+     *
      * @param {WordArray|string} messageUpdate (Optional) A final message update.
      *
      * @return {WordArray} The hash.
@@ -132,9 +136,93 @@ export class Hasher extends BufferedBlockAlgorithm {
       this._append(messageUpdate);
     }
 
+    // Nothing has been compressed yet, so the whole message can be hashed in one call
+    if (this._hashBytes && this._nDataBytes === this._data.sigBytes) {
+      return this._hashBytes(wordArrayToBytes(this._data));
+    }
+
     // Perform concrete-hasher logic
     const hash = this._doFinalize();
 
     return hash;
   }
+
+  /**
+     * Exports the context of the hash in progress: the algorithm id, the configuration,
+     * the intermediate state, the buffered bytes and the total length, in the versioned format of rust/hashcontext.
+     *
+     * Concrete hashers provide a static algorithmId and implement _contextWasm(),
+     * _getContextState() and _setContextState(state). Hashers whose state depends on
+     * their configuration also override _getContextParameters().
+     *
+     * @return {WordArray} The serialized context.
+     *
+     * @example
+     *
+     *     const context = hasher.update('first part').exportState();
+     */
+  exportState() {
+    // Shortcuts
+    const data = this._data;
+    const nBytesTotal = this._nDataBytes;
+
+    data.clamp();
+    const context = this._contextWasm().exportContext(
+      this.constructor.algorithmId,
+      this._getContextParameters(),
+      this._getContextState(),
+      data.words,
+      data.sigBytes,
+      Math.floor(nBytesTotal / 0x100000000),
+      nBytesTotal >>> 0
+    );
+    if (!context.length) {
+      throw new Error('The state of this hasher cannot be exported');
+    }
+
+    return new WordArray(Array.from(context), context.length * 4);
+  }
+
+  /**
+     * Resumes a hash from a context returned by exportState.
+     * The hasher must be of the same algorithm, created with the same configuration.
+     *
+     * @param {WordArray} context The serialized context.
+     *
+     * @return {Hasher} This hasher.
+     *
+     * @example
+     *
+     *     const hash = new CryptoJSW.algo.SHA256().importState(context).finalize('second part');
+     */
+  importState(context) {
+    const fields = context.sigBytes % 4 ? [] : this._contextWasm().importContext(
+      this.constructor.algorithmId,
+      this._getContextParameters(),
+      context.words.slice(0, context.sigBytes / 4)
+    );
+    if (!fields.length) {
+      throw new Error('The context is malformed or was not exported by this algorithm');
+    }
+
+    // Total length, state size and buffer size, then the state and the buffer
+    const nStateWords = fields[2];
+    const bufferSigBytes = fields[3];
+    this.reset();
+    this._setContextState(fields.slice(4, 4 + nStateWords));
+    this._data = new WordArray(Array.from(fields.slice(4 + nStateWords)), bufferSigBytes);
+    this._nDataBytes = fields[0] * 0x100000000 + fields[1];
+
+    // Chainable
+    return this;
+  }
+
+  /**
+     * Returns the configuration a context is only valid for, stored on export and compared on import.
+     *
+     * @return {Array} The configuration words.
+     */
+  _getContextParameters() {
+    return [];
+  }
 }
@@ -1,6 +1,10 @@
 import { aesWasm } from './aes_bg';
 import { WordArray } from '../core/core';
 import { BlockCipher } from '../core/cipher-core.js';
+import { Utf8 } from '../encoding/enc-utf8';
+import { GCM } from '../mode/mode-gcm.js';
+import { CCM } from '../mode/mode-ccm.js';
+import { CTR } from '../mode/mode-ctr.js';
 import { loadWasm } from '../utils/wasm-utils';
 import { wasmBytes } from './aes_wasm';
 
@@ -51,11 +55,263 @@ export class AESAlgo extends BlockCipher {
     this._invKeySchedule = aesWasm(AESAlgo.wasm).getInvKeySchedule(keySize, keyWords);
   }
 
+  /**
+   * Deterministic authenticated encryption with AES-SIV (RFC 5297).
+   *
+   * @param {WordArray} key The double-length key: 256, 384 or 512 bits.
+   * @param {WordArray|string} message The message to encrypt.
+   * @param {Array} aad (Optional) The associated data components, WordArrays or strings. A nonce is simply one of them.
+   *
+   * @return {WordArray} The synthetic IV followed by the ciphertext.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const ciphertext = CryptoJSW.algo.AES.sivEncrypt(key, message, [header, nonce]);
+   */
+  static sivEncrypt(key, message, aad = []) {
+    const { nRounds, macKeySchedule, ctrKeySchedule } = getSivKeySchedules(key);
+    const { aadWords, aadSigBytes } = flattenAad(aad);
+    const data = toWordArray(message);
+    const dataArray = toWordsArray(data);
+
+    const v = aesWasm(AESAlgo.wasm).sivEncrypt(nRounds, macKeySchedule, ctrKeySchedule, aadWords, aadSigBytes, dataArray, data.sigBytes);
+    if (!v.length) {
+      throw new Error('AES-SIV supports at most 126 associated data components');
+    }
+
+    return new WordArray(Array.from(v), 16).concat(new WordArray(Array.from(dataArray), data.sigBytes));
+  }
+
+  /**
+   * AES-SIV decryption (RFC 5297).
+   *
+   * @param {WordArray} key The double-length key: 256, 384 or 512 bits.
+   * @param {WordArray} ciphertext The synthetic IV followed by the ciphertext.
+   * @param {Array} aad (Optional) The associated data components used for encryption.
+   *
+   * @return {WordArray} The plaintext. Throws when the ciphertext or associated data is not authentic.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const plaintext = CryptoJSW.algo.AES.sivDecrypt(key, ciphertext, [header, nonce]);
+   */
+  static sivDecrypt(key, ciphertext, aad = []) {
+    const { nRounds, macKeySchedule, ctrKeySchedule } = getSivKeySchedules(key);
+    const { aadWords, aadSigBytes } = flattenAad(aad);
+    if (ciphertext.sigBytes < 16) {
+      throw new Error('AES-SIV ciphertext is shorter than its synthetic IV');
+    }
+    const v = sliceBytes(ciphertext, 0, 16);
+    const data = sliceBytes(ciphertext, 16, ciphertext.sigBytes);
+    const dataArray = toWordsArray(data);
+
+    if (!aesWasm(AESAlgo.wasm).sivDecrypt(nRounds, macKeySchedule, ctrKeySchedule, aadWords, aadSigBytes, dataArray, data.sigBytes, v.words)) {
+      throw new Error('AES-SIV authentication failed');
+    }
+
+    return new WordArray(Array.from(dataArray), data.sigBytes);
+  }
+
+  /**
+   * Nonce misuse-resistant authenticated encryption with AES-GCM-SIV (RFC 8452).
+   *
+   * @param {WordArray} key The 128 or 256 bit key.
+   * @param {WordArray} nonce The 96-bit nonce.
+   * @param {WordArray|string} message The message to encrypt.
+   * @param {WordArray|string} aad (Optional) The additional authenticated data.
+   *
+   * @return {WordArray} The ciphertext followed by the 128-bit tag.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const ciphertext = CryptoJSW.algo.AES.gcmSivEncrypt(key, nonce, message, aad);
+   */
+  static gcmSivEncrypt(key, nonce, message, aad = '') {
+    checkGcmSivParams(key, nonce);
+    const aadData = toWordArray(aad);
+    const data = toWordArray(message);
+    const dataArray = toWordsArray(data);
+
+    const tag = aesWasm(AESAlgo.wasm).gcmSivEncrypt(key.sigBytes / 4, key.words, nonce.words, nonce.sigBytes, aadData.words, aadData.sigBytes, dataArray, data.sigBytes);
+
+    return new WordArray(Array.from(dataArray), data.sigBytes).concat(new WordArray(Array.from(tag), 16));
+  }
+
+  /**
+   * AES-GCM-SIV decryption (RFC 8452).
+   *
+   * @param {WordArray} key The 128 or 256 bit key.
+   * @param {WordArray} nonce The 96-bit nonce.
+   * @param {WordArray} ciphertext The ciphertext followed by the 128-bit tag.
+   * @param {WordArray|string} aad (Optional) The additional authenticated data.
+   *
+   * @return {WordArray} The plaintext. Throws when the ciphertext or associated data is not authentic.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const plaintext = CryptoJSW.algo.AES.gcmSivDecrypt(key, nonce, ciphertext, aad);
+   */
+  static gcmSivDecrypt(key, nonce, ciphertext, aad = '') {
+    checkGcmSivParams(key, nonce);
+    if (ciphertext.sigBytes < 16) {
+      throw new Error('AES-GCM-SIV ciphertext is shorter than its tag');
+    }
+    const aadData = toWordArray(aad);
+    const data = sliceBytes(ciphertext, 0, ciphertext.sigBytes - 16);
+    const tag = sliceBytes(ciphertext, ciphertext.sigBytes - 16, ciphertext.sigBytes);
+    const dataArray = toWordsArray(data);
+
+    if (!aesWasm(AESAlgo.wasm).gcmSivDecrypt(key.sigBytes / 4, key.words, nonce.words, nonce.sigBytes, aadData.words, aadData.sigBytes, dataArray, data.sigBytes, tag.words)) {
+      throw new Error('AES-GCM-SIV authentication failed');
+    }
+
+    return new WordArray(Array.from(dataArray), data.sigBytes);
+  }
+
+  /**
+   * XTS-AES encryption of one data unit, such as a storage sector (IEEE 1619).
+   *
+   * @param {WordArray} key The double-length key: 256 or 512 bits. Its two halves must differ.
+   * @param {number} sectorNumber The data unit sequence number, used as the tweak.
+   * @param {WordArray} data The data unit, at least 16 bytes. It does not need to be a multiple of the block size.
+   *
+   * @return {WordArray} The ciphertext, with the same length as the data unit.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const ciphertext = CryptoJSW.algo.AES.xtsEncrypt(key, 42, sector);
+   */
+  static xtsEncrypt(key, sectorNumber, data) {
+    return xtsCrypt('xtsEncrypt', key, sectorNumber, data);
+  }
+
+  /**
+   * XTS-AES decryption of one data unit (IEEE 1619).
+   *
+   * @param {WordArray} key The double-length key: 256 or 512 bits.
+   * @param {number} sectorNumber The data unit sequence number used for encryption.
+   * @param {WordArray} data The encrypted data unit.
+   *
+   * @return {WordArray} The plaintext.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const sector = CryptoJSW.algo.AES.xtsDecrypt(key, 42, ciphertext);
+   */
+  static xtsDecrypt(key, sectorNumber, data) {
+    return xtsCrypt('xtsDecrypt', key, sectorNumber, data);
+  }
+
+  /**
+   * AES Key Wrap (RFC 3394), or Key Wrap with Padding (RFC 5649).
+   *
+   * @param {WordArray} kek The 128, 192 or 256 bit key-encryption key.
+   * @param {WordArray} key The key to wrap. Without padding it must be a multiple of 64 bits and at least 128 bits long.
+   * @param {Object} cfg (Optional) The configuration options to use. Set `padding` to true for RFC 5649, which accepts keys of any length.
+   *
+   * @return {WordArray} The wrapped key.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const wrapped = CryptoJSW.algo.AES.wrapKey(kek, dataKey);
+   *     const wrapped = CryptoJSW.algo.AES.wrapKey(kek, dataKey, { padding: true });
+   */
+  static wrapKey(kek, key, cfg = {}) {
+    const keySize = checkKekSize(kek);
+    const wrapped = aesWasm(AESAlgo.wasm).wrapKey(keySize + 6, aesWasm(AESAlgo.wasm).getKeySchedule(keySize, kek.words), key.words, key.sigBytes, !!cfg.padding);
+    if (!wrapped.length) {
+      throw new Error(cfg.padding ? 'AES Key Wrap with Padding requires a non-empty key' : 'AES Key Wrap requires a key of at least 128 bits and a multiple of 64 bits');
+    }
+
+    return new WordArray(Array.from(wrapped), wrapped.length * 4);
+  }
+
+  /**
+   * AES Key Unwrap (RFC 3394), or Key Unwrap with Padding (RFC 5649).
+   *
+   * @param {WordArray} kek The 128, 192 or 256 bit key-encryption key.
+   * @param {WordArray} wrapped The wrapped key.
+   * @param {Object} cfg (Optional) The configuration options to use. Set `padding` to true for RFC 5649.
+   *
+   * @return {WordArray} The unwrapped key. Throws when the integrity check fails.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const dataKey = CryptoJSW.algo.AES.unwrapKey(kek, wrapped);
+   */
+  static unwrapKey(kek, wrapped, cfg = {}) {
+    const keySize = checkKekSize(kek);
+    const result = aesWasm(AESAlgo.wasm).unwrapKey(keySize + 6, aesWasm(AESAlgo.wasm).getInvKeySchedule(keySize, kek.words), wrapped.words, wrapped.sigBytes, !!cfg.padding);
+    if (!result.length) {
+      throw new Error('AES Key Unwrap integrity check failed');
+    }
+
+    return new WordArray(Array.from(result.slice(1)), result[0]);
+  }
+
+  /**
+   * Encrypts or decrypts a slice of an AES-CTR stream without processing what comes before it,
+   * for example to serve a range request on encrypted media.
+   *
+   * @param {WordArray} key The 128, 192 or 256 bit key.
+   * @param {WordArray} iv The initial counter block of the stream.
+   * @param {number} byteOffset The position of the slice in the stream, in bytes. It does not need to be block aligned.
+   * @param {WordArray} data The slice to encrypt or decrypt.
+   * @param {Object} cfg (Optional) The configuration options to use. `mode` may be a CTR mode with a narrower counter, see CTR.withCounterWidth.
+   *
+   * @return {WordArray} The processed slice.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const slice = CryptoJSW.algo.AES.cryptCtrAt(key, iv, 1048576, encryptedSlice);
+   */
+  static cryptCtrAt(key, iv, byteOffset, data, cfg = {}) {
+    const keySize = key.sigBytes / 4;
+    if (keySize !== 4 && keySize !== 6 && keySize !== 8) {
+      throw new Error('AES requires a 128, 192 or 256 bit key');
+    }
+    if (!Number.isSafeInteger(byteOffset) || byteOffset < 0) {
+      throw new Error('CTR byte offset must be a non-negative integer');
+    }
+    const mode = cfg.mode || CTR;
+
+    const wasm = aesWasm(AESAlgo.wasm);
+    const keySchedule = wasm.getKeySchedule(keySize, key.words);
+    const offsetWords = [Math.floor(byteOffset / 0x100000000), byteOffset >>> 0];
+    const dataArray = toWordsArray(data);
+    if (!wasm.doCryptCtrAt(mode._name, keySize + 6, iv.words, offsetWords, dataArray, data.sigBytes, keySchedule)) {
+      throw new Error(`${mode._name} is not a CTR mode, or its IV is shorter than one block`);
+    }
+
+    return new WordArray(Array.from(dataArray), data.sigBytes);
+  }
+
   // eslint-disable-next-line no-dupe-class-members
   _process(doFlush) {
     if (!AESAlgo.wasm) {
       throw new Error('WASM is not loaded yet. \'AESAlgo.loadWasm\' should be called first');
     }
+    if (AEAD_MODES.has(this.cfg.mode)) {
+      return this._processAead(doFlush);
+    }
     let processedWords;
 
     // Shortcuts
@@ -72,8 +328,10 @@ export class AESAlgo extends BlockCipher {
       nBlocksReady = Math.ceil(nBlocksReady);
     } else {
       // Round down to include only full blocks,
-      // less the number of blocks that must remain in the buffer
-      nBlocksReady = Math.max((nBlocksReady | 0) - this._minBufferSize, 0);
+      // less the number of blocks that must remain in the buffer.
+      // Ciphertext stealing needs the last two blocks when finalizing.
+      const minBufferSize = this.cfg.mode._ciphertextStealing ? 2 : this._minBufferSize;
+      nBlocksReady = Math.max((nBlocksReady | 0) - minBufferSize, 0);
     }
 
     // Count words ready
@@ -94,17 +352,20 @@ export class AESAlgo extends BlockCipher {
       // Perform concrete-algorithm logic
       if (this._xformMode == this._ENC_XFORM_MODE) {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doEncrypt(this.cfg.mode._name, this._nRounds, nWordsReady, blockSize, this.modeProcessBlock, dataArray, this._keySchedule);
+          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doEncrypt(this.cfg.mode._name, this._nRounds, nWordsReady, this.modeProcessBlock, dataArray, this._keySchedule);
         } else {
-          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doEncrypt(this.cfg.mode._name, this._nRounds, nWordsReady, blockSize, ivWords, dataArray, this._keySchedule);
+          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doEncrypt(this.cfg.mode._name, this._nRounds, nWordsReady, ivWords, dataArray, this._keySchedule);
         }
       } else /* if (this._xformMode == this._DEC_XFORM_MODE) */ {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doDecrypt(this.cfg.mode._name, this._nRounds, nWordsReady, blockSize, this.modeProcessBlock, dataArray, this._keySchedule, this._invKeySchedule);
+          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doDecrypt(this.cfg.mode._name, this._nRounds, nWordsReady, this.modeProcessBlock, dataArray, this._keySchedule, this._invKeySchedule);
         } else {
-          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doDecrypt(this.cfg.mode._name, this._nRounds, nWordsReady, blockSize, ivWords, dataArray, this._keySchedule, this._invKeySchedule);
+          this.modeProcessBlock = aesWasm(AESAlgo.wasm).doDecrypt(this.cfg.mode._name, this._nRounds, nWordsReady, ivWords, dataArray, this._keySchedule, this._invKeySchedule);
         }
       }
+      if (this.modeProcessBlock === undefined) {
+        throw new Error(`${this.cfg.mode._name} is not supported by this cipher, or its IV is shorter than one block`);
+      }
       dataWords = Array.from(dataArray);
       // Remove processed words
       processedWords = dataWords.splice(0, nWordsReady);
@@ -115,6 +376,192 @@ export class AESAlgo extends BlockCipher {
     // Return processed words
     return new WordArray(processedWords, nBytesReady);
   }
+
+  _doEncryptCts(iv, dataArray, dataSigBytes) {
+    return aesWasm(AESAlgo.wasm).doEncryptCts(this.cfg.mode._name, this._nRounds, iv, dataArray, dataSigBytes, this._keySchedule);
+  }
+
+  _doDecryptCts(iv, dataArray, dataSigBytes) {
+    return aesWasm(AESAlgo.wasm).doDecryptCts(this.cfg.mode._name, this._nRounds, iv, dataArray, dataSigBytes, this._keySchedule, this._invKeySchedule);
+  }
+
+  _doFinalize() {
+    // Authenticated modes are not padded, the tag takes care of the message length
+    if (AEAD_MODES.has(this.cfg.mode)) {
+      return this._process(!!'flush');
+    }
+
+    return super._doFinalize();
+  }
+
+  _processAead(doFlush) {
+    // The tag covers the whole message, so keep everything buffered until finalize
+    if (!doFlush) {
+      return new WordArray([], 0);
+    }
+
+    // Shortcuts
+    const data = this._data;
+    const mode = this.cfg.mode;
+    const { validTagLengths, validIvSize, encrypt, decrypt } = AEAD_MODES.get(mode);
+    const iv = this.cfg.iv;
+    const aad = toWordArray(this.cfg.aad || new WordArray([], 0));
+    const tagLength = this.cfg.tagLength || 128;
+    const tagSigBytes = tagLength / 8;
+
+    if (!iv || !validIvSize(iv.sigBytes)) {
+      throw new Error(`Invalid ${mode._name} IV size: ${iv ? iv.sigBytes : 0} bytes`);
+    }
+    if (!validTagLengths.includes(tagLength)) {
+      throw new Error(`Invalid ${mode._name} tag length: ${tagLength}`);
+    }
+
+    let dataSigBytes = data.sigBytes;
+    let tag;
+    if (this._xformMode == this._DEC_XFORM_MODE) {
+      if (dataSigBytes < tagSigBytes) {
+        throw new Error(`${mode._name} ciphertext is shorter than its tag`);
+      }
+      dataSigBytes -= tagSigBytes;
+      tag = sliceBytes(data, dataSigBytes, data.sigBytes);
+    }
+
+    const dataArray = toWordsArray(new WordArray(data.words, dataSigBytes));
+
+    let processed;
+    const wasm = aesWasm(AESAlgo.wasm);
+    if (this._xformMode == this._ENC_XFORM_MODE) {
+      const tagWords = wasm[encrypt](this._nRounds, this._keySchedule, iv.words, iv.sigBytes, aad.words, aad.sigBytes, dataArray, dataSigBytes, tagSigBytes);
+      processed = new WordArray(Array.from(dataArray), dataSigBytes).concat(new WordArray(Array.from(tagWords), tagSigBytes));
+    } else /* if (this._xformMode == this._DEC_XFORM_MODE) */ {
+      const verified = wasm[decrypt](this._nRounds, this._keySchedule, iv.words, iv.sigBytes, aad.words, aad.sigBytes, dataArray, dataSigBytes, tag.words, tagSigBytes);
+      if (!verified) {
+        throw new Error(`${mode._name} authentication failed`);
+      }
+      processed = new WordArray(Array.from(dataArray), dataSigBytes);
+    }
+
+    // Everything buffered has been consumed
+    data.words = [];
+    data.sigBytes = 0;
+
+    return processed;
+  }
+}
+
+// Authenticated modes, processed as a whole message when finalizing
+const AEAD_MODES = new Map([
+  [GCM, {
+    validTagLengths: [32, 64, 96, 104, 112, 120, 128],
+    validIvSize: (sigBytes) => sigBytes > 0,
+    encrypt: 'gcmEncrypt',
+    decrypt: 'gcmDecrypt'
+  }],
+  [CCM, {
+    validTagLengths: [32, 48, 64, 80, 96, 112, 128],
+    validIvSize: (sigBytes) => sigBytes >= 7 && sigBytes <= 13,
+    encrypt: 'ccmEncrypt',
+    decrypt: 'ccmDecrypt'
+  }]
+]);
+
+function toWordArray(data) {
+  return typeof data === 'string' ? Utf8.parse(data) : data;
+}
+
+// Copy the significant words of a WordArray into a Uint32Array that wasm can write back to
+function toWordsArray(wordArray) {
+  const wordsArray = new Uint32Array(Math.ceil(wordArray.sigBytes / 4));
+  wordsArray.set(wordArray.words.slice(0, wordsArray.length));
+
+  return wordsArray;
+}
+
+// The two halves of an AES-SIV key are used for S2V and CTR respectively
+function getSivKeySchedules(key) {
+  if (![32, 48, 64].includes(key.sigBytes)) {
+    throw new Error('AES-SIV requires a 256, 384 or 512 bit key');
+  }
+  const halfSize = key.sigBytes / 8;
+  const wasm = aesWasm(AESAlgo.wasm);
+
+  return {
+    nRounds: halfSize + 6,
+    macKeySchedule: wasm.getKeySchedule(halfSize, key.words.slice(0, halfSize)),
+    ctrKeySchedule: wasm.getKeySchedule(halfSize, key.words.slice(halfSize, 2 * halfSize))
+  };
+}
+
+// Concatenate the associated data components, each starting on a word boundary
+function flattenAad(aad) {
+  const aadWords = [];
+  const aadSigBytes = [];
+  aad.map(toWordArray).forEach((component) => {
+    const nWords = Math.ceil(component.sigBytes / 4);
+    for (let i = 0; i < nWords; i++) {
+      aadWords.push(component.words[i]);
+    }
+    aadSigBytes.push(component.sigBytes);
+  });
+
+  return { aadWords, aadSigBytes };
+}
+
+function checkGcmSivParams(key, nonce) {
+  if (key.sigBytes !== 16 && key.sigBytes !== 32) {
+    throw new Error('AES-GCM-SIV requires a 128 or 256 bit key');
+  }
+  if (!nonce || nonce.sigBytes !== 12) {
+    throw new Error('AES-GCM-SIV requires a 96-bit nonce');
+  }
+}
+
+// XTS uses the first half of the key for the data and the second half for the tweak
+function xtsCrypt(method, key, sectorNumber, data) {
+  if (key.sigBytes !== 32 && key.sigBytes !== 64) {
+    throw new Error('XTS-AES requires a 256 or 512 bit key');
+  }
+  if (!Number.isSafeInteger(sectorNumber) || sectorNumber < 0) {
+    throw new Error('XTS-AES sector number must be a non-negative integer');
+  }
+  const halfSize = key.sigBytes / 8;
+  const dataKey = key.words.slice(0, halfSize);
+  const tweakKey = key.words.slice(halfSize, 2 * halfSize);
+  if (dataKey.every((word, i) => word === tweakKey[i])) {
+    throw new Error('XTS-AES key halves must differ');
+  }
+
+  const wasm = aesWasm(AESAlgo.wasm);
+  const keySchedule = method === 'xtsEncrypt' ? wasm.getKeySchedule(halfSize, dataKey) : wasm.getInvKeySchedule(halfSize, dataKey);
+  const tweakKeySchedule = wasm.getKeySchedule(halfSize, tweakKey);
+  const sectorWords = [Math.floor(sectorNumber / 0x100000000), sectorNumber >>> 0];
+  const dataArray = toWordsArray(data);
+
+  if (!wasm[method](halfSize + 6, keySchedule, tweakKeySchedule, sectorWords, dataArray, data.sigBytes)) {
+    throw new Error('XTS-AES data unit must be between 16 bytes and 2^20 blocks');
+  }
+
+  return new WordArray(Array.from(dataArray), data.sigBytes);
+}
+
+// Returns the key size in words
+function checkKekSize(kek) {
+  if (![16, 24, 32].includes(kek.sigBytes)) {
+    throw new Error('AES Key Wrap requires a 128, 192 or 256 bit key-encryption key');
+  }
+
+  return kek.sigBytes / 4;
+}
+
+// Copy bytes [start, end) of a WordArray into a new WordArray
+function sliceBytes(wordArray, start, end) {
+  const words = [];
+  for (let i = start; i < end; i++) {
+    const byte = (wordArray.words[i >>> 2] >>> (24 - (i % 4) * 8)) & 0xff;
+    words[(i - start) >>> 2] |= byte << (24 - ((i - start) % 4) * 8);
+  }
+
+  return new WordArray(words, end - start);
 }
 
 /**
@@ -135,13 +135,12 @@ export function aesWasm(wasm) {
   * @param {string} mode
   * @param {number} nRounds
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} keySchedule
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function doEncrypt(mode, nRounds, nWordsReady, blockSize, iv, dataWords, keySchedule) {
+  function doEncrypt(mode, nRounds, nWordsReady, iv, dataWords, keySchedule) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -152,11 +151,14 @@ export function aesWasm(wasm) {
       var len2 = WASM_VECTOR_LEN;
       var ptr3 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
       var len3 = WASM_VECTOR_LEN;
-      wasm.doEncrypt(retptr, ptr0, len0, nRounds, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3);
+      wasm.doEncrypt(retptr, ptr0, len0, nRounds, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
-      var v4 = getArrayU32FromWasm0(r0, r1).slice();
-      wasm.__wbindgen_free(r0, r1 * 4);
+      let v4;
+      if (r0 !== 0) {
+        v4 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
       return v4;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
@@ -169,14 +171,13 @@ export function aesWasm(wasm) {
   * @param {string} mode
   * @param {number} nRounds
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} keySchedule
   * @param {Uint32Array} invKeySchedule
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function doDecrypt(mode, nRounds, nWordsReady, blockSize, iv, dataWords, keySchedule, invKeySchedule) {
+  function doDecrypt(mode, nRounds, nWordsReady, iv, dataWords, keySchedule, invKeySchedule) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -189,7 +190,184 @@ export function aesWasm(wasm) {
       var len3 = WASM_VECTOR_LEN;
       var ptr4 = passArray32ToWasm0(invKeySchedule, wasm.__wbindgen_malloc);
       var len4 = WASM_VECTOR_LEN;
-      wasm.doDecrypt(retptr, ptr0, len0, nRounds, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4);
+      wasm.doDecrypt(retptr, ptr0, len0, nRounds, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      let v5;
+      if (r0 !== 0) {
+        v5 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
+      return v5;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} iv
+  * @param {number} ivSigBytes
+  * @param {Uint32Array} aad
+  * @param {number} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {number} tagSigBytes
+  * @returns {Uint32Array}
+  */
+  function gcmEncrypt(nRounds, keySchedule, iv, ivSigBytes, aad, aadSigBytes, dataWords, dataSigBytes, tagSigBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aad, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      wasm.gcmEncrypt(retptr, nRounds, ptr0, len0, ptr1, len1, ivSigBytes, ptr2, len2, aadSigBytes, ptr3, len3, dataSigBytes, tagSigBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v4 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v4;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} iv
+  * @param {number} ivSigBytes
+  * @param {Uint32Array} aad
+  * @param {number} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} tag
+  * @param {number} tagSigBytes
+  * @returns {boolean}
+  */
+  function gcmDecrypt(nRounds, keySchedule, iv, ivSigBytes, aad, aadSigBytes, dataWords, dataSigBytes, tag, tagSigBytes) {
+    try {
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aad, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(tag, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ret = wasm.gcmDecrypt(nRounds, ptr0, len0, ptr1, len1, ivSigBytes, ptr2, len2, aadSigBytes, ptr3, len3, dataSigBytes, ptr4, len4, tagSigBytes);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} nonce
+  * @param {number} nonceSigBytes
+  * @param {Uint32Array} aad
+  * @param {number} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {number} tagSigBytes
+  * @returns {Uint32Array}
+  */
+  function ccmEncrypt(nRounds, keySchedule, nonce, nonceSigBytes, aad, aadSigBytes, dataWords, dataSigBytes, tagSigBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(nonce, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aad, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      wasm.ccmEncrypt(retptr, nRounds, ptr0, len0, ptr1, len1, nonceSigBytes, ptr2, len2, aadSigBytes, ptr3, len3, dataSigBytes, tagSigBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v4 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v4;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} nonce
+  * @param {number} nonceSigBytes
+  * @param {Uint32Array} aad
+  * @param {number} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} tag
+  * @param {number} tagSigBytes
+  * @returns {boolean}
+  */
+  function ccmDecrypt(nRounds, keySchedule, nonce, nonceSigBytes, aad, aadSigBytes, dataWords, dataSigBytes, tag, tagSigBytes) {
+    try {
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(nonce, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aad, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(tag, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ret = wasm.ccmDecrypt(nRounds, ptr0, len0, ptr1, len1, nonceSigBytes, ptr2, len2, aadSigBytes, ptr3, len3, dataSigBytes, ptr4, len4, tagSigBytes);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} macKeySchedule
+  * @param {Uint32Array} ctrKeySchedule
+  * @param {Uint32Array} aadWords
+  * @param {Uint32Array} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @returns {Uint32Array}
+  */
+  function sivEncrypt(nRounds, macKeySchedule, ctrKeySchedule, aadWords, aadSigBytes, dataWords, dataSigBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(macKeySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(ctrKeySchedule, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aadWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(aadSigBytes, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      wasm.sivEncrypt(retptr, nRounds, ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, dataSigBytes);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
       var v5 = getArrayU32FromWasm0(r0, r1).slice();
@@ -197,16 +375,376 @@ export function aesWasm(wasm) {
       return v5;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
+      dataWords.set(getUint32Memory0().subarray(ptr4 / 4, ptr4 / 4 + len4));
+      wasm.__wbindgen_free(ptr4, len4 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} macKeySchedule
+  * @param {Uint32Array} ctrKeySchedule
+  * @param {Uint32Array} aadWords
+  * @param {Uint32Array} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} v
+  * @returns {boolean}
+  */
+  function sivDecrypt(nRounds, macKeySchedule, ctrKeySchedule, aadWords, aadSigBytes, dataWords, dataSigBytes, v) {
+    try {
+      var ptr0 = passArray32ToWasm0(macKeySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(ctrKeySchedule, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aadWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(aadSigBytes, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ptr5 = passArray32ToWasm0(v, wasm.__wbindgen_malloc);
+      var len5 = WASM_VECTOR_LEN;
+      var ret = wasm.sivDecrypt(nRounds, ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, dataSigBytes, ptr5, len5);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr4 / 4, ptr4 / 4 + len4));
+      wasm.__wbindgen_free(ptr4, len4 * 4);
+    }
+  }
+
+  /**
+  * @param {number} keySize
+  * @param {Uint32Array} keyWords
+  * @param {Uint32Array} nonce
+  * @param {number} nonceSigBytes
+  * @param {Uint32Array} aad
+  * @param {number} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @returns {Uint32Array}
+  */
+  function gcmSivEncrypt(keySize, keyWords, nonce, nonceSigBytes, aad, aadSigBytes, dataWords, dataSigBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(nonce, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aad, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      wasm.gcmSivEncrypt(retptr, keySize, ptr0, len0, ptr1, len1, nonceSigBytes, ptr2, len2, aadSigBytes, ptr3, len3, dataSigBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v4 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v4;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} keySize
+  * @param {Uint32Array} keyWords
+  * @param {Uint32Array} nonce
+  * @param {number} nonceSigBytes
+  * @param {Uint32Array} aad
+  * @param {number} aadSigBytes
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} tag
+  * @returns {boolean}
+  */
+  function gcmSivDecrypt(keySize, keyWords, nonce, nonceSigBytes, aad, aadSigBytes, dataWords, dataSigBytes, tag) {
+    try {
+      var ptr0 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(nonce, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(aad, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(tag, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ret = wasm.gcmSivDecrypt(keySize, ptr0, len0, ptr1, len1, nonceSigBytes, ptr2, len2, aadSigBytes, ptr3, len3, dataSigBytes, ptr4, len4);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} tweakKeySchedule
+  * @param {Uint32Array} sectorNumber
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @returns {boolean}
+  */
+  function xtsEncrypt(nRounds, keySchedule, tweakKeySchedule, sectorNumber, dataWords, dataSigBytes) {
+    try {
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(tweakKeySchedule, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(sectorNumber, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ret = wasm.xtsEncrypt(nRounds, ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3, dataSigBytes);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} invKeySchedule
+  * @param {Uint32Array} tweakKeySchedule
+  * @param {Uint32Array} sectorNumber
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @returns {boolean}
+  */
+  function xtsDecrypt(nRounds, invKeySchedule, tweakKeySchedule, sectorNumber, dataWords, dataSigBytes) {
+    try {
+      var ptr0 = passArray32ToWasm0(invKeySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(tweakKeySchedule, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(sectorNumber, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ret = wasm.xtsDecrypt(nRounds, ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3, dataSigBytes);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} keyWords
+  * @param {number} keySigBytes
+  * @param {boolean} withPadding
+  * @returns {Uint32Array}
+  */
+  function wrapKey(nRounds, keySchedule, keyWords, keySigBytes, withPadding) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.wrapKey(retptr, nRounds, ptr0, len0, ptr1, len1, keySigBytes, withPadding);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} invKeySchedule
+  * @param {Uint32Array} wrappedWords
+  * @param {number} wrappedSigBytes
+  * @param {boolean} withPadding
+  * @returns {Uint32Array}
+  */
+  function unwrapKey(nRounds, invKeySchedule, wrappedWords, wrappedSigBytes, withPadding) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(invKeySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(wrappedWords, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.unwrapKey(retptr, nRounds, ptr0, len0, ptr1, len1, wrappedSigBytes, withPadding);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} x
+  * @param {Uint32Array} dataWords
+  * @param {number} nBlocks
+  */
+  function cmacUpdate(nRounds, keySchedule, x, dataWords, nBlocks) {
+    try {
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(x, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.cmacUpdate(nRounds, ptr0, len0, ptr1, len1, ptr2, len2, nBlocks);
+    } finally {
+      x.set(getUint32Memory0().subarray(ptr1 / 4, ptr1 / 4 + len1));
+      wasm.__wbindgen_free(ptr1, len1 * 4);
+    }
+  }
+
+  /**
+  * @param {number} nRounds
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} x
+  * @param {Uint32Array} lastWords
+  * @param {number} lastSigBytes
+  * @returns {Uint32Array}
+  */
+  function cmacFinalize(nRounds, keySchedule, x, lastWords, lastSigBytes) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(x, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(lastWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      wasm.cmacFinalize(retptr, nRounds, ptr0, len0, ptr1, len1, ptr2, len2, lastSigBytes);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v3 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v3;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
+  /**
+  * @param {string} mode
+  * @param {number} nRounds
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} keySchedule
+  * @returns {boolean}
+  */
+  function doEncryptCts(mode, nRounds, iv, dataWords, dataSigBytes, keySchedule) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ret = wasm.doEncryptCts(ptr0, len0, nRounds, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3);
+      return ret !== 0;
+    } finally {
       dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
       wasm.__wbindgen_free(ptr2, len2 * 4);
     }
   }
 
+  /**
+  * @param {string} mode
+  * @param {number} nRounds
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} keySchedule
+  * @param {Uint32Array} invKeySchedule
+  * @returns {boolean}
+  */
+  function doDecryptCts(mode, nRounds, iv, dataWords, dataSigBytes, keySchedule, invKeySchedule) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(invKeySchedule, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ret = wasm.doDecryptCts(ptr0, len0, nRounds, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3, ptr4, len4);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+  * @param {string} mode
+  * @param {number} nRounds
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} byteOffset
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} keySchedule
+  * @returns {boolean}
+  */
+  function doCryptCtrAt(mode, nRounds, iv, byteOffset, dataWords, dataSigBytes, keySchedule) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(byteOffset, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(keySchedule, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ret = wasm.doCryptCtrAt(ptr0, len0, nRounds, ptr1, len1, ptr2, len2, ptr3, len3, dataSigBytes, ptr4, len4);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr3 / 4, ptr3 / 4 + len3));
+      wasm.__wbindgen_free(ptr3, len3 * 4);
+    }
+  }
+
   return {
     getKeySchedule: getKeySchedule,
     getInvKeySchedule: getInvKeySchedule,
     doEncrypt: doEncrypt,
-    doDecrypt: doDecrypt
+    doDecrypt: doDecrypt,
+    gcmEncrypt: gcmEncrypt,
+    gcmDecrypt: gcmDecrypt,
+    ccmEncrypt: ccmEncrypt,
+    ccmDecrypt: ccmDecrypt,
+    sivEncrypt: sivEncrypt,
+    sivDecrypt: sivDecrypt,
+    gcmSivEncrypt: gcmSivEncrypt,
+    gcmSivDecrypt: gcmSivDecrypt,
+    xtsEncrypt: xtsEncrypt,
+    xtsDecrypt: xtsDecrypt,
+    wrapKey: wrapKey,
+    unwrapKey: unwrapKey,
+    cmacUpdate: cmacUpdate,
+    cmacFinalize: cmacFinalize,
+    doEncryptCts: doEncryptCts,
+    doDecryptCts: doDecryptCts,
+    doCryptCtrAt: doCryptCtrAt
   };
 }
 
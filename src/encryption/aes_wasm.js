@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJy0fQd4XVl17rlFV7IlWbLHnjHjgblSJgkEkpDyZlII4ZJ4BscMQ0J6yGMITACZZo8zwIPgq95777J6s3rvvXfJalbvvVjVqm/9+5wtHdkzMHwJ8I11/nXP2WXttddae+0mfP3d7ykEQVC4Ki69rbx3T3EP/wpvK/CkoAfVPUbEH+Ft9b3TP+x3xc/fNrkn+5/w9oV7984SBOFtA+lRw2nGZ9+gpMRXkPvP6V/VLSuDc8rzmgsGJmqVuaFSdfI/NRVWqVQKiouCQmWmOH9OozFVKwwUCo2BgWCgon/UgiFBlVJJ/9DrBvQgGCroCyV9qFAas+9BpL9GRkYqhdpA8UPFlSsGKoVwUXOO6qTT6ysEY8NYxTnN99753g/u/EQpXPjWO3dvvvOTr3zj2+9887+++47w6YtEuPH99+S0T5z75g+uf/8bd37yw7vCb9PzX78jPv/Wy1/72o/+4zvf/+a33vn+177+zW9+7e4Pvvbu3a9/49bXfviD73z/7jt3hH+7KHvje1//7nd/8A3hc2Yy2n/eeecd4UuXZJQ774ivvX7uRYWgUxhf/s63//Rjf3L9N3//L/72r//znX/9l6/80Ue+/a3zvZMqq4yvKAzv/Yag03/8lqXqFUGr+nyxtV4/pnxNuWtIIINAxfnXlGsAD/R6/ZriNeUcQCUBr+deU44BVBHQm76m7AcoRwIvvabsAHAm0EHfNAC4EfC6/JqyAiARqV17TVkA0IbXKJ8MAEe89tHXlIkAoUhN9ZoyCsADxbn0mjIIYB4loAS8AILwDZXaCWCcgP7l15R6gHB6HqOC7moIOOC1q1QfgD4k8ALVB2CcQIeG6gOwjUyp1P0ADfTLp6k6eM7FDyZUHYA1pGxI1QFwpV+iLlJ1AJqRGKWcAbCJilINEgHmUFEqdBRADlKj0gQBhCE1Yo4XQByAAVUHoAjVMaPqAKQBUNF2DQhM03MU/bIGsIkSENvmAJaQADF0DKANFb1C1QFwRwlepPoA5KOg1HANALNI+iNUH4ApcIqSLgDwxjfE9wyAVORDPEgEqCKQQalFAcTS9xXGVB+ARpRAoPoAFKGgVGongDEkQKzWA7jSLxnEkF01gREwhBJYAyhAY1OTzgHUITWq3BiAFwpKvO4HiEbSxKoOgHaUgFJrABhDtc2pPgDByOcC1QdgCtWmfDIAjgCoGRMBaijpNWqFKAbQclSCIIBRAOKBF4Av8qHiOAG0oqBUOT3ABipHDNlVoaD4hgRxDWAQHFVTfQBKUNPnqT4ABSjBx6g+AD7gNclrB0AFEqCaNgB0ogroPgCR4AG1aQFAB4pDVcgAsME31HKJALXgAZUgCiAb+RBDggCqAc5RfQCS0Yz0mhNLDTygyukB+pEaCcWukkAT8qFv1gDKUB/KdA5gAWWj18YAMpEasbcfIArfUNIdANATHZR0A0A3ykasqgBIx2tUtgKAJKRGKiQDoBepEasSAbrwC2UaBTCI5IghQQChkBASZS+AVAgF9TknAGd8Q0nrAbLAXpKDXQWBRdSUmmQNQI+Wo0znAByRADX9GIA9ADVJP0AQ8iE56ACIQdLUgxsA7MAqAhUAmagpJVAAsI6CklhmAEyDb9TaiQA5eA3yBrADQSImBgHkITWqqReAL8SFCuoEkI8qQL0BPEbSlOmPoULwPSX2Q3QLVI3k49tQ3KATo9+m53q8T7n/Mz3voSTUTl9Gd0WiJDVfQDODE8Swz9FzCnImfv0JegrSpHQ+DWWBmlO3+zisBD3T61p6XEV9qDhXUTQ0HLWIOaqG1yl5I3qeRFPTM6zNZkFmdeyc/9CXqUXMoIad0kYc2x4E/wM1CnBYY3ete1ZGyhRpkjkQ1m2iWif3Ux2MqG2AXX3XK30Spx3uUfMAP3bYyai877xeQoQOEFLnvCo3XDs6P0+tBGw9W/5wyTEzp5BeqADBb2TryfJaXvQuZVEAwkLoaGRu88LRKBEyQHgS1l2xvtM6uUGERBAGyppnnsyGPXlIhCgQlps3nNoSlmL2iBAEwkp77NZQ/OOMfMrFCwSPpLF2f+9251vUiMBu1cO9SZ7VHa9ROwJnp1Y2lE14Ly5TCrsXINxFYaEpTZvLPpTCGggbc+E9q3Ed9j8lTgDb9ewvDKS415HmGQMuKM9wPkjvyaqjFPpBmFys2y60jc5tIUIHCD0hSdMHodbN3yRWALs/yM/qc17aqaYXKkDwiMo92qk9bCDTVQDclOHjdDySt10JToCQcb/absRmu/uPiBHA+btF0bM9WZU/Iz6w3xMDct36gzv/D7EBOH3ax913piHyd4gLrAS+Bz0rIZH9nyAuAA/kR/QtDrTmN1EGehCcIjbcJ8L2XH5AXDBFZxl6mLPS/uQ4GVwAwXplYcrj4UyCLRHmQHBeC55viahLXaEkxkBYqVuvKVtdCf83YgNwcVZW7UFOpBtJaAdw0qOeo7zeGp+3iAumTI9t7zWuxs7NgwsgjKzMrncdLY7bUBYFIFQl5OR4hqcteREhA4TQ4OOu+Bbv8h4IBAhTxQvjXZWxJR0QCBDiw0Yetm6u9v4lMQK43bY336U2vzQO8gDCUW3yzNyMzUIQEZxAqA+PKwoqyAn7F+IEcP/AZvZW+FSwJXHCBMYo+HF98qTnbhQ4AcKed6fbfbuBzXp0DRCWUluGfbLTCpLojTFGiAte0u/tju1AIkBoHtldyoxwG/gqsQLYe2pqZ/DBnN8bxArgw4bEZKfy9uG/I04AV9QMhVnH2k3agRMgNBXWF7pHj2dWQSBAmNvNzy9OLXlcQG8kgrDd5TbY8Gjd+yYxArh2Jyimpm708BF6BgiRrXYF7fmlhUPwDUAoLp7dHInsL48BJ0Cwz64pr52vLEongh6Ews7BZZvCZE9SbbvG6PMhsWsdW+u1d4gTwNUVruPdk7XupJXngF022/xGhlw6Pkd8AJ4PGO6ernJwJuXeD9zuYuuQ6Oe0Mo6OAULXg6KExeypVngOwJntSWNr409SfKEjQOhMWh5MqncNfYX4ADyVVd7hVZOUNgc+gBCRXLy8EdsW8CqxAXj/uHhu1aWtitRcFHDqeO3aeGPYgyWwAQRbd5sWfdK+/x8SF4AbS71q6GUnPNf7rbmGHNreP0CvAKEgb7qg7FHx1BMoh/NEKLW/7+i6PlFD9mENuMXRumm3bXZpFrIAQuz2ZMt61t7IPxILgFcTQ6PWyu0T4ZH1g5Ab2uUyEBU4sQ4egJDmuju/nJi+0UqEBhCe+Dusd9dXrgSACSA4lNVt+LvY5ZdCGkAo7PccitoqzqgAF0DIqkpJ7Vgp2o+FNIAQnlB43zbn4ZNoIkSBEFnT6fygs7z7r4kNwK3zi2m9g6s25A56Aa9NRwZF9uzmtFGSTiCkFJT0JvoslyRAGEAoeujuVdEc5fo9YsU5KHzPaOuYvPnR3yBWAIfs+Of1+1WFfoU4AezYkRpYXLE2+PvECeC+iKOgtOmF5BlwAoSY+PZHyUMVcY/BCfbFwJP4JwFHpV3gBAj9HfdtAmOOoz3BCRDCKhw22x+72P2EGAE8turt2tyxmFsMBcEIMzVFA3OjSRNQECAE+R1GxGe4ub9DfACe6bdpbOn1mD2EPICQF2m/sx+dUvNDYgTw4sFY00Dx0NBvEx+AF3xc0hzTI+rvEhuAk7qs22ucHk95UJa7sKoPHPbiSoucivopxTUQFrdy91OKA5L8oSlBmC0vDevwSWgkr2AMOCY9ZGGu4Gh+EZwAwWahNDjVpiTeGrYThKDwxHS/sIzjYXAChMac2q3DJYfh3yRGAOudH2Y+Xk/MzIVIgHCQHni8uZflf50YAey9nFnXb+dr/V/EB+CHm9XWE2WhxQ8gECCkZM7PFj2uWG8GI0Bo7nsw7FvW2UYOjhdwdeNIx3hXn++fESOAO9q6PHOTB6KO0DlAGOq/H73jkjYHnbT7Agxj4KrrTG/Lwho4AcLU/MRMcL9/FvT3HAjWHTPO+QG2dv+XOAHcuBs10J9VfQw71w9CdXG/V19NwQgNczqAcxrGxm3mEsfhEjSAsDGa7NEQNDj0WWIE8OreQXxmqHcJ/JACEFpX27vSco/TofcyQGhLGmvYmVkvLEffAGE0bqMlt+0gmUxEFPB0od1qxLZ36WeIEcAJXUXbEYX9j8iWegH7rc0+bpit2XKGngRhPG1xquH+XCicDj0IvVGxkRsNW3kY7T6P3tfW+jBuL68RgrwGwqbHQmTgXNXh7xEfgBdKt2I7HCb1aN8xECL77epCk4NjjsEIEHJj9sM8OkPiSTN2ALf2ZMXXUl/5GvEBuDd0YNFpe/8+tEYFCB0hxxtjZVOu1N0KgIOca/piQ/ejfkRsYL/ft3F03Z8PJiFMBC4J7yyOodaMh0CA4Ncc4jDZMhdCyjsIOKux7knRlKf/HxAbgJ3LkhNaPZf8SLM5AYe2L7Yuz4TGfYmYANz+xKFSX1q785/EhCtwB5JD0mcOyh9CXtZAyM/N8oza6K5MhDSAsNuS06aPCcinrjUGPFkQvZO307a8DyaAEDwQPdLt6eAEHdQBwoPk8ubk6MbZbCI0gKAvPtws9tnMgcauAGFno2B9ayujkuS+ANg3ZDDQLTCv4dvEBuCW8qRH8w2RyUxBgBC+lXE/zScikfzlKGCP1YW8x/mLkVClQSBYL4YeLj0a6fo48QE42j97s9w6t4UsjhNwm094SVhI25KO+ACcujdQ5f7YzRue1GXCbk9i4g4cSzD0XwNuSKzraE/diyH/Yg44YCZtPCBwqCcY3gMIXW55Y2Ula8XkmvcDp5dsk3SkrrxLTADOelzs7p3TNAmPtQGEWD//pb7ZtPkfEw+Ao9Pa9H5VmVVa4gHw0ORQ79Ba0H1yxDKADx49qg2IKByaBA9ASHJ4kDO72zBN7kkUcHGLd/LS6tGDPyYWAMfbDB0PjISlwbf3AmE4u2jUdWm087+JB8DBE6PJu1HDffB49SAkLuc1hj50dPkCMeE5wu4+Bf1ru9FPoH7WQBirXQvenHBwRtwEuCjBpS+KzCTp6THg5pX5pvhqOw8af/YD11rbHtjZ7WTAoHWAQK6Ff/jjGfcIiAIIs34ZTvsHDxvJmFcA9+Qtuy3XuvuQ/ioAHohMjJrxC8yxIC4APxzeXvdOcA0lRZ8IfBy+OeqZMO7YC1cShLXo3bHktoA8mMQgEBys44P6D12CG+BBgRDhNNFOmsoJzy679qWblV5l3yEOAD/KCqufDH9cQt7w7iXCVR3Vy8WZE7ZO4AAI1fq4qaXs7Ww4iXMg7GfORPmUpi8GQhBA6HH32u218cvAEAvYPak29ZHregGCLsD2B5vbg31P4jHwaADBpXq3cTUqv/kviAXAkZNPsvRVj0Y6McICYckxrWEjPCgKXn0GCOP+AV7Zw6VdWxAFELqHOuYHFko64e1GgTAxV3M0TgbVBf0BhM3cwMWlfGtbGil6XWJ+QItjeklIwn2oRxDCIlKGqvJGujGA0oOw4ODUkdJRuw3Pc/ciDH1nhffo5qp3FngBwr5LaI/zeOs8vPQ5EBJ7m0ujCpYDVcQKYM9yt/LcXP9sGs/0A3fPPgiPHvMp/A9iBfBSSXeJ03FEEoZ9DSDQ4LP6YXlnPcJqwFG2e7OZ9lUHfrATIOw01NqvbA0Ow8XOACE7ethue9UvsxasAKGpzLPau67uMYYjUSCkVsbmxddvlOWAFSC0V+3nPvKP3qVhmxdwWcb0UEDaiu+/EyeAne37ugPaY/YwwtWDMF+R0GRTmFVfA05geG572LXiYxeeSk7TGvDe4OFwRHNP078SH4BHIzwK2rqnXOBLjIEQ6J4bFnK8mfs6MQJ4a8QtQB/RjeBsB7BHT2CVc/9x2teJD8CDE3ttOdUrPnBQK0CoWQiyLujpH4RPVQBCX3P5RPlA08Q/ER+At4Ni3BobK/bI1UhkRRrXJz1sHe59j7gA3L+ZPVw0thsHHz0IhMXelYGhxFnP28QF4Nj0uaClRedw+K9OICTM19s8TPUq7wYbQFjy6py0D8y53wc2vIzevmMfkxifW5AHgQAhJ3MjdoV6GinVOeDEjRrXCmubVSj+MRBKOlJbesaPo8CYfhDCUxtCU8PSHajYHcCxaxn3s3vW0uEjN4CQ7u8Y5vkgLZI84Arg0rLu3TqniGrqoAXAOwlJq0PHfQ9Y3wDBL2Nq42B3aPQ1YgRwV+HM3oOyh5GIRkSBEFaVn37UEBGRCU6AkBReXO6ztBxygzgBvJtdGJs5sVdDnoUTcF9ullvApnsp6Qw9sFdXe+Z00SNnjCR3PwYdNrOwkPkoNonGgWvAjlvtMfnjZYHwj+ZAGCny6WjLrEgj32QMONVm8rCzpbMcPbwfhM5Sj/QU9253qJkOEJKDuxemfJ6sqokNwK1LuTmHhx3x0CoVjHBcqPd/XBtI7VsAbDeRUtVY2rFPIpgBTB3J/nCzxeWfiQ3AbXPD4ynUmH9DXACOc6++n5TjPga/PgiE2MkS//36piAaGHgBRz7sbtPnuSzCRjuxWm7NjDxp9ZklC6oHLqmO2h2aKN4gG737UcL+rYEb26PJ5eRJrAGHDDgW7M9H9YRAO4AQH5JQEdgcuneVuAAc1lkcmje7XYphQD8Iqd6rjc62Nd3w8jtAeDgat9w1tTFA3aQBeLhsz96pKKSMDTRBKBzaLSrvH9vD2LcABPdl1wjr0NhNDLAzQBjtWZ5a8vTPhLZNBKGo32vV2bPHAXouCoT83ND1/i3PLniaQSBstZZ5JKYn+ZG76wXcFLwX2BQSH2pMjABu9IjYtkvYHvtbYgTwYer4TFZdww785d2XYKk7fWacIhLdyVVdA/aLmav3O/T2ZlE5ELL7MrKt0xZaEFQbA8EhNCPQfzd87hvECeCI4tS96ICdJ5hIAX4yXOflO72eB23dAEKTy3ZMVl/OAWwGcGCC3mci6L4XplSAM9NSZkpbvJzswQcQlpejJ9vDVjKpuycCVwcMNmdNZa2QRooCnnHyH8k+DgiB3xcEgvXKcU60R+AIuWRewMHDq8uHq3NHGBI7gbCe7t/SuZIST4NqPXDeo6naBVv7KcRTdq9Bj4alzmV0HvS+QWwAjt51symrrvHAHBKwb21Kh6vf2mOyDmPAOzlxj2LJ3SWXqx+4wrosuaU4qJMcog5g8i3yCjcc2r3BBBBcgmpKtxemHL9FTAB+lFcXP+3gHE7+egGwp59Xc6vroBec5QwQ0vZ3E4drW33/lJgA7LG3ejy6ZpvyJ8QEYO+SDHvyLRIwtg0CQe/rllNQ6xZLo2cvYLv1hG3My+D5oCqiK8ppdBNTTcDBbZMD9oUjy9+n6r8IWza+7e+3bl3jCu0IQoVPVoTf/kAxfJ85EIKyvQZG+7aayfkYA3ZwKolxd+k/xtwTcE9073JDwZANxhsdIJQVFT8YK/GNQUihAYT7B1N9/Y+mIzTEAeD1kuDd+QeriZgZALYNnBr3cPN6SF5tBrCLa4PzSsR80S1iAHDa3GZY7khiB4vMgpATfew1Q9b058QA4MHm3PjRisqFMviQIDjOVAdGtnZ3kAl3Am73Txv3Xk1qhHXVsyzt5h7ORBenwf7ufgRGYKyhfHfHaQBewhoIte4LQzszm11GxAbgxemIiq7tZuvPExuAI+tG9/qbgv1ToBdA2Oj1CC5tDFtXEhuAR5IipvLaex6R59wATKPNqt65STfyayuArQ839C73y5o+SVwAnpqwKc9fTqtKgxyA0JlmE5rd1B+NcVsiCKP14x2xMYWVNGqKAo7yCZ1cKIwcJC80CHj8cPDQzvvAFY6wFwj9WWkbvjuNK4gHOYFQtZjUPOddWoKYkx6EMd+4xoCBmGj40gjvlyXlBzd49M6TI7sGvHyU1peQHLc8AKUAgqdj2HFcVm1TGJQCCHUhHqWFeyGTPyE2AKc7uCbWJ0U5/5DYALzgOr45Vt63RkO/BuDM0oSuuPzGbBp8VgDntuhjY1OX6xEpLABhOnK9/EHc+uJNYgNwi3ddTkLUoQ/8/0QQMip6ulbTUvYxRokCYW8grTb3oKuRHLog4JSd1JHejfpdeFteIATmuMXv1Xg0j4APIGzGVBaFNIwmTYMPIDTMrU84DHakQCPv/jYR9qu8h4ZCwh79ITECuL/QJ6HZbWoMHvwcCIUT9hUulTsDZG/HgBMD4ic2yjuaMGMOHDDlvlIZWO0DLxK4oL/jfvRuYBTq2QBCk23bcUif5wTCjRUgjCaVLNq2jy/DgSsAobsnv+x4MeAhSUwGcNDjwvjMldVQUlWJwGHZhwHDC+2Fd4gPwOOPAmfve7b3w+QHgdA2HNm4l9c6hZioFwgDYV0V5aXREc8RH4BDgh2KD+siHKCx9SDMz/bbdC7XxNBAfPe3YANytwriu/zmyZFZA96b7SwuGG2OIiszBxzunLjjH9UX4gZxAKFy/WhsYm40EZztByGivs+9dd6hZgHqAYSWpNyupoN8G7hwDSBshwyu5CT69lP3rwA+DqtYOJhw3YcGLQBh3X881muudPm7xAdg6kgOhfH2Sxi6J4KwubK2VUOtybxpEOrGO1IHHTKyMaILAiFzpCkuOa7Fm4ydF/BCUsJucG1uGTwVJxCKsldL0hqzQ1chESDc9yqZ2R8u3CQ1t/ubMD3NhSlJDyNpoLwGuGQ79LDQK7m1HfIAQqvvWPaEe6kbesoYCHPD7pOF+yPDf06MAG7YmZpymZgvI8eiA3i1Ky/p8EmqMzmVDcCRhzZL/u6jjTQ0rwBOKxluj8k47Psr4gLwtn37nJNP0RD5ShnAC3lZR7VNQTGwhYkgpDbs9Ngm2zZiRBAFwmBrQcm+4/wo9EEQCPGRjqmjfi6rCC55geB26PrQrbvV/u+JC8COnsVNfsVrc2Rq9MC9GdnzCVPFDX9ETHgFQu6cPeXZUxdJnXsN2HszJvpJg1MW2YY54JT+gaDQ3JkhROnGQHDvb87a9dhcJ1+7Hzg8oHS9cb8kCKOzDhDSYhoajwsedXyCuABsv+/dGRtyFAF3qwIEX71zYaNXgxO5AAXAff4bHjFONVUIMGSAkNe5vuqx07/7TWIDsOtyXHBOcP4kuYhRwDUuq5l5ru7lPyUmAO+UhzhXuK7uwXx6gbCys91WmFOeAT3rxLJwmXE69u+2xcBVD8KDx9G5zTaPez5GbPgNWOSAnIaossVJZjRBKHGwDoyOrnclpTYHPOE/F+5cUOiPke4YCINdD4rqq+0xm9EPrI/0dwhrSfTCpA0wORWbZdWzDaHoFCC4TUR0Zzyu3sAwpgKE0Jjuwdoaj0BybAuAXRqmj/QPnLox8M0AobSkZ3thpjHsd4kPwIc7xztrlf5hcK6iQIjor3KtbPUtHIR6AMHZIcQu0aFrmyyQF/Cu734UmTsnPN/3TBqrT99zRABWD8Ls+lKT92reDiyFJbTwvkvVkX6iFpHLNRCcgz1tNpfC2/+FeAAc3LBUP783vQk3YAwEu5HOPP3SsQvm0/pB8F7Mi/VPq0yjUVMH8PBwwFp3Y+cI+eYNwGWPU9wmsjxtachbATziduDWl+/nhFnBAhBcH2dEOjUs7MDbywDBfsquryFneIvGG4nAOUVtj6P95pKp10YBd5Y7xVSTRYWvFQTCdEh333JU7qgBsQB4Y7y5fCwpcR3zhk4guPSt7R70xA4yiwmCzWZyaEd4YOXzxAcL8GEqsiarZok55msgOOSupJQUJdnR0G4OOMszauBoWj+OcPoYCJPppQfjLU0P4c70g5BS3xKR0FZgjXB5Bwh+nlkbDxYeHCBY2QACjTerNzPrjmDRKkA4KtS7B0VO+1NzFQBnVo7G5AXe70PYPwOEwMpW57jUTjYuTmS5ZiTuDQ+NBWFdD3Bja6Z9l+eD8C8RJ4DLlstcPI5qezG48wIhuO9J5VbsTgHcfycQtnri/FoiRkowO6FnSXTF2q7QgIGGpLtYJ9DzyG3TdXHTaxNRWRDK+xYXe7u2UxGrngOBTN5ef3RcG4Y1YyC414/k5LSuH3yFWAFcbd+z79Nht8umt0GozSjqGEkpGCNXrwF4xHfe5dHUgHsR9AMI8Xqf5O7tyU4EXQtAaN8dTRvZ3Zomhz0D2ClmcHKozDme/LJE4Kjkecfuh4d521CTIKT2TXSVz09Z07AmCLg4IP3RXsxBdDg4AUKIg2/p/fq4dEzbAHdGhcetrvZkYeSs1yo/x/+vVeiUd3UvWVka6A6Oj49Vt7UGb5gKWgOiWip0whfoWWGq0KktNCZKY/N/Foy1CnOdpea6qcJYS6+9qjSiP5pXlQL9oaTee1Wp1hrQrzrBgn62UKg+p3zq/1pBp7p9Xf05rfLjSiNLNX31M6ubBA20wi16tFBTRoyoVety7CsE8z9GphZq8X1DreJN5E1AoBeUd63ogX4UdOq3dCiC+ua162Jqb5oqtRqtmspmYmisVdHbz7+nOzC8jS8sDXXHipv0AsgXTslGOr3yLVMVI2tOyecYWc3Ix4rbUraWaiLf4JlpKDOVTq+1OikW4FUr7TkZNLfSGsmgkZXWUILvvvuuVFKkqrNz4DWnN2I5G9AwNRwY4r0ejlBy3RRHKLBuU/6ZH09Q0CmsqG00OrWVhYYYds/KUmEqGJsowHW1+dv0ipJ+Q/2E6/S28u71O1JzCebnjFHsj1u9IhiXv6gwwYpNr4/fsjR5RUALWH1ewGoagS1NpdepQrKWvye1v/o6CZUJVUxprtGaoPmeIyo9GOlUJHvyd888nZWi53Q1NlSjP1PjcY49MqkjidOa6MytzM3oj5eRlbk5/hK+iL9Xrcwv4a/WytyUWtNIe47KcV5rzD4RPxBfF1+WXjWglwyITVr1DXDqsjG1uOFNU0Ni6/FJp1GIneYCOo2CFfECle0CsfTLlkpThclHjakXEWdJTNiL5uaffJF+Vr5noUCPeZGk1+CWxUWtkYWGqmBKJWP/3bx2y+KSVkNfChaGlIIZ/WiovfS66YtapdgLzCA96IFoNgul1gx/zLQXSUCobQ2Jpca/GkdMPwxHDLSmlLjW9IbpVSrPuZumL0nVM9KaQqS1rH4a7QVImaGlqcQ7dN0P4MBHfhEHqPZm4AAYSz+agQMKSsjQyvKy9jLYoKRnzglDsMCQWGAGFpgxFijNz/1a2fARsOFN1tD0qdktkxeNeetfN32JivcLGSPTpkqmTakGWgU0wnPGTNoXT6XdyxaPpGTpOZY9m14WJdLgjEQqRTabQiKVWlNTFbWQwkQtSa/mQ0svGQCTj0F6Fe8nvYZou+fOtt2H5TOlZ0Bffgg+P9Mf0NeVUn9QQhqeo06qONMfFJACxWl/UEIYlEwYWLMp0WxEhvReQVIfIL1KufQqPoADl0QOXPoVOQDN8uE4cCJLHz3LjMtUAQXKa3FR6iTKk05yGZ2E+puV5RXtFbGTXLR6Sl1ckneSi9RJTnlzCbyBSD8vifRzXKTNUAwzObfILzgnMYx4rnwfuTY7kWtDUa5FOVSfkUNBJocCpJBMDxNbBc9aQbpS1gxmVHGFaCW0BmomtmJLmP96W+Hls61wkbUCCqNhippagSnqi69Tp6S+b2V5SXtJbAHN0y1gLtfUGmKjEdhoJDYEF72LxlIX+3VUiwRfLLz50x3N6MTwaLjhUXHDozmph9Yc9dDgj+as4Xm/qpgbo5V+pXrohA9VDxPBWDD+dSTMGfQiy4EYZHgLEom/cLb89KJzxRxkUy6mHzmrLSwv0iMx5jK6DCkk0+umarFNr+gUJMOfg68sfW2oZsLDujWcNMVtdGTzc5SpybVfwZxrPqxckwY7Tx0aupP8QzTqSTUU2gskD+w/yMTzrFIo2CWmggWx6CQEz0PbKCDrL2hfgIAotJesnlLGV+Ra+BKTdVMSEKQCJ4cE5YKV5QUIisLEiLTHBa3yFj2YXGXaQpcIc/eqIAI/OVizkYEGCUg6WUUq5ISZvxbeqc/y6MoZVlLnYQqBOpOCMeoKOpFkrQxPGESKwUJ0aciiXJLzSeSRjDcCrLGhTLdePNGtpmwQJkC6zmkNSWLOiUyUi6mW3AVdge3p6EBMRHGSyAUxERMDiLhSp8errzG5P5sQUwkfjqHQIuhwlsoP3eUsFBgpQOpZp1NiVMObnIYLbBzxHIYk9AWGJGasQ+r6bU5Ka/h0aRUYyIiffMhCK0Rv8MMWWSmWlVxBsRwo6wVpWMX/mop/Z6kk9xQ/pyGU+S1L41cEaXC8pXgLJsz8GzTeUOiMzL+ou4x/rXTm7M8t3e7xT610P7qt+28rxktGJpVyC+9rlTeviSNftCN+sVDrEgcrMRpjo3Kt+paFShxYAWAAayq8qHsZA9vjYzLHwosY+tJ/Ru99y1K49R6pHgwZ797SfdbK2BK6TJczVCmI40PiJplv87+5ywScDfa1gvlXyXuAkFKLqW/Rs+otehaYV6Aypca3UJmoMA69zBKB3aDiUsN99D2d+jbVU0z7jWv44RqcZMqehEdg5pGy1FgILEZgqjRGrjqycnfN3+LVvE3DVupegvnff/tsYYkKEUBPQxlpNPcWPVIJadx+47YlZYXep0Ke+PyGJcuQZJCyyRlmaVsSyxBmoN/pw7euQd+BZv51CwP65DewU0VsEGpDvGP+j9RrVeY3MWpWvsVCBSpzS5RepVO9Z6nQqe7qUqncZDepQWLFGlCzUrp3LRW3UVkaF5PWZ7+hfe+gk2qMWVlZv1XgjwklrBH/UC/GB1qUUm1l/gY9kSypqPUpW525KBii0PzUSivcvCZKiXy4fVIQYg+KKbxHRRKDNmrOijdp+EvDWs51sNfwWaYrzL/KJI68wxsQFxWTD0ksqNlVNyxFdqNqaADOd+MTxmrAWM7WG+RNiWzVgK0n7aKAClY8xVJDXYn4s4XBs6ylZoXuNSJRpFYjZfsuk3QrS2qYu5aCLmdQqoJGZyTJqSCmS6IJLaIVwG82XJM1kO7nWtWPblMbGbNwkOqu+Rvk0n2DRN6Q8qHMBd3LJKEkr0jh7m1iGHh7JkeVlKPibI4KnqNCliOvGOWrlPJVMf7g36+TaCNxxm8Zt9BvBdaw78ssmSSicwlUOEiioVwSqRRMEg2NLegfbkEkSTQU/1BxjYxZsixCyLIXR7cqxhHNU1JtCKk2fEqqDU+l+pm0VLBRz4q6qcL4/UJG8k58g1RswSm8CU+MxBDBNyQh3CL+MKV7RvdSu+j0erX5F80/qzVm4cLrpsZ4MrIw0dWgRPSstjzPnul3K0uYVF0DIfaPmJsCzLt5Db9UDJ9o5hZ6tBBY//gnrREcBAHxTvQFEtGTxlEyzUPtInYHqgZ+0QmcJ3hHfGI0CBlJoJLx2xg2FF1KYh8rHGOpIAVwSVEpmfSIH5w31o3xYl8nKyjcuGMKQaY0/y9UuvnXtCYYspGciD1Fqo+l8k31PZJ/opIzobxJfpQxKV+W2GmKlKAR9A/yNtKeJ+dHYoI0fFWAA0zyJU5AgZ3Jm/xNY56nxbmTr1XsC4GGrmqRnTS+J417wk/EpskvwE+WhmILW5rd0n2HmgttD6wlCVBQv9NK9lV7zurGNUtT4soFjIQZDyEoBkx5qsREaOjHXianGIVC/WDF2fcKajPqj+ZgOQSPRPq8FRQgeX1WlNUtSwPwwZwcM3QsUQxVUqqXpVTF1BV4y/zrWjay16puWVnh/SHwFluymfNHRfxHxo9PEGcukudBktsjQkRoL2gv0h9dPwpigiJ14Ok8nlpYyfGE97UX6An81EFuoJAMEeE3hHYmO3LTlDHS9HXS/WzYeItYBsZ+3fwLCAMzkwdsST78MypH+QGKRvl+ikYJRUMuoKhozkErnIMywR8oGiaWkAMYEyNRngmric0oMVpCI7IR2kdsFQu0ETHxlpWlAesYpHwk5UnZ3kL7k3tjdYvNL0hy9obU5SBgwutkdZAJGUed4g1TA8n90Ug1J8PF1JPO5MSCSJpd1Oes+MoP0ucaYpek0tUYG6gx0wLJI7dcFDBDLk1cl5EWtGR92RA+2x02GSMZH1HqROXBVc9pf2XGgmsB6Uu5JtCInZcxljlvMt7yzKn/Vpz0bUHWkywF+EdiARQQeyKpeBm0TO7JLZLJvdRQktxLus6CvUVyL4iS/z5yzxWlyBimBHOeZQwpOZEpKvjpxFWSVbXUYoTNLU9/Vv8vSKyhKLHMjhmKTW7IJFbKhpyyKZT0+OVXf2mXLICEoCiMewA5EsiR/9IAUCKBEvlrHQA1EqiRv9YP0CKBFvlrYwA9EuiRvzYHMCSBIflrawBTEpiSv7YLsCSBJflrm/Jf9I8qRQrAwaAMOOGXAwnYyYEXACgAbnIQBOAmAT85iALwk0CYHCQChEkgVg4yAGIlkCoHBQCpEqgAyJFAjvyXBoASCZTIX+sAqJFAjfy1foAWCbTIXxsD6JFAj/y1OYAhCQzJX1sDmJLAlPy1XYAlCSzJX9MPoRUksCl/zQm/HEjg4JHsNS/8YjckNZb8tSAAN+kXN/lrUQB+EvCTv5YIECaBMPlrGQCxEoiVv5Yq/+UXGFW5yZYrLmaXRd2lgu4S4+mkw6xEJfa/pLrglKjkLumbpsL/SGWTX0ojaZykY5x0VWHMZ2ov/JKZWnF29oI0O3sBav0KwvBnZmefmdN93xnaK6cztFeenqHlU68mWlPz3zk7u/q8NC9w7v3mpy6K81OybLUXKf2LHzhPZYxZmouYpVFK6xLkszQGWjMrS3Ot2Q1TM3KqyE28oDWjcrH/EMd7jhI7z2aFzNg8B0tNLLuZWHYW1tKwOhlqn0OwX4rrnX8q8Hkef85rL52J9WsvImBubml2EtS7ZCKf3HhOXnpydplXrGSxRB6VEkshxsqkSJlYx8vP1NFErKOxxK5L102vna3u81Q8XlXK+pmankdtKcnzCPWqxNnXk1DvM7Ovl6nOVNXzrKri7NBFcXbopMImLxhT8mIs3Ux7/ky0jknQ4qkEnc56XuGzns//gllPE3HW0+TDzHqelaqnJOqlpyTK7KRN2LzflaclyhwTHeY3TK/AH75p+gKYLv7HJcqESZQ5YzObviL/nEUjL0jRyAtSNPKCFI28cBKNVJ6Z1MFn4kfiJ+IH0usGklgqIZYn4WaTp8TSBH9MzoabJbE0Jak5FcsX3lcsGQsuiyx4/hkWXBZZ8Lxc4C6dVB0S9uEq/qtU+iKfoLt2lvEvsAk6ytOCiktNIE7QmWhfECe0NFaWV7VXxQm6y09P0D1PbCLumDDuXCZJPiPNJ3yC6jLHfIUCf03Ohtw/eK7ThM91msjmOk347MtVGd81T811msjnOv/3WWlyOuN8Rk2Ic50ojOkvm+s0/YVznaZs/sdEnNsgLcW1wnOyuc7/9WqxqTwNm+s8Uyv5XKfpM3Odpk/NdZrij+nTc53PVuWSMVrpV6rHyfzIL64Hn+v8X0+YM0ia6zzVz+8z13kiplfOTsnzuc7n2FwndYbrphrJLBGLTWXznWIKl0iaL2G9zvvMd75g/OHrqPmwsk1qC+bQ/HS+0+R0vvPMHLg434mCGf7y+U7Dp+c7L8sVq+GJvMvmO/nEuMLkvLE4w0YP0hozXZCdbFbTSQ7m+EyYOaaaOCA3RgSv8ZlQ2zMzoWC+NBsqsvrXwln10ytU5Ix+Zjb08q88G2ry9JICWKj3nQ01kWZDTTAbekmcDX16ElP7HFUt0e6DZ0NNTmdDzbWmul2Jv0xHfGjL/fTk5y/rg09NfhIzxYxflZfx0kkZDU9C4c/8pGQhpAvShOkVzH5eOJkwvSjNSZqIf0suKc5jqJBhdMtSTUMF9ckxnAeGBPgxnJsA/BjOJQB+DOcUAD+GcwiAH8PZA8CP4WwB4Mdw1gDwYzhLAPgxnDkA/BjOVAB+DGcsAD+GMwyAH8PpB8CP4XQD4Mdw2gHwYzgPNAT4MZybAPwYziUAfgznFAA/hnMIQDqGswfP/BjOFgB+DGcNAD+GswSAH8OZA8CP4UwF4MdwxgLwYzjDAPgxnH4A/BhONwB+DKcdAD+G88CAAD+GcxOAH8O5BMCP4ZwC4MdwDgHwYzh7APgxnC0A/BjOGgB+DGcJAD+GMweAH8OZCsCP4YwF4MdwhgHwYzj9APgxnG4A/BhOOwB+DOeBmgA/hnMTgB/DuQTAj+GcAuDHcA4B8GM4ewD4MZwtAPwYzhoAfgxnCQA/hjMHgB/DmQrAj+GMZUA6hjMMgB/D6QfAj+F0A+DHcNoB8GM4D1QoqHQM5yYAP4ZzCYAfwzkFwI/hHALgx3D2APBjOFsA+DGcNQD8GM4SAH4MZw4AP4YzFYAfwxkLwI/hDAPgx3D6AfBjON1YatIxnHYA/BjOAyUBfgznJgA/hnMJgB/DOQXAj+EcAuDHcPYA8GM4WwD4MZw1APwYzhIAfgxnDgA/hjMVgB/DGQvAj+EMA+DHcPoB8GM43QD4MZx2APwYzgMFAX4M5yYAP4ZzCYAfwzkFwI/hHALgx3D2APBjOFsA+DGcNQD8GM4SAH4MZw4AP4YzFYAfwxkLwI/hDAPgx3D6AfBjON0A+DGcdgD8GM6fQYVIx3DeRbeQjuH8LhS3dAznN+mZH8P5VXrmx3D+PbqrdAznF9HM0jGcf03P/BjOz6CnSMdw/jGUhXQM56dgJcRjOF+hR34M50somnQM52VUTTqG04Se+TGc1F10r76qTDWSmJ8BNW8kiYzeiPgA4ARAjPQzkmQbB1q4GUnChGY1emp3i6UR9rdo+P4WDQZeGtANxf0thmx/iwHf32JorrM0kPa3aE6mtQW2QkXc34I1KAqM1e5ZsCmHb2qNblkgFqO4ZXGefImfwWVR6AxvWLCpxrNbYOBZYKZBQ8b5ntVNUwX7SwmdZ/PYb7FNK8KbpgoWRmGDD8n3MGST81oW7/u+pZFW8d1bljS2YKuS1G/cMQX/6AsD2Q4VTjnd4aIzv8uJp7thdFfv3uHU010vOqO7d+4QX9TYXGJ8gwpGf3Sbp46SRtdzClS6Gg6klIx+ZPk/yPnZyoBkZ3S6gYa+fdfCAHEiYpj2PNtEJGedofaclU7L2GDA9tZgDpjaiMptZy+rhN8JEHfGSOEracBKPixcT9M32KIUNXlGouNkKDlOSGFKqvnOOcXFD9qvQiNJtlUI4wsVZUSuq/ItFqxSvIkNRzqllaWxlu0yokHUm6YIK6usLE1Agu+twoIbtTjKUIEDlhhiv0tjKzVm4hTiSmzL5xjRFEQsojBmxMuMeAFE7LsxYcQrjAjRN8MuHxROQbU3tSAv18LExMRYFMyLVGc24sVOICyRt1Cx+K3BW+RRoyJ4Os/Kjye2sImebrDdA5r3aPjExs8XSBIsXqDRCbWnxVWRuZQRqB+hotOLWEyPH6/RjyZoB6U4WcpaQCbSCB+digqmomVChhXoMvF6l/5H9VaKNVAhUxXizmfTe16WmOnZxMyfSszShBhuLJbq6mkKirMlekGWgunTKRhT64jLE7TXTlN4Ufb5R2SfK57+HAPHi5DIi9dNTcnj5wzXbVpLYkySpquRxjYYbuh6+EAHazmn+C80yNZt8l+w58tOSkCnpP/78dRIAHWx/BfKSpdzmo+BruYEMEnQ9ZxiyINuypoHc1knMBMFw0ynuGOpgLhgd4byDikzA/QFM53qjqUhnsnVYLqb7e7T0IDJRNZkpATPkziespwa9TyTsBOCEmMsGeuIaZcoSTPOcHwvkwG8fFZTIcen1NR5JtBntCMbqj6HsTFk7C4VGS+qTn8jW3e64Q+5nMlUeCbTC89mavK+mV6hhA1PU7pwNmGTZxIWnk3Y+P0SNkMMwUBnZ3Oy/dBA58cBNZMulgPsfsuRwMI5hek9cSHr+Vf4TBKNdb+EuXEyrwo2z83Mq1KnMP8yW47IrIY4SjWywi4lPmK1vMDGrEgCI1aFVskMKukphYWGxV400NRqaP5PCVgvd+9v1Fh5CfVBkmOhoX6hvAcOvY4IKcmLIcbGmt8VFLq/vE2QzL+VsYWaPnn5toWx+CUlM6p4ky3RJM7cvfNsSlpDbO5ASmrkT8Ov3xUEStAYxos+QHprihtsUayxzgRJiJ9reEFUJwXBdwRREWNKyVh36a6OvCnhNlbfQobuUHn0+hrhdVMV7z0qXYzhG6yi+OENU4WJBtbPCEwy0rL1C0osHQOdxaWIObrye180NRChtPCXr65SvY6dXpQaKRMDKpGlmgRDd/W/LE10976I6DIVkQpPEvA6sw0qse4q3c+l98/pKkjo9GwxiwEUK3ETb5z7oA//28oSLCO34PRDsifEPrPbIqfIhX3qm59ZWarALsPbxBdw+i9v32Htw/7BFlyJFypjFILaxvwlRInU5h+9zqLqRrCsKvBIxXmEmmNBEvHghEeGIkTGFsbMwhm+jv3BaGms61F9itWYSdo5Jmlq5CcW24DLhwEvNoksWXBR4CBp4I2FifglJSNKmhqdmwnOsylJDDBA/sQhLmlMOijpc5KkIUoPSVOLn6u4pKlFSVNJkqZmFTGmlExOJY1JrVbNmcgkjdwXUUuZYpntS8Q29puFmhVHpzC2YBuU2eucy0Rvd1RSHhd4pNxSdYs8CnHFcbv9Mz8pbkq9X6c0/11GRcOZMrYxJYCXyaip/1xgTMDcqVZl/ntUtvNs0zhWItOTmnENTyZs2RdI5i+zBcIva09UjPnL0FxmtxBRNNJqSI1QfxQlxpjNx8NgTjmRJjNkCo/0FglNDyecZ9PoZqLmIo8v5JzCALpuTbhlafAKm9kXl6hiIbA4NYN2JE7ek4Yg1hA7RAt1ejKKAmZNsUJMEr2/MVW/qMV2dCvSBvSvjKrRHZ1iKIvDU6ikb6hvUJo/f4OtaRSnOBWvKi+LS2+xlHCTKvGq8qr4oyE+B78MkayxpUYciCCEraEO8aK4rktz0iNoIGJ8JrUadzE1AyK/dMa5VWoVNy1ZhPOmuFdcq8LIhtS3UvxVzEgJrquRy2nl0W3BOq1WKa65MmAtYnjtVeXHkadgpROXWeiG6NFcfOw5efy8CuNPA90SK9unMdZ5lQatmBJuEEiYPscer9LTP7Mnc3r6Mnv6OD19AasGyUs5TfmpTC6IjyiFWsxPLeaX6PHL8nv7JD8xZ5OTnI1YzhpjUXS0oqSqxEGC4oal8rpaUs8G0uJmamaw63PiNCH/XTQAWsMTgWHDGzYTpmaSyzS7CuMWHetgLE0a5Ii7qNlQlJJWQrFpRJUvYNErRgHoBQorts5dMppkYKDUMD0MVXaOCettUmRoaCxFgF7CIRHYiYCfXlW+YsI2aFDzY/eGltT9PXGMRUjUS9TWGFziU0FKUFRr5B0ztQb7q4MHSGM6fG7Iv9PAzJxoMgFJ3EGvYopJLAQVAHWldPWKG1RvpfhsjmfsPMbb6hvXsI+KNcTH8Yc656vKT0lNLi5N1C2dysRTknLxVFIuieJhIIrHwS8Vj2+fiMfbJ+IoCsorJ4KiZYJCTNQ7Vwi6T1KzfoaNOLGKRaPLcZc0lIHu06Qrzf9KME42Upy/B/VpfmrrxXXPJjqF6DooMGlFpKtM9Qm6l+D+kQK9qJb25aiwkpVtdFZaidtCTrbqaK6LtpntgxbHgeS6q19noQEF8y+0RhAnQ7KTiKwwO3mO2SW1aJfOa2GptEaSVJ1n5tmYOQ9jaCX5u8biu9yGGUs2zPDE+Blyqyn5Z2puNQ1Fq2nIraZMVM5DrkQP786J2SNuG92C9YfsUqfRsME+CsJ6HLP9RqxOLDOUQ8xPI7P3Gm7vmaNixKSZ14mkTfzAUPxAI3YDDS+gEZd9uHKMa0bMAUW97ukEg9OqGJ1WRSWvhLjnQWtwXTT/zHdg/g5zO+nRUv26aJAZxsErVpJSgUNLNVdYcO17TVpnQg9sKQLJjBg9ERcywwlnWohnhM1BzEnCLJ1OdUPyQERJ0THngX762e1bzI2H3KjFVpXcQCvG0acIyqcJqjME5tKw8TziNZaGrMUMyF95NnXJ+2GOJnxd9ip57zcl5miwwk6NWrFtIgK0k9YSHVD1+jW2tPvWBdzMJ2oiyemgIcB7aCFKGE8aY56H1KUsxJXhCNhRZ5O2JYgaGSEg+A1q6qcXBeE6zLfCzBijJMpTak5praBa7Jps4ZwYeNGwzmF2mt/ZlFgeWs0NM3ZagcrM+AM6u/GhRqEhXWH+TbZa+6uWSizw5yrD/N9NBWk5PYP/IFl1Fggil+o/LAW+Ch6rqBTSGniySUrZNh+sllCYfwG+E32KIIwgbn173+Xx6pPl8QZsRIGje0ywAP/9l8erTpbHw8ky/ztmNVh+Jnz/D18sqRXX28tKfLrt5ewycaW4ZUfcC2MmLbMX+ArQk2X2steklZcn7BA7GX3+VUsVNKiSDbHECivECitOKqw4ux9AqvAH7Ac4rTAqan5TK2sCGdcRkT7Z/3O6O8iYLTdlzHgL0oRlruImp1+wv1R4Zn+pcPMaVlHKNi6drPS3UDy1ppa6vfJ0mxW1J1s3JNyyIqcDy1v5zh3FMzt3FHznjuL9du5IO4BELmKVCpXhlVtoVSkvXtU3icQW2d4TIxtKtt1VwdhnKa66n5K+uCftD6BczF950cyYva16j+0GlBb/K57aoam6a6nC4n+FfPE/CTpb/K+ATVOI6/xVso17JmLQw8h4T6MwvKf7JOvurNI0KqShocE1mEitgRWcNLJb6tuinlUwoRYDIkzdauTqlrSqAVO1kpqFeTYgZhqJA8cTJWrwtJo1eFrNGjytZhHzUcMwGkPNGou6U1SzT6XOXlU8ZUMNEXcwhG4zOHFGRFcEcSqs8flz1mFOvRWpc9AY7SYbahvdZpvzDDBqu2WpIQuqEBW0EdlCmYJWKNn4hSeqxi4ZcXGugWRx32NFE/W1oTEvLNfXRqK+1oj6+hwTRtFeQF9jvZ/RM/raQKuR6euT+ogqF+abHl7iGpwBC4VkD3RsoyVbQ4IxP42/Tgok7pw7UegoBDHyhlgPVkgqq+7TTO0QI8gXJ6/6zwStrAjCKccU4nkw4uZT8E2DnUXPGDalJEPQaGCUQmKU8hlGacQySBUxfIpRhhhOvg+jFDxo8L7MEUTmqMQ00d4aaZGVhp3zJMXb4JPIOAM506JHo+HPiRwwYivxqfsacNPHsjpj+iZh+k6ilOegmklXm7+Ic7rwrbjO60PtwFec0ZA4D0px85pWBc1jzhyU063WKmmrtQEzugpmIrCfi2+9PpmzFK2tRoyPq7mahJetPrUy2N/7d5gSolQMsXyVet8NwkZY56VhsYYbmBIkk2PAzBGiTIwIPqJPYyM7Pd3SWeuNblzDynWx36mZpUZ492VpuysaBK2LOLeGrdTXam6R0v8hKfJX2eQTOQ/izkBMZ961IiSgHbAPlm+GhTN7S9SyZzeCYhGcAQ4uePXpvbJKZtHFLaJYuUX5k6PGd4IjMwO2QRx/Pw83Tvr3qona+MRWU4HpuzdPXldLr6vF5Ph+ywK+F020+WqZJT1N5Yapik03yorBDLfG/POim8sGrGJG4CTbUnhaupvUOwzhKv2czLj6me3MammSUi2pwHM4hUH0FFiklO96VL+/f2B46h9oTg8heKbS0r9XxYIamX8eJylg3GeuFHswNBA27H9V92PdzyCN16yY6Sf5Nf8kye455s+xdzj1RS07GlBpBkn7hxfpn28YR592MvnsNySKOX/0YMSMsS4667+sdBbwZ5q96en8TQzoj46Phdu6iLXzbwCNTNy10pmyHyIT6THiKnuecKXncWP2nDvwrpUu59EnGViz/ZMbt9lRkrolzwqB7Vw1eo8NrDQqMSrHIrSk9WjMZsnGERo8n/hiGsQ1VFihSX28zKsCThbzqLWsX6l00UrsPFCy36ykNaHq66zH3sOQA4kxLcoyp5zFMB0VSm38VMKYxaNXjsmsU7fwqWCHWlioEaUXh9UYFLPCKaUQvYFO+BKO6lDp/P0rSBuwsmOuBkwzunsH6pwNrJmzg77KdkPQaOVdFI199YZ4ohl7/p8yaNz/LINw9kG6AkckKtlvvxqDVMZPJXyGQV6BvxKDskPAIKMPwyDFKYPwFZ/yV9wWRzC6Fk9pZS1aX0RYWitGZ3RDwJ8Rnnk1XfGBr66ZkOGT33QuyG86F+Q3nQvym84F+U3ngvymc0F+07kgv+lckN90LshvOhfkN50L8pvOBflN54L8pnNBftO5IL/pXJDfdC7IbzoX5DedC/KbzgX5TeeC7KZzQX7TuSC/6VyQ33QuyG86F+Q3nQvym84F+U3ngvymc0F+07kgv+lckN90LshvOhfkN50L8pvOBflN54L8pnNBftO5IL/pXJDfdC7IbzoX5DedC/KbzgX5TeeC/KZzQX7TuSC/6VyQ33QuyG86F+Q3nQvym84F+U3ngvymc0F+07lwssSyAkB207kgv+lckN90LshvOhfkN50L8pvOBflN54L8pnNBftO5IL/pXJDfdC7IbzoX5DedC/KbzoWTJZYNALKbzgX5TeeC/KZzQX7TuSC/6VyQ33QuyG86F+Q3nQvym84F+U3ngvymc0F+07kgv+lckN90LshvOhfkN50L8pvOBflN54L8pnNBftO5IL/pXJDfdC7IbzoX5DedC/KbzgX5TeeC/KZzQX7TuSC/6VyQ33QuyG86F+Q3nQvym84F+U3ngvymc0F+07kgv+lckN90LshvOhfkN50L8pvOBdlN54LspnNBdtO5ILvpXJDddC7IbjoXZDedC7KbzgXZTeeC7KZzQXbTuXB607kgu+lckN10LshuOhdObzo3XmIWx00vZs0szkO92EuYxfHDVyaSxYFW9zKVLE6vxBBmcaAg1y5IFgetMGYmWZxAqZTM4gQjARPJ4tiicNziwOah/zCL04BfPipZnC10s+cliwPdC1XDLE6x1H+YxYnCay9JFidM6j/M4qDlvD4iWZw9tOlzksWBOe14SbI46Fkd3OJAV3U8L5mcY0l5M5PDVNp5yeQsIR9jyeQkSAtOmcmB3kHDMpOD3ghRZianHsXRSCbHCQkIkslJRLU1ksmJ1ItmipkcdLOKj0kmZxe/fEQyORVohZclkxNiLWpyZnKeoDhqyeTUSTqRmZwyNMk5yeQcWItmipmcMmmZLTM50Eh6lWRyJvSiPWYmByK29rxkcmpRn4uSyYGp9zKQTE4IwAXJ5EDq0eeYyRm1FjUFMzkwu9AHzOTAHOo/KpmcCWtRdTKTkyupTmZyYCg7rkgmB+Y9Si2ZnAAI0hXJ5MxYy0zOGn55XjI57SjBRySTEwjufEwyOUOSEQ/i1mzNRDI53qiCkWRyYE+9Lkkmx0XqZ8zkgG0ZSsnklFoz14VZnEpJITCL40lvaSWDg94ORcMMToTc4MAQVBhIBqdWsufM4BRIOjmDmwhcScEMDtNhL0kGp9taNPvM4MD7q3hBMjgpCsnYMFt+TTI24daiEWDGJgFcvigZG9j/tfOSsRmRdCszNoXI45pkbA6tRfvCjA0cN/15ydjAIdJfkIxNHr65JBmbYaRmIhmbIb2ogpmx6ZH0KTM2QZJfzIwN/Eg9NzbwFitMJGPD9PHLkrHpRHHUkrFZRstclIwN1LPXOcnYbOtFe8mMDZyoqOclYwMnGT4uMzb3ufMJgJXn8BCZsanGL+ckYwNnPOplydj4o6EUkrFZsRbNdxQ3XXB7mLGJB0OuSsbGT/LPmLHpkRQ3Mzbz1qKVh7GBpzR2TTQ28CUqjERjA4s2dlE0NscQLXPR2MCRXzMQjU2hXjQwMDYHkq2HsemT3BAYG2s8f0w0NjHWog8PY4PhBdbfw9rE4dlQtDYwQmMa0drAG4GPC2sDj8FLAWuTZg5r07uzshxfb+/6KcngNI9XDddH+ifj4mNmdHYGczr9J6odPybZnWpbex+nvJmWT0imp87eOdopMCTny5L1GTw+ehBQV7jxT5IB0vvGt6xFVzu8KNkgz+CsMH35XAfuNWJ2aLcjyjpudWcVN6UxW9QYVly2W/4wmV1uCYKPZ0Sqdeze5g3JJOXlryxF1zh6fkaySs7hRSVrfnFNfyQZpuLuOF/3tO0l3MnHjFN25sRQVnyNk6VknwqqHNtLbcoObkkmKqFos8O3L6H9tyUr5e0eUmKdsjaGa46ZpXqSPvzYpWhlCNe4MGsVU23ve5DSGoJbqJjFsl56VJ5eZev4kmS0+r3v2wyMVVhrJLtVs9jrnvAwOPuLkunyGq+0TWvwjGSXQbOxWGDaUkdHdK2ZZMGGAx9kNRavjuCuLWbFwuY7qyOm6j0/KxmymI6Y0Zb0nRXctSUas/mu8MCmzEncycsMWmzBWoXvSFJ3Hzdqq01+nbFtroG444cZtodbC4cO3hElP5Rs2/xwUamdrY2duWTe0pv9u5c6I0rvShbOca47crSmaOstycjlDGRWPxj1T0m9Jxm6rvDCCeeShZ5GQTJ2bqF5bhWpWwu4d5QZvIGVofXtvEd5/yrZvKy2yPRSp6wp3H3E7F5+g29kaeZIyR3J9CXG1LVU50w3/YFk/VYHssKneoIy/1oygMN2rkfzy5vzuBeIGcGlBq/KmrDANNy9xgzhI984n6RKa1sTyRa2bc7dn622sb8smcPiCtvR5Sa3YNxUyUxieEBqxvjK9jIuumJm8UnJflnyUGA6LpVmprF7fWK4ztHe5eOSdWxyD54Iqi3eeUMykCn3q4ayvaJrjCUbOVPjdH+q3cW/R5DsZGdk6eMnnvkr7Po2EKKogG7ZY9C2zFymZE15dT8eKvxPyWKmNnpXH5T0peGiGmY1bQ6fTB4ntkfg8i1mOfucfCOSjua72bWGILQ4e1cFzda6/p5kQMfLDx+nbE43/x/JhubGt2Q8ti/e/ivJjLaE5Hs02Ng6XJUsqcdM++poa848riJi1jQjqkIfPXl8/I5kULdyFo+KUhu8cKkTs6qjTx6HB/Q/eIR7opllbVgbs66POty7LVnXB3FNB9X5s20WfERnbW2TuD5ZbyDZ2I2YWp+F47lOXP7I7GxR+VFqVlhyTzO3tWv3K22anDwjcCsZs7cOdk5RXmuj5T+XTG67q3+XU/TRwXckq/twpiWzLmu86qJkeGsd3bN2i7rjcUMrM77TD5MPpyaODr8n2eDFqOqRlOn9neuSGZ7LHLdJmd+YxcVXzBSP+CdFN4b4xgfdk8zxwOFeaV5SWxiuAGMmuexhUszy3ONp93uSWW6YrGvtO+x9gEuDmWkeq9Bbb9qWH31DNhSsiNvf1kkGeiuhNcvlweYcbktiRnp9pGR9y+d+1XOSnd7vivWent1d/3fJVI+nPipbfLI0gGtkmbnejmuMHpxvC2f3EoGwkjsfXRyZ2PFIkMz2g7yljJje0PyvSpZ7sz+jcrrWwf2PJeMd6hPrNb4xVvlRyX63Tjcd7mQPF1lJJjy+N301bLnRF5eOMzMeam0z1FlfsP41yZI7e4StjdXZOb8iGXNPF7+Ojt741t/n9jxjTO/bE9f8mmTSp+vcMqcW18ZxUxIz625OXhVRC+uTuDmZmfbuiRrv7YRal9+RrLvvZK1vmUfeEm7NZBY+pynQ0/3+we5PJCM/NNdRWVc234VLh5mht1kZTT92zp6xvScZ+8Gl/vGAufZI3HvHDH5aQttSTKuzL+6MYka/anXkQX3M8dHXJbsfMN0YU+aaMYbL1WD7ywsfhz2OSOrC3Viw/4e1Lodxh4t9uF0LPsDSozz3TbfcBVwIDT+g0TP8cYF1ye5PRVfAf3PxuKy0NxV32MEdqPBJaNvNGC37f6JHUOuXctTfHVX5sugUzPSlLcd2hxd/V/QLgg/2y9OWthZxGxh8g6T28I2h1SZ/3CsJ/6CDbFAedVul6CJkjpZutCavT+AuRLgJ7lPNx6M7g7n/KHoKwavDGyPrI6Xvic5CXL17dotXZNmP4S/kMn+heCcve7nKPuDPeEh0fOVRC+m1n/Go6OBc0c64redFHhi1cQ4Ly55hF8OLwVE77xw395A5aGcxQHoc0L089GTxD3iM1Dde33/f1v0FHiYN9tgNyplL/TGPlHZUVO3s73RB74nR0vDIlp5k/3/g8VLPXq/g0r0pLQ+Z5udlLlY7RljyqGl4REODz3QwbnsUI6ddjSOhxds9bOzHoqeZacGh0Xbef8QDqNWzdbaRR1vP8Rhq0VzhRPesL/qmGEd1H3EKLlhr6Rd4LDWtsDUyfwVOkRhOrXKOGIx3zx3l7sL28qjnWKWtxyUeVSWTVTqmtzXgkdXFupilluAZ+EBidHXM2nG+3rOsivsLroF5GYmdE47QSmKUNWBgaTh/NeOrPNBKRu7JpGOkBY+1dqZHdBXvdMDjEOOt823FS/3rzegaYsy1YOz+RM98ym0edm1K3JupdU17zN2Foq2yFHdSvbjTTQy/Dkc0eVjbOF3lEdimpKNkMkm40k2Mws63l0cN783+Jg/E9o/HL5J0/j8eiw1b9wvPXYh9m4djQx3G+ou2+mGKxZDsSkHzwwehy/n3eFi2vS/ANvhxbbXAQ7ON7WW+6RFP4ECI4dmYmcrBrOmg2Hs8RDvgsZfWEjSBW/rEMK398VLu3uYILv4TQ7UNURN15Bd9k0drfRqHliusHc7zgO1mx0ZspY3Lx3jMtnJ1JKbOLauXewyuAf1zmfvbvVC2Yuy2NLJ1tjlw5D94+HY9LbTVxt7vMzyC65bZszS8P/kJHsS9vzLg4j1hD7UkBnJrvLLia12Sj7jH0B7xxK01dKcT10qKAV0qYEjGmBvMkBjUzWoYyFoJ24C2FAO7DfdnBlMCh7/FY7sH64H9CZ6l9dxlqHFuL9nanE++w0O85KUcTNgH/imP8pY9cT96PB2Ce0/FSG98UFJ1+P747/Ngb6jjVA05Lpd5vHd2ZydqcHMUd+mKMd8ovfXG8fHjF3nYN7f8QWCs0/1J7jKUPPHYX+5dLtbx6O9aRXVP2eGumgeA447mB7NnveB6SUFga7uD9Ukbl3s8EBztHF6+OZf2cx4LLg/odPVfiPsGDwdHWdu3O3qW1HCPwcbOK2NtNfLY+h4PC7skHYSVH22a88jwTFDKw4xxJ797PDrsEJw6lOTb+k88QPywLLns+Gj7eR4jvr86vH24P/E7PEycUVl79GSjqkngoWK/zk2YlL/n0eLDiObwBI/CZu4xLPR0bhc/eVyHG9vFqPHkTHXJYsCj7/HAcYXepi7yeP1M7Lh0f+xTPHwcH/wgsmhziF3/y0LII7su9V7jzhgOiGHkrobB/IPdlc/ySPKDUfe87aWyL/Fgcqx3bvmsR3Erdxho5Lfl4Tf34Kc8ppzXPz/RGroCQyXGlfvHohOqHUJ+j4eWfRr6M9fGPHDXtxhenj5aHEoL34q8x0PMffl5j6ecE+aFkzDzSn/S0O7qX/BIs0dBQ1SVnc+rPNjskrgb1T0TCPsmBpwzKionuqZDMewRg851Qcl5u2ttuIxZDDw7RY2t7a434ZZIMfg84RT6MNre/y95/HnCOdI5dPvhhsBj0M318b5lBwdGPAw9FzX5MGc+8S6PRK/2BVYEb1Ticl4xGr2U0b0161leyX0G34Tt9YUa5/gF7jPkr/b6DpUfr73EA9NT3vnOQWutcL5YcLrQ40mz33zSj6T4dO3RwvHWYrSVFKJ+5DDRFrI1MCtIYWqPwha3iINDYylSvVWe7p0SMHBLClb7Jtj1pEceOt6TAtb+5amxHWOecKNY0LovL2uFHEF4vCxwfRjZvr63NYgrLlnwuq2wrWjaJeVQ8hnIK7P1p36rkoLYI088HhWsN2DswgLZ08erGcshixhwsWD2SmF74WrEPpxdFtCuC05ropGt3T24Dcmi2/BoYGTRxjX8ee427DxwaCblhjRFvyFnePFxZb2TGfcb7Dw8/LJd+oNP/AankAA79xwm36Lf0Dx/tHiwFfSfJ36DvqW/ss7VlPsNjiVTQTkBBxAU0XGIGq9+XJxU+jXuN3jGPx5qWjjGeEn0HFIaSoJKs7y+wz2HR/3D87ae3h/jnoNn7GbD45ZuvxPPIXa1NaA4ueBfuOPQ11IbXNUQfJE7DtmTzh7He84/5o7DZtj8+EbjUMSJ45BekxNUEJ+q437D0Ey5f34I8/pFx8HO0723bjD9/3C/YdUxY9Xa0fkC9xvIbuVUVNqouN8w6Z226Jb9cEbgfkNFtd2sY+T9z3K3ITRoKHG9usrlxG1I3R7pyQ9aQWuKfgOZup3qdsR6Rb8h0LNypDix7N+52zAbHr+4O534Ovca1rzGxzcf7sK6im6DX2kK2ZDC3+New+jDXldSvxixiV6DZ9yOi7WdmxH3GvzKH8STVYJHJ3oNs5FR6YcbPlbca8jonp4h+YS5Fd2GgqZtv1yfNUR4RL/BNmOpvyg16x+42zA0XdTduv0Idkh0G1KaqcdN3P8C9xoSRu9HtB/U4h5e0WvYiHIfyHLr8j/xGhxLZ5PdMrvRS0SvoXzbp6QoPf8r3GnwyoypIu8I0RTRa4hdaZvS29pruNcwV9gVY23veZ57Dfmr1mkOwSl/yJ2GVBpxFacU/xv3GdpnNpZd09ehyUSnoa+1rtnGJeIydxpI000drvt9nzsNO6n2zutVlU4nToNTsC8puvLf4T5DSd1Rzf5c+VdP5oBt7QMyHFqsT1yGqdTGzLYnTVCFosvglR3X27yygQG46DPsum721Y/E/AV3GRJGog9y/J/AfRZ9BvJTNquaw17gPsOBw/FWlntn4InP0NITXHm4Fvhd7jPYZi20WDu4mHCfYb84KH1vNe/vuMtQUWG9Xl4IdSK6DKO93eE1HZkW3GOoP5xZ3B4ew1hadBnG7ld1l5XY/Bd3GY5z/AeynUbCT10GG4fNTOtazxOXoabNMzcn8BjRF9FnaJ47dN7qWkfYU3QaKqocax0joj/DfQanIK+19uOKYx7Zr/UrS/MrL3L6CfcZWh6GdGfYNdmf+AxuARE9jTOLMKei0xDp31dUXuz6U+407KTZHpZm+v+AOw1j0fVbhffj3uI+Q+JeL0wKxqGi09A+vR1aPxT3p9xnSNx/mFcYE3OTuwwb9z2Km5e3YWOkyWZr26rjXc+fnfEZMgK+x32Glt5I/6K0nL/lLkNJ7X7VWk2z3YnLELvcnFWSH/kN7jKsOmSX5kXOYiQr+gwNg4G59cPxf85dhrmiPvvN3kNcVC76DEs5A+Pu+QNwtUWfIaNzMtrWPfBF7jPELjVkZDi22574DMe5AY/a9tpxW7roM4SGDhzVdOf8NncZ8pfsEg92Iv6DuwxhsQVRNs6hl7jL4FeSGLXRPBhy4jKMRVWOP27tCDhxGdz8Q7MKEtL/insMXhlRawVxSW9wh6Gm1aW7qinyCncYatrdHPcXCv+Z+wsx3UkRZWV2t7m/0LEe3Z3jt4cQtugwLLoWtD8ZS/gi9xf657M26kej/pK7C/sF4fN2vrm/xb2FReeyR+UFHv/NvYWGR36OuzMPPic5C/UH042bPfsY0TBvoXwrcC/Xeyv9nuQu2GauNu0tZf+95C2ExZfZHR3Z/1DyFkb7OiKbl7YQcRfdBZumofajaph75i5EBnSlrtW32XB3ITR4dIHcQIxlmLvQPrt2XPQg858kbyEsoTSv9mHZJyVngayPL/VYheQsUO/sLYhNvi75Csf5XuWtWyO4R5z5CkOzJfnth3W4iZz5Cm6B4XU0qsWg9P8T9x5wUdzrHvfO7C5tQbF3XYlJjImKFTV1TWJiek7KOekaNYmaaFRiknOSSBOlSRFEivQO0qR3BKR3kCIdRECqICD1fZ5nZjbEc3Luufe9933zCbLfLfOd+c/Mf37zn2UekSyAskJPSF99ftYFCyErjF/KOGvhkBqkzAq1vdGdLd6Z54SsYHTunLn9WThdFrKCiZWFnYlzaJQyK9yOmp4cT7AcVGYFA7+CgNYsUyErGLtFtl22mCpRZoWSfs/0soqGPiErnLsYPxxyfzpImRUcfNySLjvlDAlZ4Wp3b11u9nlrISucs0sYimsPzFNmhbIRv/xLDnXdQlYIbm/JDGwrOC9khZoBE+uUSZMJISskFA11DPiEFiuzgqOXc9TlizebhKwQOtiY4GA1VKfMCtezTS38eqqchawwYlwdW5xhck7ICni4avNINxSywsD5qqv5NUHXlFkBulHj7GIbdyErWFmG1o16emYps8KlxLBae8uRBmVWwAPcHf8cayEr5Gd7ZLvZN/YKWWHwQrnveKR9q5AV4nL6mwaCksqVWSGvwSEQjh2OQlboC7pbaeHmlaTMCtlliffNjUxNhKyQ13gp+EaDd7IyKwxal/hPx5vdF7IC7GmtNypTgpVZwdU38Z692WiFMisYXo7pvnyppkvICr2R9fHe4z0xyqxQeds1NWXApk3ICuV9Nv6hUy1JyqwQX3LD0sm0M0+ZFTIart3Oqw6MUGYF90SzS3VVtzqFrJCD14Mc40OVWcEu1j8vM93IWMgKQ3h55/q5s0JWuDVicK6owMFJyAqXEkKqSyrre4Ss4D/4YLiwKi5MmRXu+rVmWZy1NheyAnZxQ3HmI0JWSLx53SS+2SNTmRUy8fJAnruDkBXcvFPHoobce4WsAPu18SXj9jRlVoi82ZbTnHQ7XpkVcmrsvP1GHoQqs8LE2bFB/75SNyErXAyz9avLmyhXZgWMJ22+RRZCVkjJmB6rvNGRr8wKcLS7HDFqMSxkBUOn6F5L47OmQlaYvFLgPh3r0ilkBY+mNIPSuj18Ugi723nzake1jZAUWlMGb4+Hht9SJgWIqukX3dLHhKSQWptn72TSV6RMCnDANKpIa8lWJgWvO9nmlfnTpcqk4Ds0NRHfGVehTApwVmF01trWTUgKJpbnbZpSm1KVScHc3THI/krmhJAU2oMKnS4a3b6uTAqmFtbOPoP3/ZRJoTgvuLK2PmtSSAqJVemm9dV5I0JSgEPo9cqSsjtCUoDAXQZHEz9lUrgTOX4/uLfMVUgKFZNB5TdLS28LSeGBTbbV3Zjxq8qkAB24YeTEuamZScHmcv6wkBT87hY7OzjWdghJoaEl+UFki+91ZVIoG/bNLbtVPCAkhdiMmpj64sEaZVKAJGF3O7T8ipAU4HyvLPpuSokyKcTUdlen3wqJVSaF6o6I5us3LKyEpGAX45NTknEnXZkUpp3zHYIn7yQok0JhYUhjb2DtRSEpuMQYmYwmWg8ISeGCnWtcWqaVmZAUzN3sA/p9rxYqk0J4SVPagF9AvjIp3Mizciorr2oWkgKkQI+SsopWISm0+GWZed8uNheSQov/DYvY+67dQlIo7bQPc3O/PiokhY64Us+L5pNlyqRw/2xdamr/xXYhKUBcimgNK3EXkkJyXZFrbq7zRSEp3DdpTHZxzZ4SkkLb1byLsYOXmvik4D11ry2+K7lMSAqNYxYOtTljVUJSSK+O7UkYdurik8KFi+4JUdNGo3xS6AvuqAobHgsRkoKZoW9RyHRzipAUrC0Cb054+6cLSaGwIKw+r94vUUgKd66NjjTerL7LJ4WicrfE9qBGBz4pQAoz9Ib9lU8KDa0p45fsKlv4pDDtkuPYl9AXLSSF3msNiXdSWpOFpHAj/4KLqbtnCpcUuIsRQ3ERGT4dDnXv8Emh+Xxow7miq04f8EHBNbc8yyoyPBhHkykpDBh5FLaOh5iq8UHB4uJAul3AbVPhWsQ905HwdE+zARpsxCdCOmzSBy1KSnfzQcHwTmpl97mIqHghKNg33H/Q0x/jNSpcjehyaXSPzu+aahSSwgPX8rSBkcJW7DYpKtSk5Lc/uOP6gK7T4hM9+YPni/y7vfGYRlmht9jnfp3fvXAc9KawcCGwqdjBttjsKB8WLDPqbwZaZ5To8WHhWkh6TkqL7V0adqUvqSW4ugTnDfXYneHTwmDHlYo+35Kz/+DDgknFeFdNsNUNhg8LcanhZhNhFZE4/khpofXujeF4Y69o7FMoLlQ4B96ecDHMP8inBaursZFVZt0jGcIViQse0VMjWZM5i/i0kBdud366IWY4XfgGQ7hnhkmD0XD5Fj4txI4meN2piEz/hU8L4QGXoi2rnUq38Wkh7Lad1cX2HPd1fFiouDhR0evsXv0EHxZqYt2q7tYUxuYJYeG826BVi+uY+XFhYKGuMqq3+MF0kHA5wrC3q+1CZbs/diGUFsz6nToL3G6E9AppoffGQGZKX++VT/iwkBgZmTUR5W6pzYeFwFsVUzE3M+3e5rNCiuHwWG6fT0encDmioffOQNnU3WYj4XLEdf+oKOsrod02QlhwcZou8yuwTa0QwkJbYldzWbpPUolwOcLPtaGycKjv5vN8WCg2vhlrnhWbjL0GpYWprKD2jnajLhz6577Rd8U34XJclOtHfFyorhm6dv9Km5MOHxcyne5lB7Vaj2JvS3FhzLbU0tOkZggvBVBe6A4pqLe7FhqHMYkCQ7evU7fB2GgTXsunwJDfMNod4WZZ8ykfGGzb2kZqr3bYv8IHhsmcgKDzqcX1f+HzQlpmnauhj0mriXA5Ii8+O97KqzniupAXOkZjYxNDku7htUAKDMNllrU5twZsX+fzQtbIZe/MG42TdMkan3AvNIkrjk2OxxxFgSEx8c5Qg3t1Kh6fKTGcvZaZmtWZnoA9HkWG+NLaHqP4IOv5fGSwcPbpL7k/kHWSDw0ZaRbN5a1ZVnP50GA+VGTfUGde8gKfGTov1Zffvm5qtpzPDMXmxqYB9ud78Ys5lBnKrib4373WVriMjwwRxYFN/c0Pgi8KkaE0sKc2MNvCZQ2fGNoiU0tsMgNDO4QLEm5BiT2DPkWXtvOJYXw6saPPvOj6bD4xhDRn9Tfnul7tFvGJwdjKqMAgcNxhMx8YcpNtMmfzYSHbvt/CedLYEztKSgtxMbfjUm4ltj0QLkYkn/U8ZzHQkqnKp4WCc4Z5o0V3uu8IFyN8hlsLBiLHGj7k40JfgItHf+rZgCYhLkS7lJnXeDi2DIj4vBBqMdrZExA2iBGGAsMDB9OB8uz0XjySUmIwTbkx6GBuEpssJIb4aus6j/uJ4XiPWIoMkdeDQ0p6E+gslyLDFf94T+Ooygd49Ykyg3tmqdnV0tTyl/jIUNh5N/RmbZ+RCp8Y+m+7X3avGI3CLyRQZAiOS7oZYNeT5C9EhoRKK5u0fA+L7/jEcM/ay9A7prPxET4xOI84xFTbX3d5TxhbKAlxTEzrr93IJ4Yqt6nLobe7gvCciRKDt1/xraC6NF+8RkqJ4VzNA78Hl6aSy4TLEdUlkPy9p70wUlJmcE0zHSq+Z27yM58ZmvpsLfJL7kYnnuFDQ1N7ZkJNR2MgnU3QAIb9pJtfuKXVIT40tFcb5RbcvHBnUnk9wv3syLhXcOb3fGq4O9GUV5NYV/c4nxq67MxDz4W5ZevzqSGwzLA48/y9NrxOi7HhqumYb3LC+QS8gI2x4e796PHgxEuBDnxsuJOa7Fpi55+7gIsN3mHOXR1xU514rMbcYNSV7BRilOSHpwqYGy5fCQizdw2frudzQ25U1v3JbtP6R7nYYGBWGXFvICAimr8eMRHmOD00FunwMhcbbHsiblSbXDT8gYsNlUMZhi0pLon4ZSzMDcERnXcS7qUN5POXI/KrrtZfTCktWsXlhozchpLmsqqLu7jYUFJUZh0dVOMxRbEhhGJDXbWn14h5aAf2Q5QbTBz7LNpvFnT1CyMMbZ0t7U7VDpHYZ1NwMCxpN4u9ZGzyOR8cckc9aqojM6bThRGGjMRqm6rMuAZNPjhE5TQ1G3UENGMMoOQw2Bh0Iedybd1zfHDoG5vwi3CxTcLsQcGhsK+4LDR6OuyWEByKAptyRtoH4vHrVxQcGn0HC6KLJoKe53PD7XiTPrdh2+Rn+NjgX5Yw7BZffWsbnxrs++/cy7mTed/sjHA9IvRuW45nhwsGDcoNNz183Adz7scIfxB2paiw0ncsJhc3XooNQxe63B07rk9u4GNDV/J9nxLTVgNcs5Qb3KtNbrgEOXlPC7kh2nvc9UKps98yPjYUVkT6ZcH+8QUfG2661Nw9PzzuWSjEhhLn6cGmlDaLjXxsuGyWWeXjMu7xI58aYPc5ZzHe6bSATw1JV0oTvWGF4hgCxQb7fGfT1oIO5xf42BCZe+NBQpu1wyY+NpilBPkXWnfbf8jHBpfiu4U97S6+b/GpofiBabpBctbIV3xq6A1yDmufSK2cEoYYYqMjrT0Gy9MDhNQwWhBVZOB9KVafDw2tcV4jMSNFPZhVKTU41Xg1lFubnsd+h2LD1aDU/CCvXBo4pdxgkDg5lGg3FDUh5IaRwbiB+/fD03/gY8NF51pHS8eYnG/41FCQGnirM8c9SDnEcOV+uGeonVuAFh8aLvR1xdyLveuO3SelBsO7LpPdtxrK1vKhwcvh2lCqYXTBdj4zFNldSXJ1LupW8JkhZKzmutU9S9vjfGawfODtO3EuCb85TJEhJ+BGSXHImLcOnxgutYc2X3Ksq3ASEkOZZUxTSlJ/4jt8YAhLGoatI6T3FB8YIu8lWtlG5bXSQAs+4WPv0F11J7TzJz4xeIUWGdhfj7gu5wNDXWvdzbr+y57awvjCrVtZl9zi6/DLA5QXAk2vRt0Zzbn9KZ8XEgtsg7r7pq5u5eOCn1HddE2Da2iSML5Qfy2h0aK7sfRXPi04tTQGjXrUV2HKpbQQ0BOT61J5zvxVPi1Y2cVV9496PbgqXI1oyup3GmoxNVvBx4UEf/MqDzg0HuLjQn5vZ55fhsmFhXxcyDI0njAxGQn3EoYYIE44XLnXbuUmDDHcsQ8/Pz5RmTubjwsVMT2WPVlWdrv4uFDjHuDRbu8YtZpPC5X1wwO2/hYuj/NpYfrKUKO1f/M5HPGiuNDvNdoUVHQppkiIC6aGfperJ82dcoQBBrfzLcWafF4wHz2bPJRuk3KYjwu3Il2zW6/cS/qETwvXSzJ6EiNajHHgiOJChoFvW/e14WsjQlwYj2j3sEsOu4ujOJQXKqxsRm8a0Xe+KC5YBWaF3LIYiJPyaeHsxNBwbdUDvxtCWjDPGM3t84jNf5YPC+6tDyINrt9qwK8mUVjoPheaM3jlsgd9J5y+7uhwyeZafXIZnvtTWCivK+ms6Uoq9RDCQktH5lQzHETNhQGGoWjHu92xhsYf8GnhnnXBubAkZ38c9KW04OoWXHc9pqHcTkgLXabnS4JLsobrhAGGc6Vpto1Dfbb4DQjKC+PmLhVmzYWdmMwpMATczE/2iOtxFAtfeUy1TI2Odri2js8L5XeuXvFqsov/ko8L3UnlSeen3QLp+000QmCTnlGZWpq9hE8LHsZjdyLOXp/AgWSKCyM5WWd779fWmwhx4ZpXvclwn31ElhAX8lKsM2xv3LhnJAwyhKT7xPhlD6ZEneHzQvH18ehbDl6ji/i8kBJ+u+5SaO/Fz/i8YHa2qvxSsfcYDcPSnzqk+ecZxUdm43doMDAYT5b12plcCXmPywtjtZP1bvkVeR9zcaHR7UJcUXmbOcYHzAuOVtGuztND0Xu4uHC/wfKSgVs5/ukypoULFY7XzaqnQ/dzYaG2ZawoKqPXDtMopoXMrsuGcRXVtdZ8WqjKT21Jrclr4QcZhi97W+bmpo09yqWFsWaDwMrC+punubBQPXStPqFp1BezOIaFuzd7a+oC7lif4MKCT1jH5e67ZlcwpWJa8O/MNqoMsUktn3E5otumtPWsY5RnlZAW8kfOegf4RcfhqCKlhaiIQZ9e2LsUfFgIGMy0SDM06rsmXI9IKgkpqGie9nAQxhmuhOS4hLiGmT7KpwWf/nDPaxX9YWlCWghzOOdqfTXU/SU+LSSnlI/eOO+W8QkfFkb8A/vqpquuKv9Gwj68bXBitK5Rj88KZfHtY1dTKt0HhUEG1+uxYVM5bm4RwgWJwCuJqXbdPc57+bQwei3eJ6JlLPMZPixURUdaXhqyStbks4JNWXHE7YRbZtnCGMOd9q6uiFs+gX/hs8K5+8Xesc0pjpiJKCw0JNiVFEWkhQoXJEKMWidLC0pTS4WsUJp8ISzYqtwKuxYKC0FO5V1tdg/6JHxYKOyOjpqcLPHDnoTCQuF0vIHDvSzH03xYMGkJvp6bXDL+sfCnfukZZyeHCsz/xoeFoo765mBYn6/xWcHXKsMzMMqqCfM79zcSrUkO49l5lx/hw4J7ZXmRQYz5XTwuU1q4c7+94UGh3Z3tfFpIyvAYrWtJHPyGTwsOhY6Dw41BqV/xYcG55lzceKdHhbMQFvyc/dMc813GFgvXI0oTXWLuDCcnCtcjQmz7cs2MM8sxzVNYqGz07SlrG6yZy2eF+pSxs+cTnFPohJKuP9SNJqRWN42FCWMMVj0WboYuPkNxwhhDY0VPW7e1Q8QNIS0kVNv0mVlXmGLf5sGFGpeB6vvWZalCXLhfmHIhICzQ/nM+LuQ5jTnmOfu5yPi4kHvBbdjEf7jpXT4uTIY0t0feyBnBjEx5YaLUrv28W4DV83xesPfuyLaftLVtE4YYrlWFXzMM7SqIFwKDqUu4o8PolY4DfGBwSwwZ87o08kCXDwwP6m/YXLw9EIM9NAWGPPNh78iqqIln+cDg6G9g13LZE//kkQJDRGhwe3KBzXkcWqfE0NPj1Vrs2htxgg8MGZdq8yPbInv38IGh/bxDw7XpS85TwgCDYe90lNcFx4Yf+MDgVN/XM9nXMYWnvpQYBsIcCkp7g/1U+cAQc6stq8v4bBuOm1BiMHMN6Qgvnbj5Ch8YvEYtjVIyMi8s4gPDxazgEgv7/nvr+MAwEuV7ywci7lI+MKQZpgQVJF4ulfN5AfJETPygabGtkBfML2cmD3e1nfuazwu3Ym743TY1u7KBzwvW9jb5hRa1NhiQKTCEjo8G1GcVXtzJB4YLY33Tjf3GwTv4vGCbFH4W8oT/PSEvGFy0jIrLsvT5jo8LJgP+w8JfKE5cdyvzON84NJuPC05FrTVn4xt6jvFxoaN52MF+wDDTQogLaXaRbvbjNYmYdyguXL5mU9NYdT//MJ8WTM8neVuZV08v4NNChdfNnpy4OqN6YXAhJSHxalPSRW8cOqC44DnRVlV967abivC3EUlOo51X+wKEP5g3dmxrvmBpU3mcDwvmFjlmvW6dCUf5rBDaMeQa3RBQQiOwdDHCa9qmHY6gv/FRoTY/2q8xLb0r5QyfFc61Zzi6F5aXfMlHhWKH0GbbvsDcKCEqGJt0VLZ7JYbiMZeiQkBTTuroyPkaXyEqZFl11Y20D5Wp8Unh7m23tLLhfMPdfFJwv9E4Vp3n5BAsXIwYvHnBKTnXdYDlo0JDoFtbTHHFrZ/4pABnmNdvdrRa/sonBcPJQQNzz5S8J/mg0NZilBrbE3o9VAgKpaFGLtfyqr2mhaDQmN1c4uMdn76Jzwkedi6tXfHutbv4mNA8WTtpYjthgeGXckJ1ZOjgxZHcXhz34f404m5gfodtMl0apaDQdNE391KNt9erXE5ICYx1yrlws3M1lxN6pkKr/IN8e/ACAwYF63Ou076RWXmufFC44XwhOX7MufVnLiiEmVoEZAd6mH3PBYUui+ahptSq/he4oBCR7F/mG5t7bRmXE6ILDHx8QnqyvfmccNt9IPWq78Dd17mcUGB7I8rfY9IOkz73vYWKsr7Q4HE8GcGkMFYTmhU9UZYr5oJC8EhIw83B7NEs/nKEY5Sl31jmhfwG/nLEkHd6gnNOY+BtSgrhlBRyOgZaTGtLgrEX5r65cN22rs7Z9dZmPihUx9v551u2Nd0UrkfEt5xNM08fqdHkg0LAJb+WwdSSPOHmMZfarHrTHTPsvuRzQlx1iafXqKMH/dUYPpFnXDTtXGXdQt/6pTGCwKS7xsXNPZnC9YjyitiU6buXKn/lg8Lle/F+Eb19Lq/wQcH12uSl+q7i+JN8Tmi+5XjH07q4Olu4GFFU7547FlPYhuOdFBRqXMvSUpO93ObxQcHZyTRx8oabKfbSlBQ671QblfZken/IB4Vr0ffj/MrsOz8R/u7/TmliXGO+h4zPCVfMAkYcPKqcLc/wQSF9YKqppaMx4LYQFNyyq6wKO00zu4SLEQWB0WV5E7FGGNwoKQw71/ZGBVysPs4HhWnXtK6JFotx7DUpKQw4NPvYdCT3fMsnBdh5TOP9znbjKTpFhaHe/vuZsEKNhHGFG80lIbWm4dfwzI2yQkRDnm+Qb4Ht83xW6Ar0H3XKik7BdMJ90/FaX1Jo7jWXPuGChKdNUvt4ffzQAj4stOfHBwdWup/is0K3cV1lvE1QIX6vmLJC4cWmay1WyZY1wuWIjnqr1vjxhvqn+ayQM9LWZt7SmbKYjwp9ZTGBkw9CzB7lo4L7pFG3g1Vj7jt8UghNqi/2Dp+sepEPCsNnizvO2yXU/Y3PCV0xkVNZeZe9p4ScEJIzUmEcZJw7KlyLqC2MSxo/19l4VcgJfu7nQhrtzftwEImCguWkRaVleeHZ9/mgcM46Mc8+sb9jBx8UboZf6/RvS8zZwueEALNrbdYVN9y/53OC7ZC314Oc85GH+ZgQXF1z2SW6vQ5H4ygmWFXnR45eGBpQ8DHhyqXkgdzxpMt4FkY5IdQ7J3c67lbJE3xMODtuW+rjPOWGEYtywkUDs/hcm5zze/icUOUweMH7fOb1VuFKREzpQN+FkerRg3xOsOjxdYpyim19RPiSo3lfRIyFVeo/+JgwkupslmbRN3ZPuA7ROzJcFB+VGj4t5IQq8/bz0w7lxveFkYWr97yi843uVazkc8KFS1E5Hil3Wy2EkYUkU0NHL69si2V8UGhx6LhiFhfvgGe0lBRqy64mZGecxSsVlBQM3B1MXQsCbD7lkwIEiaGUjDs5LkJSsGxxKw+/lzGIpy4UFVy8y2uzMi84buejgnnO7SmDq+fL64QrEclJFcNd7bmu6/moMDkyPdKf7uCKgYqyglv1dYv0wovxtUJWMDN1NgkwLRt+ks8KoxfHPdT4rOBpHdiUHTZ27rpwKeLOQHeebV/MyKt8WAgfN78+ZdCSZSaEBTMna6Oh7ivFH/FZwSmnO7tz7PZQsjC0YNJQGmPQPW1eIlyKsL0b4+MQmh76Eh8W6usv9ZfnljZ8xWeFlHvBli2R1safCd9csJywrIq1P98rDC1Y3At3P5/TNdIkXIc422ZSlRNVf/80nxaiEoruedl3BJ3iw0Jp6nnvDDiK+gkDC7edy6t6PKIbpXxYGGzOT20KDBjoFC5DmFf1j05U+NTSURKfMBoKcim54pi+kA8L4W3umZGZ3RTGKSyYRvcGJyUEmujxYSHS2qNm6rZBMw6ZU1poDUueaC7Iq6wULkQEZxe4+RfFGeYLFyLsrSMHr3ZdnRgXLkTAOWbGUMSNKTykUWCYijewuux+20GNDwwR6Y3eMY6eVTisT4HBMb3QzDeklE6HKTC0hgeM1dc1XV7KB4bcwoizZdZXr7zFB4aUnhTzC1NZN88LgcGp6kH6fZ+ROIz83PcXKnztC9wakvDKAwWG3DIf4144SdjLBYaKW5ZDFneHbIb4KxGpVXfv3iwbDinjEwMc78aqvXyL7PjEYJXdEBVVODDxHpcYMs5WjNuVmIziUR4jQ1Z4QklDcFzTb1xkaLjYaX6rrcYK/4gaM4OfgV1Q+XBrKQ6qYmYoHm0MbRi9f3sdlxnOe9e21qWY+bFcZPAI6jxXXjkZM8xHhpCqlrLUzjbDA1xkSLwUdmvMe8ILv0yEkcHZ9GKyZ7Zv2ONcYij1uOLb11cRmU6JYVTCFfPVPaojXoPVVtYc4aoaixXiXaK1cjF9BcdAoifm71Esh1ewwgf3S1u4QyrefVmDbgDO1wGgu/ixXFktFosNYKFBsb7ipyMK8WmsbafK35tPwt3OG++vuhamTiWepfh4DX/fV4mML/aiUDtCtZ8A5ohEWmK6FzgWosBf3C0DdSTcDQupPhxOF28CC++T6uvIFPuOKKSnuZvqczM2wy/6g1800y+mu/zLNY7oSBXL8cbH1C50e9QnWNFuuRm2DRZs4G58SwuLxbvVFXS/au6W+1yrcDfTZelWw2J9rAquibf6nI33R9eke8/CFPBeuFQ6TQUrq4iFyiraNP3fJ6j5xwmK+AmqKCeowk8Qb7C7Wl3GTWk5/FLH1QezjJ8X6x/RmdG68GFsWlZoU7y1oRxa9RW+QgiWnoMFlOzliqBTi1HtPlWcEpa3XKZDLYh3e5/RiFQAiFsshe4R2NBEskIJg7U+5Iz2fuHuw39yT3mqucT8q3vKs/90T3nhlutylu4pz/xf3lMeHNwd5clGN6hW3k2epboo/8Hd5Jn/7G7yzJ/fTR4n/i9u5o73o6QbzDN0Y03l0rPc0rPKpedrKOpwsywsPfsf3GCe+eMN5pnfVwHeG50Rmhvep0MsPq3D/ut7pYsV0Ib6Ouy/u1c63Rge75XOPnSvdJxVmZuElZ5hfsMCCSxNY7GORPE4SPmbOmvrSHHLFimG8MbNLJUx+O3hG89ST6D48cisVWpihmGZP/tPQpVA67i6ICLtZdgXaWu+rCUBPczkalYT9uJ7+FsqU/Tib+zE4JeYKoie/FrBnlaonrrK/SWEyITbHP/5edlq2K+4KWK9MQZriWB/KnQDNNcq2AmIJayIkc14VnW3/Lw/VQyBV6UiCcOKsT7s7mn4z3qfMUx4EnZnnDrWN1I+T2a5Cbw8Lrys8vDLuvjyfeFl6cMvp4nw9TrhdYlMoauowjWp6q+D9RpPK2afgFbW2LtMLqLu+2UsVLX7zC80ldnG8Bgn872xCbf02Gi4KnH9rRbj9GjaDN3v/iG3Nv8ZGd7Pmz7xMlemRXaPZcTKWw3DZoF39ObugIwFBKgeg0ib5W4HzJUmwHvlax/Qwbqr//7+3uI/3N+bpcpWy7CIrOhNLE2o0Kaj1u83+RZxN/nWEQtTU/xyRJvV4e7br30QVy58Cg9mchZv7S/nbu2P+yZ1sUdPwJuUBRSwMAfVI6PyHUflktfpXswimJOj9Crfh+KNr3H63L+L+fvEi6iQk4S/LbecaqvRDZq1uJuP8xroRrFmLnSn0FtTBSluOnIx5AZ6KOYmKaHbOx+gWzzLmlhGiuWcZPqrJYpVMMurcOcU85FBwZzGbg12RugRfNNEdDyT6WMRiLfpVtZSvJ05y5VSYekewHgQY45ylWe4laZ8hV+tK9+UcBWdwRiBBQfhKboxtiJcUCw4vVoso3vfUyOzXCUJmDksL/rHuZnGG0xz9YOxjEvI75PQwZsK053q8V7qeDfgCCn98zoVxqFyzXw5Flgve/Bu0PBygB9MABIEbDOwnrgCLpiFGDy+4mqmW+ufoM5ylaLOmS+jCfOmmPgdIqSKbp7+0MJyaGH5n7Wwpcv/UguvmtHC0SrUwqu4FrZx+S9aWK5sYeXcPNTCli7/poWjVeiff9vClq7/eQvLZ7awfGYLR6v86xaeBy08789aONPrf6mF585oYReWWngu18I5Xv9FC89TtrBybh5q4Uyvf9PCLiz9829b2Mb7P2/heTNbeN7MFnZhlS1ciz0zVxiWXcOnP64LnBZRjRc8txALyX8PJQrIC9rr6ZXVYq5KJHOE6rCv5apCMru4qpAsRiFNLBqqMNCmW90r+HvL45I+j0VJTu4SzSaae1rRj6RJpHJa+fIsekLz9ye0FHiY+MMklO9p+peT0FJwW8CMT2gJ70mjTyhYus84t5BcuVsJJaOjcr7aHd5vfz2V96Zl5pcUcxy88ORSrvIF13+zVI9SIZKV/qFt/08bde9DjfoftMhDbfg/WQ3/nzeqLIWloMmdM0uglR+n+k2i3TZFovfpYI1VVfE+9gr1I1gFCuvTykXcGwx0VPHXb7/6w8kk3qte0SY6pgMJTl8Rag771UaR6GmRiOrnUo3ENtG3R7n3PfQOFia6mv74JL1q44dy1dUirpSOP+haRa9RZ/Abdgw4F0foeSxhLBjFfzpl6icUGm89PAXxQ+/jNoQzM9+gewQ2ENztsaQoFg7hGgCiG3tUWwMQz/xkgfwmacBQ8/0eybiaSdonuFEE+QnsWrZh9NeWr6bipFzPgIVXqOoLhKFp0REuXIowU4opU4pxLmjWqCD9bDxFkZym9sHiTgxWrDLE+lYwoyGwPAqWmxa8grWRYE5XizTFf27T+1+XUfTcJhL+leCrdIrswx17dnPXfuB0SUQnVXu1REsVq3BXnp5WfR0rGMAmj2fsp7+GzHcaOl88oOgfVTx3RIbFV5ZzR4SoOjzrgn3+92oyigD+/EvKnX+pnOCGQbDiHav9Gh4FIFxirUcstoDRGD+AWyadlNGIAjUWfpqqSeG4yIrTCskJPO3GwQgR5loW6wPpY6kIHX6CuLHSoBFf0YzOO9W4806hvpmcTmQXz8aiLiL+lFOMYxcS4c34UVkM10pUFIgvWYpFfKhPg9SuTaUpaVRCR7yMO9OlUnlc5sd5YOkDOnQ6yQhnvpiHYRmwwCEV1aO5Z3BSYm6pqOPTxhbFSkxYBInlSjGJ+IJzEq64NFe3SLkGdHC8hDucayvmU1k+uWjPMn7YjeFORViBRNTiwnqCE3CcFp2A0+iGBJuDzm5ZrnoujhXRhxfLlCU5aQwM51wu4ocQ8GXZcUbrjKJCKKODJcSo2tn0KhxGwApHVHFNBbd+bD01PAGnesq0q1Je4Kqr4XmpUAAKm4Cmg2U0VtE/VJpHribzZ1mJsuPEIz2OvVGFdpws6PtN02in40pM8VshbvyweQgv6ki5EQCWKuTOh99rsevRxqE4PTHuivIjNJYlsFxMjxbTwKQiBKYCjxYf0V60lCrnUK8O091OxYfYJ8RqelxfL6d8ww2N0KqAheXKz0ueoHE+HCHjnmBwqAgL16mAR6GpkGi/o8Nge+LDfVy96CfEi/VYbqiSdjCujn3dOSwBK5Hz41Ys10NmMQzDH7RnQTPNknMFqCVyqtaFhXTlGnKZXFOupS2Wa8GGhYXYsbaOjHusgY9VuccqVHOH6grCg1k07AktDg8ksHyzaOunuLlalS9Sqo+tDr+ogrZCslqVag/j57mdAsviSrTfxGqa0D7cAtIYDC6GKi7GLO5AGf37YmjCYmj+68XQZv7r2dYUZluTm23N/5PZ1uRm25w/Psm5ozuzWqrwwF3Qg+8qVSgVwY6t6ODrAjJYbbNbgCNcmT18UUFDYfQSlY3ih2tEr2JNvddP4pxgh7ac6/YWK+yVlQPPvAF7vj0NgGG2xpqNhApXYcBMckZxuZYvmkVDt1w9OAanKcHNWKyNPQxtbfPhaWHiWE4ZDldcUWIGTuplItnv4ZvlxtRohE1NJItjuLbgkg43PVqL2iDBPU/CDXtzew9otfXEcspMOGysTdXb9WnMEdaiRFiLElyLVLYUIii3FlkuTuBBhD5DZYRhLbJy6R/WohRrMsNahG4GGlJYi2JuLVI9UT5l3GQYCaWMtUd1VCj5iumig5zdSyWOxVgMjQo9YtHqKAP+xARrEfkIwNDWCB/CGVenqnVq9IQav8hqOJQOM6I9D2v8qR3Rnk/Fo49oL8Df0MksxN/QELO4QWLl1k/v5t7LvZN/n1Suyg3JqMp/d9AveH0tLlcELhc7s8ijhC/xSCc1il+oNpOcq9pN2VVHSpcsuC1JqO7IMass7sixWFnbEVmFatPpiPkSR3QQUVZ3PcJXmmVnTJxOGqnerPJSDKwdWQhDI284zocdoOoJqqsEs6zKnTv8dIKuBNEsw3F5F1V8Uj0iPFJRPpIqH0mUj8TKR6zyEcM/ktCBQFgAajGac+66j/LNOKBKQRbfJrvKMCy/+4u5MybM8DDHe+lCEne1CD6mj6NzuOO+vgz2V9yI1PC0GT9AG9jiIwruOhruUXQZh1aoNtdd4SMqg0XHLu1NdEygjoTmmT9q0wUWHMLjN/MnWIkeXaqRw6YwW6b9N5Eslt8eIBcyOKI5oLGaxX8kYuX4BG4JCom+oi6YO/PXkcoZvuobntOzQn1mXHM4birGwQn49Tqdz+PxVzhAzZ7JilRYhTi+vHSvQqovx4sS9AbuoCrRh0bq4I3ChYsmZDwc41eV+HaemQUoWUhe5q4B0ED3Q0f/f3HsZx469jMzj/3Mnx77xQ8d+8W/H/tnHpbFMw7LNozyXJo6Q2VP+P9HJ0eHKitsQzioCOPaZxQMXu/BMmWQylj+MixLl8kw9GIRPwWOyGMNdBqaxw1Ygeo3qSAxHHQYvr6n9jsyypTcMRKHgWjEGLcDusohE1qHVjgd6Oz5VarNXUvm4yeF8jN8YT/afbCeKG5pWixeUVRkwnqhoWOu/KkIT9EleLkYHsuwLrecVoaYah9Cw+XA2qGLRNDia0X83sS/DV+P+uPrx7kDgZy2M/7yEJ5UrqbeSKjDjReMoCmW85GOpXNYDNfsbobbBEfpCTpFgSXWXsR3Bzgl3G9Y2inlYtm3XOoRLqhTg/0+cyBguUdPHVHMoke7WRSIFf1mnECMz6+Ri2k/pyvt2NBr6QnJEe4KPG682i+KZN8rZQztRAYMP5LPRVbBxihtDGeDs1FLzsZwNjxB5WwM7YsoZHDe5VzQdYWTVxT+j5euwPLPl4730XLOXLr/sczA6r8v+4iTyYUgxPU3Euox/tjLaP/LXgYPARJlL4NbCL9NyN6C3ULOX+bkRqLEypEo0VE+kdBVGX4kCjdqGrCitMUNQ/HXZhQi2W7YoBViPNTgURMLGvJ1DSXcKS/DfSdi5oGNG2WGPejoaimcocpexyh6hq+3C+eiCkaoAK8sgosFdLWYpXhSCx2KTCiUixnmj4VyX1G2G/cFkvn8LiTmdyFtWC2MHq04hvv+gUhYE4uP8F0vtv9L2Ep0gsnyJ38Ktd9PpdR+P5XitnC+7+n+wxmUbCfMDK6s0y+IaEBGxA8j0KL+guMiZ/boMMu46+e0LuHIheensm+UnTvDDUJrcufE2DspnlTYn+MLN3IDpzNe8RFe4UYOuJ1POYe/fxkCeyTtzSLZOhoTUPzyOnYgdADAtoVnft0LxwRYXu4rK7Mhl+vA0nDdpvZSHS4+aR/U/oCCmEj7KVDC1LjxBaxACcdgBXuSb40jmN240XT6woBItlV5OvZPSR8aIsQsjd/cudWGm+PzItlK0e/99sv8dzP4OYTDHcwBN4d4gGG4r9qoCW/FUzfu84zilze5LwLAkY2FI5uITjsgG67mgoH4z+f6EZxVVlkQVz7jCxDwdm6tL8G1TqMg+JUSRiocemSyuVidfgnuFKePwr+r9iyTLRT9y2loizj/Gf4Sq0xTJIR2bYiFIop1tMyzcSkh/+EGJDp6UqbGfXljqWzev9ggsUnWiyQK9gTMAkq4Yxy1mLaaTIObiFx0AhX0TvGJl3k31ywyvtXpI0dkc0SKyzi8OkcRcp7b8GZxry8Vv6ApglbF6fDDpvT4ERH0QY+IQMVtJz+dUD5khIeaf3h4WraE89vwJ5si7B+WwUmkSKbKNRP/+yj9hlXO/f7pCMzs7qEHbu293kEPpkSwHLvjnXwdLmdYtv4DX7rsVXq/rKeg2+KMTCxi6EcWksX0a2Cl3zSRrPlJiYj779TJAxu/PfzlhpOnRCI1kbZIA55zhB910e/sDz9zZnAA/KyYwYnwM/chfmwGJz30OvK6GZz80OvJD72e8tDrKQ+9foOfJ4FzHuK8h7jgIa6Cn8AZXPMQ33qI63k+8OUB5XPYHo/OeM9q/ucQvOmrL39/fv1D79vMv+84vEl4bgf/ngP6J5XPKeDnkRmfewV+Ns7gTx6a7mcP8bcP8bGH+KeH2PAh3zneh9vLD/qHvz0FW8wOVlukCc/Ngp8ncb5nsNYM1ua3H4GxrRbO4Mce+vxjD33+hYc+v/sh3vPQ+1+Fn00zeC/8zJvBrz3Erz/Er/LTE/YRln+MP2L+txTXz/5vvz10UL7v7e/1Dx8/tmvXD8d+PLn/+7VP7JMfPybfL9/31vFjh/bJT+//9odDIoafFuxrJ/ef/HnjKf2DG7Etv99/7PCBo4ePfQ3t+ZNYW7QcHSBcheuEZwnwSvitwrcl+lVp3XDzpsY/h/ssrisZ/Gjyz2k+9B6tGfMAs3/8AM3Fyf0/fnH60AGYhwP7v99/4LD+z/Ljpw+d/Orb4z/C+yf4+djIcsv9z58/pX8SPvuMRFu0GF63g4V9Bn4LfBFYF35v2CAS6cNz7H+z7ebxbYdtOB9+Dh87eOgn+fEf9OXHv5J/efyHYwdP7ZLrf3NI/u2hY/LDp+TyL+ElZO6N8Az0ORI88IhEdfAb+5J9wkIcOH7yEC3DV9/pbzz2w3ewHIxUW7QM3nOI9+n+pKuru0l3s+4W3a2623S36+rp7tDduUl306ZNmzdt2bR107ZN2zfpbdqxaedm3c2bNm/evGXz1s3bNm/frLd5x+adW3S3bNqyecuWLVu3bNuyfYvelh1bdm7V3bpp6+atW7Zu3bpt6/atelt3bN25TXfbpm2bt23ZtnXbtm3bt+lt27Ft53bd7Zu2b96+ZfvW7du2b9+ut33H9p16unqb9DbrbdHbqrdNb7uent4OvZ07dHds2rF5x5YdW3ds27F9h96OHTt27oRZ3An6nTDpnfCxnfDUyf3Hvj4kP6W//6Q+3zhCM3KvfHX8pPzUt4cPHMKnoDW/1v8GGm1YyrWZmoo2HKNEIu69h44d5KfxDDyvPeN1bgrca+Q6Jd+vz60V+BCB6FN4L/YBJ+H3zG1SuTpgk9oIq//wwf24gWB3I7KB92KfhAdI3P8/2bBhw2df/qwvuHBN/2GrwEf7RKJc+BzuExW8UwTLg9vSl4e+PnxM/syztChr8cET8h+/gW0IFwB2SfysRFWb+rO58Bv3n4Wq3LIK00DnseP6sMke+Gb/Sc4Ly/G0/LA+vnT42KnDBw/J5WtxNk/B9HGOhPl5FaaFfd0x+I3752n4rTJj2v/UJj9AX3H84KGN3588fEx//5ffHqLDtsgHPvcov+8vn8FL4Wc77ThiqVRFRayqoqamPkdjuWyJ5nwt7VlasyXa4rlz56kvZBZJF7NLxEtVl7MrmFUL5eInxU+x62UbGF3xJnYz488GskGSYLUxdlw6KZkST6tf/elnCysv3b/+zcLSZvmK+lmzX39jfGLDxuc//eyL6BYTqwu2doERCYlZN3LzGtpuT4skc+Y+sWmr3q6nn9372mcmF+DFqITEG3lFxW23RRKtWfTqrqdf3rP3tc8PHjKxdbmSW1SsNeeJl/f+9eAhK9tAeHNWbmPb7X6tOS/vPXjIwCQyKSW1sqp/wPishY9fSmpWdlFx7a1XLycX3igq3vvW23/96PMvzC5YR8TEpqbfyK6as3DRJ58Oj0xNG2h9d6KhcdbsVceOL1/xxa+/hYYlJo0tXLRy1Z5X3nr7bx9/+vlvZ6KzKirr+gfunzxlrf/DpY1fPLZho39YbGp2cVWj8wuOl3WtV6WlF02/9fbHn6iqzdZ+fGNv37Hjes8+v/tlG9up6fe+/iEnt6S0uubO1LRI/oWOUaPE6CW1ZRKVOYYhswyCt62VGTSJl6gxko2SrRJVMaOqojpH453Zc1U/UBVLlmuoi9XEqmJWLBZrSqRimQoza4F0l3iOqopUW/WvqqzqAq13JC+K14sZyRyV2Zq7JCse/UL+neTIowY5UqNw8VIVo0nxR6oLZIvU52vO1zyioqGyVOUj1SelezSekmhKGPEm2VOSpSoysUEIvLT6+Tck68X6as+IZ4ufUd2h9qTUaHrOYrWNc9aL5dqrZxtYSowcl8gWnLeXbpQ+rcrOWqxucG29vqbBzaWaUoNpqUGjZq+W2MRKrKdu+Ol8gzg1g/x1O8QaKjvU9qhpqujLVoo/lnykbmC8eLnGQvU3JAbmKsE+moskmzwlhrWPqWpKpQa+cwy/0/5p7ToVeNVKYpAiXiaerSVSYRhYPBY2U1ZDKmNnSbSZOexc6bw585kF7CJ2idZy6Qq1NcwR8VE2iS1lKzQr1W+yVWwt0yRtZjvYXnm/ZJR9wMKGymg+/vRzb71t7ebmrqKqvv3Z5z4cLCmVzF+8Xe/Dv54JCg1L3tY095zZBTfl5odb31tvHzz0aUzssuWqahqy+Yu279wVEFhdo65nYxugqvH0c18dtrY7/kVv38dfOrs8vvaDKx6e3j7+AVcTkjJVZJoLVux6/uV3/fwLCj1UlyzVefS557NuSOSPPPrY2i07dr362hvvvPfBh7iN7Ttw6Kujp3769Yy5T1BYeFpJaNjPBceOX/xc5x9SMaySr8TMxg0GRivEm2Yvl6xRXyl9UvqSZNY6gyCVNZI1krVqW2WMgY2hnvpCDTWDyzvFB9TUdRdKV4uXSZkXdkhel26UaKiqq74gf1yiqb5dvEu6VFWiqfrOXr0tWltUN6hpGD72l7fWqq37cPn8RepvSVbOfnHWElUNlVfVHlf/Qfa8Yp3K01INlXdV4PgnlhpYfLnyVTUNA7/PdV6WaahozdupqrH9KYm2QdozB9/TfFVdY8/Ly15Ve2/FTkPVPRorxK/s1RPPUtNQgXcZbl9iEMvM3qxl7PLVDzKDTPMkk43WpUaveMYb7VRdJ2H3PaaxR2OtdJ5R+CeHXpfsVJ3zAq5qx1E1k5vr1L1GDFdtEs+RqBlamkmOSrXE6qraF/cZDGucUju2aI+B83zNv6ovMThn+Ir47O7ZC86+86RB5XrxUglr+P0uKXNWZFD1xBsSDQlrPOelN541uP6MCiP5QLpsK2s46ynJQc0PNQxCd6zQekqiDpu0ioGzcbWallhLrK/5kSrsMpIdMP9r1XTeMnxfc4FYLFVVX6Kqqi7WUn3CIP9RDROVP+2O+d9fwKFqP/bIW7S0RWv5XIm/Bf6riMu9Av8Ffp6Dn3AIRJ/N82DnLpKvTl61b/UvT+3bKNX12LLAQ75t1Mdju6Zf0/aV43I90VTT7klm/4uMxupX1mg1vxI8a//ejYs99z69ZPWHuss9Pxxcufpj3a3Nn/UfWf3528f3f34lafUXX5eu3ieq9dwnamrer9u8/0B3i+fBko7mg+ODqw8xotBD/cz0occZFUbGPMIwzCuMBL91z7CsKE3CShh1lmHGVYsZKbNpPYNf1Ad+ipGpaMgYeL/GAm3mkBqjwUhWw4srl30i2yWDzzKLFz8p/lFXD6ekBh2PqgarxcxmdjESiRozn10KU98JO7mEha6OWcmKGRmylAHffHYhdAG7UAPvVWVl7ErmWUaT2Qzdwk6xFPoGfG4FvQM2U3Y+u5zdyXLTXcG8ykCnynzMsKoy9S8ZVl2dYVReY5fRXxLozYKFYKUyZo0685UEPsyy7BJWItaWaMFDFZi/zxkI9+IV4hXsSvYFllFVY1iZGD60XrKVUbA6zGmxhFVnVMS3WBb8jCpOlVVT0WCZxxm1Oc9LdBl4M/y/Vl2TlcPCMeId8AaYIfEuNZZ9QeUvYkaLUUW1mN38vBaj8wUjFcGrMjFug1qsplSN1cAmlm6SQpvCm7W1tqwWsSfEv8Lc7ZNvZETMGgkcyZnPVD6DM3qICsyXEnzncsk77Cydt5hF4u9YNexAmSWslHFkl87VYh5TWyLbINZlsLnnQCe6WwUWnNVk1GD9yZgt4GdZKTT3MmYW/T4oVRf/DHMhAdtFiDpiRlNbBzgQFljYSjYy69kXmQUwDy2Mg1QkhlmWrBVLGF8WIhLrJAtnts/eId4ERhbmfLN4jZRRe47RZLeqw57JfCEGDbS4ByNWWwBrT8w8qiqWZqhhUy1i3of1D29ieyUq8O8y9gM1fPcRhj7CHBLDRiIVqTP4t1gwjzbgkDByjbUqLGwD6iqseAOsThHOKvOXhawqNuffVehPTGB9vYoKBk4/1EVwUFOXiJjn58CaxmlJRGJ1bVZ1pcReLNKTbFZjZjELpcxsmNIcmooUQqBEpPqdGpwAMSv3GfRjrPKAIOgxr0nFYLlcbeEqD7WYNWnqoqfk817w2zdPN3jfAtH4vkWrp+WLRRLPxQaaq5d4LG5e8sKx/UtFFZ5L/W42LxV1Gq7SHdivYz4QqaNYqrJ0I+5KjHQRs4rJgJ2lhFmjyq6U7mPWYUvCjklHR4aZz7zHWKj5MY+pPyF6DLc49klWLPqQVRGpq8KjF9JeELEq7GPwo8b/Gc0qdhsuP+xuEDRYFYkW7Emq+rSFHcK9HbYhsVSF/5MbRgWekUilEmaOHM5Et4o2wgo/qyIRs5pkE22Yu9LkK1jduqz6OtjKRU8yWqrviOCoLoJlEL2zDw5U0MVtgdP2F0QfQJcCWwHNvyaDe40Cj+/wezkuKnhVYYUsYZbAMv/xR030Ek5PZSl30m0skYtspfuwnxTNXeSxUlPetLLvKfmT63Q9njzut+8pNqDpqZXjTetFUx7b3KabtkE/uZ3R8Ny+Rmu/XvCs5h0bF6/eqbt8/57BlZ6v9R/Z/9bbxz3fgv7x7UOlzW+Lale/A/3ju9A/vgf94/vQP74vF234oJ9J/UD0PZxqK/u+V2W62OepQtvCNvMI1+dBP7MYukpYN1Lo92Yz8pk9H/ZV8GHcOhmN/6jvw50PGkisAX3f0/BZTfjk2n/R/7EoZJGxD/zdgf2ghDoeNeZd6As11bAvlP03esJ/7gfVmfXiH/9tH6iLPaDqn/d/l2f0fjd2Yc+XsVoktoJ+TaRyGPoNRkPOvoPXK/+093qceVHZd22c0XOtg82pl++xRNraOLzwX/ZLL/8XfRIu10Jm1ox+6V3qf1jaDu7C/Pz73uk+rIf/F/0T9E7QN0nexcewnBKpmtq/7ZtUqW9SFfH9Eo4V7hcpuoPTRDIpDpZ9KVJMELyIIy8ihWUIwksAB0UKV4KXabxDEUKAA2pfiRRJBDjS+LVIUUCAQ2PfiBR1BDiudhg8BDiodgQ8BJh8joLnKsIbeCoLHoI3Ab4DD8FbePILHoK3AY6Dh+AdgO/BQ/AuwAnwEGBoOgkegvdwtAE8oQjvA+iDh+ADgB/AQ/AhnlyDhwBT2I/gIfgbDrOBh+AjgJ/BQ/AxwN/BQ5AG0ASeMIR0gGbwEFwHaAEPQQZAK3gIMgHawEOQBXAbPAQ4Xt0OHoJsgDvgIcCR6w7whCPkAnSChwDHsLvAQ5APcBc8BDia3Q0egkKAHvAQFAH0goegGKAPPAQlAP3giUAoBRgAD0EZwD3wEJQDDIKHoAJgCDwElQD3wUNwE2AYPATVAKPgIcCR9AfgiUSoBRgDDwGOqY+Dh6AOBxnBQ4Cj65PgIWgAmBIp6gkYEewN4EGA/Q+OC0ATRLDzi+B1heU1JBUgOK4oXInUgNSBQog08OoKUBKRJoNXX8FGNAsIuj9FHZE2Q0NNim6iuUDz0Ec0H2gB+qKQFgItQh/RYqAl6CNaCrQMfUTLgVagj2gl0Cr0EcmBVqOPSAfoEfQRrQF6FH3RSI8BPY4+orVAT6CPaB3Qk+gjegpoPfqINgBtRB/SQl0Gd19oKTE3vLoZeAsa6b1bgbahMQZpO5AeGol2Au1CI9HTONyLRqJngZ5DI9HzQC+gkUgBtBuXkOhFoJfQR/Qy0B70xSK9AvQq+oheA3odfURvAL2JPqK3gN5GH9E7QO+ij+gvQO+hj+h9oA/QR/Qh0F/RF4f0N6CP0Ef0MdAn6CP6FOgz9BHBaYLoC/QR7QPajz6iL4EOoI/oINAh9BF9BfQ1+uKRvsG2Rx/REaCj6CP6Fug79BEdAzqOPqLvgU6gj+gk0Cn0EekD/YA+otNAP6IvAXedn4CmQYeg8jPA31FH9A+gX1BH9CvQb6gjMsQGZEFHZARkjDoiE6CzqCMyBXof3mmZiHQO6DwuHpEZ0IfwWgiROTY8/gE9kQWQJfqILgDV4+IRWeNKgXd2E9ngSgGaILJl6DqDwjIJCa8w7IPXXInscTWgj+gSNjX6iByBvgEqILoM5IQ+IldsavQRXQE6iT4id2xcXL5kJA8gT1w+Ii8gb2xPIh8gX2xPIj8gA/QR+QMFoI8oENsTfUQhQFexPYlCsQXRl4IUBhSOPqIIIHNcPqJIIAtcPqJrQFHYnkTRQDHoI4rFNkMfURxQPPqIkoCSsT1TkTKBbqCPKIunEKJsoFxcPqIcngqI8oAK0EeUz1M3USFQEfqIioFK0JeGVApUhj6icqAK9BFVAt1EH1EVUDX6iGqAatFHdAuoDn1EDUCN6CNqAmpGXzpSC1Ar+ojagG6jj6gd6A76iDqAOtFH1AV0F31E3UA96CPqBepDH9EA0CD6riPd48mVaAjoPvqIhoEc0Ec0ApSKPqJRoAfoIxoDGkcf0QTQJPqIpnAHB7LKwN2dhSMl7g4IEJrpkqIihAgvJkpxcyFSAVLFzZNIDUgdd3ciDSAZbi5EmizerwR0RLOAZuPmmYmkDTQHfURzgeahj2g+0AL0ES0EWoQ+osVAS9BHtBRoGfqIlgOtQB/RSrwkir4sJDmQGzYnkQ7QI+gjWgP0KPqIHgN6HH1Ea4GeQB/ROqAn0Uf0FNB69BFtYOlyp8LyBpIu0CZcPqLNQFvQR7QL6NBT4CN6GugZ9BE9C+SMq4/oOaCv4J3dRAqg3egjehHIADeXbKSXgMzQR/QykCX6iPYAvYLLR/Qq0F70Eb0G9DouH9EbQG/i8hG9BfQ2+oi+h2P+CTH4cug4AnQKyJXoNNCPQCFEZ4AGgZKITIFCgAqIzgNdBaojMgMKBeomMgcKA5ogsgQqQF8udfNAhegjsgYqQh+RLVAE+ojsgCLRR3QR6Br6iOB0SBSFPiIHoGj0EV0CikFfHnX6QLHoI7oMFIc+IiegePQROQMloI/IBSgRfUSuQEnoI7oClIw+IjegFPTl08ECKBV9RB5Aaegj8gRKRx+RN1AG+oh8gDLRR+QLlIU+Ij+gG+gj8gfKRl8BUgBQDvqIAoFy0UcUBJSHPqJgoHz0ERUDVaGPqBqoBn1EtUC30EdUB1SPvkLqWoEa0UfUBNSMPqIWoFb0EbUB3UYfUTvQHfQRdQB1oo+oC+gu+oi6gXrQV0RdK1Af+oiGhPVHNAI0ij6iB0D30Ec0BjSOPqJJoH+gj2gK6Bf0EU0D/QpkVYzdp0QkekcCOgQVBuhdoBAiFugvQElEYqD3gAqIJEDvA9UR4VQ+AOomUgH6EGiCSBXor0CWJdTtAv0NfUTqQB+hj0gD6GP0EcmAPkEfkSbQp+gj0gL6DH1Es4A+Rx/RbKAv0FdK3TWQLvqI5gBtQh/RXKDN6COaB7QFfUTzgbaij2gB0Db0ES0E2o4+okVAeugro24eaAf6iJYA7UQf0VKgXegjWgb0NPqIlkvwOyTgI1oB9Cz6iFYCPYc+olVAz6OvnA4PQC+gj2g1kAJ9RDpAu9FH9AjQi+gjWgP0EvqIHgV6GX1EjwHtQR/R40CvoK+CDitAr6KP6AmgvegjWgf0GvqI8Gt3r6OP6CmgN9BHtB7oTfQRbQB6C31EG4HeRl8l0j6g/egj+hLoAPqIDgIdQh/RV0Bfo4/oG6DD6CM6AnQUfUTfAn2HPqJjQMfRd5MOFkAn0Ed0EugU+oj0gX5AH9FpoB/RR/QT0M/oI/o70D/QR/QL0K/oI/oN6Az6qpAMgAzRR2QOZIE+IksgK/QRXQCyRh+RDZAt+ojsgC6ij8geyAF9RJeAHNFXTQcEICf0ETkDuaCPyBXoCvqI3IDc0UfkAeSJPiIvIG/0EfkA+aKPyA/IH3011F0DBaKPKAgoGH1EIUBX0UcUChSGPqJwoAj0EUUCXUMfURRQNPqIYoBi0VeLFAcUjz6iBKBE9BElASWjjygFKBV9RGlAxegjSge6jj6iDKBM9BFlAd1A3y2kbKAc9BHlAuWhjygfqAB9RIVARegjKgEqRR9RGVA5+ogqgCrRR3QTqAp9dXTgAqpBH1Et0C30EdUB1aOPqAGoEX1ETUDN6CNqAWpFH1Eb0G30EbUD3UFfPR24gDrRR9QFdBd9RN1APegj6gXqQx9RP9AA+ojuAQ2ij2gI6D76iIaBRtDXgDQK9AB9RGNA4+gjmgCaRB/RFNA0UCGSVApnAVLQ0UsskBiom0gCBK8rJohUgFSBLBvpcASkDuRKpAEkAwoh0gTSAkoimgU0G6iASBtoDvqI5gLNQx/RfKAF6CNaCLQIfU10eABagj6ipUDL0Ee0HGgF+ohWAq1CH5EcaDX6iHSAHkEf0RqgR9FH9BjQ4+hrpu4a6An0Ea0DehJ9RE8BrUcf0Qagjegj2gS0H31Em4G+RB/RFqAD6CPaCnQQfS1I24AOoY9oO9BX6CPSA/oafUQ7gL5BH9FOoMPoI9oFdAR9RE8DHUUf0TNA36KvFelZoO/QR/Qc0DH0ET0PdBx9RC8AfY8+IgXQCfQR7QY6iT6iF4FOoY/oJSB99LUhvQz0A/qI9gCdRh/RK0A/oo/oVaCf0Ee0F+hn9BG9BvR39BG9DvQP9BG9AfQL+m4jvQn0K/qI3gL6DX1EbwOdQR/RO0AG6CN6F8gQfUR/ATJCH9F7QMboI3ofyAR97UgfAJ1FH9GHQKboI8JLwKL14CPyBGKACoi8gFigOiJvIDFQN5EPkARogsgXSApkeYcOFkAqQK5E/kCqQCFEAUBq6CMKBFJHH1EQkAb6iIKBZOgjCgHSRB/RVSAt9HXQQQZoFvqIwoBmo48oHEgbfUQRQHPQRxQJNBd9RNeA5qGPKApoPvqIooEWoK+TDk5AC9FHFAu0CH1EcUCL0UcUD7QEfUQJQEvRR5QItAx9RElAy9FHlAy0An1ddFADWok+olSgVegjSgOSo48oHWg1+oiuA+mgjygD6BH0EWUCrUEfURbQo+i7i5QN9Dj6iAqB1qOPyGMe7HHB4CPyBDoBVEDkBXQSqI7IG+gUUDeRD5A+0ASRL9APQJbdtL0AnQZyJfIH+hEohCgA6Cf0EQUC/Yw+oiCgv6OPKBjoH+gjCgH6BX1EV4F+RV8PbS9Av6GPKAzoDPqIwoEM0EcUAWSIPqJIICP0EV0DMkYfURSQCfqIooHOoq+XthcgU/QRxQKdQx9RHNB59BHFA5mhjygByBx9RIlAFugjSgKyRB9RMpAV+vpoewG6gD6iVCBr9BGlAdmgjygdyBZ9RNeB7NBHlAF0EX1EmUD26CPKAnJAXz/SDaBL6CPKBnJEH1EO0GX0EeUCOaGPKA/IGX1E+UAu6CMqAHJFH1Eh0BX0DSAVAbmhj6gYyB19RCVAHugjKgXyRB9RGZAX+ojKgbzRR1QB5IM+okogX/TdQ7oJ5Ic+oiogf/QRVQMFoI+oBigQfUS1QEHoI7oFFIw+ojqgEPQR1QNdRd8gUgNQKPqIGoHC0EfUBBSOPqJmoAj0EbUARaKPqBXoGvqI2oCi0Ed0GygafUNI7UAx6CO6AxSLPqIOoDj0EXUCxaOPqAsoAX1Ed4ES0UfUDZSEPqIeoGT03UfqBUpBH1EfUCr6iPqBRueBj2gA6AFQAdE9oDGgOqJBoHGgbqIhoAmgCaL7QJNAlsNINstFohJt8BHZApUChRDZAZUBJRFdBCoHKiCyB6oAqiNyAKoE6ia6BHQTaILIEagKyHIE6TJQNfqInIBq0EfkDFSLPiIXoFvoI3IFqkMf0RWgevQRuQE1oI/IHagRfaPUJwM1oY/IE6gZfUReQC3oI/IGakUfkQ9QG/qIfIFuo4/ID6gdfUT+QHfQ94D6ZKAO9BEFAnWijygIqAt9RMFAd9FHFALUjT6iq0A96CMKBepFH1EYUB/6xqhPBupHH1EE0AD6iCKB7qGP6BrQIPqIooCG0EcUDXQffUQxQMPoI4oFGkHfOPXJQKPoI4oHeoA+ogSgMfQRJQNNoo8oBWgKfUSpQNNAPUjSlRCsVoKOXmKBxECWE0gSIHhd4UqkAqQKFEKkBqQOlESkASQDKiDSBNICqiOaBTQbqJtIG2gO+ojmAs1D3yTSfKAF6CNaCLQIfUSLgZagj2gp0DL0ES0HWoE+opVAq9BHJAdajT4iHaBH0DeFtAboUfQRPQb0OPqI1gI9gT6idUBPoo/oKaD16CPaALQRfUS6QJvQR7QZaAv6ppG2Am1DH9F2ID30Ee0A2ok+ol1AT6OP6BmgZ9FH9BzQ8+gjegFIgT6i3UAvos8gHegloJfRR7QH6BX0Eb0KtBd9RK8BvY4+ojeA3kQf0VtAb6OP6B2gd9FH9Beg99BniPQ+0AfoI/oQ6K/oI/ob0EfoI/oY6BP0EX0K9Bn6iD4H+gJ9RPuA9qOP6EugA+gzQjoIdAh9RF8BfY0+om+ADqOP6AjQUfQRfQv0HfqIjgEdRx/R90An0Ed0EugU+oyR9IF+QB/RaaAf0Uf0E9DP6CP6O9A/0Ef0C9Cv6CP6DegM+ogMgAzRR2QEZIw+EyQToLPoIzIFOoc+ovNAZugjMgeyQB+RJZAV+oguAFmjj8gGyBZ9RHZAF9F3FskeyAF9SFI3oEbQ0UseAJ6oI/IC8kYdkQ+QL+qI/ID8UUcUABSIOqIgoGDUmSKFAF1FHVEoUBguHlE4UAT6iCKBrqGPKAooGn1EMUCx6COKA4pHH1ECUCL6ziElASWjjygFKBV9RGlA6egjug6UgT6iTKAs9BHdAMpGH1EOUC76iPKA8tF3HqkAqBB9REVAxegjKgEqRR9RGVA5+ogqgCrRR3QTqAp9RNVANegjqgW6hT4zpDqgevQRNeDqQx9RE1Az+ohagFrRR9QGdBt9RO1Ad9BH1AHUiT6iLqC76DNH6gbqQR9RL1Af+oj6gQbQR3QPaBB9RENA99FHNAw0gj6iUaAH6CMaAxpHnwXSBNAk+oimgKbRR6SGf9W7CnxE6kAMUAGRBhALVEckAxIDdRNpAkmAJoi0gKRAlpZIs4BUgFyJZgOpAoUQLQbSRh/REqA56CNaCjQXfUTLgOahj2g50Hz0Ea0AWoA+K6S1QHL0ET0BtBp9ROuAdNBH9CTQI+gjegpoDfqI1gM9ij6iDUCPoY9oI9Dj6LuAtANIF31EO4E2oY9oF9Bm9BE9DbQFfUTPAG1FH9GzQNvQR/Qc0Hb0ET0PpIc+azrkAL2APqK9QAr0Eb0GtBt9RK8DvYg+ojeAXkIf0ZtAL6OP6COgd9FH9AnQe+izQfoM6AP0EX0B9Ff0EX0DtA99RIeB9qOP6AjQl+gjOgp0AH1E3wIdRB/Rd0CH0GdLBxKgr9BHdBzoa/QRnQcyQB+RGZAh+ojMgYzQR2QBZIw+IksgE/QRWQGdRZ8ddexApugjsgY6hz6iy0A26CNyArJFH5EzkB36iFyALqKPyBXIHn1EV4Ac0HcRyQ3oEvqI3IEc0UcUAOSBPqJAIE/0EQUBeaGPKBjIG31EIUA+6CO6CuSLPns6IgD5oY8oDMgffURxQOHoI4oHikAfUQLQ9+gjSgQ6gT6iJKBr/08Jdx1ddd3AcZwYpVhYGCija4MBo0u6u0vGNnIMZIR0g8pAOkfDRoxuGDliwOiG0aIisx991Gfn8Hw+b//gnM+LcfY+v8vl3uvx9517KEUa7N5864wU4x46Kw1xD52ThrqHUqVk99At6ZJ76LZ02T10RxrmHrorDXdvAa+t0kP30DPpkXvoB2mke+i5NMo9lC49dQ/9JX3uHvpbGuEe+kca7d5C63/SGPdQhvS7e6hI4L93NiRZ2UoEcpN5KigZ+O8N1vy5skLzQNVQD2mQlIHCpM+k2EVWT2mwFIfCpRgpEUVIQ6QkFCkNlVJRL2mYlIZ6S8PdQ32kz91DfaUR7i3mX6000j3UXxrlHoqSRruHBkhj3EPR0lj30EBpnHtokjTZPbSnkP6eC6m3xNorXZbi0D7pipSI9ktXpSR0QLompaKD0nUpDSVJN6R0dEi6KWWgw9It95ZaR6Tb7qGj0h330DHprnvouJTmHkqW7rmHTkj33UMnpQfuoVPSQ/firNPSI/dQivTYPXRGeuIeOit94x46Jz11D6VK37qHzkvfuYcuSN+7t8y6KD2TllnZSumtqZRyfCmzFOIbwVAWqayUirJK5aQ0FCCV941gyN8lVMpA2aUKUuxyK4dUUYpDOaVK7qFcUmX30EtSFffQy1JV91BuqZp76BWpunvoVamGeyus16Sa7qHXpVruoTekT9xDeaTa7qE3pTruobekuu6ht6V67qF3pPrurbTelRq4h/JKDd1D70mN3EPvS43dQx9ITdxDH0pN3UP5pGbuoY+k5u6tsj6WWriH8kst3UOBUiv3UAGptXuooNTGPVRIauseKiy1cw8Vkdq7t9oqKnVwDxWTOrqHikud3EMlpM7uoZJSF/dQKamreyhI6uYeCpa6u7eGlzopzD3UU+rvGwdRuDQmn3oowvKNg6i31Mc91Ffq5x7qL0W5hwb4UfKNkWt5WZI+842KaKAfT/fQID+CvlERDZZifH1oqDTMPTTWzzrfqIjG+VnnGxXReGmCr2+dNVGa5OtDk6UpfjzRVGmaH0/0hfSle+grabp7KFaa4etDM6WvfX1oljTbvXhrjjTXPTRPmu8eWiAtdA8tkha7h5ZIS91DcdIy99ByaYV7aKW0yr0Ea7W0xj20VlrnHoqXEtxD66UN7qGN0ib3UKK02T20RdrqHtombXdvvbVD2uke2iXtdg/tkfa6h/ZJ+91DB6SD7qEk6ZB76LB0xD10VDrm3gZe9KVk99AJ6aR76JR02j2UIp1xD52VzrmHUqXz7qEL0kX30CXpsnsbrSvSVffQNem6e+iGdNM9dEu67R66I911D6VJ99xD96UH7qGH0iP3NlmPpSfuoedSunvoR+kn99Bv0u/uoVrxem2NVw/VlurE+wCfVVeqF+8TfFZ9qUG8j/BZDaVG8T7DZzWWmkiJqKnULN6n+KzmUgspFbWUWrmHWktt3ENtpXbuofZSB/c2Wx2lTu6hzlIX91BXqZt7qLv0qXuohxTmHuophbuHIqRI91Avqbd7W6w+Ul/3UD+pv3soShrgHhovTXAPTZQmuYcmS1PcQ1Olae6hL6Qv3dvK64Q03T0UK81wD82UvnYPzZJmu4fmSHPdQ/Ok+e6hBdJC99AiabF723idkJa6hwok6D+YE9RDhaTCCT7lx/uKVFRKRcWk4gk+58d7h1QywQf9eLeQghJ80o/3B6l0go/6WWWlcgk+62eVl0LdQxWkiu6hSlJl91AVqap7qJpU3T1UQ6rpHqolfeLeDp7JUh33UF2pnnuovtTAPdRQauQeaiw1cQ81lZq5h5pLLdxDLaVW7u3kmSy1cQ+1ldq5h9pLHdxDHaVO7qHOUhf3UFepm3uou/Spe6iHFObeLp7JUrh7KEKKdA/1knq7h/pIfd1D/aT+7qEoaYB7KFoa6B4aIY10b7c1ShrtHhojjdA7eiIaK41zD42XJriHJkqT3EOTpSnuoanSNPfQdCnWvT3WDF+R39/RLGm2rw/Nkea6hxZIC91Di6TF7qEl0lL3UJy0zD20XFrh3l5rpbTK14dWS2vcQ2ulde6heCnBPbRe2uAe2ij18ucJtEnq6s8TKNF/f/78ss/aLEX5+tAWqZ8/v6Bt0nJ/fkHb/Sj58xLa4WvwQQu00886/69btEva7etDe6S9vr791j5pv68PHZAO+vpQknTI14cOS0d8fei4lOzrQyekeX480Ulpoq8PnZJm5vOxP+u0lOIeOiOddQ/9If3pHgp8oX+3L9RDBaTaL3zuzyoo1Xnhc3+8Lkl1X/jcn1VYqvfC5/54lZLq+5whKio1kBJRManhC5/7s4pLjdxDJaTG7qGSUhP3UCmpqXsoSGrmXhKvblJz91BpqYV7qIzU0j0UIrVyD5WVWruHyklt3EPlpbbuoVCpnXuHeI2U2ruHKkod3EOVpI7uocpSJ/dQFamzdA8FZM5ULMDnuKwc/sEFxQM4+Gdm0Sxhxh42s2qWDODonxmgWcpMhNk0g8wk6O8cHMDpPzOHZukAjv+ZOTXL0IW5NEPowpc0y9I9Yr6sWY4uzK1Zni58RTOULnxVswJd+JpmRbrwdc1KdOEbmpXpwjyaVegeNd/UrEoXvqVZjS58W7M6XfiOZg268F3NmnRhXs1adOF7nnTh+5q16R4zP9CsQxd+qFmXLsynWY8u/EizPl34sWYDujC/ZkO6MFCzEV1YQLMx3eNmQc0mdGEhzaZ0YWHNZnRhEc3mdGFRzRZ04TbNW3Thds3bdOEOzTt0k82dmnfpwl2aaXThbs17dOEezft04V7NB3ThPs2HdOF+zUd04QHNx3RPmAc1n9CFSZrf0IWHNJ/ShYc1v6ULj2h+Rxce1fyeLjym+YwuPK75A92TZrLmc7rwhGY6XXhS80e68JTmT3Thac2f6cIUzV/owjOav9KFZzV/o3vKPKf5O12YqvkfuvC85h904QXNP+nCi5r/pQsvaf5FF17W/JsuvKL5D93T5lXN/9GF43NnznQ0t7twguYxMwlO1DxupsJJmslmGpysecJMh1M0T5oZcKrmKTM2xZymedqMg19optCFX2qeoQu/0jxLF07XPEcXxmqm0oUzNM/ThTM1L9A9Y36teZEunKV5iS6crXmZLpyjeYUunKt5lS6cp3mNLpyveZ0uXKB5g+5Zc6HmTbpwkeYtunCx5m26cInmHbpwqeZdujBOM40uXKZ5jy5crnmf7jlzheYDunCl5kO6cJXmI7pwteZjunCN5hO6cK3mN3ThOs2ndGG85rd0U80Eze/owvWa39OFGzSf0YUbNX+gCzdpPqcLEzXT6cLNmj/ShVs0f6J73tyq+TNduE3zF7pwu+avdOEOzd/owpV59eTP6y5cpXnMTIerNY+bGXCNZrIZe4EHR/OEGQfXaZ40E2G85ikzCSZonjZT4XrNFLpwg+YZunCj5lm6cJPmOboXeXA0U+nCzZrn6cItmhfowq2aF+nCbZqX6MLtmpfpwh2aV+jCnZpX6V7ijUDzGl24W/M6XbhH8wZduFfzJl24T/MWXbhf8zZdeEDzDl14UPMu3cu8EWim0YWHNO/RhYc179OFRzQf0IW1ojNn6hHtLvxEM8xMh7U1e5oZsI5muBl7xayrGWHGwXqakWYirK/Zy0yCDTR7m6mwoWYfurCRZl+6sLFmP7qwiWZ/ulfNpppRdGEzzQF0YXPNaLqwheZAurCl5iC6sJXmZ3Rha83BdGEbzRi618y2mkPownaaQ+nC9prD6MIOmsPpwo6an9OFnTRH0IWdNUfShV00R9G9bnbVHE0XdtMcQxd21xxLF36qOc48b2Z/ljlTAf8MhjS+mFmzoJkOs2gWMjNgVs3CZuwNM0CziBkHs2kWNROhv3MxMwnm0CzOD3uAOTVL0IW5NEvShS9plqILX9YMonvTzK0ZTBe+olmaLnxVswxd+JpmCF34umZZuvANzXJ0YR7N8nThm5qhdG+Zb2lWoAvf1qxIF76jWYkufFezMl2YV7MKXfieZlW68H3NanThB5rV6d42P9SsQRfm06xJF36kWYsu/NiTLsyvWZsuDNSso1+jcg0aPDBiaHjk4JgsOaPConsPDesdmTmg9dCYIZly60vhkTExkRFBPUdkzTZYvxeeLyQ4tEJwSP5ioZUjIyMqhZUNy+8fxx4UEhJUJqR49uFhUfpj2csEh1QOLpN7eFjMgKCefaMjekdG5ykTXDa4Upn8xcqHh4VVrhRSIbT4/wGFcb1b');
+export const wasmBytes = generateWasmBytes('eJyUvXdcc9l954+OuDDPPFOeaZ7uweMabxLbKUeJN4k9SrJJXvm9kvySTdlNdj3OeOLYydiOPbEfuugdRG8CRG8CRO8guuiiiy5AgOgCBIi+z+fMuRMp+9f+MXPf3y8gpMPVvedenffzcfnuzz6SuLi4SGqlX3rfxeV9iT/+k/i/T/z9XfA/if/7Un8w/i/xf9/Vn1VsI/F/X/D/pP5kK/F/382fdzhI/N9/yt9fbH6KEv/3H/j/R//pTxlfefgflb+LUyXxf/8Zf6evOpUS//ef9Xf+unMt8X//Of//9B3/qSHxf/95///8Pf+5I/F//5H//9375GX4+X1SubPi07Hw8+Ov38/v01/r58e++dMhFL+HdTHKfn7sx3n93Cfw6c9IayXVkocPHwjkxRefe/D88+5ffOHVZ599+41XHz548ODBs6+7PvUsefMtiYf0Gekbzwlub74uPPW5Vx9KXT8jfefdL7i+K333XalU+rz784RI3T/7qlQg7tKXXJ92lbp/Rio8cH/wovvDh+4PXAV3qUSQSATB7fOfFwRX8sYbr7762mvSV1555eWXhRdcHz165Pr0s09JpBKJVOIqFQSp1O3BU1JXV0Hq5iKRuBHB1VUqleLLUuLq4iJxFSQ/kbzyiiCVuLzg9kDiL3lPodC5PHRPlL7h9tGHH/34p57E5cG//+gXP/3uT/70Q0+X591FevuZDz767gf/7Qc/+u6//sDrQxf5C9//8OM/+dHP//RDz7/64J8//N6//+uHLn/33Pc//Nix8b+f/uCDj/7wRx/81PMnH7v8DMUffPhJ8W9P49H++iff++7HH7qESJ7+/n9838coxO/76bPf/+Cjv/rBz8UvPua1+PV/f/C9H4tf83vwvR+Lfc9nPu3//sc/c1FInvn0a6i9n/nej3//k+Kn733s4vP0z/7jV3ihEB/n508//vhn4lcCJKjELwVKXvjOd37xjz/40fe+/+GPvvPRd//1X3/8gYtG8qJD86cfftLNkTzv0P2nn374oUux5B2H1ne/973vfPzj7/zs4+9+8C/f+cmPf/Cjjz/8qYta8uBLEpf3JA9fLpNES374/2dKUiW/SJEUSiIkQZK//qBckiCJl/x5muTX3n+62uRWLWn5QOLq/3mX9xRf+Zd33b7gQr5Nvu0hfU/yw3fdPcifP+viIX1P+sN3n/Igf/KsxOMpD/LeTZzO5dFHLg893D3Ie/tiIfFwf498/MN33b9CXD73wMPdQ+LxFGs89RXiQomLx1MeD7Bxk9uaqnsKLMlLfyEjdncPN/laeOVK2FhF+l/LiBW1anCqL6ZGW252kRELGseB6tGNa03oUzJiQh2VeNyVULIZ6i8jRtQnoRfartyI4zZ/GTGgobEou06jDBNyGdGjDtjunNkPq65r9pcRHRpJK2eXB9aGPLuLjDShsZu5mlM/vHu36iIjWjQuVVO644vRjVMXGSlBY75jeOtyW3U54yIjajQOhk/Dx4r3869cZCQNjcPxgrOlohNto7+MKNGILTWNJ8ePR/yLjISjju5Zni2N6zHIZESBulbTpe9Yj987cJERu5uHm3yhRZVZPmQ7SPCXESsap5as6aNCQ4i3jFhQB09f786Xx/RLZMSEuqlTG3FTNV3T7yIjRjQ29vrPm4Py6kdcZMSAxnRG6eZNZsDw92REjzqmorFmLmL/osdFRnRoxKrr7y76bvWvykgT6iFtQvj9SsN5F0YCDW1uT/BK4PnUr8tICepGe0ve9nRNl4+MqNnXS1Lqo43pE78pI2moqzYTYhK39DlflRElewaJN9OHGTnGX5KRcNTzjdlze/OjjUMuMqJAIzz7NGZddRX5YxmxCx5u8s2lmbrD8cv7MowCGgGHu+bYma3iIH8ZsaARYU3fGcnu1xy6yIgJjcP+496Oo8Osv5cRI+rWmpq+m7qc6EcyYkBdujh91zDbm/DnMqJH3RFwfjV4VGDZwSigsXK4fTx5t7cW6C8jTWh0F9fVxWVV7iv9ZUSLRmb6/WTRSHznNHYINMytu2uTXQVtBuwQaBSpVmZGbUez35KRNNTjQbONkX2N7YXYH9C46yvbsmwF7qb5y0g4GgNZhS1pTXWq/yEjCtTGeVvtWZY5/V0Zsbt6uMl7008Gyjbi7GqMBBpX8RPRucHztgG8NdDY14wsJ9RWNpX6y4iJNQrT9xVXdtMF9gg0hlfs+9XZ0fP/ICMG1PFm88VChSXpj2REj/pWX1IW3jm+/JcyokOt611SBRQEbwRjJNAYah5ojslbq+7GDoGGxd7Y2KppO2nyl5ESNM4noxf0i8fxfyojatR9F2n5vf2rt4t4Z6CRMxrcNN7Y3rzkIiNKNFpbt20rOcbOfIwEGiG1vZ19O10tVf4yokCjeWLhILC5LO5lGbFL8Z7PKLAazo77fiojVtQ9uqi1qY2+mBdlxII60jaWtLIUafi2jJhQ76QsT212h0a8JSNG1OORQaElSeGHa3hjoDFZ0VK8V2sefVNG9Kirx0tN1rXL8kQcI9CYKD1YKB2IyvyCjDShNtd0GpS9pZUWjAMa2WWtB6cFYylURkpQX9+3Wo4ix7qflxE1as1an3VtUFWxj2FAIygmcERRep38azKiRD3Yrux9XkbCwQNJ1qiM26DcG7wr0Ghq2GzqWGw1X+LgQDzc5O0huWFRx+u97jJiRT0SFjBkH9ve38a+gEbB+cbIcc3Vyt/IiAn1UUmm2toZUmLCroBGfeZk5Lw6df0YY4BGZZR956Ck6nTURUb0aFwmhx5PDXQdpmAQ0Ajt6D9NjgxubMfegEazMW5Jfdaq1WEU0KjpLtcYDluuC7A3oJFV3JwbVDdzmecvI2o0cnonIiomOqf+QEbSUI/u7FXOLhwFusmIErV1MyctZ9peN+YiI+FolDe1zZYkHLQVY2dAo2UmRqkbVkd9JCN2CQ74cXkB+Q07q5+XESvqjIvkBmNSd+ZfyYgFdZhBk9qqsy58TUZMqOey79IqN3fLtjASaOQXjS+WLekKTzAS7CfmL4suU+7aJzESaBgNuYGp+fd5cRgJNFS6UNv4SWSwp4w0oTYdxUcNG/bqW3GAYI2t3pZ5y2rpOg4QaKQl3WYXaaNjPpQRNeotY+DgyGzs9i32BzQackIurvPKe38iI0rUezemofnWpaUvy0g46t2EyMqwquyBj2VEgbp0MmC8N/zEHOsvI4893OQVoVeF7S3hLUYXGfkJfv6s/rq8NaU0+clx8p893OTbne0qQ0Lx4Csy8j5edFXGrqXpbmfvySj8nYebPHC3PV0T2FYU8OS0+Rd4vlklVUkq7f3ykzH4Y+yedX1nt/uhy1+UkW97uMkVETPVJ8cl1fVPdoXf8nCT31Sl3tuuapL/UEa+juPKQXW/MTgx4N9l5CsebvIZW0/Aekdma8WTvcADf9Pqne2WE93x8JMX/xoOS3MVy4kdE2PvyMgjvJ0HVwxrk3OJ35SRpzzc5Iaxybj6snn13ZN3A6YMS8bcvIvISguOQfbncSJMPYramh3ZtT556VY0zDvrW+nG5Bocry1oBBi2IhpTgoL/t4yYUA/a1fPGmp57nNeMaPS0GpVzvU0rz8iIAXWd3rQWaClZwxRAj8bpalmsPm1h6fdkRIf66OqmqDozvg3zjiY0Ro/GJyvr76twnNOiMVZq0l9sHTd34r2Axmrh6Uj92E3Zt2REjXqzOfgo+zy+/XdkJA118WTLeXazcfE3ZUSJOsm6faLf7j2LwHERjbXKPbM+15KJSYYCjVl1Qc6p/qzhJRmxP4d329joTOFVwyB2XCsattjdnFRL9+2vyogF9W77WYEhdEOBv6oJjRxjcH9mWXr+PQYCjfr8a1XsREbRmzJiQD06XVPUpys8+Y6M6FHPZs7vhZ9f5+IooUPDkHF/auowR31NRppQp0X0zhVkXqt/ISNa9vXcwLCo6530V2SkBHVb1kRr/rz6rghHBDSShjNCN0YsGd+WkTTUNYP9ly3muORvyIgSdURHWfFo3H7S38hIOOrM8b3Rg63Mwj+TEQXq8cvQLkV738U/yYj9WZz+yzKqtm46Z7C7WNForK+JU59OdZVgb0DDPlI3pshPafxYRkyoN5ryLhouxg6uMQhopM/nrUzFhYbjmGNAo6Ksc7gsb3C71l9G9GgoWm9trQm2OhyhdWhcnDYdn51pu/5dRppQJ2YspEanNuj/WUa0qEc6Sxd39Dll7ICARtaZNrcyIbvkWRlRo4492m04adzLwaEzDY2Avczb/cWVya/IiBJ1XnKtrTOgfoTKSDjqsYSsNlXG2P57MqJArbma7445iY7HzOkZTDAv8wtvwtoMb8iIFbW+pN8wrrnKf1dGLKhTtirXUlKXptMxW0BjMrrB1NFmbf0LGTGirmo7Nwb3aw5/JiMG1DUnrTHxdUMbmKHq0ShISt6f267ceSwjOtR5lWOKpO7qbg8ZaUK9tLE0u2RNy30kI1rUN4uLfSnZzUsbGAM0SkMr6rbt+s1/kBE16taR+LL9o7uK35CRNNRFgUv38yuqSszllWgs17asRu2vTvjKSDjq9PXVMrt6eQ4zXAUaJQcNg5kzYZF/LCP2h5jhJjQZrfa8Sxx5rGiY+qzptvXQiLdlxIK6pThyTh1SYvpQRkyohw93hop6gmM/IyNG1H0BQTfBwRdanMAMaKQsTyVnnWzFZGNXQGM7SRt+fTMz+LyM6FBPNxxEH/TFJHxTRppQz+eUqLeSUus+JyNa1DPL58fxxVGZX5aREtT3WbbVuOK1sFlMHdGw5tlNZWMpDTgFpqERGlCUZryNTNdjxoRGdvj6+DMyEg6OtIe027qUHT+QEQXqxRrVwEbWSdvfy4j9acxdDT0HrdXrQeEYATR6FIXm/drzWkwKLWhcV2+pE9qr9lKxI6AxHaO0zwYmaXFJhTqmtE+zGHXcJMiIAXXIje18Ye6yCBcaejQie+yDR+rG4d+VER3qnI3LGkX34soErqjQ2A+r1J9mpakxi9eisZacoqxdbp88w66AxtSSYWd+t20Cs1s1GuuW3ru11dL1SLwf0LDVp+7tNwYE/bWMKJ9m5/2RsKq2jOJcHB7RUGWXL3U3rEzhgkmBxm5ouKHc0HeOmab9AU7sE7r4VdtRfA3GAo3ryMzpiLXRHczKLWiUzA63q5sOUqUyYkId1xndWV+fXPtVGTGintquyMozJTT/o4wYUO+3TbWF32eX4jJPj4ZF2dUz0zkx8LqM6FCrg662q0O6b5JwnkDjQt8Xcni2sIwptRaN2rzl4POjpOo+DAUaQx1xPfH9/Se4/FCjoekqaCgaOO2ow1CgMd59Xb+YnGd/VUaUqDu0m0splYeJ/0tGwlFHhMxNpYznX+GKVoHGjq54KLC5ZqAXI4FzatDt5GFCcJbmr2TEivpq4XY5e3h66H/KiAX1anZs09iUORKzBxMaqTH1qox7W/1/kxEj6rOV6BRF9pTuRRkxoI6dTu2OMN5XfldG9KgX1q/G6noOEzAh1aHRu5sW0DRtXMAcqgmNueHO9c75ofW/lREt6vO0/OjBQd3VF2WkhD2lNUXpzOjy7M9lRI3aaKtdbjHZCzEnT0Njb/ZwfqlkO+7fZESJuqDKkra/F5GF+Wo4GsU7A4EzGmXnFIbhKQ+395pyo1oESm5e9XB7b185sSFQYgfXlPXUelNiAw9fhOQ/psQKntV3RftQsg+uqz4t8KPEAo5W2rO9KTGDS057H1JiAsbchZV5UrIEbjNoRlwpMYLXL2tKCCXT4CyNPtOHEgM4P+Q+0JWSEXCBVZsrpUQPtpxc5fpQ0guuSg5T+VOiA/da05d8KWkDt3dM2d0oaQLfdh5MelFSB74oLj3yoUQLHti+bZdSogEnac2nPpSUgE/Kb9PdKSkATzZvXflSombfbzuO8KZEBVZ1N1b5UZIGXjucHfShJAlcmtXaSShRghNni81SSqLB9trmAgkl4eDcI8uGCyXB4Ln6mmg3ShRsOBdHm1wpufmMh9t7ysnxagw/eLFwKMGXEht4e2t3V0KJFRx13hrhSsk+OOxsPN+dEgs4OshWJqXEDF5pSTD4UWICb0bfRgmULIE1gRu3EkqM4N20A91jSqbBE+2xVd6UGMApg/dfomQEWJY+tetKiR58FFYf6E1JL3h0v77OlxIdOL6qrdqfkjbWv29WCJQ0gfeDlud8KakDB6+Xd3tSogUXb0/rfCnRsJfb1RPiS0kJOPAyc8aHkgLwmGV5zZsSNThvT9HgRokKXBjTk+tJSRq4c7o3nVCSBC7YaEv2pkQJ1k4Zt9wpiQbnzEyNuVASzp5/UnODlJJgNpxnWyuelCjAySuZA36U3LyC3bNHbfehxA6+n7mq9aPEBk4eTT11o8QKHg/Nm/GkZB+cMR/W5E2JBTy/NDEgocQMLsoo1rlTYgK3NhwtelOyBFZNtGa6UWIEp6lVB+6UTIM18UeDnpQYwIXZ48kSSkbAM6uFB4QSPdgwdZzpSUkveLnjKuQxJTpwT8jsvRclbeDmJXuLPyVNYGNu3oA3JXXgmIOobAklWvBdSUStKyUa8Or0gRm7P9iurld6UlIAbjEqjzwpUYMr7pW32P3BjfWZx48pSQNfDeUd+1KSBD4b7Yh9TIkSPF29MO9CSTR4KP0q1YuScHBTTVisLyXB4MHY7HNXShTg2t62ME9Kbl7GW1aztkUosYOtXbt2KSU28M1EwhaOPuCduIhcN0r2wUn5lgFXSizs+ye3RnD0AdfOaWsllJjAXUdd2X6ULIFDM7WpAiVGcE3zeJiUkmlwdqvmilBiAJfGNkZ5UTICvlzuV+LwA24t3DiSUtILHoo8zyeU6MD9EYEREkrawKnFigRCSRN4I7ir5DEldeDqyvItX0q0YPNxbJQ/JRrwwUHeB5SUAPNTThuw+4N7UhaGCSVq8HXjxLE7JSrwVnjyipSSNPBJ03GFPyVJ4IDD+zocfcD3/edhbpREg9OXjw58KQkHn63UfJOSYOBxVfKIhBIFe+Ul6jEvSm5e8nB7r2HR3OdFiR0ctdnY7kOJDRyh0lhcKLGCMwtsTRJK9sF59uhAHPvB6oDUOUKJGZzYV27wpsQETmgyGiSULIEv6goXcfAHL/VEhODgD9YFdJTh4M8ev9Ee7UnJCPhwZ6jBkxI9eK6oo8mPkl5wZFpvuxslOvac9ZZjQkkbeLGhvwhHH/b4hpBqHPzBcUnKYYw+uGJfNYiDP7jy2l6Cow84JMfYLaWkABx79WNK1KAFY3MCBh8c36YNcackDVxuVu/6UZIEViRG12HfB2O/xzb4uPgch31wt73wHod98E139qQLJQqw/vTgHMedF/GHGtuYx2EfHBGnXnpMiQ1sWTtPdqXECp4fXz7FcQesS6jJxn4Pbq9YHfGjxAxOq1X+D0pMQIPutgoDDw4Nb8v3pcQI7t1ItgiUTIOn82YPHlNiAG9lpu96UzIC7mhp/RVK9MDlivNvUNILzL0xz+GgAz686g/ETg8+bku3e1LSBDYpSm/9KKkDB6Wa1/wo0YLPB2cX/SjRgCOj9BF+lJSAT/sCKwRKCsCVFptKoEQNLooo/5gSFbAu717pRkkaOCR6+tybkiTwwnB9kT8lSvCOKiTZj5JocNhWT6o/JeHgy7GhAm9KgsHjyZVr2OvBKQunCY8puXkBTzPYMuNGiR3cOaCv8KLEBi4x6TtxzAE3FqX2uVCyD+6L2V3CKRdcvxqt9KHEDN7bzNYRSkzgmZHSeS9KlsA5/atXjykxgofNEa9SMg08nY1N96XEAL4uWP5DSkaAK6XZZjdK9Ow3ZW/9MyW9QL1prRu7PDj8ZGoJuzw44PZU4UVJE3sC5wk27PJg83pgpzslWvBUb/KVOyUa8ERlYKaUkhJwxlBUNHZ58OrAmsGVEjV47HpiRkqJCqxOyNzwoiSN/d61VYsXJUngtduFW1dKlODmcVssTrhgY03lqZSScHDszsawOyXB4O690uHHlCjAg1kJ3T6U3DzC/pJYOIjBB9u0o7GEEhu4o7QxHYMPnizbHRMo2Qcf3FXOYfDBCeXTZk9KzOC4MNW9QIkJPDWX/meULAH7M2LbPSkxgkcChxT+lEyDq0KjStwpMYDvWkPCfCgZAe9GrdlcKNGDW4ZVixJKesHV7cWTAiU68PpuhwrnW3D9iKLAlZImcFnk6ooPJXXgzZzjThxxwIG7qWs437LnEN9f505JCfh8YeHIjZICsFY3/ZeUqIEbqaNvUaICXs1X9gmUpIGzOnurXClJApdfaFbcKFGCi21Gm0BJNDi1LroIZ1tw181omRslwWxg87tavChRgA0hy93elNy8g53KcryOwQfPrM0n42wLvu6OX/KhxAo2q0MbMPhgY3NCMWY74PC28U0XSszg5vUQnRclJvB90My+CyVL4JKUonUpJUZwW3pSvB8l0+AUc8yhhBIDuLszIANnW3CT0ZCL2Q57zFtlvxslveChoDHMcHRge/RhkSslbeDV0rY9KSVNjBtKP6KkDjg13djhT4kWfHq30uxDiQacdtJcJKGkhL3cu5FEf0oKwKra2xTM9cFX9t52V0pU4LXF1G0JJWngxr2CWDdKksBjyzmDXpQowXuJJ+kSSqLB86rJb1MSzr7dVh3uRUkwOCM9tNWXEgX4oEhV40vJzWdxzNo24hBqBycUDq5gqg+urT9rwlwTfL0cvIDjDvhqe6L1MSUWcEDLzNpjSszgrIiSC+z64P67PvzVl8Bdx3cmQokRPNKgnnajZBqcPTAXgwMP2NyZqMXZln1PaT0ul/TgnowuHMZ7wecZC4eulOjAqSvGOE9K2sD3Kt2uDyVN7Hcd7m3hsA8+Tl77AiVa9lLKTB1elGjATw5goZ6UlIBvRstSHlNSALYdWs9w5AHXKzITvShRsZeyZtDgyAMuW4++lFKSBK5eGSrE6INLNadB3pREg3dLi3EWCgcPqRYvsfODW2qP2vwoUYCPRvNxTrx5G2czZduWNyV2cNltuj8lNuDWcHO5JyVWcNDE7g0utN5mVzNLmPVbwIeWDb1AiRk8mmiqxVEfvHhxsCylZAlsWY7ZwJEHrBw775FQMg3WX5jNXpQYwOPbQ7gAHgEfTTaU+lKiB+/YR4N9KekF59wG7uPIAw7c68zyoaQNXNm2PI59nz3+xEYfrrTA5yHjlseUaMHTveknOPCDdxtq7gRKSsCGDH0vLnTBGv3FtJQSNbhTf3eA4QcvjDa1eVOSBi6csM+6U5IELsoJ0+CsC0470Kh9KYkGR99G4eotHDwfNNCPKy1wWFzrEGb64LyVHpWUkpu3cNGrrd1xp8QOVtffBGD8wSURtd+jxAoc6FUO+1GyD4635edh+MGltVuYiJnB5cb5NEw1wRfquDgfSpbAMcbhGpx0wUvbPfHelEyDs1Laj3GhxR5/MWQUww+uzNf/CSV64OTxxTyuc8Eh1/ET2PfBwV29Wow+OFER0YwpD7hbnaRyo6QOPJd8GiulRAtuGugrxpQH3DBxfIQpD3i4OTObUFIAjjooTMfOD1602P+eEhWwN/KoGlMe9uzts6kYfPYKOzMeUKIE5l8HXeOkCz68OB/Drg+uSs/CmS2YPZvIrXB/ShTgJGXxGXb9NzEHPsmrx64PPlnMXsFsExybUqfHPB+c0LJkxDwf3BYakEoosYB1w+UBXpSYwevJlixPSkxglcaixHQTvDBZ0eJOiRG81hu45kvJNFiRkxyKeT44s61/CUcecF3hos2PEj04MUfVKVDSC45ez55yoUQHLrA1VUspaWM/mz+1gHk+2NKdtilQUgeO1G/euVCiBTeMmXcIJRpwe9v0uRclJeA4zVopDj3g24v7CxdK1OB91eCUQIkKnG3sjsKBH1yjrF/FXQZwRGhGMGb6b/KZPrb2xGv1Y0rCwTeTnUpXSoLBuXGlJndKFODLhtYBTHjewJ2H4/0hHPXBYcrKEYw9WHsd2e1HiZV9z+HliC8l++CI9DjcDLOAZ6uKjnCFC07X7w9g7MGmuzncdVkCB69MUEqMwONIW54PJdPg+L2GAoESA3g0+qzej5IR8PJyihUXuOCU6zQLDjvgjpPyaFzggtvzu6xulLSBV6Jvot0paQLrpjtwpVkHjjrR5uCwAy7Yq2jEBS44xByMa4YScF7XbAbOueC6lrETXOGCe5OvGnHYAU90hufjChccWnb9K5QkATczpnAvSQke644cxwUu+HRt+FuUhAOLCydHseODI+esuI2jAB80R9Rhx38dB1Bb2ZuU2IH55hTc1bCBteacXkKJFTw1nNckpWQfHFp/WO5DieV1NmMMr8TYg2vi1PO4uQbemtbgAmYJvFHVfoOba+CT3iijKyXT4PKBkWzMNsGTh+ur7pSMgJPiak5xzAfH1dowv+sFW7W5PTjqgC9LK7tcKGkD3zUrYh5T0gRujWhZxuCDq7tW890o0YLXI28zBUo04NSu0Qgc88GbIf0TEkoK2PPUlrCbm+Bby+6xDyUq8OBoNa7J09hzqKrre0xJErjjoCPSjxIluLhlYBinXHD63GWXQEk42Ha8efqYkmDw2XRhEoYfHBuRO4Z9/zU8/mRBkB8ldrCxKCzXnRIbeHox2uZNiRWcXL+dgPkmuHNub8+NEgvrl50kYbIPfjIdxBWVCbwXvZ3jS8kSOGZgpQ73F8ABhbptjP9r7A7Z9DXu7oCXu4N+TskIsE/bgnukenDh2AFuWfSCVxJ3Ir0p0YHrc2KTse+DixQJuL/axH62qmqWUFIHHrevVuI6F3w2rH2REg0wPH9hA/fWwBUHjbU444LVZTu476QGDxfFpvlRogJr5tb/kpI0YM566q4nJUng1pSqRcx3wC2aqxZCSTQ4IzSxHbcYwHdFyVE48IAn1FmFOOiDb10oufnykyPpM5TYsf0cJTZsZ3CYB+CG9D7gZUos2O5gUg/AtbIJ8CVKlrCdxa4NwDFzGtCCSTwAR90RAO4VY/tfKOnF9lVKdOzBsAsDMFluAuDAXgcIwn1JwO9QosG2FXN0wB72V4BeQokaoJVQogLgVJYG+ColSdhWSihRAkZxYAb8V0rCsTVJKAkGqHHxDzjC4eBLOGfhQAwYlFBiAyTiHjsgB+c+wJULJRbAJu7tAn6FEhO2z1KyhO2QCyVGQD3meIDXKTFgW4rbuADc/9EDJlwo6QWYcF4DrGJkAPsYGYBCQkkd4MuUaLHF3VQN4BElJdi+SEkBttUYF8A3KFFhi/vPaQBMGLANx7AAfp2SaGxLsMsAljEsgHcoUWBrw6h8EWdvF0rsgFGMCsCOvQVwh1EB6CSUWAC4QjYDMrC7ADwoWcL2EsMCmMSwAPqxuwCGsbsAfo8SPbazGBYADp86wJuUtGHbgVEBvEZJHbZl2F0Az1GiwXbdhZISQCN2F8ARzukA3JZVAfowLoBfoiQJ27cpUWJ7jL0F8AYl4ayBYQEM4DYd4BDvoS/gL4W9BXDmQokN8BYlVmwtGBaAjBILtpcYFcA6RgUQgrkR4DcpMWJbgVEB4BLTAHiXkhFs2Q0IgAZ3HwApGBXAKXYWwBaGBYBTWx3AinEBjEko0QDG8T4CnGBgAKnYXwD4KEkFWMLAsGfhQkkS+ykXSpQAnHWiAS9QEo5tPIYG8E1KFNjuYWQ+j9MtRgbwEiU2bPEuwhYnUmxxoLIAcPVlBnRhYACEkiVsn6fEiO23KJnGFpMkAyAObyLAZynRY/vLlPRii9vKOgCOz9jiDl0TALf+6wC4U60F4IyIrRE7C8CMMQFcYEwAT1OiwnYKQwJok1CSBIjGm+jzfM6HbTneROzhMCQATH0VgGC8i97FhQbGBBCDdxGgGKMCmMMxF9CPYwvgFUrM2K64UGICLGB3ATTjoAs4wP4CwMd3BoAB7yIA5ux6gBVvIwDuwOgACbhHC2jADgPATZs6wDJmygB8FKABLGFwAPjEsgAwjcEBfIYSFbZpGBwAPg9IAoRicABmjA6A3fgG2DE6gBO8lwBfpOTmczgMYnAAmGbbAAc4xAAGMTiAPOwzgEWckQDnGB1ADUYH8AVKjNjOY3AAX6HEgO0WxgaQK6FED/hVSnqxfYoSHbb4WLANgCvzJsAV9hpAN0YG8JASDbb4OK4EMOJCSQEAt6/UgN+lRIXt5ylJYz+LnQaAO01KwK9REo1tEoYFcI0PAwDTGBbAMHYaD5x8MS6AFYwLoBc7DQOMC6AHOw0gFu8lwG9QYsI2DMMCuMdOA/gaJdPYZmKfAeCG0wjgtynRY7uBYwzgBrsM4Bq7DGAOAwM4xcAAAvB2AuCzRA0gHyMD0GFkAJ0YGcBvUaLCthC7DIBSkoTt1ylRYjuD+2aAXXxCC3hASTC27CMSDw8XD4kH8ZB6uHoIHm44MT71Q/z/Efv/a+z/Hj989KwHsymIh7vHf1gUhFkU7ym+8sMvuDzs/0eJxMHdwGq9i8ODooGQqF/mdsXwWvfyQE5yGZbCMb3iYqFuInm9J+yz3K7oCQpJCG/YGvklblf0h0Tkhadm1P0FtysW7u8qUvqbT/+WyxWKxKIRa15P6BtcrohLr1EpOi2GeRcuV9gN6oDCo4sjrLBhcsWgqrXD3jlTxpYCoZEQl60JKLiy/Ql3KxoaD/fzesPifoerFRFZLW3WpMKhX+dmRetUYWJM5fl+qWhW1FavL9UU9Ya/y82Kpu6w8fbAjpt/4WZFcYvNkDhXPP5lLlbEx2S0BZRbTemiWHFZtXwS2XK4BAGAmRX5PSGJN+WjGbsuXK0I2F/srOoOCnuLmxXG+NzAeZMuwI2LFb17szHFM+m1/x8XK5RrXUGV+ricG1GsCE2t3DcY8vqe52LFcmpFzWDr0cqaKFaodiZ6ss0Dcb/HxYp8Q/7qSNXFIVY3MbNiYGcyK3WoegOrtJhaUdBk1SWulE7NuXC34mgoaaJgLCoVy52YXDFztnsbGp/d9hPuVuwst7QHBwUGP+JuRdVw8tT+RHb7x1ytCLNM5az2tpz9OTcr6uareypWk8s1/tysmMxqXo9o250edOFqRXRmQ7ROc7Zb7c/divnDpePzhsWG/8ndipqxnKr28BpzlD93Kxr1iTnt1SttP+VqRUl+/0hP3ebQN7hZcTRfk2WeTqv+Ay5WLAdH3e0c2Haw5pyJFft6ZVevKrUSwgEzKxYTCxNKuwKCnuFixZjNkrvdExjyMvcqWnVBqwdD0ekwiJhYkZWi0a4dnh9gTSAzKy7brjvKllKr2kSzYup4fbk/LCTyK1ysGIpJX0/ra734I+5VlOd2L9Uq83ofcq1iqzc81zwemQwdhHkVEzntJ5dxjYdQGphYoX7yBKNrTbrXuVhRXmNWTp0sNf8T9yo0g/E9N21zlS3+3KsIvL3cuC8Zz8ZaX+ZVzIUnZpfe7Uwx5QiNkYj47rTtvqhf5V7FWuftSbltc/g3uVZRXzSiPQlpPf99blWMZDTG6gODQl/jUkXs1vjR6mjdDtYnM6tCq9Yp8jbu7z/kVsVZ3d5di0avxDprplWsXp5kpRgrFrFykHkVeqspYEB9e/Vv3KuoKBy66WncHvsc1yp0AQGBJccbAwLXKk7z+xJ27y0TWH7MtIqWzjtNjapsGgvBmVdhze0KHAqPy7aLXkVocLhaaV3t9ONexXhU8mR43t3ND7hXMbM1Ut1fs9b9Atcq+sJiauwtU0UZ/tyr2JwpuzWv391+xN2KPXXPSvnm9cUfcrXCUr0WWL5zuh3iz9WKleTSvMGMxCKIOcytmL+9am8oHVOdiG5Fx0xp/oHlZDPGn8sV+o3+0bnb2YoGfy5XmHSKAFtQ590H3K1QKBQKXeH1+XtcrTgrHq2JrLBZmkW14nil7fgsIbf7JW5WXE8WxG9u24//Fxcr1jSLHXuX+/PQBZhYcV44mLewM5Z17sLFisP6nbzWnBID82zQqGjY1+bPZjb+AzcrbEZt12ZfaMxvcLEiM6FAuXZq6nqbexWjm0O3F7XLLT/kXkXRbNWR6mAwEctQmViRGRC4NDHQdPwdLlZExKqspv7giC9wsSIuMslgmC0a/Rr3Kkq0JkXidOGwjGsVm/3R1eY961qWP9cqosOVOvXu8UayqFVMrffGnxf3RX6VaxWJG32JHbEN++WiVlE3lBoXk3tj9+RWxZLF0NXfsTOJhaZMqwg8XK26j6jdgh3GtIqFfeNaimU8B6vBmVdRWTy2nz8akYglw0ys6D5aqRjIv7/7LhcrUjYH8zuitKZMLlZ0Np+oTrJLJ6e4WHHbF3lbeLs3h/WaECv2FxtibNH1u1gbDrNiMC7rpCmgze79iViRbNu772if1dRwsUKXUDxm1652eH3iVfQlld8Zp9Rd73ziVWzNVR4UTGW1/usnWkX6zXVn5f7ZHjQLeBWl41mnS0dDyVgrDLHCYNIFNKh1CvKJV1G92n46Wna8Hu//iVcRYx6+X71YqP+bT7yK9KPl05Xjlfaff+JVFA7E1I4oczoef6JVtF401B50h6R8k1sV2rXDxZHI5GkfLlVULFhaLtaC4l7gTkVXYIRKVbvFlgkzqaI7OL4uOibDgiMzkyo671OmDpYu977BpYqIxCKFMTco5lXuVESnx9rT6iyax9ypKDHoui+uLyZxzGNOxVhWzsh0WfJfc6MiLG5Wmd5+ZfbgQkVmY0P1Xk9Y9rtcqIjNytbrEzbTsWyfGRU7k4Mrma3n0xBDmFKRVl2ZnpkXHP/rolHRs90flHN39hIXKrJaLM3rU9uJeF8yoyI0ZiU8vck6YhSNirnK5tGcxkNMiJhRsdYdkb1QFFO/KhoV5wercaauoNgXuVCB01W7SREkcKGifq8/f38kfQvzH2ZUWE0BYTsDcR3dolERldqgLZlYD8MRiSkVwynz+8uNR9p/4EoFTnCXG2E5n+NKhWWiKnuy9cKA2QZTKup2xlr3jcfDeFswp0LVZMpdn94p/zfuVBQOlVxt9UVV4pDGpIqWs47ymLhsO1aJM6uicjl7KDYgMPw1blVsDpXelY1nX0E3YlaFbWe8U718tf1FLlXsG9eK9oaSZ724VDGmOk7Kqt8teJ87FTGZoSZjy5kRp2HmVDQeNg3PVGQeNIpOxfb4XEpQ+klfj+hUmAfHOxKrsi8xeWBSRXz+VtdCzWYaHAlmVWjmY68qR9LWIZMwq6I75H6//sq2AhmUaRUFevV6f1Tq0ve4VTGYMLh0oAsIfZpbFS02w2lBV2DkZ7lVYeo6Wsnvj67BO4xpFVEpRkv19fksDrTMq1hpzxndHk5d+UfuVTQdV2aOBoYk/Q73Kvqjq6f3l683folrFcrcw/nI+PUQHJKYV5HXq6wp6osswyGJiRXj2ZfRo5kXE/eiWPFkZhmaoTVF4xTEzIr0Gv18zaHqFEdKplaY9blbC+Wpy9/nasXdzXGqsTiufUBUK3ojxtvObDtlP+VqBWYoN+shqb/N1YrVjsuYu5PNjDxRrUgsSivtybpe+xpXK0Yzw8y9AUHRL3Oz4nT74kK9YFtlfjJEA6VaEXB6f3/yBjcrFuo7K1ILwnNhdzC1ou0y9vpg9qD1Pa5WNFl1PdMdt3ZXrlYkFN7tLNRuK9tEtUKnCAi+Od4IjBTViom8iKxOm6XSj6sVOZ0pE1HJu4UfcLXCoA4IGQ+La8OyduZWBAYrtdajnHtYbEyu6IssvVF13tkecbeidiutfEa7Fo7l+Myt6AlN1yyVJo7+LVcrymY6yjru784/w/UKQ+7R8vnt9fpXuV5h1Hb13V2edkNYZn7FUNKEDaeT/871io7b7OGs4tjmYVGv2J2eOG+9POmHLcH0iuqNrZ62vZTFj7he8eT4Hdifc3/8DtcrMFkwtF+bfpnbFVNF6RU5LbYluKZMr6hasUcOKNcicCnA9IrSSf1C44398Pe4XTFbsRrTcL7f8WdcrpgsiK/v3I5thbTF5IqFuomz2CRLhTd3KzIbjDvro5mHOEkxuUJjNOUV94Rm/Cp3KyIS9MZqqyk2W3Qrajbv9pYqs85y/LlbsTfX2HBijijGRRRzKxQBh8bSJfvR73K1Iiy2Sa/uDk6gXK0IiSyxq6e2UnFuY25FmlbXtT65mYlLHiZX5PenlTXYrWMrLlyuCA5Xm6z24yH4bcyuqF4Pz5zJC0n+FpcrjtcjciIyz2cg6jO5YnN4oCix4+bmKe5W1FnUGzN1OyUfc7Xi4mguVZd+2jXuwtWKhn3t1Nl2XCfMQOZWJBafH+/2RhThWpDJFY1Hs4lLnffWt7hbcWKOb4xIs44uim6Fqjn2cjhpp/QX3K3I67vbvT/by/shdysqFkPXxzLO5qFGMLdiILZ5JDr75vYhdyuuzjqr4stT5v+FuxWDicXB01U5t2H+XK4YSu7UFBhMcZhJMbmifK6h5nAs6zxDlCvubnPGj6/OFqCbMbtie6x5rGUzspxNINHQmhRByWpFgJTrFUcrl7GLTcd6XMMwveJk8/5Ie5Cxhysv5ldcHDaPNx9lX8eIfsVGf3rlUFzODVQW5lfgUCnhfkXr4vyKN/crpg0nxQL3K7QXFaHe3K+Ia+vFLXfmV1TULe8R7lekT+lGPblf0RUcG+vKBQuLeqPPjQsW3eEZKaJgsVU1UO3HBYvO4Z07by5YxNQt4f4dEywiEhX4DJoJFrm1a3FeXLCIDmszP+aCxerw7rUoWJSo13oIFyxmpu+qBC5YjMUVnYiCxU5i0KAfFyzCyvVtj7lgkW0Px/11JlhkLhqXPblgkbStKvTkgkVsXIHtMRcs5qe6sW6XCRY7BUejblywiO8dwa19JlikzY30eXLBwj43/m3uV6zVbmCNKfMrssfNZ57cr8iyqdhNNnBX7XqkD/crQqt6sYiX+RXNU11j3tyvmFvawnIE5lfUT99+6lesBcfFuHG/IrZJjzuvzK84PwrT+nO/Iig66TWuV+Bs68r1iq208no37lfUb8TjU2XmV3TYI06k3K+w6nqC3blfEZmTNP2Y+xVRmWlYG8z8ivae40Nv7lcMa85XXLlfkXMTjM8fmF+Bs7g79yuWe072fLlfYUmN6/LjfkV/VX+ND/cr6raziry5X1FU1YeP5ZlfobIq8Xkg8ysqmwZPCfcrCpPaywn3K+ymfKwqYn5Fy+rMrDf3K8LVJtwdZH5FZVzhhRf3K64srdPu3K/YTOqsEP2K3ZzUCT/uV9i2c9T+3K+YNe42Ctyv2NdObbpxv6I0MWDci/sVY01D8AqYXzGzkVDuy/2KmCDt/mPuVxzUrR64cb+icWmzRcr9Cn1qLJalMr9iu3y42Y/7FZfmqhF37leYi1dz/bhfkbyhrPDkfkX8qTrGk/sV6+GZ+MiY+RWasPZtL+5XTF23ZPpyv6K78zzBh/sVS9aEbYH7FQXKangFzK8ITSv5OtcrBgsOx1y5XhFZYjeIekWLpXlSyvWKGv18gpTrFabGowA/rlcsLi4s+nK9IkpjwycgTK9YsUdi2SDTK1bGt7AQgekVSQYrbpozvaJpbrTfm+sVh3HFx35cr8AJyJXrFeGp0b2PuV6hvNCEeHK9oqzkcopwvSIvPD1R1CuWBu42fLleMd7Wf+fK9YqAWlP0Y65XPLkuCHHjesXpyJzKnesV6WYNPqplesWyLftI4HqFWVlb6Mb1inZb1q6U6xV39iibO/crissHO724X9FbvIKVMcyvSF0y40MW5ldgBurK/YrUvXAsHWZ+xepNKFYXM79iP6wTq9qZX5E4Mo0PtJlfEa014ENdJliMBtVgaSQTLCbvGxJ9uWBxet2a5s8Fi/vhvcfcr1DqdLi1zvyK3OCYKML9ioXV2Slf7lc0HIU0eHO/om3gdkv0K842Ekv8uV/RZMqFw8D8iqwe64En9ysS7uuSPblfERdVcvOY+xU6RWCoK/cr8lsvc7y4XzHROxYncL+iJDVmwIv7FTnDlltP7le0WeOxtpf5FQZdd5jA/Yr7m7Abd+5XBIanPeJ6xeVUzx9zu6IvqaPSlfsVIfkGzWPuV9SOzGRIuV8xWreCNafMr+iJToHrwPyK68XFv+OKRVlO8pwXVyyOosov/bhiYbioDPLlisV4VNmVD1csjKa8AVGxGNFObrlyxWKo5GpWVCyazRVDUq5YdIxvQjVgisVwZsaCD1csdkuuoQswx2Jxv2FV4I5F9WlurDd3LG4G7t/nisWTqUQQ4YrFQVDtjh9XLFy4XzFxmhflywWLqZHZHIELFsHFSwWPuWBR1daH9StMsNhovU4XBYvSggNoQkywqL1uhnvJBIvZo9BaXy5YtO7XL0i5YDGpX0gVBQt140GYFzcsFiwtcwI3LA6bhs/cuGGRuV+HT9aYYZFhacKndsyw0GgnNgg3LIJa7arH3LCIKNjHZ5HMsDibvte4c8Oi5r4+xZsbFsWqwlbCDYu9zEyso2KGxXrnBdaHM8NC0biPdXbMsEjQG/HBMzMswlQFv8UFi4zVaSzHZYJFSFLbV7hfkXIUXO/JBYs0kxqfMjPBoqf1KsOHGxb50cmZhBsWA+VDLT5csQhWaj24YZE8fYO1G8ywqO4djZRyw8I4N9bjyw2L497xaNGwOD7Nx0JLZlhs5k+V+nHDojB/osqLGxZ1huM8KTcsRsI61n24YXGxF9Xkzw2LzpuQKyk3LBqMOzVSblgUpZVikTEzLBKvm7DokhkWtz2n/8wFi8a9iA5vLljoGg8DRcHiRL+Y5M4Fi4Li5WIvLlioBm42PblgURVVivX1TLDI6zyDsccEi+ntbGhDTLCoCKo+8uKChfWiCiYFMywGVEUdrtywmDdXjgvcsLhanfsLLljc2nL23blgMZgYOOTDBYvATlvSYy5YDOWkTPpwweLcuAdJggkW5Znpq15csJjbi8RCeiZY3I1v/4T7FeWas0XC/YptVTHMMOZXBARHP/WpXtEVKOV+RV/+JGQzJlgctQ3cunHBYk9zgQV1TLA4uW9U+nPB4nq/EboPEywulrbb3LlgcWNNNLtzwWIjOjXLjQsWGpsRy5SYYHGgOVr25oJFyeVUniAKFik9Id5csMjOa2OTffDCYT0WrTLBotS0NeLJBYvAsLBIVy5YGI7zety4YBEcE5XgygWLuY20RlGw2Ky7x+JGJlhkVNaxyT44QlHEJvvMVrCGxXhxwSIou4ZN9sGFZ7d3vlywMFhzuwgXLMru58oEbliEJTaf+3DDYrCrYNyPGxbJBdlsss+Ej1471qQww6Ji/xCLfZlhUTs2WeDJDYuwhBY22Wevd21zzJcbFpMXRcNu3LDI2+oNFw2L8q31Xk+uWOwVm/6IGxbzx8FxftywKD65snlyw6JlzGYRDYv06pBYH25YpOZlsMk+szDU26Pe3LCoPF2FB88Mi92OmVIpNyy6B0Kj3LhhoSpoYpN98EWQEQtdmWERGhn9GhcslNX53a5csBiZyah144LFcfhchTcXLG6CL4+lXLB4ctYOcueCRVxkGZvsg2OiK9lkH7zWdHHgzQWLlNaqBVduWHR032CZBTMsLM2TcMGZYZF7eXboyw2L4QE1BGdmWKTmp8MyZYbFaexUoTc3LObWM+u9uGHRpLdi0RwzLJbMzdArmWExtJJcSrhhYY1f73HnhsVR2d6MNzcslFo1m+xjuf/AZOuZFzcsbFm7U+5csRhaTSl344rF+HDJnB9XLE7jDMX+XLHQnC3WiIqFcadmw40rFoO64lEvrlioCltPXLliUT04U+nLFYuAtAY22Wcaw1EDlpAyxeKwZrlZyhWLqLDcfh+uWMxsqjr9uGJxkroFZ40pFlNHSizgZ4pFtX5W48kVi2ZDPxRVplj0jkYmunHFomeldtOLKxbty6PZvlyxyGmNSPHhikXT4Om6wBULvTEeuhNTLKKT0r7ODYuExmIYE8ywSGorYZN9NoSqnQkpNyzMmsF40bBYvFCE+XHDouLgAB4RMyxSWjRssg9u67s+EbhhUXx6ee7LFYsSuwHLIplisVe00efNFYuBqaYLP65YRCdn1rhyxSIqNI9N9sGts93BnlyxmL4pnSBcsegdiYgnXLHIvz+G98wUi+z8zitX7lika0PZZB/85Ngc5MYdi63ysUx37ljUzJqx8JM5Fi3jF7sCdyz08wlstTP4KvZsR8odi5uQq1N37ljMmHPavbhjkVgVX+TDHYvKkyWsCWOSRXh6Yp4rlyzqe1faPLlk0dFzfyVwy6JnVbvtxy2Lot0RLH9ilkVaiZZN9sEB6fWHPtyy6FwchErGLIvbrJEcf25ZbNb7cMfiycUwFngyx6K7PySCcMeiam9n1pc7FheBi/AzmGOx0XG66c0di+OI6Sp/7lhYlWtdUu5Y5NrP9z25Y9G5MIR10syxCEnqYHN95l4EBMEjYI7F8k1clhd3LPK2ByIF7lgM9+fD/2aORaHt7saTOxb28OM1KXcs1Gs9gQJ3LO6C7uCgMMciODr8EVcsdnLNf84Ni8ic1DJXbljEl6SwuT54q2w0XcoNi4yqRvwbE8ywCI2Ky3DlhsVBxf53uGQxPlQ+48Uli76Ztns/Llm0znWF+nLJIiS5/U6ULLQTG90ClyzSSmvXXblkkdRePunKJYuTlE2I4Eyy2K65PvPmkkVMTAU0TiZZTN+WTblxyaJh8WhBlCwulQMx3tyy2Oi0fp9LFk8u4wIIlyy65uuORMnChQsWzRN9saJgUbQ3niFwwSKxMoHN9Zlgsd5+SbhgsXwbnePHBYvJ88JBwgWLW9V4ljcXLBp75ht8uWBxnnEwL+WCRcHBMNbRM8Fi8TwoRBQs6pb3YLIywWJpswX/qgUzLBoW9rGakRkWdUsWLK5khoXRUr1GuGKR1RbL5vrghIYCNtcH73bOlrtzxeI+Yxj/EgdzLMYms5oJdyxGRzWrftyxWL0OT/XjjkVmQyCb64ML9vVYSMkci9gE1W9xxaJqd3fakysWkdlJX+GGRWP3Qp0nNyy0BhOWJDPDIqs9JtuHGxb9QzH4N0KYYZFcmNXhww2L8DSlBxcsyu6msSiaCRbrRX0RUi5YlG+v9ftywWK9uD/KnQsWl/G9cf5csJjYScK/BsMEiwlLKtRxJlhYmibwr9YwwSIop3pLFCzOQpY6/blgcRd4y5xG8H7dPOQmJliMTKdXEy5YtC+NqTy5YLHWbPux6FcEr7Z7c78iszEgVPQrzBVDie7cr5g6TIQmyfyK/LsTiNLMr+ib7rz14n7F6lVUshf3K2rHJ4p8uV/RZWyEB8j8iutUXZg/9ytiE3NaRL+iZm57SOB+xVG55W+5XnEVd77nzv2KiIBCqB/Mr8hpiWRz/dfYLYjSWR/uV+zXL0CMYH7F6EjVshf3K+r7ljt8uV+xXWv/BdcrZq8q5gn3K8amslvduF8RGBr8FNcrnlz1BYh6RXxpcqUP1ytWNjqu3bhfMXtduejG/Yr7TD3+uQDmV5xnHi66c7/isHal1Z37FfaIkw137lf0D8fin3BhfsVnuF4BZYH5FVjSaRNNBSZYPM/9il/hfgXuFzG/AuurmV+B2TkTLLDulAkWWN3NBItOUbB4lvsVuO3NBAvcomOGBRYnM8UCy9OZYvEVbljMiIbFmGhY4M43UyzCRMXis9ywyBQNiyTRsIgVDQvcAUgSzRCmWLzADQt2NvyyuNBXdEaYYpEtKhZvc8OiSzQsQkXDolk0LOZEw+I3uWABWYQZFs9xwwIriplhIeWCBY48zLBg63xF94IZFr/HBQurKFhgcToTLKJEwaJdFCzgIWi5A8EECyy9LxHtCKZYWETFAvICcyzqRMcCK3yZZPEWdyx+lTsWX+SKBS7rmGIBT4Y5Fk9xxQK2ClMsYJcwxWJTVCx2RcXCJioWkCOYYoFV3UyxwL0J5liMiY7FjOhYxIiOxYHoWMi4Y4G5FHMstkXHAoYGkyzMomSBhc7MsogXLQvYFUyzgIzANIspUbPAUmemWWDdONMsvsAtCzduWWAtN9MsnuaWxTe5ZfFrXLLAZYZC1EiYZPGQOxYromOBldBMsniZOxaYvzHJ4lCULLCMnkkW4aJkgbMIsyy+yiWLd7ljAd9hRNQcmGQRIEoWzMhxkixe544FVsozxwJL3Jlj8btcsYD3xBSLYlGx6BUVi1e5YYF122mifMEMi1XRsIDxwwyLl7hh8QwXLCDeMMMC6gwzLE5FwwJLu5li8YgrFp/jisWCqFg0iIpFm6hYQJZijsVvcccCbgKTLBL+L8kCF2/MssDKbqZZQFlimgU7S36eu1HMs/gdrlngEoVpFvCcNNzqYJrFpahZwM1gmgVEGOZZ/DrXLCBsMc0CC9uZZoEV6MyzKBM9iyPRszCInsW46FmwS5x3+dp85lnAx2KexW9zzQIOEdMssM6eeRa7omcBt4B5FtWiZ3EjehYeXLOAeME0C9GywG1QZllMiZZFsGhZfI1LFlhVzySLX+KORa3oWECiY47Ff+WKBda6M8UC12HMsYB/xRyLQ9GxeJMrFm9ww+KXuWARJAoWD7hfATPpRjQkmGDxZe5XwKVjfgWMJOZXKES/4kWuV7hzuyJDtCsEblekiHbFa9yugDIxwq0HJlfAO2N2hSu3K17hcsU3uFthFN2KY9GtwAfBTK7YFOUKiAJMrvgN7lZATGFyBRwdZld8i8sVlLsVX+JuRaLoVkCOYW4FZDDmVuhEtwISCnMrCkS3QiW6FbCSmFvBnBMPLr4wtwKaFpMrrKJcATGMyRXQ8phdcSXaFdAtmV2BD6n0om/B9AroTEyvgMTC9IpyUa+YE/WKO1Gv+C/croCUxewKCZcrvs7dCvhsTK6A6sbkCrhYzK6A7MX0CmgmTK+4EPUKKB7Mr9j4f/MrRI/i9h8lD0SPQviCi4cgbw1QKEyESRSCXBugUOieZgKFIK9QKBRWCbMnBHmXQqFQvsTUCUHejXU8zzJvQpB34gHeYtKEII8IUCgMEmZMCPLoAIVC+TKzJQR5CR7tTWZKCPIxfNvTzJIQ5GH4treZIiHIM/FoUuZHCPJYPJ0XmRwhyHfwDN5kYoQgT8PPEGZFCPK1AIVC8Q5TIgR5lkKhML3EfAhBHopve425EIJ8Dg/wKvMgBPkaViK5MQdCkJ/jl0qYACHI9QqF4utMfhDk9fjCM8x8EORWPLI7sx4EeVSAQqF+gSkPgnwYD/Yq0x0EuQ0vVMpcB0FuwQt9k3kOgrwOj/YacxwEuQqP9jLzGwR5IQqBuQ2CvAUv53kmNgjyShQSZjUI8k2FQqF+nikNgtyGZ/A28xkE+T4e4GnmMgjyMbzQV5jHIMhj8AzeYA6DIG/EE32WCQyCfBsP/TqTFwS5GSP1PDMXBHk8fuZFpi0Icg1+zzPMWRDk3QEKhfYN5isI8gIs/X7IXAVBPohn4MJEBUHegicqYZaCIDfhAdyYoSDIo56MrvZNZicI8hUMyENmJgjyJvyxCdMSBHk/Hu1t5iQIciWe6AtMSBDkeXhogckIgnwcz+BNJiIIchNe9iNmIQjydPye55iCIMjNeNkPmX4gyO9QuDP1QJD3BigU1peZdiDIe/GXI8w5EOSrKF5kwoEgT8TveZvZBoJ8FE9UwlQDQX6KF/cG0wwEuRI/8w5zDAT5AkbUlfkFgrwNr/QzzC0Q5E14Bp9lYoEgT8BYv8SsAkGuwwO8wJQCQT6Bl/Ay0wkEeQ7G4BWmEghyA56OO9MIBHkgfuZ55hAI8j6MwTtMIBDktfg9j5g9IMh7UDxg5oAgL8Of8R2mDQhyA8bgbeYMCHIjHu115gsI8iH8ngfMFRDkHXg97swTEOS7eG6vM0dAkFfj0R4yP0CQq/Ez7zA5QPhkvd/bzAwQ5FN4bm8wK0CQV+HbHjElQJCX4tHeYj6AIJ/Fo32GuQCCfBJfcWcegCBfwMO9zBwAQZ6JPeRZJgAIcg12Cilb/S/II/Azj9jSf0Feg+F9jq37F+R7eKWubM2/IFfgL/cWW/AvyMPwAISt9hfkISheYkv9BXkafs+LbJ2/IM/HQ7/G1vgL8mAM1Wtsfb8gr8YrJWxxvyA/xhN9ky3sF+SbGLfPslX9grwO3/YyW9EvyC+wI73OVvML8gY8mjtbyS/IE7G7uLJV/IK8ES/hHbaEX5Cf4KFfw/J9Qa7Cz7+MpfuCPA8v7RUs2xfkFei/gSX7gnwA3/9ZrNcX5Fd4Jg+xVl+QD+JBn8dCfUE+hJF4hFX6grwcv/k5LNEX5Hd4zDewPF+Qb+OVC1iaL8i1T/6Qj7AsX5Af4fW8hiX5gvwEf7hnsR5fkF/g25/HYnxBvoE/9fNYiS84ZSI9j8OwQyYSaqdMJDQcM5FQO2YioXbKRELDMRMJtVMmEhpOmUhoOGUioeGUiYSGUyYSGk6ZSGg4ZSKh4ZiJhNoxEwm1UybSc9i5HTOR0HDMRELtmImE2ikTCQ2nTCQ0HDORUDtlIqHhmImE2ikTCQ3HTCTUjplI7OsOmUioHTOR2DNwyERC7ZSJhIZjJtKzeLM4ZiKh4ZSJhIZTJhIajplIqB0zkVA7ZiI9y45jDplIaDhlIqHhlImEhlMmEhpOmUhoOGYioXbKRELDKRMJDcdMJNSOmUjP4GTkmImEhlMmEhpOmUis4ZiJhIZjJhJqx0wk1I6ZSKidMpHQcMpEQsMpEwkNx0wk1E6ZSGg4ZSKh4ZSJhIZTJhIajplID/Ged8hEQu2YiYTaMRMJtWMmEmqnTCQ0HDORUDtlIqHhmImE2ikTCQ3HTCTUjplIqJ0ykdBwzERCLWYigZ0ykdBwykR62kNwykRC7ZSJhIZjJhJqp0wkNJwykdBwykRCwykTCQ2nTCQ0nDKR0HDKRELDKRMJDcdMJNSOmUionTKR0HDKRELDMRPpAQ74DplIqB0zkVA7ZiKhdspEQsMpE4n9hGMmEhpOmUhoOGYioXbKRGINx0wkNBwzkVA7ZSKh4ZiJhNoxEwm1YyYSasdMJDvOqo6hSFY0HFORLGg4xCKZUDvmIhnRcAxGMqDhmIykR8MhGkmH2jEbqQkNh3AkLWqHdKQS1I7xSGo0HPOR0tBwCEhSonZISApH7RiRpEDDKSPpVZwYHTOS0HDKSELDMSMJtVNGEhqOGUmonTKS0HDMSELtlJGEhlNGEhpOGUloOGYkoXbMSELtmJGE2ikjCQ2njCQ0HDOSPoN3n2NGEhqOGUmonTKS0HDKSELDMSMJtWNGEmqnjCQ0HDOSUDtmJLGvO2QkoXbKSELDMSMJtWNGEmrHjCTUjhlJqB0zkl7BdMAxIwkNp4wkNBwzklA7ZSSh4ZSRhIZTRhIaThlJaDhmJKF2zEhC7ZSRhIZjRhJqp4wkNBwzklA7ZiShdsxIQu2YkfQyJpwOGUmoHTOSUDtlJKHhmJGE2jEjCbVTRhIajhlJqB0zklA7ZiShdspIQsMxIwm1Y0YSaqeMJDQcM5JQO2UkoeGYkfQSZryOGUloOGYkoXbMSELtmJGE2ikjCQ2njCQ0HDOSUDtmJKF2zEhC7ZiRhNopIwkNp4ykl9hNLIeMJDTEjCSwY0YSaseMpBcxl3XMSHqR3QdwyEhCwykjCQ3HjCTUjhlJqJ0yktBwzEhC7ZSRhIZTRhIaThlJaDhlJKHhlJGEhmNG0otsHuCQkYSGU0YSGk4ZSS/gRO+YkYSGU0YSGo4ZSagdM5JQO2YkoXbKSELDMSMJtVNGEhpOGUloOGUkoeGUkYSGU0YSGo4ZSagdM5JQO2UkoeGUkYTLc8eMJNSOGUmonTKS0HDMSELtmJGE2jEjCbVTRhIaThlJaDhmJKF2zEhiT8khIwm1U0YSGo4ZSaidMpLQcMpIeuQhfJqR9I6H8GlGEljMSAKLGUlgMSMJLGYkgcWMJDDPSAKKGUlgMSMJLGYkgcWMJLCYkQQWM5LAYkYSWMxIAosZSWAxIwksZiSBxYwksJiRBBYzksBiRhJYzEhi388zksBiRhJYzEgCixlJYDEjCSxmJIHFjCSwmJHEhpNnJH3WQ/g0IwksZiSBxYwksJiRBBYzksBiRhJYzEgCixlJYDEjCSxmJIHFjCQwz0gCihlJYDEjCSxmJIHFjCTW5xlJYDEjCSxmJIHFjCT2cnlGEljMSAKLGUlgMSMJLGYkgcWMJLCYkQQWM5LAYkYSe/48I4kNJ89IAosZSW9j9+QZSWAxIwksZiSBxYwksJiRBBYzksBiRhJYzEgCixlJYDEjCSxmJIHFjCSwmJEEFjOSwGJGEljMSAKLGUlgMSMJLGYkgcWMJLCYkQQWM5LAYkYSWMxIAosZSWAxIwksZiSBxYwksJiRBBYzksBiRhJYzEh6C29ZnpEEFjOSwGJGEljMSAKLGUns+3lGEljMSAKLGUlgMSMJLGYkgcWMJLCYkQQWM5LAYkYSWMxIAosZSWAxIwksZiSBxYwksJiRBOYZSUAxIwksZiSBxYwksJiRBBYzksBiRhJYzEgCixlJYJ6RBBQzktgr5xlJb3oIn2YkgcWMJLCYkQQWM5LAYkYSWMxIAosZSWAxIwksZiSB/w97bwNkx3WdiXXf/n2vu9/rN79vfkCcbsPrYYVcwVUkHi0xFO5EADiCWFA5qrVTVlW0VUrs6mEcvsEEpm2IMzJnYFiCbFmGUHZMO/7hCrIWkOU1N+taIzG0QbzKBraZLGNrN6g1HdFeZc2UuVl6zTgwEX7n3Nuv38wABGXJVjkCi/POvX379v09995zzzmfxUgCbTGSOH+DkQTaYiSBthhJoC1GEpfZYCSBthhJnL/BSAJtMZJAW4wk0BYjCbTFSAItGEmgLEYSaIuRBNpiJIG2GEkL2inCN8LiPT2ocZJAW5wk0BYnCbTFSZpHZxmcJNAWJwm0xUkCbXGSQFucJNAWJwm0wUkCaXGSQFucJNAWJwm0xUkCbXGSQBucJJAGJwmkxUkCbXGSQFucJNAWJwm0xUkCbXGSQFucJNAWJwm0xUkCbXCSQFqcJNAWJwm0xUkCbXGSQFucJNAWJwm0xUkCbXGS5lBMg5ME2uIkgbY4SaAtThJoi5ME2uIkgbY4SaAtThJoi5ME2uAkgbQ4SaANThJIi5PEXxKcJJAWJwm0xUkCbXGSuAAGJwm0xUkCbXGSQFucJNAWJwm0xUkCbXGSQFucJP6uwUkCbXGSQFucJNAWJwm0xUkCbXGSQFucpD7Gi8FJAm1xkkBbnCTQFicJtMVJAm1xkkBbnCTQBicJpMVJAm1xkkBbnCTQFicJtMVJAm1xkkBbnCTQFicJtMVJAm1xkkBbnCTQFieJy2BwkkBbnCTQBicJpMFJAmlxkkBbnCTQFicJtMVJAm1xkkBbnCRuWIOTBNriJH0LBpXBSQJtcZJAW5wk0BYnCbTFSQJtcZJAW5wk0BYnCbTFSQJtcZJAW5wk0BYnCbTFSeI8DU4SaIuTBNriJIG2OElMC04SSIuTBNriJIG2OElcXYOTBNriJIG2OEmgLU4SaIuTBNriJIG2OEmgDU4SJzc4SaAtThJoi5P0t8CzDE4SaIuTBNriJIG2OEmgLU4SaIuTBNriJIG2OEmgLU4SaIuTBNriJIG2OEmcxuAkgbY4SaAtThJoi5ME2uIk8bcMThJog5PEVTE4SaAtThJoi5ME2uIkgbY4SVwVg5ME2uIkgbY4SaAtThJoi5ME2uIkgbY4SaAtTtI3YzUzOEmgDU4SSIuTBNriJH0zn2gEJwm0xUkCbXGSQFucJNAWJwm0xUkCbXGSQFucJNAWJwm0xUkCbXGSQFucJNAWJ4nzNzhJoC1OEmiLkwTa4iSBtjhJoC1OEmiLkwTa4iSBtjhJoC1OEmiLkwTa4iSBtjhJoC1OEmiLk3QAB1+DkwTa4iSBNjhJIC1OEmiLkwTa4iSBtjhJoC1OEmiLkwTa4iSBtjhJnL/BSQJtcJJAWpwk0BYnCbTFSQJtcZJAW5wk0BYnCbTFSQJtcZJAW5wk0BYnCbTBSQJpcZK49AYniWsoOEkgLU4SaIuTBNriJHFpDE4SaIuT9E3YBxucJNAWJwm0xUkCbXGSQFucJNAWJwm0xUkCbXGSQFucJNAWJwm0xUkCbXGSQFucJNAWJwm0xUkCbXGS+F2DkwTa4iSBtjhJoC1OEmiLkwTa4iSBtjhJoC1OEmiLkwTa4iSBtjhJ32R2+vi1OEmgLU4SaIuTBNriJJWQPhicJNAWJwm0xUniNAYnCbTFSQJtcZJAW5wk0BYnCbTBSQJpcZJAW5wk0BYnCbTFSQJtcZJAW5wk0BYnCbTFSQJtcZJAW5wk0BYnCbTFSQJtcZJAW5wk0BYnCbTFSQJtcJJAWpwk0BYnCbTBSQJpcZJAW5wk0BYnqQADFZwkkBYnCbTFSQJtcZJAW5ykgneMgpME2uIkgbY4SaAtThJoi5ME2uIkgbY4SaAtThJoi5ME2uIkgbY4SaAtThJoi5ME2uIkgbY4SaAtThJoi5PE5TQ4SaAtThJoi5PEZTA4SaAtThJoi5ME2uIkgbY4SaAtThJoi5NEyN/gJIG2OEmgLU4SaIuTBNriJHG8wUkCbXGSQFucJNAWJwm0xUkilpIJThJog5ME0uIkgbY4SaAtThJoi5ME2uIk8bsGJwm0xUkCbXCSQFqcJNAWJwm0xUkCbXGSQBucJJAWJwm0xUkCbXGSQFucJNAWJwm0xUkicrVaLyO9WJWxVuuFqw7LfxTrW7du3fKOZw65+uatW7ei45nDT9zM0X7R0k7hpm6Sf9bVfhGSq/38F92ydSRzKS7chByKD6mYHGodUj707Rmd4EjmUaT7VemSv6TiMjyRudQC7ZdtLk1lgAwivVCVDoUnMkUtapNTP/Mp0vO3eRZTpOdu8yylWPtHM49aOq+KCJWQynq20i3tDbnCpyviksXUWtWnq8JJVUJuAauBcCVzQaZxwgkLNw2TxncKx2Tm6uBE5lBEqE7qJhSQo2dO6ZvREOnKRN9yj2cuR3dG0aneVCcyj6PDUXTG0T5H33KH5mOlw9EBRVg684pS8wDBuKLEBE8i3K8oa4apqgt98pByEmpptypaFGm/KiJy9UZVupmbxklCid5U+tKPXXXy/8JJCGXUV2wIRdPXbQgl0jdsKCQ3/1HXSdBw+lydBhYoJqAOo/ed0j2SOTwOeBRoP/8pNJkxv/h5cmdhfkGrpXfA0U7hm0ZW2nk0c+pRqzI8DLRbhGZsuoVPSrv5L7hliMEXFj5SkEINi5izyFcyh5R+BaN8WATaKVp47h2mkFoVvkUutTBF3Oo+xynbeuOk3nSHOjpFMSKSUURFsVacKh2P9DgyG4/0ObIzHhlwZHc8MuTIfDwy4sjeeGTMkRPjkS2OnByPbHPk1HhkwpHT45EpR86MR2YcOTse2eHI/nhklyPnGpE8hvOjmUOtIsDk8nW30qQdmtO/sSGP51cWaG7t7Y5Dvu7Iw/7Yw755mMnD2bGHs+ZhKg9nxh7OmIeJPJweezhtHrbl4dTYwynzsCUPJ8ceTpqHsTycGHs4YR5G8rA39rBnHobyMB97mJuHgTzsjj3smoe+POyMPeyYh548zMYeZuahkofp2MPUPHTlYXvsYds8xJNk7EnCT5jBgpE8UZWR7h7PnIQUtY6Cc2JGxWZiJeBbVZFixnmHwW2rsi0Tynk0U+STaea9PhIxvypbx5B7WgQJeRQcUjl5FILZe0DCicF4xyKQCt9JkDRCoRxqg1itii6swqpiQlJMk1sVU29sCVVVoiTFLAWkVkHNkG+ovnaK1C4gzIO4Ar7eeDcyvt9x9TuHRUy+3j+U9YZ8/Z9iWW3pcJ3itcKntlZV0UFJY8S173eUfudwjbmTr5/AKhJTS6frktjbkdhDYmrpiXW9ufmKM5RUflV0EkqpvUqdqsg5q1+MUNu8SKlTtFMF3jtNaVVOHgEPpUlS4IQzlFZHMjdNEpoinvn68KNZW0LMPtveYf20Cw5K/tHM57w3qrKFmsdcc1PRp6qydb/jlOHVMkMmYPz7h4WfuolJ8aFRCsyI93CSriQhX5+WxzoaorI9bhYfD8L1N0ZzMxQjVMZ6c/OaczQLEmrJIkFt7Q65erHedFG/WF90H8Pinr/rSObyC7ydQYs0QvzGf1KVoZ48njnapXBdf/jDNMwUmv4XNqqO5zquk2i3aFNctLB+ysedxOwm5Hst/UvuY1lArfx45qYomf59Z8gjev5E5qMXZrnJuQ+0qqQb+qYbTMPLqoJGbNURdWf8EDrDpwnuDLSr6Qk0J+Zw4WND5w4Lng++GZUtitEbIdeUx2SIlmyt8XhF9kjN49S3Y7LFiXlMtmC2vS6JvR2JZUyGozE56hnmVYWf/DV2zo6u8dE1RzI/9cb6ZiVTiPG54XyZSm2wnLgqUoqLdkKenqrIz/9Drou3pPqlb8a+t6SmSh+fKaMjKCE3PQ/5zfxE5mjFEdrTPqI2/ZWFIk4okQbG6zElq49lDnkaGzmK87+L2LQI8ZOjSsxEhadE3NHvHOrNjTUMBR2eKjLzLWywo7c7+Mn0VRfMk2tKPihee9NTRYfnE7+fcTHN7p/fxVY6kyw6+kXJQiXyzJNnip9JDpLS1xOn9BOcFkx4SU2Bo9V80ltSi2XLNBd3f5lJS7WkpeK6pVq2pWLTUi20VISO3KOlWjtaKkJLRdjSZhhYtpYhGOgprqz5EkUUoujRqJ0CDKfdrRPb1uE3gL+WyYt16wSJPPPkmeJno9aJKB61jpkL0gYdaYNoZxtEO9sgQhugMNEebRDtaIMMbZBVRUidZhtk0gadRhuEUpWQOo2xwm2Q1zUwL5g2CKWCIXXkxdy2gZfIM0+eKX42aoOw2QYK7RUbKmQKC3dESVXGh1RulovGKEl2zqdk53xKEop5lCTSQvGohWJKpIV4eKClShkj/J2sOZsymU2d0bea3X21ngz+Hm3kj8aJHQTyYt6YRZG0USRtFI21UbRjFkU8adEyCcVV6duWae3BaVq7OU1rxGn8IhlxGn/UMv4uTpOgQ5KqSGpO0xpxmpadQ+ZrGHsoeDKaQ2AUe8yhlm2bRDhMIo2ajHGYRDhMIs2XjM0hZNtsm4Rapm1i8mWDl/oItvYYRNEYq/F3sxp/xGpibqrWHoOoZQdRUg+isYbaxZIbg2jPhrojK/7KG8rf0VB2ELUwiLihPGm3xARVwhs8sBhfeDUYjHaP1yxGK1t3v667j40y18rXv+Nik+4hodlsm30nhCVdbNohB0AGIiby7lUxbF09TdUBp5tgh21FB8psi/SLF646+XkTycev523Mbx1QrQ33qW9ydL5augdGMgFH/ynWeqau3/rBx1hEAKZLjk6qUuknh4WnnSV19YIu/SOZ0vsLSFn+9NatCIunp/8WKR2f+u7SWT2l3SE52l1f1Y9URWC2sfY/ZHLlgi5DnXPe+jUPqZOVhdLT3qlSnSod7Q0h6HBwFnaHpHBA8tZLTz93/vMOBFeevgIKIhEFCVl8lOVQLL8SmRq6mwt87YJ+LAvJyZQtvnME4e+BAOXZnzC5xEvK108OyVstFMVF6PFas6Ty0sF+C/sUEOGS6heBabMQ/elQeIz3hFM6R8xU6SxIjm9szByRiYVcxLHSuQlegQuNvCJngeUoEfaapYNoh6ORW7xQRORw7rHNmKVQDkujgiNZKIXBVxbrOqFC2MfB7HoJIGY40wQowhSaIm0lEsrJYeGVNJ5DaBsMueCQ6ksV8zLmvkC5c0nWT6SmMTckHkyZB5DyOXVTljEpYK2uoBYKAi2KF4qQnCJOnSTRaLanKPq+4SGMiAQDqCocuBzR3hpEmIq8qvQw/n3tIiYNhG04pNa1IrVeOtohZ3Vt+D1lC0NEmSESkxoNEQftz6e6CIVNKaZIOsEUoVUXwam/7eDbIYqwCpmWiVMVcnBkGF+7oEsFFuksqesXdOE1x3ekMfu8U+tla4gkOD2v8VcIs8mUU7YsHGPLXkSNshe89HmY/Qo/OKBHo3M6pifaDPU4hDJo7o5rF3TqJ9oxI14/xYMDlbx6QSe1VJBFemFd4xA1RsNJZWNUFji5tqoRzhqmqk6zqi3tUsRVDaSqLQpMVaNRVSNeNjimjExVIf6oqxphryziaTtXWvIT2SkjI8KpC8zMwBkND+7R2LaE4pbATIsrzHoXHNvhOV4zvmCMDYTM92KeuMiriMFENJSt3FOrFOj9RxfWiwjPmUOEZSijvWyRx2clDJcWKd4kFjG1CkUtzlRRWHAc7/gwfVrEx0OK9L5T2ocEOy/DYwuIXSgg23fXi4hCLAI85OM1UzpFQT3wyR+iat4OhlZAgHwkcxPvsJ2QZYiyRYTdk1opWwsFvPp4K+AvWKUX+FTlcCkybtWpIoRIKqAI0zfGSWYBYinUg5emmI/jdnw40gSY6N7qiQzClya7jIVdxsf4AMsMrcEuwxG7jPnOww6B0PR9DL4Y72CX4U52GY7YZWgzHrFL9PcRKwmnGF9qsMxwnGXGO1imP8YyedtuWabbYJm8aITCMkOkDg3L5NqGuPrhB1PmAY+z0USNd0xUO03jeprGPAXqaerYacppN1kq4pDKV1h01VxvLT+KZJKGFNX86DV3xJBCYUivuSOOFO7gSKFMU9XoI/xYUWI8Pk3jndM0YQF86WS7dwZmHJ2w4+oLEpYbNr/SX77webDoX970K725eXqYfw/uHXAxFpotC26csC8PpFVfuKARjstWVcIfFzMGfvJFcG+U+vjCIQRGhXje8vVnz3+eZzoqzEsvV6KMqqOZIl5VnAwnuLqhX5TtBRrXwQwQvvziBY0+vrX/kHqJvw//yOdBtA6pTzMB/UUQ3OCH1FWmrzD9hUb8FaavMf18I801pq8z/cVGmutMv8D0i400LzB9g+kvN9LcYPolpl9ppHmJ6ZeZfq2R5mWmX2V685OjNK824m8yfZPps58c0VtMb30S9Mcb9DmmzzH9kw36PNPnmf65Bv0M088w/ekG/SzTzzL9uQZ9ielLTP9ag36O6eeYvsr0Faa/0Ii/wvQ1pp9vpLnG9HWmv9hIc53pF5h+sZHmBaZvMP3lRpobTL/E9CuNNC9J2zL9WiPNy0y/yvTmhVGaV6WdmT57YZTmprTzBW7zC6M0W0yf4/ifvDBKc47p80z/XCPNeaafYfrTjTTPMP0s059rpHmW6UtM/1ojzSWeDiGutZ4clo5+siqhX9BI/ZykIGeVWvo7q9KpwDriQ+AM2F0aLoitABzb+m/Mevg0O6RuXNDgBQp31GCf6rgsail2c0Neg9xTFBzNPMN/ee4iIwpXFnjSiuwwNmzAXoMLK4iIzxw1E1BJsmfublK/zjsGw0e4NFhTwVCqMl6BTBaZFU69NW40SmQaZVdD+NIQODnfoSG4oT6A7JAZrw86r1YWygiuI33t3AvuU7Qp0nmF0KXzesDqBWDT1B5gE3gHroV14NJ57PYWK+aXDpxFgs/6eD9ewRockTqaRRRRhIXgqaFs8CNSq6VjCi9qAHwIlYVMkZOvpPHYQhZrlxxeyEJZyGIKzULmNBYyR8T4HFU6ZiGLyRktZA6vVHKwVljBlJwOeFmL0yiRlQ3NhV6iVsUNLP2ChhytydEoUpkFerXwSJnVBPoUymyd5SpI8ZKlvaF2j3E9Q96h5Y/wLhyvUljhbOvLHiw0DYbFz63PJzgRoLm8et338pU0HGsupXE6bzSXqpvLazSXVygWQHNzmYN2oRoH7cJLTMuQZ9d9JT84nqQBBi12fpjBX+AZbJZSPknFPIIpbuxb6pMWH2HSMLGHC2XOeHzywsUo8jKbehDXeEQ79SHxdqeAa3IK4IMcv87SVLd+i49yo+OmPYBCLM3HtUqmka2GmZCKu95OSIfUqtmo4DpU8awMZVYC2nO0SdvNnrxEps7ztrXwEk688nrd11KhUQvwNskkvNZMOBoUssWypxibeanIe8wMgq+4o6DRk1cHHHKSv5hUnY02xEnPO6tlYuVJ1MaddgpdkYh7r8i0U3S0U3S1w7esITO401XZox6FxxfK3lHeNPVWy572hhALc0Y97Z/InGWnmEMmy04xL5IThWtOtb5KQaWfqDj7LqsLnB6uFrl3mDJcrmeUUxdfYUkaZQ8qZ5nOUKb96kHlbBXzlOm4qmNTiZ2jDh90qbuKG4GA9YH4m5V8tCoy7zDN46W5ZTqLfOY4B/M68i8y6uC2t8PnpLnl+CzcFb7WfZrmlvugb0ZPb9Hc8tLZ5c3XPcQ+dHZr62LRBYV8Ebu8dAZpOPFyn2M5j+X4zNbWMp29WOQ038h6vpH1fJ31vMk6A3WG5kdZz4+ynt+VdQci0OyQeg/kpIfUo5RQ95B6FyWUH+LZmeirTqV9HKjJz13cpp+CRk1VTtIEtXV3WE4tFNOUPKgOL9OZYg7Uu4p5/DyKiAVQ7ykWecB4w3IGG+e2VqfKjGJzg4BWLzPKlpSjNzTRjPbWV9eHPMLnaW6r2EcLtLhVLNQH+mkWyBSz2in63mEpJeQ+1KZ+PkvJvd6jKAqIw7SwXc4/wlWcX6azj3At52jfdjn3CFd1jmPHa+uSyjGF2jS7iqJR+/hCkVEfN8h9rqOt8hbt25Zqo75c2y1a2C7m9WtmKtAshjrGK86FHdS9h7r7lOiDFWpI3XyVIh54PFvQELdcYh2n/rDsreuNk0NK7ncOUm99Dfws0xs89DFZcdPiVkWHumBaXf2kiJQXlh86U87TwmgoLIyGwkJjKJSLW+U9tNAYYwuNMbZQj7EFHmNb5cKye6bcT/uQPy2/8+xWebfDmRaWo/oji8uz9UfuWf4W85H98pFigeaX3/nDyz/sPk1E+0ZZ7xtlva9Zh/mtcpH2Neqwr1GHfXUd9pk67EMd7tm++5Lva5R8vlHyxbrk95iS76NZ8KVZ6tO09MTUkUyNjdFJO0bn6zGKxly8m866Z6vc/xY6i1DRQjqrfGudRY0q39Oo8v66yoX5yD08l95TzyXMsHlatB1Y3k0HLm7xmLrbDpR6vYUOpNsMvd21mWN+8a6aXxzGwrObO1CiqVreWN6gKe2t56vC6ITzFYvCB/czlyhorPcn8lnaT8S8Y267nOOhUO7fxu/S07R/u9jPrYfn92yX8zxCykV+Tk/T4naxyHpoP7a5+cSwwZzaWIu7w6IDzpPvxXFwB5ULx8n35DjdXRwn38FxuuA4XctxcnCc3HKchBbBbHcNhf27me3eTcoMd1r762WnyXXvmt3mUmVPHW5U2uHBePc1du6qxkcz7NgS6lGsz33CaPPePvDWlj1WilDrZUat/Oddue1uREAky3GZKAfzFfKWqPHkn+MwyyYD3tDUaXzEedBnGsVBAsY3iLWicaKfd7DxS/W/vXUrWkk+mqt0I/6r2P7Nmu1f/6u5/evvuf2bvbvtXx8vzfL2r49fu/2b3WP7N9tgXbMN1jVbs67Zevs3C6Y1O2JasyOmNbvH9q/fyLrfyLpfZ92vt399ZN0fZd0fZd3/y2//7DZvVthbv97mzRnuZ7d5E3cx3ica2zzsJItJ3l4WU9SnWewA5mh+iw8F09opZmrRTJOXztiVdNaupHPbZV8YUL9mQLNgS7PCgGZvs9tzwXrw/wpYzgxm+8wOzrOwLRVvcJ657aI/2uhNm41em7KjuxjvwQr1+kq2eu6bbfWwCJb9u1gGZZt0tycV7BXcM+U+2Z3cw7uHux26NPemGyazlBdz1Lc7hXvuZgfU3+Jt7Vvari5u333Jm5vUfqPk83XJF+tN6jR40DTN0BSzn8mdO70pMIhpDFK7WZg0m4V7vcOFGbv98VfyWcPq7rDEU192DfN21zC/XSzSrGwV5u1WYd6ui3fYDGCf+rXZDMxjLs7KXJyt5+Li7rm452bAvavNwB2m5G03A5iFf62bgbfGHL+eNgO/nhnwF3e1jA84LH/i3vo7rJ3lHYeQ9DjF9zvxEEI0lnvG9zst1rDOK8aYifLvErUwyNqeGJauzuW6+MuQ1i1Bz2RJ9fN3U6xfdXDve/YjVx3WRANKTbykHi9d/DxRtvO2XMauQ8H4jSn7JLYYXLV4ST3ZqJ3JC0w+yr+Li4EUkPAvqdP50TqbI9wdvnblrSX1PawSQVB+MJlJF7jY/ySrBWz1PlBZe0Ho8rlD/VTVUY6rsK5B7dhqY6BeZUroHOx1KCV3tWzBwsMFjQtH1L/oiL1WBrsO2GulRzIXeaNwKXXIrah1s+2wbNs9pPowM1nJVF2EJzCU+I66Sy5r6XHju1hLtJt/Bz8ruuZjcoOfo1pukTObSPDXoQ6xIV1eUWY/l1NWlZncgncpW2XRrWSf6XyUfZ1vllAH73QovddxBg5yTe914oETU8x7OFQg5ar+FF+5oy1S/cxHrjr5e7CloxZrv+mcWtTS50x8s0HbBPMXUZpflDFUdqlVIdvV/N28Njt83b+kSJrmQNnRLE/m25k0x6tLFWFLl+ffRS1yT4iNDqX6ui1Ki0t2o1mCtjG7g34uj+JlF9AXMbUPqQdSZSzvcqjLoycjHpuQbz8sCko2KbKSsddF97Zte7cPqYfRMzm1uRVstQ+WEbVXZKDfVyTUZsWOiNqrJTf8A9Q2Hf9ARW0wLNszDxetRJKKHkTHTJeH0GAJtSvqmI9LWtYJ6VRS4vYh9Z3aKSKpw/ugXqEfqA6p9zK1VGEvGfNiFuvrTpV/O3rzMd5qu5TqS7YlYWcU6SuNlmQ7UdOWrm2kD1LMCDD5QL0fBgypZxoU848btC3Mz9hWcgE/ILPi/ciIOS2atDFdPphIEz5semNJPcTs6QYziGjUUh8smM98AHdmkhEmHkV1XhTxrd0HDe8Cb9nJtYRRRSPW1aLorXKtFjeWWs+/CwneJSpoksWjwsHWhSOeLh3T6VyEZl3WpS6nC1GeNt8SUxWhpI4+rkw8W0fwSVwORBWzSLFM5aaXFcPyRzaOaC4l8ZJ6f2kaLWomPyyx3njsA6OuGH+wVD+4zz5g7RmJzhofFX5nU0+NUutNF0uYSv4oUZ7oxeIky9hlonQX4I7Lr4XLrv4wLtr0X7iiIsqHhZDVU9nw6DfYoC0yZkiw1IJJr6qKEJdKISyAQigSRWIky+kVRZb05FQc6g9howRXeNCHCjAyc1E+ghVgxJa7UMPBViM0akkpeuSQynXA18TuCutC6d/51FW2/FmQhBE4KiecaqreiKYRieYyscW1gtaNwsKPyzIXMmncYtmi+jD+letwiupYzJGNqoyk9BFrn429hC2EW5WKt8RuwbZDgZ64vExnypgCPVVd3hp4D1FACKUcOkiB7iEFBdrnmCX9mc2rMN9egmHRh5y3lkds8vgXb5rH4bE8HrJ5cIrY5sspbLk2d+aJFl+UYUTMWtVxWROOmKWBt2MRVnioJ9atefhRszdA48tVuHss4zvaUYu+RzicOprZK9OoKh2YtUVi1haxWRarmDrGng36hvuHYBQUiT2bB3O0cE2SsXGbYw3ZQk71xAmjJwpDtrFUYsHmNawqI1PnA3s056NjzQndy0nTJYt1A0+ZmAO37fozHx5vYiwf0Gr0RefdwznLw4BlRcTGNPPuYsCaBF4jAa7P3ar0zJD1Gp3qHVIE9dzjvAfbs0u9sS4VlTwUb0Vm2rHMky716i4Vjd+jxubrb3SX2pn4ezu61M9/xq171rM9+1WY46+nbmRcJLQOwEQnrsDock/7RuugBR2eNn7yEueKgML8vWyLwMuxeKnQjjFf8Y+M/Co0rfb0h6pO4Hie53oJtd/mOPpL19yTbMNcKpjqfsk5uQaLlWHmQtGkvcRJPvbH6iTbLpcKEZd/87c+6p1cg5Z9M4F99hN7PMPL5vFPy2Ox3eEy/OFvuieJP/9/1J93WHuy8PADlw8utv9QxeXlxzvMXQBT9IC8HF5MoIviQzPeX8mgbVnn/qXrYzVsfiLU3mOZh+1Zrn32MpH52te57Mz47T+5vqtsOGzwB+GhxNN+rmFGjq5xtDtEzVtQ/nURIxClKKmf54hYLBL89PE6jhwpq8WgJKJPAkWeGx+VPWajYArcQ1HAdjaxUA5UobiVUmmlzLaSW4S2z7mlFIsoMkpzl5U/NqrSg50u6zw5UPphJyKss+StwrKB4uOwe3GPZj67FCkDKB9zUh9JydPqKLuDiZvPYvPMw7kw0qmxkuBnKX/CLxLW5oIGuWaNInQbrNW449CwKaxAUEi7m+B9ijvaV0Bb5uZHRDJhmuymaTIMCNNG4XgbZdJGndFI8rhtQm6bDmW5PQ/HrIxiGyc0jQOZjzQPOgLNo9A8zljzhKZ5WOdZydO4+TSun3ryNIVmljJPU9N8nGF8PPObIyALRkPT46YTWyIFilWk3KoQS7C6dcjj3CjWL9sGUhTVgyiSBuJOCflDP+Wa3kE/UACFL/g9Jv9E5rMCoC2CGPdg/jiNQnqZW6cwPfPMOfNhr+4Z704906WoCOHlpXbsE1PwGK8K6AoeTo3+CHb1B7MBl/NkC53x/mCbi9HTHf0RNvrDs/3R5fkbyBjhBR9Hlzv0ToDeYccocD/BlmYw1Kjg5iJo9k7AuUHOdq7unbDunbDRO57tHRhmFSH3jgJ3BGtE7yhRzyxSLE9JrTyIzXFqTAxabL8gB5zQHDVabLCXvJK64TfWn13rTyrrT2zXn7Sx/qSN9cf5m7X+nPvLrT+dHetP5/8H68+lr2T9Gc0M7zCzdFaxGm/1YC+GPb5s7VypbDMGYDbHF8oAzehKMyaUNNcpMChpqrj5zHLFoLFKjdawUUPutWZgveAVzANbavMlgycCBmUWqVCWK7WrMUfL1TOmPUeryVteeK5/9M4LTypdEtddYheesW4Jbt8tuzi12ZPKSrFrwbLLFe6Z0C2JdAtzjZTS5nIFu2t0Cy9Wo2e2W5LGYmU6zWt2y+0Wi0Amkn8ii7lbgrpbgrpbgr26pV6nruzqlq90xfnMhFzLfC5eLf0DDvnLvw4QZjVQNyPylz8HNOf2QL2KwGWgRLsD9TICnweg9uRAvYTAP8Hikw3UDQR+AxksDtQLCPwIoJ7dgbqOwDlgQE8N1DUEPo3cFgbqCgK/jWTtgXoOgR9Gsn0DdQmBn0Zu3kA9i8CPojgTA/UMAv8nSrAwUOcR+Em8owbqHAJ/ALzy/QO1hcDPAEN7cqBuhuQvn0Gy/kC9isDvIYPZgXoZgT8AmHQ4UC8h8O/xUXegbiDwhc3NzYMD9QLof4gH6UBdR+AV5BwN1DUEPgoc695AXUHgf0ZmswP1HAKvoqLeQF1C4Muo6MJAPYvAc8itP1DPIPAMcpsaqPMI/D0EgoE6h8A/RnW6A7WFwGcRcAfqZkD+8h8CLLw7UK8i8CpKsG+gXkbgZWTQHqiXEPhtVHR6oG4g8DGUYH6gXkDgH6Gg2UBdR+BfI+u5gbqGwEtoqe5AXUHgx/HOxEA9h8AlfCcdqEsI/BPgbs8P1LMIPAuw7WSgnkHgf0IJnIE6j8A/RkHdgTqHwIvIIByoLQQ+CrDuhYG66ZO//K/QIMlAvYrAr6Gz1UC9jMBvIrd9A/USAh9HQXsDdQOBX0DWwUC9gMDvoAQLA3UdgRdR7XygriHwU/hOZ6CuIPASqp0M1HMIvI5ANFCXELgG2PGpgXqWA+g5NVDPIPD7CEwM1HkEfgLf2TdQ5xD4LRTUHagtBP4dKjc/UDc9FBTv7B+oVxH4l2hRf6BeRuAKajozUC8h8GsowT0DdQOBT6CtJwfqBQSuIoPeQF1H4H9BFTB9EPhv0AbTA3UFgedRnGignkPgh/BOd6AuIfA/og32D9SzCPwqvpMP1DMI/A8ItAbqPAKfQTfuH6hznBvaYN9AbSHwReQ2N1A3FfnL/wzfaQ3Uqwj896hPNFAvI/BvULa5gXoJgV9BbslA3UDg5/DO/oF6AQHwief3DdR1BP45yjY/UNcQ+GUkywfqCgK/hNwWB+o5BH4Xuc0M1CUE/lc8iQbqWQT+JbKbGqhnEPhpjJBsoM4jcAmDwhuocwj8CN7JB2oLgX+A5u0M1E2X/OU/Rk39gXoVgU303OJAvYzADyMDNVAvIbCNwORA3UDgJ/GdiYF6AYFfRNb9gbqOwBaaqj9Q1xD4FdRUDdQVBP4tCrowUM8h8Idot3sG6hICzyEZxhsCf4aBNDdQzyDw3yK3aKDOI/ATGC7+QJ1D4B+hCmBvCPzfyLo/UKfBQvD+1ECtY1qgatMD9TgYN+LnB+qD5C//U6R/Y6y9n/zlP0dJkoF6H6YrMu0O1HvQzWiJNybOu8hf/vv48hvz5mHMFOQ5P1APgFmg5sFA3YdVQu7ZDpC//CeozxssbRFFQ8e90SNTqBqSdwcqJX/5S+jqN/gZ/MMcOqQuxabxPwc2H5shsxkP1DMInEVADdT52IxtIECfi81gQrfG4oEXMsUSmgpsgB+bDUrtgTca98Ab1R54o9oDLxzfjjzwhoU18Wp44I146TeaEcaR70bhszsFfJdc3Ou32N+t/iAFqziz6GilSLzxM5iIgY02w0YlZoiw/kMp8PR7yxa5j6+WKXyP6QRaN76s/vH3lXHD263O1+2TkWdc3V9fs7EjD7g6HsWOHOAiaiuuqGXCur9+sojhqwMlK3w4MUwyn1J4/mVD9wgm2Irt9/jUgGOnjz1Fom/8qNmsmM98NcspJnsRefqm/UqbIm47TwrjQOWT2HlvLP54EQ2nIfhhXwUQ1kcVKxr5+nOx+MmJyF2lUL9ss40pksMf72BN7L9uK1/0Wa86q2XnADRPJ5YdUlAPUyP1MDVSD1Nj6mGqoXmmGppnqtY8U0bzTC27T3+IBzj+/d2nycU33NE33NE33LFvuI1vuI1vuPU33OWHzpbu1tbWsnt2u5gkteyeJXf5nWe2iimjmNCtYKzTZd1aVwQKuVS2cHGShEZizOfePJ+hzr3ewfKr3wjkbhfTyPyh8qtce2Q+sV3M2Cppp+jRVKFospjl6lFFMzRNPX3LOZmvooWeLvrLDgpD6JlyjmafRvlcmiP1NDKbkHac5XZUyw71x/pvlht7lnoYfj296fJ1UY5Qbjw5wWeaafmWGCnltq3hgzT7G9rW+e3bOv8atHVLdAxdyi4ve2e2iz6M0YSEvnCvOcxpjvp1waZQMCUFc7hgszRpCjZLU6ZgUyjYpEwmsIaxgk2aGTdesM5Xe3KjycPt0mUVyYeoQxPIf2KU/8Qo/4mx/Cca+U808p+o858w+Ufb5cQj6gHOHJ85iGpc1LdghvwweTxsWTTiU5DDpUnnQaR+UB2E8uXAiyXqIVzHdR5UD28NPPhdvurwobTtzmzslluau1F2nhMcyczFa4IIRSxd8VicUaYkbt61gstYSD3KDFHhjryMXAWu0/kaF4qUHYgJiq7xoi7O38ucI3vsbp+1kRA5wZEJIlNWkEPkJEe6fNZ3tDohfu6pBwXQYgoOhjzt6ZsfN1Irl5zVYpp8GMYryKJgR683Kq1OrRYzHHhKArMc+JAE+hw4LYE5fMw7TDPE4rZZ/HjUx49Pc/gJKIHjPKyC61BFTXTnVDFPXb4qXoB3qapYpC5i91EPSe+hhB/u5+W9INZaHC3l8GXYWMRPUkzdxvJ9klqYNvXCfZKUPl2Z5oJrgUJhMwEPsNCkHcu3uyNfp5mpO5YpNF5PllO4qqD5Rha9HVm4O4q2UOewyDlkRY9Qw32NPNwdedzTzGC/zeBk6RQuTZPHolThLCkFJzK493fXSg80FP/UWqlAZ5Rqb63sUrCSdagLzJ8ft3gADFtXgwO4FTCJRmGF8MujsIfwzR+vBXZKn/t4LQp19DMmQGjwS/Vw066+8vH6g0pft0+UVvqGfZKR0i9/vFGuc6PvBPoZG1AMqmu3ezJ/2BMAa6+PeqroMbTDqBMKKNMmjX4vsJXIGk1exNThqPHtI/LdsXds89ge2zi2x+Ad1sivER1YqAfn5j3ovcLfNbJw8dYaP5vgT4zlx8VoZtcew5rQuSnEzj0tK0PXH53kjHfWxtldm2x3bZIdtek2ASf+97byZFNKvCeVFZXvG7ST85reL3rQnKXOvU6vnLi4BuUUy7f71KHecny27D2ippgBw4HHgu5QuLrGPI2dQLzmVmuXsYr0tvi1nFz+ELN43FAZFj+FP/kYi+8bFr8IFm9VkTuaVdI69gw3UqFr6Vt/wee1Dvw5OVoZRcQOZJhd/KRliLNKvA53qa97Qx2fWnsHKwfjiqDMD6lUtqzBnfPAhxrvsZOqlr714ZvRUMdPUkv3n5TQGrvv7GBBCFd1XTiW/Y8yLiRjCG27FFbkHzJ5+1ImEm1to4UaWAXt7IiB25DrrBC6/T4k3YoyZBOxUqeBI2Ebg1PwCQoVFmTClyElfEzK9dFGJQXNV1dsMXN8alcx88T2RReq2plVC8+MOjVegwM+514vLnr4cYoJyUHK2a30//YjV3FJ2T2+8K2OU0xSCIgJE9mRyCkKAS1hIjOJnKYQnj1NZCKRMyxIt5GpRM5yC7p1tMvRy/EZCvGbn9nizrOPFT/eWl46QyGQHkx0MHoL4BAmNpTEfP9k4zxJ2T/D/WZjfYnNYfwW8ydb9bNWI+92Hduu847quKiRd1zHxo286UzJ5njU2zbzkXpmunVokqZomj/fpxkxPcJLUqIJfmGxfqG/e37iOjJndf+FMmAn1s3pChbRnKvFBLw0rsK3OIQgE9ieOdTDrMb4wglF8Wiw2vesiNqyh+ZYP/OJGpomNaca8XrXHE8Gs4JZVptPOS3LsibBovgQwDvNHRyLJne10K4Kv1n9wib2xVgVEz4CHnCS32mrdMMHc33RYbMVIzJq1+wq1NFQZnBiLt7FXOZUGYpRgRLvp6F4R/XFvSjAYPK3syfDgtWP/SIdlw7B3Eh7K+xnfIkNZcijlJKcTVweKNgI5aC4n77XW1r+1G/91//w37zwS//8Uxvb5dQyhF7dp2kK9qfvrTXVjer9Q/KzJKV6lG/P4T/XaOD3K/5k/k2U4A4bOz1vXcfo45B1okXPoI/HwdjjoHbXR0kHygXsZmZ36R+SOj8AVAFUIuMqFFMQ7qQYF2iw9F4vLqcfUe+nmKa5i7+TYpp6hC0HpjjivYTWed4ZdbpD8YPq/Xj/QfWdxTR+3lfM4Oe9xSwYb6xjVv2HR8w28j1DU3z0YBPdma1iCk7RYUAjF5xTvEA98TT/vPjc71761Ma3S9eslyFNXSw7K3x3+GRFHca62tHAbWngNr9hGvh5p27heEl9ZxlRZyULQL+vcKlTiJJpRIlAvSSYqwkuBhMK73fgPnCsG+Il9d4yOZJFfFrhuRhBgBXfq9478PoUwz6XYJUT8fbC9lbUzEV89Nch/w4hL+FW/JTLR1eYF1y3WjOZXDHW32hY2nQlg2O11LVtpgwb1hRsX7NY9FgOK7e7zGFaMCdmR/XwzHRIHYTY6ZC6T0JLMOxQhykX05ilSsMc1sOxiHP5DhlfbbF0aeEU5h2mXPv4f2WB+Q+umsVhfS56SxPahT3xhA6HbJQe6pyVTeBTFCty6Wq3gnlfovPhOrlCxpXuD9cpWoOb8gRW50m9sbD2nbCNohDv6j5WnfVVvCB+bdtsQM5e0QCPUbQpowmxUZ9YLfNMjGqW4CO9i9r2qDuyaXCEtaacqW15dmVKvniwtcYV3pFMPFqN4ti1f5tRKqRDcORlNeB2JvfY2GRY6zWmubRllH/SxcBjrfS2iMcTBqjTLzosVuVO+BRrDRh56sWWm9cne0h4V8bUB3yc6n3jxjwWCjf6Tc54xOqkxqLJ0RK1gXYjG54NCfkmG6ZYhcuePHx2QhxD1VBMF30+xQNuTG/Ad/QGjqz7ofsyxEnMgyVtimPeyeEaWHjGYGfFin01llfBhvQHWNsFfkNLj3Pybp9TbHL6vFNn5TeyOvwWsvJNVn8wysopvTqrn9tgFQbOK+W8Us4rlbxOUjokT0Tm4RHGyGM9k8RqTbgF+BwrAvEjH4+gJeFrxY9U/SjGI4jfY+3xI69+lOIRNCRSvvdgcL+kCNJWMlbQvSoNgLei+2ZNA2v4ovdmfQFgt2JyV2cDWI7BqnJ2saeLlWJaOqeYkZYtZrUP2WBjsAVHMuia2MEW2MEG/iDqH22obdxmxCWUcQdkwzKlhKZkeKWZQ9OjsZXQBCeakESTMnKQaGY0ahLKOVEuiXoyJpBodjQeEupwos4QDhuQRPAFWU4mKlrBSE8mhJEpRAih1ZPxRafI6MIEY3oyHoMaNJ7F5hmLpbyGlkwwUrHllg6KhN33li5FJ7JWyrAG2peLN19cy/mHFC5qXOjK8G7ThYYM8yQwZKBlYqiN6YP5+ss/cjv9pT0/gK4XvvjvYuXLzi9fLaMDrCO4PoLegYNrHRdKx+y1PP8slv7YLFvo41gs2wKK5VgVXi6SZVYPhEYtINvu9Zwye0RBBJbxdgb3fv4j7ObS5whWZGpsaKMHlQ9juwdZxy8AEWPbEj2oUt7E+MjpzBaly+7pMt0ufHtMTCj99sz5qy4AJad3l4GSZffMsrtVpEDeGS9NR0rTeeul6exVGvNhHxmiUCgMSlaklC1vnC6zZceUyHtKor9+SqS+7tooMG30tRw/dWmyXaVJUJrElKZViHm0mXMeXwaQP/BknYqwOYwFGMGXEHvThUvW1mrZ1t6pkdt5QS8EHEPA+X0HO6AtPPzEwmBC3ru1xfrUxU6elLWzxrY7qA6p2HpLUI1dGBQGlpTB8LVKoxG7x+wmOqZYeJLdT38pVknNdLqM0ZrbYurXneO8H+tBqiNuACaoq9njapfPJ1fNHmdyj2uTWCzE4f2hKqaQiE3bo2LaO0zTWBpmZONczlJelX0Kj2cAxpujGYaR5ExmGO6nV6PC4iELsXpHGGw359wnbO6811ancIEHeN0e5EwTNGfFWZMNcdYkOn6SZswtHe+Yu2j/yYQxFbtLyoGNfDf/z/mBT5M6fRz3eTOof0499DSHINCaRC6TeNKHbx0sLdPGzwpeLnqcYTEBUV1Gnfw/oy7EEvP4cYoFiNeHZY5KpVKBNjQMWlL2co7mrK+NvPa1gVPSHM3I2jJHs7UAhHLCbv+mPSS1ueumtc/eqrBy8G56WnYTfQxl+KikPrVoWsYYu9ugBZrnK/c0/zaceNxG2dw3KRsU1WnaaormlLIokvs0ZQkgO++AWEW72snnpDkWpTn21f2NF7m/Z4HwI15X616eQUF8OEiB2fmM7eW5Ri/PoZfnKB3r5dG3geC489uIq626F2l+m/bRwvZWCf96NL91UbyCTstq2zcNZj0i9Llp68PPJB+cUGKc9jDm5xCTa9+UVA5UuU7hLmmSXZWai/kJOXNN6PRxYzM+c0REqB2xJu+MrMnlMFdOUpL/vGuL0qLJuhyJcbKSGCcrjblZp/nKHa90xftu3sXIS2U8zlC7Ho+fB5NRhsl0DjTPVi39uvMYMxl2SiUCrZ4I62MjrL86fh7DW/wGz3pBd2jtYDLxiMl0DZOZAZOZpUiYTB98zDKZ7g4m0+eTrmUy4e2YTJ9mGkymfwcm020Ov06DyXQMk+mAyXSaTKY7xmS6YDKdBpOZ3clk8LJIy52iB3Ayb1hOo/gZipoTa3O1pSplzmORp+z0aMp68DbUle7r00yTncxCl82wE27gbDSdM/a9IgK3iXo6u3whBMWFcg4/cTnP52An/zb2VAlXcXP826f5i8UMzV2sgenZsYv0wIxM+36z3buojA/ODRGuvZqifqPd+2j3PhRYGu0+KiPNUJZLsQr+cYp5xMk1hBQdXhghIM7ybzMFzU3B+yjrDEpt+GZWl8qxBSq7zO93NHHTrqaLzunCkUJKXerzmorFxMdoEW1/5h4TTe7Rb3KP/h24R49nCM8nv8hpgnojPtJmeUs5Salwi0RkaCNukRpukRpuwXc2MsnqNJFgioz50bgr8Q85qB4kNzKozdYEvnpk6HUpqYdefXa6GKtgwzMsJBQWwt/TMUD60INcArQBmJscj7z1MqrPSMqWusVQ37XXEB0+rn+g4lODtw7k6idgIhQDoBcVxAhOsecMKTV7TrgZSS6eFHCDpN53hg2hcIh9Z4Ifh1UOQrPvDOtzC+s5AnsKX0uQ95ktuMZBDdoZFyx4XP/g12XB1Dq0Tb8OC+Y9rj/0dVkwd10/9XVZsGZxLjM+sj8qUyZlyuoyJZRuP3KXRcr2KpIpS7q8wScsOXVBowlF8uWgxTrQjO3nCLKcIBRC1ossQnuYgdLJlXOGUVj12T8MVb7hPiXWXBD34tYE6E28iQHY2InMMQ7SKviW6K8XgVxku2t3SKigPhGsFUFi4Nha/I6SdxDe+z1oeogPq1B7zQ/4NqEvCZ0iTswlhc+p/bUyspmjE9WpOmsueLzOaNy4drYpOdXuohftxJivppw+bOSc7v0OFzuV7KM7Z+8UbH6cya0GbprXyo7NPmJ3hvaNji14JDm3bEpxetip09mCZ8mYj952I+d873e44Llkn9w5e6fo8m4TroA5fbpWTtjsOzTRKPiELXhHcs5sSk5VTtTpbMF7idktTnD6zlo5ZXOeoCm8M2XfmWoUfEKy767ZQcv3up5N6tmCTzK8m85PXV4+eAa34GsUr11ezs+Qf3mL2pe3QKaXtyi5vFVO8y76lVvvfHqLQN+81WUqP0PR5S3KJHV+eYu6TJbTywfPGm2aaepc3qKeJJm4vEWT8GLyjWn2jWn2jWn2jWn2jWn2jWn2N2GaXZdpJhPM1+qUKEdiyknBg3p421kWCv68nWVjCXh2hWtFaCz0cImHiVHPrng8PTdWLBl6e2UIP3CYlCM+sFa2bWYBe2e2ydu2bwPJL7ApxYdzu05n+7bVnE0+ZlN7NJv2fGc0m3zMpjtl35hNbU7fnE3tvWdTW3J+a7PJf2uzyX+Ls8m/69nkv8XZ5L+12eRjNvk14z21B+Pl2RTa2RRSvEaRzKbg8ha17no2tb/S2fTvQ6XkTomM422RvC07y44+97Grjs7zB6GAF0NPqhbdsPhZp48XIp/hOz/jCVqxFcDpyioL5AepXabL7hlK4OcfmfS3izal1jU/bny3EZ1vQz0SAjKoP69XrF2aGBvYkTdo+Pm+j9NDNZDaO7IuUyiipyNzpnRkzpSOmTOlDXOmtGHOlNbmTKmYMw08KGdm1B4r8naZ4EvJ6EvJ6EvJ2JeSxpeSxpeS+kuJ/VLfXHhwD3CF0QMibOWGcI2IzBmJyEIrH2vB3WMAL90hBXpY7VSGDvRaZTWgA/1f2eeBPsXRFOiTlVFCDvR6NabVHOjvH6X/AZv++0bpn6yamsrcdNTeLttopPaokdqjRmqPNVK70UjtRiO160Zqm0ZyIM2EtVNdnqekPDBG+cFRiU7XJdqSZBuSLPmadJ0T265DP+QH2XOxK4pxgLg3jqRFdfjPQuXV887c5eL21mgNf3+lQzEkyHGt61JnXT//mWAo3R5qYKJSdlm/Jponq5fP4urb6OvxfUKPL+6kOCP3Ui0Rk1Mn7/BNUMMSRJJ22QiE7TvYoKN7rweQmHJyq5wyIvaHWb/2YhHS5MWix2rXkLdPXCwy8dZlbzFW/pqKsNfVSY/l+mVvKDfAX5tCyfdIPnLbEvKtMoxs+O6gT11cHE3BKuQQ66l3x/XUuw+qKVzlP6hy3E6BEyNx16ird6Guzreh29CHRq7TRhG+S1O4QIHUjpXk8ZooyecQuXdg+Bjq08PS3HL0RDcp1It8uy1apHlFudFRwl4Lq7U3FIVSsxjnJzhpR+wj+VaLmzxsovDINUa44xojvO01xl6Xnm/xyqLHXc3TDbqmJJcTfxy6MauYEuMAV6i/Ime19FZko7vKm8pSPgY4qdBsDFk3j5yjsis0EQBwLVv69PFRLPurdKBOoowb4IrdVjcCqhmovQVX8KSOe8KYLQWRRaHEM2gjBbKCWzg2TjAJvUoOhfbGhF1ZDEcFYDdq4gYTebmSVyRqmSZSmUjWWamK2DtMLSjfHWHr2asu/2HQZ7SJWZXZ0/wr0bB0uf4CAozXOUSetIZOsRsoW7j0i07BtubUmv7wD23FQxjX3eGZf/tnt3ugqqpC+wBltnTJEavLgCK42vDIh9GS0vEpsd0iJb+V/vCm/7jOT6HNY27IhF3J3xTTOmApO6VrvueOvlfIjOG2ZVTmPQtbWRVYcX9XKpNI7UgEZUUu2iJqv0fJhMFyY4u245Dtglvm3OKy6g+5+vSQT0WxsaBhDyVV6dajzm2OR7c5Ht3meMTZp4URKVYwihWdkBX3a0xuI6WoWb7RoBtVqbjR4+T/Cd1wl6U2OfBSz/qmAcNWa1/DDbM3LKMFciupwbdUXCNzdRkft86vlW6JP3Mvf6hUwH/gzT9YwPVbP3g8i3VOHszHnhzid2WhwDx/0uTGWQPtOgKHiUS3lX9W9eZmfDyLCA0bU1gZtf8QvsAhf7BY3LILs2jcrMHll4FWw6xFXJ8WrAAC+G8BvEIrByZYAFXZCFgqDHPA2rbaHa5pxRhAMWBGINYQpOSYAuPJz+KyY/vwABweYl3a9XJgfKVaBOs4sSjPABdASWLrVGa1DGUrDT7qDika5RKN52KMH1jDDQUIdpfX4lPzu5GgnTcB2oE77RrcaTy9dkHDriQ0xXgsC2+fs0FGl9xsI4T5Awbo+wt15Y5nvjjH9MC9CSzKLAoOebJyGV33J4dlxGPNw1jzZKwpLCG8CXgsC/X1T1519N/WNz951cl/DOpQ5yTiSh3xpin2rlIgje/atkW7MHo2BYyYnciKh8Hn5X7pylLs6dP6SSm+0t6QbfHB1qGzbuvpjurpFjC2yGFFn/xfoQrGbdNYcNiYhiGDooDXKVHkYGtRY//kGEEDuEcL6yHbrStqA8tlZDFNSUORA2YuuFBsGM6NWay14Lmgu8IeE6MhIIl0fIjttkJBfGkz3Iu+xhZQzztV/q2NQ2XDhk3wSVr4eU/eY+gSGHVAzW1kbsYIHW+e/5J6lNdO5CVK7E7zJBtebth2beEDd/wgtcxVLOzSstouTb6vP/Wrf/gPfuDQHoZprhimZWKY1hHDtK4YpuWihxIbyxXc63YYMJJP9gnlvAvkC1s2J4RJV8JWhQjldWGdZmENqElrhBITC7hRwmg80Oi6T34WkR0baYmJ2mHeojAcUv7NxJ6w4NlprNXZeDuu9cH8pHYsyW6gKJSjEE5rtd7S7rcoYEwLHetczMnuxlhJehLmDjHsJ1rHxboxbtgupRIR1rppGLJpbcLENOvPlIExYZJFb28TJgq4Mr9tVzpyNIl8yJXF2tG3nBOstCYRJtHmJi/VIGIYCTr69Vu3HGCWffZnkmPk6BcR1C/+VvsYufpnX2kfGw7J0R//9HqlhysgNz++Xul/9bjQm+uV/tl1pj/3L05W+gcl+vVjlf7zP3ofB155+qGVYQFMCV4vHd5puEM4FTHbNAUNBAWbtVc+e5itgLGnuN/5k88eLl0GlQCLPQ49u0KRX0CQdd05xjgVlqkQEG7gUBxIJbJ7VPrXf/mqY6wxFD/TN3/VAjR5h8VIjp0ysE2jbCRYPQjvG5fZPHbGv+ukKtmrMpJpozpf+HSzOv/00+PVUY1sb3B11K7qvOA2qvOLvzRWnRfcv3x1bpiNqsO+o4sQw0U7hQM8Ed7wy7C5z/mzzxwuA+HciiGXeE/0Gtcm0Bgy8fobG57XPnPVgYRxrQjgaKbipPrcc1cdAccIYb3NKwC2yu5J3iUhFymffD1gz7r114P7nN/7lcNlaL8e2K8/a3y2m68H+ou/Yr8ejpyrN77uULhaOgJPZ74e6GeV8b2k3WHye4EKNkIjH8l59eqxzpiVjHStZKTblIwEIhlJWTIS7JSMmE5NaxzZVOAb2yeYO46BNo5DJbJUgWd1T/usyFprZvfk8AwDTuoh00lkJQtyjoWE5QwsShDxUA5RwrTuUCCihFxECVvljNHffhj6xEZ/e8rqb7N0uZyiqT0Lp33qCVPlTzp7CjhS6uYd/lwxi5+86FNOLDTIa6FBTjOQFOQ0YwznHc6ygWabQyAxjZ+8mMHPYjGHn34xL1/PCEIUVDhnYNIuA5OqwzRL08j2zNY2l2J7eQO/8fLGNnV193gZLDxNfYDz1mlyk8ZBmmDhaQjB6SxNb12UA3rPKE+bBrfq1JMNzW4KjhS9O6piBztUsYPbqmLvpUh5d2rXOcshqJd8KXBjeFQ+uFq2D+Am5oDgXELhtw1YMoWf++AvXnAv20vKGcIwFUrDXENgDPqy+PlW+d0MbffIXRnM4laKEgqW/bMULj8MnxXL/tn8b8s0v6i7QxzhtFukZqQr7cLxZXokAyIAsdeCNhxI5xJKpSI80hjkUFUlvNXB2c+SOlB0uG5FlxTfAKGGZU/ASlNYy/SMC5I2nN3n+MmLVHo3hepw184oTz8xZA9COfWqssdAZNKzjJ1qtwbUe4z5gQdQcP54Sj1GsOMP9mrYQvMl/m4PW5JeVRhg1xUBMWQgbu5jeHK/mfDGpUedquwJkId2sPntMa6HyQ9t5HEbwV9CSl7+bmkgoMSi7dtAmuvhh2CBmue2X0UwtqSWeK9ilYDxao/drjNWH5qv8OCHaQVK47IkM8YvBpBHqqIWw+pxlkoGqKpbpw2LeyUCPtUctmgmeZDa5ORbk0dq64MYwm63YS6JkctsB+5oPP2asaf8udD1N/jYDRGMXAaVOLuUTiUO9ZH/kA2S1ZBRUDkFOVXBblMhVXPNIRs693C5rr2h9o4x4qPDZ0kxSTVnTRbnyTnRw3OWj5DKH0nUTvkDXirxYelUc65XQvOpno2CUVyVP8Ks1KlKpz6FAgERu8BRAUdl6oqzrZ1Fql/CCg/5A0flK9CLXFIvXdAYZe4hUORmISIvndelkyl9a7/Zs+HI+DyOjPb8i0LxKXL0IcG6tM2GpFwqIa7jmMlv37igSzi/M5UvnSNCf+GCLn1970rm62fPf563hciRj88EAzO2NYKMoFrJwkQc87uyo6lP+lb2IMW71izeqB1YaCO7Tt4eAS+1dBgjTd/az3+OLxSuyfclNCz6DnlfuaCBOQHp7an1Er3IKPI4bF/BZ9kj1XPnP4/vC+YOR5WORBbwBXsFFMwvWMznisYqq/e7mLj4gVHL6KPSG470hvtWCw93X49xf2wcQuMnyWeCHc5i93CtCkeTk+PuVSf3dK/6EE1uF1Ps/BNuJqdtYjbP8YsZCkaOS6dpqvZZObO349JJOKGcoxnjs3Jm5Exz5mvpuPSua5Hfvhb516AWxiXopPUDCpeg2W6XoJO7XIL6KNjMuEvQwBRslnxTMB8FC7hg/rJDM2MFC7hgwS6XoOEj6mH81D48I/G2GRlvm+7Iw2bt7ejhYoZ9ahazO/1t0uS2aAnMsKMb+Itq+tv8Z4HqCrxpvlqqAwwmmb9TuLh4nBGYFL3BgB2lzxyixAED1CKkdJAtxKuafXPkZWuhbFfiOmBlAei37KRkAZVs6w1qL5RtHVMb3q/Ktjlu3O+4RSqCKs+CHvNqmVCKBVhpnr9KQC6UuBxxsflgABESkKC+4IjERYozjjcmDzNHCPL5LAX1jDY8CaSy6TN2hhYhMuMVAwoRPTE15gKF7BmceuwLb3S73BvdLvfGbpd7jdvlXuN2uVffLvf4drns8a1372LR1nGRsouwwmePCsDGYcgqhq6CwMTVscFgIaMnEFN0VPDSRc0PsCG440H1OrLpNZsnEhAegxNyr/IHbLsqa/KS8o9l4iz7DSa3geMFHEBExD3bWii7fOdKycoCrjO5PxO9QcmC2W0plsSg34FEzb3GPBRLX8p+HTyDRk2OWBU61gWgYxeiwHh4MZAlQQ1ZwiPLPu2Xrjw1kMLsV/SAk/x+oGIoaf4HenPzmsB0v3GgBWYGbh/Yxfv0Ke2Su0BBJQJT4uf+sL7hkk2ECPMxuxbYRzziRPFQcHO8oTgxMsqJnlzNeDod1gBVEVvKK0qqMqsvYLLm1UzWvJrJmlczERxmMVYQs4Q2S6mQFTsxiihrpESWRTYyJUGXwXsfpG73k7sg3sWoRc7b3LTEfUctIoanfYbZotYqXyeZm1DzeN8p7Q07vnJcRyVk7OFj/fot+KA5VTAa0y0IsuAqkA8w4sQetjQRizqwWt7yhicyxbj08L9ewBNVC75A8p4P9pIGfMvp3KvisnMRZbr1/UMNWAEs+jG/iGzLNn+kZOel5OVbbuaxJihKL4IVNfqw2vlNfbD5TZ/FIJlxPGeygwAch2q4qemFDts7YuPtmuydOntsZleKDHvHE4yAtVe9vPobzUzZWeZAxXy1Vdctkrq1R4XBZQvea8t70CzIsLHoCLMmw6xhP+fmj5k+wxTU7jHLs4VB+8Kgg1W4xjI8OlwQbh4JN49Hz5yytQBG/TqwEx7jagC5Df8fN3NerZdtC6jAQH0pD3oA1WXmoC2YCnjLN44uPUpwoMoYmEj4+SIOFpBpKzlPuXKYZH6eCD/vWn7uF+1dPH0H/2P3szVPb/E1hjndwFMVROtg6wnYei5s3TobgwwmX6aP4PQM4D3248X3HTnrdnhasfeLOGEBOfNWXDax6Hr36sIlYd1tYDYJDGFXFppuU5dBLanFmhlPDdjy1zLjKWHGDCLHPJg7qLXAfBkBt2Ls7sp4O1NoLgwSaldozib3XUQ/VEXbONdp8F5j0S0d366ZbywaHCMFDRk17XH+22bsDZNAGV2MmsmfC1RUO6ftjtmaO5qXmkCnx+BJUvsnDMC1jo9lrsS74upCNsjUrQVtM7XXAKjFlDOsNFPO5sdEe6YvvgPmYPS7WAT46RcOfqbE80NeTFAGq7h5/PjFAn7gcyB7UKXFvp2SxMlakjhlJYlTO9WPJndJEn12aFhLEY3AZgoDjXVvpjDeROYxAY1rWsQ9z76tchGeHrBLK62/h0PsPU6KMG3lhdNchGm2q99dhFodhrq8ZZVPzNBsU1yYQ8sYTQa/CmgzWtzeKiEXpJmtiyXPoVwm0pQpqxWiTY27VmBBjsMCVjMW0iOFccObYgfCvu92S+ayHZK57GsgmRMNIUqTc4GgOx80irDMN0nlf0d0vY+zEir2UPEQ1zTgoQi1ihY77BRRa/5dVl8VHPKJoVxETZXKXETl7LRHdhFwvr1aZnxTRYqlU7GIwzKzX8KruNPly7qUVMUKzpmVTWVY0DPjMJkyI5tSlFUlksJPqWO2xhVnI9Iolb+bYpZG6bMfuepoj50sliIwhqNFaFjn5lL0QOmPLgl94yx0qdGoklWHFDeqqX9b7zcVvq/wxTVhZHdOiCx99oJYKsE6V6slNMTgjq0qHYDcu+alWueK9ftxQ0BO4Uvz+XjPF0UxB8JHVZFvbvwhSfIT47YC/rGfqjrKcXEVqfMTmSfve/J5D1fa3ioe5f9xyuC88A728kesEwWWtN6wLlok+LINiojdZ4FbXMtmo5FsVlxc8gjtNEao3FBGIm6LrLtGI2RL/ihwlcF69Q7sgdI6JtFyO0vKNf+8wHV9d49/Mf5ELKS44RzNgoRlcOwZVN8KoAbj5v8RgizR7ODnHW7KLo2mTt3v/ManDr/d6XLQP6W7QxMzyTFxI2aCY9JGTI9j8kZMzg7fsPqsfTfYlqpKX/+/PFhhXaz/lElOo/RTGM76Bkc55N3rpgN++BfO251pzrrOdwoJ3uZOvcOJ0zh5YwVzBi5uB2+86LzDddJ2M+rLLqJazaiXOGrsxRscFTWjfo2jQrTfpkgvR89+m7/Dbcs6eLjnxXAjN7+HISmctzt9adqZ8aad29W0+3Y17eKupl3Y1bTz5OmZu2jamT2bdmbUtPvHm/Yeadp73uHEiW6zTFJkZo7+MK4LN91C8Qpdt8U1tEUC/484OHHqtzsZps/bHTimZEXoP/fdyFycY1vhgdlcdYdjyMTEsZscizNKp/QzzpMvanG0E91NlS8UgZxWlZjb4JJVNZU4A07PRzGFo5hvnKvjKBawgVsZ1weuuHkUi5tHsbh5FINHMoZtjfgoBv+YCWfFSpQBxY2Ucu4WcN1QThkoeMWFU+QWMfnsQT2GytDRrL55dcpYb7yHt0RurQgY6w+w/RGuWGPBBfXBzvV/eXyh4q0oBavIXdVf5MvnkJGrVgXpmg998YlMgE/FcElMAU3bjw55SsHm32dPr/Uhz8dpVw55ckYpQjnkOQaalU9bvjnmQX4TG2EFfAYAfRXHocwzF89Wiz60pyE2m2qR3zjBSZ6wp1lBg9UQLzvzVLKdFQAXxYUzZyW0TfIrfkMJv3HJbAYfdfXpqhO4juPwTkmvV9o/jr1KvuzAUoN/cpZP7r4lZc1po6SNS9KtcpKvTvnilPdvU7JjzQdyJzstd7Izdl8Z1PvKCbuvnBBkGZqwm7qgoWPduPmFM3igJjU3c6V8ZVK+MjV+VTszdlVLk3vfz3YXnsZOb8e1bBfXspPYFU5uXRTvTKYYM8Y9Ns1c1G51eYumTVneQaPKdW3ldvniaVTuTte33R2bxO7X8vr2l+shs8sHXyj+9+w2p9McPMYxXz2Auhg5sfw4rPPfUPiHTn9sdPvTrZJ1/338cXjYTMq1fj6oTRM8p7SeyGnKNDWfeaaNacI7rFVIXrtACq0LpJCbXVCQdziGS2pLiQnBF2jaSuRlwxgBRZh4hL0jTRgHJF14Y4eDJPHG7tQmB9luk4OsaXJgjz1Zw94guwt7g2y3vUFnh71B56/A3iAftzf4nK+UKLLkjZ0c1ifsAiCx9mqUk8Zlu72894jd0HqM2TxyDuySMvfhfPuesN/d/LsZfppUpTddXuR558/fwNrq16AcyuJvBLBprCMN/kYIw8g60kBtYD+b1JECYAHeW4NyKIvU0YLT3BqUQ1n8C98aofk1IocygB1sj+bXiBzKImrgrRqRQxmcDmTg1XGqRs3wa0QOZXE6ats1v0bkUBZRg/Nu17GtOu+ojgsbecd1bDSOyME2aXJ7wgcB/l6bYrzWGkFwCLiN3DxLF9oLds+OGHuXftUXDZGcIbfrVViuy+93nDKQNZE3WzwW3VMsqsO6FqQiiUllx+MdlsNf3NhMBVf1xrtFW8wdQgWUAc5b+qp7DCCi2NL5OEBhOAvYaiio7IwCUbLfb5+V01mKCRlqIBrYvIPgXAMKsXaLcnoBUFBOFUgq2LyUAam3OW6pbMqA9ym46XZl32ZEpjGfljWXgw2TAy0bQaUDbKXUkuOKmwGR1rMqPqsvm1d551Yoit/GJs4mkc/NKbsmxfsnlMdhhwmiSIYEe+QQie+yfCgLR8JbEbTM21yfs6dAk3nYGnvI6vutd7gduIvC0crHJVU83uSstLJ3SzvcMp5tmcSOBnbzF7CqBhapIPlh3+p+43I5MJfLLl8qt5cNsII7suNxIV7DQ76UQCK5NX9C7Dogx3WHAoZr7HyLmK0b6z1vLIsTiz4fVM4WAzLEVR2bSmzb+Mp1xIMD748TpGgDigTerji5SSty1MbOlQJ9sOJ9isofN9pO1F7egLPhg9iKKH3LOV7GC2X6dJmRd7r0qL1N6dPUhsVxIikfQkpOQ/5p3OS97/JpSjhZss1NIKY0rAru4sjEIMFwjF7GQH9o8Q0HTne4WtDBuv5AxdONt/u4ZMcCGeoXnWG+SizsJeM9XB5B/VudrPCmSYMbtWBJwZcgRJyApA31YUB78JpUlTE3c4tbL6gz2RjLhKXKvPwF5ub2J3zrplzQv+C604oMWMBqzU7xAG4ojhqVazamz3H6PMYAJpC24t4Zq2HK+0Wg7GBnwF4jJ8Rr5GR+TPw0TomfxmkszosCKdUHeAnkX7ndi4T1XqRn9yK7NDCbNoQdEdB2+PKfDSV5W9LckqQmfqxQ5tkhayD3Jh4gw3EPkD4KXPuBxBZP15LTXTueSdnxTBp/axB7sktHtyn6NHuTbMfeJPta7k2M+0Y+cf+Rp0KRuot7RgqMdgbAWpxjq0UMR/DLDm5qDC/srfB68cSwaC0bDxFgsEgH+xBq49bEp3ZVQvCJTQAEXR2muhTpjKmcIp0w1aNIp0xN8H7ctZbckd0kRFrVluqRDurnOhQT7kh71s4bbGhstY90a5S+bdNHo/TxmKX6JFuqZ9sw2/BrD3o4KACCMecsJ3E7DzjUEaZWur3TDWDTvR7E+jlwWuBlj33ssfO8bNzLHoTBAlIKtWW2U4zh1iSCDD8CJoa+9DHrGJpzBCTkx2o5pEVOyiCBqNjKMX/QON1LPuaLPNsobfAtGa6cfBGDNMWH2Jbw3PBEf8H7Xvx5QjvHKlEt8NaLiE/dt265EM5G+jW53HOtdY+O+Xo4MG5hY3YcxEYrMWPAKNnsKnEPqGD25GM+eqxQYV0Pkfe9MCSDTPbxVXy+jCh4zOgcaCwb4oOdbxR90SdgMYePFbOIBPPFeKCvN5TwC+xB1aR0LdgbPGzUWz3XbOq2+DLOxvmNlF4dW0PAbbHAwUaPkOeCkbMFVT+2yHPE8WEdb0DmzA6RhybvtF32P0ks0BBzObkF11+EhuXPijWMeBQYRaERk5HahmBXxFbz4XXP9UbDodn3RuqWiz7k5uYmDfkO2s1PyPbOZ1kfWhZjx+NNZVyRV+mogpcTKyJ0GOm0YCT6U+whEi+jdfTvfuLzjlb1ezE601vNDxwx13p3zPyLn9BfUeaZWMuCs78gSdxaAiTbKV82Or7VSnXlnGRbw69bw0NrFD7K6n0lDXHHckox7pzvbdrgTfK1ei/kJ/8d2L4ye4GMJRcdqK0tO8B1hzUghTDM7UJ15/hC2T0qt9GrZY6dYq+RNhcBKiQH7GlhlQJ2SwoHP7iJyvXp4Wox4R3m43CBdRrSkLIjLt3NrrBr94pT1B3tFbt2rziJ5ROKtZ1VXGUFfOAXaYV8tCq6HiDY6AwvuMhn0khN+HXkX3Sph+1jj7lrtuwP1JQoGOSUQVMMvFPh+jvDXQMbBJHPwg7Wp84JGyZRF0ia6gKyHJc9askibtboXr0Yt8wi3jKL+N16bHflqtSqLWUieOom276rNvCgtgUC0zbHQs9oUTuiqQulVD5oeWxlU2tRq3Etale0c5XEi8oyf5lVdRXL9uWQ6TXUqMXM3RP1adVQn1YN9WnsQp4cll7+iGgMV9bylWvAOZMzpj7tWvVpvLqzLPVL3EKsQM2NlK+MqwUraG6xWrAnasGKPKMW7DbUgl051XFU6Rq1YOhC1GrBrEvFShCsCAy3tkp+XFYLFn3vMW1s0aNgvexRuZtV3KmLbXMZU5qWXK6N52Jr302Sz3jKreWPfE0tksTYShLjcaNS3x7NytYWHCXFsv0o23CYVPDBLaU2P2oZ70bb5iAdafhih/MgdxxR9VsdJRiq3+oE5knI0R6jl36r8/9x996BcRXXH+9n5s7dXe2upCuvcZV3r4QwNl7Zli1LtgHjBWxsDKb3YlxkcG+y6VgG0zumhRbqLrCY3qsJvUNC7zWQQICEFBKS6M3MvSvJwhASfu+P9375mdXcMnfmfM+cmTlziuqSC7WBsvCZuC1Fw2di3SIFJcwOLdJAefh0hYnmE2kgET6f7IzjE2mgcrVfdnyYIDAMCJUI+xDEgYqZ6srC0FBBt2z4quON+aHN+XZ8bWKczbaZCJdvJg+ja6+qjqvdKcp6NA31PiFNLS1DP1w74651ZLxrbK0Oy4CSKbRR/YnVNlmBXU/bmAo2m2msI5tpzOTMMRkvTc6cQm0yCLUcW/+wIWacsFLmp0+Q3bJnjWN+PJPk0q4a7Zoq4Zmc8VE/bqwOyv3olP4227vNHWliZ/mOzXm5+njz9mDHM3IwKKdy31rRXlPpx82GyCQ7tKYqwfYtYmL8x3xzFlzhzfPd9Tdy7Ua/bd3Ea6tMOrUl1hDVr2q17vHGiKGm3E4BNUHuBav0PWxubUXusDBnemfa5WRn2mWzcC7vvFPeeWetQUOFaU5X16jSWjxRsuWOlSyRqow36hmBC/Jap2SSP7xL2Hqz9TVTnW8d+cLNb8Tay1t9hklNmijp/Dt2UlabYvdQgZFLx5bK5kb2Q6dAPxbUNcgvP76mwtZqkhpVlh629mGiJu5LyxtmXW82t0atZA3M48bA3Nsa82Zg+V7lJ44zlZX7VX78OFNZ0jx1khkFJ6w2yh/fW8/APGENzBOhy37JwDxmdR1+tCNlfWDubpQCZSUdko08XmUTQgetEaY1yaA1gbm758tAp2K25aESRpjWSNsasTV+cr3WSNsauX5rbP7IIPtuwgy4iDWRSHwlhbvSHJraKeG59iN9cp75N6V/LfN2sr7VRoMRhAIRJhRIrTPXZNZVgW2aMFFBwrnURjT3AxWrdZU1MVQCDVxtxLyvfGFWVcxbYp2acgS7r3nmc9ZkZV6tmBeGT3WCc2qj0LPBK8JIEEaQK/MZ1RnSwhw5u+uH85BmDljvIWNu32j1lGZqkh056eyj4aRoVwMi/LSwrktWs7xjyWdnvWY4xi9LmuWBEwbawHjshSuBkquO4zUmAm2U2b8l2qUs+0/izAy5v5n/cJzllk6xVhWItaoNibVxPyjTegQyLbWeTOu5vkzrU5JpJn+lkWnJbjKt53oyrRQRq6aHkWnlNkRk3KzyHLvOM7LKhi8LZZqxZim3Mm29mGLt5jArkGleF5nmBTItaWRa0q8wtDMHrMKItnLDOeUlmRaKJOVXmWR4VTuvPapGbUBAeX4k9+zpgYA6y/mJG/r/n27pu27jxcRyE1LFDXcN7iipNryDtnrmLpvoyE/YRNuAfTmRu6EUnOKH9tR/lf9hhl9vCDh2+jajwPk5o6Dn/zKz9yzN7D03NAr+48zew+/5/8rM7pSOrZP21Nqag/+Eqfrr/ySK1qe7n6hNWjXb2qNqvy+HjGft7sGdnh13fhoUVRsQSElvo+8LJItBVUkSVf2Pkqjq/01JtGHZc0OYs+NYR4iVweZLWOPTHLm3zPD6csvJ/c3EkftDMIHknNZB8u7bx/tiSnD9LrHh62+x4etDN3y594YvJzZ8ObLhy86GL/9AC79/2SQpkmHJqEeMrVuwQQscjkuG87kTIza/8N23rzP7STMzt93sLMu1td2/cm6u7az7V06ulf2DHeLvLH/1DysIK8z5hu1yD62cm+s3ub8vbCbekx3rVW3kpOwU9KXdsDlicUpbdBv3zifX0yyJrP1xf+uDLTrs5gJzOxFsa+1ePxbownoau2Bvri/72+wyqsb1Ra31MpD2sqlN9DeS2tYuShXbY6fg+MmZENqt2WZVG/XG1WvsxnuQNLa8RtXlGateEdgv9PRluZO0RnPSLoEmlAeaNt9GMJJhRJbAgitWaz0oVKnlKtyQhzqA3DFWA3DoErvtD/QJTbJPsETygpWf7a4XfK1PIiBQ542e4Y3KRKnadbZa0xVT7brzc4kTHemsFMesP+Fij/ZLk691N0za3hq3u9BWMVC3lg5qQse1kGzCRgMI3AuNo4tRqgjr49ck/cDX0O3ia2gndpGLBVoK689RetpY7brGB9Ht9EF0O30Q3fV8EN0uPohuFx9Et8MH0Q18EF3rg+gWamSoejU9mGsCzq3ndBj00qsNTDBDW23rX2S7VT0x0Lx2vN6zO4H6dCOQzUJgieTZzYAci9mJq7GQ+L2U0SDXmD+vtqzO2H85vvJaTCCuWI1n43GFUXVjxl3B+urErZAvmH1JwYSZCMPGmqOXYCq0Qc+CUNPmEMjrY+rpU9PDLzN/eH7V8cE8UtYxj5T5PXzv+FrPzCHWUSicQ8rWn0PKDBmSa82vVxtfa8+EPFPqU5uwu9YyY35fvna1mSOsuK7tcKJWuYDxcyp3iVkRLrTmP+UB2onASigZxD+IB4YE37N3M1sC3/WuMKQPwjrYTWugqXRDTeX3rd/KgoPD30vL9L6Tc1s7zEOlyd9tncms3U2gzhfDIPfRZ2KZjcJUD7k/sWypNYaYYLTXpdhYYpB97qnPTQChYNdlnKE6qj5qbm2kQjqYQ29j6yBze8ytcKSQ4QWzMXHNqb9YkhsysbZDN2pXe66NFBl66rvBitR6vK3dOn5MrfGULgR+fqEfc25nEw63bEd7rRRm1YRtssvHaMHGJdwp8NozZOsI8pWjFEPr+zU5HTVFfJGLz+8MBRXGOlo511eTa0q21HZzZoTBx1K6HWk4y6w7imf+Te5vbGA6pE4sDNTXGakl9IxOWt25XQAlzCqi3KRON0eFCcPV5euF73Naw/B99iQyHviTGj1ZmTVPbfHLSmGtjQ+QGVimHm9UMMYqgjFW2SXsTOhLV2n0a8bWMdCvJfwKo1pLhMmpKWnNk7bNPztTYU51CdBW1mlUFfoTGzfP72UqfCbUIRjtrNEdFC9V4WomtrQ2cGgZJL89K5dLmJhaidZaOaW/iddicjoE0Vg6bpfuyB+8I+b+l3e6XDCmzhPNnDfZnPDYW7Uy9+1ZRnHtmjnZPLbRihqZa3eDoek7gQuODSK60QrjLB4YjwTRWEKD7ZIfjbEkCQ8e5tnjJZOMrhR/zJe5Ey8LAnoZVYcZ5IH92tzaMGSXcSRZj5g9fHLfPFAiZlknMT8780eJGd7uIObcH7oj/ts7XS5smJifndlBTPOYJeYF0Q5i1v7fEfPr8/8zMd+Tsrxj/Et7WueUPHNV4Jhr4ytEfDXFxmRuDQLclvbzRmCE+3mC9CihtIgFdlwde3rjLVDKuhkMN1/tFPhwqpogMoMNA6zm1YTxXpRfZj06jJeCsjoIq6wKoigRBLFMmlBP1oBrsMTMimal5oWCQBrJYNRargnhWtGpwq3oosI1uvdyoyPIYQMomVSvMtzty9Ju30SPrhHW2ErMrY3MDbWFkcBjNozYGA28ib2S72mwTnlIivhK46Zv3GPCcMk2iYwNNxGA3uGlHLExwa4UxltLhoZeTk5Zp/7+lqCRIGtKYKk50QY7FUusXjUS+PtHcp79hvF8l6bfxinfbNSMKWGrXx78GZub67Ok1Y8vXVoTNwY3hiFNtAc5tzZi5XIi9POP2Fm1T2it5MeX1sRLh5Jz7fRf8jSRxtPfCu95tY7V7ZqQufbI0sY+Cxg16rulfPWl6OGBXLWHVNYI8JzQ6iQSRrz8WAphtFFtIrAiiviu1c76yiZ+NMYNc422w6q77fzh2dC/ObEiJ+c2yUnmyLlJbutH7CPWPMvEnp9rIpBGDIaj9f4zO9eP5EbP9SaUjOwHycZaaVz5ZJBU8oZQJWAVNveHhaDqQ4KfvX1r3D681uhDm+RhVoskg2imR5lldcxEBjdzmDLKNswXX8dENyXwrY4MkjvXWoeU3T3DXio09w100hHroe0FbduhYx+xXrZu4/EogsNe0RGCNbjhBzcig2RdV1/pSK5NmDXXcyGVA/s9vyLwhq8wC72efoWZ7Dy/wgCe9CvMyqHar/ArrGud2RqVwv95xm62PJhsy7u2LLQ9S3zvYLu828F2+X91sN2xhCyt340esTxwizD7pESnS6nNhR21zrAV1q/cyK5osCC34VoToeBSNklpoiNOq/07aGzExGktDy1LY8GoL++0eLPW+EHM2SekUJ2HBrEVdqeRy9jDXxPqwkq+3EDfObhWzlthAmTLnGhdmhu3rCZ0bbOremsxIDv2tTb4TbhPFL6JiW8P5QMTarugM1ldwnNkoyHo2Fb6aqndUFaWAo4aI5Baq/s3hxZmE69Kh9Xk+prWrJjny1xmYv9WayeiAv8stcQeVnhmB2r3Ta1mPR6oUzuqSiSC6POOH+ptQ2UFQfvCA27H7qcDY9WwA+p7HUhcb8Wn1ag4RnyGOmjj09AhPq2biUWuNN0o63BQkqSiZGtr2MXavCjrmxdI1A6ttul0h0SNBxLV7glznl13uzZFgpprdXz11kbGSNRk8GcoUd2lJhKqYySqY2PwGbfILpFTwtxduT5W7TwvTLjXIVHdLhJV2Pj3RrDOCwPdd+S8jQa8FwmUOa6vLNPd0mVhZOO/nVlaGLlzOxZG/zz7+wsjp3MpE97ewCLnp93pcmHDy59/nt2x/DGP2eXPPbJj+dPz/275c8LV/3n5c42UoiOKSacXhHUNt5JlSrnIVZkds/X4Ezta+1M6rOmTQaD8XHLuWptTKSjFbMnL5Y9dh7WW9x3v6MBbPHjTDgEVVFz7w+8ff1z398V/9f6DP/7+j7S825vrpJQ/XQ1lTw83pIbqDOHx0zVRqkt4lFATJX9AE6XCsCnWgsHIUWHdlUwcfGHDsXSJk7K+8sjZkPKo4/WeYZfCWr6nPCp1yyonuuqPuuyzb+2cX5MmCkRgIZYMJtZkMLEmg4k16Se/P7GW/dgkmug2iSZ+/iQakChpAwJ1TqJJ63VrMzwkOybRSGAfY/bSdoKNdQQ7j3YJdh4tTaKya7DzWJe9dLJzEg0clAqy6zlkLm4iKtppK0x4+FnU6F3iVtHjWrkesZLGmBf6bq3RS5/1PAtrXfMz3+TFsO4VH7PQ+Fe0DqM9P35zk+Mrd4wNKZv7mPmB82/Xu45Ja29iObbf8KGa0pEpzs3ZmO9ucIoffNq4uTtzrTl1x5fYYK1BHdYuj2ClHVSw0lZATrTW01YwFp6B5aWwan7bvVw8sLl0SiaXTuJ+GcRw8DqccLqo7Y+eW+HiOI6wsSgwGrNPHhfLbLTu2oiZiz4s6cyswtRGFbCPffSoWObbJz7qeCJwx7fqtEdP/1wus7mNaiPmwtrHnzvVWbbUjOauD5TurdnAPfNyePvi4LY9G7Aff3bDbbTmh+aBr54tta7rvYTd3ARGE56Nh7mTmU5iOTFxSQCVuVRDsPCowydxjRQRe9wRC7V65uwpiKRfkgu5NuMMmpP2Qs7syIQNrN/fKNTs5OUHvBmGVwmVyjnhTQsqVlb7r0xaINXxjSABzVZLcm0rzaLBZPsJQscHnzOj32igzW5hnVg6FmskacIy2IxK4XvBC+3tzpTSG0ZsRIIXo7n3O16095zgnrT3ghqCJ0Wux4rcYvtskIfFsp517ZxrF5KJfwkRC5e0nf8LVAXJQGjGatU8k07Gd6b0L0Vvd3y1Y7kIwinKUt6PqD3VD5fKdsEigmh2JjqKWfnYqFWBasANFavGMMbYmpjPlBk3fGlxNX87NpOUXTX4JmJgsDjrWlpm9I6xMAhVafcuNrSWMjZB95eO403h2bDwnRByZcexl9F8hNEfArP17v60dn1szV3MftfMPcFu7f41YayXkhdmaT1u7GJcW6/pWbBzdoLljbGZCVOv2JBcNtGnyUIjcjeE1QUJxYQJXCNLIp0g3owsvbi+P6jdcBume7u04Za2+MU5HTXK3CVrAv3/s6Jj9iqvIzBs7hMYNhsz53hg5lz2Q2bOZhKLeVG7G/qeiXOnQ33XWOp2iqpN+tFgYgvnrWTHK9FwYouGE9tPN3GWiS+FXc0EBnjWRyGw3bfT3HaBTipp5atNTyJLftN1vszVzc1dcu66YJFTa/5jGc2r7xeYN2drHbNykcaOo65WmYWVXaiY9Yqx6whVW+EyIeaLwdIeOnVEnwyOET0z9zc7MZuLM5cMVj/COoUlgxwlNjCbF1QsBzsxazOSu+T8dTR1HBT7MrBge1l0eGQ4Zr6wYTVtKHinU+g5g2QyF53YedgggkR0XaS13YWpLqK+w7Wh24Oqi8i3YtNaujh2m7h+dV2Ff7Cwt67LbrC0cjtihargKC7wNg9sYm4KebI06fkqCERdCkkqgwxPcqcul63fTbCICKJ0BOue1lpp1/r9a6VZauZMooxVscn9jXnPxP7WhC04qzdHfvubyd+ar20XbFVi5j/JsP5AcUlwvhwa0AUzTeKOEIZgp+GrQOkT6KtUGATMsnDPuZ78obwzVhNaK82PVxvIfHtOH7oXt9aI4IIbhglVNcGwLi3E7N+2zkCbYSRYSWkRrFU7Wq+C07jXRUeAqPUWFznhLTYHYhWOEgibLOvcNbnaiIke41gnD3Poeu6anLlz4ZrcBBtOL5ibB8meVY4Z0MpEH3J9x4Qccu1puFk9WX72wvcs+czfl6zJBe8l7WlcEP4vKAZD13e9U0V4lG6+u7L0fRMM6bQ1uZI8Fd5FggSJWzqlWqIOPxGsyROBOEsE4iwRiLPE98XZplac/aDoincTXfGfI7oSJdF1rQj0SqXtcqcSJPTaDvQfwsbstbORGxzQGb2xNbPoyG/vGPLPLSW5N5Is0iXHlg38G7HNEqEGI+QPq5Ls3NlU2lk74J+oL3OnrQmtnAMhmxM1kZwKc9y5a31n7TG1sfBItHPjFSsErmFth/lq3o5d7pRWsaE88MV8+7XzRJdQuL7R+0g7A5vxeaWwuSXLS8MvGfiBh5nmjM9x1F4IQ1MGM///15p7VadID2SJ6pAlPyY5VCA5kh2SI9ZdcsT+bySHFXgXdJfQ/7tI3qAkPuB/lcQXmlHUubEUpQC11KrcpmFQcWvEY2I7EZgDkVslvUEyPHovr3Vyq0SQZVHasE82coIIVQTmqnksWYsxwWeebViHN6BNFmA/VaWwW4jSTu7kTpI5P0wy5z+TLAhAUiu9GeGcGhDJxm1xOohk53/RSSRrGRDOryeKknqVqUFaMcMSP8DqIicCPhedEapNIeRzK4ps2tHgutnhdvC6CHhdGF4PQo108Lp1xVrTsVzz//NyrbpzudbTxqz90eVadW242DJBb2vteUu35doG1lR2VjypcwAK26QAjpKv3ITAC86GWrOBUwJLdxmk87jdNMkCF2R0qDcpvesp8/ax233bsUC5ZVXhIvfij7xwVEeony6nVW+f3uW06ouwECq5ZWAUbZ3znJyTO610siVzMvfP0ztMkYXxQBLreSCJ0ANJhB5IifldqWCtatbjy4ArA3W75Uuzft0/VBkGHGnWwxbBgCPD5XEHR1qxIgKOPOQnfWx91v+fPnRQuNUz43KFjbDoLLEW5XK8ye+5MghoIUOrM/OUSU+82Eb3NH/LHL6aN7G/3faHppodvhSGpWeUPlDKWWO9GNX3koZSEubKZqo1yWlN3s3Kn5AvdF9rR1uy0jScH8Q9nFfy1JBd9RNWIgVpd4O9oyGoM7cz/lNXTcBUEaiCfSe3jiUl039pnYHXnmAYfm2tOmm1PbtQJ4UzjO+sPcl44oQBGoMzTdnsxH6oOlvLCWajZKo9abUdSuqEIPS7+cYJNXJr60HZrbouGn273Qn09E6ge3dCpXmQ0yBQfTsWt2So+n7eKO0da4JqVN8brC7mh2dbXatLrlddSZP+75PWq25CGCMnyC5scvOMDnqEp2o6l37GK8iotkIXn+ASPjb0rkjsKmTpoIdB8vI11iN1bpP5y3KtWRYGufXqzcLXuqgGa+DgjZPW5Ozz5ldauZzYWoiVfpmxzs+p+TnlrawpMzHubNGwwA/keqsMc6tUeJmfX8VmifGminhNhVFhB1XETTg1W/yRKkzImKRf7vX7uRUMCSooMxbyXTtR8Z86URF0whv4cyvokdjeOGBb3ZcMNWGBGiDWVQ0Q61ADhD5UgafzaetNVolxATXK16dG+X+mhgkaMyx4PWosbUqvR80RvS3+wOvlYQVJb9LP+3oqsYV53bVJSMLXjW4xKG7g9WRnBd7uwctR4xHRtenJH2t6Mnx5pH2524j3Oke82FxUBybjfWyqtGRwRGS27ScKEmODLyfW/3Lix75sQ0x6UxNNdm0zJ5ivwgWOL+YFukYCKyG7fzVsEBwnVyZIjA4IFV+fUPEfIlQ8TJ+3b/BiNIiA1dnU+I81Ne6XeZH/9UU3kSstlTrPlETnwsZbIyrtzByYhVkXBDM7lqYv5dUnxthVsc2qMjHUtIuORBc5UdmRH6UymLRUKWRdotE02rGxBMNGm/RrQbFbo2NhkPXxiWF2Cg4hCZdmvjMvSFFm3QrMXsjO0Y7Bor40XYSrFAaHi8BgJefLZrvMUHO96wSJuo7VfpjuqIu9hImy4V0rSGQCjxi71BE5Mak0PYfKgUSajvldGeF+1pqcSSLn07/KsVN3mAe4re2wpeuRPXx/QNf6J3SvvTfdpqtKO1clepV40jKkEUbeJYJEVai2aM2JpcGMl6KLPsOXttJ+NirDYLn6vPHNgWnIYPnPc8ebFXfp1p/P7bz1RXCrFxsKsZuo7IKcFyNR3lH2qisTqQ2IxaqQ60KmWiMS3vpXBicSpebOqQz/9taKfsHHgi2FuVmfqCJ3w/nryG2cW3f+Okx/K8m9nF9HbogfLMQqyF1y+zpyI317u9S6XMw7QSQSbIxvQqhtTCIZ5JIXdsEVD755uqhMlAV/Lqo0Fw0EJwn7MFZ7bFbh4fWrBWFbczHvLJGI4eOdIkhEzR+zzM0cOTGWz87P2VqDU/iYDQXnrUxE8G4SJBwqE4ZxJInEN+cIYXhnHYnPzhHlM1oOnrPQH+e3LJzlD1rn+OP8dd5g/9BDWhb6y+bPmTln4cH+QevEQeuoWtY6fWmrP+Pw1hZ/zsJZLYf563r5c5b5i5a3+otm+zMWLV84a5n5K3i80lT4Ux/eQN0DzeMLF7X60/2Zh0xfGrwyfenhm/tzWs2tOQuXzZnV4q+L+YPMa8v8dZHBP/L1n1ddL0OKUl22rcv86a3+unJ/xvJWQ7qgSPjEer0c67ce0uLPb1loPrOuh33DXAkeNdfosXT6woNbgopLDa4t1RLcm71oqR80YtFsU9nBrYf46/CCm6az/81rw5YuX9Y6c9ioMaOHNzc1NLU0zJ4+YlTj8OYRjTNmz5g+s6FxVvOY5qZZzS2N00eObho+bP6cGUunLz182MxFS1uGLVs6c5itcpj95NClyzCXDp65YNqyOStKxS5/Lm+dM3+ZKfyvn13WOiv46uHLhrUesrRl+qxp8xfNnD5/2MJF04Lyz6p++vz5i2YGH2hd+nMq6k6e1palJRrMazn80KXTF5eKM+Yvmjnv/+RTyxfOmbloVsuwxUvnLGydPmN+y/8NJZZOP3TaipaZwxYsmtVR4bBZLYuXDZs1f4F9rH740BFDGxrs06Vrpe7NXDDd/j2sdcHiYYfOWD5n/qygywtMU80T8+fMMA8MnLlo8eHTZi9dtGCapdlYf9mi5UtntoQ8GzLsoHVDBvuzFrUEA3jB9NaZh/izWpa1zlk4vXXOooXdHxaDgZ6Ox1Tgjy7UAgcoDx/YQ0DfLuWpArYAZs6Y2XHtt8DALs98zPrv/BnIdCl/DWzcpfwVkO5S/gLIdik/1K3+UwWUdSmfBGzapXwMMLRL+ahu9xd0uz+v2/2tu5VNfzfrUm7udn9It/ubhv+WOh5JwNC3v6GZ41EB7ANUdSlfCZQDfxEeO+n2/tOBkV3KURV8c3fpETe/YMw/vvd/uyiPBLCJeadLeRugsUt5PLDdBtpXKkfDf13Ldd3KuW7lAzZQ3wQZ9G/fDZRNf6bJ4HkPqO5SHgD06lI22Dd1KVeENC+Vt+72/vhu5UlAQ5fyZCDVpbx9t/KUsLxMBfSeHeJVKh8ctrFUPgLo0a08sEv5yG73jwz5pVQ+qtv9o7rdP7rb/aO73T9f0/y6LuULu5Uv6la+pFv5eODaLuUTu5VP7lY+NSz/EP8MDelf4m8zlod3GR9/BMZ0K0/6Cfxfen6QwLajVO4ngu+Vyr02UO4qL3qGMi3X2tqyYHFryyy/dZE/Z+Gc1jnT5885osUP5sh6O2f6hx4yZ35LuOiZ0WKWd7OWLlq8uGUWzJceB4byw4xfCbb/CnDCXzccm7GwHAnHiymbNsVD/jf3EuH75SGPLzjgmVezO38+87Bcfq8l/U+9Z6Mr99+jcY/oy7sdtnbVLl9f9bfpy5a1LLUSffb0OfNbZo31Fy8z7R+3pW9/h/gL5iyctmhFy9JDWqbPgoHKs3xziwq+/UPvb9H5/vTDNvD+XSpoY9d+VXbrv+nTzOmLp8+c03q4b6qYPX/RoXo+ecXx2DnkD0ObZ6Rnsb9MwFZdyr8UAQ9caxMsKSEl65RUokwK8V30BeGKhnohhPFPFVmRiMQTQmws4ht5oiUm4kLVCCHS/fdLjE0oIUWfPkOcrIhKt2zw2GZTX0wJJxqX5aJSjBVKxURP2U9IOUYoqaR0HJGWjkiYsiukEj1lr4gQY83HVExEZUKmxZYiKUYIRzSbPFCOUObqAPtMRLiyp6yWY2RQ8wAxSUQdIfYVMpoomyFkWZkQ0cmyv80V1VwhXCHdhKgrE7OViAgpZV+pHE+VCykjolIcKHBwBsi0TMvxUkRjQiYc4Yp6d6TIyVqxwlGyTESct6QUY4WImlplLBKXYlMRGyqTPdVwIVzz/4PKktJXUgpntBDSEcIZG5NyfGRXR5SLqPm4I0dsVS5qWxDCSTjl5n8y6cZk3JDabXBFXIhy4ZWPrEEucY4WleIgf5hA1CnHdcUBkQMcBBHEDGWerFY7y4raqaK3M1s5MiYcoURf6YoLZL8e5WJgrG9iqDNcGJJXiY3k1hEppUyKmNhYJMRIIRwpXSlEf1Fhf2e5Zc7hQgpjbLGmCs8RSa9WSHGdiIqISIiNhRDDRL3cRmzkID4U57lExGaiDEdIVw1ylLhGogaKfq4QyD6iMTIx0aCOFE2Vg4WUcadBSWlqj4sRTp0rYuNEUjaWxZUU0xwphIwIKS4VTmwjOUY6YpOo406MlFsMK+QeMUPO3mL3iBBKCvmlikgh+5vrQsi5wr4uWsyvEi5lQmKYSZylpFTCjw+KSBUTZRHpDC0X8nEhHCGeMt0Su/aSUUP8IyKO4feoEJPMp0TO5IGVja4rzHepilSSdBzczaNlCnPOJ7aqGi+FLJcoEbXfUwyVvXHKPBlNq3MdmtWImKgQvVxRSVRU2e+47rgacXkFYkuF+2/5jfmkdEWZILoghotIH9T2NSwmSscAnJQYbgZeVEYM/TYOBl5ZmeijRJlwhDvE2bxssz7C7zr0zFAxxIiJMhH/SYPPIB8TUScu02JzIURSlIlBGxqAUggnLJtB2PkVMxCV5fuY2EXIaDJmBmPivxiKOKhqOxRLA7FM1DtCLP/RYTg8GIRx5QwMBmAkHH7u6JiUF3YZfI+PRzxSg3O6OMgnMsdAF/flzpINDB0hsuHgkaJObNMxeIZ1GTqbyZj4MhwyeJ6HDAfGekMi5yJ33MBgiIotuwwEIWLBUFBdB4IIkaiIOu42lskN+ZXTdVDsUrouhfxcyO8PjVjXgfEXEVc/Z2CEw8LpLRCdgwKxldpFJCTSDgLXicRiPzYMZonLo3YQqM5BQHRBFMP+15spRri9RUY8IpR6UW4clWn3ILGZAUMJ0zrTkp5iN3FqLC8Glg1moJG/cohQ7CkjJNwh0hm/bjwyIgfKiIyFiQMzcpTpiOOIqKNkRJW7joi2WonbYlg3bng9EjyrREQJqVxXiSrfkTTySUQ5Mmm/xNAe6dWzHSV27BkRDkNERWRnokmXfpF+7HxQnaprTYhKIxdE+IvhSCMtR5aPPKBXvBfjGe/3lX3ZQ2xnxpTtWdLkNRQ5oWRMREW1IYIQTlQg+4q+ItPtX4y46BmJMI5tzXcj/RjeMGJk46im5tFjps+YOatl9n5Dhw49YOb0+fNbZvkH7bTYLEbGjl2+0Oz9Bw0+yF+00J/uHzR10cKWg/wV0+cvbxk+fHjD8BHDRw5vHD5qeNPw5uGjh49pGN7Q0DCiYWRDY8OohqaG5obRDWNGDB/RMGLEiJEjGkeMGtE0onnE6BFjRg4f2TByxMiRIxtHjhrZNLJ55OiRYxqHNzY0jmgc2djYOKqxqbG5cXTjmFHDRzWMGjFq5KjGUaNGNY1qHjV61Jim4U0NTSOaRjY1No1qampqbhrdNKZ5eHND84jmkc2NzaOam5qbm0c3jxk9fHTD6BGjR45uHD1qdNPo5tGjR48ZM3xMw5gRY0aOaRwzakzTmOYxo8eMwXKIkm40KmOxMhl3E7JCeaJK9nBT1T3FRrK37Fte7Q6IZUSdmKvmyYXyJucWeb98Qb4kX06+UvaqfE2+Kd53P5C/FZ+qz+SX/tfqW/kP9zuR3HTzcVN3OvOyy3555Klrzr/y1ntPuCUSLWvactye37z4kurZp6l5z71WXn/jTQ+Mer/HiSefcZkqr6jqMbihceyEiZO3n7rTrJZTTjv9zDsffuTRx595df877+pfHY3FEz17N40Ze+11r79R1nzW2ddG45uPmz3nzHOqFk176Muv9p1xz71//mf7brtfdPHQYZsO2uPSy6+46urCtWvvvf/RSCK50YCxW03YJV949rnLo3371W4ybqv3Pv3iq/bHHlf+xpsMHDRy9NhJ2++w82577Ln3vvsfeNDMltnzlh129MpTrr7+ppvXvXjjTQsXPfHOu2sOrD3SdVS9M9sRw4a2HTvAaaisVnVlaXeIu62q2Kzt+kidqlODYo2JqU5jclVzWa94rM/mE8Y4M2Nlw3u5NU5/t//A0WqKO0zFo2XR8f6mKlnW5Ix1+0VVMrrz5OaR5SOjQ2PxyEBnQEy2vbirF4mV1QyNbdar38Dqnr3Lpqq6sm3L+0bjkUmxTcuWJ7Yet1lkczce2SUiXM9xE/vE5IAZ6UmxeFv+wNoJiXikPDU2Eo/0rM6q3m33bDFrt+SksvjECf0nxXYrnxyN7xnbu7YiHpkYH+BsN7nZqYjFI2Oi8UhWxdv+2je6uTNgD1E5ovy4i2cvT7Q9esoOM8tXD/d6xc+82D12u4vuGXvC48eOiW6m9o8MjE+MD3JTq24e2/b4fm5jdooaE60a7yaiZZe8OtifvuyKf60aWSkGRCpUbNVpJ6t5brlTFvXOPKjtX86q2yO9K6sqtytr3aLtr/FlscUbTTyiZ7Jncq+yvm0nrtrOOX7ryo1W75yJRNpeGWJWBIvrnX5KrhqfqRrrilUvbtb26hAl2tY1xWTb+21/G7yDiit5XNW2O2zZ9qstIkLt4fZvlKsqsmpWcs94242jB5RnVVlUVkTaLjruDafKKXfa3owklahMqtGxeGRQLKtkRdmmq/aODXASzuYqreLRtu9WuwjHdSMRGY3EomVV8epE32Tfcq8iWak8p0ePVFkv0Vv1EX2dfmX9RbXM9PKdIbI+MVQMVw1yhLhOXq+KsX/I79x/O+1law87/NTTrxy+196nnnZW9TsVlVN2+O6fQ4dttf8B0z5cffoZZ59z3S333vfY4089/e7Hn7Sj7JBoHrv5lpO3P2D1Gedcd8vt9973+NPPv/DxJ3QMmM3NiDlwVsvqsy++9KnnXyivGjx28wmT99rvwGmzWk4/+7rb773vsafe+/iTr8urJkye1dK2+tb7H3zolde+/uNxx596df7Bhx574oU335p04QPPPf78C5On7rTXPgdOO/mMM2+5866HHn78ideqevXeb/+//u3f7W0Llrz7XkVm4aLqAdOOPubGm959b8f77u/VO52ZuN3UncygOWblHY+9/MrbX//xL0uXndm6fODQYYWb7nroiRdee+8ixl9w4fAzM8+3T91p3/2isUpv02FffrVwUfOWW209YbeDlz/51Isvvf7Gp/9ux59We+x76thtY/1VpGrVDRVtRdePrqp2+saEGqYaVdQR0Ui0Kr5zZY/oHlFHVcfLnJgTdaTjOEnlOomIqNjInRrtH90rKt0+yZ3VNk69I1RVpDI5Vg3YZJq/QM3dpO1J99ibnX6RY//l7BPtVdanzLDc3Eg80i+yT3SIOzGeVUklnIZEVvWLJJy2GyLxyA492/4c28KpdMa5o2ND3GPbq/rEhlXVO7UVNZVtp6ljL+qV2Oikc91h7uZRWdGnrO3B2tZk26v9Vnlu2wdlf7rMaS5btX/Ptrtjbe/03MKJR0bHJsaSkdZE2tlX7VPWdlyf6nivsh1U2ymR4tXJ3qrhCrXqzYHRpOsee5u/WWQH1Xa6anvQ6e9UlrPS8dgj3LMb/VOp3C/UT00P9AlGmzBrzgpzPDLjcP+IlqWLgHXA+8DD+rkPgF8BHwKP6H3yR8Cjod70MeAT4PFQr/oE8CnwJPAZ8BTwO+Bp4Pdmvwx8Djwb6k2fA/4APA98CbwQ6ldfDPWuL4X6ll8DfwJ+A3wDvBzqaV8xehfgVeCvwOvAt8AbwN+BN4F/AG8B3wFvG30N8A7wL+Bd4N/YTIwIAVKAI0AJMBkaIwKiAmJGJ2P0OAISwrhcQbmACgGVAjwBVQJ6CEiJQGezkQh0Ob0F9DE6HBHofvobfY+AAQLSAjICfAE1AmoFbCygTsAmAgYK2FQEOqTBAjYTMERAVkC9gKEChgkYbrJJMp4RRg8hoFHAKAFNApoFjBEwVmDS7rKFgC0FjDM6DAHjBeQEbC1gGwHbCpggYKKA7QRMErC9gCkCdhCwowj06jsJ2FnALgJ2FbCbgN1FoIPfU8BeAvYWsI+AfQXsJ2B/AQcIOFDANAEHCZguYIaAmQJmCWgRMFvAwQIOETBHwFwB8wTMF7BAwEIBiwQsFrBEwFIBywS0ClguYIWAQwUcJqBd88LhAo4QcKSAowQcLeAYjfkq024Jx2rcj9O4r9a4H69xP8H0RcKJGvuTNPYnmz5JOMX0SwY6/dM0/mfo/ryjeeBM008JZ5l+Sjhb88I5mhfWmD5KONf0T8L5pj8SLjB9k3Ch5otfaH64xPRHwqWmLzLQJy2XcLnmiys0X1yp+eIqzRdXa764RvNFXuPdJqEgjM4JrjPtl3CD5ou1mi9uNO2WcJPmjZsF3KJ59xQJt2r+PVXCbZpHbtc8cofmkTs1j9xl2ivhbs0r92geuV/zyAOaFx7VfPC45ofHwt8nBDyleeLJ8PdpzRPPat54Jvx9TvPG85o3XtC88aLmjZc0b/xa0/g3mp4va954RdPxVU3D1zRvvK5p9obmjTc1zd7SvPG25ot3NV+8p2n2vuaLDzRffKjp9pHmi481X3yi+eK3mi8+1bT7TNPxd5ovfq/54nPNF19ovviD5osvNR2/0jzxR80P32ha/in8/bMw+ln4qx6X52ne+Jvm/Yc0f3wr4O+aN/6heeM7zRv/1LzxL80b/zZ8IwIlodD8ISU4EpQEV0JE80ZUQkxCmeaLuLTH7yQllEuokFCp+cHTvFAloYeElOaDnhI20nzQS/NAb41/Hwl9JfST0F9CteaBARLSmgcyGn9fBrrGWgkbS6iTsImEgRr7TSXG/sHYq7CZhCEa/6yEeglDNfbDJAyX0KBxH6ExHylhrISWLGwuYQsJW+pvX2RkgYTZJhm1hK0lbCOhzcgC3b+TpTkLgdMkTJSwnYRJEiZL2F7CFAk7SNhRwlQJO0lY7MASx+jYYZkDKxw41DFzEHzjwAkO3ODASQ6sdeBkB2504BQHbnLgNAeedeAMB55z4EwHnnfgbAduceAcB251YI0DtzlwrgO3O3CeA3c4cL4DdzpwgQN3OXChA3c78AsH7nHgIgfudeBiB+5z4BIH7nfgUgcecOAyBx504JcOPOTA5Q6sc+AKBx524CoHHnHgagcedeAaBx5zIO/A4w4UHHjCgWsdeNKB6xx4yoHrHXjagaIDzzjwggOvOfC6A2848KYDbznwtgPvOPCuA+858L4DHzjwoQMfOfCxA5848FsHPnXgMwd+58DvHfjcgS8c+IMDXzrwlQN/Dvv/Nwe+deDvGps/OfAPB75z4F/690gH/u3AURqndn3t6PBQYGcFQpnzOJAKdlXgKNhNgVKwuwJXwR4KIgr2VMEZyF4KYgr2Nvp7BfsoiCvYV0FCwX4Kkgr2N/p5Zc47oELBgQoqFUxT4CkYrqBKQYOCHgpGKEgpGKmgp4JGBRspGKWgl4ImBb3NGYyCPgpGK+irYIyCfgrGKuivYHMF1Qq2UDBAwZYK0grGKcgo2EqBr2C8ghoFOQW1CrZWsLGCbRTUKdhWwSYKJihz5gATFWyqYDsFgxRMUjBYwWQFmynYXsEQBVMUZBXsoKBewY4KhiqYqmCYgp0UHKRguoIZCmYqmKWgRcFsBQcrOETBHAVzFcxTMF/BAgULFSxSsFjBEgVLlTkPg1YFyxWsUHCogsMUHK7gCAVHKjhKwdEKjlGwUkGbxn6Vxu0UjdmpGq/TNFana5zO0BidqfE5S2NztsblHI3JGo3HuRqL8zQO52sMLtD0v1DT/hea7hdpml+s6X2JpvWlms6XaRr/UtP3ck3bKzRdr9Q0vUrT82pNy2s0HfOahgVNv2s17a7TdLte06yo6XWDptVaTacbNY1u0rxxswrOh27V/HCb5oXbNR/coXngThWc+9ytcb9HY36vxvs+jfX9GucHNMYPanwf0tiu03R+QWP7sMb1VxrTRzSej2osH9M4Pq4xfELj96TG7imN29Mas2c0Xs9qrJ7TOD2vx8aLely8pMfEr/V4+I0eCy/rcfCKHgOvav5/TfP+65rv39A8/6bm97c0r7+t+fwdzePvav5+T+P7vsb3A43vhxrfjzS+H2t8P9H4/lbj+6nG9zON7+80vr/X+H6u8f1C4/sHje+XGt+vNL5fa3z/qPH9k8b3G43vnzW+f9H4/lXj+zeN77ca379rfP+h8f1O4/tPje+/NL7/1vi2hwd+wgXpguOCcsF1IeJC1IWYC2UuxF1IuJB0odyFChcqXfBcqHKhhwspF3q6sJELvVzo7UIfF/q60M+F/i5UuzDAhbQLGdcEtjOppk2uWtjYhToXNnFhoAubujDIhcEubObCEBeyLtS7MNSFYS40uDDdhREuzHBhpAszXWh0YZYLo1xocaHJhdkuNLtwsAujXTjEhTEuzHFhrAtzXdjchXkubOHCfBe2dGGBC+NcWOjCVi4scmG8C4tdyLmwxIWtXVjqwjYuLHNhWxdaXZjgwnIXJrqwwoXtXDjUhUkuHObCZBcOd2F7F45wYYoLR7qwgwtHubCjC0e7MNWFY1zYyYWVLuzsQpsLu2j8Vrmwq8bwWBd20zge58LuGsvVLuyh8TzehT01pidoel9uDjrr4QoPRD1c6YGJS3uVBybW8tUeqHq4xgMTRjfvgYnYUfAgWg/XemCCyF/nQVk9XO9BvB6KHiTq4QYPTHittR6YwIU3elBRDzd5UFkPN3vg1cMtHlTVw60e9KiH2zxI1cPtHvSshzs82Kge7vSgVz3c5UHverjbgz71cI8HfevhXg/61cN9HvSvh/s9qK6HBzwYUA8PepCuh4c8yNTDOg/8enjYg5p6+JUHtfXwiAcb18OjHtTVw2MebFIPT3iwaT0850F9PVyegsVFuCIFS4pwZQqWFuGqFCwrwtUpaC3CNSlYXoR8ClYUoZCCQ4twbQoOK8J1KTi8CNen4IgiFFNwZBFuSMFRRVibgqOLcGMKjinCTSlYWYSbU9BWhFtSsKoIt6bg2CLcloLjinB7ClYX4Y4UHF+EO1NwQhHuSsGJRbg7BScV4Z4UnFyEe1NwShHuS8GpRbg/BacV4YEUnF6EB1NwRhEeSsGZRViXgrOK8HAKzi7Cr1JwThEeScGaIjyagnOL8FgKzivC4yk4vwhPpOCCIjyZgguL8FQKflGEp1NwURGeScHFRXg2BZcU4bkUXFqE51NwWRFeSMEvi/BiCi4vwkspuKIIv07BlUX4TQquKsLLKbi6CK+k4JoivJqCfBFeS0GhCK+n4NoivJGC64rwZgquL8JbKSgW4e0U3FCEd1KwtgjvpuDGIryXgpuK8H4Kbi7CBym4pQgfpuDWInyUgtuK8HEKbi/CJym4owi/TcGdRfg0BXcV4bMU3F2E36XgniL8PgX3FuHzFNxXhC9ScH8R/pCCB4rwZQoeLMJXKXioCF9r/vlW894fNf/8XfPenzT//EPz3jeaf77TvPdnzT//1Lz3F80//zIYV8Mp1XBWNbyox9zZ1fCSHnPnVMOv9ZhbUw2/0WPu3Gp4WY+586rhFT3mzq+GV/WYu6AaXtNj7sJqeF2PuV9Uwxt6zF1UDW/qMXdxNbylx9wl1fC2HnOXVsM7esxdVg3v6jH3y2p4T4+5y6vhfT3mrqiGD/SYu7IaPtRj7qpq+EiPuaur4WM95q6phk88yFfDb/XYK1TDpx5cWw2feXBdNfzOg+ur4fceFKvhcw9uqIYv9BhdWw1/8ODGavjSg5uq4SsPbq6Grz24pRr+6MGt1fAnD26rhm88uL0a/uzBHdXwFw/urIa/enBXNfzNg7ur4VsP7qmGv3twbzX8w4MHquFfHjxYDf/24KFqaPcCA0GRBpkGJw0qDW4aImmIpiGWhrI0xNOQSEMyDeVpqEhDZRq8NFSloUcaUmnomYaN0tArDb3T0CcNfdPQLw3901CdhgFpSKchkwY/DTVpqE3DxmmoS8MmaRiYhk3TMCgNg9OwWRqGpCGbhvo0DE3DsDQMT0NDGkakYWQaGtMwKg1NaWhOw+g0jEnD2DRsnoYt0rBlGsalYas0jE9DLg1bp2GbNGybhglpmJiG7dIwKQ2T07B9GqakYYc07JiGqWnYKQ07p2GXNOyaht3SsHsa9kjDnmnYKw17p2GfNOybhv3SsH8aDkjDgWmYloaD0jA9DTPSMDMNs9LQkobZaTg4DYekYU4a5qZhXhrmp2FBGhamYVEaFqdhSRqWpmFZGlrTsDwNK9JwaBoOS8PhaTgiDUem4ag0HJ2GY9KwMg1tGv9VGv9jNf7HafxXa/yP1/ifoPE/UeN/ksb/ZI3/KRr/UzX+p2n8T9f4n6HxP1Pjf5bG/2yN/zka/zUa/3M1/udpfC/T/XsPuFzje4XG90qN71Ua36s1vtdofPMa34LG91qN73Ua3+s1vkWN7w0a37Ua3xs1vjdpfG/W+N6i8b1V43ubxvd2je8dGt87Nb53aXzv1vjeo/G9V+N7n8b3fo3vAxrfBzW+D2l812l8H9b4/krj+4jG91GN72Ma38c1vk9ofJ/U+D6l8X1a4/uMxvdZje9zGt/nNb4vaHxf1Pi+pPH9tcb3NxrflzW+r2h8X9X4vqbxfV3j+4bG902N71sa37c1vu9ofN81/df4vq/x/UDj+6HG9yON78ca3080vr/V+H6q8f1M4/s7je/vNb6fa3y/0Pj+QeP7pcb3K43v1xrfP2p8/6Tx/Ubj+2eN7180vn/V+P5N4/utxvfvGt9/aHy/0/j+U+P7L43vvzW+7Wb8ZgIj4bIMiAzEMyAzkMiAk4FkBlQGyjPgZqAiA5EMVGYgmoE+GfAy0DcDVRnol4EeGeifgVQGqjPQMwMDMrBRBgZlwM/A4AzUZGCzDNRmYEgGNs5ANgN1GajPwCYZGJqBgRkYloFNMzA6A8MzMCYDDRkYm4ERGdg8AyMzsEUGGjOwZQZGZWBcBpoysFUGmjMwKQPjMzA5A7kMbJ+BrTMwJQPbZGCHDGybgR0zMCED+2Rglwzsl4HdMnBABvbIwLQM7JWBQzJwUAbmZGB6BuZmYEYG5mVgZgbmZ2BWBhZkoCUDCzMwOwOLMnBwBk7S9G/T9D9Z03+Vpv8pmv7Havqfqul/nKb/aZr+qzX9T9f0P17T/wxN/xM0/c/U9D9R0/9CTf+zNP1/oel/tqb/RZr+52j6X6zpv0bT/xJN/3M1/S/V9D8vA5dl4PwM/DIDF2g8rtV4XK7xuE7jcYXG43qNx5Uaj6LG4yqNxw0aj6s1Hms1HtdoPG7UeOQ1HjdpPAoaj7s1HjdrPO7ReNyi8bg3A4s1HvdlYInG436Ny22a7k9mYKmm+1MZWKbp/nQGWjXdn8nAck33ZzX9H8nA6xl4UdP9DU33lzLwZgZWaPq/lYFDNb0/0/R+X9P7d5reH2h6/z4DR2h6f56BIzW9v9B0/iQD3+p/h2Xg7/rf4fqZf2TgKP3Mdxk4Wv/9T92Obwxv1QT6rM1qAnvSITXBOdCIGphaAwfVwOIamF4DS2pgRg0srYGZNbCsBmbVQKt+p6UGltfA7BpYUQMH18ChNXBIDRxWA3Nq4PAamFsDR5hv1cCRNTC/Bo6qgQU1cHQNLKyBY2pgUQ2srIHjamB1DdxZBy/WwV118FId3F0Hv66De+rgN3Vwbx28XAf31cErdXB/HbxaBw/UwWt18GAdvF4HD9XBG3Wwrg7erIOH6+CtOvhVHbxdB4/UwTt18GgdvFsHj9XBe3XweB28XwdP1MEHdfBkHXxYB0/VwUd18HQdfFwHz9TBJ3XwbB38tg6eq4NP6+D5OvisDl6og9/VBcbJw7MgstCQBZmFEVlwsjAyCyoLjVlwszAqC5EsNGUhmoXmLMSyMDoLZVkYk4V4FsZmIWF0wllIZmGLLJRnYcssVGRhXBYqs7BVFrwsjM9CldEVZ6FHFrbOQioL22ShZxa2zcJGWZiQhV5ZmJiF3lnYLgt9sjApC32zMDkL/bKwfRb6Z2FKFqqzsEMWBmRhxyykszA1C5ks7JQFPws7Z6EmC7tkoTYLu2Zh4yzsloW6LOyehU2ysEcWBmZhzyxsmoW9sjAoC3tnYXAW9snCZlnYNwtDsrBfFrJZ2D8L9Vk4IAtDs3BgFoZlYVoWDsrC9CzMyMI8CTOzcPQAmGV+JRychUOyMCcLc80zWZifhQWmvRIWZmGJhEWmH0ZPbtovYWkWlmVheRZWZOEYQ28JKw29jS5eY75K432sxvo4jfNqjfHxGt8TNLYnalxP0pierPE8RWN5qsbxNI3h6Rq/MzR2Z2rcztKYna3xOkdjtUbjdK7G6DyNz/kamws0LhdqTH6h8bhIY3GxxuESjcGlmv6Xadr/UtP9ck3zKzS9r9S0vkrT+WpN42s0ffOatgVN12s1Ta/T9Lxe07Ko6XiDpuFaTb8bNe1u0jx7s+bXWzSv3qr59DbNo7dr/rxD8+admi/v0jx5t+bHezQv3qv58D7Ng/dr/ntA896Dmg4PaRqs0/z2sOa1X2k+e0Tz2KOavx7TvPW45qsnNE89qfnpKc1LT2s+ekbz0LOaf57TvPO85psXNK1f1PR/SdP615pPfqN55GXNH69o3nhV88Vrmide1/zwhuaFNzUfvKV54G2N/zsa+3c17u9p3N/XuH+gcf8wCx9pXD/XuH6hcf1DFr7Mwp80ft8YrPKQy8PWedgmD9vmYUIeJuZhuzxMysPkPGyfhyl52CEPO+Zhah52ysPOedglD7vmYbc87J6HPfKwZx72ysPeedgnD/vmYb887J+HA/JwYB6m5eGgPEzPw4w8zMzDrDy05GF2Hg7OwyF5mJOHuXmYl4f5eViQh7Y8rMrDsXk4Lg+r83B8Hk7Iw4l5OCkPJ+fhlDycmofT8nB6Hs7Iw5l5OCsPZ+fhnDysycO5eTgvD+fn4YI8XJiHX+ThojxcnIfaAmxcgLoCbFKAgQXYtACDCjC4AJsVYEgBsgWoL8DQAgwrwIgCjCxAYwFGFaCpAM0FGF2AMQUYW4DNC7BFAbYswLgCbFWA8QXIFWDrAmxTgG0LMKEAEwuwXQEmFWByAbYvwJQC7FCAHQswtQA7FWDnAuxSgF0LsFsBdi/AHgXYswB7FWDvAuxTgH0LsF8B9i/AAQU4sADTCnBQAaYXYEYBZhZgVgFaCjC7AAcX4JACzCnA3ALMK8D8AiwowMICLCrA4QU4ogBHFuCoAhxtrg2AYwqwsgBtBVhVgGMLcFwBVhfg+AKcUIATC3BqAU4rwOnmu+acugBnF+CcAqwpwPkFuKAAFxbgFwW4qAAXF+CSAlxagMsK8MsCXF6AKwpwpcbvKo3f1Rq/azR+eY1fQeN3rcbvOo3f9aZvEoqGFuZc2vRfwlrTNwk3mr5KuNnULeEW005zPm2+Z86nDZ2NPkvjfIfG+U6N810a57s1zvdonO/VON+ncb5f4/yAxvlBjfNDGud1GueHNc6/0jg/onF+tADnaqwfM7SR8HgBzhgAT2jMn9SYP6Uxf1pj/ozBRMKzGvfnNO7Pa9xf0Li/qHF/SeP+a92e32jcX9a4v6Jxf1Xj/prG/XWN+xsa9zc17m/pvr5t6GbOmgvwV02rmnbItUNtO2zdDhu3wzbtUNcO27bDJu0woR0GtsPEdti0HbZrh0HtMKkdBrfD5HbYrB22b4ch7TClHbLtsEM71LfDju0wtB2mtsOwdtipHYa3w87t0NAOu7TDiHbYtR1GtsNu7dDYDru3w6h22KMdmtphz3Zoboe92mF0O+zdDmPaYZ92GNsO+xpDCiUYZKyzlWCwEkgl2EwJTETJIUqglCCrBK4S1CtBRAmGKkFUCYYpQUwJhitBmRI0KEFcCUYoQUIJRipBUgkalaBcCUYpQYUSNClBpRI0K4GnBKOVoEoJxihBDyUYqwQpJdhcCXoqwRZKsJESbKkEvZRgnBL0VoKtlKCPEoxXgr5KkFOCfkqwtRL0V4JtlKBaCbZVggFKMEEJ0kowUQkySrCdEvhKMEkJapRgshLUKsH2SrCxEkxRgjol2EEJNlGCHZVgoBJMVYJNlWAn3febleB13fdblOAN3fdbleBN3ffblOAt3ffbleBt3fc7lOAd3fc7leBd3fe7lOA93fe7leB93fd7lOAD3fd7leBD3ff7lOAj3ff7leBj3fcHlOAT3fcHleC3uu8PKcGnuu/rlOAz3feHleB3uu+/UoLf674/ogSf674/qgRf6L4/pgR/0H1/XAm+1H1/Qgm+0n1/Ugm+1n1/Sgn+qPv+tBL8Sff9GSX4Rvf9WSX4s+77c0rwF93355Xgr7rvLyjB35TgRSX4VgleUoK/K8GvleAfSvAbJfhO173YFVzgCpa4ggtdwVJX8AtXsMwVXOQKWl3Bxa5guSu4xBWscAWXuoJDXcFlruAwV/BLV3C4K7jcFRzhCq5wBUe5gqtcwdGu4GpXcIwruMYVrHQFeVfQ5goKrmCVK7jWFRzrCq5zBce5gutdwWpXUHQFx7uCG1zBCa5grSs40RXc6ApOcgU3uYKTXcHNruAUV3CLKzjNFdzmCk53Bbe7gjNcwR2u4ExXcKcrOMsV3OUKznYFd7uCc1zBPa7gXFdwnys47/9p7srD5Cau/HuEy041CeZSEjAzYYCA7bZKtyAJ9oxlM9geG48vzCGXpFJP292lRtLMeEgIBMh9kvsi6+FwAHPlItkNJOSA3AFyh1x2NrsbbbIJ2fyz38eXZEdSzzCHZ7CJd+Gf7lJ3lerV7733e6+qu18fhfBALh9BeIggvJ4gfJkgXEcQvkIQricIXyUINxCErxGENxCEhwnCGwnCIwThTQTh6wThzQThGwThLQThmwThrQThWwThbQTh2wTh7QThOwThHQThuwThnQThewThXQThUYLwboLwGEG4kSA8ThDeQxC+TxDeSxB+QBDeRxB+SBDeTxB+RBA+QBB+TBA+SBB+QhA+RBB+ShA+TBB+RhA+QhCeIAgfJQg/JwgfIwi/IAg3EYRfEoSPE4RfEYR/Igi/Jgi7CcI+gjBKEPYThJsJwm8Iwi0E4V8Jwq0E4bcE4TaC8G8EYQ9B+HeC8AmC8B8E4XaC8DuCcAdByAjCnQThPwnCXoLwe4JwF0H4A0G4myD8F0G4hyD8kSDcSxD+RBDuIwhPEoRPEoQ/E4RPEYT/HsNkfQWhVUG4uIJwZQVhQwUhriD0VxCSCsLGCkJaQdhUQRisIGyuIAxVELZUEIYrCFsrCLsqCJdUEEYqCNsqCFdVEC6tILymgnBZBeG1FYTLKwhXVxCuqCC8roLgVhCuqSBsryBcW0FgFYTXVxC8CsJ1FQS/gnB9BSGoINxQQeAVhDdUEHZLCA9JCKMSwpclhJslhK9ICLdICF+VEG6VEL4mIdwmITwsIeyREB6RED4hIXxdQrhdQviGhHCHhPBNCeFOCeFbEsJeCeHbEsJdEsJ3JIS7JYTvSgj3SAjfkxDulRAelRDukxAekxA+KSE8LiF8SkL4voTwaQnhBxLCZySEH0oIn5UQfiQh3C8h/FhC+JyE8BMJ4fMSwk8lhH+WEH4mIfyLhPCEhPAFCeHnEsIDEsIvJIQHJYRfSghflBB+JSF8SUL49djalwmE7QJhuUBgAqFbIHgCoUcg+AJhhUAIBIIjELhAWCkQQoGwSiDUBMKFAmFAIPQKhLpAuEgg7BAIqwXCToGwRiA0BMJagdAUCH0CQQiEdQIhEgjrBUJLIFwsEK4UCBsEQiwQ+gVCIhA2CoRUIGwSCIMCYbNAGBIIWwTCsEDYKhB2CYRLBMKIQNgmEK4SCJcKhNcIhMsEwmsFwuUC4WqBcIVAeJ1AcAXCNQJht0D4gkAYFQgPCISbBcKDAuEWgfBFgXCrQPiSQLhNIDwkEPaMYfVlgfCJMay+IhBuH8PqqwLhjjGsviYQ7hzD6mGBsHcMq0cEwl1jWH1dINw9htU3BMI9Y1h9UyDcO4bVtwTCfWNYfVsgfHIMq+8IhE+NYfVdgfDpMay+JxA+M4bVowLhs2NYPSYQ7h/D6nGB8LkxrL4vED4/htUPRPnlv5dnCJghnJEhHJEhdGUIL8gQzswQjswQzsoQjsoQzs4Qjs4QXpEhHJMhnJMhHJshnJshzMsQFmUI8zOExRnCCzOEJRkCyRCqGUIlQ1iaIRyXIcgZwosyBJohvDhDUDKE4zMENUNYkCFoGcIJGYKeIZyYIRgZwkkZgpkhnJwhWBnCKRmCnSFIGcJ5GcJLMoTzM4SXZgivzBBeliG8KkM4NUN4dYZwWoZwQYawMENYliGcniEszxA6MoTuDKEzQ+gZk+2+IwAuP2E7LjhlX8eDp3d0vnZxx9Kj5O3qSbv3af9z63aD7NltLHxqnwF/29f9V2Q91x7TuQrn71/VVRm9cO9xnRctlTpXn/+S/dv+spBdKmuj/kLWyZ/cMcqXNTvDddH+8Ml7We2mB0YH4FFWrz2+vw5PsB2wb3TnPb/pbP7+t53RY78bjZ76C7sS4Y9XPokXxPkPyq8/sgNuPGo7XH7CblhwSsfppGP76X9avH3RufK+RdGe3YuPuL1jycKnOqrwt+3Gx/++2/grdpo4n5ldlU5r73Gj9lKp87y/LOxcvUwb7V/I9m98cgfbtC7av+mmB9hmeJRt4Y+PboEnOrfCvtFL7vlN56V/+O3+yx77Hbu8A/54xZN4jZt/12H3CduPvvbU3cecfHrHsfd3LZsHi3cvWLZn3wJ57/YT4amOkzv/vu9kOJKdcu0x+0+5loy+dLc0+rJlYvRU+OH+U/f8ePQ0+PXyl8v7Os+EbP+Z8p87z3rrn+OzqlV519M/DFve3bPCWQkPrDlSsCaHeccynlSHWdLEO9bchHCOu61PZTyhSsD9eKSVdueFUqg5YARMM5TACzRKDZmHDj7dlYspXVVf0UzODapavh5w1TlCb3fVi+I71KrxtFcMreYj/f4ADwYbPKHmQKCrnm8blmmrikVl7ryAuhv6htYmqtuX9iS6v86XlXVrtzYiVy/qu6hJGnOqpJHbiIZ57LOEH8nLIXIxZMeVu6yNvSPbvPW2a41XhZlo9Pal3bprrWhf9qV9abfpqslIYvSPJClvOkb5Tk/CvQt9xqP+eNeIqyZpcNT8XWmyogTo6LztlAgcs9Td1mdP1JahShC1e/Wk+RKp4Rmyp2iyQgNfsQLnWCWXN5e1tnVVS1935S6lb8TV8no6+eqoXRSTcXkcR7GblxFw43TeoimT2EHUnp2aA6Giqbqva4ohGwo3mDN/Rue2QLmebN3WFDOQTc1QZJs6L1Sm6kmv8XSaknSVqioPbFtWDFPzVYcsbY8x2yWXqOxHzdZgyjeyWi4RVbhnyrqm8JB7IXUqk0ws6plsNzzwwtBWTcOmnsr1wDnurHZX1febhu/5a5lPzQFZt0NLDQzVCGwmU8150bzyN42r+ciLl0zVgOyn8fo48nlS4s88qnjUtrhlBCF1jlfHpW+XRKJa/tjPm/XiHvkgpik+13xFtU1q+pbnLJif1IfaMJ6Qt9v4n6hPv5lRyjXldlwNQk81giAwLE0OuXPSonHUiz52Xh1jHBKLh5qlM081ZEP3qO+cfCidTzmUztKhdH7JGeOaSepDaqIM5eakhnJg2SHlNlV9y3JeOt/3m21sXqaXhj6rqet+NChSqgWRW7TcvC5acuqifFhPEq4JRwynl+r1Na7pum5RUYqqeStouDEv/PS0Y9pGsPD8OSYz2zWq7IkaVVSpJ+7E1em51G3tdsz0557Sm+PluQeZtsE0w1M8y5Rlj8lOp384+UcLY85nss/LxzWl1vzmNHfTbc0z1cA0PJkyzZadM96L7rY+SqnbtWZjV1kXqzrE/Wp1M/fzlzZ2rdrY1TWoyF0sKZ8m9Ula3C8LYuXlw1zBk5QH1Wp/i/sr46jZm/K4r3itvFNPV29xt1Ubu+yJUdQcsEPft6lshp7CdD2kTterS5yYe2CDCJtpgY69Moqb+Q+/Yqq0WODWRcprMWuc+XxYlUy5HoaBpWgWVwNPds6q1Pxm/wQ3nD3TetreUEYDbqi6pWm6xUyuGIHvvOKVBSqzGK6We4TZ5OlAFCQ+NXjisxZ3A+4N1ly+Kz1HO1RM1RYLzp1fmzD3RXm7LeLicZrW8vJpVmsSGSiKFvhqoDErUEPPd5acMXsQC5upPhzXU16lU8HQWDDEhM97cmcv4DQ1M2dlSzaZ7cmW6Sxtw9mWSD5zskTFQy4N5bpmekboM99nlDn0+WAazNR1Q+GWyakuyz5zlL4co62JOxsFRq1EL4ok5rSgufqGvL3DKVjBcgscV+Sazlv5C2QyFWnNw0k7VA3qSStKuOsPDIqdMwlIXzpZE1QN66Io7TQRyBm3tFBVqGzqtmcYjnH2tCRB9dPCgzizeWgYlDNDD2ngmGdOorY8/bhIzulDD5luMOrLnsqpTR2rdjjXazV5kzXqNTFzpfaUOFcK7QWchlTzZDUvfsBV57zng8XpjFLVkzmnXPcDNXDO75oEpVZry66EZhjovmFR3fB0y3nlGZNSrPYCGfdMhcnUlC1bM0LuvKp3PJ7OGVGp0m64AUsZpX4khnic1CORWONJ+qsbh9VSlUHRqIudsxnqBTeWmtEKzeSyVqtFHcwqC1gr5XFSrYaNnAtFtbqywdK1rJX37O3q6drU1dO1crriJt8ijVk9TcorlkZxtdrbbuWjNMF35SlCYMvcsFVP51wJNJs5y+bnTrOpFbCUL59OijWetgmxu17EiMBUqeopnm9opkFl2+km+fCVbY/rufjZaEavxaw1wJs8DxtcBJQ2omjnYMtNGtHwivXP5pZavvty6zURxUVKNfmOzvPBP3xD81RDsXRZN0zF0JyV03cKRZjqzqvtFkHOZoaSk1uge7pHnVUz9zkBj+tDfDUfKXYJnmwGYagpIVe4p5rOhfMm9lq98WE1eqsu8spyboPFtVk5+qLpyUdRR3hjtCWKg1xe2dB1OWCq7LPQlwPDWb3yWehdz9U+1X7W2HMk33qxn9WLUr3UKDe3xUWxuV373LurIptM1WxOA8vXFNtw+uZNZG3rLihjuOXOilIenVUx2FTrzVazL+22XXNFPWk12Ej+zvrqVJ3QerKZNerB2vzCHNACVdfD0NQtjwe6YjoXX1FOuG2WAxAjSeO6qBUWY/S327PnYVtyA2/bebG92vCGHG/bOgx+OdUhnyFUeZqnG7bBTB6GnCpO/3QaTIZZaw1LypOB3FrNQFMMneoytUPND3VnI5l8tLLpBhxPr4o8dEYYbzFR93fWRY2qRdMdYCJo8LjItWg7Iy4RXM9GGhELZjPgYjhV1udP7a5WynZy14t2bZanrkPN6TxNNrO4zkRanD8oIVNl2WJcNhn3nC3b3G19pTLNdpFjRd7Ahjdzv1eIEsjlOYBmzBMeD3GFBpHbbrtMBO2F5EmSJuu6ZzPZ5LqpKKqz9fgZx2yXqOOZ+hwIlUt0h+vpgDsQRTu3kcn7lkun88pwTigbo3Hi5H5gcN1TVd/QDC0wncu8g1wipWFd1JMBtxZHw7mZ6NSknhHatq2aoRI61UZjqFmlsmkbMlVtzVJsPa9WpdPLt5QEq83iKOMTF55C6aTZp0w6E5Yrjpt6BObmhtbbN5RPpjzDZL1F5j5ltpbzDHos6J6Nu/eqRuSxhjNTrO20NPcds2/1yrU+vWdgg///gncz17vwsGim23T9a55DF889OTDdDX09hzi73K27vDrlZI3StstsooqVhxyNU1kJLV1niqH4phP2zPAYapfIjBNr4TBWjowbidz7DUMOPM2nqqqF1FYDp7Z87sPzycrWXaO8/YAzcc9u0x1wJvass2QteVpS4NvOTfrSbsO1l+dv5YG1nKl+UGd4QXmGt+Oywx/y7DLiJWm8c57rNpKBOK2rjXmuy5KBRlpXm+as5xeT9GqVei3/RcH1B7i/U1xUDDzw0DLVUeRpNeXderPVoGaDC7dZT4rq8UXuE8lzoWS1USowcPOiRzxonfd0ojWHPdJS7uJQ1aoLP+Ys4cPXtQMmfRbupLvU6E9ZWvf70/gfCJdXLzkI3MuWGzbTa4533WGvLoJajl2BxPW4q1yG/OyWcQiskJvT5FzuBuwrlf9MB27mrIeYVCkNsxXzsL7rjbhg0vraR9pvwupcZmHmT27xnynuoBiui+DNePZsIb50IKsYEkXNt+BwGREOKHsrjamal+J268JtNZifM/yhQXxAdJ3ute5bccnsTDpNypw/34ZXHZKkvUUw0lwjKipeGmXhy95SsQf4GHGI+2pBfs4BQu3bMTxUmOYirjZnHWCid+A5c6maTmaAd6I8ruXZmZ0qpSLKTw/fVd7+Gbyt9Nd344smmWL+IcSNWJy4bT0wCkyMHNyy+9JuxVWXixEzHWlxtx7MhOE92DP3TBv4QdzlvXjeoR6GPx0l3odL5/5U1pj+qez7cfFcutParXGv/gBe/Zyxb57JfBAHy/mV/9v5p3Pmh9Bqr7s+u1oK/RpT9s3dlvvhg0S4nUZ8pKTB2brLRd9iZR/FgbznJQdrwAeXa4TNA6D6MdQPwmvN8br+7nhd/5vmsMc2x1G1/KTZLf9q4OO4eo5DmAMlByJJFXXShRvUh1xvpEg1RtHJj2WU/FsibpsVVHkSQxQpThq5uTsGLotjNpJvSLlGfd3ilDKmcG44N+O5cylFGddhgcctSOfqbE/p3M6Ibi3ta5YxijF1TOm+bcO+Da997jIigyU57+xBbXb7KB+p1eb0SUu4HYN2hDqQ2HmKbnvM35nGzOdPY8BF4CYDUZy6E2+WIbnbcKfH4KucbsO9A8Vhnmb2TVMx4Z2lNmdPEhQt4CEbbExTaBTt3FsS3CW9B5G00DJ9Kl4w2wrZwJ1DV1+37d6Nz5zF56gUf61WTnkPLp9jiKqPDxFRe5XJQL3p1hN3UCTF9xXcIeXeg3UsL4rT+3D5DG/O9TXhzZG3g/upW2Q1MQ/zs3Mz4DJVZdWUA1tVNQcundeKo2DQ53FyxLENJmqDrMbxyA2DSQqkVX7fhgdLvJEjjipkOI1Wbb0qd7xi4k/AOhRZMZbI2hKqnXP0MGvEg8nRclWRq+r/Akfkx3Y=');
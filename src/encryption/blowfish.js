@@ -83,8 +83,10 @@ export class BlowfishAlgo extends BlockCipher {
       nBlocksReady = Math.ceil(nBlocksReady);
     } else {
       // Round down to include only full blocks,
-      // less the number of blocks that must remain in the buffer
-      nBlocksReady = Math.max((nBlocksReady | 0) - this._minBufferSize, 0);
+      // less the number of blocks that must remain in the buffer.
+      // Ciphertext stealing needs the last two blocks when finalizing.
+      const minBufferSize = this.cfg.mode._ciphertextStealing ? 2 : this._minBufferSize;
+      nBlocksReady = Math.max((nBlocksReady | 0) - minBufferSize, 0);
     }
 
     // Count words ready
@@ -109,17 +111,20 @@ export class BlowfishAlgo extends BlockCipher {
       // Perform concrete-algorithm logic
       if (this._xformMode == this._ENC_XFORM_MODE) {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, blockSize, this.modeProcessBlock, dataArray, this.pbox, s);
+          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, this.pbox, s);
         } else {
-          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, blockSize, ivWords, dataArray, this.pbox, s);
+          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, this.pbox, s);
         }
       } else /* if (this._xformMode == this._DEC_XFORM_MODE) */ {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, blockSize, this.modeProcessBlock, dataArray, this.pbox, s);
+          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, this.pbox, s);
         } else {
-          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, blockSize, ivWords, dataArray, this.pbox, s);
+          this.modeProcessBlock = blowfishWasm(BlowfishAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, this.pbox, s);
         }
       }
+      if (this.modeProcessBlock === undefined) {
+        throw new Error(`${this.cfg.mode._name} is not supported by this cipher, or its IV is shorter than one block`);
+      }
       dataWords = Array.from(dataArray);
       // Remove processed words
       processedWords = dataWords.splice(0, nWordsReady);
@@ -130,6 +135,14 @@ export class BlowfishAlgo extends BlockCipher {
     // Return processed words
     return new WordArray(processedWords, nBytesReady);
   }
+
+  _doEncryptCts(iv, dataArray, dataSigBytes) {
+    return blowfishWasm(BlowfishAlgo.wasm).doEncryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, this.pbox, [].concat(...this.sbox));
+  }
+
+  _doDecryptCts(iv, dataArray, dataSigBytes) {
+    return blowfishWasm(BlowfishAlgo.wasm).doDecryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, this.pbox, [].concat(...this.sbox));
+  }
 }
 
 /**
@@ -113,14 +113,13 @@ export function blowfishWasm(wasm) {
   /**
   * @param {string} mode
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} P
   * @param {Uint32Array} S
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function doEncrypt(mode, nWordsReady, blockSize, iv, dataWords, P, S) {
+  function doEncrypt(mode, nWordsReady, iv, dataWords, P, S) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -133,11 +132,14 @@ export function blowfishWasm(wasm) {
       var len3 = WASM_VECTOR_LEN;
       var ptr4 = passArray32ToWasm0(S, wasm.__wbindgen_malloc);
       var len4 = WASM_VECTOR_LEN;
-      wasm.doEncrypt(retptr, ptr0, len0, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4);
+      wasm.doEncrypt(retptr, ptr0, len0, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
-      var v5 = getArrayU32FromWasm0(r0, r1).slice();
-      wasm.__wbindgen_free(r0, r1 * 4);
+      let v5;
+      if (r0 !== 0) {
+        v5 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
       return v5;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
@@ -149,14 +151,13 @@ export function blowfishWasm(wasm) {
   /**
   * @param {string} mode
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} P
   * @param {Uint32Array} S
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function doDecrypt(mode, nWordsReady, blockSize, iv, dataWords, P, S) {
+  function doDecrypt(mode, nWordsReady, iv, dataWords, P, S) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -169,11 +170,14 @@ export function blowfishWasm(wasm) {
       var len3 = WASM_VECTOR_LEN;
       var ptr4 = passArray32ToWasm0(S, wasm.__wbindgen_malloc);
       var len4 = WASM_VECTOR_LEN;
-      wasm.doDecrypt(retptr, ptr0, len0, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4);
+      wasm.doDecrypt(retptr, ptr0, len0, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
-      var v5 = getArrayU32FromWasm0(r0, r1).slice();
-      wasm.__wbindgen_free(r0, r1 * 4);
+      let v5;
+      if (r0 !== 0) {
+        v5 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
       return v5;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
@@ -182,10 +186,101 @@ export function blowfishWasm(wasm) {
     }
   }
 
+  /**
+  * @param {string} mode
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} P
+  * @param {Uint32Array} S
+  * @returns {boolean}
+  */
+  function doEncryptCts(mode, iv, dataWords, dataSigBytes, P, S) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(P, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(S, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ret = wasm.doEncryptCts(ptr0, len0, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3, ptr4, len4);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+  * @param {string} mode
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} P
+  * @param {Uint32Array} S
+  * @returns {boolean}
+  */
+  function doDecryptCts(mode, iv, dataWords, dataSigBytes, P, S) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(P, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(S, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ret = wasm.doDecryptCts(ptr0, len0, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3, ptr4, len4);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1);
+    getUint8Memory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+  }
+  /**
+  * @param {Uint8Array} password
+  * @param {Uint8Array} salt
+  * @param {number} cost
+  * @returns {Uint32Array}
+  */
+  function bcrypt(password, salt, cost) {
+    try {
+      const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+      var ptr0 = passArray8ToWasm0(password, wasm.__wbindgen_malloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray8ToWasm0(salt, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      wasm.bcrypt(retptr, ptr0, len0, ptr1, len1, cost);
+      var r0 = getInt32Memory0()[retptr / 4 + 0];
+      var r1 = getInt32Memory0()[retptr / 4 + 1];
+      var v2 = getArrayU32FromWasm0(r0, r1).slice();
+      wasm.__wbindgen_free(r0, r1 * 4);
+      return v2;
+    } finally {
+      wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+  }
+
   return {
     blowfishInit: blowfishInit,
     doEncrypt: doEncrypt,
-    doDecrypt: doDecrypt
+    doDecrypt: doDecrypt,
+    doEncryptCts: doEncryptCts,
+    doDecryptCts: doDecryptCts,
+    bcrypt: bcrypt
   };
 }
 
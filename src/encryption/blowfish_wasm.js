@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJzMvQlcVde1P36mO8C9Fy5qEhWjhxvTmGYQJ1CTNjk2aoxJzHtt2iZNG42axEuqUYlJX9NwUVRURFRUFAecEVFxBBEFFRVFBcUBZ1RwHnBGRfmttfY+lwOiMX19/8+/qdyzh7OHtdde+7vW3nsdodfgv4uCIIjfOHtKERFiBP4Veor4JEdE6D8YI/RUIujREWH8H4+GjPDSTz1NPFKA/0McC2KxP+FflvUnuU9vi0UymRVf2eSwSjK0QJQkSRD9fAXRZBf9LRZRNMuiIgqi2awogmKCP7JgVUTRJME/E6RJkihYRXhJEuBRsgnwI2Ek/Pr4+JhExSR+Kz7zDOQVAsw+0DrN48kRbJZY0Wr+e9+/Dxj0D0mwf/HNgO+/7Df46279+4ULL/v0GdC5f+9B//g2XGgKz+/0Zc/Nmn3++fdf9Ovf56u+/T/v1afP5+EDPh8c3qt32OffDujXP7zvIOEPAYYcf+/1zTcDegsd/Q1xXw7q21foWs8QM6gvy/aWT2NR0ERbgy96tWnQ+rdNWoS+95vP//b7/37/Nb9ePX0vbZF6D20mWYFqLwhaiTXM5WguqA5NCJHel942/qf6dnZAiurTQoJEqyYGOVVfTeyiRyohkl2TKFLyRlpDJKcmU6TsjbSHSA00hSIVb6QzRGqomSjS5I1sECI10cwUafZGNgyRVM1CkRZvZJMQqblmpUirN1INkVpoPhTp441sHiK9qvlSpK83skWIFKzZKNLmjXw1RGqr2SnS7o0MDpHaaw6KdHgj24ZIb2p+FOnnjWwfIr2t+VOkvzfyzRDpHc1JkU5v5Nsh0rtaAEUGeCPfgRFQHZ2EUOkPmhDkA2OC7AXEd9UPkT6CYIng1jxWZ3PVHuQPOXzlt1X/zsrbEPS1m2wsg+rjdvmpPqrN7QqgcRNUO47aoC7VGf00xa0GwB9DBqlmBitmsBozyDUz2DGDXc/gr/3oDvJXfTSnGxoOo+x2+WoeqatDwGYVQrsFp6o6WkgfuQLw588uH8033F3dpxcaIw00ERofIv3Z2FebFmXFQu1QHv3pHujy1zw/uYMasGxBTogkYviqQHZFdUJBP+otqwddVxt0cciqE3vtw6N9NCtrqeQO8oUkCDkhr+R21ePttos2FccnYViO4Hwd+uGHDf8DEoF6pPrxHgWxHgX52dQAIMyj3fLTuwVjVq9W15BemkeBNkDXFPrD+vejoX9616SaXWtAncMGwfhCN+yy7Zd28hd3yunUO+Rfd4caUofMrENm+gMdsmme/3EH+dXokO2RDvn9BzrkX6tD/rU65I8d8q/VIX+oDtlOgMpw/gmqoMKcE0AGyiQDaWaJquQ0QRSU87UmQ20kJiO8AjOi1m+d/+HbPV2iNm8osFVbaKao5dGjQ+RT2qJagToOnILUBedzqqL6woRS5W7Il4E2CJu7O3xVRauC/8kDVaWrQ4SQFO4ya8K7DtHYBNUMlZg1xdnN5esQ7S/aQBDDBFVN7AURedSsSUOAkkpnaoSfJqhKWJCkWoJs0B5F9QNZovp1cziBdNbuDn8YKCv71z0wLMgEBdaDAi3AFxKQlUq0UA5jJ0ik2aiHJhWEswk66dsCZyj8GYyji2IDhrUe/tRTJRjtCLfLh0bVDPIsCEYORBsRAaoU7c1sUATvhsnYDYdoIw5zQp1ObIom1GwKUACG1Wm1CzYoy9tjG/TYv0aPHazHdk5AsbPDVbPzduAKvePQhrr7XQ/7DuXa1XooiHxUf7ervlofOw/M7Nb774MdBxoBBaDj9ajjvk4rdN4fhCtQxtt9exOcuT5hKKNg0oTZoIvxHianJGSp/GruKqdHGFl4jhpGnBbIGMhUg4EkAwNJqtkhI5XsCmc2y+OZTTV3dtTnBAro7GgKJDMOCjCVCv33ZyS2Q4rYBUclqB5R2h+XK/9ujmeRpt0djaop3YxI66+TFvjR6VCh8GriqrjC4R/F+TxOWvaqD77qD+NA/3CQ6gOZA6gMHGwfnGfS21C7j1q/C7QfWM7taqA2wMJALLlr8aPdyIg2GI8AGpN6xKQgVvyCcELAuIj2Z20ogGBcnrPVRWKBTzkkG8oWERZvojIMo8hmaGeHqSZXQ7cV7BCSDWgLdPGrpiayuWQkiIR9kLwEYYWaOxMjKM5PSD4aCAN8De9jLeZahLEjYSwodvxUPyzUbGBUThhbjRkKRIE33EEWNqj+jCIBOkUCbM7/wmx6NwNrdhPym9wIIuzITpbqORkAsJMaJelSTMTeQKGMOLBSDISm+sBkFm32521IraaMWs8/kTZ21Q4RJPgNnCPWnN6wPHEC1eYcp5dA9UiC1cU5AbU5x0wEwlKAc3yYnOGsY7PxKW2z2RsT92jRUTBbgwWbd+wwsmIYj8RAsR4AKaXl6QE/QGYUaMWzZfAUqNimy3aahn4y8B8npYiUe0b1czSAgbWyf0iCAOq4YqCkpiDhrPDH+bzrmd/is/pMJzX6t7WoSojfQhIf5h9KPi7xnTqdYO4hnUTViQt5LYnPeQmArK1a6NtgZQa9wwo//lgCrtgk92kUO4PAFoETHDZkzhpi8aknDXSVT4nacwW6wmhBAIbmyKNdMrMumVmXakyRR6aHYDN0R/J2R2TdsfuwqVOjF951W4IWmnDI7I8I1wDvwq0LV4kJVxO8rEtUiYY8AJa2Gr2s90tGmwbYxge4HkLvJw+wvY4l3Ss9veOLygMOiUvurMrQ1s6DUH6hHuS0GuglYvmSPvaolEBB2jmd72tTDinAUnGK1CyFqA7qKCorLaQ+hPgApWF1CCZdQmfI4ssbgmCSUkB3djcXAE8ysIryzK4l69qCmc1ZTYT/Vnl5EP6bxwPpTZ9GB69bD/erSw/3q0sP96tLD/erSw/3q0sP96tLD/erSw/3q0sP96tLD/erSw/3q0sP96tLD/erSw/3q0sP96tLD/erSw/3e0o9vN4jengAB+0BT9DD/Z+oh/v/nB7u/3N6uH+1Hh6AS2jAU+nhDX5GD/d/Cj28PunhAVyt86vWw+ujMPAz6nZO1MNpEfB7vEaH/YXBcKJG5zTo4Tg+UUP5zHqshtfAq+E1MGp43m75G9RW51Pp4fVJD/f2T++aVLNrAdS5mmrrL+zkL+6UV211BdTdobr1cH/Sw201OuT/SIds/4EOBdTqUECtDgVghwJqdSigDj283uP0cMN/EdW6tVczf6IW/rO6OC2yQQKf3b6qtQdAC6QGPgEUMOIHCy55FjaNGbYU9JXeoLY34CqBoy5Nys7UdmyTHWc3g6OQaufLiEKrslGvsqOy64sIoFE1AtB19hprughrtA8haoZcAON2AUXLh4azeq3m6icaXAiM+eFa7ce5E3odyPjNB5+f13GEy2ykhJkoASVhilgNGaBKHSBWg5LnGChpxvtmVqlbNtVOsEBHSDj9ffSOm2t2vGF1xwHYBAXU7riE8wI7joQl1EeoHfAxtE4Vmf7t50XtyOLAxyYgAfTcST0HvYL1vomh9029vX8URz5t7xti7z8A4K8ieZ1h9kY2fdhBxSO1g/UWGotKZh2I0ceLXXwRAdV/GtW/wRNU/3pM9a9XS/X3qYth/TjDqn5Mhw0gMa3bAJrbqo0A/tALyKf4SS8GIQzD0TPBoCCexsFBdn2CUo9NUf2gO3665viiQT1BZgEdEE16XIn15bYWCW1MzdEI5B3rOlRYha11DJFiLWaEdqR0wKzpBn0XkUetMKw2HNYgsxTBu4xmGeiaplT3XILG4bhhAloJbDBw/jr0JXwu6rqMvd6TLARO3ULgNFgInHVZCOzQbT+cCy8wnbcZiuEhoLuQ/DKxCUI0NlqlmErvZ6QxVeYQ9Fr4E2qd1cT2Z8T2U7HWgNrEfoGpw5zYMCZPJLYTiI0kQpUEZaU/Esi/hr5Xzel+1aoecno9ZkuopxOkqYEg9Z0vuALU+mRLcKr1Ojt8DZqfwZZQz2BLgPx12BJUsiW4dMvLk2wJTpKi3JbASjbhtKshjUDAQSFYk72GLcGMxOO2BNDQGPHsTzS22J3IkfVq2hKqjS5+utHFj1lE0Jagaz5Ogy3h5lDdwAC6UYkeQHsDBXTzQaGewtY/rzqDy6JXndFXKnojeWhtg4OJqaYSGRwYvc1I3mdVyVGvpsFB/EUGByPpSbVhBgcRierVzv0e0c79mKQ30JTR80kGhwAvF9bjBgdgL5kMDvKjBgeDIit2Rp3+cYpsAFNkTQb904frn8wE4KdlDPVqtX61jb1WP8BHIKsRNwDnkqGDVmzD0OhJYpAPrqbVQ4jYhifWhWkMAoL4lezlpEln6DzxSxXkeA7jayjGNGeQeVBfqqG0c/hjVJzPAvxjirMzzGVrTrswUP0tsQfISsH5GUogzersrDXAv27NST9hWkXVP93a9wO1f8GoQHEUDaMShvlV0GZU4QNY/XDUMCVI0ZKKN+Jg0aYI8rDMsCYGAOUCgzTWmjHpbekOAXhEsWsd8pVLCBsCogTKEsPDtN+6bS4TVKSlHt4o4LR2CQoaZ0zO34XTBBZJIAnOjwFOKFAsMIMSBs9yD3iGZFVyyQ6EojLymaA1oEIkst0i8Hx+iKYMhH6ysrsGYkIgTC8LVA8cLZC1Bde2IMEuwex2SMgkQH/VFO58V+/mQBhF4GbB+cHXNRsLscgnCGOwjQAAesAjtNAMs2SgC6oSoUYZ68TXO7moQrEzVpN6hMp2Acl6AN1k5PqwHoGISDDO+SkIBsGJ+EBmA9IDZwgk9IDJKTvfgWiPhHEYaoStlzV5CEB2OVybB+2mBVmbznoAwwrlhrvEgdjZFhJKcUrD8R1E099GbTXhxBPxxw4Fm9mPGEIvqNhKxe18G56Al0DtEKFazckYgzHNP92q0D2QcYlRA/E2BEESNFMYAk2SB+KkUHRSfOCwgMgTdaojeS2PEl10fkwcB8KlE7KLTPzB2QKGXe7mYuTGruEA6HS3eQlrRsLqZO0GOIOR1Yxk9Y4LjJICs6UmSS3aKpYM+OER0sKwolxAkQejJmkRg4nT3S4YmHCXoKUW8y6YmdnChVOKygXWVBAqCEhvsncaBkj7SZW/H0gi0IxjHO5ELPYZsLwF6oHKBa0ZcCjwK5YQPhAIhrStUaPMaxRr1ijqNYqGGvWOQb0Sr1cm+uDfT4G1sXCit4FaOG8FGtg6iWXgRJxcAjQOOdFi5ERoBXGixRaEuoKJLQGcEy3sB418NiqW4A5Vz8zDMlHEXIurLcjVllpcbanm6kfKkhFEPcrqDtFWl5ZsnMTdQMSmVQe7OxQUS4iUSPIKYUAfEro1ZC+Mi+bxKM7OzvaqDV+1dkYLPdAFoFAWtgieFZcvPUM6gEhsaw6E6A+rTUTidQ/ElIwjXsmcC49BAs2Pj1QrrvmQYqW5ACzqHRyJJA+MC5sO0A1MQYOroPcviD1RHDIZcKBE9IZJJdGU4uSjxhFJKStEgKCSiHvYC742rVhvdmeANUK3QQ5kZCjzTyjSnX9W7Yj1BdISYabw/rikD5QI4H+IBZAhdQf4bgPhS4VVlwgFWlH+YN1WxDM6EbiWKxLE6MqgBlICBViNugFu2vQ6YWHX35YZOFF9PlAYOX26gwyvpicMNS3RkOSysBF2+Ydp/WC4cOwxrAIHAMRHmESEVn3c3QJdDqCKH9qriIbIKCYSnjIrBPQ2ygz6OjYK+4erOL0vwpjBfETjPDEesLSvGwWgana7oaowl4msi6pJw4nF2FDmpTbgpbLSRczl/BSnDcqYMLcb8+9D2no8HtoIwCb2IHq8DJQJIMuWtpMFQ+mASgCioUJsiB2blIdPqH3TS6oVnzA/aSxITw35BgUSzEc8IgHSGdaR7mRaUB1dQPb7oME1zCUQYT91apCXLXkYbmRHpFhL5EiPETRSXYJGQkEjoa2AmwzsaFtgPyhoiC2RD3AxsTJ+hjCarrDFOBJmRkaUPmxUgnCMgIhhqLBgBy2ofZHwhGrDcPwB3rjDghTVO1e78imHDCagdRgrgcVRE7s6TBz+mHnPYeEi8cTs2rSCcMnO5Dk1X3qcPDcDubhIB13EjY2ApQQ4z25iXI+ykXGTLstACrpoLlsQsyEfAaewxYdxHRMeuuipnq+0WOhSgL9plARmNnmJsATeDLTVK4f5m+Gd24JhJrkExEesAbiRhlGy3gbSFxAWGfieDxTney7rgigX8L3AOL8OvtcFJSMMCcHURwkDQo4RRUZdAagKvKrwEYOws1F1svIf4FgL41haxyxsyC3EsbwaAGVHsaVVzUJ+dkqmIYdgU4h6GEjlgVRjSg4GVvHAKmO2PAxk8UCWMVshBnJ5INeYrRgDO3lgpzFbCQb28cA+Y7ZzGDjKA0eN2coxUMoDpcZsl4wpFRi4xAM3jQHPoY0sBgOVxYZANKZU8kCUMRCHAYzBQIwxkICBGB6INwaSMBDPA9ONgWQMTOeBecZAGgbm8UAGBlJ5INWYkoOBVTywypgtDwNZPJBlzFaIgVweyDVmK8bATh7YacxWgoF9PLDPmO0cBo7ywFFjtnIMlPJAqTFbBQYu8cAlYzbPYRwFHrhpzBaNKZU8UHnIkC0OU6IO88EyZkvAQAxPiTFmS8JAPA/EG7MlY2A6D0w3ZptnTHnCompcso2Ci9ZlJrtklF0ke2DJFtxMiP2HRBeCEtkIST9wCP8rkQ24FDTp5tBq2/jfiVIEN+Pw3SgtIYp2gpklCPdS2YIGcgy3slxip6njzm9Jurxx0xV4oQLW+07HDufMmHTzdMGiiFCpHCOWji68W7Bz/6bOodI5DM/eMXblxTUPj34VKpVg+GLxkfgTc26P+HWoVIzh1SfObymfe2/sQSixECNu5w09MtQz40YklJiHEdlL16ftOnptVxZE5GDEXk/u4T1zdiyaBhEZGLHjyPRpixeljL4PZaRhxLkVSbHj8/au3Q0RyVToiISpCbdu7DgFEUkYMSf91rC9GVeuS6FSAoZHjRy+9c7V+CWfhEpxGJ4xJ3bx/P3nrx6CF6IxIvFy3NxTc69EfRsqeTC8LjJp4vVJGUex3RUAajstmR4fWXLxUoYKhMBwUXrUihnbZ41bCq08hxExx9ccj101Zxa2sgQjbg3fk1m5dtfufwIpMFyWs2r1lcKHh+8gKTAi/szymXdjh48NAkpg+EzuwxV7So7t8CAlMCLyTtyZKYkXDvw3EALDJbNmz78/uWLUv4AOGD5wduWW8UUVuR8CGTA8LmHYktgZx9JXQwFJGPHwyL6imTdHRo6BiASMSMjNunv9yvCRGyAiDiPGn86OPbY6bd9KiIjGiKT1qx/kni7fdg5a6cGICeVzcvOGVY6IgxwVoJN1ur/lSFXepdGjBgElMLxszbVrFZPLMmOREhhROHdd1Nis7aeXQUQJRlybvmZ/7Kpb2y9CmcUYsWvV1aWjVs7LQ+IVYsTD9feG7bq9reIVIAWGD08adnemp2hdCFACwxsXXJoy4tjy69DTDEovuxO7YeuJsnlQQBpGZE1cGz/2etmDfGQJjLibV7D3UOr4yjVIC4y4dOH6wVUXb557DUiB4YT750ZG3imJA2LGYfjY1ofjYldFVWQgJTAireDSjhFTJ5etgwgPRmwtm346c+n+hTFICQXZbs/olPUV2dktgBIYXpo6Y9/ydYfHfA6EwPC6Zdeuj4o/nf4e0AHDebe3TJ1fnu75LZABw/cu3h4/7NLJ8dCEQkpfn3Nvxf59Q5fg3MCIE0v2nS46dTgNMuRguKJoyq3oE9FT/wJkwPDD/MqsrXfPjQMWS8Pw1sio1TMKpk/OQSpgxPrIs4vnb4xJawxEwPDaDUvi1t9/eH4tMgRGHCxcOqIyckbpFngjDiNGFmalrbk9YUojoAKGo5LG3TlyYu/UvwERMJw7enjp9NnLluHgVsgQMWvOtatb589PSEQRgREXy85MvTZ23aI3gAoYXnbi8rYDt+PXQK9LMLxp/fXrq2NGrYYaijG8Ztb8sTsTiguw0YUYsX7jynWLDkaWKEAFDE9Lujtrw8iiLBzKHIwovrFn6fXj6TeHABkwvL1sV2ba1a07ceKkYUTR/uzi9RPLK7YjHTBi3tLNG4Ztjxr+EdABw9c33D+xZ1vOrk2QIQEjTu3bfbu05NgsYLg46tWo857SfYdLDqCAoDovrh+2ITavZDhyA0Zszp9w6ezBg1ugmxUA5ztNjck8m3f7bDpSshwjNqxYtGbEns2zh+K8wIgZO7aMu7A052AHIASGC0uu7hlTtOPBQ5wWGHFpctadrZWx6+sDITCcvHtD2tqbO5MXIz9gxN6NW/eOuDwm5c9ACAyfzFiRMWLmqfOYIQMjNhVOjZ0z8QhSJg3DO0rm7j5z8cy4mZAhGSOGjlpSvskTvQknThJG3Dix+n7ctrh7w5AjMCLywbzpexMW5/8eKEFvbE04Ofba1hVhQAgMH8scXzlzzbFN/YAOGC4pvr553oik28dQUooQsXNiSezkFRfigCnLMZxYWDU/a0V0/BnIcA4jEsavP3pj9ZX5KA5KMCJy4fY9d3af3AHzqBjDK28efrjj4soJl5AjMGJyZfn1S6dLNyOP5WHEsdELj53aEBNzE3LkYMT45IxZi4dfTT8NERkYsWzs3tGZKzLXVuKigRGrMpYvuDj1XhIowckYvnA9eWnZ5Nz9sFIlYfhU2pzM4dHDb2IJCRhxI3/fit3XL6SkoKzEiMMnsraMjJmSth95AiM2Xt2f8aB0b8J65AmMGDOjovTMtjnj0iDiBxQha8/eOBc5YkYJvPEt9vz68L2VM3ZfRhnzNYTnlmxdGrNhdhp0vCfOzpTNJ9cOTbgLTfoz8kve3sMH10xe0ilU+gg5cNqymLPH9o5GfnoXpf35/Zc8+Uu2XYfC38bCHpyec2FZwfbwUKk9Zt84L3HyrqLF1yA5GPtXNjKnZGj2NmTgFhDetmLE/Oszj+Sh5FchvCXpelHijVmTB4ZKDSF4+cSIbRf3zpp4AV53QvhI2a2q67fTx8IMskJwwZTcUbtWX76aEBGqa91W3B8VmR0Pd466kDVcZDDDSptRLehYN8MaizfEpj/ccHHjXzjUOLu5YtHsReOKx+lQY+a1ueeHlVatGcihRtm5uKUxibGzsEOENe6uqsocca5wZW+ONYrSC44cm3oqxcmhxvDd+6/MOrRwnB9HGktvjr++PW7XnN9xoFF5cELhliPXh3t0oJFSsCA/pThyRGeOM/anZW87fqbs0kkdZ0RFzky7MDG+shGHGQvLpkSV7tq57kMOMw5dqTgzMmbGne4cZlw7VLVi/f3Y8Zd0mDHhZH7ixLwT28dGcJyx4Vz65fExR2e9wGFGyrTRm+avf5Bi5zDj5qnRcyNzhm18gaMMz8MrOwon7o3BJYJQxpj4CVn7ClO3o6AimLF5+76KhROOlZ3XYUbSyDml2fsun9Y4zLi+efT9S3nbtx4ROMy4ELtkTuyO4pzfcJgx88bdnAcZRZtGRHCcMeN2VsqBpLwzGwUONDadG5N59PbS1QM4zpg0c/mRsfeKyv6Hw4w1W9Ytm/cgZf9cHWbEZ26Mi6qMmqVylHFoUfKF1ZVLVnzNQcbJw3OvZceO29CfY4yrZcu2zX+Qfbotxxil48seRs+POX5Z4Bjj4qSdl3eOjplSJHCMUTVy3NHze8efbMwhxvxRB7JX75w+PpAjjNnZ99dMLVqaWiFwiLEo7sC1knsjb3TlEOPEgjXjxhdGrXqWQ4xp449vrFqaf22KDjEerji+bNTqEzNP6RBj6uTCw1Mnnls2UocY+4vux9yYeSXubQ4xVqyaPWzV5CWT3uQQY23R2MjRF5YuKRM4xFi2PnXegqmVxxtxhLF47ZKy82NP3l2hI4yssStuP4wfsxwFCEGMMVn3UndtSt/fnUOMuLW7FiduS87YKXCMMbOyaGr53eJIlFoEMtIKV8w8ueJqWhgHGbdib52JnbOlAJmaQEbypRGLIpcWD3uZg4wl99cf3FQ+Jh35g1DGuB2ZtzcAq/+Fo4zhm0tSFnjO7D+no4yr6/KnHt03eQ2iSoIZe65WjhlTlr1V5Chj0phrMzbEXV+/IIKjjCuV6ceuXNp20s1RRvTu2PFlB3JHfc9RxridpdsjU2eebMRBxsIZx6acOLsjNoRjjKKK8mU7NowZ3pRjjMwZ+9JiCjKvxUdwkFExYeX1OZdTo6dHcJRxYOK9yOKoFSv36ihj7dop+ddnb7sxNYLDjOm7qzIWntg4E7UKghmjs9amZRwYPeuwwHFG+eix6Qm3j9x0c5iR+vDCrfNjC0Y35Sjj8q7s+GVVDyuQZQhmrEvLLZk4e9GEQzrMuLZk/+y0y9f2/InDjANXl4/NWLk1+oQOM2Zeq4o9uKMowclRxlBPYdT+xLUz6nOUsXna8EnjR19b8WsOMtJvx1y4vGnpyeYcY2xLK6+InX17dGuOMc6MmJ+w4F7ainc4xMgsvnh6afa2TX/hECNr9cPS/OUn5vfjCCMh9uTeO5vWRNHCSgWsu+i5lbZy0ywdYswq3Z68Zd7R1a9ziDHh6NLpF8sKzn7GEcasK+dvjh26afsnHGBcLzmz8tbqUwezdIARN7+kbMK4E/nNOcAYNi363u0pkcOHcICRMXnv9ajZV9YUCBxhzL4zNH7imdRZDTnAmJp4evzDLcNuvcEBxobYuWdm5m3LRExDAGPrpvE7Lpz0nPPn+GLVpf1bKlfduYsaJQGMyPwpBzcfWXMylOOLG7HbZ08t2XPPxeHF3YW5MZcf5u+r1OFF8c2xqXvT86I+4+hi2oWV5SmTjx1+k4OLqA3lnqqqVI/EwUX6qouRRyKHJX/LwcWlUbduRB/MGnNQBxfTo2eWb85ffLVK4OBi+IJrWffPRCbjvCVwEXWxcvOZwoxCZFgCF/FpR0snxk9fjbAJwUX2gdn5cXfXHfstwxZbi8ZMPzNm5TiJQYsRG2+eKS3NzIiKYNjizLVV5+MzDk74lmGLkRk7Yg/PjLk5gGGLA9uzj565V3B/KscW05bvvVeUunD2EY4tchaUDr2wLTamD8MW84cXbx8/vCjqFscWl+aNXznp6NniTgxaXEtIKXtYNXwXLkEILTI2rj17fuel27i0I7ZI25V58fDVO0XbObZYOmHfkfW522PeYdji/MnzR3KTdxxa/W9ji9hb6zJGTPek5eh2jFWl57KWbCpZPkEHF7urUpbfGLHi9F85uDg77uDDrcVF5Qd0cHFoUXz8jrO5ybh0E7o4fnzz9cgTd9OWR3B4UXT31sZdFZsP7BI4vqhI2XPh+JpFD60cX6xKH35g46ylBb/n8OJEKYiP9LMLrut2jPXxefmbo2Ztr9Txxai8KRUjlns25Ot2jNn3r524snRbqg4wDhxddvzikSXHURknhBG/bszMLVuHn+zEAcbB9dPGLd+ZOf0Fji9SlkcOS0qddSWY44vkm8c8446evPc2xxcjEiITjx6I3IerAQGMhQmnDk7NOznrjm7GyL042nMvatUNB8cXkVlbPPOrkoYtjOD4InHGmHEpF06cwflKAGPY6hNll09snH5QBxjjShNjpi3JWtWCA4wZ90r3X0s8v9fG8cX8nDP7ojZlpz/P4cXQDec27d6zrMKHw4vZE1ctX7N1/NR3dHixsnJdYuSdfT05usjLnT325MqUTGRHghcnDy3NG5MZuZFUdzJzLN5/Jm97emQ3ji9iV65dGXVl0mGV44u705PnJmeVLRuv2zBO7kw9uK38TuwY3Yaxv/Tg3MT0NQ+36jaMzTcSE7fsPn3oXQ4wMo4XzV2WcTUX0QABjHHnT1+ft6rqyiAOMKYOn5oyaeeY1OwIjjA2LItcvy138c31OsIYOSwy6n7+FM+bHGCsPDBqWsKVlHHfcnwRdXRy5rap2Yl/4vhi64jb48beHjl9CMcXFXdODjswqjiyRMcXBz3XL5+PK7//LccX0UVVty5WpV0vFTi+2L17/fzbY1I2f8rhxZXilXlLttzb/zGHF4dSo+eUlkeN8uXoovj+5PMT75aXf8HBRUJSatHs7HVlARxcnLkzYrXnQjGZYQhcpEdtL7ydFb89PYKji51T90/cODdrSoWOLhYeGb1pzdnRq7twdDFlS2Jk8fyYKeEcXKw8Vrp24rID0e9zbJF9Y8/R9GO3ZtwWOLiIPrGzOH/exSV7dBPGmJnbojL3HRz9CQcX97OvrNy68sTlDzm4mDCrdNG62fdHongidLEtcd6o7JvZnvc5uliXPGXdJk/muc84uohLubyyckT+SkSfhC52brqad+jktPS/cHBRkpGfMzr7zq3ZERxcLD62vujGguzKcI4tNm6dsTfnSvaUqboJY+G9O9MvzZ07azCHFle3Lpgzf9beDSN1E8apuJtzR2xYf+Yrji22lG/3HJtVdmSSji1ipubdGFu2fs9rHFrE5u5YkrBy2LEvObQ4NTnu0Nx5S0/058iiZHHsoclH581qw5HFnllT7x+eU3LuPY4sNp3fUV5StLNc4shi7p7TVeuHHVnejCOLVeWXFw+NWTgJmYugxbhhNyYVpO6K3qibLy7OfTBh4rTyXR9zbDHvxoyhNzcl5qJlgMDFlYlns8tPjhy6UQcX2QuituYeOTmyK8cWeSlRSxfNSzzdh2OLoymXhmdMHxNv0q0XhaevjruSP1Hh2GJ65Z47W5btjI/TscWB+EXLp2VvT3iLY4vTe+eVZZ25XY50J3CRVTB7yfD8M6NxpAhcpBctvZG9aH7+RxxcZN7cmXIr7fRGlYOL1CWRKTfnzZ+XqYOL3RcWF1ddT636MwcXdwpGblozKzrrPY4tbt5cVzT3TOGSv3JssXzn2NVzbpddc3NscefWwisnR29dk69ji8XLzl7bsGfiqDc4tliddW/khPSTdz/h2GLqtlM3UvN2rCajHkZUZmUc2PHgYOLvObgYkbMy9eKB1ffu6eBizoOqu7nLL27+mmOLeZHrcs6sP134EocWp5LLt45ImjbvTxxZHB67Yk9aduKRfgxYzC/fuGnThtFDi7jV4sy4SXuWFA0tW8ytFisLUtOnJu9+iHYQhBaFR5Y/2JZ0sqwVgxaXJpY/OFJedlxm0KJoXdHIg+fGFKL5B6HFjIqDl26uLj08OYJBi5szh8+OjD61BcUiYoull85eO5k8fgKCaMQW6YtOzLi5OsFTKHBwkZeUtGTs+rg2DFssPbDn7Jixnj1/YNDizsZ90Tdnro68zaHF9J1pKwoW7FiFigFii3Orbm1/OLMwJ75ubCGwizA/Ay9OZ2dnlR09erIjRxcrNq3NXJdfgWYAAhdLdsyruFG0NqoTBxfny4tGjNqXvhTVHwIXpfvHxsZlV9z6I8cWR6Pm7ti459QDiUOLtBuL958tuFC5U4cWuyuXLY1eN/fmaH2TZMvmDetHxhRnqhxbTN9Ysufi7pVzv+fQouTe0i3DNlyc+yJHFjkPchNW38tIepYDi8qZy9asG75w3QccWAzfuz16Ya5n6AodWJyqmnzo4vGbx9txYPFg4aJdMSXD9qH2S8hiRNLIZfNuzLz7O44s1o9LXLM0cvj61hxZJCZtOpW7adLpazqymLDl0M2xmXuPRuqmiymXi8u33p1+PjqCQ4tTQzedPXz7XuZh3XSx/ELSjEVJK88c0KHFwTPjFmXnzpi6QN8i2TI/e15CUd6DIRxanDl1Km1P0sjJ+hZJwY1RNyp3zZr1AYcW+1atTJk5Yc3lphxabDu+JO343Mpr/hxajM0+cPdyWf7ORN1ykXTq7pKYIzHrfsehxcZzi4o3jt+1J1/fH1l8zjPj8ubKjFH6/siKlIcVd4+Unu/JscWGjadKc1Mzs1pzaBF7dPnIDWfX7HyWI4uypO0jZkefj+zOgcXx2Kg1+RsPD+3NgcWhvNmTpk9LOVaP44qTCyYfzJ6x8MiHHFdcq9hWWThv+I5EHVek5h6tXDw8+8YzHFZUrdw3uWDlnksvcViRvvJu1rKzV+5tFjiuqDxVerXi/I7inhxXpIw4O7V85668LhxX5K+/OGLstjXDP+awYnvspn2zV15d+AcOKzbPjaqoLE65OVs3WzyMu+G5suHmadT5CFekT8y/O+HQlXFJERxYnLyRFJW5aObZPbrdYvH184eOpG+f05Yji+Ub90dvvV+w/SuOLPIzz0V7qkatfYkDi6F3Ku8uKE+Ja8dxxarsU6s9o5LTP+SwIjk6Z+zxzbE7cb0kXHEhZvuCzeUXR4scV0RPHjY091DGYVT3CVhcS83bfGLxiUXL9L2RsYkZSXcu5C75iuOKxCujbo++krBwCMcVp/Ouxl9eeXDKPoEDi6XzEy5WnEucma3vjSTPWDwlfcrsFf/DgUVu/L5rRZkrDyNdCFk83Dfq8rFzVakdObCoGpp3JutOxmHaKcOIY1fvHxuePDc1kAOLgvUrN5dNWhHXhAOL0THnpx9JLJ9TqhstHhyJ33kmNW+YDwcWcXEZk3bkXlrzV44rzi+4uzWq8O6oUn1r5OHWI1suJBfE/47jitLpI0fN33p14R3dZrFp9oWiK3dnzvqnDizSbmVNXLEhbmIERxZ7liZdLR2fntCeI4uzc5Yfv5dfeuB9jizGX9myaWj2muGdOLIYer54WtqUccdG6xsjySsKFh3cABpZBIcW93ddS511OuvKrziySDzy4NCeDXnLm3JkcWn19LWLyiJnLNI3RiZlLZ2VenNS3pscWMzfNHxG4ZSsFSYOLPYdmZQ8Oz3xHooaQha504uzCvYcPN+bI4vxwyo3HRxdnPl3jiwW7dy/ddyW/FF3BY4sxo4q2Lr77v4ZLTiyeHBo0/pVRQ+3/50Di2vFCyKvZJ2P6clxxfVzhw9sWbQ+97C+LVKQX1m5fOLSu2i5JWRx4EFW7Nj5+Rsq9X2RzOIDaw4VnN8gcmRx6OqmsxMunJokcWRx+N7mnQ+vr56GmzkELZbsWli8dsXaWW9waDFp74SRCTmzixFEErQ4f3x8xcT8oQ/u6LsiRSey16beX7/oe44tRixecCz+WHHKAA4t7m6ZtnHC0dgZqNwRtihMia86d2/yCoQ/hC0yN0fOLN5++GaiviuSMrIieUf8qrvTdcNFxvCFUZeOj5mwWTdcnJq6YdaC3HWbp/FdkfLFF8elgEy6wPHFuV37SneUZCzbwPHFkTtntg/dMf7YIm66mBG/c/HljInncDVEgHHlWnbC/oI7W1EVQ4Sx9/LY1TGHyueVcYSx7MCczEkFiTuyOMLYcDpzYv6BxESsDxHGxTVpWUeiSwuDGMB4mLFq+fpxyz1dGb6YMWNr8rFTR0dm832RnN3R0y/fWXB8KjdezCzYWDb/3K49BRxhpF8ZdTxzbv4pHGhEGOnTz+65sXb9g9/XABh4krn0OckSIeHdj3IhzGVqLqgmPJlhV03eO/q+dEQMD27i8QxTC8kepOCP1WXGH6fLghCE3ZM24R0Jtwt9JaiKakXMgm+qCnvTGsReCbLycvBwutX4uvWXva4JQZZfVAR/F0qqs2p8y6Q1dDtldgEB86p4gVTAp4ZBVqgQT2ibZUpy4uF9LE41u7E4t8vCz7LUSsQ7TpZqvwR4lB8PZ1rZlVhNwvPTYpAFj+5R4XiVBEmrQJJDbuwtxmPV68FHVlp1XvEDY94oK9YZZX1cpTJWKhkrlfSC5JqVOqsrdeqVevNKNSt1UqXOx1VKB95lY6WyXpBSs9KG1ZU21Cv15pVrVtqQKm1YZ6U4YjCmLdxanuBs7r2pLtGY0tVKLXr/g9nS4BCYarImdncpqtSDJdINSCxVKxofs+SHwehvAy9uKIHQBjyTK3cLrDP70vgzCSJkD8Zbeu6fyZx7ZmMg5G37NHnnlM9L+h/I3P5pMheertyJ/XrzaTIn3Fq3Ee/0vf00mUcdWnvqJ8j8ztNkPrM5aZECmd99msx3FuRslQejw5CnyFywa8/1fw5GHyJPkflSwryFWPIfniZzelHa8B8Ho+uEp8h8bPa1fMz82dNkXr14YiU2o+fTZJ4yYXoKdrDP02Q+tPhI3D8g89dPkznx9tl8ZOhvcBpCEs4QLuU1vBVqw5XHEK3aFqq2Tmr0QpYW/IS09k9Ie/sJae8+Ie2jJ6T9+QlpPZ+Q9jWEYfmgmCBR+ylI0pQgBSW79wVQKp3PB9mYOMfbkEESeokhwaPglWgowmXxCnzKIeBFf56DCnfZF9K5SbzLiKfnJfTrIWkPRbqTbV8I9MeGQANEENePtMDsbQHINL5SPKkV1bnqbInZ0BIRWyI+piVOWOkkvGD8+NbAOqE8oRl8hfnfNaPhkwnCF5SfIQjP9UhLntQOduidHVcV6bgqThVEHLik4H0KHMrcSO/FX2qj2c1uRymqhVlsLM4gZrFRuMVGYe7CQmrYeujqslnLpWtGZryhTreWBFopGczBhtBdbjPdLDKypGTT10K9ctFrMVJ+Sf2KYd30INzilYqMDxjaA0giGuuTn6Y++Qn1OVl9zur6JGN98n+6voasvoZeGAeVePmoJi1FvJdhNMA9pjrxCdWphH2g0CY173VbyVsDANma0Vg7pQAkZ9e9JUA3NyP1W/BQbqkeQHdt+/QAekqZF6l7RsNLI6t46IKP6Ihg97t92f1uumoqfIhXRhw4vHj9g3xAALxxdqNbunSdkc0AgFMOTfDOBj/iHh1EiaqE9/zFIH/4x3jTjPfTFXQw+aqA10gj3lPwQnKVOBAHL8gMPC1F8CkCDAxaA04n82uCqL01EIIgwdw2mJCy1mxgkI29CcWcED+gm8s2zRw+6NGSVAtOT5qYWL9ZVV4TBCgQSYkvYHnlYje6K27T7FgEe92sN0T2NgTfgyB2xIbiSKsXDgpRuTAQL6WHD1LlQdAejydX6OKQ+SVPKH2upSt1FBO6OkS7mfwJIJGsKl3rkfBGJcajhwg3EEfLjngf/Y9gkN+H1y8dyl3IH4yMHhVM0CJY0BuGaw2/c9m1iPfJp9FbA6HxMDWw7zCErO+y9hPP76PliAM1D93xMkFnfICa5P3ncS/+CxgXXzQZXlTgRZPmP5BRCqZDrXd+dLtkJJdlINAFKf3WwEE0PvQH2qzTQrZhI2BsnPVwOijO+p3JI4sVhS45o5J1GmHP8Z4e0MBLIwsLYsXkfQWGCt1syTjSeN1NfpV6TJzmQ5ymYH2s2SadP0x6s4FlTarMGA45DWkTZGdvQjGM09BtGWOcR0viBDBh/UAhndOIO4LQDwbjNBMUgZymsNdlndMUxmky5zSFOmKDkuzVnEZcqyo6EYnTQCsFZoRGomcbZz0gG6WhUooqlWgLwjaw7DqVIb5gpAR1kJMaB93sDiMpjne+CoY/kiR257Nfk5wvUiwOnIPIFsJuZ0Ai6NRvCEQE9Kiiys5foWdFtAsoKMbgSSGq4ZOdbkNilPMZEqLPqF4R43wGPZX4h+HVQhCaIEZgPjKOsdE1FeALrWQESDIzeVYBuQVMU6hH+NLtEn8muT4RbNN8RFOE0Z6BQgxvbuP9eKbT4zgCJZmGL2qRyHbAUqBpSm5yNUYXJznrvedQGkNSlYhuUeGvIdYMUMEbRmHxoDqIMvtH0l5/YlooQQ9cJxqwG+loaCiHTqCzSEq04OtILwsWa3MBAOhBtzNF1QwTojG77mj2zgiQ/bYapeWMYaWZILpJDbdskgpqrQUIKXanJQZoCkOM4ltiqawiCamO65pa3Xmctkg6VZXYVUQTjYglENVlEwAWt8ZuH2lH4dHJHvd5HzvJoaDYmrRz1DbQGjQ/1O0QlOcJ7hCuDzQkJ3X45HSHeLE+aIx4mdaklVaXXKsSP/aIrVBYfQqrb17Mz9XX01sfq9nurdlKNZttjHVUxqnkUgQIiE6pFC6eTfzOPwwzkutt5mJKT2cLgGrxMoyNrl3JKESJc0myy+hjQqMJRmUKaAfiw0ZFSyjYzEzkC3gXHHA9zQLRTe4f+KJpJbUiiBylgijzIWYdCIIMB9oK8gjlkpnQDUsKkZrbyW8JDD+iSxXEPV7OEnExF5hcgrFG5zj4qsALZGINndvZqUBmx4EGWfB1i/6eGZcZryQTsIhBOKtIMLFGoJtTrBliEXCjKQqfnfgsozswdHHQLTDIwgcC1VFgPBicV/mQsxu72qVqnqjFKQHVnFKPsYeJsUfFz7LH11726OllR8Yozb2MohKjABErYR5rr8CwhhBAQ/c2Zi1tDJdQJi0Y7Y9vCrYUq+gbgeLTWb3WM3cAdk1k0EFE11UQ1ZBEn6A1QegJAjRA5u5qZLzgLdoY7FK4rx7mwYb5JWdCF35wlXSBqoPrpJXWKVxukZ0ssE46BJJqwCq0LilsXfJVSbOxcq7ypeXZRuChBEfJmNfG8uprmI2vYRbv4mfRV02OzxR91bSwVdOir5oGVvFFvmIIb5B32UPrbBiu/si7MGnM5JgFG0IzjtZ+K/WJKsN2sPrMhvXerK/3BFSsxM16n4Db2AsW9oKZTQOz3kCrzvsI5YhqVgKg2K8ITTBVd8Va3RXZ2AnmCkQ1MZWNCGcivEOwEx5dShe2IFPYBSqOmwsVBLTQczFIl76B6DsAxUUgHgOwk+dinNn8fj+CcJJCekXoM4dAkgTEY1o5IhDGKcyiDUk/DgwjGC+Tp2saVQ4D3UTRWhFS7Qi5RgRBGoVGGVUGC42YCfDKo6Vz9ENAE7EuZQX03p0Tx4wXTxXsFXlPEVA6qejZTZO7BJLHgzA//MgUk0QcdIAKMARHCArGJ7NNr4NPqSDmMAHdJaFhn3nrYBIZHc0hblBgngYIQmdcvkV/dPYl07YIDSe/QquwqUmf+aAJR4ykIR7R66tZEtWhmrv5kyNO2d/2mMlue2AWzSArnH8lJwYfuyT0e6GLDOcfHQL3MkHBD/mq7ia/LLLzLy5Bdw6BHiZF7hoC1iTJ4P0GvQKITo00TBn9XTF/VnLdXiMUr9cIE2kU6G7Njn4p6vYaIXu9RiDIcnanVYPqs+tucfQ7xCpzQ2FocbU3mJreEyTmyYa5iPHn3ieER7xPGLLxC8lecrBJBq9/7JJRgkqkYrEOi6zDorfDYk03GbzDj3GTUd1h7KjzHdUwBAaqo28Ir1ucaqc5NrqFTcTogdyEt7+Z758nuF0THnG7JnQPRCc+Bn8+XgcYQWKtq+Yw7aVq70MmNGdhCWFuAB1461t3aCM+4tBG1B3aiHU5tOGOcRgVG9sx2hkYhqPK69K7+gFE0d3zCCAAIXgP2ZaQfI2YM4qj/I0I7jYDanEGNva3UW55CDnJ4j4xxFqOy+Rwl4w+MUSjTwxgdPKJIeKaJjL3F7LBn5WdGT2stntm0RKhvULTnToNWiGohqZA8rtpciNIw/2sgUzOisTUzCBC4tZsFLcgVU0karmYxeXZBMS0MsXRK0RNtcWsqbaYNdUWs7g1q+DCaEMxa2Oyk4nZWqVTVrHWGmpBu4MFZZvJC0YYFEGnmegYuh1NmGq0wicH6GjdSdW2DiSfVSbU2sJcZlhBRSagrbAWGgS0KJH+oheqoPMY2r0l0uCKO4SaxuS1xaY3VpfXViavzUxe+zAbKK0XKK99cLPwEXltUs0Gee3tDxO5uHzDQxNdglMgSOTrgUb+x1ArJJ0f9C9vg5hDKa9Ax0YAIbuxflAjoa1aMIkdIARgcUDVHQXV0AShmmIiiiCShpxuZnS488jCJnEeQomGhBI5oaRHCGVmbeAdsdQilAXVyToIJepGgzqJIzDiyKxMxWuElkg1ZXDJjKJRlQ2UQT5TcUbjwPswCljJQQVMX5O+9FFVNZa+07j0ea2UPiiaQVY7/V1oq4R3kRxP6ZhSrCEh8XsdYvdAVUbJ4ySAUu2BUOYeCE206Iq0RKCbI90joT4DRLbamlVTD3LenKEvLOj12bvKoNs7+soTlAJM7obpYOrmwK9ZmcLIlSSKOgWSG+POBdRFu9QYiXTEOd2D9u9MYVqkx9ot0MH2qoE7FFqpgS21ZtwLHA4Iji7u45nRYTDA2TAQ+t+CIEcuRJ77lDnMAq7Qwt0QEnAc0D2c7iMOwWwYk7I1/aNJqOugP8+Q2i7kJFrRmec09KwK9QNQ0x0kYmUm8puIv28gjON/HXbF5l2rzejy3PSBN7vCsyusON0NWZruoomt+YphJa0upZsD4VCNZtDCbXa+oR/coCmEFSElydNWdevegdmBLlq1n2AZVx7x8kd1SggtmQj0oX0jQgpkKdWdgSl14wNLNT4wV/vmfKTT/K+DNdTqfAMdjKLe55TYDEYJhH4sP9Z+0H5EbgwkH9HIv84XgHethOcojx7bWCUPuZI/ctqHjeHPZ7Y51ZPMwNxoTlQI/MGDlRZjbc6K79xaUHd4yh8PT77dUaF/WFUlDNRmlvt2xdDxU+FuzUEJs5LhcWZDej41Bp5P2uh59aHBbqDoKxQoH9a+20BQTkC0nRubI5BDN+sQUqzMMrPKkYUWpB7obC7SI8z47MViZrRryCAWTTDHM2NzEGQRolZpXsnaHAl9gUuUhuAAxRV+jw8GLYI2+6AwkqJUOdSsHxZBC1jNgkX09qehS3FYF47G5ZCvV9qWFJlajUoxNU7iJnqTJnyIHmxlbfzEHJAG1HYYOg2JZg0fhOKcFGsCOzhXyVM8aCuDaR8S3+Lf6qDn/y2Bjk2sSSB0CbpMRPkkUdovI5Bsq1VwDQLFTPpFBFqWgASyPg2BxGoC4VtdCcFA1ECmwWh5Y7lXZxx9FkKf2cw6oxVjOER4JOsy8bFZKxTRF23JwWEuGT0Yac35Th/Azo5CC1Xu5MH/KaEyR5EqpKANlv049TUM8bEvqWjcUkN0ltgGo4TmINxHBCT7A+DFIbiBaOHUU5jChStgCzz614I5emohNecrs74Nib5a9Z1KWH/RbSduagukwwSxQXUp/PsPEpdguExDPlO4y6b1dGumIczswRpmqF+oUb9grF8mOwy5Woc1HIaY6EIL2MuS0EkdhbRBkxqDJtRZK3fXLnD6mBhVGNyRCAzK4Xj2y47C2B81WDuhAygB0Qr5ljWj7VvWbd904MxQoL1mgQIv0Owt0MwLFLgTbyqpCfz44PDRIUF8CRbEaurCy0haSacpMp8KVO3Kbbjo1Q992NPXFkVGMfYNCywJlnMxkByUkj5uICJt0bBuacHoKkuw7VJEtMaCpP5Ux4eP0fppV0ysS+uXHtH6daUY8ABq/eL/pdYPdTCdn2ojFcKr70tkuX4KfV98On1ffLy+j4XXoW6jxCATgEiiz9t7ifVe8vZe0r0uM1/LvPfSU5gAxJomALF6CFB7FXVyQ75GFJbJuXVd2qwMqgF+oe5J2qyka7NSLW0Wm2qbqUimCPEnNGFJVEZDAEgvQaUcdjtdJuRsQbuJ0FoiQ9NPtaEBSQLte7dfM6ssipL4uP8p5Kv/KLPcCs4AcoJu6wxqooiMGiShF8/r7CiVdgV/UYjBDzljFwd9pUlDNMvgJSRcC4Uoxo6PxttA15RZibgjLNLhHKCULgao1WYUArICapTBF7NqwbM/ZNOFVJOggHIKKNzSCT8cMq7nMCj4AUxnLB13oLzxVLMaBcn39WRz7eRgTL6lJ5tqJ+cImH5UTweUGawdJPecC134TYMhmv9AoLJvt0BVIPFN/sE7RfxIpfgPg2cs5tthUaz3zDvoSyqOX5CM5VHZIlkkatXt5O/gySWB3ujMDOm265Ioe8EgsAXqXNzhMG4gkXHZKTHAxv0U2pgTQ1X+GQ1MrqGBSbT3GKiKYfh1AJyvj3EE75K9zq5/dAP0ZZYV519xcOEtXMxUiTzBM+MLzk0SsWEDIZPXxEVe3nHHmAysYarSndCyAC0hb7q6DEXVBMtnfx1ckxdoq03hipNKu98EoR1MPeTVgBj9wEFgG73i0i+Vo8qAG+hRZkUqBMA/IxBu2ykCzfGUlURbfWIYPJO6gl9aweUbfhTSsdFnrCaTl2bUaGW2zOLKLdHHLavDopsQlx4mzIVPEu5FS2z1l/WNRZL/dOKDZYGXleqXcTsRDzdVh3HHv7pyVJ7xDAwdFrPZSiTRhNuHtnDQs5rBADRDUSPrSrw4BIW0hD75k+fmkHt5yIpGxx6kOplQfZaY6V4izIlLshjGdjq6c5fbPIUzadMPiFoS1rgcD7hAFCliWqpexTNDgmQb2VqIZSRmuYTG9cDqarSmChWaIJONtnmV6vhn0JW/6CbLCOruiD6Xm+hPd9qIQbQscPM/cBm6YsamzJsHBQCtgEjo111kixOMH50GE4KYKWcgif5mWnGCfqILAhXVgeUm7RwP1aCwChRWH0fh6Kn/IQo3M1B4tZko3IxROGbqz1BY9VLY25paFI6e+gQKrzbTnydSOHra01NYNVJYNVJ4tbluCtcHCtd/HIVzkv5DFK5noHCiRBSuxyicm/QzFK7vpbC3NbUonJP0BAonSvTniRSOmf30FK5vpHB9I4UTJS+FD+M6ww4iSs05lmUCvUqgPQXUlOSaJy4B/ThfZGcuZXYqia4ueU8hiR3ZKSQJgZ0dD6lpHieZVnRbBvb0LTSCD+oo+FOo3hCtHEPk9VwzD/Em+1GEvTrCoeGiV6MIb56SOotwaIwDDG849Dw59IYmkV7LOsmOVyrs6JXKT1egfedF3DakgyF6TxGVQgJ+A55kN4PZdP5JE2x7atD2/5So3WoR9SkoUouG/84w/H9OVNsGiWAzswAoQOWX2GLdKW638AeCHha2oCqajxt3HfE8JNREGTwuC/789K+FoBqjbUQrFfq7AI+GaynRMK9aCsIbtI7+5KYzOaXCN2EsX60cuHjjvm6nqo0HW/5RtQSx79IAYJS108J7JAx+QsGArXBTPB6Z1WuUH1syyQnN98PaJci18jFGiDBmCHYDg+C0xyNsaKhmBAAgKoU50TCKeqxtBwlUiQ0ACj4QlvjsRZoKsqboJyE8pw9Y3dQ/VAUwh6lHJvr6I96rCzKxwTOrhE4g1sogmoyngUCABpDVSO5Cn9DzcdDJAWKGwSD+EOxL4W5+mwyNZcg7lZaBbn6rjaL89CirN+pZFgVB92AYqcFkq/mRLGRogCO64JFJ+rKfTOoofRrNggGAmINJ3dSwaVHe09nwX6n+EWvQGrV4/RA3Svl9OhHwnVweWMSnt0ckVqwG62y/0zmQ2ZdU/OCe0A5b4XyWXXhgUhY3TTiaBLngZmqHgNqGTNqG7GYoL4J4TfNHe68yhHgNN2bxIyRaJO5NQ5uSgTc0iZUFKbivCaMexL7I8JjaQv/jlZFS0lLQ/yqYSsaTeWwd70S3YQX6wJhY/YExsfoDY8ACaMsZ8hVoA/SBMcn7gTEJrUGS9xtSCMird4K8H5syMc3cPJAZyPC0iuT8HX3a42MVz2mp7EoBvYCznNR1sjURsfBt2glGi5n3s2NophJQ45Fwby8cuczFC8SJT+ZEfhpBYh+9U9j3HNjZBJVMHA39cUNG4MYIGa1aip4ZX7WtYVSiDT1+3BA34Gh9AH3OScfK2Ad15EBmA6FjLkwbxDZI9IKLDA2ibhOhLSanGw8n0YEYar2IRcmsV7SIOJGiuIuKG5gS20YV+GERhR0MZ3uO3hGgL/8xaOTUGtCRGlXoEsgNsiJTUiU9JBDF9XHSfqKyyDRDdi8FyUF2D4mdfEUrIr3csPqDcGQdxZZ7P0uEybYBoiOCvkmjd8f42Qyc6XRaAq+WSEg9KwolOgvNLvQo+vmSxmix0DdvkQRUDprAm9Ef/kEi20JJUryLEKImtMrS7QosFqq/xFzo8+1hzoXsFrekJ7pMzDYk0enWBvDbAsW4E420oTJORdVNVk49rMr01JBM1loylAJPDd1Oe2Pa9aIVsgW7bgvM97JsDWXrpkpYsfoL69hZdnVEeZlkt0h3DCkJjYh46MQM9WjsW70i0lP/8qWIBTcMlZgRmyYYu4NSPByPbyoqt2hKbLWJpjKf/B8zP8J64HxJlQeTSXewS4KASAErS5EoYDemOI0pDYwpDY0pTYwpqjGluTGlhTHlVWNKsDGlrTGlvTHlTWPK24OXdFJH4NM7kLwkyrZZFEUOBR3N8StM7Bg9fu/ajEc+VB8VP4Bqd+L3sKVwmAI23B/0Zc/08UozezbRviGdjbLa2FUANJc68PNiMv5Y8RtbZHzjBy3Dkfvww7EK+9Sumc5P00c9STjg0V7F2QXPnwCfsIEmKyV9VgiH08HA11N3Qvj/cSeOi2zVVqtBuYgzhE6ScrCKuhnURH2Tuwe6sHD4R0+wVMGyJHchDRHBWjiz4NJa35CfepfICxRNa2JwEGZe7O60carhhHX+mvYjNLELvW/VxRzbK4B1FPsh8dMjeKmD59F8h3glIJMVeOMC74ud+N/3D7/UXN1B6XEdlP5POyg9roOjJW8HUQEAyKcl4MqSwBGAmX0pFzBBCT+qhnVrpXrAzU5+YaLGvq9Sqp9k4PZp4V3EuN0HOei6NsIOWs0bajHew2wR78OCFkMWfySvRB+kwVbE6zsESoQWV8zPcdBeFTuiJGKZCkpn2YkLJwnRBhCtF44n/AGFsXPy4gcO0SbYqvVziW0i0JaCVbAtF0UFLyBVn3JT+Bk30rK1H2lzuoZ10WS0LppqWRdNtayLpmrropnbF/keL7tnqB9vdfOjtpKh8CBJP3Dr3emEKWlLFcmwjWZ0XEUsA0lFgCZbmDL7w0CysQrMskqlQS63/mT2Ppm8T4r3SfY+Sd4nkT8ptJrqHSCKUcvZtqo3M+5XkAZBRtQloihxZpPZbEKlUqpzNuEtXwnmEHAwcjHacfi8kXHesG1qdt9INk4amU8a+uopTplXaGFlU0Y2Thm5esoICAeUUNoJrZ4b6ZwfAFzTt4Kv+QZJ+EeRvQYz5ARNCdeKFzFTFCqA/NgLGpkk/YA6jhxuS8hhdH1ZJnWKELy+yvsbw1o2DCFqdI27aaZw9p0zzMCQiRIORCrlNer7gkcxzD47iHQW9QNmDMky/RTPskno0QSBksy/fUmfyqytPgqPqo/Co+qjUFt99K+l+hlVXtRxqjVEo1KYxtnCiP8ITdJFO5EfI6iF+OrAe2ItvCca8Z74WLwn18J7cjXeM0Ix2QDF4kSvLQqkJllWUCci3wfh+kJMyrzErAAKKjRkpyLjJtvSIbuoiRYFutuCnw9X6QyUxBZi1I/wsFQXFyB+vG+tM6sBHCpsIV6ANKx13CkCQYEm0kFvPF7SmGYCnrKSiPldMp5K6+yQcRuPilPwhqVCSpCZffiZDL50oUmm0+1d8LOvdMIcGlnjIrWiX8dgJWEn+UfLmbmKbQ2P5e3U2xoB7ZNZ+0BjoK+fK9g+XMRQIcPDYRruI9LNWLq+h1RGrNKFfZwapjY/N+rsZiNOZ0gFzb3VzcEdwxo3vllz4jnrOdkJGK4akcIYwQ+MkVTCc6o4gR0SnoPQcoB/aMOLHasV0BSnoOEEnulit0pMI7Pey1oucBFtbQPRXAIXUjwbpqfVTB8AcscLOvimNho8GpKQ1+934DY3kKIJVzck/bolTBWRTZWbFEHqM/TYaedSFktCccRwgCrbvmFIVD8GxMbP2ziogH1MWHuV30iUO0lYgaxdGskqkDG+uSqT+KTzQexGm0zLHDs3hJMML4l9661MpMnuEfn+I1On9NpEb20iq03Ukkez2kRWGxpPWG0iyQysUMS2q0wJS4gG8QIV/tu9yxv9+N7x+mR+hdLbu3+7ssp/o7JPWGWqjmaYXFRIstWUhs46pSGurIpXGiKHcJ6wfUhfsmOHM5jFWfZanIUwFR2w0MdbJd3ijExNSwJ9uY+Zm/mOsibYOgFDo/cJdsUCD8rx83IKM8eI7CSXES+w3SQZv2qOrk9s3QWSaOwcN34cVtRvFnkPV+PBbJByNvL5QKdQKAfC55oHsLt66caOvTXgU0jmU8gJwyKG0sCJ7NSUoI9EQzdfIpD+7yCVyPghccOEZq1W863Vaj7jcC57ztXQ7m0doDE4WEPepq9OawI3cVFXf0SbXQTI/0B26ofGEgAB2k5sX3sXIZFtNtmZvQalk/aKFjecHwhkGySGlCQ9hVm12OTztrD6CBdKJOergu3XZK/SfuyOAoQWKn/6NK32r26wdkF/2UE7fxtmZYYtPLYIuEWTBvGuoo84F9sSozNMgq2tV/99BItDL5NH5nBeZmOCvNZBsDUVqoVyZ35cjFcPay70CIEPNcAlstN/Vj0rLsrsfVH78QN2NgmWVwnWNIEUA8DTQQzUyI9v9QvYVMl7ilo1nMlSRb6qNMIhJfMbnnITTfq6YrPVQ3jUCDl+CH76vFmXQNuzQp1lOAVWfwQ/9WGz86NtgDN8bTaBoDD12Z9ORuCX7eEhbJDNys6T+dvq18FtSJLXBEWTBkITsBLR+0VKxWmx+bJCVGEgVkE55YGded2MLDZOdXrFbQsALQ33SAK05BGMq/xYemP5bbsAVMVyuL2enl9Ary8vCFAV45MfBnofRf3RXuNxiK0Rqz+aq4PoE1AIBDVPsFkYmfhvGP3CkLPfH9zQ2E437848c2Vuyt2HAvSj09qp8yclbI45/U9MSpiz59beyzsvjYmwyYJI/2zJm8VyX4HcQ9qOvawI7H+DB/Vu+U2/L14fNFgQrIJT8IW4S/DPR6gOX4N/r9QK/9YQvlMr/U6t9A7QoXq1wi0N4Y4inmGrDr8B4WcMYX/417RW2Jj/Wfj3kiEcXCu9Va3wm/wdPdwD/tU3hD+Gfw3hX+8venvj/gb/XjTk+QL+BcG/vpDpyy+q4/vXyjeY5xsAmfS4f/A8vcMHeeOGwr8XDO/NrtWmhbxNejitVj0raoU31gpvrhXeVSt8gNev84X+K/Fn/CfzXxO2vdc33/Tto/bs8W14vwH9O3b8rv/3g3p92+LlnuqA/movteeHA/r37akO6fXNd30FkZcFfDao16B/tBwc3qcl8t23vfr36x3Wr/9XwH3hklNognVAhc3gVw8rEht7M/xz8vot1G7WNiuPQ37Fftjgn53H2WvlcRjaAM0f0JtaMajX958P6dsb2tC717e9evcL/4c6YEjfQV9+M+B7yF/B29FSYv1+9P3B4YPg3fayk8Zngsj4Sw9PFBk/vv66IHwLcdIvpF19TjukYQP4169/n74/qAO+C1cHfKl+MeC7/n0Gd1TDv+6rftO3v9pvsKp+AUkYZhkhRhAyZBS6glAMvzgPe+qd6D1gUF/qw5d/D2/Z/7u/Qz8eQJ5A5GteX/APwcHBrYJbB7cJbhvcLjgkODS4fXCHVsGtWrVq3apNq7at2rUKaRXaqn2rDq2DW7dq3bp1m9ZtW7drHdI6tHX71h3aBLdp1aZ1mzZt2rZp1yakTWib9m06tA1u26pt67Zt2rZt265tSNvQtu3bdmgX3K5Vu9bt2rRr265du5B2oe3at+sQEhzSKqR1SJuQtiHtQkJCQkPah3QIDQ5tFdo6tE1o29B2oSGhoaHtQzu0D27fqn3r9m3at23frn1I+9D27dt36ABN7ADVd4CiO8BrHSBqUK/+X/VVB4f3GhTOiaOTkaV8OWCQOvibfr37YhRQ86vwr4Fo1xVGM8XkBPksCCxv3/59eBntId5pSGclsDSqa7DaK5yNCrxEAeHPkBflT3/4NfKkdziApVrC8Pfr0wsZZDAK5xjIi/MTFweAg8JfXn/99b9+8Y9wvS4c6RpcgU89BWELvIdzopDXWQnji7z0Rd+v+vVX3/wNdaUFPrysfv818BB2AKYkviuYnYIf/PjBL86fembWV70MrLP/gHBg2d5f9xrE6oV+vKH2C8ekfv0H9+vTV1VbYDMHQ/nYIr0970BZv4JfN/zi/BwEv2ZD2Y/Q5DuQFQP69G357aB+/cN7ffFNX1qyhCR470U+95sYwo3hXwhNHNlkMptli9lq9QnwbWJrZG/gcPo5/BWnXK9efZ9nxedMDaVGcmNLE+l5sdmzqvyK/Kr0mu11MVhuJbUWF0qLpBRlsfWedN/0QHkoV/ks+eEfY8bOCf7Tn8fExDV5/piff/f371e+3vKtz/76+epTUWNjx09YtDxz3Zat23ccLy2rEpSAei+3ahva8Y3fdHvvr1GxkLgqc93WHbsLSssExeFHqR3f6Nyl23t/69M3anzijO27CxwBL3fu9qc+fceOXwSZt2w/UVpW7gjo3K1PX0/UiqwN2fsPll8bNnzMvAUbsrds211w+Mi7Cet3bd1d0O3DHn/65G+fj4odt3xNevbGrdsOBjz73F8+u33nYZXH8feBx0/4+TfrP6DJ85//66ely9Zl3Xv2uabNunT9sMefP/3sbz9FrN6yb//R8mu3Bg0eF/7d5Jaf/+r1lguXpWdvKzh4YtrbUxKCxzXL2bi76sMen/7FYvV3vtTyytX+A0J/81anznHjH1b9/qvv8rYX7ik+dPZhlaB+7hp6Qhn6jjVQMQdEpvp5FrdrYfOUyI2sotJSaatYZNFitgT4fuRfz/KxRVaa+PrIVtkiS7Is2xWTbDOLfs+YOsoBFrPJafmTRbI84/hI+Z38miwqAWZ/e0fl+Rc/V/+uuF/05JmGpsmNzUMfyJ9YnrE959PA3sDuNvuaG5s/sbxi6uL7qmJXRLmV7VWlsdkme1IhKeit95XX5HDrm7K//KalvfUV09CqgIbWlgGvyaozyN8Towyd0sj2THS8qaXpDYvk19DHs/K1cLvnQGO7yVNl8pywX3HIUWPlUJ/Izxp4Mqye/F+3l33N7a1drHZzuK2p/KnyiY9nWMMmvs/6vK94RpsXz7M/p7SarUQe/pXFbjJ55gdE/t35Q4tfmyF1rOLZIAfK/g7BLIrQPQnYVPI12SQ/xSkGSPVM9QMaiM9Iz0mNHE1Mz1ubi245TMqS9kj77Pt9DkgHpcNiiemkdE66opYrFdJdCRhVtL/0xm8/7DFu5sxZZotPyG9++8cbhXuUBg1DQv/4p4iUpcvWtyupN3JU7Ewv+yH3fdijT9/P1qQHNrFYfW0Nngvp0DF5UfEhn9C48ckW3zd++2W/cRMGfH7l6qdfTEt8qcXHM5Jmz523MHlJZlau2WZ/5vmOb3X+rwULd+5KsjRq7Hrxt29t2aqoL7z4qxZt2nd89733P/r9x39EHuvZu++XYYN/+FfE6Hkpy9JyCpcu+8fO/gMm/s31T5MMQ/KlLLZ83TP0ebmVfxOluU9T0yumdxS/X3tSzM2V5koLa1ub6ImLDPV51tfqSegg97b6BD9rCpIDTeLb7ZXuppaKr8XH8rb6kmL3CZE7mhpbFLvlo26hbRxtLK9bfSN/9d8ftrD++o9NGjzn86HS1P93fo0svuZ3rS/5fGd7S/u1+Q2Tr/m/zKLJKZs8Y75o+q7V17Pgb67ONl+zo34Hi2/Iq4rTk/Nmn9/b3/Xx7dI58F3r75/vEGnp4vu83LVbqOxn9TVDrsiQRp500b+1Y1jil9/ZPLmjs6JajtsztOvstUM7WH6tSD1/5dvFt4Wp/tC0v/TtrnSwBLyNQz2lwhp14Nc+c+5ENmslByjWyJhRSpjJIftYnBN7em77Drb2f66LZ1oD+598GnlGRnaVh3fyf2b4R6949r8mN1akyG87msThgufgy+8rvoo0LOCd93/j2fSmWVQ+NgW2lSL9XlX62P/o61na/nnHq4oPsLTZM21YsdUhO+Rw+ycWmDJKe2h/C6vrw8g/2J+RZZPFp5HF4iM7LC978l/0jTI/Vhzz389hqeqFErml3Sm0ALHbHf7hrx7+k8Awtx7+b4HpCGkAiP5aP0mq95watL5Zz6AfX+3Z0hSc1OaZJLVdxbykEPuCkpCm99VQ4WFJpwdir9+JvkFdmztOdl3s16tby4azu73RKOiPwU1m//FG06BPg9ue/Gu5O+hvPQb0+tuMrKDPv9oT1FM4PLunUHKyV/DJXr0vnZrdp/DcyT73bwT1FYWlfcvFqr4viWbRJr4gimJXUcFLMKIkCTmKpIg+kijetxSIJrHVayLem4Hwq6LN7GsTIb/vM06xr1X0FZUgSGwa+BdbRxu8KzZs+Ir8fXAolmQFwWPxlRyiv9hRVBSr2EBqDKV3gEmuSCDqxKaSLNowbBKhvgbSsyACOmI1kNci2aSm4m9Eu9gaxEIH2QSyAeOepxzAplIDqYnUQWLlPi++K4JQFT8VJYvN5wtR8vERRfN7UiBd7An1g05IJpvY3Ef8UoGXJUlqJCmyU3HAoxna9zcRwL38vPy81FR6WxItVlGyyfDSa0pbUZNc4hBZkXxEs3xEkqB+0YKlSlazryS+JFoD3lKCRcgM/2/hY5dU6Jwot4cM0CC5o1WS3jb/tyw6RAtWLUut33KIrs9FkwCpNhl58P+Vdh/QUdX9usdnJh1Cl95C771jI/RepNiV0KUqiIiKgqAGQQUBDRakhtA7hN4h9A6hN9EgigVLrNzv8+g5973r3vOee9dd7+L19zEh3+w9e/57MjM7ZgvFRkSHsmgXR9SIYJ/yyTmz1SoRCD0XNorvrntctWAgWDqcM3nwycgn+WmWhwrBHuH6zCLhHUPZS7YP5g8bFIrWAhosGIoIfhgqlDtbsGx0waxVw6oHtbtzsYg2jmTDQ7HBaG6/rMFa9EOhCHZ34WB2/7NXREzYSL6LcGpTeKgTFozNWRIvYIP/4yipFqwSahLMy/dwLTgtIhDGtxxePiw8OC/EQ6TQ9KzLg3Vz1A+rQTHEd14zrHREMPqBYGyodgz3zODTYWTY4zODYdF5ufXCgmWiwiJ2RmtX5Q924fbnk0K3wyP5/8KhrtH67P5B/5Vg7zAOkohATFCXRvI9TqIRHozLUj4yxDEQExkKq8rNGdC3GnwoXyhKu/OlSF/xxe3VUokgP37EBDipxYQHgg/m4pbW1woPhMXkDEUVC58aFqgXXjM6mD2YLyKYg6+Uy18lggeB4YGoQdH8ABQs1n30d3pYNZMHgjPzXIkcXSQuOl/xmdFrS2+LCVSOy9MouXue6ou65w383j1/ibtxBQLhswqMji1RcGaBqwUbDU4oFDg5q1Dy6auFAjfHFK/+fULJt79fWTK+UGSharorBSPyB4sHd3JnORosHRUqFtE9WFF7kjumz47B4D3BzsEJ0cnBsjEVAmV1xIUqhcIC3UKRgZgopkbbGgVCkaGy/In+56q24qE62n7ubjzQCEWGZ+OeFPW8j7DeurdzDIVFRP5zBVwwkn8THhERHswVx0+itQPVuMHfiAwPC8W6Fqiau9i4Ptzc1UMxFTnKA5WC2aI6BjirB9iGQMfunKgCnG8CjfhfV5YUjgJ//7FB3WvidX7nn0W0qXSjuEEKBguyzf/rn+hAU329yEJ//9A9NjwuMDmiu9bJQO78M4vFxl0p9m3luEoVq8+sNCS5e+VQypXKxX6/UiXw18w6M+5eqcM6WTeYZVbd0tkS6i3KfrV+tQIlGlQvktD8TrFZrb/rn9C+w5BZ7VkfO/Q+drVD4FyJjqyPnVgfO7M+dmF97BIXqNr1u+DWroFn+VH7P9e+llmra82LYt9yzJT6e81jnSnAUsltE8G6lyMY968rn9Yq/rKOzmCW/6u1T3c+dlBYFta+e/m7sfzN8v+H9S+kYEjWGvg/G1oHw73wRAc7sRbGRmstzPr/sBL+7+tgTLBK2Ih/uwZW1woY9V+vf0n/svrtaaiVb2eJQNg7rGuByGdYN4JZ4kId9ULcf7l6lQs2+c+1q9q/rFwVOZxu/7NiBXLm1NML/+261Oy/WZO0XfmC2f9lXerk9Sfk4+AW38+/X51+4nb4/1ifWJ1Ym8I7aWY7wyOio//t2hTltSkq8M+6FM+fhEB8xoJtgawRjfWDbiA+02iiZ14C8YkLhaagVyA+yWjm5zviU4zmoE8gPtVoAfoG4tOMlqBfID7daKXnYugYrfWDLB1Dj3wG0FkktNWPsnSMdmAQHaO9ngCgY+gZyCF0jI7gWTpGJ/AcHUMPmobSMTrr2QY6i4UuesqMjqHnL4fTMbqBF+gYehQ2go7xCHiRjvFoQM9LxmcYj4GX6BjbwBU6SwQ9rXiVjrEDXKNj6AnG63SMXeBzOsZucIOOsQd8QcfYC76kY6SBDDpLhX3gJh1jP/iKjnEA3KJjHAzo2er4NENPaX5DxzgMbtMxjoBv6RhHwXd0lgnHAno6Oz7JOA5+oGOcAHfoGCfBj3SMU+AnOoaeN/2ZjpGuJwzpGGfBr3SWC+fAb3SM8+B3OsYFPelBx7gI/qRjXAJ/BeLPGsEA9wY6Avc/zgso0+LOH+Dj8YkrpEjEeSU+yYpGMSjFyqJXFlCqFaun3VGalR2x/MWnWzmDfqopPsPKjfKoZ92jp+fVWynlQ/nVswqggupZhVBh9awiqKh6VjFUXD0rDpVQzyqJSqlnlUZl1FsllUXl1LPKowrqWRVRJfWsyqiKelZVveignpSvelB3X/ZU2N9Pr9bEtVT059ZGdVRcLdVF9VS09OJFQxUtvVRxn4rW/egBFa0HUSMVrXjUWFtoNUFN1bOaoebqrZFaoJbqWa1RG/WstqidelZ71EE9qyPqpJ71EOqsntUFdVXP6oYeVm+t9Ah6VD3rMfS4etYT6En1LH5MCDytntUdJahn9UA91bN6od7qWX1QX/XWSf2079Wz+qMB6lkD0SD1rMFoiHrWs+g59ayhaJh61vNouHrWC2iEeqm667yI7pITIkeCl5SzXkavKGeNQq8qZ43RDtQv1bJeR2OVs8ahN5Sz3kRd9N+jWi+9hRK1edZ41I2PpVhva8frd2FZE9BE9ax30UVtnvWebhQ+M8OapBsFZVqTg36dIT5xg6RXGLrrPwRlTdXNoJ71gXa1etaHqB9Ks5LQdPWsT7Sr1bM+RUPVsz7TztX2bZRmolnaPms2mqP9ac1F87Q/rWQ0Wj1rPkpRz1qg/ametRgt0f60lmoPqrdJWoaWq2etQG9r+6yVaIK2z1qFVmt/WmvQWvWsddpn6lmpaL161ia0Wftzs7QL7VHP2v2PUqy9aJ+2z0r7R2nWfnRQPevAP8qwDqHD6llH0FH1tkjH0HH1rBPopHrWKXRaPesMSlfPOovOqWedRxfUsy6hy+pZV9BV9bZK19B19azP0Q31rC/Ql+pZGeimetZX6JZ61tfoG/Ws2+hb9azv0R31tkk//KMk60f0k3rWz2iaetYvaKt6Vib6VT3rN/S7etYf6E/1rL90B0fjt+vuHuJMqbuDwINmv6QYn2LpxcQIHS5WJIrS4WlFoxjd3a0sKKsOFys2pF8fRM7KjnLo8Nwh5US51LNyozzqWfegvOpZ+VB+9awCqKB6ViFUWD2rCCqqnlVML4mqt1OKQzO0O62SqJR6VmlURj2rLCqnnlUeVVDPqogqqWdVRlXUs6qG/HJnfOIuqTqqoe2zaqJa6lkNUe/K9Kx70X3qWfejj3TzWQ+gPnxmhhWPGqtnNUGjdbjslpqi8epZzdBE9azmqIW2z2qJWqlntUZttH1WW9RO22e1Rx3Us57lnP9cGL09Po+gYSjJegGNQCnWa+gOSrXeRItRmpWIlqB0azxaijKst9EylGlNRAfV2+tlHh1Sz3oPHVbPmoxWqGe9j1aqZ01Bq9Sz+HEosFo9axpao571AVqrXpoXfbROPSspTC8H07Omo/XqWR+hDepZH6ON6lmfoE3qWZ+izepZM9AW9fb5ZIG2qmfNRNvUs2ah7epZc9BO9ay5aJd61jy0Wz0rGe1Rz5qP9qq3X0pBaepZC9A+9ayFaL961iJ0QD3rCDqjnpWOzqpnnUPn1bMuoIvqHfDSii6rZ11BV9WzrqHr6lmfoxvqWV+gL9WzMtBN9ayv0C31rK/RN+od9NKKvlXP+vE/bj/rF5SpnvUr+kE96zf0u3rWn+hl9ay/0CvqWXfRKDT+kJbP8ECgYzg5ITKIOqEUK4QeQqlWGOqM0qxw1AWlW/oqXVGGFYm6oUwrCj2MEg972UWPqGfFoEfVs7Kgx9SzsqLH1bNi0RPqWdnQk+pZ2dFT6lk50NPqHfFyjaqrZ+VCNdSzcqOa6ll5UC31rHtQbfWsvKiOelY+VFc9Kz+qp95RL/OovnpWQdRAPasQaqieVRjdq55VBN2nnlUU3a+eVQw9oJ5VHD2o3jGfHlAj9awSKF49qyRqrJ5VCjVRzyqNmqpnlUHN1LPKoubqWeVQC/WO+7SCWqpn6W1lrdSzKqLW6lmVUBv1rMqorXpWFdROPasqaq+eVQ11UO+E1B0lqGf1QD3Vs3qh3upZfVBf9ax+6Bn1rP5ogHrWQDRIPWswGqLeSZ8s0HPqWUPRMPWs59Fw9awX0Aj1rBfRSPWsl9DL6lmvoFHqWa+i19Q7JY1GY9Sz3kYT1LMmonfUs95F76lnTUKT1bPeR1PUs6aiaepZH6AP1TvtEwKarp71EfpYPesT9Kl61gz0mXrWTDRLPWs2mqOeNRfNU89KRvPVO+PlGi1Qz1qIFqlnLUZL1LOWomXqWcvRCvWslWiVetZqtEY9ay1ap166lIrWq2dtQBvVszahzepZW9BW9axt6Ih61na0Qz1rJ9qlnrUb7VHvrLQXpaln7UP71bMOoIPqWYfQYfWso+iYetZxdEI96yQ6pZ51Gp1R75xPXOisetY5dF496wK6qJ51CV1Wz7qCrqpnXUPX1bM+RzfUs75AX6p33icudFM96yt0Sz3ra/SNetZt9K161nfoe/WsH9Ad9awf0U/qWT+jX9S7IGWiX9WzfkO/q2f9gf5Uz/oL3UX7pIgIfgqIIOcPhVAYyrDCER+Pz7QiURRKvOjTEYpBSVYWlBWlWLEoG0q1sqMcKM3KiXKpZ+VGedSz7kF51bPyofzqXfLpARVUzyqECqtnFUFF1bOKoeLqWXGohHpWSVRKPas0KqOeVRaVU++yl2tUQT2rIqqknlUZVVHPqoqqqWfVQAnqWTVRD/WsWqinelZt1Eu9K1Id1Fs9qy7qo55VD/VVz6qP+qlnNUDPqGc1RP3Vs+5FA9Sz7kMD1bsq3Y8GqWc9EKE32dGzHkRD1LMaoWfVs+LRc+pZjdFQ9awmaJh6VlP0vHrXpGZouHpWc/SCelYLNEI9qyV6UT2rFRqpntUavaSe1Qa9rJ7VFr2i3nWpHRqlntUevaqe1QG9pp7VEY1Wz+qExqhnPYReV8/qjMaqZ3VB49T7XOqK3lDP6obeVM/SS8CBKvSsWSiI0qzZKITSrTkoDGVYc1E4yrTmoQiUeMMnCxSJkqz5KAqlWCkoWj1rAYpRz1qIsqhnLUJZ1bMWo1j1rCUom3pf+CSDsqtnLUM51LOWo5zqWStQLvWslSi3etYqlEc9azW6Rz1rDcqr3pc+OaF86lnrUH71rFRUQD1rPSqonrUBFVLP2ogKq2dtQkXUszajoupl+KSGiqlnbUXF1bO2oTj1rO2ohHrWDlRSPWsnKqWetQuVVs/ajcqod1Pai8qpZx1CVdSzZubhHreInjULPYfSrNloKEq35qBhKMOai55HmdY8NBwlfuXjBb2Akqz5aARKsVLQi+pZC9BI9ayF6CX1rEXoZfWsxegV9awlaJR6t3y8oFfVs5ah19SzlqPR6lkr0Bj1rJXodfWsVWisetZqNE49aw16Q72vfbygN9Wz1qG31LNSUaJ61no0Xj1rA3pbPWsjmqCetQlNVM/ajN5R7xsfL+hd9ayt6D31rG1oknrWdjRZPWsHel89ayeaop61C01Vz9qNpql3W9qDPlDP2os+VM9KQ0nqWfvQdPWs/egj9awD6GP1rIPoE/WsQ+hT9b6VDqMZ6llH0GfqWUfRTPWsY2iWetZxNFs96wSao551Es1VzzqF5qn3nXQaJatnnUHz1bPSUYp61lm0QD3rHFqonnUeLVLPuoAWq2ddREvU+166hJaqZ11Gy9SzrqDl6llX0Qr1rGtopXrWdbRKPetztFo96wZao94P0hdorXrWl2idelYGSlXPuonWq2d9hTaoZ91CG9Wzvkab1LO+QZvVuyPdRlvUs75FW9WzvkOZeehZ36NfUZr1A/oNpVt30O8ow/oR/YEyrZ/QnyjxR2lSkUDgaE561mR0DKVY76PjKNWagk6gNGsqOonSrWnoFMqwPkCnUab1ITqDEn+SklC6etZ0dFY96yN0Tj3rY3RePesTdEE961N0UT1rBrqknvUZuqzez16T0RX1rFnoqnrWbHRNPWsOuq6eNRd9rp41D91Qz0pGX6hnzUdfqveL12SUoZ61AN1Uz1qIvlLPWoRuqWctRl+rZy1B36hnLUW31bOWoW/Vy/SajL5Tz1qBvlfPWol+UM9ahe6oZ61GP6pnrUE/qWetRT+rZ61Dv6j3q9dklKmetR79qp61Af2mnrUZ/ametQX9pZ61Fd1FN6WIYjywKkbOHwqhMJT4mxSO+Hh8khWJolCKFY1iUKqVBWVFaVYsyobSrewoB8qwcqJc6lm5UR71fpfuQXnVs/Kh/OpZBVBB9axCqLB6VhFUVD2rGCqunhWHSqhnlUSl1PtDKo3KqGeVReXUs8qjCupZFVEl9azKqIp6VlVUTT2rOqqhnlUT1VLvT6k2qqOeVRfVU8+qjxqoZzVE96pn3YfuV896AD2ontUIxatnNUZN1PtLaoqaqWc1Ry3Us1qiVupZrVEb9ay2qJ16VnvUQT2rI+qknvUQ6qzeXakL6qqe1Q09rJ71CHpUPesx9Lh61hPoSfWsp9DT6lndUYJ6Vg/UU73R21Ev1Fs9qw/qq57VDz2jntUfDVDPGogGqWcNRkPUs55Fz6lnDUXD1BsjPY+Gq2e9gEaoZ72IRqpnvYReVs96BY1Sz3oVvaaeNRqNUc96HY1V73VpHHpDPetN9JZ6ViIar571NpqgnjURvaOe9S56Tz1rEpqsnvU+mqLeWGkqmqaeFDEDXSbnD80Es5SzZqM5yllz0TzlrGQ0XzkrBS1QzlqIFik3TlqMlihnLUXLtHnWcrRCPWslWqWetRqtUc9ai9apZ6Wi9epZG9BG9d6QNqHN6llb0Fb1rG1ou3rWDrRTPWsX2q2etQftVc9KQ/vUs/ajA+q9KR1Eh9SzDqMj6llH0TH1rOPohHrWSXRKPes0OqOelY7OqmedQ+fVe0u6gC6qZ13SzaeedQVdVc+6hq6rZ32ObqhnfYG+VM/KQDfVs75Ct9RLlL5G36hn3Ubfqmd9h75Xz/oB3VHP+hH9pJ71M/pFPSsT/aqe9Rv6Xb3x0h/oT/Wsv9Bd9axoXdVbnJ4Vg4IozcqCQijdyorCUIYVi8JRppUNRaDEt6XsKBIlWTlQFEqxCqCc6lkFUS71rEIot3pWYZRHPasIukc9qyjKq94EqTyKU8+qgEqoZ1VEJdWzKqFS6lmVUWn1rCqojHpWVVRWPasaKqfeRKk+qq6e1QDVUM9qiGqqZ92Laqln3Ydqq2fdj+qoZz2A6qpnPYjqqfeO1BI1Us9qheLVs1qjxupZbVAT9ay2qKl6VjvUTD3rUdRJPetx1Fm9d6UnUVf1rKfRw+pZ/VB39axnUIJ6Vn/UQz1rAOqpnjUQ9VLPGoR6q/eeTySoj3rWENRXPSsRjVbPGo/GqGe9jV5Xz5qAxqpnTUTj1LPeQW+oN8kLO3pTPes99JZ6VhKapJ41HU1Wz/oIva+e9TGaop71CZqqnvUpmqbeZGkG+kA96zP0oXpWCpqpnrUAzVLPWohmq2ctQnPUsxajuepZS9A89d73GQElq2ctQ/PVs1LRcvWs9WiFetYG9Kx61kb0nHrWJrRKPSsNDVVvirQPDVPP2o+eV886gIarZx1EO9Wz0tFR9ayz6Jh61jn0gnrWeTRCvaleW9EV9ayb6Kp61lfoJfWsW+hl9ayv0Q31rEz0onrWr2iketZv6BX1pkm/o1HqWX+gO+pZZUv8/c6GVCmiYgm/yTzNqFTi7zdY+/NqgvYlqFnd0bMo00pA+k/9JX4g9UBDUZLVEw1DKVYv9DxKtXqj4SjN6oNeQOlWXzRCPasfelE96xk0Ur0Pfa9FL6lnDUAvq2cNRK+oZw1Co9SzBqNX1bOGoNfUs8aicepZa0tzO5emlyStQ8dQkpWKjqMUaz06gVKtDegkSrM2olMo3dqETqMMazM6gzKtLShdvenSVnRWPWsbOqeetR2dV8/agS6oZ+1EF9WzdqFL6lm70WX1rD3oinofSXvRVfWsNHRNPWsfuq6etR99rp51AN1QzzqIvlDPOoS+VM86jDLU+1g6gm6i6VJEZU5Nlcn5Q0FUQ28Es0KoJkqzwlAtlG6Fo9p6I5ilr1IHZVqRqC5K/ESKQvVQkhWN6qtnxaAG6llZUEP1rKzoXvWsWHSfelY2dL96Vnb0gHqfSjnQg+pZOVEj9axcKF49KzdqrJ6VBzVRz7oHNVXPyouaqWflQ83VmyHlRy3UswqglupZBVEr9axCqLV6VmHURj2rCGqrnlUUtVPPKobaq/eZVBx1UM+KQx3Vs0qgTupZJdFD6lmlUGf1rNKoi3pWGdRVPass6qbeTKkcelg9qzx6RD2rAnpUPasiekw9qxJ6XD2rMnpCPasKelI9qyp6Sr1ZXupQgnpWDzRAbxy0eqJRRelZvSS9cdDqi/qpZz2D+qtnDUAD1bMGaS/pjZGzvSyh5/RGRWuI9qd61rPag3qjojUUDdP2WcPRC+pZr+qo0xsVrdd01OmNitZoNEbbN0d6HY3V9lnj0Bvan9ab6C3tTysRjVfPehtNUM+aiN7R9lnvove0fdYkNFm9udL7aIp61lQ0TT3rA/ShelYSmq6e9RH6WD3rE/SpetYM9Jl61kw0S7150mw0Rz1rLpqnnpWM5qtnpaAF6lkL0SL1rMVoiXrWUrRMPWs5WqFesrQSrVLPWo3WqGetRevUs1LRevWsDWijetYmtFk9awvaqp61DW1Xb74XfbRTPWsX2q2etQftVc9KQ/vUs/ajA+pZB9Eh9azD6Ih61lF0TL0U6Tg6oZ51Ep1SzzqNzqhnpaOz6lnn0Hn1rAvoonrWJXRZPesKuqreAukauq6edQt9rZ71DbqtnvUDuqOe1SiZtTWZntUYNUnWBXxSU9QsWVfwSc1Ri2Rdwie1RK2SdQ2f1Bq1SdZFfFJb1C5ZV/FJ7VGHZF3GJ3VEndSzHkKd1bO6oK7qWd3Qw+otkh5Bj6pnPYYeV896Aj2pnvUUelo9qztKUM/qgXqqZ/VCvdWz+qC+6i2W+qFn1LP6owHqWQPRIPWs0WiMetbraKx61jj0hnrWm+gt9axENF69JV4n0AT1rInoHfWsd9F76lmT0GT1rPfRFPWsqWiaetYH6EP1rCQ0Xb2lXifQx+pZJefzA/N8XeTnswwqM19X+fm8gsrN12V+PpOgCvN1nZ/PHajSfF3o57MFqjJfV/r5/ICqzdelflJNVGu+rvWTaqM66ll1UT31rPqogXpWQ3SvetZ96H71rAfQg+pZjVC8est9JKMm6llNUTP1rOaohXpWS9RKPas1aqOe1Ra1U89qjzqoZ3VEndRb4SMZdVbP6oK6qmd1Qw+rZz2CHlXPegw9rp71BHpSPesp9LR6VneUoN5KH8mop3pWL9RbPasP6que1Q89o57VHw1QzxqIBqlnDUZD1LNGopfUWyW9jF5RzxqFRhbVhX/Sq+g19azRaIx61utorHrWOPSGetab6C31rAloonqrpXe0RTq/W5PQZG2f9T6aop71AfpQPSsJTVfP+gh9rJ71CfpUPWsG+ky9NdJMNEvbZ81Gc9Sz5qJ56lnJaL56VgpaoJ61EPXR4wlrEXpCjyesxbr99PhlrbQEDdT2WUtRfz1+sZajGXr8Yq3QXtLjJWultkEXWlirdNTppVtrNVqj7bPWonXavnVSKlqv7bM2oI3aPmsT2qzts7agrdo+awfaqe2zdqGp2p/WbvS6ts/ag94tqsv+pL0oTT1rH9qvnvUT+lk9q8Rd7rd3dd2fVyLU+K6u+5NKoSZ3dd2f1yXU9K6u+5PKoGZ3dd2fVynUXNcZWuVQi7u67s9rFmp5V9f9SRVQK/Wsiqi1elYl1EY9qzJqq55VBbVTb4NXN9RePasa6qCeVR11VM+qgTqpZ9VED6ln1UKd1bNqoy7qWXVQV/U2eo1E3dSz6qGH1bPqo0fUsxqgR9WzGqLH0FkrPBgoH67ruKQo/eKCCuG+8E8MMVYUEzeJYYyVwn3pnxjOWFlMMSMYq4ippr5y1XBf/SdGMVYL9+V/YjRjdXfNGMYa7ppZGGu6u1nMyljLXTOWsba7ZjbGOu6a2RnrumvmYKznrpmTsb67Zi7GBu6auRkburtFzMN4r7vmPYz3uWvmZbzfXTMf4wPumvkZH3TXLMDYyF2zoEZ3zUKMjd3dKhZmbOKuWYSxqbtmUcZm7prFGJu7axZnbOGuGcfY0l2zBGMrd82SjK3d3SaWYmzjrlmasa27ZhnGdu6aZRnbu2uWY+zgrrmcMd1dcwXjWXfNlYzn3N0urmI87665mvGCu+YaxovummsZL7lrrmO87K6ZynjFXXM941V3zQ2M19zdIW5kvO6uuYnxc3fNzYw33DW3MH7hrrmV8Ut3zW2MGe6a2xlvumvuYPzK3Z3iTsZb7pq7GL9219zN+I275h7G2+6aexm/dddMY/zOXXMf4/fumvsZf3B3l3iA8Y675kHGH901DzH+5K55mPFnd80jjL+4ax5lzHTXPMb4q7vmccbf3N0tnmD83V1zdGwwsC1WXXMM43Yx1XydcYeYZo5l3Cmmm+MYd4kZ5huMu8VM803GPWLiHvEtxr1ikpnImOauOZ5xn7vm24z73TUnMB5w15zIeNBd8x3GQ+6a7zIednev+B7jEXfNSYxH3TUnMx5z13yf8bi75hTGE+6aUxlPumtOYzzlrvkB42l308QPGc+4ayYxprtrTmc86675EeM5d82PGc+7a37CeMFd81PGi+6aMxgvubtP/IzxsrvmTMYr7pqzGK+6a85mvOauOYfxurvmXMbP3TXnMd5w10xm/MLd/eJ8xi/dNVMYM9w1FzDedNdcyPiVu+YixlvumosZv3bXXML4jbvmUsbb7h4QlzF+6665nPE7d80VjN+7a65k/MFdc2YBDv4C6pqzGLeLGeZsxh1ipjmHcaeYeNA7h3GXmGTOY9wtppjJjHvEVHM+414xzUxhTHPXXMC4z11zIeN+d81FjAfcPeSdw3jQXXMJ4yF3zaWMh901lzEecddcznjUXXMF4zF3zZWMx901VzGecPewTwSMJ9011zCectdcy3jaXXMd4xl3zVTGdHfN9Yxn3TU3MJ5z19zIeN7dIz4RMF5w19zMeNFdcwvjJXfNrYyX3TUbDQ4Gug9W14xnTBAzzMaMPcRMswljTzHxqNiUsZeYZDZj7C2mmM0Z+4ipZgvGvmKa2ZKxn7tmK8Zn3DVbM/Z312zDOMDdY2JbxoHumu0YB7lrtmcc7K7ZgXGIu2ZHxmfdNTsxPueu+RDjUHfNzozD3D0udmF83l2zK+Nwd81ujC+4az7MOMJd8xHGF901H2Uc6a75GONL7pqPM77s7gnxCcZX3DWfZBzlrvkU46vumk8zvibuEyNvBgMl9TsY0v3BIGMpMcMMMZYWM80wxjJi4kkxnLGsmGRGMJYTU0x95fJiqhnFWMG/7MGMZqzorhnDWMldMwtjZXfNrIxV3D0lxjJWddfMxljNXTM7Y3V3zRyMNdw1czLWdNfMxVjLXTM3Y213zTyMddw9Ld7DWNddMy9jPXfNfIz13TXzMzZw1yzA2NBdsyDjve6ahRjvc9cszHi/u2fEIowPuGsWZXzQXbMYYyN3zeIa3TXjGBu7a5ZgbMKfl2OeHTqk1/CevYcOC0UPTBjcd3hC397B8IeGD3s+EMuHevYeNqx3ryo9RoZFDOXf9Sxao2qdulVrxJWv06B37171E2omxOnXsVepUaNK9RoVIkckDOTTIqtXrdGgavXYEQnDBlXp8czgXn17D85dvWrNqvWrx5Wv3TMhoUH9GnXrVPgfopc1Sw==');
+export const wasmBytes = generateWasmBytes('eJzcvQl4VceVLrp37eHsM0kHjG1sueM6J9yO3O100n3d2r6Ju5siAZsmjvPdTr9O36Rf6MTpOEcZLOASd8exjkCAAAESozCTmMUsZgECiVlMRiAGMYtZzGIWINDzv2rvM4CM7X793vu+Zz7r1Fo1r121atWqqrWUf+39G1VRFHWj+mxPRemp5uJ/Nbcny81V8EfN7anlIoy/am5PPZcg+lFzexq5Epa/am5PM9fBOAE1t6cn18VZuU8G1dye3twE3p+b+E/N7RlIAnOVVFDN7RnMTYnX/v0Dg3nUdG/IG9KtIEtTA/50w7S8uubRNc3DmKdDB79mMI/u0zXPM89ohtfj95ser6EbHk1VVdUwTMNgWvv2Rrt2uu6zVE1VNVXXDEPTTK+l6bqhmYpqMkPXNA2RGtMVRdUN9X312WcNTVXamV41VxWxWLXi9yxX/eZvfvGb3/X6d6aYP/t5r39/v48SCvzs17/7/b/9qvd73X/7qz7KX3vf/V3X38qoN73v/u67v5DhtwJx/Hf69Fb+PhCPAtgj/ac//f3PfvXbd3/5i9/+9N96/eIXym/bJWF+86+//vXvfq70bp+E6/ULifzly0nIf3333Z/2+d1Pe/f5159n//T93/3qt31+0Ut5z/uiqgjV3yHnH7u88m7Pbj/99f98+y++3evH/+tr//rc677tw7QPJnL1+dyvKoJnR7ROilDCOuuMf5wJ5a2gIgGAQUQaQg2bAdUf+t9CDeucCTUUjZhdgxo3wzriOQt7uBq2qIBQ96DCmWhqbW315IQNoYS9iNc6c5N7o6iJq9wbjVhCjb6qKBGfyO0tYmqO8PTlFhD+BCLKLcEoVSAVqREymIrUCZmWijQImZ6KNAkZSkV6CNkuFWkRsn0q0kvIZ1KRPkJ2SEX6CflsKjJAyOdSkUFCPp+KTCNkx1RkOiFfSEKKVjVHhLoFFe4NGwHm57pIjwouFP6CqMqV0S92z+Av9PqWonBdpMnIjimRHZ3IoIx8PiXyeScyICOfS4l8zon0y8hnUyKfdSJ9MrJDSmQHJ9IrI59JiXzGibRkZPuUyPZOpEdGtkuJbOdEmjIylBIZciINGZmeEpnuROoyMi0lMs2J1GRkMCUy6EQyGRlIiQw4kaqM9KVE+pxIxPhTYvwU4xWhaNjLPeL9aMQj0nsEFT9n3NstqNJEClvOxPJzDzei4QBmnNaZW5hkPjmhlLeCjOvcIXNblXi4V6jRiPdNlB4IG36ucSOLhbjGzSwW4BpnWcwKqP5UBFKhHj+SetAohfsQyI6G07nKWTTcXqZ4lqvRcAduCBaNoCXh57nBWTZCz3HdCXUUSjigdWbxf5w6oIvcv0fBX1dU8Xc5YYvr4uWcsJcS6OKnPTDwhdmHW73COvcJFg2noaUWcL6vK0z8XU4v4k66eP+doALSiEAfmVh7LLGGxNwr2vcRsViTkiNT6dFwmp8HuC+bp0XDISpquge9DYUDPC3sCzA/68yf5YFo5Jmu4KD8Gc7ACZ/jgWjXoBrw+3kHTjNfdH4r6JMQsU+f1ln0V8FBud4tqFPZudGIFz23qOdORz+KRrxfV5SIWR0JohCFm6CDHlD9Too/JlJgRnyPkqTLJFwXH8po4clBZ9sRWXREmH0+Hc3JkAUoYolYbJPSLWj4uVcuEdwn1BzqniViKvpnidnq20GVW6GvdA2qlCHsRa8CzJ8EUY5/jkZM8UyPoCJUbvYReXk8J8hA+mm50TRNVVTFL9Swj1thr597ncoV2TmvU59XzFHfDhrcG/pvQTWAlokTSg6N6BffCer4Cs8TyekbCBaVn6Gj8xkcwstVBUT0xhHxj9EPH0Pn7eljgK7OlwA5MYfDOrcwfcI0H3RnVHq5ha9hUk9pTJqgpLcXjVcUj9Q0TnV3THopMY1JLzdpTFrEYFITyzFpJsZk4ssQrwrr/v8PP85jn0bHp+ka1ANayrfpHmTA6EQ4XU4lH1iOFQ0HuBX2+bkmOkS5HnqR+qJlso4R3Rn7WibrENFRTcTTFS0k0tOQj4XeCSqCEUJoQgcqpnfPCFt+7pcERnaL+7PfDipcE1aU+7kV+h/ABsImfkLoEjFRyVM89KH/LkfEcnthKAizbzjo1KVyL/d8S8FPUFSrYJ7UU64jRGtvoG84jeYT5Q9SM1tbNWJTyMuQVxaRJhpkEcwv4zQZxyhOliBT6qJ9X/E+pQUTzmQdwNHifFLLZC9FvA656PNHgpJSXkkpK04pr0spy6GUF5Ty4EO2QSnvY5TygFKeaNjDgxhYbi9NMNC+1FmnJu7hJpruSdDJwHB6kjqWSx3KwZBDZoxTx/DLOE3GMYpLUMfDrQR1nLkgaZAmaeB5nAaex2ngAQ3QGE8bNPA8RoMgaBCMhk2elkyDoKRBWhINTNkVk6cljRWiQSjeAyeDQwNTdtDkaTJjyKWB5pdxmoxjFJeggZlMAwZ6WU7IpBAWbg/3RyNWFgs5y0XSKPE/Pp/8j88nv59bNEr8kkJWgkIW90sK0fAApSJyjFA9weTZFJSzKS1RV/Lnro5PBr0NGumJceIOApkxlDSLPJJGHkkjTwqNPI/NIg9NWlDGz61oRHcp422D03if5DTeBKfRw/4Ep9ETlNGf4DR+fBB/NOyPcxpvgtN43Tnk1Iaxh4b7E3MIjKKNOeR1aeOXHMYviepP4TB+yWH8knz+lDmEYpNp4+dehzYW16WAF9ABetsYRJ4UVqM/yWr0BKuxiFTeNgaR1x1E/vggSiHUEyw5aRC1SainsuL/PKH0xwjlDiIvBhERSpN08zsg85OABxajS14NBiPUHnEWI5jbdz3edx2CMvVKF7tVCOkaEjrCtiN3+rmPp0No93OFCoBQrnDtFWbZDKXzaCcl3Q8JO/RPih8iuSMVidriaiX0LxJHm69qB7GrE/Pmqh99VRGh7IjaKaEOUMRtLPQU2tn6h7dJOwCOyxXhj0aY+CAnrAklk40uFhG9a5CJl8MGcrW2erByauJPORNW319GlOy+Qs3hilD7ZIu/jYYNR4Z1/6GQomIRMUWIyhbNGlL7u2dENKH1jbC+EUVoOV2DClewEVZzOMPuSOsT0UTBiPVKNILOFyGUyZQIy2RWxMKWyeIKti8Kt7CfYZA6lExWUizeDppcCTK3+UpXwO8J1kc0D3NKsTKZLj7I4Vp2mHErbGq00GSyUESBsAUhBQEzk3UMGw7NTHxMhZtvkkDYQYSA6RBRMmSJn0plICHYNDUxpXWqH1mi3BShKFcySInigaAZUYBWCI3SrIywhytUuuUWrGBGK1lM8XOja9CUjUEtL8X7hA5BiGOduZHJQtzEhsZAEzqAFAGvX0IhrgTVgOWXxFM4aIPxZmSxjrKLoYhF3wLtDslkHf2ypxYREhEdnIgAMsdJGbE44xZn3dELxrECWhlhkythK6D4/QJk+4h7fp+ThRHhxwCKhhXOuCa0XllMRyga0TD4daECEzAkz1A46yMYZ30iilC4kt0r572IF0OEOUPE4iwxRBTQn7Z0HjQ2wC3ukR/BaYI33gQlXreCuk00IRsKLQfHoihBkcO4pFhEGPijkslKi0VYSx7fHoHJp/XtE/HmIAm2zr2oFo7Z5LRTyiuEcdse9iS1PUzrnoapz/CD3bknsUnH9ATN0I8stEHQ5ygpFgHdLxRnxIuPaHCgk6OLhT+uECR9nhnvsYkeg3CysxY6y7KY4nbVg42G01UluateoXIPddWQXfVyw+mqJ9FVD60ZhIl4nK5C9xHvqgeCsoKuety54pU/HnfKyBGhxBtMzEBJDA/6opZLCUaUwEyzopj1Kti1QnM8zviMFDZgEt+zaOKirLAFJiJe4IZQ+2ZzQ7zcLaNP2IN44hBmxJSjPeLlGm2UMFy8nJGEGLa4N8y4lwpl3AwTjsQ9TB8vp70h94g/6Sv0nCjmmvlmBrAZYYV7hNon7OEmVgAa8lYvp3WMG/GBz/UcdE17jKGFFT/msurXOrsTMmKibR4O0Yl1j3gzwgY+eXfwFyzRGbSlUqgVQaJqh7AJfZTBPZi+FrYxGdBJoR+0Llm0F3fHhyJJgImuZb8ThOYlmV1akl1ab9LulRhaErs0E+zSwnQ13SFgOt/eAl+0HmOX5uPs0kywS9MtOMEu8b27ukpwbqGmJJZpprJM6zGWqaewTJLZXZapJrFMWjRMyTJNpDYdlkm9NbsGVRnRwYmgcZaYqNZjE9WdplZ8mlo0BeLTVHGnKaWNkUpE4Sz0VdJbJa+3Lj/yyElqck+cHzWrCYZkSobUrCY4kvkYRzLlNGVJ3wg/rh7RSp2m1uPT1E/a94gSfFIycMbRO+64mihhiFMqVLeVxevBohfF9KiIxT7MCYFEaibTnamrYC1TIZQbkqplxQKwFfFGI0oWQMwbxMwH90are2RkAUg0YobL15uHr6eZjg7T0kudiHii3YKM06qiBLF9ixN6mRQvQFwFM0Dy5WXFAt+49eUsVkH1G1ksfwQC3izWMlzIVM0IEMGz2GjEEsmz2MQRCXwRhUsoPCMpTQmFSyk8PylNKYXLKLwsKU0ZhcspXJmUppzCFRTelJSmgsLVFN6ZlKaawjUU3peUpiYJX0vhWgofTQrXU7iewmeSwg0UbqDw5aRwI4UbKXwrKdxE4SYKtySFmyncTOH8okQ4RuFYEcKFSeECChdQeDSFiyg8MQlfROESCs9ISlNC4VIKz09KU0rhMgovS0pTRuFyClcmpSmncAWFNyWlqaBwNYV3JqWppnANhfclpamhcC2FjyalqaVwPYXPJKWpp3ADhS8npWmgcCOFbyWlaaRwE4VbktI0STpTOL84kaZZ0rmYaF6cSBOj6WDiTOuDnIgiPohGWBbmXzx1gUzBlWzuFT+KRpQoWIeVBc4A6dLhghAFRGYW05ExFlOzWHmxAC9g3JTnAqyHXNQCkOZyaA1S+3KjW1Bz+C/NXRTEze4ZNGml4tBy2IDkD3I9VSIeTnuOOBNgfn+bpav+eHaSGBw+Qq3BmgqGEo1Y3aGQRWFhJS4aJxHF4xDlCULokhDYNj+FEESonigOhdH6IELR7hkRj8igpK+A+4R9n0pjoSig2AiBPaaH2DT32RACn8K1sA7ERkDaeylK/FIRHloahY78VneswR7OugU93MM9WAg+ypECvoez7IjiNJ7kPrkJlQsZ40roqwErZSGzhMoVWshMuZBZ3HQWMiVpIVOkDp9QEcVZyCyuJBYyhVYquatmWMGY3B3QsmYFPH65soFc+ErcGyUCy+8CQibWZE8CyZwFOjusceasJt2CTrgUYSgKGC1ZQssR6pvUT5MktFAGSeHIys0o9ra6lMFMh2BY/NT4/gQ7ApBLi6/7WuirATOFXExgd55ELhYnl5ZELi3MSPtM5HI22mGWtNEOa36HMlxz130mf7A9CRgYtJD8MIMn0gx2llLaSVk0grmVJLfEd1q0hQmYfndzwZw9Hu28cCqKshyhHoESGtFKfJP4WbuAErkLoI0cZSdVqhrPRVu5xHbT3YBCJ03btaicRm43nAnJ6NO7E1LhLNsRVHAWymhWmnJWWtxMEtKeZE+aX06dGS61kAk7Xpk9/q1lhxIUIDHJSViSnDAxKKSI5e5i3MIjjGtvO4PgP/2h/FwVoWgnhSv+IS/LyyYxNTuid4Iaj6RlvUsshls2NgvQxYLQ33CdRDL8hCImiGC1+OnILYt1oF0YTYMnE2P0myJmRZPyMDcP+8w8QsHOScRCyfk0N5+Wms+tpGNyYp06IjpGQxpUi5mMSz2InsleoqM1bALNZPGV1gY9k3WAiK+T0iWK/V00YmQy2ik8Fin0KDeEHnViaZeKvnYLMrkGkCiOMw6M2m5BXLgxtM6E0GkzIVh3tzgjCiph5x2N+BBMqvPxhPlWlPvw10ljoGZDxLA1k4cpmnuWQqREpSxRqZZSaShRaeixSlMS5oeo0lCblQqNOqklOqkl6tNT6uuYqK/jY/WlJMzvSPV1bLs+JWzK0foemDF9WGwTFFGw/+FU1juLdcLNKOINao+I8bZMQNoCFCjqigsXfNA7i2UKyV/VHhkY17ShzogYUgGQkmPh6HMlau8s9ioGe/RzEm86tz6jdxb75hdJO61pRul/9M5ir32RxLWnW3aie69/kcQlt9esV3pnsTe+SOLBh1ad+qh3lpzMn5f43MbSOXrvLPbdL5L47qzqLVrvLPbWF0m8e9eeG3/oncW+90USXy6ZMRsl/+CLJF5ZVz7gw95Z7IdfJPGxqdd3IPGPvkji5fNGtaAZP/kiiceNnDgXHez5RRIfmnek6N97Z7F3v0jiCXfO78CYfk8oYRyFXFaiXBedonJKYY5hqQhFuA7gfaw+mTL864iaxTq1mYWrXEtksTCmnSwBzIU2swS4lcgSxGB1sqRhlLeZJY0HE1nSMQqdLCEM3zazhHh6Iks7DC8nS3uMyzaztOftElmewbhxsnTAgGszSwf+TCLLsxgQTpbnMJLazPIcfzaR5Xl8aSdLRwyRNrN05M8nsryQxd5zs7yYxd4VH4V9Qg97+Athk78I7tp2IQY3QxG52HUgZYoJGRhFglkimScqPozKgg0pecbT0g0bSE5OQik5YwMR9tDFrYhPPMIRk198FGYi3wp75Ak2EyWDq5XQd2iHYIoKB/gijcS69o5zxq1/kZZGfG4GnQ4R22gqQ1NZSlNDYQ9OzZ/aoqQlT/tiDXGW2C/TkI6k9/wiZNFRuPYlyOJk+GKtkSvnh0lyXh+ui1tKNPS3yPwBV2lRhnD3oayiD9SPUuqKJ+sDsYuSaxAuSa5DctOV655MGTF4ABsQkuVkWu3JtJQU9xwNuZ8jYU6m1p+aOg3qWl0YbmrjqamDUkI03dTmU1OHslhHXGp1U3uemjo9i72E+7FuauupqdtnMY6rtm5q71NTt5Os2uem9j01dQdi8sLvpvY/NfUzkqcH3NSBp6Z+jlYDEXRTB5+a+lnJ/NPc1GlPTd2Rlg2R7qZOf2rq5+UqEXJTh56a+kVaX0Q7N3W7x1I7yV7IYt8V7bEpEe2z2IeiRgr0HLdOCxw252iF4myUYWcRn4RmVFTkyrMxyFXUkqS6nOljRjlpW3S5yGOIYahDbW+IYvYm9Dphg/vAR0aysCFqrLAp1DBz9isRT9L24L+iSj21Sj1sipoQ1h0Wr9DhfizeVSOlXvMz6jXi9ZrxerGFwlGWKDZlvaZTr4l6O6bW6/C5/9p6reR6OW1HPpRqJf0V1sd2+WxHeSyE66C+PkIP/cwv8QGJl+R3o+j6jbzgwbhHNBY4ayR2qs0F8QXTEmXOSBr+HZXlxi92cFWQjkaNs2idDlBCfyNPQ1iX8SMubC69sn7DVcVmzR7Ouhw7XD1pzK3Tu+fk2qwJiIVDau/t3rl/Q1ebNQKeun3Y0ksrHh39pc0aAF+qPzL6xLQ7A//MZvWAl5+4sLlp+v1hBxWb1QJxp6bfkX6xSTfzcm1WA0TVwrXlu45e31WZa7NqIPbGNh3eM237nI9zbVYBxPYjEz+eN2fukAeKzcqBaFxSOry4Zu+qTxSblVGhA0vGl9y+uf2UYrNSIKatvN1/b8XVG8xmJYAHDxqw5e610Qv+2WZFgCdNGz5v5v4L1w4pNisAYsKVoumnpl/Nf99mMcBr8kpH3RhTcRTtbjY567Jg4ui8hkuXK7jNmgDXrcxfMmnblBELc23WCETh8RXHhy+bNgWtbADi9oA9q1tW7frkDzarB3y2etnyq7WPDt8FKYAYfW7x5HvDBwwL26wG8LlNj5bsaTi2PQZKAJF3t+jcuAkXD/xPm1UAbpgydeaDsc2D/2izcsAHzi/dXFzXvOn7NisDPKKk/4Lhk46tXJ5rs1IgHh3ZVzf51qC8obk2KwGiZFPlvRtXBwxal2uzIiCKT1cNP7a8fN/SXJsVAFG6dvnDTaebtjYqNosBMbJp2qaa/i0Di3Jt1mxw1uXB5iOtNZeHDO5lsybAi1Zcv9489uzq4aAEELXT1+QPq9x2elGuzRqAuD5xxf7hy25vu6TYrB6IXcuuLRy8dEYNiFcLxKO19/vvurO1+c9tVgP48Jj+9ybH6tZk2awa8PpZl8cNPLb4xvdtVkHxZ+8OX7flxNkZuTYrB6Jy1KrRw26cfbgDQwKIezW79x6aX9yyArQA4vLFGweXXbrV+HWblQAuedA4KO9uQ9EfbVYE+NiWRyOGL8tvrgAlgCjffXn7wPFjz67JtVkMiC1nJ55evXD/7EJQQsew2zNk7trmqqpMmzUBXjh/0r7Faw4P/anNGgGvWXT9xuDRp1f+vc0aANfc2Tx+ZtPK2N/arB7w/Ut3ivtfPln8R5vVUvza6vtL9u/rtwBzA4gTC/adrjt1uPyPNqsG3Fw37nbBiYLxP7ZZBeBHO1oqt9xrHPEHm5UD3pKXv3zS7oljMbfLgFibd37ezPWF5S/arBTwqnULitY+eHRhFQYEEAdrFw5syZt0ZrNisyIgBtVWlq+4M3LcCzYrAJxfOuLukRN7x/+fNosB3jRkwJmJUxctwsdt1jjrMmXa9WtbZs4smQAWAcSls+fGXx+2Zs63bdYIeNGJK1sP3Bm94m9t1gB4w9obN5YXDl7+gs3qAa+YMnPYzpL63Wh0LRBr1y9dM+dgXoNusxrAH5fem7JuUF0lPmU1EPU39yy8cXzlrb42qwC87eyu1eXXtuzExCkHom5/Vf3aUU3N20AHIGYs3Liu/7b8AT+wWSngG+senNiztXrXBsVmJUCc2vfJnTMNx6Zk2ayIejX4QuzMvsMNB8AgqM5La/uvG17TMACjAYiNO0ZePn/w4OZv26yZcdZlfOHq8zV3zq8EJZuAWLdkzoqBezZO7Yd5AcSk7ZtHXFxYffB/2KwBcG3DtT1D67Y/fIRpAcTlsZV3t7QMX/uMzWoBl32yrnzVrZ1l8zAegNi7fsvegVeGzv2RzaoBn6xYUjFw8qkLSFABxIba8cOnjToCypQD3t4w/ZNzl86NmJxrszIg+g1e0LQhVrABE6cUiJsnlj8o2lp0vz9GBBB5D2dM3Fsyb8c/2KyIcmwpOTns+pYl2TYrAHxsdXHL5BXHNvzKZjHADfU3Ns4YWHrnGDilylmXnaMaho9dcrHoDzZrAjyhtnVm5ZKC0ecUmzUCUVK89ujN5Vdngh00AJE3e9ueu5+c3J5ps3rAS28dfrT90tKRlzEigBjb0nTj8ukzGzHGaoA4NmT2sVPrCgtvKTarBqK4rGLKvAHXVp5WbFYBxKJhe4esXrJ6VQsWDSCWVSyedWn8/dJ2NisDfPFG2cKzYzft/zOblQI+VT5t9YCCAbdQQgkQN3fsW/LJjYtz54JXAnH4ROXmQYXjyvdjTACx/tr+iodn9pasxZgAYuik5jPntk4bUf7p5PgALGTV+ZuNeQMnNXw6Jt5Hz28M2Nsy6ZMr4DHvcdZlesOWhYXrppZn2qwnZufcjSdX9Su59+ni+SOMl5q9hw+uGLugi81+gBH48aLC88f2DsF4egvc/sL+y7EdC7be+JQGnVHYw9PTLi7ava2PzV5H8vUzJozdVTfv+qcE+Cb6d3ZQdUO/qq0YwJmcddm6ZODMG5OP1IDzc866bC69UTfh5pSxOTbryFmXKycGbr20d8qoi58yhhBnXY6cvd16487KYT+wccmjy6xxmwbvWn7lWkkuzgRVbOsCzq0LuamTgoXflSzmrRu+8tG6S+t/bLPmdM66nN/YPGfqnBH1I8A1gJh8ffqF/mdaV+TYrBHw2caihYUThk9B8xuAuLesdfXAxtqlP7dZfTotwLuPHBt/am7IZrWAB3yy/+qUQ7NHpNmsBvDCW8U3thXtmvYdm1UDbjk4snbzkRsDwCQqgJi7e9aOufV5A7varBzw/vKqrcfPnb18EkwDiPy8yeUXR41uecFmpYBnnx2Xf2bXzjXft1kJ4ENXm88NKpx0t4fNigBfP9S6ZO2D4cUYtgVAjDy5Y8KomhPbhmF8ALGuceWV4sKjU75qs+Y0NOHjIRtmrn04N2CzJsC3Tg2Znlfdf/1XbdYIOPbo6vbaUXsLsSA0ADF09MjKfbXzt4Et1QOxcdu+5tkjj529gKkCROmgaWeq9l05LWxWA/jGxiEPLtds23IEMwWIi8MXTBu+vb76b2xWAXjyzXvVDyvqNgwE8wRi0p3KuQdKa86tBx2A2NA4dPXROwuX/85mpYDHTF58ZNj9urP/YbMSwCs2r1k04+Hc/dMxUYAYvXp9UX5L/hRuswLAh+aUXVzesmDJezaLAT55ePr1quEj1v3WZs1BzrpcO7to68yHVadfs1kT4DPFZx8VzCw8fgUcA4hLY3Ze2TmkcFwdBgMQrYNGHL2wt/jkizarBzxz8IGq5TsnFmfYrBbw1KoHK8bXLZzfrNisBog5RQeuN9wfdPNNm1UDPjFrxYji2vxlz9msAvDHxcfXty7ccX0cqADEoyXHFw1efmIyRMoyIMaPrT08flTjokHgnEDsr3tQeHPy1aLONisBvGTZ1P7Lxi4Y84bNigCvqhuWN+TiwgVnMRyAWLR2/oxZ41uOv2CzGOB5qxacvTDs5L0lWEoDEGKGLbnzaPTQxWAXTUAMrbw/f9eGlft72KwRcNGqXfMmbC2r2Ak6ADG5pW580736PPCoeiDKa5dMPrnkWnm2zWoB3x5++9zwaZt3Y1DXAFF2eeCcvIX1/V+xWTXgBQ/WHtzQNHQlxkcFECO2r76zbvbZcT+2WTngARsb5s6KndsPgbAMiGtrdow/um/sCsiQpUDsudYydOjZqi2qzUoAjxl6fdK6ohtrZ2E8AHG1ZeWxq5e3nozarABwwSfDi88e2DT49zaLUZU7z2zLmz/55As2a/Zjok06Nu7E+e3Ds2zWBLiuuWnR9nVDB3zFZo2AV0/aV164e/X10ZgWQDSPXHpj2pX5BRNBBiAOjLqfV5+/ZOleTAsgVq0at+PG1K03x2MFAWLiJ60Vs0+sn4w9RDUQQypXlVccGDLlMAgBRNOQYStL7hy5FbVZOeD5jy7evjBs95Cv2KwM8JVdVaMXtT5qxpApBWJN+aaGUVPnjMSmogSI6wv2Ty2/cn3PP9msiFp1bfGwiqVbCk5gRPiJ6bUOP7i9riRksxjgfrHa/P0TVk16xmbNPszujweMKR5yfcmf2awJ8Mo7hRevbFh4spPNGgFvLW9qHj71zpC/slkD4HMDZ5bMul++5Ls2qwe8uv7S6YVVWzf82Ga1gCuXPzqzY/GJmb+yWQ3gkuEn997dsCKfllEqYM2l2O3ypRumgEkCMeXMtrLNM44u/wublQMeeXThxEtnd5//ic3KKP7qhVvD+m3Y9s82KwV8o+Hc0tvLTx3Ebq4EiKKZDWdHjjixo5PNigD3/7jg/p1xeQP62qwAcMXYvTfyp15dsRv7DSCm3u03etS5+VM62qzZi3k34XTxo839b3/bZk2A1w2ffm5yzdbVkGAagdiyoXj7xZOxxnSbNQBednn/5pZld+9h/1gPRN6OcQc3Hllx0rZZLeCbw7dNHd+w537EZjWA783eVHjl0Y59EBWqgai/NWz+3pU1+T+xWQXgjy8ubZo79tjhN2xWDjh/XVOstXV+jNmsDPDKZZfyjuT1L3vfZqWALw++fbPgYOVQbB5LgJhYMLlp445511ohZQMxYNb1ygfn8sowbwuoyEstG8/VVtRiwMaAGF1+9Myo0ROXQ0hqxopbdWDqjqJ7a479rc2aAG+pGzrx3NClI5jNGgEPXH/r3JkzqyvyMSmAOHd92YXRFQdHvm+zesCDKrYPPzy58NbvbFYL+MC2qqPn7u9+QFMCiI8X771fN3/2VFoqgKiedabfxa3DC9+1WQXgmQPqtxUPqMu/DZkKiMszipeOOXq+vovNygBfL5l79lHrgF1YjUqBqFi/6vyFnZfvYJkvAaJ81+pLh6/drYNkXgTEwpH7jqzdtK3wuzYrAHzh5IUjm8q2H8IeNmZxFRrguGTB2pAsht9eUzFwYqwc24fm5/H9zzRWLtjQsHgkRAsgPmmdu/jmwCWn/8VmjYDPjzj4aEt9XRMW0YbnaX0aPXr7+U1lWLjrgTh+fOONvBP3yhdjfwpE3b3b63c1bzywC2wUiOa5ey4eXzHnkWWzaqp05YAD66cs3P0PNqsAfOLMsJUlK8/PgnBWDsTa0TU7NuZP2YZBVgbE4JpxzQMXx9Zhx1oKxNQH109cXbh1PsQLwAeOLjp+6ciC49h4FwExes3QyZu3DDjZxWYFgA+u/XjE4p2rJ37VZjHAcxfn9S+dP+XqN23W/BxY/a1jsRFHT97vbLMmwANL8iYcPZC3D2tBIxCzS04dHF9zcgo0Eg1AbLo0JHY/f9nNoM3qAedVbo7NbC3tPxt0AGLCpKEj5l48cQ6ztQaI/stPnL1yYv1EDPVqIEacmVD48YLKZZk2qwA86f6Z/dcnXNjrt1k54JnV5/blb6ha+Sc2KwPcb13jhk/2LGr22qwU8NRRyxav2FI8/rs2KwG8YmnLmgl5d/f1tFkR4JpNU4edXDp3NcZgARAnDy2sGbo6bz1t04Eonrf/XM22lXndbdb8LGddhi9dtTT/6pjD0NwAvjexbHpZ5dlFxWAgQJzcOf/g1qa7w6ElaQBi/5mD0yesXPFoC8YDEBtvTpiw+ZPTh96yWS3giuN10xdVXNsEWaDmWZLFT9+Ysaz1ai+bVQMeP2D83DE7h86vAh8FYt2ivLVbN827ha1CORCD+uflP9gxLvaGzcoALz0w+OOSq3NHgIcAzj86dvXW8VUT/slmJYC3DLwzYtidQRP72qwIcPPdk/0PDK7Pg6xQAMTB2I0rF4qaHkCDBbigrvX2pdbyG2cwLzpgGnyyduadoXM3/i+bNQG+Wr+0ZsHm+/v/0WaNgA/NL5h2pil/sM9mDYDrH4y9MOpeU9PPbFYPuKR0ft3UqjVn29msFvC5uwOXxy7Wk8qlBoiV+dtq71SO3rYS+3Qgdo7fP2r99MpxkMIqgJh9ZMiGFeeHLO9ms3LA4zZPyKufWTiuj83KAC89dmbVqEUHCr5ns1LAVTf3HF157PakO2CkQBSc2Fm/Y8alBXvAPoAYOnlr/up9B4f8s80KAD+ourp0y9ITV75vsxjgkVPOzFkz9cEgcKTmZ7BkTpgxuOpWVex7NmsCvKZs3JoNsdWNP7FZI+CiuVeWtgzcsRSyZwMQOzdcqzl08uOVP7ZZPeCGih3VQ6ru3p6KOQHEvGNr627OqmrpY7MawOu3TNpbfbVqHPhqNRCz79+deHn69Cm9bVYB+NqWWdNmTtm7DqOnHIhTRbemD1y39twvbVYGeHPTttixKWePjIFkAUTh+Jqbw86u3QPdFeDhm7YvKFna/9i/2ayIChhbdGj6jIUnfmuzAmrjvOGHxh6dMeW/2ywGeM+U8Q8OT2to/HubNbeHRH9he1ND3c4mZrMmwNP3nG5d2//I4pdt1gh4WdOVef0KZ4/B4GoAYkT/m2N2z99VgD1BPRCXpj8cOerjpl3/aLNawDNuTup3a8OETdAC1ABxddT5qqaTg/ohRzUQVbPyt2w6cnLQmzarAFwzN3/hnBkTTr9rs3LAR+deHlAxcehow2ZlgPvVnr424uqOUbrNSgFPbNlzd/OinaPBDkuAODB6zuKPq7aV/J3NigCf3jvjbOW5O02gewEQlbunLhiw49wQfKkYECvrFt6smjNzxw9s1twO4tGtnXNvl59eD84AeP6CvLm3ZsycsRqcAYhPLs6rb70xv/VHNmsAfHf3oA0rphRU/r3N6gHfurWmbvq52gX/YrNawIt3Dls+7c7Z61Gb1VD627OvnhyyZQWmSDUQ8xadv75uz6jB37ZZBeDllfcHjVx58t4/26wc8Pitp27Or9m+nBR4QLRUVhzY/vDghH+wWSnggdVL5186sPz+fcwIIKY9bL23afGlje/ZrAjwjLw11efWnq79ms0KAJ8qa9oysPTjGf9ksxjgw8OW7CmvmnDkVzZrxr5+ZtP6DRvWDemHrVUTEOdGjNmzoK7fWeiUGoFYunv+yvFlnzyC2qMBiNojix9uLT159i9tVg/48qimh0eazh7XbFYLuG5N3aCDjUNrofCpAWJS88HLt5afOTwWMwKIW5MHTM0rOLWZ2CMQCy+fv36yrHgkbb+AWDnnxKRby0titSAEENdrSksXDFtb9N9tVko5Duw5P3RYbM8PbVYC+O76fQW3Ji/PA6soAmLizvIlu2dtX4ZNQgEQjctub3s0ubYaiFiIqzgvjksWWhuSxemqqsqzR4+e/JbNml/GBm/DqtVrdjRj/98EeMH2Gc0361bld7FZI+ALTXUDB+9buRD7ngYgzuwfNryoqvn2/2GzesBH86dvX7/n1ENms1rA5Tfn7T+/+2ILtnM1QHzSsmhhwZrpt4aAUEBs3rhu7aDC+tXcZhWAJ65v2HPpk6XTf2+zcsAN9xdu7r/u0vT/ZrMywNUPN5Usv19R+pzNSgG3TF60Ys2A2WvetlkJ4AF7txXM3hTrhy1nERCnWsceunT81vG/tlkB4Iez5+wqbOi/D9veGBADSwctmnFz8r3v2Kz5K5BjRkxYsTBvwNq/slkT4AmlG05t2jDmNPQ0jUCM3Hzo1rDVe4/igKcBiHFX6pu23Jt4oQDyOBCn+m04f/jO/dXYadUCsfhi6aQ5pUvPQSCrAeLguRFzqjZNGg9KVgOxeWbVjJK6mofQ+AI+d+pU+Z7SQWNxEgJ4983BN1t2TZnyts3KAO9btnTu5JErrnzFZqWAtx5fUH58esv1dJuVAB5WdeDelbM7dkKhWARE6al7CwqPFK75js0KAK9vnFO/vnjXHszcGBDzGmOTrmxsqRiMzfqfYDTMfdR878iZCz1t1gR43fpTZzbNX135VzZrBDz86OJB686v2PmczRoAny3dNnBqwYW8HjarB3x8eP6KHesP9/u5zWoBH6qZOmbix3OPtbdZDeCTs8YerJo0+8j3bVYN+Hrz1pbaGQO2o8kVQMzfdLRl3oCqm8/arBxw69J9Y3cv3XP5azYrA7xy6b3KReev3t8IAROIllNnrjVf2F7f02YlgOcOPD++aeeumm42KwK8Y+2lgcO2rhjwjzYrALxt+IZ9U5dem/1Dm8UAb5ye39xSP/cW+GnzS9CRFN2MXV136zQ2e01ArBy1497IQ1dHlIJ3AHHyZmn+6jmTz2O9bgBi3o0Lh46s3DbtNZvVA168fn/Blge7t/3SZrWAd6xuLIi1Dl71NZvVAO53t+XerKa5RX9ts2rAy6pOLY8NLluJgyDAZQXVw45vHL4TS2U5EBcLt83a2HRpiGqzMsAFY/v323So4jD2+aVAXJ9fs/HEvBNzcIBRAsSwCRWldy9uWvBLmxUBnnB18J0hV0tmY4cK+HTNtdFXlh4ctw9DAYiFM0suNTdOmAzu1ZyBRkyaN27luKlL/sNmTYA3jd53vW710sOgSyMQj/YNvnKssXX+t2zWALi1X825yrsVh+lADIhj1x4cG1A2fT70V4B3r1268eyYJUUv2awG8JDCCxOPTGiaBmGuGoiHR0bvPDe/pr/XZhWAi4oqxmzfdHnFv9isHPCFWfe25NfeG4wMZdSELUc2XyzbPfo7NisFfGbioMEzt1ybDdm/BIgNUy/WXb03ecofbFaUQfrw25WjlqwrGgX+CcSehaXXzhSvLHndZjHA56ctPn5/x5kD37NZ84uQu69u3tCvasWALjZrAtzvQv3H5eNGHAMzawSibMnuOQfXtc7FrqoBiAe7rs+fcrry6p/arB7whCMPD+1ZV7P4KzarBXx5+cRVc87mTcLpcw0QYyoXTpl/a0zNGzarBjxzw4BJteMqlxg2qwC878iYsqkrJ9wHqykHYtPE+srdew5e+LnNyqiN/Vs2HBxSv/o3NisFPGfn/i0jNu8YfA9UAGLY4N1bPrm3f1KmzYoAPzy0Ye2yukfbfmOzAsDX62flXa28UNjTZjHANxoPH9g8Z+0m8LLmF/DldrS0LB618B5Utk1AHHhYOXzYzB3rsPFrfIGUMgdWHNp9YZ1qswbAh65tOD/y4qkxzGb1gA/f37jz0Y3lH+PMphaIBbtm169asmrKt21WA3jM3pGDSqqn1kN+rAbiwvHi5lE7+j3Et6wAou5E1ar5D9bOwTIBeOC8WcdGH6uf+zublQG+t/nj9SOPDp+EfV0pELVzR7c23h+7BJJPCTVyY97k+m2HbxGDBGLuoOay7aOX3YOKrQCIigGz8y8fHzoS7CUGxKnx66bM2rRmI/QuzThCaJp3acTcpfvG4gihCYjGXfvObG+oWATNYSMQR+6e29Zve/ExfOAGICaN3jnvSsWoRqyI9UBcvV5Vsn/33S3YitUCsffKsOWFh5pmQKtaA8SiA9NWj9k9YTvdHwBi3enVo3YcmDAB1VYAcWlFeeWRgjO1YZuVA35UsWzx2hGLY2/arIwqnbSl7Nipo4Mwn0uBqP6kYOKVu7OOQ5IsAWLy7vVnZzbu2oNtbxEQK68OPr56+o5T+OwFhJh4fs/NVWsf/oPNYh3xxkne9VBfYbo8IMHrCH9zQPW6byOsTgq9x4A9jtA36PKQ2oN7hKcHt76uWDl4VEInKdbXFS+ZGwtFcaWPe0J/KW2kwBriBzkRVTjPpxvxegUX//DEOtSJW7iuwhVRMbRaEZpgXIOBGrqZi5/3I76QJR8n94G1rVgs9gGeN+tdpXWLD3D9zrl4IotSYewv9JfUCiTAg7dM9mHo5XgpXcmegi5UypTJ3iMDARymAGRR0g6Piucy/uywj6vi/WhYdR5fe8lK4UfRNKaozM99wnon/hyYOhUJcFw2FEo4yANczY54YetQRRivb9H5cBrZLQ3CwGEoGknvGlRRMhqWztO4GuXeFp9Cz7xUXL/zCryzizfgg2gkIJ9rh7hK1mqI7ioPhGzCh0NONfIhexAdQnUBevEdQD1pPBjl6fi0AbeqICwFBuRj8BAPZAtPD7fogLBk0fEyA36exgNRnv6KotgK3rIFhBVFY9OpVz/DQ3N0OiB2Dq1WQn+q+HmQe2HwReBBvldUOuhkwvk4DD5KM3EvyYESCXFvlAe4NzvUSSjhNE7fnUeC+OkUSRdKqCO3RGaUB9Gf0F9yL1ffkYYoeUC0uJV7qS2FhUmV+hzLsjBCRaOzi4pjSYv7sthrASaNy6bBJFwoSvaEuIXLSW9IMxxuQhQkh1QIX87nktOXxd4A4dO4D712u/nNiIf7usvR+2rYz31kvcDDfdmRdOBe4z7nk74WhSEdSfc3wl6/TCYf+qc74/91EMfPfVGe7tQr05LRg/SobKwvi/2IHhZR838I+wHitShugVtEOdwBt0Q1ZqbYqURDf4YP9zbsGiAgjrokhBVNj7ic9N2wM3GJqLr0eZdbdHkrZLOfwDxfQJOUxJQCJX1BOb/I+A+1rqcc6D9BKV3JXGeIq0kz4F2/pN0bzlfIZK8TszlK890jSfRumDhGT1xpk4VgHnFPvBzuoQtw7zpcCGzicf4jWY4nwYS83PMl+Y+XiMT6hP4S8d+VtlVkCW9JXtRHsrYPI4rzoakFbjf6yG58GJbmwJxqpPFFGZLd0/EOUHO7B26HF2+eKDE61XkYRDxOVv6epJ03zuUc/E8iDrU8SYk7S6SWgnwtQf0UfGYc/6qDJ2MQEhtM1CdZlpu2QzytvC/Imf+0n2nSyFOtkh0xOsGKBVmQMfBgU4+bPlFFHl6NioeqtHdEby5NsrVEJjSryDSrxzGoCZujKi5nR8Mm7vmbsGVp4oK5R5p7pvSMe9ygxk2YJTXFH90XV7h0aWAghqQljQA9o1NlBC5W4p63jMGXyGIhYdCLFbU7GfYQVVOryYZlhkzoAZ+khB1SH+LhjgSXNrjwA8tYWo8gw0MvvPxU3wnKJ5luU3WYsZZvu7knjsVKlhuNeGTrPWRKJSUTDGqo0Qgjy7pqmKxgGqL9gi58YMTihugQXZBva6/jHjo3RIAgPOlqhxR0fxSYTDE3Vo33aZkwkdld+XJlWE4Zhz63jM4pZbzulkEpLLdcSuG2K/Z4maD4S3IYcWKjeMaOQFeH/5OxUA9WaNjaiVOz81vO2g7iy3fd6ptBenCcoOj3JD9j3YLu+19PNKLAQKtHGmj1kIFRspekOJZZYTzn5RwwB+6Rllk1GFY1e8lkZKZVcU2ympTq/Xcco0cwyZqSStpi1ZLsA3ucPndqg5xvpZATr96ecT7JS3ECd3AwnT7z0w/MSyUxlgqY6NHJfJvWNQhJTn0nSEZ1kmaZ9gXGq5NAS0pATxWjEc0ZsVrSN9XwiMCDeaJ+1hfVUr6oNC+D5nWXE+3NoCa/qBb/otJ6VTfHeOn/r7+oOxEPPvZF9dC/xb+r5nzX/4IJXuVnpmTyPDvi7QRbk1YUfC4kae3FvXIffkIR7AfwQCiTbOrR4pvF6Gm4Tg8OHBvTJGwHHjPXl8JUNfHHaJqhwL+C5ue+byiKOL1J7S3fdwVgf/q00rsXzDDmBFXYmPNlUpJhl1hvMsgdCQCxYMuuoVrvXl2DVkoCN25UG3HI7ERPkNHSICW14ewWtTen6k/Fq8c+LABDClDwhg3S/IZpFSJzPtY7QZOWHIQ8oAP3wpIULEMasFCBwUlPs0MRmAJ4hXWwE6+hsArCjGN3OdX8RDdfvD2nd6bQJLlRplDfDmqh/y0socPVQlAXurBIhKOs13Y+0RXabHi5j2uChV5CVfiqilBzQCRqtQqMLneMMOoZCgLxUtiPn47oG7YcATKkgSZIkwqwKlE4TEqh8RbREkmkC3DjFaZE0rvwgtmSgFY4KOmYxtNnhzWs3M6zd2pFALshmI0PRWS9hqxXI0MIuVGYq/ZI2uK1KxnuwFYOhI74uR8RRu+sZDKr0bDfkTlgjfOdoBcv46k0xzaZYyaQHtBzS8wvlK8hnO7Nd7rHTeqM3J4HorIfVjhNdkcValhzexHkKk+DgTay9qiHIrACgw0Uk3WgZ3g8l5CjYAYEvVPc3jGnd1o34sk6jIKwRO+IBRvc6hFkCbIHtfhAQGlhaWc03h9CUs5Pt3QT3V4x7nF6ZVI5P3Moh+eKGC7RsCZYOMAZ3uWAfKYkH0R399GlnASi2YHdVkijkhitSryRWlB1ox36Vrot0T6PvqZ86eQQzHQIRiNAlfNRT6a/HCWZTOntzkOyAtk7eTYmEY/FiecOjeTBkEQn7Uk60SgziE7OSGNhTZJLi5PLJU/qkEN+ZAR/98cNyUC2DDjm5rxkejJ5Z8C9ZLjVf8indoR+qMgiGT3JKYtj9QFPY9Vu9EwKQdaNXi4hiAVV6BTUETQoaCBoUhDWPoWHgjB7KSwKwqCd8FIQ3hyEj4LYCQs/Bf0IBigYQDBIwSCCaRRMQzCdgukIhigI9ySiHQXbYVOn0msq+ektORC80iabDz+hMJ26dQgH8NNRjpWX5FjhcC2RyTqFQ/jJDLfDz6vh9vj5ZvgZ/LwW7oCf18PP4ueN8HP46Rx+Hj/fJTcTjBvy8ZOR9PjJECm2QFif8AtCkdooeh/2ApevTl0pPhSF2dkXYWdTWgaUz2VVofbielLKF2HFw4xb8aAk7PEkVpSseziWKFSh9YI/CJW/KAKICbgxsMoUZnQ2GVFFTG4sDPlsy8BIypD9iFnYruLtndxEsZTuCXQw9lE0AnlP75ERNhM9xWYKNT/WwReSLIaoYE8vSdZMvXshyVQIWchh/CWkcVsoDSY6d8GTWhvO8PMMskqS2mj22Y3+8Es2OvS0Roe+SKNZ240OPd5o7bMb/R9fstEdn9bojl+k0Vrbje6Y2ui4ZrojdFQKHrl+jyt4dfsWV/Cc9rtcwfteCObPQMWl4CXx61zBE+HXuII3y9/kCh4jv8oVvI7O5AqePXeSDI5LrvcSV6B86ijNtXZwLQUr0k6tgoWB9OKGKLLkg0ZVxMxomMFYsCryKGgQVkdQJywFNcKyqHyvCX1FVJqp0mGFknFdNA5xlzGYrHSAWT5m/D8hG2ufKRt/ScnY87mSsfkUydh8umRsfp5k7EisEITJwQI3Qx0fF2m1gPf/LXFWS4izni8jzmoBzxPibGXhk+IsSxFn00ic1VLEWZWnzZau0FLFWZUHsTF2xVlN1ms8Kc4anyXOap8jznoeE2c1KVsYnyHOHi1sW5wNpHYHJpScnhjOFkdNpUhCIErqahsyr5Yk8/qlhT6NutzbMYKNTWs2THRzq0cGiSuy/2S0W+H+3llPCKdSGsVwMiQJVL/faWdCYjMoBRTpTpfjgueXkk9bCp+QTz3EpD+XPqZLXxqCBmaIJJD2RO/xKDveezP560NizUqSOiEEuPJlnADG0wlw+TECpI4SvyOCak+KoFqyCGpKEdRMEUEvmaqVK1XBsKYXFR/mwCJudoTsOKswEacL1jdikBpcaDlEOo9c42BBr1vcXLYqjbLjlO7DHgkssRgFzhOYo4SKks40CWDJQFxXFYX6HqS0HAcIWAypNJaUAkXRzh3HXa6nBHL1w52n9rpoIW+FiQbIiSiNwbNXFVWWRXv6OJI5SDpfiYYtsAR8MwwNQ1Sr9IfMZ2o5kmmQej6iiyZPTkSVlhykRzWVrLPDkqKkhhT2Il64NvD0JbcgvURev3wrh6tSRPyMOP2z4z4rgkWjUSlPhiCiKNKZhME92WEDuijYX4fTBtGa1+LJ4Uz+RkVeTP+1CPUFzS0iJHmYES1qDkxFwSqlElGd+tREfTQIHNqSfcs2G4vv5tCaXDcwJxF7LBHWe2raS+h9Gy2TyxwRm2QKLYcWbNjms0gM12kUiw+lq0or7jxPgaFGNT7q1OTxqCaPRzV5POL4xIsRKR3pMcfqtzdK39XialJKFEnDMxebU7KU7r9tqmbuE3KDgiMSHQQ1yAAoZjmkzZyIJ4OrUdmDr0WpRx5pWBp6A9c0uFcq07XQCxGGg8Z0vxxr8OHRI2iJENfgYgPeGOBhI4x5/oFTGhUNu6Ee2HH3QF59W/5ki1jM6hH0cBDW4qbr7seEJhoM3rVqSpriuF1ThSy7RgzBcoJebkir+DB/CA0xzvO8oQyyKaJmRzzSqjnOUHS5+vcSrJdcRSHwwp+AYzzcQAlG3LonnVQ+D+WiLs2TpGY2SBhP2AK1/K69TJxsoSWWnK9ohUnWRaUVkxzuSZTiSS0F0hLYmbTeolLyx9prxr0m4HjUsZ2UZOoWFjxV1zGBKV00kMMt2Qy4KPnMkh0bs7I0lwhm6HnHZOrEeOd6BHWy/gq+Q4r5jIjW1TXJL084aaxp0gcDxpqGsabJscZw4EAy19tBU1QWVSviL8Tlomol9CPFL1pGEDzfhT8nvu3uGJLwqktX0GSiaxN9Bpw1SG8j6X6uhdSIKq90aOJD8YFsOhNaTgb5y4BWTePM7aOa6KOK3YESSvNz5l9lqgbULN/Mjng6kR8y7Eo8qM5yuILhqAAMkn65QeeRQnFM5uFEuFtGxIulGkf53mSsz8X6krEk2jnrngnvQVFIwFYfSCEUDPXpxTXuQ7iXxPspTBJGb3K5wf1JJULmpMVQ1CjOYSpsDpMzAk8XxcYpMNluxt4MoQ5ReZrjocskL3EPzg1gnZkOIljoWWzPXmGZUqf9WXHWU+JCT4nr+JQ4/pS4zKfEffMpca8/Ja6zY98TJIPTiCcSqlyPxmmhk01t10io4xK52+fkJhVHmyUQj44beiNTvE8rJvRZDdGoIdrnN6TjZ5TAPeKbcu/9ZE4UXZ/YRuuixgF2uisWVwR/h+pW5aKriFZF2tFT41ti2j3TBELAgkEpRTxqbVVwa23hJP+bXBENAEXDLt+bXBWTm3xv5sD1U1FZn6jI6Y5grKhPVBz/tQzH+kTF5D4ULj/UOyr+INGP3oyK++d+SEBT/9e754RVsqQMD0MkMajkqsoRt+CdE8fNX1ca5nSOGFE6M1S/rpyY0zmi0tEkWGUP8njNOPaCutipvEmnna6fJ+ln1BQTyWo7SYFMzJtbrUTlksAoTlxe6N7o0agKUKJVzSFvvlIgMCAQIL9j31eFsiO1XrIk30ZnZKFJ3amYntydldNTu8OSij1K3WFPdGefmtSdUTNSurNP/b/fnaOOwKnQvp9MjqnScw68g0VdM9+vKudndo4YdP+JM7qvQ/OmmXpjCAwZqw9XROPMaoXYJHkeY4516xY089/knhhLKTlEVoXam4QdFCKbJys3nD2fU7nxqrJ1fmeZC5UbbuUzmNzMOZUboma+W7ncvFLSROUKN2HGnm4oOpUbYgaTBFCFmuO/aLBg3PWaleR6TYf1tcQm1tuVHJFq7v4TO3qf3N8n1BrOxQAvNBrPIpm8XSpw85D7uJ8/O5s/C01HKMI16VCHbDJq0guPTsdX0u6xl5QtcLgucuFVK7c3Z+LlHhmRtBxsSdNyIuk8yOF6PYckj3TyAR/u7mYlt+wiFzbFRc9oJMiD8KgSCVJJQSopKEvqzYM5PK233JZiIMZZCicBT+Gq3FiTQiTOXmSkzrGV1oUWlkInuCrXwgbszidVr4Od9ciI6FS9TtXDGXy43RP9g4t5uLDGnlynLoWf0TrHSWzgINQgEicfHBGhJY2pyYbUlxiSyKQvMSSR054kcjqXpAjmRDrwdN5eUrRDUOHPJMiZzkOUKJTD03k7JKGFxPx0FyQPzci0q6tbMuV1WD1J6UIkJv4f1uVUTZOcQ+VGWMPh2jukJPRTibTTwlFpsqpJF2VD29a7YOZJNYNXmoRnjnFthzp097nUVPVc2mpg2yk+lI76yJtFVN7kw7qaQyOJ5dCtYUrBlWiYjlCgSXB9jUHXTR7gtBwovlVpCp8s4Eu89IyAiqRsTO4JaE/IWSjjCY0tZYqgYrpc4u5lmAyXSm8NmuOSLZRBfhXhYSAueeOaoVTfuw1MtAlyK2dPNCmeye+4tiBU6KvS9j6cAuVGMUMr4K0oaAIZGyEicFrY+rKzvkFUTvEUoJBHI4TiFcmLpC7Z4t4S4v4T/NL1UTlcAbwNW+uO4f+uMgwXAbp4pXtQl05LVM1xr6RENK70kB4miCVFu8NPIHEl1XFYoj7mn0A2ryS5eQk60EZVrtC0lLh+VDAmW1+mPz0ywqpTbgUIm+zUQov7AGHSqYXGWZs+QLQnfYBoj/kA0VADXeAng4nSy55Kni4SlcqvocivoX7ZxoMpkd8FkUueH/z+owazctWPxJ+Tt21ZgkmuIbDzD0PyfravULmawQ3XdzXF6zlx7ZJz8BBydQBe1xmyRkk80p+HlhP2ORp/D6UnGU0TgZxwwMVrdJcPTk2DceVHMFktEkxWiwST1SIeHuB+0nmSnOkjtQiKCgep1GBSShQZDnIfBruPVkYPeebG5dWvczUjHJDHM1z5hhqIqO/EPbVRzy261MO92bRp87gnbRT9J32FlpOmM0VV4EBOrhuWeNQKByt9w3QlrBXCZ9hLjkLC5GyHfMLjElqrRjqmVi3nnSCjFwW40wrD1fCJm8lC7XR49QoYpGFUXmFWJG022tT67zmiPBaLYfBZlJH8+/iknzA/D9CH+X5Qo3vjaLyUhViiXvZ4leKbyVXqJLoEacFxS2PcR8oPfyYLtDPJsylupUNxQ6Ur8dLBUruHg+Bg7wS1z+iVFq8iuVDsX9NsbJLT/YmeeWTPfPG20BsQbnCfzAYH2kH/UJP5cjXIOg1KdsRMCDsk7oTk6WXPqHi3ezB+8eHTj0zOZUz30juku9D/xkIhnw3At5FHujhy3d3J9wIqZ3Lfbzo+Mc3Q39E5BpyuKPCui8PBrQr96Z5BnullTTCPv+r0o0o89PweN7vAEP/NaRumwyDJd7nZBSb/j04Yva67zd7gZhf4FtgxbOOt12z2Gje7wInBrav5ja/b7FVudoG3hKLiCbN/bbNO3OwCtwxDVu/c/YrNXuJmF7h/WN84sULDZt3sAjcTSz7esAcW2gLUWHgzFPuUaEijGwHA/QiKJFwtxweDqiYU8nNjQTjURQ230zrLdPJzWkIVSsifQGki5KDa8VBBuD1v10X9MNyOt/8BpqmZyX4Y9ovGhm0P9LAlNhwdMM8Me8Xl41PnmWFD1J0ZM90MB8SmG9smmOGguLVp/wMznCaqlXA61btPiSaqgnvnCLUXfUDJ1uNJDB5wkwRkEuPxJEGe5iZJk0nwECU3GkkP0nleR5LKfBgHPhxCh7gPh9AQxaDLARUwOHzSqY+Pp4kYVmF4fPHLceJPMrtr0mDuKl366s7VHGFK7/E6cUwPXieQDl93b+kzqWhi8hRFkcORTr8xfBsU2mLDz6IjMXXEb76hehwN1Oe/A1MfewcWePIdWMQHycTxJ0k7UnqrFOB+Z1XgfohP9LAnwJnzOCKAA/Cg+/qIOa8IApxFyQF7kDYhFMiNkuf4Twmo8GCLX5F8JwjfRuE0OMymKSWPXsBkO0IeZvQGpFPqExAeib9DeymSxlnIlC8dOrX1BCQz5QlIpyhP49iX4gWI88oovYf73IeKzHSWO1nmqxGd+8n/lrywzLIjPu4X70cj0lsmXgHITPGjK3ISQfc/FNLOSDjbfdmmYFyyKNcdxSmEU93/xOM13Xm8psn8mqxeg4N4LRtRoT+XTsNh4n6ie5in0O2FmgLneFKC9S4oT75197Fdp9THdu47L498gJbyMCQgsYHEqxDofbjqP2dI89A8O6Il8+M2bzOoaZlMdf7TDFXV1Tb+s/DHQ0LPUaVb0PCTTI+TBFW0GjhKUEN/AlDDiEnDz7dVOC5WRYe+X1cWTe38LSWdQL2vSM9xMM8QxkrCtCdMIAnTjjChJAzOOQJ0lNPrlzixZFB9PaCBqovcqLhNQUrDxEfkpusooeBPXQ3YFPlQ+ZbyLBUdLxe3WbRvqB2+rVhwJqh0UWwVmpmjDcq3VSXgS0Y1qkB5k1FnCJWS8SihPMmoCkLh/F/E5G4oEfcJ1UO0pXNM6Ngw1rgaCpDbdeVbSkdJ2udSSfvCE6T9kydI+9ITpM14grQvck089wVI+1ybpH0uQdqXU0n7FUnar3xbsfzCF3ZeF0BLIfKgq4mp8tZPghabQAscdkORQ69L1G8pQcydbykBrskz7fu66nGUlrQhB6OpxrBMlkEIG1Olk/lvqGkRnbwPM1KSuR53waO9YSOg++M3MwFAEZs4CDcoPYnUDCI1jooMR6Q25Fs5Ky44W8kitZUsUlvJIrXBdXmJwkMitdk1iCtR3CMPog1uJaVEkWF5HwUecCEuouFRahzjatjiOt30sXD04j6tMfAGw5JvMEgb5hymWqLnO4RiEoU7ZmDl4nc9MqJh8hJvZKN0Fq9R+ikDezSzpdsDEt4t6XTTyKbzWFBEj9M+Iawz5ude+SbPGxfWdexapLAupc2w9L4rXWhCIQixWXfEddwttmQnDPLaZ0rBVt7dpUj6lMBLuRbtwc2AJFFclhnROesOgpFM3laZpEmkxkitQCbTHbEXtPFX66rlmODXXR6LO9uk6Pi6okQMWbt8GkW+xfrSxViUYNDrYtySNZ3xQ0uslTRsjWpBT/IMLD8Rr4jRBUOvqMbTG5y3kR8VqZzQu5TGYjHTJneNHqH1kW7oIKtnMp0kf2w7DHleSN+KSjW4CSrJo1S6jUepDJkKNzTgO+obihphbkqDRgR0FKqcIc42wyJ5RFA7wj7pGIymHBMGBi3LVNSwD+NH7m/p4JhupzlZaY7A8/g3FCUsnSxjRBI55fhkNFLRHoVe9lIC2ii2UQJdFzNEKEe+yfTTRwdlvqHqVDw3BHcivSmRdNjs/baaxnXuxyKmQ+azUklOx65tU1ohymguZfzuaKCTPPhMkg4VDf8VjfniSmIjSUlMOhiTnE/LG9Z6xMLUcHb80sO13Hp6wIx85GfePZi0cCxNB5Me7Dadw0UXG3CxgWRskLBSUE6TZ5VhH0+TJ5VhcqlOG3o3QxZz2wU5BFVYfbiPjjrxwt096sSDeYQdTa9UIHPZMzQ8LazK09c0uvgKvkfnrJ6kc1YX63ex/mQsdUeVDVd5GjXXhD0DCqVRw6mnPtzJlw23eBoaZ1JzVec0FhjVaTr6pPfGu2e4gXeuk0rRFk+OI5gur2hWJN0mf7Hp8sJryLnvyj3xQzUQKw480lTNYRgs9WM7K1dI6ihjsRjPoR25GvqanLg6rZdgi1hyNGIXVpRrUeGBc6j4Mqs4z0mgIe4b0d1jTVWoYu6w9dD1uvngY5Nr2SGYUQiwzyt8/jDxnyo86I5XRZTJJGqci3Z1XHORyk53NcVEDT1ODT1ODQ3UkC6rtP8MIZ7aTtmMp5f7GTT4nHL9dM0f3lL9A3SV5eKrxg/DMA+cFUNzVOOKVL+qUeeSnUYHTXHVOEtVjauue1yWUI3TTXPH6y0ELLn+aEm6cXlfS5M6cZakE2dJOnEsWB/kRLRQhlQDR91rHNQD6fRLSdGJq65OHFkfb0s8Ew2IuAdjNfTV9CccGKuk69VcB8aao+tVk3S9qmT4hIqocQfGakLXq8YdGKuPOTBWSdfbhjNelbTy0hlvvN3JXXxcwZ7elqdeWUpJailu79P9/gKwASksSLeM9EKMxBCpXA5LWSNsYK39T/+v0g5bXjcjhJLJfkBPgoGle60RNfRab+C/1ztihl5D6K3eXCU8gO/25mY8pnNyzBvJMa8nx7yWHPPN5JhXk2Myk2M6Jcfw5JiXkmM6Jsd0cGNwAY58sXNTnoTJroZ6y/NF54imxn3RRfcfHOAaU41cSM40JHe1/oErIoT/e2RElOx36HIDzAnKO3Uq7tRFtCg8CdNdJYWs3bhzGRKyzqX0J81QqLjtZ9BSjvw6V8EWlGxyHE6HSHT0hepIaZEdUbMhOOMOmNysQNagW2DOtSoMJJ2OPRN3w7DvMFLvxTGuPpaIcTX0PIlQmBq4IKY7jqq5GnUmJXEj1alapfMw6BXpEJI4QEozNCzB5Fc67i8aD14cTuSe/2ih5/3SkTRecPj7aaqaK+e/SgoooYgjBlfF1b/pnoG84oosQ2h9Mlnhos4cnnYBr1Tbxh9R2sb/Rdvo59tG+9tGm22jtbbRn9HCJ9FC64NbtQRBJ4Y9r+QRcrwqUjWuiEEmHXQXLqoGS8PHiZVrvUUsVpkbFbGiytzuEZYhmdQFGksZTgFOgYKDK4uq3Kh4sXsGV+lwebBGp7XYerCEDOIyZHr54K4SdIeYK6IDZgXpHzPobFeN75/ltluVnJWWG0uu5x2gG8RTqAy6wQRmpkYY0IzQKE3NoJN4lK66BdMLOHl0ilsPcmoRJ4Deq3kY8f5MBoUelusQVHuQDeAlnLOgRu+J6MSAzH+QtAAwxJlzK0ayByuikzrSbbnurAnOMiQ+okXo9zm08qQ71/A7ylkSkpOfSdsmVFtHvyRQIqKDE5Hud4sdTcWiKyh2dLHwX2RkuoVrwugT3zHHb36BbcE+Az2Gopc5jWpvOtrHpcIb8Rc2EEbcq1oqPRVq3HYJF1okD4LbunjRsNCSxjQFm31sSpj4x2iaxlTmIMJks4WRwvPPu0XikgpdXDXoArJzGIqLQGgkKl3QxfdRxIMLKPIMC1WhjT+APRjv24Rzb+/jFlGYNJ6z6brrO/JICtux+AURlCvc8+jHStLiJeGKhO/XiZtJzuWb3CjXu4dd9VL8acQOh9djFQePnzdJd1iO1SsiVc+ZbOdQIfy4fOTvE8G9Eg0HqL3oJynajWGfGaNGv2RMEgKSQDcMzO4QJSkqwsTOoRBwDEwcJHu2b5iJVkMOGq6Jl+luFzjvs33DOqYOqXPoKoajXXE13iK3d1QuLmo2ybG5UZJ5FWnwRtwtkVefsCRh+FEe1+6N1PqmELM9V8TNtS4xvQliVg55KjGd6Dgxo58Vo37ZmCRE28SsHBInJpIRMcd54sSM/NcRs6H484m5hqm+XBzdQ9XqPF8w6bRI2ghDm7rSlTGdbqPp8oAUsw8aQk3odMyfQbo3aJN8riYKHM4r1Bzav5v/F3vvHR9VtfVxf9c5+8xMMjMwMAgDhJlJjEiZQBpJAEVGpdjA3hUCBKVNIAkoNoK9Ye8VLDPq2HtBxd4Ve1fw6tVcvZart+nVvHufMwmR63Of57n3+eN9P59XzGdmzzlnn73X77f22XuttdfxIgB86Yh7B+MLt0wguVnVV7ibXiNL2pJh72tgfjq2pC1Z3NJSWpx0TCY/Jxkq7Bp0zZTBguff5w5GZkOh3bbAXFBa7L2o0mqb73nKCjZLy/j+3Si2BWV22Mst5iWfMed6m46M8c3x/C/+7r08rrPDnWibnRZf9UjxYNxtL1oiKwqe53C5sVGE5rv7DYPJUMSfDHs/SGH97jnXzH6IsHGeuJHkYfMUsXs4C91Mf8FkqEfGr2Ivqqu42/fi83YAmVeXz9u0VyPUnSEsfaKbJicQOcId2SYVth1sqvFXvsWCddmLhwi7dp1NHrOwaxM1jTBfA2VufkGfm7nFbZBra/O7KQJCSV+yuCsXo/vVa6YTObQsVNjB4t+UsSAZ7nZiqn9fmr7/X5r/hTRvd6fi9n/0J65tsbVMRWqTTqtrPWktcyK1rp2kteuIMSS3Jp3uI5GeR/r1PBLreaSk55FkzyPlPY8M63kk1fNIZc8jtT2PNPQ8sk3XERON5WZuKOyhLcSXTWz1wj2Dz1iiNi3IAstcTqUT7sLexARNd1cmQ5P2YWXWgmVmF4+VlraW9ITW0oLvyN2KbplNEFb3hNFMUromYJI0G/dcg4tnOXenB2Y3e8FGgLtTpHB6UrW4M7XeXdHUZtlc5q6rzIKwK+uDO88iPdC0ZtkCE3A6eXCba0RSngNELXEXghFvu6mdljYzuzOz3x5VBYPeFjnba0rBeOEuBmKbjBe2O1H18gwUOqD+qQPBnPtMcZcqtnmmqILzyu7xTDHuPZ/LdL8be5NW7surC48XdxYWKOiWaw5TruvLe8york21hR0T3mOm2HvMuBsf0hF3Due4uzjV/LJQ4TFju4+ZkPe18JhxWkyQt20eMyYjkzKPGdUjwMxU4D1mlPeYcVqMHb3rMeP0eMyIu0XPPG0WFPbiuQkKApt01eetkZykMjp6R4+ZjButeXbXTMaZ3z2TWX/mP89k7E1zj8Lh35iV/M+O9Pjht+cr68/snq+Y09z5ygNW93yl3//dfOUvV/3385XrLUu68y1s8ny5URfuKLxLWLx8Zq4/TXYzAcsemZUXdelu5SskP4sVSl7is0g6e/w6XA9J0vYSqnVd6fJfFbIY/tfXn3TC5tfL/+r6R/719f+i5ZtdmbO8QCDP2p8uLrU9265yFVGlvzAOEPdn5WqJMTcYzE0azaRTZtbd57xMpswxHwvNHkrXR/UpmbJAWtpG882aieNNtFv6uPmuKnzKQs/J2fOonT7Gi4HtvPkTtYsBseA2K3JJ4xmqvFsbd749392K3n0nfrPWQpYMl0jePNCrYIVbAWlpq+Bb1wrvWcfFNWO43UsXe3Zxu8ssbgfXWt2vMnd+HauSlJ55NgzHurNc4E5FN091IaVu9ATdCTl8m+ficN0om9JpmH3wZb5f5eKwfnWC71e5OKx/uvhXuTi8FJfdSTR+o42uhb87VcY/HSs4m7xohBLXNe1mfEnL5CUeVOanUoIFb1OS4PWW+FYUzMZdphzxdmvZBZtAuj3ibUozP6TNekHczVuDzSrZHUY8CyqF8LGCszotkTqvYuVaN5TZQq667+FtVt5uSbp9hRm9zc5wb3uSdzujHOMwA4gvvU5axoGX1CW0zN19X7jOu6Cz096l6wozVvi8C/3pDd0Xusds75jlHvNq8M6UdN9l6cXuud6eXZd6bpz4fPd5HvxRpKgws9j0L+1ORkJldsHrtMBsPU7auwzu2iJkJ9VubrIIcWMg3NDUsJuLxuelnXCTnfm9m9oLvPxXyss3gvHQBtxN6uLOBNz9sF6sgZlHBMqK3SBd4xmwPekmvUdkUc9S6zi8lY9xK8wvPNHkt59oVvrUM7oTShSnLykU/ipirei26YmXxNUqeN/sTVG3JgAy6c5QXGOuWYh5zlkz/br5rEIsWyHesWtClHQTW7h7y6yk463obO8RYwzChU267i4PbxNP0vh3rijU5qWdEBOXZ3XN4/HC6ayuCy1vvu9N7t2FoGHbi5uy4pniB6u6K7TSZ5radyT4sngji1nOhMqNHzg0383xXZwMRvxekR7LGX9EuQGDIUMKNyoyZJYz4R7LmaCXWSPYY/HhZqA3qRm7ljPe2qTMRCbP27ReCXYHoxaWMz5vOVPYsm33qFEKj2gvqC/krSuS/n+jP33+X92fL8WyvJlEZcEl7rmK3dXaFC/yIeQ+KjBJqayuWPHypJUun58+8+x1XoIW15vlKlGk3yDPGZoqczPcWklruAmoNE9r8dJiS9LkpyrsZjFeDc9HONy4fpPSHY7uWXwjxjRcbwciS62J6ZA7NzYpKFQ6FFnkkW2YFfFqtYbbATccIH3mueuo6zboJy03KDP4unR7/23z3DMZwIwuuQ3vGrztYVbIS+JXsISKl3ylx1PHndSrHo+sbjf6ZieqHo+uTXuS3c3Fm1XX8yHmTRXdTVOOh6TTFf+szGDrhuG503jTqbekO870V8/utEQmGSNyL1sJ4uYt+GJVusxngtDstJtJan6d+ckc+WpVepKbOs179A2z+vWxjZIrE8ToJG0TuehmoTbpvx1XzJHCdYX8bMOsb1elveu8rONuNTGv6NEp6UT2LhjizW1XdN3ehFRuWJXuGrMkMpsgwevFW4h2TbE3rZoK0T3iZec36zvxtg9KwfrmujvM2spvBns3teT8wmsy3MSWPpMBJbZkmbtTwcsQ5MacF5Y8wcIaL4mXetIzX/d2HzDujjx/0kr/Y5U3st/kKZDJZZVWhbQdzi1J+5bjygIFc3x3IrNkIOdFmbQfmVQLdvunFGfJgu/CScpC92aH9thglDSLRPNuBzepsZcrJ1xYe3oRHO7DyA0OTAfMYZP92UvU6j2g/j/V1quEX99K0uLthLZ2c/cb2yZCwp3ULkubVDXDzHvn3IyO7iZuD+t2Nz1Tun/XKcxvdX/YdEJsvuccSp9aCBFyJ8uXdBWstJW+oatgp+30/YXC1ZvGEmVCSwpjSdKOWD2tYd0jsJmtuw5E8xHythEobzefa7uwjD3C/cEpTA3Mnm6zJC+Yv9yvbo1lvsih3hjozYE2ZaASbx+qOy5cKJ4hsWtO77mkxY13MB12rzQpDb01kRfw4Fnw2sosdxE5uMwy20yNakwe7PqaPY+qAbXm10koh5m3HQyzQl6t7ozVa1YX6oXpcvCMTc2y/8tm2f99s4zXyP1mHmnVhfHSa4wZzN1h3KvLFfKmxrgeqcLYeYkZYzYtEMXzpblLxPTWXnCdazMSE4uK57YkvdKK9LUKfrFwmZ1eKV5mHTfjsLjxh26CY2/SbJnTQmXuSy9Y4AqpO/LK3Szp3qqPwl0KdK3ITvk/FlNk3L8pohOly1bFNG+HeCFP428NBpIWdySQTdsixQ0vNCOBGaXdvc/er2aR2j0aiDcaiBkNvFjd7tHADVg6v3uakvzvpyklm6Yp/Yxz+19PU0rKCpMMa7jVz0xTAptPU35jOuFGvp+6aQgQt0keFF0RZZO8WDE3KtyNPPbiMSxvJ/N1pkkuaN5m1goCJoF+UaTSXbF35cXsipyS9Lp/ccHCng1xHc6/ooVHCs966NLCZClxlUa6GWHmYq4UXUYUZmbdjHAT4BXes7Xgf30z9W/f67D/0b1+zfJ/5z4zC8sz9/Hhbv2wl6SZMr/MmmiSN62Y4u0BTnv7lsxZJvfcYnfPkflugnnVgsmD3XV6IXakO77HELj7Bl2b893IPvVPGaHomr4rNw2ZyTxmEiv1/m+zQR3ohvV0BY0YlnvbMRZ0xQ5ZPc0J7sDjZVTzFnxGmvb8TTs7ey7cp/UwNrrzZs+EaHtmQbtgz1OuMdmzytmuXEIFq9zLxp5ouzEnxir3m9UFkgWbe8/qQr+qrsvI9/Upv6puT7G6rMcMs35Y5cYvzq8z31w8zbzRy0VSYWaZbkCjN+H0rvhgVdo933xa7vgUnGKCRF0zguXl1rbdxUegx+Ij0LX4KARZeaGY/zin5zgR3EZkhfFsmc0lC9MqsmupSS/lFb2Ewb925CVD3UvJ0v/kYhMyLys2k3Bkk4RlvJR4MTlGtkbOJljGTvebH9md4DhzY7Nh2+m6sQm494r/dONgIWVsUbI4UvHvX5oK1rpj+jxPcQsDe1IWeJYSXOe7uzaY76YoCyald5DgxK5Rd5N3UTaNkZGDewc9750UfgoZzetSDhXpFxzrPlrdlACTC2Y36d6nnZbe3Zv7e3sqobp2jwRHuZpaaG9hvE7aC7yUDW44VORQzx9rm5amukhfGMoYXngweKN70qp3ByM1P7KY4Jbdz36rvBDL1O2MMvHNkWaCcS+MDy81pEztUmJvhR+M0z0IKMPyT1elTUKNJIP72BBMUMgf1d5+ZMuvhOddXtKz9kmb1T3AS7pe5O3ZIjLQCCciwX5dULlALSIYaSLYp2B2aDN53F2F7UsPe0TSMjUOcuOYh1s/nD2x3nO4Dbe+Mt/pPvRpj0MfeIf681s7g4K9e9As4hAMd5cjxb2D0d/Q00iBNgVaHLz5D9FgsKux6d7BYq9/bYO8W3mTC3OsX7AP6SvOXUd6y/Tt567DdLYX6SfNO7RGJt1ROlSoJx2ITA8G2ZKkscNs6R4wIaHey+OKvDvs29t8MxLeo+tKkxvHpuvnBRQakw5EDgj6SRLZC+9zG4ImW3paxrH23LRboxfTFzDfApFdgw6RZQRtegcNISyCwWdWieC+gyq4dpWEZzUdNi+TnJBsysxJDltnJyck10WGJ484vCmTbF04b/a8zGHJmetk5jr6tLY1trQlZy1va0rOy8xpOjK5rn9yXmuyeWlbsnluclbz0sycVvPNO723qfB/evJv1D3UnJ5pbks2Jmcf3tjiXdLYsnx8cl6bOTQv0zpvTlNyXSA5zFzWmlznG/4v7v6fVdffiKKrLretrcnGtuS6cHLW0jYjOq9I4Yxf9XJcsu3wpuTCpoy5zbq+7hXmF+9U8xt9WxozhzV5FXc1uKyrFu/Y3OaWpNeI5rmmssPaDk+uI+IdNJ3931w2umVpa9vs0WPGNlTW11XVNVXNbaweU1tZX107a+6sxtlVtXPqx9bXzalvqm2saairHL1w3qyWxpblo2c3tzSNbm2ZPdqtcrR7y1EtrZifZs1uWb64zZT+3dpb2+Z4lS9vHd12eEtT45wZC5tnNy4cnWme4ZVb/5PqGxcubJ7t3aCt5T+pqFsKSzPzZjfPaRq9uGVepq1x1sKm/5vmtTQeMWNZ0+zRi5rndFc4ek7T4tbRcxYuck+rqBxVPaqqyj276zf31LZFi0cfMWvpvIVzRs9a2Dx7wSLTPHPWwnmzzAlDZzcvXj5jbkvzohkuhOOSrc1LW2Y3FThSIMiwdSOHJ+c0N3kKs6ixbfbhyTlNrW3zMo1t85ozm58sw4E/S4TpwD9sqOlR9iv3Baz8zo5QDEwABvUoVwN9epRrNivHgK17lM2x8s3KPc+/FBi5WXlCj/L5mx0/f7Pj7wP9NyvX9Ch/sNn9PtT3GAhMsiKEgf2AeI/ytM3OPxoo6VFeCgwAXrIjJIHvgLGblaf2KO8j3v1mz5rd/dvvgaE9zvm00Kau8g9Aokf5W2DLHuVvCm3uKn8F5m1y3eVHN6v/DIGiHuVTCxh1lY8DRvUoH7PZ8UWbHV+w2fHtNytvA4zoUa7f7PjWhb//joNd5w8TXNl3lQeJh0lXuf9vlHv2t18Bg3RbW9OixW1Nc5Jtzcl5mXlt8xoXzjuqKekNVhXu4JU84vB5C5sKD5lZTeZxOqelefHipjmwlxXh0EL/DVcsIAQowC58Onj/BQpln+lPoWzaZPoRKRwLFq43dfUycj7khbdSu385+8h0dr8lg894YItrDt6ndh//G3sdecvKPb699q+Nra1NLa5Gz22ct7Bpzrjk4lbT/gnbJt3PkclF8zIzmpc1tRze1DgHrrEjLg53KO/e/9X122y6vvHI37j+PuW1sWe/em/Wf9On2Y2LG2fPa1ueNFXMXdh8BLCHHWH3gg4Z2dxpRVz9uEpgux7lq8XjwA1ukg0llsU6ZSkpskR+8r8ijlRViIgJyJeUBH3FQZEtpXiLiDQFpFhUqYjEBx8UHBdUYkksNtJOid9yioaPqzf1BZTY/mIrLL1lnCgVkH7WILGssaIsZVm2LXHLlqApO2Ip6Wf194mMMzdTAfFbQSsu20pIqsWWepMLxBZlfh3inuMTx+pnlVhjLa/mITJV/LbIgWL5g0WzxCoqEvHvZA1284XU9xJHLCco5UUyV4lPLMsaaCk7osJiWT7pLYcKNvYQK27FrYmW+ANiBW1xpMKpkbRVJstsZRWJz37fsmSciN/UagV8xZZsLYFRVqifqhRxzP/DikJWUlmW2A0ili1ijwtY1kTfnraExW9ublvV24WlrAkRO2iHzT8r5ASsYiNqp8qRYpGwRMI1pVhL7GOlt8xMjhakXNmOI4f4DrERfMgsZc4sUbtbvcqmyQB7rrKtgNiiZKDlyMXWoL5hGRoYGBxlV4oReR/ZwtreZ1mWFZKAbClBqRGxLcuxRAZLL/dzjlNkLxdLjKfs/D5EbAlFysSSG8UvPgnKliIyWiqsHWQLG/lELnTwyQgpwhbLUcNsJddbqKEyyBHBikmtb3KwSh0tdb2Hi2UV21XKskztxVJtlzsSmCAhq7aoWFkyw7ZELJ9YcqXYgS2ssZYtW/ltZ7Iv7GLYy9onYMQ5QPb2iShLrK+VzxJrsPldxJov7uXSZD6VOBSJhSGTnKMsS0myeJjPUgEp8ln2qLBYT4vYIs+Zbsme/S2/Ef5RPtvw3S8y1dxK0ianm1XrOGLuSx9fb0K2jTPeX6QwBlTZrs9ES6ywhRK/ez/FKGsAdlHE8sfVBTb1qjogvaS/I73xSx/3Po4zoVRW90K2VTi/WN+bW1qOFAn+RQEcJD6z/VtYjJ9uBZwarDSK57d8Rn5beopXVCQxJUViizPSHl80IibJnqpnVMUIIyBFUvw/Uj6DfED8drEVl/EiEpIiGfZbCmiJ2IWyUcJNdzGKqFzeB2QPsfyhgFHG4P9CFbFRJa4qdilikVTYIkv/pRpWekpYrOyhngL6CurnNAQs65Ieyvf0ROSJUuxVMjOJb56Brjhp7W7xG6ojkioojyXlskO38ozuoTojrIB8XVAZIpEIVkExfqUSaQdrt99QBr9s20MRRAKeKqieiiAFJHr5bWcHl+RG/MruqRR7dP1uifWlWP+sGoGeivFnKVb/iWIU1MIeIMgmpUC2U3tI0MJylcCxfYHAv1KDObLa7yqB2qQE+Bf5MfS/yTxixBkgCXlClFpvbem34s5MGWHAUGJaZ1rST/aSMwJZGVo0nKFm/LVGimJfy0fQGWnZE9dNxPJZQy2fFSgkj0pYY0xHbFv8trJ8KuzY4m9zR9wmQ91iw3Wfd64SnxJLOY6SPknbopbPfMq2Qu6dGNU3fuJcW8lu/XxiM1J6+XbHH3IY5BvE7jPLVXlbUHqbcUEKnxhGmtGyJlxzSP/i/kxkYnKgNZB9ZIrRKbdnIZPbStKirID4pcQIQcT2C9ZAGSiJzf4CFEs/n48J7Gju6xtEZVV1Te2YuvqGsY2zZs9pmnvQqFGjDpnduHBh05zkzOmLzWRk3LilmSNaGhcPGz4z2ZxJNiZnTmvONM1MLmtcuLSpsrKyqrK6sqaytnJMZV1lfWVD5diqyqqqquqqmqraqjFVdVX1VQ1VY6srq6uqq6trqmurx1TXVddXN1SPramsqaqprqmpqa0ZU1NXU1/TUDO2trK2qra6tqa2tnZMbV1tfW1D7dgxlWOqxlSPqRlTO2bMmLox9WMaxoytq6yrqquuq6mrrRtTV1dXX9dQN7a+sr6qvrq+pr62fkx9XX19fUP92IbKhqqG6oaahtqGMQ11DfUNDQ1jx1aOrRpbPbZmbO3YMWPrxtaPbRg7FpchynL8fisQKLKKnaDVS0Wkj9XXiZb0ky2sAdbAcIkzJJCQcpmvFlgZ6zb7Dmut9Yr1qvVG6M2it6y3rfdkg7PR+r18rr6wvk5+q/5m/ej8JKGtx0+YNv3sq666+ugzzr/omjsfPPkOn7+obtsJ+36//lXVL1ZXv+9+K2669baHx2zoe8ppZ12lwr369B1eVTtu0uSddp42fU7T6WeuOvvex5548ukX3jr43vsGl/gDxcF+A+rGjrvhxnfeLao/59wb/MXjJ8ydd/Z5fZpnPPr1NwfOeuDBH/7Rudfel10+avTWw/a5cvWaa6/L3XDLg2uf9AVDWwwZt92kPbK5F19a7R84qGyrCdt9/PlX33Q+9bRKbrnV0GE1DeOm7rzr7nvts+/+Bx586MzZTXMXtB557IrTr7vpttvXrb/1tkzzMx9+dP6hZUc7tqqw59oyelT78UPsqt4lqrwo7ox0dlS9RrTf5CtX5WpYoDY4za4Nrawv6l8ciI2fNNaeHSiq7O+U2oOdwUMb1C7OaFXsL/JPTG6tQkV19jhnkF+F/LvvVF8TrvGPChT7htpDAlb7+j0jvkBR6ajAiP6Dhpb0G1A0TZUX7Rge6C/2TQ1sXbQ0uP2EEb7xTrFvD584EdsJHhCwhsyKTw0Ut2cPLZsULPaFo+N8xb5+JSk1oP2BbebsFZpaVDx50uCpgb3CO/mL9w3sX9ar2De5eIg9Zad6u1eg2DfWX+xLqeL2vwz0j7eH7CO9q8MnXD53abD9ydN3nR0+sTLSv/jsy53jp1z2wLiTnz5+rH+EOtg3tHhy8TAnuvL2ce1PH+TUpnZRY/19JjpBf9EVbw1PNrau+XllTW8Z4uulAivPPE0tcMJ2kT9y9sz2n+2Vd/sG9O7Te0pR2zbtfyluDSzeYvJR/UL9QvsVDWw/ZeUU+6Tte29x4u4Jn6/9zZFmRrC4wh6krJUTE33GObJy/Yj2t0YqaV9XF7DaN7T/dfiuqlhZJ/TZcddt2x/fxidqH2dwrbWyV0rNCe1b3H5rw5BwShX5rV6+9stOeNfuY4ft9vd8ISW9Q6ohUOwbFkgpq1fR1iv3Dwyxg/Z4FVfF/vafTnQQ23F8PsvvC/iL+hSXBAeGBoYjvUK9VcTu2zda1F8GqJgMtAcVDZYSK9E/aY+0KoKjpFJVWdVyo3WTygd+tH5yfrE7i245cvkZq66p3G//M848p+TDXr132fWnf4wavd3Bh8z45MRVZ5173o13PPjQU08/9/xHn37WiXJVon7c+G132vmQE88678Y77n7woaeff/mVTz+jW2HGG405dE7TiedefuVzL78S7jN83PhJO+130KEz5jStOvfGux986KnnPv70s2/DfSbtNKep/cQ71z7y6Jtvf/vdCSedcV32kUefeuaV996fesnDLz398is7TZu+3wGHzjjtrLPvuPe+Rx97+pm3+/QfcNDBf/nrL53ti5Z89HGvRKa5ZMiMY4+79baPPt7tobX9B8QTk6dMm26U5rgV9zz1xpsffPvdn1taz25bOnTU6Nxt9z36zCtvf3wZEy++pPLsxMud06YfeJA/0Duy9eivv8k012+73faT9jps6bPPrX/1nXc//6WT5Iyy4z9Wx+8YGKx8fVbe3Ks97yT9K0vsgQFRo1Wt8tvi9/n7FO/eu69/H7+tSoqL7IDtty3btkPKsYM+6bWFM80/2L+f33Jiod3VDnaFLaqPr3donBqy1YzkIjV/q/ZnneNvtwf5jv/ZPsDfvyhWZCg331fsG+Q7wD/SmVycUiEldlUwpQb5gnb7zb5i36792n8IbGP3tic4DYGRzvGdfWKB0X0q7LJepb3bz1THX9Y/uMWpFzijnfF+q1esqP2RsrZQ+1uDVkac9o1Ff7rKri9aeXC/9vsD7R/228Yu9jUEJgdCvrZg3D5QHVDUfkKspLh/0a6q/XRf/rrQAFW1Rq18b6g/5DjH35Uc4dtVta9S7Y/Yg+3eYX6yIuxTWLMbG1RX2djD6oB1wAbgMb223gg8DnwCPGHsRcCTBdvOU8BnwNMF288zwOfAs8AXwHNAB/A88AfgBeBL4MWCbecl4I/Ay8DXwCsFG9D6gm3o1YLd6TXgT8DrwPfAGwVb0pvGtgK8BfwFeAf4G/Au8HfgPeDHgu3sp4LN7B8FW9nPwEfAL2bxLyACloAtoAQcAZ+AXyBg7C7GViMQFBNyDmGBXgK9BSICfQT6CkTFs8tsIZ69ZoBAzNhpxLPvDDY2HYEhAnGBhEBSoFSgTGBLgXKBrQSGCmwtnp1ouMAIgZECKYEKgVECowUqxXRiItXG1iBQKzBGoE6gXmCswDjBpFdkG4FtBSYYO4XARIG0wPYCOwjsKDBJYLLAFIGpAjsL7CKwq8BuAtMEpgvsLrCHwJ4CewnsLZ5dcF+B/QT2FzhA4ECBgwQOFjhE4FCBGQIzBRoFZgnMFpgj0CQwV+AwgcMF5gnMF1ggsFBgkUBGoFlgscASgRaBVoE2gaUCywSOEDhSoFNzYbnAUQJHCxwjcKzAcRrzlabdFhyvcT9B436ixv0kjfvJpi8WnKKxP1Vjf5rpkwWnm35Znt3xTI3/Wbo/H2oOnG36acE5pp8WnKu5cJ7mwvmmjxZcYPpnwUWmPxZcbPpmwSWaF5dqPlxh+mPBlaYvlmczWmrBas2LNZoX12heXKt5cZ3mxfWaF1mNd7sFOTF2JbjRtN+CmzUvbtG8uNW024LbNDduF7hDc/d0C+7U/D3Dgrs0R+7WHLlHc+RezZH7THstuF9z5QHNkbWaIw9rLjypefC05sNThc9nBJ7TnHi28Pm85sSLmhsvFD5f0tx4WXPjFc2N9Zobr2puvKZl/LqW5xuaG29qOb6lZfi25sY7Wmbvam68p2X2vubGB5oXH2lefKxltkHzYqPmxSdabr/TvPhU8+IzzYvfa158rmX3hZZjh+bFHzQvvtS8+Erz4o+aF19rOX6jOfGd5sP3WpZ/Knz+IMYGC3/Renmh5sZfNfcf1fz4m8DfNTd+1Nz4SXPjH5obP2tu/GJ4I54hUDQ/LAtsC5QFjgU+zQ2/BQELijQvii3XpUnIgrAFvSzorfkQ0VzoY0FfC6KaB/0s2ELzoL/mwACNf8yCgRYMsmCwBSWaA0MsiGsOJCyThNmzJ5ZZsKUF5RZsZcFQjf3WFsZjbBz7jLBgpMY/ZUGFBaM09qMtqLSgSuNerTGvsWCcBU0pGG/BNhZsq+99mRkLLJhrko5asL0FO1jQbsYC3b/TLONDgDMtmGzBFAumWrCTBTtbsIsFu1qwmwXTLJhuwWIbltjQYkOrDctsOMKGFbr8vQ0n23CzDafacIsNp9lwqw2n23CbDWfa8KINZ9nG1g1n2/CyDefacIcN59lwpw3n23CXDRfYcLcNF9pwjw0X2XCvDRfbcJ8Nl9hwvw2X2vCADZfZ8KANl9vwkA1X2LDWhitteNiGq2x4xIarbXjUhtU2rLNhjQ2P2XCtDU/YcJ0NT9pwvQ1P2ZC14WkbcjY8Y8MNNjxrw402PGfDTTY8b0PehhdseMWGt214x4Z3bXjPhvdt+MCGD234yIaPbdhgw0YbPrGNvwY+teEzG35vw+c2fGFDhw1/sOFLG76y4Y82fG3DNzb8UOj/X234mw1/19j8yYYfbfjJhp/159E2/GLDMRqnTv3bsQXD/+4KRMEeCiwFeyqwFeylQCnYW4GjYB8FPgX7Ks/PsZ+CgIL9jY1ewQEKihUcqCCo4CAFIQUHGxu8gkMU9FJwqILeCmYoiCioVNBHQZWCvgqqFUQV1Cjop6BWwRYKxijor6BOwQDjZ1EQU9CgYKCCsQoGKRinYLCC8QpKFGyjYIiCbRXEFUxQkFCwnTIJkGGiglIFaQVlCrZXsKWCHRSUK9hRwVYKJikYqmCygq0VTFEwTMFUBcMV7KRghIKdFYxUsIuClIJdFVQo2E3BKAXTFIxWMF3BTAWNCmYpmK1gjoImBXMVHKbgcAXzFMxXsEDBQgWLFGQUNCtYrGCJghYFrQraFCxVsEzBEQqOVLBcwVEKjlZwjIJjFRynYIWCdo39So3b6RqzMzReZ2qsVmmcztIYna3xOUdjc67G5TyNyfkajws0FhdqHC7SGFys5X+Jlv2lWu6XaZlfruV9hZb1lVrOV2kZX63lu1rLdo2W6zVaptdqeV6nZXm9lmNWyzCn5XeDlt2NWm43aZnltbxu1rK6RcvpVi2j2zQ3bleeD+hOzYe7NBfu1jy4R3PgXuX5du7XuD+gMX9Q4/2QxnqtxvlhjfEjGt9HNbbrtJxf0dg+pnF9XGP6hMbzSY3lUxrHpzWGz2j8ntXYPadxe15j9oLG60WN1Usap5e1bqzXevGq1onXtD68rnXhDa0Hb2odeEvz/23N/Xc079/VnH9P8/19zfUPNM8/1Bz/SPP7Y43vBo3vRo3vJxrf32l8P9X4fqbx/b3G93ON7xca3w6N7x80vl9qfL/S+P5R4/u1xvcbje+3Gt/vNL5/0vh+r/H9QeP7Z43vXzS+f9X4/k3j+3eN748a3580vv/Q+P6s8f1F49tZcOqJA5YDtgPKAccBnwN+BwIOFDlQ7EDQgZADYQd6OdDbgYgDfRzo60DUgX4ObOFAfwcGOBBzYKADgxwY7ECJA0MciDuQcEzaGSh1TKZc2NKBcge2cmCoA1s7MMyB4Q6McGCkAykHKhwY5cBoB6ocaHSg2oFZDtQ4MNuBWgfmODDGgSYH6hyY60C9A4c50ODA4Q6MdWCeA+McmO/AeAcWOLCNAwsd2NaBRQ5McCDjwHYONDsw0YHFDqQdWOLA9g60OLCDA60O7OhAmwOTHFjqwGQHljkwxYEjHJjqwJEO7OTAcgd2duAoB3Zx4GgHdnXgGAd2c+BYB6Y5cJwD0x1Y4cDuDrQ7sIfGb6UDe2oMj3dgL43jCQ7srbE80YF9NJ4nObCvxvRkLe/VxplZAWsiYF74d00ETLKtayNgV8B1EVAVcH0ETG6wbATMjuVcBPwVcEMEAhVwYwSKKuCmCBRXQD4CwQq4OQImz8MtETBphW6NQK8KuC0CvSvg9ghEKuCOCPSpgDsj0LcC7opAtALujkC/CrgnAltUwL0R6F8B90VgQAXcH4FYBTwQgYEV8GAEBlXAQxEYXAFrI1BSAQ9HYEgFPBKBeAU8GoFEBayLQLICHotAaQU8HoGyCngiAltWwJMRKK+ApyKwVQU8E4GtK+ClCFRUwOooLM7DmigsycM1UWjJw7VRaM3DdVFoy8P1UViah2wUluUhF4Uj8nBDFI7Mw41RWJ6Hm6JwVB7yUTg6DzdH4Zg83BKFY/NwaxSOy8NtUViRh9uj0J6HO6KwMg93RuH4PNwVhRPycHcUTszDPVE4KQ/3RuHkPNwXhVPycH8UTs3DA1E4LQ8PRuH0PDwUhTPysDYKZ+bh4SisysMjUTgrD49G4ew8rIvCOXl4LArn5uHxKJyXhyeicH4enozCBXl4KgoX5uHpKFyUh2eicHEeno3CJXl4LgqX5uH5KFyWhxeicHkeXozCFXl4KQpX5uHlKFyVh1eicHUe1kdhdR5ejcKaPLwWhWvy8HoUrs3DG1G4Lg9vRuH6PLwVhWwe3o5CLg/vROGGPLwbhRvz8F4UbsrD+1HI5+GDKNychw+jcEsePorCrXn4OAq35WFDFG7Pw8Yo3JGHT6JwZx5+F4W78vBpFO7Ow2dRuCcPv4/CvXn4PAr35eGLKNyfh44oPJCHP0ThwTx8GYWH8vBVFNbm4Y9ReDgPX0fhkTx8E4VH8/Ct5s/fNPe+0/z5u+benzR/ftTc+17z5yfNvR80f/6hufdnzZ+fDcYlcHoJnFMC67XOnVsCr2qdO68EXtM6d34JvK517oISeEPr3IUl8KbWuYtK4C2tcxeXwNta5y4pgXe0zl1aAu9qnbusBN7TOnd5Cbyvde6KEvhA69yVJfCh1rmrSuAjrXNXl8DHWudWl8AGrXNrSmCj1rlrSuATrXPXlsDvtM5dVwKfap27vgQ+i0C2BH6vdS9XAp9H4IYS+CICN5ZARwRuKoE/RCBfAl9G4OYS+Err6C0l8McI3FoCX0fgthL4JgK3l8C3EbijBL6LwJ0l8KcI3FUC30fg7hL4IQL3lMCfI3BvCfwlAveVwF8jcH8J/C0CD5TA3yPwYAn8GIGHS+DnCDxSAr9E4NES6Ix4QUwSBysOdhxUHJw4+OLgj0MgDkVxKI5DMA6hOITj0CsOveMQiUOfOPSNQzQO/eKwRRz6x2FAHGJxGBiHQXEYHIeSOAyJQzwOiTgk41Aah7I4bBmH8jhsFYehcdg6DsPiMDwOI+IwMg6pOFTEYVQcRsehMg5VcaiOQ00cauMwJg51caiPQ0McxsZhXBzGx2GbOGwbhwlx2C4OE+OQjsP2cdghDjvGYVIcJsdhShymxmGnOOwch13isGscdovDtDhMj8PucdgjDnvGYa847B2HfeKwbxz2i8P+cTggDgfG4aA4HByHQ+JwaBxmxGFmHBrjMCsOs+MwJw5NcZgbh8PicHgc5sVhfhwWxGFhHBbFIROH5jgsjsOSOLTEoTUObXFYGodlcTgiDkfGYXkcjorD0XE4Jg7HxuG4OKyIQ7vGf6XG/3iN/wka/xM1/idp/E/W+J+i8T9V43+axv90jf8ZGv8zNf6rNP5nafzP1vifo/E/V+N/nsb/fI3/BRr/CzW+V+n+fQys1viu0fheo/G9VuN7ncb3eo1vVuOb0/jeoPG9UeN7k8Y3r/G9WeN7i8b3Vo3vbRrf2zW+d2h879T43qXxvVvje4/G916N730a3/s1vg9ofB/U+D6k8V2r8X1Y4/uIxvdRje86je9jGt/HNb5PaHyf1Pg+pfF9WuP7jMb3WY3vcxrf5zW+L2h8X9T4vqTxfVnj+4rGd73G91WN72sa39c1vm9ofN/U+L6l8X1b4/uOxvddje97Gt/3Nb4faHw/1Ph+ZPqv8d2g8d2o8f1E4/s7je+nGt/PNL6/1/h+rvH9QuPbofH9g8b3S43vVxrfP2p8v9b4fqPx/Vbj+53G908a3+81vj9ofP+s8f2LxvevGt+/aXz/rvH9UeP7k8b3HxrfnzW+v2h8O43+JrxAxqIESAKKE2Al3H0O2AkIJUAlIJwAJwG9EuBLQO8E+BMQS0AkAQMT0CcBgxLQNwGDExBNQEkC+iVgSAK2SMCwBCQTMDwBpQkYkYCyBIxMwJYJSCWgPAEVCdgqAaMSMDQBoxOwdQIaElCZgLEJqErAuARUJ2B8AmoSsE0CahOwbQLGJGBCAuoSsF0C6hMwNQETE7BTAtIJ2DkB2ydglwTskIBdE7BjAnZLwKQEHJCAPRJwUAL2SsAhCdgnATMSsF8CDk/AzATMS0BjAuYnYFYCFiRgdgIWJmBOAhYloCkBmQTMTUBzAg5LwKla/u1a/qdp+a/U8j9dy/94Lf8ztPxP0PI/U8v/RC3/VVr+J2n5n6Xlf7KW/9la/qdo+V+i5X+Olv+lWv7navlfpuV/npb/5Vr+52v5X6Hlf4GW/5Va/hcm4KoEXJSAqxNwscbjBo3Hao3HjRqPNRqPmzQe12g88hqPazUeN2s8rtN43KLxuF7jcavGI6vxuE3jkdN43K/xuF3j8YDG4w6Nx4MJWKzxeCgBSzQeazUud2m5P5uAFi335xLQquX+fALatNxfSMBSLfcXtfyfSMA7CViv5f6ulvurCXgvAcu0/N9PwBFa3l9oeW/Q8u7Q8t6o5f2HBByl5f1lAo7W8v5Ky/mzBPxN/x2ZgL/rv+X6nB8TcIw+56cEHKu//0O343vDrVLPnjWi1IsZHVnq+YGqS2FaKcwshcWl0FgKS0phVql5rzbMLoXWUphTCm36mqZSWFoKc0thWSkcVgpHlMLhpXBkKcwrheWlmJcGc5S5VykcXQoLS+GYUlhUCseWQqYUjiuF5lJYUQonlMKJpXBvOawvh/vK4dVyuL8cXiuHB8rh9XJ4sBzeKIeHyuHNclhbDm+Vw8Pl8HY5PFIO75TDo+XwbjmsK4f3yuGxcni/HB4vhw/K4Yly+LAcniyHj8rhqXL4uByeLocN5fBMOWwsh2fL4ZNyeK4cflcOz5fDp+XwQjl8Vg4vlsPvy+Glcvi8HF4uhy/K4ZVy6Cj3gqErUyApqEqBlYLqFNgpqEmBSkFtCszrn8akwJeCuhT4U1CfAvNiqYYUFKVgbAqKUzAuBUFjE05BKAXbpMC803XbFPRKwYQU9E7BdimIpGBiCvoYW3EK+qZg+xREU7BDCvqlYMcUbJGCSSnon4LJKRiQgikpiKVgagoGpmCnFAxKwc4pGJyCXVJQkoJdUzAkBbulIJ6CaSlIpGB6CpIp2D0FpSnYw7ziKgV7pmDLFOyVgvIU7J2CrVKwTwqGpmDfFGydgv1SMCwF+6dgeAoOSMGIFByYgpEpOCgFqRQcnIKKFBySglEpODQFo1MwIwUzU9CYglkpWGDB7BQcOwTmmE8LDkvB4SmYlwLzuq8FKViYgkWmvRZkUrDEgmbTD2MnN+23oCUFrSlYmoJlKTjOyNuCFUbexhavMV+p8T5eY32CxvlEjfFJGt+TNbanaFxP1ZiepvE8XWN5hsbxTI3hKo3fWRq7szVu52jMztV4naexOl/jdIHG6EKNz0Uam4s1LpdoTC7VeFymsbhc43CFxuBKLf+rtOyv1nJfrWW+Rsv7Gi3ra7Wcr9Myvl7LN6tlm9NyvUHL9EYtz5u0LPNajjdrGd6i5Xerlt1tmrO3a77eobl6p+bpXZqjd2t+3qO5ea/m5X2ak/drPj6gufig5uFDmoNrNf8e1tx7RMvhUS2DdZpvj2muPa559oTm2JOaX09pbj2tefWM5tSzmk/PaS49r3n0gubQi5o/L2nuvKx584qW9Xot/1e1rF/TPHldc+QNzY83NTfe0rx4W3PiHc2HdzUX3tM8eF9z4AON/4ca+4807h9r3Ddo3Ddq3D9Jwe80rl9qXL/SuP4xBV+n4E8av+8NVllIZ2H7LOyQhR2zMCkLk7MwJQtTs7BTFnbOwi5Z2DULu2VhWhamZ2H3LOyRhT2zsFcW9s7CPlnYNwv7ZWH/LByQhQOzcFAWDs7CIVk4NAszsjAzC41ZmJWF2VmYk4WmLMzNwmFZODwL87IwPwsLsrAwC4uy0J6FlVk4PgsnZOHELJyUhZOzcEoWTs3CaVk4PQtnZOHMLKzKwllZODsL52Th3Cycl4Xzs3BBFi7MwkVZuDgLl2Th0ixcloXLs1CWgy1zUJ6DrXIwNAdb52BYDobnYEQORuYglYOKHIzKwegcVOegJge1ORiTg7oc1OegIQdjczAuB+NzsE0Ots3BhBxsl4OJOUjnYPsc7JCDHXMwKQeTczAlB1NzsFMOds7BLjnYNQe75WBaDqbnYPcc7JGDPXOwVw72zsE+Odg3B/vlYP8cHJCDA3NwUA4OzsEhOTg0BzNyMDMHjTmYlYPZOZiTg6YczM3BYTk4PAfzcjA/BwtysDAHi3KQyUFzDpbn4KgcHJ2DY3JwrPltCByXgxU5aM/Byhwcn4MTcnBiDk7Kwck5OCUHZ+TgzBysMvc1fuocnJuD83Jwfg4uysHFObgkB5fm4LIcXJ6DK3JwZQ6uysHVOVidgzU5uEbjd63G7zqN3/Uav6zGL6fxu0Hjd6PG7ybTNwvyRhbGL236b8Etpm8W3Gr6asHtpm4L7jDtNP5pcz/jnzZyNvYsjfM9Gud7Nc73aZzv1zg/oHF+UOP8kMZ5rcb5YY3zIxrnRzXO6zTOj2mcH9c4P6FxfjIHF2isnzKyseDpHJw1BJ7RmD+rMX9OY/68xvwFg4kFL2rcX9K4v6xxf0Xjvl7j/qrG/TXdntc17m9o3N/UuL+lcX9b4/6Oxv1djft7Gvf3dV8/MHIzvuYc/EXLqrQT0p1Q1gnbd8KWnbBDJ5R3wo6dsFUnTOqEoZ0wuRO27oQpnTCsE6Z2wvBO2KkTRnTCzp0wshN26YRUJ+zaCRWdsFsnjOqEaZ0wuhOmd0JlJ+zeCVWdsEcnVHfCnp1Q0wl7dUJtJ+zdCWM6YZ9OqOuEfTuhvhP264SGTti/E8Z2wgGdMK4TDjSBFEoYZiKwlTBcCZYSRijBpPwaqQSlhJQSHCVUKMGnhFFK8CthtBICSqhUQpESqpRQrIRqJQSVUKOEkBJqlRBWwhgl9FJCnRJ6K6FeCRElNCihjxLGKqGvEsYpIaqE8Urop4RtlLCFErZVQn8lTFDCACVsp4SYEiYqYaAS0koYpITtlTBYCTsooUQJOyphiBImKSGuhMlKSChhihKSSpiqhFIl7KSEMiXsrIQtlbCLEsqVsKsStlLCbkoYqoRpSthaCdN1329Xwju673co4V3d9zuV8J7u+11KeF/3/W4lfKD7fo8SPtR9v1cJH+m+36eEj3Xf71fCBt33B5SwUff9QSV8ovv+kBJ+p/u+Vgmf6r4/rITPdN8fUcLvdd8fVcLnuu/rlPCF7vtjSujQfX9cCX/QfX9CCV/qvj+phK90359Swh91359Wwte6788o4Rvd92eV8K3u+3NK+E73/Xkl/En3/QUlfK/7/qISftB9f0kJf9Z9f1kJf9F9f0UJf1XCeiX8TQmvKuHvSnhNCT8q4XUl/KTrXuwIFzvCEke4xBFaHOFSR2h1hMscoc0RLneEpY5whSMsc4QrHeEIR7jKEY50hKsdYbkjrHaEoxxhjSMc4wjXOsKxjnCdIxznCNc7wgpHyDpCuyPkHGGlI9zgCMc7wo2OcIIj3OQIJzpC3hFOcoSbHeFkR7jFEU5xhFsd4VRHuM0RTnOE2x3hdEe4wxHOdIS7HGGVI9ztCGc5wj2OcLYj3OsI5zjCfY5wriPc7wjnOcIDjnCBIzzkCBc6wlrTvpCwLiSsDAmPhYTjQ8LjIeGEkPBESDgxJDwZEk4KCU+FhJNDwtMh4ZSQ8ExIODUkPBsSTgsJz4WE00PC8yHhjJDwQkg4MyS8GBJWhYSXQsJZIeHlkHB2SHglJJwTEtaHhHNDwqsh4byQ8FpIOD8kvB4SLggJb4SEC0PCmyHhopDwVki4OCS8HRIuCQnvhIRLQ8K7IeGykPBeSLg8JLwfEq4ICR+EhCtDwoch4aqQ8FFIuDokfBwSVoeEDSFhTUjYGBKuCQmfhIRrQ8LvQsJ1IeHTkHB9SPgsJGRDwu9DQi4kfB4SbggJX4SEG0NCR0i4KST8ISTkQ8KXIeHmkPBVSLglJPwxJNwaEr4OCbeFhG9Cwu0h4duQcEdI+C4k3BkS/qRlsntYWBwW9ggLS8LCnmGhJSzsFRZaw8LeYaEtLOwTFpaGhX3DwrKwsF9YOCIs7B8WjgwLB4SF5WHhwLBwVFg4KCwcHRYODgvHhIVDwsKxYeHQsHBcWJgRFlaEhZlhoT0sNIaFlWFhVlg4PizMDgsnhIU5YeHEsNAUFk4KC6tjwrqYsCYmPBYTrokJj8eEa2PCEzHhupjwZEy4PiY8FROyMeHpmJCLCc/EhBtiwrMx4caY8FxMuCkmPB8T8jHhhZhwc0x4MSbcEhNeigm3xoSXY8JtMeGVmHB7TFgfE+6ICa/GhDtjwmsx4a6Y8HpMuDsmvBET7okJb8aEe2PCWzHhvpjwdky4Pya8ExMeiAnvxoQHY8J7MeGhmPB+TFgbEz6ICQ/HhA9jwiMx4aOY8GhM+Fj3fWJGmJkR0hmhMSNsnxFmZYQdMsLsjLBjRpiTESZlhKaMMDkjzM0IUzLCYRlhakY4PCPslBHmZYSdM8L8jLBLRliQEXbNCAszwm4ZYVFGmJYRMhlhekZozgi7Z4TFGWGPjLAkI+yZEVoywl4ZoTUj7J0R2jLCPhlhaUbYNyMsywj7ZYQjMsL+GeHIjHBARlieEQ7MCEdlhIMywtEZ4eCMcExGOCQjHJsRDs0Ix2WEGRlhRUZYnREeyghrMsLajHBNRng4I1ybER7JCNdlhEczwvUZYV1GyGpZPZYRclpWj2eEG7SsnsgIN2pZPZkRbtKyeioj5LWsns4IN2tZPZMRbtGyejYj3Kpl9VxGuE3L6vmMcLuW1QsZ4Q4tqxczwp1aVi9lhLu0rF7OCHdrWb2SEe7RslqfEe7Vsno1I9ynZfVaRrhfy+r1jBf8V9YhSIewZYdgdQjlHYLdIWzVIagOYWiH4HQIW3cIvg5hWIfg7xCGdwiBDmFEh1DUIYzsEIo7hFSHEOwQKjqEUIcwqkMIdwijO4ReHUJlh9C7Q6jqECIdQnWH0KdDqOkQ+nYItR1CtEMY0yH06xDqOoQtOoT6DqF/h9DQIQzoEMZ2CLEOYVyHMLBDGN8hDOoQtukQBncI23YIJR3ChA5hSIewXYcQ7xAmdgiJDiHdISQ7hO07hNIOYQfdttstOCQ6U/oO2JB8OJEsPSaVHO1UzqzZYvWG2r9dN7MulF1dF/9pQx2/bNj+Z2ncod1fOkWKN04pD6+Zmu9VuvPoWOku4wduPPD7eONBlbVrZscbS5u+nb+maeKi0rnTmzfO/fa2xsOuXLvmcF5pnHfYqxvn8V7jfDasWXDrJ6WLvvy0tHn9F2uaf/q+cYnw9ZJvZbsWs2n8BJXkXGcmh0RX03dAMhFKzkx8k5o5ckTlhpHN2dUp64ZkRfyn5Ch+mVl3Vefqup+ltF6KG+vLw6UN+V5rxo6OlY77Pl66y8TaNXvFGzfu/e38xn2mN2/c58q1jfvySuN+Ta+u2Y/3Svdnw5oDbv2k9KCvPt148PovGg9J8vWh38qKGSbWYXV0pq+9ZLW/fyIZuLd8YhGp1X0nZjf0rczP7MdPyf6lnRv6oxoHtPs3DmgPrRm0OrZm8MTMmhLe2FiSfWvNED5Ol1VuKN2Kjo1bVX5XOvT071qGjhpVeeSmzV/p7XfYcdJk/j5eZRoXNdErPGth8xFz57UePuqIxtZFsnH8Yqpm7Dlt2W6tNTOmte3QOmb29NmV1dN3239h84wxbrqMmta2lqaq6rbmGQubj2hqmd3Y2iRN3iWV7iXzlxzZsPdOyw+ctfvYGQ1dSTa6v+w0rW37MTMadiwUp7VNa9u+fkZN6/LWur2Wt7Y1LZpU5x3ZobVp1tTZjU3Ne7UcuXxGTWvbHGvUjAOnNXQ1uKq669tOmXltVfWHN9SPrW8cM6ay/v8p7tq/27atcNO1WbNl69q9H92yVluaplbwIAiy7dpZDpOpS5TU9vJwu6IgAEqMKZIFKSvuOTvb2dn+7x0AlCLZpiJ73vKLBEkk7+u7372Ejq6w8BDxguhbYPF4MqnTrAqHqr633b/NdiAdKd8HJCBC+DBU0hPRKzfY3iCcDwCBSBY3lR3PslVXkI54KAnhAAaK+wr5QfQqMpYbq4ePbpfk3tdP0eCQeWbQifETDO3ED6a0LjQzv/Vnur54fUlIKIsotzIgHcmEqFgh7CUJSFAYRt/eWLQh6DULnKsppCMKoZcQ3ycEBBIrFb127NqN/sbaBITIUzLAHpcwUdEl4pRvVZ+IYpLX0JMFsytmBgBV37luTtuqkjvJoR/1IUnvMMoYs+NbIDYrmTGtbBS/Cxf1992sm1A9LXku/6wOTdgwFFh6AvA4QJyS6PKHK9SizeiYcD46BqK0YvNX39tYjiAQtb6vC6EqGz9IqQACcg9AJFASfV+cJ3K9RCt1HLevf+yEcHayp5NxbS8d3ir0mNe10hCVXLI0r9VQ8+wHF53b3jiOzgY5Dp0Y0xDRGFM/UGECaPTmR1Zwiys9E006VvWokJWAvqoELxWTKp4MmXpa/9A7rdq45PJH77RnRDKuyVSntfrxvZWoQAHrTGjcsQ8iK6qJVp0JlR37YKAMYyqSkNMkwFjFMvrJwEh9VLE2NBdlRexgJxNFj5Fts34S2SAGzGp20xhuVr2A/XR8nriAWKZVWVSKidEk3z+OkJ9JtjfwIevc2e3MCbk7S/bOBIEOr+zTPPr2Y7G/lZYjpTu3dzsQSZfq9n3jJPNbWBgrmSRAxjSJfj48T6OCsRrzLB3mx835RX+WwStzGKJmwSSvOYSiyA+UrtIir4JZeflldq6BQJM8S/P9tjj8Ci5nmDdU9ZZhPqV7qc0xSTHEMYqF71EfgjB667Oz2EqGmpcjNVYm0VQuIcyKYn9Ssiorpr++f5ZLeqYSs3SYF9rS4uIVf3OUFW0W9sy4NsPAKOQ+4gJiSWISw+jKpXnd+O2lOcm8/elS+SWz1bPqBX3zc2yCcEgQEQBH3Sw7GHcRoSEkFBAMKaA+gTR8R59rWIM0NwN1WMb1sDXJOkfp046r2y0eFloaNwBTRiXHQPBEAOlHv7t1hjgQE4bleP4+XFHQiO0QiJ0IB33XLtgXtl24+onjtYC1qmEYC+eTMU7H5XhQ90JGb6ZVmfFD88m73WWjYVo94Fkq75oXdORJTEiSUBLEShJEo2tfOoF7Ld2fX9U6zYc2JP5Os25n+4cGZw3cbPfw3r8vGIUCS3Vu/Fr3QIlu94ES5q1dQ2WLhLd4TFUq4WawpbXS3e5OqcQtXYz7tdLu5K1O317g9m4nnB9oGqUEcIFpHOJEAIxkdP1opldTXt7hlWNPAwcqPeQTSAAME08kJHr/8mI3uHF5sfp2/3VhVoBs7TuKvbDkeSr203wIsV2yEc9lprStRrApoc6f9/lhVnDZhhd7OkT3zVNzaFDzfcXi4ukNsGwVNvxVVw+4TnnuMhQlHAMQcAUoV3EE8Kxcr1DZyWTTtB6xUVHsw6OZNDUptFvMCEUJ6SsSYyx8z/ckjdBXbG/gAESbIX0IbPPpAyX6ee6Ct2mLGEzSPK1GbKgL0+AGoQwSxH2cKAkSlTSMQqiHQICRBz0AA0I9gB86QvFacDsTa4EL4YLsJZHH3eC9vcR6twwygtgPYUgph5ALH0bk8uKtiG+w0B8cGHXQc9Tp2/ZjSZ8yolpVSh8oBGXBmjXjuWwwYwmQz/LxdlbEPIuOK071mj5/jjhjMMYhICSUXPjSC2BrFALosuBJe8voIvCs2Qon/39n9Tj74E/ngpceZR/+/QVmvknwjyjbHmydUjroEfaHrWMIgaGzdMamFiCBsZQVuYECChLOVSilLwKEFYw+PsNFPBgLAgEJoKc4QSD6ZHP1nsMiAgjz3eVH0fyaPcr+GM3vAFoaCtMxWKc3bcOg7vks3DQf8brQTtLmWje30t3c9r44/2IZulpZ1XqLtt5JLYQ1cGF1E3OZGCmxf/NTe+LJp7p+A4Ej80xZOi4zSDOVs3Fa2cmltgGJPnjWu6wAF3Ra2F2CIM2FVrxSt/7ZFEV4htwgDPo7Na9TsVPr/6Ik9jfW8KJbsWRc33mDsWmc5nJoPGGjePepswKczYpTZLhBwWLzNhi4QD7vHpy2bh1A5OBUapWkT++9uWBcs0Fzv7sK8NQ8MTvpmk3yaZrLz662NQwO84E9oyjG21NH7CfqXdYaYjPOk6U5KzMuDFGfzrsnOjbq3WU7G+18eERHw4K735xKz76tKB7zCzszy3ejs/ouoifslh4ogS1ZRSfU6L8kp/XRKp5pKOYEOQ+urQoybFjNXushmMW3nYYhcjFw+5qPrq2RYS5HH7++AECzWbZntyQenewAnh+uZ/Gg7iGGN/NDWh+WiqXyuAc+31otaFutcZEvPjjtjtgzNv/rjdU7xf7RneIv318VM69ZzdKY/e2FUa3pQb6aOPHofyv+KEHyoLE6bY+Ijay/dFPcC1i8nnebQi+urjoa2EOtWXJkDny8LmzX6wWS8QkeVWSNPKWzQcBsNgg4aYdhw2gQuymAzI0mTiO2N4DIfDvEmszFYCGLbR9RF8wkjWRca2429UPlQUECBSHnSCk/evLeKg+imb+t+vvBimORv3RskzANmrJ/vLiOw+eVyfOx1x4X9wiDhj0XDChkUwhOUto0rmHMxX6tuVDPHKByyapRoWs2/9DVvZ7Pjha6b6Kez8r8nKW0315YeV8Hq3sF5EmV8El2JJZFsa8tnzzur9EVQNed2DdoE4ttFZ0+cr2Q1WAVSoOZS+zfTTiJk80VZ2AyOyMvGhOrUTpmacUmeWW/r2IH6GDN1IgLXU83j2WjidQ8G4v4iRI1s12DVgmkI0GlAhADTIEMMfailz6/VOpCToTS1cuvZTwfTvhQXXhle1LVL10u3XdlSm7Ehy+/alV4C3ZD0gVX3p3/M8MVBJC/AbwN6F27OOWZnlQXQReBLv4PvLNqoA==');
@@ -93,13 +93,12 @@ export function desWasm(wasm) {
   /**
   * @param {string} mode
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} keyWords
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function doEncrypt(mode, nWordsReady, blockSize, iv, dataWords, keyWords) {
+  function doEncrypt(mode, nWordsReady, iv, dataWords, keyWords) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -110,11 +109,14 @@ export function desWasm(wasm) {
       var len2 = WASM_VECTOR_LEN;
       var ptr3 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
       var len3 = WASM_VECTOR_LEN;
-      wasm.doEncrypt(retptr, ptr0, len0, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3);
+      wasm.doEncrypt(retptr, ptr0, len0, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
-      var v4 = getArrayU32FromWasm0(r0, r1).slice();
-      wasm.__wbindgen_free(r0, r1 * 4);
+      let v4;
+      if (r0 !== 0) {
+        v4 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
       return v4;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
@@ -126,13 +128,12 @@ export function desWasm(wasm) {
   /**
   * @param {string} mode
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} keyWords
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function doDecrypt(mode, nWordsReady, blockSize, iv, dataWords, keyWords) {
+  function doDecrypt(mode, nWordsReady, iv, dataWords, keyWords) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -143,11 +144,14 @@ export function desWasm(wasm) {
       var len2 = WASM_VECTOR_LEN;
       var ptr3 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
       var len3 = WASM_VECTOR_LEN;
-      wasm.doDecrypt(retptr, ptr0, len0, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3);
+      wasm.doDecrypt(retptr, ptr0, len0, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
-      var v4 = getArrayU32FromWasm0(r0, r1).slice();
-      wasm.__wbindgen_free(r0, r1 * 4);
+      let v4;
+      if (r0 !== 0) {
+        v4 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
       return v4;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
@@ -159,15 +163,14 @@ export function desWasm(wasm) {
   /**
   * @param {string} mode
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} keyWords1
   * @param {Uint32Array} keyWords2
   * @param {Uint32Array} keyWords3
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function tripleEncrypt(mode, nWordsReady, blockSize, iv, dataWords, keyWords1, keyWords2, keyWords3) {
+  function tripleEncrypt(mode, nWordsReady, iv, dataWords, keyWords1, keyWords2, keyWords3) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -182,11 +185,14 @@ export function desWasm(wasm) {
       var len4 = WASM_VECTOR_LEN;
       var ptr5 = passArray32ToWasm0(keyWords3, wasm.__wbindgen_malloc);
       var len5 = WASM_VECTOR_LEN;
-      wasm.tripleEncrypt(retptr, ptr0, len0, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, ptr5, len5);
+      wasm.tripleEncrypt(retptr, ptr0, len0, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, ptr5, len5);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
-      var v6 = getArrayU32FromWasm0(r0, r1).slice();
-      wasm.__wbindgen_free(r0, r1 * 4);
+      let v6;
+      if (r0 !== 0) {
+        v6 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
       return v6;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
@@ -198,15 +204,14 @@ export function desWasm(wasm) {
   /**
   * @param {string} mode
   * @param {number} nWordsReady
-  * @param {number} blockSize
   * @param {Uint32Array} iv
   * @param {Uint32Array} dataWords
   * @param {Uint32Array} keyWords1
   * @param {Uint32Array} keyWords2
   * @param {Uint32Array} keyWords3
-  * @returns {Uint32Array}
+  * @returns {Uint32Array | undefined}
   */
-  function tripleDecrypt(mode, nWordsReady, blockSize, iv, dataWords, keyWords1, keyWords2, keyWords3) {
+  function tripleDecrypt(mode, nWordsReady, iv, dataWords, keyWords1, keyWords2, keyWords3) {
     try {
       const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
       var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -221,11 +226,14 @@ export function desWasm(wasm) {
       var len4 = WASM_VECTOR_LEN;
       var ptr5 = passArray32ToWasm0(keyWords3, wasm.__wbindgen_malloc);
       var len5 = WASM_VECTOR_LEN;
-      wasm.tripleDecrypt(retptr, ptr0, len0, nWordsReady, blockSize, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, ptr5, len5);
+      wasm.tripleDecrypt(retptr, ptr0, len0, nWordsReady, ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, ptr5, len5);
       var r0 = getInt32Memory0()[retptr / 4 + 0];
       var r1 = getInt32Memory0()[retptr / 4 + 1];
-      var v6 = getArrayU32FromWasm0(r0, r1).slice();
-      wasm.__wbindgen_free(r0, r1 * 4);
+      let v6;
+      if (r0 !== 0) {
+        v6 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4);
+      }
       return v6;
     } finally {
       wasm.__wbindgen_add_to_stack_pointer(16);
@@ -234,10 +242,130 @@ export function desWasm(wasm) {
     }
   }
 
+  /**
+  * @param {string} mode
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} keyWords
+  * @returns {boolean}
+  */
+  function doEncryptCts(mode, iv, dataWords, dataSigBytes, keyWords) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ret = wasm.doEncryptCts(ptr0, len0, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+  * @param {string} mode
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} keyWords
+  * @returns {boolean}
+  */
+  function doDecryptCts(mode, iv, dataWords, dataSigBytes, keyWords) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(keyWords, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ret = wasm.doDecryptCts(ptr0, len0, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+  * @param {string} mode
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} keyWords1
+  * @param {Uint32Array} keyWords2
+  * @param {Uint32Array} keyWords3
+  * @returns {boolean}
+  */
+  function tripleEncryptCts(mode, iv, dataWords, dataSigBytes, keyWords1, keyWords2, keyWords3) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(keyWords1, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(keyWords2, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ptr5 = passArray32ToWasm0(keyWords3, wasm.__wbindgen_malloc);
+      var len5 = WASM_VECTOR_LEN;
+      var ret = wasm.tripleEncryptCts(ptr0, len0, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3, ptr4, len4, ptr5, len5);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
+  /**
+  * @param {string} mode
+  * @param {Uint32Array} iv
+  * @param {Uint32Array} dataWords
+  * @param {number} dataSigBytes
+  * @param {Uint32Array} keyWords1
+  * @param {Uint32Array} keyWords2
+  * @param {Uint32Array} keyWords3
+  * @returns {boolean}
+  */
+  function tripleDecryptCts(mode, iv, dataWords, dataSigBytes, keyWords1, keyWords2, keyWords3) {
+    try {
+      var ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+      var len0 = WASM_VECTOR_LEN;
+      var ptr1 = passArray32ToWasm0(iv, wasm.__wbindgen_malloc);
+      var len1 = WASM_VECTOR_LEN;
+      var ptr2 = passArray32ToWasm0(dataWords, wasm.__wbindgen_malloc);
+      var len2 = WASM_VECTOR_LEN;
+      var ptr3 = passArray32ToWasm0(keyWords1, wasm.__wbindgen_malloc);
+      var len3 = WASM_VECTOR_LEN;
+      var ptr4 = passArray32ToWasm0(keyWords2, wasm.__wbindgen_malloc);
+      var len4 = WASM_VECTOR_LEN;
+      var ptr5 = passArray32ToWasm0(keyWords3, wasm.__wbindgen_malloc);
+      var len5 = WASM_VECTOR_LEN;
+      var ret = wasm.tripleDecryptCts(ptr0, len0, ptr1, len1, ptr2, len2, dataSigBytes, ptr3, len3, ptr4, len4, ptr5, len5);
+      return ret !== 0;
+    } finally {
+      dataWords.set(getUint32Memory0().subarray(ptr2 / 4, ptr2 / 4 + len2));
+      wasm.__wbindgen_free(ptr2, len2 * 4);
+    }
+  }
+
   return {
     doEncrypt: doEncrypt,
     doDecrypt: doDecrypt,
     tripleEncrypt: tripleEncrypt,
-    tripleDecrypt: tripleDecrypt
+    tripleDecrypt: tripleDecrypt,
+    doEncryptCts: doEncryptCts,
+    doDecryptCts: doDecryptCts,
+    tripleEncryptCts: tripleEncryptCts,
+    tripleDecryptCts: tripleDecryptCts
   };
 }
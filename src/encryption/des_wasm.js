@@ -1,3 +1,3 @@
 import { generateWasmBytes } from '../utils/wasm-utils';
 
-export const wasmBytes = generateWasmBytes('eJzUvQt8HVd1Lzx7z+M85hydkSzJkmXHcw6GKORh+SXJSSAeNX7hJHZ5hmfs2Eqco8S2ZMUxX0107NjghEBEScGFQA1NiaEBTBuKS9Mi7k3BtCmY+4Xi0lwwbdrmd39crlvSXrc8/K3/WnvmzDmS8iL0fjcgn1kze/Zee621115r7bX3WFt236Ysy1KHVNtmPTGhJvCvtVnhyp6YiH9wx9rsT9T/szaXJhr/47fu3OzINf/Q25a12aIf1xTx4rLuxJ3m1xTUd9K/9q6dbiajPS/X4hRt5TpBXitbWSXL0VpbqtVSbkFlMkrlPM+2Hfp1HFdbjus4lm3lnYJSBNI/rq2crK+1svJKUwe1Rdfat+hH4y79ZrNZVzmu2qW6uqi41erlqAdRrTZl+ZnfoRZuG75t59g7tZXbtnP1jq1j79w1bvXS9dXDcn1RcXzsll23DsfPXmbg+PmihTfccMeNt+zYdvPwjhu2bNt2w/jOG3aPb9k6csOunbfsGB8esza3pkrctuXWW3dutYbaUvfGhuXmulLq5k1jw8PWa3MLlBUpf+5tv7bjtst7Vq6tXHbVG9eMVG94x+uvX95+2635Lzxo7zy3UbdP2He+zIqO65GKWmSFVuRUh6wBjauH3z1l9Wqrn0izKqrpyAneUNHOKr0q1EP7iQofr9Vqewf0OWXgBwi+fkCfjeGPEHxiYkA/Hd84QjeO0I0z8Y0P0Y1z1Nrp+Mb9dOM03TgV3/hNujE4oE/G8CTBXQN6ysA14LBrQJ+IYeCwaUAfj2HgcJyaPBbfAA6TdONofAM4nKUmj8Q3gMMpujEZ3wAOfQP6cAwDh2BA1wDT5dT7hQ57DXivkGGXAe8xVNhu4MOGCJsN/G5Dg+sNfNCQYJOBDwgF1hmwJgRYJWDt/dL/QQPeK93vM+A9pve9Bj5sOh8a+N2m710GPmi6Hhj4gPQ8a8CadNyKhcFeXbRCG4+eAW8Wsiww/BTgBSwLDD8BuIdFgeHHAHezJDD8COC5LAgMPwi4g+WA4fsBz2E5YPgg4FaWA4bPgi8LWQ4YPgN4AcsBw6cA97AYMDwFuJulgOHjgOeyEDB8FHAHywDDk4DnsAwwXAPcyjIg/UedJciAdB9gETIgvQfoQwSk8wBzkADpO8AMBEC6DtAF/6XnAG3wXzoOUIH/0m/gUgL/pdsAi+C/9BqgD/bbiezkwH3pM8AMmC9dBuiC99JjgDZ4bye8Vyneu+C9S4+O3kdPfqaY9wxDJH+kmPcMv4/gJxXznmHI6OOKec/wewl+VDHvGYbQPqyY9wzfTfADinnPMIT4XsW8B1xD++cU855htP+0Yt4zjPZPK+Y9w2j/pGLeM4z2TyjmPcNo/5hi3jOM9o8o5j3DaP+wYt4zPu9B/y3wnkGMqR9Z4D2Dh9B7C7xnEPx73ALvGbyLwCvBeoYw3C4B5xmCiM0H4xkC/QvgO+OANqE0BgVEm09b4DuDaBMqpFdAtHnSAt8ZRJuDYDtDaLMXXGcIbXaB6W7M9GyK5w547tCT/4CslZjlAP8FYAtzHOD/AFhkhgP8O4AF5jfA7wH0md0A/xvAPHMb4F8AzDGzAf4XgFnmNcBzZpidEBDSP9XCnAb4tBl1xwTEYJgqMJ8BnjaD8IiAGBtTeeYywJNmTB4WEENlKss8BvhloJEBiwH9ASAPHAb0GUAuGAzok4Ac8BcQZp8zNvgLCPPIGQ3+AoKYnVHgLyCw4YwF/gI6YVTCIEMYqFMeuAvomNEPvQxBLqYc8BbQEaMsuhjCEJ7S4C2gw0ZzZBnCf1NWirdZ8DY7VDsF0fqFxbwlcBLgv1nMWzyFpP3YYt7iKcB/tJi3eArB+77M53gK8K9lNsdTyOE3LeYtngL8msW8JXAqHkYnGGQJf8Zi3uJpPKqOyVOAT1nMWzyNB9kReQrwCZnB8TQec4flKcDHLOYtsMJweDVYC5wA9IOzeAKeXAbG4gmAV4CveIJhcgHYiicAOsFVPAFdW8BUPAHggafAwgz1QcYBwHJwFE/MsO/lJwAWgZ94YlRAFz8B0A5u4olRB1l+gv+cFC9z4GVuqPandP9shllJ0B8TdDzDnCToi3jmMSMJ+gKeecxHgj6LZy6zkaBP45nLXCTo9/DMYSYS9Ak8c5iHBD1qhskJhiDERzPMQYIeMYPmGEMQ6aMe84+gh80QOsIQBPyoy9wj6EEzoA4zBHE/6jDvCPoYcLHBOwJ+G6jY4B0Bv4UnGrwj4AN4osE7AjAbnFXgHQFQ7ccVeEcApAbG0DoAIHYfWEfXD5hRPAgAQ+yoDdYRcL8Z0b0AwO6jGqwj4F4zursAYOgdVWAdAQfNSM8CwH9hinN5cC4/9DTk4ypmXH7oJIArmG/5oWMABpht+aHDAJYx1+gdSM5iZhq9A+Bi5hm9A+BCZhm9A+BlzLH80Bkj/CcAsIRezvzKDx01I+EYABbXpcwtescMiyP8DoBXMq/oHTNGDvM7ACrMKcLNmGd7gZoxxXYBM2N2bQdixsTajPKgSwlMovK49sEjKo/rDFhE5XFtg0WEkxmQg0AJ1/PAIMLIjM1eIITrNrCHypth2oXyuM6DOVTejNgsyuM/neKND974Q/A4zoEgE8wffwjicdrcOIsb7PeYG0/jBvs95sYZ3GC/54DcOI0b7PeYG6dwA3PFCXPjJG5Auo6YG1PK4AHhPxHjcMpm3kn7GAnH4rYnbeadtIthcSRu85Rm3kl7GCOH47YmNfPOH4I5dW6/NLyXYEj2aQPvIpj9GwNvJ5j9GwNvJpj9m5rA1xPM/o2BNxGMPp0w8DqCcXnEwKtM+xiwg6btUwq8lXYxdntNm5MKvJX2xKeRtsSfkXbYl5Em0n6MB856NDQPkII5OiWTH2Bo1bO4PMB2Nz96mmES76NTPIOeEZhG+FTI3PRYix+V+fWUwFTVlMx8qAu1cV1T8q5mLsatcMnjptaDXOuxpveOGvRiHI4YnKiVkBnKneHaZN4TnEJmqWlHnu2Nm8HDXVLuoKl1uzQilW42LYTgoql+it1Uxkz02DrpLKHFaK5KwBD8M2SThvsMwU2Pehu7HwoY96Ar6S4XDuq4ZJOuptVoBgzNkBwfpbcmHeZnRkb0gYBZiof70b7DHMXDTzA6hqUZHgQxlU5LCbwwedCwNcPW10EI0kkpj8mwJpOhNBaXPhE3AJvMcFewq5kWj8UYSQ1H6xUGzF3TmIxWg3tNKj9c71qNb9Tq3QnAYOmK0GGX6QduCIvxlNkrTcSVXG8QiDmwKabIAenBujpBHDA6wZ7fHoyr7atTwgGTDRlMJWFciQaLEzwPCo8bEcomCKVHbgGMLvA4TwYugSJlZvQCPsgWk/C6wDYgSRLgMwwfYLkDfJrLs6xx+VPyHKJ2Ul4VaZ6q18vvnTD18sPjqgGnY3WcYu7y45BZa5oXdCZj9Ezzh03zZkTWTKtxTXvlKb3N4K4Yh6PCW4MEs1d6NcXDl+8fjRlbEOmRKtbV26sxY+t1DNYpy+PXNC0Few1tatKPMMGTwa64eUE7kE4bPLN4mJ5oi2BqcYgl+jAoT0wtDk1xp6lKmWaLbAwePlw7aqZZKlGDlyNMlfKHs8xRfnSgZiaVU0oqR23CVmnr8GGpfCquq2YYa5piIhxPlRbFzC0dOGwqP2qK4znfOJLgblCdjKvPMntj3A6Kcpba2GLk+VYwpW6yeo6J4oC7glZMks1Jp6SZ6w0eNdOrTaalw7WDZqqtk2hVXJcpOxiXRUN9CTXkxd4YJ6ZHGNOdMexqoGwQ98aglG0iTcLwFjC8hT1W0gw14XgLLDbQQTO/W+Le1crMbjye5DfKzPAWMfBqDJ+Oy1N1BySUxNUfRnnhuVQP3CSUZOqX8idS9Ruum/dr/P4x85zfLzPXpbyp70gKfeAzmcIH5Q+b+uI4Mj8Echo8l66YqnfFLU1yzdvNi+zOCE5m7r0+rlK6uClFEY4ySDU1QWBVDEojg6YqDXab5oWyvTFu0vEwphtj0BX3WnALYgwEoWwjBgm3S+B2icmxf/9hUIC4XYINJ0HDkri3+w8HzGgG9+/fX5sMmNFUEnCN4dNx8f37s8xmrnc/mpV5uSR+Qg2VTaUqC5jJSVOAj9dLc93HTFsSKpRH+7miI0k7NW5nMsFqvwzqGCeupxY3a0rvNZ0npPB4l7wbl94uLe1n/bA53bvrTSOmc5vq2Abgr2BEFEUtq0ylpo1BU+l+JmpfjA9bzwYZoUGYtM61dsXtMxQ0tpFNaJKOEQbgbgCfd3KS+0DMDYb2MzLC32BIBtp+iNvT/FSkBvAZKS0YaWYwl58UhXlKik9NCnfRzhSqgjRO1auSIRzEKgMVHY9LC2elEYPEUfOMMAahj5hGuG9icnFxdOiw+EhpnGqmXRTPgr3c+7juXVx2yhBje0KL/TKEpaLJGkckuCRXy1FCadQQYp3gxLyVjtSk3GDSADtGMQn4pd4GToQGT9ONLsGFGZugnAVfYzz2N/i/reBsK/nIrAz2C2tbh1grHhQlDahmnCQqOUlYHuSmz/Az1EoWhEzKreJtC9FOCYzyByTUj+JG+09JZVI6y6w1lbNiOW4Kx3UfM21hwpKxa6qOVbNgaTCbrGMirOWq4x7WktL88t56zXi6q5Ec26WmKabG5rgiB5w1CDK+m6Q3U6Y366QF2Mj7eS6OKznATi9BPGRjQnPDvfUKhbH8dKomStkgJfUFMeEdcDZpGQo5eHWlbXVRh21h20W2VekYUvsGbCtsi7JVgsudoYq6sBhM91TUXo1O3j2FxWAC6CcIVdg5YGfpp0NKPKCrlbbQI3GpSqkjdGNO6AlwPwHtUaqCYzpUqPwo/aLQJH61vH0vX/frw/TL7xw0N2r4VSgU+IS+Bvr0vzT6OoX+YIL+8ib0+wT9XkE/JBpkYsTnhBn8zI5sxiBrp5C1m5G1BdkorL4AfE9bCcJPWE0YbxKM1wnGqwzB7bDQSHAvLNQJnpm1DwXTBzfVB7e5D67pw6qRF9CHc/U+PNPch13Sh+3Sh82hHRZj7L2wiJ/ZMS4ajJ0Uxk4zxo7B+Iz1Qsh+RCUo36+aUCb9wDgfVoJ0TSWUb2mkvBu21CnvzNqPFtOPbKof2eZ+ZE0/auqF9ONEvR+PNPfjuOnHMdMPrHyHpbgHbljCz+xYlwzWuRTWuWascwbroy8I69N1rJ9oxvqUwfqkwXqqTv2gmfrB86F+YPqRT/Uj39yPvOnH1Avqx7l6P55p7sdZ04+nTT+Q9xC21qnf+uzUbzVY+yms/Was/Vj261hzOk/F7dUWEczq1dmKXuOsCgnv4OUhbpfldln7+LWIijpyx4mAoaJuoTMO9ZCq424KHAAO6nBXlScKA4MmqlohXZ0lYtnSnE3NAZWkRavsJg03tBmm6hxsarOv2ohT74tvU0UnLWZcB12estIKGJdzG5FalSA1N0GnM0Gk48Wi8HQdhbNWWn/OgMLmXw0Kh1WCwqRK68MZUCCb4VeCw7E6DsdVWpfNgMPRXxEOJ+s4nFJpzTQDDlO/FA7ThKDe8lmV1iWNLdto+Qy3bCct20nLdlPLaJfIqauLrJLviwU/bPlGLyy1/O9XVH7iZRap6pFKaRH542EuzAdFmhGoSj8s8CXZLsWwBZdICVweWeUC3X1E0gIrpO379Cr5X1iCcslHwUaHrwn16Ixd9sNcOU+vFcleKUGsKq1U+dxqpQUKQ/PgLUHyKgHNpkH9FsllpYNuZZNbYQtfIQIaFkHPEukDwvMVaG6wXMRPXznvh3myKUAxQpVllhRqARRrhbJggHoYJADoZoASvUntkqExGPpQ9n60uUp9oJqXlwsFy7f8gvKFGFeDYv1x17tSXQ/KHSDU7N3veH7d73yu7pNVZrq/Trq/6sV1v3Na99c1d//q5u6/Xrq/Sbrfm+p+WO5Muj+t651VKjmt6/nmrrfRrVTX89O6Tho77vv10vdN5RxR2/S9pd73lnTfW9J9B3XZ8Wns+/XNfX99uu8wLIOmLNlanCVLpMHC8Fmz0itRorPvkRyMSYkZnblPMqc4SIQkxvskcbKmkNLHkTFMBvB2giya31VuFSLvjey1RSsecHoiuZrxf2RcHN0/ZQVXOLh8jC+LyjAkE2bDoiEjdwmGoKoGmeRJMNuDvuYHTlgkuoX2+iKRqMMn2NtQpJ/oPP1nj4bO2iJsdj1e8SJrXVGlEQ89ws6TmH9RFS7wI6dcjBxSevxCLnjlPHqs9xAjnNWMPf2Fzkg5H2bKLnygkMwbsnnWF1tJzWY3FOGVZuVvQ89IOaAKC1RhhhSfHq+GXGOGS8zWezVb79UMvSeMWkKXadoSBmuKLtGo2IuBRf/shkhQd6okoQX8FGhwtkQTJJ/ESNg9XpWmAl1RQj3CNVfo9qkK0/8g3f+i8qmVYohB5QOhyJqtD/xkpj7wg2l9IJqT3AUi5XUak0dczjfQuEVoXDQsy60u9jSSew6NwJjUhPxLTOkCqE0IFcI5a4o2aFmttIftIDfNUtWY4i0gdQs59AWQusCkLgYFcjDz1XKeeJEQvNDpwx8bwcgm5TLiE4WwzhassHweQV+vj6Af8SUJIaz2AzyaOkTW3QZZ1ylZ16FXtEHegmPGRXamcZGbPi5y1GYuGRcLm8ZFHnKRg1y0xONCC88CMy40eOaFGjzzhGfEOvkDo9p4XJAg8djQzDCqtQKMoAwbeWLuYsKZ+UHYzEUZEn7Y9txDIiCJJj75ZkjkMCQ8krd4SLiFruYhEXddhkQLD8JihcgwE+5ydwbczYNm3M1wCFUyIEDYORgMoHMDcX0hbt7wyl1dnE+IxgTFCCjOSNDibAQtzkbQXJyBNL+Rke0hiEMtEUtlhPg8QtplhPjVSlfYBeoTL5IR4oPyfjgnPULaaIRglOTiUZLwAEqd5kMeJbpplIhUOw1SbaVGgAUdv9pZxYOA3vfi9d25KZ66hL0W8Q8dhycFo+WhjITgMxBRz0ZEPRsRE7U1v1Ftodc6BBo5HhOiZsgIhvRmoGaMCOemq5l8WnxzrGYy1XJGtKZKKCgdKry0HaLBK2jnG/tTJGFtkdFdznFPihCHlrCFOxH3AAqUesAcz5HMpDoyUyfafXAG2M840PRsA03PMtB4dL101cXEmMf1Ihxi9LrfILHB262UGLanxLBA+i+gSyJCG8YvUdVbXcwK52ReVOWiJgro2GhhNU0arAAJhgU5Sjq9hRSH4rnlpZJb0vEQtDwxjl07yzTvYjpqmoBdjFFCx4UUw9wDwsR6mjIVyXIxmTJJL1ebNLLfYJ0ksoxaQk2SUhcHVSj4Zu4s+IU5rAeiJw4SiQdEKUSPxQBpyOiRGKAZUYBBU+zB+pNsdH8MYB3g/nSxg+aJMINEALMccZxJ/5LNWkRnzLFZ+QM9cw1kp+HEyoGGF6Y30gYYVkY3+DExFRGWCEaCByWr0zQ1w4oaQbwyMfp8ctodeOyWxDDhXbDdx6xeXfSgWAoepseWRvXbRl156qABkkp0cyUFnXrqJk8RrsYYSR7lkkc+XbH3M86LfkGBge0VC4EM8rHE6doctVN/eEBkon0Qp4qF8qjEHif/qqKqkVvlZDzQSI2gogxRnqhQJA+LzBvL56q30Ztc8+qx1eJIbjaNvn7ak03mydXTnqwyT5ZPe9JnnixqfNJpbrc33u4wt53G23P5NsLbCKtE5JlGp99tqO+ZCEtOfn+6QGUQX6m9+PhKnuMrEl7IpcILcxp963MWfGiazqtUKuVbkxdJPncu7Vs/bcG3zqV96xxfbX6+QRX2rDcnjnWQdqxbn92xnjWmQnTM1emIAft8Pey9aQd7V4N/vb3Rvd7M1oXxr0+l/etN5UCIfH3dv56Y5mPP6m2HQd3PDmbxs1NKqNmTnuF+2HS/wcHufjZHQosjIXhpB1MrZ1cVVWjif6RtWup+hAdjWsOYLjb517nEv05NLnky62OzokAPyfRZU+yOzYpCk2FkzPtc2qwwzt30fqtZ6KFmoAfPQj780bkk9WR+zzO9ij1P6RbpWPIk/MR8mUezW8rirHfcONZELpq5eVjFrvI0fOr+8wz3m/AEUk0etZd41Cmq5mK7HVzjWaWAqZpt99awVWz3lmbb3Uvb7oT3S03aTpD2Wprnu8U1LpDBnuTqZYmSMbGFlOw0pIg91+eR8fX6yKj7z0HsP3e/cP/Ze5a40swxpWbfWSUxldh3TuRd3LvISayJNDONzdDXzOS0LZGiZIOThoFTbB44nc/hFzcMHC2MIZezXWR+bhLYEl+Tu2UCSn7iudmNXla947N5zmlZMHZ2s+w0+M0N4j6D25xLuc3FGeiaOLzNrRRnoaswVk3zg1thdrEvpJrGUyFsTcZTW9jGZghdN42nXHo8UR9SFJ8TD4VO8X/ZEYqTz+eHhYQHLvz9dKApYUTKxCo0Wl9dL96FnvfiXOgGIZ2Z+PpZiU/+88L/ZP+5bVb/+cX35j/XeW6d7jynUZxlrOmZx9p0z/nF1/Wi3OZ09AZhw4K4za3sNufgsrgmhjyj2+yShLpEs/wMbnO3/9LIKul2jOpcs8/sT/OZoVE14+I3+cztic/cEXbIRDzNZ25L+3f+s/vMudhnznF4AI7tU3elfOYn0sAjMRAv3eLm/XfN7AcXmqK3jXP6zHp8dsI1OcFtDXRscoJbEON9CZxgd0YdmXKCod0LheIMTnAr9eSZu57bCc5xHDN67C4TWNBNMg49L08lBuFHD8ZFm4cDF30wLpq02ZK0WYz959dzOrRxJ9c1+c+rXjr/+SXwhbF4O7M7PGdmdxjr/OIR18Qjjp3gfyLnakLhWKFgpOIvYqeK3vtXtRHzUrC9giyubLApase/1Sjgn5Ho3PnfqEZ3jEbvqsKXkdukU0ZQHvN8aF1LRiEIjCdlJ3r4ya/CM0zsOFucNQBRjeZua160UGbWzAYC6BIWZHbPzRVrZA/pHapLjY9Er676FZcaik58/6uWsIG6RxIaXDfOY1kxj6xgC83oDlULtTOC2X0juFUm5Cp2kfRr2YbNZRFbUQnZr1QQOY0L9kTOKPVT6l7bgwc98AeoeWKlxeMC83aZYzWqqE3AMXTHg9fF3RyFdNiExpu3NyJLdzEfYNgBRxraGzG+Ryoeja/RCjWlqEUbbeL1ayvcIFlR1MyJH3DdFSLZRqKbDWke2dgDLYl7wU3IVglCeM/CkI2cy+IEb6WRaAcbOQt2I2t0O1gI7O3I3kM2JQnwccK7WvaIIcekB8RWqpes8FF0tldnSeXzM/B3jEe6z7i6nMCCnwLMWvlR/fwCMmlIqwbr6YpkiZx6Rc1GgQiGCM1vVENrQ49ISdqFTxCB+iM0rT2Ekj0KaXZiUlxbpGmFJMpQHeTNTCe6CrawxJG1eC3ExWb5MGKBnJ/1FSE3ugYGxHT3E8J6IGxM1vVkKwlZPZA14YuCPlZNJM1EU/KYvJZppEWUJQPa2vDCdDSxmyW9WiHGjFes6MSTpgseaRiRU0vqJdFE1CC0QG9WoSkGRXeG9h2jrNc88Hg8WE+u9nYS+Qy1Q41b0UKSUJJX1DA+SgQDbRtatE2LqrFFFbeoUi3GHaN2tWnXZvrg35uwAEeVM71T1BL9CsbOSKyUJGJwWYQcJDGTlkTCgiUx45fpH5FEK5bEjPwQulmfq+X0dm5eZhybKeI1SXUGUp1pkupMXaqn1WUnodkGUSe/baYgVHoQrycV+2gdJNcCailERgaqsEaIPqx0G3Qv8SWq1ZxgU7CKJ3EcB+PjKktG90lg5EP95/mantPMBFwfJ4j/kdYUiLehB08e+0GimU/RZdni8fG2MAtrgTNRLfFQE+Zo1jzEFxkO1A08QSqsFfevLFd8D0JGEqiZ3j5bjTSkDPkYOSYpF4VbupFLvNW8kPejp2K0V5OhY60fK0KQqc6tUOnBthC+YEhyIiPF9Keir3UmSP7pLs3yegPZuT4pX66sXiNVmIX+QdtZeAgxEYxPqjgNEJJvKAEF1tA2WZ5+3CZ5VfHbNr9Bbsi1jpAzt6EoqbtCT2I13Cg8qmSEw5XSSHQLsQu8BxySBCgad6GZX8NcdX1PpUhUaUH4kWkIQXFZedpSSdmTwmRyAin0D7M4v6+IZzQesXLBgkcina9CAYZetUpNjVR4xSEI3QgDS8TQNrW2m1qldoVSwU0YNtAxI9Uqyp8BbbFlhc1uQvGtTI+LiDKtHGyOTgvIweewFVuWngQisCOjJ3CVx9UpxhxXKM/GGugZQW6gkGg8chCxgnlkA/uj8P0y+AlzIxWLCXtT8JrQMlMe4IWFrD9N5ehZFI2eSdFoKBqNxRezBsP+tPxA0bBYmrxVxh/2IckfkRkYgxOekBHaR7hStjg64o3AxkYHM/DXWHnCjAT/YYCPlJ0wGatrzZCDgFlraNZBIzQ5Rmpt0TXmj2d6ThMXq6eokMwgRrOLPmf09Wz63CNyGZXuIGZFTdFUQpJXcEXqeQcOS1Osy+BG8VjOwGaDHJGkyOQjUifKI1Y99fHKk0WsBcybaU3gyeBlwrLxlqJt3DiN38eSsW2lRlLFgn0kCCiIPd2yYxxkpY3MopTcG0YZuTe6rsylSO4tkfwZ5D5WlEIYVoInphOGlJwQxUasnahKsuoYjhEcLKw/dl4Cic2IxPI8lhGWZ1hiTTNklD0NTM8v7H/OIfkoJASoMPUAnDDAifSTxwFMGWAqXewJACcNcDJd7EkApwxwKl3sKQCnDXA6XexHAM4Y4Ey62DMAnjbA0+liPwNw1gBn08XOpZ8c/O9flTsAamngXgC4A+BwGrgfwGEDTKaBBwBMGuBIGngQwBEDHE0DDwM4aoBjaeARAMcMcDwNPArguAEeA3DCACfSTx4HMGWAqXSxJwCcNMDJdLEnAZwywKl0sacAnDbA6XSxHwE4Y4Az6WLPAHjaAE+ni/0MwFkDnE0XO/h9cMEA59LF7sWT2vcNs9LF7gdw2Dw5nC72AIBJA0ymiz0I4IgBjqSLPQzgqAGOpos9AuCYAY6lix1PP3mWSTU9ZacVF8/Lorts6C7WPTRlW1VRYi+R6oJRYqdN0muL1i+lsskuJU96EWHtP9qlClhlP2NxnCH0zSq7j6XjdioXr6UX+zWWeWHQxmvpMHPLHVhLR1iZQ73Y/lMphQWk6Kfy1H2sGFda03nqPvYPcep6spbumzx1SQxBA1gUbwlegZYCLAf06i4ke5AxwWvp+dDH1iIEk7GWXjIr5vn0WjrqT1bZkVxAphtZCF1hAV5eAWvpBVTcHi82Nqyl+1H4LNnqfsNaOo7mStbSN/EhWfFaOk7lSq2lxwttvJruI7Aoi+k+cgVahcqDLyhZ/VnS1XNmGT0n5ESP6J9VI7wwniNi1FN5eSGl8IIzz3NUykuWx3P1pcJismJWaFoeL01LP2+T1OjWcFr6eXplulhPP2/uDVWOZcIcLw9yJrkEXXnZgUOqOQScc/G6CXJPJkiGZsskL2HBOzc9k7wQL/5hxQv5JrJ8PQ0dosRsSeHFhp6XpOcthpolRGqbctJKMySFz9B/hN7zcbIaslfryWpYZklWXkCAfGPgXRaOEHImCunUMjSsMrOg0fJC87s7n2V9Wsv6tOb16dyz53eXpktfiVNtjPTJvodc0xp1qUHwTH538Xnmd4vgETkbl4g55O83DKIXIHLFRpErTc/U7m4WuVKDyInA5cx6sz+DvM2QdV2cPeu6FGdB9jRlXedm6GgpPl+zpzmDOo8cTpNBXZfB9kQGO8POXl7xa2uWwTlpGUQGNeSwCMpADusZ1LxynDcZ1C3PN4Nax8u/WpZ/c/x+vAbblaK1h4TP1PKvjpd/i2bB1J2BIElNCxrHawvX65nV3IIhSAHJjqByHjNkaZZBWUwvo+d4GX36Wmin4W7L7MjxWAAKTbqkZPIJCmZxthCWhE35psXZIrDJ4YfEN43UTAghwZ0DuO50wYzXWmd4FCOZLJ0WZtxJwkunCbE7GtlWaZGl04CXTkmFry7m6kunxUiVc2bp1JydBMVBPQJtsBmfl07zsnTK2m46n0mDlKhFt74SmiCDnIms/MXbdxRXzVzH7Mshp3ycPZxLKeRCtUlLtKXVQyHhPWoJFXGjnsemCkUfZhCNiCLnlWNp85kDqcXPp2IA2cNPxACyhxmI04IfS4qZNVJkET8S30TW2yPp4g8eSK+eliRJj7jI5KzMQjunkUZtDaQ022iwHU3UKC+GGjWaJPYo7GoBaQuw1hpmbh0vhtZz/DB5z7imqM1iqEZGcEkygkHEBnWCDPaD0xZD3eZKZskILvQ3ZQSXGh5xRHh5khHsY7cnR4Z7KwphHyxsFnj5C+TAPiazqqlYt4fKrGrqeFWT6aR5VTOHVc0cm7SVAi+T+FihVKu5WllwtEyrTcuTsK/NE2eGPF4fK9u8PFmKxUR+7+2UPdCnmr0Hsk2er9k8mDab+xrM5t5GszlsNJtxpkpsNq8qG+dk3Uubg5oynusGV7Pl/KwppencupTNnG+wmfN106UlMTRzqfS62GwpEC78J7rclfQkqkGzSVlCOqnR5bppZtH40STvCoNHJemkhWldK0CxF5AdquLsUDGDCmwftKQy5VSlUM8Ozc/YB2MsU8/zkh2aiy3mdLNoI2W3zNxfMePKyiwCaPQXC9tukuipUjOqQoehoDU6rLnDeZPoOXunO9Fpk7dJGpjzNr3Y8slSH5+dDL903uYsdvGMeZuFxrzNggMLMbaJFzbZxIxvAUxRzYJFNPWSvM1GwtD0IX+xwOXEKGaBy4MBnbG21k3TWSJwKW2NYDW57R7SMPNxGqZm4fLSVC2AqnlefTdpmLkZ+yHCxY4lmcU6MTHScsVmsW4wi0v1XqdM3XqvhYoa2YkNBMAe6RxwFKMapEhkkZMksXITJ0nmU7lOZgyW0rKITMc6PeaAHiZJ0oPc0ayeQkOnKcSGoRAJG2xiMs2Yu8Ox72dNknx2K7knRfrcbFZyS4MRWqejjpNNw8aRDKPUxV7ysieescnqLjZbyN50C7klrcC8uoXMQ7jQlO9YnBkxHhVovqVZo2YSjerFGjWxjr2G1EXiGn68Ro06EzKt063jBJPp1vE0JI11XAgVW8f4nd06Tjs1hbp1LImFHiyfVGKhO806RhJ/Efb+DNbxvLR1nFKdq9mf9Ga0jhvS4MQ6RtWl57aOS89qHZdmso7jdDjJE3RhHefYqOcNcGnr+Oz+FPD4/iYbmC3d/c2WrmssXVC1ODMdpqX9pclCIqVmtXRV0lnxuDj7T81i6Tak/RWfy9ItQtFI2l9+etrf/Qee29LNgb/56Mn9xhPwmisizSVPB0ROo0fios3CislVnjak/eWTNnOxkXx1kvbHwV82krvqRnLmlzOSMzCSM7GR3D6zkexMM5LlCYLZbA0XGq3h97TqlonCnSaa7iJrj2qV9V0SV4T7CuudVcE1oYujibqxDisx+u4B7RQ4M2VC/rDyy0kkRC4ErCN7T5S5lYDs+p5KPlJ7NnCaUR4PObS/oYf0eHY9NioROtDxanQNqecJTvKJMjtIjm7eQ8VpLgUCiKQ7+JkPE0OZc508CRWJgqfBv5pbyW3k9ArUxK0hfc+hzuTWm2waDFEsCHg0gkniLavfskymfRU3g7khtIEf2jj5rXvoh0/91k/++kd/8+iPrEOkFLqH/vUv/uqu7z78t1/6CcEdfBITyvUM/eQvvv2XD937N3/wDN3vDHuG/uf3P/CTDz3+5JeeIpisBPCB7REtqdHIIaxW/EstbBrE9CZZViNEmX3VckD47yUfkTNlZKOQHDaH3XySEOPi5200UPiUSDecN8DTx5wBe5B+OgbsPvrpwZmXLrHMDpH4jcHfihF/kb0pdFfo64cG332QTACC1x2qtA8Fd4ftAAb3VdoPVebiclWlCz99+yrzh8K791UWhG2HqFDXUPHucP6hShuVDNuGWu/myy66LOIynDvUcXe4AHW002tD59UhKkh1xGXxOkqhRWqZ6kPlc/dV2g5VuqhI2EUvUhVz8XB+/LDrEGGBhkPGlR+28cO2fdIWvdKG9qW+dkGJG6FHh6hBah0PDx96qNIyWsmQXCFDuRds2H/g4N7RSkkMC1JgZccEIqmQsQ/Jsg2zoxWn8QUemuSkbyfJp+IooauXUImJ10jiY1wWBXAOGFU+Etr0z24qGzkyEsi4ckgcqIWWqHtPxbncwuy0D9XRM0gnbkXnOedC8uFGICQaqTFYkSiapJhi2PZQhY2qANQ5/Gr28qELzitUaqcrNffq9cXVYEz4goqe9laIW8j9VKCKHWbXikknoon9N4URk8siHQsdaAgnyuxBgiIRg2oi8+4XP/9pZownHMVERnVi+jpRFkRf3+NXYhUR4jRDYk2GceWUQIfGCGr3aNQbVZEVVQErgF0Haoa/EWKuSPUh/QfK3El0Gh8waIIhEAciCk1EF7MjOsEbg4xO86HTqG9ki5mRT5oRiqLcE+ulChRJOcP6hWw0r1zQE5ESKaCuG6HRSFYmVBw78fcJW07cKG2sr/VxHngG3bTJejDvOnKBGdnGHCvg7gpwDfUo212reRcow74on4otGsSerkG608pLtEl3Wn+JZulJq0LRMj1pbSgap1s0Tg80Ds0j26PBUZbSoJdbZAWUJ8GaWQXNwVidY1TQnEOVDlFBnUYFzZNRDRU0J+yEJpjHemdOXQV1JiqoA8qgHXXMSVTQHKqjUQV1cIvUMtWHyjtYBXVCBXXSi1RFBx7Oix92HiIs0HDIuPLDugrqkFdYBUl9cwQlboRVUAeroE5RQa1NusQ2yidWPHasePI0yPxphXnQ0aNY8fgNiic/k+LJV8NWjGk7US/+KN3EmOZb+boiCEQRwKfjKZUPs+XVPBk0Vhy4EJMoi2z1LM1QSAIWa8amUWCRKYN8JqsMqwHjXFwEV+J0/iFfBxNebIh4i5BqpNbzdmayFzJyjUFATkGl1aQTy+pPpY1zDCJnz+7ovo8/es6i7kfOeGjtppvBHkTcdldUBBdyN5YvAeg99G4gZXZHf/iPj/+2jbf0uDzO7kHl/Oz8/p9lRpHrhtoUXstKod3Rdz7znc+4o1wldgNECvXhFB2e5Vug7LI4xQvT95zQxk87frIcP+bwV5yojk2Ga01vyZ7wkBvQbk6BtOpJCR6Md6uekYBkbtZbMRxackX1Z+kvQ21ksfFUw8SHg8Up2ci849+WIVNYS04l89ITheDR4MoaBMS64aOz0QRdz8XI9kiAm3QGbjXpDI/m2yadgVtNOsPZTXMotc2qwYNa8GK10AmY1ALbCXMBkFqgWb8Ll6sq3fhJ1EJP2EkTPGkuUQudVDLs5KFOl92sLuiSJn8aoD2oY26iFuZCLZiyeB2lxL5Afai8i0d+N9RCtxg3XXg4L37YnagFxpUfigLo3Cdt0SudaL87NmaAEjfCaqGL1UK3qAVFI5QnyVsrQbK/JMf8x1U+VKPgp0VjYXfFpmmFtPr5n//0jupoWpFwTECRcMZ3ec+BrpatWKvgwBN4MkltYTBrfaxryO2liQqnW4jOsWjskxVlNAbhtoajLBl2/m3MP/ZovOUJk1vZLkgQrBGvzC+LV9JUE2Z2HbOwtJZTpkN7xKe7VXHDxsoZ8VrIW1lroncFmn3P1rd++dSWAPwt+OjiaBLwqzgkXYQCIMc27CAlErDr24q+kTpO1AQZG5icoVqgKVRKuyjoFI2nGopHpZUScijkGcfA5CmpNagpo8BI8xklV1eAVAxjuS00qlAGdrwmgtSMM3HfcHz0KQP8j5wqTsgWrvwiK9ZNkXUddFMRuzqR4WniN0q+Bi47FmILqlIULcKHULdwVI/jdOzmal6GJXtIlT32ZzzoOweeEM1X4C1mLOKuGqVJzyl78DDJJAozLFFlN8ywcXappaKrRpGKEOkqmU/0ysLRsi9vUjU/UNfy5iQ/8sbHptdEEkhKjk1DtE/mPBywq9hGwguo7yzPOA5VUUAV8roXI2IniOA9AtERn2ryo7bxCN8ihGFLdYX2GOGDb8+SoebHq0HR72bWckfxYC3Nqx5i8PI13pAzdzU2TeA+wg88mX9l4pqiK6DZ8rbK7Cuw1xT5IFEYqS5hRKZh13jUdXulEE1cwwH2q0YJeRW66DuxUPpuR3ea8rloSo3CAQXRqDM5oiZK5GZ78V3ipFTc1IsOvehGpVGhFA2Hpnf2ke0JcmVGiS6g9FWjY8wf/gfmrKEFjT0XCX9O0M3x92Deao710vxVNV+fj2mEniMVn2iQ0CgjIBrmc/GIVZj/2ElBRrt9CfeYJS3HkuagPUHbjeXDjdHWCOvbInCQNNAGCS0saTkjaQ7RWARnek2GAC7aJwrFksbSQVXnjKS5VAUkzZHX7VjSHJE020iawx3xy/gERCJpLLWhExORJQ0RGbh6o4h8BN1ENn5WdhidSPmwTEzxmMp0/1vv0dQGb3sv8uatkUivl7123zo07ZHaYEZ/pINL+G6Zj58A2VgJoLBNLusVEl3Js4EZXKrZLM1W2NehK4ephqsCb3jArWA+b42bHyYqJpjv0/AojWABK0suVQnjUSSGF/4hF9Ej95Amy5lMAAs7duMbeU4gLYnmIgfwIznlQtedlcgXclplcxa2wPE4dcFHoqQsH6hoP8QOy6s4UBvWaJX3RhjRe03RmUePzivsPqZ/U3e96Bd1GMri53VQ8zZgmj1rd8rUhOgW7xBrl01n2ETz2D048b9LHmbwOuiVQbU+ucDYY4vZNPRoQMyTHQ1eMiLI9/Ybanv4PqnNpdvzGxaVaS7YQNa2TT9jEkmzN8CHXF3U8lQa0qC64/N8nXTLF9KFoZbdBi5zJNPTj0/SR4+RMyAJxtGTdDlHLp9ILofsAb0cdxg38iGjoF9fg8QLZN32gxt02VXFYc24Cqr4OgqueulqHWIMbvRUveamRgK5BBaOtOdIe5OTz9Xe5qQ9abmQtJzllvmMNhsdZ0llM4cIuL6CxSlRz67Z1kdsBrlW1Rev8FwmgDCTCIzPmdU2lChLLmt2GyZOxAOM60SwJGPYxlVrKDZPVL6F7V6qzHMu6Qt2LcykiYAKrCzEOkmV5VhYR0mRgdFw3KCXvAJ/o4Ef9etFBd6aTOxHEDskdT8BIwaTuSV6iXiNmDletUyFZ43LlmO1hvkXq5eEUAavZ+L3PEwziSazUMUYRhUrJkGCEEBf8ZUhtZ76reU6wLUdYemjVnPW95QzhhGIPJDgEXMuMSyXTTnRj+oy0SQp7XVJ6RDxcEU8nnpO8dieiMfmRBxFUBYlghKyoBART90D05HY+ipeS8CKthc9cJ/RUC7yqp1gteV/JqvyE1CfQX2ulx1/hUiJ6aCwAEW3ulj1WdF8GLKkQFttsyPdxh4uzk3TVfEzEyfCWy1zM6eulW3MkpUszdhFzWYw2gyzEKcMzZM4iIDnyRzPS47MS/kQM1WYNVKV5+nZZ+PhDLiULutL2XgO880clkkmv0w8axr7zIlnzYzMmpl41kyJSh5yJRbeWDLtwc0dwewP2aVB44lfgK5Z8dyf5T5xY8BD2vNS870Xz/dsqGRZmuM+kbTJC5nIrBfwMPBiBLOx7MOUY6pl2QBFvyYiy613JVvvip3uhFmfcFfL9M+2A9s7bHYi4dtZIxMyw/g8RdUoFRi0SO0ox9q3h8OyChcIB0JmJAtMlnhghMsSumkI2+LZSELQMLLXGwtEJCVi4wHRotERNuMhN45w1ZiBVaZo0w3dfMNuuMEmDQe5OMIpK1wu2SvTazfWDxuasHVNxN3ZYIjjYW+Jg17xBmkL2imsYADaa3p4U+NIi7aUFk1kjA5yAfaAQ1Qxrjw/bsMMqbLsiZTvd3SZDbmikRFehd3g0DhttXCKBxlWJUS3gKFhZyi7ZBwZmpyixQOOBSmCPRK311iTeLPe+hJ/hMAu+bMMdv+DWd0hobNVhNMiaBUe5TTOFBRIK29cJpsVy3PlthDxJF7AlVoqc/hLPrx0R8be7RVexytUivjJVhTfc4gVmhfsmEp1HOgWBrDNUQnens2uDOdF8HbjKmJepNfKLbL82cEhKV4956AhI5ULTYypPDfM4kaJo0zlLvb56qGpljh4TdCV1OjcxvBTL99qCD/RpBx2NYafuvhWQ/gpwIv4rh094fU1MjZbxVW7yO4L7RV6kCNRcwH3Ym0K0R0AXbwcxdH/sDIPP8G+Sg8Hd+aHcw9RIQ4I9RzihStZeZJA01yORCGy1MFraN28WiWRqC7EsUxZvI5SEk/qMsGmbo6AzUMkap4Es7rxsCd+OO8QYYGGJYbVHUe6EHOStjpMZCsJXiVBK45EdXMkCg+pUFMsCucikZWI6UmiUF4chcLBE1jMbYjO6BmjUFoy0fA1Ogf6OhPmWSKS2hDtmaU+iUKROkZinuZIj8eRHqc5BuVIAr6sgcg6C+cBOWU9YwzK+WWxSppqwkynYlA5E4PSSQyKc26BaTvxOheS+AcdmCIIBxqCYVEW1gqrOZFP1vbbeGF/Nc2A8R3Oz7WjVSOLLP/nnvLIgAhuqVhyhA32u8d2RHBj0TK7yxl8izH1q3wegx3cXLHiTeFI3FNmSzgZqjp16gWH6IPXwKGiVzHSLTkJZsbd4k6yW9zlMAOW3pBM5s68W9xOdotDmwRvYlOS2yvEx2HEewdD2X6ewrh+CkTjrmktJ1iEfDREyew6t+INkcmu81QxsxExIccaswZCFLVhVmmOu0iHlXRYJR1WjdvjTYdn2R5f7zA6GmwMUyxIUR1rKMlxGPXDMnzefcnE2IgpBrs+5cyPZzluyZp23JK1oQdplqlzPJKN72XVtMWUbAFdP3XExQSBGkaq5Ilgt2d8kIWadpCFig+yUDMdZGEOxBAqIpGMcCiPgKumrbir19It3nM6QQRgtx6nP/GyULBQNqE/bd6YMNvlqZWgPK/kl2QtlwPDZi+8ajqwyB6v2NgLr9J74UnQeS+8gqGrZNu7nTrHpiCR0Kz/47zK8h5Vd4Sn5HRaudmeinrsoXvxmVaLv41rDz0AQPOHce2h9xFwyuLP4tpDHwMgX8W1hz4LQL5Sbw/9KQCPP4hrD72fgDMWfw3XHvo4APnQsT30OQAOf5/IHvozAB5/nsgeuq8GI5Q/TmQP/Q4AzZ/AtYc+D8DBicv20Fdw7eHAZXvoYaDJX6O3hx7FtYcv39pD76XrPmwqtYc+SpfH+XvG9tDv49rBjlJ76E9w7eGDt/bQPTX+YvggXX6ELo/yd8jtoc/g2kEmvT30ZVx7+NatPXR3/HVqe+i38T1jjc/c2kOfxjV/5tYe+mNcewNMWFAMn789YhPwBwbjSduQ4ii5d4cBfNKQrwbgIbyTGdDntOklKjsL4BN4QsDTAP4Q71AFZ7Shco2A0zqmGBU7pQ0twaaT2qAJOk1pw1rq+QltKAWeHwcwaXhxTBui8LeMdUpOjgD4sKHuJIDfQneo2GFtmAkBqAHAboUpm7/YbPM3oCFOZ5XhVM3mLzaL0EzKV7gNOvTktG3amSTgFIAPGhE8aZt28M6UbXA7g69vAzhs0DkO4GAsj7bBgHp9lK6jMzRh0OhQwcXzTBgbRx6ZoepF7h58lJI0mRTFojZmZD3O0yd5drtxkBtGkAOfwaGaZEqFvxOd5lOg7eiUy75cdNJFzpXiRB5XLEjP5FEZexI/Uy7nnZIna+NrmtBvvFPNjqZcfHgSfp23o5LlJeeM5GQY7Dh1uhrN3xVjiJRB79aRaOFuDhNnx+VAKJoj4zcdrMWk38MUeopJEJri6BqcL6xLkYXOq0o21o+n3Ejd0U8/Aj5G7s2efvoR8KQbZQg8acDH3cgn8HEDnnKjEoGnDPiEG80h8AkDnnajuQSeNuCTbuRSQ0+6JidHVLUKzTcV5QOHKniFfNlQ+Ua9m68mki085Q5o84XEnNnPzh9O9BKgq8qZhQPxdMsfMaQpRkL2R+2qrPGzH2YyBujOyXebtStigIv0gf/wVGYiuph9LvmoKRKBK26PybJFpAwpf6Pi7Co2ImRVin1eL+3zkmvrsr9rfF3ESFx2W9wGT9Zt9nXdZl/XbfZ1s7FdiSOIK744sOLrNtXORVVTICODxZ8MHEw3iQhJPAgpG9hadCUbKPWQkTFGvDCzgdc7sqN8NpiL0PlIxStncOwLvGSkbaa8ZKU5iBxX6uCQHguBDiYNwh57GDVxmjN+jGzsNGfFafbEaZakaXHa4TTnsD9hmtPshl7KaU76I34vYih0MT92oxkoK+OUR3zOG+98wsJLUc1LEJKDuxKvGkgQIddLPxhJwjXqYzOPCMG+qLrcClMoWHWKKZh8bH0aunk42GhadEEbGeJFYIRkDKH0NEJ5goPpSKaJUNi06s1AKBWv3MxIHEuIY0ud4LdndnxgfUBiVshhVBjkCWU89lzWy2JLTiiQ5YNAyFxy4/gDN9UQf/h7uBrJUnFuI4cDdNBZwYIxPpnB206e1wGgqsEidbEMsqEntGHpBRwlqp/0aJuTHl12chSb5DhOKj75MR4BSrwbL3Q3cu7fY7Ehj81LiVWP4wXfVMRxSVtIQ3tVGg7u+iK2Xbl8hD+blg49Dgsum/9Y6uOboCPGNM7RpKuRaH8tu76n6EoyND1lz4jEMlpoTtsDQ8Bdj11NbEENvREysnfxp2Mdlrmb5GAyqNrxKkEW+IBj+OKz+BBRHBGrtvEcOrjVLmI1/c1H9Wn2oOSEOkmQJ+PUiQ+iRGMun0+J36sRSzP/zik4fuIbediP716bFHdMcUeqi497ezQ+Ckt8LCfludRrWV+0OQc6hQY7Sl5wtcQaedVAGgIl+USzOnYbQ55LnehOsgScaacpOmbHq2NUYI7nIfbMeLk6PnTNmdkfy9T9Ma9+Buq0Tpt/5wii2eBqHOSK4HtgywiGBsJ5oVuivdE+SGNPlV0tkt/glcjbZ/+Zy8R350k6vy5B0t4yj/7Z7n+yPshSwo01XU5jxwWn0lvRJ//g9mpU3kBXf/kBuspvwKrKL86ft0ajj5/NrwX0/b8br0ZFfvA7x+jy4118/Xfvpesf+nz9xb/ZXY0e+duLGTh71+D6UZp/SbU98YEpiw/Oy+7h6LZny9IoL5OT1rvUUhUO5nq4TnxfD4tLNqlFl8b4p35zCk4thzVDHld29EmNzYWan8EZg7oyebsTbAUhJxpalBunlo2952AZsrFixSnIyJaheeHk/cDXGH9K1jawMsHIaZMn4UbWdTgp2I5+/qEp0gaMOwxOEI2sMahzXt1g5xJjlTc8OpHazQYa3uK0IRIjXP+yBPrGhxsJBCP28+QsI+UZz14YgWy/qeIGAp078oII9NEHQKDs8yGQqhMIb0kIC1s0JGIUPfIBs+MG3BcIu5dkiSx67AMmu6qp6OfVrEW/6Wh3wrsz5IjVIOnkfj0ouUyO+OjJeSwocGUFSywSoiJuQQthVydnm5MLYm+oWD2fZYWjLrL7NFY89XgFh2OFHs9xsnhQoKmoukxZvG/js+8OMwfpHTvSY36FtNF6504pY1WXWHD+TRkyUmAJ2OPR4Ohn332wkkEKJDW9gcPiIY5qxKfuK0gqDzOHKlmO0VdgKu+r5CUFM4f4bg7YBfsquUMVf1+lEPoI28KnccPCUHg3ezfY7DQU3E21IOibJa8HEd8sF4OJgauACC2Zd+CfHe0dFV3MyOvqRVgLRFO9jAvHvfP4DSsxBn6Y44h2DmjkBI2QahA0sHABJMJ8goafoNFbRwP5tbw4jXTjIYuX2Tjp2GV+XlnyY3qusPDUwcYAYgnb7fKE0K0u47TmOkPIiRiTzGe7zhIUXJIuCK6AEQpBnCv9c44cFdAnMRw7WiT7A8Dqyy3EJ2oSZLBNFCiUlYku+Qlim4i/ks4hVrP8yuNWc3AMP9gqhsB1tLeKDQbIdzajUbYS4NMJZKiFbFJgbbtXLzKWnmN8LlsSgbk82XM4bpfpyDHIsiiJiiMqitAopI6dJ2+tgu9zkdMta5mCWKp9q6F9K92+zYur2P3qkk1IKoPpwsy6SFvM+Syvk4upy51FYjK2QFmGPq5QRcxnzc6FPU6OTqWAyb0VEegCW5tUA6xfdoM9JLTYcUILJ8SmKiw0VmiZCr2kQs9UCJMax7NxTfPph89Zy8ppHzZyn+vUpZdBWh3TFJJII9VZaxIzcBonddBZj019SiimZecGgvzEix4+WJjj6Skict6VdCvqwxF3lv9XjkKKBc38N8X+xixRe051UzNF7fW0qH0c1Cb7ElF79auM2lMbErPn1tglTeL1WjLdnzter55fvF7NHq9H5TOEyzEDcQhf8VSa9F5L73XSex2fli5npJve6+cRwleNIXxVZwGizyomN5VbyLDNh9LPFI2mKSfEnvdni0brOBqtm6LRQNX/OCZGdSfWpWXu6yKD+8J4ARireC4k24qegaumeaHozmZTUzZZ3FFtWZi1ldJqtv8cdD96UtIxrKCLP14QrC7SzA9BLWucvvsv+HX96Mf4hRKjH/6Ighq7OdJ7oszuz7JyPWUdFHGcft8n7W9LjbyOJ/vyMYulsPagBGyH3PLUGephZig8/BAH9OipazlK2+TVZYawwey+zXdRxT+n4YzakVaW3OeWQ5pOop/Gj73mx314/K/xY7f58ZSF50/Gz8lr6Yu+y8fqPsSZ3nui0ihROb++h2Y4qG8+139oYh/XUrqLrlHNrrsOSu/lVN8LQ/CvbKM+rltxhKup7cC8g93iFr9hsub9D9ny3UrJ+o7nkYonTKf5/qGKP1rJpXcdZWSLkst7wnLi4HJgy8NMPq0wGjLxKpTgddEcb5F0q3GeEZ+x3rRNkh7TpIJ5GeG83OqxosWbApAZ501HlLBs2MXppLHMVMtOHUvewdlUOM4nYfQydfSiiWuK1rQdnK6cwRj6yT5Nd9o+zdCSPudGZNc4YiYc5MTRhS7v9LWwycocfkGv23JuBFJV/X/Ryk6cPhquiK2YA9yRrceZPIEtjpk599WXQ2FD+zkiLXZDpEVzomdPqEbwtRXo0Vk+rFGxk48H7KuSiysR1OAWDDo5U4K0I+/BliAr71/D1DcySoWSpUP+agbSczmbZQR7weEVW4QJn04ez20IQaB++XeOidhZnNfomABJyKnG7CoXJQxkmqHp7Vo+a8fGKeP8y/WENtlzfGlLlQ472tvZ2fZ/oGnq5ZRKIoaWHWiIi9FUWrEd6SSH7+jHk5halT88ycaPxaV5FSJJ2cTxDvTaPskpop94QzGO4uZD8xH4suMKyM2osjeVwKrKjlkMs2sW79vDFlIx6pJYtmMi2TyhRfvYBTUZojznc86/vK+SILbAOolhC2wnIWzZHWzzHlTjycmWjjhpoGqymnSq8rKOc5sS+9P3z2jlwpnyx8tOtJBkYiFmJTuOH6o9mM8R9I/uf2iK10ioKNaXN3LUxkXkTkvqFrfBTrIakUy3DearCuaJGTcXXMuM02jxC9jgQLc4BhQdiZvo2FPm3YmuSLGWRWpCDttwG7E5j1gKjX7Ni1b1+x34WouqclAWYUM4vl9w+Z8NPUYkOFJtc9qJxmn7QGXyGFVAVCPKhuwAWmbNh1d8rLJEkUfZSlgYPfZAvG2HgKfqwBfc6AkDNVA4JAqHs1H4mQdeIgovTFH4ix5TeKFQ+NwDz0HhMKFwgk0ThZ954Fko/EWP/3lWCj/zsedP4TBN4TBN4S96M1N4DlF4zmwUfvjBl4jCbSkKf1QzhduEwscffA4Kz0konGDTROGHH3wWCn9U8z/PSuFzDz5/Cs9JU3hOmsIf1QmFv4epT0wSvci4PTLHnLc4fQROtd24Ekm6JrhE1iJt2ZUiy4nxLhQccSAkh42MTUpRjU8FV3EYFT29CutvY5dbJYba9kRnAfGHLSJvT/K4hW8U6jeKkcMnjqSqSMqcmbGKYiQSkHqjGJeZ4jcizSE16SS7YhU+QcEeiTdEI7R8iWwxtv2kp3Bg5POwvMgjE6RmoyKy/G830PZXStT1TUR9HhRpouGLYcN/OlH9P9PsYUmwyCEqX8ipYdbQ5Det17M1lJF0QyfKIZ5W4XM0LClQq2Twc+e7Hqq4HJaNnrJ2VMh1GY8+dC+Nq8WWdYVl8X493pPxlHXriJRrKsGHxtBsPHT+q99d/MYwU5ZPj5FvYUd/b72GlcGdUAzAosr3sWUybtGetWbWE1H+uuYa7KZyca5lqkBflc8IUbyFCWtkQgDyWfRIgKO+EPLwP21EsqaYfHWbV9KxAjnWJwpxtpe1Aj5vsKDMm6HMJ6Lq50WTLFfFq7LgTNnsTNnVlG1G97E84uxh+iBvDN9GivYjdY4QvZ/6E2mpi55wnmbQUpYPxczS2sBL3hjb9v1W/K8jn1sMLf9BmXuQTUZGOn/3UNW/e6jq3z0kkUeoas/NZFTzdw918t1DjWCXTj5tB8O0vnCafAPPlcCDNyrxP2TY6+A6/uLQlhB7S7CuAN8DL0AyORrBoTQmFt5m3wsBweRriIjC8WkSOIlIjfMR9qZCzp+VFBNHviXEh2058pkZSZ0MOYLThe9OckNm83EXPTaF8ar/R0IlXv82W6Tge7FOI7dI9tPLd77sHgnxyGH/hTgjkbNdChWOo6g45AOHAwksdo+Yu3IAP6qypVes+AJQlHPvocwk68AyuayObGaVJfqEAxUECmU6D6J23gYQWmt6TLxZmcOsYshiisd8iu7kujjyxGE9B+TgsI6W3XoIkvLLXfXvVHLwF5gnX0vDY3+nKk7wp7Li7qS/5oPFfE7mZMcH1MuWtewusCWR34nTX+chIBPnOoAEXA9WjBbyP+Y7af5DWjuJ4tS8ecGRHeGolpp/5D2cLWmyKYwUQvgL2OwpDyuuhL4078jDuRm9UD3JMRkapzvb9WMySIL5qosj8tHZd0+hTFc1aJsnyUpaIsqchRXqi+zsgOj6kO0biQnKsScFszPBuYgD3CrZqsBf/ENOrEftRAVzqiR1KD6BTaHirgEtMXoeYBIEePgwtpw5oQnYatGQ/69SykzapUX44K9seOV8dXPeLw7UwVH0LQGO89DjxPwiFpILcu3jOi/XOVx7cu3yYjMnMMsXXy2OifKnX238ZPEBPI6wmS1S43wiDakGE1/xeOcjn+PPQwSb8pzg15G0FJ+2JKFI/uYXOlWSafMFdUr9X9Kp7yuZy8K6eaUgN7wnLA5ZYdMBcuSwxWlDTwWV0x9fkQK/ajS010T2OG94csclbMszYFc14q/BaU6zY2EPJZs4SKywwDdUhBgHi3kRIlJr+P1sPPhlgYBmFz4N0KQgyRGKXCZy9yR6QUYQ9k7j5IeXrH965v45/4f7d49O+gdLTpXd6DDU7WEzLXpyOCZNlGdNejnajp6JgapkD+JhJN9CeibOhjExaWsdwiwbEMx0MHnNlymuK/rZ38YJ6Ig4MshuIJ/jxmB0MF4VcCai2pMmF4jXpyTNTaFOByrLDiTZkjRLO92OK8dWXTJNZMOruraofMuvO1paFg54GSFr+X9YH6ByWPoMAzRwn3sA+vEA9GUA+r+SASifiHp+SDv/P0P6C0rijv+3xPHIEv2c4vB0jHImQTkjKO8dZesvRllqy1TjKy+5cpMrJ7mykyudXClz5fJk3tABxlyMcpVCuwHfzyqlzcC2RXHJCaczKS6dbHhEOnIWwQ+jo2zoKEkDkEMa7LSCso2C4q9BQz318cwu6slOqye7rp4s2CMO71tI6aEvGYkg656/of7P+bLGP7KJjaNMIGzkjEePfVbiNxU3PqHBRWRGx7t6wTss+9gIMUEjc1QGVlRsZpTScPSViSovj81bT0pYvv+IAmIaOeNEpFOmxXjd9SRgOSnOP27onLbo2D7k4z6UyXtosuFmsOBUkwWn0hacmtWCs5ssOLtuwaWNKztlXH1KqWlfWpjgs8IV7+NCNtM8ZiSS+jTzrmIjCXJ10cYqHxPRwakqvG2Az+3B3ibN5yXxeQTYvPbr+Jqz5LPoxsOTnHgLttREL8Zf5JAQhawcv8/gmXwngvCzBT+yuLXJLdE838GhQS5ihGVGPg1HydH82N1EqMg350kyTZpy8Aaf/QXRTgjx1dHBgmLDKU+Czv2G0UG8yUn2HTsxhvGgQlo05K+okSYRHSaO87qLZHFbCL84yIGhaz7MKWQW2dJ7OzpCPOOVbyLahZYZY6YYnh9sfP4eo/5XkX/H4vc1Sf6Knjk4xWKmogfejat2WTQP6MYl1UiLQX9lNWrjqyE9QBOzip54Lx+WgsBlv14ewmnvq+KQCcUC2q8H+Qpy28dZWtG9VDsOcdhHeCBJnE2PSy2XfUPkBCr228Ed4pp8fRWp/7w7gZfno394L4cFVDrR2jhAXdFTeKgaH/oVUnt8LDLGrbWWHDmnbqSZlX+ETS5gty/e2Y76SCDmG6dFxwfN0PBUMjxP3iNEk1yIoM2oStQEnSKGU2j7twrR41wpkeKERfMNfW1QOpCtbxoN2NHUe6UBG/cXYeMJH5eBTHM+y8Pm2UqSqzCwQdldSWPC4Zoyi4H9Zl97zM24NSWtqej+98cywK0pzrZj9rKeMnxFa+zKHbxXWPmie/fI+2fvnWnPNofHJL170Y09/SIae7M0Fsbmn+hih7VpowYOZtTAfD5vooEhIUYm/OuUnghNBovEWu0k1mqZtVWceqDjWCuGNodk5eOSHGg1y7uR5Q+RQEe2OQcbx85UTJKqI0EdJelunAArMUizjmLjOHws1vsbLNbrsnkCX75W8ZkKyY4G7IYgXe/zaXecqsMldGiHjbse1iZ0k9zAdjOEbDOEsDdRDTDjlKSWWTEnuqpmWkrRn1SoReN0kiz6i2xrSL0WfSK+En0JwiGdeCRXR/mzzGziW9ERA4ApPgenFslN/2rQn4Mz2gROomw9DJGthyFk7Bjd/mhD9MFfCR1GYrBnlcXBTMuE4JiI+xBTnFhTUT2SdMVSQvYCYjv+9mSRQckCTkHiSdD+0cXRU+8x+b2y6JB68kz8RKJuMqwTDOsZdND4wRLLfyXH06J9G6Ca2ArnT2lZ0bvWk2FO/ZU8xxL5ORUrITUrww1MX0jtMkM6qk1ic0hUJssn0mOGGsjpqMhKFGeZWf7yhHXTPCcixM/umTIDSQQCgk4SdYFVnxdXm4Q+gyH5HNRpxdkHmMCV5Gdm46JwSuR9Fe27VrLHkK9LZoXFbhzZuGUxx+zZsX4ZUNXJvokwlTUXKjOxX0RvSKJVMMQvd0WPgjl+4yDoOei3WtHjJDHR/OjE3cK2bp71EH1EDqNyY7PA99uQaM2fENgzQv8uXNPjd1ozth9YgvuEyR3xC3wDwzAo+b7FhjjTq8QJEuPIOA+tkTG/3TKbEJmEQNOy/Kxwu9OfM4OEg8Y0R0d6lPBCy2KU8PtB3s9LzaE1ina5pD262iAkdPYNG/mVKkjyNNEiao1+ZkjSIs/n2at4CyPqMWsYfP0ynMr5MouaEsHbO5pcqviy0HC5x++W9s+ZgIEVkX7pIS/f8jNCO/M7wr8kQ/K7t0rIDj3z7x//xx//7mf+/RcW9WPoj3/7937ryH+99+9/A4+OfPLb//rf/ufjP3rvhG9biv/8+7+uzuYtPvbU/8alW7fceuvwtnDzxl3jt+zccfnlt++4Y2zLrt6LNoc7d4Rbws3X7dwxvDncs+XW24ctx5L/do9tXXzrLTdeNrbbspZbgZWnexsJ2ZxVh99KcGcKflsTfHMTPEJwewq+jeA2+t1649bkXhf9vTxVZj79lelvmArddGP9fm9TuUtNuZ1UKL63zJTZOj6W3LuS/l6Wem+I/han4C1N9W5tgsea4PEm+M4m+D1N7b2vqb3jTeX/yPQjhh9rev6XTc+/3fT8yab2nmpqL6cay/tN8AVNcNgEX9IED6jG9q5S0t7WLTt27BwPt2zdOrx7NwnZ67ePDW/ZFl6zk4QxfN34zrEtNw+LyIXbbh+7ZcfN4c6xcMtN48Nj4bbh3eNjt2+FrC4eu333+NbFK1YOD28b3LJ0y5atfUuX9W0ZvHHFsuHBlduWD69cuW3FipVLlt64pf/GZSsgsWNbxt65ePf4tsWQ4HFudfGtaJVF2fqyDqyN9HsH4TmPfpWRd/xq+ssaOD0Obh+/5dbd9PqVdmAV6N7j9DcXsmTgn9Bfd+odJ/Xn0p9HfxmmyfMfhzFeERNw9djYzrHmzu3asuOWrSNEOvTs64QLxopNnVhIvzHsEHyB6VdgcMIYzpvrrPn16Q99KZpf3GtpKlOiv3Vbdm8Px7fceOtwuHXLri1bbxl/Z7hzz/DYTbfuvGPx1i1jN+9cPDZ88y3EwXcykjffMr799hsv27rztkuXDG/d2r+UOHbjyuGtg0uXLd5Odd04tvOOHZf2XbZkyWV9/MLYljsW37ZzG/q03RFeYQxivCM1NiYCkXLn1viFG/YMb6UXpiEEXjlCh8VaeDH9fUKV3i24Aeue31SiI2L4gwT30e9ll1nW1XRPv0A+dqbkCzJzy45tw3vDnbePhztvCm/cefuObbsvD8e3D4e3Du8Ib9kdhjfSI8BSkO5Y1r0uZlfLeph+oS83Xx4yLvjv2wand4Xviru2defYMPfsptvGF++4/TYW/H+icj1UbtgSHdy3t6+vb0nf0r5lfcv7VvT19w30DfatXNK3ZMmSpUuWLVm+ZMWS/iUDSwaXrFzat3TJ0qVLly1dvnTF0v6lA0sHl65c1rdsybKly5YtW75sxbL+ZQPLBpetXN63fMnypcuXLV++fMXy/uUDyweXr1zRt2LJiqUrlq1YvmLFiv4VAysGV6zs7+tf0r+0f1n/8v4V/f39A/2D/SsH+gaWDCwdWDawfGDFQP/AwMDgwMrBvsElg0sHlw0uH1wx2D84MDg4uHIlobiSml9JVa+k11bSrbEtO0iV7B7fMjZuiBaTV57cRIpl9623bB3GLaLyzePbiZjf94SWZ+m3Qr9SdnjHNlPHokzA4yV+LjXIM26LlNq4cIteYsC6mt4Bv99Cv8WUrCUMIVFbTGJxy7YtEByoFGsvlYX+hCFAho/11ssuu+ztN75zPG4LEtAgLbjabFlfoPcwZh81bZ4i/kLGbqTBtyO88lXclV5cXBTesZ1kCx2AmqV3f0TvYGz/B/1iXP/C9DWuA22y8g63bt8yJu1SP64IbxnHo1t27L5l23AY9gLN3VQ/MIrx6csG1ivo9430C73xDvr1UnVPo8ntpMR2bhtevIsmAdYroqfvpfdebvTU/BQMnd3PA8p2Xc+zM142m2vNz/e7C+3FoKVYcgK7rW1OrlPNdbt0tz0vM18vUAs7Q/ti+xJ9qX+Z6rOX6KXqIf1p/Rnn97P/oX/q/tz5hX0+99m973zv+z7Z96br33vv5PwF/72ltOGan/7sssVXve3tN3zx7w6+7/0f+M1Pf+HLf/LnX/vGX3z/qX84bzmtbRctWT5w+RWvWv+atx98Pz185Mt/8rW/+Oa3nvoHyym28NPLr1i9Zv1r3rFt+OAHPvqxb3zzW8XWi1avf9O24fd94NNU+M+/8YOn/uFssXX1+m3DtYN/8OiffeU73z37z3cdeu+Dn/qzr/z517/5re/97bojf/pXX/vmt9Zft/FNb37HDXe//74v/NGXvvLVr339u62dc9/6tn/73784XyveNvr9H7SUFu7YOX/BDe+683Of/5NH/6Nz7gUL16y9buP1b3nbO+6c+OKfP/GdJ8/+87+O7b5v/PYPLb7hFZctfujzX/rK17/13R98ZNWHj/Tdt3Dqq988f93Gt7w1ky0FFy7+8f/asXPgVVcNrZ78wC/Ov+7m209+49S3T//NP/3ivBXeUDnwA+fA1dkex2vd/3BL7fdX9Pq1M3Z3VjmLneVOxlYZL9Oa31Rqy7whYzvz8zk7a2dsbdt2wXFt31MtHe7ldmvGc4PMmzI601Hc5PyafamtnFavVLjcWfDyG8LbnOrLayfdA8fted6Bn9tvznT4c3PthfZC1ct787w3Zy521+QvcQqOspf4lzjzPN+uPUyPyldd41xqj2evtEv2lZnB7MXugfOtXdnFrZfaYVAu1e51Dny42+84fL+72L0io1u6crU/vHS8UPvreQW3dt6t/aDw46J98H32QG7/29prJ7K1v3zloJ33BrNrsgVv3L/Afovz5lztrq75+c7cNU7tHu/3HyzMdZZ8wtn/vVdkCq5b+73W/bcFe3tf6dHT9zm1P7N77FLR8pSi7mkSU513fd3iBKpVt7lzWttVh56ru4vz3QXZRapqj+hH9bf1E4Xv5P5af1d/T51xf6if1j8Ozzrn9L9rElRVuPCKV1+38b6Pf/x3vEyu/1WvfuNPTn3bae/qH3jjmyY+87nP/+mKM23vufv9H0/ED9J33cZtw2/7oy/1zM9k83773P6Vlx/79Om/yQ1MfuBYJn/Fq2+65b7f3HnDj//XW278yEcv7H3Dx45+4ncffOjYZ7/86GOeX+hYcPlVq3/9Uw89/ldHM93zKi9/9VV//jUnfNnLX9G7bPDyda+5ZtPr3vBGyNjmrcM3jeze+66Jex78zOePT5363Off+fiOnR98R+U3XJtYcpOtFl9WO7DAXlKa7yzKXeBe7F7ttLyy9hlvkbPI6c0u91Vtcv9ArjOfrR1ZaW/N5vo63bLd46pVg84Gd7GTz+Qyq8ILnUKu377cnZdxCplN6weWFZdlLsvm97/itdf1Zl/5xvntc3PXOReUfq2lO5P31mUvzN3uXxW90rvCzXu/7ik3sN3ae2+8YF02X/vUOyqr/bxXnLMyk++/xAlqU1due11hXS6/ZnXPuuzrFqzcn1mTX2CvXT9gt2TzHpXa399d+5IqLS3e9dGbbvdrj93z6MHF9337wNpP/PGBlZlXOnrzK/Jr8r3unAPH3zq8wVmZaV0FVn/4XPbgX78y98n/vX/hErvVye6/925nxC3auUzwwc21f8vvzu6Yu6b2kfbCm3LdtffsX2sfGip1HNp0ce07l9rzHL1/1+WuOmTVvnvRNU7e0Xe1Xn3Nq2r/5UpPOW9we5br/S2XONsKb8zXPje4oHiJkyOR9mofuet0tmgX7fHCmzM0ZJxBwr83W7lu/+sLHbbtZnLdmUzOLmYuqv3ly/MHvVnVsfm9gaaqLdDIPS0B22AbjC0Ww2+yxMeL4dfS36vp7zgZJW+fc1S3zQ3Lf7pwc3nfJZsXu31Hl3UcDVece/Bof+FTZ/ov+Gk4YP3izNDP1ZZfU/ny2kXFH679/ZYt6xd3fWL9Fd3lN/bN/8Qbf3JB+S19y3/49rPV8js27tzyjo89Wr7h5m+XN1vf+8Rm68wPt/T9cMvWH/3dJ7adevqH2376k/Kwsj43fFadH75QecpXL1NKrVUO9rgpra0pRzsqp5X6aeZbylVLLlXYFkfwJcr38r6i8vmOQA1nVV45ZXp4Qc9b/ct9eld1dV1s39E3gJqypHgyeV1UJXW5cpysatfzqPaVNMgdTapOXaBt5QN2FbXXrjtJBVyOZqhsRvv6AvUqVVBLSS2stF3SDbi3gEuQmOp2PV+v1FLvArVOkVJVb1E64+duVDqXU8p7je7hfXsDLdQJ7fpqUU7d5NDLWutu7diBU6RLj/B7h7Jsy15gL9AX6FVaZbJK+za9dKmzXEW6ovbYjs4pz/5bral9lUGtOuvltbpQZVuvcvoUFab/9+YKOqTOKXuQChBC9uVZrVd5r7VVUWXQtK2XXlVUlRuUa9FT34YMFnXBzeo8SOwucYmmVDgoLitbetR+F2G3OVysLLXIoZlcvd17u00GgmepGx2UnO9s0i2V69Rc+zadhQJV3dpVH9bz2orqFdlu/zK7T4HcraREhzzquC6oLPHPV8uofa1dInePauHfbW7Ofidh4VBrHyRTx1aFoELwp6nDsZQsVpfqX1MdhMPfqd9yLZtQdnptR/2eJhNJ/7Z/XPWXBu0l1KImzJfai1yVfbUq6OU5GpnqBpuaIYofVXa2g7hnq5dnbPe/ZkGquer1xH8qpH/sePRvj35DFqWril9RwzYJiUuOOXY+E46T1Iajwnyvp0kGcp62LyN2WkBVvbZTZ0DO/8fjDZ3Er3VoQpELkLNoUss5lrqqlTiNuhzLzgU6c4Fzv20NOEuzqkV1uqpENbVyLS4ZgY6VuS1LjpG6YHPtLMyqo2QIHp1zxqvND7OdC/+/0u4Duqp6Xdf4WiEJvUgHKaH3XqRYCL0XKXalN2mCiIgoJaACokBoFqSE0HvoHULvEHqH0KQJBBsq53nf7d1333HP2efccccejv39DPA455rrP1fWXDNMT7Gq4JaUgZIRmWrEts1UdkHbLIEnbbPlexqRPRA6I/uQNPlyTM9+OUeNXu1yBhJm5Iw9cTln4NbQvGUftMs/6sHy/JE5w3OW0VMpGJYtmDe4nSfL4WDB5CF5wtoGi2tP8sT02TEYzBxsGRydIjZYOGWxQGEdcSElQpIF2oSEB1ImZ6qxpUYgJDykMP+k+Pum1bwhlbT9PN14oRESHpqWZ1Ly932EddKznWMoWVj43ze4BsP5N6FhYaHBZyL4DrlioAwP+Ijw0GQhaVwLlM6YJ6ozD3fZkJTFOcoDJYJpkzcPcFYPsA2B5m05UQU43wRq8L/WLCkcBf7vTxPUsyZS53f+P5c2lW5yHpAcwRxs8//5T4pAbf154XoRmywQGB4aERgX1lbrZCBjtul50kRcynO/ZESJ4mWnl+gd27ZkyNxLJfM8uVQq8Nf0StOeXqrEOlk5mGpG5YJp2z23IN3lKmWy56taNle7uo/yzGj4U/d2TZv1ntGU9bFZpyOXmwXO5GvO+tiC9bEl62Mr1sdWEYHSrX8Kbm4d6BNIHvjn2lc/dVmtecnZtxwzBf6x5rHOZGep5LEJY91LH4z415VPaxW/WUdnMNX/aO3Tk48dlCwVa191fm8afmfR/2T9C1EwRNYa+L8bWgdDvfCkCLZgLUyTQmth6v+HlfD/XgdTBkslG/Bv18CyWgGT/9fr35R/Wf12VtPKtz1fINmXrGuB8G6sG8FUESHNdSH9v1y9igRr/XPtKvMvK1dxDqd7f69YgQwZ9C3+f7su1flv1iRtV9Zgun9Zl1p4/QnxcXCb/55/vzo95nH4/1ifWJ1Ym0JbaGY7Q8NSpPi3a1Nyr03JA3+vS5EBvTcbmbBoSyB1mN60bR+ITDRq+R2iyCSjNugYiIxaLNTx+x2R0UZd0DkQGWPUA10CkXFGfdA1EBlvNNB7NHSMhqA7HUOvfN6lYzTWt7J0lghNQE86RlPQi46hd6960zGagz50jBbgPTqGXjT1pWO01LsNdIxWAb3FHBm1VGgN+tMx2oAP6Bh6FTaAjvEq+JCO8RoYSMd4HXxEx9gCLtExtoLLdJYJ28AVOsZ2cJWOoXejE+kYO8A1OsZOcJ2OsQvcoGPsBjfpGHvALTrLhb3gRzqG3ti+TcfQO6x36BgHwF06xkFwj45xCNynYxzWuzV0DL0t/oDOCuEoeEjHOBbQG7aRMUYCSKJjHAeP6RgnwM90jFPgVzrGafAbHeOM3kahEyecBU/oGHoj/g86xnnwJx3jgt5yCUTuMIIBng10BJ5/nBdQosWTP8DXI5OscMR5JTJqpZQi6LfyI6OtVEG/kR8ZY6UJ6oI4NSsdYvmLjLcyBP1WU2SClRFlUs/KjLKoZ2VF2dRbJWVHOdSzcqJn1bNyodzqWbqAkFc9S5cP8qln5dfFAvWsgrqUoJ5VGBVRb7VUFBVTzyqOSqhn6RJEKfWs0rrgoJ6UtWxQT1/2VLJ/vO1aHldQ0b+2IqqkolU56MsXkVFrpKqomopWdb0NrKL1AnpRRUuXOGqoaEWimtpCqxaqrZ5VB9VVz6qH6qu3VmqIGqlnNUZN1LOaBn2xLTLOao5aqGe9jFqqZ7VCrdWz2qBX1LNeRa+pt056PegLdpHRli7VvaWexbcJgXfUs9qidupZ7VEH9ayOqJN6VuegL/JFJlldte/VWy91D/qCX2S01SPoy32RMVYv1Fs9qw96Tz2rL+qnnvU+6q+e9UHQF24ik6SwD9FTchv0pYHgI+WsQehj5azB6BPlrKHagfoxh9YwNFw5KwqNUM4aiVrxK5Osz9Dn2ryN0heoDV+LtkZpx6MYazQao541Fp3X5llf6UHhVyZYX+tB0c+DtMYFff0hMsnSlYe2fC1qkxSth0E9a5J2tXrWZNQVxVlT0FT1rO+0q9Wzvkd91bN+0M7V9lnT0Qxt32ZpJpql/WnFoNnan1YsGqKeNQfNVc+ap/2pnrUQLdL+tBZrD6pnLUFL1dsiLUOjtH3WcjRa22etQHHan9ZKtEo9a7X2mXrWGrRWPWsD2qj9acWjneptlXb8rWhrF9qj7bN2/604ay/ar561728lWAfQQfWsQ+iwetYRdFS9bdIxlKCedRydUM86iU6pZ51GZ9SzzqJz6lkX0EX1rEvosnrWFXRVve1SIrqmnnUd3VDPuoluqWf9iG6rZ91Bd9Wz7qH76lkP0CP1rId/KypeSkKP1bN+RhPVs35Bm9WzfkW/qWf9jp6oZ/2B/lTP+ktPcPRYCgvhTKmnww59KSTElzojoy1d5AzT4WKFo+Q6PK0UKKWe7lYqlFqHi5UmRD8djJyVDqXX4WllQM+ot1PKiDKpZ2VGWdSzsqJs6lnZUQ71rJzoWfWsXCi3elYeXapVz4pA07Q7d0n5UQH1rIKokHpWYVREPasoKqaeVRyVUM8qiUqpZ5UO8WXQyCSrLCqn7dstlUcV1LOqoU4l6VnV0fPqWS+gb/TwWS+izvzKBCsS1VTPqoWG6HCxaqMv1Nsj1UFj1LPqonraPqs+aqCe1RA10vZZjVETbZ/VFDVTz+rDOf+9ZPSsvqgfitrrMwcagKKtT9EjFGONRAtRnPU5WoTirS/QYpRgjUJLUKI1Bu1XzxqLDqi3z8s8OqieNQ4tU88aj5arZ01AK9Sz+HYoEKeeNRGtVM+ahFapZ01Gq9Xb70UfrVHPmorWqmd9g9apZ32L1qtnfYc2qGd9jzaqZ01Dm9SzfkCb1TvgkwXaop41A21Vz5qFtqtnxaB49azZaId6VizaqZ41J5k+tkDPmot2q3fQpxW0Rz1rPtqrnrUA7VPPOoROqmedQqfVs86gs+pZ59B59awL6KJ6h7y0osvqWVfQVfWsRHRNPes6uqGedRPdUs/6Ed1Wz7qD7qpn3UP31Tvs5fN/PX7WL+hX9azf0EP1rN/RE/WsP9Eg9ay/0MfqWU/RYPRYCgsNBJqHkjuiLwVRCxRthaCXUYyVDLVEcVYoaoXiLf0prVGCFY7aoEQrOXoFJVkp0KvqHZVSotfUs1Kh19WzUqM31LPSoDfVs9Kit9Sz0qG31bPSo3fUszKgsuodk55B5dSzMqLy6lmZUAX1rMyoonpWFlRJPSsrqqyelQ09p56VHVVRL0HKgaqqZ+VE1dSznkXV1bNyoefVs3KjF9Sz8qAX1bPyopfUsyJQDfWOS/lCdfMBPSs/qqmeVQDVUs8qiGqrZxVCddSzCqO66llFUD31rKKovnonpGKogXpWcdRQPasEaqSeVRI1Vs8qhZqoZ5VGTdWzyqBm6lltUTv1TkrtUQf1rI6ok3pWZ9RFPasr6qae1R29q57VA/VUz+qFeqtn9UHvqXfKJwvUTz3rfdRfPesDNEA960M0UD3rIzRIPetjNFg96xP0qXrWEDRUvdM+kaDR6llj0JfqWWPRV+pZX6Nx6lnj0QT1rGg0UT1rEpqsnjUFTVXvjE8B6Fv1rO/Q9+pZ09AP6lnT0Qz1rJlolnpWDJqtnhWL5qhnzUXz1DvrBRotUM9aiBapZy1GS9SzlqJl6lnL0Qr1rDi0Uj1rFVqtnrUGrVXvnLQOrVfP2oA2qmdtQpvVs7agQ+pZW9E29aztKF49awfaqZ61C+1W77y0B+1Vz9oXqs+Z0bMOoIPqWYfREfWso+iYelYCOq6edQKdVM86hU6rd8EnLnRWPescOq+edQFdVM+6hC6rZ11BV9WzEtE19azr6IZ61k10S72LPnGh2+pZd9Bd9ax76L561k/ogXrWQ/RIPSsJPVbP+hn9op71K/pNvUs+qaEn6ll/oD/Vs/5CT9FKKSyM7wLCyPlLISgZSrBCEV+PTLTCUXKUZKVAKVHUZZ+AUGoUbaVBaVGMlQ6lR3FWBvSMelZGlEk9KzPKop6VFWVTz8qOcqh3xScE9Kx6Vi6UWz0rD8qrnhWB8qln5UcF1LMKokLqWYVREfWsoqiYele9QKMS6lklUSn1rNKojHpWOdROPas8aq+eVQF1UM+qiDqqZ1VCndRLlCqjzupZz6Eu6llVUFf1rKqom3pWNdRdPas6elc963nUQz3rBdRTvWvSi6iXetZLqLd6Vg3URz0rEr2nnlUT9VXPqoX6qWfVRu+rZ9VB/dW7LtVFH6hn1UMD1LPqow/VsxqggepZDdFH6lmN0CD1rMboY/WsJmiwejekpugT9axm6FP1rOZoiHpWCzRUPetlNEw9qyUarp7VCkWpZ7VGI9S7KbVBI9WzdAk4UIqeNQMFUZw1E4WgeGsWSoYSrBgUihKt2SgMJVmxKBxF3ZLmoOQo2pqLUqhnzUMp1bPmo1TqWQtQavWshSiNetYilFY9azFKp96P0hKUXj1rKcqgnrUMPaOetRxlVM9agTKpZ8WhzOpZK1EW9axVKKt6t6XVKJt61hqUXT1rLcqhnrUO5VTPWo+eVc/agHKpZ21EudWzNqE86t2RNqO86llbUIR61laUTz1rG8qvnrUdFVDPikcF1bN2oELqWbtQEfXu+hSHSqlnTc/EM24BPWsGeg/FWTNRXxRvzUL9UIIVg95HidZs1B8lWbHoAxR1z8cLGoCirbnoQ/WseWigetZ89JF61gI0SD1rIfpYPWsRGqyetRh9ot59Hy/oU/WspWiIetYyNFQ9azkapp61Ag1Xz4pDUepZK9EI9axVaKR6P/l4QZ+pZ61Bn6tnrUVfqGetQ6PUs9aj0epZG9AY9ayN6Ev1rE1orHoPfLygr9SztqCv1bO2onHqWdvQePWs7WiCelY8ilbP2oEmqmftRJPUe+ijB01Wz9qNpqhn7UFT1bP2om/Us/ahb9Wz9qPv1LMOoO/Vsw6iaeo9kg6hH9SzDqPp6llH0Az1rKNopnrWMTRLPSsBxahnHUez1bNOoFj1kqSTaI561ik0Vz3rNJqnnnUGzVfPOosWqGedQwvVs86jRepZF9Bi9R5LF9ES9axLaKl61mW0TD3rClqunnUVrVDPSkRx6lnX0Er1rOtolXo/SzfQavWsm2iNetYttFY960e0Tj3rNlqvnnUHbVDPuos2qmfdQ5vU+0W6jzarZ/2Efs1Ez3qAfkNx1kP0O4q3HqEnKMFKQn+gROsx+hMlWV/nCgQOZ6D3qzQOHUHR1nh0FMVYE9AxFGdFowQUb01Ex1GCNQmdQInWZHQSJVlT0Cn1fpOmotPqWd+gM+pZ36Kz6lnfoXPqWd+j8+pZ09AF9awf0EX1rOnoknq/e01Gl9WzZqIr6lmz0FX1rBiUqJ41G11Tz4pF19Wz5qAb6llz0U31nnhNRrfUs+ajH9WzFqDb6lkL0R31rEXornrWYnRPPWsJuq+etRT9pN4fXpPRA/Ws5eihetYK9Eg9Kw4lqWetRI/Vs1ahn9WzVqNf1LPWoF/V+9NrMvpNPWsd+l09ayP6Uz1rE/pLPWszeoqOS2F5eGGVh5y/FIKSoSQrFPH1yKi/pHCUHEVbKVBKFGOlQqlRnJUGpUXxVjqUHiVYGdAz6lkZUSb1rMwoi3pPpawom3pWdpRDPSsnelY9KxfKrZ6VB+VVz4pA+dSz8qMC6lkFUSH1hmxFhVER9ayiqJh6VnFUQj2rJCqlnlUalVHPKovKqWeVRxXUsyqiSuoNlSqj59SzqqCq6lnVUHX1rOfRC+pZL6KX1LNqoEj1rJqolnpWbVRHvWFSXVRPPas+aqCe1RA1Us9qjJqoZzVFzdSzmqMW6lkvo5bqWa1Qa/WGS23QK+pZr6LX1LNeR2+oZ72J3lLPehu9o57VFrVTz2qPOqhndUSd1IuSOqMu6lldUTf1rO7oXfWsHqinelYv1Fs9qw96Tz2rL+qnnvU+6q/eCOkDNEA960M0UD3rIzRIPetjNFg96xP0qXrWEDRUPWsYGq6eFYVGqDdSGok+U8/6HH2hnjUKjVbPGoO+VM8ai75Sz/oajVPPGo8mqGdFo4nqfYbCpqGL5ITw6WCGctZMNEs5KwbNVs6KRXOUs+aiecpZ89EC5ayFaJFyn0uL0RJtnrUULVPPWo5WqGfFoZXqWavQavWsNWitetY6tF49awPaqN4X0ia0WT1rC9qqnrUNbVfPikc71LN2ol3qWbvRHvWsvWifetZ+dEC9UdJBdEg96zA6op51FB1Tz0pAx9WzTqCT6lmn0Gn1rDPorHrWOXRevdHSBT186lmX0GX1rCvoqnpWIrqmnnUd3VDPuoluqWf9iG6rZ91Bd9UbI91D99WzfkIP1LMeokfqWUnosXrWz+gX9axf0W/qWb+jJ+pZf6A/1ftS+gs9Vc9KobuN89KzUqIgirNSoRAUb6VGyVCClQaFokQrLQpDSVY6FI6ixkrpUXIUbWVHGdSzcqBn1LNyoozqWc+iTOpZuVBm9azcKIt6VlEUod5XUjGUTz2rOMqvnlUCFVDPKokKqmeVQoXUs0qjwupZZVAR9awqqKx6X0tVUTn1rGqovHpWdVRBPet5VFE96wVUST3rRVRZPesl9Jx6Vn1UQ71xUgMUqZ7VENVUz2qEaqlnNUa11bOaoDrqWa+hFupZb6CW6llvodbqjZfeQa+oZ3VFbdWzuqF26lndUXv1rHdRB/WsHqijelZP1Ek9qxfqrN4EqTfqop71ORqinvUFGqqeNQoNU88ajYarZ41BUepZX6IR6llj0Uj1oqWv0GfqWVPQ1+pZU9E49axv0Hj1rG/RBPWs71C0etb3aKJ61jQ0Sb2J0g9osnrWXDRdPWsemqGeNR/NVM9agGapZy1EMepZi9Bs9azFKFa9SdISNEc9aw1aqp61Fi1Tz1qH+qhnrUfvqWdtQCvUs3ajvupZe1A/9SZ7LUfvq2ftQ/3Vs/aj7epZp9Bh9azT6Ih61hn0gXrWWTRAPesmuqTeFOkWuqye9SP6SD3rNhqknnUHXVPP+hV9qJ71GxqonvU7+lg96wkarN5Ur6bokXpW4Xz/+GRDjBRWPJ8/ZB5nlMj3jw9Y+9eVB03zUbPaoj4o0WqH9DfDJlntUV8U9Y3UAfVD0VZH9D6KsTqh/ijO6ow+QPFWFzRAPasr+lA9qxsaqJ7VHX2k3rd+1qJB6lk90MfqWT3RYPWsXugT9aze6FP1rOEoSj1rVUEe54L0rNXoCIr6zscjOoqirbXoGIqx1qEEFGetR8dRvLUBnUAJ1kZ0EiVam9Ap9azN6LR630tb0Bn1rK3orHrWNnROPWs7Oq+eFY8uqGftQBfVs3aiS+pZu9Bl9ab5mYKuqGftQVfVs/aiRPWsfeiaetZ+dF096wC6oZ51EN1UzzqEbqERP+igK8mpqSQ5ITyIyumDYFYIKo/irGSoAoq3QlFFfRDM0p9SCSVa4agySrKSo+dQ1HQpBaqinpUSVVXPSoWqqWelRtXVs9Kg59Wz0qIX1LPSoRfVs9Kjl9SbIWVANdSzntFfFaeelRHVVM/KhGqpZ2VGtdWzsqA66llZUV31rGyonnozpeyovnpWDtRAPSsnaqie9SxqpJ6VCzVWz8qNmqhn5UFN1bPyombqzZIiUHP1rHyohXpWfvSyelYB1FI9qyBqpZ5VCLVWzyqM2qhnFUGvqBcjFUWvqmcVQ6+pZxVHr6tnlUBvqGeVRG+qZ5VCb6lnlUZvq2e1Re3Um+3FDb2rDw5aHdDg3PSsjpI+OGh1QV3Vs7qh7upZ76Ie6lk9tZf0wUirF3pPH1SM9bKk/ame1Ud7UB9UtPqifto+qz/6QD3rEx11+qCi9amOOn1Q0RqChmr7rGFouLZvjhSFRmh/WiPRZ9qf1ufoC/WsUWi0etYY9KW2zxqLvtL2WV+jcepZ49EE9eZK0WiietYkNFk9awqaqp71DfpWPes79L161jT0g3rWdDRDPWsmmqXePCkGzVbPikVz1LPmonnqWfPRAvWshWiRetZitEQ9aylapp61HK1Qb74Uh1aqZ61Cq9Wz1qC16lnr0Hr1rA1oo3rWJrRZPWsL2qqetQ1tV2+Bl3m0Qz1rJ9qlnrUb7VHP2ov2qWftRwfUsw6iQ+pZh9ER9ayj6Jh6C6UEdFw96wQ6qZ51Cp1WzzqDzqpnnUPn1bMuoIvqWZfQZfWsK+iqeouk2+iOetZddE896yF6pJ5VI5a1NZaeVRPVitUNfFJtVAclWnVRvVjdwifVRw1idQ+f1BA1itVNfFJj1ATFWE1Rs1jdxic1Ry3Us15GLdWzWqHW6llt0CvqWa+i19RbIr2O3lDPehO9pZ71NnpHPastaqee1R51UM/qiDqpZ3VGXdSzuqJu6i2VuqN31bN6oJ7qWUPQUPWsYWi4elYUGqGeNRJ9pp71OfpCPWsUGq3eMq8T6Ev1rLHoK/Wsr9E49azxaIJ6VjSaqJ41CU1Wz5qCpqpnfYO+VW+5zzlz+IZ5jm7y81kGFUIxVmFUZI5u8/OZBBWbo/v8fO5AJeboRj+fLVAplGiVRmXm6FY/qTyqMEf3+kkVUSX1rMroOfWsKqiqelY1VF0963n0gnrWi+gl9awaKFI9qyaqpV6cj2RURz2rLqqnnlUfNVDPaogaqWc1Rk3Us5qiZupZzVEL9ayXUUv1VvpIRq3Vs9qgV9SzXkWvqWe9jt5Qz3oTvaWe9TZ6Rz2rLWqnntUedVBvlY9k1Ek9qzPqop7VFXVTz+qO3lXP6oF6qmf1Qr3Vswaij9SzBqGP1VstDUYDc+vGP+kT9Kl61hA0VD1rGBqunhWFRqhnjUSfqWeNRmPUs77UFun8vsbHPBqn7bPGownqWZPQZPWsKWiqetY36Fv1rO/Q9+pZ09AP6lnT0Qxt31ppJpqlnhWDZqtnxaI56llz0Tz1rPmos15PWAvQm3o9YS3U46fXL9Yi1EPbt05ajLrr9Yu1FE3T6xdrmfaSXi9Zy7UNutHCWqGjTpdurTi0UttnrUKrtX3WGrRW27deWofWa/usDWijts/ahDZr+6xtaLu2z4pH0dqf1g40TNtn7URjc+u2P2kX2q3eBmkP2que9Rj9rJ6V7ynP26e6788rEaqJ4q0CqNZT3ffndQnVRolWIVTnqe778yqF6uo+w41SEVTvqe7785qF6qMYqxhqoJ5VHDVUzyqBGqlnlUSN1bNKoSbqWaVRU/U2SWVQM/Wssqi5elY51EI9qzx6WT2rAmqpnlURtVLPqoRaq2dVRm3U2yw9h15Rz6qCXlXPqopeU8+qhl5HO6zQYKBoqO7jkpLrBxcUC/WNf2IIY3ExyUzGWCLUt/6JoYwlxWgzjLGUGGPqTy4d6rv/xOSMZUJ9+5+YgrGsu2ZKxnLumqkYy7trpmas4O5WMQ1jRXfNtIyV3DXTMVZ210zP+Jy7ZgbGKu6azzBWddfMyFjNXTMTY3V3t4mZGZ9318zC+IK7ZlbGF901szG+5K6ZnbGGu2YOje6aORlrums+y1jL3e1iLsba7pq5Geu4a+ZhrOuumZexnrtmBGN9d818jA3cNfMzNnTXLMDYyN14sSBjY3fNQoxN3DULMzZ11yzC2MxdcynjKXfNZYyn3TWXM55x11zBeNbdHWIc4zl3zZWM5901VzFecNdczXjRXXMN4yV3zbWMl9011zFecddcz3jV3Z3iBsZEd82NjNfcNTcxXnfX3Mx4w11zC+NNd82tjLfcNbcx/uiuuZ3xtru7xHjGO+6aOxjvumvuZLznrrmL8b675m7Gn9w19zA+cNfcy/jQXXMf4yN3d4v7GZPcNQ8wPnbXPMj4s7vmIcZf3DUPM/7qrnmE8Td3zaOMv7trHmN84u4ecUiaYGBLGnXNoYxbxRhzGOM2Mc4czrhdjDejGOPFBHME4w4x0RzJuFNMMj9j3CVG7RU/Z9ztrvkF4x53zVGMe901RzPuc9ccw7jfXfNLxgPummMZD7prfsV4yN194teMh901xzEecdccz3jUXXMC4zF3zWjGBHfNiYzH3TUnMZ5w15zMeNLd/eIUxlPumlMZT7trfsN4xl3zW8az7prfMZ5z1/ye8by75jTGC+6aPzBedPeAOJ3xkrvmDMbL7pozGa+4a85ivOquGcOY6K45m/Gau2Ys43V3zTmMN9w9KM5lvOmuOY/xlrvmfMYf3TUXMN5211zIeMddcxHjXXfNxYz33DWXMN5395DXOsaf3DWXMT5w11zO+NBdc3p2Dv7s6pozGLeKCeZMxm1iojmLcbuYZMYwxotRh71zGHeI0WYs404xxpzDuEuMM+cy7nbXnMe4x11zPuNed80FjPvcNRcy7nf3iHcO4wF3zcWMB901lzAectdcynjYXXMZ4xF3zeWMR901VzAec9eMY0xw96hPBIzH3TVXMZ5w11zNeNJdcw3jKXfNtYyn3TXXMZ5x11zPeNZdcwPjOXeP+UTAeN5dcxPjBXfNzYwX3TVr9AoG2vZS14xkbCcmmDUZ24uJZi3GDmKSWZuxoxiVINZh7CRGm3UZO4sxZj3GLmKcWZ+xq7tmA8Zu7poNGbu7azZifNddszFjD3ePi00Ye7prNmXs5a7ZjLG3u2Zzxj7umi0Y33PXfJmxr7tmS8Z+7pqtGN9394TYmrG/u2Ybxg/cNV9hHOCu+Srjh+6arzEOdNd8nfEjd803GAe5a77J+LG7J8W3GAe7a77N+Im75juMn4orxfBbwUB+/QyGeH8xyFhATDBDGAuKiWYyxkJikhnKWFiMOiWGMRYRo039yUXFGDM5YzH/sAczBWNxd82UjCXcNVMxlnTXTM1Yyl0zDWNpd0+LaRnLuGumYyzrrpmesZy7ZgbG8u6azzBWcNfMyFjRXTMTYyV3zcyMld09I2ZhfM5dMytjFXfNbIxV3TWzM1Zz18zBWN1dMyfj8+6azzK+4K6Zi/FFd8+KuRlfctfMw1jDXTOvRnfNCMaa7pr5GGvxz6CUffr27ti/Q6e+/UJS9GjXq0v/dl06BUNf7t/v/UAavqSf9d+pY6n2A5OF+W8+yF2udKXKpctFFP3n34AQoR/HXqpcuVJlyxULH9CuB78svGzpclVLl00zoF2/nqXad+vVsUunXhnLli5fukrZiKIVO7RrV7VKucqViv0Hom5QSw==');
+export const wasmBytes = generateWasmBytes('eJzsvQ2UXddVJnh+7u+77753608qpxR87o06lJqYqLtDlcZJiM7ryJIQxoahZ9zdmYkAZ2JemURPqqmIRrieFCkpJ45dbgKIboeugMEi2EQNnoXpNlDM8mLc4NDqGTeIQQtqQM1oGtNoNWFaAyEafXufe999VSXZstP56ba8XO/83fOz9z77nLPPPnuL7zryfVIIIf+TnDwoxEG5iP/l4kG1uCjwRy4e1IsI469cPOgtUox+5OJBf5Hj/CsXDwaLLsUF5OLBaHGxTKyCcvFgvDhIH4Tl4sHGYi2nFpGLB5PFel66WP8nFw+2hhIWxfoEuXiwvbiujH5YPiSlr+J2u9WK2+10fHxiQvteGqlUNlstP4iybGTE057WoVLh1q3aV6HX8MbGdKj9LVviMElk7Hv+6GiopZS+H/i+kGpiQo+P+97IiNfIskjqUEqtpad9X+sgjrTn+ToQMlC+p7XWyFWeENLz5SE5MeFrKUaCWC5K2++viiT8dzINvu993/fBw9+vRHzvB/d84HsOf/+hefEP43s/+O73cfg96fzh7z10//vKvO908TL/7zWr7/7u/BHx3mb1KaIHs6GvkfTfZ0MVIOmekfe+90Pf/b0fuPf97/vAe7/vu+6//4PfIz4q27XE/+nw+94nTsjRWtLh93HBH7i1lvhd99773vkPvvfI/Hd9z9x7D33wez8w/77D4lj8RimsTMZPye/9tr9zdP6/O9KX7/sfdu37iPzgB/7W/1z83cbnjnsPyQvfrkYW9QNvEnZNzRVyuzDSihk1bmSnj39yVjWzB6XafeP/bF9ZL3ugUHtSYVTneL+/+uP9fv/orLoiXfyxfr9/z6y6XMb/Sb/ff2ZxVl0qE073+/3Ti7NqrUz4kWs1XBGz6nyZ8MlrVZwXs+pcmfCPr9Wxa1Y9X8aXr1UxOatWXbyPPhyaVc+UcfTh7ll1toyjD2cXZ9WZMgF9WF6cVStlAvpwWcyq02UC+nBOzKrlMgF92Dmrlso4+pDNqj7i/X5/9WGGw1EXfYjBcMhFP+agcJ+LLzkgHHTxjzgY3OPiJx0I7nbxEwyBfS7aZwDs5mj/YR7/Lhd9iIe/00U/5kY/7eJLbvDGxT/ixj7p4ifd0DMXP8Ejj1y0zwMX2YOyJAe9J5VGI/fzQM+tRA4Uv4j4G4kcKP4i4lNEDRR/DvFbiBgo/jTiW4kWKP444hNEChT/JOJjRAoUP4n4CJECxS8DNbcSKVB8DfE3EilQ/BziU0QJFF9F/BYiBIqfRXwr0QHFVxCfIDKg+DLiY0QGFO8jPkJkwONHnW2QAQ8f0RRkwKNHNAEV8OARjUEEPHZEQ9AADx1RHyTAI0dUgwR44IhKkACPG31pgwR42IimIAEeNaIJKEBX5BODAHjMiIbAPw8ZUR/o5xEjqoF+XaFfDqPf25Mq43X6/ZVH+v3+FyShn+IgzJckoZ/in+j3+xckoZ/ioNQXJKGf4h/v9/vPSkI/xUG6T0pCP8UfxOSWhH6Kg5QfkoR+xPto/4ok9FMc7V+ShH6Ko/3zktBPcbT/vCT0UxztPyMJ/RRH+2ckoZ/iaP+0JPRTHO0vSUI/9eejGL8A+imKmfWSAPopegqjF0A/RYHCFwTQT9EPXxv7O4B9imHSvQXIpxiobBtwTzGgoAnUUx8+6ljHLo6izUsCqKfoKcdIpjmKNp8XQD1FP8xsZZJjaHMaiKfYceYxEcfwLxpGu78n1cbv9Pt/CYprE9YR/Y+ItgjpiP57RFPCOaJ/iGiTUI7o/4loQhhH9H9HtEEIR/Q3EI0J34j+r4hGhG5Er7jJ9gxHMQdWW4RsRC+5uXeGo5gSq01CNaLn3VQ8zVHMkNUGIRrR593MXOIoJsxqRGhG9F+gGyGwjNjPIxYAyYh9BjEfOEbsJxDzgGLEsAytaaAYMSwoawooRgyUtiaBYsSAiTUBFCP2jGMMuyiG6boaAMGInXFcYppiII1VD+hF7LRjGZMUw0ReVUAvYkuOf0QUw79VMYzeYE/qmaDTPwcC+6Ig9Aad/jKi/68g9CIX9PYfBKEXuYj+sSD0Ihfk9/u8tiMX0d/mlR25oMbfEoRe5CL664LQG3T6q+VkeoaiROefF4Re5JZz6wznInpREHqRW06105yL6Iu8miO3nHlLnIvoc4LQi15hUnwzsIs+ITID5CIHaPlG4BY5iLwZqEUOJsvXAbPIQWQLEIscgLYFvCIHkQBoRS/chN9FfUDkbUAqctzkn6YcRLYDpchxjGCSchAZB0KR45hCRDn45w2jM9yT+ibs9H8Zq0VI2Aw7/V8C/w8JmWGn/78gLyBchp3+P0deQKgMO/2nkOcTJsNO/2eQ5xMiw07/p5DnER7DTv/TyPMIjWGn/6ybLM9QDKS8EhISww6teJg6ZygGwl4JCIVhp/+km0inKQYyX/EJgWGHlkZMqyWKgehXPEJf2Ol/Cn3RQF/Y6f8YuqKBvrDT/2HkKKAv7PQfRY4C+sIOLQuXJdAXdojHn5VAX9ghwsHeaB8ix3kjtBvhx9xc3oUIJtqKBvbCDi3UmNfTiADjKwrYCzu02mCOTyKCCbgigb2wQxSI+R4hgn9mGHnRnjQwUecSqORdhLuo8zwibyfURZ0ziMwS5qLOEiJ/hxAXdS6Bft5KeIs6zyPyDYS2qHMGka8nrEWdJUTeREiLOmtuCjyDCNHp7YSyqLPi5sMZRIho/zYhLOqsuclxmr5B5G8SuqLOipspS/QNIgUhK+pccru1o+ia25kdQs/cLuw+dMztuA6ivNvl3IPybk9zN8q7Hcw+lHf7ld3ok5uWu9AlhN8AHEWdFTdDp9EhhEeBoaiz5ibrJMoj3AB+os6Km7cRyuOfGkZPvCcNTdzBMeQKYLJIKIo7IJLzLuEyEugw5BIuIYEOQy5hDQl0GDrBCeeRQIchl3AOCVg3nnEJzyMBNHbaJaxK1w9MgWfKPpzThD5uH/PhTNn2sib0cbuYHKfLNs8pQh+3h5myVLa1rAh9cQe7qyvHueGjJu6Avs+7+CETd+jQ4+L3mZhQc9rFD5q4Q4eePsfvMXGHDj0ufreJOxjTMy6+z8QE+tMuvtu1j2m7y7V9TgK93C5m8LRrc1kCvdweH3S4LT7kcDt0wOEm1h1uGnvSyDQ6/ZMn+iv9lVVeCBEHh72M4AnajFPWJYr3T1wrSAvqGsf7/ZVVQwhtEEdf4eX2HMf7Jyn2PNeF2qiuVf5WESLLVqjkWVfrSar1zLrvVlz3yj6cdn062UfpZc7l2ngN5D4Zwqprh/OOls0g8xCXO+lqvY8b4UoPuhYMEOmqX6XjK/WMGdo+Huw1UFA3d1dRAxQ6sHHDOx3A3Yimh4dvOFqOYLIaLhXOBn2JqqGu46fJnjQ2SWe5v9I/2V/2CKUJT+0TGWEVmcfRBY+QisxPU48cVhOaCiWgznMJfLB80mE2of3YSZDT81z+025ZXi0bK0s/UzaAXZpDMPeu71o8U/aIa1gZVJgRgl1jPGdd3/tc+dJgaH1K6A+GkwHHPBSGwyE3DiQwlpFLGOYmykrucR0okXB3CZETPIJ9A4B4wHXVe/p6V1ntzgEkPODZgcFVYspKFLBc9fMko3m4Q1HVoXXzt7knbZgmTfhq+jY7J5nW3BxG/CTtoRjdTdoVXqMnxNcofoKoD/HzVJ4ojsqf43wQ3PP8KdP06qBe+u4ZVy9lnpVDfToz6FOJYMo2hF3XPHdnueyea37JNe/mZd+1WtZ0lHP7q1z6UNmHFUav6wRhmEe1SpOY0ldK3DaZgLiKfYP2+oTbQR27BpClWeya5oLTDjZ9Hoep+knRybJ57nbGg3b9jJC5btFN96SJSfncugTgX5Em7azSuE+uuCU3pe3h0lJ/xS25KS3sSw6vXH4pIqRS1ol+v1prU7eTdpjltpaWuPLVsq6+w61riuBwtlaaOTS1dGLJVb7iiiOfEk5XfXddXS6rjwjDZd9OMpfm2mgPSWsv9/TaMIlPl0DxgGDuVgmSg9WguJl7XD/6blR3u5aW+ifdsjsA0e6yLld2V1kWDe2soMEfTpd9IniYEu7Uw8khyGblaFyXonWgqeO8tSdtmhYdZk8sU81XJOLHCRSKUN6qxFQ5YRzZy/RFTjhv8ZavT/HzZfkTy/0TLGii6pdQntHO1Z9gsddqVT+Xf6ZWv0O8+75P359x+fR9Tojn8q6+07Xuoz/Ltf6g/JKrr5Q1t1jqtIyxHnVDcVUfKltapprvcx/SGYf75Nbhe8oqeYh31yBCAgiups8d2F1GuZFdrioFjLvmGbLTZd944KaEG/Vgshw19y0re8AdioZ7UEd4e0+amjZLWo8vAQhXpGljV8dSxTaffI8vZYRrih4/fry/nBGu250lxPsUP18WP348Iky3Wcx1vO/W6DYfHvqobLVWWUZ4rppC/OygNNV9xrXFskTOOk4Vna7a4cV6uerVcZ7aZZ+onn7ZrCt91A3+WqeQfYi/LUvfxy0dJy5xsD66e1wjbnB3D3qbAcXco+PHqdLdrlLXxi5X6XEC6s6yP7Sfdp1hGJiqdap1smyfYtlwG1EFk3VCxGxP2jIZjsPLyzSMK9JknePUH0Zx1uHpdhxEd4lymXYQX+PS3ClFOM5KqTUjGcVXlxnBaGcVVYEmVwdV8UTOSsaBis6WpRm53IjrxIrLW74WjwjD1AgNj3dgVBwDWuKDU71PfdcuikfAMI2+rPsQlV11wLivgsVxnshc0XKfhBVUkqolMSI36gCxj/tE6OWBuHPrrqoBOi2VIKCPpocwYVw/3TAmuS+E26rLEVBb9uP4+nPxyJ60bUY6K8wVjjN2RzrEHk8yt0as705OI52V5aVVLCARYXaELytOnujzAj3CB3GG2zmOo/wJvhEYcWJNxi0qq8TWz1SVE4c56wqXdZ9xbWHx4hnsqi55NPfS9Wx50BPGLlVdjrBflaaPjw5qRu6hYXDcxzWtEjQOlhV5QK7rIPX3bh7NqhvNPm4Bu+bjtC6XlZygw/C1sjRxS0BTw9ODChm3lLvaZ+7sOsX1ZSXgPSC3ahmc2cgOjivKSNOwfdWdUU8rI+2qmFFnKXFGPel+z+BXcaHHy0IrlDijHnO/p5XabcVt4vTD1so7UpF9e2KFFTv00sO2GO3IY7MI4a56hz738d2zelVx5Dwiz7vI8sN2Vp9HlaOz+hy1pbpG2tOqm2VGGrlDn1ezeicHz6lZPc3B59WsNhxcVbN6sja8ZLPhJW54iRue3mx42g1P3/TwirGhARbjNz3EuwdD3DcY4u7BEHfVhtjcbIhNN8SmG6K32RA9N0TvlQ/RuLGZmx/UocGg7hsM6uBgUPfUBpVuNqjUDSp1g/I3G5TvBuV/BfC2LAdjXJKDQfblYJRHa6NsbTbKlhtly40y2GyUgRtl8GVB3dnauM7UxrVSG9dpWRtYe7OBtd3A2m5g4WYDC93Awq8A+s7Vhvl8bZirtWE+Ux9mttkwMzfMzA0z2myYkRtm9GXB3+XawC7VBrZWG9j5+sBGNhvYiBvYiBtYvNnAYjew+Mu8Kiyr2tRTtamnBkO8ItVuI6fVeOHhp1movSk0qJrd7AAl5CrBb1Zo41l/vlv4+HRyVgtDITOrIw5Nz+qMQztpwTGeld3Cn1HjuOw1GyumenOdGG38QdW7qqrvRjUU2ldVvRvNoWr1Kqq+p6r6UFX1fVXVB8uq9auo+mhV9bKs6l6SVeV9WdbuvYraT8uq+rOD6s8Mql+pqvdfRfXPDKo/N6j++UH1q1X1wauo/vyg+suD6i8Nql+rqg+vX/1QvYpgqvAZBa9QExRcpu0OBZcUmhDGs1F3RkVGGLlDNWmzZ9dUd7toJxa7gOy4FF+loU8ZuXXxTcKauUJvF1bkHqsrXtt/in2pKLUXjUqR6VuZB02Z4KAic88oK1ndQZsg91DAqDw0Mo+ohmw/dB3t5atXr4a93Lcij5Gvd5vAxF00Ba7VLSIru28RomjYxSO2L3s2XDAREpJBQtdEVlGp5nCipsR0ONGjxNZwok+J7eHEgBKz4cSQEkeGEyNKHB1OjClxbDixQYnjw4kJJU4MJzYpcctwYkqJW4cTW5Q4OZzYpsRbaon2quzZ7I5UmDj3myoxnm13rbHC3GJ/dZGz37B/ytxy+HYBwm1x5uRQ5qTLTDlz61DmVpfZ5MwtQ5lbXGbCmRNDmRMus8GZ40OZ4y4z5syxocwxlxlx5uhQ5qjLDDlzZChzxGUGnJkNZWYu0+fM9lBm22V6nNkaymy5TM2Z6VBm6jIVZzaHMpsuU3JmYyiz4TKRkwzlJJQT26ybxya0h7pFaNsHUpEYZeI7UkkTKY/cxEpMaPxu3sSM07tNhEnW4Akl9kHj0Dgwb9ZIaGIsrPFe1N7MfeKKM1BqxNa3aTSOn1FTJsMJKIV2EhQN0SlhGgjMdfM2zqzdfJRLTBjZzceNjzUWPcm3ghvPIbTFeC40aUXe1DUFakMD8Ozit6Di24S07+rlkfHsrb08pgKefe8BEL4N5k10OPdMw6pu3kJPI6Q1bhPKvqt3mLiTZw/dlQqAxjbnubBeV1ijsInt6LzF7XePS3ndvJWYpmnMmVY3z6iqnwwx2ixvmlbeaKpE7TYTptktxvaAhZoxo8AJt5hmd08qm0lixg3NfLt7X9rgGLHPht5tPyzBQY13R+pR3YvdIsbIIxq5G+gD3SK+TYgiWC1SVCJMADh4TZm4Ej84KIEZ8a1UpM1FjGePcbYNexjsCIHFQ0Ywf42a67EIsSKyUPS6I/UTE/MaYRpW9mh4ke1LjC+yT8g7U2mibMeeVNIHeYxRNVVSi9EXf79bBHbsQCqsNMG8PX7c9FIF0P/EYrelpcAiJfOGifI4wa6XGhc8uNi1F9ufkXemvomzt6ayiZ7ZPxA9oug33JV6wMJWAjnhwKouo2HSocEBnlcVADGuEipknAAyPDNKyABcHSYATszh3DMRpk9O88FzVBmbCNgIaKREkwEgGR8mekX1KE106pU0GVNhosnYBESTETGY4cJMk8GAJgeYIV6Ve8lXEDnrUOMBNXtSr6mHcLM/VUjxCHAeT6UGWE7UzZsmyhuJ0Xa8a7zsb9BY9LSaLDxH+5rOEmgGennCSgI9kXw/uysVVlGC1dZDUt/bP5VHiUkYwPg8Msncnakw2kZdk5goezdSm3mAnwxDIibKPCUkRL+rZ/uLh0EKNljIU9eWNLEJbxf4Se2qBPOkkRoPIVp7mwt5i+YTfZ9SN69e1cSm8K3Ct1xFy65xFSrhPM15ivK4Bi7p2dEFe4jKgglPq3FwtIpP6mm1rYgduAj9uPAFpGKGVFRBKi4hFTlIxYBUCERuAql4HaRCQCrs5qFJQVjlKAMw0AUarGvJhCZA18MBnHyQ00boRCV06AuFL/jDCjp+wnma8xTlDaATmmgAHTcXGAYthkG4HgbhehiEgAE6E24Cg3AdDFLAIO3mgWnVYZAyDFo1GAQ8lMC0arRCMMiqEbgPHAwCHmBgWvxhVsJAJ5ynOU9R3gAGQR0GCvCKXCigEBbu0CTdIppRmVsualSSrJ9Pyfr5lCQmIipJGELRAEKRSRhCRB6AVME0Qu2k9dmU8mxqDdqqo3u1mgzeJjDyBnRSEgF/mNVmUcgwChlG4RCMwnWzKKRJC8gkJuoWXgmZeBNOE2/kNPGA03h5MuA03gAy3gZOkwAhSTdPKk4TDzhNXM4h1xpoDx1PBnMIjGKTORSXsEmYwyQM1GSIwyTMYRIGXzI0h1BtHTaJiR1sIuPxBq/pIRpvQkThEKvxNrIab8BqIgJVvAkRxSURJRURDQFqA0uuEdGmgLohK371gPLWAaokohhERIDSDLfERVVCGzywGI95NRiMlQcqFmNVOXavGruHjTKNyrP/WmKTrlHQbbbdvjMxDdPGpj0xgipgkYTeoXBxbLQ1JJHADjubEwm25G5XZC8/uiqyHqfR4WvNJXxuu4oXJR4/ZvT2sVpkhP0LLPQUeuHqD9xJ4gFwXCNs0i2UPdrLtRXT6tyjll452VtzH19dvRpi5dT2zUbZaOH9hZhbsLJnhJXzc/abu7nv9rDVw0kxrV541BaBzahue0WjdLJ/qtBWLxRqoRBW9/CsUuAgLHtG4XSk5wttn3vk10S3wOBfQGhaiUJNq6iIcGSKjMDxRUAiHAHX1OEXH7V3poERqSq7L6BCLu6zat4+/bCrJZpWnj3aM3ouVybKA00LDeSYApstbFIQCKbVZO47mAVApjDBXtoQjtsMKeOFmOIar+3KAEKwaeriUO9kgk+6JrBZ14gpEqKE2GgWAsmCklFbNJWHRlDtUVmxwIwWM0okxt+TBtwZtLKtGhMGhE0cJGPTeBeBA41v6KGrSFUzTjiWGZHKZpQw8IQBbEBv/oya5CFmULkX3O+Mi00mPFLSxaeMcZfRxMcVKAtI2yKj9mMUymAFjKbywIg8aooksQDbAyb8UG8GFJGAgLo5xHLa6sMzykOoW2gQv2clUpo+8wxh1LxVRs0Xwgoj5g737itikIhyJBIZNSARAfjTkS5EZ5smwt0IMXvuQlx1QVRtC7QdoAtzEGi5NNVFDYLJ+MVHLb3/RfjCozbXdfoOLSafXpgv4h6K4Oh8mFoxmE2un7xfoZSy73lY63tO657G1Ff4wek8HBzSMT0BM4xjBn2whI4XH7VNj24KiOLtA0QcGOS5R21SSQRJnhdUIw4wYgCOBxthsGpGiXKoIQ4abqiiPtTY4iYKQ/V5qLHx3VDDwVBDWjMopQjdUCH7qIYaYqMsMNSwnCsx/4TllGGKEFWHiRmIAXkQRqMSEooggZkWdTHrJdi1oDleMT5/iA0ExPcimrioK4/AROwtxrdyYc749tY7pubzEPnEIYIiYGovYqPpoARyiY2iHWIemThXJqZKlQlySqPtHqZPbOhsaEL7xgXr9bqYa8HeKaRO5cKEVs7noQmwAhDJR4dd75TxK8I3Xg9D0+sYWi4SzGWZ6N3lhCwC9C002Dqp/UU8lftA+X7wFyzRU3SkEtSLlKA6ngeQR/kmxPSNcIyZgkwK46B1KaKzeEkfgkGAia7n7koheamzy4jZZbSXTq/E0GrsMhiwywjTNShJIHC4j8AXo3XsMljPLoMBuwzKigfsEvjeU0rBTYSWaiwzGGaZ0TqW6Q2xTNqzlyxT1lgmLRoBs8wApQPHMmm0Ad6HU8a4yyA6G0zUaN1ELadpVE3TiKZANU1FOU2pbJ9EIsKo7DaSW9XX25IfhTxJAxNW/OiKHDCkgBnSFTngSME6jhTwNFU1HOGnlCNGw9M0Wj9NE5K+FyLduDNwdHRXSVfnOX6Arna8rr3y6K+BRX+273Vtv3+sl30r30d6buoKrGV0ueQzVC8+ahGPirhbiBlEMW+QcwncG70+MDWDyKATayVff/qRX6OZjgHT0kuDKMLuHakytKqIFMe3CtCXeXsB4ArMAObLlx+1wPHVW2fU56l9f0atPoJAPKOeoUAwo55GgAA+o85R+AUKn6+lv0DhFym8VivzIoUvUPhSrcwFCl+k8OVamYsUfonCV2plXqLw5yncXx6U+Tylf4HCS8uDMl+g9JPLCC8vD8qcXB6kP0Thhyh8uhb+JIU/SeGVWvgxCj9G4TO18OMUfpzCZ2vhJyn8JIWfqYWfpvDTFF6thZ+l8LMUfr4Wfo7Cz1H4HIVfoPD5WvoLFH6Rwmu1Mi9S+AKFL9XKXKDwRQpfrpW5yLCl8JVamZcYthTuPzoo83mGLYWXHh2U+QLD+VGC/6ODMicp/BCln350UOYhCn+Swiu1Mp+k8GMUPlMr8xiFH6fw2VqZxyn8JIWfqZV5ksJPU3i1VuZpCj9L4edrZZ6l6RDgTutorxD2aLdQM5h/VennuIQRcya293QL0QXriGbAGbC7dFwQWwE7PaO8a7MeGnoz6qVHLXiBMgHfC6gDvKg1sZvr0RokF4x/R6od/6W5i4pMsH+KJi0LDiPHBpg/8HoqitDQmaNiAipJNq1dJtXntGNwfIR6gzUVDKVbRPshkEVluai2xjWghA4oGwDhMSBwbL4BIAhQB1EdKqP1wWbd/VNFaKeo6A5wn7xhQpt1EXv2EYszZkhs2jRmsQm8AdfCOvDsI9jtbesSvxQ2pKUR2gvCRPuxBodG3ZGGJjQhFoIHerzBD42aK4TrPO37+BDKC5kyIrutGQ0tZJGVRtBCFvBCFpnALWSitpAJluFTUiHcQhYZMVjIBK1UfKpWWMEUnw5oWYuaYcIrG8AFLJm4SwBmvACQgzU5HCQqt0DP5doot5rckbrwBYQhKFC0ZFnds3IvjTOgHVr2ZtqF41MTdHG29XgPFjiAYfGT1fkEJwKAS1frvs5uawZD4FIWp/MauFQFLl0Dl84VSZ8JXO6gnavaQRvqFAwZo8t1X/EPjidNH0SLnR9m8HmawW4ppZNURBRsotq+pTpp0RGmGSTl4UK5Mx6dvHArirrcph6BF4miRXVIvN4p4EU+BdBBjj4nUaqsvqKj3OC4WR5AIZOm41qXp1E5DDchFaG+nJDCqDm3UcFdqKJZGfCsjExQ26RtZE864amzVkILH+HEy59XuOYBDSBA2yRX8MV6wQFR8BarPMWUlRfK6DsdEbxqRCVG2qy7XRiR/NyIShaTB1jfxB+Ik6rTlWR5l8K0CFi+pHi7FuJ8A1URvVDENrwfvGb/VNGwcgFyQ7o/inEZjv1ZoWx7f6poh3v16tWrEK/ZRWxn5234AbvYff9CoewXOcdHz7s5b9jtJO6d3o8d/bZc4Wcy94ih0OEFfRF0O8g0h5u+sGfF3i4OtaR2YWUvxwECtese3cErvRtSN3Qw3qFFkXUWT3XCpc7xEyeXlldW+/LDJutc/eJf/9V/+rM/+NWr4uQxKBvFIIyb+QAsOlemicvhJhhoQgcNj6GpXmk95Z0JmDCE1l3j7RBiliWcBAPPibO/0CBh5w4dFVnnT3//0T//kRcu/OJFcaoYQaIoRjt/8Ruf+/DvPPl7v/jn4tSxYqxjHszHzUgnfdCMnSomOiMP5ltM1vnz3/g3v/nEQ7/7858Xp/KtZrTzf1384T//7Zd+99mXxCnSAohpHfRMXKhu0cSldZ94oEf4nLNHuzmNEsI/Pd+FaBenPZN8kxLVgzbvZJGZraeKkU72oBkxk8eKkVPFaGfiQTNqxo8Vo6eKMeSMmREzcawY6ZgHO1clOn2quAWlbjGjJjtlRsyWU0XGBeirW/DVLWbEZJ30wVNFZsbKzMGXyByhzNGNX+I7M7LuS3yHbygDBfApgiMPnjJUMuuYpVMmO/VEoXtFzBoNJu7uEKLTL7G7+OEi64jvSIWN8paJ84hUQVpY1VqYmS0g0YS9Itr43d1OiuCZ7B89YfUC6IBK4q4cmmHG26GHvqFiOc8UE89hKs4dMWFvQEikcYCeGG1vWSg06bqYyB5F1eCDpkpyeMXN51WJw1M8LSBciU06LcSMECY1Ef1MCy+PTIoC3ozwMCNnBDggdDSonZgrVbV2Yiewb9qrckOuS4tNynSfMH8AcwlZvC5NwwRzMyqyeORzNPcgxvRLvuEx3xCUbFF11EOjyuLaQ/GqUTSJegua402+Mr8CPbkDqSBrgpj/eG4PmQKkFZ7Frci9qHmcG8hyiDfpvookMc09VMtVaZpfSKjXiyye0wvgl8rG+6fylgmgLITu4ebf+DSXueNNrjfK6cL+5ub1RDWvt2Beb103ryfXzes3UBeKBmho8VSdjPLMyb60aeQeTX0mYr2bl1tF/KdYT37XSH0Q+3awv3rCqTzjq6bINEqKVl1HZsV6TjFiJsEcMjCHNzjmMAHmMOGmdcYMYcuxYrTiFLecKsZRahxM5JQZJW7DBeircXw1bkYJUIDlLWXm4EtkjlDm2MYvCcCj677Ed/iGMoiVmLFjCIJTUMkR4hQjp54o0l7R3DDTR0oO0da7TdO0oYLRBpzbJjZNE236DXGHa0vH4rF8lMA6wiBtdvmDiknEmzKJZgJu+uE8QxNdk2KWpjznmpiHEc/SKonRZBLMR43txWK30G4mRthokO67wAOO4XlpcBuJEwQWfrcb69q4S7eUpgFVs9BG2QJdmSnevHioyafLsuRCS2ZOOzbezksydkKZ5MUfm5UGfrJCQchqguxv0zUU6OjojKLdlId7MHd16e1JN+x5aJPyg92WL2CZVSem8VYh7B89J4+Q/hqveH8kjhzGbWUvlThnNKapyCf+RB0hvbWiiYSnfv1zH9dHDuOSpV6gzPuhTfLwscv+p5zN97bUh3/36/KIoeb/sGqedkD78iZ+3p2n+HlP3sLPPaye/TYo+02rnXmGn3fkI/jZlfskVMtHaTOXj+FnWz6On8kcglzsOgO9G9DCpYsJzJgZJRHeNKlTR/Q7niUuNzNt47tc3+WOVLmpAXvlXOVyWxkE+wp3NN7+FHK/aqB/9MIQsOujDay8M9XQd47YtFvqWc9GdIFK3/7ZCxuARNuuGORlVfb1VmAqTSthZQ/gjyGAlkjxQDHUYy8bQ8I2bBwBEY2f8bxNRzP0gc80ONa9+LFVke0UyaBLGjYktfF3KEGq58BPyvhpMX7aQ/jJGD8jjJ/RIfyMMX7GGT8TJX5kHjhZhra4IAaPW4+biSxxuSMm24Cb0Sq3ZdINuGlnCR1+FrtQUaMTP11hiyOYjBFuI5UJIRxomqbL8ygPEoc8hJIKwATmBRzngWkSlhOr8jZmDOrGyRgHus8/uCqyu0TiIPp5B1ETDMOwxTDMGIYjQzAcZRiOMQzHh2A4wTDcwjDcyjBsWplLCIYJBlugHupg0HQw2Mr0iet3M7oBfuNVbmZaG+A3klWCcRMZSXAUJRyDARwlRFAER7okpTyGo8T5KTXRgVTVKCvVFbGj0lwSx60ASIn0qYnsxRKM2oQOjAHVs1BHkgR2JLBidI6J6t2VeiTBcg3x5TRmnRh0RKWyzHdI++THXWtqGGkZI22EkTbKSPMZaWOMtHFG2gQjrclI28JI28pIm2SkpSbMA0bcOvAWOBbzHbqAbGYIwAEA3DItl+cIFWIes9VsMalDXuqQN+nYlqZptZ4sJqrc600rQl3rtaDu5Mcr1AUOmMqhDlehebARdcq0gDrFwse8jQU0cQonvI623QVaTMoMvN5GVmYLOBzT6vpcS7VYueQ/7/r6NbjCjjNdZszItzEvmmTuM838fPtmK+x3MsnfzbT+Hqb1e75CK2z85Vth1WCFDW9mhVXNcMMKe/K/7hU2McnNrrAKFyc3WGEfv84K+yWFIehbOhgyX7srlRtwiKWp2MqLUwnnxMEqqcE5uCGcgxvCOeRlYesTR5zmDxaGOWxdTHRgisWEADWtvmZrxyxRyXXLJa2P4LmFBKBlM0iSkmEPuDiVMJH9pANxtUze3HL63Mc2W07HGTUZo2Yb42SSsTDNqNnOqHkH42RXnjAXGmcuNMGY3cKYbZqQOFFJ6cF1sSRdH5jXTOBZxrplcUvFiUbNyAYMjlW5bdPagMEsI8DVl26HpWCuSCFZKrGEGYiUoZW8XBoxLXNJa2HJ9bCXgeQpWIemgEqYyD69Hk0q5wlEYne1cRVVL7uK/kUqGzij9uVcEW0XJMeHHmf235CKqzxgQhseMNFtIurhMoJkp9FtIqZnKlmXWWT2TtatxSu6o71CWqd2cwm3HtPQ15tWk9k3YlAC+jP9B1eF1RbI8pB3fyHxc6hoZD4rtUDjiNZpAJJ0HKJpddQI6M946D9XBVlamL2TeoECuCidVseyv1nVwkd4wYOOptV9pFhGxgy4KtbfliTJmcsbRtpD3dw55CCBWM8+0G0pIVViGhYEV6q0YVBF00CT1Yoca5qcK2KILiTC4IkRTQN+8JpCus+mtUhCh56BwGTXxCwMj4ycgZFUCD1V1YOjOLeQnk9mJKk5E+ClaWYdSs8z1w5rQKUYEZpr0kJHgr2WSbskmDHNsqkUT8yarEWUmeachfCQq27aiKuu6mwmpgVhS5uFmiaiqYTOtmlUC5D5YdRN+8kHHTNITUxECrlrbE+65AHk2qZhZLdo8vuibUwpxQg1Y5pz2TdawSMzRYyf7UVq6eLtbvz5Tvy5p2i52dlys1NMq/dkCb2poch2/JnGn12blX1HvWxGSl34M7lZ2W1YBO10lz6IsncCx3cRKUHm9Vw57KZpm7Z9sT7chnsNjWsFmhgdshcUwVzP25rKPYjOcA3FBsMBEDGj3sGqo2VJ1MTkPAKiaZSYbMyodwDnmWlUTDuaVjuL0DT288x5S94yjbxJXWiATUXT6m2m4ajpbV3TMCmj/B15M+FirJyWurm3C3hpmUbXpK5dLgtSMGmXO9uYUfdYkYfc/e+Ezpt9W3cGtqIJdDPqW01kV8EV7Auim83AuMid0MUjEySPlzBskNTt6RoMcbtXQlGW8LnXmYXOZtV78KSsqR0oMZ8BykbKk5u2g9S9gzzJ3oNqmB+OGFmbffcmDLx3ODTwIS+yF4jZhAyje3NiVwehwsCVyK5pmrCqx4SkRHGvY4HgUeuZH/O7sM4Bw5tmfiFxseydyH830Fvifh8zwvmCKj9WaIdpfKbLYcznnJtr4oGumZyv6ijEw6Nr3mp4PimtaBN2ict6XMIjBsuN38ewG7BYl/6eUnAa1grv5sTmUOLbBtAfSp+u0t/i0kmDkVPjQXvMLsuy41VZ25e4SlbJp77alr1GiXlvgHmvwrx3s8uex3zCe33Z+6pb9pq87MXrl70YcyweWvZSXvbaN7c8pRhx9k4Tl8sTLkGq5Smm3n5VLk9xuTy1N1me2uXyFA8tT+2KT8RueWrXlie8EjOxab+G5alZLk/Nr9blKX5Fy1P8JViebpZJDS9P8SbLk7jh8iQ2W568anlyF3Qelqe4vjx5g+VJcAnxGpen+LUvT+lNLE9/lCjNss1zglSdjM+vcny6ZxwoPNnj0MSzfy35DRnpsQX0fo1uUX+VzF2EzkgB7DhIJ3/B3X8A+wABFJdCNqFD5ZUJy6A2AUw9BPYHobmAszdd+oMQM36dAP2kkMz6KCfgCdy7hSYwMaMy65MeqdxPjyXsH3x6lewCTHHBELyUCo5vlLoafteIH7w21AdSZRScIylwNlZzK7vqwTQQ68uasEoFjhe7Rci9D+l5ytBHkrQzyKwYFg+yLODb0ac65iNFZHw73n3qJIw3+gaxJsV2Gt+OoASJBpAybT/TX4WEYBraEH9f3Fwdkavjd1+2jt1Ddewq66ASUVkvlSj71V9fJyC+jcnIEB+FajACe9wKQAYYQrqKaJJEISwNXDjjIIqLKCP3pqTEOYDotzI/U3ekpU5l2C0EjF6EbPQiJKMN9AZNOGsXeJB0aw+P2UzI1i40jFUEh7kYmb4QpZmLgEoduss9JIOZi6FSbN9C12yuhG7M2zcB574hcOJx1phDybYKwOMuZft1Uf+R48MgJs5ilPHoSWwpkrorpYdKtVmmXwG9ugK6VgDqtbJbaEexuoZTPQN3R5gn8noY1UMY5Sc76N5+nmh7U80Y1RVG+UXgHc4gxH/RGC0n4u+sw6iX/aMKr9rh9UswwR9LZPy6esiGy6uIJcQei/K3kQosyYUnC5KZphtuogCc8raDhK3Nr5yyRvPllTW2bHaV1HyVyhoRg8njG79tfCkyWQQsXQ7I3tSG6yE8OqD7ZZJip6/4cscnzcTrX+745eWOX13uNF+V+kTE4/D4BmEb3yBMFj6PCrw3z6zM/UolApcpWTWi9pBCg39dhQb/ugoNPu0KbnArjkph3MyvC+qRSJ++YoUGWDHLfcCJLmzUl1ah4TpgzND+iNNO8Ie0E/wbaCf4lXbC4Pqjrp1AOHDTcKS8zSJAytcCyFemXjAESDKRPVAvaG68GGnWL0YC3vYHQxcjX2io4JWqFyQvw5/VjdULkq8xDj3OHDpjtswcWt2QQycvx6G/DJf9iePQyctf9jsOrYY4dPIqL/sjBtPNcujkS8uh1XU5dPKqrt83H1WuYMXVjcS/8TV6e3CNjtGuvwpPB1fh7fpVuD8H9rPZVXj7RlfhZC7SH1yF++vuWH0q8Z/vKnxThRxJPPiVQCyoXWn7pD6zXr2D1GeGefYAYqTnX0IsqOlpbLiWZpZKPLQEWXktfV2QbXItrUrDXyIvtaNr3Dd5We77+5FKFgPHfaPt7iXXXfykix9rIUZPS9V8t2iQbR7rLcCcBaVgeIU8Yh/58WevCDzJ9OaNOlIom6GMPFJIYg9HChdRC7DrwGWO2F/44xd+TOMrNc/ZEb7ivKvHvxD2Co7h2a2wERc6Yv/tZ/7tZ/weVZlLMkpk1JHqEZfE4yIaiXcHmTaWeEWDpVOx2egdahLWoSOjdqgMxqEpFMGONIWgv4668KyJLEYazy5/gtGhB2ITZ32HK8WbOEj+qTUiRq9ok5H0Yoye3an5QvGMO4JzK4K9InuqGD9VvcG4LPDgKnvQTODDrNhSf41xrJg4VWw1Y0NvPcbMluFnHlvoxcGkGa+9lDhlxvDmYAuKTxwrxs0YPV8YO1ZMUA7Sx90LCbMVbxUmTxXjx4otx6g9KkvPRswWg1cOp4pxM0Ht8EsLvCWhrIkHzQQyt5aZW04VW/HlVvqOM8f4wcQxflvGX41VD7u2DN5WnTITeGAxRi8jUOKJXq7MWOeWpc5V8eHB67gHWI9FkNbKCL1FgR3bHbDjbMZR8yIgcNW9n3vg2IeHnkmM3U2vVmAxf6x8cmLaPTxnKEbt0S6ZD/GvVbB4zIyh+B560zBhJjryI0NV3Q0eoswIHr6MoI6cdkakV7LiyMeeRQDn3FF7jMghPpzHBgu7oGstujkAVadkL0yZls0omdSKFkxaUb8y6ZFCYMYoTABZmzQSU0UgVy0AGPW5JjHXKA/z0uXabAGzz81LzG+JMrV5bSTxr4ZxM5xWxNJYWWyk7bsRgoHYyw9x5I9j6eHsfVnOFel2QVq1mTBpB26ELzu/wORK7/JH2W23Id95a49c+7/NnvSO9/urj/T7q7eSKz17RnbBnrMRK8hUHGwPlK4M2ausm6DptDori4gNraTT6klp1zQe/nt5G1IfvRtKRMQL9nEomy3TIsgZKCRm9U4S76TTapWuJ2Gwrpt9CxKek9CpbZtWt8hMCpcHUFxCaB/5k0JoN/mTQmgX+f7jF7Ih9RrP9+mdnwGHCh2mSZXpeTmjHpcI71DwY/+YC8Pc/7LkMmflDDlWtBfp4qFxA5ig+EsOGhS59DUHjWdr0HjahS9K8ruS8vBmyC+lPS9hA7RZh8ZLdWhcJACsOWgoRC68ZmhcdNC4WELj0muBxks3hAZG+uIAAuoFF4aLhVUHjTXJNIDK4YxiJwfhQ2IXB+EtAkNKbUY3cinNzhm4xEQ23EEc5CC8TdzDQZDf3dVH91cf3cfZcBzRd63Cc8RRDsJzxKHqq4dk9Rk8f9qdXRL68XU9zndB3qbkzE3jJrzo0RyO8DyWHj7TrozvdsiKYMuEXTvSrW57hGnRczR+OUasKo9MhnN35l7Nplijo7JmuB6Y58KpvUwXRO12Ytsmspc/7pj3dSOv87nX+dzrfO51PndzfO6Wrzk+d7HG51rboRdDfK61js+16nyuNcTnWsN8rkV8Do/mR+gJZsvN6dZGPtciPhczn2tVfC62Hr1Gh92ElokdLVOIaJlCRMsUcrQcoQae2S03s1s0szMo0LS7xYhpOVqmENEyhYiWKUS03IKiN9lXeR6Ovw7CBEEbSADYmJbpkZnjcy3TqvgchR2fa/HwiM+1iM81THIDmKD4Sw4aFLn0NQeNZ2vQeNqFHZ9r8fCIz7WIzzVNWofGS3VoXCQArDlo0JXLhdcMjYsOGhdLaFx6LdB46YbQcHyuhAD4HIUdn2vx8JgGUDnzOQoyn6Mg87kWDozE51o0O8HnKJv5HAWZz1GQ+Vz50f3VR/dxtuNzFGY+R0Hmc+VXD8nqsyWYwWyB09X4HBRuKTFz07gJx7k0hyNnNyZ2fC5ipTc9D9004nOUEpN4kvhcxKwL8qs8NiPgcyOOz7XA5+KyZhNXfK7FfI64XFxnbNeJvM7lXudyr3O517nczXI572uKy/1eqDKW/WdzhSLZPy5N6B6l0HNWLThvoCzM9ikFSp8BmZiUXYgjQzKPOVV4ZObyzpQvlgzrgmZdKzOdOCUpVGPIViPco/HdpREQkI+SSk/QhYycIdR4CjLgulzcmdcRkItPDMvFx08VW8zokFx81EwMy8VZXg1xdl0uPgrh9ASKjx8rxswoybpHISIfJbn4KEmrSS6+BXLqrSw1P0btUVmy9gNBNcnFWY5+rGBbZSTfRhasBrGs3mVOQGgOkTx9x5lsKwgGxMbxHX01Whk4mnAGyUguPg4TZaMkFx89lY+CAsmWY8KWSCM2sWkl6c7rUmGUrRw3zegTvTw1o51bloqJ9ZL0MbJkSqbzWuBDNaUFz6QkUx+HLH4UMvXR68rUR0mmjptmuOViqXraNc1ekTkzQdNK7CWThdlcnkJdbhTy9VF8yvL18SFzU7Bf1OJrtdG7UzVUZz6CcY+acZLHk50kmbeamg2jjTjHk2wNbXN7aMVIFzbP0oTKm4kn7FXRK1psc2wE87HJto4oiV4BRCa1sjfH122YAlZ2oYyL8s4QUoOM7JuROa44hdk7e6xLt3JgF1AChDl/OKrD24mi7caT4tYg4ekY8ctKup4WNT1qutsus7LakyVFVhyTf9OQPm1h/MplKGzek4+BaknxjO485MKXpNGdxxBRs2oNkU/0+/1zYladR+RTiKhZdQ6RpxDxZsGpdeeXEQlotdadh/v9/hp8JSPy44ioWSxvuvNziHiz6gwiv4JIMKtWEHmkD43DWewJdOefIaJm1TIin0XEmwW/1Z1fRSSYVX1EnkRHvVl11OjOswgHs+qQ0Z2P9/v9nbPqPqM7//Ra8Oy1sRw0uvOzCHuz6h6jO/8S4WBW3W1052P9ft/Mqn1Gd/7JteCKmoWaZuczCHt4m6E7/wLhYFbtNLrzID8ymDa682PXgssKGz/d+RmEPSyPuvNLCAdYIBlmfQxZG935edfjFe2AseLPqtOI/IQD4DIiT+CbcFYtaTdKVNZH5NPICWbVFWV05xfwjT+rLisH574/qy6pEmbBrFpTDppA1Hnlugk4nVMOuddG/rxykALWVxFZdth4Rjmg9OUsvFwPKOUMIj/qoLuCyA9jOHJWnVYOnSCBZUSwV17Vs2oJkeOOoPrKYaqvZ9WVkmwAzcuy7I6eVZe0a2dZz6o1RH7IEeF57drBN+e069uanFXPI7LkurOKyMmSIrXrgZlVZ7XR9rLuWmHX5BcS4RRCYL8PjAmrrW99LHaKveBc1sgil/Nq3r1SXzwCjziYy+TN2bNr0mnvQ6qmO2JWX/L5d839nne/53yj7VndpZcKrkWyyntZslPDgPpzzocnGbwQCz5A13ZQE3CdIaWCrt12qOwQrBAE98/ZW4/gi0LOk4bKYVKyY88H7ku7Jrrrvj5k5PxhvDVg34S0XhSBnaS9G6nsnfOt/NCMQscRfdG3emFGveii530bLsyo8y56wbfJwoy64KJrvm0vzKg1F73o27GFGXXRRS/5duvCjLrkoi/51v/QjHrJZ7dLMDMLJZu9pN3sdbNvcc7MArp092DqEPOAXqfs0Od9bEgpuOZjElLwkk9zk4YFBsjCR7c1JSWeZuWEyWMnTJd9vtiEi4Q1t1/6k0BGi/wcBFaKu/YY7mDFXEH+MSRM73rYIvlsfVX3SOckZIMXWJDvqNyQSHZ2g+d8xw4MUjVVDRucyimid0mfoBZR9Uilrw4NJ4gKyYtQQlWwJoRRtRJsQNf5jS09ULFVyoAf33j2C+QFetABuCpxCkCoS3JdSFRVonKJ9MaqS8eQONfwyQHlllVJf2jPqHusf0WEX3j2Mu6q2Y8Ke6qV5PUGc4GhYZu0HYxhNTZcIHdrh+211TvqlQo518nzrp93vQzV7XYBH9hBLqQRbEXUN+Fc7tMWC8ZFbbTAd+ywWYzfrj3e9+6HtktEnn338JwJ7Rdkj6asIgUZ154ctEdE4GBLdsM37SzrrilXKCqUK6TWFYJ2AHVtG0a/Sc9Y4ZCA3UZh3WP9FtAevXDyiIrtMXYBHlVOiQUMYMuK6mSdHmWdHmWdHqE+heOOc1CsnDeVuEt4jYyslUSVRJ6L3UKxB5rk/wvk2OJg72nx/GqgrRXtIcdgeqD5NZnHrPLVYHW5hJXnmmzvJGXrJy22VtOu2a65Z8gI2bvZQMq+fHyDZljEbbmNFLTpYILAnehIaRW/jcq8Txv3HetsiLWq3DFYCi11eAcma8An6GwIyb4HN+Swhz1vF48YZW89MFXIHpTDZK+AbToQVY9MeG+hc1e+v/yUCM4uwiK4PQhXzhNkb3uCapqgmia4piNmoseqHUnJBga6jU67jbQWaVdNbwIp3yt1Hz2ryUdeaOHeBfMWNuNrjUOLEI1LalxS4/Cank9uGB08rOe30EsO+Ki3+f78DXr3ABVyDz0lc6iAJz5n4uVG6JA3RIe8CXRos4WAtqVXTBhtbmHYT6TCvGEAeG22UqGtPaPNJIowZMlH9J0D8MpSrTSAlpoiZftSPV4O1OMJEuTDeMI5JcZjJDNhwrtIiTiheskNNLT86yqlnr28tLmOJY4LvOJFbABesXFBAmfyF4EMFjcoTwu8UMS2o/DJpwHqMr7VvSKcMrLLzOPru8RMQvaVA2X00ttRzG/ZdLadlDkhpRDsiODqDxxII4vtSdIlB3NwGkhafUddbVQ1XCGEsGweYqtzJ//M2X4/OpCGBjwNEgTnwTRgrcWwctRAD7UqVw2CnFUUvlW9NDY+O/oKsLuS+9MYPgKyN8NfFB7chyyJBd15rM982KrDzo4gqDus/CH5Ti+4dNqBp7xvgk8+yIc2fOw7/f7SvUGUlC4A8LAUPYl4qUQvAnKYgIrgOTEc1BIO14K9I3YSrLUoqfi6/gaVIzi8TmZXGHXvHXBKIEtfawF7nSMfwtwNeF28bs3ObQbXVgIhyN7kvECcrwZ3IPX4IY7Gxslgd6D3lF7G+IEx0Zpmt3KgNQ1a00xrCu/9SIv8zjSwZ5dXhf1Gu7a8KrL7RWIvP0LxlTL+MvmbD8dnwMsSroDJ+dLN0xr8z7EDxXZidKbAmiS57Dhmj3LXldW9KXIBiBcu2qhyjHIwRknGpLKtiVHJC+W0M8Kau2hTJHnRFvaquIv4r6y8btL7B1qyEYggHhdwZiCg4Pdzn0r2GmHXELVrn2vsNdL++OXG3h5cci6fme/a3n4E+8vzXfv793O4P9+1Pz5P4bO/e6Rrf4CTv7i3a//yj7+TIpc/vGt/L5fk4YIVFhfoWaIuN6/MoJSRt4n+Z3YXfpfeHcrbxNWf2V1Iet4IfB/Ai2eYCM/hKvUFsZdeTLqBBez/PbCPkTcd2kUq+5ufWRVdpmtFefahz5ZmATQ1AUhclT08nnEbCnpkg+/d+xSJJ9jD7ZKHn00G4+zTDYZz/ifrw/mdnxwejqpVe4GGozYM50VZG84vPD40nBflax/OBbdhZb1OtojKHg0hCuyW7lfeIj7607sLn+VhiozQEIO+QqPxIfki5VG79NOrAmKxw+QRVjmvI4991qmvBtB3D1ANdsxODxuVcPe4cd8p27vG/beI//vJ3fwVGvfLxh9XrDDvGvftpSfLxgP35GiocQEbcIJNobjGffu4cnb/rewl/8qXyZdi/3iDfaC88T5w3eYh3bDb8Wif4PUKPOjyqr1ca8Nezqtvp2D5DtuplGpKqaaUazpi0p7xXuleTm6+l5P1vZy/bi/nwQcDuk6Ne9R4G5u2bMPoWkge4R2Mx3u50fpezsdezt+wl/NvAqTatGjgrV6RwvIuww/ey0fr+7E2FWpjP5bV9mOl/WWPhSn+YD9GpnW82n7ML/djHo+GJmTqTO0aP9fQaCr3YwM7vq9xP+a7/dhKIL1F2g7hVMpa4+S6uRBddk6Lk32PyEb19jjHERBNdHPByvBYx3jzQ4IUrM66Z/VeMrniXC85h7zskIoeVRA4ySsUHRmNyt6cbNgX4iM8nO6RhKbcbykOX2AnWdp5ws3eTBdmcOxU7Q5gC4V3n2UHB33C2mrUhi5VHyXOoxglZbexyyP4YlzsFpK8MhqZBkh89hFbwFf01Vvd8oXlfMhBkyBHkghVDbGxmRJslZOqym1Vwh4nX4IHpjtTv/K3tIfD8Mzk2R37U499xUntfCeBKMUBNvhF3Ka7H+6ZieFI5ydOrnMLxd17sd69ARxoMy2dRdV84L4OxHj1VvpzYCqXrt7PA7Cq5ktMV67XFPsS00Zt6npNb3S9pte5XtNoAZQsIWmT7NxYkoOxQaOMDcHYkDfbefAgcndlF8nhVpJc8FW0KB+w32D7/ef4eubaIgPRNU4neWRCO7FgpZFTxu+yfRxD+V6vEj4xMfNmH+eUUbKPgDQqErIbNd3LG85Kc0jlaQumbZNdSJXSX9ilSLpFWslG0rrUJK1LTdK61CTEEz16NkZSkwZJTVBVnlKtaa1kykyoAWJv0MIH32ZkisneZuQUm6mD7+W3ymbBb7oGI4/ojamJ50jSE5ZWeSn7jQtW91qeElLAby+vFJH94lX4tVvIyWrEVewt8XCFF0u6NWygj+5VCf3clSoyTAaBax6ahonxGi4b8XBx1fRJACl2qKhoPYE+Xf3+nj3b7/dBfBF9SG4VG3xxmdBzep39j6km21LoPG911KBdtb5Ju7PepEc7kxRaJ1Vtii4pfRRrjgTkUB6Wq3C4pNpFVTtY6v48BQe7K9XXGZWumqhXSgoDsyqig281spBH1qj6QrbkjG8a/BkuDtPkpC9DXOXtfCUm9OQ6E3rNjSb0igaYsfNcTHtssvLWNImbCHgl6mxXNo1yJqOaeJSdlnbblLOt1DSqm7egY0o7LAosdgsyZtmywqTk4wjDS+FFD1edLStwi8rCaNDVJPReFFnG2j5sGMsUlQm/bUXLqMxj+0/bNzOMNT1kGGs7rJQpenbnht7gIzRZQaMqp90M5zrfUngmIU+PbMZFzRV4y3yoW7BfZthG4o8qYT7dL2PTaQRJxjg+VxoFFF1652c8d57FeuwlG+z+ec7un7uv1tw8bmqMnkNWNkuOnugV4kVnC418/Xn28fLNPkefLqP8xNor7RRuH7ZTWFrIC9l035C5rOawmUgT2Z3QEJavU+GACuPXqfArRYV/7EvlbPnojY4p121NZWtaSfdP+1J6cpN/Ef6EtNu4gIee5EeObBZIe9WHnFFm04hqUEwLP2+XTexz7PjCbeJff3r37aJNUW/BtnsuZYxSolrKKKU0aykjlJLVUiAEbdIVy+H34yZR4Vr5r4hQIeW1f0FBKqPgP1J17QXBKil6h2zOUuZfi9vFBFVd1TuOAm+V428XEYwQiI6YlZB4XFgTb5ei2agnXZJIiutJFylp6MMLlBTWk56hJLi1tX0+hgzyfovaIdjS/SJkV6A1I7MJumAXt4tJBu2WYdDesgG0b9wA2m0bQDu1AbRvMNpueQWg3bIpaLcMQHvrMGi/jkH7dW8XUWIbubP8JaH6cxwykD6M3ak6nJ4DLPB2X5a25OXtIsXcuV3AFyK9y/9LT4ZOGIjNrAajWQVZ1mjeUGqfUrHJaxVeSnWS8AmKQHwvrbLR3G96lEZ7WURwezy4oPapPO1lFfaykCP7bi/rsx3LqNqxRvW9bFTfy0b1vaxvPLaVQe4YIY7wEqqKLoh9E9VKosqcLW8UAe/T6A03dU4ZmUfGI6WICHLZ0uydD82uiO2jkZTJXXJG9uBdlKQ4CXcrYOX2gwemunmUkK0G1K6qFkmgFtAlyhxbyaFdc8ROxv05uicFRLwK9oNdslKJifluLK52yR6OC7xL5m0eK+A5l+EQtGG/6rl9MqyVRjwIn7wUB7yjTLWTpkXu6VBQbijRH9zY1/bAXGfhGbUfAKPN8GZ18qt0QTdNWESmlef2m4BNsurJaJG1Qr2Sx8IYHkkYbhOi8Ll1NluIk75cID1R1OCTdWB4qQwc/dASG9XI1l+1ZC7TJ0WV2PbJHVNsoZmN9UXibOuxVMDrrLDWU0R36hoqMNOk7QGZDG25sd/3+TKBcEW1+iYAlPiehZwGUymfS0FzovCNequQ8GDMJX2iCAgHJM8Qt7+P2O8D9SOHZVbf8pRT1gfRqmkh8wbohw+WdKskyAAOf0pzJFcmequAlQ5XyCNwMn0qolT0R5DZXSpAJ7RNaiDrOL7NqA/aJIR0QOat0qPq4RbRZcZDmXQTFb9dwqNsgkXMg45iNAxyupPZHNKCIKNLyCQlNZDSk4+bSHIg7Se/4bHy4bIsTTo18X4iHno/sdPEg/cT04hU7yeMievvJ2Cy+rwYvIZtmBgSmPvpG9ZJu5etnBwsn8HG9jnRZRNE76lePDYdRcbuxeOS5GBG2t+xe/N4lENiVh+i27d4Wt27l6q8KKAkH0+r+2DqqmkakMbGO/QhCDAptCTpKS+CUJvLOHgU5BtXbx7vL588NqDd3aiePFJv7wdCEqjp34ta4Vla3QPl95jGN0Mn+thOdmFXOSbIwq5ybHetf1wYkhuCGA4ZSu3yd/DbP6dCGk+rd1dZ+9ZnjfP2GXa3rvsWsbHhLWJjk7eIvBd32utkzqtsNKu9RYztMr1FbK57i/g6LX0V0NIb/sugpedrtNTYDnf0REuNdbTUqNNSY4iWGsO01CBaKt914QhFtNQYoqUGaMk96GoQLTWR9p7q7U7q3u403NudJclBpqWGe71zlEOOliJUQbTUcLTUAC1BYATJY8s0HC1RiGmJgkxLFCRaalSvd+4vH+8kgHVSPd6h3hItNU3D0VIDIaKlBo1vhnT9G46WGgRZ0BK6t2v9M5kGA2UXv2JpgJbiGsE0QEtl1r71WeNFy2Vl131Vk2x4VZNs8qqmBVpqOVqCxK1qNKu9qmkwLa17VfM6JX1VUFL8tU9JX9RSL9beYJWyi+rYl/HNGjTXeyRHltnf4l0vOYCv3uto53rd6K4NoeddnVHZF3eucae5UHjYr0m+xbS/8Ylfw9Vk+V3Uxe3PXDa5x1klv2HlL3zCvqrKU+lesAj7PBeR1RFkz0DPUEAZXQ+g4VXQ8CpoaEAj99BX/WoAccN+cjduXO91YPAy9ZbPdIyXnPKkWgRWKw0Nutrm45Z2F7qCLw1l12mOa9J+qC501fCFruSLQsXpfHtKvoPp1lCRdIIPb7p2o8tKyJpvclXtJlfVbnJx2jvaK3T2Zr687JYKUjQCqtmIoZtcWd7k4tP1fak+IoKgu1xBFH7b8A2lwuUd3VBqvqFURrsbSlm7oZR8WqKkQrobSqinVDeUdJ2Gy0i+k2yyycQmi2wi3KMieehiWNJdMl0RD/pdH+L6a+GylqH7W67lxeFaytG3k+TPlPQX2YStkfZzV38AzyTx/4GpQsyRCUabGel0JCV0JAvdxapDumeCPAyVFAR25Bk+sLObKQntTZznigDfe0aCGMVcj7QKrOD93hyaIznzXCHnSK03sLrnPE8RZVZqcui+RxohA10/iIr8YT1HhZEPFVJGZm+iUy8QAoU/zzlRNHieIZJyDkjXtCTdAXqfc2d5aT7UDQ3FCIVJoZ0GIl63FI7+y7tynb0JPCUieb9KTmgpF5nqJN0ZWGF/D9Zz/8M790/hW/unXIfV89Pqyc/uNvIAp/+i3Dz998Tm6d+4efLWzZOTzZODzZP15snX6eHGZKxxysXICVqHngUKR5aGaBpPdj8akA7Qk59dxUQCcvpn9RHb7z+72LX95WcX9xdqiqfG/0O0NOUqcBVaA15gf3Wxa9+wf8pI0sN5UJNmC6RFarDylWyAbAJXxjYF3XDYccvumQo1RUu1rESeLCmVPJ+JyUW8iox36cmxUVO5p3cbD1aVC4VkRcmoTU6RkhJql2XFZB+b1Uyg+8VTizxA4ari6YeJ45B1SFKpyXAbgxVJzahxo+hxp6HuCPKmQmsUoplRTkGQhW9RQWZKvbLnnuNEjvnZB4j1fahH/I4ZKbvVgq8qnvyKXcVQa5MJA2iQMe4y2klZ7TmqFkNBtecetcm/V+QJx2jrz1dCTmU1v1mCFg7cXWAMkoxHX5JHSP8JNqD/Y2UDGktgqbUqyf71pX/1J9DtYx7kddkEL1UNhzctpQXks5AjKfv3ui2tpHIJObnAUXRH9Q13FNX6SIrIPimUO8UR6ESik2j0qU7jgSKEhWK+70dT6OPdeNAW30lp5UMoNomOjeQTpL58F1/fQ4JWqd+iXlvq7qyrSVc1QZuscf9ASdPpIS52jbc/L28EPDbMK5LfdLweawd4/M9+ynMsJzpc8G3htHrp49Ym0MNM5guo3OHJoneYfmrZZY66bo7s3mROLQGi5DtAmPuxgaGsQtmXPo5l1cfEQbGJhVzZqz4TjdH2VlJzBeedWMBbGdJh8VltzQnEy0tKu3iky4uLnKPd0yI/fxTsP8j+6I+xFiiWJJAffVO6EeKLuiFgjhph//yXS2DGA2Be+NgNgemyK2B2r5cjbzanlrA5MC98rAImihEwfzSsgFl86YDZ/8cvD8xnlWwsQs0Jt2PuJVhAF/zscw192sOKtB5p5nrsqE65c6e2HulETbF9B8jXytuDO0jsJXsQpNmA1aUCm1ETUBxSeBkAnSaoPkO8PW9SDkZdO9mbN43Dh/OG8eE+0TdNZ9WehV1OTSogbjSJPcv8HD7IG+RgGs/w6DV+ec+koCiFc2Q8V+iUfbWxMx+U5QecuDDx+c48rN5F0umR9nd4tXb24YHFdqhx/oqS0h3l2rCqZr1151W2jw8rL6aVQV+hxQ7LWrWH/eRPMTHNWlKDH2c2qhvygN9PtkyU/eDguU2r8rFmT5KjoSj7OBEHP0jFxURVpeKHCIqu190lYItQ1SYjEQPFhja0EiGxaSLYLMiNYyNvgaOjk+zsNhUW3i8a8ADOPi8pyP0MssM4atMDwJD5YAvYaNMZqJ1YzwQ3D7/26/BbB7//TUlvcIaIFui5ib2VTkBQ+buLNtNvNvr9hZrD43UyHH3YfvOR3N1QYxODrcI24pu8x8G6Wu4ZpMGzXTqZ8v0crWiTRpSHKewWqy2G8Q7T5qJdvoXQpM0OboYzDDZ0XnliE/YW9GZhDurjd0zN02nb42tWr0dnl6xQpIJv5Tw2JNiw1apKEn4gq7kr7pRH+9fJwSlP094K/KVbDsDbMIDkDLFB2l1rsEHPXZHrGhskYziENujvMEfESz3HEWnnEDlKIcGBRzfszBm90hkBxlxxxgZzxoQKZLTv8OkRt9ctmo4zauKMTQ46zugfxhsNDc6oTULPBAuvpkCKCpgzeswZ/cO4ris5o1/jjJJe6IJBzrmnuKSTHg0oL+B9vW88kNw/r62+pI39SLn6+t1q9b380MbVVw/WS5e9yUr6ynJqCZuvsZcfqtZYFKM19pdUtcaOf+nW2B/5Zy+/xv6UUrJyeDO4YCflLuIpB1LJLs3o2l7eiccITM0ea1XTc0Ln/2zSxdj3WWZ/+sSqoItYo9mnWvklTQDPOTK8/venPrz+e3lT3//Kjb+/Qc/XffmEYn1DlovaBjTNiX3QTPTspRAHgAadOHyaYAHhHJ40jV/grLj8W+IDhY+f+/GOk67CL4oPFJGV828Vj/zE7rcLPDp6gG4D7UVxP+tS1HO1PcY67lef/EPvAEt46YY9JqJh4Qo3Da0h3SVvHVVLYtNaB27k4DZ1UMEiVQBvFreJ5Z+AvJLliJKO3jQ822AJoi4FiBp7NuUA5Q+rxBlZd3gEGqvcDQnaPa3z+2NlTkpaovKMFKx3ikQC54FfI5jBKIIhp0hqqEAw5BRJbfh4yCkSe7msfBNt0keShVYOiDbkJbRbZFlW9vWkAYOJHVl5R49RhaRc8AKwXRiR/JSSAYkgIne8hDyIH1tqd461/QwJihIstriS3l5O4WRHbMQwbTotVacTY2VmuWKPTuQeLEh4VRv8YPpdPdtfBPeGYQh+XcjNYXLcLiS96l6Vh28XJLGFlhsZ33Df8QdXr+oD5RfgFQF/GNq16kPK05ynKI9r4JLSji7YQ1SW3w0T6dE7kC4t6MnyYGuWbBf0RmHj1iwzN9hsNYY3W43XuNnic0WCzVYw2Gwl5NaQhARJtdny8wZ7ulTz7AaTVu0G+l1utijI/VTZ4aIxvNmio0My2GwprHyvDCL5f0UQ+Ssp48XSC1v5n6XXlc1CuzucOTyRN/rAVPkKVBvvTnbEJNl5FvZP8IaqeFNLmwvJ72+gi4xNCrTptDPkQjPXCYI8frjNem9kJ6ho0EsNCNo1T0HD+6i4Hjtyu+ATHaT0XbftkZtve5Q991DlmKlh11zkipRqsRJWSnb2q9xlFvkjJ2w9AB1oaWgjS2JqnDCbijWppb3sTpTYaJfOsIi9kIcoekCsjM9HVc0bEYi63XNyeuvHLzcN7kvWyvMp2SaSUBJXJfEI1u1W5YeKiYwpik644EnLDw+8bBlpVwYVKnsOkbtF8qdSKd7P7KyZkXTu+faygbomLVhiRr3FqNJV+3aj7Pau7T+yyu9TC116VMve+Aa+vHpLQa52FXwybS887Bkk++eWBoaa3Js53Afwnc4OXNUZWTYhWFaaQag6q6PsQal22yabEgHJ22Z2XPJwplXG9aodOpqlW6z+o6tiphKGG0U66Mn/Iav7Wr2djVLRciOJ3twiApNSNrxjIEWUbAOqtvrR6cKrLZ3Vxee6gl5tCaVlyKNliI4/w9XVF1PestLjTJ8FwT6r0gvjgemT1jEdJzCon5ViSOMH75+qs3bWpNcGKWvkpzU2lLBLsaSWFPP73Lhidsy4itQExOwcM0vL8oFjdoFjds4KhK6bOnQ7bu5/+DKdTb+6OvvbsnqwMLQ7szL7byHabmlPCknWMR5/2BYBtJm1Fc5W2eMPW+ScediCa5Sbm2k1PqIxQT1ow/tGQwWeXI3Dx7tPBJy572ing/CTD1v+jl3L86N3jvJUNX52n7seQLOLZfPQzT/5sC35jcw+JBKR/LRkWUN5iBocjJ2aKJ+JJVlLkvzeWzqZIN3C4PwcglVrDARzKKxM2cHG1WRv4XbanrOfPLKBw6faxJ3jDZniKKXqbVoe6DV1aJRdfZj58s8yd4LjRxzVeff+lNFPPVBE7pbAinKrFz3BKhf9o8abu7OWU+6n3Uwy8n5q7HDtjaiBIEARo4fv6gfIHFrqBAysz0BrCemZ2wjZcPJNfXfry9dWZ39a8gUqncqY7LGmypqSDW72qqxsfZapsravz3pLlbVzfdbuKuvd67O+tcq6e5CV/LCsNmp+6eCQLozv4jfdzjuu1+WRKDcMko0VyhmvIreNoME7puiumW9UAbt3AUd0z7yXxQgR/jS5Vtr9s/SpBK47eiQfH3RLX7db+uW7hVsjCoHlfLPj+dwZMpSoy87Q8iQHnaEbKcf/T0s2eO8O25Lv0tj2xNc788902YjnA4KvLYU9rrIp5e7F0kLb45ItJSl6WUAq45LOUXQAUSjWLEQXp6U5AlKl70MPy6mpEU/Qsao83X70SwymbO+rBNEPbrLoZF9Hi831lpF4eBmJX8Mysmnrt365Wj8lS6Gn+Da2HWI92nRvxnCklcRt5OD5PIyNOG6DtYCsY3AypB0Vx5HMcSQ4Dr8tqTgO6Qj9ULXTNC+/09w22GmO42b/xjvNbYXbJ6odahw7zWj9TnOT/SC91Foa7AcldYnpsFTi2sPqWfSKiV7KsDKKM7h2Gl0iimWrB7eJyIjbRJy9g0Q/pYfrUllJ2qdv8MFcvSN02z40J3hGsBia5oRnDTEMWc0G7KYJiDQb3N66mg3kVFbybLjZtqLX0NZBd7Iy5FNWsTRN7O0WajcMhC3uTUtnrR5L4zKYFj1ET1cRhkEZb+6OKZLDOH2WSucIdFU1UBpXIR03b4PVMVHOFo9M3cG6HYx3tV/W4tg/oJWyVGQhU950VTFX6jOpuriImCEbzFTVsqrplMH3kXXBzLfVhMl0HmERsWaxr3byWo8uC1jqqgkuTSd1/S3Ii8n0sYLUddPqIuMuVerVNYeqK4W4p5eGqvtuJReV8zAgIG/LvgMigdvE6YethVDc9h9aFfavhV3FORoCY7FDEw/eoQmnVt5OhYWBBxEKqFm99LAlnZ7tIvm2SvrJlChuF20+6Enad9IZ3Eo+6KFAu/vUrKZz81OY5gz6hZLYvkOq8j7j2u7j7MOke9idQYgoEPtcNm51G3bFpIzIG2T+ov+wpfL4VcTokt1SLpqAfJt591sv+wd5gNtGigKhw28tTAiHGrUDzVteawXfkOyHiilJTRR7ytUMoah2FI6qo7BTl2NVzsvLdaaXfDO64uUpNkzcFbwb4+iGrqTO0bvrSvb21/b57cnbeS9Qp81sQJvy7XIba1iBKjXpjY/jfqebfbdIvomWi+9l5uPWDCPnWFAjWKmBjjdkZhSIayci2cWgbwyDvnF90DdoZf6mV/vh25Ld5RoykOH9/8S9CXxTVfrH/X3uPTfdUgiEpUBJWojIkpZsTVpQZLEsioAiLriUNEmhUtLaFgHX4jbuu6OjowMuiRr3fRc33BX3XcHR0Y6OyziLM472PbcpCMw4/23e90X7SU7uvefc5/f9Peeee5KcyE89vqutf0n+xwCk7yWn3WFt7VOUa2RJXf63u+2VcGb2zUbLtuVJpkn/bWva9M/3JGrrdzdLonk6RTvSKfrXdIrydFwz/3eH1ZdU93anfUD6rnUV5vL8uki9n6NztecXTDRtElVbe6a+TGdc30U1f2WsMGK9Vwx1hOtkocS3bdRo+Po+BbftPWH789iuk3q7g59GLebWDsLc7sLg2f4OZ5rM/ukexx4YlXjY1pkrO/fXnTPNXtiqghEDTLarftp5529gWt20G8/f2sflP1bopW8dx66u1e074M7XX/4zN1i9G4f21p5fV9Ve/cpn43QZJYO3+rnXzHZQrjWUDOgbz3VOk/Z81zyQ7QZ6FYZd5fDez26PMz47d2os/975OGOz/Zxtm97abtOm/KYh/KuvEpf03467q5CS0m1l16D+Je5/0be4+pze5+S2nV8oLynZerL79O977jpNhufbyg/v7I0jSwYw7UFb8tHTNvVJ3o9pH6/fwLQJFb0XZGdfRdMKXfGSEkZTwRE+RvdusD+RLL0X2qJ8E0f0t5/ZGie2Hpn/ifitL59ASXFe30LXipIC+zLXRP5xDiUl5K9i350/rbfGPPtC+1mha3GJA9cZQolJ/xLbNAYlJZ+eLWLbYgMl750tpY2ppc3piikVqXSyYuwGs2JKxQbXuIpVy1Lpio6W5kRzemnFkg2yZAMDOjrj7Z0VjWs6UxXN6WRqdcWGIRXNHRWtKzsrWpsqGltXppMd9rP87v3tCv+7O/+LusfYu6dbOyviFYll8fb8IfH2NZMrmjvtTc3pjuZkqmJDYcVY+7COig2Ocf+m9f9bdUNsKbbW1XuuHRXxzooNpRWNKztt6fJF+vbYIcpJFZ3LUhUtqbTdzIaBvUfYr+R3tV9jYHs8vTSVr3jrCY/aWkt+W1Nre0X+JFqb7MqWdi6r2IArv9EO9n9y2MT2lR2diYk1dbWBWDQYTQWb4qGaSCAWijQ2NcYTwUgyVheLJmOpSDxcGw1MbGlubI+3r5mYaG1PTexoT0zsrXJib5PV7R3YL63sbG7psAv/28o7OpP5utd0TOxc1p6KJxtaWhPxlonp1oZ8+f9UfbylpTWRb6Cz/f9S0TYRVqabE63J1MS29uZ0Z7yxJbWt1onJVFvHxGXxjmWN7a2r0lWB6mC0Oth7UHt81cQVrcn/TCTt8VUNR6USO1SYbzvZsqJ3t6pAdag6mG9662u9u3auaJu4qnFlc0tyYmNLa2L5CjsSe6+W5kZ7hzGJ1rY1DU3trSsaemFPquhoXdmeSPW5qc9KYzdMGFeRbE3lU2tFvDOxrCKZ6uhsTsc7m1vTO+8s4/ir6aICWCQwDEg0Jra99jtgDGwrf0x+n63lPwHe7cpfA6O3K38FeLYrfwH4tys/slP9ZwoUbVc+Ddh1u/LxQPV25WN32r5ip+3Ld9o+fafybsD47cqxnbZP2Gn7rn1/fxYX83X7/zAhvF25QOXrsP/19P172nKx/b96w4UTeB4Yul35W+htY1pnZ2pFW2cqWdHZWtGcbu5sjrc0H52qyGdcVW8GVqxa1tyS6usoG1P2JSHZ3trWlkrCfoaLw/tiL93u3L8B6nYqz7b3US6KtTaPAUO2Kz++U/lFYOR25VeA4duVXwMG/r9w/v+V1lvjGSv0nsfW8nCB8u3KQ/5FeXuvDerzvwG9PBRg9j1afewK+8oOu/2+sl2H3a6rb1tJ3/H2ufezPXnYc2/4F3yeWD0tc+CRI868b/BVhy6KLCp4beHqm9bu+/XVf413dKTae3OzKd7ckkpOqmjrsPWasntF7+OEihXN6YbWo1Lty1LxJDxvuno9eZvKt/1zx+/20/Hx1f/i+HtU/hy3j6v/TvHbMc2Odyyr6O1NKxLxtniiuXNNhV1bU0vrqgV9de1i62/3HTvvABxjulhAXntbx9sMV6/vrhTYY7vybyTP97redbuUGAYblKGkyBD5vuAlsSRYJSL2F0bELyWO4hKR0VI82CWpQikWVSkinhGHlEwqUWJIWdkE0y8FhlU0blLMrq9QiVlQbJRKf5kkShXKIGO4GEadKEMZhmmKxzClxC5bYigZZAxxiEyyG1OFUmCUGB7ZXZwSElNi9vJipij71ZG9+zjEMgYZ5Uadka95pMyWAlNksRgFJUWNYhQViRTMMUb0LkEW6yeWGFaJ+IqkSYlDDMMYZijTpUrFMBzSXw4XTMyRhsfwGFMNKSgUo8QUS6qssEwzRslRpjKKxGG+axgySaTArtUodBQbsqsUVhvOQSogYtn/jy1yGhXKMMSsFTFMEXNSoWFMdexnSqkU2I2bRmiPUhmVQsQsMUvt/wynVWgU21JbQUuKRUrFVRquxDjSPE76y5KKiYL4lGlZcpjjMBPBgTQqe89ytcDoN2qeDDWblGkUiilKhhmWXGIMH1gqYwqHlVSbAbElHyCDjekOwzAMpxTKaCmRsIhpGJYhMkL69T4mrSJzjRhiv2d64QBcpjhdo8SQ66VAHFIio0VkolQZM2SwiXwkF1s4ZLwUYYphqbGmkmsN1BgZbolglEnEMbMkqI6RaP9xYhjFZlAZhl17sYRMnyWFU8RpRIqKlSENpiFiOMSQK8QsHGzUGabsUmBaMx2lvQz7GYsKbTmHyv4OEWWI8aVyGGKMsF8XMY6Q3sMlZT8qsSgSA9tMcp4yDCUVxWMdhiqUIodhVpeKsVHEFHnGDkv2G2IU2OIf7TBtvxeIzLabkmn2+qxGxLLEbpcBjv44TRNrckGRwp7jlj0GTDXEKDVQUtDbnqLaGIpZ5DIKPOoik5gKFUo/GWJJfwpkQG87ljWlUtb1Q3ZXWD8a39pNGpYUCQUrCrEQz5Kur6GNArYl4OySgJ14BYbD1m90PvGKiqRMSZGYYk0wJxeNL5OK7VPPThVbjEIpkuL/VvLZ5AulwCw2PDJZRJxSJGP/VQIaImZf2U7Cn1qxE1H1+r5Q9hWjwFloJ2PJ/yAVMVHlvam4NRGLpMoUWflv0zCQT8JiZY7JJ6CjL/2s2kLDuHS75Ns4FXm8EvNsWVKBo9lGV1xhLDD4F6kj4u9LHkN8MmNb8kzcLnXGG4XyZV/K4HK5MPoSY4eUmGZh7PMvkqFAdt8uEUQK86mgtk8E6SPRr8C0ZvSa3JZfmdsnxb5bXzfE+FyMf06Nwu0T489SrP4vidGXFuZQQX5KCmQPta+UGBi9SWCZjsLCf5cGSVlX0JsE6qckoGBFAbb9b7AvMWINFa88LkptMkYXGB5riYy3YSixz84+k0GyUM4szMiYonGMsftfY4IoDjAclFgTDHPqhqkYDmOM4TAK+9aj9Bo1diCmKQWmMhyq1DKloLO3x03Z1i22ve7I76vEocRQlqVkQIVpEOEThzINZ29LVA/0nNxkKtlnkENMJkg/xwIKnBbDHcNZsMSnfJ0l0t/uF6TvEduRdm8ZLg0fNqR4CFOZWjHMGMYimWXnVG9kTnu5TJkmyiiUAim3RRAxCwRjmAwT705/hRTLIIeDKexpt+sYTiAYCkdqorHaunhjIplqOqS6uvqwRLylJZWsWDK/zR64TJq0Mr2qPd42dtySitZ0RbxiybzWdGpJxVHxlpWpQCAQDIQC4UAkUBOIBmKB2kBdMBAMBkPBcDASrAlGg7FgbbAuFAgFQ6FQOBQJ1YSioVioNlQXDoSD4VA4HI6Ea8LRcCxcG66LBCLBSCgSjkQiNZFoJBapjdTVBGqCNaGacE2kpqYmWhOrqa2piwaiwWgoGo5GojXRaDQWrY3WxQKxYCwUC8cisZpYNBaL1cbqagO1wdpQbbg2UltTG62N1dbW1tUF6oJ1obpwXaSupi5aF6urrauj1yHKsAoKjMLCIqPYKjH6KZcMMAZa7vJBMtgYagwrLbdGFnrFJ0eo5UbauMW8zXjQeMl42XjN+XrRG8abxjuy2dpi/E4+VZ8ZX1Z8rb4z/m59L85dJ0+ZN//cK6/8zTFnXvjLq26//9TbHAVF0d2nHPDtppfVoLJo7IADT7jh5lseqtk88Benn3OlKu03YOC4YGRS/cw5e82bn0ydcdbZ59796ONPbHzujUPvvmdEeUFhccmgodG6Sddd/9bbRbHzzr+uoHjylKbmcy8Y0NrwyJdfLW687/4//aNn4f6XXV49cdexi65Yt/7qa7LX3XT/g084SpyDR07ao37fTPb5F9YVDBs+apcpe3z46Rdf9Ty5UVWM3mXM2HDtpNl7zV2wcNEBBy0+9PAliVTT8o7Vx51wxjU33HLrhk0335Jufer9Dy48fNQxlqmqzCZTJlZ3nTjSDPYvV74ijzXB2lP1G991g8OnfGpsYaRknhlxro0VDSkuLJtcX2cmCosCQ6xKc4Q1Ykyt2tuaqIoLigqmVuyqnEVRc5I1vEA5CxbMiYVLwwXVhcWOMebIQqNr034uR2FRZXXh+CHDx5QPGlo0T/mK9iwdVlDsmF24a9HKkulTxjsmW8WOfR1iuUyr5OBCY2SjZ3ZhcVfm8FH1JcWOUvckR7FjULlfDe26b7fkQufsouKZ9SNmFy4snVNQfEDhQaP6FTtmFo80Z82Jmf0Kix11BcUOvyru+suwgsnmyEXSP1R60uVNK0u6njhjbqL05IBrSPG5l1snzrrsvkmnbjyxrmC8OtQxpnhm8VjLvfbWSV0bD7Ei/r1VXcGAqVZJQdGv3xhXEe9Y/8PacH8Z6einCteedbpabpWaRQWuc5d0/WCuvdMxtP+A/rOKOnfr+ktxR2Hb4JlHD3IOch5YNKzrF2tnmadM7z/45AVeh6Pr9Qn2iKCtyhyujLVTvQMmWbJ20/iuNyYo6doQLTS6Nnf9ddxcVayMkwbsOXf3rsd2c4haZI2IGGv7+VXSeUBx1821I0v9qqjA6Ofouuykt80BZqnZ9Y7DqaS/U9UWFjvGFvqV0a9o17UHFY40S8zJyqOKC7q+P9lCTMtyOIwCR2FB0YDi8pJhzmGlrn7O/splDhzoLhoiQ1WZDDOHF42QcsM7pMKcYFSVVEtABY2QXG/coHKFfze+t340e4puWr3mzLOvChx40JlnnVf+fr/+e8/9/h/VE/c49LCGj04++5zzL7j+tvsfeHLjM89+8PEnPajelIhNmrz7nL0OO/mcC66/7c77H9j47IsvffwJ2xJmsp0xhydTJ59/+RXPvPhS6YBxkybXzznwkMMbkqmzz7/+zvsfePKZDz/+5OvSAfVzkqmuk29/8OFHXn/z629OOuXMazIPP/LkUy+98+7sSx96YeOLL82ZN//Agw9vOP2cc2+7+55HHt341JsDhgw95NC//PXHnq4VR37wYT9vurV8ZMNxx998ywcf7vPAg0OGerwzZ82bbyfN8Sfc9eRrr7/39Td/bu84t3PlmOqJ2VvueeSpl9788DKmXnJp4Fzviz3z5i8+pKCwv2vXiV9+lW6N7b7H9PqFS1c+/cyml996+9Mfe6hoGHXih+rEPQtHKMeAtTf268pZFQVry81hhaImqogqMKXAUTCgeEH/gQWLCkxVXlxkFpoFpmGaplNZZolD+g225hWMKDiwwLDKnAvUDLPKFDXA0d85SY3cpaFihTpil66nrRNvNYc7TvzBPLhgSFFZkW25IxzFjuGOgwsmWDOL/cqpxAyW+NVwR4nZdaOj2DF3UNefCncz+5tTrNrCCdaJPQPKCicOqDJH9avs33WWOvGyISWDT7vImmhNLjD6lRV1PTyq09n1xvC1LqtrS9EfrzRjRWsPHdR1b2HX+4N2M4sdtYUzC52OzhKPuVgdXNR1Ull58ZCiuarrDEfuGudQFVyv1r4zpsBpWSfeUTHeMVd1na26HjZHmP1L+bvhYlHf/b09F7S1bM97RIENwGbgUX0fvqVvDuWjvrmT3wJP9M2ZPQl8Amzsm1N7CvhU31s/DXwGPAN0A88CvweeAz7vmyOy58xeAP7QNwfzJfBS39zapr45t5f75nPsOZk/Aq/2zSe91jdH97o9bwK8AfwFeAv4Dngb+Bvwjh0b8K6+X/8eeM+ebwHeB34APgB+tCcKBETAEDAFlIAl4BAoECi051TseRiBErG/XgKlAv0E+gu4BAYIDBRwS37OZbDk52KGCpTZczCSn7sZYc/XCIwU8Ah4BSoEKgVGCYwW8AnsIjBGYFfJzwGNExgvMEHAL1AlUC0wUSAgdhBTCdlzDQIRgRqBqEBMoE5gkmCv2MxuArsLTLHnKQSmCkwTmC4wQ2BPgXqBmQKzBGYL7CWwt8BcgX0E5gnMF1ggsK/AfgILBfaX/HzrAQIHChwkcLDAYoFDBA4VOEzgcIEGgSUCcYFGgYRAUiAl0CSwVGCZQLPAEQLLBVoEVgikBVoF2gSOFGgX6BDoFFgpcJTAKoHVAj3aC2sEjhY4RuBYgeMEjtfM19rnbcCJmvtJmvvJmvspmvupdiwG/EKzP02zP92OyYAz7LiM/HzuWZr/OTqe97UHzrXjNOA8O04DztdeuEB74UI7RgMusuMz4Jd2PAZcYsdmwKXaF7/Sfvi1HY8BV9ixGPk5o5UGrNO+WK99cZX2xdXaF9doX1yrfZHRvLsMyIo9rwTX2+dvwI3aFzdpX9xsn7cBt2hv3Cpwm/buGQbcrv17pgF3aI/cqT1yl/bI3doj99jna8C92iv3aY88qD3ykPbCE9oHG7Ufnux7fErgGe2Jp/sen9WeeF5747m+xxe0N17U3nhJe2OT9sbL2huvaI1f1Xq+pr3xutbxDa3hm9obb2nN3tbeeEdr9q72xnvaFx9oX3yoNdusfbFF++IjrdtvtS8+1r74RPvid9oXn2rtPtM6dmtf/F774nPtiy+0L/6gffGl1vEr7YlvtB++1Vr+se/xT2LPr8JfdF5erL3xV+39R7Q/vhP4m/bG37U3vtfe+If2xg/aGz/avpH8pKFofxgGmAYoAywDHNobBQYUGlCkfVFs9L7lidOAUgP6GdBf+8GlvTDAgIEGuLUPBhkwWPtgiPbAUM2/zIBhBgw3YIQB5doDIw3waA94Dft3HfLziaMMGG2Az4BdDBij2e9qYL+nbH9+gPEGTND8/QZUGVCt2U80IGBAUHMPaeZhAyYZkPLDZAN2M2B33fZldl9gQJO9jrkB0w2YYUCX3Rfo+E437Ll8OMuAmQbMMmC2AXMM2MuAvQ2Ya8A+BswzYL4BbSYcaUK7CR0mHGXCKhNO0OVvTTjVhBtNOM2Em0w43YSbTTjDhFtMOMu053PhHBNeMOFcE1404XwTbjPhAhNuN+FCE+4w4SIT7jThYhPuMuGXJtxtwiUm3GPCpSbca8KvTLjPhMtMuN+Ey014wIRfm/CgCVeY8JAJV5rwsAm/MeERE9aZsMGE9SY8asLVJjxuwjUmPGHCtSY8aULGhI0mZE14yoTrTHjahOtNeMaEG0x41oScCc+Z8JIJb5rwlglvm/COCe+a8J4J75vwgQkfmrDZhC0mfGTCb0342IRPTPidCZ+a8JkJ3Sb83oTPTfjChD+Y8KUJX5nwp774/2rCdyb8TbP5owl/N+F7E34w7fls+NGEYzWnHv3acX1vEixQIAr2VWAo2E+BqWChAqVgfwWWgkUKHAoOUPn3MA5UUKjgIHs+X8HBCooVLFZQouAQBU4Fh9rz9QoOU9BPweEK+itoUOBSEFAwQEFQwUAFIQVuBWEFgxREFAxWUKNgiIKogqH2eygKyhTUKhimoE7BcAWTFIxQMFlBuYLdFIxUsLsCj4IpCrwK9lD2byrAVAWVCqYpGKVguoLRCmYo8CnYU8EuCuoVjFEwU8GuCmYpGKtgtoJxCuYoGK9gLwUTFOytwK9groIqBfsoqFYwT8FEBfMVLFEQV9CoIKEgqSCloEnBUgXLFDQrOELBcgUtClYoSCtoVdCm4EgF7Qo6FHQqWKngKAWrFKxWsEbB0QqOUXCsguMUHK/gBAVdmv1aze0MzexMzesszepszekczehczec8zeZ8zeUCzeRCzeMizeJizeGXmsElWv9Ltfa/0rpfpjW/XOv9a631FVrnK7XGv9H6rtParte6XqU1vVrreY3W8lqtY0ZrmNX6Xae1u17rdoPWLKf1ulFrdZPW6Wat0S3aG7eq/PtFt2s/3KG9cKf2wV3aA3er/PtA92ru92nm92veD2jWD2rOD2nGD2u+j2i2G7TOL2m2j2quj2mmj2ueT2iWT2qOGzXDpzS/pzW7ZzS3ZzWz5zSv5zWrFzSnF3VubNJ58bLOiVd0Pryqc+E1nQev6xx4Q/v/Te39t7Tv39aef0f7/V3t9fe0z9/XHv9A+/tDzXez5rtF8/1I8/2t5vux5vuJ5vs7zfdTzfczzbdb8/295vu55vuF5vsHzfdLzfcrzfdrzfcbzfePmu+3mu+fNN8/a75/0Xz/qvl+p/n+TfP9u+b7veb7D833B833R823p+8NQLHAsMC0QFlgWeCwoMCCQguKLCi2oMQCpwWlFvSzoL8FLgsGWDDQArcFgywYbMEQC4ZaUGbBMAuGWzDCgnILRlrgscBr2asiQaVlL74Poy3wWbCLBWMs2NWCsRaMs2C8BRMs8FtQZUG1BRMtCFoQtyBkQaMFYQsSFkQsSFpQY0HKgqgFTRbELFhqQa0Fyyyos6DZgkkWHGHBZAuWW7CbBS0W7G7BCgumWJC2YA8LWi2YakGbBdMsONKC6Ra0WzDDgg4L9rSg04J6C1ZaMNOCoyyYZcEqC2ZbsNqCORassWAvC462YG8LjrFgrgXHWrCPBcdZMM+C4y2Yb8EJFiywoMuCfTW/tRbspxmeaMFCzfEkC/bXLE+2YJHmeYoFB2imp2q919lvfFbBehfYv817lQvsxeCudoFZBde4QFXBtS6w167LuMBenSDrgoIquM4FhVVwvQuKquAGFxRXQc4FJVVwowvsNV1ucoG96tXNLuhXBbe4oH8V3OoCVxXc5oIBVXC7CwZWwR0ucFfBnS4YVAV3uWBwFdztgiFVcI8LhlbBvS4oq4L7XDCsCu53wfAqeMAFI6rgQReUV8FDLhhZBQ+7wFMFj7jAWwUbXFBRBY+6oLIKHnPBqCp43AWjq+AJF/iq4EkX7FIFT7lg1yp4wQVVVbDODW05WO+GI3NwlRvac3C1GzpycI0bOnNwrRtW5iDjhqNykHXDqhxc54bVObjeDWtycIMbjs5Bzg3H5OBGNxybg5vccFwObnbD8Tm4xQ0n5OBWN3Tl4DY3rM3B7W44MQd3uOGkHNzphpNzcJcbTsnB3W44NQf3uOEXObjXDafl4D43nJ6D+91wRg4ecMOZOXjQDWfl4CE3nJ2Dh91wTg4eccO5OdjghvNy8Kgbzs/BY264IAePu+HCHDzhhoty8KQbLs7BRjf8MgdPueGSHDzthktz8IwbfpWDZ91wWQ6ec8PlOXjeDb/OwQtuuCIHL7rhyhy85Ibf5GCTG9bl4GU3rM/BK264KgevuuHqHLzmhmty8Lobrs3BG27I5OBNN2Rz8JYbrsvB2264PgfvuOGGHLzrhlwO3nPDjTl43w035eADN9ycgw/dcEsONrvh1hxsccNtOfjIDbfn4LduuCMHH7vhzhx84oa7cvA7N9ydg0/dcE8OPnPDvTnodsN9Ofi9G+7PwedueCAHX7jhwRz8wQ0P5eBLNzycg6/c8EgOvtb++U577xvtn79p7/1R++fv2nvfav98r733J+2ff2jv/Vn75webcTmcUQ7nlcMmnXPnl8PLOucuKIdXdM5dWA6v6py7qBxe0zl3cTm8rnPul+Xwhs65S8rhTZ1zl5bDWzrnflUOb+ucu6wc3tE5d3k5vKtz7tfl8J7OuSvK4X2dc1eWwwc6535TDh/qnFtXDpt1zq0vhy06564qh490zl1dDr/VOXdNOXysc+7acvjEBZly+J3OvWw5fOqC68rhMxdcXw7dLrihHH7vglw5fO6CG8vhC52jN5XDH1xwczl86YJbyuErF9xaDl+74LZy+MYFt5fDH11wRzl864I7y+FPLrirHP7sgrvL4S8uuKcc/uqCe8vhOxfcVw5/c8H95fB3FzxUDj+44OFy+NEFj5RDjyv/4TDxgOEB0wPKA5YHHB4o8EChB4o8UOyBEg84PVDqgX4e6O8BlwcGeGCgB9weGOSBwR4Y4oGhHijzwDAPDPfACA+Ue2CkBzwe8HqgwgOVHhjlgdEe8HlgFw+M8cCuHhjrgXEeGO+BCR7we6DKA9UemOiBgAeCHgh5IOyBiAdqPBD1QMwDtR6o88AkD0z2wG4e2N0DUzywhwememCaB6Z7YIYH9vRAvQdmemCWB2Z7YI4H9vLA3h6Y64F9PDDPA/M9sMAD+3pgPw8s9MD+HljkgQM8cKAHDvLAwR5Y7IFDPHCoBw7zwOEeaPDAEg/EPdDogYQHkh5IeaDJA0s9sMwDzR44wgPLPdDigRUeSHug1QNtHjjSA+0e6PBApwdWeuAoD6zywGoPrPHA0R44xgPHeuA4DxzvgRM80KX5r9X8T9T8T9L8T9b8T9H8T9X8f6H5n6b5n675n6H5n6n5n6X5n635n6P5n6v5n6f5n6/5X6D5X6j5X6T5X6z5Xqnj+xBYp/mu13yv0nyv1nyv0Xyv1Xwzmm9W871O871e871B881pvjdqvjdpvjdrvrdovrdqvrdpvrdrvndovndqvndpvndrvvdovvdqvvdpvvdrvg9ovg9qvg9pvg9rvo9ovhs030c138c038c13yc03yc1342a71Oa79Oa7zOa77Oa73Oa7/Oa7wua74ua70ua7ybN92XN9xXN91XN9zXN93XN9w3N903N9y3N923N9x3N913N9z3N933N9wM7fs13s+a7RfP9SPP9reb7seb7ieb7O833U833M823W/P9veb7ueb7heb7B833S833K833a833G833j5rvt5rvnzTfP2u+f9F8/6r5fqf5/k3z/bvm+73m+w/N9wfN90fNt8fOX2/+A6JFXhAvFHvB8GJ/FQLTC04vKC+UesHyQj8vOLzQ3wsFXijzgssLw7wwwAvDvTDQCyO84PZCuRcGeWGkFwZ7YawXKrwwzguVXhjvhVFemOCF0V7we8HnhSov7OKFai+M8cJEL+zqhVovBLxQ54WgFyZ5IeSFyV4Ie2E3L0S8sLsXarwwxQtRL+zhhZgXZnthqhfmeGGaF/bywnQv7O2FGV6Y64U9vbCPF+q9cLAX9vXCIV5Y6IXDvLDICw1eONALy7ywxAvNXoh74QgvNHphuRcSXmjxQtILK7yQ8kLaC01eaPXCUi+cpvXv0vqfrvVfq/U/Q+t/otb/TK3/SVr/s7T+J2v9z9b6n6L1P0frf6rW/1yt/y+0/pdq/c/T+v9K63++1v8yrf8FWv/Ltf4Xav1/rfW/SOt/hdb/Yi9c6YVfeuE3XrhE87hO81ineVyveazXPG7QPK7SPHKax9Wax42axzWax02ax7Wax82aR0bzuEXzyGoe92oet2oe92ket2ke93uhTfN4wAtHah4Pai53aN2f9kK71v0ZL3Ro3Z/1QqfW/TkvrNS6P6/1f9wLb3lhk9b9ba37y154xwtHaf3f9cIqrfdnWu/NWu9urfcWrffvvXC01vtzLxyj9f5C6/yJF77Tf6u98Df9t0bv83cvHKv3+d4Lx+nn/9Dn8a3trcr8fNb4yvznWSdU5t8HClXCvEpYUgltlRCvhCMrobES2ishUQkdlZCshE59TKoSVlZCUyUcVQlLK2FVJSyrhNWV0FwJayrhiEo42m6rEo6phJZKOLYSVlTCcZWQroTjK6G1Ek6ohJMq4eRKuNsHm3xwjw9e9sG9PnjFB/f54FUf3O+D13zwgA9e98GDPnjDBw/54E0fPOyDt3zwiA/e9sEGH7zjg0d98K4PHvPBez543Afv++AJH3zggyd98KEPNvpgsw+e8sEWHzztg4988IwPfuuDZ33wsQ+e88EnPnjeB7/zwQs++NQHL/rgMx+85INuX/5D5gE/iB+CfjD8EPKD6YewH5QfIn6wf1Gyxg8OP0T9UOCHmB/s36qs9UORH+r8UOyHSX4oseeE/eD0w25+sH+ffXc/9PPDFD/098MefnD5YaofBthzxX4Y6IfpfnD7YYYfBvlhTz8M9kO9H4b4YaYfhvphlh/K/DDbD8P8MMcPw/2wlx9G+GFvP5T7Ya4fRvphHz94/DDPD14/zPdDhR8W+KHSD/vav5rph/38MNoPC/3g88P+ftjFD4v8MMYPB/hhVz8c6IexfjjID+P8cLAfxvthsR8m+OEQP/j9cKgfqvxwmB+q/XC4Hyb6ocEPS/wQ90OjH5YbkPDDcSMhaT8asNQPy/zQ7Af7F0SX+6HFDyvs8zUg7YcjDWi147Dnye3zN6DdDx1+WOmHo/xwvK23ASfYettz8Zr5Ws37RM36JM35ZM34FM33VM32F5rraZrp6ZrnGZrlmZrjWZrh2ZrfOZrduZrbeZrZ+ZrXBZrVhZrTRZrRxZrPLzWbSzSXSzWTX2kel2kWl2sOv9YMrtD6X6m1/43WfZ3WfL3W+yqt9dVa52u0xtdqfTNa26zW9Tqt6fVazxu0ljmt441aw5u0fjdr7W7Rnr1V+/U27dXbtU/v0B69U/vzLu3Nu7Uv79GevFf78T7txfu1Dx/QHnxQ++8h7b2HtQ6PaA02aL89qr32mPbZ49pjT2h/Pam9tVH76intqae1n57RXnpW++g57aHntX9e0N55UfvmJa31Jq3/y1rrV7RPXtUeeU3743XtjTe0L97UnnhL++Ft7YV3tA/e1R54T/N/X7P/QHP/UHPfrLlv0dw/8sNvNdfPNdcvNNc/+OFLP/xR8/vWZpWBaRmYnoEZGdgzA/UZmJmBWRmYnYE5GdgrA3tnYG4G9snAvAzMz8CCDOybgf0ysDAD+2dgUQYOyMCBGTgoAwdnYHEGDsnAoRk4LAOHZ6AhA0syEM9AYwYSGUhmIJWBpgwszcCyDDRn4IgMLM9ASwZWZKArA2szcGIGTsrAyRk4JQOnZuAXGTgtA6dn4IwMnJmBszJwdgbOycC5GTgvA+dn4IIMXJiBizJwcQZ+mYFLMnBpBn6VgcsycHkGRmVhdBZ8WdglC2OysGsWxmZhXBbGZ2FCFvxZqMpCdRYmZiGUhXAWIlmoyUI0C7Es1GahLguTsjA5C7tlYfcsTMnCHlmYmoVpWZiehRlZ2DML9VmYmYVZWZidhTlZ2CsLe2dhbhb2ycK8LMzPwoIs7JuF/bKwMAv7Z2FRFg7IwoFZOCgLB2dhcRYOycKhWTgsC4dnoSELS7IQz0JjFhJZSGYhlYWmLCzNwrIsNGfhiCwsz0JLFlZkIZ2F1iysycLRWTgmC8dm4Tj7tZFwfBZOyEJXFtZm4cQsnJSFk7NwShZOzcIvsnBmFs7Kwtl2u/b71Fk4PwsXZOHCLPwyC5dk4dIs/CoLl2Xh8iz8OgtXZOHKLPwmC+uysD4LV2l+V2t+12h+12p+Gc0vq/ldp/ldr/ndYMdmQM7Wwn5f2o7fgJvs2Ay42Y7VgFvtug24zT5P+/1puz37/WlbZ3s+S3O+S3O+W3O+R3O+V3O+T3O+X3N+QHN+UHN+SHN+WHN+RHPeoDk/qjk/pjk/rjk/kYWLNOsnbW0M2JiFc0bCU5r505r5M5r5s5r5czYTA57X3F/Q3F/U3F/S3Ddp7i9r7q/o83lVc39Nc39dc39Dc39Tc39Lc39bc39Hc39Xx/qerZv9XnMW/qK1quyBaT0wqgem98DoHpjRA74e2LMHdumB+h4Y0wMze2DXHpjVA2N7YHYPjOuBOT0wvgf26oEJPbB3D/h7YG4PVPXAPj1Q3QPzemBiD8zvgUAPLOiBYA/s2wOhHtivB8I9sLAHIj2wfw/U9MCiHoj2wAE9EOuBA3ugtgcO6oG6Hji4Byb1wGL7gxRKGGt/AlsJ45RgKGG8EuzF3yYoQSnBrwRLCVVKcCihWgkFSpiohEIlBJRQpISgEoqVEFJCiRLCSnAqIaKEUiXUKKGfEqJK6K+EmBJcSqhVwgAl1ClhoBImKcGthMlKGKSE3ZQwWAm7K2GIEqYoYagS9lBCmRKmKmGYEqYpYbgSpithhBJmKKFcCXsqYaQS6pXgUcJMJXiVMEsJFUqYrYRKJcxRwigl7KWE0UrYWwk+JcxVwi5K2EcJY5QwTwm7KmG+jv1WJbylY79NCW/r2G9Xwjs69juU8K6O/U4lvKdjv0sJ7+vY71bCBzr2e5TwoY79XiVs1rHfp4QtOvb7lfCRjv0BJfxWx/6gEj7WsT+khE907A8r4Xc69keU8KmOfYMSPtOxP6qEbh37Y0r4vY79cSV8rmN/Qglf6NifVMIfdOwblfCljv0pJXylY39aCV/r2J9Rwjc69meV8Ecd+3NK+FbH/rwS/qRjf0EJf9axv6iEv+jYX1LCX5WwSQnfKeFlJfxNCa8o4e9KeFUJ3+u62yzhEks40hIutYR2S/iVJXRYwmWW0GkJl1vCSkv4tSUcZQlXWMIqS7jSElZbwm8sYY0lrLOEoy1hvSUcawlXW8JxlnCNJRxvCddawgmWkLGELkvIWsJaS7jOEk60hOst4SRLuMESTraEnCWcYgk3WsKplnCTJfzCEm62hNMs4RZLON0SbrWEMyzhNks4yxLusISzLeFOSzjHEu6yhHMt4W5LOM8S7rGE8y3hXku4wBLus4SLLOEBS7jYEh60z88pbHAKa53Co07hRKfwmFM4ySk87hROdgpPOIVTnMKTTuFUp7DRKfzCKTzlFE5zCk87hdOdwjNO4Qyn8KxTONMpPOcUznIKzzuFs53CC07hHKfwolM41ym85BTOcwqbnML5TuFlp3CBU3jFKVzoFF51Chc5hdecwsVO4XWn8Eun8IZTuMQpvOkULnUKbzmFXzmFt53CZU7hHadwuVN41yn82im85xSucArvO4UrncIHTuE3TuFDp7DOKWx2CuudwhancJVT+MgpXO0UfusUrnEKHzuFa53CJ04h4xR+5xSyTuFTp3CdU/jMKVzvFLqdwg1O4fdOIecUPncKNzqFL5zCTU7hD07hZqfwpVO4xSl85RRudQpfO4XbnMI3TuF2p/BHrcmCUqGtVNi3VDiyVNivVGgvFRaWCh2lwv6lQmepsKhUWFkqHFAqHFUqHFgqrCoVDioVVpcKB5cKa0qFxaXC0aXCIaXCMaXCoaXCsaXCYaXCcaXC4aXC8aVCQ6lwQqmwpFToKhXipcLaUqGxVDixVEiUCieVCslS4eRSIVUqnFIqrCsTNpQJ68uER8uEq8qEx8qEq8uEx8uEa8qEJ8qEa8uEJ8uETJmwsUzIlglPlQnXlQlPlwnXlwnPlAk3lAnPlgm5MuG5MuHGMuH5MuGmMuGFMuHmMuHFMuGWMuGlMuHWMmFTmXBbmfBymXB7mfBKmXBHmfBqmXBnmfBamXBXmfB6mXB3mfBGmXBPmfBmmXBvmfBWmXBfmfB2mXB/mfBOmfBAmfBumfBgmfBemfBQmfB+mfBwmfBBmfBImfChjn1qWliSFqalhXhamJ4WGtPCjLSQSAt7poVkWqhPC6m0MDMtNKWFWWlhaVqYnRaWpYU5aaE5LeyVFo5IC3unheVpYW5aaEkL+6SFFWlhXlpIp4X5aaE1LSxIC21pYd+0cGRa2C8ttKeFhWmhIy3snxY608KitLAyLRyQFo5KCwemhVVp4aC0sDotHJwW1qSFxWnh6LRwSFo4Ji0cmhaOTQuHpYXj0sLhaeH4tNCQFk5IC+vSwgNpYX1aeDAtXJUWHkoLV6eFh9PCNWnhkbRwbVrYkBYyWqtH00JWa/VYWrhOa/V4Wrhea/VEWrhBa/VkWshprTamhRu1Vk+lhZu0Vk+nhZu1Vs+khVu0Vs+mhVu1Vs+lhdu0Vs+nhdu1Vi+khTu0Vi+mhTu1Vi+lhbu0VpvSwt1aq5fTwj1aq1fSwr1aq1fT+Q//jeoWpFsY3S0Y3YKvWzC7hV26BdUtjOkWrG5h127B0S2M7RYKuoVx3UJhtzC+WyjqFiZ0C8Xdgr9bKOkWqroFZ7dQ3S2UdgsTu4V+3UKgW+jfLQS7BVe3EOoWBnQL4W5hYLcQ6Rbc3UJNtzCoW4h2C4O7hVi3MKRbqO0WhnYLdd1CWbcwqVsY1i1M7haGdwu7dQsjuoXdu4XybmFKtzCyW9ijW/B0C1O7BW+3MK1bqOgWpncLld3CDH1utxpwmHuJDBy6ueIhb0Xlsf6KiVZgSXjwus2R765ZEnVm1kU932+O8uPm6T9IfEZXQeUsKd4yy1e6fnauX+VeE8sq9548bMvibz3xQwKR9QlPvDL19RHrU1NXVDbNb93S9PUt8aVXPLh+GS/Fm5e+vKWZd+JHsHn98ps/qlzx+ceVrZs+W9/6/bfxI4Uvj/xa9mi3v2B+kqrgfGsJh7nXMXBohddZscT7lX/JhPGBzRNaM+v8xnUVVZ7vK6r5cUn0yp510R+kMibF8ZivtLI212993cSyyknfeir3nhpZv9AT37L/10fEF81v3bLoigfjB/BS/MDUy+sP5J3Kg9i8/uCbP6o85IuPtxy66bP4YRV8efjXckKD/VmHde4ljq7ydQVDvBWFd/umFuFfN3BqZvPAQG7JIL6vGFLZs3kIKj60q2DL0C7n+uHrytaPmJpeX85rW8ozb6wfyYfTRgU2V+5C95ZdAt9Ujjnjm/Yx1dWB1T99+Wva9Bl71s/kqekqHV+RoqgwmeqoXhXvWCF3Tu8SxjcsnhdOpjpqehctqV2a6lzY2Lp6QTC2LJ5IhSJNyUBjNBAJxqLRegk27DfvqH06wg3zOmd01CTmJwKh+fsc1NLaUNO7mEa4o7M9FQx1tja0tK5KtSfiHSkjlT8k0HvIEUeurt1/zprFjQvqGmq3LsGx7cmceZ3Taxpq9+wrzuuc1zk91hDuWNMRXbimozO1oj6a3zKjI9U4OxFPtS5sX72mIdzRmTTnNiyeV7dtkZFwe3xVKLhffNX+9pfnfXP39+3vm+Gb5pu1vy8YaU91pNqPSjW0p+z9g7Fl4WgqGIyH402NkaZgUzhZrybYtW1bCqQu2VqfTrSvaeu0RQnXJZsaI8GmVCyYjAbq6q1/2nnP1Nadk8GmxpqmVCSWiIfq6mrj9Y6JO+wcDG3be0Znh30qgXBjKBVrisSSsUgk0VRf8F8cUFMbjMQSiVAgmYoEGgPR+sKQLbgt9tKDZrXVzD9ydWjemoaIvVCLjSdY17sMSUOqvb21vcFetqChvbPo38SbCDZFauriNYHGZCocrWmsL/438SYigXhjtLY2lqgJhuuaautLZvS5yz7xGfZe0+0Dg7Fl0aZAsMb+Zl8yFQ/XJMP11S0tR62oDoZrg6FIXV1drCZSWxMJhUKhiLNqayXRzvbmtpbUDgrEo4GaQCDcFG4KBhqbwvH60p137wtm6+6hWKwp2BQL1TWlampC9f22JkAwvEPtwdiyplhtItIUSgaiwcZILJqo77/zvj/pFIsHo/ZCNanGYG04HA7Wuy6ThsXzgqFgg+2/bdasrl4Rb6uutpd22CfeZm/a2zfDd4Bvhm/hVov6VoYCvnhH74PNrbq6uTPVXl3d2R5v7uyork60trSkEp3V1fWrO1PppF2Hb+6Cvnr2W+DrrWPW/r5oqnd7byCRRG1dU7gpVpcIJ6Oh+gFVO2R9MGCn/crGvVNr8hpFguFwtDERSkVT0bq6+oE1eVP9rK1qEq0r053BSLK1ofdZg72uVIe7akfzBhKd7QvaWxOpDruVSDAQCScD4cZEoDHeFEzVD5pgtzKjo2lu05po/ZxgTfPchlhDQ0Pv0kDBsP0s2dLQnurtAwZP/jenFOtbjqhu23JEwVBzR8O20pB/e2KJpmBtTbQ2GKlNJmuDoVD90MR/shOLNLWnUv/chZVNyTcSb/jXKjet6Oytum5ma/uKeGdnqj0YaosnG5rTnaml7fGWYf/cU+xg/EggGkzEQk2RxsZksCYWqh/+XxxQEwnWpWKJcE0gEY6G64L1I3brPcOf0TxiI4+tSHUua012JILRVEci3pZqSKYaVy5tSK3uLI/8T+MLt8WTI0f/fHfWtKKzZlV7c2fKM267XmaHziHRGKxpTDYFE3XxVLIxGqj3br/rDuFGA7W1sViiMRKujQdSTY31Fbv07bpDF1eXCtdGG+vi8cbGZF20KVxfud1u210mArXRmtpkJNjUlGoMhZvqR82zAzmoo+Hnsqi1raOmd50y20GRhpr97OdH1PcaqLahN9g9bS3tZ9NrG0av+E96MhhONne0tXakGhLLVqaX/7M7fUv/k83VrkitiLc0L03/c0O7zNma1/82s4OhvicNyXhnPBhMtKaPSrV3NLemO2q3jj/GtPxHJQqtTLc0p5f/nEK7BndMp8jSVOcMuy9MtU9v7nVYMhYOhhtDjYloJBYNBurqx+77v4m1Zml7vG1ZakWqoa9/D7a0ti5f2dbQ0dK6atyC/02VEXuo1tC8NN3a3ttZbl/j+J37yt6Um26vCxiMLasN1cWjoXgiGE7WNNY0BusnlO5wFfWX7nChrGr/jyKpbU7bKzA1tMTbl/6sdat37ud61zTcv/XA1vakHUIgWlMTSMbDgUS8KRFIRusnzvxfaFhjS7gji0Ddv7lE1fSOw2p6lw0MRvODst5C76AsuEe+t6ht+NnTsPuBcHrlinDzirYV8zqn1zXE9mzuaGuJr7G3hKp3DDrY3HFAvKU5uY9diC2LJMM1NU1NsZraxlSyJhSrDx+eb3Dxzwztox2d7c3ppb1Iogv7nv98t3yg7ZE+q/SOBSJF2zrRmqJtHWX0FHuMVFfbO0TKr8dXfVQqUV19QCqRH7XvNBzafp+OtlQivyhffni0sC2VmNneumJOZ6p965B/ztaxUN22HW3TBpqiTYFEOFzX2JhM1MTrYzvnbseqeNvceEd+tGqbJJaMhKI1wZpAsK4pkmiqqa89WbZ26L2Xp51dV9cWTzcnljenlwbDvU8blsXTyZZUe2/vHuy7yuWVXBBf09IaT/6cU3oPD4YW2A99u9Z2xpenGhpbV9cFdjzzsN3rdHYcEG9vjqftC1EwGmqKhwOB2ngqEIunGusnuXYeRE927TxO3i289ar7b8LKn1fDqubOZQ3LWluX775znq2yE2z/1q1dRSqRjKZqGsPhRDQSjSRj9VOWNCyel7dXrG/1xlBgv/iqA1KJOel0HmL+ni3Y1Jxu7ljWsLS9dVUwtqwxFKxpSsTCyVRtMBKuC229d4iGwsFgIBIL1dUGa2tr6oJ7HJjvbiI/4+qtzfbaOhjcru0dmvxnGabWNyyel7d7W2d7uMZeQq+hOd3Q1hJP9N5v2vfY1fv3Cd3RG0RsWV28MdkUqUnGwoFoXTzeVD/NNtGceUfZ5xj6L85xTu84YIeTbKuP9d3MhoLJ1oatN7bxdLLPbL19ZnxrCs9qaW2Mt9T/czTT2/+bIP6L5nrveJNNgURdIBwOBUOpePRn0cwI5tPniJ8fDuax/DTq2XPl//diTY831Du3H1TOdG4/bpw1+z9isOmxhtkn/P/Yndi9xpxYw37zZvwPWw9Mr2nYa8Y/uSdYl490h5mXWjvShtZ0Kj+1FIk31tbF6pqCtcnaWP3e/4tKkrWJWDCSDETj8VhjLBGpn1u/bYz9M4MLe/TQq1jfEGJe5/RoQ900e1O8s7U9fwL7/LfuQ5P5+9B5h/7nL5x1+etmR2f7/NjP3v5sx6Q2zyS/xHJDYlkqsXzBXr0H/utD82OPUGCnZW0bmle0tQRjLal0w4rmjt4FbHsHI/seYt/hdHQmbfWCoe3XIw4GflqQOBSYGz96zcLO1vb40tS2q3cw8NNCpL39QygVb0zEG+M1oUQ4Xlu/3w7j/Z2N1zvaa4+n/5/irqW3aSAI1xVUICEhuCP1BkUkeG3HcXpBTWVED/TQSIWCYLXxbhI38YP1OokrFcpTPP402kfSJI2NU0C9JFG89nge38znGWmNowCANEzSOI4oIxiIXl+AYigPwj7Jktb2Od8qiGAgrSX6FI4fepSghBx+UuUcXAKANQjsFkPM91qM/kUxP6qU8Lb8BTsBe3UHwlHbD3GXe0xE25ux1EK/nBYrpBEerbOE8+2+DLg/PeDXcxsYwJBhH1PS8cfo7oxyqufTrhYBs86/oNjCHabhyA+xdz+PxkhsOuKMKArwE9XZ2E0cO2rg8YusNTyG8w3lfdY0IABP0WDgt/2Bz7KGuArvpZKRLE1LFY8ZBeYcQ9hnK7pnqWfc5nPYqeRn7QUlea7uPruUoqA+2V4WTraX7Z2spPGeqK4WtCOx75wtt5/bk8G1ZJIwJJ55SLye6y7hK35nVWsXpWaVlZfIOd4qijegCoG4Vl+fhFo+CQCG9KZsvg+2SoBdpovg9gwWePcwFN2Yl8sNgMKsnMbCz+ZOmNVZFhPo44sWiHaLBR2QEheJt1ft/J0XwHePi8cZ9uI4gz4q8pmlfk0ySnJ6ZVmfcy6WSvHG/xW/mKtTR2nt53tEeNae6yk0HTgsZ13FjUb3i1brYqlQa9zjC4/Khm05+tQJllg0q5XA6cVsd5IfhiqjAVPupAnlVuCnrhj+8DErVMg19RkUC+rFIshBgyGiFGWiuWwBr+bwcSQyCLHd9w+LLGhM7C1u/4NTsNaw59YqwKhoOtPOro792CjhQP+oWfmekZ/AUflzRoXPGla1II8+NtrI6zPKh9lTG5AQw6QXUQanB2URbdpwsWqeuE0bftHCfywm/5FKCPyqOcXcxbAw6aB0sODQKOp/00RWOdorwTKApEvij7pyyAFxV3dfswG/a3pRsDoTq4g3u0iRP7SdglPM2uSUMFJaJj0/gH4C0zARgzw4NH5qJTHSjij7pe1cwCX31xSXUfuYeAwK/kBJh4+Q6pjowNTNuo4bpmm5a69vxjTCqUdosn5jgMJuirpEu3aQJmztVizHiARX2tn6dXEP90C1Uavqmw+mbzfZNHTDruhWBVhbGyM0oGmyoVcNvWr+BkhzoF4=');
@@ -0,0 +1,140 @@
+import { WordArray } from '../core/core.js';
+import { BlockCipher } from '../core/cipher-core.js';
+import { sm4Wasm } from './sm4_bg';
+import { wasmBytes } from './sm4_wasm';
+import { loadWasm } from '../utils/wasm-utils';
+
+/**
+ * SM4 block cipher algorithm (GB/T 32907).
+ */
+export class SM4Algo extends BlockCipher {
+  static get keySize() {
+    return 128 / 32;
+  }
+
+  static get ivSize() {
+    return 128 / 32;
+  }
+
+  static get blockSize() {
+    return 128 / 32;
+  }
+
+  constructor(...args) {
+    super(...args);
+
+    this.keySize = 128 / 32;
+    this.ivSize = 128 / 32;
+    this.blockSize = 128 / 32;
+  }
+
+  static wasm = null;
+
+  static async loadWasm() {
+    if (SM4Algo.wasm) {
+      return SM4Algo.wasm;
+    }
+
+    SM4Algo.wasm = await loadWasm(wasmBytes);
+    return SM4Algo.wasm;
+  }
+
+  async loadWasm() {
+    return SM4Algo.loadWasm();
+  }
+
+  _doReset() {
+    // The round keys are expanded in wasm from the key words
+    if (this._key.sigBytes !== 128 / 8) {
+      throw new Error('SM4 key must be 128 bits');
+    }
+  }
+
+  _process(doFlush) {
+    if (!SM4Algo.wasm) {
+      throw new Error('WASM is not loaded yet. \'SM4Algo.loadWasm\' should be called first');
+    }
+    let processedWords;
+
+    // Shortcuts
+    const data = this._data;
+    let dataWords = data.words;
+    const dataSigBytes = data.sigBytes;
+    const blockSize = this.blockSize;
+    const blockSizeBytes = blockSize * 4;
+
+    // Count blocks ready
+    let nBlocksReady = dataSigBytes / blockSizeBytes;
+    if (doFlush) {
+      // Round up to include partial blocks
+      nBlocksReady = Math.ceil(nBlocksReady);
+    } else {
+      // Round down to include only full blocks,
+      // less the number of blocks that must remain in the buffer.
+      // Ciphertext stealing needs the last two blocks when finalizing.
+      const minBufferSize = this.cfg.mode._ciphertextStealing ? 2 : this._minBufferSize;
+      nBlocksReady = Math.max((nBlocksReady | 0) - minBufferSize, 0);
+    }
+
+    // Count words ready
+    const nWordsReady = nBlocksReady * blockSize;
+
+    // Count bytes ready
+    const nBytesReady = Math.min(nWordsReady * 4, dataSigBytes);
+
+    // Process blocks
+    if (nWordsReady) {
+      if (dataWords.length < nWordsReady) {
+        for (let i = dataWords.length; i < nWordsReady; i++) {
+          dataWords[i] = 0;
+        }
+      }
+      const dataArray = new Uint32Array(dataWords);
+      const ivWords = this.cfg.iv ? this.cfg.iv.words : '';
+      // Perform concrete-algorithm logic
+      if (this._xformMode == this._ENC_XFORM_MODE) {
+        if (this.modeProcessBlock != undefined) {
+          this.modeProcessBlock = sm4Wasm(SM4Algo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, this._key.words);
+        } else {
+          this.modeProcessBlock = sm4Wasm(SM4Algo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, this._key.words);
+        }
+      } else /* if (this._xformMode == this._DEC_XFORM_MODE) */ {
+        if (this.modeProcessBlock != undefined) {
+          this.modeProcessBlock = sm4Wasm(SM4Algo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, this._key.words);
+        } else {
+          this.modeProcessBlock = sm4Wasm(SM4Algo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, this._key.words);
+        }
+      }
+      if (this.modeProcessBlock === undefined) {
+        throw new Error(`${this.cfg.mode._name} is not supported by this cipher, or its IV is shorter than one block`);
+      }
+      dataWords = Array.from(dataArray);
+      // Remove processed words
+      processedWords = dataWords.splice(0, nWordsReady);
+      // write data back to this._data
+      this._data.words = dataWords;
+      data.sigBytes -= nBytesReady;
+    }
+
+    // Return processed words
+    return new WordArray(processedWords, nBytesReady);
+  }
+
+  _doEncryptCts(iv, dataArray, dataSigBytes) {
+    return sm4Wasm(SM4Algo.wasm).doEncryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, this._key.words);
+  }
+
+  _doDecryptCts(iv, dataArray, dataSigBytes) {
+    return sm4Wasm(SM4Algo.wasm).doDecryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, this._key.words);
+  }
+}
+
+/**
+ * Shortcut functions to the cipher's object interface.
+ *
+ * @example
+ *
+ *     const ciphertext = CryptoJSW.SM4.encrypt(message, key, cfg);
+ *     const plaintext  = CryptoJSW.SM4.decrypt(ciphertext, key, cfg);
+ */
+export const SM4 = BlockCipher._createHelper(SM4Algo);
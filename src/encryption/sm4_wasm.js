@@ -0,0 +1,3 @@
+import { generateWasmBytes } from '../utils/wasm-utils';
+
+export const wasmBytes = generateWasmBytes('eJzsvQmcXUWVMH5OVd3t3fe6b4fO2oHUe0ToQPalOwtLVzQJMUBQRFzQJCSN5HVI0gsBRqRfAJUlEBRUVEBUBERQGHUARWk+EaMi4IiIioIKyoyMMorCKJL/O6fq3ndfp8Fl5vf/fr/v+1j61a2qW8upU6dOne3ChsHTEADwNmxfD7Aeh+l/HF4vhoeB/uDwejlMafqLw+vVMD/xDw6v94bts/3F4fX+sMtxCRxeHw4Pp5lZEofXR8ON/EYah9cXhnMluQccXh8P58rk1tM80RK1tpZUKLDY4nl+qKSKpAyECKQnAlXYbz8lA+lFQRyNG6c8L5AYeJ7veUJ6qq1NFZIkRIkoUUnPk9KPQqmUJ31AX3hKSkmFUigAVB5ux/HjPYnQ5kc4jKZWG4E4uBoj/7Te07YNnCUg2rRtxdaNA2dtH4Ijok3bXtNr00cWs/xXDw3C8mJWRI+vbl237oyTN2/d9I7eretOGejthY3jcjkDvRu2bNm2EU5qy2WeZvNOnZbL27Bp07qhbesGhzZs7Fu3fdvmrUO9A/C2aAqCwbj9lNd2H/jmE5a9YcOqnoPmv+O4tdPe2Da7cOfl4rSrNE4cPhCM7qvI6WCgrEQP/auFgaNKYB/osUSFnsGyX8Q4qRosKy0MJusq/oqS1H5ZUbkW5UBjOeQGktUl0MI8u3fv3qC/7BkoR1Que7Svoyr1pFFH1UposDoToFIww4Omhv0m2KFDyogbGVUdGsG1is2ZkjNLzZmKM1uaMz3ObG3O9Dkzac4MOLOtOTPkzHHNmRFn7tecWeDM9ubMmDPHN2cWOXNCc2aJMyc2Z7Zw5qTmzFbOnJzLNHux3yQrS6CjslcUsVamtWq0AT3Z3D1si6es7tCTB5YCaGVabOGkpsJJrrBkCyc2FU50hUVbOKGpcIIrjG3h+KbC8a6wYAvbmwrbXWFkC/drKtzPFYa2cFxT4ThXGNjCtqbCNlfo28KkqTBxhZ4tbG0qbHWFyha2NBW2uEJpC0tNhSVXKGxhsamw6ArRFhaaCguukErippKYSyKTVMuRDsz2aiUwrWtKEGuho5Ul5I1UDt3GinWgvWq5SDtO9uiQNlnBbig4qiS00g7MY3US6MhgtRKtotaLZS/WUntdItFS+12iqKUWXSIsYtycQbWon5iqBjQo0AVK9FXLrRq1qJbH2RrjNVbL7dozolqhkZQnak+LPkpN0MqlJhkoF2WPyP7VPAFlhl9LDc8CNEf2l0OtzLT+csQVlFm3hhDf+EM6HCgrXTCiWm6hkYaUV5gFwhzZP8DUSZnta0tAoDHFIVtZjqosqbKOzLghU6s9C/22lqqWW2Jd1IU+3VItJ9zUJwOabVIu6pZyoShi0aPH62K1st8KoqB6Py2IEk7QxeqKEhbjWLdr3vmm56hSwT4x+SzIHnMeEgXVamVJcdvD1UpEMw955m6i51Qr0SyAij9SKVEjoH2Cgypi7Gq8q1GDdsTRXKXVVtHKnG2LTdBPk21jsCgq8Ifq2Jx/CumpEppa7V5YWfJiHdkjQhcM9vP0QlNDml9obsBjSqjDZPKKEvIL5YhmVRRx7onfeHO14pv91pTAoPaHzM6dur8kCPSfGK62SASE2GC5oMNyFOvIdQ52cpHrLzKfxmNKno6S/UtYpJGZx6GfMXrK2pKiVZjIIOc1MKJql2GSWwYHeHuqEBCjLCNbjHNpMZQex4tBcHUrQeCkPVxWOqTtU+b9oBxWRjqk1fB5poyTPkEyGmB8peapNuOpSnEy4sqMk5H2GSdDJjDNlS1O+g2cbKwM06qyiv83Ls6opVG0NCtKqiib1mZ1SVCOYsApu5UKRHLCarmow3Ih1tK0V7VKxvNcZKeYVFEO92WnaK8o6qYSrKARMugZ5WvJ2hIYwRlGGkVZNbW6oxzGOrYAptdDHfcdUwItTVjVsQ6TuZRbLPv0k9CUmIhamhLwQh/Zb2rDA4QKxt9RLrm+UEc6WAr0UzIjSMSTZ6oVpfjsLe4ot/B+4vdLPMy9eyWTKXpX0Lu2iRbzhG1CxLZM2jLBZbYFW1OZcTvMdq5LRLhTtBNFy+ik7BRTK5EDFy9/pWQhFVlIhRmkohRSoYNURJAKaCHHgFQ0ClIBQSqolgNdIsRKZ+kTAd3Bk3U96UD7NPSgASeP0Glf6IQpdPgNQW/YFzPoeLEtk7ZMcFkDOoEOG9Bxe8HCoMXCIBgNg2A0DAKCAQ0mGAMGwSgYlAgGpWrZ1y15GJQsDFpyMPDtVHzdksMVhkGSzcC94GDg2wn6usW+mKQwkLEtk7ZMcFkDBn4eBoLgFbqUzyk6uAMdVythl0jccZHDknj0fopH76c41iFjSWwhFDYgFOrYQojRgyBVsTjC/ZTyu6lkd1NLo6/8co9km0GNASPVwJMUCeyLSW4XBRZGgYVR0ASjYNQuCnjTEmRiHVYrKoVMNAalifalNFGD0qhy3KA0qgEZtQ+liWlB4mo5zihN1KA0UbqHXG+EezTwuLGHiFCMsYeiFDaxpTCxBWrcRGFiS2FiC764aQ9Rs3nYxDpysAm1sgxeUdFjNAYSBU2kRu1LalSD1IQMqmgMJIpSJIozJGoC1D4kOYdEYwLqFUnxPw4oNQpQKRJFhEQMKGnhFrtHETODRyRGWVpNBMbgmozEGJHOXWVzV8Qo86yUeRCJSZdU0THbju+MdUG3EtMea+AGiCkHLWeIsFtQ67o6HVpj4rCToyEmltxxRaZ22Qgkr7d5fPl6drfN+M50EQ3jOQeCSfoqOL0hDgDzBzroOXX/3ncew9IBorgaTFytCHNmf1ka6BT37jYVtaIkzLSyR2/t3RvQySnNQVqYcMc7KtC3w2C/BoNDfeaIatlzPGz6LzUysttUfJNw2+YFSbXj1R0VaeSOithRASP7V5RAA12EsV8Luh3JoYo0d1xyD1QrNPkRSnUKqIhOEVZCujKFGuj6Ajqk+4wgrgM6xZ7d5piSr6Ek0uHDCno+1Yghc+Mu10rYKZQ5s1/LvrLQYdmXfNB0iqQCxGwRk0IJv1NMKnsOZj4tJmh/FTOE7SahnPYKdNgW61wZgZDINA+xaXQY0ytV7ZukqqGDhSgBMZoVoGzgbGot7CgHGrj1MG0YaEdDl4BYeytKvh0M9TI1mxNNiJg40aO9TpFony40Hg2hnUBRjGL7lGgoYTGMLfBAE2wI37wuMclOMamEvBY07sRWmxTbmYYMSCpodwVFejkDZSXUQodarKZZCE0nYNhR9jWUwyLEsSGwnaODM/q7CCNiQqBqGbTQ0siBLqEoVa1IQn5lkHKKnqUZoMWQEVoMVcCAhr6B/lMrEaGIcCgSatFAESD485UuoMEWdagDuwhuCFE2BMj6BurbpyH0kUDL5YkqtQAWjffsNhVB9BE6xUO7TVnm8TswtPnkjqFK1E9V6Oo8wL1o2k1unJZf4Zx07OUgN/Yyn3uStr6gH7qdB41LOm1PghnNo4vGYHg59uw2RRUbcBhvzmHkoEneu9vEmUCQ5Xl+NmOfZkyAs5MNabKiS0A61YAuGm6qkJ9qZFAHPFXPTjXSnptq0JhqwGcG51QCN1WSfWRTDYhRBppqkO6VyP4E6ZaxGAHZgJkYQAM9eEXDFBKCIUE7LazSrkci18B7PCN8XhMZ8Jnuhbxxqa1ySETETNaewR192jPTVnYMlQMqZwrhV3yL7ZVIS74oEbpEWjCHWA51VBY64kaF9sucx+webZ9I891QB2b/HUb1V2mv+as6KLejDDowOFQOtE8nAKN8OOBGJ7SXIb5W/TQ1OYqglSGmvYyx7Ek3ZMWnsQWaWCexuhJ1lD1a8tVEX+iI7uArFfAoSgzV9rJP8ihPB7R9Q7rGdJBMiubB51LId/EUP8CCgDa67FtbIslLnlyGllyGq/j2ygQtRy79BrkMabv6KQr4bu1DoovhKHLpjyaXfoNc+mnDDXJJ670iFYLrkHrKkUy/mWSGo0imaiKZzLOnJBNzJJMPDd+STJ9q+45k8mz9FSW0Be2ugPGssVHDURs13aZhtk1D3gLZNoV0m3LdGotEQIukg+VW+fM2pUeB3aS+DjJ69AI2CJJvCdIL2KBI/iiK5NttKnJrRD+pHDFs3qbh6G0as/S9AqV9OQOHR2tTvLrfPhM7hSS6fWb3PUSiP1dTVVOrnd2fLCKlQ6dQbusCnWVITLlnofrobkPPYSWqVqCLHmnfUMljRL1p1Gs6uuihMYiHU7p+4yX38E6nCfPRy5OoBNWVJaH5VIESXd8yQD9p2QsCLtAOsHT5yd2G1njvtC7xNPfvdYkvXEKJqEvczAm/S9xICQZ4l7iX0yOcvj+XP8LpPZx+OFdnD6cf4vRjuToPcfpRTj+Zq/Mop5/g9DO5Ok9w+mlOP5er8zSnn+X0i7k6z3L6BU6ff2mjzgu5/Brn1y6l9K5c+gJOX8DpK3Lpyzh9GaevyqWv5PSVnL4ul76W09dy+uZc+kZO38jpL+TSt3L6Vk7flUvfwek7OH0vp0c4fX8uf4TTezj9cK7OHk4/xOnHcnUe4vSjnH4yV+dRTj/B6WdydZ7g9NOcfi5X52kLW06/mKvzLKdf4PT5uxt1XrBw3s0w392oU+P0BZx/xe5GnQs4fRmnr8rVuYzTV3L6ulydKzl9LadvztW5ltM3cvoLuTo3cvpWTt+Vq3MrbwefdFpn9lfAnFmtiC7af1ntO2wNDX06Mm+qVqBKpCPsIspA3KWjgsQKmM4uoWhKtRp2iSd2G6IFQvtWLyDW2EOtSNxcP59BuEN7K0vS0V/eu9SQ9ld38Ka1gsPQkQFLH+x5CpVA850jIwIijsdsHePsdeYYHB3h0dCZSgSlWglXk0CWGitDxhrngBI4oOwDCGUBQdfmVwAEA2o9NUeN8flgkurqjkpgOrjqDKI+5YIOTFKlp1svMXTHDJhM60I3MYGvQLXoHLj1EuL2plaZXoIJ+Gg0it4PV9MZHGixshToQAd0EJzTbxn8QIu+CrjBM99nL6H2IBMako5i2HSQhQY18EHm24Ms1L47yCB3kIGV4XNWBdxBFmpoHGTAJ5W9VQs6wYS9HfCxFhaD2J5sBC5aJR1VGcB2XQiQjTM5aGQKd0D3laUW7jRZWXLphyhNggLBR5aR/QZX8Tx95tCSCcyF06var9LdVlkezHcAo8MPs/sJ3QgIXDI792XSUfSbwCUM3c5z4BIZuGQOXLIsWPrM4HIX7bLIXbTLMnaQ0TI994X9oetJ0SOkJc6PdvD9vIPdUco3qZAxWIc5viW7afEVpujH6eVCuDse37xIK0ptOaaeEnsYoyG7JL7cLWCPvQXwRY5fZ1EqZm/xVa5x3UwvoCST5uta1W6jdBpuQwpe+nRDghZ9jlEhXajgXenbXRlqP8ek7UueZGy3zsMptOgluvHa17O1thNqQIDZJFdxT75iAyksi5XeYtLGK0LLYxwS/MMLFWs0SXU6aIjfU8KIjE1q2FcJpwP3STKn5GAWx8k1tDHW6HAWhP0EOMb1cBZErFJLqppuIEEyw8oBCb7b+ytoEntFeJpWqJNkC51iUjJVh+Y5IF7/0QtHgEWPkoSwnWJLBelne6WQeJYBHyKNUq1WO5NYeLXCSnDOJF3dkFHJibFrCkmhnczgUVAFIuqd4uxkStbKCpYZKIP8Uqc4lS/Bmq67tikra0ZS4Md95YJGs75aRnfBiFgTf061RQCKWBcMKZnixnVoUqWoSepmoFzSRY19lYj0+Uhp4jBp8uUWts0pkRI/WVcprightUwDK+oWjVUdvVgAJmXYJSaRScHqksgGsL1aKdkrSatGlsgy3FGXkjmcX2513djLWkITwnKiS3yrIRWCbtFJVRfptVLaVaJL1UrJXnhadamPd6ltumQS23TWZinWLVS/RRdnAHQDtVicAWE3hDqk+lUaeJFneCJdrAgARfPMhSOQHACxLumIBJwm0ZGOzGMuOw/EgiYDB6sWnWqRptKqoyo12pdMNVBONOOAttCYXmkxYDBp16HprOqSbjFJMkNHGtda0wtdNFdd5LqPeDQ3X5TrtuBsqUjtwri6HLvFYh3qQpdYWBTWnCohJWiyjiXoOiS6dZgVPKUVqSGLYK20joUUuIUucRgtQ6ILNO90onMrgS6strg8sxzrAt/XA13oqzCgF+qCW+CFVV3QLXYVDitHsa1mr7YtbjcsJvDEulDVLa5fW5ev+S1VO9hCl3iTgXJgh/8GujGbhdUucRynOqtd4mgdmhHap+Z+qCbTaemOoZs8JcyL6QqS3UhgduVASLe2FIiYwmeTDpfTtk26xUmkkC5KC0naYATJQsnuNhZ38+jWW7Q/iVpZwQYqrRpz+2FTbGF3mFuFTrGYSc9jvPsDC6JNZaYf64n/sY3QrtJB1o4OmAPb5GgSEY3R1MgSoKBBkiId/J3UKGIgiaFkBpW/xkoTbQtHWco0ZAnd2RVwC80jSKcxZKdxdtkqwFw31tzApuz0FJ18Mp0e0T6i8UGVyR7aGsgUz3Z+qoVdlNE8l39SxUEryFXusZmyKXNhA/pN+Z1Z/kyXz+IPm1tq9GcJWFq3PatrakjnkIh/EQtp1RoPQV/Fm05yG5aZesSiqEzYh2Yn8UnmL2gl/Mxl+KxdYKORu9kYKXAmJGRlg9onAw+feAKfrDd8kgMF1sCR6wsdpEmpfTLE8c27SG1joOyTOMsjREys7IgsuAK2uSQpChmc+E6qVKSV6BKJ8ZjLx9UsyjK//dgIW2102IoBUUqu2J6XnFhBkbZaJ/ohXZBcUxJarCgRr4NrS5YJSYeqyHDT3mZ0kOXSuTZcrQR29AELD5teIhESViuCbcmwzHYfnhl3y3L9nkqoPdNeveX8brlYe5qeivw0V3umjWpozyjO6TQ31UZAe0R7VXIY/H1thK6NH/7VNnqa2lictsE1wrRdrpGOqza6TYL4VItGmskoXdwoscLRfzaPCei8JulyBs2eo9xJT8C3NxlcVWIWuwHRoy09EytLKccbVCtAJkmBNUkK2KSGNQTgbJFIXDytn4iDDqwtkiRTIn/AVmPDJEiNkHyutX2tE/OTEVJTLWt9JHMWcYGb8/QxwHlUEzhJdL6fW5KpGYDbXc70l1369+xsBjEdFSSUVqywlCtKglB4bYnFyLldJv8GfHUVZK4CXX6wWpEOY2VuTWWX0KRcWcMs1ZgrKptW1ApUaXir7UZbVZJ2RWW2olZfs9KZ6/wfvaLpRvzBqBVVyVuzdZVuXf8HNvhlRfScZXs0nWwrwipRuQSMcjfGiOQvBfpJKnQ/8LSfaNYj8/HbJdx1yNlUrmiYw+fNrcy7qi0ekK+AjHVhDoD5xb04yManFUE2lr+AwQEyNegvIQkJCp1c5ZJfi0E2Oq0Iyrjlvu9cLAcHSEOar5CWXT5GGb3sij9qi63RBY/hqftwUHP3P8+6p4sDHzOyh4FN901I2gke2ifRi6/V6hKJxLNmfnF/01RybdHxdkxJJlWTGEVeACVllEmY1+JXf3v/PiOgOwL3FumClkYlE8nKlxYADPbT/CJSzyDlKHvZI5uTxKeMqeWYfibR63RbKLLggoZhb/wkarn5YssyZqMSRB+E9tgIIrQpIPEUlv10CRkawvDVnVh87RORkGQtycInIOkLDLJmk4Q1faRi1uEaMkDAlSWS5qhqxSMtEFdVVFVLI0iMwyKxRlnoyiTd1wJTdOpqLityF6ocs1iNVHmGRTu0NGQzxItD8CuSOp4GmfIFzHFgg0MgscX9xD0vJkLHkLnfQUb7GTD8DBhQlgwEPwVCpvGi638KBd9BgWRFFg4EWoKDIDhAExx8BwfWMglbGuZLw6xU2tIiycKEKy06OHGD4ZqSaqxoycvwTDKArOmGoBRLpLBatlY3GQy05KZ0aO5KwSB0kOFE4MBAoPe5mxPdEhCwtUfitbKvPa3WlhSLW90ArCUF7QPIxidLmBY70D+T9ikz0MsU9Doo++Q3k1q6E4k7hok1QZtRIwdybx+Q87Yt6ZKrPArkrMhulI4CuZ8DuUxBbumCxzvBs42/LPg9Aj97mZAtP1vukOK7Sj4DXh78HjdF96gG+P0M/H4O/NKBn+xcyj6DX5SlLpU9XSLwCyvtLhfpwIgzWSwxq0WnsY1YHWyvGr7l/CO2fYrPLaL6fyfCXzsR8P+oE+Gxi/7fiTD2ifDiRa98IjQwWvYwpdXh2jwhWFHyxqKi+WNk9MmRQssj8rCmo+IRtNBCK9Zx/twgkmIhEubLUhLm5U6NxpnSgNcYZJxIOB8okghJoYixO0B8e4CgAxkfJaNA1jhAnnFQy2j833UUXPU3HAVNcPdeDu77kM+McpOYctTxkR4eZKJKcI8t3HmHF3Uxf3iQXSnBnY+ORlkK9zh3dLhVkXm4vwz99ux+UGtLIcPdy+DuZXD3xoJ7dnLsungU3P/BM+CXIbYMZ6RaGdpBGWwVCeuVM5kNbYpgm1UIiXiEWrkKnHL7JdJk/5/xTyRJIcmY4hPFDJthMkEcHtTCTCM60W+wqmV/JWa6NzzYP0AynZgdZsur01dD+yopYMx6pgxkflKR3JJ8+ZZC19I9kDWlck31/B1NKdfUzxpNQUVmTV07XK0UdIHbKnBbBW6rYNsa1IV+LQfdsvCKuO0apriJZJdluRkuYoEo4aIis3GiZVkRW2ARKoZGcpHMiopsLzbYJYp84/VYiV/2SM2YH+pY0yaH4nLprwGHnInLrX9tNciRuNy2z3KTIzO5P9KukLzC5f3s8pTbLWzL442yPkeyJ0M3stYlttOhm5eiG2lOeJshbY8xUS7UMa9A3F8p6FCPs/hVKIHer4FcoU64UmIrtVnUoUrtDbQJdQtXarGVWi1SUKXxDYQIdZErFftJRUNV+GCJjikRlPg88xrEyNcRGfuG2l/TQfp5IjhMmgu64KpaYhTS4iNbxufKUmIU2vWXRIwYNbg0vTowqD2yqaSWIx2kxCci4hNZ9wlRtVam5PLK3mx0Vcqfk8rsuXBsik/WCpb4KKvsVPbY4pWJf+1jOGyFyKR5rpqz+8l6rK/CNo9Ip6AyYgctMTsS9zPxJ0tRZCEgWRY601K0Bsyk7Tt7TSOXmRQgRwPhxFdVlrbmHkT+IZNyVctMqapsGR5zE2VhWZ5cDWqKGCv2BU69Ctgtzm5j8kN7kT37GwNgDsVeBakttG0F6R7mTOEyWTNTLYeyR0e0UERZPTOC/IdNTWS/5chYsF9R5tmgv4LWNtZ6HyNbMpPVgYWGKbIFZ0RuAMEOdqEZMDvPPT/s12jCVyhTL1/2cgWiWq0SfEi3XUEN1vHC00Ff2SPGjGyVycHB7N35YtCvhf2tmp01tcUkOwjmIQOSvbHMi9hvxBBbcEAFXX/Y6I+RwMGWbUHGHCytm4M1uzkIV0mMqkQqeR7aVJr9GCOz1wYGditVlv1lYayLaMh6EcVYbM62YR3CzNEcyKgBM6zDPD5iHh8xj49E2uwuZbGrcBayUZXXNdSYq0lNMnoOVyvCWhXHf/DRH97nHgakXGHq5LGxjFGG+CPZXwk6NFbtDA6u8owCa4QZrkmFtsJEVgwvk/0qglSUrbHFNfJ3WVMKTaIluaOQ5wJ5o5Rpn5/pWuOmycYmIJvngBQ0x9ifPlOrhWtKgSbAhtpPXeN8kmETQ5taALGMObMBAraCqnhG9Jci7VkLcp94KlxdIk1glEwgtpiMAgJrAUzaF2XvUQNGDDA5Jb032947Q1vPcc+pNRjpOMfRXYLuWvu87NHL2LCbCePUtoR0YjSS0O5XGoVvdfyKOLx+HTRaCZpbodsnkTN2oOYDbZ/x+pmHASlWrY1V3iyMrF0wNeL3rTsDO6faYZA7z8u27OyxbGspEPxknDMvuj+b3JqSstdLSdRbE4mSK1LzdasbdawR+ysQrknCNWlxTZCqgm+vx5R888ylI2Bmm6vIzeoYiM3N9vmxS93zXykfezqeBTymcCWY3J/aDz9Mjg3WM6c11jLBClrTEGnONmfaoQsj+zvYt4TkQlKLdI7YmCOSPTckhViL+P5022kwei1TZrSUA8xeWMuOgJi5c7EQhekGJcLVZA1pXtq7F8iE57NXx6s0mCfo0TzxncIqjeaaZwur+snX67Ibh6qmfzUla5cNVc1Pt9h0bahqrhni9K0/HKyad9rsl1ZVzZ9++QZ+ePa8xav7y8imU+RSxGQP2TfNnRnkjk/atllw2Y09Fa/KKhOcBbtv7Kkga2ZovddwiBuhVZl88O6HVazsSR27bGAB31zFZpp8lAnz4xtHoGrxWnCZueqW1KBBchcEib3Yz+E7LFXziKrR+07MhaQ9bu6XTUfHmIxtNDedpz+en86vPt48HZFr9jGejthnOg9jbjpf+0TTdB7G//50HnOnJrAoqOwTulhXGXIHrKZ2fTPhQ9f1VDw2/9CCzRWYQL9guX9DKBMOaTBXXjcC1VkAA+xqKJw52800zLdaOQfRA46AggYHmWJTI3Z4tnOPL91Z595MeP6mHvsWde6lnV/nBKyuc8+8cFPaud8QhzY6B+2T3Sqba7nOPXOdsABAg/3xtT6qYT5DiJ8wZ1tvRTbprVppDPFk/XwZE/1sVsY1NFTLbBZDLGLqcEVWRuwGJ/uNXMUWNs4Q0rnHWfNQ6sgSPbbR5MNei2RCvM9hSi9VqGNmuNNDStj0Q9ZkVTq/tGQCO5eSmWVGUsnyhOXL2QAbYyKCpMU+Q8peip19L2clHdYAkTwjhqsVZB8JjSWfMm+9xFTIc3PvNLfniQY2mUsCu3VQKuvI2halYMtMRjMj0tj6fzxB9pDHlLzM+nGFTZOdpDIzVpeUtdxGttvmtZEa1lgzWz7wqqvJWZK8S8rorLZxlJGmHd6e/PAacGAOxFIt3l6pMTkh0N5p/GdNRxldu08TYPOWvTIzhBbWsldqMaYhtNzXEFqOMoSW1AOxe0jXSrSuhsjmvo1O7WqAXQ38ewdPjspsfGqG2fw1jh/zRDiM55hDOeSIbcFn+1hi6cqhDsz4HQY1dlD0o8iJ5Gq1murPrg1OQp+kzF2QRoSQXCWwEkDZXy440XjA9fnckqbYz0GVOF+yeQd5dpcyrraU53dLeX63lOd3A13UMQs9md8tML9LTZVL3GopV5OaLJd0gZC9wNQi4PAkZM80S2NHuWg1FxrmYLGCazN3NZ552M8EP+pjHt1dL13x/juM7G9RAhDIi87S9tC8tJfkQDvKLD3eSwdyOWJr6TJ7HHBgHLJL2Cv58rBX9q8tCTY5JTOncqALFBigUyRtilybih5fHWGGCCstN9CY9p7Vb26t1WqEfCG/yE4OBessRbGVaGFMSbIpIQ3eng+i0a8Y3aWZm+9SMTkv6YJutCZ0gbnauFMU23x27yZDReLIuXXIWieSurpcIgq2luX1Y81KZl3kGyVGqaVbhHxbyGYW2JkVsrGwkbD2SKBBr5HFayk+38OAtFNz/xbbaBxlG13c1za6UiDraOdHyIwJ2+8Wdew2go7pxGAD16IWzkSwSCqxUmqRK5wtXZHCd1HgjZIR1u2/RPhozX0pANqLMVhQl8imnSJktRigQAVWjEB4NYnMawVbQk5vNoTUzG0qa8zXokWirL3f9LEMITubDCGnV3WLJvaE7CCdre20NanRKzfZ6Xa4bXNmRemYzJyFNdsRfZWCjlmcx16SZAtnX8rEMAR/TSe1Bhdhi6OUpdbeUKVmqlq5SwCdxyqNVNUw6FbOoFva96XtXlJgEEnxj5LkVTZYBMmc7kq1DESklLnjAqc2sI970kerD1OpAfr0ZgP01N45sEbZTeaRRZtbbNhGziXTSIzvDNBnE33FASG1XP5fj+393e//9Lur9kA3vIBaLr/yied/8dn3Pn+97oZn6fmp39334EOPfvQX5w93w9OUceH3fvLwvfc89eWXoBueoIxbnr7ngztvv/NHtw53w6OUcdG537jn0S/8/itfGe6Ghyjjff/8+4ueO+/8W97UDXvo+ZLb7/rAnbff/tO90A0jlHHeS5+5/AeX/PiW70I33EEZ37/98U8/+eW7vzGnG26l55Hv7frhj352xQ2/hW64kTKufubi5z/x0IefegK64VoexQ3f2vm9a37x6MZuuJKen/jJHz/xpcsvueT70A2XUcYnf/vt56+6+9GLPj7cDRdQxr/vvemKS56+7aovDndDjTLuf+aCOy694rYnrxnuhjO1XP7wziu/9IffP3f5v0I3bNdy+afuffbHl197wV3vrYPiVC2XX7Pnjme/uOuRpz8x3A3rtVz+wg/ufvT8B77/wKJueJOWy59+5N1XfPTSD//HxXUwHEeT/Px/fvNnd/3shhuHu+EoLZd/49Krnrr7pccu/tJwN/Roubx29/cefvw3v/jY9cPdsFjL5U/+6nd7PvLhc5/7wHA3zNVy+edvvOXKh//y+Ud+XodBp5bLr3r3PZ/42O++fP+f6iDQWi6/6cLv3Pr+b378vA8Pd8MkLZd/5oF/+8INX//cua/uhkTL5R985l8v2/XUVz/okaW9XP7Ix69//sE7X7jm9vrsOTIo+w1VySUFX4zBjC97RrJSrSaski3k08u3MdGqJhmioCxTq6b2ouwf0J75C7JkmBV+HLqlttiGUJvK0jB+9NmarOKZDwSrbFAx4aRNdPaEzEiVPctFBdZ1108HJ7PBkeb36yOXv3OQzeU+9Mk/ftkfLAfaMw/98sYveoNlMk+47w/3Xf1PgzxofleToon0ceyoVaMtTezOoA4GtT9Y8UzC1y1i8mlqpNWatIOfJg0N8GPYqBC6LPc8UPFM6YxB7ZmJ9HeQ+FbS+Hna54CpPkdv48BmzBIA92+nVGMLdfarqQlOSlNTNpgLkb4rL7Ty6l96KBqhXPc1TGji57GlU6D7R3qICsf4J6Q/AbNoj9ngf4K18mRgv9cjiRYmk+hRErFvoZ9lSLEm0LTvmAWPf6xnKbTyo9phWvtdzn6cE+ZyxnFOMZfTxjlJLidhOT9xjwPvIME5BY80fwYbMHO4av7ASa4jKD6iqJrHOItC4GCxmwv/AkthPDedtdtOFeZg+zIIyf8TlkM30t36sSdgGQJFCWxkPY2UFeWznuSsphcf46wgn3UHZ5FnnqnZu1uj7AHuh2HL4nSSktAxoTGJWPcBS2mzEmgnNIN28j6g3X8f0E7dB7Qd+4B2ipZmwt8A2gljgnZCA7TTmkF7gAXtAcsgjE2h7MxjCfPNTrpt18giXOThdC/BIjaCr+JsHo1LoUTH3lIouhBG8Z8UBk7sRDcASTzCCKEl5O+rzDmgjQs0B1sqigNGCBud0wVJIPYqKHscKC6NcFxkv76cPsZrkCBBFwCOc+wuAJ519ggzNj/MXwDC/AUgzF8APO1sRJjGkR5AxdwUUyNPh7ma1GTZmaD4lrm14VQDlgIhxYlkA56QJICpbbiXi1TJ8owwDXy3fi1nCZvFCkSyldu2pqNa5sA+Xh+1LrIeWXTjE2fj91nTLr5qhNZP2utjtYCXCx0tc1cLIThiqNWxpVcLimzlrhaWNy7bgAnW65moJjH5yl0ukEKvOcMydrT0LRtekk5uw/dQkkqlXDhHoo60yl0cbJsVpcVqAhjfIMZqk2VBPBgrw+gUyjHpBJt4RGE4bMNhqZTGku0Ii2Uo3qpne7e2/SQewR18SFELbCCXnViEP8wd54M3eiM2wC0fHBWKPkfitMiMkO04iX1JIKCsKEUtv7ZWq/nWiIQCXlUrrFlXLNHnewpdkjwrtua14lY97ROUrES/TIFKuJZna5GisOJpMQewItKaHmMESVTQ7hB3KQr5KmF4HBw81DN2ywnjEdKKTsBygfDH3sZZf8FGZ+5V3iMULGYOQNnGxSCMZHBa/BSMqTQeYNc0rsDX2jFaCDhki0n6rVNRzItOkJmDipvXntGuMGoqZJ1HtAxbtNIxHWKK/MXCZpCz9H9sSANDRqaQiVNsYEsVjyLFsQ+sF78kUToMErmAapiRssSK2Gq1mu7nCyUm0+xKciBV3ifKmeySA7KWVRNUKdB3Snfp9WGS2SijdlDk1JijtaNB86OL7wEKhOXeC6sc3yiJVzh3lFds/LGLzT/UeMlqE0md9Kitgtm2spBTVuKkUkEnQ0Nl0FAZNCRBo6zYYusfAcQrjtMO45XbfRkY/JV2Yw6gMB20it+tUAzTqmbybZINOBIinWQXrPQQq075L1l2nEl2RbNkF1MXd9GQ7ILGzHOdTlxLkGROtGv1yNKKdEVOpCtyIl2iYGf2V2QywUoxq6l6iWfALWtoEuliKtKlV0ePJXuJESKLQoBJR+s+QQiQRZUyDUIgnagSc6JKtBSAsyqYBSHAhqgSsyAEOCoIAbKocgyHemShsnWoz8adn+Jo+XDrWN72tpU9za2ks2+N498K9IbpAGdAfGfvOykoFf2/pqMCfWtZS5ZodBpmtPEOq6ZYtZo7avTMDIPooFbaHkLWnRNJ9+1xPCt6X2mscvwvDjnBknfWF1B3LPboq2AfR6T001iKRPJYJ+qUjDR8jkOpGppSYn+8Zi2xoJk3VRIak3FMyTkaVGYvw1UdKvAeQNc1shKBLXiOSaXnTcOQpCHhiASN4HvCmbI3lLgyGefC3QlSVv5BYDycHtg24Bp7enmURrb6pPB9a4l/YrtQCn221qrL2DSD7IvYys/jRzJGYj0TXX5j5+JfrRQ59AUbwdIjmd7zaU8BMEn6TUHcvEqgbfxN1UG1+JLpV1oGtay0DlbkqIsmDTR30eRAoc0XTcrKLprSFM4gczTBf8fx30n0d5BNz9iqh2KhtZQ93Vr261c5UqOYYSuK1q1Wn0xBIcjRnX6K5A9Gt02KNHjBhZlJrDCPXpDZaQrzdPpA4b9ecA/nSsRhu9ltwFAD5seeRvObw1d30JKZ/7BLZ+RQp7jrlh6Na2z+7Th2/o9h7PzZY2dPHDs7HjvbHztbjp39MiPcN5tDTbonEmbSjccSBAtYJiUUbOa9PlsX3nXLCNEv2hO1W+WgqdXuGq6a2mV3Da+uiA5Lkf6Nt3CHa8A1yF91QBdnvUMjG+JdKFmzSIxnI5BhRn3JgD71HRdsyOQC4nGAPGGj4mF2e7KXLkwj4KCL4daIqyc6ykr2aBbSVARlC86m1rCDvxpDrWPaMDuSWDUfeXNaika95ELtoQ21x2FtSSaLNp5luxYlWeSrk2DiQ8HmRWwLEy2cVlu74GuK5cjpyJU7ANyZY87hE8dF9rTnlw1YwSEO0dp6sGs29zYptgBqFLS7gta4tSlEH+RC9P27YM9zLY03lPvUjrTWfrSlyL2U5oDsbPE0DrJSn3b67zKnCeI8UlMLZP+Pp7/5a1JIW9KvqlkQRcEO5i1CAl31iCUV5oRqixQoXEZZ2N1LkupDV1YytoStZzy2gnKKO1LkW8cAjbcsL5xTCZbrC26w+hbqisZ4HLmzR8dwXkpsyAqAY0cGN7DNzdo0Bp72MpsRatekutNRLcmsJSJWhS0NywKnPB+uarW6nAoXlI0wBPG33RFLRzYdrZ+5WjmSEw5UrM6gU7x4oTExGQ/EQxU25iVl3wD/5IrTEvGyJVj9O0tyGXQrXUmIubrKUXrI3FGYFy8kbsajjUPVxu8oC7PXcx/fkFbLwaZb43eQ9JN1iJ41G3B361RVYYYHq/ZMxz5mWoftFy7A+uubGz5kTReqNnQi8jup276V+TUBc5wG8/uvpMCMGsB85oJXBKYrzoBZfbkS/HtLchljA/OZCzJgUjUG5oeCDJiV/zlgXnbZXwfmlwUWhi2ToVIbSp/VfOk3t2gI5HagWB6ubFgY2n1WWq1YJd3BkhfffrfDyiH4y08G+8v8yRCrrfZNwj2Q3pbDhJFKmcx16KI8pEs2GVbNpP4hXRgYKBe0Z7YTtIrsSVTxmTWInZbat5wIcYpDffQChR6nkC9iqEp3ZkglVoL01KTZi/oqsmRDo1jfeaprLZ9J9OJZxVmQGRSzloq5ajL3fGZXw0+J7K6/IVA1OOdwB5uomWnM95PGm3k2c5CW76gIG1tcGBwaMEcMlp2skc4QotRTGW3tEUNkLSXZSIGiWQYhXchWPgo0pFcIOqwzCq/VANP21tR+ii6QFeWiWrOaVqX3FAoNLDg0sLChgemOqazATPUzx55YDzVJ0XzJ71RZrtY1FcfWsteFVHN3G2YfJjUHWGuN2d0gnYDaZwLxDYyFzNxIwkLlhJ0yh4UkDqZIqYqVqIyQ/KEZi5A25LHzPODbsmJRqUVMlXozaZFDzIJFzJgrJEz1PTY+J18kh5iSEbNokw4xvQEy65KEmJIj75OUOmc+QQ1YxFQWMb2BstdATC+HmPytLGK/oz5nQmyjrNvI0cqF8SMAakUYd1uO9rFqZndK+7xqRvtqF+9L+2SDWrniMejY31aSyxibwtUuzigcVWMKd6fIKFz7/xyFu/7qv07hPiUE2gg7Oi8pBRvIElnPbgM4sPwVj+GvkkEmcStaC2QX7WGSe7KRHhJz/bkjwBI1LW0EifRNxn/lwra8/PvvPm/0+/h3vf/VV37/FUY+6s0bhLX5sMJAUyA7K6YevBGVeTog9qvA/J7HG8znNae4QdqjKIbmsgdga8Wjny1k+s0yzSdhK7nVDM2Bj13bsww4aH2Vt8KTsMUKxfOl0pxtLbz23vxztcbGD2dRacRIYyUKtmtS/8gqu+VlPcGYrTZcpF3Ea9fAMDdAn3uYBdde28MfzCDhGfLFh6dnClZsJlOpmYzvEla3mXC4pmZNZt7dmnAsc3YGPrxGezyji9iY+WX7o12yWcra8Kom952K3+SSLZoq+E0u2WKfl5tcsm1Mn8yTeowxsgAw85fepyzmw9oKcJKJrMpgpyiDK/vtUlFWGSz957iLVwtEgt5lsq8STycfz4TihxWSEpuh2WcWqWjnZM2xyArWz6eQGY741hWnoMPk7Q2niUIW5Mucb781lmxJQ6ez/X8jDJiw1uSCjU6cfs3yLrENcJqZ+8Sk3/f4HhKzuYpFxYJ1ahZDNrINn0wFGnIaXJGTdpgiOb5ScK4kjq4XrIfrZZK158Z9CuUfAI/6vwg8nxLoD9tgwyIVHaC17pfuDpp95kemn/lB95kfurzZT91YpZq1M3OqMYPJobZhZb8DQn5TKuvDeui4L/Z47A5lzdndl36U9vibONrPfemHlN3scpZ+joxfcF/64TfopPHti0HuSz9cJm2Z4LLGl35oPvkv/SjSS7M9ltSiytxgfCGxU5YvtaJsVvVVpFNmWK93uabDGZM7QaRak31YN0g/rCvYZ3md+4AG8e7WnJg/dEBqTY4CEq2hryyQ9RjFESUlKjIz6dlwwJ61yObOYwrnRp8E5Xpk5+IxnxXmnwZJr1FgnWjFqzq2yBuLLRLat+FXdGRubIgDY3OHe3geUaSyVhtmzunXrQ4rqbovB7O/NbO7LMKle4D95Abx8jdf4mzg0KJhQ3bLbk82ILZnLxTS8iv8bQPrqMIG8XKtjbul0VzlWrOul0j2fCLdcWDN8ET6ovOjtduQ7yGEfPfvymKX0ONju7IGhdlFrS+B+NcohGV75jr1nlV78b5dZY2YijbQc5eYqUUavnK6FmZ61dx1yYh12q/I1Cc/SaZYxc7MCscfE1rMIEM/Yi3QBi1ErbpJesYmUSQrt/qOGaTG0ph2AVaglZDkq1uGSVX0mCIz8uTmp0wxOdlOxn05CrWYIfnLUWDu2j0CXZm8Ugs2Foy/h5kmky0FiVTSWtmAAY5WUHQAE6xsCHrQOrjmI5rwPTh3vmYqwVEVVe6cZWrDkY4lX5Gam8ufuJavZf8Fz8rqPGvzCPS5LrQ2JtZheDrEj2BmRNXEaBhMlpKMrEUqBGTfsCt30Vcyho8mQ2UOtNFFWVRy1S5DmJ2e052ivU0SEimy0PG0JLMcjhFIwRk9BnPi3uNDm9LX7jL2PRsTkpuZZB8tOmkvWe3kjNTtcNo92QtdtsukewKTN0EM8afQ3prT+0CDBDnVdfZRb8lb07O0h03riKzQRZAVIvSZX17owKkuyE5k0Ezq37EUbMQhG5mEyIjKPkdmyYjXkM7xh8gkkxHSKry4y1KOm+wGomAsdOe0fOgtWt5yTiV00kYD6bET3mA15rUz7Yf2spKUM3TLrXELd3Z8ztZf042WIu9yyLl17I9cchdlq41moseWLyYsBzawn1WLWEIYfwAt25DypFb3haw1Yp98GyNJVS1Pb41J7PE+VBF8CaI4TiF7mePKDlZqWR0CjekQ+2EZZS29OfoBebfbVvnMtBf+dNCO3Ys/6IZVk32VwnQi6fQ9PbJUhxwTEyTCfuhzhfsKcsrEWI6EvEuZiXFcSpSyKL5jYnzLxDgXSJljYtDhnd1kBTJhnA46+JvHVfz/eVxXoo0K5e5gaAXcfAszB9tvxbBYBsk8CKwuAcxOkbQIJ6wuVaTZidbnlqNpIZuEIDNIzFkIqlascCBd6ON1zGwfpP0i88H8FvNL6aXnvQ0Eky+LYPKvIxhJvCmVLHSE0KIUf3xQpijFNBsbKMWSdEcU/8cHMu8fHMj5mMql4FjrXkrBo15m3yN9ySA5PlWUSOePajc9ETl2hLe5dCHNNj7ajY+08a0dV7bx2Xbh8uyU13/9lJ/aOOUp1Ef7K5/yUyvujBYz6DvH3TIcfcqPcRqzVeQFjdOYP9ToliI1LllhzUbYYpCt0qySXFifvIdpSLxo1i1rFoQUHTRKOvl2ngY/S40o0Dz7Ci9sh+YTZozlyK2AC+idnhHGanBMqhhNjw6XDTl9aVotXu+4Tdo5O9iMVvYbWFWtiB4OVbPKen8Z675BtSicxHZ2vaA0ffNS9a3s4FuI08RmRgpNHaRumWwUo/Zx8oaU8iiOLLCmZH2lW/+qg/dbWEmeqmAJK6xpa19qACHylyWmGDZIguVfaR0kM19WlJ6/lhybE8Qxm2bFa9J9msPJuhQLWq3ESjJcik5i9QDJ2iRrcEliNWZz2Sc+8s0Vm5pLBWBPvLepudejSCWr0Cmu28WmP9UuSvF6EhJZz9xZxNSwLZDlb+wbu3YZrk+/gvdzvIrsq/g+JKzPgGReN8zxumHK6zpLEWvFdHPTvoqXWZqXn2fSmCcuw6lWzzyJg4MVq6QApu+WJysgXsg0YbMlb44waOyzFyFg1Q6zZuvYCz/W2BpDvBhxWPv8VR21xajk8LJP4gN+dB4ATUIId4JW/tEXy3FPSicaggVs7Orkda18qQB7ayT7Afd1TItySRIvcV+Rs1+AtzY9mY+cwdbMsdJuZdoX9uiLu2jMqkyxLNyYycjSPu4z5sjG/Utm/WOvzYxn88506+HomZZ91jmXjQmS422oA0krMTNFcktMyXnREk5L/bQgWwvSsye9EB+YnY1iurMEyBQzZAqYbIL4AGsEAza6Cx6VblrLjMQHQLbpFWH1Fbvo8zGooaNNAsTT3CeOaNQDTUtlX5+ab33FqLYnMiraaCbkPr0fLUWCcXuKioyIJ0OcvAXiNschDRkcsBt0HORYJy2oxSls8jdDXHdpT7dVPs0QV1EasqIrckW7bNEEGMuqOm7NLVkiIS5lz0nYGu83xr5MHJI6JHzd6IzWOE4H29UaF+z8Nk+xXdnDl8qSuA3M/btHwBxoniAyfBjELWCeowD6h2qmykXXjgmT18QxHAgaqtPhQC4gOzb75YjI9rCmlVIE4ZXpmxSsS0KavR7cYEyYHBsHoCE5CuzvAohjYqRwKTyz23CL1iImpFSYHB57kPRBLKE1JoQQEMfPX4wIHIA+fuZiLJ3c+47NW/URunfrJt05IvUReiSZoc84tXerHtyyeePmre/Q60dw/Qi0DQ5tGBjSJ5811Ks3b93Ue6YemaA3D+ptpw/pbafok7edvnXTIKVs9VZq8G+tPEbbB1H1rduG9Aa98dQNA/aVDQNnLdObh6ho89bBzZt69UioO+m1QT3iz3iF3v97zU0gUKRt8VgH9YYhPVLSJ58+RKCzj+BqNM1yqR46tVdv6d1K3YyM4zcox1alPBg3sGHrO3ptw+mAK2krtuyUbQPaDmLbKdTYO4ZO1SOQ2EKa7N/z2pyB0weHNs5ZtGTx3O6ueV29807ZMH/Rwrnd8xeefMrJGzbOW7ipe0l316bu3oUbFizumjtny+aTBzYMnDVn47aB3jmDAxvncJNzuMvZA4P/cHuDQ5tsc2cNzhk6daB3w6Z1W7Zt3LBlztZt6+zz4H+n+Q1btmzbaDsYGvjvNJTN+/Stmzdu29Q7Z/vA5q1DG07e0vs/M7yBDWes29G7cc5p2zZlDc7Z1Lt9cM6mLadxtVlzZ8+fPW8e107zuOrQadvnnHHy6Zu3bJpz8pZtG/tOo+FRrS2bT6YKB23ctv2sdacMbDttHS/aUj247fSBjb0OKxxKdI4cOkNv2tZrt8hpG4Y2nqo39Q4Obd66YWjztq2jK+MM+AMmsBYAXpQACwCy50Dxl5fg+zKBAgCsBDpbm58XjHp+7ajnDbnnJQDQDgB3y4Tb+U+Xl38+Kvd8AgJMBoCNJ2/M8n4JAAfl6jwJtk76/BwATMs9PwsAB+aefwsAB+SenwGgTy1kz3ePav9iBIhyzxcAwMG553MAYHbu+exR5aeNKu8bVb581PNhAHBI7rl7VPmho8oPdv+boaHe07YP9W7SQ9v05q2bhzZv2LL5n3q13XqzeCvqM07dvKXXEcmTe+k42DSwbfv23k0AK0QCb3fjK+Vw4OVwIu2/E4HXNn2eggBTc88TxnjOw7PdrbEAgCIAKACQ7tcD+0/onn3q3z1TG9Rv4spi9z6NvYXg/rZvPzLzuF9vPNNcf2J/x8V3jv/ESScsPCF4+Pgzb9n5umc/+fyGwcHeAd4Pp2zYvKV301K9fZDgdcThmn8P1adt3rpu247egVN7N2wCuEImDPfblO375d4/rPH+hjPHeP92ZceYn1frqPnTnDZu2L5h4+ahszQ1ccqWbWcAwKtlAseBhSfB5jqR8H65BgGOzD1/DO2a3cgOvQqFgBElFEYC8c/Bg+jhvFmISOafOBNjvxAjHoiF8Qn2hlhAVUbEAzreGi+NFQqcNOlQORMD4UUzlnZTe6FCGRRECVtxKSoVYruYgkIsQSWUEFLiAUJiTM8eCoXtYoKPuJQ6UyEGIhYH4OFYxPkosZv8jiUqyt2f6/joiXYxVSwRtuX98SgMJOJbUARxdDKKKEIMVosO9k3ubkEPhRfj9AhPUeijEGKyUDJRJRTCx1Z8O4IEub84QBwgegQGIYpYooezvAVoRAV3SCUi9OWPhcCliAG1KkK/IPBgDGeLYruai+jRf51RUWglBMrFiEIiyqWhED3+6yWWMKDOpZh/ZAkrvYAoY1mif0XRC0WBQO3N87CAWMKktKAMol++C1txvZ6DgNOV9Dx8m/82CQg+4MmKak5Vx4mWyrE4UZ6ipAhRosLJwsMPiSnjSnhQODmeLecigbwNx4vlvhBCFDHEAzHGBYhSCE8gdmAL/27yInkWCiTFxeVtkEgsJhUU+GkM0McYD0TEOThLvBrHS8Cf4wc88PEQjECi8FSnVPgpAeognOIhgpiEC/2V8Tz1TuxqnYFCFOQ8JQS1XsD5crqH4RFYFAujghK4TgpE4aPAq1GG48USIfFVgfRW+iVewxZxQkjgnIhv8BGVQPEb5QsUHZSPKKrIr2Mv/Sr0IEIBhEx4mRJCoS50+kKFGPlCzi6huA9RIn6TpoWvnyACAv4/+ZLwPUA8irpCQ9FuxELPQ+oX2vxWKEoJ3rIgUkACOTyyrUegKAlQGHB/CmaLiSCjRAQHqCskdKv5IbbgBA9bIcA27sfzjijjtS2AhyvwXhK/py6FhxFCcFoIHuAB62vPAmyHALINeFQ8lzZeIHyC34F240URTlIYoUTvULksOmQS6vzWo61CwAgxwsLftPlo5UMMZEEcgMsQsYgRdo61AQWidM+0CRu90EZUjPchvg5FUAxpM8Z/x1YECWoqb8V0I0Y4SyKe/orbcK7dhAUlD7Ib0Hfbz1scCnFlbvPd1wP4tTLIS3C9Bn8zLV1Bi+MEjLF1EGe6zSNwOr462zxzclvnEBHib9yWgSRJQLiN0bQljAfimDE2Q4CH5zYCYmi3gspvBHQr0RJI79WM5AR+JfOb4nVpvkDxaxT7bo0wvzH+gAX139kYblvIiQjY2BSAR6rXYSxA8CbwpB+Gr7QNNuG1AW8C1dgEEJwWAKH/TXTEoDcRp+HXUKmHxIGBOMBbj4fQYiik0dFI2vF4vDi8Hg+KZsBBRH/FoajgjcKH2DtUyJ6RHhC+OEj4InSBKqaJRTQRKTGQSviq5EkMhpji9hLqFgjXfVtXoa9QKM9T2KalgIXwlK+kKHJPMHvcAeefIhUe0+6jhEOxxT8OgqIHU/wpcNz66Wr6UIytRBfQ/QJhJFHLBaUFb5tQmAA90KMni8lwAq6iPcUzK1IcDTSoRIgBTiUgIMoAQUzGyTht1P8hFLDd9+EIeA3160+BufPmL1i4qKt78ZINJ2/c1HvKW2fPnv22jRu2bOndpNev3U7MyNKlp289Y2DD9s4Z6/W2rXqDXn/stq296/WODVtO7507d+68ufPnLpi7cO6iuV1zu+cunrtk3tx58+bNn7dg3sJ5i+Z1zeuet3jekvlz58+bP3/+gvkL5y+a3zW/e/7i+UsWzF0wb8H8BQsWLFywaEHXgu4FixcsWTh34byF8xcuWLhw4aKFXQu7Fy5euGTR3EXzFs1ftGDRwkWLFnUt6l60eNGSrrld87rmdy3oWti1qKurq7trcdeS7rnd87rndy/oXti9qLuru7t7cfeSxXMXz1s8f/GCxQsXL1rctbh78eLFS5bMXTJvyfwlC5YsXLJoSdeS7iWLlywBxhAlvCAQYRiJgheLFpVgmxjn7Te1HceLiWJyaaq3fzgNp2NV9Ymt4nPyNnGXeFB8Vzxc/H70iPiB+BE+4f1M/BJ/pZ4Wv9HPqhfEn7w/Y/HgZUccu3b3Ndd87J0XX/7BT/zzl95zmx9EXYcf8cbfP/Rd1T6pq/uNJw7f9NnPfWXRE+Pee+Gl16hSS9u4GfMWLl2xcvVrj127qfeiXZfs/pd7vnbvfd9+5KR/ub1jahAW4vaJXUuW3vjpR38YdV/2vhuDwrIjTtm8+/1t29bd/ZvfvuXkO7/03It7j3/DRz46e87BnSdcfe3HP3ndDTfe8qW77vXj4vj9lx654nXX33D/d64NJk+pvOqIIx//1TO/3fv1+5Q+8FUHdS5YvPSo1x593PEnvPFNbznp7es39p7SN3jmu4Yvuu6mz9068tBnP7d12zd+8tPL3155pyfVLHmKxDmza+fuL+e1TlXTowO8Q73XqJZDajf509V01RkujI+VC4s7u6MJhXDSshVL5MYwmjvBK8sOr+OgxWqNN0cVgijo0QerYtQll3pTAlUMjlvdvaC0IJgdFvyD5P6hqD30+sQPo/Ls8JAJUw6a2j4xOlZNj15TmhwU/KPCg6PT4+VHHOIv8wr+63z0EunFbw7F/icfcFRYqF3/9sqKuOCX9lvqF/z2qTPVxNqdh206vnhUVFi5ouOo8PjS6qDwxvBNlZaCv7Kwv1y1ulu2hAV/SVDwZ6pC7Y+Tg2Vy/xOwdX7pvI+ecnpcu/eiozeWzp+bTCjs/qh37qqP3Ln0PfeduyQ4RJ3kH1RYWej09tt569LafW/1Fs5co5YEbT1eHERXPTJDbxj8+F92LmjF/f0WFe7cdaHq80oyCpLd62t/kTu/4E9sbWtdFQ0dVvtjYTDcPn7lP7UX24snRpNr7925Sr57eev484+b5vu17x9KHMH2WXKKEjt7prUt9XDnQ4fUHjlUYW2kKxS1J2rPzzhaFZQ4r+01Rx9e+1+H+ahO8DoWip0tM9Wm4hsLtc8u3r80U0WBaPFrHznvh7JNlmTtR35RYWtRLQ4Lfmc4U4mW6OCdbwr3l7Fcpg5QhaD25/M9QOl5vi8CPwyitsLUeHJxcilpKbaqRI4bt180ASeqSThZTok6cKqYNkHLQ8WseDbOVfPEfPy0uEl9JvyT+LP3ktwb3XLmWRdf8om5J77p4l2XTf1JS+uao//84uw5R570tnU/P/+SS9/3/k/f9qUvf/2+b37rp08+tRcUb4nupcsOX/3at51/6fs/fdsXvvTl+771wINPPgXZhllGO+btm3rPf99Hr/7mAw+W2mYsXbZi9Ylvffu6Tb2XvO/TX/jSl7/+zceffOrZUtuK1Zt6a+f/811fvfv7P3j2P89798XXXf/Vu7/+jQd/9OOjrvzKd+574MHVx6498c1vX3fhpbtv+5fb777nvm/8oG3CxLee9MfnX9pbO63/p4+3TNu6ber+6951zmc/99PHj/nyXRMmHjBt5apj19KmOWf4i19/+PuPPfuffxgY3D10+kGz59zwudvv/saDP3j8I9DzoSvn7p72wN5j177lrUHYmhw85ze/3bqt+/Ajl684/h2n7/nmQ9999Ie/emkv6HWVcx9X574m7FB+286bW2qf8XSwc6qcHKKaoxaqQGLgB22F41rHBScEUk0tRDKUgRRSyqLyZOxjy3jv2KAjODEQ3qTicerVcpZE1ea3Fpeq/V+1Tp+mqq+q7fHOvVVO8c/9i3xzMCGaFBHKVf2CP8V/c3Cot7IwUxUVynnxTDXFj2XtZr/gH91eey48TLbKI7zF4aHeuXvbJoVz2mbJSku5tbZLnfuRCfH4C67w5njLAtEyKap9tTJUrD0yZWfi1X4W/e4a2R3tPKm9dkdY+0n7YbLgLw5XhkV/KD5AvkW9OaqdN2lqYUJ0tKpd5H/muuJENe/jauePDgqKnnfu5/Uh/tGqdomqfVV2yNYS/IdI4AR3ZycZRvo8BQC6AGAEAJ4AgHvqd+ufAcD/AoCf1++8X6vfhX8BAPc6Wc/XAeApALjPyYK+AQC/AoA9APA0AHwTAP4NAL4FAP8OAN8GgF8DwP1O1vMd6hsAHgCA3wDAg04m9JCTFX3XyaH+FQB+BwDfA4DfA8DDTrb0fZKFAMAjAPBHAHgUAF4AgB8CwH8BwI8A4E8A8GMA+DMAPEYyFAD4CQD8BQB+CgAv0eUfARABBAJIBFAI4CGAjwABAoQkJyHZCgLESAanACUEaEGAVgRIEKANAcYhwH5o5Sjj0cpXJiLAJJKroJXHdJAMBgH2R4ADEGAaAmgEKCNABQEORIDpCPAqBDgIAQ5GK9eZgQCHIMChCDATAWYhwGwEmIMAc5Em0QPzSdaAAAsRYBECdCFANwIsQYClCBTKCQ5DgMMR4AiSUyBADwIYBFiOAK9GgNcgwAoEWIkAqxDgKAR4LQKsQYCjEeAYBDgWAdYiwHEI8DoEeD0CHI8Ab0ArJ3wjApyIAG9CgDcjwFsQ4K0IcBICvA0B3o4A6xBgPQJsQICTEWAjAmxCgF4EOAUB3oEApyLAZgSoIkAfAmxBgNMQYCsCbEOA7QjQjwADCDCIAEMIcDoC7ECAMxDgTATYW8eFsxDgnxDgnQhwNgK8CwHOqa/5Thq3ADi3vu7n1df9/Pq6v7u+7u+huQiA99bX/oL62l9IcxIAF9G8hJVD7qqv/6X1+fykjgO7aZ4C4DKapwB4Xx0X3l/HhctpjgLgCpqfAPggzUcAfIjmJgCurOPFh+v4cBXNRwBcTXMRVmZ0ugC4to4XH6/jxSfqePHJOl5cV8eLT9Xx4vr6etcEwA1IciWAT9P4BcDNdby4pY4Xn6VxC4DP1XHjVgS4rY67FwmAf67j78UC4PN1HPlCHUe+WMeRf6njyO00XgFwRx1X7qzjyF11HPlKHRfurePBfXV8+Lr7/QYCfLOOE3vc77fqOHF/HTe+7X6/U8eNB+q48WAdNx6q48Z367jxr3UYf68Oz4fruPH9OhwfqcPwB3XceLQOsx/WceNHdZj9uI4bj9Xx4qd1vHi8DrMn6njxszpe/LwOt1/U8eLJOl48VceLX9bx4ld12D1dh+O/1fHi3+t48es6XjxTx4v/qOPFb+pw/G0dJ/6zjg+/r8Pyd+73OSSZKcAf6/vyA3XceL6O+3fX8eMFBPivOm78qY4bf67jxot13PhLHTdeIrxBKwjEOn4IASAFgBIAngDw67gRCIBQAER1vCgIVgFCUQCUBECLAGit40NSx4U2ATBOAOxXx4N2ATC+jgcT6jgwsb7+kwTAZAEwRQB0CICpdRzYXwAcUMeBaYJitVp5YkUAHCgApguAVwmAg+prf7AA0rCSIhwOEQCH1td/pgCYJQBm19d+jgCYKwDm1dd9fn3NFwiApQKgdybAMgFwmAA4vN73R4gWCIBTKMCZAFguAF4tAGpEC+rzu1CQPBpglwBYKQBWCYCjBMBqAfBaAbBGABwtAI4RAMcKgLUCYLsE6JcAAxJgUALskABnSIDh+vPvJcB7JMDNEuACCXCLBLhQAnxWAlwkAT4nAXZJgPslwKUS4DsSYLcEeEACvE8C3CYB3i8B/lkCXC4BPi9JngvwBQnwAQnwRQnwQQnwLxLgQxLgdglwpQS4QwJ8WALcKQE+IgG+JAE+KgG+LAGukgB3SYCrJcBXJMA1EuCrEuBjkmTjANdKgBEJ8HEJcI8E+KQE+JoEuE4C3CsBPiUBvi4BrpcA90mAGyTANyTAjRJgjwT4tAT4pgS4SQJ8SwJ8RgJ8WwI8KAF+IAEelQA/lAA/kgA/lgCPSYCfSICfSoDHJcATEuBnEuDnEuAXEuBJCfCUBPilBPiVBHhaAvybBPh3CfBrCfCMBPgPCfAbCfBbCfCcm//zEuAFCfBf9bX5nQT4kwT4swT4S/33nRLgJQlwdn2d9tbz3uUE/8cpAFQAr1MAQgG8XgFIBXC8AlAK4A0KwFMAJygAXwG8UVm9xIkKIFQAbyIZvQJ4swIoKIC3KIBYAbxVARQVwEkkg1cAb1MALQrg7QqgVQGsUwCJApirANoUwDwFME4BzFcA+ymABQqgXQEsVADjFcAiBTBBAXQpgImkF1EAkxTAYgUwWQEsUQBTFMBSBdChAJYpgKkK4DAFsL/6/4o778A2qqzt/052hiqDQBQBYiYCUTNOLMmVmjgxIZACSQgQiiJLcuzEHhvJadTQl957SygaQBBCb6H33ntL6FpY6nZ2198Z2bC05IX9+L73j8Qje2buPc/vOfdezUhnYHsDLAN2MMA2YEfDL7YIww2IGjDCgE0MaDZgUwNGGhAzYJQBmxnQYsDmBuxkwBYGjDZgSwN2NmArA8YYsLUBuxgwxIBdDXAMGGtAtQHjDBhqwHgDhhkwwYBpBqQNaDUgY0DWgJwBbQZMN6DdgA4DZhgw04BOA7oMcA3oNqDHgAMMyBtQMKDXgFkGzDZgjgFzDZhnwIEGHGTAwQYcYsChBhxmwHxlf7hyO16ZnaC8TlRWJymnk5XRKcrnVGVzmnI5XZmcoTzOVBZnKYezlcE5qv+5qv15qvv5qvkFqveFqvVFqvPFqvElqu8C1Xah6nqpanqZ6nm5anmF6lhUDT3V70rV7irV7WrVrKR6XaNaXas6LVKNrlNvLDb67wHdoH64Ub1wk/rgZvXALUb/vZ3blPvtyvwO5X2nsl6inO9Sxncr33uU7b2q8zPK9j7ler8yfUB5PqgsH1KODyvDR5Tfo8ruMeX2uDJ7Qnk9qayeUk5Pa248q3nxnObE85oPL2guvKh58JLmwMvq/1fU+6+q719Tz7+ufn9Dvf6m+vwt9fjb6u93lO9S5btM+b6rfN9Tvu8r3w+U74fK9yPl+7HyLSvfPyjfT5Tvp8r3j8r3M+X7ufL9Qvl+qXy/Ur5fK98/Kd8/K9+/KN+/Kt+/Kd+/K99/KN9vlO8/le+/lO+/lW/fwE09MWGQCb8zwTDBNGElE1Y2YRUTVjVhNRNWNyFgQpUJa5iwpglBE9YyYW0TQiasY8K6JqxnwvomhE3YwIQNTdjIhIgJG5tgmWCbfpEDiJp+VT7Y1ISYCZuZsLkJW5iwpQlbmbC1CUNMcEyoNmGoCcNMiJuQNiFhQqsJSRMyJtSakDWhzoScCfUmtJnQYMJ0ExpNaDehyYQOE7YxYYYJ25ow04TtTOg0YXsTukzYwQTXhB1N6DZhuAk9Joww4QATmk3ImzDShIIJo0zoNaHFhFkm7GTCbBNGmzDHhJ1NmGvCGBPmmbCLCQeasKsJB5kw1oSDTRhnwiEmjDfhUBMmmHCYCbuZMN+E3ZXf4SZMVIZHmDBJOR5pwmRleZQJeyjPo02YokyPUb0X+Dczq2FhEPzHw1waBL+0y2VB+F01XB4EoxquCIJfiaYYBP/brl4QVq6GK4OwSjVcFYRVq+HqIKxWDaUgrF4N1wTBrxFwbRD8IhaLgrBGNVwXhDWrYXEQgtVwfRDWqoYbgrB2NdwYhFA13BSEdarh5iCsWw23BGG9arg1COtXw21BCFfD7UHYoBruCMKG1XBnEDaqhiVBiFTDXUHYuBruDoJVDfcEwa6Ge4MwuBruC0K0Gu4PwibV8EAQNq2GB4MQq4aHgrBZNTwShC2q4akgVFfDghD0lGBhCA4owaUhyJfgshAUSnB5CHpLcEUIZpWgGILZJfBCMKcEV4ZgbgmuCsG8ElwdggNLUArBQSW4JgQHl+DaEBxSgkUhOLQE14XgsBIsDsH8ElwfgsNLcEMIjijBjSE4sgQ3heCoEtwcgqNLcEsIjinBrSE4tgS3heD3Jbg9BMeV4I4QHF+CO0NwQgmWhODEEtwVgpNKcHcITi7BPSE4pQT3huDUEtwXgtNKcH8ITi/BAyE4owQPhuDMEjwUgrNK8HAIzi7BIyE4pwSPhuDcEjwWgvNK8HgIzi/BEyG4oARPhuDCEjwVgotK8HQILi7BMyG4pATPhmBBCZ4LwcISPB+CS0vwQgguK8GLIbi8BC+F4IoSvByCYgleCYFXgldDcGUJXgvBVSV4PQRXl+CNEJRK8GYIrinBWyG4tgRvh2BRCd4JwXUlWBqCxSVYFoLrS/BuCG4owXshuLEE74fgphJ8EIKbS/BhCG4pwUchuLUEH4fgthKUQ3B7Cf4QgjtK8EkI7izBpyFYUoI/huCuEnwWgrtL8HkI7inBF+qfv6n3vlT//F2995X65x/qva/VP9+o9/6k/vmneu/P6p9/+YwjcHwETo3As5pzp0XgOc250yPwvObcGRF4QXPuzAi8qDl3VgRe0pw7OwIva86dE4FXNOfOjcCrmnPnReA1zbnzI/C65twFEXhDc+7CCLypOXdRBN7SnLs4Am9rzl0SgXc05xZEYKnm3MIILNOcuzQC72rOXRaB9zTnLo/A+5pzV0TggyAUI/Ch5p4XgY+CcGUEPg7CVREoB+HqCPwhCKUIfBKEayLwqebotRH4YxAWReCzIFwXgc+DsDgCXwTh+gh8GYQbIvBVEG6MwNdBuCkCfwrCzRH4cxBuicBfgnBrBP4ahNsi8Lcg3B6Bvwfhjgj8Iwh3ReBfQbg7Av8Owj0R6Av2f6hJLBhkwe8sMCwwLVjJgpUtWMWCVS1YzYLVLQhYUGXBGhasaeF/i5W1LFjbgpAF61iwrgXrWbC+BWELNrBgQws2siBiwcYWWBbYFgy2IGrBJhZsakHMgs0s2NyCLSzY0oKtLNjagiEWOBZUWzDUgmEW1FgQtyBhQdKCWgvqLKi3oMGCRguaLNjGgm0t2M6C7S3YwYIdLRhuwQgLmi0YacEoC1os2MmC0RbsbMEYC3axYFcLxlowzoLxFkywYDcLdrdgogWTLJhswR4WTLFgTwv2smBvC6ZasI8F+1qwnwX7W5CyYJoFaQtaLchYkLUgZ0GbBdMtaLegw4IZFsy0oNOCLgtcC7ot6LHgAAvyFhQs6LVglgWzLZhjwVwL5llwoAUHWXCwBYdYcKgFh1kwX/kfrvyPUP5HKv+jlP/Ryv8Y5X+s8v+98j9O+R+v/E9Q/icq/5OU/8nK/xTlf6ryP035n678z1D+Zyr/s5TvxRrfO8AC5btQ+V6qfC9Tvpcr3yuUb1H5esr3SuV7lfK9WvmWlO81yvda5btI+V6nfBcr3+uV7w3K90ble5PyvVn53qJ8b1W+tynf25XvHcr3TuW7RPnepXzvVr73KN97le99yvd+5fuA8n1Q+T6kfB9Wvo8o30eV72PK93Hl+4TyfVL5PqV8n1a+zyjfZ5Xvc8r3eeX7gvJ9Ufm+pHxfVr6vKN9Xle9ryvd15fuG8n1T+b6lfN/241e+S5XvMuX7rvJ9T/m+r3w/UL4fKt+PlO/HyresfP+gfD9Rvp8q3z8q38+U7+fK9wvl+6Xy/Ur5fq18/6R8/6x8/6J8/6p8/6Z8/658/6F8v1G+/1S+/1K+/1a+fX7+2v0fbFzVBrFhNRsG2ZXvBfA7GwI2GDZU2WDasIYNK9mwpg0r2xC2IWjDBjasZcOGNqxtw0Y2hGyI2LCODRvbsK4NW9ow2IatbIjasLUNm9gwxIZNbXBsiNlQbcNmNgy1YXMbhtmwhQ2NNtTY0GRD3IZtbEjYsK0NSRu2s6HWhu1tqLNhBxvqbdjRhgYbdrZhuA1jbBhhwy42NNuwqw0jbRhrwygbxtnQYsPeNuxuwz42TLJhPxv2sCFlw542tNswzYYOG9I2zLCh1YaZNmRs6LQha0OXDTkbXBvabOi2YboNv1f956v+x6n+h6v+x6v+R6j+J6j+R6r+J6r+R6n+J6n+R6v+J6v+x6j+p6j+x6r+56r+p6r+56n+p6n+56v+p6v+F6j+Z6j+F6r+Z6r+F6n+Z9lwsQ1n23CJDecojyuVxwLlcZXyWKg8rlYelyqPkvK4THlcozwuVx7XKo8rlMci5VFUHtcpD0953KY8FiuP25XH9crjDht6lMedNhygPJYolxtV90dtyKvuj9lQUN0ft6FXdX/Chlmq+5Oq/wM2vGrDs6r7a6r7cza8bsNs1f8NG+ao3h+r3ktV77LqvUz1/oMNB6ren9hwkOr9qer8gQ1/039zbfi7/pun+/zDhoN1n29sOES3/6n9+Nr3VrT/etbW0f7PqA6J9t8HSkRhfBSmRaEnCukoHBCF1qj/FEbIRKEQhWwUevWYXBRmRaEtCrOjMD0Kc6LQHoW5UeiIwrwo/uMUOdBvKwoHRaEzCgdHoSsKh0TBjcKhUeiOwmFRODIKR0Xhlhg8G4NbY/BcDG6LwfMxuD0GL8Tgjhi8GIM7Y/BSDJbE4OUY3BWDV2JwdwxejcE9MXgtBvfG4PUY3BeDN2JwfwzejMEDMXgrBg/G4O0YPBSDd2LwcAyWxuCRGCyLwaMxeDcGj8XgvRg8HoP3Y/BEDD6IwZMx+DAGT8Xgoxg8HYOPY/BMDMqx/g9H1zjgP3057oD/lO+EA79zIOmA4UCtA/6jJuocWMmBegdWdqDBAf8hFo0OrOpAkwOrObCNA6v714QdCDiwnQP+0+62d2ANB3ZwYE0HdnQg6MBwB9byrxU7sLYDzQ6EHBjpwDoOjHJgXQdaHFjPgZ0cWN+B0Q6EHdjZgQ0cGOPAhg7s4sBGDuzqQMSBsQ5s7MA4BywHxjtgOzDBgcEO7OZA1IHd/cdpODDRgU0dmORAzIHJDmzmwB4ObO7AFAe2cGBPB7Z0YC8HtnJgbwe2dmCqA0Mc2McBx4F9Hah2YD8HhjqwvwPDHEg5MM2BtAOtDswcBBkHDtkYsv7PQTDdgXYHOhzwHy0y04FOB7r8/g4C14EDBkG3H4d/ndzv/yDIO1BwYJYDsx041Nd7EBzm6+1fi1fmhyvvI5T1kcr5KGV8tPI9Rtkeq1x/r0yPU57HK8sTlOOJyvAk5XeysjtFuZ2qzE5TXqcrqzOU05nK6Czlc7ayOUe5nKtMzlMe5yuLC5TDhcrgItX/YtX+EtV9gWq+UPW+VLW+THW+XDW+QvUtqrae6nqlanqV6nm1allSHa9RDa9V/RapdtepZxerX69Xr96gPr1RPXqT+vNm9eYt6stb1ZO3qR9vVy/eoT68Uz24RP13l3rvbtXhHtXgXvXbfeq1+9VnD6jHHlR/PaTeelh99Yh66lH102PqpcfVR0+oh55U/zyl3nlaffOMav2s6v+cav28+uQF9ciL6o+X1Bsvqy9eUU+8qn54Tb3wuvrgDfXAm8r/LWX/tnJ/R7kvVe7LlPu7DrynXD9Rrp8q1z868JkDXym/r31WRRhRhOYijCzCqCK0FGGnIowuws5FGFOEXYqwaxHGFmFcEcYXYUIRdivC7kWYWIRJRZhchD2KMKUIexZhryLsXYSpRdinCPsWYb8i7F+EVBGmFSFdhNYiZIqQLUKuCG1FmF6E9iJ0FGFGEWYWobMIXUWYX4TDi3BEEY4swlFFOLoIxxTh2CL8vgjHFeH4IpxQhBOLcFIRTi7CKUU4tQinFeH0IpxRhDOLcFYRzi7COUU4twjnFeH8IlxQhE082NSDmAebebC5B1t4sKUHW3mwtQdDPHA8qPZgqAfDPEh4kPSg1oM6D+o9aPCg0YMmD7bxYFsPtvNgew928GBHD4Z7MMKDZg9GejDKgxYPdvJgtAc7ezDGg1082NWDsR6M82C8BxM82M2D3T2Y6MEkDyZ7sIcHUzzY04O9PNjbg6ke7OPBvh7s58H+HqQ8mOZB2oNWDzIeZD3IedDmwXQP2j3o8GCGBzM96PSgywPXg24P5nlwoAcHeXCwB4f4v9sYDvXgMA/me3C4B0d4cKQHR3lwtAfHeHCsByd4cKIHJ/nt+vepPTjNg9M9OMODsz04x4NzPTjPg/M9uMCDCz24yIOLPbjEgwUeLPTgUuV3mfK7XPldofyKys9Tflcqv6uU39V+bIOg5Gvh35f24x8E1/qxDYJFfqyDYLF/7kFwvd9P//60355/f9rX2b+epZxvVs63KOdblfNtyvl25XyHcr5TOS9Rzncp57uV8z3K+V7lfJ9yvl85P6CcH/TgTGX9kK/NIHjYg5M3hkeU+aPK/DFl/rgyf8JnMgieVO5PKfenlfszyv1Z5f6ccn9e+/OCcn9Rub+k3F9W7q8o91eV+2vK/XXl/obG+qavm3+v2YO/qFbRPvznrrNJHzT3waZ9MLIPYn0wqg8264OWPti8D3bqgy36YHQfbNkHO/fBVn0wpg+27oNd+mBIH+zaB04fjO2D6j4Y1wdD+2B8Hwzrgwl9UNMHu/VBvA9274NEH0zsg2QfTOqD2j6Y3Ad1fbBHH9T3wZQ+aOiDPfugsQ/26oOmPti7D7bpg6n+BykMYUv/E9iGsJUhDDKErQ3Br8A0xBAMQ3AMwTSEakNYyRCGGsLKhjDMEFYxhBpDWNUQ4oawmiEkDGF1Q0gaQsAQag2hyhDqDGENQ6g3hDUNocEQgobQaAhrGUKTIaxtCNsYQsgQtjWEdQxhO0NY1xC2N4T1DGEHQ1jfEHY0hLAhDDeEDQxhhCFsaAjNhrCRIYw0hIghjDKEjQ2hxRAsQ9jJEGxDGG0Igw1hZ0OIGsIYQ9jEEHYxhE0NYVdDiBnCWEPYzBDGGcLmhjDeELYwhAka+2JDeFVjv94QXtPYbzCE1zX2Gw3hDY39JkN4U2O/2RDe0thvMYS3NfZbDeEdjf02Q1iqsd9uCMs09jsM4V2N/U5DeE9jX2II72vsdxnCBxr73YbwocZ+jyF8pLHfawgfa+z3GUJZY7/fEP6gsT9gCJ9o7A8awqca+0OG8EeN/WFD+Exjf8QQPtfYHzWELzT2xwzhS439cUP4SmN/whC+1tifNIQ/aexPGcKfNfanDeEvGvszhvBXQ3jWEP5mCM8Zwt8N4XlD+IchvGAI3+i5e0zhHFM4wBTONYW8KZxnCgVTON8Uek3hAlOYZQoXmsJsU7jIFOaYwsWmMNcULjGFeaawwBQONIWFpnCwKVxmCoeYwuWmcKgpXGEKh5lC0RTmm4JnCoebwpWmcIQpXGUKR5rC1aZwlCmUTOFoU7jGFI4xhWtN4VhTWGQKvzeF60zhOFNYbArHm8L1pnCiKdxoCieZwk2mcLIp3GwKp5jCLaZwqincagqnmcJtpnC6KdxuCmeawp2mcJYpLPH7FxDuDQiHB4T7AsIRAeH+gHBkQHggIBwVEB4MCEcHhIcCwjEB4eGAcGxAeCQg/D4gPBoQjgsIjwWE4wPC4wHhhIDwREA4MSA8GRBOCghPBYSTA8LTAeGUgPBMQDg1IDwbEE4LCM8FhNMDwvMB4YyA8EJAODMgvBgQzgoILwWEswPCywHhnIDwSkA4NyC8GhDOCwivBYTzA8LrAeGCgPBGQLgwILwZEC4KCG8FhIsDwtsB4ZKA8E5AWBAQlgaEhQFhWUC4NCC8GxAuCwjvBYTLA8L7AeGKgPBBQCgGhA8DghcQPgoIVwaEjwPCVQGhHBCuDgh/CAilgPBJQLgmIHwaEK4NCH8MCIsCwmcB4bqA8HlAWBwQvggI1weELwPCDQHhK9Vktyqhp0rYvUo4oEqYWCXkq4RJVUKhSphcJfRWCXtUCbOqhClVwuwqYc8qYU6VsFeVMLdK2LtKmFclTK0SDqwS9qkSDqoS9q0SDq4S9qsSDqkS9q8SDq0SUlXCYVXCtCphfpWQrhIOrxJaq4QjqoRMlXBklZCtEo6qEnJVwtFVwoKwcG9YWBgW7gsLl4aF+8PCZWHhgbBweVh4MCxcERYeCgvFsPBwWPDCwiNh4cqw8GhYuCosPBYWrg4Lj4eFUlh4IixcExaeDAvXhoWnwsKisPB0WLguLDwTFhaHhWfDwvVh4bmwcENYeD4s3BgWXggLN4WFF8PCzWHhpbBwS1h4OSzcGhZeCQu3hYVXw8LtYeG1sHBHWHg9LNwZFt4IC0vCwpth4a6w8FZYuDssvB0W7gkL72jsw11hmiuMcIW0KzS7QqsrjHSFjCuMcoWsK7S4Qs4VdnKFNlcY7QrTXWFnV2h3hTGu0OEKu7jCDFfY1RVmusJYV+h0hXGu0OUK413BdYUJrtDtCru5Qo8r7O4KB7jCRFfIu8IkVyi4wmRX6HWFPVxhlitMcYXZrrCnK8xxhb1cYa4r7O0K81xhqisc6Ar7uMJBrrCvKxzsCvu5wiGusL8rHOoKKVc4zBUWuMKdrrDQFZa4wqWucJcrXOYKd7vC5a5wjytc4Qr3ukJRtbrPFTzV6n5XuFK1esAVrlKtHnSFq1Wrh1yhpFo97ArXqFaPuMK1qtWjrrBItXrMFa5TrR53hcWq1ROucL1q9aQr3KBaPeUKN6pWT7vCTarVM65ws2r1rCvcolo95wq3qlbPu8JtqtULbv+H/zYpC1IWNi0Lg8pCrCz8rixsVhaMsrB5WTDLwhZlYaWysGVZWLksbFUWVikLW5eFVcvCkLKwWllwysLqZaG6LATKwtCyUFUWhpWFNcpCTVlYsyzEy0KwLCTKwlplIVkW1i4LtWUhVBbqysI6ZaG+LKxbFhrKwnplobEsrF8WmspCuCxsUxY2KAvbloUNy8J2ZWGjsrB9WYiUhR3KwsZlYceyYJWF4WXBLgsjysLgstBcFqJlYaT2bfEg2C80TdZef+ngu+zB0YOdwcPMmmnJdRcsrf3b5dPqA8UF9dY3S+v599Lmf0l65PyVo6NltWWjY1ULdy6tEd1lWDi667YbLJv6tZXep6Z2YcZKR3NfzFiYG94VbZvQvazti+vS0y9asrCdZ9Id059b1sHr6RksXThz0bvRrk/ej3Y/+/HC7m++Th8gfHbAF7Jj3v/S+JHGYE4zp7FfaAFrrz/YDgyeZn/uTBuydc3SId3FBc6gKwdXW98MHsq/p9Vf3Leg/l8SbZDV0g2xqmhjaY2FTcPC0W2+tqK7Dq9dOMlKL5v8xYz0HhO6l+1x0ZL0FJ5J75l7buGevB7di6UL9170bnSfT99ftu+zH6f3G8xn+38hh6X8zzosCE1baX5kwcrr2YNXuSU2fFWcBWsPLy5du6Y0bR2+GbxetG/pehjp9eevvGz9+YGFGy4IL9xouLswwovLIsWXF27MOyM2qVka3Yzyss1qvoxufvyX+c2HDq2Z+58vf41oHjmqZSfOaDDcdFeOVVcpdNUOnZMudMn8hhzx1MTxs8cVkqnxvSMLdZkJmZrEhHF7dXan6ip1JpKF3nwunujtTnV2z8nlM+lCTnL9h9RUDplxwNzGyWPmTW3drSnV+G11iu82xozvba5LNY4aeDm+d3xvc0MqWZhXqJ80r9Cb62qp7//LyEKudedMOtc9KT93XipZ6M0OGpaaOr7pu7oW8US2e1Quk5/X0zuytxBvaK9pq83VtybTNa2JXG1joqHldwm/X36fpu81uqduwgFzE+PnpWr9+h1+FPGmSiGLVC6f786n/C/hp/K9xpAfNNKU7W5xK23EG9oTTZmmhrZksjXX2JCNx9Mt5k92HuhQvKG9ri1em2xrqqupzSXr0/XplpWqf9j9mkxvfrd8dyZX8DsfTyZa62tr0tmmeCaZq69vWbmuv/PL7X5dpnuW2xuvzXanKlspv5JNYZUh/mEjC21j2+bVt4yJ13WMTTWkUqlKVZJ40t/KdqbyuYrGq267gjYaBsqbNH1X3iSe6Cikvnu1Wua3pF7bls/lfsp89R36G0mnfl6Htq7eyqmbdurOd6V7e3P5eKInnU11uL256fl0Z+CnnhngOeCZZENDXSLZmMzUJuL1NXUtVZumpo5PFrpqk5O6apNubk68ob0xl0ynk7ma2mxNTTzTmmxZY7tKr5YjXK0PoqEr19venS1k4vW5Qibdk0tlc62zpqdyc3vXrP21MSV70tngpss3c1tXb92cfEdvbq3x/k57FVLL8013T6GuUgvIJ1Kbqpvob89oqQBpTFVONMrvp7/V3Jhau+u3ZBxPZjsKPd2FXCrTPsud+VPaoem/ZXONXbmudGfHdPenDa0z6nuU4zUVQzT7JvETN16XzNbGM7n61kS2KZltGdrZObtraKImmWxqaqxJNNQ2JuuSifpkzbpjvk2fFSZQPDGwkcqme9PxeKbbnZ3LFzq63ULjt8Poep2/qdCJWW5nhztzeTqvH/9hVtROz/WO9MeQXL65o5IX2YZkPNmaaM3U1zbUx2uaWsK7/zex1k3Pp3vac1053/c5NxuPd3Z3z5zVkyp0ds/ZYLf/5pS1/oyT6pjuducrY9L3z7jhj4fYSlI0+xW8/DRONKXrE+lMPJmta61rjbdslP9NRW/scP2KLqnOdH76ci0e+fGAVKkvNrl7z+58tjIg1dfV1WTTyZpMui1Tk61v2Xin/0KlOl+kH6ptNa1grK+rzIR1lRJe8fr+abHyojIt2jv2jyqNqeV2wx8vku6srmRHV0/X+N7mplTDqI5CT2d6nv+XwUN/GHS8ozAl3dmRHee/aGivzSbr6traGuoaW3PZukRDS3TV72bSTVb9bsTedP/+bkxdzsqkvtCb73CnV0DVTxrYXv6AuafvjQGLVObO2NHid7MxFRs7OdZfU2vo7Fxm6NApuYz/q8mx0ZNjsVmJmli60P/je/sUenKZ/sJaHb25/NChk3pymZ3y3V1jenP5/oNHxsZUTjB6cqzpux3jDe1tta1tjelcQ108kWlIZ1pbNvtxdhbmpHvGpgv9I5RvkoZsbaK+Ll5XE29qq8201bVsfpR8O/BXpogfu66pJ+12ZGZ2uNPjycpmqj3tZjtz+cosEB+Yafo12y09r7M7nV2eUyqHxxO7+T8Gdm3sTc/MpVq7525R88OeJ/1xpbcwJZ3vSLv+oihen2hLJ2tqGtO5moZ0rrVly+S3s9oKutzfZmpOR297qr27e+ZWP86hOX7yTO7+NtFzmWx9rq41mczU19bXZhtatt6zP9Frl+OchoGSaxXrxOMT03Om5DJjXDeXj8fbOtyOQntqer57zk87OcTXfcz42f7JE//DycdUptgfnL2npSGfK+Tys3OJeLY7NbCdSrvZAT6VYSb9rb9Hd3a3pjtbftoNJ/D9pXB14PuLnKHxfmfMWP5qoz/u/0z8wwqpqeN/2PlEzfe67ft5hO/j/6H3/pI5E2+qa2qtjddlmxLptvrlT6Y1s/7/K9mcTsV3/k2s0dyQShz2v5iCfqYlG1ITx4/8la3XNNelatO/kPcPQo43tGfS8fr6XF28NdGQTbc25JZPt67lu4XpcmZafyqtSDEwn47vba5PNY3w/5Tu7c4PgPtF726y/e9uGvb97eeLpv7potCbb2xY7nr8e2I39ovdX/szlWnPZWY27VI58OcP7Z+IEzU/qtOY6ujq6Yw3dObcVFdHoVKRsTIzb7PNfyb1FSCP9/ei8jaxscPN5HPpQm7bIwbmjPh/4di6VLx+Um+6tyMzqTf/fzFjbFf9C1Ts30q1dfVuv1YqNae1w81O95WoUBw+tz+Kmv8uil+Rd74Lvr+qGTG+H+T/9E6uYbnvTuOJfjv15HNtHXNHrf294AbeobcMXZHhG/wfqUrN3tQsd06Hm91pi+XNp/2eb6wc0d3dNXpO/3D7s/3u6c3Hk35hx1SHm+rpTGf84fPXqfuzwrY0j0vtXL38UepHffTHpjEH/qp+jqmM87Wp+u5KNaP6/qJGY/qJ/szFrNm5THJKLtPe0vIz0+oubb9WoxWNMwNDzM+0s+tWK4IcHxjVKucaW/Mt3+VPVfFEP4P+C1vjtvoFGdafo+PX/J4B/esxEyrvx/f6eQHS7rxfFvH43uZEKjnCndfQO68nl+rI/lSB3UauuKGJuV9wkt23+bXXVf4zmk8ctuJLhfU/vlQ4yVkRs9qBrW/TePIh/2tDrb8y2GNWf/OJ/7fN/3iAnNI4EHXH8olUyNb/4N1ic2Nqz1+m7sBEv9cWK9q7prJrJay92/0d9/6ltv1la4G2rp9RdGrdL8jThm9LtKa+LdG6z/JtODCixZP99dlS/UVj929JTR0fT/gX71MDmZus+V4WV9YRvd0pP2myqXQ+n54Xb2hvytXGM3WNuXg8nU7kcvUtqa1XpGDiW70r3Z/WuIJ9E/U/2HcgYQbclJ7/v7fiqE8X/DxvrV0+l/7/440Do+f3AshmByaCn+u0v3Btak1nZvbm05ncfwTIudlUob0735v67o/9815zferHE92BLc31qZz7G7ey/EV/pb22xhWvFRK12Vxbelbnj1h2d8+cXhlP9h7zC1YF8f7VSeUXDQMsJuZafj255qZUR82KXNr4rSSVwvn9Lc4YsYIjknXfHuF2D4RYaO/oSnUUUrPcQuUeR2p2YuYvTI3W7nxv54ifZKNP6rts7G6dkcv0piqrhnyuzX8H1ZDN1cSTNcmGmmxTMlnbwj6r9uS7s7MyuXxh0CqdaXf6rPT0nBgTZxV6CfT03y/KZatb5w0yK13YOD60qW5ozeAtv6s4PzhRk6ivrqmtjtdutdKcdGd+VmGlmqGJmqHJ/wNmExHQ');
@@ -141,8 +141,10 @@ export class DESAlgo extends BlockCipher {
       nBlocksReady = Math.ceil(nBlocksReady);
     } else {
       // Round down to include only full blocks,
-      // less the number of blocks that must remain in the buffer
-      nBlocksReady = Math.max((nBlocksReady | 0) - this._minBufferSize, 0);
+      // less the number of blocks that must remain in the buffer.
+      // Ciphertext stealing needs the last two blocks when finalizing.
+      const minBufferSize = this.cfg.mode._ciphertextStealing ? 2 : this._minBufferSize;
+      nBlocksReady = Math.max((nBlocksReady | 0) - minBufferSize, 0);
     }
 
     // Count words ready
@@ -163,17 +165,20 @@ export class DESAlgo extends BlockCipher {
       // Perform concrete-algorithm logic
       if (this._xformMode == this._ENC_XFORM_MODE) {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, blockSize, this.modeProcessBlock, dataArray, this._key.words);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, this._key.words);
         } else {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, blockSize, ivWords, dataArray, this._key.words);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).doEncrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, this._key.words);
         }
       } else /* if (this._xformMode == this._DEC_XFORM_MODE) */ {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, blockSize, this.modeProcessBlock, dataArray, this._key.words);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, this._key.words);
         } else {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, blockSize, ivWords, dataArray, this._key.words);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).doDecrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, this._key.words);
         }
       }
+      if (this.modeProcessBlock === undefined) {
+        throw new Error(`${this.cfg.mode._name} is not supported by this cipher, or its IV is shorter than one block`);
+      }
       dataWords = Array.from(dataArray);
       // Remove processed words
       processedWords = dataWords.splice(0, nWordsReady);
@@ -185,6 +190,14 @@ export class DESAlgo extends BlockCipher {
     // Return processed words
     return new WordArray(processedWords, nBytesReady);
   }
+
+  _doEncryptCts(iv, dataArray, dataSigBytes) {
+    return desWasm(DESAlgo.wasm).doEncryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, this._key.words);
+  }
+
+  _doDecryptCts(iv, dataArray, dataSigBytes) {
+    return desWasm(DESAlgo.wasm).doDecryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, this._key.words);
+  }
 }
 
 /**
@@ -235,6 +248,23 @@ export class TripleDESAlgo extends BlockCipher {
    */
   _doReset() {}
 
+  _getKeys() {
+    // Shortcut
+    const keyWords = this._key.words;
+
+    // Make sure the key length is valid (64, 128 or >= 192 bit)
+    if (keyWords.length !== 2 && keyWords.length !== 4 && keyWords.length < 6) {
+      throw new Error('Invalid key length - 3DES requires the key length to be 64, 128, 192 or >192.');
+    }
+
+    // Extend the key according to the keying options defined in 3DES standard
+    const key1 = keyWords.slice(0, 2);
+    const key2 = keyWords.length < 4 ? keyWords.slice(0, 2) : keyWords.slice(2, 4);
+    const key3 = keyWords.length < 6 ? keyWords.slice(0, 2) : keyWords.slice(4, 6);
+
+    return [key1, key2, key3];
+  }
+
   _process(doFlush) {
     if (!DESAlgo.wasm) {
       throw new Error('WASM is not loaded yet. \'TripleDESAlgo.loadWasm\' should be called first');
@@ -247,18 +277,7 @@ export class TripleDESAlgo extends BlockCipher {
     const dataSigBytes = data.sigBytes;
     const blockSize = this.blockSize;
     const blockSizeBytes = blockSize * 4;
-    const key = this._key;
-    const keyWords = key.words;
-
-    // Make sure the key length is valid (64, 128 or >= 192 bit)
-    if (keyWords.length !== 2 && keyWords.length !== 4 && keyWords.length < 6) {
-      throw new Error('Invalid key length - 3DES requires the key length to be 64, 128, 192 or >192.');
-    }
-
-    // Extend the key according to the keying options defined in 3DES standard
-    const key1 = keyWords.slice(0, 2);
-    const key2 = keyWords.length < 4 ? keyWords.slice(0, 2) : keyWords.slice(2, 4);
-    const key3 = keyWords.length < 6 ? keyWords.slice(0, 2) : keyWords.slice(4, 6);
+    const [key1, key2, key3] = this._getKeys();
 
     // Count blocks ready
     let nBlocksReady = dataSigBytes / blockSizeBytes;
@@ -267,8 +286,10 @@ export class TripleDESAlgo extends BlockCipher {
       nBlocksReady = Math.ceil(nBlocksReady);
     } else {
       // Round down to include only full blocks,
-      // less the number of blocks that must remain in the buffer
-      nBlocksReady = Math.max((nBlocksReady | 0) - this._minBufferSize, 0);
+      // less the number of blocks that must remain in the buffer.
+      // Ciphertext stealing needs the last two blocks when finalizing.
+      const minBufferSize = this.cfg.mode._ciphertextStealing ? 2 : this._minBufferSize;
+      nBlocksReady = Math.max((nBlocksReady | 0) - minBufferSize, 0);
     }
 
     // Count words ready
@@ -284,17 +305,20 @@ export class TripleDESAlgo extends BlockCipher {
       // Perform concrete-algorithm logic
       if (this._xformMode == this._ENC_XFORM_MODE) {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleEncrypt(this.cfg.mode._name, nWordsReady, blockSize, this.modeProcessBlock, dataArray, key1, key2, key3);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleEncrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, key1, key2, key3);
         } else {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleEncrypt(this.cfg.mode._name, nWordsReady, blockSize, ivWords, dataArray, key1, key2, key3);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleEncrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, key1, key2, key3);
         }
       } else /* if (this._xformMode == this._DEC_XFORM_MODE) */ {
         if (this.modeProcessBlock != undefined) {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleDecrypt(this.cfg.mode._name, nWordsReady, blockSize, this.modeProcessBlock, dataArray, key1, key2, key3);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleDecrypt(this.cfg.mode._name, nWordsReady, this.modeProcessBlock, dataArray, key1, key2, key3);
         } else {
-          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleDecrypt(this.cfg.mode._name, nWordsReady, blockSize, ivWords, dataArray, key1, key2, key3);
+          this.modeProcessBlock = desWasm(DESAlgo.wasm).tripleDecrypt(this.cfg.mode._name, nWordsReady, ivWords, dataArray, key1, key2, key3);
         }
       }
+      if (this.modeProcessBlock === undefined) {
+        throw new Error(`${this.cfg.mode._name} is not supported by this cipher, or its IV is shorter than one block`);
+      }
       dataWords = Array.from(dataArray);
       // Remove processed words
       processedWords = dataWords.splice(0, nWordsReady);
@@ -306,6 +330,14 @@ export class TripleDESAlgo extends BlockCipher {
     // Return processed words
     return new WordArray(processedWords, nBytesReady);
   }
+
+  _doEncryptCts(iv, dataArray, dataSigBytes) {
+    return desWasm(DESAlgo.wasm).tripleEncryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, ...this._getKeys());
+  }
+
+  _doDecryptCts(iv, dataArray, dataSigBytes) {
+    return desWasm(DESAlgo.wasm).tripleDecryptCts(this.cfg.mode._name, iv, dataArray, dataSigBytes, ...this._getKeys());
+  }
 }
 
 /**
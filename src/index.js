@@ -27,15 +27,65 @@ import { Utf8 } from './encoding/enc-utf8';
 import { Hex } from './encoding/enc-hax';
 import { Base64 } from './encoding/enc-base64.js';
 import { HMAC } from './algo/hmac/hmac.js';
+import { CMAC } from './algo/cmac/cmac.js';
 import { MD5Algo, MD5, HmacMD5 } from './algo/hash/md5.js';
 import { SHA1Algo, SHA1, HmacSHA1 } from './algo/hash/sha1.js';
 import { SHA224Algo, SHA224, HmacSHA224 } from './algo/hash/sha224.js';
 import { SHA256Algo, SHA256, HmacSHA256 } from './algo/hash/sha256.js';
 import { SHA384Algo, SHA384, HmacSHA384 } from './algo/hash/sha384.js';
 import { SHA512Algo, SHA512, HmacSHA512 } from './algo/hash/sha512.js';
+import { SHA512_224Algo, SHA512_224, HmacSHA512_224 } from './algo/hash/sha512-224.js';
+import { SHA512_256Algo, SHA512_256, HmacSHA512_256 } from './algo/hash/sha512-256.js';
 import { SHA3Algo, SHA3, HmacSHA3 } from './algo/hash/sha3.js';
+import {
+  SHA3_224Algo,
+  SHA3_256Algo,
+  SHA3_384Algo,
+  SHA3_512Algo,
+  SHA3_224,
+  HmacSHA3_224,
+  SHA3_256,
+  HmacSHA3_256,
+  SHA3_384,
+  HmacSHA3_384,
+  SHA3_512,
+  HmacSHA3_512
+} from './algo/hash/sha3-fips.js';
+import { Keccak256Algo, Keccak256, HmacKeccak256 } from './algo/hash/keccak256.js';
+import { BLAKE2bAlgo, BLAKE2b, HmacBLAKE2b } from './algo/hash/blake2b.js';
+import { BLAKE2sAlgo, BLAKE2s, HmacBLAKE2s } from './algo/hash/blake2s.js';
+import { BLAKE3Algo, BLAKE3 } from './algo/hash/blake3.js';
+import { SHAKE128Algo, SHAKE256Algo, SHAKE128, SHAKE256 } from './algo/hash/shake.js';
+import {
+  CSHAKE128Algo,
+  CSHAKE256Algo,
+  KMAC128Algo,
+  KMAC256Algo,
+  CSHAKE128,
+  CSHAKE256,
+  KMAC128,
+  KMAC256,
+  TupleHash128,
+  TupleHash256,
+  ParallelHash128,
+  ParallelHash256
+} from './algo/hash/sp800-185.js';
+import { SM3Algo, SM3, HmacSM3 } from './algo/hash/sm3.js';
+import { WhirlpoolAlgo, Whirlpool, HmacWhirlpool } from './algo/hash/whirlpool.js';
+import {
+  Streebog256Algo,
+  Streebog512Algo,
+  Streebog256,
+  HmacStreebog256,
+  Streebog512,
+  HmacStreebog512
+} from './algo/hash/streebog.js';
 import { RIPEMD160Algo, RIPEMD160, HmacRIPEMD160 } from './algo/hash/ripemd160.js';
 import { PBKDF2Algo, PBKDF2 } from './algo/pbkdf2/pbkdf2.js';
+import { ScryptAlgo, Scrypt } from './algo/scrypt/scrypt.js';
+import { Argon2Algo, Argon2 } from './algo/argon2/argon2.js';
+import { BcryptAlgo, Bcrypt } from './algo/bcrypt/bcrypt.js';
+import { HKDFAlgo, HKDF } from './algo/hkdf/hkdf.js';
 import { EvpKDFAlgo, EvpKDF } from './encryption/evpkdf.js';
 import { AESAlgo, AES } from './encryption/aes.js';
 import { BlowfishAlgo, Blowfish } from './encryption/blowfish';
@@ -45,6 +95,7 @@ import {
   TripleDESAlgo,
   TripleDES
 } from './encryption/tripledes.js';
+import { SM4Algo, SM4 } from './encryption/sm4.js';
 import { RabbitAlgo, Rabbit } from './encryption/rabbit.js';
 import { RabbitLegacyAlgo, RabbitLegacy } from './encryption/rabbit-legacy.js';
 import {
@@ -55,10 +106,13 @@ import {
 } from './encryption/rc4.js';
 import { RSAAlgo, RSA } from './encryption/rsa.js';
 
+import { CBCCS1, CBCCS2, CBCCS3 } from './mode/mode-cbc-cs.js';
+import { CCM } from './mode/mode-ccm.js';
 import { CFB } from './mode/mode-cfb.js';
 import { CTR } from './mode/mode-ctr.js';
 import { CTRGladman } from './mode/mode-ctr-gladman.js';
 import { ECB } from './mode/mode-ecb.js';
+import { GCM } from './mode/mode-gcm.js';
 import { OFB } from './mode/mode-ofb.js';
 import { Pkcs7 } from './pad/pad-pkcs7.js';
 import { AnsiX923 } from './pad/pad-ansix923.js';
@@ -101,22 +155,48 @@ export default {
 
   algo: {
     HMAC,
+    CMAC,
     MD5: MD5Algo,
     SHA1: SHA1Algo,
     SHA224: SHA224Algo,
     SHA256: SHA256Algo,
     SHA384: SHA384Algo,
     SHA512: SHA512Algo,
+    SHA512_224: SHA512_224Algo,
+    SHA512_256: SHA512_256Algo,
     SHA3: SHA3Algo,
+    SHA3_224: SHA3_224Algo,
+    SHA3_256: SHA3_256Algo,
+    SHA3_384: SHA3_384Algo,
+    SHA3_512: SHA3_512Algo,
+    Keccak256: Keccak256Algo,
+    SHAKE128: SHAKE128Algo,
+    SHAKE256: SHAKE256Algo,
+    CSHAKE128: CSHAKE128Algo,
+    CSHAKE256: CSHAKE256Algo,
+    KMAC128: KMAC128Algo,
+    KMAC256: KMAC256Algo,
+    BLAKE2b: BLAKE2bAlgo,
+    BLAKE2s: BLAKE2sAlgo,
+    BLAKE3: BLAKE3Algo,
+    SM3: SM3Algo,
+    Whirlpool: WhirlpoolAlgo,
+    Streebog256: Streebog256Algo,
+    Streebog512: Streebog512Algo,
     RIPEMD160: RIPEMD160Algo,
 
     PBKDF2: PBKDF2Algo,
+    Scrypt: ScryptAlgo,
+    Argon2: Argon2Algo,
+    Bcrypt: BcryptAlgo,
+    HKDF: HKDFAlgo,
     EvpKDF: EvpKDFAlgo,
 
     AES: AESAlgo,
     Blowfish: BlowfishAlgo,
     DES: DESAlgo,
     TripleDES: TripleDESAlgo,
+    SM4: SM4Algo,
     Rabbit: RabbitAlgo,
     RabbitLegacy: RabbitLegacyAlgo,
     RC4: RC4Algo,
@@ -126,10 +206,15 @@ export default {
 
   mode: {
     CBC,
+    CBCCS1,
+    CBCCS2,
+    CBCCS3,
+    CCM,
     CFB,
     CTR,
     CTRGladman,
     ECB,
+    GCM,
     OFB
   },
 
@@ -165,18 +250,60 @@ export default {
   HmacSHA384,
   SHA512,
   HmacSHA512,
+  SHA512_224,
+  HmacSHA512_224,
+  SHA512_256,
+  HmacSHA512_256,
   SHA3,
   HmacSHA3,
+  SHA3_224,
+  HmacSHA3_224,
+  SHA3_256,
+  HmacSHA3_256,
+  SHA3_384,
+  HmacSHA3_384,
+  SHA3_512,
+  HmacSHA3_512,
+  Keccak256,
+  HmacKeccak256,
+  SHAKE128,
+  SHAKE256,
+  CSHAKE128,
+  CSHAKE256,
+  KMAC128,
+  KMAC256,
+  TupleHash128,
+  TupleHash256,
+  ParallelHash128,
+  ParallelHash256,
+  BLAKE2b,
+  HmacBLAKE2b,
+  BLAKE2s,
+  HmacBLAKE2s,
+  BLAKE3,
+  SM3,
+  HmacSM3,
+  Whirlpool,
+  HmacWhirlpool,
+  Streebog256,
+  HmacStreebog256,
+  Streebog512,
+  HmacStreebog512,
   RIPEMD160,
   HmacRIPEMD160,
 
   PBKDF2,
+  Scrypt,
+  Argon2,
+  Bcrypt,
+  HKDF,
   EvpKDF,
 
   AES,
   Blowfish,
   DES,
   TripleDES,
+  SM4,
   Rabbit,
   RabbitLegacy,
   RC4,
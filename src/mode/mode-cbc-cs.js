@@ -0,0 +1,51 @@
+/**
+ * Cipher Block Chaining with ciphertext stealing (NIST SP 800-38A addendum).
+ *
+ * The last partial block is completed with bytes stolen from the previous ciphertext block,
+ * so the ciphertext is exactly as long as the plaintext and no padding is applied. The
+ * message must be at least one block long. The three variants only differ in the order of
+ * the last two ciphertext blocks:
+ *   CBC-CS1  truncated next to last block first
+ *   CBC-CS2  like CS1 when the message is a whole number of blocks, like CS3 otherwise
+ *   CBC-CS3  last two blocks always swapped, as in Kerberos (RFC 3962)
+ *
+ * The last two blocks depend on each other, so they are handled when finalizing by
+ * ciphers that support it: AES, DES, TripleDES, Blowfish and SM4.
+ */
+import {
+  BlockCipherMode
+} from '../core/cipher-core.js';
+
+class CBCCS extends BlockCipherMode {
+  static _ciphertextStealing = true;
+}
+CBCCS.Encryptor = class extends CBCCS {
+  processBlock() {
+    throw new Error(`${this.constructor._name} can not process single blocks, use a cipher that supports it such as AES`);
+  }
+};
+CBCCS.Decryptor = CBCCS.Encryptor;
+
+export class CBCCS1 extends CBCCS {
+  static _name = 'CBC-CS1';
+}
+CBCCS1.Encryptor = class extends CBCCS.Encryptor {
+  static _name = 'CBC-CS1';
+};
+CBCCS1.Decryptor = CBCCS1.Encryptor;
+
+export class CBCCS2 extends CBCCS {
+  static _name = 'CBC-CS2';
+}
+CBCCS2.Encryptor = class extends CBCCS.Encryptor {
+  static _name = 'CBC-CS2';
+};
+CBCCS2.Decryptor = CBCCS2.Encryptor;
+
+export class CBCCS3 extends CBCCS {
+  static _name = 'CBC-CS3';
+}
+CBCCS3.Encryptor = class extends CBCCS.Encryptor {
+  static _name = 'CBC-CS3';
+};
+CBCCS3.Decryptor = CBCCS3.Encryptor;
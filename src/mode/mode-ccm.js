@@ -0,0 +1,27 @@
+/**
+ * Counter with CBC-MAC mode (RFC 3610, NIST SP 800-38C).
+ *
+ * Like GCM, CCM authenticates the whole message, so the cipher handles it in one pass when
+ * finalizing. The IV is used as the nonce and must be 7 to 13 bytes long. The authentication
+ * tag is appended to the ciphertext and checked before any plaintext is returned on decryption.
+ * No padding is applied.
+ *
+ * Extra configuration options:
+ *   aad        (WordArray|string) Additional authenticated data. Default: none
+ *   tagLength  (number) Tag size in bits: 32, 48, 64, 80, 96, 112 or 128. Default: 128
+ *
+ * Only AES supports this mode.
+ */
+import {
+  BlockCipherMode
+} from '../core/cipher-core.js';
+
+export class CCM extends BlockCipherMode {
+  static _name = 'CCM';
+}
+CCM.Encryptor = class extends CCM {
+  processBlock() {
+    throw new Error('CCM can not process single blocks, use a cipher that supports it such as AES');
+  }
+};
+CCM.Decryptor = CCM.Encryptor;
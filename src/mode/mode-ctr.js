@@ -1,12 +1,64 @@
 /**
  * Counter block mode.
+ *
+ * By default the whole block is a big-endian counter, as in OpenSSL. Use
+ * CTR.withCounterWidth(bits) to count over the low bits only and keep the rest of the
+ * IV as a fixed nonce, e.g. CTR.withCounterWidth(32) for a 96-bit nonce and 32-bit counter.
  */
 import {
   BlockCipherMode
 } from '../core/cipher-core.js';
 
+// Increment the low `counterBits` bits of a big-endian counter, carrying across words
+const incrementCounter = (counter, counterBits) => {
+  let remaining = counterBits;
+  for (let i = counter.length - 1; i >= 0 && remaining > 0; i--) {
+    const bits = Math.min(remaining, 32);
+    const mask = bits === 32 ? 0xffffffff : (1 << bits) - 1;
+    const incremented = ((counter[i] & mask) + 1) & mask;
+    counter[i] = (counter[i] & ~mask) | incremented;
+    if (incremented !== 0) {
+      return;
+    }
+    remaining -= bits;
+  }
+};
+
+// Counter width of a whole 128-bit block, the widest of the supported ciphers
+const MAX_COUNTER_BITS = 128;
+
 export class CTR extends BlockCipherMode {
   static _name = 'CTR';
+
+  /**
+   * Creates a CTR mode that only counts over the low bits of the counter block.
+   *
+   * @param {number} counterBits The counter width in bits, up to the cipher's block size (at most 128).
+   *
+   * @return {CTR} The CTR mode.
+   *
+   * @static
+   *
+   * @example
+   *
+   *     const mode = CryptoJSW.mode.CTR.withCounterWidth(32);
+   */
+  static withCounterWidth(counterBits) {
+    // The cipher is not known yet: the widest block is checked here, the cipher's own block when processing
+    if (!Number.isInteger(counterBits) || counterBits <= 0 || counterBits > MAX_COUNTER_BITS) {
+      throw new Error(`Invalid CTR counter width: ${counterBits}`);
+    }
+
+    const Mode = class extends CTR {
+      static _name = `CTR-${counterBits}`;
+    };
+    Mode.Encryptor = class extends CTR.Encryptor {
+      static _counterBits = counterBits;
+    };
+    Mode.Decryptor = Mode.Encryptor;
+
+    return Mode;
+  }
 }
 CTR.Encryptor = class extends CTR {
   processBlock(words, offset) {
@@ -26,11 +78,15 @@ CTR.Encryptor = class extends CTR {
       // Remove IV for subsequent blocks
       this._iv = undefined;
     }
+    const counterBits = this.constructor._counterBits || blockSize * 32;
+    if (counterBits > blockSize * 32) {
+      throw new Error(`Invalid CTR counter width: ${counterBits} bits is wider than the ${blockSize * 32}-bit block`);
+    }
     const keystream = counter.slice(0);
     cipher.encryptBlock(keystream, 0);
 
     // Increment counter
-    counter[blockSize - 1] = (counter[blockSize - 1] + 1) | 0;
+    incrementCounter(counter, counterBits);
 
     // Encrypt
     for (let i = 0; i < blockSize; i++) {
@@ -0,0 +1,26 @@
+/**
+ * Galois/Counter Mode (NIST SP 800-38D).
+ *
+ * GCM authenticates the whole message, so the cipher handles it in one pass when finalizing
+ * instead of block by block. The authentication tag is appended to the ciphertext, and on
+ * decryption it is checked before any plaintext is returned. No padding is applied.
+ *
+ * Extra configuration options:
+ *   aad        (WordArray|string) Additional authenticated data. Default: none
+ *   tagLength  (number) Tag size in bits: 128, 120, 112, 104, 96, 64 or 32. Default: 128
+ *
+ * Only AES supports this mode.
+ */
+import {
+  BlockCipherMode
+} from '../core/cipher-core.js';
+
+export class GCM extends BlockCipherMode {
+  static _name = 'GCM';
+}
+GCM.Encryptor = class extends GCM {
+  processBlock() {
+    throw new Error('GCM can not process single blocks, use a cipher that supports it such as AES');
+  }
+};
+GCM.Decryptor = GCM.Encryptor;
@@ -60,6 +60,20 @@ export const isObject = (val) => toStringPro.call(val) === '[object, object]';
  */
 export const merge = (...args) => args.length && Object.assign(...args);
 
+/**
+ * Convert the significant bytes of a WordArray to a Uint8Array
+ *
+ * @param {WordArray} wordArray The words to convert, big-endian
+ * @returns {Uint8Array} The bytes
+ */
+export const wordArrayToBytes = (wordArray) => {
+  const bytes = new Uint8Array(wordArray.sigBytes);
+  for (let i = 0; i < bytes.length; i++) {
+    bytes[i] = wordArray.words[i >>> 2] >>> (24 - (i % 4) * 8);
+  }
+  return bytes;
+};
+
 /**
  * Check if the input parameter is valid
  *
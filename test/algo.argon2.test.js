@@ -0,0 +1,94 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// RFC 9106 section 5 test vectors: 32 bytes of 0x01 as the password, 16 bytes of 0x02 as the salt,
+// 8 bytes of 0x03 as the secret, 12 bytes of 0x04 as associated data, t = 3, m = 32 KiB, p = 4
+const RFC_9106_CFG = {
+  keySize: 32 / 4,
+  iterations: 3,
+  memory: 32,
+  parallelism: 4,
+  secret: hex('03'.repeat(8)),
+  associatedData: hex('04'.repeat(12))
+};
+const RFC_9106_VECTORS = [
+  ['argon2d', '512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb'],
+  ['argon2i', 'c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8'],
+  ['argon2id', '0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659']
+];
+
+// From the README of the reference implementation: 'password' hashed with the salt 'somesalt'
+const REFERENCE_ENCODED = '$argon2i$v=19$m=65536,t=2,p=4$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG';
+
+const SMALL_CFG = { iterations: 2, memory: 64, parallelism: 2 };
+const INVALID_PARAMETERS = 'Invalid Argon2 parameters';
+
+beforeAll(async () => {
+  await C.Argon2.loadWasm();
+});
+
+describe('algo-argon2-test', () => {
+  test.each(RFC_9106_VECTORS)(
+    'testRfc9106%s',
+    (type, expected) => {
+      const cfg = Object.assign({ type }, RFC_9106_CFG);
+      expect(C.Argon2(hex('01'.repeat(32)), hex('02'.repeat(16)), cfg).toString()).toBe(expected);
+    }
+  );
+
+  test('testReferenceEncoded', () => {
+    expect(C.Argon2('password', 'somesalt', { type: 'argon2i', keySize: 24 / 4, iterations: 2, memory: 65536, parallelism: 4 }).toString())
+      .toBe('45d7ac72e76f242b20b77b9bf9bf9d5915894e669a24e6c6');
+    expect(C.Argon2.verify(REFERENCE_ENCODED, 'password')).toBe(true);
+    expect(C.Argon2.verify(REFERENCE_ENCODED, 'Password')).toBe(false);
+  });
+
+  test('testEncode', () => {
+    const encoded = C.Argon2.encode('password', 'somesalt', SMALL_CFG);
+    expect(encoded).toMatch(/^\$argon2id\$v=19\$m=64,t=2,p=2\$c29tZXNhbHQ\$[A-Za-z0-9+/]{43}$/);
+    expect(encoded.split('$')[5]).toBe(C.enc.Base64.stringify(C.Argon2('password', 'somesalt', SMALL_CFG)).replace(/=+$/, ''));
+  });
+
+  test.each(['argon2d', 'argon2i', 'argon2id'])('testPhcRoundTrip%s', (type) => {
+    const cfg = Object.assign({ type }, SMALL_CFG);
+    const encoded = C.Argon2.encode('password', C.lib.WordArray.random(16), cfg);
+
+    expect(encoded.startsWith(`$${type}$`)).toBe(true);
+    expect(C.Argon2.verify(encoded, 'password')).toBe(true);
+    expect(C.Argon2.verify(encoded, 'passwore')).toBe(false);
+  });
+
+  test('testVerifyTakesParametersFromEncoded', () => {
+    // The type, cost parameters and hash length come from the string, not from the configuration
+    const encoded = C.Argon2.encode('password', 'somesalt', { type: 'argon2d', keySize: 5, iterations: 1, memory: 16, parallelism: 1 });
+    expect(new C.algo.Argon2().verify(encoded, 'password')).toBe(true);
+  });
+
+  test('testSecretAndAssociatedData', () => {
+    const cfg = Object.assign({ secret: 'pepper', associatedData: 'context' }, SMALL_CFG);
+    const encoded = C.Argon2.encode('password', 'somesalt', cfg);
+
+    expect(C.Argon2.verify(encoded, 'password', cfg)).toBe(true);
+    expect(C.Argon2.verify(encoded, 'password', { associatedData: 'context' })).toBe(false);
+    expect(C.Argon2.verify(encoded, 'password', { secret: 'pepper' })).toBe(false);
+  });
+
+  test('testInvalidEncoded', () => {
+    expect(() => C.Argon2.verify('$argon2x$v=19$m=64,t=2,p=2$c29tZXNhbHQ$aGFzaA', 'password')).toThrow('Invalid Argon2 encoded hash');
+    expect(() => C.Argon2.verify('$argon2id$v=16$m=64,t=2,p=2$c29tZXNhbHQ$aGFzaA', 'password')).toThrow('Unsupported Argon2 version 16');
+  });
+
+  test('testInvalidParameters', () => {
+    expect(() => C.Argon2('password', 'short', SMALL_CFG)).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Argon2('password', 'somesalt', { iterations: 0, memory: 64, parallelism: 1 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Argon2('password', 'somesalt', { iterations: 1, memory: 15, parallelism: 2 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Argon2('password', 'somesalt', { iterations: 1, memory: 64, parallelism: 0 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Argon2('password', 'somesalt', { type: 'argon2x' })).toThrow('Unknown Argon2 type \'argon2x\'');
+  });
+
+  test('testHelper', () => {
+    expect(C.Argon2('password', 'somesalt', SMALL_CFG).toString())
+      .toBe(new C.algo.Argon2(SMALL_CFG).compute('password', 'somesalt').toString());
+  });
+});
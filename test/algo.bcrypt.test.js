@@ -0,0 +1,79 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+const LONG_PASSWORD = '0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789';
+
+// Hashes produced by libxcrypt: password, encoded hash
+const BCRYPT_VECTORS = [
+  ['Empty', '', '$2b$06$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.'],
+  ['Short', 'U*U', '$2b$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW'],
+  ['Password', 'password', '$2b$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm'],
+  ['Utf8', 'pässwörd', '$2b$05$abcdefghijklmnopqrstuuZVEMa1pjhlynBQ1qXmSvGBJpN9h1w8G'],
+  ['71Bytes', LONG_PASSWORD.slice(0, 71), '$2b$04$abcdefghijklmnopqrstuuPoSfF1I5DbOoMYRG3EF1OqdMhjAyV4u'],
+  ['72Bytes', LONG_PASSWORD, '$2b$04$abcdefghijklmnopqrstuuRAip/W0RPQX4QKkqYqXE3GIXWH518Sm']
+];
+
+// The salt 'abcdefghijklmnopqrstuu' decoded from bcrypt's radix-64
+const SALT = hex('71d79f8218a39259a7a29aabb2dbafc3');
+
+beforeAll(async () => {
+  await C.Bcrypt.loadWasm();
+});
+
+describe('algo-bcrypt-test', () => {
+  test.each(BCRYPT_VECTORS)(
+    'testVerify%s',
+    (name, password, encoded) => {
+      expect(C.Bcrypt.verify(encoded, password)).toBe(true);
+      expect(C.Bcrypt.verify(encoded, `x${password}`)).toBe(false);
+    }
+  );
+
+  test('testEncode', () => {
+    expect(C.Bcrypt.encode('password', SALT, { cost: 4 })).toBe(BCRYPT_VECTORS[2][2]);
+    expect(C.Bcrypt.encode(LONG_PASSWORD, SALT, { cost: 4 })).toBe(BCRYPT_VECTORS[5][2]);
+    expect(C.Bcrypt.encode('password', SALT, { cost: 4, version: '2a' })).toBe('$2a$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm');
+    expect(C.Bcrypt.encode('password', SALT, { cost: 4, version: '2y' })).toBe('$2y$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm');
+  });
+
+  test('testCompute', () => {
+    const hash = C.Bcrypt('password', SALT, { cost: 4 });
+    expect(hash.sigBytes).toBe(23);
+    expect(hash.toString()).toBe('8a31be1b1fb020771689ae1e3c6d14c73a62b5d04bd5fa');
+  });
+
+  test('testVerifyOtherVersions', () => {
+    expect(C.Bcrypt.verify('$2a$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm', 'password')).toBe(true);
+    expect(C.Bcrypt.verify('$2y$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm', 'password')).toBe(true);
+  });
+
+  test('testTruncatesTo72Bytes', () => {
+    // Bytes after the 72nd do not change the hash
+    const encoded = BCRYPT_VECTORS[5][2];
+    expect(C.Bcrypt.verify(encoded, `${LONG_PASSWORD}chars after 72 are ignored`)).toBe(true);
+    expect(C.Bcrypt.encode(`${LONG_PASSWORD}xyz`, SALT, { cost: 4 })).toBe(encoded);
+    expect(C.Bcrypt.verify(encoded, LONG_PASSWORD.slice(0, 71))).toBe(false);
+  });
+
+  test('testRoundTrip', () => {
+    const encoded = C.Bcrypt.encode('correct horse battery staple', C.lib.WordArray.random(16), { cost: 5 });
+
+    expect(encoded).toMatch(/^\$2b\$05\$[./A-Za-z0-9]{53}$/);
+    expect(C.Bcrypt.verify(encoded, 'correct horse battery staple')).toBe(true);
+    expect(C.Bcrypt.verify(encoded, 'correct horse battery stapler')).toBe(false);
+  });
+
+  test('testInvalidInput', () => {
+    expect(() => C.Bcrypt('password', hex('00'.repeat(15)), { cost: 4 })).toThrow('Invalid bcrypt parameters');
+    expect(() => C.Bcrypt('password', SALT, { cost: 3 })).toThrow('Invalid bcrypt parameters');
+    expect(() => C.Bcrypt('password', SALT, { cost: 32 })).toThrow('Invalid bcrypt parameters');
+    expect(() => C.Bcrypt.encode('password', SALT, { cost: 4, version: '2x' })).toThrow('Unknown bcrypt version \'2x\'');
+    expect(() => C.Bcrypt.verify('$2x$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm', 'password')).toThrow('Invalid bcrypt encoded hash');
+    expect(() => C.Bcrypt.verify('$2b$04$abcdefghijklmnopqrstuu', 'password')).toThrow('Invalid bcrypt encoded hash');
+  });
+
+  test('testHelper', () => {
+    expect(C.Bcrypt('password', SALT, { cost: 4 }).toString()).toBe(new C.algo.Bcrypt({ cost: 4 }).compute('password', SALT).toString());
+  });
+});
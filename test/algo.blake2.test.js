@@ -0,0 +1,108 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+const range = (length) => hex(Array.from({ length }, (_, i) => i.toString(16).padStart(2, '0')).join(''));
+
+const QUICK_FOX = 'The quick brown fox jumps over the lazy dog';
+const LONG_MESSAGE = 'a'.repeat(1000);
+
+// RFC 7693 appendix A digests of 'abc', the keyed known-answer vectors over 0x00..0xfe
+// from the reference implementation, and other values checked against Python's hashlib
+const BLAKE2B_VECTORS = [
+  ['Empty', '', {}, '786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce'],
+  ['Abc', 'abc', {}, 'ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923'],
+  ['OutputLength', QUICK_FOX, { outputLength: 256 }, '01718cec35cd3d796dd00020e0bfecb473ad23457d063b75eff29c0ffa2e58a9'],
+  ['Keyed', range(255), { key: range(64) }, '142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e92484be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461'],
+  ['SaltAndPersonalization', 'abc', { outputLength: 256, salt: '0123456789abcdef', personalization: 'personalization!' }, 'dbe8233deda041edda3554928d2d7ce65159a46dd653254596459eb42ce16a24'],
+  ['LongMessage', LONG_MESSAGE, {}, 'd6a69459fe93fc6b9537ed4336e5099e0dcca3e97290a412500ed7a0daffb03d80cf3650a20e0591f748e10c3c534945ee83d5f2c9722f1a68d98b8c01af23fd']
+];
+
+const BLAKE2S_VECTORS = [
+  ['Empty', '', {}, '69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9'],
+  ['Abc', 'abc', {}, '508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982'],
+  ['OutputLength', QUICK_FOX, { outputLength: 128 }, '96fd07258925748a0d2fb1c8a1167a73'],
+  ['Keyed', range(255), { key: range(32) }, '3fb735061abc519dfe979e54c1ee5bfad0a9d858b3315bad34bde999efd724dd'],
+  ['SaltAndPersonalization', 'abc', { outputLength: 128, salt: '01234567', personalization: 'personal' }, '440df7cbe17fcdf927d11e0fdd93f90e'],
+  ['LongMessage', LONG_MESSAGE, {}, 'a4691c2bf852334ece63c024234338fc6c150bdf04fa3f6e0e4c5209b326438d']
+];
+
+beforeAll(async () => {
+  await C.BLAKE2b.loadWasm();
+  await C.BLAKE2s.loadWasm();
+});
+
+describe('algo-blake2b-test', () => {
+  test.each(BLAKE2B_VECTORS)(
+    'test%s',
+    (name, message, cfg, expected) => {
+      expect(C.BLAKE2b(message, cfg).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdate', () => {
+    const hasher = new C.algo.BLAKE2b({ key: range(64) });
+    const message = range(255);
+    hasher.update(hex(message.toString().slice(0, 256)));
+    hasher.update(hex(message.toString().slice(256, 258)));
+    hasher.update(hex(message.toString().slice(258)));
+
+    expect(hasher.finalize().toString()).toBe(BLAKE2B_VECTORS[3][3]);
+  });
+
+  test('testBlockBoundary', () => {
+    // A message of exactly one block must not be compressed before the final flag is known
+    const hasher = new C.algo.BLAKE2b();
+    hasher.update(range(128));
+
+    expect(hasher.finalize().toString()).toBe('2319e3789c47e2daa5fe807f61bec2a1a6537fa03f19ff32e87eecbfd64b7e0e8ccff439ac333b040f19b0c4ddd11a61e24ac1fe0f10a039806c5dcc0da3d115');
+  });
+
+  test('testClone', () => {
+    const hasher = new C.algo.BLAKE2b();
+    hasher.update('a');
+    expect(hasher.clone().finalize().toString()).toBe(C.BLAKE2b('a').toString());
+    hasher.update('bc');
+    expect(hasher.finalize().toString()).toBe(BLAKE2B_VECTORS[1][3]);
+  });
+
+  test('testInvalidParameters', () => {
+    const error = 'Invalid BLAKE2b parameters: the output length, key, salt or personalization is out of range';
+    expect(() => C.BLAKE2b('abc', { outputLength: 520 })).toThrow(error);
+    expect(() => C.BLAKE2b('abc', { outputLength: 12 })).toThrow(error);
+    expect(() => C.BLAKE2b('abc', { key: range(65) })).toThrow(error);
+    expect(() => C.BLAKE2b('abc', { salt: range(17) })).toThrow(error);
+  });
+});
+
+describe('algo-blake2s-test', () => {
+  test.each(BLAKE2S_VECTORS)(
+    'test%s',
+    (name, message, cfg, expected) => {
+      expect(C.BLAKE2s(message, cfg).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdate', () => {
+    const hasher = new C.algo.BLAKE2s({ key: range(32) });
+    const message = range(255);
+    hasher.update(hex(message.toString().slice(0, 128)));
+    hasher.update(hex(message.toString().slice(128, 130)));
+    hasher.update(hex(message.toString().slice(130)));
+
+    expect(hasher.finalize().toString()).toBe(BLAKE2S_VECTORS[3][3]);
+  });
+
+  test('testBlockBoundary', () => {
+    const hasher = new C.algo.BLAKE2s();
+    hasher.update(range(64));
+
+    expect(hasher.finalize().toString()).toBe('56f34e8b96557e90c1f24b52d0c89d51086acf1b00f634cf1dde9233b8eaaa3e');
+  });
+
+  test('testInvalidParameters', () => {
+    const error = 'Invalid BLAKE2s parameters: the output length, key, salt or personalization is out of range';
+    expect(() => C.BLAKE2s('abc', { outputLength: 264 })).toThrow(error);
+    expect(() => C.BLAKE2s('abc', { key: range(33) })).toThrow(error);
+    expect(() => C.BLAKE2s('abc', { personalization: range(9) })).toThrow(error);
+  });
+});
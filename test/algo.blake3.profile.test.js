@@ -0,0 +1,40 @@
+import C from '../src/index';
+
+beforeAll(async () => {
+  await C.SHA256.loadWasm();
+  await C.BLAKE3.loadWasm();
+});
+
+describe('algo-blake3-profile', () => {
+  let singlePartMessage = '';
+  for (let i = 0; i < 500; i++) {
+    singlePartMessage += '12345678901234567890123456789012345678901234567890';
+  }
+  const SHA256_DIGEST = '7d6c60e25c927dacc7ca2e522e7a1cf8ba4813f8822019e24a151aa808994814';
+
+  test('profileSinglePartMessage', () => {
+    const digest = new C.algo.BLAKE3().finalize(singlePartMessage) + '';
+    expect(digest.length).toBe(64);
+    expect(digest).toBe(C.BLAKE3(singlePartMessage).toString());
+  });
+
+  test('profileSinglePartMessageSHA256', () => {
+    expect(new C.algo.SHA256().finalize(singlePartMessage) + '').toBe(SHA256_DIGEST);
+  });
+
+  test('profileMultiPartMessage', () => {
+    let blake3 = new C.algo.BLAKE3();
+    for (let i = 0; i < 500; i++) {
+      blake3.update('12345678901234567890123456789012345678901234567890');
+    }
+    expect(blake3.finalize() + '').toBe(new C.algo.BLAKE3().finalize(singlePartMessage) + '');
+  });
+
+  test('profileMultiPartMessageSHA256', () => {
+    let sha256 = new C.algo.SHA256();
+    for (let i = 0; i < 500; i++) {
+      sha256.update('12345678901234567890123456789012345678901234567890');
+    }
+    expect(sha256.finalize() + '').toBe(SHA256_DIGEST);
+  });
+});
@@ -0,0 +1,60 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// The BLAKE3 test vector input: bytes counting up modulo 251
+const input = (length) => hex(Array.from({ length }, (_, i) => (i % 251).toString(16).padStart(2, '0')).join(''));
+
+const KEY = 'whats the Elephant\'s name, I mea';
+const CONTEXT = 'BLAKE3 2019-12-27 16:29:52 test vectors context';
+
+// Test cases from the BLAKE3 reference test vectors
+const EMPTY_XOF = 'af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262e00f03e7b69af26b7faaf09fcd333050338ddfe085b8cc869ca98b206c08243a26f5487789e8f660afe6c99ef9e0c52b92e7393024a80459cf91f476f9ffdbda7001c22e159b402631f277ca96f2defdf1078282314e763699a31c5363165421cce14d';
+const VECTORS = [
+  ['Empty', () => '', {}, EMPTY_XOF.slice(0, 64)],
+  ['Abc', () => 'abc', {}, '6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85'],
+  ['OneByte', () => input(1), {}, '2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213'],
+  ['OneChunk', () => input(1024), {}, '42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7'],
+  ['ChunkTree', () => input(31 * 1024 + 7), {}, 'c40b37349bf136062d9b600390005e60f06d397359930c0c32e44bf819a74bbc'],
+  ['ExtendedOutput', () => '', { outputLength: 131 * 8 }, EMPTY_XOF],
+  ['KeyedHash', () => input(2049), { key: KEY }, '0771d6c2c219b1e81257b177f042d9c64453db9089c856f2635500f8bf487583'],
+  ['DeriveKey', () => input(2049), { context: CONTEXT }, '2ea477c5515cc3dd606512ee72bb3e0e758cfae7232826f35fb98ca1bcbdf273']
+];
+
+beforeAll(async () => {
+  await C.BLAKE3.loadWasm();
+});
+
+describe('algo-blake3-test', () => {
+  test.each(VECTORS)(
+    'test%s',
+    (name, getMessage, cfg, expected) => {
+      expect(C.BLAKE3(getMessage(), cfg).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdate', () => {
+    const message = input(31 * 1024 + 7).toString();
+    [1, 63, 64, 65, 1023, 1024, 1025, 4096].forEach((chunk) => {
+      const hasher = new C.algo.BLAKE3();
+      for (let i = 0; i < message.length; i += 2 * chunk) {
+        hasher.update(hex(message.slice(i, i + 2 * chunk)));
+      }
+
+      expect(hasher.finalize().toString()).toBe(VECTORS[4][3]);
+    });
+  });
+
+  test('testSqueeze', () => {
+    const hasher = new C.algo.BLAKE3();
+    const output = [1, 63, 64, 3].map((nBytes) => hasher.squeeze(nBytes).toString()).join('');
+
+    expect(output).toBe(EMPTY_XOF);
+    expect(() => hasher.update('more')).toThrow('BLAKE3 cannot take more input once output has been squeezed');
+  });
+
+  test('testInvalidParameters', () => {
+    expect(() => C.BLAKE3('abc', { key: KEY.slice(0, 31) })).toThrow('BLAKE3 key must be 32 bytes');
+    expect(() => C.BLAKE3('abc', { key: KEY, context: CONTEXT })).toThrow('BLAKE3 takes either a key or a context, not both');
+  });
+});
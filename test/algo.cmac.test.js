@@ -0,0 +1,51 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+const MESSAGE = '6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710';
+const KEY_128 = '2b7e151628aed2a6abf7158809cf4f3c';
+
+// Test cases from RFC 4493, section 4, and NIST SP 800-38B, appendix D
+const VECTORS = [
+  ['Aes128Empty', KEY_128, '', 'bb1d6929e95937287fa37d129b756746'],
+  ['Aes128OneBlock', KEY_128, MESSAGE.slice(0, 32), '070a16b46b4d4144f79bdd9dd04a287c'],
+  ['Aes128PartialBlock', KEY_128, MESSAGE.slice(0, 80), 'dfa66747de9ae63030ca32611497c827'],
+  ['Aes128FourBlocks', KEY_128, MESSAGE, '51f0bebf7e3b9d92fc49741779363cfe'],
+  ['Aes192Empty', '8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b', '', 'd17ddf46adaacde531cac483de7a9367'],
+  ['Aes192FourBlocks', '8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b', MESSAGE, 'a1d5df0eed790f794d77589659f39a11'],
+  ['Aes256Empty', '603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4', '', '028962f61b7bf89efc6b551f4667d983'],
+  ['Aes256FourBlocks', '603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4', MESSAGE, 'e1992190549f6ed5696a2c056c315410']
+];
+
+beforeAll(async () => {
+  await C.algo.CMAC.loadWasm();
+});
+
+describe('algo-cmac-test', () => {
+  test.each(VECTORS)(
+    'test%s',
+    (name, key, message, expected) => {
+      expect(new C.algo.CMAC(hex(key)).finalize(hex(message)).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdate', () => {
+    const cmac = new C.algo.CMAC(hex(KEY_128));
+    cmac.update(hex(MESSAGE.slice(0, 10)));
+    cmac.update(hex(MESSAGE.slice(10, 64)));
+    cmac.update(hex(MESSAGE.slice(64)));
+
+    expect(cmac.finalize().toString()).toBe(VECTORS[3][3]);
+  });
+
+  test('testReusable', () => {
+    const cmac = new C.algo.CMAC(hex(KEY_128));
+
+    expect(cmac.finalize(hex(MESSAGE.slice(0, 80))).toString()).toBe(VECTORS[2][3]);
+    expect(cmac.finalize(hex(MESSAGE)).toString()).toBe(VECTORS[3][3]);
+  });
+
+  test('testInvalidKey', () => {
+    expect(() => new C.algo.CMAC(hex('0001020304050607'))).toThrow('AES-CMAC requires a 128, 192 or 256 bit key');
+  });
+});
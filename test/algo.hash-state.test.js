@@ -0,0 +1,92 @@
+import C from '../src/index';
+
+const FIRST_PART = 'The quick brown fox jumps over the lazy dog. '.repeat(5);
+const SECOND_PART = 'Pack my box with five dozen liquor jugs. '.repeat(4);
+
+// Hashers resumed from a context exported after FIRST_PART must match the one-shot hash
+const HASHERS = [
+  ['MD5', {}],
+  ['SHA1', {}],
+  ['SHA224', {}],
+  ['SHA256', {}],
+  ['SHA384', {}],
+  ['SHA512', {}],
+  ['SHA3', {}],
+  ['SHA3', { outputLength: 256 }],
+  ['SHA3_256', {}],
+  ['Keccak256', {}],
+  ['RIPEMD160', {}],
+  ['BLAKE2b', { outputLength: 256, key: 'secret', salt: '0123456789abcdef' }],
+  ['BLAKE2s', { personalization: 'personal' }],
+  ['BLAKE3', {}],
+  ['SM3', {}],
+  ['Whirlpool', {}],
+  ['Streebog256', {}],
+  ['Streebog512', {}]
+];
+
+beforeAll(async () => {
+  await C.MD5.loadWasm();
+  await C.SHA1.loadWasm();
+  await C.SHA256.loadWasm();
+  await C.SHA512.loadWasm();
+  await C.SHA3.loadWasm();
+  await C.RIPEMD160.loadWasm();
+  await C.BLAKE2b.loadWasm();
+  await C.BLAKE2s.loadWasm();
+  await C.BLAKE3.loadWasm();
+  await C.SM3.loadWasm();
+  await C.Whirlpool.loadWasm();
+  await C.Streebog256.loadWasm();
+});
+
+describe('algo-hash-state-test', () => {
+  test.each(HASHERS)('test%sResume %j', (name, cfg) => {
+    const expected = new C.algo[name](cfg).finalize(FIRST_PART + SECOND_PART).toString();
+    const context = new C.algo[name](cfg).update(FIRST_PART).exportState().toString(C.enc.Base64);
+
+    const resumed = new C.algo[name](cfg).importState(C.enc.Base64.parse(context));
+    expect(resumed.finalize(SECOND_PART).toString()).toBe(expected);
+  });
+
+  test('testResumeAfterReset', () => {
+    const context = new C.algo.SHA256().update(FIRST_PART).exportState();
+    const hasher = new C.algo.SHA256().update('discarded');
+
+    expect(hasher.importState(context).finalize(SECOND_PART).toString()).toBe(C.SHA256(FIRST_PART + SECOND_PART).toString());
+  });
+
+  test('testOtherAlgorithm', () => {
+    const context = new C.algo.SHA256().update(FIRST_PART).exportState();
+
+    expect(() => new C.algo.SHA224().importState(context)).toThrow('The context is malformed or was not exported by this algorithm');
+  });
+
+  test('testMalformedContext', () => {
+    const context = new C.algo.SHA256().update(FIRST_PART).exportState();
+    context.words[0] ^= 1;
+
+    expect(() => new C.algo.SHA256().importState(context)).toThrow('The context is malformed or was not exported by this algorithm');
+    expect(() => new C.algo.SHA256().importState(C.enc.Hex.parse('0102'))).toThrow('The context is malformed or was not exported by this algorithm');
+  });
+
+  test('testSha3OutputLengthMismatch', () => {
+    const context = new C.algo.SHA3({ outputLength: 256 }).update(FIRST_PART).exportState();
+
+    expect(() => new C.algo.SHA3({ outputLength: 512 }).importState(context)).toThrow('The context is malformed or was not exported by this algorithm');
+    expect(() => new C.algo.SHA3().importState(context)).toThrow('The context is malformed or was not exported by this algorithm');
+  });
+
+  test('testBlake2ConfigurationMismatch', () => {
+    const context = new C.algo.BLAKE2b({ outputLength: 256, key: 'secret' }).update(FIRST_PART).exportState();
+
+    expect(() => new C.algo.BLAKE2b({ key: 'secret' }).importState(context)).toThrow('The context is malformed or was not exported by this algorithm');
+    expect(() => new C.algo.BLAKE2b({ outputLength: 256 }).importState(context)).toThrow('The context is malformed or was not exported by this algorithm');
+    expect(() => new C.algo.BLAKE2b({ outputLength: 256, key: 'secret', salt: 'salt' }).importState(context))
+      .toThrow('The context is malformed or was not exported by this algorithm');
+
+    const blake2s = new C.algo.BLAKE2s({ personalization: 'personal' }).update(FIRST_PART).exportState();
+    expect(() => new C.algo.BLAKE2s({ personalization: 'personaL' }).importState(blake2s))
+      .toThrow('The context is malformed or was not exported by this algorithm');
+  });
+});
@@ -0,0 +1,93 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+const range = (start, end) => hex(Array.from({ length: end - start }, (_, i) => (start + i).toString(16).padStart(2, '0')).join(''));
+
+// RFC 5869 appendix A test cases 1 to 3, HKDF-SHA256: IKM, salt, info, L, PRK, OKM
+const RFC_5869_VECTORS = [
+  [
+    '1', hex('0b'.repeat(22)), range(0x00, 0x0d), range(0xf0, 0xfa), 42,
+    '077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5',
+    '3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865'
+  ],
+  [
+    '2', range(0x00, 0x50), range(0x60, 0xb0), range(0xb0, 0x100), 82,
+    '06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244',
+    'b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87'
+  ],
+  [
+    '3', hex('0b'.repeat(22)), new C.lib.WordArray(), new C.lib.WordArray(), 42,
+    '19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04',
+    '8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8'
+  ]
+];
+
+// SHA256 without the native HKDF, so that compute falls back to extract and expand over HMAC
+class FallbackSHA256Algo extends C.algo.SHA256 {}
+FallbackSHA256Algo.prototype._hkdfBytes = undefined;
+
+beforeAll(async () => {
+  await C.SHA256.loadWasm();
+});
+
+describe('algo-hkdf-test', () => {
+  test.each(RFC_5869_VECTORS)(
+    'testRfc5869Case%sNative',
+    (name, ikm, salt, info, length, prk, okm) => {
+      expect(C.HKDF(ikm, salt, info, { length }).toString()).toBe(okm);
+    }
+  );
+
+  test.each(RFC_5869_VECTORS)(
+    'testRfc5869Case%sFallback',
+    (name, ikm, salt, info, length, prk, okm) => {
+      expect(C.HKDF(ikm, salt, info, { length, hasher: FallbackSHA256Algo }).toString()).toBe(okm);
+    }
+  );
+
+  test.each(RFC_5869_VECTORS)(
+    'testRfc5869Case%sSteps',
+    (name, ikm, salt, info, length, prk, okm) => {
+      const kdf = new C.algo.HKDF({ length });
+      expect(kdf.extract(ikm, salt).toString()).toBe(prk);
+      expect(kdf.expand(hex(prk), info).toString()).toBe(okm);
+    }
+  );
+
+  test('testLengthInBytes', () => {
+    const [, ikm, salt, info, , , okm] = RFC_5869_VECTORS[0];
+    const key = C.HKDF(ikm, salt, info, { length: 42 });
+
+    expect(key.sigBytes).toBe(42);
+    expect(key.words.length).toBe(11);
+    expect(new C.algo.HKDF({ length: 42, hasher: FallbackSHA256Algo }).compute(ikm, salt, info).words).toEqual(key.words);
+    // length takes the place of keySize
+    expect(C.HKDF(ikm, salt, info, { keySize: 4, length: 42 }).toString()).toBe(okm);
+  });
+
+  test('testKeySize', () => {
+    const [, ikm, salt, info, , , okm] = RFC_5869_VECTORS[0];
+
+    expect(C.HKDF(ikm, salt, info, { keySize: 8 }).toString()).toBe(okm.slice(0, 64));
+    expect(C.HKDF(ikm, salt, info, { keySize: 8, hasher: FallbackSHA256Algo }).toString()).toBe(okm.slice(0, 64));
+    expect(C.HKDF(ikm, salt, info).toString()).toBe(okm.slice(0, 64));
+  });
+
+  test('testTooLong', () => {
+    expect(() => C.HKDF('key', 'salt', 'info', { length: 255 * 32 + 1 })).toThrow('HKDF cannot derive more than 255 digests of key');
+    expect(() => C.HKDF('key', 'salt', 'info', { length: 255 * 32 + 1, hasher: FallbackSHA256Algo }))
+      .toThrow('HKDF cannot derive more than 255 digests of key');
+    expect(C.HKDF('key', 'salt', 'info', { length: 255 * 32 }).sigBytes).toBe(255 * 32);
+  });
+
+  test('testInputIntegrity', () => {
+    const key = new C.lib.WordArray([0x12345678]);
+    const salt = new C.lib.WordArray([0x12345678]);
+    const expectedKey = key.toString();
+    const expectedSalt = salt.toString();
+
+    C.HKDF(key, salt);
+    expect(key.toString()).toBe(expectedKey);
+    expect(salt.toString()).toBe(expectedSalt);
+  });
+});
@@ -0,0 +1,52 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// Test cases from RFC 3394, section 4, and RFC 5649, section 6
+const VECTORS = [
+  ['Kek128Key128', '000102030405060708090a0b0c0d0e0f', '00112233445566778899aabbccddeeff', '1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5', false],
+  ['Kek256Key256', '000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f', '00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f', '28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21', false],
+  ['PaddingKey20', '5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8', 'c37b7e6492584340bed12207808941155068f738', '138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a', true],
+  ['PaddingKey7', '5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8', '466f7250617369', 'afbeb0f07dfbf5419200f2ccb50bb24f', true]
+];
+
+beforeAll(async () => {
+  await C.AES.loadWasm();
+});
+
+describe('algo-keywrap-test', () => {
+  test.each(VECTORS)(
+    'testWrap%s',
+    (name, kek, key, expected, padding) => {
+      expect(C.algo.AES.wrapKey(hex(kek), hex(key), { padding }).toString()).toBe(expected);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testUnwrap%s',
+    (name, kek, key, wrapped, padding) => {
+      expect(C.algo.AES.unwrapKey(hex(kek), hex(wrapped), { padding }).toString()).toBe(key);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testIntegrityCheck%s',
+    (name, kek, key, wrapped, padding) => {
+      const tampered = hex(`${wrapped.slice(0, -2)}${wrapped.slice(-2) === '00' ? '01' : '00'}`);
+      expect(() => C.algo.AES.unwrapKey(hex(kek), tampered, { padding }))
+        .toThrow('AES Key Unwrap integrity check failed');
+      expect(() => C.algo.AES.unwrapKey(hex(kek), hex(wrapped), { padding: !padding }))
+        .toThrow('AES Key Unwrap integrity check failed');
+    }
+  );
+
+  test('testInvalidParameters', () => {
+    const kek = hex(VECTORS[0][1]);
+    expect(() => C.algo.AES.wrapKey(hex('0001020304050607'), hex(VECTORS[0][2])))
+      .toThrow('AES Key Wrap requires a 128, 192 or 256 bit key-encryption key');
+    expect(() => C.algo.AES.wrapKey(kek, hex('0011223344556677')))
+      .toThrow('AES Key Wrap requires a key of at least 128 bits and a multiple of 64 bits');
+    expect(() => C.algo.AES.wrapKey(kek, hex(''), { padding: true }))
+      .toThrow('AES Key Wrap with Padding requires a non-empty key');
+  });
+});
@@ -1,7 +1,22 @@
 import C from '../src/index';
 
+// RFC 6070 test vectors for PBKDF2-HMAC-SHA1: password, salt, iterations, key length in bytes, derived key.
+// Vector 4 runs 16777216 iterations, too slow for a unit test.
+const RFC_6070_VECTORS = [
+  ['1', 'password', 'salt', 1, 20, '0c60c80f961f0e71f3a9b524af6012062fe037a6'],
+  ['2', 'password', 'salt', 2, 20, 'ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957'],
+  ['3', 'password', 'salt', 4096, 20, '4b007901b765489abead49d926f721d065a429c1'],
+  ['5', 'passwordPASSWORDpassword', 'saltSALTsaltSALTsaltSALTsaltSALTsalt', 4096, 25, '3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038'],
+  ['6', 'pass\0word', 'sa\0lt', 4096, 16, '56fa6aa75548099dcc37d7f03425e0c3']
+];
+
+// SHA1 without the native iteration loop, so that PBKDF2 falls back to HMAC in JS
+class FallbackSHA1Algo extends C.algo.SHA1 {}
+FallbackSHA1Algo.prototype._pbkdf2Bytes = undefined;
+
 beforeAll(async () => {
   await C.SHA256.loadWasm();
+  await C.SHA1.loadWasm();
 });
 
 describe('algo-pbkdf2-test', () => {
@@ -101,6 +116,20 @@ describe('algo-pbkdf2-test', () => {
     }).toString()).toBe('44b0781253db3141ac4174af29325818584698d507a79f9879033dec308a2b77');
   });
 
+  test.each(RFC_6070_VECTORS)(
+    'testRfc6070Vector%sNative',
+    (name, password, salt, iterations, dkLen, expected) => {
+      expect(C.PBKDF2(password, salt, { keySize: dkLen / 4, iterations, hasher: C.algo.SHA1 }).toString()).toBe(expected);
+    }
+  );
+
+  test.each(RFC_6070_VECTORS)(
+    'testRfc6070Vector%sFallback',
+    (name, password, salt, iterations, dkLen, expected) => {
+      expect(C.PBKDF2(password, salt, { keySize: dkLen / 4, iterations, hasher: FallbackSHA1Algo }).toString()).toBe(expected);
+    }
+  );
+
   test('testInputIntegrity', () => {
     let password = new C.lib.WordArray([0x12345678]);
     let salt = new C.lib.WordArray([0x12345678]);
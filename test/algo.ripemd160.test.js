@@ -17,4 +17,34 @@ describe('algo-ripemd160-test', () => {
       expect(C.RIPEMD160(b).toString()).toBe(expected);
     }
   );
-});
\ No newline at end of file
+
+  test('testNativeMatchesStreaming', () => {
+    const message = '12345678901234567890123456789012345678901234567890';
+    const streamed = new C.algo.RIPEMD160().update(message.slice(0, 10)).finalize(message.slice(10));
+    expect(C.RIPEMD160(message)).toEqual(streamed);
+  });
+
+  test('testNativeHmacMatchesStreaming', () => {
+    const key = C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b');
+    const streamed = new C.algo.HMAC(C.algo.RIPEMD160, key).update('Hi ').finalize('There');
+    expect(C.HmacRIPEMD160('Hi There', key)).toEqual(streamed);
+  });
+
+  test('testHmacVector', () => {
+    // RFC 2286 test case 2
+    expect(C.HmacRIPEMD160('what do ya want for nothing?', 'Jefe').toString()).toBe('dda6c0213a485a9e24f4742064a7f033b43c4069');
+  });
+
+  test('testPbkdf2', () => {
+    expect(C.PBKDF2('password', 'salt', { keySize: 8, iterations: 2, hasher: C.algo.RIPEMD160 }).toString())
+      .toBe('768dcc27b7bfdef794a1ff9d935090fcf598555e66913180b9ce363c615e9ed9');
+  });
+
+  test('testHkdf', () => {
+    const ikm = C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b');
+    const salt = C.enc.Hex.parse('000102030405060708090a0b0c');
+    const info = C.enc.Hex.parse('f0f1f2f3f4f5f6f7f8f9');
+    expect(C.HKDF(ikm, salt, info, { keySize: 8, hasher: C.algo.RIPEMD160 }).toString())
+      .toBe('8e2a6e5c36796c02636a4246873f35edf59684f394da0ec847b3643aa1f0059c');
+  });
+});
@@ -0,0 +1,71 @@
+import C from '../src/index';
+
+// RFC 7914 section 12 test vectors: password, salt, N, r, p, derived key of 64 bytes.
+// The fourth vector needs 1 GiB and is left out.
+const RFC_7914_VECTORS = [
+  ['1', '', '', 16, 1, 1, '77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906'],
+  ['2', 'password', 'NaCl', 1024, 8, 16, 'fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640'],
+  ['3', 'pleaseletmein', 'SodiumChloride', 16384, 8, 1, '7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887']
+];
+
+const INVALID_PARAMETERS = 'Invalid scrypt parameters';
+
+beforeAll(async () => {
+  await C.Scrypt.loadWasm();
+});
+
+describe('algo-scrypt-test', () => {
+  test.each(RFC_7914_VECTORS)(
+    'testVector%s',
+    (name, password, salt, N, r, p, expected) => {
+      expect(C.Scrypt(password, salt, { keySize: 64 / 4, N, r, p }).toString()).toBe(expected);
+    }
+  );
+
+  test('testDefaults', () => {
+    // N = 16384, r = 8, p = 1 and a 256-bit key, checked against Python's hashlib.scrypt
+    expect(C.Scrypt('password', 'salt').toString()).toBe('745731af4484f323968969eda289aeee005b5903ac561e64a5aca121797bf773');
+  });
+
+  test('testWordArrayInput', () => {
+    expect(C.Scrypt(C.enc.Utf8.parse('password'), C.enc.Utf8.parse('NaCl'), { keySize: 64 / 4, N: 1024, r: 8, p: 16 }).toString())
+      .toBe(RFC_7914_VECTORS[1][6]);
+  });
+
+  test('testKeySize', () => {
+    const key = C.Scrypt('password', 'NaCl', { keySize: 4, N: 1024, r: 8, p: 16 });
+    expect(key.sigBytes).toBe(16);
+    expect(key.toString()).toBe(RFC_7914_VECTORS[1][6].slice(0, 32));
+  });
+
+  test('testMaxMemory', () => {
+    // 128 * r * (N + p) = 128 * 8 * (16384 + 1) bytes, just over 16 MiB
+    const cfg = { N: 16384, r: 8, p: 1 };
+    expect(() => C.Scrypt('password', 'salt', { ...cfg, maxMemory: 16 * 1024 * 1024 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Scrypt('password', 'salt', { ...cfg, maxMemory: 128 * 8 * (16384 + 1) })).not.toThrow();
+  });
+
+  test('testInvalidParameters', () => {
+    expect(() => C.Scrypt('password', 'salt', { N: 1000 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Scrypt('password', 'salt', { N: 1 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Scrypt('password', 'salt', { r: 0 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Scrypt('password', 'salt', { p: 0 })).toThrow(INVALID_PARAMETERS);
+    expect(() => C.Scrypt('password', 'salt', { keySize: 0 })).toThrow(INVALID_PARAMETERS);
+  });
+
+  test('testHelper', () => {
+    const cfg = { keySize: 64 / 4, N: 16, r: 1, p: 1 };
+    expect(C.Scrypt('', '', cfg).toString()).toBe(new C.algo.Scrypt(cfg).compute('', '').toString());
+  });
+
+  test('testInputIntegrity', () => {
+    const password = new C.lib.WordArray([0x12345678]);
+    const salt = new C.lib.WordArray([0x12345678]);
+    const expectedPassword = password.toString();
+    const expectedSalt = salt.toString();
+
+    C.Scrypt(password, salt, { N: 16, r: 1, p: 1 });
+    expect(password.toString()).toBe(expectedPassword);
+    expect(salt.toString()).toBe(expectedSalt);
+  });
+});
@@ -57,4 +57,29 @@ describe('algo-sha1-test', () => {
     expect(C.HmacSHA1('Hi There', C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b')).toString())
       .toBe(new C.algo.HMAC(C.algo.SHA1, C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b')).finalize('Hi There').toString());
   });
+
+  test('testNativeMatchesStreaming', () => {
+    const message = '12345678901234567890123456789012345678901234567890';
+    const streamed = new C.algo.SHA1().update(message.slice(0, 10)).finalize(message.slice(10));
+    expect(C.SHA1(message)).toEqual(streamed);
+  });
+
+  test('testNativeHmacMatchesStreaming', () => {
+    const key = C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b');
+    const streamed = new C.algo.HMAC(C.algo.SHA1, key).update('Hi ').finalize('There');
+    expect(C.HmacSHA1('Hi There', key)).toEqual(streamed);
+  });
+
+  test('testPbkdf2', () => {
+    expect(C.PBKDF2('password', 'salt', { keySize: 8, iterations: 2, hasher: C.algo.SHA1 }).toString())
+      .toBe('ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957cae93136266537a8d7bf4b76');
+  });
+
+  test('testHkdf', () => {
+    const ikm = C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b');
+    const salt = C.enc.Hex.parse('000102030405060708090a0b0c');
+    const info = C.enc.Hex.parse('f0f1f2f3f4f5f6f7f8f9');
+    expect(C.HKDF(ikm, salt, info, { keySize: 8, hasher: C.algo.SHA1 }).toString())
+      .toBe('d6000ffb5b50bd3970b260017798fb9c8df9ce2e2c16b6cd709cca07dc3cf9cf');
+  });
 });
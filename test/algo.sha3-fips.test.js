@@ -0,0 +1,98 @@
+import C from '../src/index';
+
+const QUICK_FOX = 'The quick brown fox jumps over the lazy dog';
+const LONG_MESSAGE = 'a'.repeat(1000);
+
+// FIPS 202 digests of '', 'abc' and 1000 times 'a', and HMAC with the key 'key' over QUICK_FOX
+const VECTORS = [
+  ['Sha3_224', () => C.SHA3_224, () => C.HmacSHA3_224, [
+    '6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7',
+    'e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf',
+    '2461344b84416db8fe01c2a4966fea019590c231dd5724c1bfc26745',
+    'ff6fa8447ce10fb1efdccfe62caf8b640fe46c4fb1007912bf85100f'
+  ]],
+  ['Sha3_256', () => C.SHA3_256, () => C.HmacSHA3_256, [
+    'a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a',
+    '3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532',
+    '8f3934e6f7a15698fe0f396b95d8c4440929a8fa6eae140171c068b4549fbf81',
+    '8c6e0683409427f8931711b10ca92a506eb1fafa48fadd66d76126f47ac2c333'
+  ]],
+  ['Sha3_384', () => C.SHA3_384, () => C.HmacSHA3_384, [
+    '0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004',
+    'ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25',
+    'ccf4495ff20b4b33a1cc1917f9f0fe0fcb5e3d08e542cf4d4a90dd950b748e7e1cc07d2f3b36d62dd240724417cdd81b',
+    'aa739ad9fcdf9be4a04f06680ade7a1bd1e01a0af64accb04366234cf9f6934a0f8589772f857681fcde8acc256091a2'
+  ]],
+  ['Sha3_512', () => C.SHA3_512, () => C.HmacSHA3_512, [
+    'a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26',
+    'b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0',
+    'ac7e95cc95aa7f24aaa95e040ca0c79b39cd9cc84a10abb84ddd8dd5e4b45cf96543aaa70d0ef99fbf8d2769639981ee1fd0b0276f4756b9d504d0b7de19b700',
+    '237a35049c40b3ef5ddd960b3dc893d8284953b9a4756611b1b61bffcf53edd979f93547db714b06ef0a692062c609b70208ab8d4a280ceee40ed8100f293063'
+  ]]
+];
+
+beforeAll(async () => {
+  await C.SHA3_256.loadWasm();
+});
+
+describe('algo-sha3-fips-test', () => {
+  test.each(VECTORS)(
+    'testDigest%s',
+    (name, getHash, getHmac, [empty, abc, long]) => {
+      const hash = getHash();
+      expect(hash('').toString()).toBe(empty);
+      expect(hash('abc').toString()).toBe(abc);
+      expect(hash(LONG_MESSAGE).toString()).toBe(long);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testUpdate%s',
+    (name, getHash, getHmac, [, , long]) => {
+      const hasher = new C.algo[name.toUpperCase()]();
+      for (let i = 0; i < 1000; i += 7) {
+        hasher.update(LONG_MESSAGE.slice(i, i + 7));
+      }
+
+      expect(hasher.finalize().toString()).toBe(long);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testHmac%s',
+    (name, getHash, getHmac, [, , , mac]) => {
+      expect(getHmac()(QUICK_FOX, 'key').toString()).toBe(mac);
+      // Updating first skips the native one-shot path
+      expect(C.algo.HMAC.create(C.algo[name.toUpperCase()], 'key').update(QUICK_FOX).finalize().toString()).toBe(mac);
+    }
+  );
+
+  test('testOutputLengthIsFixed', () => {
+    expect(C.SHA3_256('abc', { outputLength: 512 }).toString()).toBe(VECTORS[1][3][1]);
+  });
+});
+
+describe('algo-keccak256-test', () => {
+  test('testVectors', () => {
+    expect(C.Keccak256('').toString()).toBe('c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470');
+    expect(C.Keccak256('abc').toString()).toBe('4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45');
+  });
+
+  test('testMatchesLegacySha3', () => {
+    // The legacy SHA3 hasher keeps the Keccak padding, so at 256 bits it is Keccak-256
+    expect(C.Keccak256(LONG_MESSAGE).toString()).toBe(C.SHA3(LONG_MESSAGE, { outputLength: 256 }).toString());
+  });
+
+  test('testHmacMatchesStreaming', () => {
+    // The one-shot helper runs HMAC natively, updating first goes through the generic HMAC
+    const hmac = C.algo.HMAC.create(C.algo.Keccak256, 'key');
+    hmac.update(QUICK_FOX.slice(0, 10));
+    hmac.update(QUICK_FOX.slice(10));
+
+    expect(C.HmacKeccak256(QUICK_FOX, 'key').toString()).toBe(hmac.finalize().toString());
+  });
+
+  test('testDiffersFromSha3_256', () => {
+    expect(C.Keccak256('abc').toString()).not.toBe(C.SHA3_256('abc').toString());
+  });
+});
@@ -97,4 +97,30 @@ describe('algo-sha3-test', () => {
     expect(C.HmacSHA3('Hi There', C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b')).toString())
       .toBe(new C.algo.HMAC(C.algo.SHA3, C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b')).finalize('Hi There').toString());
   });
+
+  test.each([224, 256, 384, 512])('testNativeMatchesStreaming%i', (outputLength) => {
+    const message = '12345678901234567890123456789012345678901234567890'.repeat(4);
+    const streamed = new C.algo.SHA3({ outputLength }).update(message.slice(0, 10)).finalize(message.slice(10));
+    expect(C.SHA3(message, { outputLength }).toString()).toBe(streamed.toString());
+  });
+
+  test('testNativeHmacMatchesStreaming', () => {
+    const key = C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b');
+    const streamed = new C.algo.HMAC(C.algo.SHA3, key).update('Hi ').finalize('There');
+    expect(C.HmacSHA3('Hi There', key).toString()).toBe(streamed.toString());
+  });
+
+  test('testPbkdf2MatchesStreamingHmac', () => {
+    // A single iteration is the HMAC of the salt and the block index
+    const streamed = new C.algo.HMAC(C.algo.SHA3, 'password').update('salt').finalize(C.enc.Hex.parse('00000001'));
+    expect(C.PBKDF2('password', 'salt', { keySize: 16, iterations: 1, hasher: C.algo.SHA3 }).toString())
+      .toBe(streamed.toString());
+  });
+
+  test('testHkdfMatchesStreamingHmac', () => {
+    const prk = new C.algo.HMAC(C.algo.SHA3, 'salt').update('input ').finalize('key material');
+    const okm = new C.algo.HMAC(C.algo.SHA3, prk).update('info').finalize(C.enc.Hex.parse('01'));
+    expect(C.HKDF('input key material', 'salt', 'info', { keySize: 16, hasher: C.algo.SHA3 }).toString())
+      .toBe(okm.toString());
+  });
 });
@@ -0,0 +1,60 @@
+import C from '../src/index';
+
+beforeAll(async () => {
+  await C.SHA512_224.loadWasm();
+});
+
+// Test cases from the NIST SHA-2 examples
+const VECTOR_CONFIG_TEST = [
+  [1, '', '6ed0dd02806fa89e25de060c19d3ac86cabb87d6a0ddd05c333b84f4'],
+  [2, 'abc', '4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa'],
+  [3, 'abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq', 'e5302d6d54bb242275d1e7622d68df6eb02dedd13f564c13dbda2174'],
+  [4, 'abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu', '23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9'],
+  [5, 'The quick brown fox jumps over the lazy dog', '944cd2847fb54558d4775db0485a50003111c8e5daa63fe722c6aa37']
+];
+
+describe('algo-sha512-224-test', () => {
+  test.each(VECTOR_CONFIG_TEST)(
+    'testVector%i',
+    (a, b, expected) => {
+      expect(C.SHA512_224(b).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdateAndLongMessage', () => {
+    const sha512224 = new C.algo.SHA512_224();
+    for (let i = 0; i < 100; i++) {
+      sha512224.update('12345678901234567890123456789012345678901234567890');
+    }
+
+    expect(sha512224.finalize().toString())
+      .toBe('1615d441821a7aaea5c8610944ba9ba13a54a60c7dc3889999f51ec6');
+  });
+
+  test('testClone', () => {
+    const sha512224 = new C.algo.SHA512_224();
+
+    expect(sha512224.update('a').clone().finalize().toString()).toBe(C.SHA512_224('a').toString());
+    expect(sha512224.update('b').clone().finalize().toString()).toBe(C.SHA512_224('ab').toString());
+    expect(sha512224.update('c').clone().finalize().toString()).toBe(C.SHA512_224('abc').toString());
+  });
+
+  test('testInputIntegrity', () => {
+    const message = new C.lib.WordArray([0x12345678]);
+
+    const expected = message.toString();
+
+    C.SHA512_224(message);
+
+    expect(message.toString()).toBe(expected);
+  });
+
+  test('testHelper', () => {
+    expect(C.SHA512_224('').toString()).toBe(new C.algo.SHA512_224().finalize('').toString());
+  });
+
+  test('testHmac', () => {
+    expect(C.HmacSHA512_224('The quick brown fox jumps over the lazy dog', 'key').toString())
+      .toBe('a1afb4f708cb63570639195121785ada3dc615989cc3c73f38e306a3');
+  });
+});
@@ -0,0 +1,60 @@
+import C from '../src/index';
+
+beforeAll(async () => {
+  await C.SHA512_256.loadWasm();
+});
+
+// Test cases from the NIST SHA-2 examples
+const VECTOR_CONFIG_TEST = [
+  [1, '', 'c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a'],
+  [2, 'abc', '53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23'],
+  [3, 'abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq', 'bde8e1f9f19bb9fd3406c90ec6bc47bd36d8ada9f11880dbc8a22a7078b6a461'],
+  [4, 'abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu', '3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a'],
+  [5, 'The quick brown fox jumps over the lazy dog', 'dd9d67b371519c339ed8dbd25af90e976a1eeefd4ad3d889005e532fc5bef04d']
+];
+
+describe('algo-sha512-256-test', () => {
+  test.each(VECTOR_CONFIG_TEST)(
+    'testVector%i',
+    (a, b, expected) => {
+      expect(C.SHA512_256(b).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdateAndLongMessage', () => {
+    const sha512256 = new C.algo.SHA512_256();
+    for (let i = 0; i < 100; i++) {
+      sha512256.update('12345678901234567890123456789012345678901234567890');
+    }
+
+    expect(sha512256.finalize().toString())
+      .toBe('c60995df5cbbc7a42cc0928d699087c9ff20703c07a3c329b97d06bfe1422c41');
+  });
+
+  test('testClone', () => {
+    const sha512256 = new C.algo.SHA512_256();
+
+    expect(sha512256.update('a').clone().finalize().toString()).toBe(C.SHA512_256('a').toString());
+    expect(sha512256.update('b').clone().finalize().toString()).toBe(C.SHA512_256('ab').toString());
+    expect(sha512256.update('c').clone().finalize().toString()).toBe(C.SHA512_256('abc').toString());
+  });
+
+  test('testInputIntegrity', () => {
+    const message = new C.lib.WordArray([0x12345678]);
+
+    const expected = message.toString();
+
+    C.SHA512_256(message);
+
+    expect(message.toString()).toBe(expected);
+  });
+
+  test('testHelper', () => {
+    expect(C.SHA512_256('').toString()).toBe(new C.algo.SHA512_256().finalize('').toString());
+  });
+
+  test('testHmac', () => {
+    expect(C.HmacSHA512_256('The quick brown fox jumps over the lazy dog', 'key').toString())
+      .toBe('7fb65e03577da9151a1016e9c2e514d4d48842857f13927f348588173dca6d89');
+  });
+});
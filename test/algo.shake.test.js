@@ -0,0 +1,59 @@
+import C from '../src/index';
+
+beforeAll(async () => {
+  await C.SHAKE128.loadWasm();
+});
+
+const QUICK_FOX = 'The quick brown fox jumps over the lazy dog';
+
+// Long outputs cross several rate-sized blocks: 168 bytes for SHAKE128, 136 bytes for SHAKE256
+const SHAKE128_ABC_200 = '5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc844c50af32acd3f2cdd066568706f509bc1bdde58295dae3f891a9a0fca5783789a41f8611214ce612394df286a62d1a2252aa94db9c538956c717dc2bed4f232a0294c857c730aa16067ac1062f1201fb0d377cfb9cde4c63599b27f3462bba4a0ed296c801f9ff7f57302bb3076ee145f97a32ae68e76ab66c48d51675bd49acc29082f5647584e6aa01b3f5af057805f973ff8ecb8b226ac32ada6f01c1fcd4818cb006aa5b4cd';
+const SHAKE256_BYTES_300 = '4ee1ca03272b05d3bfb1e1c79a967f823b9fc5e4bb3987b1ba9e9cb5afb07a5ee3a07fbd457a94364964a841e7f466e5a022e21ab7f673c18ba98cdb1d5aecfae62268b068f1e4bf9ee9853bcce08dcd491c629aa218b60d3d453e83a554eb176cfef9729e99ff3a8127c49e3c3cf19ad26018ed796fedce98c5f867ec2bacbdb8012cc52b76e6d24a80fa3692d02a03634b34b2fb336232e4c027dca0cc4bd03a01f1cec8c35ad0e51687fad4e18ebc23a75851d466979d59db7391b61702a7fc85a1162bdbaaeab699499162f551da8b0c839f88ff96b8dd79015606526ab78fd1c101660de85653340f3d1dac2a22bcf1a2bef88d742de9006c2d5b6d8acd586b6bee76f85cccbf94e387c53c23e716c670c4db23c67901358ae64f3f0ccedfa05b29e84e1a11a635bfe7';
+
+// 0x00, 0x01, ..., 0xc7
+const BYTES_200 = C.enc.Hex.parse(Array.from({ length: 200 }, (_, i) => i.toString(16).padStart(2, '0')).join(''));
+
+describe('algo-shake-test', () => {
+  test.each([
+    ['Shake128Empty', () => C.SHAKE128(''), '7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26'],
+    ['Shake256Empty', () => C.SHAKE256(''), '46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be'],
+    ['Shake128QuickFox', () => C.SHAKE128(QUICK_FOX), 'f4202e3c5852f9182a0430fd8144f0a74b95e7417ecae17db0f8cfeed0e3e66e'],
+    ['Shake256QuickFox', () => C.SHAKE256(QUICK_FOX), '2f671343d9b2e1604dc9dcf0753e5fe15c7c64a0d283cbbf722d411a0e36f6ca1d01d1369a23539cd80f7c054b6e5daf9c962cad5b8ed5bd11998b40d5734442'],
+    ['Shake128LongOutput', () => C.SHAKE128('abc', { outputLength: 1600 }), SHAKE128_ABC_200],
+    ['Shake256LongOutput', () => C.SHAKE256(BYTES_200, { outputLength: 2400 }), SHAKE256_BYTES_300]
+  ])(
+    'test%s',
+    (name, hash, expected) => {
+      expect(hash().toString()).toBe(expected);
+    }
+  );
+
+  test('testSqueezeIncrementally', () => {
+    const shake = new C.algo.SHAKE128().update('a').update('bc');
+    const output = [1, 31, 136, 0, 32].map((nBytes) => shake.squeeze(nBytes).toString()).join('');
+
+    expect(output).toBe(SHAKE128_ABC_200);
+  });
+
+  test('testSqueezeAcrossBlocks', () => {
+    const shake = new C.algo.SHAKE256();
+    shake.update(BYTES_200);
+
+    expect(`${shake.squeeze(135)}${shake.squeeze(2)}${shake.squeeze(163)}`).toBe(SHAKE256_BYTES_300);
+  });
+
+  test('testNoAbsorbAfterSqueeze', () => {
+    const shake = new C.algo.SHAKE256().update('abc');
+    shake.squeeze(16);
+
+    expect(() => shake.update('more')).toThrow('SHAKE cannot absorb more data once output has been squeezed');
+  });
+
+  test('testReset', () => {
+    const shake = new C.algo.SHAKE128().update('ignored');
+    shake.squeeze(8);
+    shake.reset();
+
+    expect(shake.finalize('abc').toString()).toBe(SHAKE128_ABC_200.slice(0, 64));
+  });
+});
@@ -0,0 +1,44 @@
+import C from '../src/index';
+
+const QUICK_FOX = 'The quick brown fox jumps over the lazy dog';
+
+// Test cases from GB/T 32905-2016, appendix A, and values checked against OpenSSL
+const VECTORS = [
+  ['Empty', '', '1ab21d8355cfa17f8e61194831e81a8f22bec8c728fefb747ed035eb5082aa2b'],
+  ['Example1', 'abc', '66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0'],
+  ['Example2', 'abcd'.repeat(16), 'debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732']
+];
+
+beforeAll(async () => {
+  await C.SM3.loadWasm();
+});
+
+describe('algo-sm3-test', () => {
+  test.each(VECTORS)(
+    'test%s',
+    (name, message, expected) => {
+      expect(C.SM3(message).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdate', () => {
+    const sm3 = new C.algo.SM3();
+    for (let i = 0; i < 16; i++) {
+      sm3.update('abcd');
+    }
+
+    expect(sm3.finalize().toString()).toBe(VECTORS[2][2]);
+  });
+
+  test('testClone', () => {
+    const sm3 = new C.algo.SM3();
+    sm3.update('a');
+    expect(sm3.clone().finalize().toString()).toBe(C.SM3('a').toString());
+    sm3.update('bc');
+    expect(sm3.finalize().toString()).toBe(VECTORS[1][2]);
+  });
+
+  test('testHmac', () => {
+    expect(C.HmacSM3(QUICK_FOX, 'key').toString()).toBe('bd4a34077888162b210645b8ebf74b9af357303789357a27c7fc457244ebd398');
+  });
+});
@@ -0,0 +1,62 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+const KEY = '0123456789abcdeffedcba9876543210';
+const IV = '000102030405060708090a0b0c0d0e0f';
+const PLAINTEXT = `${KEY}${KEY}`;
+
+// Test cases from GB/T 32907-2016, appendix A, and values checked against OpenSSL
+const VECTORS = [
+  ['Ecb', () => C.mode.ECB, '681edf34d206965e86b3e94f536e4246681edf34d206965e86b3e94f536e4246'],
+  ['Cbc', () => C.mode.CBC, 'a9a268883a336315bac0c9c9ff350ab1b236a4a85616d4aabf0a83555c7d4115'],
+  ['Cfb', () => C.mode.CFB, '07bbd906b40da542d4514d1a97fccb7ab08042271f518c5ff71a31881413a02d'],
+  ['Ofb', () => C.mode.OFB, '07bbd906b40da542d4514d1a97fccb7af2cc072b3e2897929f83560cab77da30'],
+  ['Ctr', () => C.mode.CTR, '07bbd906b40da542d4514d1a97fccb7a6e24482cc90831ee244da97df7549f0a']
+];
+
+beforeAll(async () => {
+  await C.SM4.loadWasm();
+  await C.MD5.loadWasm();
+});
+
+describe('algo-sm4-test', () => {
+  test.each(VECTORS)(
+    'testEncrypt%s',
+    (name, getMode, expected) => {
+      const cfg = { iv: hex(IV), mode: getMode(), padding: C.pad.NoPadding };
+      expect(C.SM4.encrypt(hex(PLAINTEXT), hex(KEY), cfg).ciphertext.toString()).toBe(expected);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testDecrypt%s',
+    (name, getMode, ciphertext) => {
+      const cfg = { iv: hex(IV), mode: getMode(), padding: C.pad.NoPadding };
+      expect(C.SM4.decrypt(new C.lib.CipherParams({ ciphertext: hex(ciphertext) }), hex(KEY), cfg).toString()).toBe(PLAINTEXT);
+    }
+  );
+
+  test('testPkcs7Padding', () => {
+    const ciphertext = 'b6556613480f80c2a4c4beadbdc795cef8e94a010adc3fcb23742afa14d086d4';
+    expect(C.SM4.encrypt('The quick brown fox', hex(KEY), { iv: hex(IV) }).ciphertext.toString()).toBe(ciphertext);
+    expect(C.SM4.decrypt(new C.lib.CipherParams({ ciphertext: hex(ciphertext) }), hex(KEY), { iv: hex(IV) }).toString(C.enc.Utf8))
+      .toBe('The quick brown fox');
+  });
+
+  test('testMultiPart', () => {
+    const sm4 = C.algo.SM4.createEncryptor(hex(KEY), { iv: hex(IV), padding: C.pad.NoPadding });
+    const ciphertext1 = sm4.process(hex(PLAINTEXT.slice(0, 10)));
+    const ciphertext2 = sm4.process(hex(PLAINTEXT.slice(10)));
+    const ciphertext3 = sm4.finalize();
+
+    expect(ciphertext1.concat(ciphertext2).concat(ciphertext3).toString()).toBe(VECTORS[1][2]);
+  });
+
+  test('testPassphrase', () => {
+    // openssl enc -sm4-cbc -md md5 -pass pass:secret -S 0102030405060708
+    const ciphertext = 'U2FsdGVkX18BAgMEBQYHCGaeb5lsAe0SDpojgW2cJ4TQZJ1zC2tJ9bX4otXefVaF';
+    expect(C.SM4.decrypt(ciphertext, 'secret').toString(C.enc.Utf8)).toBe('Hello, SM4 passphrase');
+    expect(C.SM4.decrypt(C.SM4.encrypt('Hello, SM4 passphrase', 'secret'), 'secret').toString(C.enc.Utf8)).toBe('Hello, SM4 passphrase');
+  });
+});
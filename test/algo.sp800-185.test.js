@@ -0,0 +1,112 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// 0x00, 0x01, ..., 0xc7
+const BYTES_200 = Array.from({ length: 200 }, (_, i) => i.toString(16).padStart(2, '0')).join('');
+const KMAC_KEY = '404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f';
+const TAGGED = 'My Tagged Application';
+
+// Test cases from the NIST SP 800-185 samples
+const CSHAKE_VECTORS = [
+  ['Cshake128Sample1', () => C.CSHAKE128, '00010203', 256, 'c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5'],
+  ['Cshake128Sample2', () => C.CSHAKE128, BYTES_200, 256, 'c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b'],
+  ['Cshake256Sample3', () => C.CSHAKE256, '00010203', 512, 'd008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd164020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c']
+];
+
+const KMAC_VECTORS = [
+  ['Kmac128Sample1', () => C.KMAC128, '00010203', '', 256, false, 'e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e'],
+  ['Kmac128Sample2', () => C.KMAC128, '00010203', TAGGED, 256, false, '3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5'],
+  ['Kmac128Sample3', () => C.KMAC128, BYTES_200, TAGGED, 256, false, '1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230'],
+  ['Kmac256Sample4', () => C.KMAC256, '00010203', TAGGED, 512, false, '20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd'],
+  ['KmacXof128Sample1', () => C.KMAC128, '00010203', '', 256, true, 'cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35'],
+  ['KmacXof256Sample5', () => C.KMAC256, '00010203', TAGGED, 512, true, '1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa96faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b']
+];
+
+const TUPLE = ['000102', '101112131415'];
+const TUPLE_HASH_VECTORS = [
+  ['TupleHash128Sample1', () => C.TupleHash128, {}, 'c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1'],
+  ['TupleHash128Sample2', () => C.TupleHash128, { customization: 'My Tuple App' }, '75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb'],
+  ['TupleHash256Sample4', () => C.TupleHash256, {}, 'cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec607311ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194'],
+  ['TupleHashXof128Sample1', () => C.TupleHash128, { xof: true }, '2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488']
+];
+
+const PARALLEL_DATA = '000102030405060710111213141516172021222324252627';
+const PARALLEL_HASH_VECTORS = [
+  ['ParallelHash128Sample1', () => C.ParallelHash128, {}, 'ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5'],
+  ['ParallelHash128Sample2', () => C.ParallelHash128, { customization: 'Parallel Data' }, 'fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206'],
+  ['ParallelHash256Sample4', () => C.ParallelHash256, {}, 'bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c451105531b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429'],
+  ['ParallelHashXof128Sample1', () => C.ParallelHash128, { xof: true }, 'fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3']
+];
+
+beforeAll(async () => {
+  await C.CSHAKE128.loadWasm();
+});
+
+describe('algo-sp800-185-test', () => {
+  test.each(CSHAKE_VECTORS)(
+    'test%s',
+    (name, getHash, message, outputLength, expected) => {
+      expect(getHash()(hex(message), { customization: 'Email Signature', outputLength }).toString()).toBe(expected);
+    }
+  );
+
+  test('testCshakeUpdate', () => {
+    const cshake = new C.algo.CSHAKE128({ customization: 'Email Signature' });
+    cshake.update(hex(BYTES_200.slice(0, 6)));
+    cshake.update(hex(BYTES_200.slice(6, 340)));
+
+    expect(cshake.finalize(hex(BYTES_200.slice(340))).toString()).toBe(CSHAKE_VECTORS[1][4]);
+  });
+
+  test('testCshakeWithoutStringsIsShake', () => {
+    expect(C.CSHAKE256('abc').toString()).toBe(C.SHAKE256('abc').toString());
+  });
+
+  test.each(KMAC_VECTORS)(
+    'test%s',
+    (name, getMac, message, customization, outputLength, xof, expected) => {
+      expect(getMac()(hex(message), hex(KMAC_KEY), { customization, outputLength, xof }).toString()).toBe(expected);
+    }
+  );
+
+  test('testKmacUpdate', () => {
+    const kmac = new C.algo.KMAC128(hex(KMAC_KEY), { customization: TAGGED });
+    kmac.update(hex(BYTES_200.slice(0, 200)));
+    kmac.update(hex(BYTES_200.slice(200)));
+
+    expect(kmac.finalize().toString()).toBe(KMAC_VECTORS[2][6]);
+  });
+
+  test('testKmacOutputLength', () => {
+    // KMAC binds the output length into the MAC, KMACXOF does not
+    const key = hex(KMAC_KEY);
+    expect(C.KMAC128('message', key, { outputLength: 128 }).toString())
+      .not.toBe(C.KMAC128('message', key, { outputLength: 256 }).toString().slice(0, 32));
+    expect(C.KMAC128('message', key, { outputLength: 128, xof: true }).toString())
+      .toBe(C.KMAC128('message', key, { outputLength: 256, xof: true }).toString().slice(0, 32));
+  });
+
+  test.each(TUPLE_HASH_VECTORS)(
+    'test%s',
+    (name, getHash, cfg, expected) => {
+      expect(getHash()(TUPLE.map(hex), cfg).toString()).toBe(expected);
+    }
+  );
+
+  test('testTupleHashSeparatesElements', () => {
+    expect(C.TupleHash128(['ab', 'c']).toString()).not.toBe(C.TupleHash128(['a', 'bc']).toString());
+  });
+
+  test.each(PARALLEL_HASH_VECTORS)(
+    'test%s',
+    (name, getHash, cfg, expected) => {
+      expect(getHash()(hex(PARALLEL_DATA), Object.assign({ blockLength: 8 }, cfg)).toString()).toBe(expected);
+    }
+  );
+
+  test('testParallelHashInvalidBlockLength', () => {
+    expect(() => C.ParallelHash128(hex(PARALLEL_DATA), { blockLength: 0 }))
+      .toThrow('ParallelHash block length must be a positive number of bytes');
+  });
+});
@@ -0,0 +1,57 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// Test cases from RFC 6986, section 10: M1 is 63 bytes, M2 is 72 bytes
+const M1 = '012345678901234567890123456789012345678901234567890123456789012';
+const M2 = 'd1e520e2e5f2f0e82c20d1f2f0e8e1eee6e820e2edf3f6e82c20e2e5fef2fa20f120eceef0ff20f1f2f0e5ebe0ece820ede020f5f0e0e1f0fbff20efebfaeafb20c8e3eef0e5e2fb';
+
+const VECTORS = [
+  ['Streebog512M1', () => C.Streebog512, M1, '1b54d01a4af5b9d5cc3d86d68d285462b19abc2475222f35c085122be4ba1ffa00ad30f8767b3a82384c6574f024c311e2a481332b08ef7f41797891c1646f48'],
+  ['Streebog256M1', () => C.Streebog256, M1, '9d151eefd8590b89daa6ba6cb74af9275dd051026bb149a452fd84e5e57b5500'],
+  ['Streebog512M2', () => C.Streebog512, hex(M2), '1e88e62226bfca6f9994f1f2d51569e0daf8475a3b0fe61a5300eee46d961376035fe83549ada2b8620fcd7c496ce5b33f0cb9dddc2b6460143b03dabac9fb28'],
+  ['Streebog256M2', () => C.Streebog256, hex(M2), '9dd2fe4e90409e5da87f53976d7405b0c0cac628fc669a741d50063c557e8f50']
+];
+
+// Test cases from RFC 7836, section 4.1
+const HMAC_KEY = '000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f';
+const HMAC_DATA = '0126bdb87800af214341456563780100';
+
+beforeAll(async () => {
+  await C.Streebog256.loadWasm();
+});
+
+describe('algo-streebog-test', () => {
+  test.each(VECTORS)(
+    'test%s',
+    (name, getHash, message, expected) => {
+      expect(getHash()(message).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdate', () => {
+    [1, 7, 64, 100].forEach((chunk) => {
+      const streebog = new C.algo.Streebog512();
+      for (let i = 0; i < M2.length; i += 2 * chunk) {
+        streebog.update(hex(M2.slice(i, i + 2 * chunk)));
+      }
+
+      expect(streebog.finalize().toString()).toBe(VECTORS[2][3]);
+    });
+  });
+
+  test('testClone', () => {
+    const streebog = new C.algo.Streebog256();
+    streebog.update(M1.slice(0, 10));
+    expect(streebog.clone().finalize().toString()).toBe(C.Streebog256(M1.slice(0, 10)).toString());
+    streebog.update(M1.slice(10));
+    expect(streebog.finalize().toString()).toBe(VECTORS[1][3]);
+  });
+
+  test('testHmac', () => {
+    expect(C.HmacStreebog256(hex(HMAC_DATA), hex(HMAC_KEY)).toString())
+      .toBe('a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9');
+    expect(C.HmacStreebog512(hex(HMAC_DATA), hex(HMAC_KEY)).toString())
+      .toBe('a59bab22ecae19c65fbde6e5f4e9f5d8549d31f037f9df9b905500e171923a773d5f1530f2ed7e964cb2eedc29e9ad2f3afe93b2814f79f5000ffc0366c251e6');
+  });
+});
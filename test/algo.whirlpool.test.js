@@ -0,0 +1,47 @@
+import C from '../src/index';
+
+const QUICK_FOX = 'The quick brown fox jumps over the lazy dog';
+
+// Test cases from ISO/IEC 10118-3 and values checked against OpenSSL
+const VECTORS = [
+  ['Empty', '', '19fa61d75522a4669b44e39c1d2e1726c530232130d407f89afee0964997f7a73e83be698b288febcf88e3e03c4f0757ea8964e59b63d93708b138cc42a66eb3'],
+  ['Abc', 'abc', '4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5'],
+  ['OneBlock', `${'x'.repeat(31)}${'y'.repeat(33)}`, '2e928b90e58e13296f437672b81cc627e8fc79cd83b788e1a5db4189fb1dfccece094aba281a1c3eafe7da6be852c79cea66244268e7c5b733167f6dbea4a6d8'],
+  ['QuickFox', QUICK_FOX, 'b97de512e91e3828b40d2b0fdce9ceb3c4a71f9bea8d88e75c4fa854df36725fd2b52eb6544edcacd6f8beddfea403cb55ae31f03ad62a5ef54e42ee82c3fb35'],
+  ['LongMessage', 'a'.repeat(1000), 'fe24b173807796fdac15ebcaf5769f661695601ffeb64490ec0eecd30bd5b2c3773b36d4edaf3175378b8df114e9496c833ef13606e7ab3d455681e98ecc818f']
+];
+
+beforeAll(async () => {
+  await C.Whirlpool.loadWasm();
+});
+
+describe('algo-whirlpool-test', () => {
+  test.each(VECTORS)(
+    'test%s',
+    (name, message, expected) => {
+      expect(C.Whirlpool(message).toString()).toBe(expected);
+    }
+  );
+
+  test('testUpdate', () => {
+    const whirlpool = new C.algo.Whirlpool();
+    for (let i = 0; i < 100; i++) {
+      whirlpool.update('aaaaaaaaaa');
+    }
+
+    expect(whirlpool.finalize().toString()).toBe(VECTORS[4][2]);
+  });
+
+  test('testClone', () => {
+    const whirlpool = new C.algo.Whirlpool();
+    whirlpool.update('a');
+    expect(whirlpool.clone().finalize().toString()).toBe(C.Whirlpool('a').toString());
+    whirlpool.update('bc');
+    expect(whirlpool.finalize().toString()).toBe(VECTORS[1][2]);
+  });
+
+  test('testHmac', () => {
+    expect(C.HmacWhirlpool(QUICK_FOX, 'key').toString())
+      .toBe('7f7192e3a155cb6a8171584ba146882f26821658112dfd2601272db013517a31e573637d146584596f86a884eb0decc9514dde000ecf2476dc5d436a92197527');
+  });
+});
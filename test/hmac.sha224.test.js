@@ -33,6 +33,22 @@ describe('algo-hmac-sha224-test', () => {
     expect(hmac.finalize().toString()).toBe(C.HmacSHA224(C.enc.Hex.parse('dddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddd'), C.enc.Hex.parse('aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa')).toString());
   });
 
+  test('testKeyLongerThanBlockSize', () => {
+    // RFC 4231 test case 6
+    const key = C.enc.Hex.parse('aa'.repeat(131));
+    expect(C.HmacSHA224('Test Using Larger Than Block-Size Key - Hash Key First', key).toString()).toBe('95e9a0db962095adaebe9b2d6f0dbce2d499f112f2d2b7273fa6870e');
+  });
+
+  test('testNativeFinalize', () => {
+    // A whole message given to finalize is authenticated in a single wasm call, the same as with update
+    const hmac = new C.algo.HMAC(C.algo.SHA224, 'Jefe');
+    const expected = hmac.update('what do ya want ').finalize('for nothing?').toString();
+    hmac.reset();
+    expect(hmac.finalize('what do ya want for nothing?').toString()).toBe(expected);
+    expect(new C.algo.HMAC(C.algo.SHA224, 'Jefe').finalize('').toString())
+      .toBe(new C.algo.HMAC(C.algo.SHA224, 'Jefe').update('').finalize().toString());
+  });
+
   test('testInputIntegrity', () => {
     let message = new C.lib.WordArray([0x12345678]);
     let key = new C.lib.WordArray([0x12345678]);
@@ -34,6 +34,22 @@ describe('algo-hmac-sha256-test', () => {
     expect(hmac.finalize().toString()).toBe(C.HmacSHA256(C.enc.Hex.parse('dddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddd'), C.enc.Hex.parse('aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa')).toString());
   });
 
+  test('testKeyLongerThanBlockSize', () => {
+    // RFC 4231 test case 6
+    const key = C.enc.Hex.parse('aa'.repeat(131));
+    expect(C.HmacSHA256('Test Using Larger Than Block-Size Key - Hash Key First', key).toString()).toBe('60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54');
+  });
+
+  test('testNativeFinalize', () => {
+    // A whole message given to finalize is authenticated in a single wasm call, the same as with update
+    const hmac = new C.algo.HMAC(C.algo.SHA256, 'Jefe');
+    const expected = hmac.update('what do ya want ').finalize('for nothing?').toString();
+    hmac.reset();
+    expect(hmac.finalize('what do ya want for nothing?').toString()).toBe(expected);
+    expect(new C.algo.HMAC(C.algo.SHA256, 'Jefe').finalize('').toString())
+      .toBe(new C.algo.HMAC(C.algo.SHA256, 'Jefe').update('').finalize().toString());
+  });
+
   test('testInputIntegrity', () => {
     let message = new C.lib.WordArray([0x12345678]);
     let key = new C.lib.WordArray([0x12345678]);
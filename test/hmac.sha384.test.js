@@ -29,6 +29,22 @@ describe('algo-hmac-sha384-test', () => {
     expect(hmac.finalize().toString()).toBe(C.HmacSHA384(C.enc.Hex.parse('dddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddd'), C.enc.Hex.parse('aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa')).toString());
   });
 
+  test('testKeyLongerThanBlockSize', () => {
+    // RFC 4231 test case 6
+    const key = C.enc.Hex.parse('aa'.repeat(131));
+    expect(C.HmacSHA384('Test Using Larger Than Block-Size Key - Hash Key First', key).toString()).toBe('4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c60c2ef6ab4030fe8296248df163f44952');
+  });
+
+  test('testNativeFinalize', () => {
+    // A whole message given to finalize is authenticated in a single wasm call, the same as with update
+    const hmac = new C.algo.HMAC(C.algo.SHA384, 'Jefe');
+    const expected = hmac.update('what do ya want ').finalize('for nothing?').toString();
+    hmac.reset();
+    expect(hmac.finalize('what do ya want for nothing?').toString()).toBe(expected);
+    expect(new C.algo.HMAC(C.algo.SHA384, 'Jefe').finalize('').toString())
+      .toBe(new C.algo.HMAC(C.algo.SHA384, 'Jefe').update('').finalize().toString());
+  });
+
   test('testInputIntegrity', () => {
     let message = new C.lib.WordArray([0x12345678]);
     let key = new C.lib.WordArray([0x12345678]);
@@ -29,6 +29,22 @@ describe('algo-hmac-sha512-test', () => {
     expect(hmac.finalize().toString()).toBe(C.HmacSHA512(C.enc.Hex.parse('dddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddd'), C.enc.Hex.parse('aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa')).toString());
   });
 
+  test('testKeyLongerThanBlockSize', () => {
+    // RFC 4231 test case 6
+    const key = C.enc.Hex.parse('aa'.repeat(131));
+    expect(C.HmacSHA512('Test Using Larger Than Block-Size Key - Hash Key First', key).toString()).toBe('80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598');
+  });
+
+  test('testNativeFinalize', () => {
+    // A whole message given to finalize is authenticated in a single wasm call, the same as with update
+    const hmac = new C.algo.HMAC(C.algo.SHA512, 'Jefe');
+    const expected = hmac.update('what do ya want ').finalize('for nothing?').toString();
+    hmac.reset();
+    expect(hmac.finalize('what do ya want for nothing?').toString()).toBe(expected);
+    expect(new C.algo.HMAC(C.algo.SHA512, 'Jefe').finalize('').toString())
+      .toBe(new C.algo.HMAC(C.algo.SHA512, 'Jefe').update('').finalize().toString());
+  });
+
   test('testInputIntegrity', () => {
     let message = new C.lib.WordArray([0x12345678]);
     let key = new C.lib.WordArray([0x12345678]);
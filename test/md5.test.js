@@ -72,4 +72,29 @@ describe('algo-md5-test', () => {
   test('testHmacHelper', () => {
     expect(C.HmacMD5('Hi There', C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b')).toString()).toBe(new C.algo.HMAC(C.algo.MD5, C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b')).finalize('Hi There').toString());
   });
+
+  test('testNativeMatchesStreaming', () => {
+    const message = '12345678901234567890123456789012345678901234567890';
+    const streamed = new C.algo.MD5().update(message.slice(0, 10)).finalize(message.slice(10));
+    expect(C.MD5(message)).toEqual(streamed);
+  });
+
+  test('testNativeHmacMatchesStreaming', () => {
+    const key = C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b');
+    const streamed = new C.algo.HMAC(C.algo.MD5, key).update('Hi ').finalize('There');
+    expect(C.HmacMD5('Hi There', key)).toEqual(streamed);
+  });
+
+  test('testPbkdf2', () => {
+    expect(C.PBKDF2('password', 'salt', { keySize: 8, iterations: 2, hasher: C.algo.MD5 }).toString())
+      .toBe('042407b552be345ad6eee2cf2f7ed01dd9662d8f0c6950eaec7124aa0c82279e');
+  });
+
+  test('testHkdf', () => {
+    const ikm = C.enc.Hex.parse('0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b');
+    const salt = C.enc.Hex.parse('000102030405060708090a0b0c');
+    const info = C.enc.Hex.parse('f0f1f2f3f4f5f6f7f8f9');
+    expect(C.HKDF(ikm, salt, info, { keySize: 8, hasher: C.algo.MD5 }).toString())
+      .toBe('b222c9db38d17b2fea8b3bb511c0d6d86049ef481ba7065ca5c6422618ed9cc9');
+  });
 });
@@ -0,0 +1,104 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// Test cases from RFC 3962, appendix B: AES-128 in CBC-CS3, with a zero IV
+const KEY = '636869636b656e207465726979616b69';
+const IV = '00000000000000000000000000000000';
+const PLAINTEXT = 'I would like the General Gau\'s Chicken, please, and wonton soup.';
+
+const VECTORS = [
+  ['17Bytes', 17, 'c6353568f2bf8cb4d8a580362da7ff7f97'],
+  ['31Bytes', 31, 'fc00783e0efdb2c1d445d4c8eff7ed2297687268d6ecccc0c07b25e25ecfe5'],
+  ['32Bytes', 32, '39312523a78662d5be7fcbcc98ebf5a897687268d6ecccc0c07b25e25ecfe584'],
+  ['47Bytes', 47, '97687268d6ecccc0c07b25e25ecfe584b3fffd940c16a18c1b5549d2f838029e39312523a78662d5be7fcbcc98ebf5'],
+  ['48Bytes', 48, '97687268d6ecccc0c07b25e25ecfe5849dad8bbb96c4cdc03bc103e1a194bbd839312523a78662d5be7fcbcc98ebf5a8'],
+  ['64Bytes', 64, '97687268d6ecccc0c07b25e25ecfe58439312523a78662d5be7fcbcc98ebf5a84807efe836ee89a526730dbc2f7bc8409dad8bbb96c4cdc03bc103e1a194bbd8']
+];
+
+// CBC-CS1 keeps the truncated next to last block in place, CBC-CS3 swaps it with the last block
+const toCs1 = (cs3, length) => {
+  const partial = length % 16 || 16;
+  const head = cs3.slice(0, -2 * (16 + partial));
+  const last = cs3.slice(-2 * (16 + partial), -2 * partial);
+
+  return head + cs3.slice(-2 * partial) + last;
+};
+
+const encrypt = (cipher, message, key, mode) => cipher.encrypt(message, key, { iv: hex(IV), mode }).ciphertext.toString();
+const decrypt = (cipher, ciphertext, key, mode) => cipher.decrypt(new C.lib.CipherParams({ ciphertext: hex(ciphertext) }), key, { iv: hex(IV), mode }).toString(C.enc.Utf8);
+
+beforeAll(async () => {
+  await C.AES.loadWasm();
+  await C.TripleDES.loadWasm();
+  await C.Blowfish.loadWasm();
+  await C.SM4.loadWasm();
+});
+
+describe('mode-cbc-cs-test', () => {
+  test.each(VECTORS)(
+    'testCs3%s',
+    (name, length, expected) => {
+      const message = PLAINTEXT.slice(0, length);
+
+      expect(encrypt(C.AES, message, hex(KEY), C.mode.CBCCS3)).toBe(expected);
+      expect(decrypt(C.AES, expected, hex(KEY), C.mode.CBCCS3)).toBe(message);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testCs1%s',
+    (name, length, cs3) => {
+      const message = PLAINTEXT.slice(0, length);
+      const expected = toCs1(cs3, length);
+
+      expect(encrypt(C.AES, message, hex(KEY), C.mode.CBCCS1)).toBe(expected);
+      expect(decrypt(C.AES, expected, hex(KEY), C.mode.CBCCS1)).toBe(message);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testCs2%s',
+    (name, length, cs3) => {
+      const message = PLAINTEXT.slice(0, length);
+      const expected = length % 16 ? cs3 : toCs1(cs3, length);
+
+      expect(encrypt(C.AES, message, hex(KEY), C.mode.CBCCS2)).toBe(expected);
+      expect(decrypt(C.AES, expected, hex(KEY), C.mode.CBCCS2)).toBe(message);
+    }
+  );
+
+  test('testMultiPart', () => {
+    const aes = C.algo.AES.createEncryptor(hex(KEY), { iv: hex(IV), mode: C.mode.CBCCS3 });
+    const ciphertext1 = aes.process(PLAINTEXT.slice(0, 20));
+    const ciphertext2 = aes.process(PLAINTEXT.slice(20, 47));
+    const ciphertext3 = aes.finalize();
+
+    expect(ciphertext1.concat(ciphertext2).concat(ciphertext3).toString()).toBe(VECTORS[3][2]);
+  });
+
+  test.each([
+    ['TripleDES', () => C.TripleDES, '000102030405060708090a0b0c0d0e0f1011121314151617'],
+    ['DES', () => C.DES, '0001020304050607'],
+    ['Blowfish', () => C.Blowfish, '000102030405060708090a0b0c0d0e0f'],
+    ['SM4', () => C.SM4, '0123456789abcdeffedcba9876543210']
+  ])(
+    'testRoundTrip%s',
+    (name, getCipher, key) => {
+      const cipher = getCipher();
+      [C.mode.CBCCS1, C.mode.CBCCS2, C.mode.CBCCS3].forEach((mode) => {
+        [17, 31, 32, 47].forEach((length) => {
+          const message = PLAINTEXT.slice(0, length);
+          const ciphertext = encrypt(cipher, message, hex(key), mode);
+
+          expect(ciphertext.length).toBe(2 * length);
+          expect(decrypt(cipher, ciphertext, hex(key), mode)).toBe(message);
+        });
+      });
+    }
+  );
+
+  test('testShortMessage', () => {
+    expect(() => encrypt(C.AES, 'short', hex(KEY), C.mode.CBCCS1)).toThrow('CBC-CS1 requires at least one block of data');
+  });
+});
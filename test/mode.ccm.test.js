@@ -0,0 +1,72 @@
+import C from '../src/index';
+
+// Test cases from RFC 3610 and NIST SP 800-38C
+const RFC3610_KEY = 'c0c1c2c3c4c5c6c7c8c9cacbcccdcecf';
+const SP800_38C_KEY = '404142434445464748494a4b4c4d4e4f';
+
+const VECTORS = [
+  ['PacketVector1', RFC3610_KEY, '00000003020100a0a1a2a3a4a5', '0001020304050607', '08090a0b0c0d0e0f101112131415161718191a1b1c1d1e', '588c979a61c663d2f066d0c2c0f989806d5f6b61dac38417e8d12cfdf926e0', 64],
+  ['PacketVector2', RFC3610_KEY, '00000004030201a0a1a2a3a4a5', '0001020304050607', '08090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f', '72c91a36e135f8cf291ca894085c87e3cc15c439c9e43a3ba091d56e10400916', 64],
+  ['PacketVector3', RFC3610_KEY, '00000005040302a0a1a2a3a4a5', '0001020304050607', '08090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20', '51b1e5f44a197d1da46b0f8e2d282ae871e838bb64da8596574adaa76fbd9fb0c5', 64],
+  ['ShortNonce', SP800_38C_KEY, '10111213141516', '0001020304050607', '20212223', '7162015b4dac255d', 32],
+  ['ShortTag', SP800_38C_KEY, '1011121314151617', '000102030405060708090a0b0c0d0e0f', '202122232425262728292a2b2c2d2e2f', 'd2a1f0e051ea5f62081a7792073d593d1fc64fbfaccd', 48]
+];
+
+const cfg = (iv, aad, tagLength) => ({
+  mode: C.mode.CCM,
+  iv: C.enc.Hex.parse(iv),
+  aad: C.enc.Hex.parse(aad),
+  tagLength
+});
+
+beforeAll(async () => {
+  await C.AES.loadWasm();
+});
+
+describe('mode-ccm-test', () => {
+  test.each(VECTORS)(
+    'testEncrypt%s',
+    (name, key, iv, aad, plaintext, expected, tagLength) => {
+      expect(C.AES.encrypt(C.enc.Hex.parse(plaintext), C.enc.Hex.parse(key), cfg(iv, aad, tagLength)).ciphertext.toString())
+        .toBe(expected);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testDecrypt%s',
+    (name, key, iv, aad, plaintext, ciphertext, tagLength) => {
+      expect(C.AES.decrypt(new C.lib.CipherParams({
+        ciphertext: C.enc.Hex.parse(ciphertext)
+      }), C.enc.Hex.parse(key), cfg(iv, aad, tagLength)).toString()).toBe(plaintext);
+    }
+  );
+
+  test('testMultiPart', () => {
+    const [, key, iv, aad, plaintext, expected, tagLength] = VECTORS[2];
+    const aes = C.algo.AES.createEncryptor(C.enc.Hex.parse(key), cfg(iv, aad, tagLength));
+    const ciphertext1 = aes.process(C.enc.Hex.parse(plaintext.slice(0, 14)));
+    const ciphertext2 = aes.process(C.enc.Hex.parse(plaintext.slice(14)));
+    const ciphertext3 = aes.finalize();
+
+    expect(ciphertext1.concat(ciphertext2).concat(ciphertext3).toString()).toBe(expected);
+  });
+
+  test('testAuthenticationFailure', () => {
+    const [, key, iv, aad, , ciphertext, tagLength] = VECTORS[0];
+    const tampered = C.enc.Hex.parse(`${ciphertext.slice(0, -2)}e1`);
+    expect(() => C.AES.decrypt(new C.lib.CipherParams({ ciphertext: tampered }), C.enc.Hex.parse(key),
+      cfg(iv, aad, tagLength))).toThrow('CCM authentication failed');
+
+    expect(() => C.AES.decrypt(new C.lib.CipherParams({ ciphertext: C.enc.Hex.parse(ciphertext) }), C.enc.Hex.parse(key),
+      cfg(iv, aad.slice(2), tagLength))).toThrow('CCM authentication failed');
+  });
+
+  test('testInvalidParameters', () => {
+    expect(() => C.AES.encrypt(C.enc.Hex.parse('00'), C.enc.Hex.parse(RFC3610_KEY),
+      cfg('00000003020100a0a1a2a3a4a5', '', 56))).toThrow('Invalid CCM tag length: 56');
+    expect(() => C.AES.encrypt(C.enc.Hex.parse('00'), C.enc.Hex.parse(RFC3610_KEY),
+      cfg('000000030201', '', 64))).toThrow('Invalid CCM IV size: 6 bytes');
+    expect(() => C.AES.encrypt(C.enc.Hex.parse('00'), C.enc.Hex.parse(RFC3610_KEY),
+      cfg('00000003020100a0a1a2a3a4a5a6', '', 64))).toThrow('Invalid CCM IV size: 14 bytes');
+  });
+});
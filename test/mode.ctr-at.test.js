@@ -0,0 +1,63 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// Test case from NIST SP 800-38A, appendix F.5.1: CTR-AES128
+const KEY = '2b7e151628aed2a6abf7158809cf4f3c';
+const IV = 'f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff';
+const PLAINTEXT = '6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710';
+const CIPHERTEXT = '874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee';
+
+beforeAll(async () => {
+  await C.AES.loadWasm();
+});
+
+describe('mode-ctr-at-test', () => {
+  test.each([
+    ['WholeStream', 0, 64],
+    ['SecondBlock', 16, 16],
+    ['Unaligned', 21, 36],
+    ['SingleByte', 63, 1],
+    ['Empty', 40, 0]
+  ])(
+    'testSlice%s',
+    (name, offset, length) => {
+      const slice = (str) => hex(str.slice(2 * offset, 2 * (offset + length)));
+
+      expect(C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), offset, slice(PLAINTEXT)).toString()).toBe(slice(CIPHERTEXT).toString());
+      expect(C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), offset, slice(CIPHERTEXT)).toString()).toBe(slice(PLAINTEXT).toString());
+    }
+  );
+
+  test('testMatchesStream', () => {
+    const cfg = { iv: hex(IV), mode: C.mode.CTR, padding: C.pad.NoPadding };
+    const message = hex(`${PLAINTEXT}${PLAINTEXT}`.slice(0, 250));
+    const stream = C.AES.encrypt(message, hex(KEY), cfg).ciphertext.toString();
+
+    expect(C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), 77, hex(message.toString().slice(154))).toString()).toBe(stream.slice(154));
+  });
+
+  test('testFarOffset', () => {
+    // 2^32 blocks in, the full-width counter has carried into the third word
+    const far = C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), 16 * 0x100000000 + 5, hex('00000000'));
+    const near = C.algo.AES.cryptCtrAt(hex(KEY), hex('f0f1f2f3f4f5f6f7f8f9fafcfcfdfeff'), 5, hex('00000000'));
+    expect(far.toString()).toBe(near.toString());
+
+    // A 32-bit counter wraps back to where it started
+    const cfg = { mode: C.mode.CTR.withCounterWidth(32) };
+    const wrapped = C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), 16 * 0x100000000 + 5, hex('00000000'), cfg);
+    expect(wrapped.toString()).toBe(C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), 5, hex('00000000'), cfg).toString());
+    expect(wrapped.toString()).not.toBe(far.toString());
+  });
+
+  test('testInvalidParameters', () => {
+    expect(() => C.algo.AES.cryptCtrAt(hex('00010203'), hex(IV), 0, hex(PLAINTEXT)))
+      .toThrow('AES requires a 128, 192 or 256 bit key');
+    expect(() => C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), -1, hex(PLAINTEXT)))
+      .toThrow('CTR byte offset must be a non-negative integer');
+    expect(() => C.algo.AES.cryptCtrAt(hex(KEY), hex(IV), 0, hex(PLAINTEXT), { mode: C.mode.CBC }))
+      .toThrow('CBC is not a CTR mode, or its IV is shorter than one block');
+    expect(() => C.algo.AES.cryptCtrAt(hex(KEY), hex('f0f1f2f3'), 0, hex(PLAINTEXT)))
+      .toThrow('CTR is not a CTR mode, or its IV is shorter than one block');
+  });
+});
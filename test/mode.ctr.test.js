@@ -9,6 +9,8 @@ beforeAll(async () => {
   data.key = new C.lib.WordArray([0x20212223, 0x24252627, 0x28292a2b, 0x2c2d2e2f]);
   data.iv = new C.lib.WordArray([0x30313233, 0x34353637, 0x38393a3b, 0x3c3d3e3f]);
   await C.AES.loadWasm();
+  await C.TripleDES.loadWasm();
+  await C.Blowfish.loadWasm();
 });
 
 describe('mode-ctr-test', () => {
@@ -25,4 +27,50 @@ describe('mode-ctr-test', () => {
     });
     expect(decrypted.toString()).toBe(data.message.toString());
   });
+
+  // RFC 3686, test vectors 1 and 2: a 32-bit block counter after the nonce and IV
+  test.each([
+    ['Vector1', 'ae6852f8121067cc4bf7a5765577f39e', '00000030000000000000000000000001', '53696e676c6520626c6f636b206d7367', 'e4095d4fb7a7b3792d6175a3261311b8'],
+    ['Vector2', '7e24067817fae0d743d6ce1f32539163', '006cb6dbc0543b59da48d90b00000001', '000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f', '5104a106168a72d9790d41ee8edad388eb2e1efc46da57c8fce630df9141be28']
+  ])(
+    'testCounterWidth32%s',
+    (name, key, iv, plaintext, expected) => {
+      const cfg = { iv: C.enc.Hex.parse(iv), mode: C.mode.CTR.withCounterWidth(32), padding: C.pad.NoPadding };
+      const encrypted = C.AES.encrypt(C.enc.Hex.parse(plaintext), C.enc.Hex.parse(key), cfg);
+
+      expect(encrypted.ciphertext.toString()).toBe(expected);
+      expect(C.AES.decrypt(encrypted, C.enc.Hex.parse(key), cfg).toString()).toBe(plaintext);
+    }
+  );
+
+  test('testCounterWraps', () => {
+    const iv = new C.lib.WordArray([0x30313233, 0x34353637, 0x38393a3b, 0xffffffff]);
+    const zeros = new C.lib.WordArray([0, 0, 0, 0, 0, 0, 0, 0]);
+    const keystream = (mode) => C.AES.encrypt(zeros, data.key, { iv, mode, padding: C.pad.NoPadding }).ciphertext.words.slice(4);
+    const ecb = (words) => C.AES.encrypt(new C.lib.WordArray(words), data.key, { mode: C.mode.ECB, padding: C.pad.NoPadding }).ciphertext.words;
+
+    // A 32-bit counter wraps within its width, the default counter carries into the next word
+    expect(keystream(C.mode.CTR.withCounterWidth(32))).toEqual(ecb([0x30313233, 0x34353637, 0x38393a3b, 0x00000000]));
+    expect(keystream(C.mode.CTR)).toEqual(ecb([0x30313233, 0x34353637, 0x38393a3c, 0x00000000]));
+  });
+
+  test('testInvalidCounterWidth', () => {
+    expect(() => C.mode.CTR.withCounterWidth(0)).toThrow('Invalid CTR counter width: 0');
+    expect(() => C.mode.CTR.withCounterWidth(1.5)).toThrow('Invalid CTR counter width: 1.5');
+    expect(() => C.mode.CTR.withCounterWidth(256)).toThrow('Invalid CTR counter width: 256');
+
+    // Wider than a 64-bit block
+    const iv = new C.lib.WordArray([0x30313233, 0x34353637]);
+    const cfg = { iv, mode: C.mode.CTR.withCounterWidth(96), padding: C.pad.NoPadding };
+    expect(() => C.TripleDES.encrypt(data.message, C.lib.WordArray.random(24), cfg))
+      .toThrow('CTR-96 is not supported by this cipher, or its IV is shorter than one block');
+    expect(() => C.Blowfish.encrypt(data.message, data.key, cfg))
+      .toThrow('CTR-96 is not supported by this cipher, or its IV is shorter than one block');
+  });
+
+  test('testShortIv', () => {
+    const iv = new C.lib.WordArray([0x30313233, 0x34353637]);
+    expect(() => C.AES.encrypt(data.message, data.key, { iv, mode: C.mode.CTR, padding: C.pad.NoPadding }))
+      .toThrow('CTR is not supported by this cipher, or its IV is shorter than one block');
+  });
 });
@@ -0,0 +1,78 @@
+import C from '../src/index';
+
+// Test cases from "The Galois/Counter Mode of Operation (GCM)", McGrew and Viega
+const KEY = 'feffe9928665731c6d6a8f9467308308';
+const PLAINTEXT = 'd9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39';
+const AAD = 'feedfacedeadbeeffeedfacedeadbeefabaddad2';
+
+const VECTORS = [
+  ['EmptyMessage', '00000000000000000000000000000000', '000000000000000000000000', '', '', '58e2fccefa7e3061367f1d57a4e7455a'],
+  ['SingleZeroBlock', '00000000000000000000000000000000', '000000000000000000000000', '00000000000000000000000000000000', '', '0388dace60b6a392f328c2b971b2fe78ab6e47d42cec13bdf53a67b21257bddf'],
+  ['PartialBlockWithAad', KEY, 'cafebabefacedbaddecaf888', PLAINTEXT, AAD, '42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e0915bc94fbc3221a5db94fae95ae7121a47'],
+  ['ShortIv', KEY, 'cafebabefacedbad', PLAINTEXT, AAD, '61353b4c2806934a777ff51fa22a4755699b2a714fcdc6f83766e5f97b6c742373806900e49f24b22b097544d4896b424989b5e1ebac0f07c23f45983612d2e79e3b0785561be14aaca2fccb'],
+  ['LongIv', KEY, '9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b', PLAINTEXT, AAD, '8ce24998625615b603a033aca13fb894be9112a5c3a211a8ba262a3cca7e2ca701e4a9a4fba43c90ccdcb281d48c7c6fd62875d2aca417034c34aee5619cc5aefffe0bfa462af43c1699d050']
+];
+
+const cfg = (iv, aad, extra) => Object.assign({
+  mode: C.mode.GCM,
+  iv: C.enc.Hex.parse(iv),
+  aad: C.enc.Hex.parse(aad)
+}, extra);
+
+beforeAll(async () => {
+  await C.AES.loadWasm();
+});
+
+describe('mode-gcm-test', () => {
+  test.each(VECTORS)(
+    'testEncrypt%s',
+    (name, key, iv, plaintext, aad, expected) => {
+      expect(C.AES.encrypt(C.enc.Hex.parse(plaintext), C.enc.Hex.parse(key), cfg(iv, aad)).ciphertext.toString())
+        .toBe(expected);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testDecrypt%s',
+    (name, key, iv, plaintext, aad, ciphertext) => {
+      expect(C.AES.decrypt(new C.lib.CipherParams({
+        ciphertext: C.enc.Hex.parse(ciphertext)
+      }), C.enc.Hex.parse(key), cfg(iv, aad)).toString()).toBe(plaintext);
+    }
+  );
+
+  test('testMultiPart', () => {
+    const aes = C.algo.AES.createEncryptor(C.enc.Hex.parse(KEY), cfg('cafebabefacedbaddecaf888', AAD));
+    const ciphertext1 = aes.process(C.enc.Hex.parse(PLAINTEXT.slice(0, 50)));
+    const ciphertext2 = aes.process(C.enc.Hex.parse(PLAINTEXT.slice(50)));
+    const ciphertext3 = aes.finalize();
+
+    expect(ciphertext1.concat(ciphertext2).concat(ciphertext3).toString()).toBe(VECTORS[2][5]);
+  });
+
+  test('testTruncatedTag', () => {
+    const ciphertext = C.AES.encrypt(C.enc.Hex.parse(PLAINTEXT), C.enc.Hex.parse(KEY),
+      cfg('cafebabefacedbaddecaf888', AAD, { tagLength: 96 })).ciphertext;
+
+    expect(ciphertext.toString()).toBe(VECTORS[2][5].slice(0, -8));
+    expect(C.AES.decrypt(new C.lib.CipherParams({ ciphertext }), C.enc.Hex.parse(KEY),
+      cfg('cafebabefacedbaddecaf888', AAD, { tagLength: 96 })).toString()).toBe(PLAINTEXT);
+  });
+
+  test('testAuthenticationFailure', () => {
+    const tampered = C.enc.Hex.parse(`43${VECTORS[2][5].slice(2)}`);
+    expect(() => C.AES.decrypt(new C.lib.CipherParams({ ciphertext: tampered }), C.enc.Hex.parse(KEY),
+      cfg('cafebabefacedbaddecaf888', AAD))).toThrow('GCM authentication failed');
+
+    const ciphertext = C.enc.Hex.parse(VECTORS[2][5]);
+    expect(() => C.AES.decrypt(new C.lib.CipherParams({ ciphertext }), C.enc.Hex.parse(KEY),
+      cfg('cafebabefacedbaddecaf888', AAD.slice(2)))).toThrow('GCM authentication failed');
+  });
+
+  test('testInvalidParameters', () => {
+    expect(() => C.AES.encrypt(C.enc.Hex.parse(PLAINTEXT), C.enc.Hex.parse(KEY),
+      cfg('cafebabefacedbaddecaf888', AAD, { tagLength: 16 }))).toThrow('Invalid GCM tag length: 16');
+    expect(() => C.AES.encrypt(C.enc.Hex.parse(PLAINTEXT), C.enc.Hex.parse(KEY),
+      cfg('', AAD))).toThrow('Invalid GCM IV size: 0 bytes');
+  });
+});
@@ -0,0 +1,89 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// Test cases from RFC 5297, appendix A
+const SIV_VECTORS = [
+  ['DeterministicAuthenticatedEncryption', 'fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff', ['101112131415161718191a1b1c1d1e1f2021222324252627'], '112233445566778899aabbccddee', '85632d07c6e8f37f950acd320a2ecc9340c02b9690c4dc04daef7f6afe5c'],
+  ['NonceBasedAuthenticatedEncryption', '7f7e7d7c7b7a79787776757473727170404142434445464748494a4b4c4d4e4f', ['00112233445566778899aabbccddeeffdeaddadadeaddadaffeeddccbbaa99887766554433221100', '102030405060708090a0', '09f911029d74e35bd84156c5635688c0'], '7468697320697320736f6d6520706c61696e7465787420746f20656e6372797074207573696e67205349562d414553', '7bdb6e3b432667eb06f4d14bff2fbd0fcb900f2fddbe404326601965c889bf17dba77ceb094fa663b7a3f748ba8af829ea64ad544a272e9c485b62a3fd5c0d']
+];
+
+// Test cases from RFC 8452, appendix C
+const GCM_SIV_NONCE = '030000000000000000000000';
+const GCM_SIV_VECTORS = [
+  ['Aes128Empty', '01000000000000000000000000000000', '', '', 'dc20e2d83f25705bb49e439eca56de25'],
+  ['Aes128OneWord', '01000000000000000000000000000000', '0100000000000000', '', 'b5d839330ac7b786578782fff6013b815b287c22493a364c'],
+  ['Aes128TwoBlocksWithAad', '01000000000000000000000000000000', '0100000000000000000000000000000002000000000000000000000000000000', '01', '9b12b6f5e3014e4620b29f37daf67dfe2b358c8157d8cb9e3f35381a256fd4dbb5e7a1a1c0529a16215d3c7ef4e1361c'],
+  ['Aes128PartialAad', '01000000000000000000000000000000', '0200000000000000', '010000000000000000000000', 'a6bc84ed02cc9fae8568a1005e8a523d0d06b5679a5a4a9e'],
+  ['Aes256OneWord', '0100000000000000000000000000000000000000000000000000000000000000', '0100000000000000', '', 'c2ef328e5c71c83b843122130f7364b761e0b97427e3df28'],
+  ['Aes256PartialBlockWithAad', '0100000000000000000000000000000000000000000000000000000000000000', '010000000000000000000000', '01', 'e9abc6aafde487f8750e05588b12e960e3c59a481f49954b1e34a84b']
+];
+
+beforeAll(async () => {
+  await C.AES.loadWasm();
+});
+
+describe('mode-siv-test', () => {
+  test.each(SIV_VECTORS)(
+    'testEncrypt%s',
+    (name, key, aad, plaintext, expected) => {
+      expect(C.algo.AES.sivEncrypt(hex(key), hex(plaintext), aad.map(hex)).toString()).toBe(expected);
+    }
+  );
+
+  test.each(SIV_VECTORS)(
+    'testDecrypt%s',
+    (name, key, aad, plaintext, ciphertext) => {
+      expect(C.algo.AES.sivDecrypt(hex(key), hex(ciphertext), aad.map(hex)).toString()).toBe(plaintext);
+    }
+  );
+
+  test('testAuthenticationFailure', () => {
+    const [, key, aad, , ciphertext] = SIV_VECTORS[1];
+    expect(() => C.algo.AES.sivDecrypt(hex(key), hex(ciphertext), aad.slice(1).map(hex)))
+      .toThrow('AES-SIV authentication failed');
+    expect(() => C.algo.AES.sivDecrypt(hex(key), hex(`${ciphertext.slice(0, -2)}0c`), aad.map(hex)))
+      .toThrow('AES-SIV authentication failed');
+  });
+
+  test('testInvalidParameters', () => {
+    expect(() => C.algo.AES.sivEncrypt(hex('00000000000000000000000000000000'), 'message'))
+      .toThrow('AES-SIV requires a 256, 384 or 512 bit key');
+    expect(() => C.algo.AES.sivDecrypt(hex(SIV_VECTORS[0][1]), hex('00')))
+      .toThrow('AES-SIV ciphertext is shorter than its synthetic IV');
+  });
+});
+
+describe('mode-gcm-siv-test', () => {
+  test.each(GCM_SIV_VECTORS)(
+    'testEncrypt%s',
+    (name, key, plaintext, aad, expected) => {
+      expect(C.algo.AES.gcmSivEncrypt(hex(key), hex(GCM_SIV_NONCE), hex(plaintext), hex(aad)).toString()).toBe(expected);
+    }
+  );
+
+  test.each(GCM_SIV_VECTORS)(
+    'testDecrypt%s',
+    (name, key, plaintext, aad, ciphertext) => {
+      expect(C.algo.AES.gcmSivDecrypt(hex(key), hex(GCM_SIV_NONCE), hex(ciphertext), hex(aad)).toString()).toBe(plaintext);
+    }
+  );
+
+  test('testAuthenticationFailure', () => {
+    const [, key, , aad, ciphertext] = GCM_SIV_VECTORS[2];
+    expect(() => C.algo.AES.gcmSivDecrypt(hex(key), hex(GCM_SIV_NONCE), hex(`${ciphertext.slice(0, -2)}1d`), hex(aad)))
+      .toThrow('AES-GCM-SIV authentication failed');
+    expect(() => C.algo.AES.gcmSivDecrypt(hex(key), hex(GCM_SIV_NONCE), hex(ciphertext), hex('02')))
+      .toThrow('AES-GCM-SIV authentication failed');
+  });
+
+  test('testInvalidParameters', () => {
+    const key = hex(GCM_SIV_VECTORS[0][1]);
+    expect(() => C.algo.AES.gcmSivEncrypt(hex('000000000000000000000000000000000000000000000000'), hex(GCM_SIV_NONCE), 'message'))
+      .toThrow('AES-GCM-SIV requires a 128 or 256 bit key');
+    expect(() => C.algo.AES.gcmSivEncrypt(key, hex('0300000000000000'), 'message'))
+      .toThrow('AES-GCM-SIV requires a 96-bit nonce');
+    expect(() => C.algo.AES.gcmSivDecrypt(key, hex(GCM_SIV_NONCE), hex('00')))
+      .toThrow('AES-GCM-SIV ciphertext is shorter than its tag');
+  });
+});
@@ -0,0 +1,65 @@
+import C from '../src/index';
+
+const hex = (str) => C.enc.Hex.parse(str);
+
+// 0x00, 0x01, ..., 0xff repeated to fill a 512-byte sector
+const SECTOR_512 = Array.from({ length: 512 }, (_, i) => (i & 0xff).toString(16).padStart(2, '0')).join('');
+
+// Test cases from IEEE 1619, and data units of 17 to 20 bytes checked against OpenSSL's XTS-AES-128.
+// Vector 1 is left out: its key halves are equal, which is rejected.
+const VECTORS = [
+  ['Vector2', '1111111111111111111111111111111122222222222222222222222222222222', 0x3333333333, '4444444444444444444444444444444444444444444444444444444444444444', 'c454185e6a16936e39334038acef838bfb186fff7480adc4289382ecd6d394f0'],
+  ['Stealing17', 'fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0', 0x9a78563412, '000102030405060708090a0b0c0d0e0f10', '641610679dcbf92e505c41333fb06c2a95'],
+  ['Stealing18', 'fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0', 0x9a78563412, '000102030405060708090a0b0c0d0e0f1011', '223a725cbcd4dc647b9a9826d54c99c895c8'],
+  ['Stealing19', 'fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0', 0x9a78563412, '000102030405060708090a0b0c0d0e0f101112', '0d39809a65c1d55501960b671d4b8b6b95c871'],
+  ['Stealing20', 'fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0', 0x9a78563412, '000102030405060708090a0b0c0d0e0f10111213', 'a8ba0048d75084603eb8423a09b7bf7595c871f6']
+];
+
+// IEEE 1619 vectors 4 and 10: only the first and last 32 bytes of the sector are checked
+const SECTOR_VECTORS = [
+  ['Vector4', '2718281828459045235360287471352631415926535897932384626433832795', 0, '27a7479befa1d476489f308cd4cfa6e2a96e4bbe3208ff25287dd3819616e89c', 'eb4a427d1923ce3ff262735779a418f20a282df920147beabe421ee5319d0568'],
+  ['Vector10', '27182818284590452353602874713526624977572470936999595749669676273141592653589793238462643383279502884197169399375105820974944592', 0xff, '1c3b3a102f770386e4836c99e370cf9bea00803f5e482357a4ae12d414a3e63b', '773dad38014bd2092fa755c824bb5e54c4f36ffda9fcea70b9c6e693e148c151']
+];
+
+beforeAll(async () => {
+  await C.AES.loadWasm();
+});
+
+describe('mode-xts-test', () => {
+  test.each(VECTORS)(
+    'testEncrypt%s',
+    (name, key, sectorNumber, plaintext, expected) => {
+      expect(C.algo.AES.xtsEncrypt(hex(key), sectorNumber, hex(plaintext)).toString()).toBe(expected);
+    }
+  );
+
+  test.each(VECTORS)(
+    'testDecrypt%s',
+    (name, key, sectorNumber, plaintext, ciphertext) => {
+      expect(C.algo.AES.xtsDecrypt(hex(key), sectorNumber, hex(ciphertext)).toString()).toBe(plaintext);
+    }
+  );
+
+  test.each(SECTOR_VECTORS)(
+    'testSector%s',
+    (name, key, sectorNumber, expectedHead, expectedTail) => {
+      const ciphertext = C.algo.AES.xtsEncrypt(hex(key), sectorNumber, hex(SECTOR_512)).toString();
+
+      expect(ciphertext.slice(0, 64)).toBe(expectedHead);
+      expect(ciphertext.slice(-64)).toBe(expectedTail);
+      expect(C.algo.AES.xtsDecrypt(hex(key), sectorNumber, hex(ciphertext)).toString()).toBe(SECTOR_512);
+    }
+  );
+
+  test('testInvalidParameters', () => {
+    const key = hex(VECTORS[1][1]);
+    expect(() => C.algo.AES.xtsEncrypt(hex('00000000000000000000000000000000'), 0, hex(VECTORS[0][3])))
+      .toThrow('XTS-AES requires a 256 or 512 bit key');
+    expect(() => C.algo.AES.xtsEncrypt(key, -1, hex(VECTORS[0][3])))
+      .toThrow('XTS-AES sector number must be a non-negative integer');
+    expect(() => C.algo.AES.xtsEncrypt(hex('1111111111111111111111111111111111111111111111111111111111111111'), 0, hex(VECTORS[0][3])))
+      .toThrow('XTS-AES key halves must differ');
+    expect(() => C.algo.AES.xtsEncrypt(key, 0, hex('000102030405060708090a0b0c0d0e')))
+      .toThrow('XTS-AES data unit must be between 16 bytes and 2^20 blocks');
+  });
+});
@@ -37,6 +37,87 @@ interface WasmCipherStatic extends CipherStatic {
     loadWasm(): Promise<void>;
 }
 
+interface AESStatic extends WasmCipherStatic {
+    /**
+     * Deterministic authenticated encryption with AES-SIV (RFC 5297).
+     *
+     * @param key The double-length key: 256, 384 or 512 bits.
+     * @param message The message to encrypt.
+     * @param aad The associated data components. A nonce is simply one of them.
+     *
+     * @return The synthetic IV followed by the ciphertext.
+     */
+    sivEncrypt(key: WordArray, message: WordArray | string, aad?: Array<WordArray | string>): WordArray;
+
+    /**
+     * AES-SIV decryption (RFC 5297). Throws when the ciphertext or associated data is not authentic.
+     */
+    sivDecrypt(key: WordArray, ciphertext: WordArray, aad?: Array<WordArray | string>): WordArray;
+
+    /**
+     * Nonce misuse-resistant authenticated encryption with AES-GCM-SIV (RFC 8452).
+     *
+     * @param key The 128 or 256 bit key.
+     * @param nonce The 96-bit nonce.
+     * @param message The message to encrypt.
+     * @param aad The additional authenticated data.
+     *
+     * @return The ciphertext followed by the 128-bit tag.
+     */
+    gcmSivEncrypt(key: WordArray, nonce: WordArray, message: WordArray | string, aad?: WordArray | string): WordArray;
+
+    /**
+     * AES-GCM-SIV decryption (RFC 8452). Throws when the ciphertext or associated data is not authentic.
+     */
+    gcmSivDecrypt(key: WordArray, nonce: WordArray, ciphertext: WordArray, aad?: WordArray | string): WordArray;
+
+    /**
+     * XTS-AES encryption of one data unit, such as a storage sector (IEEE 1619).
+     *
+     * @param key The double-length key: 256 or 512 bits. Its two halves must differ.
+     * @param sectorNumber The data unit sequence number, used as the tweak.
+     * @param data The data unit, at least 16 bytes. Partial last blocks use ciphertext stealing.
+     *
+     * @return The ciphertext, with the same length as the data unit.
+     */
+    xtsEncrypt(key: WordArray, sectorNumber: number, data: WordArray): WordArray;
+
+    /**
+     * XTS-AES decryption of one data unit (IEEE 1619).
+     */
+    xtsDecrypt(key: WordArray, sectorNumber: number, data: WordArray): WordArray;
+
+    /**
+     * AES Key Wrap (RFC 3394), or Key Wrap with Padding (RFC 5649) when `cfg.padding` is set.
+     *
+     * @param kek The 128, 192 or 256 bit key-encryption key.
+     * @param key The key to wrap.
+     *
+     * @return The wrapped key.
+     */
+    wrapKey(kek: WordArray, key: WordArray, cfg?: { padding?: boolean }): WordArray;
+
+    /**
+     * AES Key Unwrap (RFC 3394), or Key Unwrap with Padding (RFC 5649) when `cfg.padding` is set.
+     * Throws when the integrity check fails.
+     */
+    unwrapKey(kek: WordArray, wrapped: WordArray, cfg?: { padding?: boolean }): WordArray;
+
+    /**
+     * Encrypts or decrypts a slice of an AES-CTR stream that starts `byteOffset` bytes in,
+     * without processing what comes before it.
+     *
+     * @param key The 128, 192 or 256 bit key.
+     * @param iv The initial counter block of the stream.
+     * @param byteOffset The position of the slice in the stream. It does not need to be block aligned.
+     * @param data The slice to encrypt or decrypt.
+     * @param cfg `mode` may be a CTR mode with a narrower counter, see CTR.withCounterWidth.
+     *
+     * @return The processed slice.
+     */
+    cryptCtrAt(key: WordArray, iv: WordArray, byteOffset: number, data: WordArray, cfg?: { mode?: BlockCipherMode }): WordArray;
+}
+
 /**
  * encryptPadding: encrypt padding mode, values may be 'OAEP'(default)/'PKCS1V15'
  * signPadding: sign padding mode, values may be 'PSS'(default)/'PKCS1V15'
@@ -395,6 +476,31 @@ interface Hasher {
      *     var hash = hasher.finalize(wordArray);
      */
     finalize(messageUpdate?: WordArray | string): WordArray;
+    /**
+     * Exports the context of the hash in progress: the algorithm id, the configuration,
+     * the intermediate state, the buffered bytes and the total length, in a versioned format.
+     *
+     * @return The serialized context.
+     *
+     * @example
+     *
+     *     var context = hasher.update('first part').exportState();
+     */
+    exportState(): WordArray;
+    /**
+     * Resumes a hash from a context returned by exportState.
+     * The hasher must be of the same algorithm, created with the same configuration,
+     * or an error is thrown.
+     *
+     * @param context The serialized context.
+     *
+     * @return This hasher.
+     *
+     * @example
+     *
+     *     var hash = CryptoJSWasm.algo.SHA256.create().importState(context).finalize('second part');
+     */
+    importState(context: WordArray): this;
 }
 
 interface HasherStatic {
@@ -414,6 +520,77 @@ interface HasherHelper {
     (message: WordArray | string, cfg?: object): WordArray;
 }
 
+/**
+ * Hasher with an extendable output, such as SHAKE.
+ */
+interface XofHasher extends Hasher {
+    /**
+     * Reads the next bytes of output.
+     * The first call completes absorption; later calls continue the same output stream.
+     *
+     * @param nBytes The number of bytes to read.
+     *
+     * @return The output bytes.
+     *
+     * @example
+     *
+     *     var first = shake.squeeze(32);
+     *     var next = shake.squeeze(32);
+     */
+    squeeze(nBytes: number): WordArray;
+}
+
+interface XofHasherStatic {
+    /**
+     * Initializes a newly created extendable-output hasher.
+     *
+     * @param cfg (Optional) The configuration options, e.g. { outputLength: 512 }.
+     *
+     * @example
+     *
+     *     var shake = CryptoJSWasm.algo.SHAKE256.create();
+     */
+    create(cfg?: object): XofHasher;
+    /**
+     * Async call to load thw wasm binary
+     */
+    loadWasm(): Promise<void>;
+}
+
+interface KmacStatic {
+    /**
+     * Initializes a newly created KMAC.
+     *
+     * @param key The secret key.
+     * @param cfg (Optional) The configuration options: outputLength, customization and xof.
+     *
+     * @example
+     *
+     *     var kmac = CryptoJSWasm.algo.KMAC256.create(key, { customization: 'My Tagged Application' });
+     */
+    create(key: WordArray | string, cfg?: object): XofHasher;
+    /**
+     * Async call to load thw wasm binary
+     */
+    loadWasm(): Promise<void>;
+}
+
+interface KmacHelper {
+    (message: WordArray | string, key: WordArray | string, cfg?: object): WordArray;
+    /**
+     * Async call to load thw wasm binary
+     */
+    loadWasm(): Promise<void>;
+}
+
+interface TupleHashHelper {
+    (tuple: Array<WordArray | string>, cfg?: object): WordArray;
+    /**
+     * Async call to load thw wasm binary
+     */
+    loadWasm(): Promise<void>;
+}
+
 interface HmacHasherHelper {
     (message: WordArray | string, key: WordArray | string): WordArray;
 }
@@ -536,6 +713,14 @@ interface CipherOption {
      */
     iv?: WordArray | undefined;
     format?: Format | undefined;
+    /**
+     * Additional authenticated data for authenticated modes such as GCM.
+     */
+    aad?: WordArray | string | undefined;
+    /**
+     * Authentication tag size in bits for authenticated modes. Default: 128
+     */
+    tagLength?: number | undefined;
     [key: string]: any;
 }
 
@@ -767,6 +952,133 @@ interface KDFOption {
     iterations?: number | undefined;
 }
 
+interface HKDFOption {
+    /**
+     * The key size in words to generate, at most 255 digests.
+     */
+    keySize?: number | undefined;
+    /**
+     * The key size in bytes, in place of keySize, for keys that are not whole words.
+     */
+    length?: number | undefined;
+    /**
+     * The hasher to use.
+     */
+    hasher?: WasmHasherStatic | undefined;
+}
+
+interface ScryptOption {
+    /**
+     * The key size in words to generate.
+     */
+    keySize?: number | undefined;
+    /**
+     * The CPU/memory cost, a power of 2 greater than 1.
+     */
+    N?: number | undefined;
+    /**
+     * The block size.
+     */
+    r?: number | undefined;
+    /**
+     * The parallelization.
+     */
+    p?: number | undefined;
+    /**
+     * The maximum number of bytes used, 128 * r * (N + p).
+     */
+    maxMemory?: number | undefined;
+}
+
+interface ScryptHelper {
+    (password: WordArray | string, salt: WordArray | string, cfg?: ScryptOption): WordArray;
+
+    /**
+     * Async call to load thw wasm binary
+     */
+    loadWasm(): Promise<void>;
+}
+
+interface Argon2Option {
+    /**
+     * The variant, 'argon2d', 'argon2i' or 'argon2id'.
+     */
+    type?: 'argon2d' | 'argon2i' | 'argon2id' | undefined;
+    /**
+     * The key size in words to generate.
+     */
+    keySize?: number | undefined;
+    /**
+     * The number of passes over the memory.
+     */
+    iterations?: number | undefined;
+    /**
+     * The memory size in KiB, at least 8 * parallelism.
+     */
+    memory?: number | undefined;
+    /**
+     * The number of lanes, computed one after the other.
+     */
+    parallelism?: number | undefined;
+    /**
+     * A secret key, kept apart from the stored hash.
+     */
+    secret?: WordArray | string | undefined;
+    /**
+     * Associated data.
+     */
+    associatedData?: WordArray | string | undefined;
+}
+
+interface Argon2Helper {
+    (password: WordArray | string, salt: WordArray | string, cfg?: Argon2Option): WordArray;
+
+    /**
+     * Computes the Argon2 hash of a password and encodes it in the PHC string format.
+     */
+    encode(password: WordArray | string, salt: WordArray | string, cfg?: Argon2Option): string;
+
+    /**
+     * Checks a password against a hash in the PHC string format.
+     */
+    verify(encoded: string, password: WordArray | string, cfg?: Argon2Option): boolean;
+
+    /**
+     * Async call to load thw wasm binary
+     */
+    loadWasm(): Promise<void>;
+}
+
+interface BcryptOption {
+    /**
+     * The base-2 logarithm of the work factor, from 4 to 31.
+     */
+    cost?: number | undefined;
+    /**
+     * The version written in encoded hashes.
+     */
+    version?: '2a' | '2b' | '2y' | undefined;
+}
+
+interface BcryptHelper {
+    (password: WordArray | string, salt: WordArray, cfg?: BcryptOption): WordArray;
+
+    /**
+     * Computes the bcrypt hash of a password and encodes it as a modular crypt string.
+     */
+    encode(password: WordArray | string, salt: WordArray, cfg?: BcryptOption): string;
+
+    /**
+     * Checks a password against a bcrypt modular crypt string.
+     */
+    verify(encoded: string, password: WordArray | string): boolean;
+
+    /**
+     * Async call to load thw wasm binary
+     */
+    loadWasm(): Promise<void>;
+}
+
 declare global {
     namespace CryptoJSWasm {
         /**
@@ -1234,14 +1546,39 @@ declare global {
              */
             const CBC: BlockCipherMode;
 
+            /**
+             * CBC with ciphertext stealing, NIST SP 800-38A addendum variants 1 to 3 (AES, DES,
+             * TripleDES, Blowfish and SM4 only). No padding is applied and the ciphertext is as long as
+             * the plaintext, which must be at least one block.
+             */
+            const CBCCS1: BlockCipherMode;
+            const CBCCS2: BlockCipherMode;
+            const CBCCS3: BlockCipherMode;
+
+            /**
+             * Counter with CBC-MAC mode (AES only). The IV is the 7 to 13 byte nonce and the
+             * authentication tag is appended to the ciphertext.
+             */
+            const CCM: BlockCipherMode;
+
             /**
              * Cipher Feedback block mode.
              */
             const CFB: BlockCipherMode;
             /**
-             * Counter block mode.
+             * Counter block mode. The whole block is a big-endian counter by default.
              */
-            const CTR: BlockCipherMode;
+            const CTR: BlockCipherMode & {
+                /**
+                 * Creates a CTR mode that only counts over the low `counterBits` bits of the
+                 * counter block, keeping the rest of the IV as a fixed nonce.
+                 *
+                 * @example
+                 *
+                 *     var mode = CryptoJSWasm.mode.CTR.withCounterWidth(32);
+                 */
+                withCounterWidth(counterBits: number): BlockCipherMode;
+            };
             /**
              * @preserve
              * Counter block mode compatible with  Dr Brian Gladman fileenc.c
@@ -1258,6 +1595,11 @@ declare global {
              * Electronic Codebook block mode.
              */
             const ECB: BlockCipherMode;
+
+            /**
+             * Galois/Counter Mode (AES only). The authentication tag is appended to the ciphertext.
+             */
+            const GCM: BlockCipherMode;
         }
 
         /**
@@ -1338,10 +1680,90 @@ declare global {
              * SHA-384 hash algorithm.
              */
             const SHA384: WasmHasherStatic;
+            /**
+             * SHA-512/224 hash algorithm.
+             */
+            const SHA512_224: WasmHasherStatic;
+            /**
+             * SHA-512/256 hash algorithm.
+             */
+            const SHA512_256: WasmHasherStatic;
             /**
              * SHA-3 hash algorithm.
              */
             const SHA3: WasmHasherStatic;
+            /**
+             * FIPS 202 SHA3-224 hash algorithm.
+             */
+            const SHA3_224: WasmHasherStatic;
+            /**
+             * FIPS 202 SHA3-256 hash algorithm.
+             */
+            const SHA3_256: WasmHasherStatic;
+            /**
+             * FIPS 202 SHA3-384 hash algorithm.
+             */
+            const SHA3_384: WasmHasherStatic;
+            /**
+             * FIPS 202 SHA3-512 hash algorithm.
+             */
+            const SHA3_512: WasmHasherStatic;
+            /**
+             * Keccak-256 hash algorithm, as used by Ethereum.
+             */
+            const Keccak256: WasmHasherStatic;
+            /**
+             * SHAKE128 extendable-output function.
+             */
+            const SHAKE128: XofHasherStatic;
+            /**
+             * SHAKE256 extendable-output function.
+             */
+            const SHAKE256: XofHasherStatic;
+            /**
+             * cSHAKE128 customizable extendable-output function.
+             */
+            const CSHAKE128: XofHasherStatic;
+            /**
+             * cSHAKE256 customizable extendable-output function.
+             */
+            const CSHAKE256: XofHasherStatic;
+            /**
+             * KMAC128 keyed hash.
+             */
+            const KMAC128: KmacStatic;
+            /**
+             * KMAC256 keyed hash.
+             */
+            const KMAC256: KmacStatic;
+            /**
+             * BLAKE2b hash algorithm, configured with outputLength, key, salt and personalization.
+             */
+            const BLAKE2b: WasmHasherStatic;
+            /**
+             * BLAKE2s hash algorithm, configured with outputLength, key, salt and personalization.
+             */
+            const BLAKE2s: WasmHasherStatic;
+            /**
+             * BLAKE3 hash algorithm, configured with outputLength, and key or context.
+             */
+            const BLAKE3: XofHasherStatic;
+            /**
+             * SM3 hash algorithm (GB/T 32905).
+             */
+            const SM3: WasmHasherStatic;
+            /**
+             * Whirlpool hash algorithm (ISO/IEC 10118-3).
+             */
+            const Whirlpool: WasmHasherStatic;
+            /**
+             * Streebog-256 hash algorithm (GOST R 34.11-2012, RFC 6986).
+             */
+            const Streebog256: WasmHasherStatic;
+            /**
+             * Streebog-512 hash algorithm (GOST R 34.11-2012, RFC 6986).
+             */
+            const Streebog512: WasmHasherStatic;
             /**
              * RIPEMD160 hash algorithm.
              */
@@ -1401,26 +1823,103 @@ declare global {
                 finalize(messageUpdate?: WordArray | string): WordArray;
             }
             /**
-             * Password-Based Key Derivation Function 2 algorithm.
+             * AES-CMAC (OMAC1) algorithm, RFC 4493.
              */
-            abstract class PBKDF2 {
+            abstract class CMAC {
                 /**
-                 * Initializes a newly created key derivation function.
+                 * Initializes a newly created CMAC.
                  *
-                 * @param cfg (Optional) The configuration options to use for the derivation.
+                 * @param key The 128, 192 or 256 bit AES key.
                  *
                  * @example
                  *
-                 *     var kdf = CryptoJSWasm.algo.PBKDF2.create();
-                 *     var kdf = CryptoJSWasm.algo.PBKDF2.create({ keySize: 8 });
-                 *     var kdf = CryptoJSWasm.algo.PBKDF2.create({ keySize: 8, iterations: 1000 });
+                 *     var cmacHasher = CryptoJSWasm.algo.CMAC.create(key);
                  */
-                static create(cfg?: KDFOption): PBKDF2;
+                static create(key: WordArray | string): CMAC;
+                /**
+                 * Loads the AES wasm binary, which CMAC runs on.
+                 */
+                static loadWasm(): Promise<void>;
+                /**
+                 * Resets this CMAC to its initial state.
+                 */
+                reset(): void;
 
                 /**
-                 * Computes the Password-Based Key Derivation Function 2.
+                 * Updates this CMAC with a message.
                  *
-                 * @param password The password.
+                 * @param messageUpdate The message to append.
+                 *
+                 * @return This CMAC instance.
+                 */
+                update(messageUpdate: WordArray | string): this;
+
+                /**
+                 * Finalizes the CMAC computation.
+                 * Note that the finalize operation is effectively a destructive, read-once operation.
+                 *
+                 * @param messageUpdate (Optional) A final message update.
+                 *
+                 * @return The 128-bit CMAC.
+                 */
+                finalize(messageUpdate?: WordArray | string): WordArray;
+            }
+            /**
+             * Password-Based Key Derivation Function 2 algorithm.
+             */
+            abstract class PBKDF2 {
+                /**
+                 * Initializes a newly created key derivation function.
+                 *
+                 * @param cfg (Optional) The configuration options to use for the derivation.
+                 *
+                 * @example
+                 *
+                 *     var kdf = CryptoJSWasm.algo.PBKDF2.create();
+                 *     var kdf = CryptoJSWasm.algo.PBKDF2.create({ keySize: 8 });
+                 *     var kdf = CryptoJSWasm.algo.PBKDF2.create({ keySize: 8, iterations: 1000 });
+                 */
+                static create(cfg?: KDFOption): PBKDF2;
+
+                /**
+                 * Computes the Password-Based Key Derivation Function 2.
+                 *
+                 * @param password The password.
+                 * @param salt A salt.
+                 *
+                 * @return The derived key.
+                 *
+                 * @example
+                 *
+                 *     var key = kdf.compute(password, salt);
+                 */
+                compute(password: WordArray | string, salt: WordArray): WordArray;
+            }
+            /**
+             * scrypt key derivation function (RFC 7914).
+             */
+            abstract class Scrypt {
+                /**
+                 * Async call to load thw wasm binary
+                 */
+                static loadWasm(): Promise<void>;
+
+                /**
+                 * Initializes a newly created key derivation function.
+                 *
+                 * @param cfg (Optional) The configuration options to use for the derivation.
+                 *
+                 * @example
+                 *
+                 *     var kdf = CryptoJSWasm.algo.Scrypt.create();
+                 *     var kdf = CryptoJSWasm.algo.Scrypt.create({ keySize: 16, N: 1024, r: 8, p: 16 });
+                 */
+                static create(cfg?: ScryptOption): Scrypt;
+
+                /**
+                 * Computes the scrypt key derivation function.
+                 *
+                 * @param password The password.
                  * @param salt A salt.
                  *
                  * @return The derived key.
@@ -1429,7 +1928,192 @@ declare global {
                  *
                  *     var key = kdf.compute(password, salt);
                  */
+                compute(password: WordArray | string, salt: WordArray | string): WordArray;
+            }
+            /**
+             * Argon2 password hashing (RFC 9106), in its Argon2d, Argon2i and Argon2id variants.
+             */
+            abstract class Argon2 {
+                /**
+                 * Async call to load thw wasm binary
+                 */
+                static loadWasm(): Promise<void>;
+
+                /**
+                 * Initializes a newly created password hashing function.
+                 *
+                 * @param cfg (Optional) The configuration options to use for the hashing.
+                 *
+                 * @example
+                 *
+                 *     var kdf = CryptoJSWasm.algo.Argon2.create();
+                 *     var kdf = CryptoJSWasm.algo.Argon2.create({ type: 'argon2i', iterations: 4, memory: 19456, parallelism: 1 });
+                 */
+                static create(cfg?: Argon2Option): Argon2;
+
+                /**
+                 * Computes the Argon2 hash of a password.
+                 *
+                 * @param password The password.
+                 * @param salt A salt of at least 8 bytes.
+                 *
+                 * @return The derived key.
+                 *
+                 * @example
+                 *
+                 *     var key = kdf.compute(password, salt);
+                 */
+                compute(password: WordArray | string, salt: WordArray | string): WordArray;
+
+                /**
+                 * Computes the Argon2 hash of a password and encodes it in the PHC string format.
+                 *
+                 * @param password The password.
+                 * @param salt A salt of at least 8 bytes.
+                 *
+                 * @return The encoded hash.
+                 *
+                 * @example
+                 *
+                 *     var encoded = kdf.encode(password, salt);
+                 */
+                encode(password: WordArray | string, salt: WordArray | string): string;
+
+                /**
+                 * Checks a password against a hash in the PHC string format.
+                 *
+                 * @param encoded The encoded hash.
+                 * @param password The password.
+                 *
+                 * @return Whether the password matches.
+                 *
+                 * @example
+                 *
+                 *     var matches = kdf.verify(encoded, password);
+                 */
+                verify(encoded: string, password: WordArray | string): boolean;
+            }
+            /**
+             * bcrypt password hashing, on the Blowfish key schedule.
+             */
+            abstract class Bcrypt {
+                /**
+                 * Async call to load thw wasm binary
+                 */
+                static loadWasm(): Promise<void>;
+
+                /**
+                 * Initializes a newly created password hashing function.
+                 *
+                 * @param cfg (Optional) The configuration options to use for the hashing.
+                 *
+                 * @example
+                 *
+                 *     var kdf = CryptoJSWasm.algo.Bcrypt.create();
+                 *     var kdf = CryptoJSWasm.algo.Bcrypt.create({ cost: 12, version: '2y' });
+                 */
+                static create(cfg?: BcryptOption): Bcrypt;
+
+                /**
+                 * Computes the bcrypt hash of a password.
+                 *
+                 * @param password The password.
+                 * @param salt A 16-byte salt.
+                 *
+                 * @return The 23-byte hash.
+                 *
+                 * @example
+                 *
+                 *     var hash = kdf.compute(password, salt);
+                 */
                 compute(password: WordArray | string, salt: WordArray): WordArray;
+
+                /**
+                 * Computes the bcrypt hash of a password and encodes it as a modular crypt string.
+                 *
+                 * @param password The password.
+                 * @param salt A 16-byte salt.
+                 *
+                 * @return The encoded hash.
+                 *
+                 * @example
+                 *
+                 *     var encoded = kdf.encode(password, CryptoJSWasm.lib.WordArray.random(16));
+                 */
+                encode(password: WordArray | string, salt: WordArray): string;
+
+                /**
+                 * Checks a password against a bcrypt modular crypt string in the '$2a$', '$2b$' or '$2y$' versions.
+                 *
+                 * @param encoded The encoded hash.
+                 * @param password The password.
+                 *
+                 * @return Whether the password matches.
+                 *
+                 * @example
+                 *
+                 *     var matches = kdf.verify(encoded, password);
+                 */
+                verify(encoded: string, password: WordArray | string): boolean;
+            }
+            /**
+             * HMAC-based Extract-and-Expand Key Derivation Function (RFC 5869).
+             */
+            abstract class HKDF {
+                /**
+                 * Initializes a newly created key derivation function.
+                 *
+                 * @param cfg (Optional) The configuration options to use for the derivation.
+                 *
+                 * @example
+                 *
+                 *     var kdf = CryptoJSWasm.algo.HKDF.create();
+                 *     var kdf = CryptoJSWasm.algo.HKDF.create({ keySize: 16, hasher: CryptoJSWasm.algo.SHA512 });
+                 */
+                static create(cfg?: HKDFOption): HKDF;
+
+                /**
+                 * Derives a key from input keying material: HKDF-Extract, then HKDF-Expand.
+                 *
+                 * @param key The input keying material.
+                 * @param salt (Optional) A salt.
+                 * @param info (Optional) Context and application specific information.
+                 *
+                 * @return The derived key.
+                 *
+                 * @example
+                 *
+                 *     var key = kdf.compute(key, salt, info);
+                 */
+                compute(key: WordArray | string, salt?: WordArray | string, info?: WordArray | string): WordArray;
+
+                /**
+                 * HKDF-Extract: concentrates the input keying material into a pseudorandom key.
+                 *
+                 * @param key The input keying material.
+                 * @param salt (Optional) A salt.
+                 *
+                 * @return The pseudorandom key.
+                 *
+                 * @example
+                 *
+                 *     var prk = kdf.extract(key, salt);
+                 */
+                extract(key: WordArray | string, salt?: WordArray | string): WordArray;
+
+                /**
+                 * HKDF-Expand: expands a pseudorandom key to the configured key length.
+                 *
+                 * @param prk A pseudorandom key.
+                 * @param info (Optional) Context and application specific information.
+                 *
+                 * @return The derived key.
+                 *
+                 * @example
+                 *
+                 *     var key = kdf.expand(prk, info);
+                 */
+                expand(prk: WordArray | string, info?: WordArray | string): WordArray;
             }
             /**
              * This key derivation function is meant to conform with EVP_BytesToKey.
@@ -1467,7 +2151,7 @@ declare global {
             /**
              * AES block cipher algorithm.
              */
-            const AES: WasmCipherStatic;
+            const AES: AESStatic;
 
             /**
              * Blowfish block cipher algorithm.
@@ -1484,6 +2168,11 @@ declare global {
              */
             const TripleDES: WasmCipherStatic;
 
+            /**
+             * SM4 block cipher algorithm (GB/T 32907).
+             */
+            const SM4: WasmCipherStatic;
+
             /**
              * RC4 stream cipher algorithm.
              */
@@ -1844,10 +2533,10 @@ declare global {
          *
          * @example
          *
-         *     var hash = CryptoJSWasm.SHA3('message');
-         *     var hash = CryptoJSWasm.SHA3(wordArray);
+         *     var hash = CryptoJSWasm.SHA512_224('message');
+         *     var hash = CryptoJSWasm.SHA512_224(wordArray);
          */
-        export const SHA3: WasmHasherHelper;
+        export const SHA512_224: WasmHasherHelper;
         /**
          * Shortcut function to the HMAC's object interface.
          *
@@ -1858,9 +2547,9 @@ declare global {
          *
          * @example
          *
-         *     var hmac = CryptoJSWasm.HmacSHA3(message, key);
+         *     var hmac = CryptoJSWasm.HmacSHA512_224(message, key);
          */
-        export const HmacSHA3: WasmHmacHasherHelper;
+        export const HmacSHA512_224: WasmHmacHasherHelper;
 
         /**
          * Shortcut function to the hasher's object interface.
@@ -1871,10 +2560,10 @@ declare global {
          *
          * @example
          *
-         *     var hash = CryptoJSWasm.RIPEMD160('message');
-         *     var hash = CryptoJSWasm.RIPEMD160(wordArray);
+         *     var hash = CryptoJSWasm.SHA512_256('message');
+         *     var hash = CryptoJSWasm.SHA512_256(wordArray);
          */
-        export const RIPEMD160: WasmHasherHelper;
+        export const SHA512_256: WasmHasherHelper;
         /**
          * Shortcut function to the HMAC's object interface.
          *
@@ -1885,65 +2574,653 @@ declare global {
          *
          * @example
          *
-         *     var hmac = CryptoJSWasm.HmacRIPEMD160(message, key);
+         *     var hmac = CryptoJSWasm.HmacSHA512_256(message, key);
          */
-        export const HmacRIPEMD160: WasmHmacHasherHelper;
+        export const HmacSHA512_256: WasmHmacHasherHelper;
+
         /**
-         * Computes the Password-Based Key Derivation Function 2.
+         * Shortcut function to the hasher's object interface.
          *
-         * @param password The password.
-         * @param salt A salt.
-         * @param cfg (Optional) The configuration options to use for this computation.
+         * @param message The message to hash.
          *
-         * @return The derived key.
+         * @return The hash.
          *
          * @example
          *
-         *     var key = CryptoJSWasm.PBKDF2(password, salt);
-         *     var key = CryptoJSWasm.PBKDF2(password, salt, { keySize: 8 });
-         *     var key = CryptoJSWasm.PBKDF2(password, salt, { keySize: 8, iterations: 1000 });
+         *     var hash = CryptoJSWasm.SHA3('message');
+         *     var hash = CryptoJSWasm.SHA3(wordArray);
          */
-        export function PBKDF2(password: WordArray | string, salt: WordArray | string, cfg?: KDFOption): WordArray;
-
+        export const SHA3: WasmHasherHelper;
         /**
-         * Shortcut functions to the cipher's object interface.
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
          *
          * @example
          *
-         *     var ciphertext = CryptoJSWasm.AES.encrypt(message, key, cfg);
-         *     var plaintext  = CryptoJSWasm.AES.decrypt(ciphertext, key, cfg);
+         *     var hmac = CryptoJSWasm.HmacSHA3(message, key);
          */
-        export const AES: WasmCipherHelper;
+        export const HmacSHA3: WasmHmacHasherHelper;
 
         /**
-         * Shortcut functions to the cipher's object interface.
+         * Shortcut function to the SHA3-224 hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
          *
          * @example
          *
-         *     var ciphertext = CryptoJSWasm.Blowfish.encrypt(message, key, cfg);
-         *     var plaintext  = CryptoJSWasm.Blowfish.decrypt(ciphertext, key, cfg);
+         *     var hash = CryptoJSWasm.SHA3_224('message');
+         *     var hash = CryptoJSWasm.SHA3_224(wordArray);
          */
-        export const Blowfish: WasmCipherHelper;
-
+        export const SHA3_224: WasmHasherHelper;
         /**
-         * Shortcut functions to the cipher's object interface.
+         * Shortcut function to the SHA3-224 HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
          *
          * @example
          *
-         *     var ciphertext = CryptoJSWasm.DES.encrypt(message, key, cfg);
-         *     var plaintext  = CryptoJSWasm.DES.decrypt(ciphertext, key, cfg);
+         *     var hmac = CryptoJSWasm.HmacSHA3_224(message, key);
          */
-        export const DES: WasmCipherHelper;
+        export const HmacSHA3_224: WasmHmacHasherHelper;
 
         /**
-         * Shortcut functions to the cipher's object interface.
+         * Shortcut function to the SHA3-256 hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
          *
          * @example
          *
-         *     var ciphertext = CryptoJSWasm.TripleDES.encrypt(message, key, cfg);
-         *     var plaintext  = CryptoJSWasm.TripleDES.decrypt(ciphertext, key, cfg);
+         *     var hash = CryptoJSWasm.SHA3_256('message');
+         *     var hash = CryptoJSWasm.SHA3_256(wordArray);
          */
-        export const TripleDES: WasmCipherHelper;
+        export const SHA3_256: WasmHasherHelper;
+        /**
+         * Shortcut function to the SHA3-256 HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacSHA3_256(message, key);
+         */
+        export const HmacSHA3_256: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the SHA3-384 hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.SHA3_384('message');
+         *     var hash = CryptoJSWasm.SHA3_384(wordArray);
+         */
+        export const SHA3_384: WasmHasherHelper;
+        /**
+         * Shortcut function to the SHA3-384 HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacSHA3_384(message, key);
+         */
+        export const HmacSHA3_384: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the SHA3-512 hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.SHA3_512('message');
+         *     var hash = CryptoJSWasm.SHA3_512(wordArray);
+         */
+        export const SHA3_512: WasmHasherHelper;
+        /**
+         * Shortcut function to the SHA3-512 HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacSHA3_512(message, key);
+         */
+        export const HmacSHA3_512: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the Keccak-256 hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.Keccak256('message');
+         *     var hash = CryptoJSWasm.Keccak256(wordArray);
+         */
+        export const Keccak256: WasmHasherHelper;
+        /**
+         * Shortcut function to the Keccak-256 HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacKeccak256(message, key);
+         */
+        export const HmacKeccak256: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options, e.g. { outputLength: 512 }.
+         *
+         * @return The output.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.SHAKE128('message');
+         *     var hash = CryptoJSWasm.SHAKE128(wordArray, { outputLength: 512 });
+         */
+        export const SHAKE128: WasmHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options, e.g. { outputLength: 912 }.
+         *
+         * @return The output.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.SHAKE256('message');
+         *     var hash = CryptoJSWasm.SHAKE256(wordArray, { outputLength: 912 });
+         */
+        export const SHAKE256: WasmHasherHelper;
+
+        /**
+         * Shortcut function to the cSHAKE128 object interface.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options: outputLength, functionName and customization.
+         *
+         * @return The output.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.CSHAKE128('message', { customization: 'Email Signature' });
+         */
+        export const CSHAKE128: WasmHasherHelper;
+
+        /**
+         * Shortcut function to the cSHAKE256 object interface.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options: outputLength, functionName and customization.
+         *
+         * @return The output.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.CSHAKE256('message', { customization: 'Email Signature' });
+         */
+        export const CSHAKE256: WasmHasherHelper;
+
+        /**
+         * Shortcut function to the KMAC128 object interface.
+         *
+         * @param message The message to authenticate.
+         * @param key The secret key.
+         * @param cfg (Optional) The configuration options: outputLength, customization and xof.
+         *
+         * @return The MAC.
+         *
+         * @example
+         *
+         *     var mac = CryptoJSWasm.KMAC128(message, key, { customization: 'My Tagged Application' });
+         */
+        export const KMAC128: KmacHelper;
+
+        /**
+         * Shortcut function to the KMAC256 object interface.
+         *
+         * @param message The message to authenticate.
+         * @param key The secret key.
+         * @param cfg (Optional) The configuration options: outputLength, customization and xof.
+         *
+         * @return The MAC.
+         *
+         * @example
+         *
+         *     var mac = CryptoJSWasm.KMAC256(message, key, { customization: 'My Tagged Application' });
+         */
+        export const KMAC256: KmacHelper;
+
+        /**
+         * TupleHash128 over a list of strings.
+         *
+         * @param tuple The elements to hash.
+         * @param cfg (Optional) The configuration options: outputLength, customization and xof.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.TupleHash128(['alice', 'bob'], { customization: 'My Tuple App' });
+         */
+        export const TupleHash128: TupleHashHelper;
+
+        /**
+         * TupleHash256 over a list of strings.
+         *
+         * @param tuple The elements to hash.
+         * @param cfg (Optional) The configuration options: outputLength, customization and xof.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.TupleHash256(['alice', 'bob'], { customization: 'My Tuple App' });
+         */
+        export const TupleHash256: TupleHashHelper;
+
+        /**
+         * ParallelHash128, which hashes the message in independent chunks.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options: blockLength (bytes), outputLength, customization and xof.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.ParallelHash128(wordArray, { blockLength: 8192 });
+         */
+        export const ParallelHash128: WasmHasherHelper;
+
+        /**
+         * ParallelHash256, which hashes the message in independent chunks.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options: blockLength (bytes), outputLength, customization and xof.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.ParallelHash256(wordArray, { blockLength: 8192 });
+         */
+        export const ParallelHash256: WasmHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options: outputLength, key, salt and personalization.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.BLAKE2b('message');
+         *     var hash = CryptoJSWasm.BLAKE2b(wordArray, { outputLength: 256, key: 'secret' });
+         */
+        export const BLAKE2b: WasmHasherHelper;
+        /**
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacBLAKE2b(message, key);
+         */
+        export const HmacBLAKE2b: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options: outputLength, key, salt and personalization.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.BLAKE2s('message');
+         *     var hash = CryptoJSWasm.BLAKE2s(wordArray, { outputLength: 128, key: 'secret' });
+         */
+        export const BLAKE2s: WasmHasherHelper;
+        /**
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacBLAKE2s(message, key);
+         */
+        export const HmacBLAKE2s: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the BLAKE3 hasher's object interface.
+         *
+         * @param message The message to hash.
+         * @param cfg (Optional) The configuration options: outputLength, and key (keyed hash) or context (key derivation).
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.BLAKE3('message');
+         *     var mac = CryptoJSWasm.BLAKE3('message', { key: key32Bytes });
+         */
+        export const BLAKE3: WasmHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.SM3('message');
+         *     var hash = CryptoJSWasm.SM3(wordArray);
+         */
+        export const SM3: WasmHasherHelper;
+        /**
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacSM3(message, key);
+         */
+        export const HmacSM3: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.Whirlpool('message');
+         *     var hash = CryptoJSWasm.Whirlpool(wordArray);
+         */
+        export const Whirlpool: WasmHasherHelper;
+        /**
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacWhirlpool(message, key);
+         */
+        export const HmacWhirlpool: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.Streebog256('message');
+         *     var hash = CryptoJSWasm.Streebog256(wordArray);
+         */
+        export const Streebog256: WasmHasherHelper;
+        /**
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacStreebog256(message, key);
+         */
+        export const HmacStreebog256: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.Streebog512('message');
+         *     var hash = CryptoJSWasm.Streebog512(wordArray);
+         */
+        export const Streebog512: WasmHasherHelper;
+        /**
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacStreebog512(message, key);
+         */
+        export const HmacStreebog512: WasmHmacHasherHelper;
+
+        /**
+         * Shortcut function to the hasher's object interface.
+         *
+         * @param message The message to hash.
+         *
+         * @return The hash.
+         *
+         * @example
+         *
+         *     var hash = CryptoJSWasm.RIPEMD160('message');
+         *     var hash = CryptoJSWasm.RIPEMD160(wordArray);
+         */
+        export const RIPEMD160: WasmHasherHelper;
+        /**
+         * Shortcut function to the HMAC's object interface.
+         *
+         * @param message The message to hash.
+         * @param key The secret key.
+         *
+         * @return The HMAC.
+         *
+         * @example
+         *
+         *     var hmac = CryptoJSWasm.HmacRIPEMD160(message, key);
+         */
+        export const HmacRIPEMD160: WasmHmacHasherHelper;
+        /**
+         * Computes the Password-Based Key Derivation Function 2.
+         *
+         * @param password The password.
+         * @param salt A salt.
+         * @param cfg (Optional) The configuration options to use for this computation.
+         *
+         * @return The derived key.
+         *
+         * @example
+         *
+         *     var key = CryptoJSWasm.PBKDF2(password, salt);
+         *     var key = CryptoJSWasm.PBKDF2(password, salt, { keySize: 8 });
+         *     var key = CryptoJSWasm.PBKDF2(password, salt, { keySize: 8, iterations: 1000 });
+         */
+        export function PBKDF2(password: WordArray | string, salt: WordArray | string, cfg?: KDFOption): WordArray;
+
+        /**
+         * Computes the scrypt key derivation function.
+         *
+         * @param password The password.
+         * @param salt A salt.
+         * @param cfg (Optional) The configuration options to use for this computation.
+         *
+         * @return The derived key.
+         *
+         * @example
+         *
+         *     var key = CryptoJSWasm.Scrypt(password, salt);
+         *     var key = CryptoJSWasm.Scrypt(password, salt, { keySize: 16, N: 1024, r: 8, p: 16 });
+         */
+        export const Scrypt: ScryptHelper;
+
+        /**
+         * Computes the Argon2 hash of a password.
+         *
+         * @param password The password.
+         * @param salt A salt of at least 8 bytes.
+         * @param cfg (Optional) The configuration options to use for this computation.
+         *
+         * @return The derived key.
+         *
+         * @example
+         *
+         *     var key = CryptoJSWasm.Argon2(password, salt);
+         *     var encoded = CryptoJSWasm.Argon2.encode(password, CryptoJSWasm.lib.WordArray.random(16));
+         *     var matches = CryptoJSWasm.Argon2.verify(encoded, password);
+         */
+        export const Argon2: Argon2Helper;
+
+        /**
+         * Computes the bcrypt hash of a password.
+         *
+         * @param password The password.
+         * @param salt A 16-byte salt.
+         * @param cfg (Optional) The configuration options to use for this computation.
+         *
+         * @return The 23-byte hash.
+         *
+         * @example
+         *
+         *     var encoded = CryptoJSWasm.Bcrypt.encode(password, CryptoJSWasm.lib.WordArray.random(16), { cost: 12 });
+         *     var matches = CryptoJSWasm.Bcrypt.verify(encoded, password);
+         */
+        export const Bcrypt: BcryptHelper;
+
+        /**
+         * Computes the HMAC-based Extract-and-Expand Key Derivation Function.
+         *
+         * @param key The input keying material.
+         * @param salt (Optional) A salt.
+         * @param info (Optional) Context and application specific information.
+         * @param cfg (Optional) The configuration options to use for this computation.
+         *
+         * @return The derived key.
+         *
+         * @example
+         *
+         *     var key = CryptoJSWasm.HKDF(key, salt, info);
+         *     var key = CryptoJSWasm.HKDF(key, salt, info, { keySize: 16, hasher: CryptoJSWasm.algo.SHA512 });
+         */
+        export function HKDF(key: WordArray | string, salt?: WordArray | string, info?: WordArray | string, cfg?: HKDFOption): WordArray;
+
+        /**
+         * Shortcut functions to the cipher's object interface.
+         *
+         * @example
+         *
+         *     var ciphertext = CryptoJSWasm.AES.encrypt(message, key, cfg);
+         *     var plaintext  = CryptoJSWasm.AES.decrypt(ciphertext, key, cfg);
+         */
+        export const AES: WasmCipherHelper;
+
+        /**
+         * Shortcut functions to the cipher's object interface.
+         *
+         * @example
+         *
+         *     var ciphertext = CryptoJSWasm.Blowfish.encrypt(message, key, cfg);
+         *     var plaintext  = CryptoJSWasm.Blowfish.decrypt(ciphertext, key, cfg);
+         */
+        export const Blowfish: WasmCipherHelper;
+
+        /**
+         * Shortcut functions to the cipher's object interface.
+         *
+         * @example
+         *
+         *     var ciphertext = CryptoJSWasm.DES.encrypt(message, key, cfg);
+         *     var plaintext  = CryptoJSWasm.DES.decrypt(ciphertext, key, cfg);
+         */
+        export const DES: WasmCipherHelper;
+
+        /**
+         * Shortcut functions to the cipher's object interface.
+         *
+         * @example
+         *
+         *     var ciphertext = CryptoJSWasm.TripleDES.encrypt(message, key, cfg);
+         *     var plaintext  = CryptoJSWasm.TripleDES.decrypt(ciphertext, key, cfg);
+         */
+        export const TripleDES: WasmCipherHelper;
+
+        /**
+         * Shortcut functions to the cipher's object interface.
+         *
+         * @example
+         *
+         *     var ciphertext = CryptoJSWasm.SM4.encrypt(message, key, cfg);
+         *     var plaintext  = CryptoJSWasm.SM4.decrypt(ciphertext, key, cfg);
+         */
+        export const SM4: WasmCipherHelper;
 
         /**
          * Shortcut functions to the cipher's object interface.